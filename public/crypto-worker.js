@@ -0,0 +1,52 @@
+// IronForge crypto worker - runs key derivation, keystore decryption and signing off the main thread.
+//
+// Loads the `crypto_ops` wasm module (src/bin/crypto_ops.rs) and runs the same Rust code as the
+// main-thread fallback.
+//
+// Protocol (structured clone):
+//   request:  { id, op, payload: [Uint8Array, ...] }   arguments arrive as transferred buffers
+//   response: { id, ok: true, result: ArrayBuffer } | { id, ok: false, error: string }
+//             { id, ok: false, unavailable: true }      module failed to load; caller falls back
+//
+// Arguments are zeroed inside the worker as soon as they have been consumed.
+'use strict';
+
+function zero(view) {
+  if (view && typeof view.fill === 'function') {
+    view.fill(0);
+  }
+}
+
+let ready;
+try {
+  importScripts('./crypto_ops.js');
+  ready = wasm_bindgen({ module_or_path: './crypto_ops_bg.wasm' }).then(() => {
+    if (typeof wasm_bindgen.run !== 'function') {
+      throw new Error('crypto_ops module has no run export');
+    }
+    return wasm_bindgen.run;
+  });
+} catch (err) {
+  ready = Promise.reject(err);
+}
+
+self.onmessage = async (event) => {
+  const { id, op, payload } = event.data || {};
+  const args = payload || [];
+  let run;
+  try {
+    run = await ready;
+  } catch (err) {
+    args.forEach(zero);
+    self.postMessage({ id, ok: false, unavailable: true });
+    return;
+  }
+  try {
+    const result = run(op, args).buffer;
+    self.postMessage({ id, ok: true, result }, [result]);
+  } catch (err) {
+    self.postMessage({ id, ok: false, error: String(err && err.message ? err.message : err) });
+  } finally {
+    args.forEach(zero);
+  }
+};
@@ -0,0 +1,30 @@
+//! Crypto Ops - 加密 Worker 中运行的 WASM 模块
+//!
+//! 由 Trunk 按 worker 方式构建为 `crypto_ops.js` / `crypto_ops_bg.wasm`，
+//! `public/crypto-worker.js` 加载后对每条请求调用 [`run`]。运算逻辑与主线程降级路径共用
+//! [`iron_forge::crypto::worker::execute`]。
+
+use iron_forge::crypto::worker::{execute, WorkerOp};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// 执行一次运算：`payload` 为参数字节数组（用后由调用方清零），返回结果字节
+#[wasm_bindgen]
+pub fn run(op: &str, payload: Array) -> Result<Uint8Array, JsValue> {
+    let op = WorkerOp::from_name(op)
+        .ok_or_else(|| JsValue::from_str(&format!("unsupported operation: {}", op)))?;
+    let mut args: Vec<Vec<u8>> = payload
+        .iter()
+        .map(|arg| Uint8Array::new(&arg).to_vec())
+        .collect();
+    let result = execute(op, &args);
+    args.iter_mut().for_each(|arg| arg.zeroize());
+
+    let mut bytes = result.map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let view = Uint8Array::from(bytes.as_slice());
+    bytes.zeroize();
+    Ok(view)
+}
+
+fn main() {}
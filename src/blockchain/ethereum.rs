@@ -38,7 +38,7 @@ impl EthereumAdapter {
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     // Log error and try next
-                    // tracing::warn!("RPC call to {} failed: {}", url, e);
+                    // log::warn!("RPC call to {} failed: {}", url, e);
                     last_error = e;
                 }
             }
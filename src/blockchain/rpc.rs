@@ -40,7 +40,7 @@ impl RpcClient {
         let current = self.current_index.load(Ordering::Relaxed);
         let next = (current + 1) % self.urls.len();
         self.current_index.store(next, Ordering::Relaxed);
-        // tracing::warn!("[{}] Rotated RPC node to: {}", self.client_name, self.urls[next]);
+        // log::warn!("[{}] Rotated RPC node to: {}", self.client_name, self.urls[next]);
     }
 
     #[allow(dead_code)] // 用于 RPC POST 请求
@@ -61,7 +61,7 @@ impl RpcClient {
             match self.do_post(url, &payload).await {
                 Ok(result) => return Ok(result),
                 Err(e) => {
-                    // tracing::warn!("[{}] RPC call failed on {}: {}", self.client_name, url, e);
+                    // log::warn!("[{}] RPC call failed on {}: {}", self.client_name, url, e);
                     last_error = e;
                     self.rotate_url();
                     attempts += 1;
@@ -1,4 +1,12 @@
+//! Solana 链适配器
+//!
+//! 网络拥堵时不设置计算单元价格的交易容易被丢弃：这里通过
+//! `getRecentPrioritizationFees` 估算优先费（按百分位映射到慢/标准/快速档位），
+//! 并生成 ComputeBudgetProgram 指令（设置计算单元上限与单价）。
+//! 优先费按用户设置的上限截断，错误的估算不会多付手续费。
+
 use crate::blockchain::traits::{ChainAdapter, Transaction, TransactionReceipt};
+use crate::services::gas::GasSpeed;
 use anyhow::Result;
 use async_trait::async_trait;
 use base64::Engine;
@@ -6,8 +14,138 @@ use gloo_net::http::Request;
 use serde::Deserialize;
 use serde_json::json;
 
+/// ComputeBudget 程序地址
+pub const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+
+/// 每个签名的基础手续费（lamports）
+pub const BASE_FEE_LAMPORTS: u64 = 5_000;
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// 单价单位：1 lamport = 1_000_000 micro-lamports
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// SOL 转账的计算单元上限（系统转账 + 两条 ComputeBudget 指令约 450 CU，留足余量）
+pub const TRANSFER_COMPUTE_UNIT_LIMIT: u32 = 2_000;
+
+/// 默认优先费上限（lamports，0.001 SOL）
+pub const DEFAULT_MAX_PRIORITY_FEE_LAMPORTS: u64 = 1_000_000;
+
+/// 设置页可选的优先费上限（lamports）
+pub const MAX_PRIORITY_FEE_OPTIONS_LAMPORTS: [u64; 4] = [100_000, 1_000_000, 5_000_000, 10_000_000];
+
+/// ComputeBudget 指令标识
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+/// 计算单元预算（上限 + 单价）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeBudget {
+    pub unit_limit: u32,
+    /// 每个计算单元的价格（micro-lamports）
+    pub unit_price_micro_lamports: u64,
+}
+
+impl ComputeBudget {
+    /// SOL 转账的计算单元预算
+    pub fn transfer(unit_price_micro_lamports: u64) -> Self {
+        Self {
+            unit_limit: TRANSFER_COMPUTE_UNIT_LIMIT,
+            unit_price_micro_lamports,
+        }
+    }
+
+    /// 优先费（lamports，向上取整）
+    pub fn priority_fee_lamports(&self) -> u64 {
+        let micro = self.unit_limit as u128 * self.unit_price_micro_lamports as u128;
+        micro.div_ceil(MICRO_LAMPORTS_PER_LAMPORT) as u64
+    }
+
+    /// 基础费 + 优先费（lamports）
+    pub fn total_fee_lamports(&self) -> u64 {
+        BASE_FEE_LAMPORTS + self.priority_fee_lamports()
+    }
+
+    /// 优先费超过上限时降低单价（返回截断后的预算与是否被截断）
+    pub fn capped(self, max_priority_fee_lamports: u64) -> (Self, bool) {
+        if self.priority_fee_lamports() <= max_priority_fee_lamports || self.unit_limit == 0 {
+            return (self, false);
+        }
+        let max_price = max_priority_fee_lamports as u128 * MICRO_LAMPORTS_PER_LAMPORT
+            / self.unit_limit as u128;
+        (
+            Self {
+                unit_price_micro_lamports: max_price as u64,
+                ..self
+            },
+            true,
+        )
+    }
+
+    /// 交易开头的 ComputeBudget 指令数据（先设置上限再设置单价）
+    pub fn instructions(&self) -> Vec<Vec<u8>> {
+        vec![self.unit_limit_instruction(), self.unit_price_instruction()]
+    }
+
+    /// SetComputeUnitLimit 指令数据
+    pub fn unit_limit_instruction(&self) -> Vec<u8> {
+        let mut data = vec![SET_COMPUTE_UNIT_LIMIT];
+        data.extend_from_slice(&self.unit_limit.to_le_bytes());
+        data
+    }
+
+    /// SetComputeUnitPrice 指令数据
+    pub fn unit_price_instruction(&self) -> Vec<u8> {
+        let mut data = vec![SET_COMPUTE_UNIT_PRICE];
+        data.extend_from_slice(&self.unit_price_micro_lamports.to_le_bytes());
+        data
+    }
+}
+
+/// 各档位的计算单元单价（micro-lamports）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityFeeEstimate {
+    pub slow: u64,
+    pub average: u64,
+    pub fast: u64,
+}
+
+impl PriorityFeeEstimate {
+    /// 按最近区块的优先费百分位估算（慢 25% / 标准 50% / 快速 75%）
+    ///
+    /// 网络空闲时大部分区块优先费为 0，标准与快速档位设最低单价以保证优先打包。
+    pub fn from_recent_fees(fees: &[u64]) -> Self {
+        let mut sorted = fees.to_vec();
+        sorted.sort_unstable();
+        let percentile = |p: usize| -> u64 {
+            if sorted.is_empty() {
+                return 0;
+            }
+            sorted[(sorted.len() - 1) * p / 100]
+        };
+        Self {
+            slow: percentile(25),
+            average: percentile(50).max(1_000),
+            fast: percentile(75).max(10_000),
+        }
+    }
+
+    pub fn unit_price(&self, speed: GasSpeed) -> u64 {
+        match speed {
+            GasSpeed::Slow => self.slow,
+            GasSpeed::Average => self.average,
+            GasSpeed::Fast => self.fast,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrioritizationFee {
+    prioritization_fee: u64,
+}
+
 /// Solana链适配器
-/// 为未来扩展准备的区块链适配器实现
 #[allow(dead_code)] // 为未来扩展准备
 pub struct SolanaAdapter {
     rpc_urls: Vec<String>,
@@ -19,6 +157,28 @@ impl SolanaAdapter {
         Self { rpc_urls, cluster }
     }
 
+    /// 主网适配器（与链注册表相同的 RPC）
+    pub fn mainnet() -> Self {
+        Self::new(
+            vec!["https://api.mainnet-beta.solana.com".to_string()],
+            "mainnet-beta".to_string(),
+        )
+    }
+
+    /// 最近区块中写入这些账户的交易所付优先费（micro-lamports / CU）
+    pub async fn get_recent_prioritization_fees(&self, accounts: &[String]) -> Result<Vec<u64>> {
+        let fees: Vec<PrioritizationFee> = self
+            .rpc_call("getRecentPrioritizationFees", json!([accounts]))
+            .await?;
+        Ok(fees.into_iter().map(|f| f.prioritization_fee).collect())
+    }
+
+    /// 估算各档位的计算单元单价
+    pub async fn estimate_priority_fees(&self, accounts: &[String]) -> Result<PriorityFeeEstimate> {
+        let fees = self.get_recent_prioritization_fees(accounts).await?;
+        Ok(PriorityFeeEstimate::from_recent_fees(&fees))
+    }
+
     async fn rpc_call<T: for<'de> Deserialize<'de>>(
         &self,
         method: &str,
@@ -130,3 +290,39 @@ impl ChainAdapter for SolanaAdapter {
         Ok(signature)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priority_fee_percentiles_map_to_tiers() {
+        let fees: Vec<u64> = (0..=100).map(|i| i * 1_000).collect();
+        let estimate = PriorityFeeEstimate::from_recent_fees(&fees);
+        assert_eq!(estimate.unit_price(GasSpeed::Slow), 25_000);
+        assert_eq!(estimate.unit_price(GasSpeed::Average), 50_000);
+        assert_eq!(estimate.unit_price(GasSpeed::Fast), 75_000);
+
+        // 网络空闲：标准/快速使用最低单价
+        let idle = PriorityFeeEstimate::from_recent_fees(&[0, 0, 0]);
+        assert_eq!((idle.slow, idle.average, idle.fast), (0, 1_000, 10_000));
+    }
+
+    #[test]
+    fn compute_budget_fee_cap_and_instructions() {
+        let budget = ComputeBudget::transfer(1_000_000);
+        assert_eq!(budget.priority_fee_lamports(), 2_000);
+        assert_eq!(budget.total_fee_lamports(), 7_000);
+
+        let (capped, was_capped) = ComputeBudget::transfer(10_000_000_000).capped(1_000_000);
+        assert!(was_capped);
+        assert!(capped.priority_fee_lamports() <= 1_000_000);
+        assert_eq!(budget.capped(1_000_000), (budget, false));
+
+        assert_eq!(budget.unit_limit_instruction(), vec![2, 0xD0, 0x07, 0, 0]);
+        assert_eq!(
+            budget.unit_price_instruction(),
+            vec![3, 0x40, 0x42, 0x0F, 0, 0, 0, 0, 0]
+        );
+    }
+}
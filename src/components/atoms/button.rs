@@ -88,6 +88,9 @@ pub fn Button(
     /// 是否加载中
     #[props(default = false)]
     loading: bool,
+    /// 点击后的最短禁用时长（毫秒，0 表示不限制），防止慢响应期间重复提交
+    #[props(default = 0)]
+    cooldown_ms: u32,
     /// 点击事件
     onclick: Option<EventHandler<MouseEvent>>,
     /// 自定义类名
@@ -96,8 +99,11 @@ pub fn Button(
     /// 按钮内容
     children: Element,
 ) -> Element {
+    let mut cooling_down = use_signal(|| false);
+    let blocked = disabled || loading || cooling_down();
+
     let base_class = "inline-flex items-center justify-center font-semibold rounded-lg transition-all duration-300 cursor-pointer";
-    let disabled_class = if disabled || loading {
+    let disabled_class = if blocked {
         "opacity-50 cursor-not-allowed"
     } else {
         ""
@@ -157,12 +163,21 @@ pub fn Button(
         button {
             class: "{base_class} {disabled_class} {custom_class} {hover_style}",
             style: "{bg_style} color: {text_color}; padding: {padding}; font-size: {font_size}; border-radius: {border_radius}; {shadow_style}",
-            disabled: disabled || loading,
+            disabled: blocked,
             onclick: move |e| {
-                if !disabled && !loading {
-                    if let Some(handler) = onclick.as_ref() {
-                        handler.call(e);
-                    }
+                // 用 peek 读取最新状态：重新渲染前的连续点击同样会被拦截
+                if disabled || loading || *cooling_down.peek() {
+                    return;
+                }
+                if cooldown_ms > 0 {
+                    cooling_down.set(true);
+                    spawn(async move {
+                        gloo_timers::future::TimeoutFuture::new(cooldown_ms).await;
+                        cooling_down.set(false);
+                    });
+                }
+                if let Some(handler) = onclick.as_ref() {
+                    handler.call(e);
                 }
             },
             onmousedown: move |_| {
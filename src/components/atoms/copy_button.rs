@@ -0,0 +1,246 @@
+//! Copy Button - 复制按钮组件
+//! 统一复制交互：复制成功反馈；敏感内容（助记词、私钥、Keystore）需长按复制并定时清空剪贴板
+
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// "已复制"状态的展示时长（毫秒）
+const COPIED_FEEDBACK_MS: u32 = 2000;
+/// 敏感内容长按复制所需时长（毫秒）
+const SENSITIVE_HOLD_MS: u32 = 1000;
+/// 长按进度刷新间隔（毫秒）
+const HOLD_TICK_MS: u32 = 50;
+/// 敏感内容复制后自动清空剪贴板的延迟（毫秒）
+pub const SENSITIVE_CLEAR_MS: u32 = 60_000;
+
+/// 写入剪贴板：优先使用异步 Clipboard API，失败时回退到 `execCommand("copy")`
+pub async fn copy_text(text: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or("No window")?;
+    let clipboard = window.navigator().clipboard();
+    if wasm_bindgen_futures::JsFuture::from(clipboard.write_text(text))
+        .await
+        .is_ok()
+    {
+        return Ok(());
+    }
+    copy_text_fallback(text)
+}
+
+/// 非安全上下文或权限被拒时的回退方案：临时 textarea + execCommand
+fn copy_text_fallback(text: &str) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let textarea = document
+        .create_element("textarea")
+        .map_err(|_| "Failed to create textarea".to_string())?
+        .dyn_into::<web_sys::HtmlTextAreaElement>()
+        .map_err(|_| "Failed to create textarea".to_string())?;
+    textarea.set_value(text);
+    let _ = textarea.set_attribute("readonly", "");
+    let _ = textarea.set_attribute("style", "position: fixed; top: -1000px; opacity: 0;");
+    let body = document.body().ok_or("No body")?;
+    body.append_child(&textarea)
+        .map_err(|_| "Failed to copy to clipboard".to_string())?;
+    textarea.select();
+    let copied = document
+        .dyn_ref::<web_sys::HtmlDocument>()
+        .and_then(|d| d.exec_command("copy").ok())
+        .unwrap_or(false);
+    textarea.remove();
+    if copied {
+        Ok(())
+    } else {
+        Err("Failed to copy to clipboard".to_string())
+    }
+}
+
+/// 定时清空剪贴板（尽力而为：页面关闭或失去焦点时浏览器可能拒绝写入）
+fn schedule_clipboard_clear() {
+    spawn(async move {
+        gloo_timers::future::TimeoutFuture::new(SENSITIVE_CLEAR_MS).await;
+        if let Some(window) = web_sys::window() {
+            let _ =
+                wasm_bindgen_futures::JsFuture::from(window.navigator().clipboard().write_text(""))
+                    .await;
+        }
+    });
+}
+
+/// CopyButton 组件
+///
+/// # 示例
+///
+/// ```rust
+/// rsx! {
+///     CopyButton { value: address.clone() }
+///     CopyButton { value: phrase.clone(), label: "复制助记词".to_string(), sensitive: true }
+/// }
+/// ```
+#[component]
+pub fn CopyButton(
+    /// 要复制的内容
+    value: String,
+    /// 按钮文本
+    #[props(default = "复制".to_string())]
+    label: String,
+    /// 敏感内容：需长按复制、提示剪贴板风险，并在 60 秒后清空剪贴板
+    #[props(default = false)]
+    sensitive: bool,
+    /// 紧凑模式（仅图标，用于地址/交易哈希旁）
+    #[props(default = false)]
+    compact: bool,
+    /// 自定义类名
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let mut copied = use_signal(|| false);
+    let mut failed = use_signal(|| false);
+    let mut hold_progress = use_signal(|| 0u32);
+    // 每次按下/松开递增，用于作废上一次长按的计时任务
+    let mut hold_generation = use_signal(|| 0u64);
+
+    let do_copy = {
+        let value = value.clone();
+        move || {
+            let value = value.clone();
+            spawn(async move {
+                match copy_text(&value).await {
+                    Ok(()) => {
+                        failed.set(false);
+                        copied.set(true);
+                        if sensitive {
+                            schedule_clipboard_clear();
+                        }
+                        gloo_timers::future::TimeoutFuture::new(COPIED_FEEDBACK_MS).await;
+                        copied.set(false);
+                    }
+                    Err(_) => failed.set(true),
+                }
+            });
+        }
+    };
+
+    let start_hold = {
+        let do_copy = do_copy.clone();
+        move || {
+            let generation = {
+                let mut g = hold_generation.write();
+                *g += 1;
+                *g
+            };
+            let do_copy = do_copy.clone();
+            spawn(async move {
+                let mut elapsed = 0;
+                while elapsed < SENSITIVE_HOLD_MS {
+                    gloo_timers::future::TimeoutFuture::new(HOLD_TICK_MS).await;
+                    if *hold_generation.peek() != generation {
+                        return;
+                    }
+                    elapsed += HOLD_TICK_MS;
+                    hold_progress.set(elapsed * 100 / SENSITIVE_HOLD_MS);
+                }
+                hold_progress.set(0);
+                do_copy();
+            });
+        }
+    };
+
+    let mut release_hold = move || {
+        *hold_generation.write() += 1;
+        hold_progress.set(0);
+    };
+
+    let text = if copied() {
+        if compact {
+            "✓".to_string()
+        } else {
+            "✓ 已复制".to_string()
+        }
+    } else if failed() {
+        if compact {
+            "⚠".to_string()
+        } else {
+            "复制失败，请手动复制".to_string()
+        }
+    } else if compact {
+        "📋".to_string()
+    } else if sensitive {
+        format!("按住{}", label)
+    } else {
+        format!("📋 {}", label)
+    };
+    let accent = if copied() {
+        Colors::PAYMENT_SUCCESS
+    } else if failed() {
+        Colors::PAYMENT_ERROR
+    } else {
+        Colors::TEXT_SECONDARY
+    };
+    let padding = if compact { "2px 6px" } else { "8px 16px" };
+    let custom_class = class.unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "inline-flex flex-col gap-1 {custom_class}",
+            button {
+                r#type: "button",
+                class: "relative overflow-hidden inline-flex items-center justify-center rounded-lg text-sm font-medium transition-all select-none hover:bg-white/10",
+                style: format!(
+                    "padding: {padding}; color: {accent}; border: 1px solid {};",
+                    Colors::BORDER_PRIMARY
+                ),
+                title: if sensitive { "按住以复制" } else { "复制" },
+                onclick: {
+                    let do_copy = do_copy.clone();
+                    move |e: MouseEvent| {
+                        e.stop_propagation();
+                        if !sensitive {
+                            do_copy();
+                        }
+                    }
+                },
+                onmousedown: {
+                    let mut start_hold = start_hold.clone();
+                    move |_| {
+                        if sensitive {
+                            start_hold();
+                        }
+                    }
+                },
+                onmouseup: move |_| release_hold(),
+                onmouseleave: move |_| release_hold(),
+                ontouchstart: {
+                    let mut start_hold = start_hold.clone();
+                    move |_| {
+                        if sensitive {
+                            start_hold();
+                        }
+                    }
+                },
+                ontouchend: move |_| release_hold(),
+                if sensitive {
+                    span {
+                        class: "absolute inset-y-0 left-0",
+                        style: format!(
+                            "width: {}%; background: rgba(245, 158, 11, 0.3);",
+                            hold_progress()
+                        ),
+                    }
+                }
+                span { class: "relative", "{text}" }
+            }
+            if sensitive && !compact {
+                span {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::PAYMENT_WARNING),
+                    {format!(
+                        "⚠️ 剪贴板内容可能被其他应用读取，将在 {} 秒后自动清空",
+                        SENSITIVE_CLEAR_MS / 1000
+                    )}
+                }
+            }
+        }
+    }
+}
@@ -16,6 +16,8 @@ pub enum InputType {
     Tel,
     #[allow(dead_code)]
     Url,
+    /// 搜索框（`/` 快捷键会聚焦页面上的搜索框）
+    Search,
 }
 
 /// Input 组件
@@ -64,6 +66,9 @@ pub fn Input(
     onfocus: Option<EventHandler<FocusEvent>>,
     /// 失焦事件
     onblur: Option<EventHandler<FocusEvent>>,
+    /// 按键事件
+    #[props(default)]
+    onkeydown: Option<EventHandler<KeyboardEvent>>,
     /// 自定义类名
     #[props(default)]
     class: Option<String>,
@@ -75,6 +80,7 @@ pub fn Input(
         InputType::Number => "number",
         InputType::Tel => "tel",
         InputType::Url => "url",
+        InputType::Search => "search",
     };
 
     let base_style = format!(
@@ -112,6 +118,7 @@ pub fn Input(
     let onchange_clone = onchange;
     let onfocus_clone = onfocus;
     let onblur_clone = onblur;
+    let onkeydown_clone = onkeydown;
     let class_clone = class.clone();
 
     rsx! {
@@ -153,14 +160,13 @@ pub fn Input(
                         handler.call(e);
                     }
                 },
+                onkeydown: move |e| {
+                    if let Some(handler) = onkeydown_clone.as_ref() {
+                        handler.call(e);
+                    }
+                },
             }
-            if let Some(ref error_text) = error_clone {
-                p {
-                    class: "mt-2 text-sm",
-                    style: format!("color: {};", Colors::PAYMENT_ERROR),
-                    {error_text.clone()}
-                }
-            }
+            FieldError { error: error_clone }
             if let Some(ref help) = help_text_clone {
                 p {
                     class: "mt-2 text-sm",
@@ -171,3 +177,23 @@ pub fn Input(
         }
     }
 }
+
+/// 字段级错误提示（显示在输入框下方）
+///
+/// 供未使用 `Input` 的原生输入框复用，与 `Input` 的错误样式保持一致。
+#[component]
+pub fn FieldError(
+    /// 错误信息（None 时不渲染）
+    error: Option<String>,
+) -> Element {
+    rsx! {
+        if let Some(error_text) = error {
+            p {
+                class: "mt-2 text-sm",
+                style: format!("color: {};", Colors::PAYMENT_ERROR),
+                role: "alert",
+                {error_text}
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Masked Amount - 隐私模式下的金额显示
+//! 隐私模式开启时以 "••••" 代替余额、金额与法币价值，占位宽度与原文一致以保持布局稳定；
+//! 按住可临时显示单个数值
+
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 隐藏金额时显示的占位符
+pub const MASK: &str = "••••";
+
+/// 隐私模式下替换文本中的金额（用于提示、通知等无法使用组件的纯文本）
+///
+/// 视为金额的数字：带小数点或千分位、前面紧跟货币符号，或后面跟代币符号（如 `5 ETH`）。
+/// 其他整数（次数、秒数等）保持不变。
+pub fn redact_amounts(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len()
+            && (chars[i].is_ascii_digit()
+                || (matches!(chars[i], '.' | ',')
+                    && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())))
+        {
+            i += 1;
+        }
+        let number = &chars[start..i];
+
+        let has_separator = number.iter().any(|c| matches!(c, '.' | ','));
+        let after_currency = chars[..start]
+            .iter()
+            .rev()
+            .find(|c| !c.is_whitespace())
+            .is_some_and(|c| matches!(c, '$' | '¥' | '€' | '£' | '≈'));
+        let rest: String = chars[i..]
+            .iter()
+            .skip_while(|c| c.is_whitespace())
+            .take_while(|c| c.is_ascii_alphabetic())
+            .collect();
+        let before_symbol = rest.len() >= 2 && rest.chars().all(|c| c.is_ascii_uppercase());
+
+        if has_separator || after_currency || before_symbol {
+            out.push_str(MASK);
+        } else {
+            out.extend(number);
+        }
+    }
+    out
+}
+
+/// 金额显示组件
+///
+/// 隐私模式关闭时原样显示 `value`；开启时显示占位符，按住（鼠标或触摸）时临时显示原值。
+#[component]
+pub fn MaskedAmount(
+    /// 格式化后的金额文本（如 `$12.34`、`0.5 ETH`）
+    value: String,
+    #[props(default)] class: String,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut revealed = use_signal(|| false);
+
+    if !*app_state.privacy_mode.read() {
+        return rsx! {
+            span { class: "{class}", {value} }
+        };
+    }
+
+    // 占位宽度取原文字符数，避免切换时布局跳动
+    let width = value.chars().count().max(MASK.chars().count());
+    rsx! {
+        span {
+            class: "{class} inline-block cursor-pointer select-none",
+            style: format!("min-width: {}ch;", width),
+            title: "按住显示",
+            onpointerdown: move |_| revealed.set(true),
+            onpointerup: move |_| revealed.set(false),
+            onpointerleave: move |_| revealed.set(false),
+            onpointercancel: move |_| revealed.set(false),
+            if revealed() {
+                {value}
+            } else {
+                {MASK}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_only_amount_like_numbers() {
+        assert_eq!(redact_amounts("已发送 0.5 ETH"), "已发送 •••• ETH");
+        assert_eq!(redact_amounts("总价值 $1,234"), "总价值 $••••");
+        assert_eq!(
+            redact_amounts("收到 100 USDT，确认数 3"),
+            "收到 •••• USDT，确认数 3"
+        );
+        assert_eq!(redact_amounts("请在 30 秒后重试"), "请在 30 秒后重试");
+    }
+}
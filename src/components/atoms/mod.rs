@@ -3,7 +3,10 @@
 
 pub mod button;
 pub mod card;
+pub mod copy_button;
 pub mod icon;
 pub mod input;
+pub mod masked_amount;
 pub mod modal;
 pub mod select;
+pub mod skeleton;
@@ -36,6 +36,9 @@ pub fn Modal(
     /// 自定义类名
     #[props(default)]
     class: Option<String>,
+    /// 是否需要输入确认文本（打开期间停用全局快捷键）
+    #[props(default = false)]
+    typed_confirm: bool,
     /// 模态框内容
     children: Element,
 ) -> Element {
@@ -73,6 +76,7 @@ pub fn Modal(
         div {
             class: "modal-overlay",
             style: "{overlay_style}",
+            "data-typed-confirm": if typed_confirm { "true" } else { "false" },
             div {
                 id: "modal-overlay",
                 class: "absolute inset-0",
@@ -99,6 +103,8 @@ pub fn Modal(
                         if show_close {
                             button {
                                 class: "p-2 rounded-lg hover:bg-white/10 transition-colors",
+                                // Esc 快捷键通过此按钮关闭最上层弹窗
+                                "data-modal-close": "true",
                                 onclick: move |e| { onclose.call(e); },
                                 style: "color: #E5E7EB;",
                                 Icon {
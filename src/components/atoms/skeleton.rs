@@ -0,0 +1,147 @@
+//! Skeleton - 骨架屏组件
+//! 加载时按最终布局占位（文本行、卡片、表格行、图表），避免内容就绪后页面跳动
+
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+/// 骨架块的底色
+fn block_style() -> String {
+    format!("background: {};", Colors::BG_PRIMARY)
+}
+
+/// 文本行骨架（多行时最后一行缩短）
+#[component]
+pub fn SkeletonText(
+    /// 行数
+    #[props(default = 1)]
+    lines: usize,
+    /// 宽度（CSS 值）
+    #[props(default = "100%".to_string())]
+    width: String,
+    /// 每行高度（像素）
+    #[props(default = 14)]
+    height: u32,
+    #[props(default)] class: Option<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: format!("space-y-2 {}", class.unwrap_or_default()),
+            style: format!("width: {width};"),
+            for line in 0..lines {
+                div {
+                    key: "{line}",
+                    class: "rounded animate-pulse",
+                    style: format!(
+                        "{} height: {height}px; width: {};",
+                        block_style(),
+                        if lines > 1 && line == lines - 1 { "60%" } else { "100%" }
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// 卡片骨架：标题行 + 若干文本行，高度与实际卡片一致
+#[component]
+pub fn SkeletonCard(
+    /// 正文行数
+    #[props(default = 3)]
+    lines: usize,
+    /// 最小高度（像素），用于预留最终内容的空间
+    #[props(default)]
+    min_height: Option<u32>,
+    #[props(default)] class: Option<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: format!("p-6 rounded-lg {}", class.unwrap_or_default()),
+            style: format!(
+                "background: {}; border: 1px solid {}; {}",
+                Colors::BG_SECONDARY,
+                Colors::BORDER_PRIMARY,
+                min_height.map(|h| format!("min-height: {h}px;")).unwrap_or_default()
+            ),
+            aria_busy: "true",
+            SkeletonText { width: "40%".to_string(), height: 20, class: Some("mb-4".to_string()) }
+            div {
+                class: "space-y-3",
+                for line in 0..lines {
+                    div {
+                        key: "{line}",
+                        class: "flex justify-between gap-4",
+                        SkeletonText { width: "30%".to_string() }
+                        SkeletonText { width: "25%".to_string() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 表格/列表行骨架：图标 + 两行文本 + 右侧数值
+#[component]
+pub fn SkeletonTableRow(
+    /// 是否显示左侧圆形图标
+    #[props(default = true)]
+    icon: bool,
+    #[props(default)] class: Option<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: format!("flex items-center justify-between gap-4 p-4 rounded-xl border {}", class.unwrap_or_default()),
+            style: format!("background: {}; border-color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            aria_busy: "true",
+            div {
+                class: "flex items-center gap-3 flex-1",
+                if icon {
+                    div {
+                        class: "w-10 h-10 rounded-full animate-pulse shrink-0",
+                        style: block_style(),
+                    }
+                }
+                div {
+                    class: "flex-1 space-y-2",
+                    SkeletonText { width: "40%".to_string() }
+                    SkeletonText { width: "60%".to_string(), height: 12 }
+                }
+            }
+            div {
+                class: "flex flex-col items-end gap-2 w-24",
+                SkeletonText { height: 16 }
+                SkeletonText { width: "60%".to_string(), height: 12 }
+            }
+        }
+    }
+}
+
+/// 图表骨架：标题 + 固定高度的柱状占位
+#[component]
+pub fn SkeletonChart(
+    /// 图表区域高度（像素）
+    #[props(default = 300)]
+    height: u32,
+) -> Element {
+    // 固定的柱高比例，看起来像走势但不随渲染变化
+    const BARS: [u32; 12] = [45, 60, 52, 70, 64, 80, 72, 58, 66, 75, 62, 85];
+
+    rsx! {
+        div {
+            class: "p-6 rounded-lg",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            aria_busy: "true",
+            SkeletonText { width: "30%".to_string(), height: 20, class: Some("mb-4".to_string()) }
+            div {
+                class: "flex items-end gap-2",
+                style: format!("height: {height}px;"),
+                for (i, percent) in BARS.iter().enumerate() {
+                    div {
+                        key: "{i}",
+                        class: "flex-1 rounded-t animate-pulse",
+                        style: format!("{} height: {percent}%;", block_style()),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,612 @@
+//! Help Panel Component - 帮助面板
+//! 右下角悬浮的帮助按钮：常见问题搜索、联系客服（提交工单，可附带用户确认的上下文）、我的工单；
+//! 客服有未读回复时按钮显示角标
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::molecules::ErrorMessage;
+use crate::features::support::AvailableContext;
+use crate::services::support::{
+    search_faq, unread_total, FaqEntry, SupportService, SupportTicket, TicketDetail,
+};
+use crate::shared::design_tokens::Colors;
+use crate::shared::markdown::{self, Block, Inline};
+use crate::shared::state::AppState;
+use dioxus::events::FormEvent;
+use dioxus::prelude::*;
+
+/// 未读回复轮询间隔（毫秒）
+const UNREAD_POLL_MS: u32 = 120_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HelpTab {
+    Faq,
+    Contact,
+    Tickets,
+}
+
+/// 帮助按钮与面板（放在路由布局内，`route` 为当前页面路径）
+#[component]
+pub fn HelpWidget(route: String) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut unread = use_signal(|| 0u32);
+
+    // 登录后定期查询未读回复
+    use_future(move || async move {
+        loop {
+            if app_state.user.peek().is_authenticated {
+                match SupportService::new(app_state).list_tickets().await {
+                    Ok(tickets) => unread.set(unread_total(&tickets)),
+                    Err(e) => log::debug!("{}", e),
+                }
+            } else if *unread.peek() != 0 {
+                unread.set(0);
+            }
+            gloo_timers::future::TimeoutFuture::new(UNREAD_POLL_MS).await;
+        }
+    });
+
+    let request = app_state.help_panel.read().clone();
+
+    rsx! {
+        if let Some(request) = request {
+            HelpPanel {
+                key: "{request.order_id.clone().unwrap_or_default()}",
+                route: route.clone(),
+                order_id: request.order_id.clone(),
+                draft: request.draft.clone(),
+                unread,
+            }
+        } else {
+            button {
+                class: "fixed bottom-6 right-6 z-40 w-12 h-12 rounded-full shadow-lg flex items-center justify-center text-xl",
+                style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                title: "帮助与客服",
+                onclick: move |_| app_state.open_help(None),
+                "?"
+                if unread() > 0 {
+                    span {
+                        class: "absolute -top-1 -right-1 min-w-5 h-5 px-1 rounded-full text-xs flex items-center justify-center",
+                        style: format!("background: {}; color: white;", Colors::PAYMENT_ERROR),
+                        "{unread}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HelpPanel(
+    route: String,
+    order_id: Option<String>,
+    draft: Option<(String, String)>,
+    unread: Signal<u32>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut tab = use_signal(|| {
+        if order_id.is_some() {
+            HelpTab::Contact
+        } else {
+            HelpTab::Faq
+        }
+    });
+
+    let tab_button = |target: HelpTab, label: String| {
+        let active = tab() == target;
+        rsx! {
+            button {
+                class: "flex-1 py-2 text-sm font-medium",
+                style: format!(
+                    "color: {}; border-bottom: 2px solid {};",
+                    if active { Colors::TEXT_PRIMARY } else { Colors::TEXT_TERTIARY },
+                    if active { Colors::TECH_PRIMARY } else { "transparent" }
+                ),
+                onclick: move |_| tab.set(target),
+                "{label}"
+            }
+        }
+    };
+    let tickets_label = if unread() > 0 {
+        format!("我的工单 ({})", unread())
+    } else {
+        "我的工单".to_string()
+    };
+
+    rsx! {
+        div {
+            class: "fixed bottom-6 right-6 z-40 w-[calc(100vw-3rem)] max-w-sm max-h-[80vh] flex flex-col rounded-xl shadow-2xl",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_ELEVATED, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex items-center justify-between px-4 pt-3",
+                span {
+                    class: "text-base font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "帮助与客服"
+                }
+                button {
+                    class: "text-lg leading-none opacity-60 hover:opacity-100",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    onclick: move |_| {
+                        let mut help_panel = app_state.help_panel;
+                        help_panel.set(None);
+                    },
+                    "×"
+                }
+            }
+            div {
+                class: "flex px-4",
+                style: format!("border-bottom: 1px solid {};", Colors::BORDER_PRIMARY),
+                {tab_button(HelpTab::Faq, "常见问题".to_string())}
+                {tab_button(HelpTab::Contact, "联系客服".to_string())}
+                {tab_button(HelpTab::Tickets, tickets_label)}
+            }
+            div {
+                class: "flex-1 overflow-y-auto p-4",
+                match tab() {
+                    HelpTab::Faq => rsx! { FaqTab {} },
+                    HelpTab::Contact => rsx! {
+                        ContactTab {
+                            route: route.clone(),
+                            order_id: order_id.clone(),
+                            draft: draft.clone(),
+                            on_submitted: move |_| tab.set(HelpTab::Tickets),
+                        }
+                    },
+                    HelpTab::Tickets => rsx! { TicketsTab { unread } },
+                }
+            }
+        }
+    }
+}
+
+/// 常见问题：按界面语言获取，本地搜索
+#[component]
+fn FaqTab() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut entries = use_signal(|| None::<Result<Vec<FaqEntry>, String>>);
+    let mut query = use_signal(String::new);
+    let mut expanded = use_signal(|| None::<String>);
+
+    use_effect(move || {
+        let language = app_state.language.read().clone();
+        spawn(async move {
+            let result = SupportService::new(app_state)
+                .get_faq(&language)
+                .await
+                .map_err(|e| e.to_string());
+            entries.set(Some(result));
+        });
+    });
+
+    let entries_read = entries.read();
+    let body = match entries_read.as_ref() {
+        None => rsx! {
+            p { class: "text-sm", style: format!("color: {};", Colors::TEXT_TERTIARY), "加载中..." }
+        },
+        Some(Err(_)) => rsx! {
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                "常见问题暂时无法加载，可以在「联系客服」中直接提问"
+            }
+        },
+        Some(Ok(list)) => {
+            let results = search_faq(list, &query.read());
+            rsx! {
+                if results.is_empty() {
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "没有找到相关问题，可以在「联系客服」中提问"
+                    }
+                }
+                for entry in results.into_iter().cloned() {
+                    div {
+                        key: "{entry.id}",
+                        class: "py-2",
+                        style: format!("border-bottom: 1px solid {};", Colors::BORDER_SECONDARY),
+                        button {
+                            class: "w-full text-left text-sm font-medium",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            onclick: {
+                                let id = entry.id.clone();
+                                move |_| {
+                                    let open = expanded.peek().as_ref() == Some(&id);
+                                    expanded.set(if open { None } else { Some(id.clone()) });
+                                }
+                            },
+                            "{entry.question}"
+                        }
+                        if expanded.read().as_ref() == Some(&entry.id) {
+                            div { class: "mt-2", MarkdownView { source: entry.answer.clone() } }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            Input {
+                input_type: InputType::Search,
+                placeholder: Some("搜索问题".to_string()),
+                value: Some(query.read().clone()),
+                onchange: Some(EventHandler::new(move |e: FormEvent| query.set(e.value()))),
+            }
+            {body}
+        }
+    }
+}
+
+/// 联系客服：提交工单，上下文逐项由用户勾选
+#[component]
+fn ContactTab(
+    route: String,
+    order_id: Option<String>,
+    draft: Option<(String, String)>,
+    on_submitted: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let available = use_hook(|| AvailableContext::collect(route.clone(), order_id.clone()));
+    let (draft_subject, draft_message) = draft.unwrap_or_default();
+    let mut subject = use_signal(|| draft_subject);
+    let mut message = use_signal(|| draft_message);
+    let include_route = use_signal(|| true);
+    let include_errors = use_signal(|| false);
+    let include_order = use_signal(|| true);
+    let mut error_message = use_signal(|| None::<String>);
+    let mut submitting = use_signal(|| false);
+
+    if !app_state.user.read().is_authenticated {
+        return rsx! {
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "登录后可以提交工单并查看客服回复"
+            }
+        };
+    }
+
+    let submit = {
+        let available = available.clone();
+        move |_| {
+            let subject_val = subject.peek().trim().to_string();
+            let message_val = message.peek().trim().to_string();
+            if subject_val.is_empty() || message_val.is_empty() {
+                error_message.set(Some("请填写标题和问题描述".to_string()));
+                return;
+            }
+            let context = available.approved(include_route(), include_errors(), include_order());
+            error_message.set(None);
+            submitting.set(true);
+            spawn(async move {
+                match SupportService::new(app_state)
+                    .create_ticket(&subject_val, &message_val, &context)
+                    .await
+                {
+                    Ok(_) => {
+                        AppState::show_success(
+                            app_state.toasts,
+                            "工单已提交，客服回复后会在「我的工单」中显示".to_string(),
+                        );
+                        subject.set(String::new());
+                        message.set(String::new());
+                        on_submitted.call(());
+                    }
+                    Err(e) => error_message.set(Some(e.to_string())),
+                }
+                submitting.set(false);
+            });
+        }
+    };
+
+    let context_option = |checked: Signal<bool>, label: String, preview: String| {
+        let mut checked = checked;
+        rsx! {
+            label {
+                class: "flex items-start gap-2 text-xs cursor-pointer",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                input {
+                    r#type: "checkbox",
+                    class: "mt-0.5",
+                    checked: checked(),
+                    onchange: move |e| checked.set(e.checked()),
+                }
+                span {
+                    "{label}"
+                    span {
+                        class: "block font-mono break-all",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "{preview}"
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            Input {
+                input_type: InputType::Text,
+                label: Some("标题".to_string()),
+                placeholder: Some("简要描述问题".to_string()),
+                value: Some(subject.read().clone()),
+                onchange: Some(EventHandler::new(move |e: FormEvent| subject.set(e.value()))),
+            }
+            textarea {
+                class: "w-full p-3 rounded-lg text-sm",
+                style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
+                rows: 4,
+                placeholder: "请描述遇到的问题（请勿填写助记词、私钥或密码）",
+                value: "{message}",
+                oninput: move |e| message.set(e.value()),
+            }
+            div {
+                class: "space-y-2",
+                p {
+                    class: "text-xs font-medium",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "附带以下信息（可选，仅提交勾选的内容）"
+                }
+                {context_option(include_route, "当前页面".to_string(), available.route.clone())}
+                if !available.recent_errors.is_empty() {
+                    {context_option(
+                        include_errors,
+                        format!("最近 {} 条错误日志", available.recent_errors.len()),
+                        available.recent_errors.join("\n"),
+                    )}
+                }
+                if let Some(order) = available.order_id.clone() {
+                    {context_option(include_order, "相关订单".to_string(), order)}
+                }
+            }
+            ErrorMessage { message: error_message.read().clone() }
+            Button {
+                variant: ButtonVariant::Primary,
+                size: ButtonSize::Medium,
+                class: Some("w-full".to_string()),
+                loading: submitting(),
+                disabled: submitting(),
+                onclick: submit,
+                "提交工单"
+            }
+        }
+    }
+}
+
+/// 我的工单：列表与详情（打开后标记已读）
+#[component]
+fn TicketsTab(unread: Signal<u32>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut tickets = use_signal(|| None::<Result<Vec<SupportTicket>, String>>);
+    let mut detail = use_signal(|| None::<TicketDetail>);
+
+    use_effect(move || {
+        if !app_state.user.read().is_authenticated {
+            return;
+        }
+        spawn(async move {
+            let result = SupportService::new(app_state)
+                .list_tickets()
+                .await
+                .map_err(|e| e.to_string());
+            if let Ok(list) = &result {
+                unread.set(unread_total(list));
+            }
+            tickets.set(Some(result));
+        });
+    });
+
+    if !app_state.user.read().is_authenticated {
+        return rsx! {
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "登录后可以查看工单"
+            }
+        };
+    }
+
+    let open_ticket = move |ticket: SupportTicket| {
+        spawn(async move {
+            let service = SupportService::new(app_state);
+            match service.get_ticket(&ticket.id).await {
+                Ok(d) => detail.set(Some(d)),
+                Err(e) => {
+                    AppState::show_error(app_state.toasts, e.to_string());
+                    return;
+                }
+            }
+            if ticket.unread_replies == 0 {
+                return;
+            }
+            if let Err(e) = service.mark_read(&ticket.id).await {
+                log::warn!("{}", e);
+                return;
+            }
+            if let Some(Ok(list)) = tickets.write().as_mut() {
+                if let Some(t) = list.iter_mut().find(|t| t.id == ticket.id) {
+                    t.unread_replies = 0;
+                }
+                unread.set(unread_total(list));
+            }
+        });
+    };
+
+    if let Some(d) = detail.read().clone() {
+        return rsx! {
+            div {
+                class: "space-y-3",
+                button {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                    onclick: move |_| detail.set(None),
+                    "← 返回工单列表"
+                }
+                div {
+                    class: "flex items-center justify-between",
+                    span {
+                        class: "text-sm font-semibold",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "{d.ticket.subject}"
+                    }
+                    span {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        {d.ticket.status.label()}
+                    }
+                }
+                for (i, msg) in d.messages.iter().enumerate() {
+                    div {
+                        key: "{i}",
+                        class: "p-3 rounded-lg text-sm",
+                        style: format!(
+                            "background: {};",
+                            if msg.is_support() { Colors::BG_TERTIARY } else { Colors::BG_SECONDARY }
+                        ),
+                        div {
+                            class: "text-xs mb-1",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {if msg.is_support() { "客服" } else { "我" }}
+                            " · {msg.created_at}"
+                        }
+                        if msg.is_support() {
+                            MarkdownView { source: msg.body.clone() }
+                        } else {
+                            p {
+                                class: "whitespace-pre-wrap",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "{msg.body}"
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    let tickets_read = tickets.read();
+    match tickets_read.as_ref() {
+        None => rsx! {
+            p { class: "text-sm", style: format!("color: {};", Colors::TEXT_TERTIARY), "加载中..." }
+        },
+        Some(Err(e)) => rsx! { ErrorMessage { message: Some(e.clone()) } },
+        Some(Ok(list)) if list.is_empty() => rsx! {
+            p { class: "text-sm", style: format!("color: {};", Colors::TEXT_TERTIARY), "还没有工单" }
+        },
+        Some(Ok(list)) => rsx! {
+            div {
+                class: "space-y-2",
+                for ticket in list.iter().cloned() {
+                    button {
+                        key: "{ticket.id}",
+                        class: "w-full p-3 rounded-lg text-left",
+                        style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                        onclick: {
+                            let ticket = ticket.clone();
+                            move |_| open_ticket(ticket.clone())
+                        },
+                        div {
+                            class: "flex items-center justify-between gap-2",
+                            span {
+                                class: "text-sm font-medium truncate",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "{ticket.subject}"
+                            }
+                            if ticket.unread_replies > 0 {
+                                span {
+                                    class: "px-2 rounded-full text-xs",
+                                    style: format!("background: {}; color: white;", Colors::PAYMENT_ERROR),
+                                    "{ticket.unread_replies}"
+                                }
+                            }
+                        }
+                        div {
+                            class: "text-xs mt-1",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {format!("{} · {}", ticket.status.label(), ticket.updated_at)}
+                        }
+                    }
+                }
+            }
+        },
+    }
+}
+
+/// 渲染 Markdown 子集（结构化渲染，不插入原始 HTML）
+#[component]
+fn MarkdownView(source: String) -> Element {
+    let blocks = markdown::parse(&source);
+    rsx! {
+        div {
+            class: "space-y-2 text-sm",
+            style: format!("color: {};", Colors::TEXT_SECONDARY),
+            for (i, block) in blocks.into_iter().enumerate() {
+                match block {
+                    Block::Heading(level, inlines) => rsx! {
+                        p {
+                            key: "{i}",
+                            class: if level == 1 { "text-base font-semibold" } else { "font-semibold" },
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {render_inline(inlines)}
+                        }
+                    },
+                    Block::Paragraph(inlines) => rsx! {
+                        p { key: "{i}", {render_inline(inlines)} }
+                    },
+                    Block::List { ordered, items } => rsx! {
+                        ul {
+                            key: "{i}",
+                            class: if ordered { "list-decimal pl-5 space-y-1" } else { "list-disc pl-5 space-y-1" },
+                            for item in items {
+                                li { {render_inline(item)} }
+                            }
+                        }
+                    },
+                    Block::Code(code) => rsx! {
+                        pre {
+                            key: "{i}",
+                            class: "p-2 rounded text-xs overflow-x-auto",
+                            style: format!("background: {};", Colors::BG_PRIMARY),
+                            "{code}"
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn render_inline(inlines: Vec<Inline>) -> Element {
+    rsx! {
+        for inline in inlines {
+            match inline {
+                Inline::Text(text) => rsx! { "{text}" },
+                Inline::Bold(text) => rsx! {
+                    strong { style: format!("color: {};", Colors::TEXT_PRIMARY), "{text}" }
+                },
+                Inline::Code(text) => rsx! {
+                    code {
+                        class: "px-1 rounded text-xs",
+                        style: format!("background: {};", Colors::BG_PRIMARY),
+                        "{text}"
+                    }
+                },
+                Inline::Link { text, url } => rsx! {
+                    a {
+                        class: "underline",
+                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                        href: "{url}",
+                        target: "_blank",
+                        rel: "noopener noreferrer",
+                        "{text}"
+                    }
+                },
+            }
+        }
+    }
+}
@@ -1,35 +1,72 @@
-//! Lock Screen Component - 钱包解锁屏幕
-//! 全屏遮罩，要求用户输入密码解锁钱包
-
-#![allow(clippy::redundant_locals)]
+//! Lock Screen Component - 应用锁屏
+//! 无操作自动锁定后显示的全屏遮罩：遮住余额等敏感信息，解锁后回到原页面
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
+use crate::features::auth::hooks::use_auth;
 use crate::features::wallet::hooks::use_wallet;
+use crate::router::Route;
 use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
 
-/// Lock Screen Component - 钱包解锁屏幕
+/// Lock Screen Component - 应用锁屏
 ///
-/// 当钱包锁定时显示，要求用户输入密码解锁
+/// 应用锁定时覆盖在当前页面之上（页面状态保留）。本设备有当前钱包的加密数据时
+/// 输入钱包密码解锁，否则输入账户密码。
 #[component]
 pub fn LockScreen() -> Element {
-    let password = use_signal(String::new);
-    let error_message = use_signal(|| Option::<String>::None);
-    let is_loading = use_signal(|| false);
-    let remember_password = use_signal(|| false);
-
+    let app_state = use_context::<AppState>();
     let wallet_controller = use_wallet();
-    let app_state = use_context::<crate::shared::state::AppState>();
+    let auth_controller = use_auth();
+    let navigator = use_navigator();
+
+    let mut password = use_signal(String::new);
+    let mut error_message = use_signal(|| Option::<String>::None);
+    let mut is_loading = use_signal(|| false);
+
+    if !*app_state.app_locked.read() || !app_state.user.read().is_authenticated {
+        return rsx! {};
+    }
+
+    let uses_wallet_password = app_state
+        .wallet
+        .read()
+        .selected_wallet_id
+        .as_ref()
+        .is_some_and(|id| wallet_controller.is_wallet_in_local_storage(id));
+    let auto_lock_minutes = app_state.preferences.read().auto_lock_minutes;
+
+    let mut submit = move || {
+        let pwd = password.read().clone();
+        if pwd.is_empty() {
+            error_message.set(Some("请输入密码".to_string()));
+            return;
+        }
+        is_loading.set(true);
+        error_message.set(None);
+        spawn(async move {
+            match wallet_controller.unlock_app(&pwd).await {
+                Ok(_) => password.set(String::new()),
+                Err(e) => {
+                    error_message.set(Some(crate::shared::ui_error::sanitize_user_message(
+                        format!("解锁失败: {}", e),
+                    )));
+                    password.set(String::new());
+                }
+            }
+            is_loading.set(false);
+        });
+    };
 
     rsx! {
         div {
             class: "fixed inset-0 z-50 flex items-center justify-center",
             style: format!(
-                "background: {}; backdrop-filter: blur(20px); -webkit-backdrop-filter: blur(20px);",
-                format!("{}CC", Colors::BG_PRIMARY) // 添加透明度
+                "background: {}CC; backdrop-filter: blur(20px); -webkit-backdrop-filter: blur(20px);",
+                Colors::BG_PRIMARY
             ),
 
             Card {
@@ -47,12 +84,16 @@ pub fn LockScreen() -> Element {
                         h1 {
                             class: "text-2xl font-bold mb-2",
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "钱包已锁定"
+                            "IronForge 已锁定"
                         }
                         p {
                             class: "text-sm",
                             style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "请输入密码以解锁钱包"
+                            if uses_wallet_password {
+                                "请输入钱包密码以继续"
+                            } else {
+                                "请输入账户密码以继续"
+                            }
                         }
                     }
 
@@ -63,107 +104,27 @@ pub fn LockScreen() -> Element {
                         p {
                             class: "text-xs",
                             style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "💡 钱包将在5分钟无操作后自动锁定，以保护您的资产安全"
+                            {format!("💡 {} 分钟无操作后自动锁定，登录状态和当前页面会保留", auto_lock_minutes)}
                         }
                     }
 
                     // 密码输入
                     form {
-                        onsubmit: {
-                            let password = password;
-                            let mut error_message = error_message;
-                            let mut is_loading = is_loading;
-                            let remember_password = remember_password;
-                            let wallet_controller = wallet_controller;
-                            let app_state = app_state;
-
-                            move |e: FormEvent| {
-                                e.stop_propagation();
-                                let pwd = password.read().clone();
-
-                                if !pwd.is_empty() {
-                                    is_loading.set(true);
-                                    error_message.set(None);
-
-                                    let wallet_state = app_state.wallet.read();
-                                    let wallet_id = wallet_state.selected_wallet_id.clone();
-                                    drop(wallet_state);
-
-                                    if let Some(wallet_id) = wallet_id {
-                                        let wallet_id_clone = wallet_id.clone();
-                                        let wallet_ctrl = wallet_controller;
-                                        let mut loading = is_loading;
-                                        let mut error_msg = error_message;
-                                        let mut pwd_sig = password;
-                                        let remember = remember_password;
-
-                                        spawn(async move {
-                                            match wallet_ctrl.unlock_wallet(&wallet_id_clone, &pwd).await {
-                                                Ok(_) => {
-                                                    loading.set(false);
-                                                    pwd_sig.set(String::new());
-
-                                                    // 如果选择了"记住密码"，设置记住时间
-                                                    if remember() {
-                                                        // 可以在这里实现"记住密码5分钟"的逻辑
-                                                        // 暂时只是解锁
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    loading.set(false);
-                                                    error_msg.set(Some(
-                                                        crate::shared::ui_error::sanitize_user_message(
-                                                            format!("解锁失败: {}", e),
-                                                        ),
-                                                    ));
-                                                    pwd_sig.set(String::new());
-                                                }
-                                            }
-                                        });
-                                    }
-                                }
-                            }
+                        class: "mb-6",
+                        onsubmit: move |e: FormEvent| {
+                            e.prevent_default();
+                            submit();
                         },
                         Input {
                             input_type: InputType::Password,
-                            label: Some("密码".to_string()),
-                            placeholder: Some("请输入钱包密码".to_string()),
+                            label: Some(if uses_wallet_password { "钱包密码" } else { "账户密码" }.to_string()),
+                            placeholder: Some("请输入密码".to_string()),
                             value: Some(password.read().clone()),
                             error: error_message.read().clone(),
-                            onchange: {
-                                let mut password = password;
-                                let mut error_message = error_message;
-                                Some(EventHandler::new(move |e: FormEvent| {
-                                    password.set(e.value());
-                                    error_message.set(None);
-                                }))
-                            },
-                        }
-                    }
-
-                    // 记住密码选项
-                    div {
-                        class: "mb-6",
-                        label {
-                            class: "flex items-center gap-3 cursor-pointer",
-                            input {
-                                r#type: "checkbox",
-                                checked: remember_password(),
-                                onchange: {
-                                    let mut remember_password = remember_password;
-                                    move |_e: FormEvent| {
-                                        // 切换checkbox状态
-                                        remember_password.set(!remember_password());
-                                    }
-                                },
-                                class: "w-5 h-5 rounded",
-                                style: format!("accent-color: {};", Colors::TECH_PRIMARY),
-                            }
-                            span {
-                                class: "text-sm",
-                                style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                "记住密码5分钟"
-                            }
+                            onchange: Some(EventHandler::new(move |e: FormEvent| {
+                                password.set(e.value());
+                                error_message.set(None);
+                            })),
                         }
                     }
 
@@ -172,75 +133,25 @@ pub fn LockScreen() -> Element {
                         variant: ButtonVariant::Primary,
                         size: ButtonSize::Large,
                         class: Some("w-full".to_string()),
-                        disabled: password.read().is_empty() || *is_loading.read(),
-                        loading: *is_loading.read(),
-                        onclick: {
-                            let password = password;
-                            let mut error_message = error_message;
-                            let is_loading = is_loading;
-                            let remember_password = remember_password;
-                            let wallet_controller = wallet_controller;
-                            let app_state = app_state;
-
-                            move |_| {
-                                let pwd = password.read().clone();
-
-                                if pwd.is_empty() {
-                                    error_message.set(Some("请输入密码".to_string()));
-                                    return;
-                                }
-
-                                let wallet_state = app_state.wallet.read();
-                                let wallet_id = wallet_state.selected_wallet_id.clone();
-                                drop(wallet_state);
-
-                                if let Some(wallet_id) = wallet_id {
-                                    let wallet_id_clone = wallet_id.clone();
-                                    let wallet_ctrl = wallet_controller;
-                                    let mut loading = is_loading;
-                                    let mut error_msg = error_message;
-                                    let mut pwd_sig = password;
-                                    let remember = remember_password;
-
-                                    loading.set(true);
-                                    error_msg.set(None);
-
-                                    spawn(async move {
-                                        match wallet_ctrl.unlock_wallet(&wallet_id_clone, &pwd).await {
-                                            Ok(_) => {
-                                                loading.set(false);
-                                                pwd_sig.set(String::new());
-
-                                                // 如果选择了"记住密码"，设置记住时间
-                                                if remember() {
-                                                    // 可以在这里实现"记住密码5分钟"的逻辑
-                                                    // 暂时只是解锁
-                                                }
-                                            }
-                                            Err(e) => {
-                                                loading.set(false);
-                                                error_msg.set(Some(
-                                                    crate::shared::ui_error::sanitize_user_message(
-                                                        format!("解锁失败: {}", e),
-                                                    ),
-                                                ));
-                                                pwd_sig.set(String::new());
-                                            }
-                                        }
-                                    });
-                                }
-                            }
-                        },
-                        "解锁钱包"
+                        disabled: password.read().is_empty() || is_loading(),
+                        loading: is_loading(),
+                        onclick: move |_| submit(),
+                        "解锁"
                     }
 
-                    // 帮助文本
+                    // 退出登录
                     div {
                         class: "mt-4 text-center",
-                        p {
+                        button {
                             class: "text-xs",
                             style: format!("color: {};", Colors::TEXT_TERTIARY),
-                            "忘记密码？您可以使用助记词恢复钱包"
+                            onclick: move |_| {
+                                spawn(async move {
+                                    let _ = auth_controller.logout().await;
+                                    navigator.push(Route::Login {});
+                                });
+                            },
+                            "不是你？退出登录"
                         }
                     }
                 }
@@ -4,6 +4,7 @@
 
 pub mod atoms;
 pub mod error_boundary;
+pub mod help_panel;
 pub mod lock_screen;
 pub mod logo;
 pub mod molecules;
@@ -11,5 +12,6 @@ pub mod navbar;
 pub mod payment_method_selector;
 pub mod payment_modal;
 pub mod route_guard;
+pub mod shortcut_layer;
 pub mod wallet_recover_modal;
 pub mod wallet_unlock_modal;
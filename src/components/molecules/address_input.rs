@@ -2,7 +2,8 @@
 //! 支持多链地址验证和实时错误提示
 
 use crate::components::atoms::input::{Input, InputType};
-use crate::shared::validation;
+use crate::services::address_detector::ChainType;
+use crate::services::address_validation::validate_address;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
 
@@ -52,15 +53,8 @@ pub fn AddressInput(
                     // 实时验证地址
                     let addr_val = value_sig.read().trim().to_string();
                     if !addr_val.is_empty() {
-                        let chain_val = chain_sig.read().clone();
-                        let validation_result = match chain_val.as_str() {
-                            "ethereum" | "eth" => validation::validate_eth_address(&addr_val),
-                            "bitcoin" | "btc" => validation::validate_btc_address(&addr_val),
-                            "solana" | "sol" => validation::validate_sol_address(&addr_val),
-                            "ton" => validation::validate_ton_address(&addr_val),
-                            _ => Ok(()),
-                        };
-                        if let Err(e) = validation_result {
+                        let chain_val = ChainType::from_str(&chain_sig.read());
+                        if let Some(Err(e)) = chain_val.map(|chain| validate_address(chain, &addr_val)) {
                             internal_err.set(Some(e.to_string()));
                         }
                     }
@@ -1,95 +1,231 @@
 //! AmountInput - 金额输入组件
-//! 支持MAX按钮和余额检查
+//! 代币图标与符号、实时余额（有占用时显示可用/总额）、法币估值、25/50/75/最大快捷比例，
+//! 输入与粘贴内容自动清洗并截断到代币精度
 
-use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
-use crate::components::atoms::input::{Input, InputType};
+use crate::components::atoms::input::FieldError;
+use crate::components::molecules::token_logo::TokenLogo;
+use crate::features::market::hooks::use_price;
 use crate::features::wallet::state::Account;
-use dioxus::events::FormEvent;
+use crate::services::balance::BalanceService;
+use crate::services::price::PriceService;
+use crate::services::token::TokenInfo;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::validation::{sanitize_amount_input, TokenAmount};
 use dioxus::prelude::*;
 
+/// 快捷比例（100 显示为“最大”）
+const PERCENT_SHORTCUTS: [u32; 4] = [25, 50, 75, 100];
+
+/// 法币估值所需的价格数据
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FiatQuote {
+    /// 代币 USD 价格
+    token_usd: f64,
+    /// 1 USD 折合显示货币
+    rate: f64,
+    currency_symbol: &'static str,
+    /// 价格服务不可用，使用的是最近一次价格
+    stale: bool,
+}
+
 /// 金额输入组件
 #[component]
 pub fn AmountInput(
-    /// 金额值
+    /// 金额值（十进制字符串）
     value: Signal<String>,
-    /// 当前账户（用于MAX按钮和余额显示）
-    current_account: ReadSignal<Option<Account>>,
-    /// Gas费估算（用于计算可用余额）
-    gas_estimate: Signal<Option<crate::services::gas::GasEstimate>>,
+    /// 当前代币（决定精度、图标与法币价格）
+    token: ReadSignal<Option<TokenInfo>>,
+    /// 持有该代币的账户（用于余额与快捷比例）
+    #[props(default)]
+    account: ReadSignal<Option<Account>>,
     /// 标签文本
-    #[props(default = "金额".to_string())]
-    label: String,
+    #[props(default)]
+    label: Option<String>,
     /// 占位符
     #[props(default = "0.0".to_string())]
     placeholder: String,
-    /// 值变化回调
-    onchange: Option<EventHandler<FormEvent>>,
-    /// 显示余额信息
-    #[props(default = true)]
-    show_balance: bool,
+    /// 字段错误（显示在输入框下方）
+    #[props(default)]
+    error: Option<String>,
+    /// 值变化回调（传入清洗后的值）
+    #[props(default)]
+    oninput: Option<EventHandler<String>>,
+    /// 失焦回调
+    #[props(default)]
+    onblur: Option<EventHandler<()>>,
 ) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut balance = use_signal(|| Option::<TokenAmount>::None);
+    // (1 USD 折合显示货币, 货币符号)
+    let mut fx = use_signal(|| Option::<(f64, &'static str)>::None);
+
+    // 代币或账户变化时刷新余额
+    use_effect(move || {
+        let token = token.read().clone();
+        let account = account.read().clone();
+        balance.set(None);
+        let (Some(token), Some(account)) = (token, account) else {
+            return;
+        };
+        spawn(async move {
+            match BalanceService::new(app_state)
+                .get_asset_balance(&account, &token)
+                .await
+            {
+                Ok(amount) => balance.set(Some(amount)),
+                Err(e) => log::warn!("获取 {} 余额失败: {:?}", token.symbol, e),
+            }
+        });
+    });
+
+    // 价格来自共享行情（后台统一轮询），显示货币变化时刷新汇率
+    let symbol = use_memo(move || token.read().as_ref().map(|t| t.symbol.clone()));
+    let price = use_price(symbol);
+    use_effect(move || {
+        let currency = app_state.preferences.read().currency.clone();
+        spawn(async move {
+            // 汇率获取失败时退回 USD 显示
+            let quote = match PriceService::new(app_state)
+                .get_fiat_rate(currency.code())
+                .await
+            {
+                Ok(rate) => (rate, currency.symbol()),
+                Err(_) => (1.0, "$"),
+            };
+            fx.set(Some(quote));
+        });
+    });
+
+    let token_val = token.read().clone();
+    let decimals = token_val.as_ref().map_or(18, |t| t.decimals);
+    // 快捷比例按可用余额计算（扣除挂单与进行中订单的占用）
+    let committed = app_state.committed_balances.read().clone();
+    let available = balance().zip(token_val.as_ref()).map(|(total, token)| {
+        (
+            committed.available(&token.symbol, total),
+            committed.is_committed(&token.symbol),
+        )
+    });
+    let fiat = price()
+        .zip(fx())
+        .map(|(price, (rate, currency_symbol))| FiatQuote {
+            token_usd: price.usd,
+            rate,
+            currency_symbol,
+            stale: price.is_stale(),
+        });
+    let fiat_value = fiat.and_then(|quote| {
+        let amount = TokenAmount::parse_input(&value.read(), decimals).ok()?;
+        Some(format!(
+            "≈ {}{:.2}{}",
+            quote.currency_symbol,
+            amount.to_f64_lossy() * quote.token_usd * quote.rate,
+            if quote.stale {
+                "（价格可能已过时）"
+            } else {
+                ""
+            }
+        ))
+    });
+    let border = if error.is_some() {
+        Colors::PAYMENT_ERROR
+    } else {
+        Colors::BORDER_PRIMARY
+    };
+
     rsx! {
         div {
-            class: "mb-6",
+            if let Some(label_text) = label {
+                label {
+                    class: "block text-sm font-medium mb-2",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {label_text}
+                }
+            }
             div {
-                class: "flex items-end gap-2",
-                div {
-                    class: "flex-1",
-                    Input {
-                        input_type: InputType::Number,
-                        label: Some(label),
-                        placeholder: Some(placeholder),
-                        value: Some(value.read().clone()),
-                        onchange: {
-                            let mut value_sig = value;
-                            let onchange_cb = onchange;
-                            Some(EventHandler::new(move |e: FormEvent| {
-                                value_sig.set(e.value());
-
-                                // 调用外部回调
-                                if let Some(ref cb) = onchange_cb {
-                                    cb.call(e);
-                                }
-                            }))
-                        },
-                    }
+                class: "flex items-center gap-3 p-3 rounded-lg",
+                style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, border),
+                input {
+                    class: "flex-1 min-w-0 bg-transparent text-lg focus:outline-none",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    r#type: "text",
+                    inputmode: "decimal",
+                    autocomplete: "off",
+                    value: "{value.read()}",
+                    placeholder: "{placeholder}",
+                    oninput: move |e| {
+                        let sanitized = sanitize_amount_input(&e.value(), decimals);
+                        value.set(sanitized.clone());
+                        if let Some(handler) = oninput.as_ref() {
+                            handler.call(sanitized);
+                        }
+                    },
+                    onblur: move |_| {
+                        if let Some(handler) = onblur.as_ref() {
+                            handler.call(());
+                        }
+                    },
                 }
-                {
-                    if let Some(account) = current_account.read().as_ref() {
-                        let account_clone = account.clone();
-                        rsx! {
-                            Button {
-                                variant: ButtonVariant::Secondary,
-                                size: ButtonSize::Medium,
-                                onclick: {
-                                    let account = account_clone;
-                                    let gas_est = gas_estimate;
-                                    let mut value_sig = value;
-                                    move |_| {
-                                        // 设置最大金额（余额 - Gas费）
-                                        let balance: f64 = account.balance.parse().unwrap_or(0.0);
-                                        let gas_cost = gas_est.read().as_ref().map(|g| {
-                                            g.max_fee_per_gas_gwei * 21000.0 / 1e9
-                                        }).unwrap_or(0.0);
-                                        let max_amount = (balance - gas_cost).max(0.0);
-                                        value_sig.set(format!("{:.6}", max_amount));
-                                    }
-                                },
-                                "MAX"
-                            }
+                if let Some(token) = token_val.as_ref() {
+                    div {
+                        class: "flex items-center gap-2 shrink-0",
+                        TokenLogo { token: token.clone(), size: 24 }
+                        span {
+                            class: "font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{token.symbol}"
                         }
-                    } else {
-                        rsx! { div {} }
                     }
                 }
             }
+            FieldError { error: error }
 
-            // 余额显示
-            if show_balance {
-                if let Some(account) = current_account.read().as_ref() {
+            // 余额、法币估值与快捷比例
+            div {
+                class: "flex items-center justify-between gap-2 mt-2 text-xs",
+                div {
+                    class: "flex flex-col",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    if let Some(fiat_text) = fiat_value {
+                        span { {fiat_text} }
+                    }
+                    if let (Some(total), Some((available, committed)), Some(token)) =
+                        (balance(), available, token_val.as_ref())
+                    {
+                        if committed {
+                            span {
+                                {format!(
+                                    "可用 / 总额: {} / {} {}",
+                                    available.format(6),
+                                    total.format(6),
+                                    token.symbol
+                                )}
+                            }
+                        } else {
+                            span { {format!("可用余额: {} {}", total.format(6), token.symbol)} }
+                        }
+                    }
+                }
+                if let Some((balance, _)) = available {
                     div {
-                        class: "mt-2 text-sm text-gray-400",
-                        "可用余额: {account.balance}"
+                        class: "flex gap-1",
+                        for percent in PERCENT_SHORTCUTS {
+                            button {
+                                class: "px-2 py-1 rounded",
+                                style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
+                                disabled: balance.is_zero(),
+                                onclick: move |_| {
+                                    // 按最小单位向下取整，不会超过余额
+                                    let amount = balance.percent(percent).to_string();
+                                    value.set(amount.clone());
+                                    if let Some(handler) = oninput.as_ref() {
+                                        handler.call(amount);
+                                    }
+                                },
+                                if percent == 100 { "最大" } else { "{percent}%" }
+                            }
+                        }
                     }
                 }
             }
@@ -0,0 +1,194 @@
+//! Backup Reminder - 助记词备份验证提醒
+//! 仪表盘提示条（信息 → 警告）与转出交易前的拦截页，均可直接输入密码开始补验证
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::atoms::modal::Modal;
+use crate::features::wallet::backup_reminder::{self, ReminderLevel};
+use crate::features::wallet::hooks::use_wallet;
+use crate::features::wallet::state::Wallet;
+use crate::router::Route;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use dioxus::prelude::*;
+
+/// 当前钱包的提醒级别（未推迟验证或已验证时为 None）
+fn selected_reminder(app_state: &AppState) -> Option<(Wallet, ReminderLevel)> {
+    let wallet = app_state.wallet.read().get_selected_wallet().cloned()?;
+    let level = backup_reminder::reminder(&wallet, time::now_unix())?;
+    Some((wallet, level))
+}
+
+/// 替换文案中的 {wallet}、{days}
+fn fill(text: String, wallet: &str, days: u64) -> String {
+    text.replace("{wallet}", wallet)
+        .replace("{days}", &days.to_string())
+}
+
+/// 仪表盘提示条：推迟后 3 天内为信息提示，之后为警告
+#[component]
+pub fn BackupReminderBanner() -> Element {
+    let app_state = use_context::<AppState>();
+    let t = crate::i18n::use_translation();
+    let mut verifying = use_signal(|| false);
+
+    let Some((wallet, level)) = selected_reminder(&app_state) else {
+        return rsx! {};
+    };
+    let days = backup_reminder::deferred_at(&wallet.id)
+        .map(|at| backup_reminder::days_until_blocking(at, time::now_unix()))
+        .unwrap_or(0);
+    let (key, color, background) = match level {
+        ReminderLevel::Info => (
+            "backup.reminder.info",
+            Colors::TECH_PRIMARY,
+            "rgba(99, 102, 241, 0.1)",
+        ),
+        _ => (
+            "backup.reminder.warning",
+            Colors::PAYMENT_WARNING,
+            "rgba(245, 158, 11, 0.1)",
+        ),
+    };
+
+    rsx! {
+        div {
+            class: "mb-4 p-4 rounded-lg text-sm flex flex-col sm:flex-row sm:items-center gap-3",
+            style: format!("background: {}; border: 1px solid {};", background, color),
+            div {
+                class: "flex-1",
+                style: format!("color: {};", color),
+                {format!("🔐 {}", fill(t(key), &wallet.name, days))}
+            }
+            Button {
+                variant: ButtonVariant::Primary,
+                size: ButtonSize::Small,
+                onclick: move |_| verifying.set(true),
+                {t("backup.reminder.verify_now")}
+            }
+        }
+        VerifyBackupDialog {
+            open: verifying(),
+            wallet_id: wallet.id.clone(),
+            on_close: move |_| verifying.set(false),
+        }
+    }
+}
+
+/// 转出交易页面的拦截页：推迟验证超过 7 天时覆盖整个页面
+#[component]
+pub fn BackupGate() -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let t = crate::i18n::use_translation();
+    let mut verifying = use_signal(|| false);
+
+    let Some((wallet, ReminderLevel::Blocking)) = selected_reminder(&app_state) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-40 flex items-center justify-center p-4",
+            style: "background: rgba(0, 0, 0, 0.75);",
+            div {
+                class: "max-w-md w-full p-6 rounded-xl space-y-4",
+                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::PAYMENT_WARNING),
+                h2 {
+                    class: "text-lg font-bold",
+                    style: format!("color: {};", Colors::PAYMENT_WARNING),
+                    {format!("🔐 {}", t("backup.gate.title"))}
+                }
+                p {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {fill(t("backup.gate.body"), &wallet.name, 0)}
+                }
+                div {
+                    class: "flex gap-3",
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        size: ButtonSize::Medium,
+                        class: Some("flex-1".to_string()),
+                        onclick: move |_| verifying.set(true),
+                        {t("backup.reminder.verify_now")}
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Medium,
+                        onclick: move |_| navigator.go_back(),
+                        {t("backup.gate.back")}
+                    }
+                }
+            }
+        }
+        VerifyBackupDialog {
+            open: verifying(),
+            wallet_id: wallet.id.clone(),
+            on_close: move |_| verifying.set(false),
+        }
+    }
+}
+
+/// 输入钱包密码取出暂存的助记词，进入验证页
+#[component]
+fn VerifyBackupDialog(open: bool, wallet_id: String, on_close: EventHandler<()>) -> Element {
+    let navigator = use_navigator();
+    let wallet_controller = use_wallet();
+    let t = crate::i18n::use_translation();
+    let mut password = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut loading = use_signal(|| false);
+
+    let submit = move |_| {
+        let wallet_id = wallet_id.clone();
+        let pwd = password.peek().clone();
+        loading.set(true);
+        error.set(None);
+        spawn(async move {
+            match wallet_controller
+                .deferred_backup_phrase(&wallet_id, &pwd)
+                .await
+            {
+                Ok(phrase) => {
+                    password.set(String::new());
+                    backup_reminder::start_verification(&wallet_id);
+                    navigator.push(Route::MnemonicVerify { phrase });
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    rsx! {
+        Modal {
+            open: open,
+            onclose: move |_| {
+                password.set(String::new());
+                on_close.call(());
+            },
+            title: Some(t("backup.gate.title")),
+            div {
+                class: "space-y-4",
+                Input {
+                    input_type: InputType::Password,
+                    label: Some(t("backup.unlock.password")),
+                    value: Some(password.read().clone()),
+                    error: error.read().clone(),
+                    onchange: move |e: FormEvent| password.set(e.value()),
+                }
+                Button {
+                    variant: ButtonVariant::Primary,
+                    size: ButtonSize::Medium,
+                    class: Some("w-full".to_string()),
+                    disabled: password.read().is_empty() || loading(),
+                    loading: loading(),
+                    onclick: submit,
+                    {t("backup.unlock.submit")}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Bridge Fee Summary - 跨链费用明细
+//! 分项显示源链 Gas、跨链桥协议费用与目标链 Gas，并给出目标链预计到账数量；
+//! 开启到账兑换 Gas 时单独列出预留数量
+
+use crate::components::atoms::skeleton::SkeletonText;
+use crate::features::bridge::gas_on_arrival::GasOnArrivalPlan;
+use crate::services::address_detector::ChainType;
+use crate::services::bridge_fee::{trim_amount, BridgeFeeQuote};
+use crate::services::price::PriceService;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 跨链费用明细卡片
+#[component]
+pub fn BridgeFeeSummary(
+    quote: Option<BridgeFeeQuote>,
+    is_loading: bool,
+    /// 桥接代币符号
+    token: String,
+    /// 桥接数量
+    amount: f64,
+    source_chain: ChainType,
+    destination_chain: ChainType,
+    /// 到账时兑换为目标链 Gas 的预留方案
+    #[props(default)]
+    gas_reserve: Option<GasOnArrivalPlan>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut prices = use_signal(HashMap::<String, f64>::new);
+
+    let source_native = source_chain.native_token_symbol();
+    let destination_native = destination_chain.native_token_symbol();
+
+    // 代币与原生币价格（用于美元折算，获取失败时只显示数量）
+    let price_token = token.clone();
+    use_effect(use_reactive!(|(
+        price_token,
+        source_chain,
+        destination_chain,
+    )| {
+        spawn(async move {
+            let symbols = [
+                price_token.as_str(),
+                source_chain.native_token_symbol(),
+                destination_chain.native_token_symbol(),
+            ];
+            match PriceService::new(app_state).get_prices(&symbols).await {
+                Ok(result) => prices.set(result.into_iter().map(|(k, v)| (k, v.usd)).collect()),
+                Err(e) => log::warn!("跨链费用折算价格获取失败: {:?}", e),
+            }
+        });
+    }));
+
+    if !is_loading && quote.is_none() {
+        return rsx! {};
+    }
+
+    let usd = |symbol: &str, value: f64| -> String {
+        prices
+            .read()
+            .get(symbol)
+            .map(|price| format!("≈ ${:.2}", value * price))
+            .unwrap_or_default()
+    };
+
+    let row = |label: &str, value: String, fiat: String| {
+        rsx! {
+            div {
+                class: "flex justify-between items-center text-sm",
+                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{label}" }
+                span {
+                    class: "text-right",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "{value}"
+                    if !fiat.is_empty() {
+                        span {
+                            class: "ml-1 text-xs",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "{fiat}"
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-2",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex items-center justify-between",
+                span {
+                    class: "text-sm font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "费用明细"
+                }
+                if quote.as_ref().is_some_and(|q| q.is_estimated()) {
+                    span {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::PAYMENT_WARNING),
+                        "估算值，实际以执行时为准"
+                    }
+                }
+            }
+            if is_loading {
+                div {
+                    class: "space-y-2",
+                    SkeletonText { width: "100%".to_string(), height: 16 }
+                    SkeletonText { width: "100%".to_string(), height: 16 }
+                    SkeletonText { width: "100%".to_string(), height: 16 }
+                }
+            } else if let Some(q) = quote.as_ref() {
+                {row(
+                    &format!("源链 Gas（{}）", source_chain.label()),
+                    format!("{} {}", trim_amount(q.source_gas_fee), source_native),
+                    usd(source_native, q.source_gas_fee),
+                )}
+                {row(
+                    "跨链桥费用",
+                    format!("{} {}", trim_amount(q.bridge_fee), token),
+                    usd(&token, q.bridge_fee),
+                )}
+                {row(
+                    &format!("目标链 Gas（{}）", destination_chain.label()),
+                    format!("{} {}", trim_amount(q.target_gas_fee), source_native),
+                    usd(source_native, q.target_gas_fee),
+                )}
+                div {
+                    class: "pt-2 mt-2 border-t space-y-2",
+                    style: format!("border-color: {};", Colors::BORDER_PRIMARY),
+                    if let Some(reserve) = gas_reserve.as_ref() {
+                        {row(
+                            &format!(
+                                "预留兑换 {}（{}）",
+                                reserve.native_symbol,
+                                if reserve.via_provider { "服务商到账兑换" } else { "到账后自动兑换" },
+                            ),
+                            format!("{} {}", trim_amount(reserve.reserve_amount), token),
+                            usd(&token, reserve.reserve_amount),
+                        )}
+                    }
+                    {
+                        let reserved = gas_reserve.as_ref().map_or(0.0, |r| r.reserve_amount);
+                        let received = (q.net_received(amount) - reserved).max(0.0);
+                        row(
+                            "预计收到",
+                            format!("{} {}", trim_amount(received), token),
+                            usd(&token, received),
+                        )
+                    }
+                    if q.destination_gas_dropoff > 0.0 {
+                        {row(
+                            "附送目标链 Gas",
+                            format!("+{} {}", trim_amount(q.destination_gas_dropoff), destination_native),
+                            usd(destination_native, q.destination_gas_dropoff),
+                        )}
+                    }
+                    if q.min_amount.is_some() || q.max_amount.is_some() {
+                        div {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {
+                                let min = q.min_amount.map(trim_amount).unwrap_or_else(|| "0".to_string());
+                                let max = q.max_amount.map(trim_amount).unwrap_or_else(|| "不限".to_string());
+                                format!("单笔限额：{} – {} {}", min, max, token)
+                            }
+                        }
+                    }
+                    if q.estimated_time_seconds > 0 {
+                        div {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {format!("预计 {} 分钟到账 · {}", q.estimated_time_seconds.div_ceil(60), q.bridge_protocol)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
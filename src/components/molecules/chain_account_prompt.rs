@@ -0,0 +1,112 @@
+//! Chain Account Prompt - 缺少目标链账户时的添加提示
+//! 当前钱包没有所选代币所在链的账户时，询问是否按该链的派生路径添加；
+//! 确认后派生并保存账户，余额随钱包地址变化自动重新加载；拒绝则标注余额不可用
+
+use crate::features::wallet::chain_accounts::{account_for_chain, can_derive};
+use crate::features::wallet::hooks::use_wallet;
+use crate::services::address_detector::ChainType;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 缺少目标链账户时的添加提示（钱包已有该链账户时不渲染）
+#[component]
+pub fn ChainAccountPrompt(
+    /// 所选代币所在链
+    chain: ChainType,
+    /// 所选代币符号（用于余额不可用提示）
+    token_symbol: Option<String>,
+    /// 暂不添加时的提示（默认为余额不可用）
+    #[props(default)]
+    unavailable_label: Option<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let wallet_controller = use_wallet();
+    let mut declined = use_signal(|| Option::<ChainType>::None);
+    let mut adding = use_signal(|| false);
+
+    let wallet_state = app_state.wallet.read();
+    let Some(wallet) = wallet_state.get_selected_wallet() else {
+        return VNode::empty();
+    };
+    if wallet.accounts.is_empty() || account_for_chain(wallet, chain).is_some() {
+        return VNode::empty();
+    }
+    let wallet_id = wallet.id.clone();
+    let derivable = can_derive(wallet);
+    drop(wallet_state);
+
+    let unavailable = match (unavailable_label, token_symbol) {
+        (Some(label), _) => label,
+        (None, Some(symbol)) => {
+            format!("{} 余额不可用：当前钱包没有 {} 账户", symbol, chain.label())
+        }
+        (None, None) => format!("余额不可用：当前钱包没有 {} 账户", chain.label()),
+    };
+
+    if !derivable || declined() == Some(chain) {
+        return rsx! {
+            div {
+                class: "mt-2 flex items-center justify-between gap-2 text-xs",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                span { {unavailable} }
+                if derivable {
+                    button {
+                        class: "underline shrink-0",
+                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                        onclick: move |_| declined.set(None),
+                        "添加账户"
+                    }
+                }
+            }
+        };
+    }
+
+    let on_confirm = move |_| {
+        let wallet_id = wallet_id.clone();
+        adding.set(true);
+        spawn(async move {
+            match wallet_controller.add_chain_account(&wallet_id, chain).await {
+                Ok(account) => AppState::show_success(
+                    app_state.toasts,
+                    format!("已添加 {} 账户 {}", chain.label(), account.address),
+                ),
+                Err(e) => AppState::show_error(app_state.toasts, e.to_string()),
+            }
+            adding.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "mt-2 p-3 rounded-lg text-sm space-y-2",
+            style: format!("background: rgba(245, 158, 11, 0.1); border: 1px solid {};", Colors::PAYMENT_WARNING),
+            div {
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                {format!("为此钱包添加 {} 账户？", chain.label())}
+            }
+            div {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "将按该链的标准派生路径从助记词生成账户，添加后自动查询余额"
+            }
+            div {
+                class: "flex gap-3 text-xs",
+                button {
+                    class: "underline",
+                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                    disabled: adding(),
+                    onclick: on_confirm,
+                    if adding() { "添加中..." } else { "添加账户" }
+                }
+                button {
+                    class: "underline",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    disabled: adding(),
+                    onclick: move |_| declined.set(Some(chain)),
+                    "暂不添加"
+                }
+            }
+        }
+    }
+}
@@ -1,63 +1,190 @@
 //! Chain Selector - 链选择器组件
-//! 用于选择区块链（Ethereum、Bitcoin、Solana、TON）
+//! 按 EVM / Bitcoin / Solana / TON 分组展示网络，
+//! 可用性来自链配置与功能开关，不可用的链置灰并提示原因
 
-use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::services::chain_config::{ChainConfigManager, ChainFeature, ChainGroup, ALL_CHAINS};
 use crate::shared::design_tokens::Colors;
+use crate::shared::feature_flags::use_feature_flags;
 use dioxus::prelude::*;
 
 // 使用服务层的ChainType
 use crate::services::address_detector::ChainType;
 
-/// 链选择器组件（支持所有链）
+/// 网络图标（字符, 品牌色）
+fn chain_icon(chain: ChainType) -> (&'static str, &'static str) {
+    match chain {
+        ChainType::Ethereum => ("Ξ", "#627EEA"),
+        ChainType::BSC => ("B", "#F3BA2F"),
+        ChainType::Polygon => ("⬡", "#8247E5"),
+        ChainType::Bitcoin => ("₿", "#F7931A"),
+        ChainType::Solana => ("◎", "#9945FF"),
+        ChainType::TON => ("◆", "#0098EA"),
+    }
+}
+
+/// (链, 不可用原因)
+type ChainAvailability = (ChainType, Option<String>);
+
+/// 链选择器组件
 #[component]
-pub fn ChainSelector(selected_chain: Signal<String>) -> Element {
-    let current_chain_str = selected_chain.read().clone();
-    let current_chain = ChainType::from_str(&current_chain_str).unwrap_or(ChainType::Ethereum);
+pub fn ChainSelector(
+    /// 当前选择的链（`ChainType::as_str` 形式）
+    selected_chain: Signal<String>,
+    /// 页面允许的链（None 表示全部）
+    #[props(default)]
+    allowed_chains: Option<Vec<ChainType>>,
+    /// 用于判断可用性的功能场景
+    #[props(default = ChainFeature::Send)]
+    feature: ChainFeature,
+    /// 标签文本
+    #[props(default = Some("选择链".to_string()))]
+    label: Option<String>,
+    /// 标签旁的说明
+    #[props(default)]
+    hint: Option<String>,
+    /// 用户切换链后的回调
+    #[props(default)]
+    onselect: Option<EventHandler<ChainType>>,
+) -> Element {
+    let flags = use_feature_flags();
+    let current_chain = ChainType::from_str(&selected_chain.read());
 
-    // 所有支持的链
-    let all_chains = [
-        ChainType::Ethereum,
-        ChainType::BSC,
-        ChainType::Polygon,
-        ChainType::Bitcoin,
-        ChainType::Solana,
-        ChainType::TON,
-    ];
+    let registry = ChainConfigManager::new();
+    let flag_off = |key: &str| flags.read().is_switched_off(key);
+    let groups: Vec<(ChainGroup, Vec<ChainAvailability>)> = ChainGroup::ALL
+        .iter()
+        .map(|group| {
+            let chains = ALL_CHAINS
+                .iter()
+                .filter(|chain| ChainGroup::of(**chain) == *group)
+                .filter(|chain| {
+                    allowed_chains
+                        .as_ref()
+                        .is_none_or(|allowed| allowed.contains(chain))
+                })
+                .map(|chain| {
+                    (
+                        *chain,
+                        registry.unavailable_reason(*chain, feature, &flag_off),
+                    )
+                })
+                .collect::<Vec<_>>();
+            (*group, chains)
+        })
+        .filter(|(_, chains)| !chains.is_empty())
+        .collect();
+    // 只有一个分组时不显示分组标题
+    let show_group_titles = groups.len() > 1;
 
     rsx! {
         div {
             class: "mb-6",
-            div {
-                class: "flex items-center justify-between mb-2",
-                label {
-                    class: "block text-sm font-medium",
-                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                    "选择链"
-                }
-                span {
-                    class: "text-xs",
-                    style: format!("color: {};", Colors::TEXT_TERTIARY),
-                    "(选择代币后自动匹配)"
+            if label.is_some() || hint.is_some() {
+                div {
+                    class: "flex items-center justify-between mb-2",
+                    if let Some(label_text) = label {
+                        label {
+                            class: "block text-sm font-medium",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {label_text}
+                        }
+                    }
+                    if let Some(hint_text) = hint {
+                        span {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {hint_text}
+                        }
+                    }
                 }
             }
             div {
-                class: "grid grid-cols-2 sm:grid-cols-3 md:grid-cols-6 gap-2",
-                for chain in all_chains.iter() {
-                    Button {
-                        variant: if current_chain == *chain {
-                            ButtonVariant::Primary
-                        } else {
-                            ButtonVariant::Secondary
-                        },
-                        size: ButtonSize::Medium,
-                        onclick: {
-                            let mut selected_chain = selected_chain;
-                            let chain_str = chain.as_str().to_string();
-                            move |_| {
-                                selected_chain.set(chain_str.clone());
+                class: "space-y-3",
+                for (group, chains) in groups {
+                    div {
+                        key: "{group.label()}",
+                        if show_group_titles {
+                            div {
+                                class: "text-xs font-semibold uppercase tracking-wide mb-1",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                {group.label()}
                             }
-                        },
-                        {chain.label()}
+                        }
+                        div {
+                            class: "grid grid-cols-2 sm:grid-cols-3 gap-2",
+                            for (chain, reason) in chains {
+                                ChainOption {
+                                    key: "{chain.as_str()}",
+                                    chain,
+                                    selected: current_chain == Some(chain),
+                                    reason,
+                                    onclick: move |_| {
+                                        selected_chain.set(chain.as_str().to_string());
+                                        if let Some(handler) = onselect.as_ref() {
+                                            handler.call(chain);
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 单个网络选项（不可用时置灰，悬停显示原因）
+#[component]
+fn ChainOption(
+    chain: ChainType,
+    selected: bool,
+    reason: Option<String>,
+    onclick: EventHandler<()>,
+) -> Element {
+    let (glyph, color) = chain_icon(chain);
+    let disabled = reason.is_some();
+    let tooltip = reason.clone().unwrap_or_default();
+    let border = if selected {
+        Colors::TECH_PRIMARY
+    } else {
+        Colors::BORDER_PRIMARY
+    };
+    let background = if selected {
+        "rgba(99, 102, 241, 0.12)"
+    } else {
+        Colors::BG_PRIMARY
+    };
+
+    rsx! {
+        // 禁用的按钮不触发悬停提示，原因放在外层
+        div {
+            title: tooltip,
+            button {
+                class: "w-full flex items-center gap-2 px-3 py-2 rounded-lg text-sm font-medium text-left transition-all",
+                style: format!(
+                    "background: {background}; border: 2px solid {border}; color: {}; opacity: {}; cursor: {};",
+                    Colors::TEXT_PRIMARY,
+                    if disabled { "0.5" } else { "1" },
+                    if disabled { "not-allowed" } else { "pointer" },
+                ),
+                disabled,
+                "aria-pressed": "{selected}",
+                onclick: move |_| onclick.call(()),
+                span {
+                    class: "w-6 h-6 rounded-full flex items-center justify-center text-xs font-bold text-white shrink-0",
+                    style: format!("background: {color};"),
+                    {glyph}
+                }
+                span {
+                    class: "flex flex-col min-w-0",
+                    span { {chain.label()} }
+                    if let Some(reason) = reason {
+                        span {
+                            class: "text-[10px] truncate",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {reason}
+                        }
                     }
                 }
             }
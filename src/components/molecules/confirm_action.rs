@@ -0,0 +1,335 @@
+//! Confirm Action - 统一的操作确认弹窗
+//! 按风险等级控制配色与确认方式：普通确认 / 输入名称确认 / 长按确认
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::atoms::modal::Modal;
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+/// 长按确认所需时长（毫秒）
+pub const HOLD_TO_CONFIRM_MS: u32 = 1500;
+/// 长按进度刷新间隔（毫秒）
+const HOLD_TICK_MS: u32 = 50;
+
+/// 操作风险等级
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RiskLevel {
+    /// 普通信息确认
+    #[default]
+    Info,
+    /// 需要留意的操作（如取消订单）
+    Caution,
+    /// 不可恢复的破坏性操作（如删除钱包），必须输入确认文本或长按确认
+    Destructive,
+}
+
+impl RiskLevel {
+    /// 风险等级对应的强调色
+    pub fn accent_color(&self) -> &'static str {
+        match self {
+            RiskLevel::Info => Colors::TECH_PRIMARY,
+            RiskLevel::Caution => Colors::PAYMENT_WARNING,
+            RiskLevel::Destructive => Colors::PAYMENT_ERROR,
+        }
+    }
+
+    fn button_variant(&self) -> ButtonVariant {
+        match self {
+            RiskLevel::Info => ButtonVariant::Primary,
+            RiskLevel::Caution => ButtonVariant::Warning,
+            RiskLevel::Destructive => ButtonVariant::Error,
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            RiskLevel::Info => "ℹ️",
+            RiskLevel::Caution => "⚠️",
+            RiskLevel::Destructive => "⛔",
+        }
+    }
+}
+
+/// 输入的确认文本是否与要求一致（忽略首尾空白，区分大小写）
+pub fn confirm_phrase_matches(expected: &str, typed: &str) -> bool {
+    !expected.trim().is_empty() && typed.trim() == expected.trim()
+}
+
+/// 当前输入状态下是否允许提交确认
+///
+/// 破坏性操作在未设置确认文本时走长按确认，不允许直接点击/回车提交。
+pub fn can_submit(risk: RiskLevel, confirm_phrase: Option<&str>, typed: &str, busy: bool) -> bool {
+    if busy {
+        return false;
+    }
+    match (risk, confirm_phrase) {
+        (_, Some(phrase)) => confirm_phrase_matches(phrase, typed),
+        (RiskLevel::Destructive, None) => false,
+        _ => true,
+    }
+}
+
+/// 统一确认弹窗
+///
+/// - `Esc` 取消、`Enter` 在满足条件时确认
+/// - `busy` 期间按钮置灰、弹窗不可关闭，由调用方在异步操作结束后复位
+/// - `Destructive` 且提供 `confirm_phrase` 时需输入该文本；否则需长按确认按钮
+///
+/// # 示例
+///
+/// ```rust
+/// rsx! {
+///     ConfirmAction {
+///         open: show_confirm(),
+///         risk: RiskLevel::Destructive,
+///         title: "删除钱包".to_string(),
+///         message: "删除后无法恢复".to_string(),
+///         confirm_phrase: Some(wallet_name.clone()),
+///         busy: deleting(),
+///         on_confirm: move |_| { /* 执行删除 */ },
+///         on_cancel: move |_| show_confirm.set(false),
+///     }
+/// }
+/// ```
+#[component]
+pub fn ConfirmAction(
+    /// 是否打开
+    open: bool,
+    /// 风险等级
+    #[props(default)]
+    risk: RiskLevel,
+    /// 标题
+    title: String,
+    /// 主提示
+    message: String,
+    /// 补充说明（列表形式）
+    #[props(default)]
+    details: Vec<String>,
+    /// 确认按钮文本
+    #[props(default = "确认".to_string())]
+    confirm_text: String,
+    /// 取消按钮文本
+    #[props(default = "取消".to_string())]
+    cancel_text: String,
+    /// 需要用户输入的确认文本（如钱包名称）
+    #[props(default)]
+    confirm_phrase: Option<String>,
+    /// 异步操作进行中
+    #[props(default = false)]
+    busy: bool,
+    /// 确认回调
+    on_confirm: EventHandler<()>,
+    /// 取消回调
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut typed = use_signal(String::new);
+    let mut hold_progress = use_signal(|| 0u32);
+    // 每次按下/松开递增，用于作废上一次长按的计时任务
+    let mut hold_generation = use_signal(|| 0u64);
+
+    // 关闭后清空输入与长按进度，下次打开重新确认
+    use_effect(use_reactive!(|open| {
+        if !open {
+            typed.set(String::new());
+            hold_progress.set(0);
+            *hold_generation.write() += 1;
+        }
+    }));
+
+    let phrase = confirm_phrase.clone();
+    let needs_hold = risk == RiskLevel::Destructive && phrase.is_none();
+    let submittable = can_submit(risk, phrase.as_deref(), &typed.read(), busy);
+    let accent = risk.accent_color();
+
+    let cancel = move || {
+        if !busy {
+            on_cancel.call(());
+        }
+    };
+
+    let mut start_hold = move || {
+        if busy {
+            return;
+        }
+        let generation = {
+            let mut g = hold_generation.write();
+            *g += 1;
+            *g
+        };
+        spawn(async move {
+            let mut elapsed = 0;
+            while elapsed < HOLD_TO_CONFIRM_MS {
+                gloo_timers::future::TimeoutFuture::new(HOLD_TICK_MS).await;
+                if *hold_generation.peek() != generation {
+                    return;
+                }
+                elapsed += HOLD_TICK_MS;
+                hold_progress.set(elapsed * 100 / HOLD_TO_CONFIRM_MS);
+            }
+            hold_progress.set(0);
+            on_confirm.call(());
+        });
+    };
+
+    let mut release_hold = move || {
+        *hold_generation.write() += 1;
+        hold_progress.set(0);
+    };
+
+    rsx! {
+        Modal {
+            open: open,
+            onclose: move |_| cancel(),
+            title: Some(title.clone()),
+            show_close: !busy,
+            typed_confirm: phrase.is_some(),
+            children: rsx! {
+                div {
+                    class: "space-y-4",
+                    tabindex: "0",
+                    onkeydown: move |evt: KeyboardEvent| match evt.key() {
+                        Key::Escape => {
+                            evt.prevent_default();
+                            cancel();
+                        }
+                        Key::Enter if submittable => {
+                            evt.prevent_default();
+                            on_confirm.call(());
+                        }
+                        _ => {}
+                    },
+                    div {
+                        class: "flex items-start gap-3 p-3 rounded-lg",
+                        style: format!("background: {}1A; border: 1px solid {}4D;", accent, accent),
+                        span { class: "text-lg", {risk.icon()} }
+                        p {
+                            class: "text-base font-medium",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{message}"
+                        }
+                    }
+                    if !details.is_empty() {
+                        ul {
+                            class: "text-sm list-disc list-inside space-y-1",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            for detail in details.iter() {
+                                li { "{detail}" }
+                            }
+                        }
+                    }
+                    if let Some(expected) = phrase.clone() {
+                        div {
+                            p {
+                                class: "text-sm mb-2",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "请输入 "
+                                span {
+                                    class: "font-mono font-semibold",
+                                    style: format!("color: {};", accent),
+                                    "{expected}"
+                                }
+                                " 以确认"
+                            }
+                            Input {
+                                input_type: InputType::Text,
+                                value: Some(typed.read().clone()),
+                                placeholder: Some(expected.clone()),
+                                disabled: busy,
+                                onchange: move |e: FormEvent| typed.set(e.value()),
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex gap-3 mt-6",
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            class: Some("flex-1".to_string()),
+                            disabled: busy,
+                            onclick: move |_| cancel(),
+                            "{cancel_text}"
+                        }
+                        if needs_hold {
+                            button {
+                                class: "flex-1 relative overflow-hidden rounded-lg font-semibold text-sm select-none",
+                                style: format!(
+                                    "padding: 8px 16px; color: {}; border: 1px solid {}; background: {}26; {}",
+                                    Colors::TEXT_PRIMARY,
+                                    accent,
+                                    accent,
+                                    if busy { "opacity: 0.5; cursor: not-allowed;" } else { "cursor: pointer;" }
+                                ),
+                                disabled: busy,
+                                onmousedown: move |_| start_hold(),
+                                onmouseup: move |_| release_hold(),
+                                onmouseleave: move |_| release_hold(),
+                                ontouchstart: move |_| start_hold(),
+                                ontouchend: move |_| release_hold(),
+                                span {
+                                    class: "absolute inset-y-0 left-0",
+                                    style: format!("width: {}%; background: {};", hold_progress(), accent),
+                                }
+                                span {
+                                    class: "relative",
+                                    if busy {
+                                        "处理中..."
+                                    } else {
+                                        {format!("按住{}", confirm_text)}
+                                    }
+                                }
+                            }
+                        } else {
+                            Button {
+                                variant: risk.button_variant(),
+                                size: ButtonSize::Small,
+                                class: Some("flex-1".to_string()),
+                                disabled: !submittable && !busy,
+                                loading: busy,
+                                onclick: move |_| {
+                                    if submittable {
+                                        on_confirm.call(());
+                                    }
+                                },
+                                "{confirm_text}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_phrase_must_match_exactly() {
+        assert!(confirm_phrase_matches("我的钱包", " 我的钱包 "));
+        assert!(!confirm_phrase_matches("Main", "main"));
+        assert!(!confirm_phrase_matches("", ""));
+    }
+
+    #[test]
+    fn submit_gate_follows_risk_level() {
+        assert!(can_submit(RiskLevel::Info, None, "", false));
+        assert!(can_submit(RiskLevel::Caution, None, "", false));
+        assert!(!can_submit(RiskLevel::Caution, None, "", true));
+        // 破坏性操作无确认文本时只能长按
+        assert!(!can_submit(RiskLevel::Destructive, None, "", false));
+        assert!(!can_submit(
+            RiskLevel::Destructive,
+            Some("钱包A"),
+            "钱包",
+            false
+        ));
+        assert!(can_submit(
+            RiskLevel::Destructive,
+            Some("钱包A"),
+            "钱包A",
+            false
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+//! Demo Mode Banner - 演示模式水印
+//! 演示模式下在页面顶部显示提示条，并在页面上叠加“DEMO”水印
+
+use crate::shared::demo;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 演示模式提示条与水印（未开启时不显示）
+#[component]
+pub fn DemoModeBanner() -> Element {
+    let app_state = use_context::<AppState>();
+    if !app_state.preferences.read().demo_mode {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "sticky top-0 z-40 flex flex-wrap items-center justify-center gap-3 px-4 py-2 text-sm",
+            style: format!("background: {}; color: #FFFFFF;", Colors::PAYMENT_WARNING),
+            span { class: "font-semibold", "🧪 演示模式 · Demo" }
+            span { "所有报价、余额与订单均为演示数据，不会提交真实交易" }
+            button {
+                class: "px-2 py-0.5 rounded text-xs font-medium",
+                style: "background: rgba(0, 0, 0, 0.25); color: #FFFFFF;",
+                onclick: move |_| demo::set_enabled(app_state, false),
+                "退出演示"
+            }
+        }
+        div {
+            class: "fixed inset-0 z-30 flex items-center justify-center pointer-events-none select-none",
+            aria_hidden: "true",
+            span {
+                class: "text-8xl font-black",
+                style: "color: rgba(245, 158, 11, 0.08); transform: rotate(-30deg);",
+                "DEMO"
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Device Step-Up - 新设备追加验证
+//! 未信任的设备发起高风险操作时，通过邮箱验证码或身份验证器确认后将本设备设为已信任
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::atoms::modal::Modal;
+use crate::components::molecules::ErrorMessage;
+use crate::services::device::{DeviceService, StepUpMethod};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 追加验证弹窗；验证通过后回调 `on_verified`，调用方重试原操作
+#[component]
+pub fn DeviceStepUpModal(
+    open: bool,
+    device_id: String,
+    on_verified: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut method = use_signal(|| StepUpMethod::Email);
+    let mut code = use_signal(String::new);
+    let mut code_sent = use_signal(|| false);
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    let send_device_id = device_id.clone();
+    let send_code = move |_| {
+        let device_id = send_device_id.clone();
+        busy.set(true);
+        error.set(None);
+        spawn(async move {
+            match DeviceService::new(app_state)
+                .request_step_up(&device_id, method())
+                .await
+            {
+                Ok(()) => code_sent.set(true),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    let verify_device_id = device_id.clone();
+    let verify = move |_| {
+        let device_id = verify_device_id.clone();
+        let input = code.read().trim().to_string();
+        if input.is_empty() {
+            error.set(Some("请输入验证码".to_string()));
+            return;
+        }
+        busy.set(true);
+        error.set(None);
+        spawn(async move {
+            match DeviceService::new(app_state)
+                .verify_step_up(&device_id, method(), &input)
+                .await
+            {
+                Ok(_) => {
+                    code.set(String::new());
+                    code_sent.set(false);
+                    AppState::show_success(app_state.toasts, "本设备已设为可信设备".to_string());
+                    on_verified.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    let needs_send = method() == StepUpMethod::Email && !code_sent();
+
+    rsx! {
+        Modal {
+            open: open,
+            onclose: move |_| on_cancel.call(()),
+            title: Some("验证此设备".to_string()),
+            div {
+                class: "space-y-4",
+                p {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "这是您首次在此浏览器进行高风险操作。完成一次验证后，本设备会被设为可信设备，之后的操作将自动签名确认。"
+                }
+                div {
+                    class: "flex gap-2",
+                    for m in StepUpMethod::ALL {
+                        button {
+                            key: "{m.label()}",
+                            class: "flex-1 px-3 py-2 rounded-lg text-sm",
+                            style: if method() == m {
+                                format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                            } else {
+                                format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY)
+                            },
+                            disabled: busy(),
+                            onclick: move |_| {
+                                method.set(m);
+                                code.set(String::new());
+                                error.set(None);
+                            },
+                            "{m.label()}"
+                        }
+                    }
+                }
+                if needs_send {
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Medium,
+                        loading: busy(),
+                        disabled: busy(),
+                        onclick: send_code,
+                        "发送验证码"
+                    }
+                } else {
+                    Input {
+                        input_type: InputType::Text,
+                        label: Some(if method() == StepUpMethod::Email {
+                            "邮箱验证码".to_string()
+                        } else {
+                            "验证器中的 6 位验证码".to_string()
+                        }),
+                        value: Some(code.read().clone()),
+                        onchange: move |e: FormEvent| code.set(e.value()),
+                    }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        size: ButtonSize::Medium,
+                        loading: busy(),
+                        disabled: busy(),
+                        onclick: verify,
+                        "验证并继续"
+                    }
+                }
+                ErrorMessage { message: error() }
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Dust Sweep Panel - 小额余额归集面板
+//! 列出低于阈值的代币，多选后批量报价并按顺序兑换为稳定币
+
+use crate::features::swap::dust::{
+    self, DustHolding, DustQuote, DustQuoteOutcome, SweepStepStatus, SWEEP_TARGETS,
+};
+use crate::features::wallet::unlock::ensure_wallet_unlocked;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+/// 小额余额归集面板
+#[component]
+pub fn DustSweepPanel(on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut threshold = use_signal(|| dust::load_threshold().to_string());
+    let mut target = use_signal(|| SWEEP_TARGETS[0].to_string());
+    let mut holdings = use_signal(Vec::<DustHolding>::new);
+    let mut selected = use_signal(HashSet::<String>::new);
+    let mut quotes = use_signal(|| Option::<Vec<DustQuote>>::None);
+    let mut progress = use_signal(Vec::<SweepStepStatus>::new);
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut scan_nonce = use_signal(|| 0u32);
+
+    let current_wallet = use_memo(move || app_state.wallet.read().get_selected_wallet().cloned());
+
+    // 扫描小额持仓（阈值、目标稳定币变化或手动刷新时）
+    use_effect(move || {
+        let _ = scan_nonce();
+        let target_val = target();
+        let Some(wallet) = current_wallet() else {
+            return;
+        };
+        let threshold_val = threshold.peek().parse::<f64>().unwrap_or(0.0);
+        if threshold_val <= 0.0 {
+            return;
+        }
+        dust::save_threshold(threshold_val);
+        spawn(async move {
+            busy.set(true);
+            error.set(None);
+            quotes.set(None);
+            progress.set(Vec::new());
+            match dust::scan_dust(app_state, &wallet, threshold_val, &target_val).await {
+                Ok(found) => {
+                    selected.set(found.iter().map(|h| h.symbol.clone()).collect());
+                    holdings.set(found);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            busy.set(false);
+        });
+    });
+
+    let fetch_quotes = move |_| {
+        let chosen: Vec<DustHolding> = holdings
+            .read()
+            .iter()
+            .filter(|h| selected.read().contains(&h.symbol))
+            .cloned()
+            .collect();
+        if chosen.is_empty() {
+            error.set(Some("请至少选择一个代币".to_string()));
+            return;
+        }
+        let target_val = target();
+        spawn(async move {
+            busy.set(true);
+            error.set(None);
+            let result = dust::quote_dust(app_state, chosen, &target_val).await;
+            progress.set(vec![SweepStepStatus::Pending; result.len()]);
+            quotes.set(Some(result));
+            busy.set(false);
+        });
+    };
+
+    let execute = move |_| {
+        let Some(wallet) = current_wallet() else {
+            error.set(Some("请先选择钱包".to_string()));
+            return;
+        };
+        if let Err(e) = ensure_wallet_unlocked(&app_state, &wallet.id) {
+            error.set(Some(e.to_string()));
+            return;
+        }
+        let Some(planned) = quotes() else {
+            return;
+        };
+        let target_val = target();
+        spawn(async move {
+            busy.set(true);
+            error.set(None);
+            let batch_id = dust::new_batch_id();
+            let result = dust::execute_sweep(
+                app_state,
+                &wallet,
+                &planned,
+                &target_val,
+                &batch_id,
+                |index, status| {
+                    if let Some(slot) = progress.write().get_mut(index) {
+                        *slot = status;
+                    }
+                },
+            )
+            .await;
+            match result {
+                Ok(()) => {
+                    let done = progress
+                        .read()
+                        .iter()
+                        .filter(|s| matches!(s, SweepStepStatus::Done { .. }))
+                        .count();
+                    AppState::show_success(
+                        app_state.toasts,
+                        format!("小额余额归集完成：{} 笔兑换已提交", done),
+                    );
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            busy.set(false);
+        });
+    };
+
+    let summary = quotes.read().as_ref().map(|q| dust::summarize(q));
+    let started = progress
+        .read()
+        .iter()
+        .any(|s| !matches!(s, SweepStepStatus::Pending));
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-4",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+
+            div {
+                class: "flex items-center justify-between",
+                h3 {
+                    class: "text-lg font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "🧹 转换小额余额"
+                }
+                button {
+                    class: "text-sm px-2",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    disabled: busy(),
+                    onclick: move |_| on_close.call(()),
+                    "✕"
+                }
+            }
+
+            // 阈值与目标稳定币
+            div {
+                class: "flex flex-wrap items-end gap-3 text-sm",
+                label {
+                    class: "flex flex-col gap-1",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "低于（USD）"
+                    input {
+                        class: "w-24 p-2 rounded",
+                        style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                        r#type: "number",
+                        min: "0.01",
+                        step: "0.5",
+                        value: "{threshold}",
+                        oninput: move |e| threshold.set(e.value()),
+                    }
+                }
+                label {
+                    class: "flex flex-col gap-1",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "兑换为"
+                    select {
+                        class: "p-2 rounded",
+                        style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                        disabled: busy(),
+                        onchange: move |e| target.set(e.value()),
+                        for symbol in SWEEP_TARGETS {
+                            option { value: symbol, selected: target() == symbol, "{symbol}" }
+                        }
+                    }
+                }
+                button {
+                    class: "px-3 py-2 rounded",
+                    style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                    disabled: busy(),
+                    onclick: move |_| {
+                        let next = scan_nonce() + 1;
+                        scan_nonce.set(next);
+                    },
+                    "重新扫描"
+                }
+            }
+
+            if let Some(err) = error() {
+                div {
+                    class: "text-sm p-2 rounded",
+                    style: "background: rgba(239, 68, 68, 0.1); color: rgba(239, 68, 68, 1);",
+                    "{err}"
+                }
+            }
+
+            if busy() && holdings.read().is_empty() {
+                div {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "正在扫描持仓..."
+                }
+            } else if holdings.read().is_empty() {
+                div {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "没有低于阈值的小额代币"
+                }
+            } else if let Some(planned) = quotes() {
+                // 报价与执行进度
+                div {
+                    class: "space-y-2",
+                    for (index, quote) in planned.iter().enumerate() {
+                        div {
+                            class: "flex items-center justify-between text-sm p-2 rounded",
+                            style: format!("background: {};", Colors::BG_PRIMARY),
+                            span {
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {format!("{} ≈ ${:.2}", quote.holding.symbol, quote.holding.usd_value)}
+                            }
+                            span {
+                                class: "text-xs text-right",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                {step_label(quote, progress.read().get(index), &target())}
+                            }
+                        }
+                    }
+                }
+                if let Some(summary) = summary {
+                    div {
+                        class: "text-sm space-y-1 pt-2",
+                        style: format!("border-top: 1px solid {}; color: {};", Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY),
+                        div { {format!("兑换 {} 个代币，跳过 {} 个", summary.convert_count, summary.skipped_count)} }
+                        div { {format!("预计 Gas：${:.2}", summary.gas_usd)} }
+                        div {
+                            class: "font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {format!("扣除费用后约得 {:.2} {}", summary.net_output, target())}
+                        }
+                    }
+                    if !started {
+                        button {
+                            class: "w-full py-2 rounded-lg font-medium",
+                            style: format!("background: {}; color: #FFFFFF;", Colors::TECH_PRIMARY),
+                            disabled: busy() || summary.convert_count == 0,
+                            onclick: execute,
+                            "开始兑换"
+                        }
+                    }
+                }
+            } else {
+                // 持仓多选
+                div {
+                    class: "space-y-2",
+                    for holding in holdings.read().iter().cloned() {
+                        label {
+                            class: "flex items-center justify-between text-sm p-2 rounded cursor-pointer",
+                            style: format!("background: {};", Colors::BG_PRIMARY),
+                            div {
+                                class: "flex items-center gap-2",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: selected.read().contains(&holding.symbol),
+                                    onchange: {
+                                        let symbol = holding.symbol.clone();
+                                        move |e: Event<FormData>| {
+                                            if e.checked() {
+                                                selected.write().insert(symbol.clone());
+                                            } else {
+                                                selected.write().remove(&symbol);
+                                            }
+                                        }
+                                    },
+                                }
+                                span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{holding.symbol}" }
+                            }
+                            span {
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                {format!("{} ≈ ${:.2}", holding.amount, holding.usd_value)}
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "w-full py-2 rounded-lg font-medium",
+                    style: format!("background: {}; color: #FFFFFF;", Colors::TECH_PRIMARY),
+                    disabled: busy() || selected.read().is_empty(),
+                    onclick: fetch_quotes,
+                    if busy() { "正在获取报价..." } else { "获取批量报价" }
+                }
+            }
+        }
+    }
+}
+
+/// 单个代币的报价/进度说明
+fn step_label(quote: &DustQuote, status: Option<&SweepStepStatus>, target: &str) -> String {
+    match (&quote.outcome, status) {
+        (DustQuoteOutcome::Skip { reason }, _) => format!("跳过：{}", reason),
+        (_, Some(SweepStepStatus::Running)) => "兑换中…".to_string(),
+        (_, Some(SweepStepStatus::Done { tx_hash })) => {
+            format!("✅ {}…", &tx_hash[..tx_hash.len().min(10)])
+        }
+        (_, Some(SweepStepStatus::Failed { error })) => format!("❌ {}", error),
+        (DustQuoteOutcome::Convert { output, gas_usd }, _) => {
+            format!("→ {:.2} {}（Gas ${:.2}）", output, target, gas_usd)
+        }
+    }
+}
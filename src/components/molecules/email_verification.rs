@@ -0,0 +1,87 @@
+//! Email Verification - 邮箱验证提示
+//! 未验证邮箱时：全局可关闭的横幅，以及法币功能入口处的拦截提示（钱包功能不受影响）
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::router::Route;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 邮箱未验证横幅（关闭后不再显示，直到下次注册/登录）
+#[component]
+pub fn EmailVerificationBanner() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let route = use_route::<Route>();
+
+    let visible = {
+        let user = app_state.user.read();
+        user.needs_email_verification() && !user.verification_banner_dismissed
+    };
+    // 验证页本身已说明，不重复显示
+    if !visible || matches!(route, Route::VerifyEmail {}) {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "px-4 py-2 flex items-center justify-center gap-3 text-sm",
+            style: format!(
+                "background: rgba(245, 158, 11, 0.12); border-bottom: 1px solid {}; color: {};",
+                Colors::PAYMENT_WARNING,
+                Colors::TEXT_PRIMARY
+            ),
+            span { "📧 您的邮箱尚未验证，验证后即可使用法币买卖功能" }
+            button {
+                class: "font-medium underline",
+                style: format!("color: {};", Colors::TECH_PRIMARY),
+                onclick: move |_| {
+                    navigator.push(Route::VerifyEmail {});
+                },
+                "去验证"
+            }
+            button {
+                class: "ml-2",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                aria_label: "关闭",
+                onclick: move |_| {
+                    let mut user = app_state.user.write();
+                    user.verification_banner_dismissed = true;
+                    let _ = user.save();
+                },
+                "✕"
+            }
+        }
+    }
+}
+
+/// 法币功能拦截提示（邮箱未验证时替代买入/提现表单）
+#[component]
+pub fn EmailVerificationRequired() -> Element {
+    let navigator = use_navigator();
+
+    rsx! {
+        div {
+            class: "p-6 rounded-lg space-y-3 text-center",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::PAYMENT_WARNING),
+            div {
+                class: "text-lg font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "📧 请先验证邮箱"
+            }
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "为保障资金安全，法币买入和提现需要先完成邮箱验证。创建钱包、收款和链上兑换不受影响。"
+            }
+            Button {
+                variant: ButtonVariant::Primary,
+                size: ButtonSize::Medium,
+                onclick: move |_| {
+                    navigator.push(Route::VerifyEmail {});
+                },
+                "前往验证邮箱"
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Empty State - 空状态组件
+//! 列表为空时给出说明与下一步操作；与"加载失败"区分，后者保留重试入口（服务不可用时附带状态页链接）
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::router::Route;
+use crate::shared::design_tokens::Colors;
+use crate::shared::ui_error::{classify, ErrorClass};
+use dioxus::prelude::*;
+
+/// 空状态组件
+///
+/// # 示例
+///
+/// ```rust
+/// rsx! {
+///     EmptyState {
+///         icon: "🔄".to_string(),
+///         title: t("empty.history.title"),
+///         description: t("empty.history.description"),
+///         cta_label: Some(t("empty.history.cta")),
+///         on_cta: move |_| navigator.push(Route::Swap {}),
+///     }
+/// }
+/// ```
+#[component]
+pub fn EmptyState(
+    /// 插画（提供时替代图标）
+    #[props(default)]
+    illustration: Option<Element>,
+    /// 图标
+    #[props(default = "📭".to_string())]
+    icon: String,
+    /// 标题
+    title: String,
+    /// 说明
+    #[props(default)]
+    description: Option<String>,
+    /// 主操作文本
+    #[props(default)]
+    cta_label: Option<String>,
+    /// 主操作回调
+    #[props(default)]
+    on_cta: Option<EventHandler<()>>,
+    /// 次要操作文本
+    #[props(default)]
+    secondary_label: Option<String>,
+    /// 次要操作回调
+    #[props(default)]
+    on_secondary: Option<EventHandler<()>>,
+    /// 自定义类名
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: format!("text-center py-10 px-4 {}", class.unwrap_or_default()),
+            if let Some(illustration) = illustration {
+                div { class: "mb-4 flex justify-center", {illustration} }
+            } else {
+                div {
+                    class: "mb-4",
+                    style: format!("color: {}; font-size: 48px;", Colors::TEXT_SECONDARY),
+                    "{icon}"
+                }
+            }
+            div {
+                class: "text-base font-semibold mb-2",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "{title}"
+            }
+            if let Some(description) = description {
+                div {
+                    class: "text-sm mb-5 max-w-md mx-auto",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "{description}"
+                }
+            }
+            if cta_label.is_some() || secondary_label.is_some() {
+                div {
+                    class: "flex flex-wrap gap-3 justify-center",
+                    if let Some(label) = cta_label {
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Medium,
+                            onclick: move |_| {
+                                if let Some(handler) = on_cta {
+                                    handler.call(());
+                                }
+                            },
+                            "{label}"
+                        }
+                    }
+                    if let Some(label) = secondary_label {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Medium,
+                            onclick: move |_| {
+                                if let Some(handler) = on_secondary {
+                                    handler.call(());
+                                }
+                            },
+                            "{label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 加载失败状态（区别于空状态，保留重试入口）
+#[component]
+pub fn LoadFailedState(
+    /// 标题
+    title: String,
+    /// 错误详情
+    #[props(default)]
+    message: Option<String>,
+    /// 重试按钮文本
+    retry_label: String,
+    /// 重试回调
+    on_retry: EventHandler<()>,
+) -> Element {
+    rsx! {
+        div {
+            class: "text-center py-10 px-4 rounded-lg",
+            style: "background: rgba(239, 68, 68, 0.06); border: 1px solid rgba(239, 68, 68, 0.3);",
+            div { class: "mb-3 text-4xl", "⚠️" }
+            div {
+                class: "text-base font-semibold mb-2",
+                style: format!("color: {};", Colors::PAYMENT_ERROR),
+                "{title}"
+            }
+            if let Some(message) = message.clone() {
+                div {
+                    class: "text-sm mb-5",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "{message}"
+                }
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: move |_| on_retry.call(()),
+                {format!("🔄 {}", retry_label)}
+            }
+            if message.as_deref().map(classify) == Some(ErrorClass::ServiceUnavailable) {
+                div {
+                    class: "mt-3",
+                    Link {
+                        to: Route::Status {},
+                        class: "text-sm underline",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "查看服务状态"
+                    }
+                }
+            }
+        }
+    }
+}
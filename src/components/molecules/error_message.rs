@@ -1,11 +1,18 @@
 //! Error Message - 错误消息显示组件
 //! 企业级用户友好的错误消息显示，隐藏技术细节
 
+use crate::router::Route;
 use crate::shared::design_tokens::Colors;
+use crate::shared::ui_error::{classify, maintenance_notice, ErrorClass};
 use dioxus::prelude::*;
 
 /// 将技术错误消息转换为用户友好的消息
 fn user_friendly_error(error: &str) -> String {
+    // 紧急开关的维护提示原样显示
+    if let Some(notice) = maintenance_notice(error) {
+        return notice.to_string();
+    }
+
     let error_lower = error.to_lowercase();
 
     // 网络相关错误
@@ -88,6 +95,10 @@ pub fn ErrorMessage(
 ) -> Element {
     if let Some(error) = message {
         let friendly_msg = user_friendly_error(&error);
+        let service_unavailable = matches!(
+            classify(&error),
+            ErrorClass::ServiceUnavailable | ErrorClass::Maintenance
+        );
         let display_msg = if show_technical && error != friendly_msg {
             format!("{}\n\n技术详情: {}", friendly_msg, error)
         } else {
@@ -119,6 +130,13 @@ pub fn ErrorMessage(
                             class: "text-sm whitespace-pre-line",
                             {display_msg}
                         }
+                        if service_unavailable {
+                            Link {
+                                to: Route::Status {},
+                                class: "inline-block mt-2 text-sm underline",
+                                "查看服务状态"
+                            }
+                        }
                     }
                 }
             }
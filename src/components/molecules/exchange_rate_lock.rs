@@ -1,60 +1,45 @@
 //! Exchange Rate Lock - 汇率锁定倒计时组件
-//! 显示汇率锁定剩余时间（30秒有效期）
+//! 显示汇率锁定剩余时间（30秒有效期），按单调时钟倒计时，修改系统时间不影响
 
 use crate::shared::design_tokens::Colors;
+use crate::shared::time::Countdown;
 use dioxus::prelude::*;
 
 /// 汇率锁定倒计时组件
 #[component]
 pub fn ExchangeRateLockCountdown(
-    /// 锁定开始时间（Unix timestamp，秒）
-    lock_start_time: u64,
-    /// 锁定有效期（秒）
-    lock_duration: u64,
+    /// 报价锁定时开始的倒计时
+    countdown: Countdown,
     /// 过期回调
     on_expired: Option<EventHandler<()>>,
 ) -> Element {
-    let current_time = use_signal(|| js_sys::Date::now() as u64 / 1000);
-    let expired = use_signal(|| false);
+    let mut current = use_signal(|| countdown);
+    let mut tick = use_signal(|| 0u64);
+    let mut expired = use_signal(|| false);
 
-    // 每秒更新一次倒计时
-    use_effect({
-        let current_time_sig = current_time;
-        let expired_sig = expired;
-        let lock_start = lock_start_time;
-        let lock_dur = lock_duration;
-        let expired_handler = on_expired;
-
-        move || {
-            let _interval_id = gloo_timers::callback::Interval::new(1000, {
-                let mut current_time_sig = current_time_sig;
-                let mut expired_sig = expired_sig;
-                let lock_start = lock_start;
-                let lock_dur = lock_dur;
-                let expired_handler = expired_handler;
+    // 新报价会传入新的倒计时
+    use_effect(use_reactive!(|countdown| {
+        current.set(countdown);
+        expired.set(false);
+    }));
 
-                move || {
-                    let now = js_sys::Date::now() as u64 / 1000;
-                    current_time_sig.set(now);
-
-                    let elapsed = now.saturating_sub(lock_start);
-                    if elapsed >= lock_dur && !*expired_sig.read() {
-                        expired_sig.set(true);
-                        if let Some(handler) = expired_handler.as_ref() {
-                            handler.call(());
-                        }
-                    }
+    // 每秒更新一次倒计时
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(1000).await;
+            tick += 1;
+            if current.peek().remaining() == 0 && !*expired.peek() {
+                expired.set(true);
+                if let Some(handler) = on_expired.as_ref() {
+                    handler.call(());
                 }
-            });
-
-            // 注意：Dioxus 0.7 的 use_effect 不直接支持清理函数
-            // 定时器会在组件卸载时自动停止（因为闭包被丢弃）
+            }
         }
     });
 
-    let now = *current_time.read();
-    let elapsed = now.saturating_sub(lock_start_time);
-    let remaining = lock_duration.saturating_sub(elapsed);
+    let _ = tick.read();
+    let lock_duration = countdown.total();
+    let remaining = countdown.remaining();
 
     if *expired.read() || remaining == 0 {
         return rsx! {
@@ -75,7 +60,7 @@ pub fn ExchangeRateLockCountdown(
 
     let minutes = remaining / 60;
     let seconds = remaining % 60;
-    let progress = (remaining as f64 / lock_duration as f64) * 100.0;
+    let progress = (remaining as f64 / lock_duration.max(1) as f64) * 100.0;
 
     // 根据剩余时间显示不同颜色
     let bg_color = if remaining <= 10 {
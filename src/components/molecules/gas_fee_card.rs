@@ -1,17 +1,175 @@
 //! Gas Fee Card - Gas费显示卡片组件
-//! 显示Gas费估算信息，支持加载状态
+//! 显示Gas费估算信息，支持加载状态、慢/标准/快速档位选择、法币换算与手动费用；
+//! L2 链的网络费包含 L1 数据费，可展开查看执行费与数据费构成
 
-use crate::services::gas::{gas_fee_eth_from_max_fee_per_gas_gwei, GasEstimate};
+use crate::features::market::hooks::use_prices;
+use crate::services::address_detector::ChainType;
+use crate::services::gas::{
+    fee_breakdown, format_confirmation_time, pick_estimate, validate_manual_fee, GasEstimate,
+    GasEstimateResponse, GasSpeed, L1DataFee, ManualGasFee,
+};
+use crate::services::price::PriceService;
 use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
 use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 需要换算法币的 EVM 原生币
+const EVM_NATIVE_SYMBOLS: [&str; 3] = ["ETH", "BNB", "MATIC"];
+
+/// 法币换算所需的价格数据
+#[derive(Debug, Clone, PartialEq)]
+struct FiatPricing {
+    /// 原生币 USD 价格
+    native_usd: HashMap<String, f64>,
+    /// 1 USD 折合显示货币
+    rate: f64,
+    currency_symbol: &'static str,
+    /// 价格服务不可用，使用的是最近一次价格
+    stale: bool,
+}
+
+impl FiatPricing {
+    fn convert(&self, symbol: &str, native_amount: f64) -> Option<String> {
+        let usd = self.native_usd.get(symbol)?;
+        Some(format!(
+            "≈ {}{:.2}{}",
+            self.currency_symbol,
+            native_amount * usd * self.rate,
+            if self.stale { "（旧价格）" } else { "" }
+        ))
+    }
+}
 
 /// Gas费显示卡片组件（含平台服务费）
+///
+/// 传入 `gas_options` 与 `selected_speed` 时显示档位选择；
+/// 传入 `manual_fee` 时提供“高级”手动费用输入，签名时优先使用手动费用。
 #[component]
 pub fn GasFeeCard(
     gas_estimate: Option<GasEstimate>,
     platform_fee: Option<f64>,
+    /// 平台服务费暂时无法预估，由服务端在执行时计算
+    #[props(default)]
+    platform_fee_at_execution: bool,
     is_loading: bool,
+    /// 链名（用于原生币符号与法币换算），默认 Ethereum
+    #[props(default)]
+    chain: Option<String>,
+    /// 全部档位估算
+    #[props(default)]
+    gas_options: Option<GasEstimateResponse>,
+    /// 当前选择的档位（由签名流程读取）
+    #[props(default)]
+    selected_speed: Option<Signal<GasSpeed>>,
+    /// 手动费用（None 表示使用档位）
+    #[props(default)]
+    manual_fee: Option<Signal<Option<ManualGasFee>>>,
+    #[props(default = 21_000)] gas_limit: u64,
+    /// L2 链的 L1 数据费（按同一笔交易估算）
+    #[props(default)]
+    data_fee: Option<L1DataFee>,
+    /// L2 链的 L1 数据费查询失败
+    #[props(default)]
+    data_fee_unavailable: bool,
 ) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut show_advanced = use_signal(|| false);
+    let mut show_breakdown = use_signal(|| false);
+    let mut max_fee_input = use_signal(String::new);
+    let mut priority_fee_input = use_signal(String::new);
+
+    // 原生币价格来自共享行情（后台统一轮询），显示货币变化时刷新汇率
+    let native_prices = use_prices(use_memo(|| {
+        EVM_NATIVE_SYMBOLS.iter().map(|s| s.to_string()).collect()
+    }));
+    let mut fx = use_signal(|| Option::<(f64, &'static str)>::None);
+    use_effect(move || {
+        let currency = app_state.preferences.read().currency.clone();
+        spawn(async move {
+            // 汇率获取失败时退回 USD 显示
+            let quote = match PriceService::new(app_state)
+                .get_fiat_rate(currency.code())
+                .await
+            {
+                Ok(rate) => (rate, currency.symbol()),
+                Err(e) => {
+                    log::warn!("获取 {} 汇率失败: {:?}", currency.code(), e);
+                    (1.0, "$")
+                }
+            };
+            fx.set(Some(quote));
+        });
+    });
+    let pricing = move || {
+        let prices = native_prices();
+        let (rate, currency_symbol) = fx()?;
+        (!prices.is_empty()).then(|| FiatPricing {
+            stale: prices.values().any(|p| p.is_stale()),
+            native_usd: prices.into_iter().map(|(k, v)| (k, v.usd)).collect(),
+            rate,
+            currency_symbol,
+        })
+    };
+
+    let native_symbol = chain
+        .as_deref()
+        .and_then(ChainType::from_str)
+        .map(|c| c.native_token_symbol())
+        .unwrap_or("ETH");
+    let speed = selected_speed.map(|s| s());
+    let manual = manual_fee.and_then(|m| m());
+    let fast_estimate = gas_options
+        .as_ref()
+        .map(|all| all.fast.clone())
+        .or_else(|| gas_estimate.clone());
+
+    // 当前生效的估算：档位选择优先于单一估算
+    let active = match (&gas_options, speed) {
+        (Some(all), Some(speed)) => Some(pick_estimate(all, speed).clone()),
+        _ => gas_estimate.clone(),
+    };
+    let effective_gwei = manual
+        .map(|m| m.max_fee_per_gas_gwei)
+        .or_else(|| active.as_ref().map(|g| g.max_fee_per_gas_gwei));
+
+    // 网络费（L2 链含 L1 数据费）
+    let network_fee = effective_gwei.map(|gwei| fee_breakdown(gwei, gas_limit, data_fee));
+
+    let manual_check = if show_advanced() && !max_fee_input().is_empty() {
+        parse_manual_fee(&max_fee_input(), &priority_fee_input()).and_then(|fee| {
+            match &fast_estimate {
+                Some(fast) => validate_manual_fee(&fee, fast),
+                None => Ok(None),
+            }
+        })
+    } else {
+        Ok(None)
+    };
+
+    // 输入变化后同步手动费用（仅在校验通过时生效）
+    let apply_manual = {
+        let fast_estimate = fast_estimate.clone();
+        move |max_fee: String, priority: String| {
+            let Some(mut manual_sig) = manual_fee else {
+                return;
+            };
+            let parsed =
+                parse_manual_fee(&max_fee, &priority).and_then(|fee| match &fast_estimate {
+                    Some(fast) => validate_manual_fee(&fee, fast).map(|_| fee),
+                    None => Ok(fee),
+                });
+            manual_sig.set(parsed.ok());
+        }
+    };
+
+    let pricing_val = pricing();
+    let fiat = move |native_amount: f64| -> Option<String> {
+        pricing_val
+            .as_ref()
+            .and_then(|p| p.convert(native_symbol, native_amount))
+    };
+
     rsx! {
         div {
             class: "mb-6",
@@ -30,10 +188,60 @@ pub fn GasFeeCard(
                         "正在获取最优Gas费..."
                     }
                 }
-            } else if let Some(gas) = gas_estimate {
+            } else if let (Some(gas), Some(network_fee)) = (active.clone(), network_fee) {
                 div {
                     class: "p-4 rounded-lg",
                     style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    // 档位选择
+                    if let (Some(all), Some(mut speed_sig)) = (gas_options.clone(), selected_speed) {
+                        div {
+                            class: "grid grid-cols-3 gap-2 mb-3",
+                            for tier in GasSpeed::ALL {
+                                {
+                                    let estimate = pick_estimate(&all, tier).clone();
+                                    let fee = fee_breakdown(
+                                        estimate.max_fee_per_gas_gwei,
+                                        gas_limit,
+                                        data_fee,
+                                    )
+                                    .total;
+                                    let selected = manual.is_none() && speed == Some(tier);
+                                    rsx! {
+                                        button {
+                                            class: "p-2 rounded-lg border text-left transition-all",
+                                            style: format!(
+                                                "background: {}; border-color: {};",
+                                                if selected { "rgba(99, 102, 241, 0.15)" } else { Colors::BG_PRIMARY },
+                                                if selected { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY }
+                                            ),
+                                            onclick: move |_| {
+                                                speed_sig.set(tier);
+                                                show_advanced.set(false);
+                                                if let Some(mut manual_sig) = manual_fee {
+                                                    manual_sig.set(None);
+                                                }
+                                            },
+                                            div {
+                                                class: "text-sm font-medium",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                "{tier.label()}"
+                                            }
+                                            div {
+                                                class: "text-xs",
+                                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                                {format_confirmation_time(estimate.estimated_time_seconds)}
+                                            }
+                                            div {
+                                                class: "text-xs mt-1",
+                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                {fiat(fee).unwrap_or_else(|| format!("{:.6} {}", fee, native_symbol))}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                     div {
                         class: "space-y-2",
                         // Gas费（区块链网络费用）
@@ -43,17 +251,56 @@ pub fn GasFeeCard(
                                 class: "text-sm flex items-center gap-1",
                                 style: format!("color: {};", Colors::TEXT_SECONDARY),
                                 span { "⛽" }
-                                span { "Gas费" }
+                                span { if manual.is_some() { "Gas费（手动）" } else { "Gas费" } }
                             }
                             span {
-                                class: "text-sm font-semibold",
+                                class: "text-sm font-semibold text-right",
                                 style: format!("color: {};", Colors::TEXT_PRIMARY),
                                 {
-                                    let gas_fee = gas_fee_eth_from_max_fee_per_gas_gwei(
-                                        gas.max_fee_per_gas_gwei,
-                                        21_000,
-                                    );
-                                    format!("{:.8} ETH", gas_fee)
+                                    match fiat(network_fee.total) {
+                                        Some(converted) => format!("{:.8} {} {}", network_fee.total, native_symbol, converted),
+                                        None => format!("{:.8} {}", network_fee.total, native_symbol),
+                                    }
+                                }
+                            }
+                        }
+                        // L2：执行费 + L1 数据费构成
+                        if data_fee.is_some() || data_fee_unavailable {
+                            div {
+                                button {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                                    onclick: move |_| show_breakdown.set(!show_breakdown()),
+                                    if show_breakdown() { "▾ 费用构成" } else { "▸ 费用构成" }
+                                }
+                                if show_breakdown() {
+                                    div {
+                                        class: "mt-1 pl-3 space-y-1 text-xs",
+                                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                        div {
+                                            class: "flex justify-between",
+                                            span { "L2 执行费" }
+                                            span { {format!("{:.8} {}", network_fee.execution, native_symbol)} }
+                                        }
+                                        div {
+                                            class: "flex justify-between",
+                                            span { "L1 数据费" }
+                                            span {
+                                                if data_fee_unavailable {
+                                                    "暂时无法获取"
+                                                } else {
+                                                    {format!("{:.8} {}", network_fee.data, native_symbol)}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                if data_fee_unavailable {
+                                    div {
+                                        class: "text-xs mt-1",
+                                        style: "color: rgba(245, 158, 11, 1);",
+                                        "⚠️ L1 数据费暂时无法获取，实际网络费可能更高"
+                                    }
                                 }
                             }
                         }
@@ -69,7 +316,21 @@ pub fn GasFeeCard(
                                 span {
                                     class: "text-sm font-semibold",
                                     style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    {format!("{:.6} ETH", fee)}
+                                    {format!("{:.6} {}", fee, native_symbol)}
+                                }
+                            }
+                        } else if platform_fee_at_execution {
+                            div {
+                                class: "flex justify-between items-center",
+                                span {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    "平台服务费"
+                                }
+                                span {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "执行时确定"
                                 }
                             }
                         }
@@ -84,15 +345,14 @@ pub fn GasFeeCard(
                                 span { "总计" }
                             }
                             span {
-                                class: "text-sm font-bold",
+                                class: "text-sm font-bold text-right",
                                 style: format!("color: {};", Colors::TECH_PRIMARY),
                                 {
-                                    let gas_fee = gas_fee_eth_from_max_fee_per_gas_gwei(
-                                        gas.max_fee_per_gas_gwei,
-                                        21_000,
-                                    );
-                                    let total = gas_fee + platform_fee.unwrap_or(0.0);
-                                    format!("{:.8} ETH", total)
+                                    let total = network_fee.total + platform_fee.unwrap_or(0.0);
+                                    match fiat(total) {
+                                        Some(converted) => format!("{:.8} {} {}", total, native_symbol, converted),
+                                        None => format!("{:.8} {}", total, native_symbol),
+                                    }
                                 }
                             }
                         }
@@ -107,7 +367,94 @@ pub fn GasFeeCard(
                             span {
                                 class: "text-sm",
                                 style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                {format!("约 {:.0} 秒", gas.estimated_time_seconds)}
+                                {format_confirmation_time(gas.estimated_time_seconds)}
+                            }
+                        }
+                        // 高级：手动费用
+                        if manual_fee.is_some() {
+                            div {
+                                class: "pt-2 border-t",
+                                style: format!("border-color: {};", Colors::BORDER_PRIMARY),
+                                button {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                                    onclick: {
+                                        let gas = gas.clone();
+                                        move |_| {
+                                            let open = !show_advanced();
+                                            show_advanced.set(open);
+                                            if open {
+                                                max_fee_input.set(format!("{:.2}", gas.max_fee_per_gas_gwei));
+                                                priority_fee_input.set(format!("{:.2}", gas.max_priority_fee_gwei));
+                                            } else if let Some(mut manual_sig) = manual_fee {
+                                                manual_sig.set(None);
+                                            }
+                                        }
+                                    },
+                                    if show_advanced() { "▾ 高级（手动设置费用）" } else { "▸ 高级（手动设置费用）" }
+                                }
+                                if show_advanced() {
+                                    div {
+                                        class: "grid grid-cols-2 gap-2 mt-2",
+                                        label {
+                                            class: "flex flex-col gap-1 text-xs",
+                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                            "最大费用（gwei）"
+                                            input {
+                                                class: "p-2 rounded text-sm",
+                                                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                                r#type: "number",
+                                                min: "0",
+                                                step: "0.1",
+                                                value: "{max_fee_input}",
+                                                oninput: {
+                                                    let apply_manual = apply_manual.clone();
+                                                    move |e: Event<FormData>| {
+                                                        max_fee_input.set(e.value());
+                                                        apply_manual(e.value(), priority_fee_input());
+                                                    }
+                                                },
+                                            }
+                                        }
+                                        label {
+                                            class: "flex flex-col gap-1 text-xs",
+                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                            "优先费（gwei）"
+                                            input {
+                                                class: "p-2 rounded text-sm",
+                                                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                                r#type: "number",
+                                                min: "0",
+                                                step: "0.1",
+                                                value: "{priority_fee_input}",
+                                                oninput: {
+                                                    let apply_manual = apply_manual.clone();
+                                                    move |e: Event<FormData>| {
+                                                        priority_fee_input.set(e.value());
+                                                        apply_manual(max_fee_input(), e.value());
+                                                    }
+                                                },
+                                            }
+                                        }
+                                    }
+                                    match manual_check {
+                                        Err(err) => rsx! {
+                                            div {
+                                                class: "text-xs mt-2",
+                                                style: "color: rgba(239, 68, 68, 1);",
+                                                "❌ {err}"
+                                            }
+                                        },
+                                        Ok(Some(warning)) => rsx! {
+                                            div {
+                                                class: "text-xs mt-2",
+                                                style: "color: rgba(245, 158, 11, 1);",
+                                                "⚠️ {warning}"
+                                            }
+                                        },
+                                        Ok(None) => rsx! {},
+                                    }
+                                }
                             }
                         }
                         // 透明度说明
@@ -144,3 +491,23 @@ pub fn GasFeeCard(
         }
     }
 }
+
+/// 解析手动费用输入（优先费留空视为 0）
+fn parse_manual_fee(max_fee: &str, priority: &str) -> Result<ManualGasFee, String> {
+    let max_fee_per_gas_gwei = max_fee
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| "请输入有效的最大费用".to_string())?;
+    let max_priority_fee_gwei = if priority.trim().is_empty() {
+        0.0
+    } else {
+        priority
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| "请输入有效的优先费".to_string())?
+    };
+    Ok(ManualGasFee {
+        max_fee_per_gas_gwei,
+        max_priority_fee_gwei,
+    })
+}
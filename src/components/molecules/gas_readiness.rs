@@ -0,0 +1,93 @@
+//! Gas Readiness Banner - 原生币手续费不足提示条
+//! 不阻止操作，只说明原因并给出补充原生币的途径（购买、跨链、稳定币代付）
+
+use crate::features::gas::readiness::{BuyPrefill, GasReadiness, RELAYER_FLAG};
+use crate::router::Route;
+use crate::shared::design_tokens::Colors;
+use crate::shared::feature_flags::use_feature;
+use dioxus::prelude::*;
+
+/// 手续费不足提示条（余额足够或尚未检查完成时不显示）
+#[component]
+pub fn GasReadinessBanner(
+    readiness: Signal<Option<GasReadiness>>,
+    /// 点击“购买”：携带预填参数（代币、建议金额）
+    on_buy: EventHandler<BuyPrefill>,
+) -> Element {
+    let navigator = use_navigator();
+    let relayer_enabled = use_feature(RELAYER_FLAG);
+    // 关闭后只对当前链生效，切换链后重新提示
+    let mut dismissed_chain = use_signal(|| None::<String>);
+
+    let Some(status) = readiness.read().clone() else {
+        return rsx! {};
+    };
+    if status.is_ready() || dismissed_chain.read().as_deref() == Some(status.chain.as_str()) {
+        return rsx! {};
+    }
+
+    let symbol = status.symbol.clone();
+    let buy_usd = status.suggested_buy_usd();
+    let buy_symbol = symbol.clone();
+    let chain = status.chain.clone();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg text-sm space-y-3",
+            style: format!("background: rgba(245, 158, 11, 0.1); border: 1px solid {};", Colors::PAYMENT_WARNING),
+            div {
+                class: "flex items-start justify-between gap-2",
+                div {
+                    div {
+                        class: "font-semibold",
+                        style: format!("color: {};", Colors::PAYMENT_WARNING),
+                        {format!("⛽ {} 余额不足以支付网络手续费", symbol)}
+                    }
+                    p {
+                        class: "mt-1",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {format!(
+                            "按当前 gas 价格，一笔交易约需 {:.6} {}，可用余额为 {:.6} {}。代币余额再多，也需要 {} 支付手续费，否则交易会在广播时失败。",
+                            status.required, symbol, status.balance, symbol, symbol
+                        )}
+                    }
+                }
+                button {
+                    class: "text-xs px-2 py-1 rounded",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    onclick: move |_| dismissed_chain.set(Some(chain.clone())),
+                    "✕"
+                }
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                button {
+                    class: "px-3 py-1.5 rounded-lg font-medium",
+                    style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                    onclick: move |_| {
+                        on_buy.call(BuyPrefill {
+                            token: buy_symbol.clone(),
+                            amount_usd: format!("{:.0}", buy_usd),
+                        });
+                    },
+                    {format!("💳 购买 ${:.0} 的 {}", buy_usd, symbol)}
+                }
+                button {
+                    class: "px-3 py-1.5 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                    onclick: move |_| {
+                        navigator.push(Route::Bridge {});
+                    },
+                    {format!("🌉 从其他链跨链 {}", symbol)}
+                }
+            }
+            if relayer_enabled {
+                p {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    "💡 已开启稳定币代付手续费：确认交易时可选择用 USDT/USDC 支付网络费。"
+                }
+            }
+        }
+    }
+}
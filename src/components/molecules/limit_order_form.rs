@@ -2,8 +2,11 @@
 //! 企业级限价单功能，支持设置目标价格
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::input::FieldError;
+use crate::features::fees::estimate::PlatformFeeEstimate;
 use crate::services::token::TokenInfo;
 use crate::shared::design_tokens::Colors;
+use crate::shared::validation::{all_valid, ValidatedField};
 use dioxus::prelude::*;
 
 /// 限价单类型
@@ -33,9 +36,9 @@ pub fn LimitOrderForm(
     /// 代币选择（To）
     to_token: Signal<Option<TokenInfo>>,
     /// 数量
-    amount: Signal<String>,
+    amount: ValidatedField,
     /// 限价（目标价格）
-    limit_price: Signal<String>,
+    limit_price: ValidatedField,
     /// 有效期（天数）
     expiry_days: Signal<u32>,
     /// 错误消息
@@ -45,6 +48,9 @@ pub fn LimitOrderForm(
     /// 提交回调
     #[allow(clippy::type_complexity)]
     on_submit: Option<EventHandler<(LimitOrderType, String, String, String, u32)>>,
+    /// 平台服务费估算（仅展示，不影响提交）
+    #[props(default)]
+    platform_fee: Option<PlatformFeeEstimate>,
 ) -> Element {
     let order_type_val = *order_type.read();
 
@@ -154,11 +160,13 @@ pub fn LimitOrderForm(
                     style: format!("background: {}; border: 1px solid {}; color: {};",
                         Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
                     r#type: "number",
-                    value: "{amount.read()}",
+                    value: "{amount.value.read()}",
                     oninput: move |e| amount.set(e.value()),
+                    onblur: move |_| amount.touch(),
                     placeholder: "0.0",
                     step: "0.000001"
                 }
+                FieldError { error: amount.visible_error() }
             }
 
             // 限价输入
@@ -175,8 +183,9 @@ pub fn LimitOrderForm(
                         style: format!("background: {}; border: 1px solid {}; color: {};",
                             Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
                         r#type: "number",
-                        value: "{limit_price.read()}",
+                        value: "{limit_price.value.read()}",
                         oninput: move |e| limit_price.set(e.value()),
+                        onblur: move |_| limit_price.touch(),
                         placeholder: "0.0",
                         step: "0.000001"
                     }
@@ -188,6 +197,7 @@ pub fn LimitOrderForm(
                         }
                     }
                 }
+                FieldError { error: limit_price.visible_error() }
                 div {
                     class: "text-xs mt-1",
                     style: format!("color: {};", Colors::TEXT_SECONDARY),
@@ -245,7 +255,7 @@ pub fn LimitOrderForm(
             }
 
             // 订单摘要
-            if !amount.read().is_empty() && !limit_price.read().is_empty() {
+            if all_valid(&[amount, limit_price]) {
                 div {
                     class: "p-4 rounded-lg mt-4",
                     style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
@@ -264,18 +274,25 @@ pub fn LimitOrderForm(
                         div {
                             class: "flex justify-between",
                             span { style: format!("color: {};", Colors::TEXT_SECONDARY), "数量" }
-                            span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{amount.read()}" }
+                            span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{amount.value.read()}" }
                         }
                         div {
                             class: "flex justify-between",
                             span { style: format!("color: {};", Colors::TEXT_SECONDARY), "限价" }
-                            span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{limit_price.read()}" }
+                            span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{limit_price.value.read()}" }
                         }
                         div {
                             class: "flex justify-between",
                             span { style: format!("color: {};", Colors::TEXT_SECONDARY), "有效期" }
                             span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{expiry_days.read()} 天" }
                         }
+                        if let Some(fee) = platform_fee {
+                            div {
+                                class: "flex justify-between",
+                                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "平台服务费" }
+                                span { style: format!("color: {};", Colors::TEXT_PRIMARY), {fee.label()} }
+                            }
+                        }
                     }
                 }
             }
@@ -300,14 +317,13 @@ pub fn LimitOrderForm(
                 onclick: move |_| {
                     if let Some(handler) = on_submit {
                         let order_type_val = *order_type.read();
-                        let amount_val = amount.read().clone();
-                        let limit_price_val = limit_price.read().clone();
+                        let amount_val = amount.get();
+                        let limit_price_val = limit_price.get();
                         let expiry_days_val = *expiry_days.read();
                         handler.call((order_type_val, amount_val, limit_price_val, "".to_string(), expiry_days_val));
                     }
                 },
-                disabled: amount.read().is_empty()
-                    || limit_price.read().is_empty()
+                disabled: !all_valid(&[amount, limit_price])
                     || from_token.read().is_none()
                     || to_token.read().is_none()
                     || *loading.read(),
@@ -0,0 +1,157 @@
+//! List Controls - 列表控制组件
+//! 状态筛选标签、排序按钮、分页与每页数量选择、筛选结果为空时的提示
+
+use crate::shared::design_tokens::Colors;
+use crate::shared::list_query::{ListQueryState, SortField, PAGE_SIZE_OPTIONS};
+use dioxus::prelude::*;
+
+fn chip_style(active: bool) -> String {
+    format!(
+        "background: {}; color: {}; border: 1px solid {};",
+        if active {
+            Colors::TECH_PRIMARY
+        } else {
+            Colors::BG_PRIMARY
+        },
+        if active {
+            "white"
+        } else {
+            Colors::TEXT_SECONDARY
+        },
+        Colors::BORDER_PRIMARY
+    )
+}
+
+/// 状态筛选标签（options 为 (状态值, 显示名称)，首个标签为“全部”）
+#[component]
+pub fn StatusFilterChips(
+    state: Signal<ListQueryState>,
+    options: Vec<(&'static str, &'static str)>,
+) -> Element {
+    let current = state.read().status.clone();
+
+    rsx! {
+        div {
+            class: "flex gap-2 flex-wrap",
+            button {
+                class: "px-3 py-1 text-sm rounded-full transition-all",
+                style: chip_style(current.is_none()),
+                onclick: move |_| state.write().set_status(None),
+                "全部"
+            }
+            for (value, label) in options {
+                button {
+                    class: "px-3 py-1 text-sm rounded-full transition-all",
+                    style: chip_style(current.as_deref() == Some(value)),
+                    onclick: move |_| state.write().set_status(Some(value.to_string())),
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+/// 排序按钮（日期/金额/状态，再次点击切换升降序）
+#[component]
+pub fn SortControls(state: Signal<ListQueryState>) -> Element {
+    let (sort_by, sort_order) = {
+        let current = state.read();
+        (current.sort_by, current.sort_order)
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-xs",
+            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "排序:" }
+            for field in SortField::ALL {
+                button {
+                    class: "px-2 py-1 rounded transition-all",
+                    style: chip_style(sort_by == field),
+                    onclick: move |_| state.write().toggle_sort(field),
+                    if sort_by == field {
+                        "{field.label()} {sort_order.arrow()}"
+                    } else {
+                        "{field.label()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 分页与每页数量选择
+#[component]
+pub fn PaginationControls(state: Signal<ListQueryState>, total_pages: u32) -> Element {
+    let (page, page_size) = {
+        let current = state.read();
+        (current.page, current.page_size)
+    };
+    let total = total_pages.max(1);
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center justify-center gap-2 mt-4 text-sm",
+            button {
+                class: "px-3 py-1 rounded transition-all",
+                style: chip_style(false),
+                disabled: page <= 1,
+                onclick: move |_| state.write().go_to(page.saturating_sub(1), total),
+                "上一页"
+            }
+            span {
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {format!("第 {} / {} 页", page.min(total), total)}
+            }
+            button {
+                class: "px-3 py-1 rounded transition-all",
+                style: chip_style(false),
+                disabled: page >= total,
+                onclick: move |_| state.write().go_to(page + 1, total),
+                "下一页"
+            }
+            select {
+                class: "px-2 py-1 rounded",
+                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                onchange: move |e| {
+                    if let Ok(size) = e.value().parse::<u32>() {
+                        state.write().set_page_size(size);
+                    }
+                },
+                for size in PAGE_SIZE_OPTIONS {
+                    option { value: "{size}", selected: size == page_size, "每页 {size} 条" }
+                }
+            }
+        }
+    }
+}
+
+/// 筛选结果为空时的提示（带“清除筛选”操作）
+#[component]
+pub fn FilteredEmptyState(message: String, on_clear: EventHandler<()>) -> Element {
+    rsx! {
+        div {
+            class: "text-center py-12",
+            div {
+                class: "mb-4",
+                style: format!("color: {}; font-size: 56px;", Colors::TEXT_SECONDARY),
+                "🔍"
+            }
+            div {
+                class: "text-base font-semibold mb-2",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "没有符合条件的记录"
+            }
+            div {
+                class: "text-sm mb-4",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "{message}"
+            }
+            button {
+                class: "px-4 py-2 rounded-lg text-sm font-medium",
+                style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                onclick: move |_| on_clear.call(()),
+                "清除筛选"
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //! 企业级加载状态显示，支持进度和预计时间
 
 use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
 use dioxus::prelude::*;
 
 /// 加载状态显示组件
@@ -86,3 +87,35 @@ pub fn LoadingState(
         }
     }
 }
+
+/// 请求超过常规耗时时的提示（如报价接口），请求正常时不显示
+#[component]
+pub fn SlowRequestHint(
+    /// 接口路径前缀（如 `/api/v1/swap/quote`）
+    path: String,
+    /// 提示文案
+    #[props(default)]
+    message: Option<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let slow = app_state
+        .slow_requests
+        .read()
+        .keys()
+        .any(|p| p.starts_with(&path));
+    if !slow {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-xs mt-2",
+            style: format!("color: {};", Colors::TEXT_SECONDARY),
+            div {
+                class: "animate-spin rounded-full h-3 w-3 border-2 border-t-transparent",
+                style: format!("border-color: {};", Colors::TECH_PRIMARY),
+            }
+            {message.unwrap_or_else(|| "比平时慢，仍在处理中…".to_string())}
+        }
+    }
+}
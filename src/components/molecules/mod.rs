@@ -3,39 +3,73 @@
 
 pub mod address_input;
 pub mod amount_input;
+pub mod backup_reminder;
+pub mod bridge_fee_summary;
+pub mod chain_account_prompt;
 pub mod chain_selector;
+pub mod confirm_action;
 pub mod country_detection_hint;
+pub mod demo_banner;
+pub mod device_step_up;
+pub mod dust_sweep;
+pub mod email_verification;
+pub mod empty_state;
 pub mod error_message;
 pub mod exchange_rate_lock;
 pub mod gas_fee_card;
+pub mod gas_readiness;
 pub mod kyc_verification;
 pub mod limit_display;
 pub mod limit_order_form;
+pub mod list_controls;
 pub mod loading_state;
 pub mod onboarding_tour;
 pub mod order_list;
 pub mod order_tracking;
+pub mod password_strength_meter;
+pub mod payment_region;
+pub mod payout_details;
+pub mod pending_swap_banner;
 pub mod performance_monitor;
+pub mod prepare_trading;
 pub mod price_change_indicator;
 pub mod price_chart;
 pub mod process_steps;
 pub mod provider_status_badge;
 pub mod qr_code_display;
+pub mod rebroadcast_prompt;
+pub mod route_preference_selector;
+pub mod saved_cards;
+pub mod solana_fee_card;
 pub mod stablecoin_balance;
+pub mod stale_data;
+pub mod step_wizard;
 pub mod swap_confirm_dialog;
 pub mod toast;
+pub mod token_logo;
 pub mod token_selector;
+pub mod token_visibility_manager;
 pub mod transaction_notification;
 pub mod user_feedback;
 pub mod wallet_delete_modal;
 
 // pub use address_input::AddressInput; // 未使用
-// pub use amount_input::AmountInput; // 未使用
+pub use amount_input::AmountInput;
+pub use backup_reminder::{BackupGate, BackupReminderBanner};
+pub use bridge_fee_summary::BridgeFeeSummary;
+pub use chain_account_prompt::ChainAccountPrompt;
 pub use chain_selector::ChainSelector;
+pub use confirm_action::{ConfirmAction, RiskLevel};
 pub use country_detection_hint::{CountryDetectionHint, CountryDetectionResult};
+pub use demo_banner::DemoModeBanner;
+pub use device_step_up::DeviceStepUpModal;
+pub use dust_sweep::DustSweepPanel;
+pub use email_verification::{EmailVerificationBanner, EmailVerificationRequired};
+pub use empty_state::{EmptyState, LoadFailedState};
 pub use error_message::ErrorMessage;
 pub use exchange_rate_lock::ExchangeRateLockCountdown;
 pub use gas_fee_card::GasFeeCard;
+pub use gas_readiness::GasReadinessBanner;
 #[allow(unused_imports)]
 pub use kyc_verification::{
     KycProvider, KycVerification, KycVerificationInfo, KycVerificationStatus,
@@ -43,25 +77,39 @@ pub use kyc_verification::{
 #[allow(unused_imports)]
 pub use limit_display::{KycLevel, LimitDisplay, LimitInfo};
 pub use limit_order_form::{LimitOrderForm, LimitOrderType};
-pub use loading_state::LoadingState;
-pub use onboarding_tour::OnboardingManager;
+pub use list_controls::{FilteredEmptyState, PaginationControls, SortControls, StatusFilterChips};
+pub use loading_state::{LoadingState, SlowRequestHint};
+pub use onboarding_tour::{OnboardingManager, OnboardingTour, PageTour};
 pub use order_list::{OrderList, OrderListItem, OrderType};
 #[allow(unused_imports)]
 pub use order_tracking::{OrderStatus, OrderTracking, OrderTrackingInfo};
+pub use password_strength_meter::{BreachCheck, PasswordStrengthMeter};
+pub use payment_region::{FiatRegionBlockedNotice, PaymentMethodOption, PaymentRegionBanner};
+pub use payout_details::{BankDetailsForm, SavedPayoutMethods};
+pub use pending_swap_banner::PendingSwapBanner;
 #[allow(unused_imports)]
 pub use performance_monitor::{PerformanceMonitor, PerformanceMonitorProps};
+pub use prepare_trading::PrepareTradingPanel;
 pub use price_change_indicator::{PriceChangeDirection, PriceChangeIndicator, PriceChangeInfo};
-pub use price_chart::{PriceChart, PriceDataPoint};
+pub use price_chart::{PriceChart, PriceDataPoint, Sparkline};
 pub use process_steps::ProcessSteps;
 #[allow(unused_imports)]
 pub use provider_status_badge::{
     ProviderStatus, ProviderStatusBadge, ProviderStatusInfo, ProviderStatusList,
 };
 pub use qr_code_display::QrCodeDisplay;
+pub use rebroadcast_prompt::RebroadcastPrompt;
+pub use route_preference_selector::RoutePreferenceSelector;
+pub use saved_cards::{CardForm, SavedCardList, SavedCardsManager};
+pub use solana_fee_card::SolanaFeeCard;
 pub use stablecoin_balance::StablecoinBalanceCard;
+pub use stale_data::StaleData;
+pub use step_wizard::{use_step_wizard, StepWizard, StepWizardState, WizardReviewRow, WizardStep};
 pub use swap_confirm_dialog::{SwapConfirmDialog, SwapConfirmInfo};
 pub use toast::ToastContainer;
+pub use token_logo::TokenLogo;
 pub use token_selector::TokenSelector;
+pub use token_visibility_manager::TokenVisibilityManager;
 pub use transaction_notification::{
     NotificationType, TransactionNotification, TransactionNotificationContainer,
 };
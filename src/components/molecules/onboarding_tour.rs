@@ -1,179 +1,660 @@
 //! Onboarding Tour - 新手引导组件
-//! 交互式教程，引导新用户了解功能
-#![allow(dead_code)]
+//! 数据驱动的页面引导：按路由注册引导，提示框锚定到目标元素（滚动到可见区域、
+//! 窗口尺寸变化时重新定位），各引导的完成情况统一记录在偏好存储中
 
+use crate::services::preferences;
 use crate::shared::design_tokens::Colors;
 use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+
+/// 引导流程进度的存储键前缀（LocalStorage，跨会话保留）
+const PROGRESS_KEY_PREFIX: &str = "onboarding_progress_";
+
+/// 提示框宽度（与样式中的 max-width 保持一致）
+const TOOLTIP_WIDTH: f64 = 320.0;
+/// 未测量到提示框高度时使用的估计值
+const TOOLTIP_FALLBACK_HEIGHT: f64 = 200.0;
+/// 提示框与目标元素的间距
+const TOOLTIP_GAP: f64 = 12.0;
+/// 提示框与视口边缘的最小距离
+const VIEWPORT_MARGIN: f64 = 8.0;
+/// 页面渲染完成后再开始引导（毫秒）
+const TOUR_START_DELAY_MS: u32 = 600;
+
+const TOOLTIP_ELEMENT_ID: &str = "onboarding-tour-tooltip";
+
+/// 提示框相对目标元素的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourPlacement {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    /// 居中显示（无目标元素）
+    Center,
+}
+
+impl TourPlacement {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Center => Self::Center,
+        }
+    }
+}
 
 /// 新手引导步骤
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TourStep {
-    pub id: String,
-    pub title: String,
-    pub content: String,
-    pub target_selector: Option<String>, // CSS选择器，用于高亮目标元素
+    /// 目标元素ID（None 表示居中显示）
+    pub target_id: Option<&'static str>,
+    /// 标题翻译键
+    pub title_key: &'static str,
+    /// 内容翻译键
+    pub body_key: &'static str,
+    pub placement: TourPlacement,
+}
+
+/// 页面引导
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tour {
+    pub id: &'static str,
+    /// 引导所属路由路径
+    pub route: &'static str,
+    /// 引导名称翻译键
+    pub name_key: &'static str,
+    pub steps: Vec<TourStep>,
+}
+
+fn step(
+    target_id: Option<&'static str>,
+    title_key: &'static str,
+    body_key: &'static str,
+    placement: TourPlacement,
+) -> TourStep {
+    TourStep {
+        target_id,
+        title_key,
+        body_key,
+        placement,
+    }
+}
+
+/// 视口坐标系中的矩形
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// 计算后的提示框位置
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TooltipPosition {
+    pub left: f64,
+    pub top: f64,
+    /// 实际使用的位置（空间不足时会翻转到其他方向）
+    pub placement: TourPlacement,
+}
+
+/// 计算提示框位置：优先使用指定方向，放不下时依次尝试对侧与其余方向，
+/// 都放不下则居中；交叉轴方向始终限制在视口内
+pub fn position_tooltip(
+    target: Option<Rect>,
+    tooltip: (f64, f64),
+    viewport: (f64, f64),
+    preferred: TourPlacement,
+) -> TooltipPosition {
+    let (width, height) = tooltip;
+    let (viewport_width, viewport_height) = viewport;
+    let center = TooltipPosition {
+        left: ((viewport_width - width) / 2.0).max(VIEWPORT_MARGIN),
+        top: ((viewport_height - height) / 2.0).max(VIEWPORT_MARGIN),
+        placement: TourPlacement::Center,
+    };
+    let Some(target) = target else {
+        return center;
+    };
+    if preferred == TourPlacement::Center {
+        return center;
+    }
+
+    let candidate = |placement: TourPlacement| -> Option<(f64, f64)> {
+        let center_x = target.left + target.width / 2.0 - width / 2.0;
+        let center_y = target.top + target.height / 2.0 - height / 2.0;
+        let (left, top, fits) = match placement {
+            TourPlacement::Top => {
+                let top = target.top - TOOLTIP_GAP - height;
+                (center_x, top, top >= VIEWPORT_MARGIN)
+            }
+            TourPlacement::Bottom => {
+                let top = target.top + target.height + TOOLTIP_GAP;
+                (
+                    center_x,
+                    top,
+                    top + height <= viewport_height - VIEWPORT_MARGIN,
+                )
+            }
+            TourPlacement::Left => {
+                let left = target.left - TOOLTIP_GAP - width;
+                (left, center_y, left >= VIEWPORT_MARGIN)
+            }
+            TourPlacement::Right => {
+                let left = target.left + target.width + TOOLTIP_GAP;
+                (
+                    left,
+                    center_y,
+                    left + width <= viewport_width - VIEWPORT_MARGIN,
+                )
+            }
+            TourPlacement::Center => return None,
+        };
+        fits.then_some((left, top))
+    };
+
+    let order = match preferred {
+        TourPlacement::Top | TourPlacement::Bottom => [
+            preferred,
+            preferred.opposite(),
+            TourPlacement::Right,
+            TourPlacement::Left,
+        ],
+        _ => [
+            preferred,
+            preferred.opposite(),
+            TourPlacement::Bottom,
+            TourPlacement::Top,
+        ],
+    };
+    let clamp = |value: f64, size: f64, limit: f64| {
+        value
+            .min(limit - size - VIEWPORT_MARGIN)
+            .max(VIEWPORT_MARGIN)
+    };
+
+    order
+        .into_iter()
+        .find_map(|placement| {
+            candidate(placement).map(|(left, top)| TooltipPosition {
+                left: clamp(left, width, viewport_width),
+                top: clamp(top, height, viewport_height),
+                placement,
+            })
+        })
+        .unwrap_or(center)
+}
+
+/// 各引导的完成情况（统一存储）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TourProgress {
+    #[serde(default)]
+    pub completed: BTreeSet<String>,
+}
+
+impl TourProgress {
+    /// 读取完成记录（保存在偏好存储中，旧版存储键由偏好服务迁移）
+    pub fn load() -> Self {
+        Self {
+            completed: preferences::load().completed_tours,
+        }
+    }
+
+    pub fn save(&self) {
+        preferences::update(|stored| stored.completed_tours = self.completed.clone());
+    }
+
+    pub fn is_completed(&self, tour_id: &str) -> bool {
+        self.completed.contains(tour_id)
+    }
+}
+
+/// 新手引导管理器（引导注册表与完成记录）
+pub struct OnboardingManager {
+    tours: Vec<Tour>,
+}
+
+impl OnboardingManager {
+    pub fn new() -> Self {
+        use TourPlacement::*;
+        Self {
+            tours: vec![
+                Tour {
+                    id: "swap",
+                    route: "/swap",
+                    name_key: "tour.swap.name",
+                    steps: vec![
+                        step(
+                            None,
+                            "tour.swap.welcome.title",
+                            "tour.swap.welcome.body",
+                            Center,
+                        ),
+                        step(
+                            Some("swap-tab"),
+                            "tour.swap.swap.title",
+                            "tour.swap.swap.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("buy-tab"),
+                            "tour.swap.buy.title",
+                            "tour.swap.buy.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("withdraw-tab"),
+                            "tour.swap.withdraw.title",
+                            "tour.swap.withdraw.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("history-tab"),
+                            "tour.swap.history.title",
+                            "tour.swap.history.body",
+                            Bottom,
+                        ),
+                    ],
+                },
+                Tour {
+                    id: "dashboard",
+                    route: "/dashboard",
+                    name_key: "tour.dashboard.name",
+                    steps: vec![
+                        step(
+                            Some("dashboard-balance-overview"),
+                            "tour.dashboard.balance.title",
+                            "tour.dashboard.balance.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("dashboard-quick-actions"),
+                            "tour.dashboard.actions.title",
+                            "tour.dashboard.actions.body",
+                            Top,
+                        ),
+                        step(
+                            Some("dashboard-wallet-list"),
+                            "tour.dashboard.wallets.title",
+                            "tour.dashboard.wallets.body",
+                            Top,
+                        ),
+                    ],
+                },
+                Tour {
+                    id: "send",
+                    route: "/send",
+                    name_key: "tour.send.name",
+                    steps: vec![
+                        step(
+                            Some("send-token-step"),
+                            "tour.send.token.title",
+                            "tour.send.token.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("send-recipient-step"),
+                            "tour.send.recipient.title",
+                            "tour.send.recipient.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("send-amount-step"),
+                            "tour.send.amount.title",
+                            "tour.send.amount.body",
+                            Bottom,
+                        ),
+                        step(
+                            Some("send-submit-step"),
+                            "tour.send.submit.title",
+                            "tour.send.submit.body",
+                            Top,
+                        ),
+                    ],
+                },
+            ],
+        }
+    }
+
+    /// 所有已注册的引导
+    pub fn tours(&self) -> &[Tour] {
+        &self.tours
+    }
+
+    /// 路由对应的引导
+    pub fn tour_for_route(&self, route: &str) -> Option<&Tour> {
+        let path = route.split(['?', '#']).next().unwrap_or(route);
+        self.tours.iter().find(|tour| tour.route == path)
+    }
+
+    /// 标记引导已完成
+    pub fn mark_completed(tour_id: &str) {
+        let mut progress = TourProgress::load();
+        if progress.completed.insert(tour_id.to_string()) {
+            progress.save();
+        }
+    }
+
+    /// 重置引导，下次进入对应页面时重新播放
+    pub fn reset_tour(tour_id: &str) {
+        let mut progress = TourProgress::load();
+        if progress.completed.remove(tour_id) {
+            progress.save();
+        }
+    }
+
+    /// 读取引导流程进度
+    pub fn load_progress<T: DeserializeOwned>(flow: &str) -> Option<T> {
+        LocalStorage::get(format!("{}{}", PROGRESS_KEY_PREFIX, flow)).ok()
+    }
+
+    /// 保存引导流程进度，便于下次访问时继续
+    pub fn save_progress<T: Serialize>(flow: &str, progress: &T) {
+        let _ = LocalStorage::set(format!("{}{}", PROGRESS_KEY_PREFIX, flow), progress);
+    }
+}
+
+impl Default for OnboardingManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn element_rect(id: &str) -> Option<Rect> {
+    let element = web_sys::window()?.document()?.get_element_by_id(id)?;
+    let rect = element.get_bounding_client_rect();
+    Some(Rect {
+        left: rect.left(),
+        top: rect.top(),
+        width: rect.width(),
+        height: rect.height(),
+    })
+}
+
+fn viewport_size() -> (f64, f64) {
+    web_sys::window()
+        .map(|w| {
+            (
+                w.inner_width()
+                    .ok()
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(1024.0),
+                w.inner_height()
+                    .ok()
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(768.0),
+            )
+        })
+        .unwrap_or((1024.0, 768.0))
 }
 
-/// 新手引导组件
+/// 把目标元素滚动到视口中部
+fn scroll_target_into_view(id: &str) {
+    if let Some(element) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id))
+    {
+        let options = web_sys::ScrollIntoViewOptions::new();
+        options.set_block(web_sys::ScrollLogicalPosition::Center);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+}
+
+/// 当前路由的页面引导（放在应用布局中，以路由作为 key 挂载）
+#[component]
+pub fn PageTour(route: String) -> Element {
+    let tour = OnboardingManager::new().tour_for_route(&route).cloned();
+    let mut active = use_signal(|| false);
+
+    use_effect({
+        let tour_id = tour.as_ref().map(|t| t.id);
+        move || {
+            let Some(tour_id) = tour_id else {
+                return;
+            };
+            if TourProgress::load().is_completed(tour_id) {
+                return;
+            }
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(TOUR_START_DELAY_MS).await;
+                active.set(true);
+            });
+        }
+    });
+
+    match tour {
+        Some(tour) if *active.read() => rsx! {
+            OnboardingTour {
+                tour: tour.clone(),
+                on_finish: move |_| {
+                    OnboardingManager::mark_completed(tour.id);
+                    active.set(false);
+                },
+            }
+        },
+        _ => rsx! {},
+    }
+}
+
+/// 新手引导组件：锚定目标元素显示提示框并高亮目标
 #[component]
 pub fn OnboardingTour(
-    /// 是否显示引导
-    show: Signal<bool>,
-    /// 当前步骤索引
-    current_step: Signal<usize>,
-    /// 引导步骤列表
-    steps: Signal<Vec<TourStep>>,
-    /// 关闭回调
-    on_close: Option<EventHandler<()>>,
-    /// 下一步回调
-    on_next: Option<EventHandler<()>>,
-    /// 上一步回调
-    on_prev: Option<EventHandler<()>>,
-    /// 跳过回调
-    on_skip: Option<EventHandler<()>>,
+    /// 要播放的引导
+    tour: Tour,
+    /// 完成或跳过时回调
+    on_finish: EventHandler<()>,
 ) -> Element {
-    let show_val = *show.read();
-    let current_step_val = *current_step.read();
-    let steps_val = steps.read();
+    let t = crate::i18n::use_translation();
+    let mut current_step = use_signal(|| 0usize);
+    let mut layout_tick = use_signal(|| 0u32);
+    let mut target_rect = use_signal(|| None::<Rect>);
+    let mut position = use_signal(|| {
+        position_tooltip(
+            None,
+            (TOOLTIP_WIDTH, TOOLTIP_FALLBACK_HEIGHT),
+            viewport_size(),
+            TourPlacement::Center,
+        )
+    });
 
-    if !show_val || steps_val.is_empty() || current_step_val >= steps_val.len() {
-        return rsx! { div {} };
-    }
+    // 窗口尺寸变化或滚动时重新定位
+    let listener = use_hook(|| {
+        let closure = Closure::wrap(Box::new(move || {
+            let next = layout_tick.peek().wrapping_add(1);
+            layout_tick.set(next);
+        }) as Box<dyn FnMut()>);
+        if let Some(window) = web_sys::window() {
+            let callback = closure.as_ref().unchecked_ref::<js_sys::Function>();
+            let _ = window.add_event_listener_with_callback("resize", callback);
+            let _ = window.add_event_listener_with_callback_and_bool("scroll", callback, true);
+        }
+        Rc::new(closure)
+    });
+    use_drop(move || {
+        if let Some(window) = web_sys::window() {
+            let callback = listener
+                .as_ref()
+                .as_ref()
+                .unchecked_ref::<js_sys::Function>();
+            let _ = window.remove_event_listener_with_callback("resize", callback);
+            let _ = window.remove_event_listener_with_callback_and_bool("scroll", callback, true);
+        }
+    });
+
+    // 切换步骤时把目标滚动到可见区域
+    use_effect({
+        let steps = tour.steps.clone();
+        move || {
+            let index = current_step();
+            if let Some(target_id) = steps.get(index).and_then(|s| s.target_id) {
+                scroll_target_into_view(target_id);
+            }
+            let next = layout_tick.peek().wrapping_add(1);
+            layout_tick.set(next);
+        }
+    });
+
+    // 计算提示框位置
+    use_effect({
+        let steps = tour.steps.clone();
+        move || {
+            let _ = layout_tick();
+            let Some(step) = steps.get(current_step()) else {
+                return;
+            };
+            let rect = step.target_id.and_then(element_rect);
+            let height = element_rect(TOOLTIP_ELEMENT_ID)
+                .map(|r| r.height)
+                .filter(|h| *h > 0.0)
+                .unwrap_or(TOOLTIP_FALLBACK_HEIGHT);
+            target_rect.set(rect);
+            position.set(position_tooltip(
+                rect,
+                (TOOLTIP_WIDTH, height),
+                viewport_size(),
+                step.placement,
+            ));
+        }
+    });
 
-    let step = &steps_val[current_step_val];
-    let is_first = current_step_val == 0;
-    let is_last = current_step_val == steps_val.len() - 1;
+    let index = current_step();
+    let Some(step) = tour.steps.get(index).cloned() else {
+        return rsx! {};
+    };
+    let total = tour.steps.len();
+    let is_first = index == 0;
+    let is_last = index + 1 == total;
+    let pos = position();
 
     rsx! {
-        // 遮罩层
+        // 遮罩层（有目标时由高亮框的阴影形成遮罩）
         div {
             class: "fixed inset-0 z-50",
-            style: "background: rgba(0, 0, 0, 0.5);",
-            onclick: move |_| {
-                if let Some(handler) = on_close {
-                    handler.call(());
-                }
-            },
+            style: if target_rect.read().is_some() { "background: transparent;" } else { "background: rgba(0, 0, 0, 0.5);" },
+            onclick: move |_| on_finish.call(()),
+        }
 
-            // 引导卡片
+        // 目标高亮
+        if let Some(rect) = *target_rect.read() {
             div {
-                class: "absolute top-1/2 left-1/2 transform -translate-x-1/2 -translate-y-1/2 w-full max-w-md mx-4",
-                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                onclick: move |e| {
-                    e.stop_propagation();
-                },
+                class: "fixed z-50 rounded-lg pointer-events-none transition-all",
+                style: format!(
+                    "left: {}px; top: {}px; width: {}px; height: {}px; box-shadow: 0 0 0 9999px rgba(0, 0, 0, 0.5); border: 2px solid {};",
+                    rect.left - 4.0,
+                    rect.top - 4.0,
+                    rect.width + 8.0,
+                    rect.height + 8.0,
+                    Colors::TECH_PRIMARY
+                ),
+            }
+        }
 
-                div {
-                    class: "p-6 rounded-lg",
-                    // 标题栏
-                    div {
-                        class: "flex items-center justify-between mb-4",
-                        h3 {
-                            class: "text-lg font-semibold",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {step.title.clone()}
-                        }
-                        button {
-                            class: "text-xl leading-none",
-                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            onclick: move |_| {
-                                if let Some(handler) = on_close {
-                                    handler.call(());
-                                }
-                            },
-                            "×"
-                        }
-                    }
+        // 引导提示框
+        div {
+            id: TOOLTIP_ELEMENT_ID,
+            class: "fixed z-50 rounded-lg p-5 shadow-2xl transition-all",
+            style: format!(
+                "left: {}px; top: {}px; width: {}px; max-width: calc(100vw - 16px); background: {}; border: 1px solid {};",
+                pos.left,
+                pos.top,
+                TOOLTIP_WIDTH,
+                Colors::BG_SECONDARY,
+                Colors::BORDER_PRIMARY
+            ),
+            onclick: move |e| e.stop_propagation(),
 
-                    // 内容
-                    div {
-                        class: "mb-6",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        {step.content.clone()}
-                    }
+            // 标题栏
+            div {
+                class: "flex items-center justify-between mb-3",
+                h3 {
+                    class: "text-base font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {t(step.title_key)}
+                }
+                button {
+                    class: "text-xl leading-none",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    onclick: move |_| on_finish.call(()),
+                    "×"
+                }
+            }
+
+            // 内容
+            div {
+                class: "text-sm mb-4",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                {t(step.body_key)}
+            }
 
-                    // 步骤指示器
+            // 步骤指示器
+            div {
+                class: "flex items-center justify-center gap-2 mb-4",
+                for idx in 0..total {
                     div {
-                        class: "flex items-center justify-center gap-2 mb-4",
-                        for (idx, _) in steps_val.iter().enumerate() {
-                            div {
-                                class: "w-2 h-2 rounded-full transition-all",
-                                style: format!(
-                                    "background: {};",
-                                    if idx == current_step_val {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    }
-                                ),
+                        class: "w-2 h-2 rounded-full transition-all",
+                        style: format!(
+                            "background: {};",
+                            if idx == index {
+                                Colors::TECH_PRIMARY
+                            } else {
+                                Colors::BORDER_PRIMARY
                             }
-                        }
+                        ),
                     }
+                }
+            }
 
-                    // 按钮组
-                    div {
-                        class: "flex items-center justify-between gap-3",
-                        // 跳过按钮
+            // 按钮组
+            div {
+                class: "flex items-center justify-between gap-3",
+                button {
+                    class: "px-3 py-1.5 rounded text-sm transition-all",
+                    style: format!(
+                        "background: {}; border: 1px solid {}; color: {};",
+                        Colors::BG_PRIMARY,
+                        Colors::BORDER_PRIMARY,
+                        Colors::TEXT_SECONDARY
+                    ),
+                    onclick: move |_| on_finish.call(()),
+                    {t("tour.skip")}
+                }
+                div {
+                    class: "flex gap-2",
+                    if !is_first {
                         button {
-                            class: "px-4 py-2 rounded transition-all",
+                            class: "px-3 py-1.5 rounded text-sm transition-all",
                             style: format!(
                                 "background: {}; border: 1px solid {}; color: {};",
                                 Colors::BG_PRIMARY,
                                 Colors::BORDER_PRIMARY,
-                                Colors::TEXT_SECONDARY
+                                Colors::TEXT_PRIMARY
                             ),
-                            onclick: move |_| {
-                                if let Some(handler) = on_skip {
-                                    handler.call(());
-                                }
-                            },
-                            "跳过"
+                            onclick: move |_| current_step.set(index - 1),
+                            {t("tour.prev")}
                         }
-
-                        // 导航按钮
-                        div {
-                            class: "flex gap-2",
-                            // 上一步
-                            if !is_first {
-                                button {
-                                    class: "px-4 py-2 rounded transition-all",
-                                    style: format!(
-                                        "background: {}; border: 1px solid {}; color: {};",
-                                        Colors::BG_PRIMARY,
-                                        Colors::BORDER_PRIMARY,
-                                        Colors::TEXT_PRIMARY
-                                    ),
-                                    onclick: move |_| {
-                                        if let Some(handler) = on_prev {
-                                            handler.call(());
-                                        }
-                                    },
-                                    "上一步"
-                                }
-                            }
-
-                            // 下一步/完成
-                            button {
-                                class: "px-4 py-2 rounded transition-all",
-                                style: format!(
-                                    "background: {}; color: white;",
-                                    Colors::TECH_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    if is_last {
-                                        if let Some(handler) = on_close {
-                                            handler.call(());
-                                        }
-                                    } else if let Some(handler) = on_next {
-                                        handler.call(());
-                                    }
-                                },
-                                if is_last {
-                                    "完成"
-                                } else {
-                                    "下一步"
-                                }
+                    }
+                    button {
+                        class: "px-3 py-1.5 rounded text-sm transition-all",
+                        style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                        onclick: move |_| {
+                            if is_last {
+                                on_finish.call(());
+                            } else {
+                                current_step.set(index + 1);
                             }
+                        },
+                        if is_last {
+                            {t("tour.done")}
+                        } else {
+                            {t("tour.next")}
                         }
                     }
                 }
@@ -182,56 +663,78 @@ pub fn OnboardingTour(
     }
 }
 
-/// 新手引导管理器
-pub struct OnboardingManager {
-    steps: Vec<TourStep>,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl OnboardingManager {
-    pub fn new() -> Self {
-        Self {
-            steps: vec![
-                TourStep {
-                    id: "welcome".to_string(),
-                    title: "欢迎使用交换功能".to_string(),
-                    content: "在这里您可以交换代币、购买稳定币、提现到法币。让我们开始探索吧！".to_string(),
-                    target_selector: None,
-                },
-                TourStep {
-                    id: "swap".to_string(),
-                    title: "代币交换".to_string(),
-                    content: "选择要交换的代币和数量，系统会自动为您获取最佳报价。支持所有主流代币和稳定币。".to_string(),
-                    target_selector: Some("#swap-tab".to_string()),
-                },
-                TourStep {
-                    id: "buy".to_string(),
-                    title: "购买稳定币".to_string(),
-                    content: "使用信用卡、银行卡或PayPal购买USDT/USDC稳定币。支持多种支付方式，即时到账。".to_string(),
-                    target_selector: Some("#buy-tab".to_string()),
-                },
-                TourStep {
-                    id: "withdraw".to_string(),
-                    title: "提现到法币".to_string(),
-                    content: "将您的代币提现到银行卡、银行账户或PayPal。系统会自动执行代币→稳定币→法币的两步流程。".to_string(),
-                    target_selector: Some("#withdraw-tab".to_string()),
-                },
-                TourStep {
-                    id: "history".to_string(),
-                    title: "交易历史".to_string(),
-                    content: "查看所有交易记录，包括交换、充值和提现。支持按类型和状态筛选。".to_string(),
-                    target_selector: Some("#history-tab".to_string()),
-                },
-            ],
+    const VIEWPORT: (f64, f64) = (1000.0, 800.0);
+    const TOOLTIP: (f64, f64) = (320.0, 200.0);
+
+    fn rect(left: f64, top: f64) -> Rect {
+        Rect {
+            left,
+            top,
+            width: 100.0,
+            height: 40.0,
         }
     }
 
-    pub fn get_steps(&self) -> &[TourStep] {
-        &self.steps
+    #[test]
+    fn tooltip_uses_preferred_side_and_flips_when_out_of_space() {
+        let below = position_tooltip(
+            Some(rect(450.0, 100.0)),
+            TOOLTIP,
+            VIEWPORT,
+            TourPlacement::Bottom,
+        );
+        assert_eq!(below.placement, TourPlacement::Bottom);
+        assert_eq!(below.top, 100.0 + 40.0 + TOOLTIP_GAP);
+        assert_eq!(below.left, 500.0 - 160.0);
+
+        // 目标贴近底部：向上翻转
+        let flipped = position_tooltip(
+            Some(rect(450.0, 700.0)),
+            TOOLTIP,
+            VIEWPORT,
+            TourPlacement::Bottom,
+        );
+        assert_eq!(flipped.placement, TourPlacement::Top);
+        assert_eq!(flipped.top, 700.0 - TOOLTIP_GAP - 200.0);
+
+        // 目标贴近左边缘：交叉轴限制在视口内
+        let clamped = position_tooltip(
+            Some(rect(0.0, 100.0)),
+            TOOLTIP,
+            VIEWPORT,
+            TourPlacement::Bottom,
+        );
+        assert_eq!(clamped.left, VIEWPORT_MARGIN);
     }
-}
 
-impl Default for OnboardingManager {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn tooltip_centers_without_target_or_space() {
+        let centered = position_tooltip(None, TOOLTIP, VIEWPORT, TourPlacement::Bottom);
+        assert_eq!(centered.placement, TourPlacement::Center);
+        assert_eq!((centered.left, centered.top), (340.0, 300.0));
+
+        let tiny = position_tooltip(
+            Some(rect(10.0, 10.0)),
+            TOOLTIP,
+            (300.0, 220.0),
+            TourPlacement::Top,
+        );
+        assert_eq!(tiny.placement, TourPlacement::Center);
+    }
+
+    #[test]
+    fn tours_are_registered_per_route() {
+        let manager = OnboardingManager::new();
+        assert_eq!(manager.tour_for_route("/swap").map(|t| t.id), Some("swap"));
+        assert_eq!(
+            manager.tour_for_route("/send?to=0x1").map(|t| t.id),
+            Some("send")
+        );
+        assert!(manager.tour_for_route("/bridge").is_none());
+        assert!(manager.tours().iter().all(|t| !t.steps.is_empty()));
     }
 }
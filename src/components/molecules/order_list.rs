@@ -1,12 +1,17 @@
 //! Order List Component - 订单列表组件
 //! 显示法币订单列表（充值/提现）
 
+use crate::components::atoms::skeleton::SkeletonTableRow;
+use crate::components::molecules::empty_state::EmptyState;
 use crate::components::molecules::order_tracking::OrderStatus;
 use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::time;
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// 订单类型
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum OrderType {
     Onramp,  // 充值
     Offramp, // 提现
@@ -22,7 +27,7 @@ impl OrderType {
 }
 
 /// 订单列表项
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrderListItem {
     pub order_id: String,
     pub order_type: OrderType,
@@ -52,40 +57,30 @@ pub struct OrderListProps {
     pub on_retry: Option<EventHandler<String>>,
     /// 查看详情回调
     pub on_view_details: Option<EventHandler<String>>,
+    /// 重新加载列表回调（加载失败时的重试）
+    #[props(default)]
+    pub on_reload: Option<EventHandler<()>>,
+    /// 空列表时的创建订单回调
+    #[props(default)]
+    pub on_create: Option<EventHandler<()>>,
+    /// 继续支付回调（待支付的充值订单）
+    #[props(default)]
+    pub on_resume: Option<EventHandler<String>>,
 }
 
 /// 订单列表组件
 #[component]
 pub fn OrderList(props: OrderListProps) -> Element {
+    let t = crate::i18n::use_translation();
+    let lang = use_context::<AppState>().language.read().clone();
+
     if props.loading {
         return rsx! {
             div {
                 class: "space-y-4 py-8",
                 // 骨架屏加载效果
-                for _ in 0..3 {
-                    div {
-                        class: "p-4 rounded-lg animate-pulse",
-                        style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                        div {
-                            class: "flex items-center justify-between mb-3",
-                            div {
-                                class: "h-4 w-32 rounded",
-                                style: format!("background: {};", Colors::BG_PRIMARY),
-                            }
-                            div {
-                                class: "h-4 w-20 rounded",
-                                style: format!("background: {};", Colors::BG_PRIMARY),
-                            }
-                        }
-                        div {
-                            class: "h-3 w-48 rounded mb-2",
-                            style: format!("background: {};", Colors::BG_PRIMARY),
-                        }
-                        div {
-                            class: "h-3 w-36 rounded",
-                            style: format!("background: {};", Colors::BG_PRIMARY),
-                        }
-                    }
+                for i in 0..3 {
+                    SkeletonTableRow { key: "{i}", icon: false }
                 }
                 div {
                     class: "text-center mt-4",
@@ -129,9 +124,11 @@ pub fn OrderList(props: OrderListProps) -> Element {
                     class: "w-full px-4 py-2 rounded-lg font-medium text-sm transition-all",
                     style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
                     onclick: {
-                        // 重试功能由父组件处理
+                        let on_reload = props.on_reload;
                         move |_| {
-                            // 这里可以触发父组件的刷新
+                            if let Some(handler) = on_reload {
+                                handler.call(());
+                            }
                         }
                     },
                     "🔄 重试"
@@ -141,29 +138,18 @@ pub fn OrderList(props: OrderListProps) -> Element {
     }
 
     if props.orders.is_empty() {
+        let on_create = props.on_create;
         return rsx! {
-            div {
-                class: "text-center py-16",
-                div {
-                    class: "mb-6",
-                    style: format!("color: {}; font-size: 64px;", Colors::TEXT_SECONDARY),
-                    "📋"
-                }
-                div {
-                    class: "text-lg font-semibold mb-2",
-                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                    "暂无订单"
-                }
-                div {
-                    class: "text-sm mb-6",
-                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                    "您还没有任何法币订单记录"
-                }
-                div {
-                    class: "text-xs",
-                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                    "提示：您可以尝试购买稳定币或提现来创建订单"
-                }
+            EmptyState {
+                icon: "📋".to_string(),
+                title: t("empty.orders.title"),
+                description: t("empty.orders.description"),
+                cta_label: on_create.map(|_| t("empty.orders.cta")),
+                on_cta: move |_| {
+                    if let Some(handler) = on_create {
+                        handler.call(());
+                    }
+                },
             }
         };
     }
@@ -253,7 +239,7 @@ pub fn OrderList(props: OrderListProps) -> Element {
                             }
                             div {
                                 style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "{order.created_at}"
+                                {time::format_api_datetime(&order.created_at, &lang)}
                             }
                         }
                         if let Some(updated) = &order.updated_at {
@@ -265,7 +251,7 @@ pub fn OrderList(props: OrderListProps) -> Element {
                                 }
                                 div {
                                     style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "{updated}"
+                                    {time::format_api_datetime(updated, &lang)}
                                 }
                             }
                         }
@@ -309,8 +295,36 @@ pub fn OrderList(props: OrderListProps) -> Element {
                                 }
                             }
                         }
-                        // 取消按钮（待处理状态）
-                        if matches!(order.status, OrderStatus::Pending) {
+                        // 继续支付按钮（待支付的充值订单）
+                        if matches!(
+                            (order.order_type, order.status),
+                            (OrderType::Onramp, OrderStatus::Pending)
+                        ) {
+                            if let Some(ref on_resume) = props.on_resume {
+                                {
+                                    let order_id = order.order_id.clone();
+                                    let handler = *on_resume;
+                                    rsx! {
+                                        button {
+                                            class: "px-3 py-1.5 rounded text-xs font-medium transition-all",
+                                            style: format!(
+                                                "background: {}; color: white;",
+                                                Colors::TECH_PRIMARY
+                                            ),
+                                            onclick: move |_| {
+                                                handler.call(order_id.clone());
+                                            },
+                                            "继续支付"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        // 取消按钮（待处理状态；充值订单在支付处理中也可尝试取消）
+                        if matches!(
+                            (order.order_type, order.status),
+                            (_, OrderStatus::Pending) | (OrderType::Onramp, OrderStatus::Processing)
+                        ) {
                             if let Some(ref on_cancel) = props.on_cancel {
                                 {
                                     let order_id = order.order_id.clone();
@@ -3,9 +3,10 @@
 
 use crate::shared::design_tokens::Colors;
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// 订单状态枚举
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderStatus {
     Pending,    // 待处理
     Processing, // 处理中
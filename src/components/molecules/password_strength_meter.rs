@@ -0,0 +1,117 @@
+//! Password Strength Meter - 密码强度条
+//! 显示强度分段条、主要问题、改进建议以及泄露密码检查结果
+
+use crate::features::auth::password_strength::PasswordStrength;
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+/// 泄露密码检查状态
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BreachCheck {
+    /// 未检查（未开启或密码过短）
+    #[default]
+    Idle,
+    Checking,
+    /// 未在已知泄露中发现
+    Clean,
+    /// 在已知泄露中出现的次数
+    Breached(u64),
+    /// 检查服务不可用
+    Unavailable,
+}
+
+fn score_color(score: u8) -> &'static str {
+    match score {
+        0 | 1 => Colors::PAYMENT_ERROR,
+        2 => Colors::PAYMENT_WARNING,
+        _ => Colors::PAYMENT_SUCCESS,
+    }
+}
+
+/// 密码强度条
+#[component]
+pub fn PasswordStrengthMeter(
+    strength: PasswordStrength,
+    #[props(default)] breach: BreachCheck,
+) -> Element {
+    let color = score_color(strength.score);
+    // 0 分时也点亮第一格，让用户看到"极弱"
+    let filled = strength.score.max(1);
+
+    rsx! {
+        div {
+            class: "mt-2 space-y-2",
+            div {
+                class: "flex items-center gap-2",
+                div {
+                    class: "flex-1 grid grid-cols-4 gap-1",
+                    for segment in 1..=4u8 {
+                        div {
+                            key: "{segment}",
+                            class: "h-1.5 rounded-full",
+                            style: format!(
+                                "background: {};",
+                                if segment <= filled { color } else { Colors::BORDER_PRIMARY }
+                            ),
+                        }
+                    }
+                }
+                span {
+                    class: "text-xs font-medium w-10 text-right",
+                    style: format!("color: {};", color),
+                    {strength.label()}
+                }
+            }
+            if let Some(warning) = strength.warning {
+                p {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::PAYMENT_WARNING),
+                    "⚠️ {warning}"
+                }
+            }
+            if !strength.suggestions.is_empty() {
+                ul {
+                    class: "text-xs space-y-1 list-disc pl-4",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    for suggestion in strength.suggestions.iter() {
+                        li { key: "{suggestion}", "{suggestion}" }
+                    }
+                }
+            }
+            match breach {
+                BreachCheck::Checking => rsx! {
+                    p {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "正在检查是否为已泄露密码..."
+                    }
+                },
+                BreachCheck::Breached(count) => rsx! {
+                    p {
+                        class: "text-xs p-2 rounded",
+                        style: format!(
+                            "color: {}; background: rgba(239, 68, 68, 0.1);",
+                            Colors::PAYMENT_ERROR
+                        ),
+                        {format!("⛔ 常见泄露密码：该密码已在 {} 次已知数据泄露中出现，极易被撞库破解，请更换。", count)}
+                    }
+                },
+                BreachCheck::Clean => rsx! {
+                    p {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "✓ 未在已知数据泄露中发现该密码"
+                    }
+                },
+                BreachCheck::Unavailable => rsx! {
+                    p {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "泄露检查暂不可用，不影响注册"
+                    }
+                },
+                BreachCheck::Idle => rsx! {},
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Payment Region - 法币地区提示与支付方式选项组件
+//! 显示检测到的国家/地区（可手动更改），并按地区禁用不可用的支付方式
+
+use crate::services::country_support::{MethodAvailability, PaymentMethodMatrix};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 可手动选择的国家/地区
+const SELECTABLE_COUNTRIES: [(&str, &str); 12] = [
+    ("CN", "中国大陆"),
+    ("HK", "中国香港"),
+    ("TW", "中国台湾"),
+    ("SG", "新加坡"),
+    ("JP", "日本"),
+    ("KR", "韩国"),
+    ("US", "美国"),
+    ("GB", "英国"),
+    ("DE", "德国"),
+    ("FR", "法国"),
+    ("ES", "西班牙"),
+    ("AU", "澳大利亚"),
+];
+
+/// 地区提示条：显示当前地区及检测方式，提供手动更改入口
+#[component]
+pub fn PaymentRegionBanner(region: Signal<Option<PaymentMethodMatrix>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let override_code = app_state
+        .preferences
+        .read()
+        .country_override
+        .clone()
+        .unwrap_or_default();
+
+    let (label, method) = match region.read().as_ref() {
+        Some(matrix) => (
+            if matrix.country_name.is_empty() {
+                matrix.country_code.clone()
+            } else {
+                matrix.country_name.clone()
+            },
+            match matrix.detection_method.as_str() {
+                "Manual" => "手动设置",
+                "IP" => "IP 定位",
+                _ => "浏览器语言",
+            },
+        ),
+        None => ("正在检测您的国家/地区...".to_string(), ""),
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center justify-between gap-2 p-3 rounded-lg text-sm",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex items-center gap-2",
+                span { "🌍" }
+                span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{label}" }
+                if !method.is_empty() {
+                    span {
+                        class: "text-xs px-2 py-0.5 rounded",
+                        style: format!("background: {}; color: {};", Colors::BG_PRIMARY, Colors::TEXT_SECONDARY),
+                        "{method}"
+                    }
+                }
+            }
+            select {
+                class: "text-xs p-1 rounded",
+                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                value: "{override_code}",
+                onchange: move |evt| {
+                    let value = evt.value();
+                    let mut preferences = app_state.preferences;
+                    let mut prefs = preferences.write();
+                    prefs.country_override = (!value.is_empty()).then_some(value);
+                    prefs.save();
+                },
+                option { value: "", selected: override_code.is_empty(), "自动检测" }
+                for (code, name) in SELECTABLE_COUNTRIES {
+                    option { value: code, selected: override_code == code, "{name}" }
+                }
+            }
+        }
+    }
+}
+
+/// 受限地区的法币功能阻断提示（兑换功能不受影响）
+#[component]
+pub fn FiatRegionBlockedNotice(matrix: PaymentMethodMatrix) -> Element {
+    let reason = matrix
+        .restriction_reason
+        .clone()
+        .unwrap_or_else(|| "根据适用的合规要求，您所在的地区暂不提供法币服务".to_string());
+
+    rsx! {
+        div {
+            class: "p-6 rounded-lg space-y-2",
+            style: format!("background: {}; border: 1px solid rgba(239, 68, 68, 0.4);", Colors::BG_SECONDARY),
+            div {
+                class: "text-lg font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "⛔ 法币服务在您的地区不可用"
+            }
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "{reason}"
+            }
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "您仍可以使用「兑换」功能在链上交换代币。如果地区检测有误，可在上方手动更改国家/地区。"
+            }
+        }
+    }
+}
+
+/// 支付/提现方式选项按钮（不可用时置灰并通过提示气泡说明原因）
+#[component]
+pub fn PaymentMethodOption(
+    #[props(default)] icon: Option<Element>,
+    label: String,
+    hint: String,
+    #[props(default)] badge: Option<String>,
+    selected: bool,
+    availability: MethodAvailability,
+    onselect: EventHandler<()>,
+) -> Element {
+    let available = availability.is_available();
+    let tooltip = availability.reason().unwrap_or_default().to_string();
+
+    rsx! {
+        button {
+            class: if available {
+                "p-3 rounded-lg border text-left transition-all hover:scale-105"
+            } else {
+                "p-3 rounded-lg border text-left opacity-50 cursor-not-allowed"
+            },
+            style: format!(
+                "background: {}; border-color: {}; color: {};",
+                if selected { "rgba(99, 102, 241, 0.15)" } else { Colors::BG_SECONDARY },
+                if selected { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY },
+                Colors::TEXT_PRIMARY
+            ),
+            disabled: !available,
+            title: "{tooltip}",
+            onclick: move |_| {
+                if available {
+                    onselect.call(());
+                }
+            },
+            div {
+                class: "font-medium flex items-center gap-2",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                if let Some(icon) = icon {
+                    {icon}
+                }
+                span { "{label}" }
+                if let Some(badge) = badge {
+                    span {
+                        class: "text-xs px-2 py-0.5 rounded",
+                        style: "background: rgba(99, 102, 241, 0.2); color: rgb(99, 102, 241);",
+                        "{badge}"
+                    }
+                }
+            }
+            div {
+                class: "text-xs mt-1",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                if available { "{hint}" } else { "{tooltip}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Payout Details - 提现收款信息组件
+//! 按收款国家切换字段的银行信息表单（逐字段校验），以及已保存收款方式列表
+
+use crate::features::swap::payout::{
+    self, BankDetails, BankSchema, SavedPayoutMethod, PAYOUT_COUNTRIES,
+};
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+fn input_style() -> String {
+    format!(
+        "background: {}; border: 1px solid {}; color: {};",
+        Colors::BG_PRIMARY,
+        Colors::BORDER_PRIMARY,
+        Colors::TEXT_PRIMARY
+    )
+}
+
+/// 单个输入字段（已填写但校验失败时显示错误）
+#[component]
+fn BankField(
+    label: &'static str,
+    placeholder: &'static str,
+    value: String,
+    error: Option<String>,
+    oninput: EventHandler<String>,
+) -> Element {
+    rsx! {
+        div {
+            label {
+                class: "block text-xs font-medium mb-1",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "{label}"
+            }
+            input {
+                class: "w-full p-3 rounded-lg",
+                style: input_style(),
+                r#type: "text",
+                value: "{value}",
+                placeholder: placeholder,
+                oninput: move |e| oninput.call(e.value()),
+            }
+            if let Some(err) = error {
+                div {
+                    class: "text-xs mt-1",
+                    style: "color: rgba(239, 68, 68, 1);",
+                    "{err}"
+                }
+            }
+        }
+    }
+}
+
+/// 字段非空时才显示校验结果
+fn field_error(value: &str, check: impl Fn(&str) -> Result<String, String>) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        check(value).err()
+    }
+}
+
+/// 结构化银行收款信息表单
+#[component]
+pub fn BankDetailsForm(details: Signal<BankDetails>) -> Element {
+    let current = details.read().clone();
+    let schema = current.schema();
+    let country = current.country.clone();
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            div {
+                label {
+                    class: "block text-xs font-medium mb-1",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "收款国家/地区"
+                }
+                select {
+                    class: "w-full p-3 rounded-lg",
+                    style: input_style(),
+                    onchange: move |e| details.write().country = e.value(),
+                    if !PAYOUT_COUNTRIES.iter().any(|(code, _)| *code == country) {
+                        option { value: "{country}", selected: true, "{country}" }
+                    }
+                    for (code, name) in PAYOUT_COUNTRIES {
+                        option { value: code, selected: country == code, "{name} ({code})" }
+                    }
+                }
+            }
+            BankField {
+                label: "开户人姓名",
+                placeholder: "与银行账户一致的姓名",
+                value: current.holder_name.clone(),
+                error: None,
+                oninput: move |v: String| details.write().holder_name = v,
+            }
+            match schema {
+                BankSchema::Iban => rsx! {
+                    BankField {
+                        label: "IBAN",
+                        placeholder: "例: DE89 3704 0044 0532 0130 00",
+                        value: current.iban.clone(),
+                        error: field_error(&current.iban, |v| payout::validate_iban(v, &country)),
+                        oninput: move |v: String| details.write().iban = v,
+                    }
+                    BankField {
+                        label: "SWIFT/BIC（可选）",
+                        placeholder: "例: DEUTDEFF",
+                        value: current.swift_bic.clone(),
+                        error: field_error(&current.swift_bic, payout::validate_bic),
+                        oninput: move |v: String| details.write().swift_bic = v,
+                    }
+                },
+                BankSchema::UsAch => rsx! {
+                    BankField {
+                        label: "ABA 路由号",
+                        placeholder: "9 位数字",
+                        value: current.routing_number.clone(),
+                        error: field_error(&current.routing_number, payout::validate_aba_routing),
+                        oninput: move |v: String| details.write().routing_number = v,
+                    }
+                    BankField {
+                        label: "银行账号",
+                        placeholder: "4-17 位数字",
+                        value: current.account_number.clone(),
+                        error: field_error(&current.account_number, |v| {
+                            payout::validate_account_number(v, schema)
+                        }),
+                        oninput: move |v: String| details.write().account_number = v,
+                    }
+                },
+                BankSchema::Swift => rsx! {
+                    BankField {
+                        label: "SWIFT/BIC",
+                        placeholder: "例: BKCHCNBJ",
+                        value: current.swift_bic.clone(),
+                        error: field_error(&current.swift_bic, payout::validate_bic),
+                        oninput: move |v: String| details.write().swift_bic = v,
+                    }
+                    BankField {
+                        label: "银行账号",
+                        placeholder: "收款银行账号",
+                        value: current.account_number.clone(),
+                        error: field_error(&current.account_number, |v| {
+                            payout::validate_account_number(v, schema)
+                        }),
+                        oninput: move |v: String| details.write().account_number = v,
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 已保存的收款方式（仅显示当前提现方式的记录）
+#[component]
+pub fn SavedPayoutMethods(
+    methods: Signal<Vec<SavedPayoutMethod>>,
+    method: String,
+    on_select: EventHandler<SavedPayoutMethod>,
+) -> Element {
+    let saved: Vec<SavedPayoutMethod> = methods
+        .read()
+        .iter()
+        .filter(|m| m.method == method)
+        .cloned()
+        .collect();
+
+    if saved.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "space-y-2",
+            div {
+                class: "text-xs font-medium",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "已保存的收款方式"
+            }
+            for item in saved {
+                div {
+                    key: "{item.id}",
+                    class: "flex items-center justify-between gap-2 p-2 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+                    button {
+                        class: "flex-1 text-left text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        onclick: {
+                            let item = item.clone();
+                            move |_| on_select.call(item.clone())
+                        },
+                        "{item.masked()}"
+                        if item.is_default {
+                            span {
+                                class: "ml-2 px-2 py-0.5 rounded text-xs",
+                                style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                "默认"
+                            }
+                        }
+                    }
+                    if !item.is_default {
+                        button {
+                            class: "text-xs px-2 py-1 rounded",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            onclick: {
+                                let id = item.id.clone();
+                                move |_| {
+                                    let mut list = methods.write();
+                                    payout::set_default(&mut list, &id);
+                                    payout::store_saved_methods(&list);
+                                }
+                            },
+                            "设为默认"
+                        }
+                    }
+                    button {
+                        class: "text-xs px-2 py-1 rounded",
+                        style: "color: rgba(239, 68, 68, 1);",
+                        onclick: {
+                            let id = item.id.clone();
+                            move |_| {
+                                let mut list = methods.write();
+                                payout::remove_method(&mut list, &id);
+                                payout::store_saved_methods(&list);
+                            }
+                        },
+                        "删除"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Pending Swap Banner - 进行中的授权/兑换提示
+//! 兑换表单上方提示所选支付代币还有未确认的授权或兑换；同一交易对的兑换未完成时
+//! 默认阻止再次提交，用户可勾选“仍要提交”。记录在确认或失败后自动消失
+
+use crate::features::swap::history::{SwapHistoryStatus, PENDING_REFRESH_INTERVAL_MS};
+use crate::features::swap::pending::PendingKind;
+use crate::services::swap::SwapService;
+use crate::shared::design_tokens::Colors;
+use crate::shared::scheduler::{self, TickPolicy};
+use crate::shared::state::AppState;
+use crate::shared::time::now_unix;
+use dioxus::prelude::*;
+
+/// 查询进行中交易的状态，确认或失败的从记录中移除
+async fn refresh_pending(app_state: AppState) {
+    let mut pending_swaps = app_state.pending_swaps;
+    pending_swaps.write().prune(now_unix());
+    let swap_ids: Vec<String> = pending_swaps
+        .peek()
+        .items()
+        .iter()
+        .map(|item| item.swap_id.clone())
+        .collect();
+    let service = SwapService::new(app_state);
+    for swap_id in swap_ids {
+        match service.get_status(&swap_id).await {
+            Ok(status) => {
+                if SwapHistoryStatus::from_status(&status.status).is_some_and(|s| !s.is_in_flight())
+                {
+                    pending_swaps.write().resolve(&swap_id);
+                }
+            }
+            Err(e) => log::warn!("查询进行中兑换状态失败: swap_id={}, {}", swap_id, e),
+        }
+    }
+}
+
+/// 进行中的授权/兑换提示条
+#[component]
+pub fn PendingSwapBanner(
+    wallet_id: Option<String>,
+    network: String,
+    /// 表单当前的支付代币
+    from_token: Option<String>,
+    /// 同一交易对有兑换未完成
+    duplicate: bool,
+    /// 用户确认仍要提交同一交易对的兑换
+    allow_duplicate: Signal<bool>,
+    /// 打开兑换记录
+    on_view: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    // 页面刷新后原来的确认轮询已中断，由提示条继续查询
+    use_future(move || async move {
+        loop {
+            if !app_state.pending_swaps.peek().items().is_empty() {
+                refresh_pending(app_state).await;
+            }
+            scheduler::next_tick(TickPolicy::PauseWhenHidden, PENDING_REFRESH_INTERVAL_MS).await;
+        }
+    });
+
+    let (Some(wallet_id), Some(from_token)) = (wallet_id, from_token) else {
+        return VNode::empty();
+    };
+    let items = app_state
+        .pending_swaps
+        .read()
+        .for_token(&wallet_id, &network, &from_token);
+    if items.is_empty() {
+        return VNode::empty();
+    }
+
+    rsx! {
+        div {
+            class: "p-3 rounded-lg text-sm space-y-2",
+            style: format!("background: rgba(245, 158, 11, 0.1); border: 1px solid {};", Colors::PAYMENT_WARNING),
+            for item in items {
+                div {
+                    key: "{item.swap_id}",
+                    class: "flex items-center justify-between gap-2",
+                    span {
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        if item.kind == PendingKind::Swap {
+                            {format!("⏳ 您有一笔 {} → {} 兑换正在确认中", item.from_token, item.to_token)}
+                        } else {
+                            {format!("⏳ 您有一笔 {} {}正在确认中", item.from_token, item.kind.label())}
+                        }
+                    }
+                    button {
+                        class: "text-xs underline shrink-0",
+                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                        onclick: move |_| on_view.call(()),
+                        "查看"
+                    }
+                }
+            }
+            if duplicate {
+                label {
+                    class: "flex items-center gap-2 text-xs cursor-pointer",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    input {
+                        r#type: "checkbox",
+                        checked: allow_duplicate(),
+                        onchange: move |evt| {
+                            let mut allow_duplicate = allow_duplicate;
+                            allow_duplicate.set(evt.checked());
+                        },
+                    }
+                    "同一交易对的兑换尚未完成，为避免重复提交已暂停；确需再次兑换请勾选"
+                }
+            }
+        }
+    }
+}
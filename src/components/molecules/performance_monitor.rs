@@ -1,8 +1,12 @@
 //! Performance Monitor Component - 性能监控组件
-//! 显示页面性能指标和资源使用情况
+//! 显示页面性能指标和资源使用情况；`PerfOverlay` 为开发者性能浮层
 #![allow(dead_code)]
 
 use crate::shared::design_tokens::Colors;
+use crate::shared::feature_flags::use_feature;
+use crate::shared::logging::{self, LogCategory};
+use crate::shared::metrics::{self, MetricsSnapshot};
+use crate::shared::state::AppState;
 use dioxus::prelude::*;
 use js_sys::{Object, Reflect};
 use wasm_bindgen::{JsCast, JsValue};
@@ -17,6 +21,32 @@ pub struct PerformanceMetrics {
     pub cache_hit_rate: Option<f64>,    // 缓存命中率（%）
 }
 
+/// 分阶段加载耗时（如仪表盘的关键数据/次要数据/空闲预取）
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseTiming {
+    pub label: String,    // 阶段名称，如 "dashboard.critical"
+    pub duration_ms: f64, // 耗时（毫秒）
+    pub recorded_at: u64, // 记录时间（秒）
+}
+
+/// 最多保留的阶段耗时记录数
+const MAX_PHASE_TIMINGS: usize = 50;
+
+/// 记录一次阶段耗时，供 PerformanceMonitor 展示
+pub fn record_phase_timing(app_state: AppState, label: &str, duration_ms: f64) {
+    let mut timings_sig = app_state.phase_timings;
+    let mut timings = timings_sig.write();
+    timings.push(PhaseTiming {
+        label: label.to_string(),
+        duration_ms,
+        recorded_at: crate::shared::time::now_unix(),
+    });
+    if timings.len() > MAX_PHASE_TIMINGS {
+        let overflow = timings.len() - MAX_PHASE_TIMINGS;
+        timings.drain(0..overflow);
+    }
+}
+
 /// 性能监控组件属性
 #[derive(Props, PartialEq, Clone)]
 pub struct PerformanceMonitorProps {
@@ -31,6 +61,7 @@ pub struct PerformanceMonitorProps {
 /// 性能监控组件
 #[component]
 pub fn PerformanceMonitor(props: PerformanceMonitorProps) -> Element {
+    let app_state = use_context::<AppState>();
     #[allow(unused_mut)]
     let metrics = use_signal(|| PerformanceMetrics {
         page_load_time: None,
@@ -61,12 +92,14 @@ pub fn PerformanceMonitor(props: PerformanceMonitorProps) -> Element {
                         // 内存使用（如果支持）- Chrome特有的API
                         let memory = get_memory_usage(&window);
 
+                        let snapshot = metrics::snapshot();
+
                         metrics_clone.set(PerformanceMetrics {
                             page_load_time: page_load,
-                            render_time: None,
-                            api_response_time: None,
+                            render_time: snapshot.route_transition_p50_ms,
+                            api_response_time: weighted_p50(&snapshot),
                             memory_usage: memory,
-                            cache_hit_rate: None,
+                            cache_hit_rate: snapshot.cache_hit_rate,
                         });
                     }
 
@@ -102,6 +135,26 @@ pub fn PerformanceMonitor(props: PerformanceMonitorProps) -> Element {
                         }
                     }
                 }
+                if let Some(api_time) = metrics.read().api_response_time {
+                    div {
+                        class: "flex justify-between",
+                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "API响应(p50)" }
+                        span {
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{api_time:.0}ms"
+                        }
+                    }
+                }
+                if let Some(hit_rate) = metrics.read().cache_hit_rate {
+                    div {
+                        class: "flex justify-between",
+                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "缓存命中率" }
+                        span {
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{hit_rate:.0}%"
+                        }
+                    }
+                }
                 if let Some(memory) = metrics.read().memory_usage {
                     div {
                         class: "flex justify-between",
@@ -112,11 +165,223 @@ pub fn PerformanceMonitor(props: PerformanceMonitorProps) -> Element {
                         }
                     }
                 }
+                // 最近的分阶段加载耗时（每个阶段只显示最新一次）
+                for timing in latest_phase_timings(&app_state.phase_timings.read()) {
+                    div {
+                        class: "flex justify-between",
+                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{timing.label}" }
+                        span {
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {format!("{:.0}ms", timing.duration_ms)}
+                        }
+                    }
+                }
             }
         }
     }
 }
 
+/// 各端点 p50 按样本数加权平均
+fn weighted_p50(snapshot: &MetricsSnapshot) -> Option<f64> {
+    let total: usize = snapshot.endpoints.iter().map(|e| e.count).sum();
+    if total == 0 {
+        return None;
+    }
+    let weighted: f64 = snapshot
+        .endpoints
+        .iter()
+        .map(|e| e.p50_ms * e.count as f64)
+        .sum();
+    Some(weighted / total as f64)
+}
+
+/// 调试面板可选的日志级别
+const LOG_LEVEL_OPTIONS: [log::LevelFilter; 6] = [
+    log::LevelFilter::Off,
+    log::LevelFilter::Error,
+    log::LevelFilter::Warn,
+    log::LevelFilter::Info,
+    log::LevelFilter::Debug,
+    log::LevelFilter::Trace,
+];
+
+/// 开发者性能浮层（功能开关 `perf_overlay` 或 URL 参数 `?perf=1` 开启），附带日志级别调整
+#[component]
+pub fn PerfOverlay() -> Element {
+    let flag_enabled = use_feature("perf_overlay");
+    let enabled = use_signal(move || flag_enabled || metrics::overlay_requested());
+    let mut collapsed = use_signal(|| false);
+    let mut consent = use_signal(metrics::has_reporting_consent);
+    let mut snapshot = use_signal(MetricsSnapshot::default);
+    let mut log_levels = use_signal(|| LogCategory::ALL.map(logging::level));
+
+    use_future(move || async move {
+        if !enabled() {
+            return;
+        }
+        metrics::start_frame_sampler();
+        loop {
+            snapshot.set(metrics::snapshot());
+            gloo_timers::future::TimeoutFuture::new(2000).await;
+        }
+    });
+
+    if !enabled() {
+        return VNode::empty();
+    }
+
+    let snap = snapshot.read();
+    let fmt_ms = |v: Option<f64>| {
+        v.map(|v| format!("{:.0}ms", v))
+            .unwrap_or_else(|| "—".into())
+    };
+    let fmt_mb = |v: Option<f64>| {
+        v.map(|v| format!("{:.1}MB", v))
+            .unwrap_or_else(|| "—".into())
+    };
+    let dropped_ratio = if snap.frames_sampled > 0 {
+        format!(
+            "{} ({:.1}%)",
+            snap.dropped_frames,
+            snap.dropped_frames as f64 / (snap.frames_sampled + snap.dropped_frames) as f64 * 100.0
+        )
+    } else {
+        "—".to_string()
+    };
+    let rows = vec![
+        (
+            "路由切换 p50/p95",
+            format!(
+                "{} / {}",
+                fmt_ms(snap.route_transition_p50_ms),
+                fmt_ms(snap.route_transition_p95_ms)
+            ),
+        ),
+        ("首个余额", fmt_ms(snap.time_to_first_balance_ms)),
+        (
+            "缓存命中率",
+            snap.cache_hit_rate
+                .map(|r| format!("{:.0}%", r))
+                .unwrap_or_else(|| "—".into()),
+        ),
+        ("JS 堆", fmt_mb(snap.js_heap_mb)),
+        ("WASM 内存", fmt_mb(snap.wasm_memory_mb)),
+        ("掉帧", dropped_ratio),
+        ("Gas Limit 降级", snap.gas_limit_fallbacks.to_string()),
+        (
+            "代币元数据校正",
+            snap.token_metadata_corrections.to_string(),
+        ),
+    ];
+    let endpoints: Vec<_> = snap.endpoints.iter().take(6).cloned().collect();
+
+    rsx! {
+        div {
+            class: "fixed bottom-4 right-4 z-50 w-80 p-3 rounded-lg shadow-lg text-xs font-mono",
+            style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+            div {
+                class: "flex justify-between items-center",
+                span { class: "font-semibold", "⚡ Perf" }
+                button {
+                    class: "px-2",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    onclick: move |_| {
+                        let next = !collapsed();
+                        collapsed.set(next);
+                    },
+                    if collapsed() { "▲" } else { "▼" }
+                }
+            }
+            if !collapsed() {
+                div {
+                    class: "mt-2 space-y-1",
+                    for (label, value) in rows {
+                        div {
+                            class: "flex justify-between",
+                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{label}" }
+                            span { "{value}" }
+                        }
+                    }
+                }
+                if !endpoints.is_empty() {
+                    div {
+                        class: "mt-2 pt-2 space-y-1",
+                        style: format!("border-top: 1px solid {};", Colors::BORDER_PRIMARY),
+                        div {
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            "API p50 / p95 / p99"
+                        }
+                        for endpoint in endpoints {
+                            div {
+                                class: "flex justify-between gap-2",
+                                span { class: "truncate", title: "{endpoint.endpoint}", "{endpoint.endpoint}" }
+                                span {
+                                    class: "whitespace-nowrap",
+                                    {format!("{:.0}/{:.0}/{:.0}", endpoint.p50_ms, endpoint.p95_ms, endpoint.p99_ms)}
+                                }
+                            }
+                        }
+                    }
+                }
+                label {
+                    class: "mt-2 pt-2 flex items-center gap-2 cursor-pointer",
+                    style: format!("border-top: 1px solid {}; color: {};", Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY),
+                    input {
+                        r#type: "checkbox",
+                        checked: consent(),
+                        onchange: move |evt| {
+                            let checked = evt.checked();
+                            metrics::set_reporting_consent(checked);
+                            consent.set(checked);
+                        },
+                    }
+                    "匿名上报性能数据"
+                }
+                div {
+                    class: "mt-2 pt-2 space-y-1",
+                    style: format!("border-top: 1px solid {};", Colors::BORDER_PRIMARY),
+                    div {
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "日志级别"
+                    }
+                    for (index, category) in LogCategory::ALL.into_iter().enumerate() {
+                        div {
+                            key: "{category.as_str()}",
+                            class: "flex justify-between items-center",
+                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{category.label()}" }
+                            select {
+                                class: "bg-transparent text-xs",
+                                value: "{log_levels.read()[index]}",
+                                onchange: move |evt| {
+                                    if let Ok(level) = evt.value().parse::<log::LevelFilter>() {
+                                        logging::set_level(category, level);
+                                        log_levels.write()[index] = level;
+                                    }
+                                },
+                                for level in LOG_LEVEL_OPTIONS {
+                                    option { value: "{level}", "{level}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 每个阶段只保留最新一条记录，按首次出现顺序返回
+fn latest_phase_timings(timings: &[PhaseTiming]) -> Vec<PhaseTiming> {
+    let mut latest: Vec<PhaseTiming> = Vec::new();
+    for timing in timings {
+        match latest.iter_mut().find(|t| t.label == timing.label) {
+            Some(existing) => *existing = timing.clone(),
+            None => latest.push(timing.clone()),
+        }
+    }
+    latest
+}
+
 /// 获取页面加载时间（毫秒）
 /// 使用js_sys::Reflect访问performance API
 fn get_page_load_time(window: &web_sys::Window) -> Option<f64> {
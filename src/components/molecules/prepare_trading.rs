@@ -0,0 +1,209 @@
+//! Prepare Trading - 为交易做准备（预先授权）
+//! 新手引导中充值完成后的可选步骤：解释什么是授权，并提前为常用稳定币授权兑换路由合约，
+//! 之后第一次兑换就不会再多出一笔授权交易
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::molecules::{ChainSelector, ErrorMessage};
+use crate::features::swap::approvals::{self, ApprovalScope, SWAP_ROUTER_LABEL};
+use crate::features::wallet::chain_accounts::account_for_chain;
+use crate::features::wallet::state::Wallet;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainFeature;
+use crate::services::token::{TokenInfo, TokenService};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 预先授权面板；授权交易广播后（或用户跳过时）回调 `on_done`
+#[component]
+pub fn PrepareTradingPanel(wallet: Wallet, on_done: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let chain = use_signal(|| "ethereum".to_string());
+    let mut symbol = use_signal(|| approvals::STABLECOINS[0].to_string());
+    let mut scope = use_signal(ApprovalScope::default);
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut current_allowance = use_signal(|| Option::<String>::None);
+
+    let wallet_for_account = wallet.clone();
+    let account = use_memo(move || {
+        let chain = ChainType::from_str(&chain.read())?;
+        account_for_chain(&wallet_for_account, chain).cloned()
+    });
+
+    // 所选网络上的稳定币
+    let token = use_resource(move || async move {
+        let chain = ChainType::from_str(&chain())?;
+        let symbol = symbol();
+        TokenService::new(app_state)
+            .get_token_list(chain)
+            .await
+            .ok()?
+            .into_iter()
+            .find(|t| !t.is_native && t.symbol.eq_ignore_ascii_case(&symbol))
+    });
+
+    // 当前额度（已有额度时提示无需授权）
+    use_effect(move || {
+        let token = token.read().clone().flatten();
+        let account = account();
+        current_allowance.set(None);
+        let (Some(token), Some(account)) = (token, account) else {
+            return;
+        };
+        spawn(async move {
+            if let Ok(current) =
+                approvals::allowance(app_state, &token, &account.address, approvals::SWAP_ROUTER)
+                    .await
+            {
+                if !current.is_zero() {
+                    current_allowance
+                        .set(Some(approvals::allowance_label(&current, &token.symbol)));
+                }
+            }
+        });
+    });
+
+    let wallet_id = wallet.id.clone();
+    let approve = move |_| {
+        let (Some(token), Some(account)) = (token.read().clone().flatten(), account()) else {
+            error.set(Some("当前网络没有可授权的账户或代币".to_string()));
+            return;
+        };
+        let wallet_id = wallet_id.clone();
+        let scope = scope();
+        busy.set(true);
+        error.set(None);
+        spawn(async move {
+            match approvals::grant(app_state, &wallet_id, &account, &token, scope).await {
+                Ok(granted) => {
+                    AppState::show_success(
+                        app_state.toasts,
+                        format!(
+                            "授权交易已广播：{} 授权 {}，可在设置的代币授权中查看或撤销",
+                            granted.amount_label(),
+                            SWAP_ROUTER_LABEL
+                        ),
+                    );
+                    on_done.call(());
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            busy.set(false);
+        });
+    };
+
+    let token_ready: Option<TokenInfo> = token.read().clone().flatten();
+    let selected_symbol = symbol();
+
+    rsx! {
+        div {
+            class: "space-y-4 text-left",
+            div {
+                h4 {
+                    class: "font-semibold mb-1",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "为交易做准备（可选）"
+                }
+                p {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "用稳定币兑换其他代币前，需要先“授权”兑换路由合约动用这种代币——这是一笔单独的链上交易，需要支付少量网络费。现在完成授权，第一次兑换时就只需确认一笔交易。授权只允许路由合约在您发起兑换时转走代币，随时可以在设置中撤销。"
+                }
+            }
+
+            ChainSelector {
+                selected_chain: chain,
+                allowed_chains: Some(vec![ChainType::Ethereum, ChainType::BSC, ChainType::Polygon]),
+                feature: ChainFeature::Swap,
+                label: Some("网络".to_string()),
+            }
+
+            div {
+                class: "flex gap-2",
+                for s in approvals::STABLECOINS {
+                    button {
+                        key: "{s}",
+                        class: "flex-1 px-3 py-2 rounded-lg text-sm",
+                        style: if selected_symbol == s {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY)
+                        },
+                        disabled: busy(),
+                        onclick: move |_| symbol.set(s.to_string()),
+                        "{s}"
+                    }
+                }
+            }
+
+            div {
+                class: "space-y-2",
+                for option in ApprovalScope::ALL {
+                    label {
+                        key: "{option.label()}",
+                        class: "flex items-start gap-2 p-3 rounded-lg text-sm cursor-pointer",
+                        style: format!(
+                            "background: {}; border: 1px solid {};",
+                            Colors::BG_SECONDARY,
+                            if scope() == option { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY }
+                        ),
+                        input {
+                            r#type: "radio",
+                            class: "mt-1",
+                            name: "approval-scope",
+                            checked: scope() == option,
+                            onchange: move |_| scope.set(option),
+                        }
+                        div {
+                            div {
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "{option.label()}"
+                            }
+                            div {
+                                class: "text-xs mt-1",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                {option.description(&selected_symbol)}
+                            }
+                        }
+                    }
+                }
+            }
+
+            if account().is_none() {
+                p {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::PAYMENT_WARNING),
+                    "当前钱包没有该网络的账户"
+                }
+            }
+            if let Some(current) = current_allowance() {
+                p {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {format!("当前已授权 {}", current)}
+                }
+            }
+            ErrorMessage { message: error() }
+
+            div {
+                class: "flex gap-3",
+                Button {
+                    variant: ButtonVariant::Primary,
+                    size: ButtonSize::Medium,
+                    loading: busy(),
+                    disabled: busy() || token_ready.is_none() || account().is_none(),
+                    onclick: approve,
+                    {format!("发起授权交易：{} → {}", selected_symbol, SWAP_ROUTER_LABEL)}
+                }
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    size: ButtonSize::Medium,
+                    disabled: busy(),
+                    onclick: move |_| on_done.call(()),
+                    "跳过"
+                }
+            }
+        }
+    }
+}
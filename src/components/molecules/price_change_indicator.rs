@@ -65,6 +65,9 @@ pub fn PriceChangeIndicator(
     /// 是否显示动画
     #[props(default = true)]
     show_animation: bool,
+    /// 紧凑样式（列表行内使用，只显示箭头和百分比）
+    #[props(default)]
+    compact: bool,
 ) -> Element {
     let change_info = change_info.clone();
 
@@ -88,6 +91,16 @@ pub fn PriceChangeIndicator(
                 PriceChangeDirection::NoChange => return rsx! { div {} },
             };
 
+            if compact {
+                return rsx! {
+                    span {
+                        class: "text-xs font-medium px-2 py-0.5 rounded",
+                        style: format!("background: {}; color: {};", bg_color, text_color),
+                        {format!("{} {}", icon, info.format_change())}
+                    }
+                };
+            }
+
             rsx! {
                 div {
                     class: "flex items-center gap-2 px-3 py-2 rounded-lg transition-all",
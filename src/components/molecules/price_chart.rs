@@ -1,6 +1,8 @@
 //! Price Chart - 价格图表组件
 //! 显示代币价格走势图（基础版本）
 
+use crate::components::atoms::skeleton::SkeletonChart;
+use crate::services::price::PriceHistoryPoint;
 use crate::shared::design_tokens::Colors;
 use dioxus::prelude::*;
 
@@ -12,6 +14,16 @@ pub struct PriceDataPoint {
     pub volume: Option<f64>,
 }
 
+impl From<PriceHistoryPoint> for PriceDataPoint {
+    fn from(point: PriceHistoryPoint) -> Self {
+        Self {
+            timestamp: point.timestamp,
+            price: point.price,
+            volume: None,
+        }
+    }
+}
+
 /// 价格图表组件（基础版本）
 /// 使用SVG绘制简单的价格走势图
 #[component]
@@ -22,7 +34,16 @@ pub fn PriceChart(
     data: Vec<PriceDataPoint>,
     /// 时间范围（小时）
     time_range_hours: Option<u32>,
+    /// 数据加载中（显示与图表同尺寸的骨架）
+    #[props(default)]
+    loading: bool,
 ) -> Element {
+    if loading {
+        return rsx! {
+            SkeletonChart {}
+        };
+    }
+
     if data.is_empty() {
         return rsx! {
             div {
@@ -293,3 +314,81 @@ pub fn PriceChart(
         }
     }
 }
+
+/// 迷你走势线的 SVG 路径（价格按最小/最大值归一化到给定尺寸，少于两个点时为空）
+pub fn sparkline_path(prices: &[f64], width: f64, height: f64) -> String {
+    if prices.len() < 2 {
+        return String::new();
+    }
+    let min = prices.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = prices.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    let step = width / (prices.len() - 1) as f64;
+
+    let mut path = String::new();
+    for (i, price) in prices.iter().enumerate() {
+        let x = i as f64 * step;
+        // 价格不变时画在中线
+        let y = if range > 0.0 {
+            height - (price - min) / range * height
+        } else {
+            height / 2.0
+        };
+        path.push_str(&format!(
+            "{}{:.1} {:.1}",
+            if i == 0 { "M " } else { " L " },
+            x,
+            y
+        ));
+    }
+    path
+}
+
+/// 迷你走势线（列表行内使用，无坐标轴）
+#[component]
+pub fn Sparkline(
+    data: Vec<PriceDataPoint>,
+    #[props(default = 96)] width: u32,
+    #[props(default = 32)] height: u32,
+) -> Element {
+    let prices: Vec<f64> = data.iter().map(|p| p.price).collect();
+    let path_data = sparkline_path(&prices, width as f64, height as f64);
+    let color = match (prices.first(), prices.last()) {
+        (Some(first), Some(last)) if last < first => Colors::PAYMENT_ERROR,
+        _ => Colors::PAYMENT_SUCCESS,
+    };
+
+    rsx! {
+        svg {
+            width: "{width}",
+            height: "{height}",
+            view_box: format!("0 0 {} {}", width, height),
+            if !path_data.is_empty() {
+                path {
+                    d: "{path_data}",
+                    fill: "none",
+                    stroke: "{color}",
+                    stroke_width: "1.5",
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_path_spans_the_box() {
+        assert_eq!(sparkline_path(&[1.0], 100.0, 20.0), "");
+        assert_eq!(
+            sparkline_path(&[1.0, 3.0, 2.0], 100.0, 20.0),
+            "M 0.0 20.0 L 50.0 0.0 L 100.0 10.0"
+        );
+        assert_eq!(
+            sparkline_path(&[5.0, 5.0], 10.0, 20.0),
+            "M 0.0 10.0 L 10.0 10.0"
+        );
+    }
+}
@@ -1,13 +1,12 @@
 //! QR Code Display - 二维码显示组件
 //! 显示地址的二维码，支持复制功能
 
-use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::copy_button::CopyButton;
 use crate::shared::design_tokens::Colors;
 use crate::shared::security;
 use dioxus::prelude::*;
 use qrcode::render::svg;
 use qrcode::QrCode;
-use wasm_bindgen_futures;
 
 /// 生成二维码SVG
 fn generate_qr_code(data: &str) -> String {
@@ -30,25 +29,15 @@ fn generate_qr_code(data: &str) -> String {
     }
 }
 
-/// 复制到剪贴板
-async fn copy_to_clipboard(text: &str) -> Result<(), String> {
-    use web_sys::window;
-
-    let window = window().ok_or("No window")?;
-    let navigator = window.navigator();
-    let clipboard = navigator.clipboard();
-
-    wasm_bindgen_futures::JsFuture::from(clipboard.write_text(text))
-        .await
-        .map_err(|_| "Failed to copy to clipboard".to_string())?;
-
-    Ok(())
-}
-
 /// 二维码显示组件
+///
+/// `badge` 为网络标识（如 "BSC"），嵌在二维码边框上，截图分享时与地址一起出现
 #[component]
-pub fn QrCodeDisplay(address: String, show_copy_button: Option<bool>) -> Element {
-    let copy_success = use_signal(|| false);
+pub fn QrCodeDisplay(
+    address: String,
+    show_copy_button: Option<bool>,
+    badge: Option<String>,
+) -> Element {
     let show_copy = show_copy_button.unwrap_or(true);
 
     // 安全验证和清理地址
@@ -68,30 +57,20 @@ pub fn QrCodeDisplay(address: String, show_copy_button: Option<bool>) -> Element
     let address_for_qr = sanitized_address.clone();
     let qr_code_svg = use_memo(move || generate_qr_code(&address_for_qr));
 
-    // 复制地址到剪贴板（使用清理后的地址）
-    let address_for_copy = sanitized_address.clone();
-    let handle_copy = {
-        let address_clone = address_for_copy.clone();
-        move |_| {
-            let addr = address_clone.clone();
-            let mut success = copy_success;
-            spawn(async move {
-                if copy_to_clipboard(&addr).await.is_ok() {
-                    success.set(true);
-                    gloo_timers::future::TimeoutFuture::new(2000).await;
-                    success.set(false);
-                }
-            });
-        }
-    };
-
     rsx! {
         div {
             class: "flex flex-col items-center gap-6",
             // 二维码显示 - 增强视觉
             div {
-                class: "p-6 rounded-2xl shadow-xl",
+                class: "relative p-6 rounded-2xl shadow-xl",
                 style: format!("background: white; border: 3px solid {}; box-shadow: 0 20px 25px -5px rgba(0, 0, 0, 0.1), 0 10px 10px -5px rgba(0, 0, 0, 0.04);", Colors::TECH_PRIMARY),
+                if let Some(badge) = badge {
+                    div {
+                        class: "absolute -top-3 left-1/2 -translate-x-1/2 px-3 py-0.5 rounded-full text-xs font-bold whitespace-nowrap",
+                        style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                        "⛓️ {badge}"
+                    }
+                }
                 div {
                     class: "w-64 h-64",
                     dangerous_inner_html: qr_code_svg.read().clone(),
@@ -121,19 +100,10 @@ pub fn QrCodeDisplay(address: String, show_copy_button: Option<bool>) -> Element
                 }
 
                 if show_copy {
-                    button {
-                        class: "w-full py-4 px-6 rounded-xl font-semibold text-base transition-all duration-300 transform hover:scale-[1.02] active:scale-[0.98]",
-                        style: if *copy_success.read() {
-                            "background: linear-gradient(135deg, #10b981 0%, #059669 100%); color: white; box-shadow: 0 4px 12px rgba(16, 185, 129, 0.4);"
-                        } else {
-                            format!("background: linear-gradient(135deg, {} 0%, #4f46e5 100%); color: white; box-shadow: 0 4px 12px rgba(99, 102, 241, 0.4);", Colors::TECH_PRIMARY)
-                        },
-                        onclick: handle_copy,
-                        if *copy_success.read() {
-                            "✅ 已复制到剪贴板"
-                        } else {
-                            "📋 复制地址"
-                        }
+                    CopyButton {
+                        value: sanitized_address.clone(),
+                        label: "复制地址".to_string(),
+                        class: Some("w-full".to_string()),
                     }
                 }
             }
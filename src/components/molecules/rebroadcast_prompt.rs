@@ -0,0 +1,98 @@
+//! Rebroadcast Prompt - 交易未传播提示
+//! 广播后在节点中查不到交易时显示，由用户决定是否重新广播同一笔已签名交易
+
+use crate::services::transaction::TransactionService;
+use crate::services::tx_propagation::{PendingBroadcast, Propagation};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 交易可能未传播的提示（无待确认交易时不显示）
+#[component]
+pub fn RebroadcastPrompt(
+    pending: Signal<Option<PendingBroadcast>>,
+    /// 交易已被节点收到，或用户选择稍后查看
+    on_done: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut rebroadcasting = use_signal(|| false);
+
+    let Some(tx) = pending.read().clone() else {
+        return rsx! {};
+    };
+    let short_hash = if tx.tx_hash.len() > 16 {
+        format!(
+            "{}...{}",
+            &tx.tx_hash[..10],
+            &tx.tx_hash[tx.tx_hash.len() - 6..]
+        )
+    } else {
+        tx.tx_hash.clone()
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg text-sm space-y-3 mt-4",
+            style: format!("background: rgba(245, 158, 11, 0.1); border: 1px solid {};", Colors::PAYMENT_WARNING),
+            div {
+                class: "font-semibold",
+                style: format!("color: {};", Colors::PAYMENT_WARNING),
+                "⚠️ 交易可能未传播到网络，是否重新广播？"
+            }
+            p {
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {format!(
+                    "交易 {} 已提交，但 30 秒内在各节点中都查不到，可能已被节点丢弃。重新广播会发送同一笔已签名交易，不会重复扣款。",
+                    short_hash
+                )}
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                button {
+                    class: "px-3 py-1.5 rounded-lg font-medium",
+                    style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                    disabled: rebroadcasting(),
+                    onclick: move |_| {
+                        if *rebroadcasting.peek() {
+                            return;
+                        }
+                        let tx = tx.clone();
+                        let toasts = app_state.toasts;
+                        rebroadcasting.set(true);
+                        spawn(async move {
+                            let propagation = TransactionService::new(app_state).rebroadcast(&tx).await;
+                            rebroadcasting.set(false);
+                            match propagation {
+                                Err(e) => {
+                                    AppState::show_error(toasts, e.to_string());
+                                }
+                                Ok(Propagation::NotFound) => {
+                                    AppState::show_error(
+                                        toasts,
+                                        "仍未在节点中找到该交易，请稍后在交易记录中查看".to_string(),
+                                    );
+                                }
+                                Ok(_) => {
+                                    pending.set(None);
+                                    AppState::show_success(toasts, "交易已重新广播".to_string());
+                                    on_done.call(());
+                                }
+                            }
+                        });
+                    },
+                    if rebroadcasting() { "正在重新广播..." } else { "🔁 重新广播" }
+                }
+                button {
+                    class: "px-3 py-1.5 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                    disabled: rebroadcasting(),
+                    onclick: move |_| {
+                        pending.set(None);
+                        on_done.call(());
+                    },
+                    "稍后查看"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Route Preference Selector - 兑换路由偏好选择
+//! 设置页的全局默认值与兑换页高级设置中的单次覆盖共用
+
+use crate::features::swap::routing::RoutePreference;
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+/// 路由偏好选择（单选列表，附说明）
+#[component]
+pub fn RoutePreferenceSelector(
+    selected: RoutePreference,
+    onselect: EventHandler<RoutePreference>,
+) -> Element {
+    rsx! {
+        div {
+            class: "space-y-2",
+            role: "radiogroup",
+            for preference in RoutePreference::ALL {
+                button {
+                    key: "{preference.as_str()}",
+                    class: "w-full text-left p-3 rounded-lg",
+                    role: "radio",
+                    aria_checked: "{preference == selected}",
+                    style: format!(
+                        "background: {}; border: 1px solid {};",
+                        Colors::BG_PRIMARY,
+                        if preference == selected { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY }
+                    ),
+                    onclick: move |_| onselect.call(preference),
+                    div {
+                        class: "text-sm font-medium",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {preference.label()}
+                    }
+                    div {
+                        class: "text-xs mt-1",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {preference.description()}
+                    }
+                }
+            }
+        }
+    }
+}
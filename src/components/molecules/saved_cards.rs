@@ -0,0 +1,257 @@
+//! Saved Cards - 已保存的银行卡
+//! 支付弹窗中的一键选卡列表、新卡表单，以及设置中的已保存支付方式管理
+
+use crate::services::payment_gateway::{self, CardBrand, PaymentGatewayService, SavedCard};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+fn input_style() -> String {
+    format!(
+        "background: {}; border: 1px solid {}; color: {};",
+        Colors::BG_PRIMARY,
+        Colors::BORDER_PRIMARY,
+        Colors::TEXT_PRIMARY
+    )
+}
+
+fn row_style(active: bool) -> String {
+    format!(
+        "background: {}; border: 1px solid {};",
+        Colors::BG_PRIMARY,
+        if active {
+            Colors::TECH_PRIMARY
+        } else {
+            Colors::BORDER_PRIMARY
+        }
+    )
+}
+
+/// 加载已保存的银行卡（组件挂载时请求一次）
+pub fn use_saved_cards() -> Signal<Vec<SavedCard>> {
+    let app_state = use_context::<AppState>();
+    let mut cards = use_signal(Vec::<SavedCard>::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match PaymentGatewayService::new(app_state)
+                .list_saved_cards()
+                .await
+            {
+                Ok(list) => cards.set(list),
+                Err(e) => log::warn!("加载已保存的银行卡失败: {}", e),
+            }
+        });
+    });
+
+    cards
+}
+
+/// 已保存的银行卡列表
+///
+/// 传入 `selected` 时用于支付选卡（None 表示使用新卡，已过期的卡不可选）；
+/// 不传时仅用于管理。删除会在服务端吊销令牌。
+#[component]
+pub fn SavedCardList(
+    cards: Signal<Vec<SavedCard>>,
+    selected: Option<Signal<Option<String>>>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut deleting = use_signal(|| None::<String>);
+    let (now_year, now_month) = payment_gateway::current_year_month();
+    let list = cards.read().clone();
+    let current = selected.and_then(|s| s.read().clone());
+
+    let delete_card = move |card_id: String| {
+        if deleting.peek().is_some() {
+            return;
+        }
+        deleting.set(Some(card_id.clone()));
+        spawn(async move {
+            match PaymentGatewayService::new(app_state)
+                .delete_saved_card(&card_id)
+                .await
+            {
+                Ok(()) => {
+                    cards.write().retain(|card| card.id != card_id);
+                    if let Some(mut selected) = selected {
+                        if selected.peek().as_deref() == Some(card_id.as_str()) {
+                            selected.set(None);
+                        }
+                    }
+                    AppState::show_success(app_state.toasts, "银行卡已删除".to_string());
+                }
+                Err(e) => AppState::show_error(app_state.toasts, e),
+            }
+            deleting.set(None);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "space-y-2",
+            for card in list {
+                {
+                    let expired = card.is_expired_at(now_year, now_month);
+                    let active = current.as_deref() == Some(card.id.as_str());
+                    let is_deleting = deleting.read().as_deref() == Some(card.id.as_str());
+                    rsx! {
+                        div {
+                            key: "{card.id}",
+                            class: "flex items-center justify-between gap-2 p-3 rounded-lg",
+                            style: row_style(active),
+                            button {
+                                class: "flex-1 flex items-center gap-2 text-left text-sm",
+                                style: format!(
+                                    "color: {}; opacity: {};",
+                                    Colors::TEXT_PRIMARY,
+                                    if expired { "0.6" } else { "1" }
+                                ),
+                                disabled: expired || selected.is_none(),
+                                onclick: {
+                                    let id = card.id.clone();
+                                    move |_| {
+                                        if let Some(mut selected) = selected {
+                                            selected.set(Some(id.clone()));
+                                        }
+                                    }
+                                },
+                                if selected.is_some() {
+                                    span { if active { "◉" } else { "○" } }
+                                }
+                                span { "💳 {card.label()}" }
+                                span {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    "{card.expiry_label()}"
+                                }
+                                if expired {
+                                    span {
+                                        class: "px-2 py-0.5 rounded text-xs",
+                                        style: "background: rgba(239, 68, 68, 0.1); color: rgba(239, 68, 68, 1);",
+                                        "已过期"
+                                    }
+                                }
+                            }
+                            button {
+                                class: "text-xs px-2 py-1 rounded",
+                                style: "color: rgba(239, 68, 68, 1);",
+                                disabled: is_deleting,
+                                onclick: {
+                                    let id = card.id.clone();
+                                    let mut delete_card = delete_card;
+                                    move |_| delete_card(id.clone())
+                                },
+                                if is_deleting { "删除中..." } else { "删除" }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(mut selected) = selected {
+                button {
+                    class: "w-full flex items-center gap-2 p-3 rounded-lg text-left text-sm",
+                    style: format!("{} color: {};", row_style(current.is_none()), Colors::TEXT_PRIMARY),
+                    onclick: move |_| selected.set(None),
+                    span { if current.is_none() { "◉" } else { "○" } }
+                    span { "➕ 使用新卡" }
+                }
+            }
+        }
+    }
+}
+
+/// 新卡信息表单（卡号仅保存在内存中，提交时令牌化）
+#[component]
+pub fn CardForm(
+    card_number: Signal<String>,
+    card_expiry: Signal<String>,
+    card_cvv: Signal<String>,
+    card_holder_name: Signal<String>,
+) -> Element {
+    let brand = CardBrand::detect(&card_number.read());
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            div {
+                class: "relative",
+                input {
+                    class: "w-full p-3 rounded-lg",
+                    style: input_style(),
+                    r#type: "text",
+                    inputmode: "numeric",
+                    autocomplete: "cc-number",
+                    placeholder: "卡号",
+                    value: "{card_number.read()}",
+                    oninput: move |e| card_number.set(e.value()),
+                }
+                if brand != CardBrand::Unknown {
+                    span {
+                        class: "absolute right-3 top-3 text-xs",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "{brand.label()}"
+                    }
+                }
+            }
+            div {
+                class: "grid grid-cols-2 gap-3",
+                input {
+                    class: "w-full p-3 rounded-lg",
+                    style: input_style(),
+                    r#type: "text",
+                    autocomplete: "cc-exp",
+                    placeholder: "有效期 MM/YY",
+                    value: "{card_expiry.read()}",
+                    oninput: move |e| card_expiry.set(e.value()),
+                }
+                input {
+                    class: "w-full p-3 rounded-lg",
+                    style: input_style(),
+                    r#type: "password",
+                    inputmode: "numeric",
+                    autocomplete: "cc-csc",
+                    placeholder: "CVV",
+                    value: "{card_cvv.read()}",
+                    oninput: move |e| card_cvv.set(e.value()),
+                }
+            }
+            input {
+                class: "w-full p-3 rounded-lg",
+                style: input_style(),
+                r#type: "text",
+                autocomplete: "cc-name",
+                placeholder: "持卡人姓名",
+                value: "{card_holder_name.read()}",
+                oninput: move |e| card_holder_name.set(e.value()),
+            }
+        }
+    }
+}
+
+/// 已保存支付方式管理（设置页）
+#[component]
+pub fn SavedCardsManager() -> Element {
+    let cards = use_saved_cards();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "已保存的支付方式"
+            }
+            if cards.read().is_empty() {
+                div {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "暂无已保存的银行卡。支付成功后可选择保存，下次购买时一键使用。"
+                }
+            } else {
+                SavedCardList { cards, selected: None }
+            }
+        }
+    }
+}
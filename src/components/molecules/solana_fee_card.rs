@@ -0,0 +1,173 @@
+//! Solana Fee Card - Solana 交易费用卡片
+//! 显示基础费、按档位估算的优先费与总计；“高级”中可手动指定计算单元单价，
+//! 优先费超过设置的上限时按上限截断并提示
+
+use crate::blockchain::solana::{
+    ComputeBudget, BASE_FEE_LAMPORTS, LAMPORTS_PER_SOL, TRANSFER_COMPUTE_UNIT_LIMIT,
+};
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
+
+/// Solana 交易费用卡片
+///
+/// `budget` 为截断后的生效预算，`capped` 表示估算或手动单价超出了上限。
+#[component]
+pub fn SolanaFeeCard(
+    budget: Option<ComputeBudget>,
+    #[props(default)] capped: bool,
+    /// 优先费上限（lamports）
+    max_priority_fee_lamports: u64,
+    platform_fee: Option<f64>,
+    is_loading: bool,
+    /// 手动指定的计算单元单价（micro-lamports，None 表示使用档位估算）
+    unit_price_override: Signal<Option<u64>>,
+) -> Element {
+    let mut show_advanced = use_signal(|| unit_price_override.peek().is_some());
+    let mut price_input = use_signal(String::new);
+    let mut input_error = use_signal(|| None::<String>);
+
+    let row = |label: String, value: String| {
+        rsx! {
+            div {
+                class: "flex justify-between items-center",
+                span {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {label}
+                }
+                span {
+                    class: "text-sm font-semibold text-right",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {value}
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "mb-6",
+            label {
+                class: "block text-sm font-medium mb-2",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "交易费用明细"
+            }
+            if is_loading {
+                div {
+                    class: "p-4 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    div {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "正在估算优先费..."
+                    }
+                }
+            } else if let Some(budget) = budget {
+                div {
+                    class: "p-4 rounded-lg space-y-2",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    {row("基础费".to_string(), format!("{:.6} SOL", lamports_to_sol(BASE_FEE_LAMPORTS)))}
+                    {row(
+                        if unit_price_override().is_some() { "优先费（手动）".to_string() } else { "优先费".to_string() },
+                        format!(
+                            "{:.9} SOL（{} µ-lamports/CU）",
+                            lamports_to_sol(budget.priority_fee_lamports()),
+                            budget.unit_price_micro_lamports
+                        ),
+                    )}
+                    if let Some(fee) = platform_fee {
+                        {row("平台服务费".to_string(), format!("{:.6} SOL", fee))}
+                    }
+                    if capped {
+                        div {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::PAYMENT_WARNING),
+                            {format!(
+                                "优先费已按上限 {:.6} SOL 截断，拥堵时确认可能变慢",
+                                lamports_to_sol(max_priority_fee_lamports)
+                            )}
+                        }
+                    }
+                    div {
+                        class: "flex justify-between items-center pt-2 border-t",
+                        style: format!("border-color: {};", Colors::BORDER_PRIMARY),
+                        span {
+                            class: "text-sm font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "💰 总计"
+                        }
+                        span {
+                            class: "text-sm font-bold",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            {format!(
+                                "{:.9} SOL",
+                                lamports_to_sol(budget.total_fee_lamports()) + platform_fee.unwrap_or(0.0)
+                            )}
+                        }
+                    }
+
+                    // 高级：手动计算单元单价
+                    div {
+                        class: "pt-2 border-t",
+                        style: format!("border-color: {};", Colors::BORDER_PRIMARY),
+                        button {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            onclick: move |_| {
+                                let open = !show_advanced();
+                                show_advanced.set(open);
+                                if open {
+                                    price_input.set(budget.unit_price_micro_lamports.to_string());
+                                } else {
+                                    unit_price_override.set(None);
+                                    input_error.set(None);
+                                }
+                            },
+                            if show_advanced() { "▾ 高级（手动设置优先费）" } else { "▸ 高级（手动设置优先费）" }
+                        }
+                        if show_advanced() {
+                            label {
+                                class: "flex flex-col gap-1 mt-2 text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                {format!("计算单元单价（µ-lamports，上限 {} CU）", TRANSFER_COMPUTE_UNIT_LIMIT)}
+                                input {
+                                    class: "p-2 rounded text-sm",
+                                    style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                    r#type: "number",
+                                    min: "0",
+                                    step: "1",
+                                    value: "{price_input}",
+                                    oninput: move |e| {
+                                        let value = e.value();
+                                        price_input.set(value.clone());
+                                        match value.trim().parse::<u64>() {
+                                            Ok(price) => {
+                                                input_error.set(None);
+                                                unit_price_override.set(Some(price));
+                                            }
+                                            Err(_) => {
+                                                input_error.set(Some("请输入非负整数".to_string()));
+                                                unit_price_override.set(None);
+                                            }
+                                        }
+                                    },
+                                }
+                            }
+                            if let Some(err) = input_error() {
+                                div {
+                                    class: "text-xs mt-1",
+                                    style: format!("color: {};", Colors::PAYMENT_ERROR),
+                                    {err}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
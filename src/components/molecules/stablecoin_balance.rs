@@ -1,32 +1,143 @@
 //! Stablecoin Balance Card - 稳定币余额卡片组件
-//! 显示USDT和USDC余额，支持快速购买
+//! 汇总钱包所有链上的 USDT/USDC/DAI，各链分别加载、先到先显示；
+//! 点击总额展开各链明细，可直接跳转到预填该链的兑换、跨链与提现。
+//! 有挂单占用时同时显示可用/总额
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::skeleton::SkeletonText;
+use crate::components::molecules::StaleData;
+use crate::features::bridge::prefill::{self as bridge_prefill, BridgePrefill};
+use crate::features::swap::history::{self, SwapRetryRequest};
+use crate::features::wallet::token_preferences;
+use crate::router::Route;
 use crate::services::address_detector::ChainType;
-use crate::services::token::TokenService;
+use crate::services::balance::{
+    stablecoin_targets, AssetBalance, BalanceService, STABLECOINS, STABLECOIN_SYMBOLS,
+};
+use crate::services::chain_config::{ChainConfigManager, ChainFeature};
+use crate::services::token::TokenInfo;
 use crate::shared::design_tokens::Colors;
+use crate::shared::feature_flags::use_feature_flags;
 use crate::shared::state::AppState;
+use crate::shared::time::now_unix;
 use dioxus::prelude::*;
-use futures::join;
+
+/// 单条链的加载状态
+#[derive(Debug, Clone, PartialEq)]
+enum ChainLoad {
+    Loading,
+    Loaded(Vec<AssetBalance>),
+    Failed,
+}
+
+/// 单条链的稳定币余额
+#[derive(Debug, Clone, PartialEq)]
+struct ChainRow {
+    chain: ChainType,
+    address: String,
+    load: ChainLoad,
+    /// 最近一次成功加载的时间（Unix 秒）
+    fetched_at: Option<u64>,
+}
+
+impl ChainRow {
+    fn balances(&self) -> &[AssetBalance] {
+        match &self.load {
+            ChainLoad::Loaded(balances) => balances,
+            _ => &[],
+        }
+    }
+
+    fn amount(&self, symbol: &str) -> f64 {
+        self.balances()
+            .iter()
+            .filter(|b| b.symbol == symbol)
+            .map(|b| b.balance.to_f64_lossy())
+            .sum()
+    }
+
+    /// 该链上余额最多的稳定币（快捷操作默认使用）
+    fn top(&self) -> Option<&AssetBalance> {
+        self.balances()
+            .iter()
+            .filter(|b| !b.balance.is_zero())
+            .max_by(|a, b| {
+                a.balance
+                    .to_f64_lossy()
+                    .total_cmp(&b.balance.to_f64_lossy())
+            })
+    }
+}
+
+/// 加载单条链的余额（钱包切换后，旧的加载结果被丢弃）
+fn load_chain(
+    app_state: AppState,
+    mut rows: Signal<Vec<ChainRow>>,
+    generation: Signal<u32>,
+    chain: ChainType,
+    address: String,
+) {
+    let started = *generation.peek();
+    spawn(async move {
+        let result = BalanceService::new(app_state)
+            .get_stablecoin_balances(chain, &address)
+            .await;
+        if *generation.peek() != started {
+            return;
+        }
+        let mut rows = rows.write();
+        let Some(row) = rows.iter_mut().find(|r| r.chain == chain) else {
+            return;
+        };
+        match result {
+            Ok(balances) => {
+                row.load = ChainLoad::Loaded(balances);
+                row.fetched_at = Some(now_unix());
+            }
+            // 刷新失败时保留上次的余额（按过期数据展示）
+            Err(e) => {
+                log::warn!("{} 稳定币余额加载失败: {}", chain.label(), e);
+                if row.fetched_at.is_none() {
+                    row.load = ChainLoad::Failed;
+                }
+            }
+        }
+    });
+}
 
 /// 稳定币余额卡片组件
 #[component]
 pub fn StablecoinBalanceCard(
     /// 是否显示快速购买按钮
     show_buy_button: Option<bool>,
-    /// 当前链类型（可选，默认Ethereum）
+    /// 点击代币查看详情时使用的链（可选，默认Ethereum）
     chain: Option<ChainType>,
     /// 快速购买按钮点击回调
     on_buy_click: Option<EventHandler<()>>,
+    /// 兑换快捷操作回调（在兑换页内直接预填；未提供时跳转兑换页）
+    #[props(default)]
+    on_swap: Option<EventHandler<SwapRetryRequest>>,
 ) -> Element {
     let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let flags = use_feature_flags();
     let show_buy = show_buy_button.unwrap_or(true);
     let current_chain = chain.unwrap_or(ChainType::Ethereum);
 
-    // 稳定币余额状态
-    let usdt_balance = use_signal(|| 0.0f64);
-    let usdc_balance = use_signal(|| 0.0f64);
-    let loading = use_signal(|| true);
+    let mut rows = use_signal(Vec::<ChainRow>::new);
+    let mut generation = use_signal(|| 0u32);
+    let mut expanded = use_signal(|| false);
+    // 手动刷新与切回标签页时递增，触发重新加载
+    let mut reload = use_signal(|| 0u32);
+    let mut seen_resume = use_signal(|| *app_state.resume_tick.peek());
+    use_effect(move || {
+        let tick = (app_state.resume_tick)();
+        if tick != *seen_resume.peek() {
+            seen_resume.set(tick);
+            *reload.write() += 1;
+        }
+    });
 
     // 获取当前钱包
     let current_wallet = use_memo(move || {
@@ -34,104 +145,65 @@ pub fn StablecoinBalanceCard(
         wallet_state.get_selected_wallet().cloned()
     });
 
-    // 从钱包获取实际稳定币余额
-    use_effect({
-        let app_state_clone = app_state;
-        let chain_clone = current_chain;
-        let wallet_opt = current_wallet.read().clone();
-        let mut loading_mut = loading;
-        let mut usdt_mut = usdt_balance;
-        let mut usdc_mut = usdc_balance;
-
-        move || {
-            if wallet_opt.is_none() {
-                loading_mut.set(false);
-                return;
-            }
-
-            let wallet = wallet_opt.clone().unwrap();
-            let wallet_address = wallet
-                .accounts
-                .first()
-                .map(|a| a.address.clone())
-                .unwrap_or_default();
-            let app_state_for_spawn = app_state_clone;
-
-            spawn(async move {
-                loading_mut.set(true);
-
-                // 使用硬编码默认地址（简化实现，避免复杂的async嵌套）
-                // 如果需要动态获取，可以在组件初始化时预先获取
-                let usdt_address = match chain_clone {
-                    ChainType::Ethereum => "0xdAC17F958D2ee523a2206206994597C13D831ec7",
-                    ChainType::Polygon => "0xc2132D05D31c914a87C6611C10748AEb04B58e8F",
-                    ChainType::BSC => "0x55d398326f99059fF775485246999027B3197955",
-                    _ => "0xdAC17F958D2ee523a2206206994597C13D831ec7", // 默认Ethereum
-                }
-                .to_string();
-
-                let usdc_address = match chain_clone {
-                    ChainType::Ethereum => "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
-                    ChainType::Polygon => "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
-                    ChainType::BSC => "0x8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d",
-                    _ => "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", // 默认Ethereum
-                }
-                .to_string();
-
-                // 并行获取USDT和USDC余额
-                // 创建两个TokenService实例用于并行调用
-                let token_service1 = TokenService::new(app_state_for_spawn);
-                let token_service2 = TokenService::new(app_state_for_spawn);
-                let chain_clone1 = chain_clone;
-                let chain_clone2 = chain_clone;
-                let usdt_address_clone1 = usdt_address.clone();
-                let _usdt_address_clone2 = usdt_address.clone();
-                let _usdc_address_clone1 = usdc_address.clone();
-                let usdc_address_clone2 = usdc_address.clone();
-                let wallet_address_clone1 = wallet_address.clone();
-                let wallet_address_clone2 = wallet_address.clone();
-
-                let usdt_future = async move {
-                    token_service1
-                        .get_token_balance(
-                            chain_clone1,
-                            &usdt_address_clone1,
-                            &wallet_address_clone1,
-                        )
-                        .await
-                };
-
-                let usdc_future = async move {
-                    token_service2
-                        .get_token_balance(
-                            chain_clone2,
-                            &usdc_address_clone2,
-                            &wallet_address_clone2,
-                        )
-                        .await
-                };
-
-                let (usdt_result, usdc_result) = join!(usdt_future, usdc_future);
+    // 按链并行加载，每条链返回后立即更新；刷新同一钱包时保留旧余额直到新结果返回
+    use_effect(move || {
+        let _ = reload();
+        let targets = current_wallet()
+            .map(|wallet| stablecoin_targets(&wallet.accounts))
+            .unwrap_or_default();
+        let next = generation.peek().wrapping_add(1);
+        generation.set(next);
+        let same_targets = rows
+            .peek()
+            .iter()
+            .map(|r| (r.chain, r.address.as_str()))
+            .eq(targets
+                .iter()
+                .map(|(chain, address)| (*chain, address.as_str())));
+        if !same_targets {
+            rows.set(
+                targets
+                    .iter()
+                    .map(|(chain, address)| ChainRow {
+                        chain: *chain,
+                        address: address.clone(),
+                        load: ChainLoad::Loading,
+                        fetched_at: None,
+                    })
+                    .collect(),
+            );
+        }
+        for (chain, address) in targets {
+            load_chain(app_state, rows, generation, chain, address);
+        }
+    });
 
-                if let Ok(balance) = usdt_result {
-                    usdt_mut.set(balance.balance_formatted);
-                } else {
-                    usdt_mut.set(0.0);
-                }
+    // 挂单与进行中订单占用的数量
+    let committed = app_state.committed_balances.read().clone();
 
-                if let Ok(balance) = usdc_result {
-                    usdc_mut.set(balance.balance_formatted);
-                } else {
-                    usdc_mut.set(0.0);
-                }
+    let rows_now = rows.read().clone();
+    let loading_count = rows_now
+        .iter()
+        .filter(|r| r.load == ChainLoad::Loading)
+        .count();
+    let failed_count = rows_now
+        .iter()
+        .filter(|r| r.load == ChainLoad::Failed)
+        .count();
+    let all_loading = !rows_now.is_empty() && loading_count == rows_now.len();
+    let fetched_at = rows_now.iter().filter_map(|r| r.fetched_at).min();
+    let settled = loading_count == 0;
 
-                loading_mut.set(false);
-            });
-        }
-    });
+    // 各稳定币跨链合计（简化：1 USDT/USDC/DAI = 1 USD）
+    let totals: Vec<(&str, f64)> = STABLECOIN_SYMBOLS
+        .iter()
+        .map(|symbol| (*symbol, rows_now.iter().map(|r| r.amount(symbol)).sum()))
+        .collect();
+    let total_usd_value: f64 = totals.iter().map(|(_, amount)| amount).sum();
+    let has_stablecoins = total_usd_value > 0.0;
 
-    // 计算总法币价值（简化：1 USDT/USDC = 1 USD）
-    let total_usd_value = *usdt_balance.read() + *usdc_balance.read();
+    let registry = ChainConfigManager::new();
+    let flag_off = |key: &str| flags.read().is_switched_off(key);
 
     rsx! {
         div {
@@ -164,100 +236,231 @@ pub fn StablecoinBalanceCard(
                 }
             }
 
+            // 跨链总额（点击展开各链明细）
             div {
-                class: "grid grid-cols-2 gap-4",
-                // USDT余额卡片
+                class: "p-4 rounded-lg cursor-pointer",
+                style: format!("background: {};", Colors::BG_PRIMARY),
+                onclick: move |_| expanded.toggle(),
                 div {
-                    class: "p-4 rounded-lg",
-                    style: format!("background: {};", Colors::BG_PRIMARY),
-                    div {
-                        class: "text-sm font-medium mb-1",
+                    class: "flex items-center justify-between",
+                    span {
+                        class: "text-sm",
                         style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "USDT"
+                        {format!("总价值（{} 条链）", rows_now.len())}
                     }
-                    if loading() {
-                        div {
-                            class: "space-y-2",
-                            // 骨架屏加载效果
-                            div {
-                                class: "h-6 w-20 bg-gray-300 rounded animate-pulse",
-                            }
-                            div {
-                                class: "h-4 w-16 bg-gray-200 rounded animate-pulse",
-                            }
-                        }
-                    } else {
+                    span {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        if expanded() { "收起明细 ▴" } else { "各链明细 ▾" }
+                    }
+                }
+                if all_loading {
+                    div {
+                        class: "mt-2",
+                        SkeletonText { width: "8rem".to_string(), height: 32 }
+                    }
+                } else {
+                    StaleData {
+                        fetched_at,
+                        on_refresh: move |_| *reload.write() += 1,
                         div {
-                            class: "text-lg font-bold mb-1",
+                            class: "text-3xl font-bold mt-1",
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "{usdt_balance.read():.2}"
-                        }
-                        div {
-                            class: "text-xs",
-                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "≈ ${usdt_balance.read():.2}"
+                            MaskedAmount { value: format!("${:.2}", total_usd_value) }
                         }
                     }
                 }
-
-                // USDC余额卡片
-                div {
-                    class: "p-4 rounded-lg",
-                    style: format!("background: {};", Colors::BG_PRIMARY),
+                if loading_count > 0 && !all_loading {
                     div {
-                        class: "text-sm font-medium mb-1",
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "USDC"
+                        class: "text-xs mt-1",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        {format!("正在加载 {} 条链…", loading_count)}
+                    }
+                }
+                if failed_count > 0 {
+                    div {
+                        class: "text-xs mt-1",
+                        style: format!("color: {};", Colors::PAYMENT_WARNING),
+                        {format!("{} 条链加载失败，总额暂不包含这些链", failed_count)}
                     }
-                    if loading() {
+                }
+            }
+
+            // 各稳定币合计（点击查看代币详情）
+            div {
+                class: "grid grid-cols-3 gap-3 mt-4",
+                for (symbol, amount) in totals.iter().copied() {
+                    div {
+                        key: "{symbol}",
+                        class: "p-3 rounded-lg cursor-pointer",
+                        style: format!("background: {};", Colors::BG_PRIMARY),
+                        onclick: move |_| {
+                            navigator.push(Route::TokenDetail {
+                                chain: current_chain.as_str().to_string(),
+                                address: symbol.to_string(),
+                            });
+                        },
                         div {
-                            class: "space-y-2",
-                            // 骨架屏加载效果
+                            class: "text-sm font-medium mb-1",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            "{symbol}"
+                        }
+                        if all_loading {
+                            SkeletonText { width: "4rem".to_string(), height: 20 }
+                        } else {
                             div {
-                                class: "h-6 w-20 bg-gray-300 rounded animate-pulse",
+                                class: "text-base font-bold",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                MaskedAmount { value: format!("{:.2}", amount) }
                             }
-                            div {
-                                class: "h-4 w-16 bg-gray-200 rounded animate-pulse",
+                            if committed.is_committed(symbol) {
+                                div {
+                                    class: "text-xs mt-1",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "可用 "
+                                    MaskedAmount { value: format!("{:.2}", committed.available_f64(symbol, amount)) }
+                                    " / 总额 "
+                                    MaskedAmount { value: format!("{:.2}", amount) }
+                                }
                             }
                         }
-                    } else {
-                        div {
-                            class: "text-lg font-bold mb-1",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "{usdc_balance.read():.2}"
-                        }
-                        div {
-                            class: "text-xs",
-                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "≈ ${usdc_balance.read():.2}"
-                        }
                     }
                 }
             }
 
-            // 总价值显示
-            if !loading() && total_usd_value > 0.0 {
+            // 各链明细
+            if expanded() {
                 div {
-                    class: "mt-4 pt-4 border-t",
-                    style: format!("border-color: {};", Colors::BORDER_PRIMARY),
-                    div {
-                        class: "flex justify-between items-center",
-                        span {
-                            class: "text-sm",
-                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "总价值"
-                        }
-                        span {
-                            class: "text-lg font-bold",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "${total_usd_value:.2}"
+                    class: "mt-4 space-y-2",
+                    for row in rows_now.iter().cloned() {
+                        div {
+                            key: "{row.chain.as_str()}",
+                            class: "p-3 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+                            div {
+                                class: "flex items-center justify-between",
+                                span {
+                                    class: "text-sm font-medium",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "{row.chain.label()}"
+                                }
+                                match &row.load {
+                                    ChainLoad::Loading => rsx! {
+                                        SkeletonText { width: "5rem".to_string(), height: 16 }
+                                    },
+                                    ChainLoad::Failed => rsx! {
+                                        button {
+                                            class: "text-xs underline",
+                                            style: format!("color: {};", Colors::PAYMENT_WARNING),
+                                            onclick: {
+                                                let row = row.clone();
+                                                move |_| {
+                                                    if let Some(r) = rows.write().iter_mut().find(|r| r.chain == row.chain) {
+                                                        r.load = ChainLoad::Loading;
+                                                    }
+                                                    load_chain(app_state, rows, generation, row.chain, row.address.clone());
+                                                }
+                                            },
+                                            "加载失败，重试"
+                                        }
+                                    },
+                                    ChainLoad::Loaded(_) => rsx! {
+                                        span {
+                                            class: "text-sm font-semibold",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            MaskedAmount {
+                                                value: format!(
+                                                    "${:.2}",
+                                                    STABLECOIN_SYMBOLS.iter().map(|s| row.amount(s)).sum::<f64>()
+                                                )
+                                            }
+                                        }
+                                    },
+                                }
+                            }
+                            if let Some(top) = row.top().cloned() {
+                                div {
+                                    class: "text-xs mt-1",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    {
+                                        row.balances()
+                                            .iter()
+                                            .filter(|b| !b.balance.is_zero())
+                                            .map(|b| format!("{} {}", b.symbol, b.balance.format(2)))
+                                            .collect::<Vec<_>>()
+                                            .join(" · ")
+                                    }
+                                }
+                                div {
+                                    class: "flex flex-wrap gap-2 mt-2",
+                                    if registry.unavailable_reason(row.chain, ChainFeature::Swap, &flag_off).is_none() {
+                                        Button {
+                                            variant: ButtonVariant::Secondary,
+                                            size: ButtonSize::Small,
+                                            onclick: {
+                                                let request = SwapRetryRequest {
+                                                    from_token: top.symbol.clone(),
+                                                    to_token: row.chain.native_token_symbol().to_string(),
+                                                    amount: String::new(),
+                                                    network: Some(row.chain.as_str().to_string()),
+                                                };
+                                                move |_| {
+                                                    if let Some(handler) = on_swap {
+                                                        handler.call(request.clone());
+                                                    } else {
+                                                        history::set_pending_prefill(&request);
+                                                        navigator.push(Route::Swap {});
+                                                    }
+                                                }
+                                            },
+                                            "兑换"
+                                        }
+                                    }
+                                    if registry.unavailable_reason(row.chain, ChainFeature::Bridge, &flag_off).is_none() {
+                                        Button {
+                                            variant: ButtonVariant::Secondary,
+                                            size: ButtonSize::Small,
+                                            onclick: {
+                                                let prefill = BridgePrefill {
+                                                    from_chain: row.chain.as_str().to_string(),
+                                                    token: top.symbol.clone(),
+                                                    ..Default::default()
+                                                };
+                                                move |_| {
+                                                    bridge_prefill::set_pending_prefill(&prefill);
+                                                    navigator.push(Route::Bridge {});
+                                                }
+                                            },
+                                            "跨链"
+                                        }
+                                    }
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        size: ButtonSize::Small,
+                                        onclick: {
+                                            let token = stablecoin_token(row.chain, &top);
+                                            move |_| {
+                                                token_preferences::set_pending_sell_token(&token);
+                                                navigator.push(Route::Sell {});
+                                            }
+                                        },
+                                        "提现"
+                                    }
+                                }
+                            } else if matches!(row.load, ChainLoad::Loaded(_)) {
+                                div {
+                                    class: "text-xs mt-1",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "该链上暂无稳定币"
+                                }
+                            }
                         }
                     }
                 }
             }
 
-            // 余额不足提示（优化版）
-            if !loading() && total_usd_value == 0.0 {
+            // 余额不足提示（所有链加载完成后判断）
+            if settled && failed_count == 0 && !has_stablecoins {
                 div {
                     class: "mt-4 p-4 rounded-lg",
                     style: format!(
@@ -303,3 +506,22 @@ pub fn StablecoinBalanceCard(
         }
     }
 }
+
+/// 提现页预选的稳定币
+fn stablecoin_token(chain: ChainType, balance: &AssetBalance) -> TokenInfo {
+    let decimals = STABLECOINS
+        .iter()
+        .find(|(c, symbol, ..)| *c == chain && *symbol == balance.symbol)
+        .map(|(.., decimals)| *decimals)
+        .unwrap_or(6);
+    TokenInfo {
+        address: balance.token_address.clone().unwrap_or_default(),
+        symbol: balance.symbol.clone(),
+        name: balance.symbol.clone(),
+        decimals,
+        chain,
+        logo_url: None,
+        is_native: false,
+        is_spam: false,
+    }
+}
@@ -0,0 +1,89 @@
+//! Stale Data - 数据新鲜度提示
+//! 在价格、报价、余额下方显示「X秒前更新」；超过设置的过期时长或网络离线时
+//! 将内容变暗并显示刷新按钮
+
+use crate::shared::design_tokens::Colors;
+use crate::shared::freshness::Freshness;
+use crate::shared::state::AppState;
+use crate::shared::time::now_unix;
+use dioxus::prelude::*;
+
+/// 「X秒前更新」刷新间隔（毫秒）
+const TICK_MS: u32 = 5_000;
+
+/// 数据新鲜度包装组件
+///
+/// # 示例
+///
+/// ```rust
+/// rsx! {
+///     StaleData {
+///         fetched_at: quote_fetched_at(),
+///         on_refresh: move |_| refresh_quote(),
+///         span { "1 ETH = 3000 USDT" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn StaleData(
+    /// 数据查询时间（Unix 秒），None 表示尚未查询
+    fetched_at: Option<u64>,
+    /// 点击刷新
+    on_refresh: EventHandler<()>,
+    /// 自定义类名
+    #[props(default)]
+    class: Option<String>,
+    children: Element,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut now = use_signal(now_unix);
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(TICK_MS).await;
+            now.set(now_unix());
+        }
+    });
+
+    let freshness = Freshness::evaluate(
+        fetched_at,
+        now(),
+        app_state.preferences.read().stale_after_secs,
+        *app_state.is_online.read(),
+    );
+    let offline = !*app_state.is_online.read();
+
+    rsx! {
+        div {
+            class: class.unwrap_or_default(),
+            div {
+                class: "transition-opacity",
+                style: if freshness.stale { "opacity: 0.5;" } else { "" },
+                {children}
+            }
+            if !freshness.label.is_empty() || freshness.stale {
+                div {
+                    class: "flex items-center gap-2 mt-1 text-xs",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    if !freshness.label.is_empty() {
+                        span { {freshness.label.clone()} }
+                    }
+                    if freshness.stale {
+                        span {
+                            style: format!("color: {};", Colors::PAYMENT_WARNING),
+                            if offline { "· 网络离线" } else { "· 数据可能已过期" }
+                        }
+                        button {
+                            class: "underline",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            onclick: move |e: MouseEvent| {
+                                e.stop_propagation();
+                                on_refresh.call(());
+                            },
+                            "刷新"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
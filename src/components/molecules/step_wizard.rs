@@ -0,0 +1,315 @@
+//! Step Wizard - 分步表单组件
+//! 管理步骤状态、逐步校验与前进/返回导航，并自动渲染 ProcessSteps 指示器
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::molecules::process_steps::ProcessSteps;
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+/// 步骤定义（每次渲染按当前表单状态重新计算校验结果）
+#[derive(Clone, PartialEq, Debug)]
+pub struct WizardStep {
+    pub label: String,
+    /// 不能进入下一步的原因（None 表示本步已完成）
+    pub blocker: Option<String>,
+}
+
+impl WizardStep {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            blocker: None,
+        }
+    }
+
+    /// 设置本步的校验结果
+    pub fn blocked_by(mut self, blocker: Option<String>) -> Self {
+        self.blocker = blocker;
+        self
+    }
+}
+
+/// 步骤进度（从0开始）
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WizardProgress {
+    current: usize,
+    /// 已到达过的最远步骤，允许直接跳回其中任意一步
+    furthest: usize,
+    total: usize,
+}
+
+impl WizardProgress {
+    pub fn new(total: usize) -> Self {
+        Self {
+            current: 0,
+            furthest: 0,
+            total: total.max(1),
+        }
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_first(&self) -> bool {
+        self.current == 0
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.current + 1 == self.total
+    }
+
+    /// 前进一步（当前步骤校验未通过时不动）
+    pub fn next(&mut self, can_advance: bool) -> bool {
+        if !can_advance || self.is_last() {
+            return false;
+        }
+        self.current += 1;
+        self.furthest = self.furthest.max(self.current);
+        true
+    }
+
+    pub fn back(&mut self) {
+        self.current = self.current.saturating_sub(1);
+    }
+
+    /// 跳转到已到达过的步骤（如确认页的"修改"链接）
+    pub fn go_to(&mut self, step: usize) -> bool {
+        if step > self.furthest || step >= self.total {
+            return false;
+        }
+        self.current = step;
+        true
+    }
+
+    /// 之前的步骤变为未完成（如报价过期）时退回到该步骤
+    pub fn rewind_to_blocked(&mut self, first_blocked: Option<usize>) -> bool {
+        match first_blocked {
+            Some(step) if step < self.current => {
+                self.current = step;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(self.total);
+    }
+}
+
+/// 分步表单状态句柄
+#[derive(Clone, Copy, PartialEq)]
+pub struct StepWizardState {
+    progress: Signal<WizardProgress>,
+}
+
+impl StepWizardState {
+    pub fn current(&self) -> usize {
+        self.progress.read().current()
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.progress.read().is_last()
+    }
+
+    pub fn go_to(&mut self, step: usize) {
+        self.progress.write().go_to(step);
+    }
+
+    /// 提交成功后回到第一步
+    pub fn reset(&mut self) {
+        self.progress.write().reset();
+    }
+}
+
+/// 创建分步表单状态
+pub fn use_step_wizard(total: usize) -> StepWizardState {
+    StepWizardState {
+        progress: use_signal(|| WizardProgress::new(total)),
+    }
+}
+
+/// 分步表单组件
+///
+/// 调用方按 `state.current()` 渲染当前步骤内容；最后一步的提交按钮由调用方放在内容中。
+///
+/// # 示例
+///
+/// ```rust
+/// rsx! {
+///     StepWizard {
+///         state: wizard,
+///         steps: vec![
+///             WizardStep::new("金额").blocked_by(amount_field.error()),
+///             WizardStep::new("确认"),
+///         ],
+///         match wizard.current() {
+///             0 => rsx! { AmountStep {} },
+///             _ => rsx! { ReviewStep {} },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn StepWizard(
+    state: StepWizardState,
+    steps: Vec<WizardStep>,
+    /// 返回按钮文本
+    #[props(default = "上一步".to_string())]
+    back_label: String,
+    /// 下一步按钮文本
+    #[props(default = "下一步".to_string())]
+    next_label: String,
+    /// 当前步骤内容
+    children: Element,
+) -> Element {
+    let mut progress = state.progress;
+    let current = progress.read().current();
+    let is_first = progress.read().is_first();
+    let is_last = progress.read().is_last();
+    let blocker = steps.get(current).and_then(|s| s.blocker.clone());
+    let first_blocked = steps.iter().position(|s| s.blocker.is_some());
+
+    // 之前的步骤失效时退回（渲染期间不写信号，放到 effect 中执行）
+    use_effect(use_reactive!(|first_blocked| {
+        if progress.peek().current() > first_blocked.unwrap_or(usize::MAX) {
+            progress.write().rewind_to_blocked(first_blocked);
+        }
+    }));
+
+    rsx! {
+        div {
+            class: "space-y-4",
+            div {
+                class: "p-4 rounded-lg",
+                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                ProcessSteps {
+                    current_step: (current + 1) as u8,
+                    total_steps: steps.len() as u8,
+                    steps: steps.iter().map(|s| s.label.clone()).collect::<Vec<_>>(),
+                }
+            }
+
+            {children}
+
+            if !is_first || !is_last {
+                div {
+                    class: "flex items-center gap-3",
+                    if !is_first {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Medium,
+                            onclick: move |_| progress.write().back(),
+                            {format!("← {}", back_label)}
+                        }
+                    }
+                    if !is_last {
+                        div {
+                            class: "flex-1 flex flex-col items-end gap-1",
+                            Button {
+                                variant: ButtonVariant::Primary,
+                                size: ButtonSize::Medium,
+                                disabled: blocker.is_some(),
+                                onclick: {
+                                    let can_advance = blocker.is_none();
+                                    move |_| {
+                                        progress.write().next(can_advance);
+                                    }
+                                },
+                                {format!("{} →", next_label)}
+                            }
+                            if let Some(reason) = blocker.clone() {
+                                span {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "{reason}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 确认页中的一行摘要，带返回对应步骤修改的链接
+#[component]
+pub fn WizardReviewRow(
+    label: String,
+    value: String,
+    /// 点击"修改"时跳转的步骤
+    step: usize,
+    state: StepWizardState,
+    #[props(default = "修改".to_string())] edit_label: String,
+) -> Element {
+    let mut state = state;
+    rsx! {
+        div {
+            class: "flex items-center justify-between gap-3 py-2",
+            style: format!("border-bottom: 1px solid {};", Colors::BORDER_SECONDARY),
+            span {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "{label}"
+            }
+            div {
+                class: "flex items-center gap-3 min-w-0",
+                span {
+                    class: "text-sm font-medium truncate",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "{value}"
+                }
+                button {
+                    r#type: "button",
+                    class: "text-xs underline shrink-0",
+                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                    onclick: move |_| state.go_to(step),
+                    "{edit_label}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advances_only_when_step_is_valid() {
+        let mut progress = WizardProgress::new(4);
+        assert!(!progress.next(false));
+        assert_eq!(progress.current(), 0);
+        assert!(progress.next(true));
+        assert!(progress.next(true));
+        assert!(progress.next(true));
+        assert!(progress.is_last());
+        // 最后一步由调用方提交，不再前进
+        assert!(!progress.next(true));
+        progress.back();
+        assert_eq!(progress.current(), 2);
+    }
+
+    #[test]
+    fn jumps_only_to_reached_steps_and_rewinds_on_invalidation() {
+        let mut progress = WizardProgress::new(4);
+        assert!(!progress.go_to(2));
+        progress.next(true);
+        progress.next(true);
+        assert!(progress.go_to(0));
+        // 返回修改后仍可直接跳回已到达过的步骤
+        assert!(progress.go_to(2));
+        assert!(!progress.go_to(3));
+
+        assert!(progress.rewind_to_blocked(Some(1)));
+        assert_eq!(progress.current(), 1);
+        assert!(!progress.rewind_to_blocked(Some(3)));
+        assert!(!progress.rewind_to_blocked(None));
+
+        progress.reset();
+        assert_eq!(progress.current(), 0);
+        assert!(!progress.go_to(1));
+    }
+}
@@ -1,5 +1,5 @@
 //! Swap Confirm Dialog - 交换确认对话框组件
-//! 在用户执行交换前显示确认信息
+//! 在用户执行交换前显示确认信息；确认时复核余额与 gas，未通过时在对话框内显示差额
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::shared::design_tokens::Colors;
@@ -19,8 +19,12 @@ pub struct SwapConfirmInfo {
     pub gas_fee: Option<String>,
     /// 平台服务费：钱包服务商收取的服务费用（与Gas费用完全独立）
     pub platform_service_fee: Option<String>,
+    /// 客户端未能估算平台服务费（价格或费率服务不可用），由服务端在执行时计算
+    pub platform_fee_at_execution: bool,
     pub slippage: f64,
-    /// 是否需要先执行approval交易（企业级实现）
+    /// 扣除滑点后的最少收到数量（含代币符号）
+    pub min_received: Option<String>,
+    /// 是否需要先执行approval交易（按链上额度判断；未知时为 None）
     pub needs_approval: Option<bool>,
     /// 1inch路由器地址（用于前端显示和验证）
     pub router_address: Option<String>,
@@ -37,6 +41,12 @@ pub fn SwapConfirmDialog(
     on_confirm: Option<EventHandler<()>>,
     /// 取消回调
     on_cancel: Option<EventHandler<()>>,
+    /// 是否正在复核余额与 gas
+    #[props(default)]
+    rechecking: bool,
+    /// 复核未通过的原因（含差额）
+    #[props(default)]
+    recheck_error: Option<String>,
 ) -> Element {
     let show_val = *show.read();
     let info_opt = confirm_info.clone();
@@ -190,6 +200,13 @@ pub fn SwapConfirmDialog(
                                     }
                                 }
                             }
+                            if info.platform_fee_at_execution {
+                                p {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "服务费暂时无法预估，将在执行时按平台费率计算并在交易记录中显示"
+                                }
+                            }
                             div {
                                 class: "flex justify-between text-sm",
                                 span {
@@ -201,6 +218,19 @@ pub fn SwapConfirmDialog(
                                     "{info.slippage:.1}%"
                                 }
                             }
+                            if let Some(min_received) = info.min_received.clone() {
+                                div {
+                                    class: "flex justify-between text-sm",
+                                    span {
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        "最少收到"
+                                    }
+                                    span {
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        "{min_received}"
+                                    }
+                                }
+                            }
                             // 是否需要approval提示
                             if let Some(true) = info.needs_approval {
                                 div {
@@ -216,6 +246,16 @@ pub fn SwapConfirmDialog(
                                     }
                                 }
                             }
+                            if let Some(false) = info.needs_approval {
+                                div {
+                                    class: "flex items-center gap-2 text-xs mt-2",
+                                    span { "✅" }
+                                    span {
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        "已预先授权，本次兑换无需额外的授权交易"
+                                    }
+                                }
+                            }
                             // 路由器地址显示（用于验证）
                             if let Some(router) = &info.router_address {
                                 div {
@@ -240,6 +280,15 @@ pub fn SwapConfirmDialog(
                         }
                     }
 
+                    // 复核未通过
+                    if let Some(error) = recheck_error {
+                        div {
+                            class: "p-3 rounded-lg mb-3 text-sm",
+                            style: format!("background: rgba(239, 68, 68, 0.1); border: 1px solid {}; color: {};", Colors::PAYMENT_ERROR, Colors::PAYMENT_ERROR),
+                            "⛔ {error}"
+                        }
+                    }
+
                     // 警告提示
                     div {
                         class: "p-3 rounded-lg mb-6",
@@ -273,13 +322,14 @@ pub fn SwapConfirmDialog(
                         Button {
                             variant: ButtonVariant::Primary,
                             size: ButtonSize::Large,
+                            loading: rechecking,
                             onclick: move |_| {
                                 if let Some(handler) = on_confirm {
                                     handler.call(());
                                 }
                             },
                             class: "flex-1",
-                            "确认交换"
+                            if rechecking { "复核中..." } else { "确认交换" }
                         }
                     }
                 }
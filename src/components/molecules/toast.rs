@@ -1,11 +1,23 @@
 //! Toast - 全局提示组件
-//! 用于显示成功、错误、警告等信息提示
+//! 用于显示成功、错误、警告等信息提示；支持操作按钮、可按标识更新的进度提示、
+//! 相同内容去重、最多同时显示条数（其余排队）以及悬停暂停自动关闭
 #![allow(dead_code)]
 
+use crate::components::atoms::masked_amount::redact_amounts;
+use crate::router::Route;
 use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::state_updates;
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
 
+/// 最多同时显示的提示数量，超出的排队等待
+pub const MAX_VISIBLE: usize = 3;
+/// 去重窗口（毫秒）：窗口内相同内容的提示合并为一条
+pub const DEDUP_WINDOW_MS: f64 = 3000.0;
+/// 自动关闭计时的刷新间隔（毫秒）
+const TICK_MS: u32 = 100;
+
 /// Toast类型
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ToastType {
@@ -14,6 +26,53 @@ pub enum ToastType {
     #[allow(dead_code)] // 为未来扩展准备
     Warning,
     Info,
+    /// 进行中（不自动关闭，通常按标识更新为最终状态）
+    Progress,
+}
+
+/// 操作按钮的目标
+#[derive(Clone, PartialEq)]
+pub enum ToastActionTarget {
+    Route(Route),
+    Callback(Callback<()>),
+}
+
+/// Toast上的操作按钮（点击后执行并关闭提示）
+#[derive(Clone, PartialEq)]
+pub struct ToastAction {
+    pub label: String,
+    pub target: ToastActionTarget,
+}
+
+impl ToastAction {
+    /// 跳转到指定页面
+    pub fn route(label: impl Into<String>, route: Route) -> Self {
+        Self {
+            label: label.into(),
+            target: ToastActionTarget::Route(route),
+        }
+    }
+
+    /// 执行回调（需在组件作用域内创建）
+    pub fn callback(label: impl Into<String>, mut f: impl FnMut() + 'static) -> Self {
+        Self {
+            label: label.into(),
+            target: ToastActionTarget::Callback(Callback::new(move |_| f())),
+        }
+    }
+}
+
+impl std::fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let target = match &self.target {
+            ToastActionTarget::Route(route) => format!("{:?}", route),
+            ToastActionTarget::Callback(_) => "callback".to_string(),
+        };
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .field("target", &target)
+            .finish()
+    }
 }
 
 /// Toast消息
@@ -22,23 +81,144 @@ pub struct ToastMessage {
     pub id: u64,
     pub message: String,
     pub toast_type: ToastType,
-    pub duration: u32, // 显示时长（毫秒）
+    pub duration: u32, // 显示时长（毫秒），0 表示不自动关闭
+    /// 调用方指定的标识（进度提示按此更新同一条）
+    pub key: Option<String>,
+    pub action: Option<ToastAction>,
+    /// 去重窗口内相同内容出现的次数
+    pub count: u32,
+    /// 创建或最近一次更新的时间（毫秒）
+    pub updated_at: f64,
+    /// 已显示时长（毫秒，悬停时暂停累计）
+    pub elapsed: u32,
+    pub paused: bool,
+}
+
+impl ToastMessage {
+    pub fn new(id: u64, message: String, toast_type: ToastType, duration: u32, now: f64) -> Self {
+        Self {
+            id,
+            message,
+            toast_type,
+            duration,
+            key: None,
+            action: None,
+            count: 1,
+            updated_at: now,
+            elapsed: 0,
+            paused: false,
+        }
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn with_action(mut self, action: Option<ToastAction>) -> Self {
+        self.action = action;
+        self
+    }
+
+    fn is_expired(&self) -> bool {
+        self.duration > 0 && self.elapsed >= self.duration
+    }
+}
+
+/// 加入一条提示；去重窗口内已有相同内容时合并计数并重新计时，返回实际使用的 id
+pub fn push_toast(toasts: &mut Vec<ToastMessage>, toast: ToastMessage) -> u64 {
+    let duplicate = toasts.iter_mut().find(|t| {
+        t.key.is_none()
+            && toast.key.is_none()
+            && t.toast_type == toast.toast_type
+            && t.message == toast.message
+            && toast.updated_at - t.updated_at <= DEDUP_WINDOW_MS
+    });
+    if let Some(existing) = duplicate {
+        existing.count += 1;
+        existing.elapsed = 0;
+        existing.updated_at = toast.updated_at;
+        return existing.id;
+    }
+    let id = toast.id;
+    toasts.push(toast);
+    id
+}
+
+/// 按标识新增或更新提示（保留原 id 与位置，重新计时）
+pub fn upsert_keyed_toast(toasts: &mut Vec<ToastMessage>, toast: ToastMessage) -> u64 {
+    let existing = toast
+        .key
+        .as_ref()
+        .and_then(|key| toasts.iter_mut().find(|t| t.key.as_ref() == Some(key)));
+    match existing {
+        Some(existing) => {
+            existing.message = toast.message;
+            existing.toast_type = toast.toast_type;
+            existing.duration = toast.duration;
+            existing.action = toast.action;
+            existing.updated_at = toast.updated_at;
+            existing.elapsed = 0;
+            existing.id
+        }
+        None => {
+            let id = toast.id;
+            toasts.push(toast);
+            id
+        }
+    }
+}
+
+/// 推进显示中提示的计时（排队中与悬停中的不计时），移除到期的提示
+pub fn tick_toasts(toasts: &mut Vec<ToastMessage>, delta_ms: u32) {
+    for toast in toasts.iter_mut().take(MAX_VISIBLE) {
+        if !toast.paused && toast.duration > 0 {
+            toast.elapsed = toast.elapsed.saturating_add(delta_ms);
+        }
+    }
+    toasts.retain(|t| !t.is_expired());
 }
 
 /// Toast容器组件
 #[component]
 pub fn ToastContainer(messages: Signal<Vec<ToastMessage>>) -> Element {
+    // 统一计时：只在有提示时写入，避免空闲时重复渲染
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(TICK_MS).await;
+            if !messages.peek().is_empty() {
+                state_updates::enqueue(messages, |list: &mut Vec<ToastMessage>| {
+                    tick_toasts(list, TICK_MS)
+                });
+            }
+        }
+    });
+
+    let visible: Vec<ToastMessage> = messages.read().iter().take(MAX_VISIBLE).cloned().collect();
+    let queued = messages.read().len().saturating_sub(MAX_VISIBLE);
+
     rsx! {
         div {
             class: "fixed top-4 right-4 z-50 flex flex-col gap-2 max-w-md",
-            for message in messages.read().iter() {
+            for message in visible {
                 ToastItem {
                     key: "{message.id}",
                     message: message.clone(),
-                    on_close: move |id| {
-                        let mut msgs = messages.write();
-                        msgs.retain(|m| m.id != id);
-                    }
+                    on_close: move |id| AppState::remove_toast(messages, id),
+                    on_hover: move |(id, hovered): (u64, bool)| {
+                        state_updates::enqueue(messages, move |list: &mut Vec<ToastMessage>| {
+                            if let Some(toast) = list.iter_mut().find(|m| m.id == id) {
+                                toast.paused = hovered;
+                            }
+                        });
+                    },
+                }
+            }
+            if queued > 0 {
+                div {
+                    class: "text-xs text-right px-2",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    "还有 {queued} 条提示"
                 }
             }
         }
@@ -47,20 +227,21 @@ pub fn ToastContainer(messages: Signal<Vec<ToastMessage>>) -> Element {
 
 /// 单个Toast项
 #[component]
-fn ToastItem(message: ToastMessage, on_close: EventHandler<u64>) -> Element {
+fn ToastItem(
+    message: ToastMessage,
+    on_close: EventHandler<u64>,
+    on_hover: EventHandler<(u64, bool)>,
+) -> Element {
+    let navigator = use_navigator();
+    let app_state = use_context::<AppState>();
     let msg_id = message.id;
-    let msg_duration = message.duration;
     let toast_type = message.toast_type;
-    let msg_text = message.message.clone();
-
-    // 自动关闭
-    use_effect(move || {
-        let close_handler = on_close;
-        spawn(async move {
-            TimeoutFuture::new(msg_duration).await;
-            close_handler.call(msg_id);
-        });
-    });
+    // 隐私模式下不在提示中显示金额
+    let msg_text = if *app_state.privacy_mode.read() {
+        redact_amounts(&message.message)
+    } else {
+        message.message.clone()
+    };
 
     let bg_color = match toast_type {
         ToastType::Success => format!(
@@ -75,7 +256,7 @@ fn ToastItem(message: ToastMessage, on_close: EventHandler<u64>) -> Element {
             "background: rgba(251, 191, 36, 0.1); border: 1px solid {};",
             Colors::PAYMENT_WARNING
         ),
-        ToastType::Info => format!(
+        ToastType::Info | ToastType::Progress => format!(
             "background: rgba(59, 130, 246, 0.1); border: 1px solid {};",
             Colors::TECH_PRIMARY
         ),
@@ -85,7 +266,7 @@ fn ToastItem(message: ToastMessage, on_close: EventHandler<u64>) -> Element {
         ToastType::Success => Colors::PAYMENT_SUCCESS,
         ToastType::Error => Colors::PAYMENT_ERROR,
         ToastType::Warning => Colors::PAYMENT_WARNING,
-        ToastType::Info => Colors::TECH_PRIMARY,
+        ToastType::Info | ToastType::Progress => Colors::TECH_PRIMARY,
     };
 
     let icon = match toast_type {
@@ -93,21 +274,64 @@ fn ToastItem(message: ToastMessage, on_close: EventHandler<u64>) -> Element {
         ToastType::Error => "✕",
         ToastType::Warning => "⚠",
         ToastType::Info => "ℹ",
+        ToastType::Progress => "",
+    };
+
+    // 剩余显示时间比例（进度条）
+    let remaining_percent = if message.duration > 0 {
+        100.0 - (message.elapsed as f64 / message.duration as f64 * 100.0).min(100.0)
+    } else {
+        0.0
     };
 
     rsx! {
         div {
-            class: "p-4 rounded-lg shadow-lg backdrop-blur-sm animate-slide-in-right",
+            class: "relative overflow-hidden p-4 rounded-lg shadow-lg backdrop-blur-sm animate-slide-in-right",
             style: format!("{} color: {};", bg_color, text_color),
+            role: if toast_type == ToastType::Error { "alert" } else { "status" },
+            onmouseenter: move |_| on_hover.call((msg_id, true)),
+            onmouseleave: move |_| on_hover.call((msg_id, false)),
             div {
                 class: "flex items-start gap-3",
                 div {
                     class: "flex-shrink-0 text-xl font-bold",
-                    {icon}
+                    if toast_type == ToastType::Progress {
+                        div {
+                            class: "animate-spin rounded-full h-5 w-5 border-b-2 mt-0.5",
+                            style: format!("border-color: {};", text_color),
+                        }
+                    } else {
+                        {icon}
+                    }
                 }
                 div {
                     class: "flex-1",
                     {msg_text}
+                    if message.count > 1 {
+                        span {
+                            class: "ml-2 text-xs px-1.5 py-0.5 rounded-full",
+                            style: "background: rgba(0, 0, 0, 0.1);",
+                            "×{message.count}"
+                        }
+                    }
+                    if let Some(action) = message.action.clone() {
+                        div {
+                            class: "mt-2",
+                            button {
+                                class: "text-sm font-semibold underline hover:opacity-80",
+                                onclick: move |_| {
+                                    match &action.target {
+                                        ToastActionTarget::Route(route) => {
+                                            navigator.push(route.clone());
+                                        }
+                                        ToastActionTarget::Callback(callback) => callback.call(()),
+                                    }
+                                    on_close.call(msg_id);
+                                },
+                                {action.label.clone()}
+                            }
+                        }
+                    }
                 }
                 button {
                     class: "flex-shrink-0 text-lg opacity-70 hover:opacity-100 transition-opacity",
@@ -117,6 +341,58 @@ fn ToastItem(message: ToastMessage, on_close: EventHandler<u64>) -> Element {
                     "×"
                 }
             }
+            if message.duration > 0 {
+                div {
+                    class: "absolute bottom-0 left-0 h-0.5",
+                    style: format!("width: {remaining_percent}%; background: {text_color}; opacity: 0.5;"),
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toast(id: u64, message: &str, now: f64) -> ToastMessage {
+        ToastMessage::new(id, message.to_string(), ToastType::Success, 3000, now)
+    }
+
+    #[test]
+    fn deduplicates_within_window_and_updates_keyed_toasts() {
+        let mut toasts = Vec::new();
+        assert_eq!(push_toast(&mut toasts, toast(1, "已复制", 0.0)), 1);
+        assert_eq!(push_toast(&mut toasts, toast(2, "已复制", 1000.0)), 1);
+        assert_eq!(toasts.len(), 1);
+        assert_eq!(toasts[0].count, 2);
+        // 超出去重窗口后作为新提示
+        assert_eq!(push_toast(&mut toasts, toast(3, "已复制", 5000.0)), 3);
+        assert_eq!(toasts.len(), 2);
+
+        let signing = ToastMessage::new(10, "正在签名".into(), ToastType::Progress, 0, 0.0)
+            .with_key("swap:1");
+        let confirmed = ToastMessage::new(11, "已确认".into(), ToastType::Success, 3000, 10.0)
+            .with_key("swap:1");
+        assert_eq!(upsert_keyed_toast(&mut toasts, signing), 10);
+        assert_eq!(upsert_keyed_toast(&mut toasts, confirmed), 10);
+        assert_eq!(toasts.len(), 3);
+        assert_eq!(toasts[2].message, "已确认");
+        assert_eq!(toasts[2].toast_type, ToastType::Success);
+    }
+
+    #[test]
+    fn only_visible_unpaused_toasts_count_down() {
+        let mut toasts: Vec<ToastMessage> = (0..5).map(|i| toast(i, &i.to_string(), 0.0)).collect();
+        toasts[1].paused = true;
+        toasts[2].duration = 0; // 进度提示不自动关闭
+        tick_toasts(&mut toasts, 3000);
+        let ids: Vec<u64> = toasts.iter().map(|t| t.id).collect();
+        assert_eq!(ids, [1, 2, 3, 4]);
+        // 排队中的提示此前未计时，前移后才开始计时
+        assert_eq!(toasts[2].elapsed, 0);
+        tick_toasts(&mut toasts, 3000);
+        let ids: Vec<u64> = toasts.iter().map(|t| t.id).collect();
+        assert_eq!(ids, [1, 2, 4]);
+    }
+}
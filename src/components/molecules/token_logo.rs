@@ -0,0 +1,105 @@
+//! Token Logo - 代币图标
+//! 按来源顺序加载图标，加载失败时依次回退，最终显示由合约地址生成的标识图
+
+use crate::features::wallet::token_preferences::token_key;
+use crate::services::token::TokenInfo;
+use crate::services::token_logo::{self, identicon_data_uri, metadata_logo, static_sources};
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 代币图标（疑似垃圾代币始终显示标识图）
+#[component]
+pub fn TokenLogo(token: TokenInfo, #[props(default = 40)] size: u32) -> Element {
+    let app_state = use_context::<AppState>();
+    // 本次会话中加载失败的图标链接
+    let mut failed = use_signal(Vec::<String>::new);
+    // 链上元数据查到的图标（按代币键，None 表示没有）
+    let mut metadata = use_signal(HashMap::<String, Option<String>>::new);
+
+    let key = token_key(&token);
+    let identicon = identicon_data_uri(if token.address.is_empty() {
+        &token.symbol
+    } else {
+        &token.address
+    });
+    let src = if token.is_spam {
+        None
+    } else {
+        let failed = failed.read();
+        match token_logo::cached(&key) {
+            Some(Some(url)) if !failed.contains(&url) => Some(url),
+            Some(None) => None,
+            _ => static_sources(&token)
+                .into_iter()
+                .chain(metadata.read().get(&key).cloned().flatten())
+                .find(|url| !failed.contains(url)),
+        }
+    };
+    let style = format!(
+        "width: {size}px; height: {size}px; background: url(\"{identicon}\") center / cover;"
+    );
+
+    let Some(url) = src else {
+        return rsx! {
+            img {
+                src: identicon,
+                alt: token.symbol.clone(),
+                class: "rounded-full shadow-md shrink-0",
+                style: format!("width: {size}px; height: {size}px;"),
+            }
+        };
+    };
+
+    let on_load = {
+        let key = key.clone();
+        let url = url.clone();
+        move |_| {
+            if token_logo::cached(&key) != Some(Some(url.clone())) {
+                token_logo::remember(&key, Some(url.clone()));
+            }
+        }
+    };
+    let on_error = {
+        let token = token.clone();
+        let url = url.clone();
+        move |_| {
+            failed.write().push(url.clone());
+            let exhausted = static_sources(&token)
+                .iter()
+                .all(|source| failed.peek().contains(source));
+            if !exhausted {
+                return;
+            }
+            if metadata.peek().contains_key(&key) {
+                // 链上元数据的图标也加载失败
+                token_logo::remember(&key, None);
+                return;
+            }
+            let token = token.clone();
+            let key = key.clone();
+            spawn(async move {
+                let logo = metadata_logo(app_state, &token).await;
+                if logo.is_none() {
+                    token_logo::remember(&key, None);
+                }
+                metadata.write().insert(key, logo);
+            });
+        }
+    };
+
+    rsx! {
+        img {
+            key: "{url}",
+            src: url.clone(),
+            alt: token.symbol.clone(),
+            class: "rounded-full shadow-md shrink-0 object-cover",
+            style,
+            loading: "lazy",
+            decoding: "async",
+            referrerpolicy: "no-referrer",
+            onload: on_load,
+            onerror: on_error,
+        }
+    }
+}
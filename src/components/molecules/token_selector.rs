@@ -1,14 +1,66 @@
 //! Token Selector - 企业级代币选择器组件
-//! 提供代币选择、搜索、余额显示等功能
+//! 提供代币搜索、收藏置顶、最近选择、按持有价值排序与余额显示；
+//! 列表按固定行高虚拟滚动，支持 ↑/↓/Enter/Esc 键盘操作
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::input::{Input, InputType};
+use crate::components::atoms::masked_amount::MaskedAmount;
 use crate::components::atoms::modal::Modal;
+use crate::components::atoms::skeleton::SkeletonTableRow;
+use crate::components::molecules::token_logo::TokenLogo;
+use crate::components::molecules::token_visibility_manager::TokenVisibilityManager;
+use crate::features::swap::wrap;
+use crate::features::wallet::token_preferences::{
+    matches_query, token_key, TokenPreferences, TokenRanking,
+};
 use crate::services::address_detector::ChainType;
+use crate::services::price::PriceService;
 use crate::services::token::{TokenInfo, TokenService};
+use crate::services::token_metadata::TokenMetadataService;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 列表行高（像素），虚拟滚动按固定行高计算可见区间
+const ROW_HEIGHT: f64 = 72.0;
+/// 列表可视高度（像素）
+const LIST_HEIGHT: f64 = 360.0;
+/// 可见区间上下额外渲染的行数
+const OVERSCAN: usize = 4;
+/// 低于该数量的持仓视为零余额（粉尘）
+const DUST_THRESHOLD: f64 = 0.0001;
+const LIST_ELEMENT_ID: &str = "token-selector-list";
+
+/// 当前滚动位置下需要渲染的行区间 [start, end)
+fn visible_range(scroll_top: f64, len: usize) -> (usize, usize) {
+    let first = (scroll_top.max(0.0) / ROW_HEIGHT) as usize;
+    let visible = (LIST_HEIGHT / ROW_HEIGHT).ceil() as usize;
+    let start = first.saturating_sub(OVERSCAN).min(len);
+    let end = (first + visible + OVERSCAN).min(len);
+    (start, end)
+}
+
+/// 键盘移动高亮行后，必要时滚动列表使其可见
+fn scroll_row_into_view(index: usize, scroll_top: f64) -> Option<f64> {
+    let row_top = index as f64 * ROW_HEIGHT;
+    if row_top < scroll_top {
+        Some(row_top)
+    } else if row_top + ROW_HEIGHT > scroll_top + LIST_HEIGHT {
+        Some(row_top + ROW_HEIGHT - LIST_HEIGHT)
+    } else {
+        None
+    }
+}
+
+fn set_list_scroll_top(top: f64) {
+    if let Some(element) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(LIST_ELEMENT_ID))
+    {
+        element.set_scroll_top(top as i32);
+    }
+}
 
 /// 代币选择器组件
 #[component]
@@ -17,22 +69,33 @@ pub fn TokenSelector(
     chain: ChainType,
     /// 当前选择的代币（Signal）
     selected_token: Signal<Option<TokenInfo>>,
-    /// 钱包地址（用于显示余额）
+    /// 钱包地址（用于显示余额；传入时视为支付方，选中零余额代币会提示）
     wallet_address: Option<String>,
 ) -> Element {
     let app_state = use_context::<AppState>();
-    let show_modal = use_signal(|| false);
+    let mut show_modal = use_signal(|| false);
     let mut search_query = use_signal(String::new);
     let tokens = use_signal(Vec::<TokenInfo>::new);
     let loading = use_signal(|| false);
     let error = use_signal(|| Option::<String>::None);
-    let token_balances = use_signal(std::collections::HashMap::<String, f64>::new);
+    let token_balances = use_signal(HashMap::<String, f64>::new);
+    let token_prices = use_signal(HashMap::<String, f64>::new);
+    let mut prefs = use_signal(TokenPreferences::load);
+    let mut scroll_top = use_signal(|| 0.0f64);
+    let mut highlighted = use_signal(|| 0usize);
+    let mut show_manager = use_signal(|| false);
+    let wallet_id = use_memo(move || {
+        app_state
+            .wallet
+            .read()
+            .selected_wallet_id
+            .clone()
+            .unwrap_or_default()
+    });
 
-    // ✅ 克隆 wallet_address 用于多处使用（因为 Option<String> 不实现 Copy）
     let has_wallet = wallet_address.is_some();
 
-    // ✅ 智能代币加载：从钱包中获取有余额的代币，而不是硬编码所有代币
-    // 如果没有钱包地址，则fallback到加载默认代币列表
+    // ✅ 代币加载：有钱包地址时一并加载余额（批量查询）与持仓代币价格，用于排序
     // 🔧 修复：明确追踪 chain 和 wallet_address 的变化
     use_effect(move || {
         let app_state_clone = app_state;
@@ -42,134 +105,182 @@ pub fn TokenSelector(
         let mut loading_mut = loading;
         let mut error_mut = error;
         let mut balances_mut = token_balances;
+        let mut prices_mut = token_prices;
 
         spawn(async move {
             loading_mut.set(true);
             error_mut.set(None);
 
             let token_service = TokenService::new(app_state_clone);
+            let token_list = match token_service.get_token_list(chain_clone).await {
+                Ok(list) => list,
+                Err(e) => {
+                    #[cfg(debug_assertions)]
+                    log::error!("TokenSelector - API error: {}", e);
+
+                    error_mut.set(Some(crate::shared::ui_error::sanitize_user_message(
+                        format!("加载代币列表失败: {}", e),
+                    )));
+                    loading_mut.set(false);
+                    return;
+                }
+            };
 
-            // ✅ 智能策略：优先从钱包余额中获取代币
-            if let Some(ref wallet_addr) = wallet_opt_clone {
-                // 1. 获取钱包账户信息（包含原生代币余额）
-                let wallet_state = app_state_clone.wallet.read();
-                let mut tokens_with_balance = Vec::new();
-                let mut balances_map = std::collections::HashMap::new();
-
-                // 2. 添加当前链的原生代币（如果有余额）
-                if let Some(wallet) = wallet_state.get_selected_wallet() {
-                    if let Some(account) = wallet
+            let Some(wallet_addr) = wallet_opt_clone else {
+                // ✅ 没有钱包地址：显示所有可交换代币（用于交换页面的To选择器）
+                tokens_mut.set(token_list);
+                balances_mut.set(HashMap::new());
+                loading_mut.set(false);
+                return;
+            };
+
+            let mut all_tokens = Vec::with_capacity(token_list.len() + 1);
+            let mut balances_map = HashMap::new();
+
+            // 原生代币始终显示，余额取自钱包账户
+            let native_balance = app_state_clone
+                .wallet
+                .read()
+                .get_selected_wallet()
+                .and_then(|wallet| {
+                    wallet
                         .accounts
                         .iter()
-                        .find(|acc| acc.address.to_lowercase() == wallet_addr.to_lowercase())
-                    {
-                        // 原生代币始终显示
-                        let native_token = TokenInfo {
-                            address: "0x0000000000000000000000000000000000000000".to_string(),
-                            symbol: chain_clone.native_token_symbol().to_string(),
-                            name: format!("{} Native Token", chain_clone.label()),
-                            decimals: 18,
-                            chain: chain_clone,
-                            logo_url: None,
-                            is_native: true,
-                        };
-
-                        // 解析余额（从字符串转换为f64）
-                        let balance = account.balance.parse::<f64>().unwrap_or(0.0);
-                        if balance > 0.0 {
-                            balances_map.insert(native_token.address.clone(), balance);
-                        }
-                        tokens_with_balance.push(native_token);
-                    }
+                        .find(|acc| acc.address.eq_ignore_ascii_case(&wallet_addr))
+                        .map(|acc| acc.balance.parse::<f64>().unwrap_or(0.0))
+                });
+            if let Some(balance) = native_balance {
+                let native_token = TokenInfo {
+                    address: "0x0000000000000000000000000000000000000000".to_string(),
+                    symbol: chain_clone.native_token_symbol().to_string(),
+                    name: format!("{} Native Token", chain_clone.label()),
+                    decimals: 18,
+                    chain: chain_clone,
+                    logo_url: None,
+                    is_native: true,
+                    is_spam: false,
+                };
+                if balance > DUST_THRESHOLD {
+                    balances_map.insert(native_token.address.clone(), balance);
                 }
+                all_tokens.push(native_token);
+            }
 
-                // 3. 获取所有ERC-20代币并过滤有余额的
-                match token_service.get_token_list(chain_clone).await {
-                    Ok(all_tokens) => {
-                        for token in all_tokens {
-                            if !token.is_native {
-                                // 查询ERC-20代币余额
-                                if let Ok(balance_info) = token_service
-                                    .get_token_balance(chain_clone, &token.address, wallet_addr)
-                                    .await
-                                {
-                                    // ✅ 只添加有余额的代币（大于0.0001）
-                                    if balance_info.balance_formatted > 0.0001 {
-                                        balances_map.insert(
-                                            token.address.clone(),
-                                            balance_info.balance_formatted,
-                                        );
-                                        tokens_with_balance.push(token);
-                                    }
-                                }
-                            }
+            let erc20: Vec<TokenInfo> = token_list.into_iter().filter(|t| !t.is_native).collect();
+            let addresses: Vec<String> = erc20.iter().map(|t| t.address.clone()).collect();
+            match token_service
+                .get_token_balances_batch(chain_clone, &wallet_addr, &addresses)
+                .await
+            {
+                Ok(balances) => {
+                    for b in balances {
+                        if b.balance_formatted > DUST_THRESHOLD {
+                            balances_map.insert(b.token.address.clone(), b.balance_formatted);
                         }
                     }
-                    Err(e) => {
-                        error_mut.set(Some(crate::shared::ui_error::sanitize_user_message(
-                            format!("加载代币列表失败: {}", e),
-                        )));
-                    }
                 }
-
-                tokens_mut.set(tokens_with_balance);
-                balances_mut.set(balances_map);
-            } else {
-                // ✅ 没有钱包地址：显示所有可交换代币（用于交换页面的To选择器）
-                #[cfg(debug_assertions)]
+                Err(e) => log::warn!("批量查询代币余额失败: {}", e),
+            }
+            all_tokens.extend(erc20);
+
+            // 持仓代币的价格（按持有价值排序）
+            let held_symbols: Vec<String> = all_tokens
+                .iter()
+                .filter(|t| balances_map.contains_key(&t.address))
+                .map(|t| t.symbol.clone())
+                .collect();
+            if !held_symbols.is_empty() {
+                let symbols: Vec<&str> = held_symbols.iter().map(String::as_str).collect();
+                match PriceService::new(app_state_clone)
+                    .get_prices(&symbols)
+                    .await
                 {
-                    use tracing::info;
-                    info!("TokenSelector - Loading all tokens (no wallet address provided)");
-                }
-
-                match token_service.get_token_list(chain_clone).await {
-                    Ok(token_list) => {
-                        #[cfg(debug_assertions)]
-                        {
-                            use tracing::info;
-                            info!(
-                                "TokenSelector - Loaded {} tokens for selection",
-                                token_list.len()
-                            );
-                        }
-                        tokens_mut.set(token_list);
-                    }
-                    Err(e) => {
-                        error_mut.set(Some(crate::shared::ui_error::sanitize_user_message(
-                            format!("加载代币列表失败: {}", e),
-                        )));
-
-                        #[cfg(debug_assertions)]
-                        {
-                            use tracing::error;
-                            error!("TokenSelector - API error: {}", e);
-                        }
-                    }
+                    Ok(prices) => prices_mut.set(
+                        prices
+                            .into_iter()
+                            .map(|(symbol, p)| (symbol.to_uppercase(), p.usd))
+                            .collect(),
+                    ),
+                    Err(e) => log::warn!("获取持仓代币价格失败: {:?}", e),
                 }
             }
 
+            tokens_mut.set(all_tokens);
+            balances_mut.set(balances_map);
             loading_mut.set(false);
         });
     });
 
-    // ✅ 余额加载已合并到上面的智能代币加载中
-
-    // 过滤代币列表
+    // 过滤并排序：收藏置顶 → 持有价值 → 市值排名
     let filtered_tokens = use_memo(move || {
-        let query = search_query.read().to_lowercase();
-        tokens
+        let query = search_query.read().clone();
+        let prefs = prefs.read();
+        let wallet_id = wallet_id.read();
+        let balances = token_balances.read();
+        let prices = token_prices.read();
+        let is_favorite = |t: &TokenInfo| prefs.is_favorite(&wallet_id, &token_key(t));
+        let mut list: Vec<TokenInfo> = tokens
             .read()
             .iter()
-            .filter(|token| {
-                query.is_empty()
-                    || token.symbol.to_lowercase().contains(&query)
-                    || token.name.to_lowercase().contains(&query)
-                    || token.address.to_lowercase().contains(&query)
-            })
+            .filter(|t| matches_query(t, &query))
+            // 已隐藏的代币只在搜索时显示
+            .filter(|t| !query.trim().is_empty() || !prefs.is_token_hidden(t))
             .cloned()
+            .collect();
+        TokenRanking {
+            balances: &balances,
+            prices: &prices,
+            is_favorite: &is_favorite,
+        }
+        .sort(&mut list);
+        list
+    });
+
+    // 最近选择（仅显示当前列表中存在的代币）
+    let recent_tokens = use_memo(move || {
+        let list = tokens.read();
+        prefs
+            .read()
+            .recent()
+            .iter()
+            .filter_map(|key| list.iter().find(|t| token_key(t) == *key).cloned())
             .collect::<Vec<_>>()
     });
 
+    // 搜索词变化时回到列表顶部
+    use_effect(move || {
+        let _ = search_query.read();
+        highlighted.set(0);
+        scroll_top.set(0.0);
+        set_list_scroll_top(0.0);
+    });
+
+    let mut select_token = move |token: TokenInfo| {
+        prefs.write().record_selection(&token_key(&token));
+        prefs.read().save();
+        selected_token.set(Some(token.clone()));
+        show_modal.set(false);
+        search_query.set(String::new());
+
+        // 会话中首次选择该代币时核对链上精度与符号，不一致则替换为校正后的代币
+        spawn(async move {
+            let verified = TokenMetadataService::new(app_state).verify(&token).await;
+            let still_selected = selected_token
+                .peek()
+                .as_ref()
+                .is_some_and(|t| token_key(t) == token_key(&token));
+            if verified != token && still_selected {
+                selected_token.set(Some(verified));
+            }
+        });
+    };
+
+    let mut toggle_favorite = move |token: &TokenInfo| {
+        let wallet_id = wallet_id.read().clone();
+        prefs.write().toggle_favorite(&wallet_id, &token_key(token));
+        prefs.read().save();
+    };
+
     // 当前选择的代币显示
     let selected_token_display = if let Some(token) = selected_token.read().as_ref() {
         format!("{} ({})", token.symbol, token.name)
@@ -177,6 +288,21 @@ pub fn TokenSelector(
         "选择代币".to_string()
     };
 
+    // 支付方选中零余额代币时的提示（余额加载完成后判断）
+    let no_balance_hint = has_wallet
+        && !loading()
+        && selected_token
+            .read()
+            .as_ref()
+            .is_some_and(|t| !token_balances.read().contains_key(&t.address));
+
+    // 挂单与进行中订单占用的数量（有占用时显示可用/总额）
+    let committed = app_state.committed_balances.read().clone();
+
+    let list_len = filtered_tokens.read().len();
+    let (start, end) = visible_range(scroll_top(), list_len);
+    let highlighted_index = highlighted();
+
     rsx! {
         div {
             class: "mb-6",
@@ -189,12 +315,7 @@ pub fn TokenSelector(
                 variant: ButtonVariant::Secondary,
                 size: ButtonSize::Medium,
                 class: Some("w-full justify-between".to_string()),
-                onclick: {
-                    let mut show_modal_mut = show_modal;
-                    move |_| {
-                        show_modal_mut.set(true);
-                    }
-                },
+                onclick: move |_| show_modal.set(true),
                 div {
                     class: "flex items-center justify-between w-full",
                     span {
@@ -209,11 +330,31 @@ pub fn TokenSelector(
 
             // 显示当前代币余额（如果有）
             if let Some(token) = selected_token.read().as_ref() {
-                if let Some(balance) = token_balances.read().get(&token.address) {
+                if let Some(balance) = token_balances.read().get(&token.address).copied() {
+                    if committed.is_committed(&token.symbol) {
+                        div {
+                            class: "mt-2 text-sm",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "可用 / 总额: "
+                            MaskedAmount { value: format!("{:.6}", committed.available_f64(&token.symbol, balance)) }
+                            " / "
+                            MaskedAmount { value: format!("{:.6}", balance) }
+                            " {token.symbol}"
+                        }
+                    } else {
+                        div {
+                            class: "mt-2 text-sm",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "余额: "
+                            MaskedAmount { value: format!("{:.6}", balance) }
+                            " {token.symbol}"
+                        }
+                    }
+                } else if no_balance_hint {
                     div {
-                        class: "mt-2 text-sm",
+                        class: "mt-2 text-xs",
                         style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        {format!("余额: {:.6} {}", balance, token.symbol)}
+                        {format!("您的钱包中没有 {}，无法从该代币支付", token.symbol)}
                     }
                 }
             }
@@ -223,17 +364,39 @@ pub fn TokenSelector(
         if show_modal() {
             Modal {
                 open: true,
-                onclose: {
-                    let mut show_modal_mut = show_modal;
-                    EventHandler::new(move |_| {
-                        show_modal_mut.set(false);
-                    })
-                },
+                onclose: move |_| show_modal.set(false),
                 title: Some("选择代币".to_string()),
                 children: rsx! {
                     div {
                         class: "flex flex-col",
                         style: "height: 600px; max-height: 80vh;",
+                        // ⌨️ 键盘操作：↑/↓ 移动高亮，Enter 选择，Esc 关闭
+                        onkeydown: move |evt: KeyboardEvent| {
+                            let len = filtered_tokens.read().len();
+                            let current = highlighted();
+                            let next = match evt.key() {
+                                Key::ArrowDown if len > 0 => (current + 1).min(len - 1),
+                                Key::ArrowUp => current.saturating_sub(1),
+                                Key::Enter => {
+                                    let token = filtered_tokens.read().get(current).cloned();
+                                    if let Some(token) = token {
+                                        select_token(token);
+                                    }
+                                    return;
+                                }
+                                Key::Escape => {
+                                    show_modal.set(false);
+                                    return;
+                                }
+                                _ => return,
+                            };
+                            evt.prevent_default();
+                            highlighted.set(next);
+                            if let Some(top) = scroll_row_into_view(next, scroll_top()) {
+                                scroll_top.set(top);
+                                set_list_scroll_top(top);
+                            }
+                        },
 
                         // 🔍 搜索框 - 根据场景调整文案
                         div {
@@ -241,19 +404,10 @@ pub fn TokenSelector(
                             style: format!("background: {};", Colors::BG_PRIMARY),
 
                             Input {
-                                input_type: InputType::Text,
-                                placeholder: Some(if has_wallet {
-                                    "🔍 搜索钱包中的代币...".to_string()
-                                } else {
-                                    "🔍 搜索代币名称或粘贴合约地址".to_string()
-                                }),
+                                input_type: InputType::Search,
+                                placeholder: Some("🔍 搜索代币符号、名称或粘贴合约地址".to_string()),
                                 value: Some(search_query.read().clone()),
-                                onchange: {
-                                    let mut search_query_mut = search_query;
-                                    Some(EventHandler::new(move |e: dioxus::html::FormEvent| {
-                                        search_query_mut.set(e.value());
-                                    }))
-                                },
+                                onchange: move |e: FormEvent| search_query.set(e.value()),
                             }
 
                             // 搜索结果统计
@@ -262,9 +416,9 @@ pub fn TokenSelector(
                                     class: "mt-2 flex items-center justify-between text-xs",
                                     div {
                                         style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                        "找到 {filtered_tokens.read().len()} 个代币"
+                                        "找到 {list_len} 个代币"
                                     }
-                                    if !filtered_tokens.read().is_empty() {
+                                    if list_len > 0 {
                                         button {
                                             class: "text-xs font-medium hover:underline",
                                             style: format!("color: {};", Colors::TECH_PRIMARY),
@@ -276,70 +430,69 @@ pub fn TokenSelector(
                             }
                         }
 
-                        // 🏷️ 热门代币快捷选择 - 仅在没有搜索时显示
-                        if search_query.read().is_empty() && !has_wallet {
+                        // 🕘 最近选择 / 🔥 热门代币 - 仅在没有搜索时显示
+                        if search_query.read().is_empty() {
                             div {
                                 class: "pb-4 mb-4 border-b",
                                 style: format!("border-color: {};", Colors::BORDER_PRIMARY),
-
-                                div {
-                                    class: "flex items-center justify-between mb-3",
+                                if !recent_tokens.read().is_empty() {
+                                    div {
+                                        class: "text-sm font-bold flex items-center gap-2 mb-3",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        span { class: "text-base", "🕘" }
+                                        span { "最近选择" }
+                                    }
                                     div {
-                                        class: "text-sm font-bold flex items-center gap-2",
+                                        class: "flex flex-wrap gap-2",
+                                        for token in recent_tokens.read().iter().cloned() {
+                                            button {
+                                                key: "{token_key(&token)}",
+                                                class: "flex items-center gap-2 px-3 py-1.5 rounded-xl text-sm font-semibold transition-all hover:scale-105",
+                                                style: format!(
+                                                    "background: rgba(99, 102, 241, 0.1); color: {}; border: 2px solid rgba(99, 102, 241, 0.3);",
+                                                    Colors::TECH_PRIMARY
+                                                ),
+                                                onclick: {
+                                                    let token = token.clone();
+                                                    move |_| select_token(token.clone())
+                                                },
+                                                TokenLogo { token: token.clone(), size: 20 }
+                                                {token.symbol.clone()}
+                                            }
+                                        }
+                                    }
+                                } else {
+                                    div {
+                                        class: "text-sm font-bold flex items-center gap-2 mb-3",
                                         style: format!("color: {};", Colors::TEXT_PRIMARY),
                                         span { class: "text-base", "🔥" }
                                         span { "热门代币" }
                                     }
                                     div {
-                                        class: "text-xs",
-                                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                        "共 {tokens.read().len()} 个可用"
-                                    }
-                                }
-
-                                div {
-                                    class: "flex flex-wrap gap-2",
-                                    // 热门代币快捷按钮
-                                    for symbol in ["ETH", "USDT", "USDC", "DAI", "WBTC"] {
-                                        button {
-                                            class: "px-4 py-2 rounded-xl text-sm font-semibold transition-all hover:scale-105 hover:shadow-lg",
-                                            style: format!(
-                                                "background: {}; color: {}; border: 2px solid {};",
-                                                "rgba(99, 102, 241, 0.1)",
-                                                Colors::TECH_PRIMARY,
-                                                "rgba(99, 102, 241, 0.3)"
-                                            ),
-                                            onclick: {
-                                                let symbol_str = symbol.to_string();
-                                                let mut search_mut = search_query;
-                                                move |_| {
-                                                    search_mut.set(symbol_str.clone());
-                                                }
-                                            },
-                                            {symbol}
+                                        class: "flex flex-wrap gap-2",
+                                        for symbol in ["ETH", "USDT", "USDC", "DAI", "WBTC"] {
+                                            button {
+                                                class: "px-4 py-2 rounded-xl text-sm font-semibold transition-all hover:scale-105 hover:shadow-lg",
+                                                style: format!(
+                                                    "background: rgba(99, 102, 241, 0.1); color: {}; border: 2px solid rgba(99, 102, 241, 0.3);",
+                                                    Colors::TECH_PRIMARY
+                                                ),
+                                                onclick: move |_| search_query.set(symbol.to_string()),
+                                                {symbol}
+                                            }
                                         }
                                     }
                                 }
                             }
                         }
 
-
-
                         // ⚠️ 加载/错误状态
                         if loading() {
                             div {
-                                class: "flex-1 flex items-center justify-center py-12",
-                                div {
-                                    class: "text-center",
-                                    div {
-                                        class: "animate-spin rounded-full h-8 w-8 border-b-2 mx-auto mb-2",
-                                        style: format!("border-color: {};", Colors::TECH_PRIMARY),
-                                    }
-                                    p {
-                                        class: "text-sm",
-                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                        "加载代币中..."
-                                    }
+                                class: "flex-1 space-y-2 overflow-hidden",
+                                style: format!("max-height: {LIST_HEIGHT}px;"),
+                                for i in 0..5 {
+                                    SkeletonTableRow { key: "{i}" }
                                 }
                             }
                         } else if let Some(err) = error.read().as_ref() {
@@ -350,162 +503,63 @@ pub fn TokenSelector(
                                 div { class: "text-sm font-medium mb-1", "加载失败" }
                                 div { class: "text-xs", {err.clone()} }
                             }
-                        }
-
-                        // 📋 代币列表 - Uniswap风格
-                        if !loading() && error.read().is_none() {
+                        } else if list_len == 0 {
                             div {
-                                class: "flex-1 overflow-y-auto custom-scrollbar",
-                                style: "max-height: 360px; padding-right: 4px;",
-
-                                // 无结果提示 - 根据场景调整文案
-                                if filtered_tokens.read().is_empty() {
-                                    div {
-                                        class: "flex flex-col items-center justify-center py-16",
-                                        div {
-                                            class: "text-6xl mb-4 opacity-50",
-                                            if has_wallet { "💰" } else { "🔍" }
-                                        }
-                                        p {
-                                            class: "text-base font-semibold mb-2",
-                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                            if has_wallet {
-                                                "钱包中暂无此代币"
-                                            } else {
-                                                "未找到匹配的代币"
-                                            }
-                                        }
-                                        p {
-                                            class: "text-sm text-center px-4",
-                                            style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                            if has_wallet {
-                                                "您的钱包中还没有这个代币的余额"
-                                            } else {
-                                                "尝试搜索其他名称或直接粘贴代币合约地址"
-                                            }
-                                        }
-                                        button {
-                                            class: "mt-4 px-4 py-2 rounded-lg text-sm font-medium transition-all hover:scale-105",
-                                            style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
-                                            onclick: move |_| search_query.set(String::new()),
-                                            "清除搜索"
-                                        }
-                                    }
+                                class: "flex flex-col items-center justify-center py-16",
+                                div { class: "text-6xl mb-4 opacity-50", "🔍" }
+                                p {
+                                    class: "text-base font-semibold mb-2",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "未找到匹配的代币"
                                 }
-
-                                // 代币列表项
-                                for token in filtered_tokens.read().iter() {
-                                    div {
-                                        class: "flex items-center justify-between p-4 mb-2 cursor-pointer transition-all rounded-xl border-2",
-                                        style: format!(
-                                            "background: {}; border-color: {};",
-                                            if selected_token.read().as_ref().map(|t| t.address == token.address).unwrap_or(false) {
-                                                "rgba(99, 102, 241, 0.15)"
-                                            } else {
-                                                "transparent"
-                                            },
-                                            if selected_token.read().as_ref().map(|t| t.address == token.address).unwrap_or(false) {
-                                                Colors::TECH_PRIMARY
-                                            } else {
-                                                "rgba(99, 102, 241, 0.2)"
-                                            }
-                                        ),
-                                        onclick: {
-                                            let mut selected_token_mut = selected_token;
-                                            let mut show_modal_mut = show_modal;
-                                            let token_clone = token.clone();
-                                            move |_| {
-                                                selected_token_mut.set(Some(token_clone.clone()));
-                                                show_modal_mut.set(false);
-                                            }
+                                p {
+                                    class: "text-sm text-center px-4",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "尝试搜索其他名称或直接粘贴代币合约地址"
+                                }
+                                button {
+                                    class: "mt-4 px-4 py-2 rounded-lg text-sm font-medium transition-all hover:scale-105",
+                                    style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                    onclick: move |_| search_query.set(String::new()),
+                                    "清除搜索"
+                                }
+                            }
+                        } else {
+                            // 📋 代币列表（虚拟滚动：只渲染可见区间，上下用占位撑开高度）
+                            div {
+                                id: LIST_ELEMENT_ID,
+                                class: "flex-1 overflow-y-auto custom-scrollbar",
+                                style: format!("max-height: {LIST_HEIGHT}px; padding-right: 4px;"),
+                                role: "listbox",
+                                onscroll: move |e: ScrollEvent| scroll_top.set(e.scroll_top()),
+                                div { style: format!("height: {}px;", start as f64 * ROW_HEIGHT) }
+                                for (index, token) in filtered_tokens.read()[start..end].iter().cloned().enumerate().map(|(i, t)| (start + i, t)) {
+                                    TokenRow {
+                                        key: "{token_key(&token)}",
+                                        token: token.clone(),
+                                        balance: token_balances.read().get(&token.address).copied(),
+                                        available: token_balances
+                                            .read()
+                                            .get(&token.address)
+                                            .filter(|_| committed.is_committed(&token.symbol))
+                                            .map(|b| committed.available_f64(&token.symbol, *b)),
+                                        show_balance: has_wallet,
+                                        selected: selected_token.read().as_ref().is_some_and(|t| t.address == token.address),
+                                        highlighted: index == highlighted_index,
+                                        favorite: prefs.read().is_favorite(&wallet_id.read(), &token_key(&token)),
+                                        hidden: prefs.read().is_token_hidden(&token),
+                                        onselect: {
+                                            let token = token.clone();
+                                            move |_| select_token(token.clone())
                                         },
-
-                                        // 左侧：图标 + 信息
-                                        div {
-                                            class: "flex items-center gap-3 flex-1",
-
-                                            // 代币图标
-                                            div {
-                                                class: "relative",
-                                                if let Some(logo_url) = &token.logo_url {
-                                                    img {
-                                                        src: logo_url.clone(),
-                                                        alt: token.symbol.clone(),
-                                                        class: "w-12 h-12 rounded-full shadow-md",
-                                                    }
-                                                } else {
-                                                    div {
-                                                        class: "w-12 h-12 rounded-full flex items-center justify-center font-bold text-white text-xl shadow-md",
-                                                        style: format!(
-                                                            "background: linear-gradient(135deg, {} 0%, {} 100%);",
-                                                            Colors::TECH_PRIMARY,
-                                                            Colors::TECH_SECONDARY
-                                                        ),
-                                                        {token.symbol.chars().next().unwrap_or('?').to_string()}
-                                                    }
-                                                }
-                                                // 原生代币标记
-                                                if token.is_native {
-                                                    div {
-                                                        class: "absolute -bottom-1 -right-1 w-4 h-4 rounded-full flex items-center justify-center text-[10px]",
-                                                        style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
-                                                        "⭐"
-                                                    }
-                                                }
-                                            }
-
-                                            // 代币信息
-                                            div {
-                                                class: "flex-1",
-                                                div {
-                                                    class: "flex items-center gap-2",
-                                                    span {
-                                                        class: "font-bold text-lg",
-                                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                        {token.symbol.clone()}
-                                                    }
-                                                    if token.is_native {
-                                                        span {
-                                                            class: "text-[10px] px-1.5 py-0.5 rounded",
-                                                            style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
-                                                            "原生"
-                                                        }
-                                                    }
-                                                }
-                                                div {
-                                                    class: "text-xs mt-0.5",
-                                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                                    {token.name.clone()}
-                                                }
-                                            }
-                                        }
-
-                                        // 右侧：余额信息
-                                        div {
-                                            class: "text-right",
-                                            if has_wallet {
-                                                if let Some(balance) = token_balances.read().get(&token.address) {
-                                                    div {
-                                                        class: "font-semibold text-sm",
-                                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                        {format!("{:.6}", balance)}
-                                                    }
-                                                    div {
-                                                        class: "text-xs",
-                                                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                                        {token.symbol.clone()}
-                                                    }
-                                                } else {
-                                                    div {
-                                                        class: "text-xs",
-                                                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                                        "—"
-                                                    }
-                                                }
-                                            }
-                                        }
+                                        ontogglefavorite: {
+                                            let token = token.clone();
+                                            move |_| toggle_favorite(&token)
+                                        },
+                                        onhover: move |_| highlighted.set(index),
                                     }
                                 }
+                                div { style: format!("height: {}px;", (list_len - end) as f64 * ROW_HEIGHT) }
                             }
                         }
 
@@ -517,18 +571,20 @@ pub fn TokenSelector(
                                     Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
 
                                 if has_wallet {
-                                    // 有钱包场景：显示余额提示
                                     div {
                                         class: "flex items-center justify-center gap-2 p-3 rounded-xl",
-                                        style: format!("background: {}; border: 2px solid {};",
-                                            "rgba(99, 102, 241, 0.05)",
-                                            "rgba(99, 102, 241, 0.2)"
-                                        ),
+                                        style: "background: rgba(99, 102, 241, 0.05); border: 2px solid rgba(99, 102, 241, 0.2);",
                                         span { class: "text-base", "💡" }
                                         p {
                                             class: "text-xs",
                                             style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                            "只显示有余额的代币 · 共 {filtered_tokens.read().len()} 个"
+                                            "☆ 收藏的代币置顶 · 持有 {token_balances.read().len()} 个 · 共 {list_len} 个"
+                                        }
+                                        button {
+                                            class: "text-xs font-medium underline",
+                                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                                            onclick: move |_| show_manager.set(true),
+                                            "管理代币"
                                         }
                                     }
                                 } else {
@@ -558,5 +614,156 @@ pub fn TokenSelector(
                 }
             }
         }
+        TokenVisibilityManager {
+            open: show_manager(),
+            on_close: move |_| {
+                show_manager.set(false);
+                prefs.set(TokenPreferences::load());
+            },
+        }
+    }
+}
+
+/// 代币列表行（固定行高）
+#[component]
+fn TokenRow(
+    token: TokenInfo,
+    balance: Option<f64>,
+    /// 有挂单占用时的可用余额
+    available: Option<f64>,
+    show_balance: bool,
+    selected: bool,
+    highlighted: bool,
+    favorite: bool,
+    /// 已隐藏（搜索时仍显示，带标记）
+    hidden: bool,
+    onselect: EventHandler<()>,
+    ontogglefavorite: EventHandler<()>,
+    onhover: EventHandler<()>,
+) -> Element {
+    let background = if selected {
+        "rgba(99, 102, 241, 0.15)"
+    } else if highlighted {
+        "rgba(99, 102, 241, 0.08)"
+    } else {
+        "transparent"
+    };
+    let border = if selected || highlighted {
+        Colors::TECH_PRIMARY
+    } else {
+        "rgba(99, 102, 241, 0.2)"
+    };
+    let wrapped_hint = wrap::wrapped_hint(&token);
+
+    rsx! {
+        div {
+            style: format!("height: {ROW_HEIGHT}px; padding-bottom: 8px;"),
+            div {
+                class: "h-full flex items-center justify-between px-4 cursor-pointer transition-all rounded-xl border-2",
+                style: format!("background: {background}; border-color: {border};"),
+                role: "option",
+                "aria-selected": "{selected}",
+                onclick: move |_| onselect.call(()),
+                onmouseenter: move |_| onhover.call(()),
+
+                // 左侧：收藏 + 图标 + 信息
+                div {
+                    class: "flex items-center gap-3 flex-1 min-w-0",
+                    button {
+                        class: "text-lg leading-none transition-transform hover:scale-110",
+                        style: format!("color: {};", if favorite { "#F59E0B" } else { Colors::TEXT_TERTIARY }),
+                        title: if favorite { "取消收藏" } else { "收藏" },
+                        onclick: move |e| {
+                            e.stop_propagation();
+                            ontogglefavorite.call(());
+                        },
+                        if favorite { "★" } else { "☆" }
+                    }
+                    div {
+                        class: "relative",
+                        TokenLogo { token: token.clone() }
+                        // 原生代币标记
+                        if token.is_native {
+                            div {
+                                class: "absolute -bottom-1 -right-1 w-4 h-4 rounded-full flex items-center justify-center text-[10px]",
+                                style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                "⭐"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex-1 min-w-0",
+                        div {
+                            class: "flex items-center gap-2",
+                            span {
+                                class: "font-bold text-lg",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {token.symbol.clone()}
+                            }
+                            if token.is_native {
+                                span {
+                                    class: "text-[10px] px-1.5 py-0.5 rounded",
+                                    style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                    "原生"
+                                }
+                            }
+                            if hidden {
+                                span {
+                                    class: "text-[10px] px-1.5 py-0.5 rounded",
+                                    style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_TERTIARY),
+                                    "已隐藏"
+                                }
+                            }
+                        }
+                        div {
+                            class: "text-xs mt-0.5 truncate",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {token.name.clone()}
+                            // 包装原生币（如 WETH）提示与原生币 1:1 兑换
+                            if let Some(hint) = wrapped_hint {
+                                span {
+                                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                                    " · {hint}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 右侧：余额信息
+                if show_balance {
+                    div {
+                        class: "text-right",
+                        if let Some(balance) = balance {
+                            div {
+                                class: "font-semibold text-sm",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                MaskedAmount { value: format!("{:.6}", balance) }
+                            }
+                            if let Some(available) = available {
+                                div {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "可用 "
+                                    MaskedAmount { value: format!("{:.6}", available) }
+                                }
+                            } else {
+                                div {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    {token.symbol.clone()}
+                                }
+                            }
+                        } else {
+                            div {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                "无余额"
+                            }
+                        }
+                    }
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,274 @@
+//! Token Visibility Manager - 代币显示管理
+//! 列出持有的代币，多选后批量隐藏/显示；自动隐藏垃圾代币、举报垃圾代币与资产总额设置
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::modal::Modal;
+use crate::components::molecules::token_logo::TokenLogo;
+use crate::features::wallet::token_preferences::{token_key, TokenPreferences};
+use crate::features::wallet::token_visibility::{self, HeldToken};
+use crate::services::token::TokenInfo;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+/// 代币显示管理弹窗（关闭时调用方应重新读取 [`TokenPreferences`]）
+#[component]
+pub fn TokenVisibilityManager(open: bool, on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut prefs = use_signal(TokenPreferences::load);
+    let mut held = use_signal(Vec::<HeldToken>::new);
+    let mut selected = use_signal(HashSet::<String>::new);
+    let mut loading = use_signal(|| false);
+    let mut reporting = use_signal(|| Option::<String>::None);
+
+    let current_wallet = use_memo(move || app_state.wallet.read().get_selected_wallet().cloned());
+
+    // 打开时加载持有的代币
+    use_effect(use_reactive!(|open| {
+        if !open {
+            return;
+        }
+        prefs.set(TokenPreferences::load());
+        selected.set(HashSet::new());
+        let Some(wallet) = current_wallet() else {
+            return;
+        };
+        spawn(async move {
+            loading.set(true);
+            held.set(token_visibility::load_held_tokens(app_state, &wallet).await);
+            loading.set(false);
+        });
+    }));
+
+    let mut set_selected_hidden = move |hidden: bool| {
+        let keys = selected.read().clone();
+        let mut p = prefs.write();
+        for key in &keys {
+            p.set_hidden(key, hidden);
+        }
+        p.save();
+        drop(p);
+        selected.set(HashSet::new());
+    };
+
+    let mut report = move |token: TokenInfo, spam: bool| {
+        reporting.set(Some(token_key(&token)));
+        spawn(async move {
+            let mut updated = prefs.peek().clone();
+            match token_visibility::report_spam(app_state, &mut updated, &token, spam).await {
+                Ok(()) => {
+                    prefs.set(updated);
+                    let message = if spam {
+                        format!("已举报 {} 为垃圾代币并隐藏", token.symbol)
+                    } else {
+                        format!("已标记 {} 为正常代币", token.symbol)
+                    };
+                    AppState::show_success(app_state.toasts, message);
+                }
+                Err(e) => AppState::show_error(app_state.toasts, e),
+            }
+            reporting.set(None);
+        });
+    };
+
+    let all_keys: Vec<String> = held.read().iter().map(|h| token_key(&h.token)).collect();
+    let all_selected = !all_keys.is_empty() && all_keys.iter().all(|k| selected.read().contains(k));
+    let hidden_count = held
+        .read()
+        .iter()
+        .filter(|h| prefs.read().is_token_hidden(&h.token))
+        .count();
+
+    rsx! {
+        Modal {
+            open: open,
+            onclose: move |_| on_close.call(()),
+            title: Some("管理代币".to_string()),
+            div {
+                class: "space-y-4",
+
+                // 设置
+                div {
+                    class: "space-y-2 text-sm",
+                    label {
+                        class: "flex items-center justify-between cursor-pointer",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "自动隐藏垃圾代币"
+                        input {
+                            r#type: "checkbox",
+                            checked: prefs.read().auto_hide_spam,
+                            onchange: move |e: Event<FormData>| {
+                                prefs.write().auto_hide_spam = e.checked();
+                                prefs.read().save();
+                            },
+                        }
+                    }
+                    label {
+                        class: "flex items-center justify-between cursor-pointer",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "资产总额包含已隐藏代币"
+                        input {
+                            r#type: "checkbox",
+                            checked: prefs.read().include_hidden_in_totals,
+                            onchange: move |e: Event<FormData>| {
+                                prefs.write().include_hidden_in_totals = e.checked();
+                                prefs.read().save();
+                            },
+                        }
+                    }
+                }
+
+                // 批量操作
+                div {
+                    class: "flex items-center justify-between gap-2 pt-3 border-t",
+                    style: format!("border-color: {};", Colors::BORDER_PRIMARY),
+                    label {
+                        class: "flex items-center gap-2 text-xs cursor-pointer",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        input {
+                            r#type: "checkbox",
+                            checked: all_selected,
+                            onchange: move |e: Event<FormData>| {
+                                if e.checked() {
+                                    selected.set(all_keys.iter().cloned().collect());
+                                } else {
+                                    selected.set(HashSet::new());
+                                }
+                            },
+                        }
+                        "全选 · 持有 {held.read().len()} 个 · 已隐藏 {hidden_count} 个"
+                    }
+                    div {
+                        class: "flex gap-2",
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            disabled: selected.read().is_empty(),
+                            onclick: move |_| set_selected_hidden(true),
+                            "隐藏所选"
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            disabled: selected.read().is_empty(),
+                            onclick: move |_| set_selected_hidden(false),
+                            "显示所选"
+                        }
+                    }
+                }
+
+                // 代币列表
+                if loading() {
+                    p {
+                        class: "text-sm text-center py-6",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "正在加载持有的代币..."
+                    }
+                } else if held.read().is_empty() {
+                    p {
+                        class: "text-sm text-center py-6",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "当前钱包没有持有其他代币"
+                    }
+                } else {
+                    div {
+                        class: "space-y-2 overflow-y-auto custom-scrollbar",
+                        style: "max-height: 360px;",
+                        for item in held.read().iter().cloned() {
+                            HeldTokenRow {
+                                key: "{token_key(&item.token)}",
+                                hidden: prefs.read().is_token_hidden(&item.token),
+                                spam: prefs.read().is_spam(&item.token),
+                                checked: selected.read().contains(&token_key(&item.token)),
+                                reporting: reporting.read().as_deref() == Some(token_key(&item.token).as_str()),
+                                ontoggle: {
+                                    let key = token_key(&item.token);
+                                    move |checked: bool| {
+                                        if checked {
+                                            selected.write().insert(key.clone());
+                                        } else {
+                                            selected.write().remove(&key);
+                                        }
+                                    }
+                                },
+                                onreport: {
+                                    let token = item.token.clone();
+                                    move |spam: bool| report(token.clone(), spam)
+                                },
+                                item: item,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 持有代币行
+#[component]
+fn HeldTokenRow(
+    item: HeldToken,
+    hidden: bool,
+    spam: bool,
+    checked: bool,
+    reporting: bool,
+    ontoggle: EventHandler<bool>,
+    onreport: EventHandler<bool>,
+) -> Element {
+    rsx! {
+        div {
+            class: "flex items-center gap-3 p-2 rounded-lg",
+            style: format!(
+                "background: {}; opacity: {};",
+                Colors::BG_PRIMARY,
+                if hidden { "0.6" } else { "1" },
+            ),
+            input {
+                r#type: "checkbox",
+                checked: checked,
+                onchange: move |e: Event<FormData>| ontoggle.call(e.checked()),
+            }
+            TokenLogo { token: item.token.clone() }
+            div {
+                class: "flex-1 min-w-0",
+                div {
+                    class: "flex items-center gap-2",
+                    span {
+                        class: "font-semibold text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {item.token.symbol.clone()}
+                    }
+                    if spam {
+                        span {
+                            class: "text-[10px] px-1.5 py-0.5 rounded",
+                            style: format!("background: rgba(239, 68, 68, 0.15); color: {};", Colors::PAYMENT_ERROR),
+                            "疑似垃圾"
+                        }
+                    }
+                    if hidden {
+                        span {
+                            class: "text-[10px] px-1.5 py-0.5 rounded",
+                            style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_TERTIARY),
+                            "已隐藏"
+                        }
+                    }
+                }
+                div {
+                    class: "text-xs truncate",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    {format!("{:.6} · {}", item.balance, item.token.name)}
+                }
+            }
+            Button {
+                variant: ButtonVariant::Text,
+                size: ButtonSize::Small,
+                disabled: reporting,
+                loading: reporting,
+                onclick: move |_| onreport.call(!spam),
+                if spam { "不是垃圾" } else { "举报垃圾" }
+            }
+        }
+    }
+}
@@ -2,7 +2,10 @@
 //! 实时显示交易状态变化通知
 #![allow(dead_code)]
 
+use crate::components::atoms::masked_amount::redact_amounts;
 use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::time;
 use dioxus::prelude::*;
 
 /// 交易通知类型
@@ -62,10 +65,18 @@ pub fn TransactionNotificationItem(
     /// 关闭回调
     on_close: Option<EventHandler<String>>,
 ) -> Element {
+    let app_state = use_context::<AppState>();
     let notification_type = notification.notification_type;
     let id = notification.id.clone();
-    let title = notification.title.clone();
-    let message = notification.message.clone();
+    // 隐私模式下不在通知中显示金额
+    let (title, message) = if *app_state.privacy_mode.read() {
+        (
+            redact_amounts(&notification.title),
+            redact_amounts(&notification.message),
+        )
+    } else {
+        (notification.title.clone(), notification.message.clone())
+    };
     let transaction_id = notification.transaction_id.clone();
     let timestamp = notification.timestamp;
 
@@ -107,7 +118,7 @@ pub fn TransactionNotificationItem(
                     div {
                         class: "mt-1 text-xs",
                         style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        {format_time(timestamp)}
+                        {time::format_relative(timestamp as i64, &app_state.language.read())}
                     }
                 }
                 // 关闭按钮
@@ -152,29 +163,3 @@ pub fn TransactionNotificationContainer(
         }
     }
 }
-
-/// 格式化时间显示
-fn format_time(timestamp: u64) -> String {
-    // 使用js_sys::Date获取当前时间
-    let now_js = (js_sys::Date::now() / 1000.0) as u64;
-    let diff = now_js.saturating_sub(timestamp);
-
-    if diff < 60 {
-        "刚刚".to_string()
-    } else if diff < 3600 {
-        format!("{}分钟前", diff / 60)
-    } else if diff < 86400 {
-        format!("{}小时前", diff / 3600)
-    } else {
-        // 简单格式化，显示日期和时间
-        let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64((timestamp * 1000) as f64));
-        format!(
-            "{}-{:02}-{:02} {:02}:{:02}",
-            date.get_full_year(),
-            date.get_month() + 1,
-            date.get_date(),
-            date.get_hours(),
-            date.get_minutes()
-        )
-    }
-}
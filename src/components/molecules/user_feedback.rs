@@ -2,6 +2,7 @@
 //! 提供用户操作反馈和交互增强
 #![allow(dead_code)]
 
+use crate::components::molecules::confirm_action::{ConfirmAction, RiskLevel};
 use crate::shared::design_tokens::Colors;
 use dioxus::prelude::*;
 
@@ -145,61 +146,19 @@ pub struct ConfirmDialogProps {
     pub on_cancel: EventHandler<()>,
 }
 
-/// 确认对话框组件
+/// 确认对话框组件（基于 [`ConfirmAction`] 的普通信息确认）
 #[component]
 pub fn ConfirmDialog(props: ConfirmDialogProps) -> Element {
-    if !props.visible {
-        return rsx! { div {} };
-    }
-
     rsx! {
-        div {
-            class: "fixed inset-0 z-50 flex items-center justify-center",
-            style: "background: rgba(0, 0, 0, 0.5);",
-            onclick: move |_| {
-                props.on_cancel.call(());
-            },
-            div {
-                class: "p-6 rounded-lg max-w-md w-full mx-4",
-                style: format!("background: {};", Colors::BG_PRIMARY),
-                onclick: move |e| {
-                    e.stop_propagation();
-                },
-                h3 {
-                    class: "text-lg font-semibold mb-3",
-                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                    "{props.title}"
-                }
-                p {
-                    class: "text-sm mb-6",
-                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                    "{props.message}"
-                }
-                div {
-                    class: "flex gap-3 justify-end",
-                    button {
-                        class: "px-4 py-2 rounded-lg text-sm font-medium",
-                        style: format!(
-                            "background: {}; color: {}; border: 1px solid {};",
-                            Colors::BG_SECONDARY,
-                            Colors::TEXT_PRIMARY,
-                            Colors::BORDER_PRIMARY
-                        ),
-                        onclick: move |_| {
-                            props.on_cancel.call(());
-                        },
-                        "{props.cancel_text}"
-                    }
-                    button {
-                        class: "px-4 py-2 rounded-lg text-sm font-medium",
-                        style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
-                        onclick: move |_| {
-                            props.on_confirm.call(());
-                        },
-                        "{props.confirm_text}"
-                    }
-                }
-            }
+        ConfirmAction {
+            open: props.visible,
+            risk: RiskLevel::Info,
+            title: props.title.clone(),
+            message: props.message.clone(),
+            confirm_text: props.confirm_text.clone(),
+            cancel_text: props.cancel_text.clone(),
+            on_confirm: props.on_confirm,
+            on_cancel: props.on_cancel,
         }
     }
 }
@@ -1,8 +1,12 @@
 //! Wallet Delete Modal - 删除钱包确认弹窗
+//! 未验证备份的钱包需先确认"删除后无法恢复"；删除为软删除，7 天内可在设置中撤销
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::modal::Modal;
+use crate::components::molecules::confirm_action::{ConfirmAction, RiskLevel};
+use crate::components::molecules::toast::{ToastAction, ToastType};
 use crate::features::wallet::hooks::WalletController;
+use crate::features::wallet::state::SOFT_DELETE_RETENTION_DAYS;
 use crate::router::Route;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
@@ -20,86 +24,135 @@ pub fn WalletDeleteModal(
     on_close: EventHandler<()>,
 ) -> Element {
     let navigator = use_navigator();
+    // 未验证备份时：是否已勾选"了解无法恢复"，以及是否已通过该检查
+    let mut acknowledged = use_signal(|| false);
+    let mut backup_checked = use_signal(|| false);
 
     let app_state = *app_state.read();
     let wallet_controller = *wallet_controller.read();
 
+    // 每次重新打开都重新检查
+    use_effect(use_reactive!(|open| {
+        if !open {
+            acknowledged.set(false);
+            backup_checked.set(false);
+        }
+    }));
+
+    let backup_verified = app_state
+        .wallet
+        .read()
+        .get_wallet(&wallet_id)
+        .is_some_and(|w| w.backup_verified);
+    let show_backup_check = open && !backup_verified && !backup_checked();
+
     rsx! {
         Modal {
-            open: open,
-            onclose: move |_| {
-                on_close.call(());
-            },
-            title: Some("确认删除钱包".to_string()),
+            open: show_backup_check,
+            onclose: move |_| on_close.call(()),
+            title: Some("删除前请确认备份".to_string()),
             children: rsx! {
                 div {
                     class: "space-y-4",
-                    // 主提示：提高字号和对比度
-                    p {
-                        class: "text-base font-medium",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        {format!("确定要删除钱包 \"{}\" 吗？", wallet_name)}
+                    div {
+                        class: "p-3 rounded-lg text-sm",
+                        style: format!(
+                            "background: rgba(239, 68, 68, 0.1); border: 1px solid {}; color: {};",
+                            Colors::PAYMENT_ERROR,
+                            Colors::TEXT_PRIMARY
+                        ),
+                        {format!("钱包 \"{}\" 尚未完成助记词备份验证。删除后若没有助记词或私钥，钱包中的资产将永久无法找回。", wallet_name)}
                     }
-                    // 风险说明：稍大字号，突出红色警告
                     p {
                         class: "text-sm",
-                        style: format!("color: {};", Colors::PAYMENT_ERROR),
-                        "删除后将无法恢复：包括私钥、账户配置和本地交易记录。请确保已安全备份助记词。"
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "请先在钱包详情中导出并妥善保存助记词或私钥，再继续删除。"
                     }
-                    // 说明列表：字号提升一档，保持次要颜色
-                    ul {
-                        class: "text-sm list-disc list-inside",
-                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        li { "此操作只影响本设备，不会删除区块链上的历史交易。" }
-                        li { "如需在新设备继续使用，请先确认已备份助记词。" }
+                    label {
+                        class: "flex items-center gap-3 cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            checked: acknowledged(),
+                            onchange: move |_| acknowledged.set(!acknowledged()),
+                            class: "w-5 h-5 rounded",
+                            style: format!("accent-color: {};", Colors::PAYMENT_ERROR),
+                        }
+                        span {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "我已备份，或了解删除后该钱包无法恢复"
+                        }
                     }
                     div {
-                        class: "flex gap-3 mt-6",
+                        class: "flex gap-3",
                         Button {
                             variant: ButtonVariant::Secondary,
                             size: ButtonSize::Small,
                             class: Some("flex-1".to_string()),
-                            onclick: move |_| {
-                                on_close.call(());
-                            },
+                            onclick: move |_| on_close.call(()),
                             "取消"
                         }
                         Button {
-                            variant: ButtonVariant::Primary,
+                            variant: ButtonVariant::Error,
                             size: ButtonSize::Small,
                             class: Some("flex-1".to_string()),
-                            onclick: move |_| {
-                                let wallet_id_clone = wallet_id.clone();
-                                let wallet_name_clone = wallet_name.clone();
-                                let app_state_clone = app_state;
-                                let wallet_controller_clone = wallet_controller;
-                                let navigator_clone = navigator;
-                                let on_close = on_close;
-                                spawn(async move {
-                                    match wallet_controller_clone.delete_wallet(&wallet_id_clone).await {
-                                        Ok(_) => {
-                                            AppState::show_success(
-                                                app_state_clone.toasts,
-                                                format!("钱包 \"{}\" 已删除", wallet_name_clone)
-                                            );
-                                            on_close.call(());
-                                            navigator_clone.push(Route::Dashboard {});
-                                        }
-                                        Err(e) => {
-                                            AppState::show_error(
-                                                app_state_clone.toasts,
-                                                format!("删除钱包失败: {}", e)
-                                            );
-                                            on_close.call(());
-                                        }
-                                    }
-                                });
-                            },
-                            "确认删除"
+                            disabled: !acknowledged(),
+                            onclick: move |_| backup_checked.set(true),
+                            "继续"
                         }
                     }
                 }
-            }
+            },
+        }
+
+        ConfirmAction {
+            open: open && !show_backup_check,
+            risk: RiskLevel::Destructive,
+            title: "确认删除钱包".to_string(),
+            message: format!("确定要删除钱包 \"{}\" 吗？", wallet_name),
+            details: vec![
+                format!("钱包将移入\"最近删除\"，{} 天内可在设置中恢复，之后永久删除私钥、账户配置和本地记录。", SOFT_DELETE_RETENTION_DAYS),
+                "此操作只影响本设备，不会删除区块链上的历史交易。".to_string(),
+            ],
+            confirm_text: "确认删除".to_string(),
+            // 输入钱包名称才能删除，避免误触
+            confirm_phrase: Some(wallet_name.clone()),
+            on_cancel: move |_| on_close.call(()),
+            on_confirm: move |_| {
+                match wallet_controller.delete_wallet(&wallet_id) {
+                    Ok(()) => {
+                        let restore_id = wallet_id.clone();
+                        let restore_name = wallet_name.clone();
+                        AppState::show_toast_with_action(
+                            app_state.toasts,
+                            format!("钱包 \"{}\" 已删除", wallet_name),
+                            ToastType::Success,
+                            Some(8000),
+                            Some(ToastAction::callback("撤销", move || {
+                                match wallet_controller.restore_wallet(&restore_id) {
+                                    Ok(()) => AppState::show_success(
+                                        app_state.toasts,
+                                        format!("钱包 \"{}\" 已恢复", restore_name),
+                                    ),
+                                    Err(e) => AppState::show_error(
+                                        app_state.toasts,
+                                        format!("恢复钱包失败: {}", e),
+                                    ),
+                                }
+                            })),
+                        );
+                        on_close.call(());
+                        navigator.push(Route::Dashboard {});
+                    }
+                    Err(e) => {
+                        AppState::show_error(
+                            app_state.toasts,
+                            format!("删除钱包失败: {}", e)
+                        );
+                        on_close.call(());
+                    }
+                }
+            },
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::logo::LogoPlanet;
 use crate::features::auth::hooks::use_auth;
 use crate::router::Route;
+use crate::services::preferences::PreferencesService;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
@@ -18,6 +19,7 @@ pub fn Navbar() -> Element {
     let auth_controller = use_auth();
     let user_state = app_state.user.read();
     let is_authenticated = user_state.is_authenticated;
+    let privacy_mode = *app_state.privacy_mode.read();
     let mut show_mobile_menu = use_signal(|| false);
 
     // 获取翻译函数
@@ -80,6 +82,11 @@ pub fn Navbar() -> Element {
                                 label: t("nav.swap"),
                                 icon: "swap".to_string(),
                             }
+                            NavLink {
+                                route: Route::Dapps {},
+                                label: t("nav.dapps"),
+                                icon: "dapps".to_string(),
+                            }
                         }
                         // 未登录用户不显示额外导航项，只显示Logo
                     }
@@ -87,6 +94,18 @@ pub fn Navbar() -> Element {
                     // 右侧操作区
                     div {
                         class: "flex items-center gap-2",
+                        // 隐私模式（隐藏金额）
+                        if is_authenticated {
+                            button {
+                                class: "p-2 rounded-lg text-lg",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                title: if privacy_mode { "显示金额 (Ctrl+Shift+H)" } else { "隐藏金额 (Ctrl+Shift+H)" },
+                                aria_pressed: "{privacy_mode}",
+                                onclick: move |_| app_state.toggle_privacy_mode(),
+                                if privacy_mode { "🙈" } else { "👁" }
+                            }
+                        }
+
                         // 语言切换器
                         LanguageSwitcher {}
 
@@ -206,6 +225,14 @@ pub fn Navbar() -> Element {
                                     show_mobile_menu.set(false);
                                 },
                             }
+                            MobileNavLink {
+                                route: Route::Dapps {},
+                                label: "dApp".to_string(),
+                                icon: "dapps".to_string(),
+                                on_click: move |_| {
+                                    show_mobile_menu.set(false);
+                                },
+                            }
                         } else {
                             MobileNavLink {
                                 route: Route::Login {},
@@ -272,7 +299,7 @@ fn MobileNavLink(route: Route, label: String, icon: String, on_click: EventHandl
 /// 语言切换器组件
 #[component]
 fn LanguageSwitcher() -> Element {
-    let mut app_state = use_context::<AppState>();
+    let app_state = use_context::<AppState>();
     let current_lang = app_state.language.read().clone();
     let mut show_menu = use_signal(|| false);
 
@@ -317,10 +344,7 @@ fn LanguageSwitcher() -> Element {
                                 format!("color: {};", Colors::TEXT_SECONDARY)
                             },
                             onclick: move |_| {
-                                let mut lang = app_state.language.write();
-                                *lang = code.to_string();
-                                // 保存到 LocalStorage
-                                let _ = gloo_storage::LocalStorage::set("app_language", code);
+                                PreferencesService::new(app_state).set_language(code);
                                 show_menu.set(false);
                             },
                             span { class: "text-base", {flag} }
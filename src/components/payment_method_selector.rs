@@ -1 +1,265 @@
+//! Payment Method Selector - 支付/提现方式选择器
+//! 支付方式的数据模型（图标、多语言名称与说明、适用地区、到账速度），
+//! 购买与提现共用同一个选择器：按用户所在地区把本地常用方式排在前面，
+//! 并按地区矩阵禁用不可用的方式
 
+use crate::components::atoms::icon::{Icon, IconSize};
+use crate::components::molecules::PaymentMethodOption;
+use crate::i18n::translations::get_text;
+use crate::services::country_support::{MethodAvailability, PaymentMethodMatrix};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 支付方式定义
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaymentMethodDef {
+    /// 方式 ID（与后端一致）
+    pub id: &'static str,
+    /// Icon 原子组件的图标名
+    pub icon: &'static str,
+    /// 名称的 i18n key
+    pub name_key: &'static str,
+    /// 说明的 i18n key
+    pub description_key: &'static str,
+    /// 常用地区（国家/地区代码）；为空表示全球通用
+    pub regions: &'static [&'static str],
+    /// 是否即时到账
+    pub instant: bool,
+}
+
+impl PaymentMethodDef {
+    pub fn name(&self, lang: &str) -> String {
+        get_text(self.name_key, lang)
+    }
+
+    pub fn description(&self, lang: &str) -> String {
+        let speed = if self.instant {
+            "payment_method.instant"
+        } else {
+            "payment_method.business_days"
+        };
+        format!(
+            "{} · {}",
+            get_text(speed, lang),
+            get_text(self.description_key, lang)
+        )
+    }
+
+    /// 是否为该地区的本地常用方式
+    pub fn is_local_to(&self, country_code: &str) -> bool {
+        self.regions
+            .iter()
+            .any(|r| r.eq_ignore_ascii_case(country_code))
+    }
+}
+
+const CHINA_REGIONS: &[&str] = &["CN", "HK"];
+
+/// 购买（入金）支付方式
+pub const BUY_METHODS: [PaymentMethodDef; 6] = [
+    PaymentMethodDef {
+        id: "credit_card",
+        icon: "credit-card",
+        name_key: "payment_method.credit_card",
+        description_key: "payment_method.credit_card.desc",
+        regions: &[],
+        instant: true,
+    },
+    PaymentMethodDef {
+        id: "paypal",
+        icon: "at-symbol",
+        name_key: "payment_method.paypal",
+        description_key: "payment_method.paypal.desc",
+        regions: &[],
+        instant: true,
+    },
+    PaymentMethodDef {
+        id: "apple_pay",
+        icon: "device-phone",
+        name_key: "payment_method.apple_pay",
+        description_key: "payment_method.apple_pay.desc",
+        regions: &[],
+        instant: true,
+    },
+    PaymentMethodDef {
+        id: "google_pay",
+        icon: "device-phone",
+        name_key: "payment_method.google_pay",
+        description_key: "payment_method.google_pay.desc",
+        regions: &[],
+        instant: true,
+    },
+    PaymentMethodDef {
+        id: "alipay",
+        icon: "qr-code",
+        name_key: "payment_method.alipay",
+        description_key: "payment_method.alipay.desc",
+        regions: CHINA_REGIONS,
+        instant: true,
+    },
+    PaymentMethodDef {
+        id: "wechat_pay",
+        icon: "qr-code",
+        name_key: "payment_method.wechat_pay",
+        description_key: "payment_method.wechat_pay.desc",
+        regions: CHINA_REGIONS,
+        instant: true,
+    },
+];
+
+/// 提现（出金）方式
+pub const WITHDRAW_METHODS: [PaymentMethodDef; 6] = [
+    PaymentMethodDef {
+        id: "bank_card",
+        icon: "bank",
+        name_key: "payment_method.bank_card",
+        description_key: "payment_method.bank_card.desc",
+        regions: &[],
+        instant: false,
+    },
+    BUY_METHODS[1],
+    BUY_METHODS[2],
+    BUY_METHODS[3],
+    BUY_METHODS[4],
+    BUY_METHODS[5],
+];
+
+/// 支付方向：决定使用哪组方式与地区矩阵中的哪一列
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaymentDirection {
+    Buy,
+    Withdraw,
+}
+
+impl PaymentDirection {
+    pub fn methods(self) -> &'static [PaymentMethodDef] {
+        match self {
+            PaymentDirection::Buy => &BUY_METHODS,
+            PaymentDirection::Withdraw => &WITHDRAW_METHODS,
+        }
+    }
+
+    /// 默认推荐的方式
+    fn recommended(self) -> &'static str {
+        match self {
+            PaymentDirection::Buy => "credit_card",
+            PaymentDirection::Withdraw => "bank_card",
+        }
+    }
+
+    fn availability(self, matrix: &PaymentMethodMatrix, method: &str) -> MethodAvailability {
+        match self {
+            PaymentDirection::Buy => matrix.buy_availability(method),
+            PaymentDirection::Withdraw => matrix.withdraw_availability(method),
+        }
+    }
+
+    /// 方式的本地化名称（未知 ID 原样返回）
+    pub fn method_name(self, id: &str, lang: &str) -> String {
+        self.methods()
+            .iter()
+            .find(|m| m.id == id)
+            .map(|m| m.name(lang))
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+/// 按地区分组：(本地常用, 全球通用)；地区未知时全部视为全球通用
+pub fn group_by_region(
+    methods: &'static [PaymentMethodDef],
+    country_code: Option<&str>,
+) -> (Vec<PaymentMethodDef>, Vec<PaymentMethodDef>) {
+    match country_code {
+        Some(code) => methods.iter().partition(|m| m.is_local_to(code)),
+        None => (Vec::new(), methods.to_vec()),
+    }
+}
+
+/// 支付/提现方式选择器（购买与提现共用）
+#[component]
+pub fn PaymentMethodSelector(
+    direction: PaymentDirection,
+    /// 当前选中的方式 ID
+    selected: String,
+    /// 用户所在地区的支付方式矩阵（检测中时全部可选，以服务端校验为准）
+    region: Signal<Option<PaymentMethodMatrix>>,
+    onselect: EventHandler<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let lang = app_state.language.read().clone();
+    let matrix = region.read().clone();
+    let (local, global) = group_by_region(
+        direction.methods(),
+        matrix.as_ref().map(|m| m.country_code.as_str()),
+    );
+
+    let render_group = |methods: Vec<PaymentMethodDef>| {
+        let lang = lang.clone();
+        let matrix = matrix.clone();
+        let selected = selected.clone();
+        rsx! {
+            div {
+                class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-2",
+                for method in methods {
+                    PaymentMethodOption {
+                        key: "{method.id}",
+                        icon: rsx! { Icon { name: method.icon.to_string(), size: IconSize::MD } },
+                        label: method.name(&lang),
+                        hint: method.description(&lang),
+                        badge: (method.id == direction.recommended())
+                            .then(|| get_text("payment_method.recommended", &lang)),
+                        selected: selected == method.id,
+                        availability: matrix
+                            .as_ref()
+                            .map(|m| direction.availability(m, method.id))
+                            .unwrap_or(MethodAvailability::Available),
+                        onselect: move |_| onselect.call(method.id.to_string()),
+                    }
+                }
+            }
+        }
+    };
+
+    if local.is_empty() {
+        return render_group(global);
+    }
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            div {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {get_text("payment_method.local_group", &lang)}
+            }
+            {render_group(local)}
+            div {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {get_text("payment_method.global_group", &lang)}
+            }
+            {render_group(global)}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_methods_are_grouped_first_for_the_users_region() {
+        let (local, global) = group_by_region(&BUY_METHODS, Some("cn"));
+        let local_ids: Vec<&str> = local.iter().map(|m| m.id).collect();
+        assert_eq!(local_ids, ["alipay", "wechat_pay"]);
+        assert_eq!(global.len(), 4);
+
+        let (local, global) = group_by_region(&WITHDRAW_METHODS, Some("US"));
+        assert!(local.is_empty());
+        assert_eq!(global.len(), 6);
+
+        let (local, _) = group_by_region(&WITHDRAW_METHODS, None);
+        assert!(local.is_empty());
+    }
+}
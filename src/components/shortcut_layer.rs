@@ -0,0 +1,191 @@
+//! Shortcut Layer - 全局键盘快捷键分发器
+//! 挂在应用外壳中，统一处理快捷键并提供快捷键速查表（按 `?` 打开）
+
+use crate::components::atoms::modal::Modal;
+use crate::features::shortcuts::{self, ShortcutAction, ShortcutParser, BINDINGS};
+use crate::features::wallet::hooks::use_wallet;
+use crate::router::Route;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// 搜索框标记：`/` 优先聚焦带此属性的输入框，其次是 `type="search"` 的输入框
+pub const SEARCH_SELECTOR: &str = "[data-shortcut-search], input[type='search']";
+
+/// 全局键盘快捷键
+#[component]
+pub fn ShortcutLayer() -> Element {
+    let app_state = use_context::<AppState>();
+    let wallet_controller = use_wallet();
+    let navigator = use_navigator();
+    let mut cheat_sheet_open = use_signal(|| false);
+
+    let listener = use_hook(|| {
+        let parser = Rc::new(RefCell::new(ShortcutParser::default()));
+        let closure = Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+            if e.ctrl_key() || e.meta_key() || e.alt_key() || e.repeat() {
+                return;
+            }
+            if !app_state.preferences.peek().keyboard_shortcuts
+                || *app_state.app_locked.peek()
+                || typed_confirmation_open()
+                || is_typing(&e)
+            {
+                parser.borrow_mut().reset();
+                return;
+            }
+            let Some(action) = parser.borrow_mut().handle(&e.key(), time::now_ms()) else {
+                return;
+            };
+            let authenticated = app_state.user.peek().is_authenticated;
+            let handled = match action {
+                ShortcutAction::FocusSearch => focus_search(),
+                ShortcutAction::GoDashboard => navigator.push(Route::Dashboard {}).is_none(),
+                ShortcutAction::GoSwap => navigator.push(Route::Swap {}).is_none(),
+                ShortcutAction::GoOrders => navigator.push(Route::Orders {}).is_none(),
+                ShortcutAction::LockWallet if authenticated => {
+                    wallet_controller.lock_app();
+                    true
+                }
+                ShortcutAction::LockWallet => false,
+                ShortcutAction::CloseModal => close_topmost_modal(),
+                ShortcutAction::ToggleCheatSheet => {
+                    let open = !*cheat_sheet_open.peek();
+                    cheat_sheet_open.set(open);
+                    true
+                }
+            };
+            if handled {
+                e.prevent_default();
+            }
+        }) as Box<dyn FnMut(web_sys::KeyboardEvent)>);
+        if let Some(window) = web_sys::window() {
+            let _ = window.add_event_listener_with_callback(
+                "keydown",
+                closure.as_ref().unchecked_ref::<js_sys::Function>(),
+            );
+        }
+        Rc::new(closure)
+    });
+    use_drop(move || {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                "keydown",
+                listener
+                    .as_ref()
+                    .as_ref()
+                    .unchecked_ref::<js_sys::Function>(),
+            );
+        }
+    });
+
+    rsx! {
+        Modal {
+            open: cheat_sheet_open(),
+            onclose: move |_| cheat_sheet_open.set(false),
+            title: Some("键盘快捷键".to_string()),
+            div {
+                class: "space-y-2",
+                for (keys, description) in BINDINGS.iter() {
+                    div {
+                        key: "{keys}",
+                        class: "flex items-center justify-between gap-4 text-sm",
+                        span {
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {*description}
+                        }
+                        span {
+                            class: "flex gap-1",
+                            for part in keys.split(' ') {
+                                kbd {
+                                    class: "px-2 py-0.5 rounded font-mono text-xs",
+                                    style: format!(
+                                        "background: {}; border: 1px solid {}; color: {};",
+                                        Colors::BG_PRIMARY,
+                                        Colors::BORDER_PRIMARY,
+                                        Colors::TEXT_PRIMARY
+                                    ),
+                                    {part}
+                                }
+                            }
+                        }
+                    }
+                }
+                p {
+                    class: "text-xs pt-2",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    "输入文字时快捷键不会触发；可在设置中关闭快捷键。"
+                }
+            }
+        }
+    }
+}
+
+/// 焦点是否在输入框、文本域、下拉框或可编辑区域中
+fn is_typing(e: &web_sys::KeyboardEvent) -> bool {
+    let target = e
+        .target()
+        .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok());
+    target.is_some_and(|el| shortcuts::is_typing_target(&el.tag_name(), el.is_content_editable()))
+}
+
+/// 是否打开了需要输入确认文本的弹窗
+fn typed_confirmation_open() -> bool {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| {
+            d.query_selector(".modal-overlay[data-typed-confirm='true']")
+                .ok()
+                .flatten()
+        })
+        .is_some()
+}
+
+/// 最上层（DOM 中最后一个）打开的弹窗
+fn topmost_modal() -> Option<web_sys::Element> {
+    let overlays = web_sys::window()?
+        .document()?
+        .query_selector_all(".modal-overlay")
+        .ok()?;
+    overlays
+        .item(overlays.length().checked_sub(1)?)?
+        .dyn_into::<web_sys::Element>()
+        .ok()
+}
+
+/// 点击最上层弹窗的关闭按钮；弹窗不可关闭（如操作进行中）时不处理
+fn close_topmost_modal() -> bool {
+    let close_button = topmost_modal()
+        .and_then(|modal| modal.query_selector("[data-modal-close]").ok().flatten())
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+    match close_button {
+        Some(button) => {
+            button.click();
+            true
+        }
+        None => false,
+    }
+}
+
+/// 聚焦搜索框：有弹窗时只在最上层弹窗内查找
+fn focus_search() -> bool {
+    let scope: Option<web_sys::Element> = match topmost_modal() {
+        Some(modal) => Some(modal),
+        None => web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.body())
+            .map(Into::into),
+    };
+    let input = scope
+        .and_then(|s| s.query_selector(SEARCH_SELECTOR).ok().flatten())
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+    match input {
+        Some(input) => input.focus().is_ok(),
+        None => false,
+    }
+}
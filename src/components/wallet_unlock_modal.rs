@@ -131,6 +131,15 @@ pub fn WalletUnlockModal(
                         message: error_message.read().clone()
                     }
 
+                    // 密钥派生进度（加密运算在 Worker 或让出一帧后执行，界面保持响应）
+                    if is_loading() {
+                        p {
+                            class: "text-xs mt-3 animate-pulse",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "正在派生密钥并解密钱包，请稍候…"
+                        }
+                    }
+
                     div {
                         class: "flex gap-4 mt-6",
                         Button {
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use bip39::{Language, Mnemonic};
+use sha2::{Digest, Sha256};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 #[derive(Zeroize, ZeroizeOnDrop)]
@@ -26,13 +27,65 @@ impl MnemonicSecret {
     }
 }
 
-pub fn generate_mnemonic(word_count: usize) -> Result<MnemonicSecret> {
-    // bip39 2.0 generate_in takes language and word count
-    let mnemonic = Mnemonic::generate_in(Language::English, word_count)
+/// 创建钱包时可选的助记词长度
+pub const WORD_COUNTS: [usize; 2] = [12, 24];
+
+/// 熵混合的域分隔标签（避免与其他用途的 SHA-256 输出混淆）
+const ENTROPY_DOMAIN: &[u8] = b"IronForge/mnemonic-entropy/v1";
+
+/// 助记词长度对应的熵字节数（12 词 = 128 位，24 词 = 256 位）
+pub fn entropy_len(word_count: usize) -> Result<usize> {
+    match word_count {
+        12 => Ok(16),
+        24 => Ok(32),
+        _ => Err(anyhow!("Unsupported mnemonic length: {}", word_count)),
+    }
+}
+
+/// 熵白化：将系统随机数与用户操作收集的额外熵混合
+///
+/// `out = SHA-256(domain || len(system) || system || SHA-256(extra))[..len]`
+///
+/// - `system` 必须来自 CSPRNG（浏览器中为 `crypto.getRandomValues`），输出的随机性不低于它
+/// - `extra`（如指针移动轨迹）只会增加熵；即使完全可预测也不会削弱结果
+/// - 额外熵先单独哈希，长度不定的用户数据不会影响系统熵在输入中的位置
+pub fn mix_entropy(system: &[u8], extra: &[u8], len: usize) -> Vec<u8> {
+    let extra_digest = Sha256::digest(extra);
+    let mut hasher = Sha256::new();
+    hasher.update(ENTROPY_DOMAIN);
+    hasher.update((system.len() as u32).to_be_bytes());
+    hasher.update(system);
+    hasher.update(extra_digest);
+    let mut digest = hasher.finalize();
+    let out = digest[..len.min(digest.len())].to_vec();
+    digest.zeroize();
+    out
+}
+
+/// 由熵生成助记词（同一熵总是得到同一助记词）
+pub fn mnemonic_from_entropy(entropy: &[u8]) -> Result<MnemonicSecret> {
+    let mnemonic = Mnemonic::from_entropy_in(Language::English, entropy)
         .map_err(|e| anyhow!("Failed to generate mnemonic: {}", e))?;
     Ok(MnemonicSecret::new(mnemonic.to_string()))
 }
 
+/// 生成助记词：系统 CSPRNG（wasm 下为 `crypto.getRandomValues`）经 [`mix_entropy`] 混合额外熵
+pub fn generate_mnemonic_with_entropy(word_count: usize, extra: &[u8]) -> Result<MnemonicSecret> {
+    let len = entropy_len(word_count)?;
+    let mut system = vec![0u8; len];
+    getrandom::getrandom(&mut system)
+        .map_err(|e| anyhow!("Secure random source unavailable: {}", e))?;
+    let mut entropy = mix_entropy(&system, extra, len);
+    system.zeroize();
+    let mnemonic = mnemonic_from_entropy(&entropy);
+    entropy.zeroize();
+    mnemonic
+}
+
+pub fn generate_mnemonic(word_count: usize) -> Result<MnemonicSecret> {
+    generate_mnemonic_with_entropy(word_count, &[])
+}
+
 /// 验证助记词
 /// 为未来扩展准备的验证函数
 #[allow(dead_code)] // 为未来扩展准备
@@ -0,0 +1,150 @@
+//! Derivation Path - BIP32 / SLIP-0010 派生路径
+//!
+//! 解析与格式化 `m/44'/60'/0'/0/0` 形式的路径（硬化层级可写作 `'`、`h` 或 `H`），
+//! 供自定义派生路径导入与按账户路径签名使用。
+
+use std::fmt;
+use std::str::FromStr;
+
+/// 硬化派生标志位
+pub const HARDENED: u32 = 0x8000_0000;
+
+/// 路径最大层级（防止输入异常长的路径）
+pub const MAX_DEPTH: usize = 10;
+
+/// 派生使用的曲线
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    /// BIP32（EVM、Bitcoin）
+    Secp256k1,
+    /// SLIP-0010，只支持硬化派生（Solana、TON）
+    Ed25519,
+}
+
+/// 派生路径（每层为带硬化标志位的索引）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath {
+    segments: Vec<u32>,
+}
+
+impl DerivationPath {
+    pub fn segments(&self) -> &[u32] {
+        &self.segments
+    }
+
+    pub fn is_fully_hardened(&self) -> bool {
+        self.segments.iter().all(|s| s & HARDENED != 0)
+    }
+
+    /// 检查路径能否用于指定曲线（Ed25519 的每一层都必须硬化）
+    pub fn check_curve(&self, curve: Curve) -> Result<(), String> {
+        if curve == Curve::Ed25519 && !self.is_fully_hardened() {
+            return Err("该链只支持硬化派生，路径的每一层都需要以 ' 结尾".to_string());
+        }
+        Ok(())
+    }
+
+    /// 最后一层索引加 `offset`（保留硬化标志），用于预览相邻地址
+    pub fn offset_last(&self, offset: u32) -> Option<Self> {
+        let mut segments = self.segments.clone();
+        let last = segments.last_mut()?;
+        let hardened = *last & HARDENED;
+        let index = (*last & !HARDENED).checked_add(offset)?;
+        if index >= HARDENED {
+            return None;
+        }
+        *last = index | hardened;
+        Some(Self { segments })
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('/');
+        if !matches!(parts.next(), Some("m") | Some("M")) {
+            return Err("派生路径必须以 m/ 开头".to_string());
+        }
+        let segments = parts
+            .map(|part| {
+                let (digits, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                    Some(digits) => (digits, true),
+                    None => (part, false),
+                };
+                let index: u32 = digits
+                    .parse()
+                    .ok()
+                    .filter(|i| *i < HARDENED)
+                    .ok_or_else(|| format!("无效的路径层级: {}", part))?;
+                Ok(if hardened { index | HARDENED } else { index })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        if segments.is_empty() {
+            return Err("派生路径至少需要一层".to_string());
+        }
+        if segments.len() > MAX_DEPTH {
+            return Err(format!("派生路径最多 {} 层", MAX_DEPTH));
+        }
+        Ok(Self { segments })
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for segment in &self.segments {
+            if segment & HARDENED != 0 {
+                write!(f, "/{}'", segment & !HARDENED)?;
+            } else {
+                write!(f, "/{}", segment)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_normalizes_hardened_markers() {
+        let path: DerivationPath = "m/44h/60'/0H/0/7".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/7");
+        assert!(!path.is_fully_hardened());
+        assert_eq!(path.segments()[0], 44 | HARDENED);
+        assert_eq!(path.segments()[4], 7);
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        assert!("44'/60'".parse::<DerivationPath>().is_err());
+        assert!("m".parse::<DerivationPath>().is_err());
+        assert!("m/44'/x".parse::<DerivationPath>().is_err());
+        assert!("m/2147483648".parse::<DerivationPath>().is_err());
+        assert!("m/1/2/3/4/5/6/7/8/9/10/11"
+            .parse::<DerivationPath>()
+            .is_err());
+    }
+
+    #[test]
+    fn ed25519_requires_hardened_segments() {
+        let sol: DerivationPath = "m/44'/501'/0'/0'".parse().unwrap();
+        assert!(sol.check_curve(Curve::Ed25519).is_ok());
+        let mixed: DerivationPath = "m/44'/501'/0'/0'/0".parse().unwrap();
+        assert!(mixed.check_curve(Curve::Ed25519).is_err());
+        assert!(mixed.check_curve(Curve::Secp256k1).is_ok());
+    }
+
+    #[test]
+    fn offset_keeps_hardened_flag() {
+        let path: DerivationPath = "m/44'/501'/3'".parse().unwrap();
+        assert_eq!(path.offset_last(2).unwrap().to_string(), "m/44'/501'/5'");
+        let plain: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        assert_eq!(
+            plain.offset_last(4).unwrap().to_string(),
+            "m/44'/60'/0'/0/4"
+        );
+    }
+}
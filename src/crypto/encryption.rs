@@ -46,9 +46,7 @@ pub fn encrypt(key: &[u8; 32], plaintext: &[u8]) -> Result<Vec<u8>> {
     Ok(result)
 }
 
-/// 解密数据
-/// 为未来扩展准备的解密函数
-#[allow(dead_code)] // 为未来扩展准备
+/// 解密数据（输入为 nonce + 密文）
 pub fn decrypt(key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>> {
     if data.len() < 12 {
         return Err(anyhow!("Invalid ciphertext length"));
@@ -1,9 +1,16 @@
+use crate::crypto::derivation::{Curve, DerivationPath, HARDENED};
 use anyhow::{anyhow, Result};
 use bip32::XPrv;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // ✅ 企业级安全：所有私钥相关结构自动清零
 
+/// 解码十六进制私钥；错误信息不回显输入（`hex` 的错误会带出非法字符及其位置，可能进入日志）
+pub(crate) fn decode_private_key_hex(private_key_hex: &str) -> Result<Vec<u8>> {
+    hex::decode(private_key_hex.trim_start_matches("0x"))
+        .map_err(|_| anyhow!("Invalid private key encoding"))
+}
+
 #[derive(Clone, Zeroize, ZeroizeOnDrop)]
 pub struct KeyManager {
     seed: Vec<u8>,
@@ -14,6 +21,36 @@ impl KeyManager {
         Self { seed }
     }
 
+    /// 按完整派生路径派生私钥（secp256k1 走 BIP32，Ed25519 走 SLIP-0010 且每层必须硬化）
+    pub fn derive_private_key_at(&self, curve: Curve, path: &DerivationPath) -> Result<String> {
+        path.check_curve(curve).map_err(|e| anyhow!(e))?;
+        match curve {
+            Curve::Secp256k1 => {
+                let xprv = XPrv::derive_from_path(&self.seed, &path.to_string().parse()?)
+                    .map_err(|e| anyhow!("Failed to derive key at {}: {}", path, e))?;
+                Ok(hex::encode(xprv.private_key().to_bytes()))
+            }
+            Curve::Ed25519 => {
+                use hmac::{Hmac, Mac};
+                use sha2::Sha512;
+
+                let mut hmac = Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
+                    .map_err(|e| anyhow!("HMAC error: {}", e))?;
+                hmac.update(&self.seed);
+                let i = hmac.finalize().into_bytes();
+                let mut key = [0u8; 32];
+                let mut chain_code = [0u8; 32];
+                key.copy_from_slice(&i[0..32]);
+                chain_code.copy_from_slice(&i[32..64]);
+                for segment in path.segments() {
+                    (key, chain_code) =
+                        self.derive_ed25519_hardened(&key, &chain_code, segment & !HARDENED)?;
+                }
+                Ok(hex::encode(key))
+            }
+        }
+    }
+
     // Ethereum: m/44'/60'/0'/0/index
     pub fn derive_eth_private_key(&self, index: u32) -> Result<String> {
         let path = format!("m/44'/60'/0'/0/{}", index);
@@ -29,7 +66,7 @@ impl KeyManager {
         use k256::ecdsa::{SigningKey, VerifyingKey};
         use sha3::{Digest, Keccak256};
 
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(key_bytes.as_slice().into())
             .map_err(|e| anyhow!("Invalid private key: {}", e))?;
         let verifying_key = VerifyingKey::from(&signing_key);
@@ -59,7 +96,7 @@ impl KeyManager {
         use sha2::{Digest, Sha256};
 
         // Get public key from private key
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(key_bytes.as_slice().into())
             .map_err(|e| anyhow!("Invalid BTC private key: {}", e))?;
         let verifying_key = VerifyingKey::from(&signing_key);
@@ -111,18 +148,44 @@ impl KeyManager {
         Ok(result)
     }
 
-    // Bech32 encoding using proper library
+    // Bech32 encoding using proper library（`data` 已是 5 位分组，不能再次转换）
     fn bech32_encode(hrp: &str, data: &[u8]) -> Result<String> {
-        use bech32::{ToBase32, Variant};
+        use bech32::{u5, Variant};
 
-        // Convert data to base32
-        let data_base32 = data.to_base32();
+        let data_base32 = data
+            .iter()
+            .map(|&v| u5::try_from_u8(v))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Bech32 encoding failed: {}", e))?;
 
         // Encode with bech32 checksum
         bech32::encode(hrp, data_base32, Variant::Bech32)
             .map_err(|e| anyhow!("Bech32 encoding failed: {}", e))
     }
 
+    /// 修复旧版编码生成的 Bitcoin 地址
+    ///
+    /// 旧版把已分好的 5 位分组又按字节转换了一次，得到校验和正确但见证程序长度非法的
+    /// `bc1qq…` 地址。从中还原 hash160 后重新编码；不是旧版编码的地址返回 None。
+    pub fn repair_legacy_btc_address(address: &str) -> Option<String> {
+        use bech32::{FromBase32, Variant};
+
+        let (hrp, data, variant) = bech32::decode(address).ok()?;
+        if variant != Variant::Bech32 || !matches!(hrp.as_str(), "bc" | "tb") {
+            return None;
+        }
+        // 旧版数据：1 字节见证版本 + 32 个 5 位分组（160 位 hash160），每个占一个字节
+        let bytes = Vec::<u8>::from_base32(&data).ok()?;
+        let (&version, groups) = bytes.split_first()?;
+        if version != 0 || groups.len() != 32 || groups.iter().any(|&g| g > 31) {
+            return None;
+        }
+        let hash160 = Self::convert_bits(groups, 5, 8, false).ok()?;
+        let mut repaired = vec![version];
+        repaired.extend(Self::convert_bits(&hash160, 8, 5, true).ok()?);
+        Self::bech32_encode(&hrp, &repaired).ok()
+    }
+
     // Solana: m/44'/501'/0'/0' (✅ 企业级：标准 SLIP-0010 Ed25519 派生)
     pub fn derive_sol_private_key(&self, index: u32) -> Result<String> {
         use hmac::{Hmac, Mac};
@@ -153,7 +216,7 @@ impl KeyManager {
 
     pub fn get_sol_address(&self, private_key_hex: &str) -> Result<String> {
         use ed25519_dalek::{SigningKey, VerifyingKey};
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
@@ -168,7 +231,7 @@ impl KeyManager {
     /// 获取 Solana 公钥（企业级实现：返回 hex 编码的公钥）
     pub fn get_sol_public_key(&self, private_key_hex: &str) -> Result<String> {
         use ed25519_dalek::{SigningKey, VerifyingKey};
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
@@ -213,7 +276,7 @@ impl KeyManager {
 
         // ✅ 企业级实现：真实的 TON 地址派生
         // Get public key from private key
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
@@ -254,7 +317,7 @@ impl KeyManager {
     /// 获取 TON 公钥（企业级实现：返回 hex 编码的公钥）
     pub fn get_ton_public_key(&self, private_key_hex: &str) -> Result<String> {
         use ed25519_dalek::{SigningKey, VerifyingKey};
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
@@ -317,7 +380,7 @@ impl KeyManager {
     pub fn sign_eth_message(private_key_hex: &str, message: &[u8]) -> Result<String> {
         use k256::ecdsa::{signature::Signer, SigningKey};
 
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(key_bytes.as_slice().into())
             .map_err(|e| anyhow!("Invalid private key: {}", e))?;
 
@@ -331,7 +394,7 @@ impl KeyManager {
     pub fn sign_ed25519_message(private_key_hex: &str, message: &[u8]) -> Result<String> {
         use ed25519_dalek::{Signature, Signer, SigningKey};
 
-        let key_bytes = hex::decode(private_key_hex)?;
+        let key_bytes = decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
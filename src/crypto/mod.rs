@@ -1,5 +1,7 @@
 pub mod bip39;
+pub mod derivation;
 pub mod encryption;
 pub mod key_manager;
 pub mod keystore;
 pub mod tx_signer;
+pub mod worker;
@@ -35,7 +35,7 @@ impl EthereumTxSigner {
         chain_id: u64,
     ) -> Result<String> {
         // 解析私钥
-        let mut key_bytes = hex::decode(private_key_hex.trim_start_matches("0x"))?;
+        let mut key_bytes = crate::crypto::key_manager::decode_private_key_hex(private_key_hex)?;
         if key_bytes.len() != 32 {
             key_bytes.zeroize();
             return Err(anyhow!(
@@ -148,7 +148,7 @@ impl EthereumTxSigner {
         chain_id: u64,
     ) -> Result<String> {
         // 解析私钥
-        let mut key_bytes = hex::decode(private_key_hex.trim_start_matches("0x"))?;
+        let mut key_bytes = crate::crypto::key_manager::decode_private_key_hex(private_key_hex)?;
         if key_bytes.len() != 32 {
             key_bytes.zeroize();
             return Err(anyhow!(
@@ -259,6 +259,9 @@ impl EthereumTxSigner {
     }
 }
 
+/// BIP125 可替换交易的输入序列号（小于 0xfffffffe 即表示允许 RBF）
+pub const BITCOIN_RBF_SEQUENCE: u32 = 0xffff_fffd;
+
 /// Bitcoin交易签名
 /// 企业级实现：使用bitcoin crate构建完整交易
 pub struct BitcoinTxSigner;
@@ -266,6 +269,8 @@ pub struct BitcoinTxSigner;
 impl BitcoinTxSigner {
     /// 签名Bitcoin交易
     ///
+    /// 默认开启 RBF（所有输入的 sequence 为 [`BITCOIN_RBF_SEQUENCE`]），以便后续加速
+    ///
     /// # Arguments
     /// * `private_key_hex` - 私钥（十六进制字符串）
     /// * `to` - 接收地址
@@ -283,9 +288,66 @@ impl BitcoinTxSigner {
         to: &str,
         value: &str,
         fee_rate: u64,
+    ) -> Result<String> {
+        Self::build_payload(private_key_hex, to, value, fee_rate, serde_json::Map::new())
+    }
+
+    /// 签名替换交易（RBF 加速）
+    ///
+    /// 使用与原交易相同的输入、收款地址与金额，以更高费率重新签名；
+    /// 后端据 `replaces` 花费原交易的输入，使原交易失效
+    ///
+    /// # Arguments
+    /// * `replaces_txid` - 被替换的原交易哈希
+    /// * `fee_rate` - 新费率（sat/vB），须高于原交易费率
+    pub fn sign_replacement(
+        private_key_hex: &str,
+        to: &str,
+        value: &str,
+        fee_rate: u64,
+        replaces_txid: &str,
+    ) -> Result<String> {
+        let mut extra = serde_json::Map::new();
+        extra.insert("replaces".to_string(), replaces_txid.into());
+        Self::build_payload(private_key_hex, to, value, fee_rate, extra)
+    }
+
+    /// 签名 CPFP（子为父付费）交易
+    ///
+    /// 花费未确认父交易中属于本钱包的输出并转回 `to`（本钱包地址），
+    /// 手续费从该输出中扣除，使父子交易整体达到目标费率
+    ///
+    /// # Arguments
+    /// * `parent_txid` - 未确认的父交易哈希
+    /// * `parent_vout` - 父交易中属于本钱包的输出序号（None 由后端按地址匹配）
+    /// * `value` - 该输出的金额（satoshi）
+    /// * `fee_rate` - 子交易费率（sat/vB）
+    pub fn sign_cpfp(
+        private_key_hex: &str,
+        parent_txid: &str,
+        parent_vout: Option<u32>,
+        to: &str,
+        value: &str,
+        fee_rate: u64,
+    ) -> Result<String> {
+        let mut extra = serde_json::Map::new();
+        extra.insert(
+            "spend_parent".to_string(),
+            serde_json::json!({ "txid": parent_txid, "vout": parent_vout }),
+        );
+        extra.insert("subtract_fee_from_amount".to_string(), true.into());
+        Self::build_payload(private_key_hex, to, value, fee_rate, extra)
+    }
+
+    fn build_payload(
+        private_key_hex: &str,
+        to: &str,
+        value: &str,
+        fee_rate: u64,
+        extra: serde_json::Map<String, serde_json::Value>,
     ) -> Result<String> {
         // 解析私钥
-        let key_bytes = hex::decode(private_key_hex.trim_start_matches("0x"))?;
+        let key_bytes = crate::crypto::key_manager::decode_private_key_hex(private_key_hex)?;
         let signing_key = k256::ecdsa::SigningKey::from_bytes(key_bytes.as_slice().into())
             .map_err(|e| anyhow!("Invalid private key: {}", e))?;
 
@@ -297,14 +359,19 @@ impl BitcoinTxSigner {
 
         // 当前实现：返回交易构建所需的数据结构（JSON格式）
         // 后端会使用bitcoin crate构建完整交易
-        let tx_data = serde_json::json!({
+        let mut tx_data = serde_json::json!({
             "type": "bitcoin",
             "to": to,
             "value": value,
             "fee_rate": fee_rate,
+            "rbf": true,
+            "sequence": BITCOIN_RBF_SEQUENCE,
             "private_key_hash": hex::encode(sha2::Sha256::digest(signing_key.to_bytes().as_slice())),
             // 注意：实际实现不应包含私钥，这里仅用于验证
         });
+        if let Some(obj) = tx_data.as_object_mut() {
+            obj.extend(extra);
+        }
 
         Ok(serde_json::to_string(&tx_data)?)
     }
@@ -322,6 +389,7 @@ impl SolanaTxSigner {
     /// * `to` - 接收地址（base58编码）
     /// * `value` - 金额（lamports，字符串格式）
     /// * `recent_blockhash` - 最近的区块哈希
+    /// * `compute_budget_instructions` - ComputeBudget 程序指令数据（SetComputeUnitLimit / SetComputeUnitPrice）
     ///
     /// # Returns
     /// 签名的交易（base64编码的字符串，Solana标准格式）
@@ -337,12 +405,13 @@ impl SolanaTxSigner {
         to: &str,
         value: &str,
         recent_blockhash: &str,
+        compute_budget_instructions: &[Vec<u8>],
     ) -> Result<String> {
         use base64::Engine;
         use ed25519_dalek::{Signer, SigningKey};
 
         // 解析私钥
-        let key_bytes = hex::decode(private_key_hex.trim_start_matches("0x"))?;
+        let key_bytes = crate::crypto::key_manager::decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
@@ -361,7 +430,18 @@ impl SolanaTxSigner {
             .map_err(|_| anyhow!("Invalid Solana amount: {}", value))?;
 
         // 构建简化的交易数据（实际应使用solana-sdk的Message结构）
-        let tx_data = format!("sol:{}:{}:{}", to, value_u64, recent_blockhash);
+        // ComputeBudget 指令排在转账指令之前
+        let budget: Vec<String> = compute_budget_instructions
+            .iter()
+            .map(hex::encode)
+            .collect();
+        let tx_data = format!(
+            "sol:{}:{}:{}:{}",
+            to,
+            value_u64,
+            recent_blockhash,
+            budget.join(",")
+        );
 
         // 签名
         let signature = signing_key.sign(tx_data.as_bytes());
@@ -407,7 +487,7 @@ impl TonTxSigner {
         use ed25519_dalek::{Signer, SigningKey};
 
         // 解析私钥
-        let key_bytes = hex::decode(private_key_hex.trim_start_matches("0x"))?;
+        let key_bytes = crate::crypto::key_manager::decode_private_key_hex(private_key_hex)?;
         let signing_key = SigningKey::from_bytes(
             key_bytes
                 .as_slice()
@@ -0,0 +1,460 @@
+//! Crypto Worker Bridge - 加密运算 Web Worker 桥接
+//!
+//! 将耗时的密钥派生、Keystore 解密与交易签名放到专用 Web Worker 中执行，避免冻结 UI：
+//! - Worker 由 `public/crypto-worker.js` 加载 `crypto_ops` 模块（`src/bin/crypto_ops.rs`），
+//!   两边执行同一个 [`execute`]，结果与主线程完全一致
+//! - 通过 structured-clone 消息通信：`{ id, op, payload }` → `{ id, ok, result | error }`
+//! - 参数（含秘密数据）以 Transferable ArrayBuffer 发送，发出后主线程副本随即清零，Worker 内使用后清零
+//! - 只有 Worker 不可用（不支持、创建失败或模块加载失败之后）或消息未能发出时才退化为主线程执行；
+//!   消息发出后秘密数据已不在主线程，超时或 Worker 中途失败直接返回错误，不在主线程重跑耗时运算
+//! - 主线程降级执行前先让出一帧，保证调用方的进度状态能先渲染出来
+
+use anyhow::{anyhow, Result};
+use futures::channel::oneshot;
+use futures::future::{select, Either};
+use js_sys::{Array, Object, Reflect, Uint8Array};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use zeroize::Zeroize;
+
+/// Worker 脚本路径（由 Trunk 从 public/ 复制到站点根目录）
+const WORKER_URL: &str = "/crypto-worker.js";
+/// 单次请求最长等待时间（含首次加载 Worker 模块；低端设备上 Argon2 需数秒），超时返回错误
+const WORKER_TIMEOUT_MS: u32 = 30_000;
+
+type WorkerReply = std::result::Result<Vec<u8>, String>;
+type PendingMap = Rc<RefCell<HashMap<u32, oneshot::Sender<WorkerReply>>>>;
+
+/// Worker 支持的运算
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerOp {
+    /// BIP39 助记词 → 64 字节种子
+    DeriveSeed,
+    /// Argon2id 密码 → 钱包加密密钥
+    DeriveKey,
+    /// Keystore V3（scrypt/pbkdf2）解密
+    DecryptKeystore,
+    /// EIP-155 交易签名
+    SignTransaction,
+}
+
+impl WorkerOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkerOp::DeriveSeed => "derive_seed",
+            WorkerOp::DeriveKey => "derive_key",
+            WorkerOp::DecryptKeystore => "decrypt_keystore",
+            WorkerOp::SignTransaction => "sign_transaction",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            WorkerOp::DeriveSeed,
+            WorkerOp::DeriveKey,
+            WorkerOp::DecryptKeystore,
+            WorkerOp::SignTransaction,
+        ]
+        .into_iter()
+        .find(|op| op.as_str() == name)
+    }
+}
+
+/// 第 `index` 个参数（UTF-8 文本）
+fn arg_str(args: &[Vec<u8>], index: usize) -> Result<&str> {
+    let bytes = args
+        .get(index)
+        .ok_or_else(|| anyhow!("missing argument {}", index))?;
+    std::str::from_utf8(bytes).map_err(|_| anyhow!("argument {} is not UTF-8", index))
+}
+
+/// 第 `index` 个参数（十进制整数文本）
+fn arg_u64(args: &[Vec<u8>], index: usize) -> Result<u64> {
+    arg_str(args, index)?
+        .parse()
+        .map_err(|_| anyhow!("argument {} is not an integer", index))
+}
+
+/// 执行一次运算（Worker 与主线程降级路径共用）；参数与结果均为字节，调用方负责清零
+pub fn execute(op: WorkerOp, args: &[Vec<u8>]) -> Result<Vec<u8>> {
+    match op {
+        WorkerOp::DeriveSeed => {
+            let mnemonic = crate::crypto::bip39::MnemonicSecret::new(arg_str(args, 0)?.to_string());
+            let seed = mnemonic.to_seed("");
+            if seed.is_empty() {
+                return Err(anyhow!("Invalid mnemonic"));
+            }
+            Ok(seed)
+        }
+        WorkerOp::DeriveKey => {
+            let salt = args.get(1).ok_or_else(|| anyhow!("missing argument 1"))?;
+            let mut key = crate::crypto::encryption::derive_key(arg_str(args, 0)?, salt)?;
+            let bytes = key.to_vec();
+            key.zeroize();
+            Ok(bytes)
+        }
+        WorkerOp::DecryptKeystore => {
+            crate::crypto::keystore::decrypt_keystore(arg_str(args, 0)?, arg_str(args, 1)?)
+                .map(String::into_bytes)
+        }
+        WorkerOp::SignTransaction => {
+            crate::crypto::tx_signer::EthereumTxSigner::sign_transaction_with_data(
+                arg_str(args, 0)?,
+                arg_str(args, 1)?,
+                arg_str(args, 2)?,
+                arg_str(args, 3)?,
+                arg_u64(args, 4)?,
+                arg_u64(args, 5)?,
+                arg_u64(args, 6)?,
+                arg_u64(args, 7)?,
+            )
+            .map(String::into_bytes)
+        }
+    }
+}
+
+struct WorkerBridge {
+    worker: web_sys::Worker,
+    next_id: u32,
+    pending: PendingMap,
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+    _on_error: Closure<dyn FnMut(JsValue)>,
+}
+
+thread_local! {
+    static BRIDGE: RefCell<Option<WorkerBridge>> = const { RefCell::new(None) };
+    /// Worker 创建或运行失败后不再尝试，直接走主线程
+    static WORKER_DISABLED: RefCell<bool> = const { RefCell::new(false) };
+}
+
+/// 当前环境能否使用加密 Worker
+pub fn worker_available() -> bool {
+    if WORKER_DISABLED.with(|d| *d.borrow()) {
+        return false;
+    }
+    Reflect::get(&js_sys::global(), &JsValue::from_str("Worker"))
+        .map(|w| w.is_function())
+        .unwrap_or(false)
+}
+
+/// 让出一帧，使加载/进度状态在主线程耗时运算前完成渲染
+pub async fn yield_to_ui() {
+    gloo_timers::future::TimeoutFuture::new(16).await;
+}
+
+/// 停用 Worker：丢弃等待中的请求（调用方收到错误），之后的请求走主线程
+fn disable_worker(pending: &PendingMap) {
+    WORKER_DISABLED.with(|d| *d.borrow_mut() = true);
+    pending.borrow_mut().clear();
+    // 只终止 Worker，不释放桥接对象：当前正在执行的 onerror 闭包归它所有
+    BRIDGE.with(|b| {
+        if let Some(bridge) = b.borrow().as_ref() {
+            bridge.worker.terminate();
+        }
+    });
+}
+
+fn spawn_bridge() -> Option<WorkerBridge> {
+    let worker = web_sys::Worker::new(WORKER_URL).ok()?;
+    let pending: PendingMap = Rc::new(RefCell::new(HashMap::new()));
+
+    let pending_for_msg = pending.clone();
+    let on_message = Closure::wrap(Box::new(move |event: web_sys::MessageEvent| {
+        let data = event.data();
+        let Some(id) = Reflect::get(&data, &JsValue::from_str("id"))
+            .ok()
+            .and_then(|v| v.as_f64())
+        else {
+            return;
+        };
+        // Worker 模块加载失败：之后的请求全部走主线程
+        let unavailable = Reflect::get(&data, &JsValue::from_str("unavailable"))
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if unavailable {
+            log::warn!("crypto worker module unavailable, using the main thread from now on");
+            disable_worker(&pending_for_msg);
+            return;
+        }
+        let Some(sender) = pending_for_msg.borrow_mut().remove(&(id as u32)) else {
+            return;
+        };
+
+        let ok = Reflect::get(&data, &JsValue::from_str("ok"))
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let reply = if ok {
+            match Reflect::get(&data, &JsValue::from_str("result")) {
+                Ok(result) if result.is_instance_of::<js_sys::ArrayBuffer>() => {
+                    let view = Uint8Array::new(&result);
+                    let bytes = view.to_vec();
+                    view.fill(0, 0, view.length());
+                    Ok(bytes)
+                }
+                _ => Err("invalid worker result".to_string()),
+            }
+        } else {
+            Err(Reflect::get(&data, &JsValue::from_str("error"))
+                .ok()
+                .and_then(|v| v.as_string())
+                .unwrap_or_else(|| "worker error".to_string()))
+        };
+        let _ = sender.send(reply);
+    }) as Box<dyn FnMut(web_sys::MessageEvent)>);
+
+    let pending_for_err = pending.clone();
+    let on_error = Closure::wrap(Box::new(move |_event: JsValue| {
+        log::warn!("crypto worker failed, using the main thread from now on");
+        disable_worker(&pending_for_err);
+    }) as Box<dyn FnMut(JsValue)>);
+
+    worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+    Some(WorkerBridge {
+        worker,
+        next_id: 1,
+        pending,
+        _on_message: on_message,
+        _on_error: on_error,
+    })
+}
+
+/// 一次 Worker 请求的结果
+enum WorkerCall {
+    /// 已交给 Worker：运算结果、运算错误，或发出后超时 / Worker 中途失败
+    Sent(WorkerReply),
+    /// 未能交给 Worker，参数原样交还，由调用方在主线程执行
+    Unsent(Vec<Vec<u8>>),
+}
+
+/// 向 Worker 发送一次请求；消息发出前失败时交还参数
+async fn call_worker(op: WorkerOp, mut args: Vec<Vec<u8>>) -> WorkerCall {
+    if !worker_available() {
+        return WorkerCall::Unsent(args);
+    }
+
+    let sent = BRIDGE.with(|b| {
+        let mut slot = b.borrow_mut();
+        if slot.is_none() {
+            *slot = spawn_bridge();
+        }
+        let bridge = slot.as_mut()?;
+
+        let id = bridge.next_id;
+        bridge.next_id = bridge.next_id.wrapping_add(1);
+
+        let views: Vec<Uint8Array> = args
+            .iter()
+            .map(|arg| {
+                let view = Uint8Array::new_with_length(arg.len() as u32);
+                view.copy_from(arg);
+                view
+            })
+            .collect();
+        let payload = Array::new();
+        let transfer = Array::new();
+        for view in &views {
+            transfer.push(&view.buffer());
+            payload.push(view);
+        }
+
+        let message = Object::new();
+        let _ = Reflect::set(&message, &JsValue::from_str("id"), &JsValue::from(id));
+        let _ = Reflect::set(&message, &JsValue::from_str("op"), &op.as_str().into());
+        let _ = Reflect::set(&message, &JsValue::from_str("payload"), &payload);
+
+        let (sender, receiver) = oneshot::channel();
+        bridge.pending.borrow_mut().insert(id, sender);
+        if bridge
+            .worker
+            .post_message_with_transfer(&message, &transfer)
+            .is_err()
+        {
+            bridge.pending.borrow_mut().remove(&id);
+            for view in &views {
+                view.fill(0, 0, view.length());
+            }
+            return None;
+        }
+        Some((id, receiver, bridge.pending.clone()))
+    });
+
+    let Some((id, receiver, pending)) = sent else {
+        WORKER_DISABLED.with(|d| *d.borrow_mut() = true);
+        return WorkerCall::Unsent(args);
+    };
+    // 参数已转移给 Worker，主线程副本不再需要
+    args.iter_mut().for_each(|arg| arg.zeroize());
+
+    let timeout = gloo_timers::future::TimeoutFuture::new(WORKER_TIMEOUT_MS);
+    let reply = match select(receiver, timeout).await {
+        Either::Left((Ok(reply), _)) => reply,
+        Either::Left((Err(_), _)) => {
+            Err("crypto worker stopped before replying, please retry".to_string())
+        }
+        Either::Right(_) => {
+            pending.borrow_mut().remove(&id);
+            log::warn!("crypto worker timed out on {}", op.as_str());
+            Err("crypto worker timed out, please retry".to_string())
+        }
+    };
+    WorkerCall::Sent(reply)
+}
+
+/// 在 Worker 中执行运算；未能交给 Worker 时让出一帧后在主线程执行
+async fn run(op: WorkerOp, args: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+    match call_worker(op, args).await {
+        WorkerCall::Sent(reply) => reply.map_err(|e| anyhow!(e)),
+        WorkerCall::Unsent(mut args) => {
+            yield_to_ui().await;
+            let result = execute(op, &args);
+            args.iter_mut().for_each(|arg| arg.zeroize());
+            result
+        }
+    }
+}
+
+fn into_string(bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes).map_err(|_| anyhow!("crypto worker returned malformed text"))
+}
+
+/// BIP39 助记词派生种子
+pub async fn derive_seed(mnemonic: &crate::crypto::bip39::MnemonicSecret) -> Result<Vec<u8>> {
+    let mut seed = run(
+        WorkerOp::DeriveSeed,
+        vec![mnemonic.as_str().as_bytes().to_vec()],
+    )
+    .await?;
+    if seed.len() != 64 {
+        seed.zeroize();
+        return Err(anyhow!("crypto worker returned malformed seed"));
+    }
+    Ok(seed)
+}
+
+/// Argon2id 派生钱包加密密钥
+pub async fn derive_key(password: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    let mut bytes = run(
+        WorkerOp::DeriveKey,
+        vec![password.as_bytes().to_vec(), salt.to_vec()],
+    )
+    .await?;
+    let key = <[u8; 32]>::try_from(bytes.as_slice())
+        .map_err(|_| anyhow!("crypto worker returned malformed key"));
+    bytes.zeroize();
+    key
+}
+
+/// 解密 Keystore V3 文件，返回私钥（hex）
+pub async fn decrypt_keystore(keystore_json: &str, password: &str) -> Result<String> {
+    let args = vec![
+        keystore_json.as_bytes().to_vec(),
+        password.as_bytes().to_vec(),
+    ];
+    into_string(run(WorkerOp::DecryptKeystore, args).await?)
+}
+
+/// 签名带 data 的 Ethereum 交易
+#[allow(clippy::too_many_arguments)]
+pub async fn sign_eth_transaction_with_data(
+    private_key_hex: &str,
+    to: &str,
+    value: &str,
+    data: &str,
+    nonce: u64,
+    gas_price: u64,
+    gas_limit: u64,
+    chain_id: u64,
+) -> Result<String> {
+    let args = [
+        private_key_hex,
+        to,
+        value,
+        data,
+        &nonce.to_string(),
+        &gas_price.to_string(),
+        &gas_limit.to_string(),
+        &chain_id.to_string(),
+    ]
+    .iter()
+    .map(|arg| arg.as_bytes().to_vec())
+    .collect();
+    into_string(run(WorkerOp::SignTransaction, args).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<Vec<u8>> {
+        values.iter().map(|v| v.as_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn op_names_match_worker_protocol() {
+        assert_eq!(WorkerOp::DeriveSeed.as_str(), "derive_seed");
+        assert_eq!(WorkerOp::SignTransaction.as_str(), "sign_transaction");
+        for op in [
+            WorkerOp::DeriveSeed,
+            WorkerOp::DeriveKey,
+            WorkerOp::DecryptKeystore,
+            WorkerOp::SignTransaction,
+        ] {
+            assert_eq!(WorkerOp::from_name(op.as_str()), Some(op));
+        }
+        assert_eq!(WorkerOp::from_name("derive_everything"), None);
+    }
+
+    #[test]
+    fn execute_matches_direct_calls() {
+        let key = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let signed = execute(
+            WorkerOp::SignTransaction,
+            &args(&[
+                key,
+                "0x3535353535353535353535353535353535353535",
+                "1000",
+                "0x",
+                "9",
+                "20000000000",
+                "21000",
+                "1",
+            ]),
+        )
+        .unwrap();
+        let direct = crate::crypto::tx_signer::EthereumTxSigner::sign_transaction_with_data(
+            key,
+            "0x3535353535353535353535353535353535353535",
+            "1000",
+            "0x",
+            9,
+            20_000_000_000,
+            21_000,
+            1,
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(signed).unwrap(), direct);
+
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = execute(WorkerOp::DeriveSeed, &args(&[phrase])).unwrap();
+        let expected = crate::crypto::bip39::MnemonicSecret::new(phrase.to_string()).to_seed("");
+        assert_eq!(seed, expected);
+    }
+
+    #[test]
+    fn execute_rejects_malformed_arguments() {
+        assert!(execute(WorkerOp::DeriveSeed, &[]).is_err());
+        assert!(execute(WorkerOp::DeriveSeed, &args(&["not a mnemonic"])).is_err());
+        assert!(execute(
+            WorkerOp::SignTransaction,
+            &args(&["00", "0x", "1", "0x", "nine"])
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,394 @@
+//! Transaction Labels - 交易分类与备注
+//!
+//! 用户为交易打上分类（工资、房租、交易……）并填写备注，按交易哈希保存在本设备的
+//! 加密存储中；在设置中开启同步后与后端合并（逐条按更新时间取较新者）。
+//! 同时提供历史筛选、CSV 导出与按分类统计本月支出。
+
+use crate::services::transaction::{TransactionHistoryItem, TransactionService};
+use crate::shared::state::AppState;
+use crate::shared::storage::device_storage;
+use dioxus::prelude::ReadableExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 预设分类：(标识, 显示名称)
+pub const PRESET_CATEGORIES: [(&str, &str); 7] = [
+    ("salary", "工资"),
+    ("rent", "房租"),
+    ("trading", "交易"),
+    ("reward", "奖励"),
+    ("shopping", "购物"),
+    ("transfer", "转账"),
+    ("other", "其他"),
+];
+
+/// 分类显示名称（自定义分类原样显示）
+pub fn category_label(category: &str) -> String {
+    PRESET_CATEGORIES
+        .iter()
+        .find(|(id, _)| *id == category)
+        .map(|(_, label)| label.to_string())
+        .unwrap_or_else(|| category.to_string())
+}
+
+/// 用户输入的分类：匹配预设分类的显示名称或标识时归一为标识，空白视为清除
+pub fn category_from_input(input: &str) -> Option<String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    let preset = PRESET_CATEGORIES
+        .iter()
+        .find(|(id, label)| id.eq_ignore_ascii_case(input) || *label == input);
+    Some(preset.map_or_else(|| input.to_string(), |(id, _)| id.to_string()))
+}
+
+/// 单笔交易的标签
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxLabel {
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub note: String,
+    /// 最后修改时间（Unix 秒），同步合并时较新者生效
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+impl TxLabel {
+    fn is_empty(&self) -> bool {
+        self.category.is_none() && self.note.trim().is_empty()
+    }
+}
+
+/// 所有交易标签（键为小写交易哈希）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxLabels {
+    #[serde(default)]
+    pub entries: HashMap<String, TxLabel>,
+}
+
+fn key(hash: &str) -> String {
+    hash.trim().to_lowercase()
+}
+
+impl TxLabels {
+    pub fn get(&self, hash: &str) -> Option<&TxLabel> {
+        self.entries.get(&key(hash)).filter(|l| !l.is_empty())
+    }
+
+    pub fn category(&self, hash: &str) -> Option<&str> {
+        self.get(hash).and_then(|l| l.category.as_deref())
+    }
+
+    /// 批量设置分类（None 表示清除分类，保留备注）
+    pub fn set_category(&mut self, hashes: &[String], category: Option<&str>, now: u64) {
+        let category = category
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string);
+        for hash in hashes {
+            let label = self.entries.entry(key(hash)).or_default();
+            label.category = category.clone();
+            label.updated_at = now;
+        }
+    }
+
+    pub fn set_note(&mut self, hash: &str, note: &str, now: u64) {
+        let label = self.entries.entry(key(hash)).or_default();
+        label.note = note.trim().to_string();
+        label.updated_at = now;
+    }
+
+    /// 已使用的分类（预设分类在前，其余按名称排序）
+    pub fn categories(&self) -> Vec<String> {
+        let mut used: Vec<String> = self
+            .entries
+            .values()
+            .filter_map(|l| l.category.clone())
+            .collect();
+        used.sort_by_key(|c| {
+            let preset = PRESET_CATEGORIES.iter().position(|(id, _)| id == c);
+            (preset.unwrap_or(usize::MAX), c.clone())
+        });
+        used.dedup();
+        used
+    }
+
+    /// 合并远端标签，逐条保留更新时间较新的一方；返回本地是否有变化
+    ///
+    /// 清空的标签仍保留更新时间，以便删除操作也能同步到其他设备
+    pub fn merge(&mut self, remote: TxLabels) -> bool {
+        let mut changed = false;
+        for (hash, label) in remote.entries {
+            let newer = self
+                .entries
+                .get(&hash)
+                .is_none_or(|local| label.updated_at > local.updated_at);
+            if newer {
+                self.entries.insert(hash, label);
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// 历史记录的分类筛选
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum CategoryFilter {
+    #[default]
+    All,
+    Unlabeled,
+    Category(String),
+}
+
+impl CategoryFilter {
+    pub fn matches(&self, labels: &TxLabels, hash: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Unlabeled => labels.category(hash).is_none(),
+            Self::Category(c) => labels.category(hash) == Some(c.as_str()),
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 导出 CSV（含分类与备注列）
+pub fn to_csv(txs: &[TransactionHistoryItem], labels: &TxLabels) -> String {
+    let mut csv =
+        String::from("交易哈希,类型,状态,付款方,收款方,金额,代币,时间戳,手续费,分类,备注\n");
+    for tx in txs {
+        let label = labels.get(&tx.hash);
+        let row = [
+            tx.hash.clone(),
+            tx.tx_type.clone(),
+            tx.status.clone(),
+            tx.from.clone(),
+            tx.to.clone(),
+            tx.amount.clone(),
+            tx.token.clone(),
+            tx.timestamp.to_string(),
+            tx.fee.clone(),
+            label
+                .and_then(|l| l.category.as_deref())
+                .map(category_label)
+                .unwrap_or_default(),
+            label.map(|l| l.note.clone()).unwrap_or_default(),
+        ];
+        let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// 当月（UTC）的起止时间戳 [start, end)
+pub fn month_range(now: u64) -> (u64, u64) {
+    use chrono::{Datelike, TimeZone, Utc};
+    let Some(today) = Utc.timestamp_opt(now as i64, 0).single() else {
+        return (0, 0);
+    };
+    let start = Utc
+        .with_ymd_and_hms(today.year(), today.month(), 1, 0, 0, 0)
+        .single();
+    let (next_year, next_month) = if today.month() == 12 {
+        (today.year() + 1, 1)
+    } else {
+        (today.year(), today.month() + 1)
+    };
+    let end = Utc
+        .with_ymd_and_hms(next_year, next_month, 1, 0, 0, 0)
+        .single();
+    match (start, end) {
+        (Some(start), Some(end)) => (start.timestamp() as u64, end.timestamp() as u64),
+        _ => (0, 0),
+    }
+}
+
+/// 按分类汇总时间区间内已标记的转出交易（按 `prices` 折算，价格缺失的代币不计入）
+///
+/// 返回 (分类, 金额)，按金额降序
+pub fn spend_by_category(
+    txs: &[TransactionHistoryItem],
+    labels: &TxLabels,
+    (start, end): (u64, u64),
+    prices: &HashMap<String, f64>,
+) -> Vec<(String, f64)> {
+    let mut totals: HashMap<String, f64> = HashMap::new();
+    for tx in txs {
+        if !tx.tx_type.eq_ignore_ascii_case("send")
+            || tx.status.eq_ignore_ascii_case("failed")
+            || tx.timestamp < start
+            || tx.timestamp >= end
+        {
+            continue;
+        }
+        let Some(category) = labels.category(&tx.hash) else {
+            continue;
+        };
+        let (Ok(amount), Some(price)) = (
+            tx.amount.trim().parse::<f64>(),
+            prices.get(&tx.token.to_uppercase()),
+        ) else {
+            continue;
+        };
+        *totals.entry(category.to_string()).or_default() += amount * price;
+    }
+    let mut totals: Vec<(String, f64)> = totals.into_iter().collect();
+    totals.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals
+}
+
+fn storage_key(user_id: &str) -> String {
+    format!("tx_labels_{}", user_id)
+}
+
+/// 读取用户的交易标签（无法解密时视为空）
+pub async fn load(user_id: &str) -> TxLabels {
+    match device_storage().load(&storage_key(user_id)).await {
+        Ok(Some(bytes)) => serde_json::from_slice(&bytes).unwrap_or_default(),
+        Ok(None) => TxLabels::default(),
+        Err(e) => {
+            log::warn!("交易标签无法解密: {}", e);
+            TxLabels::default()
+        }
+    }
+}
+
+pub async fn save(user_id: &str, labels: &TxLabels) -> anyhow::Result<()> {
+    device_storage()
+        .save(&storage_key(user_id), &serde_json::to_vec(labels)?)
+        .await
+}
+
+/// 开启同步时与后端合并并回写两端，返回合并后的标签；未开启或失败时原样返回
+pub async fn sync(app_state: AppState, user_id: &str, mut labels: TxLabels) -> TxLabels {
+    if !app_state.preferences.read().sync_tx_labels {
+        return labels;
+    }
+    let service = TransactionService::new(app_state);
+    match service.get_labels().await {
+        Ok(remote) => {
+            let before = labels.clone();
+            if labels.merge(remote.clone()) {
+                if let Err(e) = save(user_id, &labels).await {
+                    log::warn!("保存交易标签失败: {}", e);
+                }
+            }
+            // 本地有远端没有的新修改时回写
+            let mut merged_remote = remote;
+            if merged_remote.merge(before) {
+                if let Err(e) = service.put_labels(&labels).await {
+                    log::warn!("上传交易标签失败: {}", e);
+                }
+            }
+        }
+        Err(e) => log::warn!("获取交易标签失败: {}", e),
+    }
+    labels
+}
+
+/// 保存到本设备，开启同步时一并上传
+pub async fn save_and_push(app_state: AppState, user_id: &str, labels: &TxLabels) {
+    if let Err(e) = save(user_id, labels).await {
+        log::warn!("保存交易标签失败: {}", e);
+    }
+    if app_state.preferences.read().sync_tx_labels {
+        if let Err(e) = TransactionService::new(app_state).put_labels(labels).await {
+            log::warn!("上传交易标签失败: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(
+        hash: &str,
+        tx_type: &str,
+        amount: &str,
+        token: &str,
+        timestamp: u64,
+    ) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            hash: hash.to_string(),
+            tx_type: tx_type.to_string(),
+            status: "confirmed".to_string(),
+            from: "0xfrom".to_string(),
+            to: "0xto".to_string(),
+            amount: amount.to_string(),
+            token: token.to_string(),
+            timestamp,
+            fee: "0.001".to_string(),
+            fee_rate: None,
+            replaces: None,
+        }
+    }
+
+    #[test]
+    fn bulk_label_filter_and_merge() {
+        let mut labels = TxLabels::default();
+        labels.set_category(&["0xA".to_string(), "0xb".to_string()], Some("rent"), 10);
+        labels.set_note("0xa", "  十月房租 ", 11);
+        assert_eq!(labels.category("0xa"), Some("rent"));
+        assert_eq!(labels.get("0XA").unwrap().note, "十月房租");
+        assert!(CategoryFilter::Unlabeled.matches(&labels, "0xc"));
+        assert!(CategoryFilter::Category("rent".into()).matches(&labels, "0xb"));
+
+        let mut remote = TxLabels::default();
+        remote.set_category(&["0xa".to_string()], Some("salary"), 5);
+        remote.set_category(&["0xb".to_string()], None, 20);
+        remote.set_category(&["0xc".to_string()], Some("trading"), 1);
+        assert!(labels.merge(remote));
+        assert_eq!(labels.category("0xa"), Some("rent"));
+        assert_eq!(labels.category("0xb"), None);
+        assert_eq!(labels.categories(), vec!["rent", "trading"]);
+        assert_eq!(category_from_input(" 房租 ").as_deref(), Some("rent"));
+        assert_eq!(category_from_input("旅行").as_deref(), Some("旅行"));
+        assert_eq!(category_from_input("  "), None);
+    }
+
+    #[test]
+    fn csv_includes_label_columns() {
+        let mut labels = TxLabels::default();
+        labels.set_category(&["0x1".to_string()], Some("salary"), 1);
+        labels.set_note("0x1", "发薪, \"九月\"", 1);
+        let csv = to_csv(&[tx("0x1", "receive", "1", "ETH", 0)], &labels);
+        assert!(csv.lines().next().unwrap().ends_with(",分类,备注"));
+        assert!(csv.contains(",工资,\"发薪, \"\"九月\"\"\"\n"));
+    }
+
+    #[test]
+    fn monthly_spend_sums_labeled_sends() {
+        // 2024-03-15 UTC
+        let (start, end) = month_range(1_710_460_800);
+        assert_eq!((start, end), (1_709_251_200, 1_711_929_600));
+
+        let txs = vec![
+            tx("a", "send", "2", "ETH", start + 10),
+            tx("b", "send", "100", "USDT", start + 20),
+            tx("c", "receive", "5", "ETH", start + 30),
+            tx("d", "send", "1", "ETH", end + 1),
+            tx("e", "send", "1", "ETH", start + 40),
+        ];
+        let mut labels = TxLabels::default();
+        labels.set_category(&["a".to_string(), "d".to_string()], Some("trading"), 1);
+        labels.set_category(&["b".to_string(), "c".to_string()], Some("rent"), 1);
+        let prices = HashMap::from([("ETH".to_string(), 3000.0), ("USDT".to_string(), 1.0)]);
+
+        let spend = spend_by_category(&txs, &labels, (start, end), &prices);
+        assert_eq!(
+            spend,
+            vec![("trading".to_string(), 6000.0), ("rent".to_string(), 100.0)]
+        );
+    }
+}
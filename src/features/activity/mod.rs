@@ -1,2 +1,5 @@
 // Activity feature module
 // Activity feature module - Production-ready implementation
+
+pub mod labels;
+pub mod receipt;
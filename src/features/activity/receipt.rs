@@ -0,0 +1,460 @@
+//! Transaction Receipt - 交易收据
+//!
+//! 把一笔转账或兑换整理为可分享的付款凭证（收付方、金额、确认时法币价值、
+//! 网络费、交易哈希、区块与时间），并在客户端生成 PDF。
+//! PDF 使用内置 Helvetica 字体，非 ASCII 字符以 `?` 代替，标签统一为英文。
+
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainConfigManager;
+use crate::services::price::PriceHistoryPoint;
+use crate::services::transaction::TransactionHistoryItem as TransferItem;
+use crate::services::transaction_history::TransactionHistoryItem as SwapItem;
+use qrcode::{Color, QrCode};
+
+/// 历史价格与交易时间的最大间隔（超过则不显示法币价值）
+const MAX_PRICE_GAP_SECS: u64 = 6 * 3600;
+/// 历史价格最多回溯的小时数
+pub const MAX_PRICE_LOOKBACK_HOURS: u32 = 24 * 365;
+
+/// 收据类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptKind {
+    Send,
+    Receive,
+    Swap,
+}
+
+impl ReceiptKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Send => "转出",
+            Self::Receive => "转入",
+            Self::Swap => "兑换",
+        }
+    }
+
+    fn label_en(&self) -> &'static str {
+        match self {
+            Self::Send => "Payment sent",
+            Self::Receive => "Payment received",
+            Self::Swap => "Swap",
+        }
+    }
+}
+
+/// 确认时的法币价值
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiatValue {
+    pub amount: f64,
+    /// ISO 4217 货币代码
+    pub currency: String,
+}
+
+/// 交易收据
+#[derive(Debug, Clone, PartialEq)]
+pub struct Receipt {
+    pub kind: ReceiptKind,
+    /// 链标识（`ChainType::as_str`）
+    pub chain: String,
+    pub tx_hash: String,
+    pub from: String,
+    pub to: String,
+    pub amount: String,
+    pub token: String,
+    /// 兑换得到的 (金额, 代币)
+    pub received: Option<(String, String)>,
+    pub network_fee: String,
+    pub fiat_value: Option<FiatValue>,
+    pub block_number: Option<u64>,
+    /// 确认时间（Unix 秒）
+    pub timestamp: u64,
+    pub explorer_url: Option<String>,
+}
+
+/// 收据中的一行
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceiptField {
+    pub label: &'static str,
+    pub label_en: &'static str,
+    pub value: String,
+}
+
+impl Receipt {
+    /// 由钱包转账记录生成
+    pub fn from_transfer(chain: &str, tx: &TransferItem) -> Self {
+        let kind = if tx.tx_type.eq_ignore_ascii_case("receive") {
+            ReceiptKind::Receive
+        } else {
+            ReceiptKind::Send
+        };
+        Self {
+            kind,
+            chain: chain.to_lowercase(),
+            tx_hash: tx.hash.clone(),
+            from: tx.from.clone(),
+            to: tx.to.clone(),
+            amount: tx.amount.clone(),
+            token: tx.token.clone(),
+            received: None,
+            network_fee: tx.fee.clone(),
+            fiat_value: None,
+            block_number: None,
+            timestamp: tx.timestamp,
+            explorer_url: explorer_tx_url(chain, &tx.hash),
+        }
+    }
+
+    /// 由兑换记录生成（收付方均为本钱包，以兑换的代币对表示）
+    pub fn from_swap(chain: &str, tx: &SwapItem) -> Option<Self> {
+        let tx_hash = tx.tx_hash.clone()?;
+        let timestamp = tx
+            .completed_at
+            .as_deref()
+            .and_then(parse_iso_timestamp)
+            .or_else(|| parse_iso_timestamp(&tx.created_at))
+            .unwrap_or(0);
+        let metadata_str = |key: &str| {
+            tx.metadata
+                .as_ref()
+                .and_then(|m| m.get(key))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+        Some(Self {
+            kind: ReceiptKind::Swap,
+            chain: chain.to_lowercase(),
+            explorer_url: explorer_tx_url(chain, &tx_hash),
+            tx_hash,
+            from: metadata_str("from_address").unwrap_or_default(),
+            to: metadata_str("to_address").unwrap_or_default(),
+            amount: tx.from_amount.clone(),
+            token: tx.from_token.clone(),
+            received: Some((tx.to_amount.clone(), tx.to_token.clone())),
+            network_fee: tx.gas_fee.clone().unwrap_or_default(),
+            fiat_value: None,
+            block_number: None,
+            timestamp,
+        })
+    }
+
+    /// 依次展示的字段（空值显示为“—”）
+    pub fn fields(&self) -> Vec<ReceiptField> {
+        let or_dash = |s: &str| {
+            if s.trim().is_empty() {
+                "—".to_string()
+            } else {
+                s.to_string()
+            }
+        };
+        let mut fields = vec![
+            ReceiptField {
+                label: "类型",
+                label_en: "Type",
+                value: self.kind.label().to_string(),
+            },
+            ReceiptField {
+                label: "网络",
+                label_en: "Network",
+                value: self.chain.clone(),
+            },
+            ReceiptField {
+                label: "付款方",
+                label_en: "From",
+                value: or_dash(&self.from),
+            },
+            ReceiptField {
+                label: "收款方",
+                label_en: "To",
+                value: or_dash(&self.to),
+            },
+            ReceiptField {
+                label: "金额",
+                label_en: "Amount",
+                value: format!("{} {}", self.amount, self.token),
+            },
+        ];
+        if let Some((amount, token)) = &self.received {
+            fields.push(ReceiptField {
+                label: "兑换得到",
+                label_en: "Received",
+                value: format!("{} {}", amount, token),
+            });
+        }
+        fields.extend([
+            ReceiptField {
+                label: "确认时价值",
+                label_en: "Value at confirmation",
+                value: self
+                    .fiat_value
+                    .as_ref()
+                    .map(|v| format!("{:.2} {}", v.amount, v.currency))
+                    .unwrap_or_else(|| "—".to_string()),
+            },
+            ReceiptField {
+                label: "网络费",
+                label_en: "Network fee",
+                value: or_dash(&self.network_fee),
+            },
+            ReceiptField {
+                label: "交易哈希",
+                label_en: "Transaction hash",
+                value: self.tx_hash.clone(),
+            },
+            ReceiptField {
+                label: "区块",
+                label_en: "Block",
+                value: self
+                    .block_number
+                    .map(|b| b.to_string())
+                    .unwrap_or_else(|| "—".to_string()),
+            },
+            ReceiptField {
+                label: "时间",
+                label_en: "Time",
+                value: format_utc(self.timestamp),
+            },
+        ]);
+        fields
+    }
+
+    /// 下载文件名（不含扩展名）
+    pub fn file_stem(&self) -> String {
+        let short: String = self.tx_hash.chars().take(10).collect();
+        format!("receipt_{}_{}", self.chain, short)
+    }
+}
+
+/// 区块浏览器交易链接
+pub fn explorer_tx_url(network: &str, tx_hash: &str) -> Option<String> {
+    let chain = ChainType::from_str(network)?;
+    let explorer_base = ChainConfigManager::new()
+        .get_config(chain)
+        .ok()?
+        .explorer_url
+        .clone()?;
+    Some(format!("{}/tx/{}", explorer_base, tx_hash))
+}
+
+/// 最接近交易时间的历史价格（间隔过大时返回 None）
+pub fn price_at(points: &[PriceHistoryPoint], timestamp: u64) -> Option<f64> {
+    points
+        .iter()
+        .min_by_key(|p| p.timestamp.abs_diff(timestamp))
+        .filter(|p| p.timestamp.abs_diff(timestamp) <= MAX_PRICE_GAP_SECS)
+        .map(|p| p.price)
+}
+
+/// 覆盖交易时间所需的历史价格小时数
+pub fn lookback_hours(timestamp: u64, now: u64) -> u32 {
+    let hours = now.saturating_sub(timestamp) / 3600 + 2;
+    hours.min(MAX_PRICE_LOOKBACK_HOURS as u64) as u32
+}
+
+fn parse_iso_timestamp(value: &str) -> Option<u64> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .and_then(|dt| u64::try_from(dt.timestamp()).ok())
+}
+
+/// UTC 时间（收据会被转发给他人，不使用本地时区）
+pub fn format_utc(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "—".to_string())
+}
+
+/// 二维码模块矩阵：(边长, 按行排列的深色标记)
+pub fn qr_modules(data: &str) -> Option<(usize, Vec<bool>)> {
+    let code = QrCode::new(data).ok()?;
+    let modules = code
+        .to_colors()
+        .into_iter()
+        .map(|c| c == Color::Dark)
+        .collect();
+    Some((code.width(), modules))
+}
+
+/// PDF 字符串转义；内置字体无法显示的字符以 `?` 代替
+fn pdf_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+/// 生成单页 A4 PDF 收据（字段列表 + 浏览器链接二维码）
+pub fn render_pdf(receipt: &Receipt) -> Vec<u8> {
+    const PAGE_W: f64 = 595.0;
+    const PAGE_H: f64 = 842.0;
+    const MARGIN: f64 = 56.0;
+
+    let mut content = String::new();
+    let mut text = |x: f64, y: f64, size: u32, font: &str, value: &str| {
+        content.push_str(&format!(
+            "BT /{} {} Tf {:.1} {:.1} Td ({}) Tj ET\n",
+            font,
+            size,
+            x,
+            y,
+            pdf_text(value)
+        ));
+    };
+
+    let mut y = PAGE_H - MARGIN;
+    text(MARGIN, y, 20, "F2", "Transaction Receipt");
+    y -= 22.0;
+    text(MARGIN, y, 11, "F1", receipt.kind.label_en());
+    y -= 30.0;
+    for field in receipt.fields() {
+        text(MARGIN, y, 9, "F2", field.label_en);
+        y -= 13.0;
+        text(MARGIN, y, 10, "F1", &field.value);
+        y -= 20.0;
+    }
+    if let Some(url) = &receipt.explorer_url {
+        text(MARGIN, y, 9, "F2", "Explorer");
+        y -= 13.0;
+        text(MARGIN, y, 9, "F1", url);
+        y -= 20.0;
+    }
+
+    if let Some((width, modules)) = receipt.explorer_url.as_deref().and_then(qr_modules) {
+        let size = 140.0;
+        let cell = size / width as f64;
+        let left = PAGE_W - MARGIN - size;
+        let top = PAGE_H - MARGIN;
+        content.push_str("0 0 0 rg\n");
+        for (i, dark) in modules.iter().enumerate() {
+            if *dark {
+                let (row, col) = (i / width, i % width);
+                content.push_str(&format!(
+                    "{:.2} {:.2} {:.2} {:.2} re f\n",
+                    left + col as f64 * cell,
+                    top - (row + 1) as f64 * cell,
+                    cell,
+                    cell
+                ));
+            }
+        }
+    }
+    let footer_y = y.min(MARGIN + 20.0);
+    content.push_str(&format!(
+        "BT /F1 8 Tf {:.1} {:.1} Td ({}) Tj ET\n",
+        MARGIN,
+        footer_y,
+        pdf_text("Generated client-side by IronForge. Verify on the block explorer.")
+    ));
+
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>",
+            PAGE_W, PAGE_H
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold >>".to_string(),
+        format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ),
+    ];
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+    }
+    let xref_offset = pdf.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        xref.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.extend_from_slice(xref.as_bytes());
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt() -> Receipt {
+        Receipt::from_transfer(
+            "ethereum",
+            &TransferItem {
+                hash: "0xabc(123)".to_string(),
+                tx_type: "send".to_string(),
+                status: "confirmed".to_string(),
+                from: "0xfrom".to_string(),
+                to: "0xto".to_string(),
+                amount: "1.5".to_string(),
+                token: "ETH".to_string(),
+                timestamp: 1_700_000_000,
+                fee: "0.0004".to_string(),
+                fee_rate: None,
+                replaces: None,
+            },
+        )
+    }
+
+    #[test]
+    fn picks_nearest_price_within_gap() {
+        let points = vec![
+            PriceHistoryPoint {
+                timestamp: 1_000,
+                price: 10.0,
+            },
+            PriceHistoryPoint {
+                timestamp: 4_600,
+                price: 12.0,
+            },
+        ];
+        assert_eq!(price_at(&points, 4_000), Some(12.0));
+        assert_eq!(price_at(&points, 4_600 + MAX_PRICE_GAP_SECS + 1), None);
+        assert_eq!(lookback_hours(0, 10 * 3600), 12);
+    }
+
+    #[test]
+    fn pdf_is_well_formed() {
+        let receipt = receipt();
+        assert_eq!(receipt.kind, ReceiptKind::Send);
+        assert_eq!(
+            receipt.explorer_url.as_deref(),
+            Some("https://etherscan.io/tx/0xabc(123)")
+        );
+        assert!(receipt
+            .fields()
+            .iter()
+            .any(|f| f.value == "2023-11-14 22:13:20 UTC"));
+
+        let pdf = render_pdf(&receipt);
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.trim_end().ends_with("%%EOF"));
+        assert!(text.contains("(0xabc\\(123\\)) Tj"));
+        // xref 中记录的偏移量必须指向对应对象
+        let start = text.find("xref\n").unwrap();
+        let first = text[start..].lines().nth(3).unwrap();
+        let offset: usize = first[..10].parse().unwrap();
+        assert!(text[offset..].starts_with("1 0 obj"));
+        assert_eq!(pdf_text("¥"), "?");
+    }
+}
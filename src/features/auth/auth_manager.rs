@@ -12,16 +12,25 @@
 //! ├── mod.rs
 //! ├── state.rs           # UserState数据结构
 //! ├── hooks.rs           # 登录/注册/登出hooks
+//! ├── session_vault.rs   # "记住此设备"的加密会话
 //! └── auth_manager.rs    # ← 本文件：统一认证管理器
 //! ```
 
-use crate::features::auth::state::UserState;
+use crate::components::molecules::toast::ToastType;
+use crate::features::auth::session_vault;
+use crate::features::auth::state::{UserState, ACCESS_TOKEN_TTL_SECS};
+use crate::services::auth::AuthService;
+use crate::services::user::NotificationCategory;
 use crate::shared::api::ApiClient;
+use crate::shared::error::{ApiError, AppError, AuthError};
 use crate::shared::state::AppState;
+use crate::shared::time::{self, MINUTE_SECS};
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
-use tracing::{debug, info, warn};
-use web_sys::js_sys::Date;
+use log::{debug, info, warn};
+
+/// 访问令牌过期前多久开始刷新
+const TOKEN_REFRESH_MARGIN_SECS: u64 = 5 * MINUTE_SECS;
 
 /// 认证管理器 - 单例模式
 ///
@@ -52,7 +61,7 @@ impl AuthManager {
     /// auth_manager.set_token("jwt_token_here".to_string()).await;
     /// ```
     pub async fn set_token(mut self, token: String) {
-        let now = Self::current_timestamp();
+        let now = time::now_unix();
 
         // 1. 更新UserState
         {
@@ -78,37 +87,103 @@ impl AuthManager {
     /// 🔄 刷新Token（即将过期时调用）
     ///
     /// ## Token刷新策略
-    /// - **提前刷新**：在过期前5分钟开始尝试刷新
-    /// - **优雅降级**：刷新失败则清理状态，引导用户重新登录
+    /// - **仅限记住的设备**：未勾选"记住此设备"时不保存刷新令牌，保持原有行为
+    /// - **提前刷新**：在过期前5分钟开始尝试刷新；未登录时（如重新打开页面）立即恢复会话
+    /// - **轮换**：每次刷新后保存新的刷新令牌，旧令牌记入已轮换列表
+    /// - **重放检测**：已轮换的令牌再次出现或后端报告重用时，强制登出并提示
     ///
     /// ## 返回值
     /// - `Ok(true)`: 刷新成功
-    /// - `Ok(false)`: Token仍然有效，无需刷新
+    /// - `Ok(false)`: 无需刷新（或其他标签页正在刷新）
     /// - `Err(_)`: 刷新失败
     pub async fn refresh_token_if_needed(&self) -> Result<bool, String> {
+        let now = time::now_unix();
         let should_refresh = {
             let user_state = self.app_state.user.read();
-            if let Some(created_at) = user_state.token_created_at {
-                let now = Self::current_timestamp();
-                let age_seconds = (now - created_at) / 1000;
-                // 55分钟后刷新（token有效期1小时）
-                age_seconds >= 3300
-            } else {
-                false
-            }
+            !user_state.is_authenticated
+                || user_state.token_created_at.is_none_or(|created_at| {
+                    now.saturating_sub(created_at)
+                        >= ACCESS_TOKEN_TTL_SECS - TOKEN_REFRESH_MARGIN_SECS
+                })
         };
-
         if !should_refresh {
             return Ok(false);
         }
 
-        // TODO: 调用后端refresh_token API
-        // let api = self.app_state.api.read();
-        // let response = api.post::<RefreshTokenResp>("/api/v1/auth/refresh", &()).await?;
-        // self.set_token(response.access_token).await;
+        let Some(session) = session_vault::load(now).await else {
+            return Ok(false);
+        };
+        if !session_vault::try_lock_refresh(time::now_ms()) {
+            return Ok(false);
+        }
+        let result = self.rotate_session(session, now).await;
+        session_vault::unlock_refresh();
+        result.map(|_| true)
+    }
+
+    /// 用刷新令牌换取新的访问令牌并轮换刷新令牌
+    async fn rotate_session(
+        mut self,
+        mut session: session_vault::RememberedSession,
+        now: u64,
+    ) -> Result<(), String> {
+        if session_vault::is_retired(&session.refresh_token) {
+            self.handle_refresh_token_reuse();
+            return Err("刷新令牌已被使用过".to_string());
+        }
 
-        warn!("⚠️ Token刷新功能待实现");
-        Ok(false)
+        match AuthService::new(self.app_state)
+            .refresh_token(&session.refresh_token)
+            .await
+        {
+            Ok(resp) => {
+                session_vault::rotate(&mut session, resp.refresh_token)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                {
+                    let mut user_state = self.app_state.user.write();
+                    user_state.is_authenticated = true;
+                    user_state.user_id = Some(session.user_id.clone());
+                    user_state.email = Some(session.email.clone());
+                    user_state.access_token = Some(resp.access_token.clone());
+                    user_state.token_created_at = Some(now);
+                    user_state.remember_device = true;
+                    let _ = user_state.save();
+                }
+                self.app_state
+                    .api
+                    .write()
+                    .set_bearer_token(resp.access_token);
+                info!("🔄 已使用记住的会话刷新Token");
+                Ok(())
+            }
+            Err(AppError::Auth(AuthError::RefreshTokenReused)) => {
+                self.handle_refresh_token_reuse();
+                Err("刷新令牌已被使用过".to_string())
+            }
+            Err(AppError::Api(ApiError::Unauthorized)) => {
+                // 刷新令牌已过期或被吊销（如在其他设备修改了密码）
+                session_vault::clear();
+                self.clear_auth();
+                Err("登录已过期，请重新登录".to_string())
+            }
+            // 网络等临时错误：保留记住的会话，稍后重试
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// 检测到刷新令牌重放：忘记本设备、强制登出并提示用户
+    fn handle_refresh_token_reuse(self) {
+        warn!("🚨 检测到已轮换的刷新令牌被再次使用，强制登出");
+        session_vault::clear();
+        self.clear_auth();
+        self.app_state.notify(
+            NotificationCategory::Security,
+            "检测到您的登录凭证在其他地方被重复使用，为保护账户已强制退出。请重新登录，并建议修改密码。"
+                .to_string(),
+            ToastType::Warning,
+            Some(15_000),
+        );
     }
 
     /// ❌ 清理认证状态（登出/Token过期/401错误）
@@ -146,7 +221,7 @@ impl AuthManager {
     ///
     /// ## 验证规则
     /// 1. Token存在
-    /// 2. 未过期（< 3600秒）
+    /// 2. 未过期（< ACCESS_TOKEN_TTL_SECS）
     /// 3. 格式有效（可选）
     ///
     /// ## 返回值
@@ -163,16 +238,13 @@ impl AuthManager {
         }
 
         // 2. 检查Token是否过期
-        if let Some(created_at) = user_state.token_created_at {
-            let now = Self::current_timestamp();
-            let age_seconds = (now - created_at) / 1000;
-
-            if age_seconds >= 3600 {
-                warn!("⏰ Token已过期（{}秒）", age_seconds);
+        if let Some(remaining) = user_state.token_remaining_secs(time::now_unix()) {
+            if remaining == 0 {
+                warn!("⏰ Token已过期");
                 return Ok(false);
             }
 
-            debug!("✅ Token有效（剩余{}秒）", 3600 - age_seconds);
+            debug!("✅ Token有效（剩余{}秒）", remaining);
             Ok(true)
         } else {
             // 旧Token没有created_at，视为有效（向后兼容）
@@ -211,18 +283,10 @@ impl AuthManager {
 
     /// 📊 获取Token剩余有效时间（秒）
     pub fn get_token_remaining_seconds(&self) -> Option<u64> {
-        let user_state = self.app_state.user.read();
-        if let Some(created_at) = user_state.token_created_at {
-            let now = Self::current_timestamp();
-            let age_seconds = (now - created_at) / 1000;
-            if age_seconds < 3600 {
-                Some(3600 - age_seconds)
-            } else {
-                Some(0)
-            }
-        } else {
-            None
-        }
+        self.app_state
+            .user
+            .read()
+            .token_remaining_secs(time::now_unix())
     }
 
     /// 🔐 检查是否已认证
@@ -230,11 +294,6 @@ impl AuthManager {
         let user_state = self.app_state.user.read();
         user_state.is_authenticated && user_state.access_token.is_some()
     }
-
-    /// ⏰ 获取当前时间戳（毫秒）
-    fn current_timestamp() -> u64 {
-        Date::new_0().get_time() as u64
-    }
 }
 
 /// 🎯 401错误处理器 - 全局拦截器
@@ -1,6 +1,9 @@
 //! Authentication Hooks - 认证相关的Hook
 
+use crate::features::auth::session_vault::{self, RememberedSession};
 use crate::features::auth::state::UserState;
+use crate::features::security::{app_lock, device_binding};
+use crate::features::wallet::vault::VaultRekey;
 use crate::services::auth::AuthService;
 use crate::shared::state::AppState;
 use anyhow::Result;
@@ -32,7 +35,7 @@ impl AuthController {
 
         // 更新用户状态
         {
-            let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+            let now = crate::shared::time::now_unix();
             let mut user_state = app_state.user.write();
             user_state.is_authenticated = true;
             user_state.user_id = Some(response.user.id.clone());
@@ -40,6 +43,9 @@ impl AuthController {
             user_state.access_token = Some(response.access_token.clone());
             user_state.token_created_at = Some(now); // 记录token创建时间
             user_state.created_at = Some(response.user.created_at.clone());
+            // 新注册账户在点击验证邮件前视为未验证
+            user_state.email_verified = response.user.email_verified.or(Some(false));
+            user_state.verification_banner_dismissed = false;
 
             // 保存状态
             user_state.save()?;
@@ -55,7 +61,9 @@ impl AuthController {
     }
 
     /// 用户登录
-    pub async fn login(&self, email: &str, password: &str) -> Result<()> {
+    ///
+    /// `remember_device` 为 true 时将刷新令牌加密保存，下次打开应用时自动恢复会话
+    pub async fn login(&self, email: &str, password: &str, remember_device: bool) -> Result<()> {
         let mut app_state = self.app_state;
         let auth_service = AuthService::new(app_state);
         let response = auth_service.login_email(email, password).await?;
@@ -63,7 +71,7 @@ impl AuthController {
         // 更新用户状态
         // 企业级实现：登录成功后，统一更新 UserState 并持久化
         {
-            let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+            let now = crate::shared::time::now_unix();
             let mut user_state = app_state.user.write();
             user_state.is_authenticated = true;
             user_state.user_id = Some(response.user.id.clone());
@@ -72,9 +80,28 @@ impl AuthController {
             user_state.access_token = Some(response.access_token.clone());
             user_state.token_created_at = Some(now); // 记录token创建时间
             user_state.created_at = Some(response.user.created_at.clone());
+            user_state.email_verified = response.user.email_verified;
+            user_state.remember_device = remember_device && response.refresh_token.is_some();
             let _ = user_state.save();
         } // Drop user_state borrow here
 
+        // 记住此设备：刷新令牌只写入加密会话存储
+        match response.refresh_token.clone().filter(|_| remember_device) {
+            Some(refresh_token) => {
+                let session = RememberedSession {
+                    user_id: response.user.id.clone(),
+                    email: response.user.email.clone(),
+                    refresh_token,
+                    remembered_at: crate::shared::time::now_unix(),
+                };
+                if let Err(e) = session_vault::save(&session).await {
+                    log::warn!("保存记住的会话失败: {}", e);
+                    app_state.user.write().remember_device = false;
+                }
+            }
+            None => session_vault::clear(),
+        }
+
         // 验证token确实被保存（防止LocalStorage失败）
         {
             let user_state = app_state.user.read();
@@ -87,7 +114,7 @@ impl AuthController {
             {
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::error;
+                    use log::error;
                     error!("❌ Token保存失败！可能是LocalStorage被禁用或浏览器隐私模式");
                 }
                 return Err(anyhow::anyhow!(
@@ -102,9 +129,18 @@ impl AuthController {
             .write()
             .set_bearer_token(response.access_token);
 
-        // 更新活动时间
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+        // 更新活动时间；重新登录即视为解锁
+        let now = crate::shared::time::now_unix();
         *app_state.last_active.write() = now;
+        app_lock::persist_locked(false);
+        app_state.app_locked.set(false);
+
+        // 本设备首次登录时生成设备密钥并登记，后台执行，不阻塞登录
+        spawn(async move {
+            if let Err(e) = device_binding::ensure_registered(app_state).await {
+                log::warn!("设备登记失败（高风险操作时会重试）: {}", e);
+            }
+        });
 
         // 登录成功后，从后端获取用户的钱包列表
         self.sync_wallets_from_backend().await?;
@@ -132,7 +168,7 @@ impl AuthController {
                 app_state.api.write().set_bearer_token(token.clone());
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::debug;
+                    use log::debug;
                     debug!(
                         "🔄 同步钱包: Token已同步到API客户端 (length: {})",
                         token.len()
@@ -141,7 +177,7 @@ impl AuthController {
             } else {
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::warn;
+                    use log::warn;
                     warn!("⚠️ 同步钱包: Token为空，跳过同步");
                 }
                 return Ok(()); // token为空，跳过同步
@@ -149,7 +185,7 @@ impl AuthController {
         } else {
             #[cfg(debug_assertions)]
             {
-                use tracing::warn;
+                use log::warn;
                 warn!("⚠️ 同步钱包: 没有token，跳过同步");
             }
             return Ok(()); // 没有token，跳过同步
@@ -170,7 +206,7 @@ impl AuthController {
 
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::info;
+                    use log::info;
                     info!(
                         "🔄 开始同步钱包: 后端返回 {} 个单链钱包记录",
                         backend_wallet_count
@@ -188,7 +224,7 @@ impl AuthController {
                 if backend_wallet_count == 0 && !wallet_state.wallets.is_empty() {
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::warn;
+                        use log::warn;
                         warn!(
                             "⚠️ 检测到数据库可能已重建：后端返回0个钱包，但本地有 {} 个钱包",
                             wallet_state.wallets.len()
@@ -203,7 +239,7 @@ impl AuthController {
                         Ok(synced_count) => {
                             #[cfg(debug_assertions)]
                             {
-                                use tracing::info;
+                                use log::info;
                                 info!(
                                     "✅ 自动同步成功：已将 {} 个本地钱包重新注册到后端",
                                     synced_count
@@ -216,7 +252,7 @@ impl AuthController {
                         Err(e) => {
                             #[cfg(debug_assertions)]
                             {
-                                use tracing::error;
+                                use log::error;
                                 error!("❌ 自动同步失败: {}，保留本地钱包（仍可正常使用）", e);
                             }
                             // 即使同步失败，本地钱包仍然可用
@@ -240,12 +276,17 @@ impl AuthController {
 
                 // 首先，从本地存储中查找所有已有的钱包，建立名称到ID的映射
                 let mut name_to_id_map: HashMap<String, String> = HashMap::new();
+                // 已验证备份的钱包ID（重新构建钱包时保留该标记）
+                let mut backup_verified_ids = std::collections::HashSet::new();
                 {
                     // 尝试从本地存储中加载钱包状态
                     use crate::features::wallet::state::WalletState;
                     if let Ok(local_wallet_state) = LocalStorage::get::<WalletState>("wallet_state")
                     {
                         for local_wallet in local_wallet_state.wallets.iter() {
+                            if local_wallet.backup_verified {
+                                backup_verified_ids.insert(local_wallet.id.clone());
+                            }
                             // 检查这个本地钱包是否在本地存储中有加密种子
                             let seed_key = format!("wallet_{}_seed", local_wallet.id);
                             if LocalStorage::get::<String>(&seed_key).is_ok() {
@@ -257,6 +298,16 @@ impl AuthController {
                 }
 
                 for backend_wallet in backend_wallets {
+                    // 已软删除的钱包在保留期内不重新加入列表（后端记录在永久清除时删除）
+                    let base_name = backend_wallet
+                        .name
+                        .split(" (")
+                        .next()
+                        .unwrap_or(&backend_wallet.name);
+                    if wallet_state.is_soft_deleted_name(base_name) {
+                        continue;
+                    }
+
                     // ✅ 使用group_id作为合并键（如果有），否则使用名称
                     let merge_key = if let Some(ref gid) = backend_wallet.group_id {
                         gid.clone()
@@ -266,7 +317,7 @@ impl AuthController {
 
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::info;
+                        use log::info;
                         info!(
                             "  处理后端钱包: '{}' (链: {}, group_id: {:?})",
                             backend_wallet.name, backend_wallet.chain, backend_wallet.group_id
@@ -287,7 +338,9 @@ impl AuthController {
                             use uuid::Uuid;
                             Uuid::new_v4().to_string()
                         };
-                        Wallet::new(id, backend_wallet.name.clone())
+                        let mut wallet = Wallet::new(id, backend_wallet.name.clone());
+                        wallet.backup_verified = backup_verified_ids.contains(&wallet.id);
+                        wallet
                     });
 
                     // 添加账户（✅ 使用后端返回的公钥）
@@ -325,7 +378,7 @@ impl AuthController {
 
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::info;
+                    use log::info;
                     info!(
                         "✅ 钱包合并完成: {} 个钱包（后端返回 {} 个单链钱包）",
                         wallet_state.wallets.len(),
@@ -362,7 +415,7 @@ impl AuthController {
 
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::warn;
+                    use log::warn;
                     if is_unauthorized {
                         warn!(
                             "Failed to sync wallets from backend: Token may be expired or invalid"
@@ -373,16 +426,20 @@ impl AuthController {
                 }
 
                 // 如果本地有钱包，保留它们；如果没有，尝试从本地存储加载
-                let mut wallet_state = app_state.wallet.write();
-                if wallet_state.wallets.is_empty() {
-                    // 尝试从本地存储加载钱包
-                    // 使用WalletState::load()方法，它是async的，但这里在async上下文中
+                // 加载是异步的，不能跨 await 持有钱包写锁
+                if app_state.wallet.peek().wallets.is_empty() {
                     use crate::features::wallet::state::WalletState;
                     let local_wallet_state = WalletState::load().await;
                     if !local_wallet_state.wallets.is_empty() {
-                        wallet_state.wallets = local_wallet_state.wallets;
-                        wallet_state.selected_wallet_id = local_wallet_state.selected_wallet_id;
-                        let _ = wallet_state.save();
+                        app_state.update_wallet(move |wallet_state| {
+                            // 加载期间其他任务可能已写入钱包
+                            if wallet_state.wallets.is_empty() {
+                                wallet_state.wallets = local_wallet_state.wallets;
+                                wallet_state.selected_wallet_id =
+                                    local_wallet_state.selected_wallet_id;
+                                wallet_state.deleted_wallets = local_wallet_state.deleted_wallets;
+                            }
+                        });
                     }
                 }
 
@@ -403,7 +460,10 @@ impl AuthController {
             let _ = auth_service.logout().await;
         }
 
-        // 2. 清除本地状态
+        // 2. 清除本地状态（包括记住的会话与锁屏状态）
+        session_vault::clear();
+        app_lock::persist_locked(false);
+        app_state.app_locked.set(false);
         {
             let mut user_state = app_state.user.write();
             user_state.logout()?;
@@ -425,6 +485,9 @@ impl AuthController {
     /// 仅清除本地状态，不调用后端API
     pub fn logout_local(&self) -> Result<()> {
         let mut app_state = self.app_state;
+        session_vault::clear();
+        app_lock::persist_locked(false);
+        app_state.app_locked.set(false);
         {
             let mut user_state = app_state.user.write();
             user_state.logout()?;
@@ -470,30 +533,106 @@ impl AuthController {
         Ok(())
     }
 
+    /// 修改账户密码
+    ///
+    /// `rekey_wallets` 为 true 时，本地使用旧账户密码加密的钱包改为用新密码加密：
+    /// 先写入本地新密文，再提交后端；后端失败时恢复本地旧密文，两边保持一致。
+    /// 返回 (已更新的钱包数, 使用其他密码而未改动的钱包数)。
+    pub async fn change_password(
+        &self,
+        old_password: &str,
+        new_password: &str,
+        confirm_password: &str,
+        revoke_other_sessions: bool,
+        rekey_wallets: bool,
+    ) -> Result<(usize, usize)> {
+        if new_password != confirm_password {
+            return Err(anyhow::anyhow!("两次输入的新密码不一致"));
+        }
+
+        let plan = if rekey_wallets {
+            let wallet_ids: Vec<String> = {
+                let wallet_state = self.app_state.wallet.read();
+                wallet_state
+                    .wallets
+                    .iter()
+                    .map(|w| w.id.clone())
+                    .chain(
+                        wallet_state
+                            .deleted_wallets
+                            .iter()
+                            .map(|d| d.wallet.id.clone()),
+                    )
+                    .collect()
+            };
+            VaultRekey::prepare(&wallet_ids, old_password, new_password).await?
+        } else {
+            VaultRekey::default()
+        };
+        plan.commit()?;
+
+        if let Err(e) = AuthService::new(self.app_state)
+            .change_password(
+                old_password,
+                new_password,
+                confirm_password,
+                revoke_other_sessions,
+            )
+            .await
+        {
+            plan.rollback();
+            return Err(e.into());
+        }
+
+        Ok((plan.rekeyed.len(), plan.skipped.len()))
+    }
+
+    /// 向后端查询邮箱验证状态并同步到 UserState，返回是否已验证
+    pub async fn refresh_email_verification(&self) -> Result<bool> {
+        let mut app_state = self.app_state;
+        let verified = AuthService::new(app_state)
+            .email_verification_status()
+            .await?;
+        if verified {
+            app_state.user.write().mark_email_verified();
+        }
+        Ok(verified)
+    }
+
     /// 更新活动时间（用于账户自动锁定）
     ///
     /// 注意：此方法当前未使用，但保留用于未来扩展
     #[allow(dead_code)]
     pub fn update_activity(&self) {
         let mut app_state = self.app_state;
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+        let now = crate::shared::time::now_unix();
         *app_state.last_active.write() = now;
     }
 
-    /// 检查账户自动锁定（5分钟无操作）
-    ///
-    /// 注意：此方法当前未使用，但保留用于未来扩展
-    #[allow(dead_code)]
-    pub fn check_auto_lock(&self) {
-        let app_state = self.app_state;
-        let last_active = *app_state.last_active.read();
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
-
-        // 5分钟 = 300秒
-        if now - last_active > 300 {
-            // 自动锁定仅清除本地状态，不调用后端API
-            self.logout_local().ok();
+    /// 用账户密码重新验证身份（锁屏解锁时本设备没有钱包密钥的情况）
+    pub async fn reauthenticate(&self, password: &str) -> Result<()> {
+        let mut app_state = self.app_state;
+        let email = app_state
+            .user
+            .peek()
+            .email
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("登录已失效，请重新登录"))?;
+        let response = AuthService::new(app_state)
+            .login_email(&email, password)
+            .await?;
+        {
+            let now = crate::shared::time::now_unix();
+            let mut user_state = app_state.user.write();
+            user_state.access_token = Some(response.access_token.clone());
+            user_state.token_created_at = Some(now);
+            let _ = user_state.save();
         }
+        app_state
+            .api
+            .write()
+            .set_bearer_token(response.access_token);
+        Ok(())
     }
 
     /// 🔄 重新同步本地钱包到后端（数据库重建后的自动修复）
@@ -520,7 +659,7 @@ impl AuthController {
 
         #[cfg(debug_assertions)]
         {
-            use tracing::info;
+            use log::info;
             info!(
                 "🔍 发现 {} 个本地钱包需要重新同步",
                 local_wallet_state.wallets.len()
@@ -562,7 +701,7 @@ impl AuthController {
 
         #[cfg(debug_assertions)]
         {
-            use tracing::info;
+            use log::info;
             info!(
                 "📤 准备批量注册 {} 个账户到后端",
                 wallet_registrations.len()
@@ -583,14 +722,14 @@ impl AuthController {
 
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::info;
+                    use log::info;
                     info!(
                         "✅ 批量注册完成: {} 成功, {} 失败",
                         success_count, failed_count
                     );
 
                     if !response.failed.is_empty() {
-                        use tracing::warn;
+                        use log::warn;
                         for err in response.failed.iter() {
                             warn!("  ⚠️ 失败: {} - {} ({})", err.chain, err.address, err.error);
                         }
@@ -0,0 +1,126 @@
+//! Login Guard - 登录失败节流与输入提示
+//!
+//! 连续登录失败后按指数递增的延迟限制再次提交（LocalStorage 持久化，刷新页面不会清零）；
+//! 后端返回账户锁定或限流时按返回的时长锁定提交按钮。
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const STORAGE_KEY: &str = "login_throttle";
+/// 不触发延迟的连续失败次数
+const FREE_ATTEMPTS: u32 = 2;
+/// 首次延迟（毫秒），之后每次失败翻倍
+const BASE_DELAY_MS: f64 = 2_000.0;
+/// 客户端延迟上限（毫秒）
+const MAX_DELAY_MS: f64 = 60_000.0;
+
+/// 登录节流状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LoginThrottle {
+    #[serde(default)]
+    consecutive_failures: u32,
+    /// 允许再次提交的时间（毫秒时间戳）
+    #[serde(default)]
+    blocked_until_ms: f64,
+}
+
+impl LoginThrottle {
+    pub fn load() -> Self {
+        LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    /// 第 n 次连续失败后的等待时长（毫秒）
+    pub fn delay_after(failures: u32) -> f64 {
+        if failures <= FREE_ATTEMPTS {
+            return 0.0;
+        }
+        let exponent = (failures - FREE_ATTEMPTS - 1).min(16) as i32;
+        (BASE_DELAY_MS * 2f64.powi(exponent)).min(MAX_DELAY_MS)
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    pub fn blocked_until_ms(&self) -> f64 {
+        self.blocked_until_ms
+    }
+
+    /// 记录一次密码错误
+    pub fn record_failure(&mut self, now_ms: f64) {
+        self.consecutive_failures += 1;
+        let delay = Self::delay_after(self.consecutive_failures);
+        self.blocked_until_ms = self.blocked_until_ms.max(now_ms + delay);
+    }
+
+    /// 按后端返回的锁定/限流时长禁止提交
+    pub fn block_for(&mut self, secs: u64, now_ms: f64) {
+        self.blocked_until_ms = self.blocked_until_ms.max(now_ms + secs as f64 * 1000.0);
+    }
+
+    /// 登录成功后清零
+    pub fn record_success(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 距离可再次提交的剩余秒数（向上取整）
+    pub fn remaining_secs(&self, now_ms: f64) -> u64 {
+        ((self.blocked_until_ms - now_ms).max(0.0) / 1000.0).ceil() as u64
+    }
+}
+
+/// 根据按键推断大写锁定状态：字母大小写与 Shift 不一致即为开启；非字母键无法判断
+pub fn caps_lock_from_key(key: &str, shift: bool) -> Option<bool> {
+    let mut chars = key.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() || !c.is_alphabetic() || c.is_lowercase() == c.is_uppercase() {
+        return None;
+    }
+    Some(c.is_uppercase() != shift)
+}
+
+/// 倒计时显示（mm:ss）
+pub fn format_countdown(secs: u64) -> String {
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_grows_after_free_attempts_and_is_capped() {
+        assert_eq!(LoginThrottle::delay_after(1), 0.0);
+        assert_eq!(LoginThrottle::delay_after(2), 0.0);
+        assert_eq!(LoginThrottle::delay_after(3), 2_000.0);
+        assert_eq!(LoginThrottle::delay_after(4), 4_000.0);
+        assert_eq!(LoginThrottle::delay_after(30), MAX_DELAY_MS);
+
+        let mut throttle = LoginThrottle::default();
+        for _ in 0..3 {
+            throttle.record_failure(10_000.0);
+        }
+        assert_eq!(throttle.remaining_secs(10_000.0), 2);
+        assert_eq!(throttle.remaining_secs(12_000.0), 0);
+
+        throttle.block_for(900, 10_000.0);
+        assert_eq!(throttle.remaining_secs(10_000.0), 900);
+        throttle.record_success();
+        assert_eq!(throttle, LoginThrottle::default());
+    }
+
+    #[test]
+    fn caps_lock_is_inferred_from_letter_case_and_shift() {
+        assert_eq!(caps_lock_from_key("A", false), Some(true));
+        assert_eq!(caps_lock_from_key("a", true), Some(true));
+        assert_eq!(caps_lock_from_key("a", false), Some(false));
+        assert_eq!(caps_lock_from_key("A", true), Some(false));
+        assert_eq!(caps_lock_from_key("1", false), None);
+        assert_eq!(caps_lock_from_key("Enter", false), None);
+        assert_eq!(format_countdown(125), "02:05");
+    }
+}
@@ -4,9 +4,15 @@
 //! - `state.rs`: UserState数据结构 + LocalStorage持久化
 //! - `hooks.rs`: 登录/注册/登出 hooks
 //! - `auth_manager.rs`: 统一认证状态管理器（新增）
+//! - `login_guard.rs`: 登录失败节流与大写锁定检测
+//! - `password_strength.rs`: 注册密码强度估算
+//! - `session_vault.rs`: "记住此设备"的加密会话与刷新令牌轮换
 
 pub mod auth_manager;
 pub mod hooks;
+pub mod login_guard;
+pub mod password_strength;
+pub mod session_vault;
 pub mod state;
 
 pub use auth_manager::{handle_unauthorized, is_unauthorized_error, AuthManager};
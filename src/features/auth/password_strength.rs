@@ -0,0 +1,338 @@
+//! Password Strength - 密码强度估算
+//!
+//! 参考 zxcvbn 的思路：按字符集估算每个字符的猜测空间，对常见密码、邮箱信息、年份、
+//! 重复/顺序字符与键盘相邻序列等可预测片段大幅降权，再按猜测次数（log10）映射为 0-4 分。
+
+/// 常见密码（去除 leet 替换后匹配）
+const COMMON_PASSWORDS: &[&str] = &[
+    "password",
+    "123456",
+    "12345678",
+    "123456789",
+    "qwerty",
+    "abc123",
+    "111111",
+    "iloveyou",
+    "admin",
+    "welcome",
+    "letmein",
+    "monkey",
+    "dragon",
+    "football",
+    "baseball",
+    "sunshine",
+    "princess",
+    "master",
+    "shadow",
+    "superman",
+    "trustno1",
+    "login",
+    "starwars",
+    "passw0rd",
+    "zaq12wsx",
+    "1q2w3e4r",
+    "qwertyuiop",
+    "ironforge",
+    "bitcoin",
+    "crypto",
+    "wallet",
+];
+
+/// 键盘行（用于检测相邻按键序列）
+const KEYBOARD_ROWS: &[&str] = &["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// 常见密码片段的猜测次数（log10），约等于在前一百条常见密码中的排名
+const COMMON_WORD_GUESSES_LOG10: f64 = 2.0;
+/// 邮箱等用户信息片段的猜测次数（log10）
+const USER_INPUT_GUESSES_LOG10: f64 = 1.0;
+/// 年份片段（1900-2099）的猜测次数（log10）
+const YEAR_GUESSES_LOG10: f64 = 2.3;
+/// 延续规律（重复/顺序/键盘相邻）的字符贡献（log10）
+const PATTERN_CHAR_GUESSES_LOG10: f64 = 0.3;
+
+/// 密码强度估算结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordStrength {
+    /// 0-4 分（0 极弱，4 很强）
+    pub score: u8,
+    /// 估算的猜测次数（log10）
+    pub guesses_log10: f64,
+    /// 主要问题
+    pub warning: Option<&'static str>,
+    /// 改进建议
+    pub suggestions: Vec<&'static str>,
+}
+
+impl PasswordStrength {
+    /// 注册允许的最低分数
+    pub const MIN_ACCEPTABLE_SCORE: u8 = 2;
+
+    pub fn is_acceptable(&self) -> bool {
+        self.score >= Self::MIN_ACCEPTABLE_SCORE
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self.score {
+            0 => "极弱",
+            1 => "弱",
+            2 => "一般",
+            3 => "强",
+            _ => "很强",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Finding {
+    Common,
+    UserInput,
+    Year,
+    Sequence,
+    Keyboard,
+}
+
+/// 还原常见的 leet 替换（p@ssw0rd -> password）
+fn unleet(c: char) -> char {
+    match c {
+        '0' => 'o',
+        '1' | '!' => 'i',
+        '3' => 'e',
+        '4' | '@' => 'a',
+        '5' | '$' => 's',
+        '7' => 't',
+        _ => c,
+    }
+}
+
+fn charset_size(password: &str) -> f64 {
+    let mut size = 0.0;
+    if password.chars().any(|c| c.is_ascii_lowercase()) {
+        size += 26.0;
+    }
+    if password.chars().any(|c| c.is_ascii_uppercase()) {
+        size += 26.0;
+    }
+    if password.chars().any(|c| c.is_ascii_digit()) {
+        size += 10.0;
+    }
+    if password
+        .chars()
+        .any(|c| c.is_ascii_punctuation() || c == ' ')
+    {
+        size += 33.0;
+    }
+    if !password.is_ascii() {
+        size += 100.0;
+    }
+    f64::max(size, 10.0)
+}
+
+fn keyboard_adjacent(a: char, b: char) -> bool {
+    KEYBOARD_ROWS
+        .iter()
+        .any(|row| match (row.find(a), row.find(b)) {
+            (Some(i), Some(j)) => i.abs_diff(j) == 1,
+            _ => false,
+        })
+}
+
+/// 在 `haystack` 中查找 `needle` 的所有出现位置，标记为已覆盖并返回命中次数
+fn cover_matches(haystack: &[char], needle: &str, covered: &mut [bool]) -> usize {
+    let needle: Vec<char> = needle.chars().collect();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return 0;
+    }
+    let mut hits = 0;
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        if haystack[i..i + needle.len()] == needle[..]
+            && !covered[i..i + needle.len()].iter().all(|c| *c)
+        {
+            covered[i..i + needle.len()]
+                .iter_mut()
+                .for_each(|c| *c = true);
+            hits += 1;
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    hits
+}
+
+/// 估算密码强度
+///
+/// `user_inputs` 为与用户相关、容易被猜到的信息（如邮箱、用户名）。
+pub fn estimate(password: &str, user_inputs: &[&str]) -> PasswordStrength {
+    let chars: Vec<char> = password.chars().collect();
+    if chars.is_empty() {
+        return PasswordStrength {
+            score: 0,
+            guesses_log10: 0.0,
+            warning: None,
+            suggestions: vec!["使用 12 位以上的密码，或由几个不相关单词组成的短语"],
+        };
+    }
+
+    // 逐字符转小写，保证下标与原密码一一对应
+    let lowered: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let normalized: Vec<char> = lowered.iter().copied().map(unleet).collect();
+    let mut covered = vec![false; chars.len()];
+    let mut guesses_log10 = 0.0;
+    let mut findings = Vec::new();
+
+    // 字典片段：先匹配较长的单词
+    let mut words: Vec<&str> = COMMON_PASSWORDS.to_vec();
+    words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+    for word in words {
+        let hits = cover_matches(&normalized, word, &mut covered)
+            + cover_matches(&lowered, word, &mut covered);
+        if hits > 0 {
+            guesses_log10 += COMMON_WORD_GUESSES_LOG10 * hits as f64;
+            findings.push(Finding::Common);
+        }
+    }
+
+    // 用户信息：邮箱整体及其 @ 前、按分隔符拆开的片段
+    for input in user_inputs {
+        let input = input.to_lowercase();
+        let local = input.split('@').next().unwrap_or_default().to_string();
+        let mut tokens: Vec<String> = local
+            .split(|c: char| !c.is_alphanumeric())
+            .map(str::to_string)
+            .collect();
+        tokens.push(local);
+        for token in tokens.iter().filter(|t| t.chars().count() >= 3) {
+            if cover_matches(&lowered, token, &mut covered) > 0 {
+                guesses_log10 += USER_INPUT_GUESSES_LOG10;
+                findings.push(Finding::UserInput);
+            }
+        }
+    }
+
+    // 年份
+    let mut i = 0;
+    while i + 4 <= chars.len() {
+        let window: String = chars[i..i + 4].iter().collect();
+        let is_year = (window.starts_with("19") || window.starts_with("20"))
+            && window.chars().all(|c| c.is_ascii_digit());
+        if is_year && !covered[i..i + 4].iter().any(|c| *c) {
+            covered[i..i + 4].iter_mut().for_each(|c| *c = true);
+            guesses_log10 += YEAR_GUESSES_LOG10;
+            findings.push(Finding::Year);
+            i += 4;
+        } else {
+            i += 1;
+        }
+    }
+
+    // 剩余字符：延续规律的字符只贡献很小的猜测空间
+    let per_char = charset_size(password).log10();
+    for (idx, c) in lowered.iter().enumerate() {
+        if covered[idx] {
+            continue;
+        }
+        let prev = idx.checked_sub(1).map(|p| lowered[p]);
+        let continues = prev.and_then(|p| {
+            let diff = (*c as i64) - (p as i64);
+            if diff == 0 || (diff.abs() == 1 && c.is_alphanumeric() && p.is_alphanumeric()) {
+                Some(Finding::Sequence)
+            } else if keyboard_adjacent(p, *c) {
+                Some(Finding::Keyboard)
+            } else {
+                None
+            }
+        });
+        match continues {
+            Some(finding) => {
+                guesses_log10 += PATTERN_CHAR_GUESSES_LOG10;
+                findings.push(finding);
+            }
+            None => guesses_log10 += per_char,
+        }
+    }
+
+    let score = match guesses_log10 {
+        g if g < 3.0 => 0,
+        g if g < 6.0 => 1,
+        g if g < 8.0 => 2,
+        g if g < 10.0 => 3,
+        _ => 4,
+    };
+
+    let warning = [
+        (Finding::Common, "这是非常常见的密码或其变体"),
+        (Finding::UserInput, "密码中包含了您的邮箱信息"),
+        (Finding::Keyboard, "键盘上相邻的按键序列很容易被猜到"),
+        (
+            Finding::Sequence,
+            "\"aaa\"、\"abc\"、\"123\" 这类规律字符很容易被猜到",
+        ),
+        (Finding::Year, "年份很容易被猜到"),
+    ]
+    .into_iter()
+    .find(|(finding, _)| score < 3 && findings.contains(finding))
+    .map(|(_, text)| text);
+
+    let mut suggestions = Vec::new();
+    if score < 3 {
+        if chars.len() < 12 {
+            suggestions.push("使用 12 位以上的密码，或由几个不相关单词组成的短语");
+        }
+        let classes = [
+            chars.iter().any(|c| c.is_ascii_lowercase()),
+            chars.iter().any(|c| c.is_ascii_uppercase()),
+            chars.iter().any(|c| c.is_ascii_digit()),
+            chars.iter().any(|c| !c.is_alphanumeric()),
+        ];
+        if classes.iter().filter(|c| **c).count() < 3 {
+            suggestions.push("混合使用大小写字母、数字和符号");
+        }
+        if findings.contains(&Finding::Common) {
+            suggestions.push("把字母替换成相似的数字或符号（如 a→@）并不能让常见密码变安全");
+        }
+        if findings.contains(&Finding::UserInput) {
+            suggestions.push("不要在密码中使用邮箱、姓名等个人信息");
+        }
+        if findings.contains(&Finding::Year) {
+            suggestions.push("避免使用生日、年份等与您相关的日期");
+        }
+    }
+
+    PasswordStrength {
+        score,
+        guesses_log10,
+        warning,
+        suggestions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predictable_passwords_score_low() {
+        assert_eq!(estimate("password", &[]).score, 0);
+        assert_eq!(estimate("P@ssw0rd", &[]).score, 0);
+        assert!(estimate("qwerty123", &[]).score <= 1);
+        assert!(estimate("aaaaaaaaaaaa", &[]).score <= 1);
+        let with_email = estimate("alice2024", &["alice@example.com"]);
+        assert!(!with_email.is_acceptable());
+        assert_eq!(with_email.warning, Some("密码中包含了您的邮箱信息"));
+        assert!(!with_email.suggestions.is_empty());
+    }
+
+    #[test]
+    fn long_or_varied_passwords_score_high() {
+        let phrase = estimate("correct horse battery staple", &[]);
+        assert_eq!(phrase.score, 4);
+        assert!(phrase.warning.is_none());
+        assert!(phrase.suggestions.is_empty());
+        assert!(estimate("Gx7#mQ2v!Lp9", &[]).score >= 3);
+        assert!(estimate("", &[]).score == 0);
+    }
+}
@@ -0,0 +1,159 @@
+//! Session Vault - "记住此设备"的会话持久化
+//!
+//! 刷新令牌经 `EncryptedStorage` 加密后保存，不以明文写入 LocalStorage；
+//! 密钥为本设备生成的不可导出 WebCrypto 密钥，保存在 IndexedDB 中而非密文旁边。
+//! 每次使用后轮换；已轮换令牌的指纹保留一段时间，再次出现即视为重放。
+//! 未勾选"记住此设备"的会话不写入本存储，保持原有行为。
+
+use crate::shared::storage::device_storage;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const SESSION_KEY: &str = "remembered_session";
+const RETIRED_KEY: &str = "retired_refresh_tokens";
+const REFRESH_LOCK_KEY: &str = "session_refresh_lock";
+/// 记住设备的最长时间（天）
+pub const REMEMBER_DAYS: u64 = 30;
+/// 保留的已轮换令牌指纹数量
+const RETIRED_CAPACITY: usize = 20;
+/// 跨标签页刷新锁的有效期（毫秒），防止两个标签页同时使用同一令牌被误判为重放
+const REFRESH_LOCK_TTL_MS: f64 = 10_000.0;
+
+/// 已记住的会话
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RememberedSession {
+    pub user_id: String,
+    pub email: String,
+    pub refresh_token: String,
+    /// 勾选"记住此设备"的时间（秒）
+    pub remembered_at: u64,
+}
+
+impl RememberedSession {
+    pub fn is_expired(&self, now_secs: u64) -> bool {
+        now_secs.saturating_sub(self.remembered_at) >= REMEMBER_DAYS * 24 * 3600
+    }
+}
+
+/// 已轮换的刷新令牌指纹（只保存哈希，不保存令牌本身）
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RetiredTokens {
+    fingerprints: Vec<String>,
+}
+
+impl RetiredTokens {
+    pub fn fingerprint(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    pub fn contains(&self, token: &str) -> bool {
+        self.fingerprints.contains(&Self::fingerprint(token))
+    }
+
+    pub fn retire(&mut self, token: &str) {
+        let fingerprint = Self::fingerprint(token);
+        if !self.fingerprints.contains(&fingerprint) {
+            self.fingerprints.push(fingerprint);
+        }
+        let overflow = self.fingerprints.len().saturating_sub(RETIRED_CAPACITY);
+        self.fingerprints.drain(..overflow);
+    }
+}
+
+/// 读取已记住的会话（过期或无法解密时清除并返回 None）
+pub async fn load(now_secs: u64) -> Option<RememberedSession> {
+    let bytes = match device_storage().load(SESSION_KEY).await {
+        Ok(Some(bytes)) => bytes,
+        Ok(None) => return None,
+        Err(e) => {
+            log::warn!("已记住的会话无法解密，已清除: {}", e);
+            clear();
+            return None;
+        }
+    };
+    match serde_json::from_slice::<RememberedSession>(&bytes) {
+        Ok(session) if !session.is_expired(now_secs) => Some(session),
+        _ => {
+            clear();
+            None
+        }
+    }
+}
+
+pub async fn save(session: &RememberedSession) -> anyhow::Result<()> {
+    device_storage()
+        .save(SESSION_KEY, &serde_json::to_vec(session)?)
+        .await
+}
+
+/// 忘记本设备（登出或检测到重放时调用）
+pub fn clear() {
+    LocalStorage::delete(SESSION_KEY);
+}
+
+/// 令牌是否已被轮换过（再次使用即为重放）
+pub fn is_retired(token: &str) -> bool {
+    LocalStorage::get::<RetiredTokens>(RETIRED_KEY)
+        .unwrap_or_default()
+        .contains(token)
+}
+
+/// 轮换：记录旧令牌指纹后保存新令牌
+pub async fn rotate(
+    session: &mut RememberedSession,
+    new_refresh_token: String,
+) -> anyhow::Result<()> {
+    let mut retired = LocalStorage::get::<RetiredTokens>(RETIRED_KEY).unwrap_or_default();
+    retired.retire(&session.refresh_token);
+    let _ = LocalStorage::set(RETIRED_KEY, &retired);
+    session.refresh_token = new_refresh_token;
+    save(session).await
+}
+
+/// 尝试获取跨标签页刷新锁
+pub fn try_lock_refresh(now_ms: f64) -> bool {
+    let held_until = LocalStorage::get::<f64>(REFRESH_LOCK_KEY).unwrap_or(0.0);
+    if held_until > now_ms {
+        return false;
+    }
+    LocalStorage::set(REFRESH_LOCK_KEY, now_ms + REFRESH_LOCK_TTL_MS).is_ok()
+}
+
+pub fn unlock_refresh() {
+    LocalStorage::delete(REFRESH_LOCK_KEY);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retired_tokens_are_detected_and_capped() {
+        let mut retired = RetiredTokens::default();
+        retired.retire("token-0");
+        assert!(retired.contains("token-0"));
+        assert!(!retired.contains("token-1"));
+        // 只保存指纹
+        assert!(!retired.fingerprints.iter().any(|f| f.contains("token")));
+
+        for i in 1..=RETIRED_CAPACITY {
+            retired.retire(&format!("token-{}", i));
+        }
+        assert_eq!(retired.fingerprints.len(), RETIRED_CAPACITY);
+        assert!(!retired.contains("token-0"));
+        assert!(retired.contains(&format!("token-{}", RETIRED_CAPACITY)));
+    }
+
+    #[test]
+    fn remembered_session_expires_after_retention() {
+        let session = RememberedSession {
+            user_id: "u1".to_string(),
+            email: "a@b.c".to_string(),
+            refresh_token: "r".to_string(),
+            remembered_at: 1_000,
+        };
+        assert!(!session.is_expired(1_000 + REMEMBER_DAYS * 24 * 3600 - 1));
+        assert!(session.is_expired(1_000 + REMEMBER_DAYS * 24 * 3600));
+    }
+}
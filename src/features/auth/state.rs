@@ -1,9 +1,13 @@
 //! User Authentication State - 用户认证状态
 //! 管理用户账户信息、头像、登录状态等
 
+use crate::shared::time::{self, HOUR_SECS};
 use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
 
+/// 访问令牌（JWT）有效期
+pub const ACCESS_TOKEN_TTL_SECS: u64 = HOUR_SECS;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct UserState {
     pub is_authenticated: bool,
@@ -15,6 +19,15 @@ pub struct UserState {
     pub created_at: Option<String>,
     #[serde(default)]
     pub token_created_at: Option<u64>, // Token创建时间戳（秒），用于判断是否过期
+    /// 邮箱是否已验证（None 表示后端未返回，按已验证处理）
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    /// 用户已关闭"邮箱未验证"横幅
+    #[serde(default)]
+    pub verification_banner_dismissed: bool,
+    /// 登录时勾选了"记住此设备"（刷新令牌保存在加密会话存储中）
+    #[serde(default)]
+    pub remember_device: bool,
 }
 
 impl UserState {
@@ -22,17 +35,13 @@ impl UserState {
     /// 自动检查token是否过期（1小时），过期则清理
     pub fn load() -> Self {
         if let Ok(mut stored) = LocalStorage::get::<UserState>("user_state") {
-            // 检查token是否过期（JWT token过期时间为3600秒=1小时）
-            if let Some(token_time) = stored.token_created_at {
-                let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
-                let token_age = now.saturating_sub(token_time);
-
-                // Token已过期（1小时=3600秒）
-                if token_age >= 3600 {
+            // 检查token是否过期
+            if stored.token_created_at.is_some() {
+                if stored.token_expired(time::now_unix()) {
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::warn;
-                        warn!("⚠️ Token已过期（{}s），自动清理", token_age);
+                        use log::warn;
+                        warn!("⚠️ Token已过期，自动清理");
                     }
                     // 清理过期token
                     stored.is_authenticated = false;
@@ -44,7 +53,7 @@ impl UserState {
                 // 旧数据没有token_created_at字段，保守处理：清理token
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::warn;
+                    use log::warn;
                     warn!("⚠️ 检测到旧token格式（无创建时间），自动清理");
                 }
                 stored.is_authenticated = false;
@@ -69,6 +78,30 @@ impl UserState {
         Ok(())
     }
 
+    /// 访问令牌剩余有效秒数（没有创建时间时为 None）
+    pub fn token_remaining_secs(&self, now_secs: u64) -> Option<u64> {
+        self.token_created_at.map(|created_at| {
+            ACCESS_TOKEN_TTL_SECS.saturating_sub(now_secs.saturating_sub(created_at))
+        })
+    }
+
+    /// 访问令牌是否已过期
+    pub fn token_expired(&self, now_secs: u64) -> bool {
+        self.token_remaining_secs(now_secs) == Some(0)
+    }
+
+    /// 已登录但邮箱明确未验证（法币功能需先完成验证）
+    pub fn needs_email_verification(&self) -> bool {
+        self.is_authenticated && self.email_verified == Some(false)
+    }
+
+    /// 标记邮箱已验证并持久化
+    pub fn mark_email_verified(&mut self) {
+        self.email_verified = Some(true);
+        self.verification_banner_dismissed = false;
+        let _ = self.save();
+    }
+
     /// 生成默认头像（基于邮箱或用户ID）
     pub fn generate_default_avatar(&self) -> String {
         // 使用邮箱或用户ID生成头像
@@ -0,0 +1,232 @@
+//! Gas on Arrival - 跨链到账时兑换目标链 Gas
+//!
+//! 桥接稳定币等代币到新链时，按比例预留一小部分兑换为目标链原生币，避免到账后无 Gas 可用：
+//! - 服务商支持 gas drop-off 时，预留数量随桥接请求提交，由服务商在到账时直接兑换
+//! - 否则在本地排队一笔兑换，桥接状态轮询检测到到账后自动执行；失败时保留任务供重试
+
+use crate::features::swap::dust::swap_and_broadcast;
+use crate::services::address_detector::ChainType;
+use crate::services::bridge_fee::BridgeFeeQuote;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+/// 可选的预留比例
+pub const RESERVE_SHARE_OPTIONS: [f64; 3] = [0.01, 0.02, 0.05];
+/// 默认预留比例
+pub const DEFAULT_RESERVE_SHARE: f64 = 0.02;
+/// 预留比例上限
+const MAX_RESERVE_SHARE: f64 = 0.1;
+const SHARE_STORAGE_KEY: &str = "bridge_gas_reserve_share";
+const JOBS_STORAGE_KEY: &str = "bridge_gas_swaps";
+/// 兑换的 client_request_id 前缀
+const REQUEST_PREFIX: &str = "gas-on-arrival:";
+
+/// 预留比例（LocalStorage）
+pub fn reserve_share() -> f64 {
+    LocalStorage::get::<f64>(SHARE_STORAGE_KEY)
+        .ok()
+        .filter(|s| *s > 0.0 && *s <= MAX_RESERVE_SHARE)
+        .unwrap_or(DEFAULT_RESERVE_SHARE)
+}
+
+pub fn set_reserve_share(share: f64) {
+    let _ = LocalStorage::set(SHARE_STORAGE_KEY, share.clamp(0.0, MAX_RESERVE_SHARE));
+}
+
+/// 到账 Gas 方案
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasOnArrivalPlan {
+    /// 预留兑换为 Gas 的代币数量
+    pub reserve_amount: f64,
+    /// 目标链原生币
+    pub native_symbol: &'static str,
+    /// 是否由服务商 gas drop-off 完成（否则到账后自动兑换）
+    pub via_provider: bool,
+}
+
+/// 计算预留方案；桥接的本身就是目标链原生币时无需预留
+pub fn plan(
+    amount: f64,
+    share: f64,
+    token: &str,
+    destination: ChainType,
+    quote: Option<&BridgeFeeQuote>,
+) -> Option<GasOnArrivalPlan> {
+    let native_symbol = destination.native_token_symbol();
+    if !amount.is_finite() || amount <= 0.0 || token.eq_ignore_ascii_case(native_symbol) {
+        return None;
+    }
+    let reserve_amount = amount * share.clamp(0.0, MAX_RESERVE_SHARE);
+    (reserve_amount > 0.0).then(|| GasOnArrivalPlan {
+        reserve_amount,
+        native_symbol,
+        via_provider: quote.is_some_and(|q| q.destination_gas_dropoff > 0.0),
+    })
+}
+
+/// 到账后兑换任务状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum GasSwapStatus {
+    /// 等待桥接到账
+    Queued,
+    Failed {
+        error: String,
+    },
+    Done {
+        tx_hash: String,
+    },
+}
+
+/// 到账后兑换任务
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GasSwapJob {
+    pub bridge_id: String,
+    pub wallet_id: String,
+    /// 目标链（ChainType::as_str）
+    pub network: String,
+    pub token: String,
+    pub amount: String,
+    pub native_symbol: String,
+    pub status: GasSwapStatus,
+}
+
+pub fn load_jobs() -> Vec<GasSwapJob> {
+    LocalStorage::get(JOBS_STORAGE_KEY).unwrap_or_default()
+}
+
+fn save_jobs(jobs: &[GasSwapJob]) {
+    let _ = LocalStorage::set(JOBS_STORAGE_KEY, jobs);
+}
+
+/// 桥接提交成功后排队兑换任务
+pub fn queue(job: GasSwapJob) {
+    let mut jobs = load_jobs();
+    jobs.retain(|j| j.bridge_id != job.bridge_id);
+    jobs.push(job);
+    save_jobs(&jobs);
+}
+
+pub fn job_for(bridge_id: &str) -> Option<GasSwapJob> {
+    load_jobs().into_iter().find(|j| j.bridge_id == bridge_id)
+}
+
+fn set_status(bridge_id: &str, status: GasSwapStatus) {
+    let mut jobs = load_jobs();
+    if let Some(job) = jobs.iter_mut().find(|j| j.bridge_id == bridge_id) {
+        job.status = status;
+    }
+    save_jobs(&jobs);
+}
+
+/// 待执行（排队中或失败待重试）的任务
+pub fn unfinished(jobs: &[GasSwapJob]) -> Vec<&GasSwapJob> {
+    jobs.iter()
+        .filter(|j| !matches!(j.status, GasSwapStatus::Done { .. }))
+        .collect()
+}
+
+/// 执行到账后的兑换，返回交易哈希；结果写回任务状态
+pub async fn run_swap(app_state: AppState, bridge_id: &str) -> Result<String, String> {
+    let job = job_for(bridge_id).ok_or_else(|| "未找到到账 Gas 兑换任务".to_string())?;
+    if let GasSwapStatus::Done { tx_hash } = &job.status {
+        return Ok(tx_hash.clone());
+    }
+    let result = execute(app_state, &job).await;
+    set_status(
+        bridge_id,
+        match &result {
+            Ok(tx_hash) => GasSwapStatus::Done {
+                tx_hash: tx_hash.clone(),
+            },
+            Err(error) => GasSwapStatus::Failed {
+                error: error.clone(),
+            },
+        },
+    );
+    result
+}
+
+async fn execute(app_state: AppState, job: &GasSwapJob) -> Result<String, String> {
+    swap_and_broadcast(
+        app_state,
+        &job.wallet_id,
+        &job.network,
+        &job.token,
+        &job.native_symbol,
+        &job.amount,
+        format!("{}{}", REQUEST_PREFIX, job.bridge_id),
+        None,
+    )
+    .await
+    .map(|(tx_hash, _)| tx_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(dropoff: f64) -> BridgeFeeQuote {
+        BridgeFeeQuote {
+            bridge_fee: 0.0,
+            source_gas_fee: 0.0,
+            target_gas_fee: 0.0,
+            total_fee: 0.0,
+            bridge_protocol: "stargate".to_string(),
+            estimated_time_seconds: 0,
+            min_amount: None,
+            max_amount: None,
+            destination_gas_dropoff: dropoff,
+        }
+    }
+
+    #[test]
+    fn plan_reserves_share_and_picks_route() {
+        let p = plan(100.0, 0.02, "USDC", ChainType::Polygon, Some(&quote(0.0))).unwrap();
+        assert!((p.reserve_amount - 2.0).abs() < 1e-9);
+        assert_eq!(p.native_symbol, "MATIC");
+        assert!(!p.via_provider);
+
+        let p = plan(100.0, 0.5, "USDC", ChainType::BSC, Some(&quote(0.01))).unwrap();
+        assert!((p.reserve_amount - 10.0).abs() < 1e-9);
+        assert!(p.via_provider);
+
+        assert!(plan(1.0, 0.02, "eth", ChainType::Ethereum, None).is_none());
+        assert!(plan(0.0, 0.02, "USDC", ChainType::Ethereum, None).is_none());
+    }
+
+    #[test]
+    fn finished_jobs_are_not_rerun() {
+        let job = |id: &str, status: GasSwapStatus| GasSwapJob {
+            bridge_id: id.to_string(),
+            wallet_id: "w".to_string(),
+            network: "polygon".to_string(),
+            token: "USDC".to_string(),
+            amount: "2".to_string(),
+            native_symbol: "MATIC".to_string(),
+            status,
+        };
+        let jobs = vec![
+            job("a", GasSwapStatus::Queued),
+            job(
+                "b",
+                GasSwapStatus::Done {
+                    tx_hash: "0x1".to_string(),
+                },
+            ),
+            job(
+                "c",
+                GasSwapStatus::Failed {
+                    error: "x".to_string(),
+                },
+            ),
+        ];
+        let ids: Vec<&str> = unfinished(&jobs)
+            .iter()
+            .map(|j| j.bridge_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "c"]);
+    }
+}
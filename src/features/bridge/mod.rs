@@ -1,2 +1,5 @@
 // Bridge feature module
 // Bridge feature module - Production-ready implementation
+
+pub mod gas_on_arrival;
+pub mod prefill;
@@ -0,0 +1,38 @@
+//! Bridge Prefill - 跨链页预填参数
+//! 从其他页面跳转到跨链页时记录源链与代币（内部转账还会带上目标链、数量与接收地址），
+//! 跨链页初始化时读取一次后删除
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const PENDING_PREFILL_KEY: &str = "bridge_pending_prefill";
+
+/// 跨链页待预填的参数
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BridgePrefill {
+    /// 源链（ChainType::as_str）
+    pub from_chain: String,
+    /// 代币符号
+    pub token: String,
+    /// 目标链（ChainType::as_str；为空时由跨链页自动选择）
+    #[serde(default)]
+    pub to_chain: Option<String>,
+    /// 数量
+    #[serde(default)]
+    pub amount: Option<String>,
+    /// 目标链接收地址（为空时发送到当前钱包在目标链的账户）
+    #[serde(default)]
+    pub destination_address: Option<String>,
+}
+
+/// 记录跳转到跨链页后需要预填的参数
+pub fn set_pending_prefill(prefill: &BridgePrefill) {
+    let _ = LocalStorage::set(PENDING_PREFILL_KEY, prefill);
+}
+
+/// 取出待预填的参数（只生效一次）
+pub fn take_pending_prefill() -> Option<BridgePrefill> {
+    let prefill = LocalStorage::get::<BridgePrefill>(PENDING_PREFILL_KEY).ok();
+    LocalStorage::delete(PENDING_PREFILL_KEY);
+    prefill
+}
@@ -1,2 +1,161 @@
-// DApps feature module
-// DApps feature module - Production-ready implementation
+//! DApps - 已连接 dApp 的会话与授权
+//!
+//! WalletConnect 配对成功后在本地记录会话（授权的账户、链、方法与最近活动），
+//! dApp 页面据此展示授权情况，并支持断开连接或限制为单个账户
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const SESSIONS_STORAGE_KEY: &str = "dapp_sessions";
+
+/// 已连接的 dApp 会话
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DappSession {
+    /// WalletConnect 会话 topic
+    pub topic: String,
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    /// 授权的账户地址
+    pub accounts: Vec<String>,
+    /// 授权的链
+    pub chains: Vec<String>,
+    /// 授权的 RPC 方法（如 eth_sendTransaction、personal_sign）
+    pub methods: Vec<String>,
+    pub connected_at: u64,
+    pub last_active: u64,
+    /// 会话过期时间（秒）
+    pub expires_at: u64,
+}
+
+impl DappSession {
+    pub fn is_live(&self, now: u64) -> bool {
+        self.expires_at > now
+    }
+}
+
+/// 已记录的会话，最近活动在前
+pub fn load_sessions() -> Vec<DappSession> {
+    let mut sessions: Vec<DappSession> =
+        LocalStorage::get(SESSIONS_STORAGE_KEY).unwrap_or_default();
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.last_active));
+    sessions
+}
+
+fn save_sessions(sessions: &[DappSession]) {
+    let _ = LocalStorage::set(SESSIONS_STORAGE_KEY, sessions);
+}
+
+/// 配对成功或会话更新时记录
+pub fn upsert_session(session: DappSession) {
+    let mut sessions = load_sessions();
+    sessions.retain(|s| s.topic != session.topic);
+    sessions.push(session);
+    save_sessions(&sessions);
+}
+
+/// 断开连接（删除本地会话记录）
+pub fn disconnect(topic: &str) -> Vec<DappSession> {
+    let mut sessions = load_sessions();
+    sessions.retain(|s| s.topic != topic);
+    save_sessions(&sessions);
+    sessions
+}
+
+/// 将会话的授权账户限制为单个账户
+pub fn restrict_to_account(topic: &str, account: &str) -> Vec<DappSession> {
+    let mut sessions = load_sessions();
+    if let Some(session) = sessions.iter_mut().find(|s| s.topic == topic) {
+        restrict_accounts(session, account);
+    }
+    save_sessions(&sessions);
+    sessions
+}
+
+/// 只保留指定账户（账户未授权时不做修改）
+pub fn restrict_accounts(session: &mut DappSession, account: &str) -> bool {
+    if !session
+        .accounts
+        .iter()
+        .any(|a| a.eq_ignore_ascii_case(account))
+    {
+        return false;
+    }
+    session.accounts.retain(|a| a.eq_ignore_ascii_case(account));
+    true
+}
+
+/// URL 的主机名（小写，去掉 www.），用于匹配目录与会话
+pub fn host_of(url: &str) -> Option<String> {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = rest
+        .split(['/', '?', '#'])
+        .next()?
+        .rsplit('@')
+        .next()?
+        .split(':')
+        .next()?
+        .to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host).to_string();
+    (!host.is_empty()).then_some(host)
+}
+
+/// 某个 dApp 当前有效的会话
+pub fn live_session_for<'a>(
+    sessions: &'a [DappSession],
+    url: &str,
+    now: u64,
+) -> Option<&'a DappSession> {
+    let host = host_of(url)?;
+    sessions
+        .iter()
+        .find(|s| s.is_live(now) && host_of(&s.url).as_deref() == Some(host.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(url: &str, expires_at: u64) -> DappSession {
+        DappSession {
+            topic: "t1".to_string(),
+            name: "Uniswap".to_string(),
+            url: url.to_string(),
+            icon_url: None,
+            accounts: vec!["0xAAA".to_string(), "0xbbb".to_string()],
+            chains: vec!["eip155:1".to_string()],
+            methods: vec!["eth_sendTransaction".to_string()],
+            connected_at: 0,
+            last_active: 0,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn sessions_match_catalog_by_host() {
+        assert_eq!(
+            host_of("https://www.App.Uniswap.org/swap?x=1"),
+            Some("app.uniswap.org".to_string())
+        );
+        assert_eq!(
+            host_of("app.uniswap.org:443"),
+            Some("app.uniswap.org".to_string())
+        );
+        assert_eq!(host_of("https://"), None);
+
+        let sessions = vec![session("https://app.uniswap.org", 100)];
+        assert!(live_session_for(&sessions, "https://app.uniswap.org/#/swap", 50).is_some());
+        assert!(live_session_for(&sessions, "https://app.uniswap.org", 100).is_none());
+        assert!(live_session_for(&sessions, "https://aave.com", 50).is_none());
+    }
+
+    #[test]
+    fn restrict_keeps_only_granted_account() {
+        let mut s = session("https://app.uniswap.org", 100);
+        assert!(!restrict_accounts(&mut s, "0xccc"));
+        assert_eq!(s.accounts.len(), 2);
+        assert!(restrict_accounts(&mut s, "0xaaa"));
+        assert_eq!(s.accounts, vec!["0xAAA".to_string()]);
+    }
+}
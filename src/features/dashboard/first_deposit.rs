@@ -0,0 +1,174 @@
+//! First Deposit - 首次充值引导
+//!
+//! 新钱包余额为空时，引导用户完成首次充值：
+//! 1. 选择充值方式（刷卡购买 / 从交易所转入 / 跨链桥入）
+//! 2. 在引导内直接完成该方式的操作
+//! 3. 余额轮询检测到入账后庆祝完成，并建议进行第一次兑换
+//!
+//! 进度按钱包保存在新手引导管理器的存储中，跨会话可继续。
+
+use crate::components::molecules::OnboardingManager;
+use serde::{Deserialize, Serialize};
+
+/// 充值方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FundingMethod {
+    /// 刷卡购买稳定币
+    Buy,
+    /// 从交易所或其他钱包转入
+    Receive,
+    /// 从其他链跨链转入
+    Bridge,
+}
+
+impl FundingMethod {
+    pub const ALL: [FundingMethod; 3] = [Self::Buy, Self::Receive, Self::Bridge];
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Buy => "💳",
+            Self::Receive => "📥",
+            Self::Bridge => "🌉",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Buy => "刷卡购买",
+            Self::Receive => "从交易所转入",
+            Self::Bridge => "跨链转入",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Buy => "使用银行卡或第三方支付购买 USDT/USDC，几分钟内到账",
+            Self::Receive => "复制收款地址或扫码，从交易所或其他钱包提币到此钱包",
+            Self::Bridge => "已有其他链上的资产？通过跨链桥转入当前钱包",
+        }
+    }
+}
+
+/// 引导步骤
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FirstDepositStep {
+    #[default]
+    ChooseMethod,
+    Execute,
+    Complete,
+}
+
+impl FirstDepositStep {
+    /// 步骤序号（从 1 开始）
+    pub fn number(&self) -> usize {
+        match self {
+            Self::ChooseMethod => 1,
+            Self::Execute => 2,
+            Self::Complete => 3,
+        }
+    }
+}
+
+/// 首次充值引导进度（按钱包保存）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FirstDepositProgress {
+    pub step: FirstDepositStep,
+    pub method: Option<FundingMethod>,
+    /// 用户是否已开始引导（从空状态入口点击后为 true）
+    pub started: bool,
+    /// 已完成或已关闭，不再显示
+    pub finished: bool,
+}
+
+impl FirstDepositProgress {
+    fn storage_flow(wallet_id: &str) -> String {
+        format!("first_deposit_{}", wallet_id)
+    }
+
+    /// 读取钱包的引导进度
+    pub fn load(wallet_id: &str) -> Self {
+        OnboardingManager::load_progress(&Self::storage_flow(wallet_id)).unwrap_or_default()
+    }
+
+    /// 保存钱包的引导进度
+    pub fn save(&self, wallet_id: &str) {
+        OnboardingManager::save_progress(&Self::storage_flow(wallet_id), self);
+    }
+
+    /// 从空状态入口开始（或继续）引导
+    pub fn start(&mut self) {
+        self.started = true;
+    }
+
+    /// 选择充值方式，进入执行步骤
+    pub fn choose(&mut self, method: FundingMethod) {
+        self.started = true;
+        self.method = Some(method);
+        self.step = FirstDepositStep::Execute;
+    }
+
+    /// 返回重新选择充值方式
+    pub fn back(&mut self) {
+        self.method = None;
+        self.step = FirstDepositStep::ChooseMethod;
+    }
+
+    /// 余额更新：执行步骤中检测到入账即完成，返回是否刚刚完成
+    pub fn observe_balance(&mut self, has_funds: bool) -> bool {
+        if has_funds && self.step == FirstDepositStep::Execute && !self.finished {
+            self.step = FirstDepositStep::Complete;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 关闭引导（完成后或用户主动跳过）
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// 是否需要显示引导面板
+    pub fn is_visible(&self) -> bool {
+        self.started && !self.finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flow_advances_to_completion_only_from_execute() {
+        let mut progress = FirstDepositProgress::default();
+        assert!(!progress.is_visible());
+        assert!(!progress.observe_balance(true));
+
+        progress.start();
+        progress.choose(FundingMethod::Receive);
+        assert_eq!(progress.step, FirstDepositStep::Execute);
+        assert!(!progress.observe_balance(false));
+        assert!(progress.observe_balance(true));
+        assert_eq!(progress.step, FirstDepositStep::Complete);
+        assert!(!progress.observe_balance(true));
+        assert!(progress.is_visible());
+
+        progress.finish();
+        assert!(!progress.is_visible());
+    }
+
+    #[test]
+    fn back_resets_method_and_progress_round_trips() {
+        let mut progress = FirstDepositProgress::default();
+        progress.choose(FundingMethod::Bridge);
+        progress.back();
+        assert_eq!(progress.step, FirstDepositStep::ChooseMethod);
+        assert_eq!(progress.method, None);
+        assert!(progress.started);
+
+        progress.choose(FundingMethod::Buy);
+        let json = serde_json::to_string(&progress).unwrap();
+        let restored: FirstDepositProgress = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, progress);
+    }
+}
@@ -0,0 +1,382 @@
+//! Dashboard Loader - 仪表盘分级数据加载
+//!
+//! 加载顺序：
+//! 1. 关键数据：选中钱包的原生币余额 + 稳定币余额（骨架屏等待此阶段）
+//! 2. 次要数据：价格、交易历史与主要持仓的代币图标
+//! 3. 空闲预取：浏览器空闲时预取其他钱包余额（写入请求缓存，切换钱包时秒开）
+
+use crate::components::molecules::performance_monitor::record_phase_timing;
+use crate::features::market;
+use crate::features::wallet::state::Wallet;
+use crate::features::wallet::token_preferences::TokenPreferences;
+use crate::services::address_detector::ChainType;
+use crate::services::balance::{BalanceService, PortfolioSnapshot, BALANCE_CACHE_PREFIXES};
+use crate::services::lazy_loader::LazyLoader;
+use crate::services::price::PRICE_CACHE_PREFIX;
+use crate::services::token::TokenInfo;
+use crate::services::token_logo;
+use crate::services::token_metadata::TokenMetadataService;
+use crate::shared::metrics;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use crate::shared::validation::TokenAmount;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 自动刷新间隔（毫秒）
+const REFRESH_INTERVAL_MS: u32 = 30_000;
+/// 空闲预取最长等待时间（毫秒）
+const IDLE_PREFETCH_TIMEOUT_MS: u32 = 3_000;
+/// 每次加载核对链上元数据的持仓代币数量
+const VERIFY_TOP_HOLDINGS: usize = 10;
+
+/// 加载阶段（按优先级排序，可用 `>=` 判断某阶段是否已就绪）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DashboardLoadPhase {
+    /// 正在加载关键数据（余额）
+    Critical,
+    /// 关键数据就绪，正在加载价格与历史
+    Secondary,
+    /// 首屏数据就绪，空闲时预取其他钱包
+    Prefetch,
+    /// 全部完成
+    Complete,
+}
+
+impl DashboardLoadPhase {
+    /// 余额是否已可展示
+    pub fn balances_ready(self) -> bool {
+        self >= DashboardLoadPhase::Secondary
+    }
+
+    /// 价格（法币估值）是否已可展示
+    pub fn prices_ready(self) -> bool {
+        self >= DashboardLoadPhase::Prefetch
+    }
+}
+
+/// 仪表盘数据（所有字段均为 Signal，可作为组件 Props 传递）
+#[derive(Clone, Copy, PartialEq)]
+pub struct DashboardData {
+    pub phase: Signal<DashboardLoadPhase>,
+    /// 资产快照（原生币 + 稳定币，最小单位）
+    pub portfolio: Signal<PortfolioSnapshot>,
+    /// 原生币 USD 价格，按链符号索引
+    pub prices: Signal<HashMap<String, f64>>,
+    /// 余额与价格中最旧的查询时间（Unix 秒）
+    pub updated_at: Signal<Option<u64>>,
+    /// 代币显示偏好（已隐藏的代币默认不计入总额）
+    pub token_prefs: Signal<TokenPreferences>,
+    refresh_nonce: Signal<u32>,
+}
+
+impl DashboardData {
+    /// 手动触发重新加载
+    pub fn refresh(&self) {
+        let mut nonce = self.refresh_nonce;
+        let next = nonce.peek().wrapping_add(1);
+        nonce.set(next);
+    }
+
+    /// 跳过请求缓存重新加载（数据过期后手动刷新、切回标签页时使用）
+    pub fn force_refresh(&self, app_state: AppState) {
+        let mut cache = app_state.cache;
+        cache.write().retain(|key, _| {
+            !BALANCE_CACHE_PREFIXES
+                .iter()
+                .chain(std::iter::once(&PRICE_CACHE_PREFIX))
+                .any(|prefix| key.starts_with(prefix))
+        });
+        let mut market = app_state.market;
+        market.write().expire_prices();
+        self.refresh();
+    }
+
+    /// 原生币余额（未查询到时为 None）
+    pub fn native_balance(&self, symbol: &str) -> Option<TokenAmount> {
+        self.portfolio.read().native(symbol)
+    }
+
+    /// 原生币 USD 价值（余额或价格未就绪时为 None）
+    pub fn native_usd(&self, symbol: &str) -> Option<f64> {
+        let price = self.prices.read().get(symbol).copied()?;
+        Some(self.native_balance(symbol)?.to_f64_lossy() * price)
+    }
+
+    /// 稳定币（USDT + USDC）USD 价值，不含已隐藏的代币（除非设置为包含）
+    pub fn stablecoin_usd(&self) -> f64 {
+        let prefs = self.token_prefs.read();
+        self.portfolio
+            .read()
+            .stablecoin_usd_excluding(|key| prefs.excluded_from_totals(key))
+    }
+
+    /// 代币显示设置变更后重新读取
+    pub fn reload_token_prefs(&self) {
+        let mut prefs = self.token_prefs;
+        prefs.set(TokenPreferences::load());
+    }
+
+    /// 总资产 USD 价值（原生币 + 稳定币）
+    pub fn total_usd(&self) -> f64 {
+        let symbols = self.portfolio.read().native_symbols();
+        let native: f64 = symbols.iter().filter_map(|s| self.native_usd(s)).sum();
+        native + self.stablecoin_usd()
+    }
+
+    /// 是否已有任何资产（原生币或稳定币余额大于 0）
+    pub fn has_funds(&self) -> bool {
+        self.portfolio.read().has_funds()
+    }
+
+    /// 余额已加载完成但没有任何一条成功查询到（全部失败）
+    pub fn balances_failed(&self) -> bool {
+        (self.phase)().balances_ready() && self.portfolio.read().is_empty()
+    }
+}
+
+/// 仪表盘分级加载 Hook
+///
+/// 监听选中钱包变化与手动刷新，每30秒自动刷新一次
+pub fn use_dashboard_loader() -> DashboardData {
+    let app_state = use_context::<AppState>();
+
+    let data = DashboardData {
+        phase: use_signal(|| DashboardLoadPhase::Critical),
+        portfolio: use_signal(PortfolioSnapshot::default),
+        prices: use_signal(HashMap::new),
+        updated_at: use_signal(|| None),
+        token_prefs: use_signal(TokenPreferences::load),
+        refresh_nonce: use_signal(|| 0),
+    };
+    // 每次加载递增，旧的加载任务发现代次变化后放弃写入
+    let mut generation = use_signal(|| 0u64);
+    // 上次加载的钱包ID，切换钱包时清空旧数据并重新显示骨架屏
+    let mut loaded_wallet_id = use_signal(|| Option::<String>::None);
+
+    // 定时刷新
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(REFRESH_INTERVAL_MS).await;
+            data.refresh();
+        }
+    });
+
+    // 切回标签页时立即刷新
+    let mut seen_resume = use_signal(|| *app_state.resume_tick.peek());
+    use_effect(move || {
+        let tick = (app_state.resume_tick)();
+        if tick != *seen_resume.peek() {
+            seen_resume.set(tick);
+            data.force_refresh(app_state);
+        }
+    });
+
+    // 选中钱包与其他钱包（仅在内容变化时触发重新加载）
+    let targets = use_memo(move || {
+        let wallet_state = app_state.wallet.read();
+        let selected = wallet_state.get_selected_wallet().cloned()?;
+        let others: Vec<Wallet> = wallet_state
+            .wallets
+            .iter()
+            .filter(|w| w.id != selected.id)
+            .cloned()
+            .collect();
+        Some((selected, others))
+    });
+
+    use_effect(move || {
+        let _ = (data.refresh_nonce)();
+        let Some((selected, others)) = targets() else {
+            return;
+        };
+
+        if loaded_wallet_id.peek().as_deref() != Some(selected.id.as_str()) {
+            loaded_wallet_id.set(Some(selected.id.clone()));
+            let (mut portfolio, mut prices, mut updated_at) =
+                (data.portfolio, data.prices, data.updated_at);
+            portfolio.set(PortfolioSnapshot::default());
+            prices.set(HashMap::new());
+            updated_at.set(None);
+        }
+
+        let current = *generation.peek() + 1;
+        generation.set(current);
+        spawn(run_load(
+            app_state, data, generation, current, selected, others,
+        ));
+    });
+
+    data
+}
+
+async fn run_load(
+    app_state: AppState,
+    data: DashboardData,
+    generation: Signal<u64>,
+    current: u64,
+    wallet: Wallet,
+    others: Vec<Wallet>,
+) {
+    let is_stale = move || *generation.peek() != current;
+    let mut phase = data.phase;
+    let mut portfolio_sig = data.portfolio;
+    let mut prices_sig = data.prices;
+    let mut updated_at = data.updated_at;
+    let balance_service = BalanceService::new(app_state);
+
+    // 阶段1：关键数据（首次加载显示骨架屏，刷新时保留旧数据）
+    let started = time::now_ms();
+    if portfolio_sig.peek().is_empty() {
+        phase.set(DashboardLoadPhase::Critical);
+    }
+    let portfolio = balance_service.get_portfolio(&wallet).await;
+    if is_stale() {
+        return;
+    }
+    portfolio_sig.set(portfolio);
+    phase.set(DashboardLoadPhase::Secondary);
+    metrics::record_first_balance();
+    record_phase_timing(app_state, "dashboard.critical", time::now_ms() - started);
+
+    // 阶段2：价格（交易历史组件在此阶段开始自行加载）
+    let started = time::now_ms();
+    let symbols = portfolio_sig.peek().native_symbols();
+    let (prices, prices_at) = load_prices(app_state, &symbols).await;
+    if is_stale() {
+        return;
+    }
+    prices_sig.set(prices);
+    let balances_at = portfolio_sig.peek().fetched_at();
+    updated_at.set(balances_at.into_iter().chain(prices_at).min());
+    phase.set(DashboardLoadPhase::Prefetch);
+    record_phase_timing(app_state, "dashboard.secondary", time::now_ms() - started);
+
+    // 核对主要持仓代币的链上精度与符号（每条链一次 Multicall，已校验的跳过）
+    let holdings = top_holdings(&portfolio_sig.peek(), VERIFY_TOP_HOLDINGS);
+    // 同时预取这些持仓的图标，资产列表展开时直接命中缓存
+    token_logo::prefetch(&holdings);
+    TokenMetadataService::new(app_state)
+        .verify_batch(&holdings)
+        .await;
+    if is_stale() {
+        return;
+    }
+
+    // 阶段3：空闲时预取其他钱包余额
+    if !others.is_empty() {
+        LazyLoader::wait_for_idle(IDLE_PREFETCH_TIMEOUT_MS).await;
+        if is_stale() {
+            return;
+        }
+        let started = time::now_ms();
+        for other in &others {
+            let _ = balance_service.get_portfolio(other).await;
+            if is_stale() {
+                return;
+            }
+        }
+        record_phase_timing(app_state, "dashboard.prefetch", time::now_ms() - started);
+    }
+    phase.set(DashboardLoadPhase::Complete);
+}
+
+/// 余额最多的前 `limit` 个代币持仓（不含原生币与零余额）
+fn top_holdings(portfolio: &PortfolioSnapshot, limit: usize) -> Vec<TokenInfo> {
+    let mut held: Vec<(f64, TokenInfo)> = Vec::new();
+    for account in &portfolio.accounts {
+        let Some(chain) = ChainType::from_str(&account.chain) else {
+            continue;
+        };
+        for asset in &account.tokens {
+            let Some(address) = asset.token_address.as_ref() else {
+                continue;
+            };
+            if asset.balance.is_zero()
+                || held
+                    .iter()
+                    .any(|(_, t)| t.chain == chain && t.address.eq_ignore_ascii_case(address))
+            {
+                continue;
+            }
+            let token = TokenInfo {
+                address: address.clone(),
+                symbol: asset.symbol.clone(),
+                name: asset.symbol.clone(),
+                decimals: asset.balance.decimals(),
+                chain,
+                logo_url: None,
+                is_native: false,
+                is_spam: false,
+            };
+            held.push((asset.balance.to_f64_lossy(), token));
+        }
+    }
+    held.sort_by(|a, b| b.0.total_cmp(&a.0));
+    held.into_iter().take(limit).map(|(_, t)| t).collect()
+}
+
+/// 价格及最旧的价格更新时间：共享行情刚更新过的直接使用，其余合并为一次请求
+/// （失败时返回空表，界面只显示余额）
+async fn load_prices(
+    app_state: AppState,
+    symbols: &[String],
+) -> (HashMap<String, f64>, Option<u64>) {
+    if symbols.is_empty() {
+        return (HashMap::new(), None);
+    }
+    let max_age_secs = u64::from(REFRESH_INTERVAL_MS / 1000);
+    let prices = market::poller::ensure_prices(app_state, symbols, max_age_secs).await;
+    let oldest = prices.values().map(|p| p.last_updated).min();
+    (
+        prices.into_iter().map(|(k, v)| (k, v.usd)).collect(),
+        oldest,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_are_ordered_by_priority() {
+        assert!(!DashboardLoadPhase::Critical.balances_ready());
+        assert!(DashboardLoadPhase::Secondary.balances_ready());
+        assert!(!DashboardLoadPhase::Secondary.prices_ready());
+        assert!(DashboardLoadPhase::Prefetch.prices_ready());
+        assert!(DashboardLoadPhase::Complete.prices_ready());
+    }
+
+    #[test]
+    fn top_holdings_skip_native_and_empty_balances() {
+        use crate::services::balance::{AccountBalances, AssetBalance};
+
+        let asset = |symbol: &str, address: Option<&str>, raw: &str| AssetBalance {
+            symbol: symbol.to_string(),
+            token_address: address.map(str::to_string),
+            balance: TokenAmount::from_raw_str(raw, 6).unwrap(),
+            is_stablecoin: true,
+        };
+        let portfolio = PortfolioSnapshot {
+            accounts: vec![AccountBalances {
+                address: "0xabc".to_string(),
+                chain: "ethereum".to_string(),
+                native: Some(asset("ETH", None, "1000000")),
+                tokens: vec![
+                    asset("USDC", Some("0xa0b8"), "5000000"),
+                    asset("DAI", Some("0x6b17"), "0"),
+                    asset("USDT", Some("0xdac1"), "9000000"),
+                ],
+                fetched_at: 0,
+            }],
+        };
+
+        let top = top_holdings(&portfolio, 10);
+        let symbols: Vec<&str> = top.iter().map(|t| t.symbol.as_str()).collect();
+        assert_eq!(symbols, ["USDT", "USDC"]);
+        assert!(top
+            .iter()
+            .all(|t| t.chain == ChainType::Ethereum && t.decimals == 6));
+        assert_eq!(top_holdings(&portfolio, 1).len(), 1);
+    }
+}
@@ -1,2 +1,6 @@
 // Dashboard feature module
 // Dashboard feature module - Production-ready implementation
+pub mod first_deposit;
+pub mod loader;
+pub mod watchlist;
+pub mod widgets;
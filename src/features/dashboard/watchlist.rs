@@ -0,0 +1,159 @@
+//! Watchlist - 价格关注列表
+//!
+//! 用户关注的代币（不要求持有），按用户保存在本设备的加密存储中，
+//! 切换钱包不受影响；其他用户登录同一设备看不到。
+
+use crate::services::address_detector::ChainType;
+use crate::services::token::TokenInfo;
+use crate::shared::storage::device_storage;
+use serde::{Deserialize, Serialize};
+
+/// 关注列表最多保留的代币数量
+pub const MAX_WATCHLIST: usize = 20;
+
+/// 关注的代币
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    pub symbol: String,
+    pub name: String,
+    pub chain: ChainType,
+    pub address: String,
+}
+
+impl WatchlistEntry {
+    /// 唯一键（链 + 小写合约地址），与代币选择偏好一致
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.chain.as_str(), self.address.to_lowercase())
+    }
+}
+
+impl From<&TokenInfo> for WatchlistEntry {
+    fn from(token: &TokenInfo) -> Self {
+        Self {
+            symbol: token.symbol.clone(),
+            name: token.name.clone(),
+            chain: token.chain,
+            address: token.address.clone(),
+        }
+    }
+}
+
+/// 关注列表（按添加顺序）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Watchlist {
+    #[serde(default)]
+    pub entries: Vec<WatchlistEntry>,
+}
+
+impl Watchlist {
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.iter().any(|e| e.key() == key)
+    }
+
+    /// 添加代币，已存在或已满时返回 false
+    pub fn add(&mut self, entry: WatchlistEntry) -> bool {
+        if self.entries.len() >= MAX_WATCHLIST || self.contains(&entry.key()) {
+            return false;
+        }
+        self.entries.push(entry);
+        true
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        self.entries.retain(|e| e.key() != key);
+    }
+
+    /// 合并另一份关注列表（保留现有顺序，追加新代币直到上限），返回新增数量
+    pub fn merge(&mut self, other: &Watchlist) -> usize {
+        other
+            .entries
+            .iter()
+            .filter(|e| self.add((*e).clone()))
+            .count()
+    }
+
+    /// 去重后的代币符号（用于批量查询价格）
+    pub fn symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = Vec::new();
+        for entry in &self.entries {
+            let symbol = entry.symbol.to_uppercase();
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        symbols
+    }
+
+    /// 未保存过关注列表时的默认内容
+    pub fn default_entries() -> Self {
+        let entries = [
+            (ChainType::Bitcoin, "BTC", "Bitcoin"),
+            (ChainType::Ethereum, "ETH", "Ethereum"),
+            (ChainType::Solana, "SOL", "Solana"),
+        ]
+        .into_iter()
+        .map(|(chain, symbol, name)| WatchlistEntry {
+            symbol: symbol.to_string(),
+            name: name.to_string(),
+            chain,
+            address: "native".to_string(),
+        })
+        .collect();
+        Self { entries }
+    }
+}
+
+fn storage_key(user_id: &str) -> String {
+    format!("watchlist_{}", user_id)
+}
+
+/// 读取用户的关注列表（未保存过时返回默认列表，无法解密时同样回退默认）
+pub async fn load(user_id: &str) -> Watchlist {
+    match device_storage().load(&storage_key(user_id)).await {
+        Ok(Some(bytes)) => serde_json::from_slice(&bytes).unwrap_or_default(),
+        Ok(None) => Watchlist::default_entries(),
+        Err(e) => {
+            log::warn!("关注列表无法解密，已使用默认列表: {}", e);
+            Watchlist::default_entries()
+        }
+    }
+}
+
+pub async fn save(user_id: &str, watchlist: &Watchlist) -> anyhow::Result<()> {
+    device_storage()
+        .save(&storage_key(user_id), &serde_json::to_vec(watchlist)?)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(chain: ChainType, symbol: &str, address: &str) -> WatchlistEntry {
+        WatchlistEntry {
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            chain,
+            address: address.to_string(),
+        }
+    }
+
+    #[test]
+    fn add_dedupes_and_caps() {
+        let mut list = Watchlist::default();
+        assert!(list.add(entry(ChainType::Ethereum, "USDT", "0xAbC")));
+        assert!(!list.add(entry(ChainType::Ethereum, "USDT", "0xabc")));
+        // 同一符号在不同链上视为不同代币，但只查询一次价格
+        assert!(list.add(entry(ChainType::BSC, "USDT", "0xabc")));
+        assert_eq!(list.symbols(), vec!["USDT".to_string()]);
+
+        for i in list.entries.len()..MAX_WATCHLIST {
+            assert!(list.add(entry(ChainType::Solana, "SOL", &format!("addr{}", i))));
+        }
+        assert!(!list.add(entry(ChainType::TON, "TON", "native")));
+
+        list.remove("ethereum:0xabc");
+        assert!(!list.contains("ethereum:0xabc"));
+        assert_eq!(list.entries.len(), MAX_WATCHLIST - 1);
+    }
+}
@@ -0,0 +1,174 @@
+//! Dashboard Widgets - 仪表盘小组件布局
+//!
+//! 仪表盘各区块注册为小组件，用户可调整顺序与显示/隐藏。布局保存在用户偏好设置中
+//! （与钱包无关，切换钱包不受影响）；未自定义时按钱包是否有资产给出默认布局。
+
+use serde::{Deserialize, Serialize};
+
+/// 仪表盘小组件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardWidget {
+    BalanceSummary,
+    PortfolioChart,
+    StablecoinCard,
+    RecentTransactions,
+    PendingOrders,
+    PriceWatchlist,
+    SpendByCategory,
+}
+
+impl DashboardWidget {
+    /// 所有已注册的小组件
+    pub const ALL: [DashboardWidget; 7] = [
+        DashboardWidget::BalanceSummary,
+        DashboardWidget::PortfolioChart,
+        DashboardWidget::StablecoinCard,
+        DashboardWidget::RecentTransactions,
+        DashboardWidget::PendingOrders,
+        DashboardWidget::PriceWatchlist,
+        DashboardWidget::SpendByCategory,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DashboardWidget::BalanceSummary => "资产概览",
+            DashboardWidget::PortfolioChart => "资产分布",
+            DashboardWidget::StablecoinCard => "稳定币余额",
+            DashboardWidget::RecentTransactions => "最近交易",
+            DashboardWidget::PendingOrders => "进行中的订单",
+            DashboardWidget::PriceWatchlist => "价格关注",
+            DashboardWidget::SpendByCategory => "本月分类支出",
+        }
+    }
+}
+
+/// 小组件布局：显示顺序 + 隐藏列表
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WidgetLayout {
+    pub order: Vec<DashboardWidget>,
+    #[serde(default)]
+    pub hidden: Vec<DashboardWidget>,
+}
+
+impl WidgetLayout {
+    /// 默认布局：空钱包优先展示充值相关与行情，有资产时优先展示资产与交易
+    pub fn default_for(funded: bool) -> Self {
+        use DashboardWidget::*;
+        if funded {
+            Self {
+                order: vec![
+                    BalanceSummary,
+                    PortfolioChart,
+                    RecentTransactions,
+                    StablecoinCard,
+                    PendingOrders,
+                    PriceWatchlist,
+                    SpendByCategory,
+                ],
+                // 分类支出依赖用户手动标记交易，默认隐藏
+                hidden: vec![SpendByCategory],
+            }
+        } else {
+            Self {
+                order: vec![
+                    BalanceSummary,
+                    StablecoinCard,
+                    PriceWatchlist,
+                    PendingOrders,
+                    RecentTransactions,
+                    PortfolioChart,
+                    SpendByCategory,
+                ],
+                hidden: vec![PortfolioChart, RecentTransactions, SpendByCategory],
+            }
+        }
+    }
+
+    /// 去重，并把新增的小组件追加到末尾（隐藏），兼容旧版本保存的布局
+    pub fn normalized(mut self) -> Self {
+        let mut seen = Vec::with_capacity(DashboardWidget::ALL.len());
+        self.order.retain(|w| {
+            let first = !seen.contains(w);
+            seen.push(*w);
+            first
+        });
+        for widget in DashboardWidget::ALL {
+            if !self.order.contains(&widget) {
+                self.order.push(widget);
+                self.hidden.push(widget);
+            }
+        }
+        self.hidden.dedup();
+        self
+    }
+
+    pub fn is_hidden(&self, widget: DashboardWidget) -> bool {
+        self.hidden.contains(&widget)
+    }
+
+    /// 按顺序排列的可见小组件
+    pub fn visible(&self) -> Vec<DashboardWidget> {
+        self.order
+            .iter()
+            .copied()
+            .filter(|w| !self.is_hidden(*w))
+            .collect()
+    }
+
+    pub fn set_hidden(&mut self, widget: DashboardWidget, hidden: bool) {
+        self.hidden.retain(|w| *w != widget);
+        if hidden {
+            self.hidden.push(widget);
+        }
+    }
+
+    pub fn move_up(&mut self, widget: DashboardWidget) {
+        if let Some(index) = self.order.iter().position(|w| *w == widget) {
+            if index > 0 {
+                self.order.swap(index, index - 1);
+            }
+        }
+    }
+
+    pub fn move_down(&mut self, widget: DashboardWidget) {
+        if let Some(index) = self.order.iter().position(|w| *w == widget) {
+            if index + 1 < self.order.len() {
+                self.order.swap(index, index + 1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DashboardWidget::*;
+
+    #[test]
+    fn reorder_and_hide() {
+        let mut layout = WidgetLayout::default_for(true);
+        layout.move_up(RecentTransactions);
+        layout.move_up(RecentTransactions);
+        layout.move_up(RecentTransactions);
+        assert_eq!(layout.visible()[0], RecentTransactions);
+        layout.move_down(PriceWatchlist);
+        assert_eq!(layout.order.last(), Some(&PriceWatchlist));
+
+        layout.set_hidden(PortfolioChart, true);
+        assert!(!layout.visible().contains(&PortfolioChart));
+        layout.set_hidden(PortfolioChart, false);
+        assert!(layout.visible().contains(&PortfolioChart));
+    }
+
+    #[test]
+    fn normalized_appends_missing_widgets_as_hidden() {
+        let layout = WidgetLayout {
+            order: vec![RecentTransactions, BalanceSummary, RecentTransactions],
+            hidden: Vec::new(),
+        }
+        .normalized();
+        assert_eq!(layout.order.len(), DashboardWidget::ALL.len());
+        assert_eq!(layout.visible(), vec![RecentTransactions, BalanceSummary]);
+    }
+}
@@ -1,2 +1,204 @@
-// Earn feature module
-// Earn feature module - Production-ready implementation
+//! Earn - 理财奖励领取、复投与自动复投提醒
+//!
+//! - 领取/复投交易由本地私钥签名并广播（不托管资产）
+//! - 成功的交易在历史中标记为"奖励"分类，备注记录当时的美元价值，随 CSV 一并导出
+//! - 自动复投为本地偏好：奖励累积超过阈值时提醒用户手动复投，每次越过阈值只提醒一次
+
+use crate::features::activity::labels;
+use crate::features::wallet::chain_accounts;
+use crate::services::earn::{EarnAction, EarnPosition, PreparedEarnTx};
+use crate::services::transaction::TransactionService;
+use crate::shared::kill_switch::{self, MoneyAction};
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 奖励交易在历史记录中的分类
+pub const REWARD_CATEGORY: &str = "reward";
+/// 默认自动复投提醒阈值（USD）
+pub const DEFAULT_COMPOUND_THRESHOLD_USD: f64 = 50.0;
+const PREFS_STORAGE_KEY: &str = "earn_auto_compound";
+const REMINDED_STORAGE_KEY: &str = "earn_compound_reminded";
+
+/// 单个持仓的自动复投偏好
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AutoCompoundPref {
+    pub enabled: bool,
+    pub threshold_usd: f64,
+}
+
+impl Default for AutoCompoundPref {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_usd: DEFAULT_COMPOUND_THRESHOLD_USD,
+        }
+    }
+}
+
+/// 所有持仓的自动复投偏好（键为持仓ID）
+pub fn load_prefs() -> HashMap<String, AutoCompoundPref> {
+    LocalStorage::get(PREFS_STORAGE_KEY).unwrap_or_default()
+}
+
+pub fn auto_compound_pref(position_id: &str) -> AutoCompoundPref {
+    load_prefs().get(position_id).copied().unwrap_or_default()
+}
+
+pub fn set_auto_compound_pref(position_id: &str, pref: AutoCompoundPref) {
+    let mut prefs = load_prefs();
+    prefs.insert(position_id.to_string(), pref);
+    let _ = LocalStorage::set(PREFS_STORAGE_KEY, &prefs);
+}
+
+/// 需要提醒复投的持仓
+///
+/// 返回（本次新提醒的持仓, 更新后的已提醒列表）；奖励回落到阈值以下的持仓从已提醒列表移除，
+/// 下次越过阈值时再提醒
+pub fn compound_reminders<'a>(
+    positions: &'a [EarnPosition],
+    prefs: &HashMap<String, AutoCompoundPref>,
+    reminded: &[String],
+) -> (Vec<&'a EarnPosition>, Vec<String>) {
+    let mut due = Vec::new();
+    let mut still_reminded = Vec::new();
+    for position in positions {
+        let Some(pref) = prefs.get(&position.id).filter(|p| p.enabled) else {
+            continue;
+        };
+        if !position.compoundable || position.pending_rewards_usd < pref.threshold_usd {
+            continue;
+        }
+        if !reminded.contains(&position.id) {
+            due.push(position);
+        }
+        still_reminded.push(position.id.clone());
+    }
+    (due, still_reminded)
+}
+
+/// 取出需要提醒复投的持仓并记录为已提醒
+pub fn take_compound_reminders(positions: &[EarnPosition]) -> Vec<EarnPosition> {
+    let reminded: Vec<String> = LocalStorage::get(REMINDED_STORAGE_KEY).unwrap_or_default();
+    let (due, reminded) = compound_reminders(positions, &load_prefs(), &reminded);
+    let _ = LocalStorage::set(REMINDED_STORAGE_KEY, &reminded);
+    due.into_iter().cloned().collect()
+}
+
+/// 奖励交易的备注（记录领取时的美元价值，供税务导出）
+pub fn reward_note(action: EarnAction, position: &EarnPosition) -> String {
+    format!(
+        "{}：{} {} {}，价值 ${:.2}",
+        action.label(),
+        position.protocol,
+        position.pending_rewards,
+        position.reward_asset,
+        position.pending_rewards_usd
+    )
+}
+
+/// 签名并广播领取/复投交易，成功后在历史中标记为奖励；返回交易哈希
+pub async fn execute(app_state: AppState, prepared: &PreparedEarnTx) -> Result<String, String> {
+    let position = &prepared.position;
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Sign).await?;
+    let private_key = chain_accounts::selected_wallet_signing_key(&app_state, &position.address)
+        .map_err(|e| e.to_string())?;
+    let tx_service = TransactionService::new(app_state);
+    let nonce = tx_service
+        .get_nonce(&position.address, prepared.chain_id)
+        .await
+        .map_err(|e| format!("获取nonce失败: {}", e))?;
+
+    let signed = crate::crypto::worker::sign_eth_transaction_with_data(
+        &private_key,
+        &prepared.tx.to,
+        &prepared.tx.value,
+        &prepared.tx.data,
+        nonce,
+        prepared.gas_price,
+        prepared.gas_limit,
+        prepared.chain_id,
+    )
+    .await
+    .map_err(|e| format!("签名交易失败: {}", e))?;
+
+    let tx_hash = tx_service
+        .broadcast(&position.network, &signed)
+        .await
+        .map(|r| r.tx_hash)
+        .map_err(|e| format!("广播交易失败: {}", e))?;
+
+    record_reward(app_state, &tx_hash, prepared).await;
+    Ok(tx_hash)
+}
+
+/// 在交易标签中记录奖励分类与美元价值
+async fn record_reward(app_state: AppState, tx_hash: &str, prepared: &PreparedEarnTx) {
+    let Some(user_id) = app_state.user.read().user_id.clone() else {
+        return;
+    };
+    let now = crate::shared::time::now_unix();
+    let mut tx_labels = labels::load(&user_id).await;
+    tx_labels.set_category(&[tx_hash.to_string()], Some(REWARD_CATEGORY), now);
+    tx_labels.set_note(
+        tx_hash,
+        &reward_note(prepared.action, &prepared.position),
+        now,
+    );
+    labels::save_and_push(app_state, &user_id, &tx_labels).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(id: &str, rewards_usd: f64) -> EarnPosition {
+        EarnPosition {
+            id: id.to_string(),
+            protocol: "Aave".to_string(),
+            network: "ethereum".to_string(),
+            address: "0xabc".to_string(),
+            asset: "USDC".to_string(),
+            staked_amount: "1000".to_string(),
+            reward_asset: "AAVE".to_string(),
+            pending_rewards: 0.5,
+            pending_rewards_usd: rewards_usd,
+            compoundable: true,
+        }
+    }
+
+    #[test]
+    fn reminders_fire_once_per_threshold_crossing() {
+        let enabled = AutoCompoundPref {
+            enabled: true,
+            threshold_usd: 50.0,
+        };
+        let prefs = HashMap::from([
+            ("a".to_string(), enabled),
+            ("b".to_string(), enabled),
+            ("c".to_string(), AutoCompoundPref::default()),
+        ]);
+        let positions = vec![
+            position("a", 60.0),
+            position("b", 10.0),
+            position("c", 99.0),
+        ];
+
+        let (due, reminded) = compound_reminders(&positions, &prefs, &[]);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "a");
+        assert_eq!(reminded, vec!["a".to_string()]);
+
+        // 已提醒过不再提醒
+        let (due, reminded) = compound_reminders(&positions, &prefs, &reminded);
+        assert!(due.is_empty());
+        assert_eq!(reminded, vec!["a".to_string()]);
+
+        // 复投后回落到阈值以下，重置提醒
+        let positions = vec![position("a", 1.0)];
+        let (_, reminded) = compound_reminders(&positions, &prefs, &reminded);
+        assert!(reminded.is_empty());
+    }
+}
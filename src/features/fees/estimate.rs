@@ -0,0 +1,169 @@
+//! Platform Fee Estimate - 平台服务费估算（可降级）
+//!
+//! 兑换、购买稳定币、限价单三个标签页共用的服务费估算：
+//! - 价格服务不可用时使用最近一次价格换算美元价值，并标记为按过时价格估算
+//! - 价格或费率都拿不到时标记为“执行时确定”，由服务端在执行时计算
+//!
+//! 服务费只用于展示，任何一步失败都不应阻止下单。
+
+use crate::services::fee::FeeService;
+use crate::services::price::PriceService;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 服务费的计费基数
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeeBasis {
+    /// 已是美元金额（如法币入金）
+    Usd(f64),
+    /// 代币数量，按代币美元价格换算
+    Token { symbol: String, amount: f64 },
+}
+
+impl FeeBasis {
+    /// 金额为正且有效时才需要估算
+    fn is_billable(&self) -> bool {
+        let amount = match self {
+            FeeBasis::Usd(amount) => *amount,
+            FeeBasis::Token { amount, .. } => *amount,
+        };
+        amount.is_finite() && amount > 0.0
+    }
+}
+
+/// 平台服务费估算结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlatformFeeEstimate {
+    /// 客户端已算出服务费（美元）；`stale_price` 表示换算用的是最近一次缓存价格
+    Quoted { fee: f64, stale_price: bool },
+    /// 客户端无法计算，服务端在执行时计算
+    AtExecution,
+}
+
+impl PlatformFeeEstimate {
+    /// 已算出的服务费（美元）
+    pub fn fee(&self) -> Option<f64> {
+        match self {
+            PlatformFeeEstimate::Quoted { fee, .. } => Some(*fee),
+            PlatformFeeEstimate::AtExecution => None,
+        }
+    }
+
+    /// 是否由服务端在执行时确定
+    pub fn at_execution(&self) -> bool {
+        matches!(self, PlatformFeeEstimate::AtExecution)
+    }
+
+    /// 展示文本
+    pub fn label(&self) -> String {
+        match self {
+            PlatformFeeEstimate::Quoted {
+                fee,
+                stale_price: false,
+            } => format!("${:.2}", fee),
+            PlatformFeeEstimate::Quoted {
+                fee,
+                stale_price: true,
+            } => format!("≈ ${:.2}（价格可能已过时）", fee),
+            PlatformFeeEstimate::AtExecution => "执行时确定".to_string(),
+        }
+    }
+}
+
+/// 估算平台服务费；失败时降级而不是报错
+pub async fn estimate_platform_fee(
+    app_state: AppState,
+    chain: &str,
+    operation: &str,
+    basis: FeeBasis,
+) -> PlatformFeeEstimate {
+    let (usd_value, stale_price) = match basis {
+        FeeBasis::Usd(amount) => (amount, false),
+        FeeBasis::Token { symbol, amount } => {
+            match PriceService::new(app_state)
+                .get_price_or_last_known(&symbol)
+                .await
+            {
+                Ok(price) => (amount * price.usd, price.is_stale()),
+                Err(e) => {
+                    log::warn!("获取{}价格失败，服务费改为执行时确定: {}", symbol, e);
+                    return PlatformFeeEstimate::AtExecution;
+                }
+            }
+        }
+    };
+
+    match FeeService::new(app_state)
+        .calculate(chain, operation, usd_value)
+        .await
+    {
+        Ok(quote) => {
+            log::info!(
+                "平台服务费({}): ${:.2} (规则ID: {})",
+                operation,
+                quote.platform_fee,
+                quote.applied_rule_id
+            );
+            PlatformFeeEstimate::Quoted {
+                fee: quote.platform_fee,
+                stale_price,
+            }
+        }
+        Err(e) => {
+            log::warn!("计算平台服务费失败，改为执行时确定: {}", e);
+            PlatformFeeEstimate::AtExecution
+        }
+    }
+}
+
+/// 在后台估算服务费并写入 `target`；没有可计费金额时清空
+pub fn refresh_platform_fee(
+    app_state: AppState,
+    mut target: Signal<Option<PlatformFeeEstimate>>,
+    chain: String,
+    operation: &'static str,
+    basis: Option<FeeBasis>,
+) {
+    let Some(basis) = basis.filter(FeeBasis::is_billable) else {
+        target.set(None);
+        return;
+    };
+    spawn(async move {
+        let estimate = estimate_platform_fee(app_state, &chain, operation, basis).await;
+        target.set(Some(estimate));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_positive_amounts_are_billable() {
+        assert!(FeeBasis::Usd(10.0).is_billable());
+        assert!(!FeeBasis::Usd(0.0).is_billable());
+        assert!(!FeeBasis::Usd(f64::NAN).is_billable());
+        assert!(!FeeBasis::Token {
+            symbol: "ETH".to_string(),
+            amount: -1.0
+        }
+        .is_billable());
+    }
+
+    #[test]
+    fn labels_mark_stale_and_deferred_fees() {
+        let fresh = PlatformFeeEstimate::Quoted {
+            fee: 1.5,
+            stale_price: false,
+        };
+        let stale = PlatformFeeEstimate::Quoted {
+            fee: 1.5,
+            stale_price: true,
+        };
+        assert_eq!(fresh.label(), "$1.50");
+        assert!(stale.label().contains("过时"));
+        assert_eq!(PlatformFeeEstimate::AtExecution.label(), "执行时确定");
+        assert_eq!(PlatformFeeEstimate::AtExecution.fee(), None);
+        assert!(PlatformFeeEstimate::AtExecution.at_execution());
+    }
+}
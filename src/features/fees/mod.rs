@@ -1,2 +1,3 @@
+pub mod estimate;
 pub mod hooks;
 pub mod service;
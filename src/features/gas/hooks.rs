@@ -1,5 +1,8 @@
+use crate::features::gas::readiness::{self, GasReadiness};
 use crate::features::gas::service::GasService;
+use crate::services::balance::{native_symbol, BalanceService};
 use crate::services::gas::GasEstimateResponse;
+use crate::services::price::PriceService;
 use crate::shared::error::AppError;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
@@ -30,3 +33,113 @@ pub fn use_gas_estimate(chain: &str) -> Signal<Option<Result<GasEstimateResponse
 
     gas_data
 }
+
+/// 原生币手续费余量检查的轮询间隔
+const READINESS_POLL_MS: u32 = 60_000;
+
+/// 检查所选钱包在 `chain` 上的原生币能否支付一笔常规交易（`gas_units` 为 gas 用量）
+///
+/// 链或钱包切换时立即检查；之后定时轮询，gas 价格变化不明显时沿用上次的门槛
+pub fn use_gas_readiness(
+    chain: Memo<Option<String>>,
+    gas_units: u64,
+) -> Signal<Option<GasReadiness>> {
+    let app_state = use_context::<AppState>();
+    let mut readiness = use_signal(|| None::<GasReadiness>);
+    let wallet_id = use_memo(move || app_state.wallet.read().selected_wallet_id.clone());
+
+    use_effect(move || {
+        let requested = chain();
+        let _ = wallet_id();
+        readiness.set(None);
+        if let Some(requested) = requested {
+            spawn(async move {
+                let result = check_readiness(app_state, &requested, gas_units, None).await;
+                // 检查期间用户可能已切换链
+                if chain.peek().as_deref() == Some(requested.as_str()) {
+                    readiness.set(result);
+                }
+            });
+        }
+    });
+
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(READINESS_POLL_MS).await;
+            let Some(requested) = chain.peek().clone() else {
+                continue;
+            };
+            let previous = readiness.peek().clone();
+            let result = check_readiness(app_state, &requested, gas_units, previous.as_ref()).await;
+            if chain.peek().as_deref() == Some(requested.as_str()) && result != previous {
+                readiness.set(result);
+            }
+        }
+    });
+
+    readiness
+}
+
+/// 查询 gas 价格与原生币余额并计算是否足够；不支持的链或查询失败返回 None
+async fn check_readiness(
+    app_state: AppState,
+    chain: &str,
+    gas_units: u64,
+    previous: Option<&GasReadiness>,
+) -> Option<GasReadiness> {
+    if !readiness::supports_chain(chain) {
+        return None;
+    }
+    let account = app_state
+        .wallet
+        .peek()
+        .get_selected_wallet()
+        .and_then(|w| {
+            w.accounts
+                .iter()
+                .find(|a| a.chain.eq_ignore_ascii_case(chain))
+        })
+        .cloned()?;
+
+    let gwei = match GasService::new(app_state).estimate_all(chain).await {
+        Ok(all) => all.average.max_fee_per_gas_gwei,
+        Err(e) => {
+            log::warn!("手续费余量检查：获取 gas 价格失败: {:?}", e);
+            return None;
+        }
+    };
+    // gas 价格变化不明显时沿用上次的门槛，避免提示反复出现/消失
+    let gwei = match previous.filter(|p| p.chain == chain) {
+        Some(p) if !readiness::is_material_change(p.max_fee_per_gas_gwei, gwei) => {
+            p.max_fee_per_gas_gwei
+        }
+        _ => gwei,
+    };
+
+    let symbol = native_symbol(chain);
+    let total = match BalanceService::new(app_state)
+        .get_native_balance(&account)
+        .await
+    {
+        Ok(amount) => amount.to_f64_lossy(),
+        Err(_) => account.balance.parse::<f64>().unwrap_or(0.0),
+    };
+    let balance = app_state
+        .committed_balances
+        .peek()
+        .available_f64(symbol, total);
+    let native_price_usd = PriceService::new(app_state)
+        .get_price(symbol)
+        .await
+        .ok()
+        .map(|p| p.usd);
+
+    Some(GasReadiness {
+        chain: chain.to_string(),
+        symbol: symbol.to_string(),
+        balance,
+        required: readiness::required_native(gwei, gas_units),
+        max_fee_per_gas_gwei: gwei,
+        native_price_usd,
+    })
+}
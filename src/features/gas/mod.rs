@@ -1,2 +1,4 @@
 pub mod hooks;
+pub mod out_of_gas;
+pub mod readiness;
 pub mod service;
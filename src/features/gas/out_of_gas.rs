@@ -0,0 +1,76 @@
+//! Out of Gas - Gas 不足失败的事后检测
+//! EVM 交易失败后查询收据：状态为失败且已用 Gas 等于 Gas 上限，即判定为 Gas 耗尽，
+//! 由调用方提示用户以更高的上限重新构建交易（见 `gas_limit::retry_gas_limit`）
+
+use crate::services::gas_limit::retry_gas_limit;
+use crate::services::transaction::{TransactionService, TransactionStatus};
+use crate::shared::state::AppState;
+
+/// 等待交易结果的最长轮询次数
+const MAX_POLLS: u32 = 60;
+/// 轮询间隔（毫秒）
+const POLL_INTERVAL_MS: u32 = 5_000;
+
+/// 收据是否符合 Gas 耗尽的特征（失败且已用 Gas 达到上限）
+pub fn is_out_of_gas(status: &TransactionStatus) -> bool {
+    status.status == "failed"
+        && matches!(
+            (status.gas_used, status.gas_limit),
+            (Some(used), Some(limit)) if limit > 0 && used >= limit
+        )
+}
+
+/// 等待交易上链；若因 Gas 不足失败，返回重试应使用的 Gas 上限
+pub async fn detect(app_state: AppState, chain: &str, chain_id: u64, tx_hash: &str) -> Option<u64> {
+    let status = TransactionService::new(app_state)
+        .wait_for_confirmation(tx_hash, chain, MAX_POLLS, POLL_INTERVAL_MS)
+        .await
+        .map_err(|e| log::warn!("查询交易结果失败: tx_hash={}, {}", tx_hash, e))
+        .ok()?;
+    if !is_out_of_gas(&status) {
+        return None;
+    }
+    let limit = status.gas_limit?;
+    log::warn!(
+        "交易因 Gas 不足失败: tx_hash={}, gas_used={:?}, gas_limit={}",
+        tx_hash,
+        status.gas_used,
+        limit
+    );
+    Some(retry_gas_limit(limit, chain_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(status: &str, gas_used: Option<u64>, gas_limit: Option<u64>) -> TransactionStatus {
+        TransactionStatus {
+            tx_hash: "0xabc".to_string(),
+            status: status.to_string(),
+            confirmations: 1,
+            last_seen: None,
+            block_number: Some(100),
+            gas_used,
+            gas_limit,
+        }
+    }
+
+    #[test]
+    fn detects_failed_receipt_that_used_whole_limit() {
+        assert!(is_out_of_gas(&status("failed", Some(65_000), Some(65_000))));
+        // 其他原因回滚（未用尽 Gas）
+        assert!(!is_out_of_gas(&status(
+            "failed",
+            Some(40_000),
+            Some(65_000)
+        )));
+        assert!(!is_out_of_gas(&status(
+            "confirmed",
+            Some(65_000),
+            Some(65_000)
+        )));
+        // 后端未返回收据字段时不做判断
+        assert!(!is_out_of_gas(&status("failed", None, Some(65_000))));
+    }
+}
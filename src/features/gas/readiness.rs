@@ -0,0 +1,153 @@
+//! Gas Readiness - 原生币手续费余量检查
+//! 选中钱包/链后检查原生币余额能否支付一笔常规交易的手续费，
+//! 不足时在兑换/发送页提示用户先补充原生币（而不是等到广播失败）
+
+use crate::services::gas::gas_fee_eth_from_max_fee_per_gas_gwei;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const PENDING_BUY_KEY: &str = "gas_readiness_pending_buy";
+
+/// 稳定币代付手续费（中继）功能开关
+pub const RELAYER_FLAG: &str = "gas_stablecoin_relayer";
+
+/// 常规交易的 gas 用量：ERC-20 转账约 65k，DEX 兑换约 200k
+pub const TRANSFER_GAS_UNITS: u64 = 65_000;
+pub const SWAP_GAS_UNITS: u64 = 200_000;
+
+/// 门槛安全系数：预留 gas 价格短时上涨的余量
+pub const SAFETY_MARGIN: f64 = 1.5;
+
+/// gas 价格变化超过该比例才重新计算门槛（避免每次轮询都刷新提示）
+pub const MATERIAL_CHANGE_RATIO: f64 = 0.2;
+
+/// 建议购买的最小法币金额（充值渠道最低 $10）
+pub const MIN_BUY_USD: f64 = 10.0;
+
+/// 支持检查的链：gas 价格按 EIP-1559 估算，且余额接口能查询原生币
+/// （BSC/Polygon 的余额接口尚未区分原生币，暂不检查）
+pub fn supports_chain(chain: &str) -> bool {
+    matches!(chain.to_lowercase().as_str(), "ethereum" | "eth")
+}
+
+/// 以当前 max fee 计算一笔交易所需的原生币（含安全系数）
+pub fn required_native(max_fee_per_gas_gwei: f64, gas_units: u64) -> f64 {
+    gas_fee_eth_from_max_fee_per_gas_gwei(max_fee_per_gas_gwei, gas_units) * SAFETY_MARGIN
+}
+
+/// gas 价格相对上次计算时是否发生明显变化
+pub fn is_material_change(previous_gwei: f64, current_gwei: f64) -> bool {
+    if previous_gwei <= 0.0 {
+        return current_gwei > 0.0;
+    }
+    ((current_gwei - previous_gwei) / previous_gwei).abs() >= MATERIAL_CHANGE_RATIO
+}
+
+/// 检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasReadiness {
+    pub chain: String,
+    pub symbol: String,
+    /// 可用原生币余额（已扣除挂单/处理中占用）
+    pub balance: f64,
+    /// 一笔常规交易所需原生币
+    pub required: f64,
+    /// 计算门槛时使用的 gas 价格
+    pub max_fee_per_gas_gwei: f64,
+    /// 原生币价格（用于折算建议购买金额）
+    pub native_price_usd: Option<f64>,
+}
+
+impl GasReadiness {
+    pub fn is_ready(&self) -> bool {
+        self.balance >= self.required
+    }
+
+    /// 还差多少原生币
+    pub fn shortfall(&self) -> f64 {
+        (self.required - self.balance).max(0.0)
+    }
+
+    /// 建议购买的法币金额：缺口按价格折算后取整，且不低于充值最低额
+    pub fn suggested_buy_usd(&self) -> f64 {
+        let needed = self
+            .native_price_usd
+            .filter(|p| p.is_finite() && *p > 0.0)
+            .map(|p| ((self.shortfall() * p * 100.0).round() / 100.0).ceil())
+            .unwrap_or(0.0);
+        needed.max(MIN_BUY_USD)
+    }
+}
+
+/// 跳转到购买标签页时预填的参数
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuyPrefill {
+    pub token: String,
+    pub amount_usd: String,
+}
+
+/// 记录跳转到兑换页后需要打开的购买预填
+pub fn set_pending_buy(prefill: &BuyPrefill) {
+    let _ = LocalStorage::set(PENDING_BUY_KEY, prefill);
+}
+
+/// 取出待预填的购买参数（只生效一次）
+pub fn take_pending_buy() -> Option<BuyPrefill> {
+    let prefill = LocalStorage::get::<BuyPrefill>(PENDING_BUY_KEY).ok();
+    LocalStorage::delete(PENDING_BUY_KEY);
+    prefill
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn readiness(balance: f64, required: f64, price: Option<f64>) -> GasReadiness {
+        GasReadiness {
+            chain: "ethereum".to_string(),
+            symbol: "ETH".to_string(),
+            balance,
+            required,
+            max_fee_per_gas_gwei: 20.0,
+            native_price_usd: price,
+        }
+    }
+
+    #[test]
+    fn required_native_applies_margin() {
+        // 20 gwei * 200k gas = 0.004 ETH，安全系数 1.5 → 0.006 ETH
+        let required = required_native(20.0, SWAP_GAS_UNITS);
+        assert!((required - 0.006).abs() < 1e-12);
+        assert_eq!(required_native(f64::NAN, SWAP_GAS_UNITS), 0.0);
+        assert!(supports_chain("Ethereum"));
+        assert!(!supports_chain("solana"));
+        assert!(!supports_chain("bsc"));
+    }
+
+    #[test]
+    fn material_change_uses_relative_threshold() {
+        assert!(!is_material_change(20.0, 23.0));
+        assert!(is_material_change(20.0, 24.0));
+        assert!(is_material_change(20.0, 15.0));
+        assert!(is_material_change(0.0, 1.0));
+    }
+
+    #[test]
+    fn shortfall_and_suggested_buy() {
+        let r = readiness(0.001, 0.006, Some(3000.0));
+        assert!(!r.is_ready());
+        assert!((r.shortfall() - 0.005).abs() < 1e-12);
+        // 0.005 ETH * $3000 = $15
+        assert_eq!(r.suggested_buy_usd(), 15.0);
+        // 缺口很小或无价格时按最低额购买
+        assert_eq!(
+            readiness(0.001, 0.006, Some(100.0)).suggested_buy_usd(),
+            MIN_BUY_USD
+        );
+        assert_eq!(
+            readiness(0.001, 0.006, None).suggested_buy_usd(),
+            MIN_BUY_USD
+        );
+        assert!(readiness(0.01, 0.006, None).is_ready());
+    }
+}
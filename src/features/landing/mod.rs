@@ -1,2 +1,4 @@
 // Landing feature module
 // Landing feature module - Production-ready implementation
+
+pub mod stats;
@@ -0,0 +1,329 @@
+//! Landing Stats - 首页实时数据
+//! 支持的链与代币、24 小时兑换量、各地区支付方式与示例汇率；无需登录即可获取，
+//! 结果缓存在本地存储中（过期后后台刷新），接口不可用时依次使用过期缓存与静态内容
+
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainConfigManager;
+use crate::services::country_support::{BUY_METHODS, REGIONAL_METHODS};
+use crate::services::price::PriceService;
+use crate::services::token::TokenService;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use gloo_storage::{LocalStorage, Storage};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+const STATS_KEY: &str = "landing_stats_cache";
+const ASSETS_KEY: &str = "landing_assets_cache";
+const RATES_KEY: &str = "landing_rates_cache";
+/// 聚合数据缓存 10 分钟
+const STATS_TTL_MS: f64 = 10.0 * 60.0 * 1000.0;
+/// 支持的资产变化很少，缓存一天
+const ASSETS_TTL_MS: f64 = 24.0 * 3600.0 * 1000.0;
+/// 示例汇率缓存 5 分钟（与价格服务一致）
+const RATES_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+/// 每条链最多展示的代币数
+const MAX_TOKENS_PER_CHAIN: usize = 6;
+
+/// 首页展示的链（按展示顺序）
+pub const LANDING_CHAINS: [ChainType; 6] = [
+    ChainType::Bitcoin,
+    ChainType::Ethereum,
+    ChainType::BSC,
+    ChainType::Polygon,
+    ChainType::Solana,
+    ChainType::TON,
+];
+
+/// 示例汇率使用的币种
+pub const RATE_SYMBOLS: [&str; 4] = ["BTC", "ETH", "SOL", "TON"];
+
+/// 某地区可用的支付方式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionMethods {
+    /// 地区名称（"全球" 或国家/地区名）
+    pub region: String,
+    pub methods: Vec<String>,
+}
+
+/// 公开统计接口返回的聚合数据
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicStats {
+    #[serde(default)]
+    pub swap_volume_24h_usd: Option<f64>,
+    #[serde(default, alias = "payment_methods")]
+    pub payment_methods_by_region: Vec<RegionMethods>,
+}
+
+impl PublicStats {
+    /// 静态内容：不含兑换量，支付方式取内置矩阵
+    pub fn fallback() -> Self {
+        let regional: Vec<&str> = REGIONAL_METHODS.iter().map(|(m, _)| *m).collect();
+        let mut regions = vec![RegionMethods {
+            region: "全球".to_string(),
+            methods: BUY_METHODS
+                .iter()
+                .filter(|m| !regional.contains(m))
+                .map(|m| m.to_string())
+                .collect(),
+        }];
+        for (method, countries) in REGIONAL_METHODS {
+            for country in countries {
+                let region = country_region_name(country).to_string();
+                match regions.iter_mut().find(|r| r.region == region) {
+                    Some(r) => r.methods.push(method.to_string()),
+                    None => regions.push(RegionMethods {
+                        region,
+                        methods: vec![method.to_string()],
+                    }),
+                }
+            }
+        }
+        Self {
+            swap_volume_24h_usd: None,
+            payment_methods_by_region: regions,
+        }
+    }
+}
+
+/// 某条链支持的代币
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChainAssets {
+    pub chain: ChainType,
+    pub tokens: Vec<String>,
+}
+
+/// 示例汇率（1 单位币种 ≈ 美元）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExampleRate {
+    pub symbol: String,
+    pub usd: f64,
+}
+
+/// 数据来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsSource {
+    /// 接口或未过期缓存
+    Live,
+    /// 接口不可用，使用过期缓存
+    Stale,
+    /// 接口不可用且没有缓存，使用静态内容
+    Static,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Cached<T> {
+    value: T,
+    at: f64,
+}
+
+fn is_fresh(at: f64, now: f64, ttl_ms: f64) -> bool {
+    now >= at && now - at < ttl_ms
+}
+
+fn read_cache<T: DeserializeOwned>(key: &str) -> Option<Cached<T>> {
+    LocalStorage::get::<Cached<T>>(key).ok()
+}
+
+fn write_cache<T: Serialize>(key: &str, value: &T) {
+    let _ = LocalStorage::set(
+        key,
+        serde_json::json!({ "value": value, "at": time::now_ms() }),
+    );
+}
+
+/// 先用缓存，过期时请求；请求失败时退回过期缓存，都没有时返回 None
+async fn cached_or_fetch<T, F>(key: &str, ttl_ms: f64, fetch: F) -> Option<(T, StatsSource)>
+where
+    T: Serialize + DeserializeOwned,
+    F: std::future::Future<Output = Option<T>>,
+{
+    let cached = read_cache::<T>(key);
+    if let Some(entry) = &cached {
+        if is_fresh(entry.at, time::now_ms(), ttl_ms) {
+            return cached.map(|c| (c.value, StatsSource::Live));
+        }
+    }
+    match fetch.await {
+        Some(value) => {
+            write_cache(key, &value);
+            Some((value, StatsSource::Live))
+        }
+        None => cached.map(|c| (c.value, StatsSource::Stale)),
+    }
+}
+
+/// 聚合统计（公开接口，无需登录）
+pub async fn load_public_stats(app_state: AppState) -> (PublicStats, StatsSource) {
+    let fetch = async move {
+        app_state
+            .get_api_client()
+            .get::<PublicStats>("/api/v1/public/stats")
+            .await
+            .map_err(|e| log::warn!("首页统计获取失败: {}", e))
+            .ok()
+    };
+    cached_or_fetch(STATS_KEY, STATS_TTL_MS, fetch)
+        .await
+        .unwrap_or_else(|| (PublicStats::fallback(), StatsSource::Static))
+}
+
+/// 支持的链与代币（链来自链配置，代币来自代币服务；代币服务失败时自带默认列表）
+pub async fn load_supported_assets(app_state: AppState) -> Vec<ChainAssets> {
+    let fetch = async move {
+        let registry = ChainConfigManager::new();
+        let service = TokenService::new(app_state);
+        let mut assets = Vec::new();
+        for chain in LANDING_CHAINS {
+            if registry.get_config(chain).is_err() {
+                continue;
+            }
+            let tokens = service.get_token_list(chain).await.unwrap_or_default();
+            assets.push(ChainAssets {
+                chain,
+                tokens: token_symbols(chain, tokens.iter().map(|t| (t.symbol.as_str(), t.is_spam))),
+            });
+        }
+        Some(assets)
+    };
+    cached_or_fetch(ASSETS_KEY, ASSETS_TTL_MS, fetch)
+        .await
+        .map(|(assets, _)| assets)
+        .unwrap_or_default()
+}
+
+/// 示例汇率（价格不可用时为空）
+pub async fn load_example_rates(app_state: AppState) -> Vec<ExampleRate> {
+    let fetch = async move {
+        let prices = PriceService::new(app_state)
+            .get_prices(&RATE_SYMBOLS)
+            .await
+            .map_err(|e| log::warn!("首页示例汇率获取失败: {:?}", e))
+            .ok()?;
+        let rates: Vec<ExampleRate> = RATE_SYMBOLS
+            .iter()
+            .filter_map(|s| {
+                prices.get(*s).filter(|p| p.usd > 0.0).map(|p| ExampleRate {
+                    symbol: s.to_string(),
+                    usd: p.usd,
+                })
+            })
+            .collect();
+        (!rates.is_empty()).then_some(rates)
+    };
+    cached_or_fetch(RATES_KEY, RATES_TTL_MS, fetch)
+        .await
+        .map(|(rates, _)| rates)
+        .unwrap_or_default()
+}
+
+/// 展示用代币符号：原生币在前，去重并排除疑似垃圾代币
+pub fn token_symbols<'a>(
+    chain: ChainType,
+    tokens: impl IntoIterator<Item = (&'a str, bool)>,
+) -> Vec<String> {
+    let mut symbols = vec![chain.native_token_symbol().to_string()];
+    for (symbol, is_spam) in tokens {
+        let symbol = symbol.trim().to_uppercase();
+        if is_spam || symbol.is_empty() || symbols.contains(&symbol) {
+            continue;
+        }
+        symbols.push(symbol);
+    }
+    symbols.truncate(MAX_TOKENS_PER_CHAIN);
+    symbols
+}
+
+/// 紧凑美元金额（$1.23B / $45.6M / $7.8K）
+pub fn format_usd_compact(value: f64) -> String {
+    let value = value.max(0.0);
+    if value >= 1e9 {
+        format!("${:.2}B", value / 1e9)
+    } else if value >= 1e6 {
+        format!("${:.1}M", value / 1e6)
+    } else if value >= 1e3 {
+        format!("${:.1}K", value / 1e3)
+    } else {
+        format!("${:.0}", value)
+    }
+}
+
+/// 示例汇率文案（大额取整，小额保留更多小数）
+pub fn format_rate(rate: &ExampleRate) -> String {
+    let usd = if rate.usd >= 100.0 {
+        format!("{:.0}", rate.usd)
+    } else {
+        format!("{:.2}", rate.usd)
+    };
+    format!("1 {} ≈ ${}", rate.symbol, usd)
+}
+
+/// 支付方式显示名称
+pub fn method_label(method: &str) -> &str {
+    match method {
+        "credit_card" => "银行卡",
+        "bank_card" => "银行卡",
+        "paypal" => "PayPal",
+        "apple_pay" => "Apple Pay",
+        "google_pay" => "Google Pay",
+        "alipay" => "支付宝",
+        "wechat_pay" => "微信支付",
+        other => other,
+    }
+}
+
+fn country_region_name(country_code: &str) -> &str {
+    match country_code {
+        "CN" => "中国大陆",
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_separates_regional_methods() {
+        let stats = PublicStats::fallback();
+        assert_eq!(stats.swap_volume_24h_usd, None);
+        let global = &stats.payment_methods_by_region[0];
+        assert_eq!(global.region, "全球");
+        assert!(global.methods.contains(&"credit_card".to_string()));
+        assert!(!global.methods.contains(&"alipay".to_string()));
+        let cn = stats
+            .payment_methods_by_region
+            .iter()
+            .find(|r| r.region == "中国大陆")
+            .unwrap();
+        assert_eq!(cn.methods, vec!["alipay", "wechat_pay"]);
+    }
+
+    #[test]
+    fn symbols_put_native_first_and_skip_spam() {
+        let symbols = token_symbols(
+            ChainType::BSC,
+            [
+                ("usdt", false),
+                ("BNB", false),
+                ("FREE", true),
+                ("USDC", false),
+            ],
+        );
+        assert_eq!(symbols, vec!["BNB", "USDT", "USDC"]);
+    }
+
+    #[test]
+    fn compact_usd_and_rates() {
+        assert_eq!(format_usd_compact(1_234_000_000.0), "$1.23B");
+        assert_eq!(format_usd_compact(45_600_000.0), "$45.6M");
+        assert_eq!(format_usd_compact(999.0), "$999");
+        let rate = ExampleRate {
+            symbol: "ETH".to_string(),
+            usd: 3456.78,
+        };
+        assert_eq!(format_rate(&rate), "1 ETH ≈ $3457");
+        assert!(is_fresh(1_000.0, 1_500.0, 1_000.0));
+        assert!(!is_fresh(1_000.0, 2_500.0, 1_000.0));
+    }
+}
@@ -0,0 +1,73 @@
+//! Market Hooks - 订阅共享的价格与 gas 价格
+//!
+//! 组件挂载时登记需要的数据，卸载时取消；数据由后台轮询任务统一更新，
+//! 同一代币或同一条链无论有多少组件订阅都只查询一次。
+
+use super::poller;
+use super::store::{Feed, GasQuote};
+use crate::services::price::CoinPrice;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 订阅 `keys`（变化时先取消旧订阅），有新数据需要查询时唤醒轮询任务
+fn use_subscription(feed: Feed, keys: Memo<Vec<String>>) {
+    let app_state = use_context::<AppState>();
+    let mut current = use_signal(Vec::<String>::new);
+
+    use_effect(move || {
+        let next = keys();
+        let mut market = app_state.market;
+        let mut missing = false;
+        {
+            let mut market = market.write();
+            for key in current.peek().iter() {
+                market.unsubscribe(feed, key);
+            }
+            for key in &next {
+                missing |= market.subscribe(feed, key);
+            }
+        }
+        current.set(next);
+        if missing {
+            poller::wake();
+        }
+    });
+
+    use_drop(move || {
+        let mut market = app_state.market;
+        let mut market = market.write();
+        for key in current.peek().iter() {
+            market.unsubscribe(feed, key);
+        }
+    });
+}
+
+/// 单个代币的价格（`last_updated` 为价格时间，可用于过期提示）；尚无数据时为 None
+pub fn use_price(symbol: Memo<Option<String>>) -> Memo<Option<CoinPrice>> {
+    let app_state = use_context::<AppState>();
+    let keys = use_memo(move || symbol().into_iter().collect::<Vec<_>>());
+    use_subscription(Feed::Price, keys);
+    use_memo(move || symbol().and_then(|s| app_state.market.read().price(&s)))
+}
+
+/// 多个代币的价格（键为大写符号，尚无数据的代币不包含在内）
+pub fn use_prices(symbols: Memo<Vec<String>>) -> Memo<HashMap<String, CoinPrice>> {
+    let app_state = use_context::<AppState>();
+    use_subscription(Feed::Price, symbols);
+    use_memo(move || app_state.market.read().prices(&symbols()))
+}
+
+/// 某条链的 gas 价格（`fetched_at` 为查询时间）；尚无数据时为 None
+pub fn use_gas(chain: Memo<Option<String>>) -> Memo<Option<GasQuote>> {
+    let app_state = use_context::<AppState>();
+    let keys = use_memo(move || chain().into_iter().collect::<Vec<_>>());
+    use_subscription(Feed::Gas, keys);
+    use_memo(move || chain().and_then(|c| app_state.market.read().gas(&c)))
+}
+
+/// 某条链的 gas 价格是否查询失败且没有旧数据
+pub fn use_gas_unavailable(chain: Memo<Option<String>>) -> Memo<bool> {
+    let app_state = use_context::<AppState>();
+    use_memo(move || chain().is_some_and(|c| app_state.market.read().gas_unavailable(&c)))
+}
@@ -0,0 +1,4 @@
+// Market feature module - 共享的价格与 gas 价格（一个后台任务统一轮询）
+pub mod hooks;
+pub mod poller;
+pub mod store;
@@ -0,0 +1,162 @@
+//! Market Poller - 价格与 gas 价格的统一后台轮询
+//!
+//! 应用启动时运行一个轮询任务：每轮把所有被订阅的代币合并为一次批量价格请求，
+//! 每条被订阅的链查询一次 gas 价格，结果写入 [`MarketData`](super::store::MarketData)。
+//! - 标签页隐藏时放慢（见 [`scheduler`]），切回前台立即补跑一次
+//! - 出现新的订阅时提前唤醒，只查询还没有数据的部分
+//! - 被限流时轮询间隔逐次翻倍（见 [`poll_interval_ms`]），恢复后回到正常间隔
+
+use super::store::{poll_interval_ms, Feed, POLL_INTERVAL_MS};
+use crate::services::gas::GasService;
+use crate::services::price::{CoinPrice, PriceService, PRICE_CACHE_PREFIX};
+use crate::shared::scheduler::{self, TickPolicy};
+use crate::shared::state::AppState;
+use crate::shared::ui_error::{self, ErrorClass};
+use dioxus::prelude::*;
+use futures::channel::oneshot;
+use futures::future::{select, Either};
+use gloo_timers::future::TimeoutFuture;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// 被唤醒后等待的时间，同一次渲染中的多个新订阅合并为一次请求
+const BATCH_WINDOW_MS: u32 = 50;
+
+thread_local! {
+    /// 唤醒等待中的轮询任务
+    static WAKE: RefCell<Option<oneshot::Sender<()>>> = const { RefCell::new(None) };
+    /// 轮询进行中收到的唤醒（本轮结束后立即处理）
+    static WOKEN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// 出现新订阅时唤醒轮询任务
+pub fn wake() {
+    match WAKE.with(|w| w.borrow_mut().take()) {
+        Some(waker) => {
+            let _ = waker.send(());
+        }
+        None => WOKEN.with(|w| w.set(true)),
+    }
+}
+
+/// 等待下一轮：返回是否因新订阅被提前唤醒
+async fn wait(interval_ms: u32) -> bool {
+    if WOKEN.with(|w| w.replace(false)) {
+        return true;
+    }
+    let (tx, rx) = oneshot::channel();
+    WAKE.with(|w| *w.borrow_mut() = Some(tx));
+    let tick = Box::pin(scheduler::next_tick(
+        TickPolicy::SlowWhenHidden,
+        interval_ms,
+    ));
+    let woken = matches!(select(tick, rx).await, Either::Right((Ok(()), _)));
+    WAKE.with(|w| w.borrow_mut().take());
+    woken
+}
+
+/// 轮询任务（应用根组件启动一次）
+pub async fn run(app_state: AppState) {
+    let mut rate_limited_streak = 0;
+    let mut max_age_secs = 0;
+    loop {
+        if poll(app_state, max_age_secs).await {
+            rate_limited_streak += 1;
+            log::warn!("行情轮询被限流，放慢轮询（第 {} 次）", rate_limited_streak);
+        } else {
+            rate_limited_streak = 0;
+        }
+
+        let interval = poll_interval_ms(rate_limited_streak);
+        max_age_secs = if wait(interval).await {
+            // 新订阅：只查询还没有数据（或已超过轮询间隔）的部分
+            TimeoutFuture::new(BATCH_WINDOW_MS).await;
+            u64::from(interval / 1000)
+        } else {
+            // 定时轮询：刷新全部订阅（刚被其他调用方查询过的除外）
+            u64::from(POLL_INTERVAL_MS / 2000)
+        };
+    }
+}
+
+/// 查询被订阅且超过 `max_age_secs` 未更新的数据，返回是否被限流
+async fn poll(app_state: AppState, max_age_secs: u64) -> bool {
+    let now = crate::shared::time::now_unix();
+    let (symbols, chains) = {
+        let market = app_state.market.peek();
+        let symbols = market.subscribed(Feed::Price);
+        let chains = market.subscribed(Feed::Gas);
+        (
+            market.due(Feed::Price, &symbols, now, max_age_secs),
+            market.due(Feed::Gas, &chains, now, max_age_secs),
+        )
+    };
+
+    let mut rate_limited = false;
+    if !symbols.is_empty() {
+        if let Err(e) = fetch_prices(app_state, &symbols).await {
+            rate_limited |= ui_error::classify(&e) == ErrorClass::RateLimited;
+        }
+    }
+    for chain in chains {
+        match GasService::new(app_state).estimate_all(&chain).await {
+            Ok(estimates) => {
+                let mut market = app_state.market;
+                market.write().record_gas(&chain, estimates, now);
+            }
+            Err(e) => {
+                let message = e.to_string();
+                log::warn!("行情轮询：获取 {} gas 价格失败: {}", chain, message);
+                let mut market = app_state.market;
+                market.write().record_gas_failure(&chain);
+                rate_limited |= ui_error::classify(&message) == ErrorClass::RateLimited;
+            }
+        }
+    }
+    rate_limited
+}
+
+/// 一次批量请求查询多个代币价格并写入共享数据（服务不可用时退回最近一次价格）
+async fn fetch_prices(app_state: AppState, symbols: &[String]) -> Result<(), String> {
+    // 共享数据是价格的唯一来源，跳过价格服务的批量缓存
+    let mut cache = app_state.cache;
+    cache
+        .write()
+        .retain(|key, _| !key.starts_with(PRICE_CACHE_PREFIX));
+
+    let refs: Vec<&str> = symbols.iter().map(String::as_str).collect();
+    match PriceService::new(app_state)
+        .get_prices_or_last_known(&refs)
+        .await
+    {
+        Ok(prices) => {
+            let mut market = app_state.market;
+            market
+                .write()
+                .record_prices(prices, crate::shared::time::now_unix());
+            Ok(())
+        }
+        Err(e) => {
+            log::warn!("行情轮询：获取价格失败: {}", e);
+            Err(e.to_string())
+        }
+    }
+}
+
+/// 需要立即使用价格的调用方（如仪表盘加载）：共享数据在 `max_age_secs` 内更新过的直接使用，
+/// 其余合并为一次请求；返回已有价格的代币（全部失败时为空表）
+pub async fn ensure_prices(
+    app_state: AppState,
+    symbols: &[String],
+    max_age_secs: u64,
+) -> HashMap<String, CoinPrice> {
+    let now = crate::shared::time::now_unix();
+    let due = app_state
+        .market
+        .peek()
+        .due(Feed::Price, symbols, now, max_age_secs);
+    if !due.is_empty() {
+        let _ = fetch_prices(app_state, &due).await;
+    }
+    app_state.market.peek().prices(symbols)
+}
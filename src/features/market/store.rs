@@ -0,0 +1,232 @@
+//! Market Store - 共享的价格与 gas 价格数据
+//!
+//! 组件通过订阅声明需要哪些代币价格、哪些链的 gas 价格（引用计数，卸载时取消），
+//! 后台轮询任务只查询被订阅的数据，并把结果写回这里供所有组件读取。
+
+use crate::services::gas::GasEstimateResponse;
+use crate::services::price::CoinPrice;
+use std::collections::{HashMap, HashSet};
+
+/// 正常轮询间隔（毫秒）
+pub const POLL_INTERVAL_MS: u32 = 30_000;
+/// 连续被限流时轮询间隔的上限（毫秒）
+pub const MAX_POLL_INTERVAL_MS: u32 = 240_000;
+
+/// 订阅的数据类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feed {
+    /// 代币价格（键为大写符号）
+    Price,
+    /// gas 价格（键为小写链名）
+    Gas,
+}
+
+impl Feed {
+    fn normalize(self, key: &str) -> String {
+        match self {
+            Feed::Price => key.trim().to_uppercase(),
+            Feed::Gas => key.trim().to_lowercase(),
+        }
+    }
+}
+
+/// 某条链的 gas 价格及查询时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasQuote {
+    pub estimates: GasEstimateResponse,
+    /// 查询时间（Unix 秒），供数据新鲜度提示使用
+    pub fetched_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PriceSlot {
+    price: CoinPrice,
+    /// 最近一次查询时间（价格服务降级时 `price.last_updated` 可能更早）
+    checked_at: u64,
+}
+
+/// 共享的价格与 gas 价格（全局状态 `AppState::market`）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketData {
+    prices: HashMap<String, PriceSlot>,
+    gas: HashMap<String, GasQuote>,
+    /// 最近一次查询失败且没有旧数据的链
+    gas_failed: HashSet<String>,
+    subscribers: HashMap<(Feed, String), usize>,
+}
+
+impl MarketData {
+    /// 增加订阅，返回是否需要立即查询（此前没有该数据）
+    pub fn subscribe(&mut self, feed: Feed, key: &str) -> bool {
+        let key = feed.normalize(key);
+        let missing = match feed {
+            Feed::Price => !self.prices.contains_key(&key),
+            Feed::Gas => !self.gas.contains_key(&key),
+        };
+        *self.subscribers.entry((feed, key)).or_insert(0) += 1;
+        missing
+    }
+
+    /// 取消订阅（已查询的数据保留，重新订阅时先显示旧值）
+    pub fn unsubscribe(&mut self, feed: Feed, key: &str) {
+        let id = (feed, feed.normalize(key));
+        if let Some(count) = self.subscribers.get_mut(&id) {
+            *count -= 1;
+            if *count == 0 {
+                self.subscribers.remove(&id);
+            }
+        }
+    }
+
+    /// 被订阅的键（排序后返回，批量请求的参数稳定）
+    pub fn subscribed(&self, feed: Feed) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .subscribers
+            .keys()
+            .filter(|(f, _)| *f == feed)
+            .map(|(_, key)| key.clone())
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// `keys` 中没有数据或查询时间早于 `max_age_secs` 的键
+    pub fn due(&self, feed: Feed, keys: &[String], now: u64, max_age_secs: u64) -> Vec<String> {
+        keys.iter()
+            .map(|key| feed.normalize(key))
+            .filter(|key| {
+                let checked_at = match feed {
+                    Feed::Price => self.prices.get(key).map(|p| p.checked_at),
+                    Feed::Gas => self.gas.get(key).map(|g| g.fetched_at),
+                };
+                checked_at.is_none_or(|at| now.saturating_sub(at) >= max_age_secs)
+            })
+            .collect()
+    }
+
+    pub fn price(&self, symbol: &str) -> Option<CoinPrice> {
+        self.prices
+            .get(&Feed::Price.normalize(symbol))
+            .map(|p| p.price.clone())
+    }
+
+    /// 多个代币的价格（没有数据的代币不包含在结果中）
+    pub fn prices(&self, symbols: &[String]) -> HashMap<String, CoinPrice> {
+        symbols
+            .iter()
+            .filter_map(|s| {
+                let symbol = Feed::Price.normalize(s);
+                let price = self.price(&symbol)?;
+                Some((symbol, price))
+            })
+            .collect()
+    }
+
+    pub fn gas(&self, chain: &str) -> Option<GasQuote> {
+        self.gas.get(&Feed::Gas.normalize(chain)).cloned()
+    }
+
+    pub fn record_prices(&mut self, prices: HashMap<String, CoinPrice>, now: u64) {
+        for (symbol, price) in prices {
+            let slot = PriceSlot {
+                price,
+                checked_at: now,
+            };
+            self.prices.insert(Feed::Price.normalize(&symbol), slot);
+        }
+    }
+
+    pub fn record_gas(&mut self, chain: &str, estimates: GasEstimateResponse, now: u64) {
+        let chain = Feed::Gas.normalize(chain);
+        let quote = GasQuote {
+            estimates,
+            fetched_at: now,
+        };
+        self.gas_failed.remove(&chain);
+        self.gas.insert(chain, quote);
+    }
+
+    pub fn record_gas_failure(&mut self, chain: &str) {
+        let chain = Feed::Gas.normalize(chain);
+        if !self.gas.contains_key(&chain) {
+            self.gas_failed.insert(chain);
+        }
+    }
+
+    /// 该链的 gas 价格查询失败且没有可用的旧数据（界面停止显示加载状态）
+    pub fn gas_unavailable(&self, chain: &str) -> bool {
+        self.gas_failed.contains(&Feed::Gas.normalize(chain))
+    }
+
+    /// 标记所有价格需要重新查询（强制刷新时调用，旧值继续显示到新数据返回）
+    pub fn expire_prices(&mut self) {
+        for slot in self.prices.values_mut() {
+            slot.checked_at = 0;
+        }
+    }
+}
+
+/// 轮询间隔：连续被限流时逐次翻倍，直到 [`MAX_POLL_INTERVAL_MS`]
+pub fn poll_interval_ms(rate_limited_streak: u32) -> u32 {
+    POLL_INTERVAL_MS
+        .saturating_mul(1 << rate_limited_streak.min(8))
+        .min(MAX_POLL_INTERVAL_MS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(symbol: &str, usd: f64) -> CoinPrice {
+        CoinPrice {
+            symbol: symbol.to_string(),
+            usd,
+            usd_24h_change: 0.0,
+            last_updated: 0,
+            market_cap: None,
+        }
+    }
+
+    #[test]
+    fn subscriptions_are_reference_counted_and_normalized() {
+        let mut market = MarketData::default();
+        assert!(market.subscribe(Feed::Price, "eth"));
+        assert!(market.subscribe(Feed::Price, "ETH"));
+        assert!(market.subscribe(Feed::Gas, "Ethereum"));
+        assert_eq!(market.subscribed(Feed::Price), ["ETH"]);
+        assert_eq!(market.subscribed(Feed::Gas), ["ethereum"]);
+        market.record_gas_failure("ethereum");
+        assert!(market.gas_unavailable("Ethereum"));
+
+        market.unsubscribe(Feed::Price, "ETH");
+        assert_eq!(market.subscribed(Feed::Price), ["ETH"]);
+        market.unsubscribe(Feed::Price, "eth");
+        assert!(market.subscribed(Feed::Price).is_empty());
+
+        // 已有数据时订阅不需要立即查询
+        market.record_prices(HashMap::from([("BTC".to_string(), price("BTC", 1.0))]), 100);
+        assert!(!market.subscribe(Feed::Price, "btc"));
+        assert_eq!(market.price("btc").map(|p| p.usd), Some(1.0));
+    }
+
+    #[test]
+    fn due_keys_follow_age_and_expiry() {
+        let mut market = MarketData::default();
+        market.record_prices(HashMap::from([("ETH".to_string(), price("ETH", 2.0))]), 100);
+        let keys = vec!["ETH".to_string(), "BNB".to_string()];
+        assert_eq!(market.due(Feed::Price, &keys, 110, 30), ["BNB"]);
+        assert_eq!(market.due(Feed::Price, &keys, 130, 30), ["ETH", "BNB"]);
+
+        market.expire_prices();
+        assert_eq!(market.due(Feed::Price, &keys, 110, 30), ["ETH", "BNB"]);
+        // 过期后仍保留旧值
+        assert_eq!(market.prices(&keys).len(), 1);
+    }
+
+    #[test]
+    fn poll_interval_backs_off_when_rate_limited() {
+        assert_eq!(poll_interval_ms(0), POLL_INTERVAL_MS);
+        assert_eq!(poll_interval_ms(1), POLL_INTERVAL_MS * 2);
+        assert_eq!(poll_interval_ms(20), MAX_POLL_INTERVAL_MS);
+    }
+}
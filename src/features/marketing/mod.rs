@@ -1,2 +1,4 @@
 // Marketing feature module
 // Marketing feature module - Production-ready implementation
+
+pub mod referral;
@@ -0,0 +1,110 @@
+//! Referral - 邀请码捕获与奖励提醒
+//! 落地页 `?ref=` 参数中的邀请码保存在本地，直到注册成功后绑定；
+//! 已提醒过的奖励记录在本地，新到账的奖励只提醒一次
+
+use crate::services::referral::{normalize_code, ReferralReward};
+use gloo_storage::{LocalStorage, Storage};
+
+const PENDING_CODE_KEY: &str = "referral_pending_code";
+const SEEN_REWARDS_KEY: &str = "referral_seen_rewards";
+/// 本地记录的已提醒奖励上限
+const MAX_SEEN_REWARDS: usize = 200;
+
+/// 从查询字符串（`?a=1&ref=CODE`）中取出邀请码
+pub fn code_from_query(search: &str) -> Option<String> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "ref")
+        .and_then(|(_, value)| urlencoding::decode(value).ok())
+        .and_then(|value| normalize_code(&value))
+}
+
+/// 记录当前页面链接中的邀请码（已有待绑定的邀请码时以最新链接为准）
+pub fn capture_from_location() {
+    let search = web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .unwrap_or_default();
+    if let Some(code) = code_from_query(&search) {
+        let _ = LocalStorage::set(PENDING_CODE_KEY, code);
+    }
+}
+
+/// 待绑定的邀请码
+pub fn pending_code() -> Option<String> {
+    LocalStorage::get::<String>(PENDING_CODE_KEY).ok()
+}
+
+/// 注册时手动输入或绑定完成后更新待绑定的邀请码
+pub fn set_pending_code(code: Option<&str>) {
+    match code {
+        Some(code) => {
+            let _ = LocalStorage::set(PENDING_CODE_KEY, code);
+        }
+        None => LocalStorage::delete(PENDING_CODE_KEY),
+    }
+}
+
+/// 尚未提醒过的奖励
+pub fn unseen_rewards<'a>(
+    rewards: &'a [ReferralReward],
+    seen: &[String],
+) -> Vec<&'a ReferralReward> {
+    rewards.iter().filter(|r| !seen.contains(&r.id)).collect()
+}
+
+/// 取出新到账的奖励并标记为已提醒
+///
+/// 本设备首次查询时只记录现有奖励、不提醒，避免一次弹出全部历史记录
+pub fn take_new_rewards(rewards: &[ReferralReward]) -> Vec<ReferralReward> {
+    let stored = LocalStorage::get::<Vec<String>>(SEEN_REWARDS_KEY).ok();
+    let first_check = stored.is_none();
+    let mut seen = stored.unwrap_or_default();
+    let new: Vec<ReferralReward> = unseen_rewards(rewards, &seen)
+        .into_iter()
+        .cloned()
+        .collect();
+    seen.extend(new.iter().map(|r| r.id.clone()));
+    if seen.len() > MAX_SEEN_REWARDS {
+        seen.drain(..seen.len() - MAX_SEEN_REWARDS);
+    }
+    let _ = LocalStorage::set(SEEN_REWARDS_KEY, &seen);
+    if first_check {
+        Vec::new()
+    } else {
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_is_read_from_query() {
+        assert_eq!(code_from_query("?ref=iron42"), Some("IRON42".to_string()));
+        assert_eq!(
+            code_from_query("?utm=x&ref=%20abcd%20"),
+            Some("ABCD".to_string())
+        );
+        assert_eq!(code_from_query("?referrer=abcd"), None);
+        assert_eq!(code_from_query("?ref=<script>"), None);
+        assert_eq!(code_from_query(""), None);
+    }
+
+    #[test]
+    fn only_unseen_rewards_are_returned() {
+        let reward = |id: &str| ReferralReward {
+            id: id.to_string(),
+            amount: "5".to_string(),
+            asset: "USDT".to_string(),
+            description: String::new(),
+            created_at: String::new(),
+        };
+        let rewards = vec![reward("a"), reward("b")];
+        let new = unseen_rewards(&rewards, &["a".to_string()]);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].id, "b");
+    }
+}
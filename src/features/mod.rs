@@ -10,9 +10,12 @@ pub mod earn;
 pub mod fees;
 pub mod gas;
 pub mod landing;
+pub mod market;
 pub mod marketing;
 pub mod nft;
 pub mod security;
 pub mod settings;
+pub mod shortcuts;
+pub mod support;
 pub mod swap;
 pub mod wallet;
@@ -0,0 +1,47 @@
+//! App Lock - 无操作自动锁定
+//!
+//! 超过设置的无操作时长后锁定应用：清除内存中的密钥、以锁屏遮住余额等敏感信息，
+//! 但保留登录会话与当前页面。只有 JWT 真正过期且无法刷新时才登出。
+//! 锁定状态写入 LocalStorage，刷新页面不会绕过锁屏。
+
+use gloo_storage::{LocalStorage, Storage};
+
+const LOCKED_KEY: &str = "app_locked";
+
+/// 可选的自动锁定时长（分钟）
+pub const AUTO_LOCK_MINUTE_OPTIONS: [u32; 5] = [1, 5, 15, 30, 60];
+/// 默认自动锁定时长（分钟）
+pub const DEFAULT_AUTO_LOCK_MINUTES: u32 = 15;
+
+/// 距最近一次活动是否已超过锁定时长
+pub fn is_idle(last_active_secs: u64, now_secs: u64, window_minutes: u32) -> bool {
+    now_secs.saturating_sub(last_active_secs) >= u64::from(window_minutes.max(1)) * 60
+}
+
+/// 上次关闭页面时是否处于锁定状态
+pub fn persisted_locked() -> bool {
+    LocalStorage::get::<bool>(LOCKED_KEY).unwrap_or(false)
+}
+
+pub fn persist_locked(locked: bool) {
+    if locked {
+        let _ = LocalStorage::set(LOCKED_KEY, true);
+    } else {
+        LocalStorage::delete(LOCKED_KEY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idle_after_configured_window() {
+        assert!(!is_idle(1_000, 1_000 + 15 * 60 - 1, 15));
+        assert!(is_idle(1_000, 1_000 + 15 * 60, 15));
+        // 活动时间晚于当前时间（如时钟回拨）不视为空闲
+        assert!(!is_idle(2_000, 1_000, 1));
+        // 0 分钟按 1 分钟处理，避免立即锁定
+        assert!(!is_idle(1_000, 1_030, 0));
+    }
+}
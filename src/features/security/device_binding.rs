@@ -0,0 +1,334 @@
+//! Device Binding - 高风险操作的可信设备绑定
+//!
+//! - 首次在本浏览器登录时生成不可导出的 ECDSA P-256 密钥对（WebCrypto），
+//!   `CryptoKey` 对象直接存入 IndexedDB，私钥永远不离开浏览器
+//! - 公钥登记到后端；账户的第一台设备直接信任，之后的新设备需邮箱 / TOTP 追加验证
+//! - 高风险请求（大额提现、移除其他设备）前向后端取一次性挑战并签名，签名随请求头发送，
+//!   登记完成后对用户无感
+
+use crate::services::device::{DeviceChallenge, DeviceProof, DeviceService, DeviceStatus};
+use crate::shared::state::AppState;
+use crate::shared::web_crypto::{call, get, resolve, set, subtle, KeyStore};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use dioxus::prelude::ReadableExt;
+use gloo_storage::{LocalStorage, Storage};
+use js_sys::{Array, Object, Uint8Array};
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+
+/// 提现金额（美元）达到该值时需要设备签名
+pub const WITHDRAWAL_THRESHOLD_USD: f64 = 1_000.0;
+/// 登记到后端的签名算法
+pub const ALGORITHM: &str = "ECDSA_P256_SHA256";
+/// 签名内容的版本前缀（后端按相同格式拼接后验签）
+const PAYLOAD_PREFIX: &str = "ironforge-device-v1";
+
+const KEY_STORE: KeyStore = KeyStore {
+    db_name: "ironforge_device",
+    store: "device_keys",
+};
+/// 各用户在本设备上登记得到的设备ID
+const BINDINGS_STORAGE_KEY: &str = "device_bindings";
+
+thread_local! {
+    /// 登录后的后台登记与高风险操作可能同时触发登记，串行执行避免重复登记
+    static REGISTER_LOCK: Rc<futures::lock::Mutex<()>> = Rc::new(futures::lock::Mutex::new(()));
+}
+
+/// 需要设备签名的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighValueOp {
+    /// 超过阈值的法币提现
+    Withdrawal,
+    /// 移除其他已信任设备
+    DeviceRevoke,
+}
+
+impl HighValueOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HighValueOp::Withdrawal => "withdrawal",
+            HighValueOp::DeviceRevoke => "device_revoke",
+        }
+    }
+}
+
+/// 提现是否需要设备签名
+pub fn withdrawal_requires_proof(amount_usd: f64) -> bool {
+    amount_usd.is_finite() && amount_usd >= WITHDRAWAL_THRESHOLD_USD
+}
+
+/// 签名内容：版本前缀、设备、操作与挑战逐行拼接
+pub fn signing_payload(device_id: &str, op: HighValueOp, challenge: &DeviceChallenge) -> Vec<u8> {
+    format!(
+        "{}\n{}\n{}\n{}\n{}",
+        PAYLOAD_PREFIX,
+        device_id,
+        op.as_str(),
+        challenge.challenge_id,
+        challenge.nonce
+    )
+    .into_bytes()
+}
+
+/// 由 User-Agent 生成设备名称（如 "Chrome · macOS"）
+pub fn device_name(user_agent: &str) -> String {
+    let browser = if user_agent.contains("Edg/") {
+        "Edge"
+    } else if user_agent.contains("OPR/") {
+        "Opera"
+    } else if user_agent.contains("Firefox/") {
+        "Firefox"
+    } else if user_agent.contains("Chrome/") {
+        "Chrome"
+    } else if user_agent.contains("Safari/") {
+        "Safari"
+    } else {
+        "浏览器"
+    };
+    let os = if user_agent.contains("iPhone") || user_agent.contains("iPad") {
+        "iOS"
+    } else if user_agent.contains("Android") {
+        "Android"
+    } else if user_agent.contains("Mac OS X") {
+        "macOS"
+    } else if user_agent.contains("Windows") {
+        "Windows"
+    } else if user_agent.contains("Linux") {
+        "Linux"
+    } else {
+        "未知系统"
+    };
+    format!("{} · {}", browser, os)
+}
+
+/// 高风险操作授权失败的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceAuthError {
+    /// 本设备尚未被信任，需要追加验证后重试
+    StepUpRequired {
+        device_id: String,
+    },
+    Failed(String),
+}
+
+impl std::fmt::Display for DeviceAuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeviceAuthError::StepUpRequired { .. } => write!(f, "本设备尚未验证，请先完成追加验证"),
+            DeviceAuthError::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+fn load_bindings() -> HashMap<String, String> {
+    LocalStorage::get(BINDINGS_STORAGE_KEY).unwrap_or_default()
+}
+
+/// 当前用户在本设备上的设备ID
+pub fn current_device_id(user_id: &str) -> Option<String> {
+    load_bindings().get(user_id).cloned()
+}
+
+fn save_binding(user_id: &str, device_id: &str) {
+    let mut bindings = load_bindings();
+    bindings.insert(user_id.to_string(), device_id.to_string());
+    let _ = LocalStorage::set(BINDINGS_STORAGE_KEY, &bindings);
+}
+
+/// 忘记本设备（本设备被移除后调用）：删除登记记录与密钥，下次登录重新登记
+pub async fn forget(user_id: &str) {
+    let mut bindings = load_bindings();
+    bindings.remove(user_id);
+    let _ = LocalStorage::set(BINDINGS_STORAGE_KEY, &bindings);
+    if let Err(e) = delete_key_pair(user_id).await {
+        log::warn!("删除设备密钥失败: {}", e);
+    }
+}
+
+fn current_user_id(app_state: &AppState) -> Result<String, String> {
+    app_state
+        .user
+        .read()
+        .user_id
+        .clone()
+        .ok_or_else(|| "请先登录".to_string())
+}
+
+/// 确保本设备已生成密钥并登记到后端，返回设备ID（登录成功后调用，失败不影响登录）
+pub async fn ensure_registered(app_state: AppState) -> Result<String, String> {
+    let user_id = current_user_id(&app_state)?;
+    let lock = REGISTER_LOCK.with(Rc::clone);
+    let _guard = lock.lock().await;
+
+    let existing_key = load_key_pair(&user_id).await?;
+    if let (Some(device_id), Some(_)) = (current_device_id(&user_id), &existing_key) {
+        return Ok(device_id);
+    }
+
+    let key_pair = match existing_key {
+        Some(key_pair) => key_pair,
+        None => {
+            let key_pair = generate_key_pair().await?;
+            store_key_pair(&user_id, &key_pair).await?;
+            key_pair
+        }
+    };
+    let public_key = export_public_key(&key_pair).await?;
+    let user_agent = web_sys::window()
+        .and_then(|w| w.navigator().user_agent().ok())
+        .unwrap_or_default();
+    let device = DeviceService::new(app_state)
+        .register(&public_key, ALGORITHM, &device_name(&user_agent))
+        .await
+        .map_err(|e| e.to_string())?;
+    save_binding(&user_id, &device.id);
+    log::info!("本设备已登记: {}（{}）", device.id, device.status.label());
+    Ok(device.id)
+}
+
+/// 为高风险操作取挑战并用本设备密钥签名
+pub async fn authorize(
+    app_state: AppState,
+    op: HighValueOp,
+) -> Result<DeviceProof, DeviceAuthError> {
+    let user_id = current_user_id(&app_state).map_err(DeviceAuthError::Failed)?;
+    let device_id = ensure_registered(app_state)
+        .await
+        .map_err(DeviceAuthError::Failed)?;
+
+    let service = DeviceService::new(app_state);
+    let challenge = service
+        .challenge(&device_id, op.as_str())
+        .await
+        .map_err(|e| DeviceAuthError::Failed(e.to_string()))?;
+    match challenge.device_status {
+        DeviceStatus::Trusted => {}
+        DeviceStatus::Pending => return Err(DeviceAuthError::StepUpRequired { device_id }),
+        DeviceStatus::Revoked => {
+            // 本设备已在其他设备上被移除：重新登记，新设备需要追加验证
+            forget(&user_id).await;
+            let device_id = ensure_registered(app_state)
+                .await
+                .map_err(DeviceAuthError::Failed)?;
+            return Err(DeviceAuthError::StepUpRequired { device_id });
+        }
+    }
+
+    let key_pair = load_key_pair(&user_id)
+        .await
+        .map_err(DeviceAuthError::Failed)?
+        .ok_or_else(|| DeviceAuthError::Failed("本设备密钥丢失，请重新登录".to_string()))?;
+    let signature = sign(&key_pair, &signing_payload(&device_id, op, &challenge))
+        .await
+        .map_err(DeviceAuthError::Failed)?;
+    Ok(DeviceProof {
+        device_id,
+        challenge_id: challenge.challenge_id,
+        signature,
+    })
+}
+
+// ---- 设备密钥（WebCrypto / IndexedDB） ----
+
+fn algorithm(extra: &[(&str, &str)]) -> Result<JsValue, String> {
+    let params: JsValue = Object::new().into();
+    set(&params, "name", &JsValue::from_str("ECDSA"))?;
+    for (key, value) in extra {
+        set(&params, key, &JsValue::from_str(value))?;
+    }
+    Ok(params)
+}
+
+/// 生成不可导出私钥的 P-256 密钥对
+async fn generate_key_pair() -> Result<JsValue, String> {
+    let usages = Array::of2(&JsValue::from_str("sign"), &JsValue::from_str("verify"));
+    let promise = call(
+        &subtle()?,
+        "generateKey",
+        &[
+            algorithm(&[("namedCurve", "P-256")])?,
+            JsValue::from_bool(false),
+            usages.into(),
+        ],
+    )?;
+    resolve(promise).await
+}
+
+/// 导出公钥（SPKI，base64）
+async fn export_public_key(key_pair: &JsValue) -> Result<String, String> {
+    let public_key = get(key_pair, "publicKey")?;
+    let promise = call(
+        &subtle()?,
+        "exportKey",
+        &[JsValue::from_str("spki"), public_key],
+    )?;
+    let spki = resolve(promise).await?;
+    Ok(BASE64.encode(Uint8Array::new(&spki).to_vec()))
+}
+
+/// 签名（IEEE P1363 格式 r‖s，base64）
+async fn sign(key_pair: &JsValue, payload: &[u8]) -> Result<String, String> {
+    let private_key = get(key_pair, "privateKey")?;
+    let data = Uint8Array::from(payload);
+    let promise = call(
+        &subtle()?,
+        "sign",
+        &[algorithm(&[("hash", "SHA-256")])?, private_key, data.into()],
+    )?;
+    let signature = resolve(promise).await?;
+    Ok(BASE64.encode(Uint8Array::new(&signature).to_vec()))
+}
+
+async fn load_key_pair(user_id: &str) -> Result<Option<JsValue>, String> {
+    KEY_STORE.load(user_id).await
+}
+
+async fn store_key_pair(user_id: &str, key_pair: &JsValue) -> Result<(), String> {
+    KEY_STORE.store(user_id, key_pair).await
+}
+
+async fn delete_key_pair(user_id: &str) -> Result<(), String> {
+    KEY_STORE.delete(user_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn withdrawal_threshold_is_inclusive() {
+        assert!(!withdrawal_requires_proof(999.99));
+        assert!(withdrawal_requires_proof(WITHDRAWAL_THRESHOLD_USD));
+        assert!(!withdrawal_requires_proof(f64::NAN));
+    }
+
+    #[test]
+    fn payload_binds_device_operation_and_challenge() {
+        let challenge = DeviceChallenge {
+            challenge_id: "ch_1".to_string(),
+            nonce: "n0nce".to_string(),
+            device_status: DeviceStatus::Trusted,
+        };
+        let payload = signing_payload("dev_1", HighValueOp::Withdrawal, &challenge);
+        assert_eq!(
+            String::from_utf8(payload).unwrap(),
+            "ironforge-device-v1\ndev_1\nwithdrawal\nch_1\nn0nce"
+        );
+        assert_ne!(
+            signing_payload("dev_1", HighValueOp::DeviceRevoke, &challenge),
+            signing_payload("dev_1", HighValueOp::Withdrawal, &challenge)
+        );
+    }
+
+    #[test]
+    fn names_devices_from_user_agent() {
+        let chrome_mac = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Safari/537.36";
+        assert_eq!(device_name(chrome_mac), "Chrome · macOS");
+        let edge_win = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 Chrome/120.0 Safari/537.36 Edg/120.0";
+        assert_eq!(device_name(edge_win), "Edge · Windows");
+        let safari_ios = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 Version/17.0 Mobile/15E148 Safari/604.1";
+        assert_eq!(device_name(safari_ios), "Safari · iOS");
+    }
+}
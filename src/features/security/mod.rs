@@ -1,2 +1,4 @@
 // Security feature module
 // Security feature module - Production-ready implementation
+pub mod app_lock;
+pub mod device_binding;
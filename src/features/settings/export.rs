@@ -0,0 +1,469 @@
+//! Settings Export - 设置导出与导入
+//!
+//! 在另一台浏览器上恢复设置时无需云备份：设置序列化为带版本号的 JSON，
+//! 用口令（Argon2id 派生密钥 + AES-256-GCM）加密后下载为文件。
+//! - 导出格式只由下列非敏感设置组成，私钥、助记词、会话令牌等在结构上无法进入导出文件
+//! - 导入时先校验格式与版本，再预览哪些设置会被覆盖、哪些会被合并，确认后一次性写入
+
+use crate::crypto::encryption::{self, generate_salt};
+use crate::features::dashboard::watchlist::{self, Watchlist};
+use crate::features::settings::state::UserPreferences;
+use crate::features::wallet::send_safeguards::SafeguardConfig;
+use crate::features::wallet::token_preferences::TokenPreferences;
+use crate::services::preferences::PreferencesService;
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 当前导出格式版本
+pub const SCHEMA_VERSION: u32 = 1;
+/// 导出文件的格式标识
+const FILE_FORMAT: &str = "ironforge-settings";
+const KDF: &str = "argon2id";
+/// 导出口令最短长度
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// 导出的设置内容（只包含非敏感设置；未知字段一律拒绝）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettingsExport {
+    pub version: u32,
+    pub exported_at: u64,
+    /// 主题、货币、默认网络、自动锁定、确认数等偏好
+    pub preferences: UserPreferences,
+    /// 界面语言代码（zh/en/ja/ko）
+    pub language: String,
+    pub privacy_mode: bool,
+    pub send_safeguards: SafeguardConfig,
+    /// 代币收藏与隐藏
+    pub token_preferences: TokenPreferences,
+    /// 价格关注列表（未登录时为 None）
+    #[serde(default)]
+    pub watchlist: Option<Watchlist>,
+}
+
+/// 加密后的导出文件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ExportFile {
+    format: String,
+    version: u32,
+    kdf: String,
+    /// base64 编码的 Argon2id 盐
+    salt: String,
+    /// base64 编码的密文（nonce 在前）
+    ciphertext: String,
+}
+
+/// 当前设备上的设置快照
+pub async fn snapshot(app_state: AppState) -> SettingsExport {
+    let user_id = app_state.user.read().user_id.clone();
+    let watchlist = match user_id {
+        Some(user_id) => Some(watchlist::load(&user_id).await),
+        None => None,
+    };
+    SettingsExport {
+        version: SCHEMA_VERSION,
+        exported_at: crate::shared::time::now_unix(),
+        preferences: app_state.preferences.read().clone(),
+        language: app_state.language.read().clone(),
+        privacy_mode: *app_state.privacy_mode.read(),
+        send_safeguards: SafeguardConfig::load(),
+        token_preferences: TokenPreferences::load(),
+        watchlist,
+    }
+}
+
+/// 用口令加密设置，返回导出文件内容
+pub async fn encrypt_export(export: &SettingsExport, passphrase: &str) -> Result<Vec<u8>> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(anyhow!("口令至少 {} 个字符", MIN_PASSPHRASE_LEN));
+    }
+    let salt = generate_salt();
+    let key = crate::crypto::worker::derive_key(passphrase, &salt).await?;
+    seal(export, &key, &salt)
+}
+
+/// 校验并解密导出文件
+pub async fn decrypt_export(bytes: &[u8], passphrase: &str) -> Result<SettingsExport> {
+    let (file, salt) = parse_file(bytes)?;
+    let key = crate::crypto::worker::derive_key(passphrase, &salt).await?;
+    open(&file, &key)
+}
+
+fn seal(export: &SettingsExport, key: &[u8; 32], salt: &[u8]) -> Result<Vec<u8>> {
+    let plaintext = serde_json::to_vec(export)?;
+    let file = ExportFile {
+        format: FILE_FORMAT.to_string(),
+        version: export.version,
+        kdf: KDF.to_string(),
+        salt: BASE64.encode(salt),
+        ciphertext: BASE64.encode(encryption::encrypt(key, &plaintext)?),
+    };
+    Ok(serde_json::to_vec_pretty(&file)?)
+}
+
+/// 解析文件外层并校验格式与版本，返回（文件, 盐）
+fn parse_file(bytes: &[u8]) -> Result<(ExportFile, Vec<u8>)> {
+    let file: ExportFile =
+        serde_json::from_slice(bytes).map_err(|_| anyhow!("不是有效的设置导出文件"))?;
+    if file.format != FILE_FORMAT || file.kdf != KDF {
+        return Err(anyhow!("不是有效的设置导出文件"));
+    }
+    check_version(file.version)?;
+    let salt = BASE64
+        .decode(&file.salt)
+        .map_err(|_| anyhow!("导出文件已损坏"))?;
+    Ok((file, salt))
+}
+
+fn check_version(version: u32) -> Result<()> {
+    match version {
+        SCHEMA_VERSION => Ok(()),
+        v if v > SCHEMA_VERSION => Err(anyhow!(
+            "导出文件版本 {} 高于当前支持的版本 {}，请升级应用后再导入",
+            v,
+            SCHEMA_VERSION
+        )),
+        v => Err(anyhow!("不支持的导出文件版本: {}", v)),
+    }
+}
+
+fn open(file: &ExportFile, key: &[u8; 32]) -> Result<SettingsExport> {
+    let data = BASE64
+        .decode(&file.ciphertext)
+        .map_err(|_| anyhow!("导出文件已损坏"))?;
+    let plaintext = encryption::decrypt(key, &data).map_err(|_| anyhow!("口令错误或文件已损坏"))?;
+    let export: SettingsExport =
+        serde_json::from_slice(&plaintext).map_err(|e| anyhow!("导出内容无效: {}", e))?;
+    // 外层版本未加密，以密文内的版本为准再校验一次
+    check_version(export.version)?;
+    Ok(export)
+}
+
+/// 导入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// 用文件中的值替换当前设置
+    Overwrite,
+    /// 保留当前内容，追加文件中的新条目
+    Merge,
+}
+
+impl ImportMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportMode::Overwrite => "覆盖",
+            ImportMode::Merge => "合并",
+        }
+    }
+}
+
+/// 导入预览中的一项
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewItem {
+    pub label: &'static str,
+    pub mode: ImportMode,
+    pub detail: String,
+}
+
+/// 计算导入后的设置与预览；`current` 未登录（无关注列表）时跳过关注列表
+pub fn plan_import(
+    current: &SettingsExport,
+    incoming: &SettingsExport,
+) -> (SettingsExport, Vec<PreviewItem>) {
+    let mut merged = current.clone();
+    let mut items = Vec::new();
+
+    let (old, new) = (&current.preferences, &incoming.preferences);
+    let changed: Vec<&str> = [
+        ("主题", old.theme != new.theme),
+        ("货币", old.currency != new.currency),
+        ("国家/地区", old.country_override != new.country_override),
+        ("默认网络", old.default_chain != new.default_chain),
+        ("自动锁定", old.auto_lock_minutes != new.auto_lock_minutes),
+        ("仪表盘布局", old.dashboard_widgets != new.dashboard_widgets),
+        (
+            "Solana 优先费上限",
+            old.solana_max_priority_fee_lamports != new.solana_max_priority_fee_lamports,
+        ),
+        ("标签同步", old.sync_tx_labels != new.sync_tx_labels),
+        ("演示模式", old.demo_mode != new.demo_mode),
+        (
+            "键盘快捷键",
+            old.keyboard_shortcuts != new.keyboard_shortcuts,
+        ),
+        ("过期提示", old.stale_after_secs != new.stale_after_secs),
+        (
+            "Gas 上限余量",
+            old.gas_limit_buffer_percent != new.gas_limit_buffer_percent,
+        ),
+        ("偏好同步", old.sync_preferences != new.sync_preferences),
+        (
+            "兑换路由偏好",
+            old.swap_route_preference != new.swap_route_preference,
+        ),
+        (
+            "确认数",
+            old.confirmation_overrides != new.confirmation_overrides,
+        ),
+        ("通知偏好", old.notifications != new.notifications),
+    ]
+    .into_iter()
+    .filter(|(_, changed)| *changed)
+    .map(|(name, _)| name)
+    .collect();
+    items.push(PreviewItem {
+        label: "偏好设置",
+        mode: ImportMode::Overwrite,
+        detail: if changed.is_empty() {
+            "与当前相同".to_string()
+        } else {
+            format!("将修改：{}", changed.join("、"))
+        },
+    });
+    merged.preferences = new.clone();
+
+    items.push(overwrite_item(
+        "界面语言",
+        &current.language,
+        &incoming.language,
+    ));
+    merged.language = incoming.language.clone();
+
+    items.push(overwrite_item(
+        "隐私模式",
+        on_off(current.privacy_mode),
+        on_off(incoming.privacy_mode),
+    ));
+    merged.privacy_mode = incoming.privacy_mode;
+
+    items.push(PreviewItem {
+        label: "发送风险提示阈值",
+        mode: ImportMode::Overwrite,
+        detail: if current.send_safeguards == incoming.send_safeguards {
+            "与当前相同".to_string()
+        } else {
+            format!(
+                "代币余额 {}% / 总价值 {}%",
+                incoming.send_safeguards.balance_warn_percent,
+                incoming.send_safeguards.portfolio_warn_percent
+            )
+        },
+    });
+    merged.send_safeguards = incoming.send_safeguards;
+
+    let added = merged.token_preferences.merge(&incoming.token_preferences);
+    items.push(PreviewItem {
+        label: "代币收藏与隐藏",
+        mode: ImportMode::Merge,
+        detail: format!("新增 {} 项", added),
+    });
+
+    if let Some(incoming_watchlist) = &incoming.watchlist {
+        let detail = match merged.watchlist.as_mut() {
+            Some(list) => format!("新增 {} 个代币", list.merge(incoming_watchlist)),
+            None => "未登录，跳过".to_string(),
+        };
+        items.push(PreviewItem {
+            label: "关注列表",
+            mode: ImportMode::Merge,
+            detail,
+        });
+    }
+
+    (merged, items)
+}
+
+fn overwrite_item(label: &'static str, old: &str, new: &str) -> PreviewItem {
+    PreviewItem {
+        label,
+        mode: ImportMode::Overwrite,
+        detail: if old == new {
+            "与当前相同".to_string()
+        } else {
+            format!("{} → {}", old, new)
+        },
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "开启"
+    } else {
+        "关闭"
+    }
+}
+
+/// 写入导入后的设置
+///
+/// 唯一可能失败的步骤（关注列表的加密存储）最先执行，失败时其余设置均未改动；
+/// 之后同步写入本地存储并更新界面状态
+pub async fn apply(app_state: AppState, merged: SettingsExport) -> Result<()> {
+    let user_id = app_state.user.read().user_id.clone();
+    if let (Some(user_id), Some(list)) = (user_id, &merged.watchlist) {
+        watchlist::save(&user_id, list)
+            .await
+            .map_err(|e| anyhow!("保存关注列表失败: {}", e))?;
+    }
+
+    merged.send_safeguards.save();
+    merged.token_preferences.save();
+
+    let preferences = PreferencesService::new(app_state);
+    preferences.update_settings(|settings| *settings = merged.preferences);
+    preferences.set_language(&merged.language);
+    preferences.set_privacy_mode(merged.privacy_mode);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::dashboard::watchlist::WatchlistEntry;
+    use crate::services::address_detector::ChainType;
+
+    /// 导出内容中不允许出现的字段名（子串匹配，不区分大小写）
+    const FORBIDDEN_KEYS: &[&str] = &[
+        "private",
+        "mnemonic",
+        "seed",
+        "password",
+        "passphrase",
+        "secret",
+        "keystore",
+        "access_token",
+        "refresh_token",
+        "session",
+        "jwt",
+    ];
+
+    fn full_export() -> SettingsExport {
+        let mut preferences = UserPreferences {
+            default_chain: Some("polygon".to_string()),
+            country_override: Some("DE".to_string()),
+            sync_tx_labels: true,
+            ..Default::default()
+        };
+        preferences
+            .confirmation_overrides
+            .insert("ethereum".to_string(), 20);
+        let mut token_preferences = TokenPreferences::default();
+        token_preferences.toggle_favorite("w1", "ethereum:0xabc");
+        token_preferences.toggle_hidden("bsc:0xdef");
+        token_preferences.record_selection("ethereum:native");
+        SettingsExport {
+            version: SCHEMA_VERSION,
+            exported_at: 1_700_000_000,
+            preferences,
+            language: "en".to_string(),
+            privacy_mode: true,
+            send_safeguards: SafeguardConfig::default(),
+            token_preferences,
+            watchlist: Some(Watchlist::default_entries()),
+        }
+    }
+
+    fn collect_keys(value: &serde_json::Value, keys: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (k, v) in map {
+                    keys.push(k.to_lowercase());
+                    collect_keys(v, keys);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for v in items {
+                    collect_keys(v, keys);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn export_format_excludes_secret_material() {
+        let value = serde_json::to_value(full_export()).unwrap();
+        let mut keys = Vec::new();
+        collect_keys(&value, &mut keys);
+        for key in &keys {
+            assert!(
+                !FORBIDDEN_KEYS.iter().any(|f| key.contains(f)),
+                "导出内容包含敏感字段: {}",
+                key
+            );
+        }
+
+        // 结构之外的字段（如手工加入的助记词）在导入时被拒绝
+        let mut tampered = value;
+        tampered["mnemonic"] = serde_json::json!("abandon abandon");
+        assert!(serde_json::from_value::<SettingsExport>(tampered).is_err());
+    }
+
+    #[test]
+    fn round_trip_requires_matching_key_and_version() {
+        let export = full_export();
+        let key = [7u8; 32];
+        let bytes = seal(&export, &key, &[1u8; 16]).unwrap();
+
+        let (file, salt) = parse_file(&bytes).unwrap();
+        assert_eq!(salt, vec![1u8; 16]);
+        assert_eq!(open(&file, &key).unwrap(), export);
+        assert!(open(&file, &[8u8; 32]).is_err());
+
+        let mut newer: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        newer["version"] = serde_json::json!(SCHEMA_VERSION + 1);
+        assert!(parse_file(&serde_json::to_vec(&newer).unwrap()).is_err());
+        assert!(parse_file(b"{\"format\":\"other\"}").is_err());
+    }
+
+    #[test]
+    fn import_plan_overwrites_settings_and_merges_lists() {
+        let mut current = full_export();
+        current.language = "zh".to_string();
+        current.watchlist = Some(Watchlist::default());
+        let mut incoming = full_export();
+        incoming.preferences.default_chain = Some("bsc".to_string());
+        incoming.token_preferences.toggle_hidden("ethereum:0x123");
+        incoming.watchlist.as_mut().unwrap().add(WatchlistEntry {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            chain: ChainType::Ethereum,
+            address: "0xa0b8".to_string(),
+        });
+
+        let (merged, items) = plan_import(&current, &incoming);
+        assert_eq!(merged.language, "en");
+        assert_eq!(merged.preferences.default_chain.as_deref(), Some("bsc"));
+        assert!(merged.token_preferences.is_hidden("bsc:0xdef"));
+        assert!(merged.token_preferences.is_hidden("ethereum:0x123"));
+        assert_eq!(merged.watchlist.as_ref().unwrap().entries.len(), 4);
+
+        let detail = |label: &str| {
+            items
+                .iter()
+                .find(|i| i.label == label)
+                .map(|i| (i.mode, i.detail.clone()))
+                .unwrap()
+        };
+        assert_eq!(
+            detail("偏好设置"),
+            (ImportMode::Overwrite, "将修改：默认网络".to_string())
+        );
+        assert_eq!(
+            detail("代币收藏与隐藏"),
+            (ImportMode::Merge, "新增 1 项".to_string())
+        );
+        assert_eq!(
+            detail("关注列表"),
+            (ImportMode::Merge, "新增 4 个代币".to_string())
+        );
+
+        // 未登录时不写入关注列表
+        current.watchlist = None;
+        let (merged, items) = plan_import(&current, &incoming);
+        assert!(merged.watchlist.is_none());
+        assert!(items.iter().any(|i| i.detail == "未登录，跳过"));
+    }
+}
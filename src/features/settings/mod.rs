@@ -1,3 +1,4 @@
 // Settings feature module
 // Settings feature module - Production-ready implementation
+pub mod export;
 pub mod state;
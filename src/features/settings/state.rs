@@ -1,5 +1,8 @@
-use gloo_storage::{LocalStorage, Storage};
+use crate::features::dashboard::widgets::WidgetLayout;
+use crate::features::swap::routing::RoutePreference;
+use crate::services::user::NotificationPreferences;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Theme {
@@ -32,6 +35,68 @@ pub struct UserPreferences {
     pub theme: Theme,
     pub language: Language,
     pub currency: Currency,
+    /// 手动指定的国家/地区（ISO 3166-1 alpha-2），None 表示自动检测
+    #[serde(default)]
+    pub country_override: Option<String>,
+    /// 收款等页面默认选中的网络（`ChainType::as_str`），None 表示 Ethereum
+    #[serde(default)]
+    pub default_chain: Option<String>,
+    /// 无操作多少分钟后自动锁定应用
+    #[serde(default = "default_auto_lock_minutes")]
+    pub auto_lock_minutes: u32,
+    /// 仪表盘小组件布局，None 表示按钱包状态使用默认布局
+    #[serde(default)]
+    pub dashboard_widgets: Option<WidgetLayout>,
+    /// Solana 优先费上限（lamports），估算或手动单价超出时按上限截断
+    #[serde(default = "default_solana_max_priority_fee")]
+    pub solana_max_priority_fee_lamports: u64,
+    /// 交易分类与备注是否同步到后端（默认仅保存在本设备）
+    #[serde(default)]
+    pub sync_tx_labels: bool,
+    /// 演示模式：接口返回固定演示数据，不提交真实交易
+    #[serde(default)]
+    pub demo_mode: bool,
+    /// 按链自定义的确认数（键为 `ChainType::as_str`），未设置的链使用默认值
+    #[serde(default)]
+    pub confirmation_overrides: HashMap<String, u32>,
+    /// 是否启用全局键盘快捷键
+    #[serde(default = "default_keyboard_shortcuts")]
+    pub keyboard_shortcuts: bool,
+    /// 价格、报价与余额超过多少秒未更新时按过期数据展示
+    #[serde(default = "default_stale_after_secs")]
+    pub stale_after_secs: u64,
+    /// Gas Limit 估算值的安全余量（百分比）
+    #[serde(default = "default_gas_limit_buffer_percent")]
+    pub gas_limit_buffer_percent: u32,
+    /// 登录后将偏好设置同步到账户（跨设备）
+    #[serde(default)]
+    pub sync_preferences: bool,
+    /// 兑换默认路由偏好（单次兑换可在高级设置中覆盖）
+    #[serde(default)]
+    pub swap_route_preference: RoutePreference,
+    /// 通知类别、渠道与邮件摘要频率（登录后同步到后端）
+    #[serde(default)]
+    pub notifications: NotificationPreferences,
+}
+
+fn default_auto_lock_minutes() -> u32 {
+    crate::features::security::app_lock::DEFAULT_AUTO_LOCK_MINUTES
+}
+
+fn default_solana_max_priority_fee() -> u64 {
+    crate::blockchain::solana::DEFAULT_MAX_PRIORITY_FEE_LAMPORTS
+}
+
+fn default_keyboard_shortcuts() -> bool {
+    true
+}
+
+fn default_stale_after_secs() -> u64 {
+    crate::shared::freshness::DEFAULT_STALE_AFTER_SECS
+}
+
+fn default_gas_limit_buffer_percent() -> u32 {
+    crate::services::gas_limit::DEFAULT_GAS_LIMIT_BUFFER_PERCENT
 }
 
 impl Default for UserPreferences {
@@ -40,6 +105,40 @@ impl Default for UserPreferences {
             theme: Theme::System,
             language: Language::ChineseSimple, // 默认简体中文
             currency: Currency::CNY,
+            country_override: None,
+            default_chain: None,
+            auto_lock_minutes: default_auto_lock_minutes(),
+            dashboard_widgets: None,
+            solana_max_priority_fee_lamports: default_solana_max_priority_fee(),
+            sync_tx_labels: false,
+            demo_mode: false,
+            confirmation_overrides: HashMap::new(),
+            keyboard_shortcuts: default_keyboard_shortcuts(),
+            stale_after_secs: default_stale_after_secs(),
+            gas_limit_buffer_percent: default_gas_limit_buffer_percent(),
+            sync_preferences: false,
+            swap_route_preference: RoutePreference::default(),
+            notifications: NotificationPreferences::default(),
+        }
+    }
+}
+
+impl Currency {
+    /// ISO 4217 货币代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::USD => "USD",
+            Currency::CNY => "CNY",
+            Currency::EUR => "EUR",
+        }
+    }
+
+    /// 货币符号
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::USD => "$",
+            Currency::CNY => "¥",
+            Currency::EUR => "€",
         }
     }
 }
@@ -93,13 +192,11 @@ impl Language {
 
 impl UserPreferences {
     pub fn load() -> Self {
-        LocalStorage::get("user_preferences").unwrap_or_default()
+        crate::services::preferences::load().settings
     }
 
     /// 保存用户偏好设置
-    /// 为未来扩展准备的方法
-    #[allow(dead_code)] // 为未来扩展准备
     pub fn save(&self) {
-        let _ = LocalStorage::set("user_preferences", self);
+        crate::services::preferences::save_settings(self);
     }
 }
@@ -0,0 +1,118 @@
+//! Keyboard Shortcuts - 全局键盘快捷键
+//!
+//! 应用外壳中只有一个按键分发器（见 `components/shortcut_layer.rs`）：
+//! - 正在输入（焦点在输入框、文本域、下拉框或可编辑区域）时不触发
+//! - 打开了需要输入确认文本的弹窗时全部停用，避免误触
+//! - `g` 开头的组合键需在 `SEQUENCE_TIMEOUT_MS` 内按下第二个键
+//! - 可在设置中关闭（`UserPreferences::keyboard_shortcuts`）
+
+/// `g` 前缀组合键的等待时长（毫秒）
+pub const SEQUENCE_TIMEOUT_MS: f64 = 1500.0;
+
+/// 快捷键对应的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    FocusSearch,
+    GoDashboard,
+    GoSwap,
+    GoOrders,
+    LockWallet,
+    CloseModal,
+    ToggleCheatSheet,
+}
+
+/// 快捷键速查表（按键, 说明）
+pub const BINDINGS: &[(&str, &str)] = &[
+    ("/", "聚焦当前页面的搜索框"),
+    ("g d", "前往仪表盘"),
+    ("g s", "前往兑换"),
+    ("g o", "前往订单"),
+    ("l", "锁定钱包"),
+    ("Esc", "关闭最上层弹窗"),
+    ("?", "显示/隐藏快捷键列表"),
+];
+
+/// 焦点元素是否处于输入状态（此时不处理快捷键）
+pub fn is_typing_target(tag_name: &str, content_editable: bool) -> bool {
+    content_editable
+        || matches!(
+            tag_name.to_ascii_lowercase().as_str(),
+            "input" | "textarea" | "select"
+        )
+}
+
+/// 把按键序列解析为操作；记录 `g` 前缀的等待状态
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShortcutParser {
+    /// 按下 `g` 的时间（毫秒）
+    pending_since: Option<f64>,
+}
+
+impl ShortcutParser {
+    /// 处理一次按键（`KeyboardEvent.key`），返回要执行的操作
+    pub fn handle(&mut self, key: &str, now_ms: f64) -> Option<ShortcutAction> {
+        if let Some(since) = self.pending_since.take() {
+            if now_ms - since <= SEQUENCE_TIMEOUT_MS {
+                return match key {
+                    "d" | "D" => Some(ShortcutAction::GoDashboard),
+                    "s" | "S" => Some(ShortcutAction::GoSwap),
+                    "o" | "O" => Some(ShortcutAction::GoOrders),
+                    // 无效的第二个键：放弃组合，不再当作单键处理
+                    _ => None,
+                };
+            }
+        }
+        match key {
+            "g" | "G" => {
+                self.pending_since = Some(now_ms);
+                None
+            }
+            "/" => Some(ShortcutAction::FocusSearch),
+            "l" | "L" => Some(ShortcutAction::LockWallet),
+            "Escape" => Some(ShortcutAction::CloseModal),
+            "?" => Some(ShortcutAction::ToggleCheatSheet),
+            _ => None,
+        }
+    }
+
+    /// 清除未完成的组合键（如焦点进入输入框时）
+    pub fn reset(&mut self) {
+        self.pending_since = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn g_sequences_navigate_within_timeout() {
+        let mut parser = ShortcutParser::default();
+        assert_eq!(parser.handle("g", 0.0), None);
+        assert_eq!(parser.handle("s", 500.0), Some(ShortcutAction::GoSwap));
+
+        // 超时后第二个键按单键处理
+        assert_eq!(parser.handle("g", 1000.0), None);
+        assert_eq!(
+            parser.handle("l", 1000.0 + SEQUENCE_TIMEOUT_MS + 1.0),
+            Some(ShortcutAction::LockWallet)
+        );
+
+        // 无效的第二个键放弃组合，不会触发锁定
+        assert_eq!(parser.handle("g", 5000.0), None);
+        assert_eq!(parser.handle("l", 5100.0), None);
+        assert_eq!(
+            parser.handle("?", 5200.0),
+            Some(ShortcutAction::ToggleCheatSheet)
+        );
+    }
+
+    #[test]
+    fn typing_targets_are_ignored() {
+        assert!(is_typing_target("INPUT", false));
+        assert!(is_typing_target("textarea", false));
+        assert!(is_typing_target("DIV", true));
+        assert!(!is_typing_target("BUTTON", false));
+        assert!(!is_typing_target("BODY", false));
+    }
+}
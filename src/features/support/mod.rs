@@ -0,0 +1,51 @@
+// Support feature module - 帮助面板
+
+use crate::services::error_logger::recent_stored_logs;
+use crate::services::support::TicketContext;
+
+/// 工单附带的最近错误日志条数
+pub const CONTEXT_ERROR_LOGS: usize = 5;
+
+/// 打开帮助面板的请求（从订单详情打开时带上订单号）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HelpPanelRequest {
+    pub order_id: Option<String>,
+    /// 预填的工单标题与描述（如“报告延迟到账”）
+    pub draft: Option<(String, String)>,
+}
+
+/// 可附带的上下文（面板中逐项展示，用户勾选后才提交）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AvailableContext {
+    pub route: String,
+    pub recent_errors: Vec<String>,
+    pub order_id: Option<String>,
+}
+
+impl AvailableContext {
+    /// 收集当前页面、最近错误日志与订单号
+    pub fn collect(route: String, order_id: Option<String>) -> Self {
+        let recent_errors = recent_stored_logs(CONTEXT_ERROR_LOGS)
+            .into_iter()
+            .map(|log| format!("[{}] {}", log.level.label(), log.message))
+            .collect();
+        Self {
+            route,
+            recent_errors,
+            order_id,
+        }
+    }
+
+    /// 按用户勾选生成提交的上下文
+    pub fn approved(&self, route: bool, errors: bool, order: bool) -> TicketContext {
+        TicketContext {
+            route: route.then(|| self.route.clone()),
+            recent_errors: if errors {
+                self.recent_errors.clone()
+            } else {
+                Vec::new()
+            },
+            order_id: self.order_id.clone().filter(|_| order),
+        }
+    }
+}
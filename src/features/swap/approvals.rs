@@ -0,0 +1,404 @@
+//! Approvals - 预先授权兑换路由合约
+//!
+//! 首次用 ERC-20 代币兑换时需要先授权路由合约动用该代币，会多出一笔交易和手续费。
+//! 新手引导中可以提前为常用稳定币授权（默认按常用金额精确授权，也可选择无限授权）；
+//! 兑换前查询链上额度，额度足够时跳过授权步骤。
+//!
+//! 在这里发起的授权记录在本地，设置页的“代币授权”中列出并可撤销。
+
+use crate::features::swap::contract_call;
+use crate::features::wallet::state::Account;
+use crate::services::address_detector::ChainType;
+use crate::services::erc20::Erc20Encoder;
+use crate::services::token::TokenInfo;
+use crate::services::transaction::{SimulationRequest, TransactionService};
+use crate::shared::state::AppState;
+use crate::shared::validation::TokenAmount;
+use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+/// 兑换路由合约（1inch v6 聚合路由，Ethereum/BSC/Polygon 地址相同）
+pub const SWAP_ROUTER: &str = "0x111111125421cA6dc452d289314280a0f8842A65";
+/// 路由合约的显示名称
+pub const SWAP_ROUTER_LABEL: &str = "1inch 兑换路由";
+/// 精确授权的默认金额（覆盖大多数新用户的单笔兑换）
+pub const TYPICAL_SWAP_AMOUNT: &str = "500";
+/// 可预先授权的稳定币
+pub const STABLECOINS: [&str; 2] = ["USDT", "USDC"];
+/// 本地授权记录（LocalStorage）
+const STORAGE_KEY: &str = "granted_approvals";
+
+/// 授权额度
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApprovalScope {
+    /// 按常用金额精确授权，用完后需重新授权
+    #[default]
+    Exact,
+    /// 无限授权，之后的兑换都不再需要授权
+    Unlimited,
+}
+
+impl ApprovalScope {
+    pub const ALL: [ApprovalScope; 2] = [Self::Exact, Self::Unlimited];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Exact => "精确授权（推荐）",
+            Self::Unlimited => "无限授权",
+        }
+    }
+
+    pub fn description(&self, symbol: &str) -> String {
+        match self {
+            Self::Exact => format!(
+                "允许路由合约最多动用 {} {}，超出后兑换时会再次请求授权",
+                TYPICAL_SWAP_AMOUNT, symbol
+            ),
+            Self::Unlimited => format!(
+                "之后兑换 {} 都不再需要授权；如路由合约出现问题，授权范围内的余额都有风险",
+                symbol
+            ),
+        }
+    }
+
+    /// 授权额度（最小单位）；无限授权为 None
+    pub fn raw_amount(&self, decimals: u8) -> Option<String> {
+        match self {
+            Self::Exact => {
+                TokenAmount::from_units(TYPICAL_SWAP_AMOUNT, decimals).map(|a| a.to_raw_string())
+            }
+            Self::Unlimited => None,
+        }
+    }
+}
+
+/// 在本应用中发起的授权
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrantedApproval {
+    pub wallet_id: String,
+    pub chain: ChainType,
+    /// 授权方（钱包账户地址）
+    pub owner: String,
+    pub token_symbol: String,
+    pub token_address: String,
+    pub decimals: u8,
+    pub spender: String,
+    pub spender_label: String,
+    /// 授权数量（格式化）；无限授权为 None
+    pub amount: Option<String>,
+    pub tx_hash: String,
+    pub granted_at: u64,
+}
+
+impl GrantedApproval {
+    fn same_grant(&self, chain: ChainType, owner: &str, token: &str, spender: &str) -> bool {
+        self.chain == chain
+            && self.owner.eq_ignore_ascii_case(owner)
+            && self.token_address.eq_ignore_ascii_case(token)
+            && self.spender.eq_ignore_ascii_case(spender)
+    }
+
+    /// 授权数量的显示文字
+    pub fn amount_label(&self) -> String {
+        match &self.amount {
+            Some(amount) => format!("{} {}", amount, self.token_symbol),
+            None => format!("无限 {}", self.token_symbol),
+        }
+    }
+}
+
+/// 读取本地授权记录
+pub fn load() -> Vec<GrantedApproval> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+fn save(approvals: &[GrantedApproval]) {
+    let _ = LocalStorage::set(STORAGE_KEY, approvals);
+}
+
+/// 记录授权（同一账户、代币与授权对象只保留最新一条）
+fn upsert(approvals: &mut Vec<GrantedApproval>, approval: GrantedApproval) {
+    approvals.retain(|a| {
+        !a.same_grant(
+            approval.chain,
+            &approval.owner,
+            &approval.token_address,
+            &approval.spender,
+        )
+    });
+    approvals.push(approval);
+}
+
+/// 额度是否视为无限（超过 uint256 最大值的一半）
+pub fn is_unlimited(allowance: &TokenAmount) -> bool {
+    allowance.raw() >= U256::MAX / 2
+}
+
+/// 额度的显示文字
+pub fn allowance_label(allowance: &TokenAmount, symbol: &str) -> String {
+    if is_unlimited(allowance) {
+        format!("无限 {}", symbol)
+    } else {
+        format!("{} {}", allowance.format(6), symbol)
+    }
+}
+
+/// USDT（Ethereum）不允许直接修改非零额度，需先归零
+pub fn requires_reset(token: &TokenInfo, current: &TokenAmount) -> bool {
+    token.chain == ChainType::Ethereum
+        && token.symbol.eq_ignore_ascii_case("USDT")
+        && !current.is_zero()
+}
+
+/// 兑换前需要先授权：ERC-20 代币且链上额度不足
+pub fn needs_approval(token: &TokenInfo, allowance: &TokenAmount, amount: &TokenAmount) -> bool {
+    !token.is_native && allowance < amount
+}
+
+/// 查询链上授权额度（eth_call `allowance(owner, spender)`）
+pub async fn allowance(
+    app_state: AppState,
+    token: &TokenInfo,
+    owner: &str,
+    spender: &str,
+) -> Result<TokenAmount, String> {
+    let data = Erc20Encoder::encode_allowance(owner, spender).map_err(|e| e.to_string())?;
+    let result = TransactionService::new(app_state)
+        .simulate(&SimulationRequest {
+            chain: token.chain.as_str().to_string(),
+            from: owner.to_string(),
+            to: token.address.clone(),
+            value: "0".to_string(),
+            data,
+        })
+        .await
+        .map_err(|e| format!("查询授权额度失败: {}", e))?;
+    if !result.success {
+        return Err(format!(
+            "查询授权额度失败: {}",
+            result.revert_reason.unwrap_or_default()
+        ));
+    }
+    result
+        .return_data
+        .as_deref()
+        .and_then(|data| TokenAmount::from_raw_str(data, token.decimals))
+        .ok_or_else(|| "授权额度返回数据无效".to_string())
+}
+
+/// 兑换该数量前是否需要授权；非 EVM 链或原生币为 None，查询失败时也为 None（交由后端判断）
+pub async fn swap_needs_approval(
+    app_state: AppState,
+    account: &Account,
+    token: &TokenInfo,
+    amount: &TokenAmount,
+) -> Option<bool> {
+    let evm = matches!(
+        token.chain,
+        ChainType::Ethereum | ChainType::BSC | ChainType::Polygon
+    );
+    if token.is_native || !evm {
+        return None;
+    }
+    match allowance(app_state, token, &account.address, SWAP_ROUTER).await {
+        Ok(current) => Some(needs_approval(token, &current, amount)),
+        Err(e) => {
+            log::warn!("{}", e);
+            None
+        }
+    }
+}
+
+/// 签名并广播一笔 approve 交易，返回交易哈希
+async fn send_approve(
+    app_state: AppState,
+    wallet_id: &str,
+    account: &Account,
+    token_address: &str,
+    chain: ChainType,
+    spender: &str,
+    raw_amount: Option<&str>,
+) -> Result<String, String> {
+    let data = Erc20Encoder::encode_approve(spender, raw_amount).map_err(|e| e.to_string())?;
+    contract_call::send(
+        app_state,
+        wallet_id,
+        account,
+        chain,
+        token_address,
+        "0",
+        &data,
+    )
+    .await
+    .map_err(|e| format!("授权交易失败: {}", e))
+}
+
+/// 为兑换路由合约授权该代币，并记录到本地授权列表
+pub async fn grant(
+    app_state: AppState,
+    wallet_id: &str,
+    account: &Account,
+    token: &TokenInfo,
+    scope: ApprovalScope,
+) -> Result<GrantedApproval, String> {
+    if token.is_native {
+        return Err(format!("{} 是原生币，兑换时无需授权", token.symbol));
+    }
+    let current = allowance(app_state, token, &account.address, SWAP_ROUTER).await?;
+    let raw_amount = scope.raw_amount(token.decimals);
+    let target = match raw_amount.as_deref() {
+        Some(raw) => TokenAmount::from_raw_str(raw, token.decimals),
+        None => Some(TokenAmount::new(U256::MAX, token.decimals)),
+    };
+    if target.is_some_and(|t| current >= t) {
+        return Err(format!(
+            "当前已授权 {}，无需再次授权",
+            allowance_label(&current, &token.symbol)
+        ));
+    }
+    if requires_reset(token, &current) {
+        return Err(format!(
+            "{} 需先将现有授权（{}）归零，请在设置的代币授权中撤销后重试",
+            token.symbol,
+            allowance_label(&current, &token.symbol)
+        ));
+    }
+
+    let tx_hash = send_approve(
+        app_state,
+        wallet_id,
+        account,
+        &token.address,
+        token.chain,
+        SWAP_ROUTER,
+        raw_amount.as_deref(),
+    )
+    .await?;
+
+    let approval = GrantedApproval {
+        wallet_id: wallet_id.to_string(),
+        chain: token.chain,
+        owner: account.address.clone(),
+        token_symbol: token.symbol.clone(),
+        token_address: token.address.clone(),
+        decimals: token.decimals,
+        spender: SWAP_ROUTER.to_string(),
+        spender_label: SWAP_ROUTER_LABEL.to_string(),
+        amount: (scope == ApprovalScope::Exact).then(|| TYPICAL_SWAP_AMOUNT.to_string()),
+        tx_hash: tx_hash.clone(),
+        granted_at: crate::shared::time::now_unix(),
+    };
+    let mut approvals = load();
+    upsert(&mut approvals, approval.clone());
+    save(&approvals);
+    Ok(approval)
+}
+
+/// 撤销授权（额度设为 0），成功后从本地授权列表移除
+pub async fn revoke(app_state: AppState, approval: &GrantedApproval) -> Result<String, String> {
+    let account = app_state
+        .wallet
+        .peek()
+        .get_wallet(&approval.wallet_id)
+        .and_then(|w| {
+            w.accounts
+                .iter()
+                .find(|a| a.address.eq_ignore_ascii_case(&approval.owner))
+                .cloned()
+        })
+        .ok_or_else(|| "未找到授权账户所在的钱包".to_string())?;
+    let tx_hash = send_approve(
+        app_state,
+        &approval.wallet_id,
+        &account,
+        &approval.token_address,
+        approval.chain,
+        &approval.spender,
+        Some("0"),
+    )
+    .await?;
+
+    let mut approvals = load();
+    approvals.retain(|a| {
+        !a.same_grant(
+            approval.chain,
+            &approval.owner,
+            &approval.token_address,
+            &approval.spender,
+        )
+    });
+    save(&approvals);
+    Ok(tx_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usdt(chain: ChainType) -> TokenInfo {
+        TokenInfo {
+            address: "0xdac17f958d2ee523a2206206994597c13d831ec7".to_string(),
+            symbol: "USDT".to_string(),
+            name: "Tether USD".to_string(),
+            decimals: 6,
+            chain,
+            logo_url: None,
+            is_native: false,
+            is_spam: false,
+        }
+    }
+
+    fn approval(owner: &str, amount: Option<&str>) -> GrantedApproval {
+        GrantedApproval {
+            wallet_id: "w".to_string(),
+            chain: ChainType::Ethereum,
+            owner: owner.to_string(),
+            token_symbol: "USDT".to_string(),
+            token_address: "0xdac17f958d2ee523a2206206994597c13d831ec7".to_string(),
+            decimals: 6,
+            spender: SWAP_ROUTER.to_string(),
+            spender_label: SWAP_ROUTER_LABEL.to_string(),
+            amount: amount.map(str::to_string),
+            tx_hash: "0x1".to_string(),
+            granted_at: 0,
+        }
+    }
+
+    #[test]
+    fn scope_amounts_and_allowance_checks() {
+        assert_eq!(
+            ApprovalScope::Exact.raw_amount(6).as_deref(),
+            Some("500000000")
+        );
+        assert_eq!(ApprovalScope::Unlimited.raw_amount(6), None);
+
+        let token = usdt(ChainType::Ethereum);
+        let granted = TokenAmount::from_units(TYPICAL_SWAP_AMOUNT, 6).unwrap();
+        let small = TokenAmount::from_units("100", 6).unwrap();
+        let large = TokenAmount::from_units("800", 6).unwrap();
+        assert!(!needs_approval(&token, &granted, &small));
+        assert!(needs_approval(&token, &granted, &large));
+
+        let unlimited = TokenAmount::from_raw_str(&format!("0x{}", "f".repeat(64)), 6).unwrap();
+        assert!(is_unlimited(&unlimited));
+        assert_eq!(allowance_label(&unlimited, "USDT"), "无限 USDT");
+        assert!(!is_unlimited(&granted));
+
+        assert!(requires_reset(&token, &small));
+        assert!(!requires_reset(&token, &TokenAmount::zero(6)));
+        assert!(!requires_reset(&usdt(ChainType::BSC), &small));
+    }
+
+    #[test]
+    fn upsert_keeps_latest_grant_per_token_and_spender() {
+        let mut approvals = Vec::new();
+        upsert(&mut approvals, approval("0xAAA", Some("500")));
+        upsert(&mut approvals, approval("0xaaa", None));
+        upsert(&mut approvals, approval("0xBBB", Some("500")));
+        assert_eq!(approvals.len(), 2);
+        assert_eq!(approvals[0].amount_label(), "无限 USDT");
+        assert_eq!(approvals[1].amount_label(), "500 USDT");
+    }
+}
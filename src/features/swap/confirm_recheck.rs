@@ -0,0 +1,170 @@
+//! Confirm Recheck - 兑换确认时的复核
+//! 确认对话框可能停留较久（期间挂单或其他交易会占用余额、gas 价格也会变化），
+//! 点击确认时重新核对支付代币余额与原生币能否覆盖当前 gas，不足时不发起广播
+
+use crate::features::gas::readiness::SWAP_GAS_UNITS;
+use crate::services::gas::gas_fee_eth_from_max_fee_per_gas_gwei;
+use crate::shared::validation::TokenAmount;
+
+/// 确认对话框打开超过该时长后刷新显示的 gas 费用
+pub const GAS_REFRESH_AFTER_MS: u32 = 30_000;
+
+/// 复核未通过的原因（含具体差额）
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecheckFailure {
+    /// 支付代币可用余额不足
+    Balance {
+        symbol: String,
+        available: TokenAmount,
+        required: TokenAmount,
+    },
+    /// 原生币不足以支付当前 gas（支付原生币时已扣除兑换数量）
+    Gas {
+        symbol: String,
+        available: f64,
+        required: f64,
+    },
+}
+
+impl RecheckFailure {
+    /// 对话框内显示的简短说明
+    pub fn message(&self) -> String {
+        match self {
+            RecheckFailure::Balance {
+                symbol,
+                available,
+                required,
+            } => {
+                let shortfall = required
+                    .checked_sub(available)
+                    .unwrap_or(*required)
+                    .format(6);
+                format!(
+                    "复核未通过：{} 可用 {}，需要 {}，还差 {} {}",
+                    symbol,
+                    available.format(6),
+                    required.format(6),
+                    shortfall,
+                    symbol
+                )
+            }
+            RecheckFailure::Gas {
+                symbol,
+                available,
+                required,
+            } => format!(
+                "复核未通过：当前 Gas 约需 {:.6} {}，可用 {:.6} {}，还差 {:.6} {}",
+                required,
+                symbol,
+                available,
+                symbol,
+                (required - available).max(0.0),
+                symbol
+            ),
+        }
+    }
+}
+
+/// 报价中的 gas 用量（无法解析时按常规兑换估算）
+pub fn swap_gas_units(estimated_gas: Option<&str>) -> u64 {
+    estimated_gas
+        .and_then(|g| {
+            let g = g.trim();
+            match g.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16).ok(),
+                None => g.parse::<u64>().ok(),
+            }
+        })
+        .filter(|units| *units > 0)
+        .unwrap_or(SWAP_GAS_UNITS)
+}
+
+/// 按当前 gas 价格估算的网络费用（原生币）
+pub fn gas_cost(max_fee_per_gas_gwei: f64, gas_units: u64) -> f64 {
+    gas_fee_eth_from_max_fee_per_gas_gwei(max_fee_per_gas_gwei, gas_units)
+}
+
+/// 复核余额与 gas
+///
+/// `native_available` 为 None 时（链不支持原生币余额查询）跳过 gas 复核；
+/// 支付代币为原生币时，gas 需要在兑换数量之外另行覆盖。
+pub fn recheck(
+    amount: &TokenAmount,
+    available: &TokenAmount,
+    from_symbol: &str,
+    from_is_native: bool,
+    native_symbol: &str,
+    native_available: Option<f64>,
+    gas_required: f64,
+) -> Result<(), RecheckFailure> {
+    if amount > available {
+        return Err(RecheckFailure::Balance {
+            symbol: from_symbol.to_string(),
+            available: *available,
+            required: *amount,
+        });
+    }
+    let Some(native) = native_available else {
+        return Ok(());
+    };
+    let spendable = if from_is_native {
+        native - amount.to_f64_lossy()
+    } else {
+        native
+    };
+    if spendable < gas_required {
+        return Err(RecheckFailure::Gas {
+            symbol: native_symbol.to_string(),
+            available: spendable.max(0.0),
+            required: gas_required,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(raw: &str, decimals: u8) -> TokenAmount {
+        TokenAmount::from_raw_str(raw, decimals).unwrap()
+    }
+
+    #[test]
+    fn balance_shortfall_is_reported_first() {
+        let err = recheck(
+            &amount("5000000", 6),
+            &amount("3500000", 6),
+            "USDT",
+            false,
+            "ETH",
+            Some(0.0),
+            0.01,
+        )
+        .unwrap_err();
+        assert!(matches!(err, RecheckFailure::Balance { .. }));
+        assert!(err.message().contains("还差 1.5"));
+    }
+
+    #[test]
+    fn gas_must_be_covered_after_native_amount() {
+        let one_eth = amount("1000000000000000000", 18);
+        let balance = amount("1005000000000000000", 18);
+        // 支付代币足够，但扣除兑换数量后剩余 0.005 ETH 不足以支付 0.01 ETH gas
+        let err = recheck(&one_eth, &balance, "ETH", true, "ETH", Some(1.005), 0.01).unwrap_err();
+        assert!(matches!(err, RecheckFailure::Gas { .. }));
+
+        // 支付 ERC-20 时原生币只需覆盖 gas
+        assert!(recheck(&one_eth, &balance, "USDC", false, "ETH", Some(0.02), 0.01).is_ok());
+        // 不支持原生币查询的链跳过 gas 复核
+        assert!(recheck(&one_eth, &balance, "USDC", false, "BNB", None, 0.01).is_ok());
+    }
+
+    #[test]
+    fn gas_units_parse_quote_estimate() {
+        assert_eq!(swap_gas_units(Some("150000")), 150_000);
+        assert_eq!(swap_gas_units(Some("0x249f0")), 150_000);
+        assert_eq!(swap_gas_units(Some("≈ $3")), SWAP_GAS_UNITS);
+        assert_eq!(swap_gas_units(None), SWAP_GAS_UNITS);
+    }
+}
@@ -0,0 +1,81 @@
+//! Contract Call - 不经过聚合器、直接发起的 EVM 合约调用
+//!
+//! 授权（approve）、包装/解包原生币（deposit/withdraw）等交易由本地构造 calldata，
+//! 用当前钱包的账户签名并广播。
+
+use crate::crypto::tx_signer::EthereumTxSigner;
+use crate::features::wallet::chain_accounts;
+use crate::features::wallet::state::Account;
+use crate::features::wallet::unlock::ensure_wallet_unlocked;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainConfigManager;
+use crate::services::gas_limit::GasLimitService;
+use crate::services::transaction::TransactionService;
+use crate::shared::kill_switch::{self, MoneyAction};
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 签名并广播一笔合约调用，返回交易哈希
+///
+/// `value_wei` 为随交易发送的原生币数量（最小单位，十进制），`data` 为 0x 开头的 calldata。
+pub async fn send(
+    app_state: AppState,
+    wallet_id: &str,
+    account: &Account,
+    chain: ChainType,
+    to: &str,
+    value_wei: &str,
+    data: &str,
+) -> Result<String, String> {
+    ensure_wallet_unlocked(&app_state, wallet_id).map_err(|e| e.to_string())?;
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Swap).await?;
+    let key_manager = app_state
+        .key_manager
+        .read()
+        .clone()
+        .ok_or_else(|| "钱包未解锁，无法签名交易".to_string())?;
+    let account_index = app_state
+        .wallet
+        .peek()
+        .get_wallet(wallet_id)
+        .and_then(|w| w.accounts.iter().position(|a| a.address == account.address))
+        .unwrap_or(0) as u32;
+    let private_key = chain_accounts::signing_key(&key_manager, account, account_index)
+        .map_err(|e| format!("派生私钥失败: {}", e))?;
+
+    let chain_id = ChainConfigManager::new()
+        .get_chain_id(chain)
+        .map_err(|e| format!("获取Chain ID失败: {}", e))?;
+
+    let tx_service = TransactionService::new(app_state);
+    let nonce = tx_service
+        .get_nonce(&account.address, chain_id)
+        .await
+        .map_err(|e| format!("获取nonce失败: {}", e))?;
+    let gas = GasLimitService::new(app_state)
+        .estimate_full(chain_id, &account.address, to, value_wei, Some(data))
+        .await
+        .map_err(|e| format!("估算Gas失败: {}", e))?;
+    let gas_price = gas
+        .gas_price
+        .parse::<u64>()
+        .map_err(|_| format!("Gas价格无效: {}", gas.gas_price))?;
+
+    let signed_tx = EthereumTxSigner::sign_transaction_with_data(
+        &private_key,
+        to,
+        value_wei,
+        data,
+        nonce,
+        gas_price,
+        gas.gas_limit,
+        chain_id,
+    )
+    .map_err(|e| format!("签名交易失败: {}", e))?;
+
+    tx_service
+        .broadcast(chain.as_str(), &signed_tx)
+        .await
+        .map(|r| r.tx_hash)
+        .map_err(|e| format!("广播交易失败: {}", e))
+}
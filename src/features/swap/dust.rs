@@ -0,0 +1,662 @@
+//! Dust Sweep - 小额余额归集
+//!
+//! 扫描低于阈值（USD）的代币余额，逐个报价兑换为所选稳定币：
+//! - Gas 费用超过代币价值的代币自动跳过
+//! - 选中的代币按顺序执行（本地递增 nonce，避免并发签名冲突）
+//! - 每笔兑换携带同一批次的 `client_request_id`，交易历史中合并为一条记录
+
+use crate::features::wallet::chain_accounts;
+use crate::features::wallet::state::{Account, Wallet};
+use crate::features::wallet::unlock::ensure_wallet_unlocked;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::network_to_chain_id;
+use crate::services::gas::{GasService, GasSpeed};
+use crate::services::gas_limit::GasLimitService;
+use crate::services::price::PriceService;
+use crate::services::swap::{SwapQuoteResponse, SwapService, SwapTransactionData};
+use crate::services::token::{TokenBalance, TokenService};
+use crate::services::transaction::TransactionService;
+use crate::services::transaction_history::TransactionHistoryItem;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use crate::shared::validation::parse_quantity;
+use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use std::collections::HashMap;
+
+/// 默认小额阈值（USD）
+pub const DEFAULT_DUST_THRESHOLD_USD: f64 = 1.0;
+/// 可选的归集目标稳定币
+pub const SWEEP_TARGETS: [&str; 2] = ["USDT", "USDC"];
+/// 归集使用的网络（聚合器报价仅支持 EVM）
+const SWEEP_NETWORK: &str = "ethereum";
+/// 小额阈值（LocalStorage）
+const THRESHOLD_STORAGE_KEY: &str = "dust_threshold_usd";
+/// 批量兑换的 client_request_id 前缀
+const SWEEP_REQUEST_PREFIX: &str = "dust-sweep:";
+
+/// 一笔小额持仓
+#[derive(Debug, Clone, PartialEq)]
+pub struct DustHolding {
+    pub symbol: String,
+    pub token_address: String,
+    pub amount: f64,
+    pub usd_value: f64,
+}
+
+/// 单个代币的报价评估结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum DustQuoteOutcome {
+    /// 值得兑换：预计得到的稳定币数量与 Gas 费用（USD）
+    Convert { output: f64, gas_usd: f64 },
+    /// 跳过（报价失败或 Gas 超过价值）
+    Skip { reason: String },
+}
+
+/// 带报价的小额持仓
+#[derive(Debug, Clone, PartialEq)]
+pub struct DustQuote {
+    pub holding: DustHolding,
+    pub outcome: DustQuoteOutcome,
+}
+
+/// 归集汇总（仅统计可兑换的代币）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SweepSummary {
+    pub convert_count: usize,
+    pub skipped_count: usize,
+    pub gross_output: f64,
+    pub gas_usd: f64,
+    pub net_output: f64,
+}
+
+/// 单笔兑换的执行进度
+#[derive(Debug, Clone, PartialEq)]
+pub enum SweepStepStatus {
+    Pending,
+    Running,
+    Done { tx_hash: String },
+    Failed { error: String },
+}
+
+/// 读取用户设置的小额阈值
+pub fn load_threshold() -> f64 {
+    LocalStorage::get::<f64>(THRESHOLD_STORAGE_KEY)
+        .ok()
+        .filter(|v| v.is_finite() && *v > 0.0)
+        .unwrap_or(DEFAULT_DUST_THRESHOLD_USD)
+}
+
+/// 保存小额阈值
+pub fn save_threshold(threshold: f64) {
+    if threshold.is_finite() && threshold > 0.0 {
+        let _ = LocalStorage::set(THRESHOLD_STORAGE_KEY, threshold);
+    }
+}
+
+/// 从余额列表中筛选低于阈值的非零持仓（排除原生币与目标稳定币本身）
+pub fn select_dust(
+    balances: &[TokenBalance],
+    prices: &HashMap<String, f64>,
+    threshold_usd: f64,
+    target: &str,
+) -> Vec<DustHolding> {
+    let mut holdings: Vec<DustHolding> = balances
+        .iter()
+        .filter(|b| !b.token.is_native && !b.token.symbol.eq_ignore_ascii_case(target))
+        .filter(|b| b.balance_formatted > 0.0)
+        .filter_map(|b| {
+            let price = prices.get(&b.token.symbol.to_uppercase()).copied()?;
+            let usd_value = b.balance_formatted * price;
+            (usd_value > 0.0 && usd_value < threshold_usd).then(|| DustHolding {
+                symbol: b.token.symbol.clone(),
+                token_address: b.token.address.clone(),
+                amount: b.balance_formatted,
+                usd_value,
+            })
+        })
+        .collect();
+    holdings.sort_by(|a, b| b.usd_value.total_cmp(&a.usd_value));
+    holdings
+}
+
+/// 评估报价：Gas 超过代币价值或扣除 Gas 后无剩余时跳过
+pub fn evaluate_quote(holding: DustHolding, quote: Result<&SwapQuoteResponse, &str>) -> DustQuote {
+    let outcome = match quote {
+        Err(e) => DustQuoteOutcome::Skip {
+            reason: format!("报价失败：{}", e),
+        },
+        Ok(q) => {
+            let output = q.to_amount.parse::<f64>().unwrap_or(0.0);
+            let gas_usd = q.estimated_gas_usd.unwrap_or(0.0);
+            if gas_usd >= holding.usd_value {
+                DustQuoteOutcome::Skip {
+                    reason: format!(
+                        "Gas 费用 ${:.2} 超过代币价值 ${:.2}",
+                        gas_usd, holding.usd_value
+                    ),
+                }
+            } else if output - gas_usd <= 0.0 {
+                DustQuoteOutcome::Skip {
+                    reason: "扣除 Gas 后无剩余".to_string(),
+                }
+            } else {
+                DustQuoteOutcome::Convert { output, gas_usd }
+            }
+        }
+    };
+    DustQuote { holding, outcome }
+}
+
+/// 汇总预计输出
+pub fn summarize(quotes: &[DustQuote]) -> SweepSummary {
+    quotes.iter().fold(SweepSummary::default(), |mut acc, q| {
+        match q.outcome {
+            DustQuoteOutcome::Convert { output, gas_usd } => {
+                acc.convert_count += 1;
+                acc.gross_output += output;
+                acc.gas_usd += gas_usd;
+                acc.net_output += output - gas_usd;
+            }
+            DustQuoteOutcome::Skip { .. } => acc.skipped_count += 1,
+        }
+        acc
+    })
+}
+
+/// 钱包中的 EVM 账户（索引用于派生私钥）
+fn evm_account(wallet: &Wallet) -> Option<(u32, &Account)> {
+    wallet
+        .accounts
+        .iter()
+        .enumerate()
+        .find(|(_, a)| ChainType::from_str(&a.chain) == Some(ChainType::Ethereum))
+        .map(|(i, a)| (i as u32, a))
+}
+
+/// 扫描钱包中的小额持仓
+pub async fn scan_dust(
+    app_state: AppState,
+    wallet: &Wallet,
+    threshold_usd: f64,
+    target: &str,
+) -> Result<Vec<DustHolding>, String> {
+    let address = evm_account(wallet)
+        .map(|(_, a)| a.address.clone())
+        .ok_or_else(|| "当前钱包没有以太坊账户".to_string())?;
+
+    let token_service = TokenService::new(app_state);
+    let tokens = token_service
+        .get_token_list(ChainType::Ethereum)
+        .await
+        .map_err(|e| format!("获取代币列表失败：{}", e))?;
+    let addresses: Vec<String> = tokens
+        .iter()
+        .filter(|t| !t.is_native)
+        .map(|t| t.address.clone())
+        .collect();
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let balances = token_service
+        .get_token_balances_batch(ChainType::Ethereum, &address, &addresses)
+        .await
+        .map_err(|e| format!("获取代币余额失败：{}", e))?;
+    let held: Vec<TokenBalance> = balances
+        .into_iter()
+        .filter(|b| b.balance_formatted > 0.0)
+        .collect();
+    if held.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let symbols: Vec<String> = held.iter().map(|b| b.token.symbol.to_uppercase()).collect();
+    let refs: Vec<&str> = symbols.iter().map(String::as_str).collect();
+    let prices: HashMap<String, f64> = PriceService::new(app_state)
+        .get_prices(&refs)
+        .await
+        .map_err(|e| format!("获取价格失败：{}", e))?
+        .into_iter()
+        .map(|(symbol, price)| (symbol.to_uppercase(), price.usd))
+        .collect();
+
+    Ok(select_dust(&held, &prices, threshold_usd, target))
+}
+
+/// 逐个获取兑换报价
+pub async fn quote_dust(
+    app_state: AppState,
+    holdings: Vec<DustHolding>,
+    target: &str,
+) -> Vec<DustQuote> {
+    let swap_service = SwapService::new(app_state);
+    let mut quotes = Vec::with_capacity(holdings.len());
+    for holding in holdings {
+        let quote = swap_service
+            .get_quote(
+                &holding.symbol,
+                target,
+                &holding.amount.to_string(),
+                SWEEP_NETWORK,
+            )
+            .await;
+        quotes.push(evaluate_quote(
+            holding,
+            quote.as_ref().map_err(String::as_str),
+        ));
+    }
+    quotes
+}
+
+/// 生成批次ID（同一批次的兑换在历史中合并显示）
+pub fn new_batch_id() -> String {
+    format!("{:x}", time::now_ms() as u64)
+}
+
+/// 按顺序执行归集；`on_progress(index, status)` 报告每笔兑换的进度
+pub async fn execute_sweep(
+    app_state: AppState,
+    wallet: &Wallet,
+    quotes: &[DustQuote],
+    target: &str,
+    batch_id: &str,
+    mut on_progress: impl FnMut(usize, SweepStepStatus),
+) -> Result<(), String> {
+    let (account_index, account) =
+        evm_account(wallet).ok_or_else(|| "当前钱包没有以太坊账户".to_string())?;
+    let address = account.address.clone();
+    let chain_id = network_to_chain_id(SWEEP_NETWORK).unwrap_or(1);
+
+    let key_manager = app_state
+        .key_manager
+        .read()
+        .clone()
+        .ok_or_else(|| "钱包未解锁，无法签名交易".to_string())?;
+    let private_key = chain_accounts::signing_key(&key_manager, account, account_index)
+        .map_err(|e| format!("获取私钥失败: {}", e))?;
+
+    let tx_service = TransactionService::new(app_state);
+    let mut nonce = tx_service
+        .get_nonce(&address, chain_id)
+        .await
+        .map_err(|e| format!("获取nonce失败: {}", e))?;
+
+    let swap_service = SwapService::new(app_state);
+    for (index, quote) in quotes.iter().enumerate() {
+        if !matches!(quote.outcome, DustQuoteOutcome::Convert { .. }) {
+            continue;
+        }
+        on_progress(index, SweepStepStatus::Running);
+
+        let request_id = format!("{}{}:{}", SWEEP_REQUEST_PREFIX, batch_id, index);
+        let result = async {
+            let response = swap_service
+                .execute_with_request_id(
+                    &wallet.id,
+                    &quote.holding.symbol,
+                    target,
+                    &quote.holding.amount.to_string(),
+                    SWEEP_NETWORK,
+                    None,
+                    Some(request_id),
+                )
+                .await?;
+            if response.needs_approval == Some(true) {
+                return Err(format!(
+                    "{} 需要先授权，请在兑换页单独处理",
+                    quote.holding.symbol
+                ));
+            }
+            let tx = response
+                .transaction
+                .ok_or_else(|| "未返回交易数据".to_string())?;
+            sign_and_broadcast(
+                app_state,
+                SWEEP_NETWORK,
+                &private_key,
+                &address,
+                chain_id,
+                nonce,
+                &tx,
+            )
+            .await
+        }
+        .await;
+
+        match result {
+            Ok(tx_hash) => {
+                nonce += 1;
+                on_progress(index, SweepStepStatus::Done { tx_hash });
+            }
+            Err(error) => on_progress(
+                index,
+                SweepStepStatus::Failed {
+                    error: crate::shared::ui_error::sanitize_user_message(error),
+                },
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// 签名并广播聚合器返回的交易，返回交易哈希
+pub(crate) async fn sign_and_broadcast(
+    app_state: AppState,
+    network: &str,
+    private_key: &str,
+    from: &str,
+    chain_id: u64,
+    nonce: u64,
+    tx: &SwapTransactionData,
+) -> Result<String, String> {
+    let gas_limit = match tx.gas.as_deref().and_then(parse_quantity) {
+        Some(limit) => GasLimitService::new(app_state).with_margin(chain_id, limit),
+        None => GasLimitService::new(app_state)
+            .estimate(chain_id, from, &tx.to, &tx.value, Some(&tx.data))
+            .await
+            .map_err(|e| format!("估算Gas失败: {}", e))?,
+    };
+    let gas_price = match tx.gas_price.as_deref().and_then(parse_quantity) {
+        Some(price) => price,
+        None => {
+            let estimate = GasService::new(app_state)
+                .estimate(network, GasSpeed::Average)
+                .await
+                .map_err(|e| format!("获取Gas价格失败: {}", e))?;
+            (estimate.max_fee_per_gas_gwei * 1e9) as u64
+        }
+    };
+
+    let signed = crate::crypto::worker::sign_eth_transaction_with_data(
+        private_key,
+        &tx.to,
+        &tx.value,
+        &tx.data,
+        nonce,
+        gas_price,
+        gas_limit,
+        chain_id,
+    )
+    .await
+    .map_err(|e| format!("签名交易失败: {}", e))?;
+
+    TransactionService::new(app_state)
+        .broadcast(network, &signed)
+        .await
+        .map(|r| r.tx_hash)
+        .map_err(|e| format!("广播交易失败: {}", e))
+}
+
+/// 单笔兑换：按网络找到钱包账户，请求兑换交易并签名广播
+///
+/// `min_nonce` 为上一笔交易之后的 nonce（连续兑换时链上 nonce 可能尚未更新）；
+/// 返回（交易哈希, 本笔使用的 nonce）
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn swap_and_broadcast(
+    app_state: AppState,
+    wallet_id: &str,
+    network: &str,
+    from_token: &str,
+    to_token: &str,
+    amount: &str,
+    request_id: String,
+    min_nonce: Option<u64>,
+) -> Result<(String, u64), String> {
+    ensure_wallet_unlocked(&app_state, wallet_id).map_err(|e| e.to_string())?;
+    let (account_index, account) = {
+        let wallet_state = app_state.wallet.read();
+        let wallet = wallet_state
+            .get_wallet(wallet_id)
+            .ok_or_else(|| "未找到钱包".to_string())?;
+        let index = wallet
+            .accounts
+            .iter()
+            .position(|a| a.chain.eq_ignore_ascii_case(network))
+            .ok_or_else(|| format!("钱包没有 {} 账户", network))?;
+        (index as u32, wallet.accounts[index].clone())
+    };
+    let address = account.address.clone();
+    let chain_id =
+        network_to_chain_id(network).ok_or_else(|| format!("不支持的网络: {}", network))?;
+
+    let key_manager = app_state
+        .key_manager
+        .read()
+        .clone()
+        .ok_or_else(|| "钱包未解锁，无法签名交易".to_string())?;
+    let private_key = chain_accounts::signing_key(&key_manager, &account, account_index)
+        .map_err(|e| format!("获取私钥失败: {}", e))?;
+    let nonce = TransactionService::new(app_state)
+        .get_nonce(&address, chain_id)
+        .await
+        .map_err(|e| format!("获取nonce失败: {}", e))?
+        .max(min_nonce.unwrap_or(0));
+
+    let response = SwapService::new(app_state)
+        .execute_with_request_id(
+            wallet_id,
+            from_token,
+            to_token,
+            amount,
+            network,
+            None,
+            Some(request_id),
+        )
+        .await?;
+    if response.needs_approval == Some(true) {
+        return Err(format!("{} 需要先授权，请在兑换页单独处理", from_token));
+    }
+    let tx = response
+        .transaction
+        .ok_or_else(|| "未返回交易数据".to_string())?;
+    let tx_hash = sign_and_broadcast(
+        app_state,
+        network,
+        &private_key,
+        &address,
+        chain_id,
+        nonce,
+        &tx,
+    )
+    .await?;
+    Ok((tx_hash, nonce))
+}
+
+/// 历史记录中属于某个归集批次的条目返回批次ID
+fn sweep_batch_id(item: &TransactionHistoryItem) -> Option<String> {
+    let request_id = item.metadata.as_ref()?.get("client_request_id")?.as_str()?;
+    let rest = request_id.strip_prefix(SWEEP_REQUEST_PREFIX)?;
+    Some(rest.split(':').next()?.to_string())
+}
+
+/// 是否为 [`group_sweep_history`] 合并出的归集批次记录
+pub fn is_sweep_group(item: &TransactionHistoryItem) -> bool {
+    item.id.starts_with(SWEEP_REQUEST_PREFIX)
+}
+
+/// 把同一归集批次的多条兑换合并为一条历史记录（保持原有顺序）
+pub fn group_sweep_history(items: Vec<TransactionHistoryItem>) -> Vec<TransactionHistoryItem> {
+    let mut grouped: Vec<TransactionHistoryItem> = Vec::with_capacity(items.len());
+    let mut batch_positions: HashMap<String, usize> = HashMap::new();
+    let mut batch_sources: HashMap<String, Vec<String>> = HashMap::new();
+
+    for item in items {
+        let Some(batch_id) = sweep_batch_id(&item) else {
+            grouped.push(item);
+            continue;
+        };
+        batch_sources
+            .entry(batch_id.clone())
+            .or_default()
+            .push(item.from_token.clone());
+
+        match batch_positions.get(&batch_id) {
+            Some(&pos) => {
+                let entry = &mut grouped[pos];
+                let total = entry.to_amount.parse::<f64>().unwrap_or(0.0)
+                    + item.to_amount.parse::<f64>().unwrap_or(0.0);
+                entry.to_amount = format!("{}", total);
+                entry.status = merge_status(&entry.status, &item.status).to_string();
+                if item.created_at < entry.created_at {
+                    entry.created_at = item.created_at;
+                }
+            }
+            None => {
+                batch_positions.insert(batch_id.clone(), grouped.len());
+                grouped.push(TransactionHistoryItem {
+                    id: format!("{}{}", SWEEP_REQUEST_PREFIX, batch_id),
+                    tx_hash: None,
+                    fiat_order_id: None,
+                    ..item
+                });
+            }
+        }
+    }
+
+    for (batch_id, sources) in batch_sources {
+        if let Some(&pos) = batch_positions.get(&batch_id) {
+            let entry = &mut grouped[pos];
+            entry.from_token = format!("{} 种小额代币", sources.len());
+            entry.from_amount = sources.join(", ");
+            entry.metadata = Some(serde_json::json!({
+                "dust_sweep": true,
+                "batch_id": batch_id,
+                "tokens": sources,
+            }));
+        }
+    }
+    grouped
+}
+
+/// 合并状态：任一失败即失败，任一进行中即进行中
+fn merge_status<'a>(a: &'a str, b: &'a str) -> &'a str {
+    const PRIORITY: [&str; 5] = ["failed", "processing", "pending", "cancelled", "completed"];
+    PRIORITY
+        .iter()
+        .find(|s| **s == a || **s == b)
+        .copied()
+        .unwrap_or(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::token::TokenInfo;
+
+    fn balance(symbol: &str, amount: f64, native: bool) -> TokenBalance {
+        TokenBalance {
+            token: TokenInfo {
+                address: format!("0x{}", symbol.to_lowercase()),
+                symbol: symbol.to_string(),
+                name: symbol.to_string(),
+                decimals: 18,
+                chain: ChainType::Ethereum,
+                logo_url: None,
+                is_native: native,
+                is_spam: false,
+            },
+            balance_raw: "0".to_string(),
+            balance_formatted: amount,
+        }
+    }
+
+    fn quote(to_amount: &str, gas_usd: Option<f64>) -> SwapQuoteResponse {
+        SwapQuoteResponse {
+            from_token: "X".to_string(),
+            to_token: "USDT".to_string(),
+            from_amount: "1".to_string(),
+            to_amount: to_amount.to_string(),
+            estimated_gas: None,
+            protocol_fee: None,
+            exchange_rate: None,
+            price_impact: None,
+            route: None,
+            estimated_gas_usd: gas_usd,
+            valid_for: None,
+            route_preference: None,
+        }
+    }
+
+    fn history(
+        id: &str,
+        request_id: Option<&str>,
+        to_amount: &str,
+        status: &str,
+    ) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            id: id.to_string(),
+            tx_type: "swap".to_string(),
+            status: status.to_string(),
+            from_token: id.to_uppercase(),
+            to_token: "USDT".to_string(),
+            from_amount: "1".to_string(),
+            to_amount: to_amount.to_string(),
+            fee_amount: None,
+            gas_fee: None,
+            tx_hash: Some(format!("0x{}", id)),
+            created_at: format!("2026-01-01T00:00:0{}Z", id.len()),
+            completed_at: None,
+            fiat_order_id: None,
+            metadata: request_id.map(|r| serde_json::json!({ "client_request_id": r })),
+        }
+    }
+
+    #[test]
+    fn select_dust_filters_by_threshold() {
+        let balances = vec![
+            balance("ETH", 0.0001, true),
+            balance("SHIB", 1000.0, false),
+            balance("UNI", 10.0, false),
+            balance("USDT", 0.5, false),
+            balance("NOPRICE", 3.0, false),
+        ];
+        let prices = HashMap::from([
+            ("SHIB".to_string(), 0.0005),
+            ("UNI".to_string(), 8.0),
+            ("USDT".to_string(), 1.0),
+        ]);
+        let dust = select_dust(&balances, &prices, 1.0, "USDT");
+        assert_eq!(dust.len(), 1);
+        assert_eq!(dust[0].symbol, "SHIB");
+        assert!((dust[0].usd_value - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn evaluate_quote_skips_when_gas_exceeds_value() {
+        let holding = DustHolding {
+            symbol: "SHIB".to_string(),
+            token_address: "0xshib".to_string(),
+            amount: 1000.0,
+            usd_value: 0.8,
+        };
+        let expensive = evaluate_quote(holding.clone(), Ok(&quote("0.79", Some(2.5))));
+        assert!(matches!(expensive.outcome, DustQuoteOutcome::Skip { .. }));
+
+        let cheap = evaluate_quote(holding.clone(), Ok(&quote("0.79", Some(0.1))));
+        assert!(matches!(cheap.outcome, DustQuoteOutcome::Convert { .. }));
+
+        let failed = evaluate_quote(holding, Err("timeout"));
+        let summary = summarize(&[cheap, failed]);
+        assert_eq!(summary.convert_count, 1);
+        assert_eq!(summary.skipped_count, 1);
+        assert!((summary.net_output - 0.69).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sweep_entries_are_grouped_in_history() {
+        let items = vec![
+            history("a", None, "5", "completed"),
+            history("b", Some("dust-sweep:abc:0"), "0.4", "completed"),
+            history("c", Some("dust-sweep:abc:1"), "0.6", "failed"),
+            history("d", None, "1", "completed"),
+        ];
+        let grouped = group_sweep_history(items);
+        assert_eq!(grouped.len(), 3);
+        assert_eq!(grouped[1].id, "dust-sweep:abc");
+        assert_eq!(grouped[1].to_amount, "1");
+        assert_eq!(grouped[1].status, "failed");
+        assert_eq!(grouped[1].from_token, "2 种小额代币");
+        assert_eq!(grouped[2].id, "d");
+    }
+}
@@ -0,0 +1,381 @@
+//! Execution Quality - 兑换执行质量与夹子攻击检测
+//!
+//! 提交兑换时记录报价（预期收到数量与滑点容忍度）；兑换确认后拉取同一区块中
+//! 同一池子的相邻交易，检测经典的夹子攻击：用户交易之前有同方向的抢跑交易，
+//! 之后有同一发送方的反方向交易。结合实际收到数量计算滑点消耗与相对报价的损失，
+//! 报告保存在本地，供历史详情展示与汇总统计。
+
+use crate::features::swap::history::{self, SwapHistoryStatus};
+use crate::services::swap::{PoolActivityResponse, PoolTx, SwapService};
+use crate::services::transaction_history::TransactionHistoryItem;
+use crate::shared::state::AppState;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 提交时的报价快照（LocalStorage）
+const QUOTES_STORAGE_KEY: &str = "swap_quote_snapshots";
+/// 执行质量报告（LocalStorage）
+const REPORTS_STORAGE_KEY: &str = "swap_execution_reports";
+/// 最多保留的记录数（按写入顺序淘汰最早的）
+const MAX_ENTRIES: usize = 200;
+
+/// 提交兑换时的报价
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuoteSnapshot {
+    /// 报价的预期收到数量
+    pub expected_output: String,
+    /// 滑点容忍度（百分比）
+    pub slippage_pct: f64,
+    /// 是否通过防 MEV 的私有通道提交
+    #[serde(default)]
+    pub mev_protected: Option<bool>,
+}
+
+/// 检测到的夹子攻击
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SandwichMatch {
+    pub attacker: String,
+    pub front_run_tx: String,
+    pub back_run_tx: String,
+}
+
+/// 一笔兑换的执行质量报告
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionReport {
+    pub swap_id: String,
+    pub to_token: String,
+    pub expected_output: f64,
+    pub actual_output: f64,
+    pub slippage_pct: f64,
+    /// 相对报价少收到的比例（百分比，负数表示比报价多收到）
+    pub shortfall_pct: f64,
+    /// 检测到的夹子攻击（未发现或未能检测时为 None）
+    pub sandwich: Option<SandwichMatch>,
+    /// 是否取得了池子活动数据并完成检测
+    pub mev_checked: bool,
+    #[serde(default)]
+    pub mev_protected: Option<bool>,
+}
+
+impl ExecutionReport {
+    /// 消耗的滑点容忍度比例（0–100+，超过 100 说明成交价差于容忍度）
+    pub fn slippage_consumed_pct(&self) -> f64 {
+        if self.slippage_pct <= 0.0 {
+            return 0.0;
+        }
+        (self.shortfall_pct.max(0.0) / self.slippage_pct) * 100.0
+    }
+
+    pub fn mev_detected(&self) -> bool {
+        self.sandwich.is_some()
+    }
+
+    /// 相对报价的估算损失（以收到的代币计）；仅在检测到夹子攻击时计入 MEV 损失
+    pub fn estimated_mev_loss(&self) -> Option<f64> {
+        self.sandwich
+            .as_ref()
+            .map(|_| (self.expected_output - self.actual_output).max(0.0))
+    }
+}
+
+fn same_token(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+/// 检测经典夹子攻击：用户交易前有同方向交易，之后有同一发送方的反方向交易
+pub fn detect_sandwich(
+    activity: &PoolActivityResponse,
+    from_token: &str,
+    to_token: &str,
+) -> Option<SandwichMatch> {
+    let user_index = activity.user_tx_index;
+    let is_front = |tx: &PoolTx| {
+        tx.tx_index < user_index
+            && same_token(&tx.token_in, from_token)
+            && same_token(&tx.token_out, to_token)
+    };
+    let is_back = |tx: &PoolTx| {
+        tx.tx_index > user_index
+            && same_token(&tx.token_in, to_token)
+            && same_token(&tx.token_out, from_token)
+    };
+    activity
+        .transactions
+        .iter()
+        .filter(|tx| is_front(tx))
+        .find_map(|front| {
+            activity
+                .transactions
+                .iter()
+                .find(|back| is_back(back) && same_token(&back.sender, &front.sender))
+                .map(|back| SandwichMatch {
+                    attacker: front.sender.clone(),
+                    front_run_tx: front.tx_hash.clone(),
+                    back_run_tx: back.tx_hash.clone(),
+                })
+        })
+}
+
+/// 由报价快照、已确认的兑换记录与池子活动生成报告（缺少报价或实际数量时返回 None）
+pub fn build_report(
+    item: &TransactionHistoryItem,
+    quote: &QuoteSnapshot,
+    activity: Option<&PoolActivityResponse>,
+) -> Option<ExecutionReport> {
+    let expected_output: f64 = quote.expected_output.trim().parse().ok()?;
+    let actual_output: f64 = item.to_amount.trim().parse().ok()?;
+    if expected_output <= 0.0 || actual_output <= 0.0 {
+        return None;
+    }
+    Some(ExecutionReport {
+        swap_id: item.id.clone(),
+        to_token: item.to_token.clone(),
+        expected_output,
+        actual_output,
+        slippage_pct: quote.slippage_pct,
+        shortfall_pct: (expected_output - actual_output) / expected_output * 100.0,
+        sandwich: activity.and_then(|a| detect_sandwich(a, &item.from_token, &item.to_token)),
+        mev_checked: activity.is_some(),
+        mev_protected: quote.mev_protected,
+    })
+}
+
+/// 按 swap_id 保存的有序记录（超过上限时淘汰最早的）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Entries<T> {
+    order: Vec<String>,
+    items: HashMap<String, T>,
+}
+
+impl<T> Default for Entries<T> {
+    fn default() -> Self {
+        Self {
+            order: Vec::new(),
+            items: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Entries<T> {
+    fn insert(&mut self, id: &str, value: T) {
+        if self.items.insert(id.to_string(), value).is_none() {
+            self.order.push(id.to_string());
+        }
+        while self.order.len() > MAX_ENTRIES {
+            let oldest = self.order.remove(0);
+            self.items.remove(&oldest);
+        }
+    }
+}
+
+/// 记录提交时的报价
+pub fn record_quote(swap_id: &str, quote: QuoteSnapshot) {
+    let mut quotes: Entries<QuoteSnapshot> =
+        LocalStorage::get(QUOTES_STORAGE_KEY).unwrap_or_default();
+    quotes.insert(swap_id, quote);
+    let _ = LocalStorage::set(QUOTES_STORAGE_KEY, &quotes);
+}
+
+pub fn quote_for(swap_id: &str) -> Option<QuoteSnapshot> {
+    LocalStorage::get::<Entries<QuoteSnapshot>>(QUOTES_STORAGE_KEY)
+        .ok()?
+        .items
+        .remove(swap_id)
+}
+
+pub fn save_report(report: &ExecutionReport) {
+    let mut reports: Entries<ExecutionReport> =
+        LocalStorage::get(REPORTS_STORAGE_KEY).unwrap_or_default();
+    reports.insert(&report.swap_id, report.clone());
+    let _ = LocalStorage::set(REPORTS_STORAGE_KEY, &reports);
+}
+
+pub fn report_for(swap_id: &str) -> Option<ExecutionReport> {
+    LocalStorage::get::<Entries<ExecutionReport>>(REPORTS_STORAGE_KEY)
+        .ok()?
+        .items
+        .remove(swap_id)
+}
+
+pub fn all_reports() -> Vec<ExecutionReport> {
+    let mut reports: Entries<ExecutionReport> =
+        LocalStorage::get(REPORTS_STORAGE_KEY).unwrap_or_default();
+    reports
+        .order
+        .iter()
+        .filter_map(|id| reports.items.remove(id))
+        .collect()
+}
+
+/// 可以评估执行质量的记录：已确认的单笔兑换
+pub fn is_analyzable(item: &TransactionHistoryItem) -> bool {
+    history::is_single_swap(item)
+        && SwapHistoryStatus::from_status(&item.status) == Some(SwapHistoryStatus::Confirmed)
+}
+
+/// 生成并保存兑换的执行质量报告（已有报告直接返回；没有提交时的报价返回 None）。
+/// 池子活动查询失败时仍生成报告，只是标记为未检测 MEV
+pub async fn analyze(
+    app_state: AppState,
+    item: &TransactionHistoryItem,
+) -> Option<ExecutionReport> {
+    if !is_analyzable(item) {
+        return None;
+    }
+    if let Some(report) = report_for(&item.id) {
+        return Some(report);
+    }
+    let quote = quote_for(&item.id)?;
+    let activity = match SwapService::new(app_state)
+        .get_pool_activity(&item.id)
+        .await
+    {
+        Ok(activity) => Some(activity),
+        Err(e) => {
+            log::warn!("获取池子活动失败 {}: {}", item.id, e);
+            None
+        }
+    };
+    let report = build_report(item, &quote, activity.as_ref())?;
+    // 未检测 MEV 的报告不保存，下次打开时重新尝试
+    if report.mev_checked {
+        save_report(&report);
+    }
+    Some(report)
+}
+
+/// 执行质量汇总
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ExecutionStats {
+    pub analyzed: usize,
+    pub mev_checked: usize,
+    pub mev_detected: usize,
+    /// 成交不差于报价的笔数
+    pub at_or_better: usize,
+    /// 平均滑点消耗（占容忍度的百分比）
+    pub avg_slippage_consumed_pct: f64,
+}
+
+pub fn stats(reports: &[ExecutionReport]) -> ExecutionStats {
+    if reports.is_empty() {
+        return ExecutionStats::default();
+    }
+    let consumed: f64 = reports.iter().map(|r| r.slippage_consumed_pct()).sum();
+    ExecutionStats {
+        analyzed: reports.len(),
+        mev_checked: reports.iter().filter(|r| r.mev_checked).count(),
+        mev_detected: reports.iter().filter(|r| r.mev_detected()).count(),
+        at_or_better: reports.iter().filter(|r| r.shortfall_pct <= 0.0).count(),
+        avg_slippage_consumed_pct: consumed / reports.len() as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_tx(index: u32, sender: &str, token_in: &str, token_out: &str) -> PoolTx {
+        PoolTx {
+            tx_hash: format!("0x{}", index),
+            tx_index: index,
+            sender: sender.to_string(),
+            token_in: token_in.to_string(),
+            token_out: token_out.to_string(),
+            amount_in: None,
+            amount_out: None,
+        }
+    }
+
+    fn activity(transactions: Vec<PoolTx>) -> PoolActivityResponse {
+        PoolActivityResponse {
+            block_number: 100,
+            pool_address: None,
+            user_tx_index: 5,
+            transactions,
+        }
+    }
+
+    fn swap_item(to_amount: &str) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            id: "swap-1".to_string(),
+            tx_type: "swap".to_string(),
+            status: "completed".to_string(),
+            from_token: "ETH".to_string(),
+            to_token: "USDC".to_string(),
+            from_amount: "1".to_string(),
+            to_amount: to_amount.to_string(),
+            fee_amount: None,
+            gas_fee: None,
+            tx_hash: Some("0xuser".to_string()),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            completed_at: None,
+            fiat_order_id: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn detects_front_and_back_run_by_same_sender() {
+        let sandwiched = activity(vec![
+            pool_tx(4, "0xbot", "ETH", "USDC"),
+            pool_tx(6, "0xBOT", "USDC", "ETH"),
+        ]);
+        let found = detect_sandwich(&sandwiched, "eth", "usdc").unwrap();
+        assert_eq!(found.front_run_tx, "0x4");
+        assert_eq!(found.back_run_tx, "0x6");
+    }
+
+    #[test]
+    fn ignores_unrelated_pool_activity() {
+        // 不同发送方、方向不符或顺序不符都不算夹子攻击
+        let benign = activity(vec![
+            pool_tx(4, "0xa", "ETH", "USDC"),
+            pool_tx(6, "0xb", "USDC", "ETH"),
+            pool_tx(3, "0xc", "USDC", "ETH"),
+            pool_tx(7, "0xc", "ETH", "USDC"),
+            pool_tx(8, "0xa", "ETH", "USDC"),
+        ]);
+        assert!(detect_sandwich(&benign, "ETH", "USDC").is_none());
+    }
+
+    #[test]
+    fn report_measures_shortfall_against_quote() {
+        let quote = QuoteSnapshot {
+            expected_output: "2000".to_string(),
+            slippage_pct: 1.0,
+            mev_protected: None,
+        };
+        let sandwiched = activity(vec![
+            pool_tx(1, "0xbot", "ETH", "USDC"),
+            pool_tx(9, "0xbot", "USDC", "ETH"),
+        ]);
+        let report = build_report(&swap_item("1990"), &quote, Some(&sandwiched)).unwrap();
+        assert!((report.shortfall_pct - 0.5).abs() < 1e-9);
+        assert!((report.slippage_consumed_pct() - 50.0).abs() < 1e-9);
+        assert_eq!(report.estimated_mev_loss(), Some(10.0));
+
+        let clean = build_report(&swap_item("2004"), &quote, None).unwrap();
+        assert!(!clean.mev_checked);
+        assert_eq!(clean.slippage_consumed_pct(), 0.0);
+        assert_eq!(clean.estimated_mev_loss(), None);
+
+        let summary = stats(&[report, clean]);
+        assert_eq!(summary.analyzed, 2);
+        assert_eq!(summary.mev_detected, 1);
+        assert_eq!(summary.at_or_better, 1);
+        assert!((summary.avg_slippage_consumed_pct - 25.0).abs() < 1e-9);
+
+        assert!(build_report(&swap_item("0"), &quote, None).is_none());
+    }
+
+    #[test]
+    fn entries_evict_oldest() {
+        let mut entries = Entries::default();
+        for i in 0..=MAX_ENTRIES {
+            entries.insert(&i.to_string(), i);
+        }
+        assert_eq!(entries.items.len(), MAX_ENTRIES);
+        assert!(!entries.items.contains_key("0"));
+    }
+}
@@ -0,0 +1,312 @@
+//! Swap History - 兑换历史状态与重试
+//!
+//! 把后端的原始状态字符串归并为四种展示状态，提供失败兑换的重试参数，
+//! 以及用状态接口结果就地更新单行记录（无需重新拉取整页）。
+
+use crate::features::swap::{dust, wrap};
+use crate::services::swap::SwapStatusResponse;
+use crate::services::transaction_history::TransactionHistoryItem;
+use crate::shared::paginated_query::PagedQuery;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+/// 未完成的兑换自动刷新间隔
+pub const PENDING_REFRESH_INTERVAL_MS: u32 = 10_000;
+
+/// 交易历史每页数量
+pub const PAGE_SIZE: u32 = 10;
+
+/// 从其他页面跳转到兑换页时待预填的参数（读取一次后删除）
+const PENDING_PREFILL_KEY: &str = "swap_pending_prefill";
+
+/// 兑换展示状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapHistoryStatus {
+    Pending,
+    Executing,
+    Confirmed,
+    Failed,
+}
+
+impl SwapHistoryStatus {
+    /// 从后端状态字符串归并（兼容历史接口与兑换状态接口的不同取值）
+    pub fn from_status(status: &str) -> Option<Self> {
+        match status.to_lowercase().as_str() {
+            "pending" | "created" | "quoted" => Some(Self::Pending),
+            "processing" | "executing" | "submitted" | "broadcast" => Some(Self::Executing),
+            "completed" | "confirmed" | "success" => Some(Self::Confirmed),
+            "failed" | "cancelled" | "reverted" | "expired" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pending => "待处理",
+            Self::Executing => "执行中",
+            Self::Confirmed => "已确认",
+            Self::Failed => "失败",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            Self::Pending => "#F59E0B",   // 警告橙
+            Self::Executing => "#3B82F6", // 蓝色
+            Self::Confirmed => "#10B981", // 成功绿
+            Self::Failed => "#EF4444",    // 错误红
+        }
+    }
+
+    /// 仍在进行中（需要自动刷新）
+    pub fn is_in_flight(&self) -> bool {
+        matches!(self, Self::Pending | Self::Executing)
+    }
+}
+
+/// 预填到兑换表单的参数（重试失败兑换，或从价格关注列表发起买入/兑换）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwapRetryRequest {
+    pub from_token: String,
+    pub to_token: String,
+    pub amount: String,
+    pub network: Option<String>,
+}
+
+/// 记录跳转到兑换页后需要预填的参数
+pub fn set_pending_prefill(request: &SwapRetryRequest) {
+    let _ = LocalStorage::set(PENDING_PREFILL_KEY, request);
+}
+
+/// 取出待预填的参数（只生效一次）
+pub fn take_pending_prefill() -> Option<SwapRetryRequest> {
+    let request = LocalStorage::get::<SwapRetryRequest>(PENDING_PREFILL_KEY).ok();
+    LocalStorage::delete(PENDING_PREFILL_KEY);
+    request
+}
+
+/// 单笔兑换（不含小额归集的合并记录）
+pub fn is_single_swap(item: &TransactionHistoryItem) -> bool {
+    item.tx_type == "swap" && !dust::is_sweep_group(item)
+}
+
+/// 需要轮询状态接口的兑换记录（归集批次为合并记录、直接发起的包装/解包只有本地记录，都没有兑换 ID）
+pub fn needs_status_refresh(item: &TransactionHistoryItem) -> bool {
+    is_single_swap(item)
+        && !wrap::is_local_record(item)
+        && SwapHistoryStatus::from_status(&item.status)
+            .map(|s| s.is_in_flight())
+            .unwrap_or(false)
+}
+
+/// 失败兑换的重试参数
+pub fn retry_request(item: &TransactionHistoryItem) -> Option<SwapRetryRequest> {
+    if !is_single_swap(item)
+        || SwapHistoryStatus::from_status(&item.status) != Some(SwapHistoryStatus::Failed)
+    {
+        return None;
+    }
+    Some(SwapRetryRequest {
+        from_token: item.from_token.clone(),
+        to_token: item.to_token.clone(),
+        amount: item.from_amount.clone(),
+        network: metadata_str(item, "network"),
+    })
+}
+
+/// 用状态接口结果更新单行记录，返回是否有变化
+pub fn apply_status_update(item: &mut TransactionHistoryItem, status: &SwapStatusResponse) -> bool {
+    let before = item.clone();
+    item.status = status.status.clone();
+    if status.tx_hash.is_some() {
+        item.tx_hash = status.tx_hash.clone();
+    }
+    if let Some(to_amount) = &status.to_amount {
+        item.to_amount = to_amount.clone();
+    }
+    if status.gas_used.is_some() {
+        item.gas_fee = status.gas_used.clone();
+    }
+    let metadata = item.metadata.get_or_insert_with(|| serde_json::json!({}));
+    if let Some(map) = metadata.as_object_mut() {
+        map.insert(
+            "confirmations".to_string(),
+            serde_json::json!(status.confirmations),
+        );
+        map.entry("network")
+            .or_insert_with(|| serde_json::json!(status.network));
+    }
+    *item != before
+}
+
+/// 读取 metadata 中的字符串字段
+pub fn metadata_str(item: &TransactionHistoryItem, key: &str) -> Option<String> {
+    item.metadata
+        .as_ref()
+        .and_then(|m| m.get(key))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+/// 兑换路由描述（后端 metadata 中的 route/protocols 字段）
+pub fn route_summary(item: &TransactionHistoryItem) -> Option<String> {
+    let metadata = item.metadata.as_ref()?;
+    if let Some(route) = metadata.get("route").and_then(|v| v.as_str()) {
+        return Some(route.to_string());
+    }
+    let protocols: Vec<&str> = metadata
+        .get("protocols")
+        .and_then(|v| v.as_array())?
+        .iter()
+        .filter_map(|p| p.as_str())
+        .collect();
+    (!protocols.is_empty()).then(|| protocols.join(" → "))
+}
+
+/// 确认数
+pub fn confirmations(item: &TransactionHistoryItem) -> Option<u64> {
+    item.metadata
+        .as_ref()
+        .and_then(|m| m.get("confirmations"))
+        .and_then(|v| v.as_u64())
+}
+
+/// 交易历史的查询条件（类型、状态筛选与页码）
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryQuery {
+    /// "swap" / "onramp" / "offramp"，None 表示全部
+    pub tx_type: Option<String>,
+    /// None 表示全部
+    pub status: Option<String>,
+    pub page: u32,
+}
+
+impl Default for HistoryQuery {
+    fn default() -> Self {
+        Self {
+            tx_type: None,
+            status: None,
+            page: 1,
+        }
+    }
+}
+
+impl HistoryQuery {
+    /// 切换类型筛选（回到第一页）
+    pub fn set_type(&mut self, tx_type: Option<&str>) {
+        self.tx_type = tx_type.map(str::to_string);
+        self.page = 1;
+    }
+
+    /// 切换状态筛选（回到第一页）
+    pub fn set_status(&mut self, status: Option<&str>) {
+        self.status = status.map(str::to_string);
+        self.page = 1;
+    }
+}
+
+impl PagedQuery for HistoryQuery {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page.max(1);
+    }
+
+    fn cache_key(&self, prefix: &str) -> String {
+        format!(
+            "{}:{}:{}:page:{}",
+            prefix,
+            self.tx_type.as_deref().unwrap_or("all"),
+            self.status.as_deref().unwrap_or("all"),
+            self.page
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(status: &str) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            id: "swap-1".to_string(),
+            tx_type: "swap".to_string(),
+            status: status.to_string(),
+            from_token: "ETH".to_string(),
+            to_token: "USDT".to_string(),
+            from_amount: "0.5".to_string(),
+            to_amount: "0".to_string(),
+            fee_amount: None,
+            gas_fee: None,
+            tx_hash: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            completed_at: None,
+            fiat_order_id: None,
+            metadata: Some(serde_json::json!({ "network": "ethereum" })),
+        }
+    }
+
+    #[test]
+    fn status_strings_map_to_chips() {
+        assert_eq!(
+            SwapHistoryStatus::from_status("processing"),
+            Some(SwapHistoryStatus::Executing)
+        );
+        assert_eq!(
+            SwapHistoryStatus::from_status("Confirmed"),
+            Some(SwapHistoryStatus::Confirmed)
+        );
+        assert_eq!(
+            SwapHistoryStatus::from_status("cancelled"),
+            Some(SwapHistoryStatus::Failed)
+        );
+        assert_eq!(SwapHistoryStatus::from_status("weird"), None);
+    }
+
+    #[test]
+    fn only_failed_single_swaps_are_retryable() {
+        let retry = retry_request(&item("failed")).unwrap();
+        assert_eq!(retry.amount, "0.5");
+        assert_eq!(retry.network.as_deref(), Some("ethereum"));
+        assert!(retry_request(&item("completed")).is_none());
+
+        let mut onramp = item("failed");
+        onramp.tx_type = "onramp".to_string();
+        assert!(retry_request(&onramp).is_none());
+
+        let mut sweep = item("failed");
+        sweep.id = "dust-sweep:batch".to_string();
+        assert!(retry_request(&sweep).is_none());
+        assert!(!needs_status_refresh(&sweep));
+    }
+
+    #[test]
+    fn status_update_refreshes_row_in_place() {
+        let mut row = item("pending");
+        assert!(needs_status_refresh(&row));
+
+        let status = SwapStatusResponse {
+            swap_id: "swap-1".to_string(),
+            status: "confirmed".to_string(),
+            from_token: "ETH".to_string(),
+            to_token: "USDT".to_string(),
+            from_amount: "0.5".to_string(),
+            to_amount: Some("1500".to_string()),
+            network: "ethereum".to_string(),
+            tx_hash: Some("0xabc".to_string()),
+            gas_used: None,
+            confirmations: 12,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        assert!(apply_status_update(&mut row, &status));
+        assert_eq!(row.to_amount, "1500");
+        assert_eq!(row.tx_hash.as_deref(), Some("0xabc"));
+        assert_eq!(confirmations(&row), Some(12));
+        assert!(!needs_status_refresh(&row));
+        assert!(!apply_status_update(&mut row, &status));
+    }
+}
@@ -0,0 +1,404 @@
+//! Limit Order Edit - 限价单就地修改
+//!
+//! 服务端支持时原子修改挂单的价格与数量；不支持时先取消原订单，确认取消后再按新参数重建，
+//! 重建失败则保留取消结果并提示用户重新下单。每次修改都记入订单事件时间线（本设备保存），
+//! 重建出的新订单继承原订单的时间线。
+
+use crate::features::swap::limit_expiry;
+use crate::services::limit_order::{
+    LimitOrderResponse, LimitOrderService, LimitOrderType, UPDATE_UNSUPPORTED,
+};
+use chrono::{DateTime, Utc};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const STORAGE_KEY: &str = "limit_order_events";
+/// 单个订单保留的事件数量上限
+const MAX_EVENTS_PER_ORDER: usize = 50;
+/// 重建订单时无法得知剩余有效期所使用的天数
+const DEFAULT_RECREATE_EXPIRY_DAYS: u32 = 7;
+
+/// 时间线事件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitOrderEventKind {
+    Created,
+    /// 服务端原子修改
+    Edited,
+    /// 取消后按新参数重建（记录在新订单上）
+    Replaced,
+    /// 已取消原订单但重建失败
+    RecreateFailed,
+    Cancelled,
+    Expired,
+}
+
+impl LimitOrderEventKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Created => "创建订单",
+            Self::Edited => "修改订单",
+            Self::Replaced => "取消并重建",
+            Self::RecreateFailed => "重建失败",
+            Self::Cancelled => "已取消",
+            Self::Expired => "已过期",
+        }
+    }
+}
+
+/// 订单时间线中的一条事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LimitOrderEvent {
+    pub kind: LimitOrderEventKind,
+    /// 发生时间（RFC3339）
+    pub at: String,
+    #[serde(default)]
+    pub detail: String,
+}
+
+impl LimitOrderEvent {
+    pub fn new(kind: LimitOrderEventKind, now: DateTime<Utc>, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            at: now.to_rfc3339(),
+            detail: detail.into(),
+        }
+    }
+}
+
+/// 可以修改的挂单（部分成交的订单不支持修改）
+pub fn can_edit(order: &LimitOrderResponse) -> bool {
+    order.status == "pending"
+}
+
+fn positive(value: &str) -> bool {
+    value
+        .trim()
+        .parse::<f64>()
+        .is_ok_and(|v| v.is_finite() && v > 0.0)
+}
+
+/// 校验修改参数：订单仍在挂单中、价格与数量为正且至少有一项变化
+pub fn validate_edit(
+    order: &LimitOrderResponse,
+    new_price: &str,
+    new_amount: &str,
+) -> Result<(), String> {
+    if !can_edit(order) {
+        return Err("该限价单已不在挂单中，无法修改".to_string());
+    }
+    if !positive(new_amount) {
+        return Err("数量必须大于0".to_string());
+    }
+    if !positive(new_price) {
+        return Err("限价必须大于0".to_string());
+    }
+    if new_price.trim() == order.limit_price.trim() && new_amount.trim() == order.amount.trim() {
+        return Err("价格和数量均未变化".to_string());
+    }
+    Ok(())
+}
+
+/// 修改内容说明，例如“价格 2500 → 2600，数量 100 → 120”
+pub fn change_summary(order: &LimitOrderResponse, new_price: &str, new_amount: &str) -> String {
+    let mut parts = Vec::new();
+    if new_price.trim() != order.limit_price.trim() {
+        parts.push(format!("价格 {} → {}", order.limit_price, new_price.trim()));
+    }
+    if new_amount.trim() != order.amount.trim() {
+        parts.push(format!("数量 {} → {}", order.amount, new_amount.trim()));
+    }
+    parts.join("，")
+}
+
+/// 重建订单使用的有效期：保留原订单剩余天数（向上取整，1-365 天）
+pub fn remaining_expiry_days(order: &LimitOrderResponse, now: DateTime<Utc>) -> u32 {
+    let Some(expires) = limit_expiry::expires_at(order) else {
+        return DEFAULT_RECREATE_EXPIRY_DAYS;
+    };
+    let remaining = (expires - now).num_seconds().max(0);
+    let days = (remaining + 86_399) / 86_400;
+    days.clamp(1, 365) as u32
+}
+
+/// 修改结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditOutcome {
+    /// 服务端原子修改成功，订单ID不变
+    Updated(LimitOrderResponse),
+    /// 原订单已取消，新订单已按新参数创建
+    Replaced {
+        previous_id: String,
+        order: LimitOrderResponse,
+    },
+    /// 原订单已取消，但新订单创建失败（需要用户重新下单）
+    RecreateFailed { previous_id: String, error: String },
+}
+
+/// 修改挂单：优先原子修改，不支持时取消确认后重建
+///
+/// 取消失败或无法确认已取消时原订单保持不变并返回错误
+pub async fn edit_order(
+    service: &LimitOrderService,
+    order: &LimitOrderResponse,
+    new_price: &str,
+    new_amount: &str,
+    network: &str,
+    wallet_id: Option<&str>,
+) -> Result<EditOutcome, String> {
+    validate_edit(order, new_price, new_amount)?;
+    let new_price = new_price.trim();
+    let new_amount = new_amount.trim();
+
+    match service
+        .update_order(&order.order_id, new_price, new_amount)
+        .await
+    {
+        Ok(updated) => return Ok(EditOutcome::Updated(updated)),
+        Err(e) if e != UPDATE_UNSUPPORTED => return Err(e),
+        Err(_) => {}
+    }
+
+    let cancelled = service
+        .cancel_order(&order.order_id)
+        .await
+        .map_err(|e| format!("取消原订单失败，订单未修改：{}", e))?;
+    // 取消接口未直接返回已取消状态时再查询一次，确认后才重建
+    let confirmed = cancelled.status == "cancelled"
+        || service
+            .get_order(&order.order_id)
+            .await
+            .is_ok_and(|o| o.status == "cancelled");
+    if !confirmed {
+        return Err("无法确认原订单已取消，未重建订单，请刷新后查看".to_string());
+    }
+
+    let order_type = if order.order_type.eq_ignore_ascii_case("sell") {
+        LimitOrderType::Sell
+    } else {
+        LimitOrderType::Buy
+    };
+    match service
+        .create_order(
+            order_type,
+            &order.from_token,
+            &order.to_token,
+            new_amount,
+            new_price,
+            network,
+            remaining_expiry_days(order, Utc::now()),
+            wallet_id,
+        )
+        .await
+    {
+        Ok(created) => Ok(EditOutcome::Replaced {
+            previous_id: order.order_id.clone(),
+            order: created,
+        }),
+        Err(error) => Ok(EditOutcome::RecreateFailed {
+            previous_id: order.order_id.clone(),
+            error,
+        }),
+    }
+}
+
+/// 各订单在本设备记录的事件（键为订单ID）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderTimelines {
+    #[serde(default)]
+    pub entries: HashMap<String, Vec<LimitOrderEvent>>,
+}
+
+impl OrderTimelines {
+    pub fn load() -> Self {
+        LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    pub fn events(&self, order_id: &str) -> &[LimitOrderEvent] {
+        self.entries.get(order_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn record(&mut self, order_id: &str, event: LimitOrderEvent) {
+        let events = self.entries.entry(order_id.to_string()).or_default();
+        events.push(event);
+        if events.len() > MAX_EVENTS_PER_ORDER {
+            events.drain(..events.len() - MAX_EVENTS_PER_ORDER);
+        }
+    }
+
+    /// 记录修改结果；重建出的新订单继承原订单的完整时间线
+    pub fn record_outcome(
+        &mut self,
+        order: &LimitOrderResponse,
+        outcome: &EditOutcome,
+        summary: &str,
+        now: DateTime<Utc>,
+    ) {
+        match outcome {
+            EditOutcome::Updated(_) => self.record(
+                &order.order_id,
+                LimitOrderEvent::new(LimitOrderEventKind::Edited, now, summary),
+            ),
+            EditOutcome::Replaced {
+                previous_id,
+                order: created,
+            } => {
+                self.record(
+                    previous_id,
+                    LimitOrderEvent::new(
+                        LimitOrderEventKind::Cancelled,
+                        now,
+                        format!("修改时取消，已由订单 {} 替代", created.order_id),
+                    ),
+                );
+                let mut inherited = timeline(order, self.events(previous_id));
+                inherited.push(LimitOrderEvent::new(
+                    LimitOrderEventKind::Replaced,
+                    now,
+                    format!("由订单 {} 取消重建：{}", previous_id, summary),
+                ));
+                self.entries.insert(created.order_id.clone(), inherited);
+            }
+            EditOutcome::RecreateFailed { previous_id, error } => self.record(
+                previous_id,
+                LimitOrderEvent::new(
+                    LimitOrderEventKind::RecreateFailed,
+                    now,
+                    format!("原订单已取消，按新参数（{}）重建失败：{}", summary, error),
+                ),
+            ),
+        }
+    }
+}
+
+/// 订单的完整时间线：本设备无记录时以订单创建时间作为起点
+pub fn timeline(order: &LimitOrderResponse, recorded: &[LimitOrderEvent]) -> Vec<LimitOrderEvent> {
+    let mut events = Vec::with_capacity(recorded.len() + 1);
+    if recorded
+        .first()
+        .is_none_or(|e| e.kind != LimitOrderEventKind::Created)
+    {
+        events.push(LimitOrderEvent {
+            kind: LimitOrderEventKind::Created,
+            at: order.created_at.clone(),
+            detail: format!(
+                "{} {} {} @ {}",
+                order.order_type, order.amount, order.from_token, order.limit_price
+            ),
+        });
+    }
+    events.extend_from_slice(recorded);
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(id: &str, status: &str) -> LimitOrderResponse {
+        LimitOrderResponse {
+            order_id: id.to_string(),
+            order_type: "buy".to_string(),
+            from_token: "USDT".to_string(),
+            to_token: "ETH".to_string(),
+            amount: "100".to_string(),
+            limit_price: "2500".to_string(),
+            status: status.to_string(),
+            filled_amount: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            expires_at: Some("2026-01-08T00:00:00Z".to_string()),
+            message: None,
+        }
+    }
+
+    fn at(raw: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(raw)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn edit_requires_open_order_and_a_change() {
+        let open = order("lo-1", "pending");
+        assert!(validate_edit(&open, "2600", "100").is_ok());
+        assert!(validate_edit(&open, " 2500 ", "100").is_err());
+        assert!(validate_edit(&open, "0", "100").is_err());
+        assert!(validate_edit(&open, "2600", "abc").is_err());
+        assert!(validate_edit(&order("lo-2", "partially_filled"), "2600", "100").is_err());
+        assert_eq!(
+            change_summary(&open, "2600", "120"),
+            "价格 2500 → 2600，数量 100 → 120"
+        );
+        assert_eq!(change_summary(&open, "2500", "120"), "数量 100 → 120");
+    }
+
+    #[test]
+    fn recreate_keeps_remaining_expiry() {
+        let o = order("lo-1", "pending");
+        assert_eq!(remaining_expiry_days(&o, at("2026-01-05T12:00:00Z")), 3);
+        assert_eq!(remaining_expiry_days(&o, at("2026-01-09T00:00:00Z")), 1);
+        let mut no_expiry = o.clone();
+        no_expiry.expires_at = None;
+        assert_eq!(
+            remaining_expiry_days(&no_expiry, at("2026-01-05T00:00:00Z")),
+            DEFAULT_RECREATE_EXPIRY_DAYS
+        );
+    }
+
+    #[test]
+    fn outcomes_are_recorded_in_timeline() {
+        let now = at("2026-01-02T00:00:00Z");
+        let original = order("lo-1", "pending");
+        let mut timelines = OrderTimelines::default();
+
+        let mut updated = original.clone();
+        updated.limit_price = "2600".to_string();
+        timelines.record_outcome(
+            &original,
+            &EditOutcome::Updated(updated.clone()),
+            "价格 2500 → 2600",
+            now,
+        );
+        let kinds: Vec<_> = timeline(&updated, timelines.events("lo-1"))
+            .iter()
+            .map(|e| e.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![LimitOrderEventKind::Created, LimitOrderEventKind::Edited]
+        );
+
+        let replacement = order("lo-2", "pending");
+        timelines.record_outcome(
+            &updated,
+            &EditOutcome::Replaced {
+                previous_id: "lo-1".to_string(),
+                order: replacement.clone(),
+            },
+            "数量 100 → 120",
+            now,
+        );
+        let kinds: Vec<_> = timeline(&replacement, timelines.events("lo-2"))
+            .iter()
+            .map(|e| e.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LimitOrderEventKind::Created,
+                LimitOrderEventKind::Edited,
+                LimitOrderEventKind::Cancelled,
+                LimitOrderEventKind::Replaced,
+            ]
+        );
+        assert_eq!(
+            timelines.events("lo-1").last().map(|e| e.kind),
+            Some(LimitOrderEventKind::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Limit Order Expiry - 限价单到期倒计时
+//!
+//! 解析后端返回的 `expires_at`，生成倒计时/到期日期文案，
+//! 在页面打开期间把已到期的挂单就地标记为过期，并计算延长有效期后的到期时间。
+
+use crate::services::limit_order::LimitOrderResponse;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+/// 24 小时内到期的挂单需要突出显示
+pub const EXPIRING_SOON_SECS: i64 = 24 * 3600;
+
+/// 超过该时长的到期时间显示绝对日期而不是倒计时
+pub const COUNTDOWN_HORIZON_SECS: i64 = 7 * 24 * 3600;
+
+/// “延长有效期”每次延长的天数
+pub const EXTEND_DAYS: u32 = 7;
+
+/// 到期紧急程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryUrgency {
+    Normal,
+    Soon,
+    Expired,
+}
+
+/// 仍在挂单中（可能到期）的订单
+pub fn is_open(order: &LimitOrderResponse) -> bool {
+    matches!(order.status.as_str(), "pending" | "partially_filled")
+}
+
+/// 解析到期时间（兼容 RFC3339 与不带时区的 UTC 时间）
+pub fn expires_at(order: &LimitOrderResponse) -> Option<DateTime<Utc>> {
+    let raw = order.expires_at.as_deref()?.trim();
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|dt| dt.and_utc())
+        })
+}
+
+/// 挂单的到期紧急程度（非挂单或无到期时间返回 None）
+pub fn urgency(order: &LimitOrderResponse, now: DateTime<Utc>) -> Option<ExpiryUrgency> {
+    if !is_open(order) {
+        return None;
+    }
+    let remaining = (expires_at(order)? - now).num_seconds();
+    Some(if remaining <= 0 {
+        ExpiryUrgency::Expired
+    } else if remaining <= EXPIRING_SOON_SECS {
+        ExpiryUrgency::Soon
+    } else {
+        ExpiryUrgency::Normal
+    })
+}
+
+/// 到期文案：7 天内显示倒计时，更远显示到期日期
+pub fn expiry_label(order: &LimitOrderResponse, now: DateTime<Utc>) -> Option<String> {
+    if !is_open(order) {
+        return None;
+    }
+    let expires = expires_at(order)?;
+    let remaining = (expires - now).num_seconds();
+    if remaining <= 0 {
+        return Some("已到期".to_string());
+    }
+    if remaining > COUNTDOWN_HORIZON_SECS {
+        return Some(format!("到期: {}", expires.format("%Y-%m-%d %H:%M UTC")));
+    }
+    let days = remaining / 86_400;
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        remaining % 86_400 / 3600,
+        remaining % 3600 / 60,
+        remaining % 60
+    );
+    Some(if days > 0 {
+        format!("剩余 {}天 {}", days, clock)
+    } else {
+        format!("剩余 {}", clock)
+    })
+}
+
+/// 把已到期的挂单标记为过期，返回本次新过期的订单ID
+pub fn expire_due_orders(orders: &mut [LimitOrderResponse], now: DateTime<Utc>) -> Vec<String> {
+    orders
+        .iter_mut()
+        .filter(|order| urgency(order, now) == Some(ExpiryUrgency::Expired))
+        .map(|order| {
+            order.status = "expired".to_string();
+            order.order_id.clone()
+        })
+        .collect()
+}
+
+/// 延长有效期后的到期时间（从当前到期时间与现在中较晚者开始计算）
+pub fn extended_expires_at(order: &LimitOrderResponse, days: u32, now: DateTime<Utc>) -> String {
+    let base = expires_at(order).map_or(now, |expires| expires.max(now));
+    (base + Duration::days(i64::from(days))).to_rfc3339()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(status: &str, expires_at: Option<&str>) -> LimitOrderResponse {
+        LimitOrderResponse {
+            order_id: "lo-1".to_string(),
+            order_type: "buy".to_string(),
+            from_token: "USDT".to_string(),
+            to_token: "ETH".to_string(),
+            amount: "100".to_string(),
+            limit_price: "2500".to_string(),
+            status: status.to_string(),
+            filled_amount: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            expires_at: expires_at.map(|s| s.to_string()),
+            message: None,
+        }
+    }
+
+    fn at(raw: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(raw)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn label_switches_between_countdown_and_date() {
+        let now = at("2026-01-01T00:00:00Z");
+        let soon = order("pending", Some("2026-01-01T05:04:03Z"));
+        assert_eq!(expiry_label(&soon, now).as_deref(), Some("剩余 05:04:03"));
+        assert_eq!(urgency(&soon, now), Some(ExpiryUrgency::Soon));
+
+        let days = order("pending", Some("2026-01-03T01:00:00Z"));
+        assert_eq!(
+            expiry_label(&days, now).as_deref(),
+            Some("剩余 2天 01:00:00")
+        );
+        assert_eq!(urgency(&days, now), Some(ExpiryUrgency::Normal));
+
+        let far = order("pending", Some("2026-02-01 08:30:00"));
+        assert_eq!(
+            expiry_label(&far, now).as_deref(),
+            Some("到期: 2026-02-01 08:30 UTC")
+        );
+
+        let filled = order("filled", Some("2026-01-01T05:00:00Z"));
+        assert_eq!(expiry_label(&filled, now), None);
+    }
+
+    #[test]
+    fn due_orders_are_marked_expired_once() {
+        let now = at("2026-01-02T00:00:00Z");
+        let mut orders = vec![
+            order("pending", Some("2026-01-01T23:59:59Z")),
+            order("pending", Some("2026-01-05T00:00:00Z")),
+            order("cancelled", Some("2026-01-01T00:00:00Z")),
+        ];
+        assert_eq!(expire_due_orders(&mut orders, now), vec!["lo-1"]);
+        assert_eq!(orders[0].status, "expired");
+        assert_eq!(orders[2].status, "cancelled");
+        assert!(expire_due_orders(&mut orders, now).is_empty());
+    }
+
+    #[test]
+    fn extension_starts_from_later_of_expiry_and_now() {
+        let now = at("2026-01-10T00:00:00Z");
+        let future = order("pending", Some("2026-01-12T00:00:00Z"));
+        assert_eq!(
+            at(&extended_expires_at(&future, 7, now)),
+            at("2026-01-19T00:00:00Z")
+        );
+        let lapsed = order("pending", Some("2026-01-01T00:00:00Z"));
+        assert_eq!(
+            at(&extended_expires_at(&lapsed, 7, now)),
+            at("2026-01-17T00:00:00Z")
+        );
+    }
+}
@@ -1,2 +1,18 @@
 // Swap feature module
 // Swap feature module - Production-ready implementation
+pub mod approvals;
+pub mod confirm_recheck;
+pub mod contract_call;
+pub mod dust;
+pub mod execution;
+pub mod history;
+pub mod limit_edit;
+pub mod limit_expiry;
+pub mod onramp_orders;
+pub mod payout;
+pub mod pending;
+pub mod region;
+pub mod routing;
+pub mod trade_size;
+pub mod withdrawal_eta;
+pub mod wrap;
@@ -0,0 +1,380 @@
+//! Onramp Orders - 法币充值订单的取消与支付超时
+//!
+//! 充值订单在服务商扣款前可以取消：尚未支付的订单可安全取消；支付处理中的订单
+//! 取消后服务商仍可能完成扣款（扣款会原路退回）。下单时记录服务商的支付时限，
+//! 超时仍未支付的订单显示为“已过期”并提醒一次。已取消、已过期的订单默认不在订单列表中显示。
+//!
+//! 未完成的支付会话保存在本地：关闭支付弹窗或标签页后，回到“购买”标签或在订单列表中
+//! 点击“继续支付”会先查询订单状态，再恢复到对应的支付步骤；已过期的订单可按原参数重新下单。
+
+use crate::services::fiat_onramp::{FiatOnrampService, FiatOrderResponse, FiatOrderStatus};
+use crate::shared::state::AppState;
+use chrono::{DateTime, Duration, Utc};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const STORAGE_KEY: &str = "onramp_payment_windows";
+/// 最近一笔未完成的支付会话
+const SESSION_KEY: &str = "onramp_payment_session";
+/// 订单列表中点击“继续支付”后待恢复的订单
+const RESUME_KEY: &str = "onramp_resume_request";
+/// 最多保留的记录数（超出时丢弃最早的）
+const MAX_RECORDS: usize = 100;
+/// 服务商未返回支付时限时的默认支付窗口（分钟）
+const DEFAULT_PAYMENT_WINDOW_MINUTES: i64 = 30;
+/// 支付超时检查间隔（毫秒）
+pub const EXPIRY_POLL_MS: u32 = 60_000;
+/// 恢复“处理中”订单后查询结果的间隔与次数（约 5 分钟）
+const SETTLE_POLL_MS: u32 = 5_000;
+const SETTLE_POLL_ATTEMPTS: u32 = 60;
+
+/// 一笔充值订单的支付时限
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentWindow {
+    pub order_id: String,
+    /// 支付截止时间（Unix 秒）
+    pub expires_at: i64,
+    pub fiat_amount: String,
+    /// 超时后已提醒过
+    #[serde(default)]
+    pub notified: bool,
+}
+
+/// 未完成的支付会话
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentSession {
+    pub order_id: String,
+    pub fiat_amount: String,
+    pub fiat_currency: String,
+    /// 购买的稳定币
+    pub stablecoin: String,
+    /// 支付方式（如 "credit_card"）
+    pub payment_method: String,
+    /// 支付截止时间（Unix 秒）
+    pub expires_at: i64,
+}
+
+/// 恢复支付时订单所处的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeStage {
+    /// 等待支付：重新打开支付弹窗
+    AwaitingPayment,
+    /// 支付处理中：打开弹窗并跟踪结果
+    Processing,
+    /// 已超过支付时限：提供按原参数重新下单
+    Expired,
+    /// 已完成、失败或取消：无需恢复
+    Closed,
+}
+
+/// 取消订单的风险
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelSafety {
+    /// 尚未支付，可安全取消
+    Unpaid,
+    /// 支付处理中，取消不一定能阻止扣款
+    PaymentInProgress,
+}
+
+impl CancelSafety {
+    /// 订单状态对应的取消风险；其他状态不可取消
+    pub fn for_status(status: &str) -> Option<Self> {
+        match status {
+            "pending" => Some(Self::Unpaid),
+            "processing" => Some(Self::PaymentInProgress),
+            _ => None,
+        }
+    }
+
+    pub fn is_safe(&self) -> bool {
+        matches!(self, Self::Unpaid)
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Unpaid => "取消订单",
+            Self::PaymentInProgress => "支付处理中，仍要取消？",
+        }
+    }
+
+    pub fn message(&self, order_id: &str) -> String {
+        match self {
+            Self::Unpaid => format!("订单 {} 尚未支付，取消后不会产生任何扣款。", order_id),
+            Self::PaymentInProgress => format!(
+                "订单 {} 的支付正在处理中，取消可能无法阻止本次扣款。",
+                order_id
+            ),
+        }
+    }
+
+    pub fn details(&self) -> Vec<String> {
+        match self {
+            Self::Unpaid => vec!["如需购买，可重新下单".to_string()],
+            Self::PaymentInProgress => vec![
+                "若服务商已完成扣款，款项将原路退回，通常需要 3–7 个工作日".to_string(),
+                "如已在支付页面完成付款，建议等待订单完成而不是取消".to_string(),
+            ],
+        }
+    }
+}
+
+/// 支付截止时间：优先使用服务商返回的 `expires_at`，否则按默认支付窗口计算
+pub fn payment_deadline(expires_at: Option<&str>, created_at: &str) -> i64 {
+    if let Some(at) = expires_at.and_then(|t| DateTime::parse_from_rfc3339(t.trim()).ok()) {
+        return at.timestamp();
+    }
+    let created_at = DateTime::parse_from_rfc3339(created_at.trim())
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+    (created_at + Duration::minutes(DEFAULT_PAYMENT_WINDOW_MINUTES)).timestamp()
+}
+
+/// 下单成功后记录支付时限
+pub fn record(order: &FiatOrderResponse) {
+    let window = PaymentWindow {
+        order_id: order.order_id.clone(),
+        expires_at: payment_deadline(order.expires_at.as_deref(), &order.created_at),
+        fiat_amount: order.fiat_amount.clone(),
+        notified: false,
+    };
+    let mut records = load();
+    records.retain(|r| r.order_id != window.order_id);
+    records.push(window);
+    if records.len() > MAX_RECORDS {
+        let overflow = records.len() - MAX_RECORDS;
+        records.drain(..overflow);
+    }
+    save(&records);
+}
+
+pub fn load() -> Vec<PaymentWindow> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+fn save(records: &[PaymentWindow]) {
+    let _ = LocalStorage::set(STORAGE_KEY, records);
+}
+
+pub fn find(order_id: &str) -> Option<PaymentWindow> {
+    load().into_iter().find(|r| r.order_id == order_id)
+}
+
+/// 订单已结束且不需要显示在默认订单列表中
+pub fn is_closed(status: &str) -> bool {
+    matches!(status, "cancelled" | "expired")
+}
+
+/// 结合支付时限的订单状态：超过时限仍未支付的订单视为已过期
+pub fn status_with_window<'a>(
+    status: &'a str,
+    window: Option<&PaymentWindow>,
+    now: i64,
+) -> &'a str {
+    match window {
+        Some(w) if status == "pending" && now >= w.expires_at => "expired",
+        _ => status,
+    }
+}
+
+/// 订单在界面上显示的状态
+pub fn effective_status(order_id: &str, status: &str, now: i64) -> String {
+    status_with_window(status, find(order_id).as_ref(), now).to_string()
+}
+
+/// 已超过支付时限、尚未提醒的订单
+pub fn due_expiries(records: &[PaymentWindow], now: i64) -> Vec<PaymentWindow> {
+    records
+        .iter()
+        .filter(|r| !r.notified && now >= r.expires_at)
+        .cloned()
+        .collect()
+}
+
+pub fn mark_notified(order_id: &str) {
+    let mut records = load();
+    if let Some(r) = records.iter_mut().find(|r| r.order_id == order_id) {
+        r.notified = true;
+        save(&records);
+    }
+}
+
+/// 订单已取消或已完成支付，不再需要跟踪支付时限
+pub fn forget(order_id: &str) {
+    let mut records = load();
+    let before = records.len();
+    records.retain(|r| r.order_id != order_id);
+    if records.len() != before {
+        save(&records);
+    }
+}
+
+/// 取消充值订单
+pub async fn cancel(app_state: AppState, order_id: &str) -> Result<(), String> {
+    FiatOnrampService::new(app_state)
+        .cancel_order(order_id)
+        .await?;
+    forget(order_id);
+    clear_session(order_id);
+    Ok(())
+}
+
+/// 下单后保存支付会话
+pub fn save_session(session: &PaymentSession) {
+    let _ = LocalStorage::set(SESSION_KEY, session);
+}
+
+pub fn active_session() -> Option<PaymentSession> {
+    LocalStorage::get(SESSION_KEY).ok()
+}
+
+/// 订单支付结束（完成、取消或过期）后清除会话
+pub fn clear_session(order_id: &str) {
+    if active_session().is_some_and(|s| s.order_id == order_id) {
+        LocalStorage::delete(SESSION_KEY);
+    }
+}
+
+/// 请求在兑换页“购买”标签中恢复该订单的支付
+pub fn request_resume(order_id: &str) {
+    let _ = LocalStorage::set(RESUME_KEY, order_id);
+}
+
+pub fn has_resume_request() -> bool {
+    LocalStorage::get::<String>(RESUME_KEY).is_ok()
+}
+
+/// 取出待恢复的订单（只生效一次）
+pub fn take_resume_request() -> Option<String> {
+    let order_id = LocalStorage::get::<String>(RESUME_KEY).ok();
+    LocalStorage::delete(RESUME_KEY);
+    order_id
+}
+
+/// 按订单当前状态重建支付会话；本地会话中的稳定币与支付方式优先（订单状态中没有这两项）
+pub fn session_from_status(
+    status: &FiatOrderStatus,
+    previous: Option<&PaymentSession>,
+) -> PaymentSession {
+    let expires_at = find(&status.order_id)
+        .map(|w| w.expires_at)
+        .or_else(|| previous.map(|s| s.expires_at))
+        .unwrap_or_else(|| payment_deadline(status.expires_at.as_deref(), &status.created_at));
+    PaymentSession {
+        order_id: status.order_id.clone(),
+        fiat_amount: status.fiat_amount.clone(),
+        fiat_currency: previous
+            .map(|s| s.fiat_currency.clone())
+            .unwrap_or_else(|| "USD".to_string()),
+        stablecoin: previous
+            .map(|s| s.stablecoin.clone())
+            .unwrap_or_else(|| "USDT".to_string()),
+        payment_method: previous
+            .map(|s| s.payment_method.clone())
+            .unwrap_or_else(|| "credit_card".to_string()),
+        expires_at,
+    }
+}
+
+/// 等待处理中的订单出结果，返回最终状态；超时仍在处理中时返回 "processing"
+pub async fn wait_until_settled(app_state: AppState, order_id: &str) -> Result<String, String> {
+    let service = FiatOnrampService::new(app_state);
+    for _ in 0..SETTLE_POLL_ATTEMPTS {
+        gloo_timers::future::TimeoutFuture::new(SETTLE_POLL_MS).await;
+        let status = service.get_order_status(order_id).await?;
+        if status.status != "processing" {
+            clear_session(order_id);
+            return Ok(status.status);
+        }
+    }
+    Ok("processing".to_string())
+}
+
+/// 订单当前状态对应的恢复阶段
+pub fn resume_stage(status: &str, expires_at: i64, now: i64) -> ResumeStage {
+    match status {
+        "pending" if now >= expires_at => ResumeStage::Expired,
+        "pending" => ResumeStage::AwaitingPayment,
+        "processing" => ResumeStage::Processing,
+        "expired" => ResumeStage::Expired,
+        _ => ResumeStage::Closed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(expires_at: i64, notified: bool) -> PaymentWindow {
+        PaymentWindow {
+            order_id: "o1".to_string(),
+            expires_at,
+            fiat_amount: "100".to_string(),
+            notified,
+        }
+    }
+
+    #[test]
+    fn cancel_safety_follows_payment_state() {
+        assert_eq!(
+            CancelSafety::for_status("pending"),
+            Some(CancelSafety::Unpaid)
+        );
+        assert_eq!(
+            CancelSafety::for_status("processing"),
+            Some(CancelSafety::PaymentInProgress)
+        );
+        assert_eq!(CancelSafety::for_status("completed"), None);
+        assert!(CancelSafety::Unpaid.is_safe());
+        assert!(!CancelSafety::PaymentInProgress.is_safe());
+    }
+
+    #[test]
+    fn unpaid_orders_expire_after_payment_window() {
+        let created = "2026-01-01T00:00:00Z";
+        let start = DateTime::parse_from_rfc3339(created).unwrap().timestamp();
+        assert_eq!(payment_deadline(None, created), start + 30 * 60);
+        assert_eq!(
+            payment_deadline(Some("2026-01-01T00:15:00Z"), created),
+            start + 15 * 60
+        );
+
+        let w = window(start + 60, false);
+        assert_eq!(status_with_window("pending", Some(&w), start), "pending");
+        assert_eq!(
+            status_with_window("pending", Some(&w), start + 60),
+            "expired"
+        );
+        assert_eq!(
+            status_with_window("processing", Some(&w), start + 60),
+            "processing"
+        );
+        assert_eq!(status_with_window("pending", None, start + 60), "pending");
+        assert!(is_closed("expired") && is_closed("cancelled") && !is_closed("failed"));
+
+        assert_eq!(due_expiries(std::slice::from_ref(&w), start).len(), 0);
+        assert_eq!(due_expiries(&[w], start + 60).len(), 1);
+        assert!(due_expiries(&[window(start, true)], start + 60).is_empty());
+    }
+
+    #[test]
+    fn resume_stage_depends_on_status_and_payment_window() {
+        let expires_at = 1_000;
+        assert_eq!(
+            resume_stage("pending", expires_at, 999),
+            ResumeStage::AwaitingPayment
+        );
+        assert_eq!(
+            resume_stage("pending", expires_at, 1_000),
+            ResumeStage::Expired
+        );
+        assert_eq!(
+            resume_stage("processing", expires_at, 5_000),
+            ResumeStage::Processing
+        );
+        assert_eq!(resume_stage("expired", expires_at, 0), ResumeStage::Expired);
+        assert_eq!(
+            resume_stage("completed", expires_at, 0),
+            ResumeStage::Closed
+        );
+    }
+}
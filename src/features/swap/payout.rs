@@ -0,0 +1,479 @@
+//! Payout Details - 提现收款信息
+//!
+//! 银行提现按收款国家选择字段：IBAN 国家填写 IBAN（BIC 可选），美国填写 ABA 路由号与账号，
+//! 其他国家填写 SWIFT/BIC 与账号；所有银行方式都需要开户人姓名。
+//! PayPal、支付宝、微信等方式仍使用单个账号字段。
+//!
+//! 校验通过并成功下单的收款方式保存在本地，下次提现可直接选用（列表中仅显示掩码）。
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const SAVED_PAYOUT_METHODS_KEY: &str = "saved_payout_methods";
+
+/// 使用 IBAN 的国家及其 IBAN 长度
+const IBAN_LENGTHS: [(&str, usize); 38] = [
+    ("AD", 24),
+    ("AE", 23),
+    ("AT", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("CH", 21),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DK", 18),
+    ("EE", 20),
+    ("ES", 24),
+    ("FI", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GR", 27),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IS", 26),
+    ("IT", 27),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("MC", 27),
+    ("MT", 31),
+    ("NL", 18),
+    ("NO", 15),
+    ("PL", 28),
+    ("PT", 25),
+    ("RO", 24),
+    ("SA", 24),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("TR", 26),
+];
+
+/// 收款国家下拉选项：(国家代码, 名称)
+pub const PAYOUT_COUNTRIES: [(&str, &str); 14] = [
+    ("US", "美国"),
+    ("GB", "英国"),
+    ("DE", "德国"),
+    ("FR", "法国"),
+    ("ES", "西班牙"),
+    ("IT", "意大利"),
+    ("NL", "荷兰"),
+    ("CH", "瑞士"),
+    ("CN", "中国"),
+    ("HK", "中国香港"),
+    ("SG", "新加坡"),
+    ("JP", "日本"),
+    ("AU", "澳大利亚"),
+    ("CA", "加拿大"),
+];
+
+/// 银行字段方案
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BankSchema {
+    /// IBAN（BIC 可选）
+    Iban,
+    /// 美国 ABA 路由号 + 账号
+    UsAch,
+    /// SWIFT/BIC + 账号
+    Swift,
+}
+
+impl BankSchema {
+    pub fn for_country(country: &str) -> Self {
+        let country = country.to_uppercase();
+        if country == "US" {
+            BankSchema::UsAch
+        } else if iban_length(&country).is_some() {
+            BankSchema::Iban
+        } else {
+            BankSchema::Swift
+        }
+    }
+}
+
+fn iban_length(country: &str) -> Option<usize> {
+    IBAN_LENGTHS
+        .iter()
+        .find(|(code, _)| *code == country)
+        .map(|(_, len)| *len)
+}
+
+fn compact(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// 校验 IBAN（国家长度 + mod-97 校验位），返回规范化后的 IBAN
+pub fn validate_iban(value: &str, country: &str) -> Result<String, String> {
+    let iban = compact(value);
+    if iban.len() < 4 || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err("IBAN 格式不正确".to_string());
+    }
+    let iban_country = &iban[..2];
+    if !iban_country.eq_ignore_ascii_case(country) {
+        return Err(format!("IBAN 国家代码应为 {}", country.to_uppercase()));
+    }
+    match iban_length(iban_country) {
+        Some(len) if len == iban.len() => {}
+        Some(len) => return Err(format!("{} 的 IBAN 应为 {} 位", iban_country, len)),
+        None => return Err("该国家不使用 IBAN".to_string()),
+    }
+
+    // 前四位移到末尾，字母转为数字（A=10 … Z=35），逐位取模
+    let rearranged = format!("{}{}", &iban[4..], &iban[..4]);
+    let mut remainder = 0u32;
+    for c in rearranged.chars() {
+        let digits = c
+            .to_digit(36)
+            .ok_or_else(|| "IBAN 格式不正确".to_string())?;
+        remainder = if digits >= 10 {
+            (remainder * 100 + digits) % 97
+        } else {
+            (remainder * 10 + digits) % 97
+        };
+    }
+    if remainder != 1 {
+        return Err("IBAN 校验位不正确，请检查是否输错".to_string());
+    }
+    Ok(iban)
+}
+
+/// 校验 SWIFT/BIC（8 或 11 位：银行代码 4 位字母 + 国家 2 位字母 + 地区 2 位 + 可选分行 3 位）
+pub fn validate_bic(value: &str) -> Result<String, String> {
+    let bic = compact(value);
+    let valid = bic.is_ascii()
+        && (bic.len() == 8 || bic.len() == 11)
+        && bic[..6].chars().all(|c| c.is_ascii_alphabetic())
+        && bic[6..].chars().all(|c| c.is_ascii_alphanumeric());
+    if valid {
+        Ok(bic)
+    } else {
+        Err("SWIFT/BIC 应为 8 或 11 位（例: DEUTDEFF）".to_string())
+    }
+}
+
+/// 校验美国 ABA 路由号（9 位数字 + 加权校验和）
+pub fn validate_aba_routing(value: &str) -> Result<String, String> {
+    let routing = compact(value);
+    let digits: Vec<u32> = routing.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() != 9 || routing.len() != 9 {
+        return Err("路由号应为 9 位数字".to_string());
+    }
+    let checksum = 3 * (digits[0] + digits[3] + digits[6])
+        + 7 * (digits[1] + digits[4] + digits[7])
+        + (digits[2] + digits[5] + digits[8]);
+    if !checksum.is_multiple_of(10) {
+        return Err("路由号校验失败，请检查是否输错".to_string());
+    }
+    Ok(routing)
+}
+
+/// 校验银行账号（美国 4-17 位数字，其他地区 5-34 位字母数字）
+pub fn validate_account_number(value: &str, schema: BankSchema) -> Result<String, String> {
+    let account = compact(value);
+    let valid = match schema {
+        BankSchema::UsAch => {
+            (4..=17).contains(&account.len()) && account.chars().all(|c| c.is_ascii_digit())
+        }
+        _ => {
+            (5..=34).contains(&account.len()) && account.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+    };
+    if valid {
+        Ok(account)
+    } else if schema == BankSchema::UsAch {
+        Err("账号应为 4-17 位数字".to_string())
+    } else {
+        Err("账号应为 5-34 位字母或数字".to_string())
+    }
+}
+
+/// 结构化银行收款信息
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BankDetails {
+    pub country: String,
+    pub holder_name: String,
+    #[serde(default)]
+    pub iban: String,
+    #[serde(default)]
+    pub swift_bic: String,
+    #[serde(default)]
+    pub routing_number: String,
+    #[serde(default)]
+    pub account_number: String,
+}
+
+impl BankDetails {
+    pub fn schema(&self) -> BankSchema {
+        BankSchema::for_country(&self.country)
+    }
+
+    /// 校验全部字段，返回规范化后的副本
+    pub fn validate(&self) -> Result<BankDetails, String> {
+        let holder_name = self.holder_name.trim();
+        if holder_name.chars().count() < 2 {
+            return Err("请输入开户人姓名".to_string());
+        }
+        if self.country.trim().is_empty() {
+            return Err("请选择收款国家".to_string());
+        }
+        let schema = self.schema();
+        let mut normalized = BankDetails {
+            country: self.country.trim().to_uppercase(),
+            holder_name: holder_name.to_string(),
+            ..Default::default()
+        };
+        match schema {
+            BankSchema::Iban => {
+                normalized.iban = validate_iban(&self.iban, &normalized.country)?;
+                if !self.swift_bic.trim().is_empty() {
+                    normalized.swift_bic = validate_bic(&self.swift_bic)?;
+                }
+            }
+            BankSchema::UsAch => {
+                normalized.routing_number = validate_aba_routing(&self.routing_number)?;
+                normalized.account_number = validate_account_number(&self.account_number, schema)?;
+            }
+            BankSchema::Swift => {
+                normalized.swift_bic = validate_bic(&self.swift_bic)?;
+                normalized.account_number = validate_account_number(&self.account_number, schema)?;
+            }
+        }
+        Ok(normalized)
+    }
+
+    /// 提交给后端的 recipient_info（仅包含当前方案使用的字段）
+    pub fn to_recipient_json(&self) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "account_type": "bank",
+            "country": self.country,
+            "account_holder_name": self.holder_name,
+        });
+        let fields = [
+            ("iban", &self.iban),
+            ("swift_bic", &self.swift_bic),
+            ("routing_number", &self.routing_number),
+            ("bank_account", &self.account_number),
+        ];
+        if let Some(map) = json.as_object_mut() {
+            for (key, value) in fields {
+                if !value.is_empty() {
+                    map.insert(key.to_string(), serde_json::json!(value));
+                }
+            }
+        }
+        json
+    }
+
+    /// 掩码显示（如 "DE89 •••• 3000"）
+    pub fn masked(&self) -> String {
+        let number = if self.iban.is_empty() {
+            &self.account_number
+        } else {
+            &self.iban
+        };
+        let tail: String = number
+            .chars()
+            .rev()
+            .take(4)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+        if self.iban.is_empty() {
+            format!("{} •••• {}", self.holder_name, tail)
+        } else {
+            format!("{} •••• {}", &self.iban[..4.min(self.iban.len())], tail)
+        }
+    }
+}
+
+/// 掩码显示单个账号标识（邮箱保留首字符与域名，其他保留末 4 位）
+pub fn mask_identifier(identifier: &str) -> String {
+    let identifier = identifier.trim();
+    if let Some((name, domain)) = identifier.split_once('@') {
+        let first = name.chars().next().unwrap_or('*');
+        return format!("{}•••@{}", first, domain);
+    }
+    let count = identifier.chars().count();
+    if count <= 4 {
+        return identifier.to_string();
+    }
+    let tail: String = identifier.chars().skip(count - 4).collect();
+    format!("•••• {}", tail)
+}
+
+/// 收款账户内容
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PayoutAccount {
+    Bank(BankDetails),
+    Identifier { value: String },
+}
+
+/// 已保存的收款方式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedPayoutMethod {
+    pub id: String,
+    /// 提现方式（bank_card / paypal / alipay …）
+    pub method: String,
+    pub account: PayoutAccount,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl SavedPayoutMethod {
+    pub fn masked(&self) -> String {
+        match &self.account {
+            PayoutAccount::Bank(details) => details.masked(),
+            PayoutAccount::Identifier { value } => mask_identifier(value),
+        }
+    }
+}
+
+/// 加入收款方式列表：相同账户只保留一条；同一提现方式的第一条自动设为默认
+pub fn upsert_method(methods: &mut Vec<SavedPayoutMethod>, method: &str, account: PayoutAccount) {
+    if methods
+        .iter()
+        .any(|m| m.method == method && m.account == account)
+    {
+        return;
+    }
+    let is_default = !methods.iter().any(|m| m.method == method);
+    methods.push(SavedPayoutMethod {
+        id: uuid::Uuid::new_v4().to_string(),
+        method: method.to_string(),
+        account,
+        is_default,
+    });
+}
+
+/// 设为默认（同一提现方式只有一个默认）
+pub fn set_default(methods: &mut [SavedPayoutMethod], id: &str) {
+    let Some(method) = methods
+        .iter()
+        .find(|m| m.id == id)
+        .map(|m| m.method.clone())
+    else {
+        return;
+    };
+    for saved in methods.iter_mut().filter(|m| m.method == method) {
+        saved.is_default = saved.id == id;
+    }
+}
+
+/// 删除；若删除的是默认项，则把同方式的下一条设为默认
+pub fn remove_method(methods: &mut Vec<SavedPayoutMethod>, id: &str) {
+    let Some(index) = methods.iter().position(|m| m.id == id) else {
+        return;
+    };
+    let removed = methods.remove(index);
+    if removed.is_default {
+        if let Some(next) = methods.iter_mut().find(|m| m.method == removed.method) {
+            next.is_default = true;
+        }
+    }
+}
+
+pub fn load_saved_methods() -> Vec<SavedPayoutMethod> {
+    LocalStorage::get(SAVED_PAYOUT_METHODS_KEY).unwrap_or_default()
+}
+
+pub fn store_saved_methods(methods: &[SavedPayoutMethod]) {
+    let _ = LocalStorage::set(SAVED_PAYOUT_METHODS_KEY, methods);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iban_checksum_and_length() {
+        assert_eq!(
+            validate_iban("de89 3704 0044 0532 0130 00", "DE").unwrap(),
+            "DE89370400440532013000"
+        );
+        assert!(validate_iban("GB82WEST12345698765432", "GB").is_ok());
+        // 校验位错误
+        assert!(validate_iban("DE89370400440532013001", "DE").is_err());
+        // 长度错误
+        assert!(validate_iban("DE8937040044053201300", "DE").is_err());
+        // 国家不一致
+        assert!(validate_iban("GB82WEST12345698765432", "DE").is_err());
+    }
+
+    #[test]
+    fn bic_and_aba_routing() {
+        assert_eq!(validate_bic("deutdeff").unwrap(), "DEUTDEFF");
+        assert!(validate_bic("DEUTDEFF500").is_ok());
+        assert!(validate_bic("DEUT1EFF").is_err());
+        assert!(validate_bic("DEUTDE").is_err());
+
+        assert!(validate_aba_routing("021000021").is_ok());
+        assert!(validate_aba_routing("011000015").is_ok());
+        assert!(validate_aba_routing("021000022").is_err());
+        assert!(validate_aba_routing("02100002").is_err());
+    }
+
+    #[test]
+    fn bank_details_follow_country_schema() {
+        let us = BankDetails {
+            country: "us".to_string(),
+            holder_name: " Jane Doe ".to_string(),
+            routing_number: "021000021".to_string(),
+            account_number: "000123456789".to_string(),
+            ..Default::default()
+        };
+        let normalized = us.validate().unwrap();
+        assert_eq!(normalized.country, "US");
+        assert_eq!(normalized.holder_name, "Jane Doe");
+        let json = normalized.to_recipient_json();
+        assert_eq!(json["routing_number"], "021000021");
+        assert!(json.get("iban").is_none());
+        assert_eq!(normalized.masked(), "Jane Doe •••• 6789");
+
+        let sg = BankDetails {
+            country: "SG".to_string(),
+            holder_name: "Tan".to_string(),
+            account_number: "1234567890".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(sg.schema(), BankSchema::Swift);
+        assert!(sg.validate().is_err());
+
+        let missing_name = BankDetails {
+            holder_name: String::new(),
+            ..us
+        };
+        assert!(missing_name.validate().is_err());
+    }
+
+    #[test]
+    fn saved_methods_keep_single_default() {
+        let mut methods = Vec::new();
+        let paypal = |v: &str| PayoutAccount::Identifier {
+            value: v.to_string(),
+        };
+        upsert_method(&mut methods, "paypal", paypal("a@example.com"));
+        upsert_method(&mut methods, "paypal", paypal("b@example.com"));
+        upsert_method(&mut methods, "paypal", paypal("a@example.com"));
+        assert_eq!(methods.len(), 2);
+        assert!(methods[0].is_default && !methods[1].is_default);
+        assert_eq!(methods[0].masked(), "a•••@example.com");
+
+        let second = methods[1].id.clone();
+        set_default(&mut methods, &second);
+        assert!(!methods[0].is_default && methods[1].is_default);
+
+        remove_method(&mut methods, &second);
+        assert_eq!(methods.len(), 1);
+        assert!(methods[0].is_default);
+    }
+}
@@ -0,0 +1,192 @@
+//! Pending Swaps - 进行中的授权与兑换
+//!
+//! 兑换广播后按 (钱包, 网络, 代币) 记录，兑换表单据此提示“有一笔授权/兑换正在确认中”，
+//! 并默认阻止重复提交同一交易对。确认或失败后由状态轮询移除；页面刷新后轮询中断时，
+//! 兑换页的提示条会继续查询状态，超过 `MAX_PENDING_SECS` 的记录视为已失效。
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const STORAGE_KEY: &str = "pending_swaps";
+/// 记录最长保留时间（秒），避免状态查询失败时提示永远不消失
+pub const MAX_PENDING_SECS: u64 = 30 * 60;
+
+/// 进行中交易的类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingKind {
+    /// 代币授权（后端返回 `needs_approval` 时签名的是授权交易）
+    Approval,
+    Swap,
+}
+
+impl PendingKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Approval => "授权",
+            Self::Swap => "兑换",
+        }
+    }
+}
+
+/// 一笔进行中的授权或兑换
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingSwapTx {
+    pub swap_id: String,
+    pub kind: PendingKind,
+    pub wallet_id: String,
+    pub network: String,
+    pub from_token: String,
+    pub to_token: String,
+    pub tx_hash: Option<String>,
+    /// 广播时间（Unix 秒）
+    pub created_at: u64,
+}
+
+impl PendingSwapTx {
+    /// 涉及表单当前选择的支付代币（同钱包、同网络）
+    pub fn involves(&self, wallet_id: &str, network: &str, from_token: &str) -> bool {
+        self.wallet_id == wallet_id
+            && self.network.eq_ignore_ascii_case(network)
+            && self.from_token.eq_ignore_ascii_case(from_token)
+    }
+
+    /// 与表单是同一交易对的兑换
+    pub fn same_pair(
+        &self,
+        wallet_id: &str,
+        network: &str,
+        from_token: &str,
+        to_token: &str,
+    ) -> bool {
+        self.kind == PendingKind::Swap
+            && self.involves(wallet_id, network, from_token)
+            && self.to_token.eq_ignore_ascii_case(to_token)
+    }
+}
+
+/// 进行中的授权与兑换（LocalStorage 持久化）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PendingSwaps {
+    items: Vec<PendingSwapTx>,
+}
+
+impl PendingSwaps {
+    /// 读取并丢弃已失效的记录
+    pub fn load(now: u64) -> Self {
+        let mut pending: Self = LocalStorage::get(STORAGE_KEY).unwrap_or_default();
+        pending.prune(now);
+        pending
+    }
+
+    fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    pub fn items(&self) -> &[PendingSwapTx] {
+        &self.items
+    }
+
+    pub fn add(&mut self, item: PendingSwapTx) {
+        self.items.retain(|i| i.swap_id != item.swap_id);
+        self.items.push(item);
+        self.save();
+    }
+
+    /// 确认或失败后移除
+    pub fn resolve(&mut self, swap_id: &str) {
+        let before = self.items.len();
+        self.items.retain(|i| i.swap_id != swap_id);
+        if self.items.len() != before {
+            self.save();
+        }
+    }
+
+    pub fn prune(&mut self, now: u64) {
+        if self.drop_expired(now) {
+            self.save();
+        }
+    }
+
+    /// 丢弃超过保留时间的记录，返回是否有变化
+    fn drop_expired(&mut self, now: u64) -> bool {
+        let before = self.items.len();
+        self.items
+            .retain(|i| now.saturating_sub(i.created_at) <= MAX_PENDING_SECS);
+        self.items.len() != before
+    }
+
+    /// 涉及表单支付代币的进行中交易
+    pub fn for_token(
+        &self,
+        wallet_id: &str,
+        network: &str,
+        from_token: &str,
+    ) -> Vec<PendingSwapTx> {
+        self.items
+            .iter()
+            .filter(|i| i.involves(wallet_id, network, from_token))
+            .cloned()
+            .collect()
+    }
+
+    /// 同一交易对是否有兑换尚未完成
+    pub fn has_pending_pair(
+        &self,
+        wallet_id: &str,
+        network: &str,
+        from_token: &str,
+        to_token: &str,
+    ) -> bool {
+        self.items
+            .iter()
+            .any(|i| i.same_pair(wallet_id, network, from_token, to_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(swap_id: &str, kind: PendingKind, to_token: &str, created_at: u64) -> PendingSwapTx {
+        PendingSwapTx {
+            swap_id: swap_id.to_string(),
+            kind,
+            wallet_id: "w1".to_string(),
+            network: "ethereum".to_string(),
+            from_token: "USDT".to_string(),
+            to_token: to_token.to_string(),
+            tx_hash: None,
+            created_at,
+        }
+    }
+
+    #[test]
+    fn matches_by_wallet_network_and_token() {
+        let pending = PendingSwaps {
+            items: vec![
+                item("a", PendingKind::Approval, "ETH", 1000),
+                item("b", PendingKind::Swap, "DAI", 1000),
+            ],
+        };
+        assert_eq!(pending.for_token("w1", "Ethereum", "usdt").len(), 2);
+        assert!(pending.for_token("w2", "ethereum", "USDT").is_empty());
+        assert!(pending.for_token("w1", "bsc", "USDT").is_empty());
+
+        // 授权不算同一交易对的兑换
+        assert!(!pending.has_pending_pair("w1", "ethereum", "USDT", "ETH"));
+        assert!(pending.has_pending_pair("w1", "ethereum", "USDT", "DAI"));
+    }
+
+    #[test]
+    fn expired_items_are_pruned() {
+        let mut pending = PendingSwaps {
+            items: vec![
+                item("old", PendingKind::Swap, "DAI", 1000),
+                item("new", PendingKind::Swap, "ETH", 1000 + MAX_PENDING_SECS),
+            ],
+        };
+        assert!(pending.drop_expired(1001 + MAX_PENDING_SECS));
+        assert_eq!(pending.items().len(), 1);
+        assert_eq!(pending.items()[0].swap_id, "new");
+    }
+}
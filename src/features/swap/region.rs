@@ -0,0 +1,68 @@
+//! Payment Region - 法币支付地区检测
+//!
+//! 国家/地区来源优先级：用户手动指定（偏好设置）> 后端地理定位 > 浏览器语言。
+//! 确定国家后拉取该地区的支付方式矩阵；矩阵接口失败时使用本地规则兜底。
+
+use crate::services::country_support::{
+    country_from_locale, CountrySupportService, PaymentMethodMatrix,
+};
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 检测用户所在地区并加载可用支付方式（None 表示仍在检测中）
+pub fn use_payment_region() -> Signal<Option<PaymentMethodMatrix>> {
+    let app_state = use_context::<AppState>();
+    let mut region = use_signal(|| Option::<PaymentMethodMatrix>::None);
+    // 用户在检测期间切换地区时，旧任务发现代次变化后放弃写入
+    let mut generation = use_signal(|| 0u64);
+
+    use_effect(move || {
+        let override_code = app_state.preferences.read().country_override.clone();
+        let current = *generation.peek() + 1;
+        generation.set(current);
+        region.set(None);
+        spawn(async move {
+            let service = CountrySupportService::new(app_state);
+
+            let (country_code, country_name, method) = match override_code {
+                Some(code) => (code, String::new(), "Manual"),
+                None => match service.detect_country().await {
+                    Ok(detected) => (detected.country_code, detected.country_name, "IP"),
+                    Err(e) => {
+                        log::warn!("{}", e);
+                        let code = browser_locale()
+                            .as_deref()
+                            .and_then(country_from_locale)
+                            .unwrap_or_else(|| "US".to_string());
+                        (code, String::new(), "Locale")
+                    }
+                },
+            };
+
+            let mut matrix = match service.get_payment_methods(&country_code).await {
+                Ok(matrix) => matrix,
+                Err(e) => {
+                    log::warn!("{}", e);
+                    PaymentMethodMatrix::fallback(&country_code)
+                }
+            };
+            if !country_name.is_empty()
+                && (matrix.country_name.is_empty() || matrix.country_name == matrix.country_code)
+            {
+                matrix.country_name = country_name;
+            }
+            matrix.detection_method = method.to_string();
+
+            if *generation.peek() == current {
+                region.set(Some(matrix));
+            }
+        });
+    });
+
+    region
+}
+
+/// 浏览器首选语言（如 "zh-CN"）
+fn browser_locale() -> Option<String> {
+    web_sys::window()?.navigator().language()
+}
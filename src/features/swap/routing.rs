@@ -0,0 +1,84 @@
+//! Swap Routing Preference - 兑换路由偏好
+//!
+//! 报价请求按偏好附带路由参数，由后端映射为聚合器支持的选项：
+//! 最大输出（默认，聚合器默认路由）、最低 Gas、仅简单路由（单跳、授权更少）。
+//! 全局默认值在设置中修改，单次兑换可在高级设置中覆盖；报价按偏好分别缓存，便于即时对比。
+
+use serde::{Deserialize, Serialize};
+
+/// 路由偏好
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutePreference {
+    /// 最大输出（聚合器默认）
+    #[default]
+    BestOutput,
+    /// 最低 Gas：优先更少的拆分与跳数
+    LowestGas,
+    /// 仅简单路由：单跳，授权更少
+    SimpleRoute,
+}
+
+impl RoutePreference {
+    pub const ALL: [RoutePreference; 3] = [
+        RoutePreference::BestOutput,
+        RoutePreference::LowestGas,
+        RoutePreference::SimpleRoute,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RoutePreference::BestOutput => "best_output",
+            RoutePreference::LowestGas => "lowest_gas",
+            RoutePreference::SimpleRoute => "simple_route",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RoutePreference::BestOutput => "最大输出",
+            RoutePreference::LowestGas => "最低 Gas",
+            RoutePreference::SimpleRoute => "仅简单路由",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            RoutePreference::BestOutput => "聚合所有流动性，获得最多的目标代币",
+            RoutePreference::LowestGas => "减少拆单与跳数，网络费更低，收到的数量可能略少",
+            RoutePreference::SimpleRoute => "只走单一流动性池，需要的授权更少，路线最易核对",
+        }
+    }
+
+    /// 报价请求附带的查询参数（最大输出沿用聚合器默认路由，不附加参数）
+    pub fn query_params(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            RoutePreference::BestOutput => &[],
+            RoutePreference::LowestGas => &[("route_preference", "lowest_gas")],
+            RoutePreference::SimpleRoute => {
+                &[("route_preference", "simple_route"), ("max_hops", "1")]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preference_serializes_as_query_value() {
+        for preference in RoutePreference::ALL {
+            let json = serde_json::to_string(&preference).unwrap();
+            assert_eq!(json, format!("\"{}\"", preference.as_str()));
+            if let Some((_, value)) = preference
+                .query_params()
+                .iter()
+                .find(|(key, _)| *key == "route_preference")
+            {
+                assert_eq!(*value, preference.as_str());
+            }
+        }
+        assert!(RoutePreference::default().query_params().is_empty());
+    }
+}
@@ -0,0 +1,335 @@
+//! Trade Size - 按流动性建议的交易规模
+//!
+//! 报价的价格影响超过提示阈值时，额外请求 2–3 个较小金额的报价（二分查找，报价走页面缓存），
+//! 找出价格影响不超过阈值的最大金额，并建议拆分为多笔依次执行：
+//! - 拆分后的每一笔都需要用户单独确认，按顺序签名广播（nonce 依次递增）
+//! - 某一笔失败时停在该笔，可重试或放弃剩余部分
+
+use crate::features::swap::dust::swap_and_broadcast;
+use crate::services::cache::{CacheKey, MemoryCache};
+use crate::services::swap::{SwapQuoteResponse, SwapService};
+use crate::shared::state::AppState;
+use crate::shared::time;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+/// 价格影响提示阈值（%）
+pub const PRICE_IMPACT_WARN_PERCENT: f64 = 1.0;
+/// 查找安全金额时最多额外请求的报价次数
+const MAX_PROBES: usize = 3;
+/// 最多拆分的笔数（超过时只提示安全金额）
+pub const MAX_LEGS: usize = 10;
+/// 报价缓存时长（与兑换页一致）
+const QUOTE_CACHE_SECS: u64 = 30;
+/// 拆分兑换的 client_request_id 前缀
+const SPLIT_REQUEST_PREFIX: &str = "split-swap:";
+
+/// 报价的价格影响是否超过提示阈值
+pub fn exceeds_threshold(quote: &SwapQuoteResponse) -> bool {
+    quote
+        .price_impact
+        .is_some_and(|impact| impact > PRICE_IMPACT_WARN_PERCENT)
+}
+
+/// 二分查找价格影响不超过阈值的最大金额
+///
+/// `safe` 为已知满足阈值的最大金额（初始为 0），`unsafe_amount` 为已知超过阈值的最小金额
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeSearch {
+    safe: f64,
+    unsafe_amount: f64,
+    probes: usize,
+}
+
+impl SizeSearch {
+    /// 从超过阈值的完整金额开始
+    pub fn new(amount: f64) -> Self {
+        Self {
+            safe: 0.0,
+            unsafe_amount: amount,
+            probes: 0,
+        }
+    }
+
+    /// 下一个要报价的金额；次数用完时返回 None
+    pub fn next_probe(&self) -> Option<f64> {
+        (self.probes < MAX_PROBES).then(|| (self.safe + self.unsafe_amount) / 2.0)
+    }
+
+    /// 记录一次报价结果；报价失败（无价格影响数据）视为超过阈值
+    pub fn record(&mut self, amount: f64, impact: Option<f64>) {
+        self.probes += 1;
+        match impact {
+            Some(impact) if impact <= PRICE_IMPACT_WARN_PERCENT => self.safe = amount,
+            _ => self.unsafe_amount = amount,
+        }
+    }
+
+    /// 找到的安全金额（一次都未满足阈值时为 None）
+    pub fn result(&self) -> Option<f64> {
+        (self.safe > 0.0).then_some(self.safe)
+    }
+}
+
+/// 交易规模建议
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeSuggestion {
+    /// 用户输入的金额
+    pub amount: f64,
+    /// 价格影响不超过阈值的最大单笔金额
+    pub max_amount: f64,
+    /// 拆分后的每笔金额（超过 MAX_LEGS 笔时为空，只提示安全金额）
+    pub legs: Vec<String>,
+}
+
+/// 均分为每笔不超过 `max_amount` 的若干笔；最后一笔补足舍入误差，总和等于 `total`
+pub fn split_legs(total: f64, max_amount: f64) -> Vec<String> {
+    if !(total > 0.0 && max_amount > 0.0) {
+        return Vec::new();
+    }
+    let count = (total / max_amount).ceil().max(1.0) as usize;
+    if count > MAX_LEGS {
+        return Vec::new();
+    }
+    let per_leg = ((total / count as f64) * 1e8).floor() / 1e8;
+    let mut legs: Vec<String> = (0..count - 1).map(|_| format_amount(per_leg)).collect();
+    legs.push(format_amount(total - per_leg * (count - 1) as f64));
+    legs
+}
+
+/// 金额字符串（最多 8 位小数，去掉末尾的 0）
+pub fn format_amount(value: f64) -> String {
+    let s = format!("{:.8}", value);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// 获取报价的价格影响（优先读取兑换页的报价缓存）
+async fn probe_impact(
+    app_state: AppState,
+    mut cache: Signal<MemoryCache>,
+    from: &str,
+    to: &str,
+    amount: &str,
+    network: &str,
+) -> Option<f64> {
+    let key = CacheKey::quote(from, to, amount);
+    if let Some(quote) = cache.read().get::<SwapQuoteResponse>(&key) {
+        return quote.price_impact;
+    }
+    let quote = SwapService::new(app_state)
+        .get_quote(from, to, amount, network)
+        .await
+        .ok()?;
+    cache.write().set(
+        key,
+        quote.clone(),
+        Some(Duration::from_secs(QUOTE_CACHE_SECS)),
+    );
+    quote.price_impact
+}
+
+/// 查找安全金额并生成拆分建议；找不到满足阈值的金额时返回 None
+pub async fn suggest_size(
+    app_state: AppState,
+    cache: Signal<MemoryCache>,
+    from: &str,
+    to: &str,
+    amount: f64,
+    network: &str,
+) -> Option<SizeSuggestion> {
+    let mut search = SizeSearch::new(amount);
+    while let Some(probe) = search.next_probe() {
+        let probe_amount = format_amount(probe);
+        let impact = probe_impact(app_state, cache, from, to, &probe_amount, network).await;
+        search.record(probe, impact);
+    }
+    let max_amount = search.result()?;
+    Some(SizeSuggestion {
+        amount,
+        max_amount,
+        legs: split_legs(amount, max_amount),
+    })
+}
+
+/// 拆分兑换中单笔的状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum LegStatus {
+    /// 等待用户确认
+    Pending,
+    Running,
+    Done {
+        tx_hash: String,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+/// 拆分兑换计划
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPlan {
+    pub batch_id: String,
+    pub wallet_id: String,
+    pub network: String,
+    pub from: String,
+    pub to: String,
+    pub legs: Vec<(String, LegStatus)>,
+    /// 上一笔使用的 nonce（下一笔至少使用其后一个）
+    pub last_nonce: Option<u64>,
+}
+
+impl SplitPlan {
+    pub fn new(wallet_id: &str, network: &str, from: &str, to: &str, legs: &[String]) -> Self {
+        Self {
+            batch_id: format!("{:x}", time::now_ms() as u64),
+            wallet_id: wallet_id.to_string(),
+            network: network.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            legs: legs
+                .iter()
+                .map(|amount| (amount.clone(), LegStatus::Pending))
+                .collect(),
+            last_nonce: None,
+        }
+    }
+
+    /// 下一笔待确认（或失败待重试）的序号；有正在执行的兑换时返回 None
+    pub fn next_leg(&self) -> Option<usize> {
+        if self
+            .legs
+            .iter()
+            .any(|(_, status)| *status == LegStatus::Running)
+        {
+            return None;
+        }
+        self.legs
+            .iter()
+            .position(|(_, status)| !matches!(status, LegStatus::Done { .. }))
+    }
+
+    pub fn completed(&self) -> usize {
+        self.legs
+            .iter()
+            .filter(|(_, status)| matches!(status, LegStatus::Done { .. }))
+            .count()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.completed() == self.legs.len()
+    }
+}
+
+/// 执行拆分计划中的一笔，结果写回计划
+pub async fn execute_leg(app_state: AppState, mut plan: Signal<Option<SplitPlan>>, index: usize) {
+    let Some(current) = plan.read().clone() else {
+        return;
+    };
+    let Some((amount, _)) = current.legs.get(index).cloned() else {
+        return;
+    };
+    set_leg_status(plan, index, LegStatus::Running);
+
+    let result = swap_and_broadcast(
+        app_state,
+        &current.wallet_id,
+        &current.network,
+        &current.from,
+        &current.to,
+        &amount,
+        format!("{}{}:{}", SPLIT_REQUEST_PREFIX, current.batch_id, index),
+        current.last_nonce.map(|n| n + 1),
+    )
+    .await;
+
+    match result {
+        Ok((tx_hash, nonce)) => {
+            if let Some(p) = plan.write().as_mut() {
+                p.last_nonce = Some(nonce);
+            }
+            set_leg_status(plan, index, LegStatus::Done { tx_hash });
+        }
+        Err(error) => set_leg_status(
+            plan,
+            index,
+            LegStatus::Failed {
+                error: crate::shared::ui_error::sanitize_user_message(error),
+            },
+        ),
+    }
+}
+
+fn set_leg_status(mut plan: Signal<Option<SplitPlan>>, index: usize, status: LegStatus) {
+    if let Some(leg) = plan.write().as_mut().and_then(|p| p.legs.get_mut(index)) {
+        leg.1 = status;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_finds_largest_probe_under_threshold() {
+        // 价格影响与金额线性相关：1000 → 4%，安全上限为 250
+        let impact = |amount: f64| amount / 250.0;
+        let mut search = SizeSearch::new(1000.0);
+        let mut probes = Vec::new();
+        while let Some(probe) = search.next_probe() {
+            probes.push(probe);
+            search.record(probe, Some(impact(probe)));
+        }
+        assert_eq!(probes, vec![500.0, 250.0, 375.0]);
+        assert_eq!(search.result(), Some(250.0));
+
+        // 报价失败或始终超过阈值时不给出建议
+        let mut search = SizeSearch::new(1000.0);
+        while let Some(probe) = search.next_probe() {
+            search.record(probe, None);
+        }
+        assert_eq!(search.result(), None);
+    }
+
+    #[test]
+    fn legs_stay_under_max_and_sum_to_total() {
+        let legs = split_legs(1000.0, 300.0);
+        assert_eq!(legs, vec!["250", "250", "250", "250"]);
+
+        let legs = split_legs(10.0, 3.5);
+        assert_eq!(legs.len(), 3);
+        let sum: f64 = legs.iter().map(|l| l.parse::<f64>().unwrap()).sum();
+        assert!((sum - 10.0).abs() < 1e-8);
+        assert!(legs.iter().all(|l| l.parse::<f64>().unwrap() <= 3.5));
+
+        assert!(split_legs(1000.0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn next_leg_waits_for_running_and_retries_failed() {
+        let mut plan = SplitPlan {
+            batch_id: "b".to_string(),
+            wallet_id: "w".to_string(),
+            network: "ethereum".to_string(),
+            from: "USDC".to_string(),
+            to: "PEPE".to_string(),
+            legs: vec![
+                (
+                    "1".to_string(),
+                    LegStatus::Done {
+                        tx_hash: "0x1".to_string(),
+                    },
+                ),
+                ("1".to_string(), LegStatus::Running),
+                ("1".to_string(), LegStatus::Pending),
+            ],
+            last_nonce: Some(4),
+        };
+        assert_eq!(plan.next_leg(), None);
+        plan.legs[1].1 = LegStatus::Failed {
+            error: "x".to_string(),
+        };
+        assert_eq!(plan.next_leg(), Some(1));
+        assert_eq!(plan.completed(), 1);
+        assert!(!plan.is_finished());
+    }
+}
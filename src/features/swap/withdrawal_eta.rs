@@ -0,0 +1,282 @@
+//! Withdrawal ETA - 提现预计到账时间
+//!
+//! 银行卡等法币提现通常需要 1–3 个工作日。下单时按服务商给出的预计到账时间
+//! （按工作日计算，跳过周末）在本地记录，订单行与详情中显示“预计 {日期} 前到账”，
+//! 可下载日历提醒（ICS）。到预计时间仍未完成的订单提醒一次，并提供“报告延迟到账”入口。
+
+use crate::services::fiat_offramp::{FiatOfframpOrderResponse, FiatOfframpOrderStatus};
+use chrono::{DateTime, Datelike, Duration, Local, Utc, Weekday};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const STORAGE_KEY: &str = "withdrawal_etas";
+/// 最多保留的记录数（超出时丢弃最早的）
+const MAX_RECORDS: usize = 100;
+/// 到账检查间隔（毫秒）
+pub const FOLLOW_UP_POLL_MS: u32 = 600_000;
+
+/// 一笔提现订单的预计到账时间
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WithdrawalEta {
+    pub order_id: String,
+    /// 预计到账时间（Unix 秒）
+    pub expected_by: i64,
+    pub fiat_amount: String,
+    pub fiat_currency: String,
+    /// 到期后已检查并提醒过
+    #[serde(default)]
+    pub followed_up: bool,
+}
+
+/// 服务商返回的到账说明
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArrivalHint {
+    /// 明确的时间点
+    At(DateTime<Utc>),
+    /// N 个工作日（区间取上限）
+    BusinessDays(u32),
+    /// N 小时内
+    Hours(u32),
+}
+
+/// 解析到账说明：RFC3339 时间、“1-3 business days”/“1-3个工作日”、“24 hours”/“24小时”
+fn parse_arrival(text: &str) -> Option<ArrivalHint> {
+    let text = text.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(text) {
+        return Some(ArrivalHint::At(at.with_timezone(&Utc)));
+    }
+    let upper = text
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse::<u32>().ok())
+        .max()?;
+    let lower = text.to_lowercase();
+    if lower.contains("hour") || lower.contains("小时") {
+        Some(ArrivalHint::Hours(upper))
+    } else if lower.contains("day") || lower.contains("工作日") || lower.contains("天") {
+        Some(ArrivalHint::BusinessDays(upper))
+    } else {
+        None
+    }
+}
+
+/// 服务商未给出可解析的说明时，各提现方式的默认工作日数
+fn default_business_days(withdraw_method: &str) -> u32 {
+    match withdraw_method {
+        "paypal" => 1,
+        _ => 3,
+    }
+}
+
+/// 从 `start` 起经过 `days` 个工作日（跳过周六、周日）
+pub fn add_business_days(start: DateTime<Utc>, days: u32) -> DateTime<Utc> {
+    let mut at = start;
+    let mut remaining = days;
+    while remaining > 0 {
+        at += Duration::days(1);
+        if !matches!(at.weekday(), Weekday::Sat | Weekday::Sun) {
+            remaining -= 1;
+        }
+    }
+    at
+}
+
+/// 预计到账时间
+pub fn expected_by(
+    created_at: DateTime<Utc>,
+    estimated_arrival: &str,
+    withdraw_method: &str,
+) -> DateTime<Utc> {
+    match parse_arrival(estimated_arrival) {
+        Some(ArrivalHint::At(at)) => at,
+        Some(ArrivalHint::Hours(hours)) => created_at + Duration::hours(hours as i64),
+        Some(ArrivalHint::BusinessDays(days)) => add_business_days(created_at, days),
+        None => add_business_days(created_at, default_business_days(withdraw_method)),
+    }
+}
+
+/// 下单成功后记录预计到账时间
+pub fn record(order: &FiatOfframpOrderResponse, withdraw_method: &str) {
+    let created_at = DateTime::parse_from_rfc3339(order.created_at.trim())
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+    let eta = WithdrawalEta {
+        order_id: order.order_id.clone(),
+        expected_by: expected_by(created_at, &order.estimated_arrival, withdraw_method).timestamp(),
+        fiat_amount: order.fiat_amount.clone(),
+        fiat_currency: order.fiat_currency.clone(),
+        followed_up: false,
+    };
+    let mut records = load();
+    records.retain(|r| r.order_id != eta.order_id);
+    records.push(eta);
+    if records.len() > MAX_RECORDS {
+        let overflow = records.len() - MAX_RECORDS;
+        records.drain(..overflow);
+    }
+    save(&records);
+}
+
+pub fn load() -> Vec<WithdrawalEta> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+fn save(records: &[WithdrawalEta]) {
+    let _ = LocalStorage::set(STORAGE_KEY, records);
+}
+
+pub fn find(order_id: &str) -> Option<WithdrawalEta> {
+    load().into_iter().find(|r| r.order_id == order_id)
+}
+
+/// 订单的预计到账时间：优先本地记录，其次按订单返回的到账说明计算
+pub fn expected_by_for(order: &FiatOfframpOrderStatus) -> Option<i64> {
+    if let Some(record) = find(&order.order_id) {
+        return Some(record.expected_by);
+    }
+    let created_at = DateTime::parse_from_rfc3339(order.created_at.trim())
+        .ok()?
+        .with_timezone(&Utc);
+    let arrival = order
+        .estimated_arrival
+        .as_deref()
+        .filter(|a| parse_arrival(a).is_some())?;
+    Some(expected_by(created_at, arrival, "bank_card").timestamp())
+}
+
+/// 订单已有最终结果（不再需要到账提醒）
+pub fn is_settled(status: &str) -> bool {
+    matches!(
+        status,
+        "completed" | "failed" | "cancelled" | "refunded" | "expired"
+    )
+}
+
+/// 已过预计到账时间但仍未完成
+pub fn is_overdue(expected_by: i64, status: &str, now: i64) -> bool {
+    !is_settled(status) && now >= expected_by
+}
+
+/// 到期且尚未提醒过的记录
+pub fn due_follow_ups(records: &[WithdrawalEta], now: i64) -> Vec<WithdrawalEta> {
+    records
+        .iter()
+        .filter(|r| !r.followed_up && now >= r.expected_by)
+        .cloned()
+        .collect()
+}
+
+/// 标记为已提醒（订单已完成时也标记，避免重复查询）
+pub fn mark_followed_up(order_id: &str) {
+    let mut records = load();
+    if let Some(record) = records.iter_mut().find(|r| r.order_id == order_id) {
+        record.followed_up = true;
+        save(&records);
+    }
+}
+
+/// 本地日期
+fn local_date(expected_by: i64) -> Option<String> {
+    DateTime::from_timestamp(expected_by, 0)
+        .map(|t| t.with_timezone(&Local).format("%Y-%m-%d").to_string())
+}
+
+/// “预计 {日期} 前到账”
+pub fn expected_label(expected_by: i64) -> String {
+    match local_date(expected_by) {
+        Some(date) => format!("预计 {} 前到账", date),
+        None => "预计到账时间未知".to_string(),
+    }
+}
+
+/// 日历提醒（全天事件，ICS 格式）
+pub fn to_ics(eta: &WithdrawalEta, now: DateTime<Utc>) -> String {
+    let date = DateTime::from_timestamp(eta.expected_by, 0)
+        .map(|t| t.with_timezone(&Local).date_naive())
+        .unwrap_or_else(|| now.date_naive());
+    let next_day = date + Duration::days(1);
+    [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//IronForge//Withdrawal ETA//ZH".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:withdrawal-{}@ironforge", eta.order_id),
+        format!("DTSTAMP:{}", now.format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")),
+        format!("DTEND;VALUE=DATE:{}", next_day.format("%Y%m%d")),
+        format!(
+            "SUMMARY:提现预计到账 {} {}",
+            eta.fiat_amount, eta.fiat_currency
+        ),
+        format!(
+            "DESCRIPTION:提现订单 {} 预计今天前到账，如未收到请在应用中报告延迟到账。",
+            eta.order_id
+        ),
+        "END:VEVENT".to_string(),
+        "END:VCALENDAR".to_string(),
+    ]
+    .join("\r\n")
+}
+
+/// 报告延迟到账工单的标题与描述
+pub fn delay_report(order_id: &str, expected_by: i64) -> (String, String) {
+    let date = local_date(expected_by).unwrap_or_default();
+    (
+        "提现未按预计时间到账".to_string(),
+        format!(
+            "我的提现订单 {} 预计 {} 前到账，目前仍未收到款项，请帮忙查询。",
+            order_id, date
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn business_days_skip_weekends() {
+        // 2026-10-16 是周五
+        let friday = Utc.with_ymd_and_hms(2026, 10, 16, 10, 0, 0).unwrap();
+        let eta = expected_by(friday, "1-3 business days", "bank_card");
+        assert_eq!(eta, Utc.with_ymd_and_hms(2026, 10, 21, 10, 0, 0).unwrap());
+        assert_eq!(expected_by(friday, "1-3个工作日", "bank_card"), eta);
+        assert_eq!(
+            expected_by(friday, "", "paypal"),
+            Utc.with_ymd_and_hms(2026, 10, 19, 10, 0, 0).unwrap()
+        );
+        assert_eq!(
+            expected_by(friday, "24 hours", "bank_card"),
+            friday + Duration::hours(24)
+        );
+        assert_eq!(
+            expected_by(friday, "2026-10-20T00:00:00Z", "bank_card"),
+            Utc.with_ymd_and_hms(2026, 10, 20, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn follow_ups_fire_once_for_unsettled_orders() {
+        let record = |id: &str, expected_by: i64, followed_up: bool| WithdrawalEta {
+            order_id: id.to_string(),
+            expected_by,
+            fiat_amount: "100".to_string(),
+            fiat_currency: "USD".to_string(),
+            followed_up,
+        };
+        let records = vec![
+            record("due", 100, false),
+            record("later", 300, false),
+            record("done", 100, true),
+        ];
+        let due: Vec<_> = due_follow_ups(&records, 200)
+            .into_iter()
+            .map(|r| r.order_id)
+            .collect();
+        assert_eq!(due, vec!["due"]);
+
+        assert!(is_overdue(100, "processing", 200));
+        assert!(!is_overdue(100, "completed", 200));
+        assert!(!is_overdue(300, "pending", 200));
+    }
+}
@@ -0,0 +1,324 @@
+//! Wrap - 原生币与包装币之间的兑换（ETH ↔ WETH、BNB ↔ WBNB、SOL ↔ WSOL 等）
+//!
+//! 包装/解包按 1:1 兑换，不需要聚合器报价。EVM 链上直接调用包装合约的
+//! `deposit()` / `withdraw(uint256)`，只需支付网络费、没有兑换手续费；
+//! Solana 的 WSOL 需要关联代币账户，仍交给后端路由，但界面与历史记录中同样标为包装/解包。
+//!
+//! 直接发起的包装/解包不经过后端兑换接口，记录保存在本地并合并到兑换历史中。
+
+use crate::features::swap::contract_call;
+use crate::features::swap::history::SwapHistoryStatus;
+use crate::features::wallet::state::Account;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::{wrapped_native, ChainGroup, WrappedNative};
+use crate::services::swap::SwapQuoteResponse;
+use crate::services::token::TokenInfo;
+use crate::services::transaction_history::TransactionHistoryItem;
+use crate::shared::state::AppState;
+use crate::shared::validation::TokenAmount;
+use gloo_storage::{LocalStorage, Storage};
+
+/// `deposit()` 的函数选择器
+const DEPOSIT_SELECTOR: &str = "d0e30db0";
+/// `withdraw(uint256)` 的函数选择器
+const WITHDRAW_SELECTOR: &str = "2e1a7d4d";
+/// 本地包装/解包记录（LocalStorage）
+const STORAGE_KEY: &str = "wrap_history";
+/// 最多保留的记录数
+const MAX_RECORDS: usize = 50;
+/// 历史记录 metadata 中标记包装方向的字段
+const METADATA_KEY: &str = "wrap";
+
+/// 包装方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapDirection {
+    /// 原生币 → 包装币
+    Wrap,
+    /// 包装币 → 原生币
+    Unwrap,
+}
+
+impl WrapDirection {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Wrap => "包装",
+            Self::Unwrap => "解包",
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Wrap => "wrap",
+            Self::Unwrap => "unwrap",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "wrap" => Some(Self::Wrap),
+            "unwrap" => Some(Self::Unwrap),
+            _ => None,
+        }
+    }
+}
+
+/// 代币是否为所在链的包装原生币（按合约地址判断，地址缺失时按符号）
+fn is_wrapped(token: &TokenInfo, wrapped: &WrappedNative) -> bool {
+    if token.is_native {
+        return false;
+    }
+    if token.address.is_empty() {
+        token.symbol.eq_ignore_ascii_case(wrapped.symbol)
+    } else {
+        token.address.eq_ignore_ascii_case(wrapped.address)
+    }
+}
+
+/// 判断交易对是否为同链的包装/解包
+pub fn wrap_pair(from: &TokenInfo, to: &TokenInfo) -> Option<WrapDirection> {
+    if from.chain != to.chain {
+        return None;
+    }
+    let wrapped = wrapped_native(from.chain)?;
+    if from.is_native && is_wrapped(to, &wrapped) {
+        Some(WrapDirection::Wrap)
+    } else if to.is_native && is_wrapped(from, &wrapped) {
+        Some(WrapDirection::Unwrap)
+    } else {
+        None
+    }
+}
+
+/// 按代币符号判断（历史记录只有符号）
+pub fn wrap_pair_by_symbol(chain: ChainType, from: &str, to: &str) -> Option<WrapDirection> {
+    let wrapped = wrapped_native(chain)?;
+    let native = chain.native_token_symbol();
+    if from.eq_ignore_ascii_case(native) && to.eq_ignore_ascii_case(wrapped.symbol) {
+        Some(WrapDirection::Wrap)
+    } else if from.eq_ignore_ascii_case(wrapped.symbol) && to.eq_ignore_ascii_case(native) {
+        Some(WrapDirection::Unwrap)
+    } else {
+        None
+    }
+}
+
+/// 是否在客户端直接调用包装合约（目前仅 EVM 链）
+pub fn is_direct(chain: ChainType) -> bool {
+    ChainGroup::of(chain) == ChainGroup::Evm
+}
+
+/// 代币选择器中的提示：“ETH 的包装版本”
+pub fn wrapped_hint(token: &TokenInfo) -> Option<String> {
+    let wrapped = wrapped_native(token.chain)?;
+    is_wrapped(token, &wrapped).then(|| format!("{} 的包装版本", token.chain.native_token_symbol()))
+}
+
+/// 1:1 的本地报价（无协议费、无价格影响）
+pub fn quote(from: &TokenInfo, to: &TokenInfo, amount: &str) -> SwapQuoteResponse {
+    SwapQuoteResponse {
+        from_token: from.symbol.clone(),
+        to_token: to.symbol.clone(),
+        from_amount: amount.to_string(),
+        to_amount: amount.to_string(),
+        estimated_gas: None,
+        protocol_fee: Some("0".to_string()),
+        exchange_rate: Some(1.0),
+        price_impact: Some(0.0),
+        route: None,
+        estimated_gas_usd: None,
+        valid_for: None,
+        route_preference: None,
+    }
+}
+
+/// 包装合约调用数据
+pub fn calldata(direction: WrapDirection, amount: &TokenAmount) -> String {
+    match direction {
+        WrapDirection::Wrap => format!("0x{}", DEPOSIT_SELECTOR),
+        WrapDirection::Unwrap => {
+            let mut word = [0u8; 32];
+            amount.raw().to_big_endian(&mut word);
+            format!("0x{}{}", WITHDRAW_SELECTOR, hex::encode(word))
+        }
+    }
+}
+
+/// 签名并广播包装/解包交易，返回交易哈希
+pub async fn execute(
+    app_state: AppState,
+    wallet_id: &str,
+    account: &Account,
+    chain: ChainType,
+    direction: WrapDirection,
+    amount: &TokenAmount,
+) -> Result<String, String> {
+    let wrapped = wrapped_native(chain).ok_or_else(|| format!("{} 没有包装代币", chain.label()))?;
+    // 包装时随交易发送原生币；解包时从合约取回，不发送原生币
+    let value_wei = match direction {
+        WrapDirection::Wrap => amount.to_raw_string(),
+        WrapDirection::Unwrap => "0".to_string(),
+    };
+    contract_call::send(
+        app_state,
+        wallet_id,
+        account,
+        chain,
+        wrapped.address,
+        &value_wei,
+        &calldata(direction, amount),
+    )
+    .await
+    .map_err(|e| format!("{}交易失败: {}", direction.label(), e))
+}
+
+/// 历史记录中的包装方向（本地记录带标记；后端记录按网络与符号判断）
+pub fn history_direction(item: &TransactionHistoryItem) -> Option<WrapDirection> {
+    if item.tx_type != "swap" {
+        return None;
+    }
+    let metadata = item.metadata.as_ref();
+    if let Some(direction) = metadata
+        .and_then(|m| m.get(METADATA_KEY))
+        .and_then(|v| v.as_str())
+        .and_then(WrapDirection::from_str)
+    {
+        return Some(direction);
+    }
+    let chain = metadata
+        .and_then(|m| m.get("network"))
+        .and_then(|v| v.as_str())
+        .and_then(ChainType::from_str)?;
+    wrap_pair_by_symbol(chain, &item.from_token, &item.to_token)
+}
+
+/// 直接发起的包装/解包记录
+pub fn load() -> Vec<TransactionHistoryItem> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+fn save(records: &[TransactionHistoryItem]) {
+    let _ = LocalStorage::set(STORAGE_KEY, records);
+}
+
+/// 记录已广播的包装/解包（最新的在前）
+pub fn record(
+    chain: ChainType,
+    direction: WrapDirection,
+    from: &TokenInfo,
+    to: &TokenInfo,
+    amount: &str,
+    tx_hash: &str,
+) {
+    let item = TransactionHistoryItem {
+        id: format!("wrap:{}", tx_hash),
+        tx_type: "swap".to_string(),
+        status: "submitted".to_string(),
+        from_token: from.symbol.clone(),
+        to_token: to.symbol.clone(),
+        from_amount: amount.to_string(),
+        to_amount: amount.to_string(),
+        fee_amount: None,
+        gas_fee: None,
+        tx_hash: Some(tx_hash.to_string()),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        completed_at: None,
+        fiat_order_id: None,
+        metadata: Some(serde_json::json!({
+            "network": chain.as_str(),
+            METADATA_KEY: direction.as_str(),
+        })),
+    };
+    let mut records = load();
+    records.insert(0, item);
+    records.truncate(MAX_RECORDS);
+    save(&records);
+}
+
+/// 更新链上确认结果
+pub fn set_status(tx_hash: &str, status: &str) {
+    let mut records = load();
+    if let Some(item) = records
+        .iter_mut()
+        .find(|r| r.tx_hash.as_deref() == Some(tx_hash))
+    {
+        item.status = status.to_string();
+        if status == "confirmed" {
+            item.completed_at = Some(chrono::Utc::now().to_rfc3339());
+        }
+        save(&records);
+    }
+}
+
+/// 本地记录是否为包装/解包记录（没有后端兑换 ID，不能查询兑换状态或重试）
+pub fn is_local_record(item: &TransactionHistoryItem) -> bool {
+    item.id.starts_with("wrap:")
+}
+
+/// 把符合筛选条件的本地记录按时间合并进历史记录第一页
+pub fn merge_history(
+    mut items: Vec<TransactionHistoryItem>,
+    tx_type: Option<&str>,
+    status: Option<&str>,
+    page: u32,
+) -> Vec<TransactionHistoryItem> {
+    if page != 1 || tx_type.is_some_and(|t| t != "swap") {
+        return items;
+    }
+    let wanted = status.map(SwapHistoryStatus::from_status);
+    items.extend(load().into_iter().filter(|item| match wanted {
+        Some(wanted) => SwapHistoryStatus::from_status(&item.status) == wanted,
+        None => true,
+    }));
+    items.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitive_types::U256;
+
+    fn token(symbol: &str, address: &str, is_native: bool) -> TokenInfo {
+        TokenInfo {
+            address: address.to_string(),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            decimals: 18,
+            chain: ChainType::Ethereum,
+            logo_url: None,
+            is_native,
+            is_spam: false,
+        }
+    }
+
+    #[test]
+    fn detects_wrap_pairs_by_contract_address() {
+        let eth = token("ETH", "", true);
+        let weth = token("WETH", "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", false);
+        let fake_weth = token("WETH", "0x0000000000000000000000000000000000000001", false);
+        let usdc = token("USDC", "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", false);
+
+        assert_eq!(wrap_pair(&eth, &weth), Some(WrapDirection::Wrap));
+        assert_eq!(wrap_pair(&weth, &eth), Some(WrapDirection::Unwrap));
+        assert_eq!(wrap_pair(&eth, &fake_weth), None);
+        assert_eq!(wrap_pair(&eth, &usdc), None);
+        assert_eq!(wrapped_hint(&weth), Some("ETH 的包装版本".to_string()));
+        assert_eq!(wrapped_hint(&fake_weth), None);
+
+        assert_eq!(
+            wrap_pair_by_symbol(ChainType::Solana, "wsol", "SOL"),
+            Some(WrapDirection::Unwrap)
+        );
+        assert!(!is_direct(ChainType::Solana));
+    }
+
+    #[test]
+    fn encodes_deposit_and_withdraw() {
+        let amount = TokenAmount::new(U256::from(1_000_000_000_000_000_000u64), 18);
+        assert_eq!(calldata(WrapDirection::Wrap, &amount), "0xd0e30db0");
+        let withdraw = calldata(WrapDirection::Unwrap, &amount);
+        assert_eq!(withdraw.len(), 2 + 8 + 64);
+        assert!(withdraw.starts_with("0x2e1a7d4d"));
+        assert!(withdraw.ends_with("0de0b6b3a7640000"));
+    }
+}
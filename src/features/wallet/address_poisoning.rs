@@ -0,0 +1,268 @@
+//! Address Poisoning - 地址投毒检测
+//!
+//! 攻击者从首尾字符与常用收款方相同的“仿冒地址”向用户发送 0 金额或极小金额的转账，
+//! 诱导用户之后从交易历史中复制错误的地址。
+//!
+//! 同步交易历史时在本地维护相似度索引：
+//! - 常用收款方：用户主动发送过非零金额的地址（含本地发送记录）
+//! - 疑似投毒地址：向用户转入 0 / 极小金额，且首尾字符与某个常用收款方相同的地址
+//!
+//! 历史列表默认把疑似投毒交易折叠到“疑似垃圾交易”中；发送页粘贴的地址命中疑似投毒地址时
+//! 阻止发送，并与真正的常用收款方逐段对比显示。
+
+use crate::features::wallet::send_safeguards::SendHistory;
+use crate::services::transaction::TransactionHistoryItem;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 相似度索引（LocalStorage）
+const STORAGE_KEY: &str = "address_poisoning_index";
+/// 首尾各比较的字符数（不含 "0x" 前缀）
+const MATCH_CHARS: usize = 4;
+/// 不超过该数量的转入视为粉尘（按代币单位）
+const DUST_AMOUNT: f64 = 0.001;
+/// 最多保留的常用收款方数量（超出时丢弃使用次数最少的）
+const MAX_COUNTERPARTIES: usize = 500;
+
+fn key(address: &str) -> String {
+    address.trim().to_lowercase()
+}
+
+/// 去掉 "0x" 前缀后的地址主体
+fn body(address: &str) -> &str {
+    address.strip_prefix("0x").unwrap_or(address)
+}
+
+/// 两个地址是否“形似”：不相同，但首尾各 [`MATCH_CHARS`] 个字符一致
+pub fn looks_alike(a: &str, b: &str) -> bool {
+    let (a, b) = (key(a), key(b));
+    if a == b || a.starts_with("0x") != b.starts_with("0x") {
+        return false;
+    }
+    let (a, b) = (body(&a), body(&b));
+    if a.len() < MATCH_CHARS * 2 || b.len() < MATCH_CHARS * 2 {
+        return false;
+    }
+    a.get(..MATCH_CHARS) == b.get(..MATCH_CHARS)
+        && a.get(a.len() - MATCH_CHARS..) == b.get(b.len() - MATCH_CHARS..)
+}
+
+fn is_dust(amount: &str) -> bool {
+    amount
+        .trim()
+        .parse::<f64>()
+        .map(|v| v <= DUST_AMOUNT)
+        .unwrap_or(false)
+}
+
+/// 疑似投毒地址与其仿冒的常用收款方
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoisoningMatch {
+    /// 疑似投毒地址
+    pub suspect: String,
+    /// 被仿冒的常用收款方
+    pub legitimate: String,
+    /// 常用收款方地址的原始大小写是否已知（Base58 等区分大小写的地址只有已知时才能直接填入）
+    pub exact_case: bool,
+}
+
+/// 本地相似度索引
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PoisoningIndex {
+    /// 常用收款方（小写地址 → 主动发送次数）
+    #[serde(default)]
+    counterparties: HashMap<String, u32>,
+    /// 疑似投毒地址（小写地址 → 被仿冒的常用收款方）
+    #[serde(default)]
+    flagged: HashMap<String, String>,
+    /// 常用收款方的原始地址（小写地址 → 交易中的原始写法）
+    #[serde(default)]
+    originals: HashMap<String, String>,
+    /// 已计入索引的交易哈希，避免重复同步时重复计数
+    #[serde(default)]
+    seen: HashSet<String>,
+}
+
+impl PoisoningIndex {
+    pub fn load() -> Self {
+        LocalStorage::get::<Self>(STORAGE_KEY).unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    /// 同步交易历史到索引并保存，返回最新索引
+    pub fn sync(txs: &[TransactionHistoryItem], owned: &HashSet<String>) -> Self {
+        let mut index = Self::load();
+        let before = index.clone();
+        index.merge_sends(&SendHistory::load());
+        index.ingest(txs, owned);
+        if index != before {
+            index.save();
+        }
+        index
+    }
+
+    /// 计入本地发送记录
+    fn merge_sends(&mut self, history: &SendHistory) {
+        for (address, count) in history.counts() {
+            let entry = self.counterparties.entry(key(address)).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+    }
+
+    /// 计入交易历史：先统计主动发送的收款方，再检查粉尘转入
+    pub fn ingest(&mut self, txs: &[TransactionHistoryItem], owned: &HashSet<String>) {
+        for tx in txs {
+            let (from, to) = (key(&tx.from), key(&tx.to));
+            if owned.contains(&from) && !owned.contains(&to) && !is_dust(&tx.amount) {
+                let hash = key(&tx.hash);
+                if self.seen.insert(hash) {
+                    *self.counterparties.entry(to.clone()).or_insert(0) += 1;
+                }
+                self.originals.insert(to.clone(), tx.to.trim().to_string());
+                // 用户确实向该地址发送过资产，不再视为投毒地址
+                self.flagged.remove(&to);
+            }
+        }
+        for tx in txs {
+            let (from, to) = (key(&tx.from), key(&tx.to));
+            if !owned.contains(&to)
+                || owned.contains(&from)
+                || self.counterparties.contains_key(&from)
+                || !is_dust(&tx.amount)
+            {
+                continue;
+            }
+            if let Some(legitimate) = self.lookalike_of(&from) {
+                self.flagged.insert(from, legitimate);
+            }
+        }
+        self.prune();
+    }
+
+    fn prune(&mut self) {
+        if self.counterparties.len() <= MAX_COUNTERPARTIES {
+            return;
+        }
+        let mut entries: Vec<_> = self.counterparties.drain().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+        entries.truncate(MAX_COUNTERPARTIES);
+        self.counterparties = entries.into_iter().collect();
+        let counterparties = &self.counterparties;
+        self.originals.retain(|k, _| counterparties.contains_key(k));
+    }
+
+    /// 与该地址形似的常用收款方（使用次数最多者）
+    fn lookalike_of(&self, address: &str) -> Option<String> {
+        self.counterparties
+            .iter()
+            .filter(|(counterparty, _)| looks_alike(counterparty, address))
+            .max_by_key(|(_, count)| **count)
+            .map(|(counterparty, _)| counterparty.clone())
+    }
+
+    /// 交易是否为疑似投毒的粉尘转入
+    pub fn is_suspected(&self, tx: &TransactionHistoryItem) -> bool {
+        is_dust(&tx.amount) && self.flagged.contains_key(&key(&tx.from))
+    }
+
+    /// 发送页检查：接收地址命中疑似投毒地址时返回对比信息
+    pub fn check_recipient(&self, address: &str) -> Option<PoisoningMatch> {
+        let legitimate = self.flagged.get(&key(address))?;
+        let original = self.originals.get(legitimate);
+        Some(PoisoningMatch {
+            suspect: address.trim().to_string(),
+            legitimate: original.unwrap_or(legitimate).clone(),
+            exact_case: original.is_some() || legitimate.starts_with("0x"),
+        })
+    }
+}
+
+/// 把地址切分为与对比地址相同/不同的连续片段（逐字符比较，忽略大小写），用于高亮差异
+pub fn diff_segments(address: &str, other: &str) -> Vec<(String, bool)> {
+    let other: Vec<char> = other.to_lowercase().chars().collect();
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    for (i, c) in address.chars().enumerate() {
+        let same = other
+            .get(i)
+            .is_some_and(|o| c.to_lowercase().eq(o.to_lowercase()));
+        match segments.last_mut() {
+            Some((text, last_same)) if *last_same == same => text.push(c),
+            _ => segments.push((c.to_string(), same)),
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ME: &str = "0x1111111111111111111111111111111111111111";
+    const FRIEND: &str = "0xAbCd567890123456789012345678901234569f3E";
+    const FAKE: &str = "0xabcd000000000000000000000000000000009f3e";
+
+    fn tx(hash: &str, from: &str, to: &str, amount: &str) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            hash: hash.to_string(),
+            tx_type: String::new(),
+            status: "confirmed".to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            amount: amount.to_string(),
+            token: "USDT".to_string(),
+            timestamp: 0,
+            fee: "0".to_string(),
+            fee_rate: None,
+            replaces: None,
+        }
+    }
+
+    #[test]
+    fn flags_dust_from_lookalike_of_counterparty() {
+        let owned: HashSet<String> = [key(ME)].into_iter().collect();
+        let send = tx("0x01", ME, FRIEND, "250");
+        let poison = tx("0x02", FAKE, ME, "0");
+        let mut index = PoisoningIndex::default();
+        index.ingest(&[poison.clone(), send.clone()], &owned);
+        // 重复同步不重复计数
+        index.ingest(&[send], &owned);
+
+        assert_eq!(index.counterparties.get(&key(FRIEND)), Some(&1));
+        assert!(index.is_suspected(&poison));
+        let hit = index
+            .check_recipient("0xABCD000000000000000000000000000000009F3E")
+            .unwrap();
+        assert_eq!(hit.legitimate, FRIEND);
+        assert!(hit.exact_case);
+        assert!(index.check_recipient(FRIEND).is_none());
+
+        // 非粉尘转入或不相似的地址不标记
+        assert!(!index.is_suspected(&tx("0x03", FAKE, ME, "5")));
+        assert!(!looks_alike(
+            FRIEND,
+            "0x9999567890123456789012345678901234569f3e"
+        ));
+        assert!(!looks_alike(FRIEND, FRIEND));
+
+        // 用户确认向该地址发送资产后解除标记
+        index.ingest(&[tx("0x04", ME, FAKE, "1")], &owned);
+        assert!(index.check_recipient(FAKE).is_none());
+    }
+
+    #[test]
+    fn diff_segments_highlight_middle() {
+        let segments = diff_segments("0xabcd12ef", "0xABCD99EF");
+        assert_eq!(
+            segments,
+            vec![
+                ("0xabcd".to_string(), true),
+                ("12".to_string(), false),
+                ("ef".to_string(), true),
+            ]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Backup Reminder - 助记词备份验证提醒
+//!
+//! 备份页选择“稍后提醒”时先完成钱包创建，记录推迟时间，助记词用钱包密码加密暂存，
+//! 直到用户在验证页通过抽查。未验证期间提醒逐步升级：
+//! - 推迟后 3 天内：仪表盘信息提示
+//! - 3 天后：仪表盘警告
+//! - 7 天后：发起任何转出交易前显示拦截页，需先完成验证
+
+use crate::features::wallet::state::Wallet;
+use crate::shared::time::DAY_SECS;
+use gloo_storage::{LocalStorage, Storage};
+use std::collections::HashMap;
+
+/// 推迟验证的钱包（钱包 ID → 推迟时间，Unix 秒）
+const STORAGE_KEY: &str = "backup_verification_deferrals";
+/// 正在进行补验证的钱包（验证页据此区分“创建时验证”与“稍后补验证”）
+const VERIFYING_KEY: &str = "backup_verification_in_progress";
+/// 推迟多久后提醒升级为警告
+pub const WARNING_AFTER_SECS: u64 = 3 * DAY_SECS;
+/// 推迟多久后拦截转出交易
+pub const BLOCKING_AFTER_SECS: u64 = 7 * DAY_SECS;
+
+/// 提醒级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReminderLevel {
+    Info,
+    Warning,
+    /// 转出交易前拦截
+    Blocking,
+}
+
+impl ReminderLevel {
+    pub fn at(deferred_at: u64, now: u64) -> Self {
+        let elapsed = now.saturating_sub(deferred_at);
+        if elapsed >= BLOCKING_AFTER_SECS {
+            Self::Blocking
+        } else if elapsed >= WARNING_AFTER_SECS {
+            Self::Warning
+        } else {
+            Self::Info
+        }
+    }
+}
+
+fn load_all() -> HashMap<String, u64> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+fn save_all(all: &HashMap<String, u64>) {
+    let _ = LocalStorage::set(STORAGE_KEY, all);
+}
+
+/// 记录推迟验证
+pub fn defer(wallet_id: &str, now: u64) {
+    let mut all = load_all();
+    all.entry(wallet_id.to_string()).or_insert(now);
+    save_all(&all);
+}
+
+pub fn deferred_at(wallet_id: &str) -> Option<u64> {
+    load_all().remove(wallet_id)
+}
+
+/// 验证通过或钱包删除后清除记录
+pub fn clear(wallet_id: &str) {
+    let mut all = load_all();
+    if all.remove(wallet_id).is_some() {
+        save_all(&all);
+    }
+}
+
+/// 钱包当前的提醒级别（已验证或未推迟的钱包不提醒）
+pub fn reminder(wallet: &Wallet, now: u64) -> Option<ReminderLevel> {
+    if wallet.backup_verified {
+        return None;
+    }
+    deferred_at(&wallet.id).map(|at| ReminderLevel::at(at, now))
+}
+
+/// 距离拦截转出交易还剩的天数（向上取整）
+pub fn days_until_blocking(deferred_at: u64, now: u64) -> u64 {
+    (deferred_at + BLOCKING_AFTER_SECS)
+        .saturating_sub(now)
+        .div_ceil(DAY_SECS)
+}
+
+/// 开始为该钱包补验证
+pub fn start_verification(wallet_id: &str) {
+    let _ = LocalStorage::set(VERIFYING_KEY, wallet_id);
+}
+
+/// 正在补验证的钱包
+pub fn verifying_wallet() -> Option<String> {
+    LocalStorage::get(VERIFYING_KEY).ok()
+}
+
+/// 结束补验证（通过，或开始创建新钱包时丢弃未完成的补验证）
+pub fn finish_verification() {
+    LocalStorage::delete(VERIFYING_KEY);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reminder_escalates_after_three_and_seven_days() {
+        let start = 1_000_000;
+        assert_eq!(ReminderLevel::at(start, start), ReminderLevel::Info);
+        assert_eq!(
+            ReminderLevel::at(start, start + WARNING_AFTER_SECS - 1),
+            ReminderLevel::Info
+        );
+        assert_eq!(
+            ReminderLevel::at(start, start + WARNING_AFTER_SECS),
+            ReminderLevel::Warning
+        );
+        assert_eq!(
+            ReminderLevel::at(start, start + BLOCKING_AFTER_SECS),
+            ReminderLevel::Blocking
+        );
+        // 系统时间早于推迟时间时按刚推迟处理
+        assert_eq!(ReminderLevel::at(start, 0), ReminderLevel::Info);
+
+        assert_eq!(days_until_blocking(start, start), 7);
+        assert_eq!(days_until_blocking(start, start + DAY_SECS + 1), 6);
+        assert_eq!(days_until_blocking(start, start + BLOCKING_AFTER_SECS), 0);
+    }
+}
@@ -0,0 +1,275 @@
+//! 按链查找与派生钱包账户
+//!
+//! 钱包账户按"账户族"存储：EVM 链（Ethereum/BSC/Polygon）共用同一个以太坊账户。
+//! 查找时只返回目标链的账户，绝不退回其他链的地址——用错链地址查询余额会得到错误结果。
+//! 账户记录自己的派生路径（导入时可自定义），签名时按该路径派生私钥。
+
+use crate::crypto::derivation::{Curve, DerivationPath};
+use crate::crypto::key_manager::{decode_private_key_hex, KeyManager};
+use crate::features::wallet::state::{Account, AccountType, Wallet};
+use crate::features::wallet::unlock::ensure_wallet_unlocked;
+use crate::services::address_detector::ChainType;
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use dioxus::prelude::ReadableExt;
+
+/// 助记词钱包默认派生的账户族
+pub const DERIVED_CHAINS: [ChainType; 4] = [
+    ChainType::Ethereum,
+    ChainType::Bitcoin,
+    ChainType::Solana,
+    ChainType::TON,
+];
+
+/// 链对应的账户族（EVM 链共用以太坊账户）
+pub fn account_family(chain: ChainType) -> ChainType {
+    match chain {
+        ChainType::BSC | ChainType::Polygon => ChainType::Ethereum,
+        other => other,
+    }
+}
+
+/// 账户族的默认派生路径（BIP44/BIP84；Solana 与 TON 为 SLIP-0010 全硬化路径）
+pub fn derivation_path(chain: ChainType) -> &'static str {
+    match account_family(chain) {
+        ChainType::Bitcoin => "m/84'/0'/0'/0/0",
+        ChainType::Solana => "m/44'/501'/0'/0'",
+        ChainType::TON => "m/44'/607'/0'/0'/0'/0'",
+        _ => "m/44'/60'/0'/0/0",
+    }
+}
+
+/// 链使用的派生曲线
+pub fn curve(chain: ChainType) -> Curve {
+    match account_family(chain) {
+        ChainType::Solana | ChainType::TON => Curve::Ed25519,
+        _ => Curve::Secp256k1,
+    }
+}
+
+/// 钱包中目标链的账户；没有时返回 None（不退回第一个账户）
+pub fn account_for_chain(wallet: &Wallet, chain: ChainType) -> Option<&Account> {
+    let family = account_family(chain);
+    wallet
+        .accounts
+        .iter()
+        .find(|acc| ChainType::from_str(&acc.chain).map(account_family) == Some(family))
+}
+
+/// 钱包能否按链补派生账户（只有助记词钱包可以）
+pub fn can_derive(wallet: &Wallet) -> bool {
+    wallet
+        .accounts
+        .iter()
+        .any(|acc| acc.account_type == AccountType::Derived)
+}
+
+/// 私钥对应的（地址, 公钥）
+fn address_and_public_key(
+    key_manager: &KeyManager,
+    family: ChainType,
+    priv_key: &str,
+) -> Result<(String, String)> {
+    Ok(match family {
+        // 压缩格式公钥
+        ChainType::Bitcoin => (
+            key_manager.get_btc_address(priv_key)?,
+            secp256k1_public_key(priv_key, true)?,
+        ),
+        ChainType::Solana => (
+            key_manager.get_sol_address(priv_key)?,
+            key_manager.get_sol_public_key(priv_key)?,
+        ),
+        ChainType::TON => (
+            key_manager.get_ton_address(priv_key)?,
+            key_manager.get_ton_public_key(priv_key)?,
+        ),
+        // ✅ 完整的65字节未压缩公钥（包含0x04前缀）
+        _ => (
+            key_manager.get_eth_address(priv_key)?,
+            secp256k1_public_key(priv_key, false)?,
+        ),
+    })
+}
+
+/// 按指定派生路径从种子派生账户（同时提取公钥）
+pub fn derive_account_at(
+    key_manager: &KeyManager,
+    chain: ChainType,
+    path: &DerivationPath,
+) -> Result<Account> {
+    let family = account_family(chain);
+    let priv_key = key_manager.derive_private_key_at(curve(family), path)?;
+    let (address, public_key) = address_and_public_key(key_manager, family, &priv_key)?;
+
+    Ok(Account {
+        address,
+        chain: family.as_str().to_string(),
+        public_key,
+        derivation_path: Some(path.to_string()),
+        account_type: AccountType::Derived,
+        balance: "0".to_string(),
+    })
+}
+
+/// 按链的默认派生路径从种子派生账户
+pub fn derive_account(key_manager: &KeyManager, chain: ChainType) -> Result<Account> {
+    let path = derivation_path(chain)
+        .parse::<DerivationPath>()
+        .map_err(|e| anyhow!(e))?;
+    derive_account_at(key_manager, chain, &path)
+}
+
+/// 旧版按账户序号派生（早期账户记录的路径与实际派生方式不一致时使用）
+fn legacy_private_key(key_manager: &KeyManager, family: ChainType, index: u32) -> Result<String> {
+    match family {
+        ChainType::Bitcoin => key_manager.derive_btc_private_key(index),
+        ChainType::Solana => key_manager.derive_sol_private_key(index),
+        ChainType::TON => key_manager.derive_ton_private_key(index),
+        _ => key_manager.derive_eth_private_key(index),
+    }
+}
+
+/// 账户的签名私钥：按账户记录的派生路径派生，并核对派生出的地址与账户地址一致。
+/// 早期账户记录的路径不准确，依次尝试链的默认路径和按账户序号派生的旧规则；
+/// 都对不上时返回错误，绝不用与账户地址不一致的私钥签名
+pub fn signing_key(
+    key_manager: &KeyManager,
+    account: &Account,
+    fallback_index: u32,
+) -> Result<String> {
+    let family = ChainType::from_str(&account.chain)
+        .map(account_family)
+        .ok_or_else(|| anyhow!("不支持的链: {}", account.chain))?;
+    let matches_account = |priv_key: &String| {
+        key_manager_address(key_manager, family, priv_key)
+            .is_some_and(|address| address.eq_ignore_ascii_case(&account.address))
+    };
+    [
+        account.derivation_path.as_deref(),
+        Some(derivation_path(family)),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|p| p.parse::<DerivationPath>().ok())
+    .filter_map(|path| key_manager.derive_private_key_at(curve(family), &path).ok())
+    .chain(legacy_private_key(key_manager, family, fallback_index).ok())
+    .find(matches_account)
+    .ok_or_else(|| anyhow!("无法派生与账户地址一致的私钥: {}", account.address))
+}
+
+/// 已选钱包中 `address` 对应账户的签名私钥（要求钱包已解锁）
+pub fn selected_wallet_signing_key(app_state: &AppState, address: &str) -> Result<String> {
+    let wallet_state = app_state.wallet.read();
+    let wallet_id = wallet_state
+        .selected_wallet_id
+        .as_ref()
+        .ok_or_else(|| anyhow!("未选择钱包"))?;
+    ensure_wallet_unlocked(app_state, wallet_id)?;
+
+    let key_manager = app_state
+        .key_manager
+        .read()
+        .clone()
+        .ok_or_else(|| anyhow!("钱包未解锁，无法签名交易"))?;
+    let (account_index, account) = wallet_state
+        .wallets
+        .iter()
+        .find(|w| w.id == *wallet_id)
+        .and_then(|w| {
+            w.accounts
+                .iter()
+                .enumerate()
+                .find(|(_, a)| a.address.eq_ignore_ascii_case(address))
+        })
+        .ok_or_else(|| anyhow!("当前钱包中没有该地址的账户: {}", address))?;
+
+    signing_key(&key_manager, account, account_index as u32)
+        .map_err(|e| anyhow!("获取私钥失败: {}", e))
+}
+
+fn key_manager_address(
+    key_manager: &KeyManager,
+    family: ChainType,
+    priv_key: &str,
+) -> Option<String> {
+    address_and_public_key(key_manager, family, priv_key)
+        .ok()
+        .map(|(address, _)| address)
+}
+
+fn secp256k1_public_key(priv_key_hex: &str, compressed: bool) -> Result<String> {
+    use k256::ecdsa::{SigningKey, VerifyingKey};
+    let signing_key = SigningKey::from_slice(&decode_private_key_hex(priv_key_hex)?)?;
+    let verifying_key = VerifyingKey::from(&signing_key);
+    Ok(hex::encode(
+        verifying_key.to_encoded_point(compressed).as_bytes(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(chain: &str, address: &str) -> Account {
+        Account {
+            address: address.to_string(),
+            chain: chain.to_string(),
+            public_key: String::new(),
+            derivation_path: None,
+            account_type: AccountType::Derived,
+            balance: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn default_paths_match_legacy_derivation() {
+        let key_manager = KeyManager::new(vec![7u8; 64]);
+        for chain in DERIVED_CHAINS {
+            let account = derive_account(&key_manager, chain).unwrap();
+            let legacy = legacy_private_key(&key_manager, chain, 0).unwrap();
+            assert_eq!(signing_key(&key_manager, &account, 0).unwrap(), legacy);
+        }
+    }
+
+    #[test]
+    fn signing_uses_stored_custom_path() {
+        let key_manager = KeyManager::new(vec![7u8; 64]);
+        let ledger_live: DerivationPath = "m/44'/60'/1'/0/0".parse().unwrap();
+        let account = derive_account_at(&key_manager, ChainType::Ethereum, &ledger_live).unwrap();
+        assert_eq!(account.derivation_path.as_deref(), Some("m/44'/60'/1'/0/0"));
+        let key = signing_key(&key_manager, &account, 0).unwrap();
+        assert_ne!(key, key_manager.derive_eth_private_key(0).unwrap());
+        assert_eq!(key_manager.get_eth_address(&key).unwrap(), account.address);
+
+        // 早期账户记录的路径不准确时退回默认路径
+        let mut legacy = derive_account(&key_manager, ChainType::Solana).unwrap();
+        legacy.derivation_path = Some("m/44'/501'/0'/0'/0".to_string());
+        assert_eq!(
+            signing_key(&key_manager, &legacy, 2).unwrap(),
+            key_manager.derive_sol_private_key(0).unwrap()
+        );
+
+        // 任何规则派生出的地址都与账户不一致：拒绝签名
+        let foreign = derive_account(&KeyManager::new(vec![9u8; 64]), ChainType::Ethereum).unwrap();
+        assert!(signing_key(&key_manager, &foreign, 0).is_err());
+    }
+
+    #[test]
+    fn account_for_chain_never_falls_back_to_other_chain() {
+        let mut wallet = Wallet::new("w".to_string(), "test".to_string());
+        wallet.accounts.push(account("ethereum", "0xabc"));
+        wallet.accounts.push(account("solana", "So1"));
+
+        assert_eq!(
+            account_for_chain(&wallet, ChainType::BSC).map(|a| a.address.as_str()),
+            Some("0xabc")
+        );
+        assert_eq!(
+            account_for_chain(&wallet, ChainType::Solana).map(|a| a.address.as_str()),
+            Some("So1")
+        );
+        assert!(account_for_chain(&wallet, ChainType::Bitcoin).is_none());
+        assert!(account_for_chain(&wallet, ChainType::TON).is_none());
+    }
+}
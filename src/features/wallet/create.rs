@@ -316,15 +316,15 @@ pub fn WalletCreatePage() -> Element {
 /// 注册钱包地址到后端
 async fn register_wallet_with_backend(wallet_data: WalletData, user_state: Signal<UserState>) {
     // 🔍 调试：检查public_keys是否为空
-    tracing::info!("WalletData public_keys count: {}", wallet_data.public_keys.len());
+    log::info!("WalletData public_keys count: {}", wallet_data.public_keys.len());
     for (chain, pubkey) in &wallet_data.public_keys {
-        tracing::info!("Chain: {}, PubKey length: {}", chain, pubkey.len());
+        log::info!("Chain: {}, PubKey length: {}", chain, pubkey.len());
     }
     
     // 🔍 调试：检查addresses和public_keys的一致性
     for (chain, _) in &wallet_data.addresses {
         if !wallet_data.public_keys.contains_key(chain) {
-            tracing::error!("❌ CRITICAL: Chain {} has address but NO public_key!", chain);
+            log::error!("❌ CRITICAL: Chain {} has address but NO public_key!", chain);
         }
     }
     
@@ -335,11 +335,11 @@ async fn register_wallet_with_backend(wallet_data: WalletData, user_state: Signa
         // ✅ 修复：使用filter_map过滤掉缺失或空的公钥
         let pubkey = wallet_data.public_keys.get(chain)?;
         if pubkey.is_empty() {
-            tracing::error!("❌ Empty public_key for chain: {}", chain);
+            log::error!("❌ Empty public_key for chain: {}", chain);
             return None;
         }
         
-        tracing::info!("✅ Preparing wallet: chain={}, addr={}, pubkey_len={}", 
+        log::info!("✅ Preparing wallet: chain={}, addr={}, pubkey_len={}", 
             chain, address, pubkey.len());
         
         Some(WalletRegistrationInfo {
@@ -353,11 +353,11 @@ async fn register_wallet_with_backend(wallet_data: WalletData, user_state: Signa
     
     // ✅ 验证：确保至少有一个有效钱包
     if wallets.is_empty() {
-        tracing::error!("❌ CRITICAL: No valid wallets to register - all chains missing public_keys!");
+        log::error!("❌ CRITICAL: No valid wallets to register - all chains missing public_keys!");
         return;
     }
     
-    tracing::info!("✅ Total valid wallets: {}", wallets.len());
+    log::info!("✅ Total valid wallets: {}", wallets.len());
     
     // 使用WalletService批量创建钱包
     let app_state_ctx = app_state.clone();
@@ -367,19 +367,19 @@ async fn register_wallet_with_backend(wallet_data: WalletData, user_state: Signa
     
     match wallet_service.batch_create_wallets(request).await {
         Ok(response) => {
-            tracing::info!("✅ Wallets registered successfully: {} created, {} failed", 
+            log::info!("✅ Wallets registered successfully: {} created, {} failed", 
                 response.wallets.len(), response.failed.len());
             
             for wallet in &response.wallets {
-                tracing::info!("  Created: {} - {}", wallet.chain, wallet.address);
+                log::info!("  Created: {} - {}", wallet.chain, wallet.address);
             }
             
             for error in &response.failed {
-                tracing::error!("  Failed: {} - {} ({})", error.chain, error.address, error.error);
+                log::error!("  Failed: {} - {} ({})", error.chain, error.address, error.error);
             }
         }
         Err(e) => {
-            tracing::error!("❌ Failed to register wallets: {}", e);
+            log::error!("❌ Failed to register wallets: {}", e);
         }
     }
 }
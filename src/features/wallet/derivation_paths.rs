@@ -0,0 +1,183 @@
+//! Derivation Paths - 导入助记词时可选的派生路径
+//!
+//! 从其他钱包迁移时，同一助记词在不同钱包下的地址可能不同（Ledger Live、
+//! 旧版 Ledger、Phantom 等使用的路径各不相同）。这里提供各链常见的路径模板，
+//! 以及自定义路径的校验与预览。模板中的 `{i}` 为账户序号。
+
+use crate::crypto::derivation::DerivationPath;
+use crate::features::wallet::chain_accounts::{account_family, curve};
+use crate::services::address_detector::ChainType;
+
+/// 预览的地址数量
+pub const PREVIEW_COUNT: u32 = 5;
+
+/// 路径模板
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathTemplate {
+    pub id: &'static str,
+    pub label: &'static str,
+    /// 适用的账户族
+    pub family: ChainType,
+    /// 路径格式，`{i}` 为账户序号
+    pub pattern: &'static str,
+}
+
+impl PathTemplate {
+    /// 第 `index` 个账户的路径
+    pub fn path(&self, index: u32) -> DerivationPath {
+        self.pattern
+            .replace("{i}", &index.to_string())
+            .parse()
+            .expect("内置路径模板必须合法")
+    }
+}
+
+/// 内置模板（每个账户族的第一个为默认模板）
+pub const TEMPLATES: &[PathTemplate] = &[
+    PathTemplate {
+        id: "bip44",
+        label: "BIP44 标准（MetaMask 等）",
+        family: ChainType::Ethereum,
+        pattern: "m/44'/60'/0'/0/{i}",
+    },
+    PathTemplate {
+        id: "ledger_live",
+        label: "Ledger Live",
+        family: ChainType::Ethereum,
+        pattern: "m/44'/60'/{i}'/0/0",
+    },
+    PathTemplate {
+        id: "ledger_legacy",
+        label: "Ledger 旧版（MEW / MyCrypto）",
+        family: ChainType::Ethereum,
+        pattern: "m/44'/60'/0'/{i}",
+    },
+    PathTemplate {
+        id: "bip84",
+        label: "BIP84 原生隔离见证",
+        family: ChainType::Bitcoin,
+        pattern: "m/84'/0'/0'/0/{i}",
+    },
+    PathTemplate {
+        id: "bip44_btc",
+        label: "BIP44 路径（原生隔离见证地址）",
+        family: ChainType::Bitcoin,
+        pattern: "m/44'/0'/0'/0/{i}",
+    },
+    PathTemplate {
+        id: "solana",
+        label: "Solana 标准（Phantom 等）",
+        family: ChainType::Solana,
+        pattern: "m/44'/501'/{i}'/0'",
+    },
+    PathTemplate {
+        id: "solana_no_change",
+        label: "Solana 无找零层（Ledger 等）",
+        family: ChainType::Solana,
+        pattern: "m/44'/501'/{i}'",
+    },
+    PathTemplate {
+        id: "ton",
+        label: "TON 标准",
+        family: ChainType::TON,
+        pattern: "m/44'/607'/0'/0'/0'/{i}'",
+    },
+];
+
+/// 链可用的模板
+pub fn templates_for(chain: ChainType) -> Vec<&'static PathTemplate> {
+    let family = account_family(chain);
+    TEMPLATES.iter().filter(|t| t.family == family).collect()
+}
+
+/// 链的默认模板
+pub fn default_template(chain: ChainType) -> &'static PathTemplate {
+    templates_for(chain)
+        .into_iter()
+        .next()
+        .expect("每个账户族都有默认模板")
+}
+
+/// 校验用户输入的自定义路径（格式与曲线限制）
+pub fn validate_custom(chain: ChainType, input: &str) -> Result<DerivationPath, String> {
+    let path: DerivationPath = input.parse()?;
+    path.check_curve(curve(chain))?;
+    Ok(path)
+}
+
+/// 预览路径的选择
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathChoice {
+    Template(&'static str),
+    Custom(String),
+}
+
+/// 选择对应的前 [`PREVIEW_COUNT`] 个路径；自定义路径从其最后一层开始递增
+pub fn preview_paths(chain: ChainType, choice: &PathChoice) -> Result<Vec<DerivationPath>, String> {
+    match choice {
+        PathChoice::Template(id) => {
+            let template = templates_for(chain)
+                .into_iter()
+                .find(|t| t.id == *id)
+                .ok_or_else(|| format!("该链不支持路径模板: {}", id))?;
+            Ok((0..PREVIEW_COUNT).map(|i| template.path(i)).collect())
+        }
+        PathChoice::Custom(input) => {
+            let path = validate_custom(chain, input)?;
+            (0..PREVIEW_COUNT)
+                .map(|offset| {
+                    path.offset_last(offset)
+                        .ok_or_else(|| "路径最后一层的序号过大".to_string())
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::wallet::chain_accounts::{derivation_path, DERIVED_CHAINS};
+
+    #[test]
+    fn default_templates_match_default_paths() {
+        for chain in DERIVED_CHAINS {
+            assert_eq!(
+                default_template(chain).path(0).to_string(),
+                derivation_path(chain)
+            );
+        }
+    }
+
+    #[test]
+    fn templates_are_valid_for_their_curve() {
+        for template in TEMPLATES {
+            let path = template.path(3);
+            assert!(path.check_curve(curve(template.family)).is_ok());
+        }
+    }
+
+    #[test]
+    fn previews_template_and_custom_paths() {
+        let ledger =
+            preview_paths(ChainType::Polygon, &PathChoice::Template("ledger_live")).unwrap();
+        assert_eq!(ledger.len(), PREVIEW_COUNT as usize);
+        assert_eq!(ledger[2].to_string(), "m/44'/60'/2'/0/0");
+
+        let custom = preview_paths(
+            ChainType::Solana,
+            &PathChoice::Custom("m/44'/501'/7'".to_string()),
+        )
+        .unwrap();
+        assert_eq!(custom[4].to_string(), "m/44'/501'/11'");
+
+        assert!(preview_paths(ChainType::Bitcoin, &PathChoice::Template("solana")).is_err());
+    }
+
+    #[test]
+    fn custom_ed25519_paths_must_be_hardened() {
+        assert!(validate_custom(ChainType::TON, "m/44'/607'/0'/0").is_err());
+        assert!(validate_custom(ChainType::Ethereum, "m/44'/60'/0'/0").is_ok());
+        assert!(validate_custom(ChainType::Ethereum, "44/60").is_err());
+    }
+}
@@ -0,0 +1,492 @@
+//! Account Discovery - 导入助记词后的账户发现
+//!
+//! 导入时只创建每条链的 0 号账户，但用户在原钱包中可能用过更多账户。导入完成后
+//! 按链并行扫描后续账户序号：有余额或交易记录即视为已使用，连续 [`GAP_LIMIT`] 个
+//! 未使用账户后停止。发现的账户默认勾选，由用户决定是否加入钱包。
+//!
+//! 扫描状态保存在 `AppState.account_discovery` 中，离开导入页后扫描继续进行；
+//! 用户完成导入后新发现的账户直接追加到钱包。
+
+use crate::crypto::derivation::DerivationPath;
+use crate::crypto::key_manager::KeyManager;
+use crate::features::wallet::chain_accounts;
+use crate::features::wallet::derivation_paths;
+use crate::features::wallet::hooks::WalletController;
+use crate::features::wallet::state::Account;
+use crate::services::address_detector::ChainType;
+use crate::services::balance::{native_symbol, BalanceService};
+use crate::services::transaction::TransactionService;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 连续未使用账户数达到该值后停止扫描
+pub const GAP_LIMIT: u32 = 3;
+/// 每条链最多扫描的账户序号（防止异常情况下无限扫描）
+pub const MAX_INDEX: u32 = 20;
+
+/// 单条链的扫描状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanStatus {
+    Scanning,
+    Done,
+    Cancelled,
+    Failed(String),
+}
+
+/// 发现的账户
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveredAccount {
+    pub index: u32,
+    pub account: Account,
+    /// 原生代币余额（如 "0.5 ETH"），查询失败为 None
+    pub balance: Option<String>,
+    pub tx_count: usize,
+    /// 是否加入钱包（发现的账户默认勾选）
+    pub include: bool,
+    /// 已加入钱包
+    pub added: bool,
+    /// 用户手动添加（未经扫描）
+    pub manual: bool,
+}
+
+/// 单条链的扫描进度
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainScan {
+    pub chain: ChainType,
+    /// 0 号账户的派生路径，后续序号据此推算
+    pub base: DerivationPath,
+    /// 下一个待扫描的序号
+    pub next_index: u32,
+    /// 当前连续未使用账户数
+    pub gap: u32,
+    pub status: ScanStatus,
+    pub found: Vec<DiscoveredAccount>,
+}
+
+impl ChainScan {
+    pub fn new(chain: ChainType, base: DerivationPath) -> Self {
+        Self {
+            chain,
+            base,
+            // 0 号账户导入时已创建
+            next_index: 1,
+            gap: 0,
+            status: ScanStatus::Scanning,
+            found: Vec::new(),
+        }
+    }
+
+    /// 记录一个序号的扫描结果，返回是否继续扫描
+    pub fn record(&mut self, used: bool) -> bool {
+        self.next_index += 1;
+        self.gap = if used { 0 } else { self.gap + 1 };
+        if self.gap >= GAP_LIMIT || self.next_index > MAX_INDEX {
+            self.status = ScanStatus::Done;
+            return false;
+        }
+        true
+    }
+
+    /// 已扫描的账户数
+    pub fn scanned(&self) -> u32 {
+        self.next_index - 1
+    }
+
+    /// 下一个可手动添加的序号（跳过已列出的账户）
+    pub fn next_manual_index(&self) -> u32 {
+        self.found.iter().map(|f| f.index).max().unwrap_or(0) + 1
+    }
+}
+
+/// 账户发现的整体状态
+#[derive(Clone, Default)]
+pub struct AccountDiscovery {
+    pub wallet_id: Option<String>,
+    pub chains: Vec<ChainScan>,
+    /// 用户已完成导入：之后发现的账户直接加入钱包
+    pub auto_append: bool,
+    /// 扫描期间持有的密钥（关闭发现面板时清除，Drop 时清零）
+    key_manager: Option<KeyManager>,
+}
+
+impl AccountDiscovery {
+    pub fn is_active_for(&self, wallet_id: &str) -> bool {
+        self.wallet_id.as_deref() == Some(wallet_id)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.chains.iter().any(|c| c.status == ScanStatus::Scanning)
+    }
+
+    fn chain_mut(&mut self, chain: ChainType) -> Option<&mut ChainScan> {
+        self.chains.iter_mut().find(|c| c.chain == chain)
+    }
+
+    /// 切换某个发现账户的勾选状态
+    pub fn toggle(&mut self, chain: ChainType, index: u32) {
+        if let Some(found) = self
+            .chain_mut(chain)
+            .and_then(|c| c.found.iter_mut().find(|f| f.index == index))
+        {
+            found.include = !found.include;
+        }
+    }
+
+    /// 勾选且尚未加入钱包的账户，取出后标记为已加入
+    pub fn take_included(&mut self) -> Vec<Account> {
+        self.chains
+            .iter_mut()
+            .flat_map(|c| c.found.iter_mut())
+            .filter(|f| f.include && !f.added)
+            .map(|f| {
+                f.added = true;
+                f.account.clone()
+            })
+            .collect()
+    }
+
+    /// 用户已完成导入且扫描全部结束后，不再需要密钥
+    fn release_key_if_idle(&mut self) {
+        if self.auto_append && !self.is_running() {
+            self.key_manager = None;
+        }
+    }
+
+    /// 取消仍在进行的扫描
+    pub fn cancel(&mut self) {
+        for chain in &mut self.chains {
+            if chain.status == ScanStatus::Scanning {
+                chain.status = ScanStatus::Cancelled;
+            }
+        }
+    }
+}
+
+/// 第 `index` 个账户的派生路径：0 号路径与某个模板一致时按模板推算，否则递增最后一层
+pub fn account_path(chain: ChainType, base: &DerivationPath, index: u32) -> Option<DerivationPath> {
+    match derivation_paths::templates_for(chain)
+        .into_iter()
+        .find(|t| t.path(0) == *base)
+    {
+        Some(template) => Some(template.path(index)),
+        None => base.offset_last(index),
+    }
+}
+
+/// 查询账户是否使用过：返回（余额显示, 交易数, 是否使用过）
+async fn probe(
+    app_state: AppState,
+    account: &Account,
+) -> Result<(Option<String>, usize, bool), String> {
+    let balance_service = BalanceService::new(app_state);
+    let tx_service = TransactionService::new(app_state);
+    let (balance, history) = futures::future::join(
+        balance_service.get_native_balance(account),
+        tx_service.get_history(&account.address, &account.chain),
+    )
+    .await;
+    if let (Err(balance_err), Err(_)) = (&balance, &history) {
+        return Err(format!("查询失败: {}", balance_err));
+    }
+    let has_balance = balance.as_ref().is_ok_and(|b| !b.is_zero());
+    let tx_count = history.map(|h| h.len()).unwrap_or(0);
+    let balance = balance
+        .ok()
+        .map(|b| format!("{} {}", b.format(6), native_symbol(&account.chain)));
+    Ok((balance, tx_count, has_balance || tx_count > 0))
+}
+
+/// 开始为刚导入的钱包扫描账户；`bases` 为各链 0 号账户的派生路径
+pub fn start(
+    app_state: AppState,
+    wallet_ctrl: WalletController,
+    wallet_id: String,
+    key_manager: KeyManager,
+    bases: HashMap<ChainType, DerivationPath>,
+) {
+    let mut discovery = app_state.account_discovery;
+    let chains: Vec<ChainScan> = bases
+        .into_iter()
+        .map(|(chain, base)| ChainScan::new(chain, base))
+        .collect();
+    let chain_types: Vec<ChainType> = chains.iter().map(|c| c.chain).collect();
+    discovery.set(AccountDiscovery {
+        wallet_id: Some(wallet_id.clone()),
+        chains,
+        auto_append: false,
+        key_manager: Some(key_manager),
+    });
+
+    // 每条链独立扫描；离开导入页后继续进行
+    for chain in chain_types {
+        let wallet_id = wallet_id.clone();
+        dioxus::core::spawn_forever(async move {
+            scan_chain(app_state, wallet_ctrl, wallet_id, chain).await;
+        });
+    }
+}
+
+async fn scan_chain(
+    app_state: AppState,
+    wallet_ctrl: WalletController,
+    wallet_id: String,
+    chain: ChainType,
+) {
+    let mut discovery = app_state.account_discovery;
+    loop {
+        // 每个序号前检查是否已取消或已开始新的扫描
+        let derived = {
+            let state = discovery.peek();
+            if !state.is_active_for(&wallet_id) {
+                return;
+            }
+            let Some(scan) = state.chains.iter().find(|c| c.chain == chain) else {
+                return;
+            };
+            if scan.status != ScanStatus::Scanning {
+                return;
+            }
+            let index = scan.next_index;
+            let (Some(key_manager), Some(path)) = (
+                state.key_manager.as_ref(),
+                account_path(chain, &scan.base, index),
+            ) else {
+                return;
+            };
+            chain_accounts::derive_account_at(key_manager, chain, &path)
+                .map(|account| (index, account))
+                .map_err(|e| e.to_string())
+        };
+        let (index, account) = match derived {
+            Ok(derived) => derived,
+            Err(e) => {
+                set_status(app_state, &wallet_id, chain, ScanStatus::Failed(e));
+                return;
+            }
+        };
+
+        let probed = probe(app_state, &account).await;
+
+        let mut state = discovery.write();
+        if !state.is_active_for(&wallet_id) {
+            return;
+        }
+        let auto_append = state.auto_append;
+        let Some(scan) = state.chain_mut(chain) else {
+            return;
+        };
+        if scan.status != ScanStatus::Scanning {
+            return;
+        }
+        let (balance, tx_count, used) = match probed {
+            Ok(probed) => probed,
+            Err(e) => {
+                scan.status = ScanStatus::Failed(e);
+                state.release_key_if_idle();
+                return;
+            }
+        };
+        if used {
+            scan.found.push(DiscoveredAccount {
+                index,
+                account: account.clone(),
+                balance,
+                tx_count,
+                include: true,
+                added: auto_append,
+                manual: false,
+            });
+        }
+        let more = scan.record(used);
+        state.release_key_if_idle();
+        drop(state);
+
+        if used && auto_append {
+            if let Err(e) = wallet_ctrl
+                .add_discovered_accounts(&wallet_id, vec![account])
+                .await
+            {
+                log::warn!("追加发现的 {} 账户失败: {}", chain.label(), e);
+            } else {
+                AppState::show_info(
+                    app_state.toasts,
+                    format!("已发现并添加 {} 账户 #{}", chain.label(), index),
+                );
+            }
+        }
+        if !more {
+            return;
+        }
+    }
+}
+
+fn set_status(app_state: AppState, wallet_id: &str, chain: ChainType, status: ScanStatus) {
+    let mut discovery = app_state.account_discovery;
+    let mut state = discovery.write();
+    if !state.is_active_for(wallet_id) {
+        return;
+    }
+    if let Some(scan) = state.chain_mut(chain) {
+        scan.status = status;
+    }
+    state.release_key_if_idle();
+}
+
+/// 手动添加某条链的下一个账户（不检查是否使用过），默认勾选
+pub fn add_manual(app_state: AppState, chain: ChainType) -> Result<(), String> {
+    let mut discovery = app_state.account_discovery;
+    let mut state = discovery.write();
+    let key_manager = state
+        .key_manager
+        .clone()
+        .ok_or_else(|| "账户发现已结束".to_string())?;
+    let scan = state
+        .chain_mut(chain)
+        .ok_or_else(|| format!("未扫描 {} 账户", chain.label()))?;
+    let index = scan.next_manual_index();
+    let path = account_path(chain, &scan.base, index).ok_or_else(|| "账户序号过大".to_string())?;
+    let account =
+        chain_accounts::derive_account_at(&key_manager, chain, &path).map_err(|e| e.to_string())?;
+    scan.found.push(DiscoveredAccount {
+        index,
+        account,
+        balance: None,
+        tx_count: 0,
+        include: true,
+        added: false,
+        manual: true,
+    });
+    scan.found.sort_by_key(|f| f.index);
+    Ok(())
+}
+
+/// 加入勾选的账户；`finish` 为 true 时表示用户已完成导入，之后发现的账户直接加入
+pub async fn commit(
+    app_state: AppState,
+    wallet_ctrl: WalletController,
+    finish: bool,
+) -> Result<usize, String> {
+    let mut discovery = app_state.account_discovery;
+    let (wallet_id, accounts) = {
+        let mut state = discovery.write();
+        let Some(wallet_id) = state.wallet_id.clone() else {
+            return Ok(0);
+        };
+        if finish {
+            state.auto_append = true;
+            state.release_key_if_idle();
+        }
+        (wallet_id, state.take_included())
+    };
+    if accounts.is_empty() {
+        return Ok(0);
+    }
+    wallet_ctrl
+        .add_discovered_accounts(&wallet_id, accounts)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 取消扫描并清除扫描期间持有的密钥
+pub fn cancel(app_state: AppState) {
+    let mut discovery = app_state.account_discovery;
+    let mut state = discovery.write();
+    state.cancel();
+    state.key_manager = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(chain: ChainType) -> ChainScan {
+        ChainScan::new(
+            chain,
+            chain_accounts::derivation_path(chain).parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn stops_after_gap_limit_of_unused_accounts() {
+        let mut scan = scan(ChainType::Ethereum);
+        // 1 号已使用，2、3 未使用，4 已使用，5、6、7 未使用
+        let results = [true, false, false, true, false, false];
+        for used in results {
+            assert!(scan.record(used));
+        }
+        assert!(!scan.record(false));
+        assert_eq!(scan.status, ScanStatus::Done);
+        assert_eq!(scan.scanned(), 7);
+    }
+
+    #[test]
+    fn stops_at_max_index() {
+        let mut scan = scan(ChainType::Bitcoin);
+        let mut steps = 0;
+        while scan.record(true) {
+            steps += 1;
+        }
+        assert_eq!(scan.scanned(), MAX_INDEX);
+        assert_eq!(steps, MAX_INDEX - 1);
+    }
+
+    #[test]
+    fn account_paths_follow_matching_template() {
+        let sol: DerivationPath = chain_accounts::derivation_path(ChainType::Solana)
+            .parse()
+            .unwrap();
+        assert_eq!(
+            account_path(ChainType::Solana, &sol, 2)
+                .unwrap()
+                .to_string(),
+            "m/44'/501'/2'/0'"
+        );
+        let bip44: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        assert_eq!(
+            account_path(ChainType::Ethereum, &bip44, 3)
+                .unwrap()
+                .to_string(),
+            "m/44'/60'/0'/0/3"
+        );
+        let custom: DerivationPath = "m/44'/60'/5'/0/9".parse().unwrap();
+        assert_eq!(
+            account_path(ChainType::Ethereum, &custom, 1)
+                .unwrap()
+                .to_string(),
+            "m/44'/60'/5'/0/10"
+        );
+    }
+
+    #[test]
+    fn take_included_only_returns_checked_accounts_once() {
+        let key_manager = KeyManager::new(vec![3u8; 64]);
+        let mut state = AccountDiscovery {
+            wallet_id: Some("w".to_string()),
+            chains: vec![scan(ChainType::Ethereum)],
+            auto_append: false,
+            key_manager: Some(key_manager.clone()),
+        };
+        for index in [1, 2] {
+            let path = account_path(ChainType::Ethereum, &state.chains[0].base, index).unwrap();
+            state.chains[0].found.push(DiscoveredAccount {
+                index,
+                account: chain_accounts::derive_account_at(
+                    &key_manager,
+                    ChainType::Ethereum,
+                    &path,
+                )
+                .unwrap(),
+                balance: None,
+                tx_count: 1,
+                include: true,
+                added: false,
+                manual: false,
+            });
+        }
+        state.toggle(ChainType::Ethereum, 2);
+        assert_eq!(state.take_included().len(), 1);
+        assert!(state.take_included().is_empty());
+        assert_eq!(state.chains[0].next_manual_index(), 3);
+    }
+}
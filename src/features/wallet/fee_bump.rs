@@ -0,0 +1,244 @@
+//! Bitcoin Fee Bump - Bitcoin 交易加速
+//!
+//! - RBF：未确认的转出交易以更高费率重新签名并替换原交易
+//! - CPFP：未确认的转入交易由我们花费其输出，以子交易手续费带动父交易确认
+//! - 历史记录中，替换交易确认后与原交易合并为一条
+
+use crate::crypto::tx_signer::BitcoinTxSigner;
+use crate::features::wallet::chain_accounts;
+use crate::features::wallet::state::Account;
+use crate::services::bitcoin_fee::BitcoinFeeService;
+use crate::services::transaction::{TransactionHistoryItem, TransactionService};
+use crate::shared::kill_switch::{self, MoneyAction};
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use dioxus::prelude::ReadableExt;
+use std::collections::HashSet;
+
+/// BIP125 要求替换交易至少提高的费率（sat/vB，对应最低中继费率）
+pub const MIN_RBF_INCREMENT_SAT_VB: u64 = 1;
+/// 历史记录未返回费率时按典型 P2WPKH（1 输入 2 输出）估算的父交易大小
+const TYPICAL_PARENT_VSIZE: u64 = 141;
+/// CPFP 子交易（1 输入 1 输出 P2WPKH）的大小
+const CPFP_CHILD_VSIZE: u64 = 110;
+const SATS_PER_BTC: f64 = 100_000_000.0;
+
+fn is_bitcoin(tx: &TransactionHistoryItem) -> bool {
+    tx.token.eq_ignore_ascii_case("BTC")
+}
+
+fn is_unconfirmed(tx: &TransactionHistoryItem) -> bool {
+    tx.status.eq_ignore_ascii_case("pending")
+}
+
+/// 未确认的 Bitcoin 转出交易可通过 RBF 加速
+pub fn can_bump_fee(tx: &TransactionHistoryItem) -> bool {
+    is_bitcoin(tx) && is_unconfirmed(tx) && tx.tx_type.eq_ignore_ascii_case("send")
+}
+
+/// 未确认的 Bitcoin 转入交易可通过 CPFP 加速
+pub fn can_cpfp(tx: &TransactionHistoryItem) -> bool {
+    is_bitcoin(tx) && is_unconfirmed(tx) && tx.tx_type.eq_ignore_ascii_case("receive")
+}
+
+/// 原交易费率：优先使用后端返回值，否则由手续费按典型大小估算
+pub fn original_fee_rate(tx: &TransactionHistoryItem) -> u64 {
+    tx.fee_rate.unwrap_or_else(|| {
+        let fee_sats = btc_to_sats(&tx.fee).unwrap_or(0);
+        fee_sats.div_ceil(TYPICAL_PARENT_VSIZE)
+    })
+}
+
+/// RBF 新费率：不低于当前市场费率，且至少比原费率高 [`MIN_RBF_INCREMENT_SAT_VB`]
+pub fn bumped_fee_rate(original: u64, market: u64) -> u64 {
+    market.max(original + MIN_RBF_INCREMENT_SAT_VB)
+}
+
+/// CPFP 子交易费率：使父子交易合计达到 `target` sat/vB
+pub fn cpfp_child_fee_rate(parent_fee_rate: u64, target: u64) -> u64 {
+    let package_fee = target * (TYPICAL_PARENT_VSIZE + CPFP_CHILD_VSIZE);
+    let parent_fee = parent_fee_rate * TYPICAL_PARENT_VSIZE;
+    package_fee
+        .saturating_sub(parent_fee)
+        .div_ceil(CPFP_CHILD_VSIZE)
+        .max(target)
+}
+
+fn btc_to_sats(amount: &str) -> Option<u64> {
+    let value = amount.trim().parse::<f64>().ok()?;
+    (value.is_finite() && value >= 0.0).then(|| (value * SATS_PER_BTC).round() as u64)
+}
+
+/// 合并 RBF 替换链：替换交易确认后，隐藏其替换掉的所有原交易
+///
+/// 替换交易尚未确认时保留原交易，两者都可能最终上链
+pub fn collapse_replacements(txs: Vec<TransactionHistoryItem>) -> Vec<TransactionHistoryItem> {
+    let mut hidden = HashSet::new();
+    for tx in txs
+        .iter()
+        .filter(|tx| tx.status.eq_ignore_ascii_case("confirmed"))
+    {
+        let mut current = tx.replaces.clone();
+        while let Some(hash) = current {
+            if !hidden.insert(hash.clone()) {
+                break;
+            }
+            current = txs
+                .iter()
+                .find(|t| t.hash == hash)
+                .and_then(|t| t.replaces.clone());
+        }
+    }
+    txs.into_iter()
+        .filter(|tx| !hidden.contains(&tx.hash))
+        .collect()
+}
+
+async fn market_fee_rate(app_state: AppState) -> u64 {
+    BitcoinFeeService::new(app_state)
+        .get_fee_rate()
+        .await
+        .unwrap_or(20)
+}
+
+/// RBF 加速：以更高费率重新签名并广播替换交易，返回新交易哈希
+pub async fn bump_fee(
+    app_state: AppState,
+    account: &Account,
+    tx: &TransactionHistoryItem,
+) -> Result<String> {
+    if !can_bump_fee(tx) {
+        return Err(anyhow!("仅未确认的 Bitcoin 转出交易可以加速"));
+    }
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Sign)
+        .await
+        .map_err(|e| anyhow!(e))?;
+    let private_key_hex =
+        chain_accounts::selected_wallet_signing_key(&app_state, &account.address)?;
+    let fee_rate = bumped_fee_rate(original_fee_rate(tx), market_fee_rate(app_state).await);
+    let value = btc_to_sats(&tx.amount).ok_or_else(|| anyhow!("无效金额: {}", tx.amount))?;
+
+    let signed_tx = BitcoinTxSigner::sign_replacement(
+        &private_key_hex,
+        &tx.to,
+        &value.to_string(),
+        fee_rate,
+        &tx.hash,
+    )
+    .map_err(|e| anyhow!("Bitcoin签名失败: {}", e))?;
+    let response = TransactionService::new(app_state)
+        .broadcast("bitcoin", &signed_tx)
+        .await
+        .map_err(|e| anyhow!("Bitcoin广播失败: {}", e))?;
+
+    log::info!(
+        "RBF 替换已广播: {} -> {} ({} sat/vB)",
+        tx.hash,
+        response.tx_hash,
+        fee_rate
+    );
+    Ok(response.tx_hash)
+}
+
+/// CPFP 加速：花费未确认转入交易中属于本账户的输出，返回子交易哈希
+pub async fn child_pays_for_parent(
+    app_state: AppState,
+    account: &Account,
+    tx: &TransactionHistoryItem,
+) -> Result<String> {
+    if !can_cpfp(tx) {
+        return Err(anyhow!("仅未确认的 Bitcoin 转入交易可以使用 CPFP"));
+    }
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Sign)
+        .await
+        .map_err(|e| anyhow!(e))?;
+    let private_key_hex =
+        chain_accounts::selected_wallet_signing_key(&app_state, &account.address)?;
+    let fee_rate = cpfp_child_fee_rate(original_fee_rate(tx), market_fee_rate(app_state).await);
+    let value = btc_to_sats(&tx.amount).ok_or_else(|| anyhow!("无效金额: {}", tx.amount))?;
+    if value <= fee_rate * CPFP_CHILD_VSIZE {
+        return Err(anyhow!("该输出金额不足以支付加速手续费"));
+    }
+
+    let signed_tx = BitcoinTxSigner::sign_cpfp(
+        &private_key_hex,
+        &tx.hash,
+        None,
+        &account.address,
+        &value.to_string(),
+        fee_rate,
+    )
+    .map_err(|e| anyhow!("Bitcoin签名失败: {}", e))?;
+    let response = TransactionService::new(app_state)
+        .broadcast("bitcoin", &signed_tx)
+        .await
+        .map_err(|e| anyhow!("Bitcoin广播失败: {}", e))?;
+
+    log::info!(
+        "CPFP 子交易已广播: parent={} child={} ({} sat/vB)",
+        tx.hash,
+        response.tx_hash,
+        fee_rate
+    );
+    Ok(response.tx_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(
+        hash: &str,
+        tx_type: &str,
+        status: &str,
+        replaces: Option<&str>,
+    ) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            hash: hash.to_string(),
+            tx_type: tx_type.to_string(),
+            status: status.to_string(),
+            from: "bc1qfrom".to_string(),
+            to: "bc1qto".to_string(),
+            amount: "0.001".to_string(),
+            token: "BTC".to_string(),
+            timestamp: 0,
+            fee: "0.00000282".to_string(),
+            fee_rate: None,
+            replaces: replaces.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn fee_rates_respect_minimum_increments() {
+        assert_eq!(original_fee_rate(&tx("a", "send", "pending", None)), 2);
+        assert_eq!(bumped_fee_rate(10, 5), 11);
+        assert_eq!(bumped_fee_rate(10, 25), 25);
+        // 父交易 2 sat/vB、目标 20 sat/vB：(20*251 - 2*141) / 110 ≈ 43.07
+        assert_eq!(cpfp_child_fee_rate(2, 20), 44);
+        assert_eq!(cpfp_child_fee_rate(50, 20), 20);
+        assert!(can_bump_fee(&tx("a", "send", "pending", None)));
+        assert!(!can_bump_fee(&tx("a", "send", "confirmed", None)));
+        assert!(can_cpfp(&tx("a", "receive", "pending", None)));
+    }
+
+    #[test]
+    fn collapses_replacement_chain_once_confirmed() {
+        let pending = vec![
+            tx("a", "send", "pending", None),
+            tx("b", "send", "pending", Some("a")),
+        ];
+        assert_eq!(collapse_replacements(pending).len(), 2);
+
+        let confirmed = vec![
+            tx("a", "send", "pending", None),
+            tx("b", "send", "pending", Some("a")),
+            tx("c", "send", "confirmed", Some("b")),
+            tx("d", "receive", "confirmed", None),
+        ];
+        let hashes: Vec<_> = collapse_replacements(confirmed)
+            .into_iter()
+            .map(|t| t.hash)
+            .collect();
+        assert_eq!(hashes, vec!["c", "d"]);
+    }
+}
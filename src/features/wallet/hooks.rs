@@ -1,16 +1,59 @@
-use crate::crypto::bip39::generate_mnemonic;
-use crate::crypto::encryption::{decrypt, derive_key, encrypt, generate_salt};
+use crate::crypto::bip39::generate_mnemonic_with_entropy;
+use crate::crypto::derivation::DerivationPath;
+use crate::crypto::encryption::{decrypt, encrypt, generate_salt};
 use crate::crypto::key_manager::KeyManager;
-use crate::crypto::keystore::decrypt_keystore;
+use crate::crypto::worker;
+use crate::features::security::app_lock;
+use crate::features::wallet::backup_reminder;
+use crate::features::wallet::chain_accounts::{self, DERIVED_CHAINS};
 use crate::features::wallet::state::{Account, AccountType, Wallet};
+use crate::features::wallet::token_preferences::TokenPreferences;
+use crate::services::address_detector::ChainType;
 use crate::services::wallet::WalletService;
 use crate::shared::cache::CacheEntry;
 use crate::shared::state::AppState;
+use crate::shared::time;
+use crate::shared::websocket;
 use anyhow::{anyhow, Result};
 use dioxus::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
+use std::cell::Cell;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// 推迟验证时加密暂存的助记词
+fn backup_phrase_key(wallet_id: &str) -> String {
+    format!("wallet_{}_backup_phrase", wallet_id)
+}
+
+/// 待创建钱包的名称（备份页打印时使用）
+pub fn pending_wallet_name() -> Option<String> {
+    let wallet_id: String = LocalStorage::get("wallet_pending_id").ok()?;
+    LocalStorage::get(format!("wallet_pending_{}_name", wallet_id)).ok()
+}
+
+thread_local! {
+    static CREATION_IN_FLIGHT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// 钱包创建进行中的标记：同一时间只允许一次生成/落库，重复点击直接拒绝
+struct CreationGuard;
+
+impl CreationGuard {
+    fn acquire() -> Result<Self> {
+        if CREATION_IN_FLIGHT.with(|f| f.replace(true)) {
+            return Err(anyhow!("钱包正在创建中，请勿重复提交"));
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for CreationGuard {
+    fn drop(&mut self) {
+        CREATION_IN_FLIGHT.with(|f| f.set(false));
+    }
+}
+
 pub fn use_wallet() -> WalletController {
     let app_state = use_context::<AppState>();
     WalletController { app_state }
@@ -25,29 +68,79 @@ impl WalletController {
     /// 更新活动时间（账户锁）
     pub fn update_activity(&self) {
         let mut app_state = self.app_state;
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+        let now = crate::shared::time::now_unix();
         *app_state.last_active.write() = now;
     }
 
-    /// 检查账户自动锁定（5分钟无操作）
-    #[allow(dead_code)] // 用于自动锁定功能
+    /// 检查自动锁定：超过设置的无操作时长后锁定应用（不登出）
+    ///
+    /// 交易/余额类 WebSocket 推送也计为活动
     pub fn check_auto_lock(&self) {
         let app_state = self.app_state;
-        let last_active = *app_state.last_active.read();
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+        if *app_state.app_locked.peek() || !app_state.user.peek().is_authenticated {
+            return;
+        }
+        let last_active = (*app_state.last_active.peek()).max(websocket::last_activity_secs());
+        let now = crate::shared::time::now_unix();
+        let window = app_state.preferences.peek().auto_lock_minutes;
+        if app_lock::is_idle(last_active, now, window) {
+            self.lock_app();
+        }
+    }
 
-        // 5 minutes = 300 seconds
-        if now - last_active > 300 {
-            // 账户自动锁定 - 仅清除本地状态（不调用后端API）
-            let auth_ctrl = crate::features::auth::hooks::use_auth();
-            auth_ctrl.logout_local().ok();
+    /// 锁定应用：锁定所有已解锁的钱包并清除内存中的密钥，保留登录会话与当前页面
+    pub fn lock_app(&self) {
+        let mut app_state = self.app_state;
+        let unlocked: Vec<String> = app_state
+            .wallet_unlock_time
+            .peek()
+            .keys()
+            .cloned()
+            .collect();
+        for wallet_id in &unlocked {
+            self.lock_wallet(Some(wallet_id));
         }
+        self.lock_wallet(None);
+
+        app_lock::persist_locked(true);
+        app_state.app_locked.set(true);
+    }
+
+    /// 解锁应用：本设备有当前钱包的加密数据时校验钱包密码（同时解锁该钱包），
+    /// 否则校验账户密码
+    pub async fn unlock_app(&self, password: &str) -> Result<()> {
+        let mut app_state = self.app_state;
+        let selected_id = app_state.wallet.peek().selected_wallet_id.clone();
+        match selected_id.filter(|id| self.is_wallet_in_local_storage(id)) {
+            Some(wallet_id) => self.unlock_wallet(&wallet_id, password).await?,
+            None => {
+                crate::features::auth::hooks::AuthController { app_state }
+                    .reauthenticate(password)
+                    .await?
+            }
+        }
+
+        app_lock::persist_locked(false);
+        app_state.app_locked.set(false);
+        self.update_activity();
+        Ok(())
     }
 
     /// 创建新钱包（多钱包系统）
     /// 注意：此函数只生成助记词，不创建钱包
     /// 钱包将在助记词验证通过后创建（调用 finalize_wallet_creation）
-    pub async fn create_wallet(&self, name: &str, password: &str) -> Result<String> {
+    ///
+    /// - `word_count`: 助记词长度（12 或 24）
+    /// - `extra_entropy`: 创建页面收集的额外熵（指针轨迹），与系统随机数混合
+    pub async fn create_wallet(
+        &self,
+        name: &str,
+        password: &str,
+        word_count: usize,
+        extra_entropy: &[u8],
+    ) -> Result<String> {
+        let _guard = CreationGuard::acquire()?;
+
         // 只生成助记词，不创建钱包
         // 钱包将在助记词验证通过后创建
 
@@ -64,15 +157,15 @@ impl WalletController {
         let wallet_id = Uuid::new_v4().to_string();
 
         // 2. Generate Mnemonic
-        let mnemonic = generate_mnemonic(12)?;
+        let mnemonic = generate_mnemonic_with_entropy(word_count, extra_entropy)?;
         let phrase = mnemonic.as_str().to_string();
 
-        // 3. Derive Seed
-        let seed = mnemonic.to_seed("");
+        // 3. Derive Seed（优先在加密 Worker 中执行）
+        let seed = worker::derive_seed(&mnemonic).await?;
 
         // 4. Encrypt Seed
         let salt = generate_salt();
-        let key = derive_key(password, &salt)?;
+        let key = worker::derive_key(password, &salt).await?;
         let encrypted_seed = encrypt(&key, &seed)?;
 
         // 5. Save to Storage (临时保存，等待验证通过后创建钱包)
@@ -99,7 +192,11 @@ impl WalletController {
 
     /// 完成钱包创建（在助记词验证通过后调用）
     /// 此函数会从临时存储中读取钱包数据，创建钱包并保存到本地和数据库
-    pub async fn finalize_wallet_creation(&self) -> Result<()> {
+    ///
+    /// `backup_verified` 为 false 表示用户选择“稍后提醒”：助记词用钱包密码加密暂存，
+    /// 直到补验证通过（见 [`backup_reminder`]）
+    pub async fn finalize_wallet_creation(&self, backup_verified: bool) -> Result<()> {
+        let _guard = CreationGuard::acquire()?;
         let mut app_state = self.app_state;
 
         // 1. 从临时存储中读取钱包数据
@@ -126,7 +223,7 @@ impl WalletController {
         // 2. 解密种子
         let salt = hex::decode(salt_hex)?;
         let encrypted_seed = hex::decode(encrypted_seed_hex)?;
-        let key = derive_key(&password, &salt)?;
+        let key = worker::derive_key(&password, &salt).await?;
         let seed = decrypt(&key, &encrypted_seed)?;
 
         // 3. 创建钱包对象
@@ -135,78 +232,27 @@ impl WalletController {
         // 4. 创建 KeyManager 并派生账户（✅ 同时提取公钥）
         let key_manager = KeyManager::new(seed.to_vec());
 
-        // Ethereum
-        let eth_priv = key_manager.derive_eth_private_key(0)?;
-        let eth_addr = key_manager.get_eth_address(&eth_priv)?;
-        let eth_pubkey = {
-            use k256::ecdsa::{SigningKey, VerifyingKey};
-            let signing_key = SigningKey::from_slice(&hex::decode(&eth_priv)?)?;
-            let verifying_key = VerifyingKey::from(&signing_key);
-            let pub_bytes = verifying_key.to_encoded_point(false).as_bytes().to_vec();
-            hex::encode(&pub_bytes) // ✅ 完整的65字节未压缩公钥（包含0x04前缀）
-        };
-        wallet.accounts.push(Account {
-            address: eth_addr,
-            chain: "ethereum".to_string(),
-            public_key: eth_pubkey,
-            derivation_path: Some("m/44'/60'/0'/0/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
-
-        // Bitcoin
-        let btc_priv = key_manager.derive_btc_private_key(0)?;
-        let btc_addr = key_manager.get_btc_address(&btc_priv)?;
-        let btc_pubkey = {
-            use k256::ecdsa::{SigningKey, VerifyingKey};
-            let signing_key = SigningKey::from_slice(&hex::decode(&btc_priv)?)?;
-            let verifying_key = VerifyingKey::from(&signing_key);
-            let pub_bytes = verifying_key.to_encoded_point(true).as_bytes().to_vec();
-            hex::encode(&pub_bytes) // 压缩格式公钥
-        };
-        wallet.accounts.push(Account {
-            address: btc_addr,
-            chain: "bitcoin".to_string(),
-            public_key: btc_pubkey,
-            derivation_path: Some("m/84'/0'/0'/0/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
-
-        // Solana
-        let sol_priv = key_manager.derive_sol_private_key(0)?;
-        let sol_addr = key_manager.get_sol_address(&sol_priv)?;
-        let sol_pubkey = key_manager.get_sol_public_key(&sol_priv)?;
-        wallet.accounts.push(Account {
-            address: sol_addr,
-            chain: "solana".to_string(),
-            public_key: sol_pubkey,
-            derivation_path: Some("m/44'/501'/0'/0'/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
-
-        // TON
-        let ton_priv = key_manager.derive_ton_private_key(0)?;
-        let ton_addr = key_manager.get_ton_address(&ton_priv)?;
-        let ton_pubkey = key_manager.get_ton_public_key(&ton_priv)?;
-        wallet.accounts.push(Account {
-            address: ton_addr,
-            chain: "ton".to_string(),
-            public_key: ton_pubkey,
-            derivation_path: Some("m/44'/607'/0'/0'/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
+        for chain in DERIVED_CHAINS {
+            wallet
+                .accounts
+                .push(chain_accounts::derive_account(&key_manager, chain)?);
+        }
 
         wallet.selected_account_index = Some(0);
         wallet.is_locked = true;
+        // 助记词已通过抄写验证（或推迟验证）
+        wallet.backup_verified = backup_verified;
 
         // 5. 将临时数据移动到正式存储
         let salt_key = format!("wallet_{}_salt", wallet_id);
         let seed_key = format!("wallet_{}_seed", wallet_id);
         LocalStorage::set(&salt_key, hex::encode(salt))?;
         LocalStorage::set(&seed_key, hex::encode(encrypted_seed))?;
+        if !backup_verified {
+            let encrypted_phrase = encrypt(&key, mnemonic_phrase.as_bytes())?;
+            LocalStorage::set(backup_phrase_key(&wallet_id), hex::encode(encrypted_phrase))?;
+            backup_reminder::defer(&wallet_id, time::now_unix());
+        }
 
         // 6. 清理临时数据
         LocalStorage::delete(&temp_salt_key);
@@ -274,14 +320,14 @@ impl WalletController {
                     let saved_count = response.wallets.len();
                     let failed_count = response.failed.len();
 
-                    tracing::info!(
+                    log::info!(
                         "✅ Batch wallet creation: {} succeeded, {} failed",
                         saved_count,
                         failed_count
                     );
 
                     for wallet_result in &response.wallets {
-                        tracing::info!(
+                        log::info!(
                             "  ✅ Wallet saved: {} - {}",
                             wallet_result.chain,
                             wallet_result.address
@@ -297,18 +343,18 @@ impl WalletController {
                         });
 
                         if has_fk_error {
-                            tracing::error!("🚨 检测到数据库不一致错误（后端数据库可能已重建）");
-                            tracing::error!("📝 请执行以下操作：");
-                            tracing::error!("   1. 点击右上角【Logout】登出");
-                            tracing::error!(
+                            log::error!("🚨 检测到数据库不一致错误（后端数据库可能已重建）");
+                            log::error!("📝 请执行以下操作：");
+                            log::error!("   1. 点击右上角【Logout】登出");
+                            log::error!(
                                 "   2. 清除浏览器缓存（F12 → Application → Local Storage → 清除）"
                             );
-                            tracing::error!("   3. 重新注册账号");
+                            log::error!("   3. 重新注册账号");
 
                             // 自动清理本地存储（可选，取消注释启用）
                             // use gloo_storage::{LocalStorage, Storage};
                             // LocalStorage::delete("user_state");
-                            // tracing::warn!("⚠️ 已自动清理本地登录状态，请刷新页面后重新注册");
+                            // log::warn!("⚠️ 已自动清理本地登录状态，请刷新页面后重新注册");
 
                             return Err(anyhow::anyhow!(
                                 "数据库不一致：后端数据库可能已重建。请登出后重新注册账号。\n\
@@ -318,7 +364,7 @@ impl WalletController {
                         }
 
                         for err in &response.failed {
-                            tracing::warn!(
+                            log::warn!(
                                 "  ⚠️ Failed to save: {} - {} ({})",
                                 err.chain,
                                 err.address,
@@ -328,7 +374,7 @@ impl WalletController {
 
                         // ✅ 修复：即使部分失败，也不阻止用户继续（钱包已在本地创建）
                         // 用户可以稍后手动同步或重新创建
-                        tracing::warn!(
+                        log::warn!(
                             "⚠️ 部分钱包保存失败（{}/{} 成功），但本地钱包已创建成功，您可以继续使用",
                             saved_count,
                             wallet.accounts.len()
@@ -336,12 +382,12 @@ impl WalletController {
                     }
                 }
                 Err(e) => {
-                    tracing::error!("❌ 后端保存失败: {}", e);
+                    log::error!("❌ 后端保存失败: {}", e);
 
                     // 检查是否是401认证错误
                     let error_msg = e.to_string().to_lowercase();
                     if error_msg.contains("unauthorized") || error_msg.contains("401") {
-                        tracing::warn!("⚠️ 认证已过期，请重新登录");
+                        log::warn!("⚠️ 认证已过期，请重新登录");
 
                         // 清理认证状态
                         app_state.handle_unauthorized();
@@ -354,7 +400,7 @@ impl WalletController {
                         return Err(anyhow!("认证已过期，请重新登录后再创建钱包"));
                     } else {
                         // 其他错误：网络错误等，不阻止用户（钱包已在本地创建）
-                        tracing::warn!("⚠️ 钱包已在本地创建成功，但未同步到服务器。您可以继续使用，稍后会自动同步");
+                        log::warn!("⚠️ 钱包已在本地创建成功，但未同步到服务器。您可以继续使用，稍后会自动同步");
                         // 不返回错误，允许用户继续
                     }
                 }
@@ -422,7 +468,7 @@ impl WalletController {
         let encrypted_data = hex::decode(encrypted_data_hex)?;
 
         // 3. Derive Key
-        let key = derive_key(password, &salt)?;
+        let key = worker::derive_key(password, &salt).await?;
 
         // 4. Decrypt Seed or Private Key
         let seed = decrypt(&key, &encrypted_data)
@@ -432,17 +478,19 @@ impl WalletController {
         let key_manager = KeyManager::new(seed);
 
         // 5. Update Wallet State (unlock this wallet)
-        let mut wallet_state = app_state.wallet.write();
-        if let Some(wallet) = wallet_state.get_wallet_mut(wallet_id) {
-            wallet.is_locked = false;
-            wallet_state.save()?;
-        }
+        {
+            let mut wallet_state = app_state.wallet.write();
+            if let Some(wallet) = wallet_state.get_wallet_mut(wallet_id) {
+                wallet.is_locked = false;
+                wallet_state.save()?;
+            }
+        } // 释放写锁，后续状态更新不与其嵌套
 
         // 6. Set KeyManager in global state (for current wallet)
         *app_state.key_manager.write() = Some(key_manager);
 
         // 7. Record unlock time (for auto-lock after 5 minutes)
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+        let now = crate::shared::time::now_unix();
         app_state
             .wallet_unlock_time
             .write()
@@ -454,31 +502,23 @@ impl WalletController {
     }
 
     /// 锁定钱包（清除内存中的密钥）
+    ///
+    /// 会在自动锁定计时器和渲染期间的过期检查中调用，钱包状态经修改队列写入
     pub fn lock_wallet(&self, wallet_id: Option<&str>) {
         let mut app_state = self.app_state;
 
         // 如果指定了钱包ID，锁定该钱包；否则锁定当前选中的钱包
-        if let Some(id) = wallet_id {
-            let mut wallet_state = app_state.wallet.write();
-            if let Some(wallet) = wallet_state.get_wallet_mut(id) {
-                wallet.is_locked = true;
-                wallet_state.save().ok();
-            }
-            app_state.wallet_unlock_time.write().remove(id);
-        } else {
-            // 锁定当前选中的钱包
-            let selected_id = {
-                let wallet_state = app_state.wallet.read();
-                wallet_state.selected_wallet_id.clone()
-            };
-            if let Some(selected_id) = selected_id {
-                let mut wallet_state = app_state.wallet.write();
-                if let Some(wallet) = wallet_state.get_wallet_mut(&selected_id) {
+        let target_id = match wallet_id {
+            Some(id) => Some(id.to_string()),
+            None => app_state.wallet.peek().selected_wallet_id.clone(),
+        };
+        if let Some(id) = target_id {
+            app_state.wallet_unlock_time.write().remove(&id);
+            app_state.update_wallet(move |wallet_state| {
+                if let Some(wallet) = wallet_state.get_wallet_mut(&id) {
                     wallet.is_locked = true;
-                    wallet_state.save().ok();
                 }
-                app_state.wallet_unlock_time.write().remove(&selected_id);
-            }
+            });
         }
 
         // 清除KeyManager
@@ -499,7 +539,7 @@ impl WalletController {
             // 检查解锁时间是否过期（5分钟）
             let unlock_time = app_state.wallet_unlock_time.read().get(wallet_id).copied();
             if let Some(timestamp) = unlock_time {
-                let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+                let now = crate::shared::time::now_unix();
                 if now - timestamp > 300 {
                     // 自动锁定
                     drop(wallet_state);
@@ -514,12 +554,137 @@ impl WalletController {
         false
     }
 
+    /// 为钱包补派生目标链账户（按该链的派生路径），保存到本地并同步到后端
+    ///
+    /// 需要钱包已解锁（KeyManager 在内存中），且只支持助记词钱包。
+    pub async fn add_chain_account(&self, wallet_id: &str, chain: ChainType) -> Result<Account> {
+        let mut app_state = self.app_state;
+
+        let (name, derivable) = {
+            let wallet_state = app_state.wallet.read();
+            let wallet = wallet_state
+                .get_wallet(wallet_id)
+                .ok_or_else(|| anyhow!("钱包不存在"))?;
+            if let Some(existing) = chain_accounts::account_for_chain(wallet, chain) {
+                return Ok(existing.clone());
+            }
+            (wallet.name.clone(), chain_accounts::can_derive(wallet))
+        };
+        if !derivable {
+            return Err(anyhow!("私钥导入的钱包无法派生 {} 账户", chain.label()));
+        }
+        if !self.is_wallet_unlocked(wallet_id) {
+            return Err(anyhow!("钱包已锁定，请先在钱包页解锁"));
+        }
+
+        let account = {
+            let key_manager = app_state.key_manager.read();
+            let key_manager = key_manager
+                .as_ref()
+                .ok_or_else(|| anyhow!("钱包已锁定，请先在钱包页解锁"))?;
+            chain_accounts::derive_account(key_manager, chain)?
+        };
+
+        {
+            let mut wallet_state = app_state.wallet.write();
+            if let Some(wallet) = wallet_state.get_wallet_mut(wallet_id) {
+                wallet.accounts.push(account.clone());
+            }
+            wallet_state.save()?;
+        }
+
+        self.sync_accounts_to_backend(&name, std::slice::from_ref(&account))
+            .await;
+
+        self.update_activity();
+        Ok(account)
+    }
+
+    /// 把账户发现找到的账户加入钱包（跳过已存在的地址），保存到本地并同步到后端；返回新增数量
+    pub async fn add_discovered_accounts(
+        &self,
+        wallet_id: &str,
+        accounts: Vec<Account>,
+    ) -> Result<usize> {
+        let mut app_state = self.app_state;
+
+        let (name, added) = {
+            let mut wallet_state = app_state.wallet.write();
+            let wallet = wallet_state
+                .get_wallet_mut(wallet_id)
+                .ok_or_else(|| anyhow!("钱包不存在"))?;
+            let mut added = Vec::new();
+            for account in accounts {
+                let exists = wallet.accounts.iter().any(|a| {
+                    a.chain == account.chain && a.address.eq_ignore_ascii_case(&account.address)
+                });
+                if !exists {
+                    wallet.accounts.push(account.clone());
+                    added.push(account);
+                }
+            }
+            let name = wallet.name.clone();
+            wallet_state.save()?;
+            (name, added)
+        };
+
+        self.sync_accounts_to_backend(&name, &added).await;
+        Ok(added.len())
+    }
+
+    /// 同步账户到后端（失败不影响本地账户使用）
+    async fn sync_accounts_to_backend(&self, name: &str, accounts: &[Account]) {
+        let app_state = self.app_state;
+        if accounts.is_empty() || !app_state.user.read().is_authenticated {
+            return;
+        }
+        use crate::services::wallet::{BatchCreateWalletsRequest, WalletRegistrationInfo};
+
+        let wallets = accounts
+            .iter()
+            .map(|account| {
+                let chain_code =
+                    match ChainType::from_str(&account.chain).map(chain_accounts::account_family) {
+                        Some(ChainType::Bitcoin) => "BTC",
+                        Some(ChainType::Solana) => "SOL",
+                        Some(ChainType::TON) => "TON",
+                        _ => "ETH",
+                    };
+                WalletRegistrationInfo {
+                    chain: chain_code.to_string(),
+                    address: account.address.clone(),
+                    public_key: account.public_key.clone(),
+                    derivation_path: account.derivation_path.clone(),
+                    name: Some(name.to_string()),
+                }
+            })
+            .collect();
+        if let Err(e) = WalletService::new(app_state)
+            .batch_create_wallets(BatchCreateWalletsRequest { wallets })
+            .await
+        {
+            log::warn!("⚠️ {} 个账户同步到后端失败: {}", accounts.len(), e);
+        }
+    }
+
     /// 恢复钱包（导入助记词）
     pub async fn recover_wallet(
         &self,
         name: &str,
         mnemonic_phrase: &str,
         password: &str,
+    ) -> Result<String> {
+        self.recover_wallet_with_paths(name, mnemonic_phrase, password, &HashMap::new())
+            .await
+    }
+
+    /// 恢复钱包并按指定派生路径派生账户（未指定的链使用默认路径）
+    pub async fn recover_wallet_with_paths(
+        &self,
+        name: &str,
+        mnemonic_phrase: &str,
+        password: &str,
+        paths: &HashMap<ChainType, DerivationPath>,
     ) -> Result<String> {
         let mut app_state = self.app_state;
 
@@ -546,12 +711,13 @@ impl WalletController {
         let mnemonic = Mnemonic::parse_in(Language::English, &mnemonic_phrase)
             .map_err(|e| anyhow!("Invalid mnemonic phrase: {}", e))?;
 
-        // 3. Derive Seed
-        let seed = mnemonic.to_seed("");
+        // 3. Derive Seed（优先在加密 Worker 中执行）
+        let mnemonic = crate::crypto::bip39::MnemonicSecret::new(mnemonic.to_string());
+        let seed = worker::derive_seed(&mnemonic).await?;
 
         // 4. Encrypt Seed
         let salt = generate_salt();
-        let key = derive_key(password, &salt)?;
+        let key = worker::derive_key(password, &salt).await?;
         let encrypted_seed = encrypt(&key, &seed)?;
 
         // 5. Save to Storage
@@ -560,75 +726,20 @@ impl WalletController {
         LocalStorage::set(&salt_key, hex::encode(salt))?;
         LocalStorage::set(&seed_key, hex::encode(encrypted_seed))?;
 
-        // 6. Create Wallet Object
+        // 6. Create Wallet Object（导入即说明用户持有助记词）
         let mut wallet = Wallet::new(wallet_id.clone(), name.to_string());
+        wallet.backup_verified = true;
 
         // 7. Create KeyManager and derive accounts (✅ 同时提取公钥)
         let key_manager = KeyManager::new(seed.to_vec());
 
-        // Ethereum
-        let eth_priv = key_manager.derive_eth_private_key(0)?;
-        let eth_addr = key_manager.get_eth_address(&eth_priv)?;
-        let eth_pubkey = {
-            use k256::ecdsa::{SigningKey, VerifyingKey};
-            let signing_key = SigningKey::from_slice(&hex::decode(&eth_priv)?)?;
-            let verifying_key = VerifyingKey::from(&signing_key);
-            let pub_bytes = verifying_key.to_encoded_point(false).as_bytes().to_vec();
-            hex::encode(&pub_bytes) // ✅ 完整的65字节未压缩公钥（包含0x04前缀）
-        };
-        wallet.accounts.push(Account {
-            address: eth_addr,
-            chain: "ethereum".to_string(),
-            public_key: eth_pubkey,
-            derivation_path: Some("m/44'/60'/0'/0/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
-
-        // Bitcoin
-        let btc_priv = key_manager.derive_btc_private_key(0)?;
-        let btc_addr = key_manager.get_btc_address(&btc_priv)?;
-        let btc_pubkey = {
-            use k256::ecdsa::{SigningKey, VerifyingKey};
-            let signing_key = SigningKey::from_slice(&hex::decode(&btc_priv)?)?;
-            let verifying_key = VerifyingKey::from(&signing_key);
-            let pub_bytes = verifying_key.to_encoded_point(true).as_bytes().to_vec();
-            hex::encode(&pub_bytes) // 压缩格式公钥
-        };
-        wallet.accounts.push(Account {
-            address: btc_addr,
-            chain: "bitcoin".to_string(),
-            public_key: btc_pubkey,
-            derivation_path: Some("m/84'/0'/0'/0/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
-
-        // Solana
-        let sol_priv = key_manager.derive_sol_private_key(0)?;
-        let sol_addr = key_manager.get_sol_address(&sol_priv)?;
-        let sol_pubkey = key_manager.get_sol_public_key(&sol_priv)?;
-        wallet.accounts.push(Account {
-            address: sol_addr,
-            chain: "solana".to_string(),
-            public_key: sol_pubkey,
-            derivation_path: Some("m/44'/501'/0'/0'/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
-
-        // TON
-        let ton_priv = key_manager.derive_ton_private_key(0)?;
-        let ton_addr = key_manager.get_ton_address(&ton_priv)?;
-        let ton_pubkey = key_manager.get_ton_public_key(&ton_priv)?;
-        wallet.accounts.push(Account {
-            address: ton_addr,
-            chain: "ton".to_string(),
-            public_key: ton_pubkey,
-            derivation_path: Some("m/44'/607'/0'/0'/0".to_string()),
-            account_type: AccountType::Derived,
-            balance: "0".to_string(),
-        });
+        for chain in DERIVED_CHAINS {
+            let account = match paths.get(&chain) {
+                Some(path) => chain_accounts::derive_account_at(&key_manager, chain, path)?,
+                None => chain_accounts::derive_account(&key_manager, chain)?,
+            };
+            wallet.accounts.push(account);
+        }
 
         wallet.selected_account_index = Some(0);
         wallet.is_locked = true;
@@ -677,14 +788,14 @@ impl WalletController {
         let wallet_id = Uuid::new_v4().to_string();
 
         // 2. 从私钥获取地址
-        use crate::crypto::key_manager::KeyManager;
+        use crate::crypto::key_manager::{decode_private_key_hex, KeyManager};
         let key_manager = KeyManager::new(vec![]); // 空seed，因为我们只使用私钥
         let eth_address = key_manager.get_eth_address(&private_key)?;
 
         // 3. 加密私钥（存储私钥而不是seed）
         let salt = generate_salt();
-        let key = derive_key(password, &salt)?;
-        let encrypted_private_key = encrypt(&key, &hex::decode(&private_key)?)?;
+        let key = worker::derive_key(password, &salt).await?;
+        let encrypted_private_key = encrypt(&key, &decode_private_key_hex(&private_key)?)?;
 
         // 4. Save to Storage
         let salt_key = format!("wallet_{}_salt", wallet_id);
@@ -693,7 +804,7 @@ impl WalletController {
         LocalStorage::set(&priv_key, hex::encode(encrypted_private_key))?;
 
         // 5. Create Wallet Object (✅ 从私钥提取公钥)
-        let private_key_bytes = hex::decode(&private_key)?;
+        let private_key_bytes = decode_private_key_hex(&private_key)?;
         let public_key = {
             use k256::ecdsa::{SigningKey, VerifyingKey};
             let signing_key = SigningKey::from_slice(&private_key_bytes)?;
@@ -703,6 +814,7 @@ impl WalletController {
         };
 
         let mut wallet = Wallet::new(wallet_id.clone(), name.to_string());
+        wallet.backup_verified = true; // 导入即说明用户持有私钥
         wallet.accounts.push(Account {
             address: eth_address,
             chain: "ethereum".to_string(),
@@ -772,7 +884,8 @@ impl WalletController {
         }
 
         // 4. 解密Keystore获取私钥
-        let private_key_hex = decrypt_keystore(keystore_json, keystore_password)
+        let private_key_hex = worker::decrypt_keystore(keystore_json, keystore_password)
+            .await
             .map_err(|e| anyhow!("Failed to decrypt keystore: {}", e))?;
 
         // 5. 使用私钥导入逻辑（复用现有代码）
@@ -815,7 +928,7 @@ impl WalletController {
             if let Some(idx) = wallet.selected_account_index {
                 if let Some(account) = wallet.accounts.get(idx) {
                     let cache_key = format!("{}:{}", account.chain, account.address);
-                    let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+                    let now = crate::shared::time::now_unix();
 
                     // 1. Check Cache
                     if let Some(entry) = app_state.cache.read().get(&cache_key) {
@@ -867,23 +980,132 @@ impl WalletController {
         Err(anyhow!("No account selected"))
     }
 
-    /// 删除钱包（从用户账号绑定的钱包删除）
-    /// 删除钱包及其所有存储数据（salt、seed/private_key等）
-    /// 同时从后端删除钱包记录
-    pub async fn delete_wallet(&self, wallet_id: &str) -> Result<()> {
+    /// 取出推迟验证时暂存的助记词（需要钱包密码）
+    pub async fn deferred_backup_phrase(&self, wallet_id: &str, password: &str) -> Result<String> {
+        let salt_hex: String = LocalStorage::get(format!("wallet_{}_salt", wallet_id))
+            .map_err(|_| anyhow!("未找到钱包盐值"))?;
+        let phrase_hex: String = LocalStorage::get(backup_phrase_key(wallet_id))
+            .map_err(|_| anyhow!("本设备上没有待验证的助记词"))?;
+        let salt = hex::decode(salt_hex)?;
+        let key = worker::derive_key(password, &salt).await?;
+        let phrase = decrypt(&key, &hex::decode(phrase_hex)?).map_err(|_| anyhow!("密码错误"))?;
+        String::from_utf8(phrase).map_err(|_| anyhow!("助记词数据已损坏"))
+    }
+
+    /// 补验证通过：标记已备份并删除暂存的助记词
+    pub fn complete_backup_verification(&self, wallet_id: &str) -> Result<()> {
         let mut app_state = self.app_state;
+        {
+            let mut wallet_state = app_state.wallet.write();
+            let wallet = wallet_state
+                .get_wallet_mut(wallet_id)
+                .ok_or_else(|| anyhow!("Wallet not found"))?;
+            wallet.backup_verified = true;
+            wallet_state.save()?;
+        }
+        LocalStorage::delete(backup_phrase_key(wallet_id));
+        backup_reminder::clear(wallet_id);
+        backup_reminder::finish_verification();
+        Ok(())
+    }
 
-        // 1. 获取钱包信息（用于后端删除）
-        let wallet_name = {
-            let wallet_state = app_state.wallet.read();
-            if let Some(wallet) = wallet_state.get_wallet(wallet_id) {
-                wallet.name.clone()
-            } else {
-                return Err(anyhow!("Wallet not found"));
+    /// 删除钱包（软删除）
+    /// 钱包移入"最近删除"，保留期内可在设置中撤销；密钥与后端记录在永久清除时才删除
+    pub fn delete_wallet(&self, wallet_id: &str) -> Result<()> {
+        let mut app_state = self.app_state;
+
+        let (wallet, was_selected) = {
+            let mut wallet_state = app_state.wallet.write();
+            let was_selected = wallet_state.selected_wallet_id.as_deref() == Some(wallet_id);
+            if let Some(w) = wallet_state.get_wallet_mut(wallet_id) {
+                w.is_locked = true;
+            }
+            let wallet = wallet_state
+                .get_wallet(wallet_id)
+                .cloned()
+                .ok_or_else(|| anyhow!("Wallet not found"))?;
+            wallet_state.soft_delete_wallet(wallet_id, chrono::Utc::now());
+            wallet_state.save()?;
+            (wallet, was_selected)
+        };
+
+        self.clear_wallet_runtime(&wallet, was_selected);
+        self.update_activity();
+
+        Ok(())
+    }
+
+    /// 撤销软删除
+    pub fn restore_wallet(&self, wallet_id: &str) -> Result<()> {
+        let mut app_state = self.app_state;
+        let mut wallet_state = app_state.wallet.write();
+        if !wallet_state.restore_wallet(wallet_id) {
+            return Err(anyhow!("Wallet not found in recently deleted"));
+        }
+        wallet_state.save()?;
+        Ok(())
+    }
+
+    /// 立即永久删除"最近删除"中的钱包
+    pub async fn purge_wallet(&self, wallet_id: &str) -> Result<()> {
+        let mut app_state = self.app_state;
+        let wallet = {
+            let mut wallet_state = app_state.wallet.write();
+            let wallet = wallet_state
+                .take_deleted_wallet(wallet_id)
+                .ok_or_else(|| anyhow!("Wallet not found in recently deleted"))?;
+            wallet_state.save()?;
+            wallet
+        };
+        self.purge_wallet_data(&wallet).await;
+        Ok(())
+    }
+
+    /// 永久清除已过保留期的钱包（应用启动加载钱包状态后调用）
+    pub async fn purge_expired_wallets(&self) -> Result<usize> {
+        let mut app_state = self.app_state;
+        let expired = {
+            let mut wallet_state = app_state.wallet.write();
+            let expired = wallet_state.take_expired_wallets(chrono::Utc::now());
+            if !expired.is_empty() {
+                wallet_state.save()?;
             }
+            expired
         };
+        for wallet in &expired {
+            self.purge_wallet_data(wallet).await;
+        }
+        Ok(expired.len())
+    }
+
+    /// 清理引用该钱包的运行时状态：解锁时间、内存密钥、余额缓存与进行中的请求
+    fn clear_wallet_runtime(&self, wallet: &Wallet, was_selected: bool) {
+        let mut app_state = self.app_state;
+        app_state.wallet_unlock_time.write().remove(&wallet.id);
+        if was_selected {
+            *app_state.key_manager.write() = None;
+        }
+        let references_wallet = |key: &String| {
+            wallet
+                .accounts
+                .iter()
+                .any(|a| !a.address.is_empty() && key.contains(&a.address))
+        };
+        app_state
+            .cache
+            .write()
+            .retain(|key, _| !references_wallet(key));
+        app_state
+            .inflight_requests
+            .write()
+            .retain(|key| !references_wallet(key));
+    }
+
+    /// 永久删除钱包数据：后端记录、本地密钥、代币收藏及运行时状态
+    async fn purge_wallet_data(&self, wallet: &Wallet) {
+        let mut app_state = self.app_state;
 
-        // 2. 从后端删除钱包（如果已登录）
+        // 1. 从后端删除钱包（如果已登录）
         if app_state.user.read().is_authenticated {
             // 确保 API 客户端有最新的认证 token
             let user_state = app_state.user.read();
@@ -894,7 +1116,6 @@ impl WalletController {
 
             // 从后端删除所有链的钱包记录
             // 注意：后端每个链的钱包都有不同的ID，我们需要通过钱包名称来查找并删除
-            use crate::services::wallet::WalletService;
             let wallet_service = WalletService::new(app_state);
 
             // 获取所有后端钱包，找到匹配的钱包并删除
@@ -908,12 +1129,12 @@ impl WalletController {
                         .unwrap_or(&backend_wallet.name)
                         .to_string();
 
-                    if backend_base_name == wallet_name {
+                    if backend_base_name == wallet.name {
                         // 删除后端钱包记录
                         // 将 String ID 转换为 Uuid
                         if let Ok(uuid) = uuid::Uuid::parse_str(&backend_wallet.id) {
                             if let Err(e) = wallet_service.delete_wallet(uuid).await {
-                                tracing::warn!(
+                                log::warn!(
                                     "Failed to delete backend wallet {}: {}",
                                     backend_wallet.id,
                                     e
@@ -921,34 +1142,31 @@ impl WalletController {
                                 // 继续删除其他链的钱包，不因为一个失败而停止
                             }
                         } else {
-                            tracing::warn!("Invalid wallet ID format: {}", backend_wallet.id);
+                            log::warn!("Invalid wallet ID format: {}", backend_wallet.id);
                         }
                     }
                 }
             }
         }
 
-        // 3. 从钱包状态中移除钱包
-        let mut wallet_state = app_state.wallet.write();
-        if !wallet_state.remove_wallet(wallet_id) {
-            return Err(anyhow!("Wallet not found in local state"));
-        }
-        wallet_state.save()?;
-        drop(wallet_state);
-
-        // 4. 清理LocalStorage中的钱包数据
-        let salt_key = format!("wallet_{}_salt", wallet_id);
-        let seed_key = format!("wallet_{}_seed", wallet_id);
-        let priv_key = format!("wallet_{}_private_key", wallet_id);
+        // 2. 清理LocalStorage中的钱包数据
+        let salt_key = format!("wallet_{}_salt", wallet.id);
+        let seed_key = format!("wallet_{}_seed", wallet.id);
+        let priv_key = format!("wallet_{}_private_key", wallet.id);
 
         // 尝试删除所有可能存在的key
         // 使用delete方法删除LocalStorage中的项（gloo-storage 0.3 API）
         LocalStorage::delete(&salt_key);
         LocalStorage::delete(&seed_key);
         LocalStorage::delete(&priv_key);
+        LocalStorage::delete(backup_phrase_key(&wallet.id));
+        backup_reminder::clear(&wallet.id);
 
-        self.update_activity();
+        // 3. 清理按钱包保存的代币收藏
+        let mut preferences = TokenPreferences::load();
+        preferences.remove_wallet(&wallet.id);
+        preferences.save();
 
-        Ok(())
+        self.clear_wallet_runtime(wallet, false);
     }
 }
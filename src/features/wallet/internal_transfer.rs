@@ -0,0 +1,225 @@
+//! Internal Transfer - 自有账户之间的转账
+//!
+//! 发送页的“内部转账”模式：接收方从当前钱包的其他账户及其他钱包中选择（按链分组）。
+//! - 同链：直接发送，跳过“从未向该地址发送过”等陌生地址提示
+//! - 跨链：跳转到跨链页，源链、目标链、代币、数量与接收地址全部预填
+//!
+//! 内部转账在交易记录中标记为内部，统计支出时不计入（避免把资金挪动当成支出/收入重复计算）。
+
+use crate::features::wallet::chain_accounts::account_family;
+use crate::features::wallet::state::{Account, Wallet};
+use crate::services::address_detector::ChainType;
+use crate::services::transaction::TransactionHistoryItem;
+use gloo_storage::{LocalStorage, Storage};
+use std::collections::HashSet;
+
+/// 已标记为内部转账的交易哈希（LocalStorage）
+const INTERNAL_TX_STORAGE_KEY: &str = "internal_transfer_hashes";
+/// 最多保留的标记数量（超出时丢弃最早的）
+const MAX_TAGGED_HASHES: usize = 500;
+
+/// 内部转账的接收方
+#[derive(Debug, Clone, PartialEq)]
+pub struct InternalTarget {
+    pub wallet_id: String,
+    pub wallet_name: String,
+    pub account: Account,
+    /// 接收链（EVM 账户在 Ethereum/BSC/Polygon 上各对应一个接收方）
+    pub chain: ChainType,
+}
+
+impl InternalTarget {
+    /// 稳定标识（用于列表 key 与选中状态）
+    pub fn key(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.wallet_id,
+            self.chain.as_str(),
+            self.account.address.to_lowercase()
+        )
+    }
+}
+
+/// 账户可接收资产的链
+fn receiving_chains(account: &Account) -> Vec<ChainType> {
+    match ChainType::from_str(&account.chain).map(account_family) {
+        Some(ChainType::Ethereum) => vec![ChainType::Ethereum, ChainType::BSC, ChainType::Polygon],
+        Some(chain) => vec![chain],
+        None => Vec::new(),
+    }
+}
+
+/// 内部转账的接收方，按链分组（源链在最前，其余链按首次出现）
+///
+/// 当前钱包排在最前；排除与源账户相同链、相同地址的一项。
+pub fn internal_targets(
+    wallets: &[Wallet],
+    current_wallet_id: &str,
+    source_chain: ChainType,
+    source_address: &str,
+) -> Vec<(ChainType, Vec<InternalTarget>)> {
+    let mut ordered: Vec<&Wallet> = wallets.iter().collect();
+    ordered.sort_by_key(|w| w.id != current_wallet_id);
+
+    let mut groups: Vec<(ChainType, Vec<InternalTarget>)> = Vec::new();
+    for wallet in ordered {
+        for account in &wallet.accounts {
+            for chain in receiving_chains(account) {
+                if chain == source_chain && account.address.eq_ignore_ascii_case(source_address) {
+                    continue;
+                }
+                let target = InternalTarget {
+                    wallet_id: wallet.id.clone(),
+                    wallet_name: wallet.name.clone(),
+                    account: account.clone(),
+                    chain,
+                };
+                match groups.iter_mut().find(|(c, _)| *c == chain) {
+                    Some((_, targets)) => targets.push(target),
+                    None => groups.push((chain, vec![target])),
+                }
+            }
+        }
+    }
+    // 同链可直接发送，排在跨链之前
+    if let Some(index) = groups.iter().position(|(c, _)| *c == source_chain) {
+        let same_chain = groups.remove(index);
+        groups.insert(0, same_chain);
+    }
+    groups
+}
+
+/// 所有钱包的账户地址（小写）
+pub fn owned_addresses(wallets: &[Wallet]) -> HashSet<String> {
+    wallets
+        .iter()
+        .flat_map(|w| w.accounts.iter())
+        .map(|a| a.address.trim().to_lowercase())
+        .collect()
+}
+
+/// 已标记为内部转账的交易哈希
+pub fn tagged_hashes() -> HashSet<String> {
+    LocalStorage::get::<Vec<String>>(INTERNAL_TX_STORAGE_KEY)
+        .unwrap_or_default()
+        .into_iter()
+        .collect()
+}
+
+/// 将交易标记为内部转账
+pub fn tag(tx_hash: &str) {
+    let hash = tx_hash.trim().to_lowercase();
+    if hash.is_empty() {
+        return;
+    }
+    let mut hashes = LocalStorage::get::<Vec<String>>(INTERNAL_TX_STORAGE_KEY).unwrap_or_default();
+    if hashes.contains(&hash) {
+        return;
+    }
+    hashes.push(hash);
+    if hashes.len() > MAX_TAGGED_HASHES {
+        let overflow = hashes.len() - MAX_TAGGED_HASHES;
+        hashes.drain(..overflow);
+    }
+    let _ = LocalStorage::set(INTERNAL_TX_STORAGE_KEY, &hashes);
+}
+
+/// 交易是否为内部转账：已标记，或收发双方都是自有地址
+pub fn is_internal(
+    tx: &TransactionHistoryItem,
+    owned: &HashSet<String>,
+    tagged: &HashSet<String>,
+) -> bool {
+    if tagged.contains(&tx.hash.trim().to_lowercase()) {
+        return true;
+    }
+    let from = tx.from.trim().to_lowercase();
+    let to = tx.to.trim().to_lowercase();
+    !from.is_empty() && from != to && owned.contains(&from) && owned.contains(&to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::wallet::state::AccountType;
+
+    fn account(chain: &str, address: &str) -> Account {
+        Account {
+            address: address.to_string(),
+            chain: chain.to_string(),
+            public_key: String::new(),
+            derivation_path: None,
+            account_type: AccountType::Derived,
+            balance: String::new(),
+        }
+    }
+
+    fn wallet(id: &str, accounts: Vec<Account>) -> Wallet {
+        let mut wallet = Wallet::new(id.to_string(), format!("钱包 {}", id));
+        wallet.accounts = accounts;
+        wallet
+    }
+
+    fn tx(hash: &str, from: &str, to: &str) -> TransactionHistoryItem {
+        TransactionHistoryItem {
+            hash: hash.to_string(),
+            tx_type: "send".to_string(),
+            status: "confirmed".to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            amount: "1".to_string(),
+            token: "ETH".to_string(),
+            timestamp: 0,
+            fee: "0".to_string(),
+            fee_rate: None,
+            replaces: None,
+        }
+    }
+
+    #[test]
+    fn targets_are_grouped_by_chain_and_exclude_the_source() {
+        let wallets = vec![
+            wallet("b", vec![account("ethereum", "0xBBB")]),
+            wallet(
+                "a",
+                vec![account("ethereum", "0xAAA"), account("solana", "SoLaNa")],
+            ),
+        ];
+        let groups = internal_targets(&wallets, "a", ChainType::Ethereum, "0xaaa");
+        let chains: Vec<_> = groups.iter().map(|(c, _)| *c).collect();
+        assert_eq!(
+            chains,
+            vec![
+                ChainType::Ethereum,
+                ChainType::BSC,
+                ChainType::Polygon,
+                ChainType::Solana
+            ]
+        );
+
+        // 同链只剩其他钱包的账户；当前钱包的同一地址可作为跨链接收方
+        let ethereum: Vec<_> = groups[0].1.iter().map(|t| t.wallet_id.as_str()).collect();
+        assert_eq!(ethereum, vec!["b"]);
+        let bsc: Vec<_> = groups[1].1.iter().map(|t| t.wallet_id.as_str()).collect();
+        assert_eq!(bsc, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn internal_transactions_are_detected_by_tag_or_owned_ends() {
+        let wallets = vec![
+            wallet("a", vec![account("ethereum", "0xAAA")]),
+            wallet("b", vec![account("ethereum", "0xBBB")]),
+        ];
+        let owned = owned_addresses(&wallets);
+        let tagged: HashSet<String> = ["0xtagged".to_string()].into_iter().collect();
+
+        assert!(is_internal(&tx("0x1", "0xaaa", "0xBBB"), &owned, &tagged));
+        assert!(is_internal(
+            &tx("0xTAGGED", "0xaaa", "0xccc"),
+            &owned,
+            &tagged
+        ));
+        assert!(!is_internal(&tx("0x2", "0xaaa", "0xccc"), &owned, &tagged));
+        assert!(!is_internal(&tx("0x3", "0xaaa", "0xaaa"), &owned, &tagged));
+    }
+}
@@ -1,5 +1,18 @@
 // Wallet feature module
 // Production-ready implementation
+pub mod address_poisoning;
+pub mod backup_reminder;
+pub mod chain_accounts;
+pub mod derivation_paths;
+pub mod discovery;
+pub mod fee_bump;
 pub mod hooks;
+pub mod internal_transfer;
+pub mod receive_prefs;
+pub mod send_safeguards;
+pub mod simulate;
 pub mod state;
+pub mod token_preferences;
+pub mod token_visibility;
 pub mod unlock;
+pub mod vault;
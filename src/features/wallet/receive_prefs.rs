@@ -0,0 +1,134 @@
+//! Receive Preferences - 收款页的链与账户选择
+//!
+//! 按钱包记住上次收款使用的链和账户，下次打开收款页时直接恢复；
+//! 没有记录时使用设置中的默认网络。EVM 链共用同一个地址，
+//! 收款提示始终以所选网络为准，避免发送方选错链。
+
+use crate::features::wallet::chain_accounts::account_family;
+use crate::features::wallet::state::{Account, Wallet};
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainGroup;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const STORAGE_KEY: &str = "receive_preferences";
+
+/// 某个钱包上次收款的选择
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReceiveChoice {
+    /// 链（`ChainType::as_str` 形式）
+    pub chain: String,
+    /// 账户地址（该链有多个账户时）
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+fn load_all() -> HashMap<String, ReceiveChoice> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+/// 钱包上次收款的选择
+pub fn last_choice(wallet_id: &str) -> Option<ReceiveChoice> {
+    load_all().remove(wallet_id)
+}
+
+/// 记住钱包本次收款的链与账户
+pub fn remember(wallet_id: &str, chain: ChainType, address: Option<&str>) {
+    let mut all = load_all();
+    all.insert(
+        wallet_id.to_string(),
+        ReceiveChoice {
+            chain: chain.as_str().to_string(),
+            address: address.map(|a| a.to_string()),
+        },
+    );
+    let _ = LocalStorage::set(STORAGE_KEY, &all);
+}
+
+/// 打开收款页时的初始链：上次使用的链 → 设置中的默认网络 → Ethereum
+pub fn initial_chain(last: Option<&ReceiveChoice>, default_chain: Option<&str>) -> ChainType {
+    last.and_then(|c| ChainType::from_str(&c.chain))
+        .or_else(|| default_chain.and_then(ChainType::from_str))
+        .unwrap_or(ChainType::Ethereum)
+}
+
+/// 钱包中可用于该链收款的账户（EVM 链共用以太坊账户）
+pub fn receive_accounts(wallet: &Wallet, chain: ChainType) -> Vec<Account> {
+    let family = account_family(chain);
+    wallet
+        .accounts
+        .iter()
+        .filter(|acc| ChainType::from_str(&acc.chain).map(account_family) == Some(family))
+        .cloned()
+        .collect()
+}
+
+/// 选中的账户：上次使用的地址仍存在时沿用，否则取第一个
+pub fn pick_account<'a>(accounts: &'a [Account], remembered: Option<&str>) -> Option<&'a Account> {
+    remembered
+        .and_then(|address| {
+            accounts
+                .iter()
+                .find(|acc| acc.address.eq_ignore_ascii_case(address))
+        })
+        .or_else(|| accounts.first())
+}
+
+/// 针对所选链的收款警告
+pub fn chain_warnings(chain: ChainType) -> Vec<String> {
+    let mut warnings = vec![format!("仅向此地址发送 {} 网络的资产", chain.label())];
+    if ChainGroup::of(chain) == ChainGroup::Evm {
+        warnings.push(format!(
+            "此地址在 Ethereum、BSC、Polygon 上相同，请确认发送方选择的是 {} 网络",
+            chain.label()
+        ));
+    } else {
+        warnings.push("跨链转账将导致资产永久丢失，无法找回".to_string());
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::wallet::state::AccountType;
+
+    fn account(chain: &str, address: &str) -> Account {
+        Account {
+            address: address.to_string(),
+            chain: chain.to_string(),
+            public_key: String::new(),
+            derivation_path: None,
+            account_type: AccountType::Derived,
+            balance: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn restores_last_choice_before_configured_default() {
+        let last = ReceiveChoice {
+            chain: "bsc".to_string(),
+            address: None,
+        };
+        assert_eq!(initial_chain(Some(&last), Some("solana")), ChainType::BSC);
+        assert_eq!(initial_chain(None, Some("solana")), ChainType::Solana);
+        assert_eq!(initial_chain(None, None), ChainType::Ethereum);
+    }
+
+    #[test]
+    fn evm_chains_share_accounts_and_keep_remembered_one() {
+        let mut wallet = Wallet::new("w".to_string(), "test".to_string());
+        wallet.accounts.push(account("ethereum", "0xAAA"));
+        wallet.accounts.push(account("solana", "So1"));
+        wallet.accounts.push(account("ethereum", "0xBBB"));
+
+        let bsc = receive_accounts(&wallet, ChainType::BSC);
+        assert_eq!(bsc.len(), 2);
+        assert_eq!(pick_account(&bsc, Some("0xbbb")).unwrap().address, "0xBBB");
+        assert_eq!(pick_account(&bsc, Some("0xCCC")).unwrap().address, "0xAAA");
+        assert!(receive_accounts(&wallet, ChainType::TON).is_empty());
+
+        assert!(chain_warnings(ChainType::BSC)[0].contains("BSC"));
+    }
+}
@@ -0,0 +1,344 @@
+//! Send Safeguards - 发送前的安全检查
+//!
+//! 在确认发送前给出可逐条确认的风险提示：
+//! - 金额占该代币余额或钱包总价值的比例超过阈值（阈值可配置）
+//! - 向代币自身的合约地址发送，或向非收款用途的已知合约发送
+//!
+//! 同时基于本地发送记录提示“已向该地址发送过 N 次 / 从未发送过”。
+//! 内部转账（接收方为自有账户）不提示总价值占比，也不提示发送记录。
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 默认：金额超过代币余额的 50% 时提示
+pub const DEFAULT_BALANCE_WARN_PERCENT: f64 = 50.0;
+/// 默认：金额超过钱包总价值的 25% 时提示
+pub const DEFAULT_PORTFOLIO_WARN_PERCENT: f64 = 25.0;
+/// 阈值配置（LocalStorage）
+const CONFIG_STORAGE_KEY: &str = "send_safeguard_config";
+/// 本地发送记录（LocalStorage）
+const HISTORY_STORAGE_KEY: &str = "send_address_history";
+
+/// 已知合约
+struct KnownContract {
+    address: &'static str,
+    label: &'static str,
+    /// 是否为正常的收款对象（如向 WETH 转入 ETH 即包装）
+    recognized_recipient: bool,
+}
+
+/// 已知合约列表（Ethereum 主网，地址统一小写）
+const KNOWN_CONTRACTS: &[KnownContract] = &[
+    KnownContract {
+        address: "0xdac17f958d2ee523a2206206994597c13d831ec7",
+        label: "USDT 代币合约",
+        recognized_recipient: false,
+    },
+    KnownContract {
+        address: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        label: "USDC 代币合约",
+        recognized_recipient: false,
+    },
+    KnownContract {
+        address: "0x6b175474e89094c44da98b954eedeac495271d0f",
+        label: "DAI 代币合约",
+        recognized_recipient: false,
+    },
+    KnownContract {
+        address: "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+        label: "WETH 合约",
+        recognized_recipient: true,
+    },
+    KnownContract {
+        address: "0x7a250d5630b4cf539739df2c5dacb4c659f2488d",
+        label: "Uniswap V2 路由合约",
+        recognized_recipient: false,
+    },
+    KnownContract {
+        address: "0x3fc91a3afd70395cd496c647d5a6cc9d4b2b7fad",
+        label: "Uniswap 通用路由合约",
+        recognized_recipient: false,
+    },
+];
+
+/// 风险提示阈值配置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SafeguardConfig {
+    /// 金额占代币余额的百分比阈值
+    pub balance_warn_percent: f64,
+    /// 金额占钱包总价值的百分比阈值
+    pub portfolio_warn_percent: f64,
+}
+
+impl Default for SafeguardConfig {
+    fn default() -> Self {
+        Self {
+            balance_warn_percent: DEFAULT_BALANCE_WARN_PERCENT,
+            portfolio_warn_percent: DEFAULT_PORTFOLIO_WARN_PERCENT,
+        }
+    }
+}
+
+impl SafeguardConfig {
+    /// 读取用户设置的阈值
+    pub fn load() -> Self {
+        LocalStorage::get::<Self>(CONFIG_STORAGE_KEY)
+            .ok()
+            .filter(|c| c.is_valid())
+            .unwrap_or_default()
+    }
+
+    /// 保存阈值
+    pub fn save(&self) {
+        if self.is_valid() {
+            let _ = LocalStorage::set(CONFIG_STORAGE_KEY, self);
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        [self.balance_warn_percent, self.portfolio_warn_percent]
+            .iter()
+            .all(|p| p.is_finite() && *p > 0.0 && *p <= 100.0)
+    }
+}
+
+/// 发送前风险提示
+#[derive(Debug, Clone, PartialEq)]
+pub enum SendWarning {
+    /// 金额占代币余额比例过高
+    LargeShareOfBalance { percent: f64 },
+    /// 金额占钱包总价值比例过高
+    LargeShareOfPortfolio { percent: f64 },
+    /// 接收地址是该代币自身的合约地址
+    TokenContractRecipient { symbol: String },
+    /// 接收地址是非收款用途的已知合约
+    KnownContractRecipient { label: String },
+}
+
+impl SendWarning {
+    /// 稳定标识（用于确认状态与审计日志）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::LargeShareOfBalance { .. } => "large_share_of_balance",
+            Self::LargeShareOfPortfolio { .. } => "large_share_of_portfolio",
+            Self::TokenContractRecipient { .. } => "token_contract_recipient",
+            Self::KnownContractRecipient { .. } => "known_contract_recipient",
+        }
+    }
+
+    /// 用户可读的提示
+    pub fn message(&self) -> String {
+        match self {
+            Self::LargeShareOfBalance { percent } => {
+                format!("本次发送占该代币余额的 {:.0}%，请确认金额无误", percent)
+            }
+            Self::LargeShareOfPortfolio { percent } => {
+                format!("本次发送约占钱包总价值的 {:.0}%，请确认金额无误", percent)
+            }
+            Self::TokenContractRecipient { symbol } => format!(
+                "接收地址是 {} 的代币合约地址，发送到此地址的资产通常无法找回",
+                symbol
+            ),
+            Self::KnownContractRecipient { label } => format!(
+                "接收地址是{}，该合约不是普通收款地址，资产可能无法找回",
+                label
+            ),
+        }
+    }
+}
+
+/// 风险检查输入
+#[derive(Debug, Clone, Default)]
+pub struct SendCheck<'a> {
+    pub recipient: &'a str,
+    pub amount: f64,
+    pub token_symbol: &'a str,
+    /// 代币合约地址（原生币为 None）
+    pub token_address: Option<&'a str>,
+    /// 代币可用余额（未加载时为 None）
+    pub token_balance: Option<f64>,
+    /// 本次发送的 USD 价值（价格未就绪时为 None）
+    pub amount_usd: Option<f64>,
+    /// 钱包总价值 USD（未就绪时为 None）
+    pub portfolio_usd: Option<f64>,
+    /// 接收方为自有账户（资产仍在钱包内，不计入总价值占比）
+    pub internal: bool,
+}
+
+/// 计算风险提示
+pub fn evaluate(check: &SendCheck<'_>, config: &SafeguardConfig) -> Vec<SendWarning> {
+    let mut warnings = Vec::new();
+    if !(check.amount.is_finite() && check.amount > 0.0) {
+        return warnings;
+    }
+
+    if let Some(balance) = check.token_balance.filter(|b| *b > 0.0) {
+        let percent = check.amount / balance * 100.0;
+        if percent >= config.balance_warn_percent {
+            warnings.push(SendWarning::LargeShareOfBalance { percent });
+        }
+    }
+
+    if let (false, Some(value), Some(total)) = (
+        check.internal,
+        check.amount_usd,
+        check.portfolio_usd.filter(|t| *t > 0.0),
+    ) {
+        let percent = (value / total * 100.0).min(100.0);
+        if percent >= config.portfolio_warn_percent {
+            warnings.push(SendWarning::LargeShareOfPortfolio { percent });
+        }
+    }
+
+    let recipient = check.recipient.trim().to_lowercase();
+    if recipient.is_empty() {
+        return warnings;
+    }
+    if check
+        .token_address
+        .is_some_and(|addr| addr.trim().to_lowercase() == recipient)
+    {
+        warnings.push(SendWarning::TokenContractRecipient {
+            symbol: check.token_symbol.to_string(),
+        });
+    } else if let Some(contract) = KNOWN_CONTRACTS
+        .iter()
+        .find(|c| c.address == recipient && !c.recognized_recipient)
+    {
+        warnings.push(SendWarning::KnownContractRecipient {
+            label: contract.label.to_string(),
+        });
+    }
+
+    warnings
+}
+
+/// 本地发送记录（地址 → 成功发送次数）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SendHistory {
+    counts: HashMap<String, u32>,
+}
+
+impl SendHistory {
+    pub fn load() -> Self {
+        LocalStorage::get::<Self>(HISTORY_STORAGE_KEY).unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let _ = LocalStorage::set(HISTORY_STORAGE_KEY, self);
+    }
+
+    fn key(address: &str) -> String {
+        address.trim().to_lowercase()
+    }
+
+    /// 向该地址成功发送过的次数
+    pub fn count(&self, address: &str) -> u32 {
+        self.counts.get(&Self::key(address)).copied().unwrap_or(0)
+    }
+
+    /// 所有发送过的地址（小写）及次数
+    pub fn counts(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(address, count)| (address.as_str(), *count))
+    }
+
+    /// 记录一次成功发送
+    pub fn increment(&mut self, address: &str) {
+        *self.counts.entry(Self::key(address)).or_insert(0) += 1;
+    }
+
+    /// 记录一次成功发送并保存
+    pub fn record(address: &str) {
+        let mut history = Self::load();
+        history.increment(address);
+        history.save();
+    }
+
+    /// 发送记录提示
+    pub fn hint(count: u32) -> String {
+        if count == 0 {
+            "您从未向该地址发送过资产，请仔细核对地址".to_string()
+        } else {
+            format!("您此前已向该地址发送过 {} 次", count)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_share_warnings_respect_thresholds() {
+        let config = SafeguardConfig::default();
+        let mut check = SendCheck {
+            recipient: "0x1111111111111111111111111111111111111111",
+            amount: 6.0,
+            token_symbol: "ETH",
+            token_balance: Some(10.0),
+            amount_usd: Some(100.0),
+            portfolio_usd: Some(1000.0),
+            ..Default::default()
+        };
+        let codes: Vec<_> = evaluate(&check, &config).iter().map(|w| w.code()).collect();
+        assert_eq!(codes, vec!["large_share_of_balance"]);
+
+        check.amount = 1.0;
+        check.amount_usd = Some(300.0);
+        let codes: Vec<_> = evaluate(&check, &config).iter().map(|w| w.code()).collect();
+        assert_eq!(codes, vec!["large_share_of_portfolio"]);
+
+        check.internal = true;
+        assert!(evaluate(&check, &config).is_empty());
+
+        check.internal = false;
+        check.token_balance = None;
+        check.portfolio_usd = None;
+        assert!(evaluate(&check, &config).is_empty());
+    }
+
+    #[test]
+    fn contract_recipients_are_flagged_and_history_counts() {
+        let config = SafeguardConfig::default();
+        let usdt = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+        let check = SendCheck {
+            recipient: usdt,
+            amount: 1.0,
+            token_symbol: "USDT",
+            token_address: Some(usdt),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate(&check, &config),
+            vec![SendWarning::TokenContractRecipient {
+                symbol: "USDT".to_string()
+            }]
+        );
+
+        let to_router = SendCheck {
+            recipient: "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D",
+            amount: 1.0,
+            token_symbol: "ETH",
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate(&to_router, &config)[0].code(),
+            "known_contract_recipient"
+        );
+
+        let to_weth = SendCheck {
+            recipient: "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+            ..to_router
+        };
+        assert!(evaluate(&to_weth, &config).is_empty());
+
+        let mut history = SendHistory::default();
+        assert_eq!(history.count(usdt), 0);
+        history.increment(usdt);
+        history.increment(&usdt.to_lowercase());
+        assert_eq!(history.count(usdt), 2);
+    }
+}
@@ -0,0 +1,595 @@
+//! Simulate - 任意 calldata 的交易模拟（"这笔交易会花多少？"）
+//!
+//! - 解码常见函数调用（ERC-20 / ERC-721 / WETH），高风险授权给出提示
+//! - 由节点 eth_call 模拟执行，返回结果或回滚原因，不签名、不广播
+//! - 按模拟的 Gas 用量与当前 Gas 价格估算手续费（原生币与美元）
+//! - 用户确认后，同一份 payload 走正常的签名与广播流程
+
+use crate::features::wallet::chain_accounts;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::{ChainConfigManager, ChainGroup};
+use crate::services::gas::{gas_fee_eth_from_max_fee_per_gas_gwei, GasService};
+use crate::services::gas_limit::GasLimitService;
+use crate::services::price::PriceService;
+use crate::services::transaction::{BroadcastReceipt, SimulationRequest, TransactionService};
+use crate::shared::kill_switch::{self, MoneyAction};
+use crate::shared::state::AppState;
+use dioxus::prelude::ReadableExt;
+use sha3::{Digest, Keccak256};
+
+/// 交易模拟功能开关（默认关闭）
+pub const FEATURE_FLAG: &str = "tx_simulator";
+
+/// 可模拟的链（仅 EVM）
+pub const SIMULATABLE_CHAINS: [ChainType; 3] =
+    [ChainType::Ethereum, ChainType::BSC, ChainType::Polygon];
+
+/// EVM 原生币精度
+const NATIVE_DECIMALS: u32 = 18;
+
+/// Error(string) 回滚数据的选择器
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Panic(uint256) 回滚数据的选择器
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// ABI 参数类型（只解码静态类型）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AbiType {
+    Address,
+    Uint256,
+    Bool,
+}
+
+/// 可识别的函数：（签名, 参数名）
+const KNOWN_FUNCTIONS: &[(&str, &[&str])] = &[
+    ("transfer(address,uint256)", &["to", "amount"]),
+    ("approve(address,uint256)", &["spender", "amount"]),
+    (
+        "transferFrom(address,address,uint256)",
+        &["from", "to", "amount"],
+    ),
+    (
+        "increaseAllowance(address,uint256)",
+        &["spender", "addedValue"],
+    ),
+    ("setApprovalForAll(address,bool)", &["operator", "approved"]),
+    (
+        "safeTransferFrom(address,address,uint256)",
+        &["from", "to", "tokenId"],
+    ),
+    ("deposit()", &[]),
+    ("withdraw(uint256)", &["amount"]),
+];
+
+/// 函数签名的 4 字节选择器
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = Keccak256::digest(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn param_types(signature: &str) -> Vec<AbiType> {
+    let inner = signature
+        .split_once('(')
+        .and_then(|(_, rest)| rest.strip_suffix(')'))
+        .unwrap_or_default();
+    inner
+        .split(',')
+        .filter(|t| !t.is_empty())
+        .map(|t| match t {
+            "address" => AbiType::Address,
+            "bool" => AbiType::Bool,
+            _ => AbiType::Uint256,
+        })
+        .collect()
+}
+
+/// 解码后的参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedParam {
+    pub name: &'static str,
+    pub value: String,
+}
+
+/// 解码结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodedCall {
+    /// 无 calldata：原生币转账
+    NativeTransfer,
+    /// 可识别的函数调用
+    Known {
+        signature: &'static str,
+        params: Vec<DecodedParam>,
+        /// 需要用户注意的风险（如无限授权）
+        warnings: Vec<String>,
+    },
+    /// 未识别的选择器
+    Unknown { selector: String },
+}
+
+impl DecodedCall {
+    /// 显示名称
+    pub fn title(&self) -> String {
+        match self {
+            DecodedCall::NativeTransfer => "原生币转账（无 calldata）".to_string(),
+            DecodedCall::Known { signature, .. } => signature.to_string(),
+            DecodedCall::Unknown { selector } => format!("未知函数 {}", selector),
+        }
+    }
+}
+
+/// 解析十六进制字符串（可带 0x，允许为空）
+pub fn parse_hex(input: &str) -> Result<Vec<u8>, String> {
+    let trimmed = input.trim();
+    let clean = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+    if !clean.len().is_multiple_of(2) {
+        return Err("十六进制数据长度必须为偶数".to_string());
+    }
+    hex::decode(clean).map_err(|_| "包含非十六进制字符".to_string())
+}
+
+/// 32 字节大端整数：能放入 u128 时显示十进制，否则显示十六进制
+fn format_uint(word: &[u8]) -> String {
+    if word[..16].iter().all(|b| *b == 0) {
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&word[16..]);
+        u128::from_be_bytes(low).to_string()
+    } else if word.iter().all(|b| *b == 0xff) {
+        "2^256-1（无限）".to_string()
+    } else {
+        format!("0x{}", hex::encode(word))
+    }
+}
+
+/// 解码 calldata
+pub fn decode_calldata(data: &[u8]) -> Result<DecodedCall, String> {
+    if data.is_empty() {
+        return Ok(DecodedCall::NativeTransfer);
+    }
+    if data.len() < 4 {
+        return Err("calldata 长度不足 4 字节（缺少函数选择器）".to_string());
+    }
+    let (head, args) = data.split_at(4);
+    let Some(&(signature, names)) = KNOWN_FUNCTIONS
+        .iter()
+        .find(|(signature, _)| selector(signature) == head)
+    else {
+        return Ok(DecodedCall::Unknown {
+            selector: format!("0x{}", hex::encode(head)),
+        });
+    };
+
+    let types = param_types(signature);
+    if args.len() < types.len() * 32 {
+        return Err(format!(
+            "{} 需要 {} 字节参数，实际只有 {} 字节",
+            signature,
+            types.len() * 32,
+            args.len()
+        ));
+    }
+
+    let mut params = Vec::with_capacity(types.len());
+    let mut warnings = Vec::new();
+    for (i, (ty, name)) in types.iter().zip(names.iter()).enumerate() {
+        let word = &args[i * 32..(i + 1) * 32];
+        let value = match ty {
+            AbiType::Address => format!("0x{}", hex::encode(&word[12..])),
+            AbiType::Bool => (word[31] != 0).to_string(),
+            AbiType::Uint256 => format_uint(word),
+        };
+        params.push(DecodedParam { name, value });
+    }
+
+    let is_unlimited = |i: usize| args[i * 32..(i + 1) * 32].iter().all(|b| *b == 0xff);
+    match signature {
+        "approve(address,uint256)" | "increaseAllowance(address,uint256)" if is_unlimited(1) => {
+            warnings.push("无限授权：授权对象可以随时转走该代币的全部余额".to_string());
+        }
+        "setApprovalForAll(address,bool)" if args[63] != 0 => {
+            warnings.push("授权对象可以转走该合集中的全部 NFT".to_string());
+        }
+        _ => {}
+    }
+
+    Ok(DecodedCall::Known {
+        signature,
+        params,
+        warnings,
+    })
+}
+
+/// 解码回滚数据：Error(string) / Panic(uint256)，其他情况显示原始数据
+pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    if data.len() < 4 {
+        return None;
+    }
+    let (head, args) = data.split_at(4);
+    if head == ERROR_SELECTOR && args.len() >= 64 {
+        let len_word = &args[32..64];
+        if len_word[..24].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut len_bytes = [0u8; 8];
+        len_bytes.copy_from_slice(&len_word[24..]);
+        let len = u64::from_be_bytes(len_bytes) as usize;
+        let text = args.get(64..64 + len)?;
+        return Some(String::from_utf8_lossy(text).into_owned());
+    }
+    if head == PANIC_SELECTOR && args.len() >= 32 {
+        let code = args[31];
+        let meaning = match code {
+            0x01 => "断言失败",
+            0x11 => "算术溢出",
+            0x12 => "除以零",
+            0x32 => "数组越界",
+            _ => "合约异常",
+        };
+        return Some(format!("Panic(0x{:02x})：{}", code, meaning));
+    }
+    Some(format!("自定义错误 0x{}", hex::encode(data)))
+}
+
+/// 将原生币金额（如 "0.5"）转换为 wei，按字符串计算避免浮点误差
+pub fn parse_native_amount(input: &str) -> Result<u128, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(0);
+    }
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if !whole.chars().all(|c| c.is_ascii_digit()) || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err("金额格式无效".to_string());
+    }
+    if fraction.len() > NATIVE_DECIMALS as usize {
+        return Err(format!("小数位最多 {} 位", NATIVE_DECIMALS));
+    }
+    let padded = format!(
+        "{}{:0<width$}",
+        whole,
+        fraction,
+        width = NATIVE_DECIMALS as usize
+    );
+    let digits = padded.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits.parse().map_err(|_| "金额过大".to_string())
+}
+
+/// 校验 EVM 地址格式
+fn parse_evm_address(input: &str) -> Result<String, String> {
+    let address = input.trim();
+    let valid = address.len() == 42
+        && address.starts_with("0x")
+        && address[2..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err("目标地址必须是 0x 开头的 40 位十六进制地址".to_string());
+    }
+    Ok(address.to_string())
+}
+
+/// 待模拟（以及确认后签名）的交易
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxPayload {
+    pub chain: ChainType,
+    pub from: String,
+    pub to: String,
+    pub value_wei: u128,
+    /// 0x 开头的 calldata（可能只有 "0x"）
+    pub data: String,
+}
+
+impl TxPayload {
+    /// 由表单输入构建并校验
+    pub fn from_input(
+        chain: ChainType,
+        from: &str,
+        to: &str,
+        value: &str,
+        data: &str,
+    ) -> Result<Self, String> {
+        if ChainGroup::of(chain) != ChainGroup::Evm {
+            return Err(format!("{} 暂不支持交易模拟", chain.label()));
+        }
+        let to = parse_evm_address(to)?;
+        let value_wei = parse_native_amount(value)?;
+        let data = parse_hex(data)?;
+        Ok(Self {
+            chain,
+            from: from.to_string(),
+            to,
+            value_wei,
+            data: format!("0x{}", hex::encode(data)),
+        })
+    }
+
+    pub fn data_bytes(&self) -> Vec<u8> {
+        parse_hex(&self.data).unwrap_or_default()
+    }
+}
+
+/// 模拟执行结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimOutcome {
+    Success { return_data: Option<String> },
+    Reverted { reason: String },
+}
+
+/// 手续费估算
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeQuote {
+    /// 已加安全余量的 Gas 上限（签名时使用）
+    pub gas_limit: u64,
+    pub max_fee_per_gas_gwei: f64,
+    pub native_symbol: String,
+    pub native: f64,
+    /// 原生币价格不可用时为空
+    pub usd: Option<f64>,
+}
+
+impl FeeQuote {
+    /// 签名使用的 Gas 价格（wei）
+    pub fn gas_price_wei(&self) -> u64 {
+        (self.max_fee_per_gas_gwei * 1e9).round() as u64
+    }
+}
+
+/// 一次模拟的完整报告
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationReport {
+    pub payload: TxPayload,
+    pub decoded: Result<DecodedCall, String>,
+    pub outcome: SimOutcome,
+    pub fee: Option<FeeQuote>,
+}
+
+impl SimulationReport {
+    /// 只有模拟成功且有手续费估算时才允许继续签名
+    pub fn can_proceed(&self) -> bool {
+        matches!(self.outcome, SimOutcome::Success { .. }) && self.fee.is_some()
+    }
+}
+
+fn chain_id(chain: ChainType) -> Result<u64, String> {
+    ChainConfigManager::new()
+        .get_chain_id(chain)
+        .map_err(|e| format!("获取Chain ID失败: {}", e))
+}
+
+/// 模拟执行并估算手续费（不签名、不广播）
+pub async fn simulate(app_state: AppState, payload: TxPayload) -> Result<SimulationReport, String> {
+    let chain_id = chain_id(payload.chain)?;
+    let decoded = decode_calldata(&payload.data_bytes());
+
+    let request = SimulationRequest {
+        chain: payload.chain.as_str().to_string(),
+        from: payload.from.clone(),
+        to: payload.to.clone(),
+        value: payload.value_wei.to_string(),
+        data: payload.data.clone(),
+    };
+    let result = TransactionService::new(app_state)
+        .simulate(&request)
+        .await
+        .map_err(|e| format!("模拟执行失败: {}", e))?;
+
+    if !result.success {
+        let reason = result
+            .revert_reason
+            .filter(|r| !r.trim().is_empty())
+            .or_else(|| {
+                result
+                    .revert_data
+                    .as_deref()
+                    .and_then(|d| parse_hex(d).ok())
+                    .and_then(|d| decode_revert_reason(&d))
+            })
+            .unwrap_or_else(|| "未返回回滚原因".to_string());
+        return Ok(SimulationReport {
+            payload,
+            decoded,
+            outcome: SimOutcome::Reverted { reason },
+            fee: None,
+        });
+    }
+
+    let fee = estimate_fee(app_state, &payload, chain_id, result.gas_used).await;
+    Ok(SimulationReport {
+        payload,
+        decoded,
+        outcome: SimOutcome::Success {
+            return_data: result.return_data.filter(|d| d != "0x" && !d.is_empty()),
+        },
+        fee,
+    })
+}
+
+/// 按模拟的 Gas 用量（缺失时重新估算）与当前标准档 Gas 价格计算手续费
+async fn estimate_fee(
+    app_state: AppState,
+    payload: &TxPayload,
+    chain_id: u64,
+    gas_used: Option<u64>,
+) -> Option<FeeQuote> {
+    let gas_limit_service = GasLimitService::new(app_state);
+    let gas_limit = match gas_used {
+        Some(used) => gas_limit_service.with_margin(chain_id, used),
+        None => gas_limit_service
+            .estimate(
+                chain_id,
+                &payload.from,
+                &payload.to,
+                &payload.value_wei.to_string(),
+                Some(&payload.data),
+            )
+            .await
+            .ok()?,
+    };
+    let gas = GasService::new(app_state)
+        .estimate_all(payload.chain.as_str())
+        .await
+        .map_err(|e| log::warn!("获取Gas价格失败: {}", e))
+        .ok()?;
+    let max_fee_per_gas_gwei = gas.average.max_fee_per_gas_gwei;
+    let native = gas_fee_eth_from_max_fee_per_gas_gwei(max_fee_per_gas_gwei, gas_limit);
+    let native_symbol = ChainConfigManager::new()
+        .get_native_token(payload.chain)
+        .unwrap_or_else(|_| "ETH".to_string());
+    let usd = PriceService::new(app_state)
+        .get_price(&native_symbol)
+        .await
+        .ok()
+        .map(|p| p.usd * native);
+    Some(FeeQuote {
+        gas_limit,
+        max_fee_per_gas_gwei,
+        native_symbol,
+        native,
+        usd,
+    })
+}
+
+/// 用户确认后，按模拟时的 payload 与手续费签名并广播
+pub async fn sign_and_broadcast(
+    app_state: AppState,
+    report: &SimulationReport,
+) -> Result<BroadcastReceipt, String> {
+    let fee = report
+        .fee
+        .as_ref()
+        .filter(|_| report.can_proceed())
+        .ok_or_else(|| "模拟未通过，不能签名".to_string())?;
+    let payload = &report.payload;
+    let chain_id = chain_id(payload.chain)?;
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Sign).await?;
+    let private_key = chain_accounts::selected_wallet_signing_key(&app_state, &payload.from)
+        .map_err(|e| e.to_string())?;
+
+    let tx_service = TransactionService::new(app_state);
+    let nonce = tx_service
+        .get_nonce(&payload.from, chain_id)
+        .await
+        .map_err(|e| format!("获取nonce失败: {}", e))?;
+
+    let signed = crate::crypto::worker::sign_eth_transaction_with_data(
+        &private_key,
+        &payload.to,
+        &payload.value_wei.to_string(),
+        &payload.data,
+        nonce,
+        fee.gas_price_wei(),
+        fee.gas_limit,
+        chain_id,
+    )
+    .await
+    .map_err(|e| format!("签名交易失败: {}", e))?;
+
+    tx_service
+        .broadcast_verified(payload.chain.as_str(), &signed)
+        .await
+        .map_err(|e| format!("广播交易失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(hex_str: &str) -> String {
+        format!("{:0>64}", hex_str)
+    }
+
+    #[test]
+    fn selectors_match_known_values() {
+        assert_eq!(
+            selector("transfer(address,uint256)"),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+        assert_eq!(
+            selector("approve(address,uint256)"),
+            [0x09, 0x5e, 0xa7, 0xb3]
+        );
+        assert_eq!(selector("Error(string)"), ERROR_SELECTOR);
+        assert_eq!(selector("Panic(uint256)"), PANIC_SELECTOR);
+    }
+
+    #[test]
+    fn decodes_transfer_and_flags_unlimited_approval() {
+        let to = "1111111111111111111111111111111111111111";
+        let transfer = parse_hex(&format!("0xa9059cbb{}{}", word(to), word("3e8"))).unwrap();
+        let DecodedCall::Known {
+            signature,
+            params,
+            warnings,
+        } = decode_calldata(&transfer).unwrap()
+        else {
+            panic!("transfer 应被识别");
+        };
+        assert_eq!(signature, "transfer(address,uint256)");
+        assert_eq!(params[0].value, format!("0x{}", to));
+        assert_eq!(params[1].value, "1000");
+        assert!(warnings.is_empty());
+
+        let approve = parse_hex(&format!("095ea7b3{}{}", word(to), "f".repeat(64))).unwrap();
+        let DecodedCall::Known { warnings, .. } = decode_calldata(&approve).unwrap() else {
+            panic!("approve 应被识别");
+        };
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn handles_empty_unknown_and_truncated_calldata() {
+        assert_eq!(decode_calldata(&[]).unwrap(), DecodedCall::NativeTransfer);
+        assert_eq!(
+            decode_calldata(&[0xde, 0xad, 0xbe, 0xef]).unwrap(),
+            DecodedCall::Unknown {
+                selector: "0xdeadbeef".to_string()
+            }
+        );
+        assert!(decode_calldata(&[0xa9, 0x05]).is_err());
+        assert!(decode_calldata(&[0xa9, 0x05, 0x9c, 0xbb, 0x00]).is_err());
+    }
+
+    #[test]
+    fn decodes_revert_reasons() {
+        let message = hex::encode("too little received");
+        let error = parse_hex(&format!(
+            "08c379a0{}{}{:0<64}",
+            word("20"),
+            word("13"),
+            message
+        ))
+        .unwrap();
+        assert_eq!(
+            decode_revert_reason(&error).as_deref(),
+            Some("too little received")
+        );
+
+        let panic = parse_hex(&format!("4e487b71{}", word("11"))).unwrap();
+        assert!(decode_revert_reason(&panic).unwrap().contains("算术溢出"));
+        assert!(decode_revert_reason(&[0x01, 0x02, 0x03, 0x04])
+            .unwrap()
+            .starts_with("自定义错误"));
+    }
+
+    #[test]
+    fn parses_native_amounts_without_float_error() {
+        assert_eq!(parse_native_amount("").unwrap(), 0);
+        assert_eq!(parse_native_amount("0.1").unwrap(), 100_000_000_000_000_000);
+        assert_eq!(parse_native_amount("1").unwrap(), 1_000_000_000_000_000_000);
+        assert!(parse_native_amount("1.2.3").is_err());
+        assert!(parse_native_amount("0.0000000000000000001").is_err());
+    }
+
+    #[test]
+    fn payload_validates_chain_address_and_data() {
+        let to = "0x1111111111111111111111111111111111111111";
+        let payload =
+            TxPayload::from_input(ChainType::Polygon, "0xabc", to, "0", "0XD0E30DB0").unwrap();
+        assert_eq!(payload.data, "0xd0e30db0");
+        assert!(TxPayload::from_input(ChainType::Solana, "a", to, "0", "").is_err());
+        assert!(TxPayload::from_input(ChainType::Ethereum, "0xabc", "0x12", "0", "").is_err());
+        assert!(TxPayload::from_input(ChainType::Ethereum, "0xabc", to, "0", "0xabc").is_err());
+    }
+}
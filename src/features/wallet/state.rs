@@ -1,6 +1,11 @@
+use crate::crypto::key_manager::KeyManager;
+use chrono::{DateTime, Duration, Utc};
 use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
 
+/// 软删除的钱包保留天数，期间可在设置中撤销，到期后永久清除
+pub const SOFT_DELETE_RETENTION_DAYS: i64 = 7;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AccountType {
     Derived,  // From HD Seed
@@ -66,6 +71,9 @@ pub struct Wallet {
     pub created_at: String,     // 创建时间
     pub accounts: Vec<Account>, // 账户列表
     pub selected_account_index: Option<usize>,
+    /// 助记词/私钥备份已验证（创建时完成抄写验证或通过导入获得）
+    #[serde(default)]
+    pub backup_verified: bool,
 }
 
 impl Wallet {
@@ -78,10 +86,32 @@ impl Wallet {
             created_at: now,
             accounts: Vec::new(),
             selected_account_index: None,
+            backup_verified: false,
         }
     }
 }
 
+/// 已软删除、等待永久清除的钱包
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeletedWallet {
+    pub wallet: Wallet,
+    pub deleted_at: String, // 删除时间（RFC3339）
+}
+
+impl DeletedWallet {
+    /// 永久清除时间
+    pub fn purge_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.deleted_at)
+            .ok()
+            .map(|t| t.with_timezone(&Utc) + Duration::days(SOFT_DELETE_RETENTION_DAYS))
+    }
+
+    /// 是否已过保留期（删除时间无法解析时视为已过期）
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.purge_at().is_none_or(|t| now >= t)
+    }
+}
+
 /// 钱包状态（多钱包设计）
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WalletState {
@@ -90,18 +120,24 @@ pub struct WalletState {
     pub version: u32,
     pub wallets: Vec<Wallet>,               // 钱包列表
     pub selected_wallet_id: Option<String>, // 当前选中的钱包ID
+    #[serde(default)]
+    pub deleted_wallets: Vec<DeletedWallet>, // 最近删除（保留期内可撤销）
 }
 
 fn default_version() -> u32 {
     3 // 新版本：支持多钱包
 }
 
+/// 当前存储版本（4：修复旧版编码生成的 Bitcoin 地址）
+const CURRENT_VERSION: u32 = 4;
+
 impl Default for WalletState {
     fn default() -> Self {
         Self {
-            version: 3,
+            version: CURRENT_VERSION,
             wallets: Vec::new(),
             selected_wallet_id: None,
+            deleted_wallets: Vec::new(),
         }
     }
 }
@@ -111,9 +147,9 @@ impl WalletState {
     pub async fn load() -> Self {
         // 尝试加载当前版本
         if let Ok(mut stored) = LocalStorage::get::<WalletState>("wallet_state") {
-            if stored.version < 3 {
+            if stored.version < CURRENT_VERSION {
                 // 迁移旧版本数据（如果有）
-                stored.version = 3;
+                stored.migrate();
                 let _ = LocalStorage::set("wallet_state", &stored);
             }
             return stored;
@@ -122,6 +158,26 @@ impl WalletState {
         Self::default()
     }
 
+    /// 升级到当前存储版本
+    fn migrate(&mut self) {
+        if self.version < 4 {
+            let wallets = self
+                .wallets
+                .iter_mut()
+                .chain(self.deleted_wallets.iter_mut().map(|d| &mut d.wallet));
+            for account in wallets.flat_map(|w| w.accounts.iter_mut()) {
+                if !account.chain.eq_ignore_ascii_case("bitcoin") {
+                    continue;
+                }
+                if let Some(repaired) = KeyManager::repair_legacy_btc_address(&account.address) {
+                    log::info!("已修复旧版编码的 Bitcoin 账户地址");
+                    account.address = repaired;
+                }
+            }
+        }
+        self.version = CURRENT_VERSION;
+    }
+
     /// 保存钱包状态（到LocalStorage）
     pub fn save(&self) -> Result<(), gloo_storage::errors::StorageError> {
         LocalStorage::set("wallet_state", self)
@@ -159,16 +215,71 @@ impl WalletState {
 
     /// 删除钱包
     pub fn remove_wallet(&mut self, wallet_id: &str) -> bool {
-        if let Some(pos) = self.wallets.iter().position(|w| w.id == wallet_id) {
-            self.wallets.remove(pos);
-            // 如果删除的是选中的钱包，清空选中状态
-            if self.selected_wallet_id.as_ref() == Some(&wallet_id.to_string()) {
-                self.selected_wallet_id = None;
+        self.detach_wallet(wallet_id).is_some()
+    }
+
+    /// 从列表中移出钱包；若是选中的钱包，改选剩余的第一个
+    fn detach_wallet(&mut self, wallet_id: &str) -> Option<Wallet> {
+        let pos = self.wallets.iter().position(|w| w.id == wallet_id)?;
+        let wallet = self.wallets.remove(pos);
+        if self.selected_wallet_id.as_deref() == Some(wallet_id) {
+            self.selected_wallet_id = self.wallets.first().map(|w| w.id.clone());
+        }
+        Some(wallet)
+    }
+
+    /// 软删除：移入"最近删除"，保留期内可撤销
+    pub fn soft_delete_wallet(&mut self, wallet_id: &str, now: DateTime<Utc>) -> bool {
+        match self.detach_wallet(wallet_id) {
+            Some(wallet) => {
+                self.deleted_wallets.push(DeletedWallet {
+                    wallet,
+                    deleted_at: now.to_rfc3339(),
+                });
+                true
             }
-            true
-        } else {
-            false
+            None => false,
+        }
+    }
+
+    /// 撤销软删除；当前没有选中钱包时选中恢复的钱包
+    pub fn restore_wallet(&mut self, wallet_id: &str) -> bool {
+        let Some(pos) = self
+            .deleted_wallets
+            .iter()
+            .position(|d| d.wallet.id == wallet_id)
+        else {
+            return false;
+        };
+        let wallet = self.deleted_wallets.remove(pos).wallet;
+        if self.selected_wallet_id.is_none() {
+            self.selected_wallet_id = Some(wallet.id.clone());
         }
+        self.wallets.push(wallet);
+        true
+    }
+
+    /// 从"最近删除"中取出指定钱包（立即永久删除）
+    pub fn take_deleted_wallet(&mut self, wallet_id: &str) -> Option<Wallet> {
+        let pos = self
+            .deleted_wallets
+            .iter()
+            .position(|d| d.wallet.id == wallet_id)?;
+        Some(self.deleted_wallets.remove(pos).wallet)
+    }
+
+    /// 取出所有已过保留期的钱包（由调用方执行永久清除）
+    pub fn take_expired_wallets(&mut self, now: DateTime<Utc>) -> Vec<Wallet> {
+        let (expired, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.deleted_wallets)
+            .into_iter()
+            .partition(|d| d.is_expired(now));
+        self.deleted_wallets = kept;
+        expired.into_iter().map(|d| d.wallet).collect()
+    }
+
+    /// 名称是否属于已软删除的钱包（后端同步时跳过，避免被重新加回列表）
+    pub fn is_soft_deleted_name(&self, name: &str) -> bool {
+        self.deleted_wallets.iter().any(|d| d.wallet.name == name)
     }
 
     /// 检查是否有钱包
@@ -209,3 +320,81 @@ impl WalletState {
             .and_then(|w| w.selected_account_index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with(ids: &[&str]) -> WalletState {
+        let mut state = WalletState::default();
+        for id in ids {
+            state.add_wallet(Wallet::new(id.to_string(), format!("钱包{}", id)));
+        }
+        state.selected_wallet_id = ids.first().map(|id| id.to_string());
+        state
+    }
+
+    #[test]
+    fn migration_repairs_legacy_bitcoin_addresses() {
+        let legacy = "bc1qqp3kzgvpvvp6xc5qsqqcxcppy9pzzsurqzqgrgfzcfsuyc8rsypsjxgll6";
+        let mut wallet = Wallet::new("w".to_string(), "钱包".to_string());
+        for (chain, address) in [("bitcoin", legacy), ("ethereum", "0xabc")] {
+            wallet.accounts.push(Account {
+                address: address.to_string(),
+                chain: chain.to_string(),
+                public_key: String::new(),
+                derivation_path: None,
+                account_type: AccountType::Derived,
+                balance: String::new(),
+            });
+        }
+        let mut state = WalletState {
+            version: 3,
+            ..state_with(&[])
+        };
+        state.add_wallet(wallet);
+        state.migrate();
+
+        assert_eq!(state.version, CURRENT_VERSION);
+        let accounts = &state.wallets[0].accounts;
+        assert_eq!(
+            Some(accounts[0].address.clone()),
+            KeyManager::repair_legacy_btc_address(legacy)
+        );
+        assert_eq!(accounts[0].address.len(), 42);
+        assert_eq!(accounts[1].address, "0xabc");
+    }
+
+    #[test]
+    fn soft_delete_reselects_and_restore_brings_wallet_back() {
+        let now = Utc::now();
+        let mut state = state_with(&["a", "b"]);
+        assert!(state.soft_delete_wallet("a", now));
+        assert_eq!(state.selected_wallet_id.as_deref(), Some("b"));
+        assert!(state.get_wallet("a").is_none());
+        assert!(state.is_soft_deleted_name("钱包a"));
+
+        assert!(state.restore_wallet("a"));
+        assert!(state.get_wallet("a").is_some());
+        assert!(state.deleted_wallets.is_empty());
+        // 已有选中钱包时不抢占选中状态
+        assert_eq!(state.selected_wallet_id.as_deref(), Some("b"));
+        assert!(!state.restore_wallet("a"));
+    }
+
+    #[test]
+    fn deleted_wallets_expire_after_retention_period() {
+        let now = Utc::now();
+        let mut state = state_with(&["a", "b"]);
+        state.soft_delete_wallet("a", now - Duration::days(SOFT_DELETE_RETENTION_DAYS));
+        state.soft_delete_wallet("b", now - Duration::days(1));
+        assert_eq!(state.selected_wallet_id, None);
+
+        let expired = state.take_expired_wallets(now);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, "a");
+        assert_eq!(state.deleted_wallets.len(), 1);
+        assert!(state.take_deleted_wallet("b").is_some());
+        assert!(state.deleted_wallets.is_empty());
+    }
+}
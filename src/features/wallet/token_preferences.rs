@@ -0,0 +1,406 @@
+//! Token Preferences - 代币选择偏好
+//!
+//! 代币选择器的收藏（按钱包保存）、隐藏与最近选择记录，以及列表排序规则：
+//! 收藏置顶 → 持有价值从高到低 → 常见代币按市值排名 → 符号字母序。
+//!
+//! 隐藏规则：用户手动隐藏的代币始终隐藏；开启“自动隐藏垃圾代币”后，检测为垃圾代币
+//! （或用户举报为垃圾）的代币也隐藏，除非用户手动设为显示。隐藏的代币默认不计入资产总额。
+
+use crate::services::token::TokenInfo;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// 收藏与最近选择（LocalStorage）
+const STORAGE_KEY: &str = "token_preferences";
+/// 跳转到发送页时预选的代币（读取一次后删除）
+const PENDING_SEND_TOKEN_KEY: &str = "send_pending_token";
+/// 跳转到提现页时预选的代币（读取一次后删除）
+const PENDING_SELL_TOKEN_KEY: &str = "sell_pending_token";
+/// 最近选择最多保留的数量
+pub const MAX_RECENT: usize = 6;
+
+/// 常见代币的市值排名（越靠前市值越高，列表外的代币排在其后）
+const MARKET_CAP_ORDER: &[&str] = &[
+    "BTC", "ETH", "USDT", "BNB", "SOL", "USDC", "TON", "WBTC", "WETH", "LINK", "DAI", "MATIC",
+    "POL", "UNI", "AAVE", "ARB", "OP",
+];
+
+/// 代币在偏好中的唯一键（链 + 小写合约地址）
+pub fn token_key(token: &TokenInfo) -> String {
+    format!("{}:{}", token.chain.as_str(), token.address.to_lowercase())
+}
+
+/// 代币选择偏好
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenPreferences {
+    /// 钱包 ID → 收藏的代币键
+    #[serde(default)]
+    favorites: HashMap<String, Vec<String>>,
+    /// 最近选择的代币键（最新在前）
+    #[serde(default)]
+    recent: Vec<String>,
+    /// 用户隐藏的代币键（不在代币列表中显示，搜索时仍可找到）
+    #[serde(default)]
+    hidden: Vec<String>,
+    /// 用户手动设为显示的代币键（不受自动隐藏垃圾代币影响）
+    #[serde(default)]
+    shown: Vec<String>,
+    /// 自动隐藏垃圾代币
+    #[serde(default)]
+    pub auto_hide_spam: bool,
+    /// 资产总额包含已隐藏的代币
+    #[serde(default)]
+    pub include_hidden_in_totals: bool,
+    /// 用户的举报（代币键 → 是否为垃圾代币），覆盖检测结果
+    #[serde(default)]
+    spam_reports: HashMap<String, bool>,
+}
+
+impl TokenPreferences {
+    pub fn load() -> Self {
+        LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    pub fn is_favorite(&self, wallet_id: &str, key: &str) -> bool {
+        self.favorites
+            .get(wallet_id)
+            .is_some_and(|keys| keys.iter().any(|k| k == key))
+    }
+
+    /// 切换收藏状态
+    pub fn toggle_favorite(&mut self, wallet_id: &str, key: &str) {
+        let keys = self.favorites.entry(wallet_id.to_string()).or_default();
+        if let Some(pos) = keys.iter().position(|k| k == key) {
+            keys.remove(pos);
+        } else {
+            keys.push(key.to_string());
+        }
+    }
+
+    /// 记录一次选择（去重后移到最前）
+    pub fn record_selection(&mut self, key: &str) {
+        self.recent.retain(|k| k != key);
+        self.recent.insert(0, key.to_string());
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    pub fn recent(&self) -> &[String] {
+        &self.recent
+    }
+
+    pub fn is_hidden(&self, key: &str) -> bool {
+        self.hidden.iter().any(|k| k == key)
+    }
+
+    /// 切换隐藏状态
+    pub fn toggle_hidden(&mut self, key: &str) {
+        let hidden = self.is_hidden(key);
+        self.set_hidden(key, !hidden);
+    }
+
+    /// 手动隐藏或显示（显示的代币不再被自动隐藏）
+    pub fn set_hidden(&mut self, key: &str, hidden: bool) {
+        self.hidden.retain(|k| k != key);
+        self.shown.retain(|k| k != key);
+        if hidden {
+            self.hidden.push(key.to_string());
+        } else {
+            self.shown.push(key.to_string());
+        }
+    }
+
+    /// 是否为垃圾代币（用户举报优先于检测结果）
+    pub fn is_spam(&self, token: &TokenInfo) -> bool {
+        self.spam_reports
+            .get(&token_key(token))
+            .copied()
+            .unwrap_or(token.is_spam)
+    }
+
+    /// 代币是否隐藏（含自动隐藏的垃圾代币）
+    pub fn is_token_hidden(&self, token: &TokenInfo) -> bool {
+        let key = token_key(token);
+        self.is_hidden(&key)
+            || (self.auto_hide_spam && self.is_spam(token) && !self.shown.contains(&key))
+    }
+
+    /// 记录举报：举报为垃圾时同时隐藏，举报为正常时同时显示
+    pub fn record_spam_report(&mut self, token: &TokenInfo, spam: bool) {
+        let key = token_key(token);
+        self.spam_reports.insert(key.clone(), spam);
+        self.set_hidden(&key, spam);
+    }
+
+    /// 资产总额是否排除该代币
+    pub fn excluded_from_totals(&self, key: &str) -> bool {
+        !self.include_hidden_in_totals && self.is_hidden(key)
+    }
+
+    /// 合并另一份偏好中的收藏与隐藏（最近选择属于本设备，不合并），返回新增数量
+    pub fn merge(&mut self, other: &TokenPreferences) -> usize {
+        let mut added = 0;
+        for (wallet_id, keys) in &other.favorites {
+            let existing = self.favorites.entry(wallet_id.clone()).or_default();
+            for key in keys {
+                if !existing.contains(key) {
+                    existing.push(key.clone());
+                    added += 1;
+                }
+            }
+        }
+        for key in &other.hidden {
+            if !self.hidden.contains(key) {
+                self.hidden.push(key.clone());
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// 钱包永久删除时清除其收藏
+    pub fn remove_wallet(&mut self, wallet_id: &str) {
+        self.favorites.remove(wallet_id);
+    }
+}
+
+/// 市值排名（未收录的代币返回列表长度）
+pub fn market_cap_rank(symbol: &str) -> usize {
+    let symbol = symbol.to_uppercase();
+    MARKET_CAP_ORDER
+        .iter()
+        .position(|s| *s == symbol)
+        .unwrap_or(MARKET_CAP_ORDER.len())
+}
+
+/// 按合约地址或符号查找代币（`native` 表示链的原生代币；符号不区分大小写）
+pub fn find_token<'a>(tokens: &'a [TokenInfo], address_or_symbol: &str) -> Option<&'a TokenInfo> {
+    if address_or_symbol.eq_ignore_ascii_case("native") {
+        return tokens.iter().find(|t| t.is_native);
+    }
+    tokens
+        .iter()
+        .find(|t| t.address.eq_ignore_ascii_case(address_or_symbol))
+        .or_else(|| {
+            tokens
+                .iter()
+                .find(|t| t.symbol.eq_ignore_ascii_case(address_or_symbol))
+        })
+}
+
+/// 记录跳转到发送页后需要预选的代币
+pub fn set_pending_send_token(token: &TokenInfo) {
+    let _ = LocalStorage::set(PENDING_SEND_TOKEN_KEY, token);
+}
+
+/// 取出待预选的代币（只生效一次）
+pub fn take_pending_send_token() -> Option<TokenInfo> {
+    let token = LocalStorage::get::<TokenInfo>(PENDING_SEND_TOKEN_KEY).ok();
+    LocalStorage::delete(PENDING_SEND_TOKEN_KEY);
+    token
+}
+
+/// 记录跳转到提现页后需要预选的代币
+pub fn set_pending_sell_token(token: &TokenInfo) {
+    let _ = LocalStorage::set(PENDING_SELL_TOKEN_KEY, token);
+}
+
+/// 取出提现页待预选的代币（只生效一次）
+pub fn take_pending_sell_token() -> Option<TokenInfo> {
+    let token = LocalStorage::get::<TokenInfo>(PENDING_SELL_TOKEN_KEY).ok();
+    LocalStorage::delete(PENDING_SELL_TOKEN_KEY);
+    token
+}
+
+/// 是否匹配搜索词（符号、名称或合约地址，不区分大小写）
+pub fn matches_query(token: &TokenInfo, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || token.symbol.to_lowercase().contains(&query)
+        || token.name.to_lowercase().contains(&query)
+        || token.address.to_lowercase().contains(&query)
+}
+
+/// 代币排序所需的上下文
+pub struct TokenRanking<'a> {
+    /// 合约地址 → 持有数量
+    pub balances: &'a HashMap<String, f64>,
+    /// 代币符号（大写）→ USD 价格
+    pub prices: &'a HashMap<String, f64>,
+    pub is_favorite: &'a dyn Fn(&TokenInfo) -> bool,
+}
+
+impl TokenRanking<'_> {
+    fn held(&self, token: &TokenInfo) -> f64 {
+        self.balances
+            .get(&token.address)
+            .copied()
+            .filter(|b| b.is_finite() && *b > 0.0)
+            .unwrap_or(0.0)
+    }
+
+    fn held_usd(&self, token: &TokenInfo) -> f64 {
+        let price = self
+            .prices
+            .get(&token.symbol.to_uppercase())
+            .copied()
+            .unwrap_or(0.0);
+        self.held(token) * price
+    }
+
+    pub fn compare(&self, a: &TokenInfo, b: &TokenInfo) -> Ordering {
+        let by_f64 = |x: f64, y: f64| y.partial_cmp(&x).unwrap_or(Ordering::Equal);
+        (self.is_favorite)(b)
+            .cmp(&(self.is_favorite)(a))
+            .then_with(|| (self.held(b) > 0.0).cmp(&(self.held(a) > 0.0)))
+            .then_with(|| by_f64(self.held_usd(a), self.held_usd(b)))
+            .then_with(|| market_cap_rank(&a.symbol).cmp(&market_cap_rank(&b.symbol)))
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    }
+
+    pub fn sort(&self, tokens: &mut [TokenInfo]) {
+        tokens.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+/// 无图标代币的标识色（由合约地址确定，同一代币颜色固定）
+pub fn identicon_hues(seed: &str) -> (u16, u16) {
+    let hash = seed
+        .to_lowercase()
+        .bytes()
+        .fold(2166136261u32, |h, b| (h ^ b as u32).wrapping_mul(16777619));
+    let hue = (hash % 360) as u16;
+    (hue, (hue + 40 + (hash >> 16) as u16 % 80) % 360)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::address_detector::ChainType;
+
+    fn token(symbol: &str, address: &str) -> TokenInfo {
+        TokenInfo {
+            address: address.to_string(),
+            symbol: symbol.to_string(),
+            name: format!("{} Token", symbol),
+            decimals: 18,
+            chain: ChainType::Ethereum,
+            logo_url: None,
+            is_native: false,
+            is_spam: false,
+        }
+    }
+
+    #[test]
+    fn sorts_favorites_then_holdings_then_market_cap() {
+        let mut tokens = vec![
+            token("ZRX", "0x5"),
+            token("DAI", "0x4"),
+            token("USDT", "0x3"),
+            token("LINK", "0x2"),
+            token("ETH", "0x1"),
+        ];
+        let balances = HashMap::from([
+            ("0x4".to_string(), 500.0),
+            ("0x2".to_string(), 10.0),
+            ("0x1".to_string(), 0.0),
+        ]);
+        let prices = HashMap::from([("DAI".to_string(), 1.0), ("LINK".to_string(), 80.0)]);
+        let ranking = TokenRanking {
+            balances: &balances,
+            prices: &prices,
+            is_favorite: &|t: &TokenInfo| t.symbol == "ZRX",
+        };
+        ranking.sort(&mut tokens);
+        let order: Vec<&str> = tokens.iter().map(|t| t.symbol.as_str()).collect();
+        assert_eq!(order, ["ZRX", "LINK", "DAI", "ETH", "USDT"]);
+    }
+
+    #[test]
+    fn favorites_are_per_wallet_and_recent_is_bounded() {
+        let mut prefs = TokenPreferences::default();
+        prefs.toggle_favorite("w1", "ethereum:0xa");
+        assert!(prefs.is_favorite("w1", "ethereum:0xa"));
+        assert!(!prefs.is_favorite("w2", "ethereum:0xa"));
+        prefs.toggle_favorite("w1", "ethereum:0xa");
+        assert!(!prefs.is_favorite("w1", "ethereum:0xa"));
+
+        for i in 0..10 {
+            prefs.record_selection(&format!("ethereum:0x{}", i));
+        }
+        prefs.record_selection("ethereum:0x5");
+        assert_eq!(prefs.recent().len(), MAX_RECENT);
+        assert_eq!(prefs.recent()[0], "ethereum:0x5");
+        assert_eq!(
+            prefs
+                .recent()
+                .iter()
+                .filter(|k| *k == "ethereum:0x5")
+                .count(),
+            1
+        );
+
+        prefs.toggle_hidden("ethereum:0xb");
+        assert!(prefs.is_hidden("ethereum:0xb"));
+        prefs.toggle_hidden("ethereum:0xb");
+        assert!(!prefs.is_hidden("ethereum:0xb"));
+
+        assert_eq!(identicon_hues("0xAbC"), identicon_hues("0xabc"));
+        assert!(matches_query(&token("USDC", "0xA0b8"), "a0B8"));
+        assert!(!matches_query(&token("USDC", "0xA0b8"), "dai"));
+    }
+
+    #[test]
+    fn auto_hide_spam_respects_manual_show_and_reports() {
+        let mut spam = token("AIRDROP", "0xSpam");
+        spam.is_spam = true;
+        let usdt = token("USDT", "0xdAC1");
+        let mut prefs = TokenPreferences::default();
+        assert!(!prefs.is_token_hidden(&spam));
+
+        prefs.auto_hide_spam = true;
+        assert!(prefs.is_token_hidden(&spam));
+        assert!(!prefs.is_token_hidden(&usdt));
+
+        // 手动显示后不再自动隐藏
+        prefs.set_hidden(&token_key(&spam), false);
+        assert!(!prefs.is_token_hidden(&spam));
+
+        // 举报覆盖检测结果
+        prefs.record_spam_report(&usdt, true);
+        assert!(prefs.is_spam(&usdt));
+        assert!(prefs.is_token_hidden(&usdt));
+        assert!(prefs.excluded_from_totals(&token_key(&usdt)));
+        prefs.include_hidden_in_totals = true;
+        assert!(!prefs.excluded_from_totals(&token_key(&usdt)));
+
+        prefs.record_spam_report(&spam, false);
+        assert!(!prefs.is_spam(&spam));
+        assert!(!prefs.is_token_hidden(&spam));
+    }
+
+    #[test]
+    fn find_token_by_address_symbol_or_native() {
+        let mut native = token("ETH", "0xEeee");
+        native.is_native = true;
+        let tokens = vec![native, token("USDT", "0xdAC1"), token("USDC", "0xA0b8")];
+        assert_eq!(
+            find_token(&tokens, "0xdac1").map(|t| t.symbol.as_str()),
+            Some("USDT")
+        );
+        assert_eq!(
+            find_token(&tokens, "usdc").map(|t| t.symbol.as_str()),
+            Some("USDC")
+        );
+        assert_eq!(
+            find_token(&tokens, "native").map(|t| t.symbol.as_str()),
+            Some("ETH")
+        );
+        assert!(find_token(&tokens, "DAI").is_none());
+    }
+}
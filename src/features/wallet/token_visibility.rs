@@ -0,0 +1,156 @@
+//! Token Visibility - 代币显示管理
+//!
+//! 汇总当前钱包 EVM 账户上持有的代币（代币列表 + 自动检测，含疑似垃圾代币），
+//! 供代币管理页面批量隐藏/显示；举报结果提交给后端，同时记录在本地偏好中。
+
+use crate::features::wallet::state::Wallet;
+use crate::features::wallet::token_preferences::{token_key, TokenPreferences};
+use crate::services::address_detector::ChainType;
+use crate::services::token::{TokenInfo, TokenService};
+use crate::services::token_detection::TokenDetectionService;
+use crate::shared::state::AppState;
+use std::collections::HashMap;
+
+/// 持有的代币
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeldToken {
+    pub token: TokenInfo,
+    pub balance: f64,
+}
+
+/// 检测接口使用的链名称
+fn detection_chain(chain: ChainType) -> Option<&'static str> {
+    match chain {
+        ChainType::Ethereum => Some("eth"),
+        ChainType::BSC => Some("bsc"),
+        ChainType::Polygon => Some("polygon"),
+        _ => None,
+    }
+}
+
+/// 合并同一代币的余额（按 [`token_key`] 去重，保留较大值；代币列表收录的代币不视为垃圾代币）
+fn merge_held(held: &mut HashMap<String, HeldToken>, token: TokenInfo, balance: f64) {
+    if !balance.is_finite() || balance <= 0.0 || token.is_native {
+        return;
+    }
+    held.entry(token_key(&token))
+        .and_modify(|h| {
+            h.balance = h.balance.max(balance);
+            h.token.is_spam &= token.is_spam;
+        })
+        .or_insert(HeldToken { token, balance });
+}
+
+/// 按符号排序的持有代币列表
+fn sorted(held: HashMap<String, HeldToken>) -> Vec<HeldToken> {
+    let mut tokens: Vec<HeldToken> = held.into_values().collect();
+    tokens.sort_by(|a, b| {
+        a.token
+            .symbol
+            .to_uppercase()
+            .cmp(&b.token.symbol.to_uppercase())
+    });
+    tokens
+}
+
+/// 加载钱包持有的全部非原生代币（单条链查询失败时跳过该链）
+pub async fn load_held_tokens(app_state: AppState, wallet: &Wallet) -> Vec<HeldToken> {
+    let token_service = TokenService::new(app_state);
+    let detection = TokenDetectionService::new(app_state);
+    let mut held = HashMap::new();
+
+    for account in &wallet.accounts {
+        let Some(chain) = ChainType::from_str(&account.chain) else {
+            continue;
+        };
+        let Some(detect_chain) = detection_chain(chain) else {
+            continue;
+        };
+
+        if let Ok(list) = token_service.get_token_list(chain).await {
+            let addresses: Vec<String> = list
+                .iter()
+                .filter(|t| !t.is_native)
+                .map(|t| t.address.clone())
+                .collect();
+            if let Ok(balances) = token_service
+                .get_token_balances_batch(chain, &account.address, &addresses)
+                .await
+            {
+                for b in balances {
+                    merge_held(&mut held, b.token, b.balance_formatted);
+                }
+            }
+        }
+
+        if let Ok(detected) = detection
+            .detect_tokens_with_spam(detect_chain, &account.address)
+            .await
+        {
+            for metadata in detected {
+                let balance = metadata
+                    .balance
+                    .as_deref()
+                    .and_then(|b| b.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                if let Some(token) = metadata.to_token_info() {
+                    merge_held(&mut held, token, balance);
+                }
+            }
+        }
+    }
+
+    sorted(held)
+}
+
+/// 举报为垃圾代币（或取消误判），成功后更新本地偏好
+pub async fn report_spam(
+    app_state: AppState,
+    prefs: &mut TokenPreferences,
+    token: &TokenInfo,
+    spam: bool,
+) -> Result<(), String> {
+    let chain = detection_chain(token.chain).unwrap_or(token.chain.as_str());
+    TokenDetectionService::new(app_state)
+        .report_spam(chain, &token.address, spam)
+        .await
+        .map_err(|e| format!("举报失败: {}", e))?;
+    prefs.record_spam_report(token, spam);
+    prefs.save();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(symbol: &str, address: &str, spam: bool) -> TokenInfo {
+        TokenInfo {
+            address: address.to_string(),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            decimals: 18,
+            chain: ChainType::Ethereum,
+            logo_url: None,
+            is_native: false,
+            is_spam: spam,
+        }
+    }
+
+    #[test]
+    fn merges_duplicates_and_skips_empty_balances() {
+        let mut held = HashMap::new();
+        merge_held(&mut held, token("usdt", "0xdAC1", false), 10.0);
+        merge_held(&mut held, token("USDT", "0xdac1", true), 4.0);
+        merge_held(&mut held, token("DAI", "0x6B17", false), 0.0);
+        merge_held(&mut held, token("AIRDROP", "0xBad", true), 1.0);
+
+        let tokens = sorted(held);
+        let symbols: Vec<&str> = tokens.iter().map(|h| h.token.symbol.as_str()).collect();
+        assert_eq!(symbols, ["AIRDROP", "usdt"]);
+        assert_eq!(tokens[1].balance, 10.0);
+        assert!(tokens[0].token.is_spam);
+        // 代币列表收录的代币不因检测结果被标记
+        assert!(!tokens[1].token.is_spam);
+    }
+}
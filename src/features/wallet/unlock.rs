@@ -5,19 +5,7 @@ use dioxus::prelude::{ReadableExt, WritableExt};
 const WALLET_UNLOCK_TTL_SECS: u64 = 5 * 60;
 
 fn now_ts() -> u64 {
-    // WASM-compatible: Use js_sys::Date instead of SystemTime
-    #[cfg(target_arch = "wasm32")]
-    {
-        (js_sys::Date::now() / 1000.0) as u64
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0)
-    }
+    crate::shared::time::now_unix()
 }
 
 pub fn is_wallet_unlocked(app_state: &AppState, wallet_id: &str) -> bool {
@@ -0,0 +1,160 @@
+//! Wallet Vault Rekey - 本地钱包加密材料的批量重新加密
+//!
+//! 修改账户密码时，与账户密码相同的钱包密码一并更新：先在内存中为所有钱包生成新的
+//! 盐值与密文，再一次性写入 LocalStorage；任一写入失败或后续步骤失败时恢复全部旧值。
+//! 使用其他密码的钱包保持不变。
+
+use crate::crypto::encryption::{decrypt, encrypt, generate_salt};
+use crate::crypto::worker;
+use anyhow::{anyhow, Result};
+use gloo_storage::{LocalStorage, Storage};
+use zeroize::Zeroize;
+
+/// 一条待替换的 LocalStorage 记录
+#[derive(Debug, Clone, PartialEq)]
+struct VaultEntry {
+    key: String,
+    old_value: String,
+    new_value: String,
+}
+
+/// 重新加密计划（准备阶段不写存储）
+#[derive(Debug, Default)]
+pub struct VaultRekey {
+    entries: Vec<VaultEntry>,
+    /// 已重新加密的钱包
+    pub rekeyed: Vec<String>,
+    /// 使用其他密码、未改动的钱包
+    pub skipped: Vec<String>,
+}
+
+impl VaultRekey {
+    /// 为能用旧密码解密的钱包生成新密文
+    pub async fn prepare(
+        wallet_ids: &[String],
+        old_password: &str,
+        new_password: &str,
+    ) -> Result<Self> {
+        let mut plan = Self::default();
+        for wallet_id in wallet_ids {
+            let salt_key = format!("wallet_{}_salt", wallet_id);
+            let seed_key = format!("wallet_{}_seed", wallet_id);
+            let priv_key = format!("wallet_{}_private_key", wallet_id);
+
+            let Ok(old_salt) = LocalStorage::get::<String>(&salt_key) else {
+                // 本设备上没有该钱包的加密数据（如其他设备创建）
+                continue;
+            };
+            let (data_key, old_data) = match LocalStorage::get::<String>(&seed_key) {
+                Ok(seed) => (seed_key, seed),
+                Err(_) => match LocalStorage::get::<String>(&priv_key) {
+                    Ok(private_key) => (priv_key, private_key),
+                    Err(_) => continue,
+                },
+            };
+
+            let old_key = worker::derive_key(old_password, &hex::decode(&old_salt)?).await?;
+            let Ok(mut secret) = decrypt(&old_key, &hex::decode(&old_data)?) else {
+                plan.skipped.push(wallet_id.clone());
+                continue;
+            };
+
+            let new_salt = generate_salt();
+            let new_key = worker::derive_key(new_password, &new_salt).await?;
+            let new_data = encrypt(&new_key, &secret);
+            secret.zeroize();
+            let new_data = new_data?;
+
+            plan.entries.push(VaultEntry {
+                key: salt_key,
+                old_value: old_salt,
+                new_value: hex::encode(new_salt),
+            });
+            plan.entries.push(VaultEntry {
+                key: data_key,
+                old_value: old_data,
+                new_value: hex::encode(new_data),
+            });
+            plan.rekeyed.push(wallet_id.clone());
+        }
+        Ok(plan)
+    }
+
+    /// 写入全部新密文；中途失败时恢复已写入的记录
+    pub fn commit(&self) -> Result<()> {
+        apply_with_rollback(&self.entries, |key, value| {
+            LocalStorage::set(key, value).map_err(|e| anyhow!("写入 {} 失败: {}", key, e))
+        })
+    }
+
+    /// 恢复全部旧密文（提交后后续步骤失败时调用）
+    pub fn rollback(&self) {
+        for entry in &self.entries {
+            let _ = LocalStorage::set(&entry.key, &entry.old_value);
+        }
+    }
+}
+
+/// 依次写入新值；任一写入失败时把已写入的记录恢复为旧值
+fn apply_with_rollback<W>(entries: &[VaultEntry], mut write: W) -> Result<()>
+where
+    W: FnMut(&str, &str) -> Result<()>,
+{
+    for (index, entry) in entries.iter().enumerate() {
+        if let Err(e) = write(&entry.key, &entry.new_value) {
+            for written in entries[..index].iter().rev() {
+                let _ = write(&written.key, &written.old_value);
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn entry(key: &str, old_value: &str, new_value: &str) -> VaultEntry {
+        VaultEntry {
+            key: key.to_string(),
+            old_value: old_value.to_string(),
+            new_value: new_value.to_string(),
+        }
+    }
+
+    #[test]
+    fn failed_write_restores_previous_entries() {
+        let entries = vec![
+            entry("wallet_a_salt", "old-salt", "new-salt"),
+            entry("wallet_a_seed", "old-seed", "new-seed"),
+            entry("wallet_b_salt", "old-b", "new-b"),
+        ];
+        let mut store: HashMap<String, String> = entries
+            .iter()
+            .map(|e| (e.key.clone(), e.old_value.clone()))
+            .collect();
+
+        let result = apply_with_rollback(&entries, |key, value| {
+            if key == "wallet_b_salt" && value == "new-b" {
+                return Err(anyhow!("quota exceeded"));
+            }
+            store.insert(key.to_string(), value.to_string());
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert_eq!(store["wallet_a_salt"], "old-salt");
+        assert_eq!(store["wallet_a_seed"], "old-seed");
+        assert_eq!(store["wallet_b_salt"], "old-b");
+
+        let mut store = HashMap::new();
+        apply_with_rollback(&entries, |key, value| {
+            store.insert(key.to_string(), value.to_string());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(store["wallet_a_seed"], "new-seed");
+        assert_eq!(store["wallet_b_salt"], "new-b");
+    }
+}
@@ -143,6 +143,19 @@ static TRANSLATIONS: LazyLock<TranslationDict> = LazyLock::new(|| {
         "교환",
     );
 
+    add_translation(
+        &mut dict,
+        "nav.dapps",
+        "zh",
+        "dApp",
+        "en",
+        "dApps",
+        "ja",
+        "dApp",
+        "ko",
+        "디앱",
+    );
+
     // ============ 页面标题 ============
     add_translation(
         &mut dict,
@@ -1850,25 +1863,25 @@ static TRANSLATIONS: LazyLock<TranslationDict> = LazyLock::new(|| {
         &mut dict,
         "buy.step1_select",
         "zh",
-        "选择稳定币",
+        "输入金额",
         "en",
-        "Select Stablecoin",
+        "Amount",
         "ja",
-        "選択",
+        "金額",
         "ko",
-        "선택",
+        "금액",
     );
     add_translation(
         &mut dict,
         "buy.step2_amount",
         "zh",
-        "输入金额",
+        "支付方式",
         "en",
-        "Enter Amount",
+        "Method",
         "ja",
-        "金額",
+        "支払方法",
         "ko",
-        "금액",
+        "결제 수단",
     );
     add_translation(
         &mut dict,
@@ -1876,7 +1889,7 @@ static TRANSLATIONS: LazyLock<TranslationDict> = LazyLock::new(|| {
         "zh",
         "查看报价",
         "en",
-        "View Quote",
+        "Quote",
         "ja",
         "見積",
         "ko",
@@ -1886,13 +1899,13 @@ static TRANSLATIONS: LazyLock<TranslationDict> = LazyLock::new(|| {
         &mut dict,
         "buy.step4_confirm",
         "zh",
-        "确认购买",
+        "确认支付",
         "en",
-        "Confirm",
+        "Pay",
         "ja",
-        "確認",
+        "支払",
         "ko",
-        "확인",
+        "결제",
     );
     add_translation(
         &mut dict,
@@ -2051,6 +2064,236 @@ static TRANSLATIONS: LazyLock<TranslationDict> = LazyLock::new(|| {
         "USDT 구매",
     );
 
+    // ============ Payment Methods (支付方式) ============
+    add_translation(
+        &mut dict,
+        "payment_method.credit_card",
+        "zh",
+        "信用卡/借记卡",
+        "en",
+        "Credit/Debit Card",
+        "ja",
+        "クレジット/デビットカード",
+        "ko",
+        "신용/직불 카드",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.credit_card.desc",
+        "zh",
+        "支持 Visa/Mastercard",
+        "en",
+        "Visa/Mastercard",
+        "ja",
+        "Visa/Mastercard対応",
+        "ko",
+        "Visa/Mastercard 지원",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.bank_card",
+        "zh",
+        "银行卡/借记卡",
+        "en",
+        "Bank Card/Debit Card",
+        "ja",
+        "銀行カード/デビットカード",
+        "ko",
+        "은행 카드/직불 카드",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.bank_card.desc",
+        "zh",
+        "全球支持",
+        "en",
+        "Supported worldwide",
+        "ja",
+        "全世界対応",
+        "ko",
+        "전 세계 지원",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.paypal",
+        "zh",
+        "PayPal",
+        "en",
+        "PayPal",
+        "ja",
+        "PayPal",
+        "ko",
+        "PayPal",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.paypal.desc",
+        "zh",
+        "全球支付",
+        "en",
+        "Global payments",
+        "ja",
+        "グローバル決済",
+        "ko",
+        "글로벌 결제",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.apple_pay",
+        "zh",
+        "Apple Pay",
+        "en",
+        "Apple Pay",
+        "ja",
+        "Apple Pay",
+        "ko",
+        "Apple Pay",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.apple_pay.desc",
+        "zh",
+        "iOS 设备",
+        "en",
+        "iOS devices",
+        "ja",
+        "iOSデバイス",
+        "ko",
+        "iOS 기기",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.google_pay",
+        "zh",
+        "Google Pay",
+        "en",
+        "Google Pay",
+        "ja",
+        "Google Pay",
+        "ko",
+        "Google Pay",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.google_pay.desc",
+        "zh",
+        "Android 设备",
+        "en",
+        "Android devices",
+        "ja",
+        "Androidデバイス",
+        "ko",
+        "안드로이드 기기",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.alipay",
+        "zh",
+        "支付宝",
+        "en",
+        "Alipay",
+        "ja",
+        "アリペイ",
+        "ko",
+        "알리페이",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.alipay.desc",
+        "zh",
+        "中国地区",
+        "en",
+        "China region",
+        "ja",
+        "中国地域",
+        "ko",
+        "중국 지역",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.wechat_pay",
+        "zh",
+        "微信支付",
+        "en",
+        "WeChat Pay",
+        "ja",
+        "ウィーチャットペイ",
+        "ko",
+        "위챗페이",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.wechat_pay.desc",
+        "zh",
+        "中国地区",
+        "en",
+        "China region",
+        "ja",
+        "中国地域",
+        "ko",
+        "중국 지역",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.instant",
+        "zh",
+        "即时到账",
+        "en",
+        "Instant",
+        "ja",
+        "即時",
+        "ko",
+        "즉시",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.business_days",
+        "zh",
+        "1-3 工作日",
+        "en",
+        "1-3 business days",
+        "ja",
+        "1〜3営業日",
+        "ko",
+        "1-3 영업일",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.recommended",
+        "zh",
+        "推荐",
+        "en",
+        "Recommended",
+        "ja",
+        "おすすめ",
+        "ko",
+        "추천",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.local_group",
+        "zh",
+        "您所在地区常用",
+        "en",
+        "Popular in your region",
+        "ja",
+        "お住まいの地域で人気",
+        "ko",
+        "내 지역 인기",
+    );
+    add_translation(
+        &mut dict,
+        "payment_method.global_group",
+        "zh",
+        "全球通用",
+        "en",
+        "Available worldwide",
+        "ja",
+        "全世界で利用可能",
+        "ko",
+        "전 세계 사용 가능",
+    );
+
     // ============ Withdraw (提现) ============
     add_translation(
         &mut dict,
@@ -2113,6 +2356,1046 @@ static TRANSLATIONS: LazyLock<TranslationDict> = LazyLock::new(|| {
         "출금 수량",
     );
 
+    // ============ 新手引导 ============
+    add_translation(
+        &mut dict,
+        "tour.skip",
+        "zh",
+        "跳过",
+        "en",
+        "Skip",
+        "ja",
+        "スキップ",
+        "ko",
+        "건너뛰기",
+    );
+    add_translation(
+        &mut dict,
+        "tour.prev",
+        "zh",
+        "上一步",
+        "en",
+        "Back",
+        "ja",
+        "戻る",
+        "ko",
+        "이전",
+    );
+    add_translation(
+        &mut dict,
+        "tour.next",
+        "zh",
+        "下一步",
+        "en",
+        "Next",
+        "ja",
+        "次へ",
+        "ko",
+        "다음",
+    );
+    add_translation(
+        &mut dict,
+        "tour.done",
+        "zh",
+        "完成",
+        "en",
+        "Done",
+        "ja",
+        "完了",
+        "ko",
+        "완료",
+    );
+    add_translation(
+        &mut dict,
+        "tour.replay_title",
+        "zh",
+        "新手引导",
+        "en",
+        "Guided Tours",
+        "ja",
+        "ガイドツアー",
+        "ko",
+        "가이드 투어",
+    );
+    add_translation(
+        &mut dict,
+        "tour.replay_hint",
+        "zh",
+        "重新播放各页面的功能引导",
+        "en",
+        "Replay the feature tour for each page",
+        "ja",
+        "各ページの機能ガイドをもう一度再生します",
+        "ko",
+        "각 페이지의 기능 안내를 다시 재생합니다",
+    );
+    add_translation(
+        &mut dict,
+        "tour.replay",
+        "zh",
+        "重新播放",
+        "en",
+        "Replay",
+        "ja",
+        "再生",
+        "ko",
+        "다시 보기",
+    );
+    add_translation(
+        &mut dict,
+        "tour.completed",
+        "zh",
+        "已看过",
+        "en",
+        "Seen",
+        "ja",
+        "視聴済み",
+        "ko",
+        "확인함",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.name",
+        "zh",
+        "交易页引导",
+        "en",
+        "Trade page tour",
+        "ja",
+        "取引ページのガイド",
+        "ko",
+        "거래 페이지 안내",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.welcome.title",
+        "zh",
+        "欢迎使用交换功能",
+        "en",
+        "Welcome to Trade",
+        "ja",
+        "取引機能へようこそ",
+        "ko",
+        "거래 기능에 오신 것을 환영합니다",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.welcome.body",
+        "zh",
+        "在这里您可以交换代币、购买稳定币、提现到法币。让我们开始探索吧！",
+        "en",
+        "Here you can swap tokens, buy stablecoins and withdraw to fiat. Let's take a look!",
+        "ja",
+        "ここではトークンの交換、ステーブルコインの購入、法定通貨への出金ができます。さっそく見てみましょう！",
+        "ko",
+        "여기에서 토큰 교환, 스테이블코인 구매, 법정화폐 출금을 할 수 있습니다. 함께 살펴볼까요!",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.swap.title",
+        "zh",
+        "代币交换",
+        "en",
+        "Token Swap",
+        "ja",
+        "トークン交換",
+        "ko",
+        "토큰 교환",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.swap.body",
+        "zh",
+        "选择要交换的代币和数量，系统会自动为您获取最佳报价。支持所有主流代币和稳定币。",
+        "en",
+        "Pick the tokens and amount to swap and we fetch the best quote for you. All major tokens and stablecoins are supported.",
+        "ja",
+        "交換するトークンと数量を選ぶと、最適な見積もりを自動で取得します。主要なトークンとステーブルコインに対応しています。",
+        "ko",
+        "교환할 토큰과 수량을 선택하면 최적의 견적을 자동으로 가져옵니다. 주요 토큰과 스테이블코인을 모두 지원합니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.buy.title",
+        "zh",
+        "购买稳定币",
+        "en",
+        "Buy Stablecoins",
+        "ja",
+        "ステーブルコインを購入",
+        "ko",
+        "스테이블코인 구매",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.buy.body",
+        "zh",
+        "使用信用卡、银行卡或PayPal购买USDT/USDC稳定币。支持多种支付方式，即时到账。",
+        "en",
+        "Buy USDT/USDC with a credit card, bank card or PayPal. Multiple payment methods, credited instantly.",
+        "ja",
+        "クレジットカード、銀行カード、PayPalでUSDT/USDCを購入できます。複数の支払い方法に対応し、即時に反映されます。",
+        "ko",
+        "신용카드, 은행 카드 또는 PayPal로 USDT/USDC를 구매하세요. 다양한 결제 수단을 지원하며 즉시 입금됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.withdraw.title",
+        "zh",
+        "提现到法币",
+        "en",
+        "Withdraw to Fiat",
+        "ja",
+        "法定通貨へ出金",
+        "ko",
+        "법정화폐로 출금",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.withdraw.body",
+        "zh",
+        "将您的代币提现到银行卡、银行账户或PayPal。系统会自动执行代币→稳定币→法币的两步流程。",
+        "en",
+        "Withdraw tokens to a bank card, bank account or PayPal. We run the token → stablecoin → fiat steps for you.",
+        "ja",
+        "トークンを銀行カード、銀行口座、PayPalへ出金できます。トークン→ステーブルコイン→法定通貨の手順は自動で実行されます。",
+        "ko",
+        "토큰을 은행 카드, 은행 계좌 또는 PayPal로 출금하세요. 토큰 → 스테이블코인 → 법정화폐 단계는 자동으로 진행됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.history.title",
+        "zh",
+        "交易历史",
+        "en",
+        "History",
+        "ja",
+        "取引履歴",
+        "ko",
+        "거래 내역",
+    );
+    add_translation(
+        &mut dict,
+        "tour.swap.history.body",
+        "zh",
+        "查看所有交易记录，包括交换、充值和提现。支持按类型和状态筛选。",
+        "en",
+        "See every swap, deposit and withdrawal, filterable by type and status.",
+        "ja",
+        "交換・入金・出金のすべての記録を確認できます。種類やステータスで絞り込めます。",
+        "ko",
+        "교환, 입금, 출금 기록을 모두 확인할 수 있으며 유형과 상태로 필터링할 수 있습니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.name",
+        "zh",
+        "仪表盘引导",
+        "en",
+        "Dashboard tour",
+        "ja",
+        "ダッシュボードのガイド",
+        "ko",
+        "대시보드 안내",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.balance.title",
+        "zh",
+        "资产概览",
+        "en",
+        "Portfolio Overview",
+        "ja",
+        "資産概要",
+        "ko",
+        "자산 개요",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.balance.body",
+        "zh",
+        "这里汇总了当前钱包在各条链上的余额与估值，每30秒自动刷新。",
+        "en",
+        "Your balances and value across every chain for the selected wallet, refreshed every 30 seconds.",
+        "ja",
+        "選択中のウォレットの各チェーン残高と評価額をまとめて表示し、30秒ごとに更新します。",
+        "ko",
+        "선택한 지갑의 체인별 잔액과 평가액을 모아 보여주며 30초마다 새로고침됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.actions.title",
+        "zh",
+        "快速操作",
+        "en",
+        "Quick Actions",
+        "ja",
+        "クイックアクション",
+        "ko",
+        "빠른 작업",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.actions.body",
+        "zh",
+        "一键进入交换、提现和交易记录，也可以手动刷新余额。",
+        "en",
+        "Jump straight to swapping, withdrawing or your records, or refresh balances manually.",
+        "ja",
+        "交換、出金、取引記録へすぐに移動でき、残高を手動で更新することもできます。",
+        "ko",
+        "교환, 출금, 거래 기록으로 바로 이동하거나 잔액을 직접 새로고침할 수 있습니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.wallets.title",
+        "zh",
+        "我的钱包",
+        "en",
+        "My Wallets",
+        "ja",
+        "マイウォレット",
+        "ko",
+        "내 지갑",
+    );
+    add_translation(
+        &mut dict,
+        "tour.dashboard.wallets.body",
+        "zh",
+        "管理多个钱包，点击卡片即可切换当前使用的钱包。",
+        "en",
+        "Manage multiple wallets; click a card to switch the active one.",
+        "ja",
+        "複数のウォレットを管理できます。カードをクリックすると使用中のウォレットを切り替えます。",
+        "ko",
+        "여러 지갑을 관리할 수 있으며 카드를 클릭하면 사용 중인 지갑이 전환됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.name",
+        "zh",
+        "发送页引导",
+        "en",
+        "Send page tour",
+        "ja",
+        "送金ページのガイド",
+        "ko",
+        "송금 페이지 안내",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.token.title",
+        "zh",
+        "选择代币",
+        "en",
+        "Choose a Token",
+        "ja",
+        "トークンを選択",
+        "ko",
+        "토큰 선택",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.token.body",
+        "zh",
+        "列表只显示当前钱包中有余额的代币。",
+        "en",
+        "Only tokens you hold in this wallet are listed.",
+        "ja",
+        "このウォレットで残高のあるトークンのみ表示されます。",
+        "ko",
+        "이 지갑에 잔액이 있는 토큰만 표시됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.recipient.title",
+        "zh",
+        "接收地址",
+        "en",
+        "Recipient Address",
+        "ja",
+        "受取アドレス",
+        "ko",
+        "수신 주소",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.recipient.body",
+        "zh",
+        "粘贴接收地址，系统会自动识别所属网络并校验格式。",
+        "en",
+        "Paste the recipient address; we detect its network and check the format automatically.",
+        "ja",
+        "受取アドレスを貼り付けると、ネットワークを自動判別して形式をチェックします。",
+        "ko",
+        "수신 주소를 붙여넣으면 네트워크를 자동으로 감지하고 형식을 검사합니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.amount.title",
+        "zh",
+        "发送金额",
+        "en",
+        "Amount",
+        "ja",
+        "送金額",
+        "ko",
+        "송금 금액",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.amount.body",
+        "zh",
+        "输入金额后会实时估算网络手续费与平台服务费。",
+        "en",
+        "Enter an amount to see live network and platform fee estimates.",
+        "ja",
+        "金額を入力すると、ネットワーク手数料とプラットフォーム手数料をリアルタイムで見積もります。",
+        "ko",
+        "금액을 입력하면 네트워크 수수료와 플랫폼 수수료를 실시간으로 추정합니다.",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.submit.title",
+        "zh",
+        "确认发送",
+        "en",
+        "Confirm and Send",
+        "ja",
+        "確認して送金",
+        "ko",
+        "확인 후 송금",
+    );
+    add_translation(
+        &mut dict,
+        "tour.send.submit.body",
+        "zh",
+        "发送前会再次展示交易详情，确认无误后签名广播。",
+        "en",
+        "You'll review the full details once more before signing and broadcasting.",
+        "ja",
+        "送金前に取引内容をもう一度表示し、確認後に署名してブロードキャストします。",
+        "ko",
+        "송금 전에 거래 내용을 다시 보여주며, 확인 후 서명하여 전송합니다.",
+    );
+
+    // ============ 空状态 ============
+    add_translation(
+        &mut dict,
+        "empty.load_failed.title",
+        "zh",
+        "加载失败",
+        "en",
+        "Failed to load",
+        "ja",
+        "読み込みに失敗しました",
+        "ko",
+        "불러오지 못했습니다",
+    );
+    add_translation(
+        &mut dict,
+        "empty.load_failed.retry",
+        "zh",
+        "重试",
+        "en",
+        "Retry",
+        "ja",
+        "再試行",
+        "ko",
+        "다시 시도",
+    );
+    add_translation(
+        &mut dict,
+        "empty.wallets.title",
+        "zh",
+        "还没有钱包",
+        "en",
+        "No wallets yet",
+        "ja",
+        "ウォレットがまだありません",
+        "ko",
+        "아직 지갑이 없습니다",
+    );
+    add_translation(
+        &mut dict,
+        "empty.wallets.description",
+        "zh",
+        "创建新钱包，或使用助记词/私钥导入已有钱包。",
+        "en",
+        "Create a new wallet, or import an existing one with a recovery phrase or private key.",
+        "ja",
+        "新しいウォレットを作成するか、リカバリーフレーズ/秘密鍵で既存のウォレットをインポートしてください。",
+        "ko",
+        "새 지갑을 만들거나 복구 문구/개인 키로 기존 지갑을 가져오세요.",
+    );
+    add_translation(
+        &mut dict,
+        "empty.wallets.cta",
+        "zh",
+        "创建钱包",
+        "en",
+        "Create wallet",
+        "ja",
+        "ウォレットを作成",
+        "ko",
+        "지갑 만들기",
+    );
+    add_translation(
+        &mut dict,
+        "empty.wallets.secondary",
+        "zh",
+        "导入/恢复钱包",
+        "en",
+        "Import / restore",
+        "ja",
+        "インポート/復元",
+        "ko",
+        "가져오기/복원",
+    );
+    add_translation(
+        &mut dict,
+        "empty.balances.title",
+        "zh",
+        "为钱包充值，开始使用",
+        "en",
+        "Fund your wallet to get started",
+        "ja",
+        "ウォレットに入金して始めましょう",
+        "ko",
+        "지갑에 입금하고 시작하세요",
+    );
+    add_translation(
+        &mut dict,
+        "empty.balances.description",
+        "zh",
+        "钱包还没有资产。跟随三步引导完成首次充值，之后即可兑换、转账。",
+        "en",
+        "This wallet has no assets yet. Follow the three-step guide to make your first deposit, then swap and send.",
+        "ja",
+        "このウォレットにはまだ資産がありません。3ステップのガイドで初回入金を完了すると、スワップや送金ができます。",
+        "ko",
+        "이 지갑에는 아직 자산이 없습니다. 3단계 안내에 따라 첫 입금을 완료하면 스왑과 송금을 할 수 있습니다.",
+    );
+    add_translation(
+        &mut dict,
+        "empty.balances.cta",
+        "zh",
+        "为钱包充值",
+        "en",
+        "Fund your wallet",
+        "ja",
+        "ウォレットに入金",
+        "ko",
+        "지갑에 입금",
+    );
+    add_translation(
+        &mut dict,
+        "empty.balances.dismiss",
+        "zh",
+        "暂不需要",
+        "en",
+        "Not now",
+        "ja",
+        "後で",
+        "ko",
+        "나중에",
+    );
+    add_translation(
+        &mut dict,
+        "empty.balances.failed",
+        "zh",
+        "余额加载失败，请检查网络后重试。",
+        "en",
+        "Balances failed to load. Check your connection and try again.",
+        "ja",
+        "残高を読み込めませんでした。接続を確認して再試行してください。",
+        "ko",
+        "잔액을 불러오지 못했습니다. 연결을 확인한 후 다시 시도하세요.",
+    );
+    add_translation(
+        &mut dict,
+        "empty.history.title",
+        "zh",
+        "暂无交易记录",
+        "en",
+        "No transactions yet",
+        "ja",
+        "取引履歴はまだありません",
+        "ko",
+        "거래 내역이 없습니다",
+    );
+    add_translation(
+        &mut dict,
+        "empty.history.description",
+        "zh",
+        "完成第一笔兑换后，交易记录会显示在这里。",
+        "en",
+        "Your transactions will appear here after your first swap.",
+        "ja",
+        "最初のスワップを行うと、ここに取引履歴が表示されます。",
+        "ko",
+        "첫 스왑을 완료하면 거래 내역이 여기에 표시됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "empty.history.cta",
+        "zh",
+        "完成第一笔兑换",
+        "en",
+        "Make your first swap",
+        "ja",
+        "最初のスワップを行う",
+        "ko",
+        "첫 스왑하기",
+    );
+    add_translation(
+        &mut dict,
+        "empty.history.failed",
+        "zh",
+        "交易记录加载失败",
+        "en",
+        "Transactions failed to load",
+        "ja",
+        "取引履歴を読み込めませんでした",
+        "ko",
+        "거래 내역을 불러오지 못했습니다",
+    );
+    add_translation(
+        &mut dict,
+        "empty.limit_orders.title",
+        "zh",
+        "暂无限价单",
+        "en",
+        "No limit orders",
+        "ja",
+        "指値注文はありません",
+        "ko",
+        "지정가 주문이 없습니다",
+    );
+    add_translation(
+        &mut dict,
+        "empty.limit_orders.description",
+        "zh",
+        "设置目标价格，达到时自动成交。",
+        "en",
+        "Set a target price and the order fills automatically when it's reached.",
+        "ja",
+        "目標価格を設定すると、到達時に自動で約定します。",
+        "ko",
+        "목표 가격을 설정하면 도달 시 자동으로 체결됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "empty.limit_orders.cta",
+        "zh",
+        "创建限价单",
+        "en",
+        "Create a limit order",
+        "ja",
+        "指値注文を作成",
+        "ko",
+        "지정가 주문 만들기",
+    );
+    add_translation(
+        &mut dict,
+        "empty.orders.title",
+        "zh",
+        "暂无订单",
+        "en",
+        "No orders yet",
+        "ja",
+        "注文はまだありません",
+        "ko",
+        "주문이 없습니다",
+    );
+    add_translation(
+        &mut dict,
+        "empty.orders.description",
+        "zh",
+        "购买或出售稳定币后，订单会显示在这里。",
+        "en",
+        "Orders appear here after you buy or sell stablecoins.",
+        "ja",
+        "ステーブルコインを購入・売却すると、ここに注文が表示されます。",
+        "ko",
+        "스테이블코인을 구매하거나 판매하면 주문이 여기에 표시됩니다.",
+    );
+    add_translation(
+        &mut dict,
+        "empty.orders.cta",
+        "zh",
+        "购买稳定币",
+        "en",
+        "Buy stablecoins",
+        "ja",
+        "ステーブルコインを購入",
+        "ko",
+        "스테이블코인 구매",
+    );
+
+    // ============ 分步表单 ============
+    add_translation(
+        &mut dict,
+        "wizard.back",
+        "zh",
+        "上一步",
+        "en",
+        "Back",
+        "ja",
+        "戻る",
+        "ko",
+        "이전",
+    );
+    add_translation(
+        &mut dict,
+        "wizard.next",
+        "zh",
+        "下一步",
+        "en",
+        "Next",
+        "ja",
+        "次へ",
+        "ko",
+        "다음",
+    );
+    add_translation(
+        &mut dict,
+        "wizard.edit",
+        "zh",
+        "修改",
+        "en",
+        "Edit",
+        "ja",
+        "編集",
+        "ko",
+        "수정",
+    );
+    add_translation(
+        &mut dict,
+        "wizard.review_title",
+        "zh",
+        "请核对以下信息",
+        "en",
+        "Review your details",
+        "ja",
+        "内容をご確認ください",
+        "ko",
+        "내용을 확인하세요",
+    );
+    add_translation(
+        &mut dict,
+        "wizard.quote_pending",
+        "zh",
+        "正在获取报价，请稍候",
+        "en",
+        "Fetching a quote…",
+        "ja",
+        "見積を取得中…",
+        "ko",
+        "견적을 가져오는 중…",
+    );
+    add_translation(
+        &mut dict,
+        "wizard.refresh_quote",
+        "zh",
+        "重新获取报价",
+        "en",
+        "Refresh quote",
+        "ja",
+        "見積を再取得",
+        "ko",
+        "견적 새로고침",
+    );
+    add_translation(
+        &mut dict,
+        "buy.wizard.amount_required",
+        "zh",
+        "请输入有效的购买金额",
+        "en",
+        "Enter a valid amount",
+        "ja",
+        "有効な金額を入力してください",
+        "ko",
+        "유효한 금액을 입력하세요",
+    );
+    add_translation(
+        &mut dict,
+        "buy.wizard.method_unavailable",
+        "zh",
+        "当前地区不支持该支付方式",
+        "en",
+        "This method is unavailable in your region",
+        "ja",
+        "お住まいの地域では利用できません",
+        "ko",
+        "해당 지역에서 사용할 수 없는 결제 수단입니다",
+    );
+    add_translation(
+        &mut dict,
+        "withdraw.wizard.token_required",
+        "zh",
+        "请选择代币并输入有效金额",
+        "en",
+        "Select a token and enter a valid amount",
+        "ja",
+        "トークンと有効な金額を入力してください",
+        "ko",
+        "토큰과 유효한 금액을 입력하세요",
+    );
+
+    // ============ 助记词备份 ============
+    add_translation(
+        &mut dict,
+        "backup.guide.title",
+        "zh",
+        "备份步骤",
+        "en",
+        "Backup steps",
+        "ja",
+        "バックアップ手順",
+        "ko",
+        "백업 단계",
+    );
+    add_translation(
+        &mut dict,
+        "backup.guide.step1",
+        "zh",
+        "准备纸笔，在离线环境中按编号顺序抄写全部单词",
+        "en",
+        "Offline, with pen and paper, write down every word in numbered order",
+        "ja",
+        "オフラインで紙とペンを使い、すべての単語を番号順に書き写してください",
+        "ko",
+        "오프라인에서 종이와 펜으로 모든 단어를 번호 순서대로 적으세요",
+    );
+    add_translation(
+        &mut dict,
+        "backup.guide.step2",
+        "zh",
+        "逐个核对拼写和顺序，抄错一个单词就无法恢复钱包",
+        "en",
+        "Check the spelling and order of each word — one mistake makes the wallet unrecoverable",
+        "ja",
+        "各単語のつづりと順番を確認してください。1 語でも誤るとウォレットを復元できません",
+        "ko",
+        "각 단어의 철자와 순서를 확인하세요. 한 단어라도 틀리면 지갑을 복구할 수 없습니다",
+    );
+    add_translation(
+        &mut dict,
+        "backup.guide.step3",
+        "zh",
+        "不要截图、拍照，也不要粘贴到聊天软件或云笔记",
+        "en",
+        "Never screenshot or photograph the words, or paste them into chat apps or cloud notes",
+        "ja",
+        "スクリーンショットや写真を撮ったり、チャットやクラウドメモに貼り付けたりしないでください",
+        "ko",
+        "스크린샷이나 사진을 찍거나 채팅 앱·클라우드 메모에 붙여넣지 마세요",
+    );
+    add_translation(
+        &mut dict,
+        "backup.guide.step4",
+        "zh",
+        "建议再制作一份金属助记词板：耐火、防水，比纸张保存得更久",
+        "en",
+        "We recommend also stamping the words onto a metal backup plate — it survives fire and water and outlasts paper",
+        "ja",
+        "金属製のバックアッププレートにも刻印することをおすすめします。耐火・防水で紙より長持ちします",
+        "ko",
+        "금속 백업 플레이트에도 새겨 두는 것을 권장합니다. 불과 물에 강하고 종이보다 오래 보관됩니다",
+    );
+    add_translation(
+        &mut dict,
+        "backup.guide.step5",
+        "zh",
+        "把纸质和金属备份分别存放在两个安全的地方，不要告诉任何人",
+        "en",
+        "Keep the paper and metal copies in two separate safe places and never share them",
+        "ja",
+        "紙と金属のバックアップは別々の安全な場所に保管し、誰にも教えないでください",
+        "ko",
+        "종이와 금속 백업을 서로 다른 안전한 장소에 보관하고 누구에게도 알려주지 마세요",
+    );
+    add_translation(
+        &mut dict,
+        "backup.print",
+        "zh",
+        "🖨️ 打印备份单",
+        "en",
+        "🖨️ Print backup sheet",
+        "ja",
+        "🖨️ バックアップシートを印刷",
+        "ko",
+        "🖨️ 백업 시트 인쇄",
+    );
+    add_translation(
+        &mut dict,
+        "backup.print_blocked",
+        "zh",
+        "检测到屏幕共享，已禁止打印助记词",
+        "en",
+        "Screen sharing detected — printing the recovery phrase is disabled",
+        "ja",
+        "画面共有が検出されたため、リカバリーフレーズの印刷はできません",
+        "ko",
+        "화면 공유가 감지되어 복구 문구를 인쇄할 수 없습니다",
+    );
+    add_translation(
+        &mut dict,
+        "backup.sheet.title",
+        "zh",
+        "钱包助记词备份",
+        "en",
+        "Wallet Recovery Phrase Backup",
+        "ja",
+        "ウォレット リカバリーフレーズ バックアップ",
+        "ko",
+        "지갑 복구 문구 백업",
+    );
+    add_translation(
+        &mut dict,
+        "backup.sheet.wallet",
+        "zh",
+        "钱包",
+        "en",
+        "Wallet",
+        "ja",
+        "ウォレット",
+        "ko",
+        "지갑",
+    );
+    add_translation(
+        &mut dict,
+        "backup.sheet.date",
+        "zh",
+        "日期",
+        "en",
+        "Date",
+        "ja",
+        "日付",
+        "ko",
+        "날짜",
+    );
+    add_translation(
+        &mut dict,
+        "backup.remind_later",
+        "zh",
+        "稍后提醒我验证",
+        "en",
+        "Remind me later",
+        "ja",
+        "後で確認する",
+        "ko",
+        "나중에 확인하기",
+    );
+    add_translation(
+        &mut dict,
+        "backup.remind_later_hint",
+        "zh",
+        "钱包会先创建完成；7 天内未验证助记词，转出资产前需要先完成验证",
+        "en",
+        "Your wallet is created now. If you don't verify within 7 days, you'll have to verify before any outgoing transaction",
+        "ja",
+        "ウォレットは今すぐ作成されます。7 日以内に確認しない場合、送金前に確認が必要になります",
+        "ko",
+        "지갑은 지금 생성됩니다. 7일 안에 확인하지 않으면 출금 전에 확인해야 합니다",
+    );
+    add_translation(
+        &mut dict,
+        "backup.reminder.info",
+        "zh",
+        "钱包「{wallet}」的助记词备份尚未验证，{days} 天后转出资产前必须先完成验证",
+        "en",
+        "The recovery phrase for \"{wallet}\" hasn't been verified. In {days} days you'll have to verify before sending funds",
+        "ja",
+        "「{wallet}」のリカバリーフレーズは未確認です。{days} 日後からは送金前に確認が必要です",
+        "ko",
+        "\"{wallet}\"의 복구 문구가 아직 확인되지 않았습니다. {days}일 후에는 출금 전에 확인해야 합니다",
+    );
+    add_translation(
+        &mut dict,
+        "backup.reminder.warning",
+        "zh",
+        "钱包「{wallet}」的助记词仍未验证！{days} 天后将无法直接转出资产，请尽快完成验证",
+        "en",
+        "The recovery phrase for \"{wallet}\" is still unverified! In {days} days outgoing transactions will be blocked until you verify",
+        "ja",
+        "「{wallet}」のリカバリーフレーズがまだ確認されていません！{days} 日後には確認するまで送金できなくなります",
+        "ko",
+        "\"{wallet}\"의 복구 문구가 아직 확인되지 않았습니다! {days}일 후에는 확인 전까지 출금이 차단됩니다",
+    );
+    add_translation(
+        &mut dict,
+        "backup.reminder.verify_now",
+        "zh",
+        "立即验证",
+        "en",
+        "Verify now",
+        "ja",
+        "今すぐ確認",
+        "ko",
+        "지금 확인",
+    );
+    add_translation(
+        &mut dict,
+        "backup.gate.title",
+        "zh",
+        "请先验证助记词备份",
+        "en",
+        "Verify your recovery phrase first",
+        "ja",
+        "先にリカバリーフレーズを確認してください",
+        "ko",
+        "먼저 복구 문구를 확인하세요",
+    );
+    add_translation(
+        &mut dict,
+        "backup.gate.body",
+        "zh",
+        "钱包「{wallet}」的助记词已超过 7 天未验证。为防止资产因备份错误而永久丢失，转出资产前需要先完成验证。",
+        "en",
+        "The recovery phrase for \"{wallet}\" has gone unverified for over 7 days. To keep a faulty backup from costing you your funds, verify it before sending any assets.",
+        "ja",
+        "「{wallet}」のリカバリーフレーズが 7 日以上確認されていません。バックアップの誤りで資産を失わないよう、送金前に確認してください。",
+        "ko",
+        "\"{wallet}\"의 복구 문구가 7일 넘게 확인되지 않았습니다. 잘못된 백업으로 자산을 잃지 않도록 출금 전에 확인하세요.",
+    );
+    add_translation(
+        &mut dict,
+        "backup.gate.back",
+        "zh",
+        "返回",
+        "en",
+        "Go back",
+        "ja",
+        "戻る",
+        "ko",
+        "돌아가기",
+    );
+    add_translation(
+        &mut dict,
+        "backup.unlock.password",
+        "zh",
+        "输入钱包密码以取出待验证的助记词",
+        "en",
+        "Enter your wallet password to load the phrase to verify",
+        "ja",
+        "確認するフレーズを読み込むためにウォレットのパスワードを入力してください",
+        "ko",
+        "확인할 문구를 불러오려면 지갑 비밀번호를 입력하세요",
+    );
+    add_translation(
+        &mut dict,
+        "backup.unlock.submit",
+        "zh",
+        "开始验证",
+        "en",
+        "Start verification",
+        "ja",
+        "確認を開始",
+        "ko",
+        "확인 시작",
+    );
+
     dict
 });
 
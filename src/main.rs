@@ -1,7 +1,6 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
-use dioxus_logger::tracing;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -17,7 +16,7 @@ mod services;
 mod shared;
 
 // 业务逻辑导入
-use components::molecules::ToastContainer;
+use components::molecules::performance_monitor::PerfOverlay;
 use features::wallet::state::WalletState;
 #[allow(unused_imports)]
 use gloo_storage::Storage;
@@ -27,9 +26,9 @@ fn main() {
     // Init panic hook for better error messages in console
     console_error_panic_hook::set_once();
 
-    // Init logger
-    dioxus_logger::init(tracing::Level::INFO).expect("failed to init logger");
-    tracing::info!("IronForge - Starting application");
+    // Init logger（统一日志门面：分类、分级、脱敏与调试日志缓冲）
+    shared::logging::init();
+    log::info!("IronForge - Starting application");
     launch(App);
 }
 
@@ -48,60 +47,169 @@ fn App() -> Element {
         if user_state.is_authenticated {
             if let Some(ref token) = user_state.access_token {
                 api_sig.write().set_bearer_token(token.clone());
-                tracing::info!("Auth token hydrated from UserState");
+                log::info!("Auth token hydrated from UserState");
             }
         }
     });
 
-    // Auto-Lock Timer - 1小时无操作自动锁定（与JWT token过期时间一致）
+    // 恢复"记住此设备"的会话：在路由渲染（及任何页面发起API请求）之前完成
+    let mut session_ready = use_signal(|| false);
+    use_future(move || async move {
+        let auth_manager = features::auth::auth_manager::AuthManager::new(app_state);
+        if let Err(e) = auth_manager.refresh_token_if_needed().await {
+            log::warn!("Failed to restore remembered session: {}", e);
+        }
+        session_ready.set(true);
+    });
+
+    // Auto-Lock Timer - 无操作超过设置时长后锁定应用（保留会话）；JWT过期且无法刷新时才登出
+    let wallet_controller = features::wallet::hooks::use_wallet();
+    let auth_controller = features::auth::hooks::use_auth();
     use_effect(move || {
-        let mut app_state_clone = app_state;
         spawn(async move {
             loop {
-                gloo_timers::future::TimeoutFuture::new(30000).await; // Check every 30 seconds
-                                                                      // 检查账户自动锁定
-                let last_active = *app_state_clone.last_active.read();
-                let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
-                if now - last_active > 3600 {
-                    // 1小时无操作，自动登出（与JWT token过期时间一致）
-                    if let Ok(mut user_state) = app_state_clone.user.try_write() {
-                        user_state.logout().ok();
-                    }
-                    app_state_clone.api.write().clear_auth();
+                // 每30秒检查一次；标签页隐藏时暂停，切回前台时补跑一次
+                shared::scheduler::next_tick(
+                    shared::scheduler::TickPolicy::PauseWhenHidden,
+                    30_000,
+                )
+                .await;
+                if !app_state.user.peek().is_authenticated {
+                    continue;
                 }
+
+                // 记住的设备：在Token过期前轮换刷新令牌
+                let auth_manager = features::auth::auth_manager::AuthManager::new(app_state);
+                if let Err(e) = auth_manager.refresh_token_if_needed().await {
+                    log::warn!("Token refresh failed: {}", e);
+                }
+
+                let now = shared::time::now_unix();
+                if app_state.user.peek().token_expired(now) {
+                    log::info!("Access token expired and could not be refreshed, logging out");
+                    auth_controller.logout_local().ok();
+                    continue;
+                }
+
+                wallet_controller.check_auto_lock();
+
+                // 订单进入终态后释放占用的余额
+                if !*app_state.app_locked.peek() {
+                    services::balance::BalanceService::new(app_state)
+                        .refresh_committed()
+                        .await;
+                }
+            }
+        });
+    });
+
+    // 价格与 gas 价格：一个后台任务按组件订阅统一轮询
+    use_future(move || features::market::poller::run(app_state));
+
+    // 登录或解锁后立即汇总占用的余额（之后随上面的定时任务刷新）
+    use_effect(move || {
+        if app_state.user.read().is_authenticated && !*app_state.app_locked.read() {
+            spawn(async move {
+                services::balance::BalanceService::new(app_state)
+                    .refresh_committed()
+                    .await;
+            });
+        } else if !app_state.user.read().is_authenticated {
+            let mut committed = app_state.committed_balances;
+            committed.set(Default::default());
+        }
+    });
+
+    // 偏好同步：开启同步的登录用户先与账户中的偏好合并，之后设置或语言变化时上传
+    let mut preferences_synced_at = use_signal(|| Option::<u64>::None);
+    use_effect(move || {
+        let service = services::preferences::PreferencesService::new(app_state);
+        let _ = app_state.preferences.read();
+        let _ = app_state.language.read();
+        let _ = app_state.user.read();
+        if !service.sync_enabled() {
+            preferences_synced_at.set(None);
+            return;
+        }
+        let updated_at = services::preferences::load().updated_at;
+        let synced_at = *preferences_synced_at.peek();
+        if synced_at.is_some_and(|at| at >= updated_at) {
+            return;
+        }
+        spawn(async move {
+            let result = match synced_at {
+                None => service.pull().await,
+                Some(_) => service.push().await,
+            };
+            match result {
+                Ok(()) => preferences_synced_at.set(Some(services::preferences::load().updated_at)),
+                Err(e) => log::debug!("Preferences sync failed: {}", e),
             }
         });
     });
 
-    // Activity Listener - 监听用户活动（更新账户活动时间）
+    // Activity Listener - 监听用户活动（更新最近活动时间）
     use_effect(move || {
         let app_state_clone = app_state;
         if let Some(window) = web_sys::window() {
             let mut app_state_clone = app_state_clone;
             let on_activity = Closure::wrap(Box::new(move || {
                 // 更新活动时间
-                let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+                let now = shared::time::now_unix();
                 *app_state_clone.last_active.write() = now;
             }) as Box<dyn FnMut()>);
 
-            let _ = window.add_event_listener_with_callback(
-                "mousemove",
-                on_activity.as_ref().unchecked_ref::<js_sys::Function>(),
-            );
+            for event in ["mousemove", "keydown", "click", "touchstart"] {
+                let _ = window.add_event_listener_with_callback(
+                    event,
+                    on_activity.as_ref().unchecked_ref::<js_sys::Function>(),
+                );
+            }
+
+            // 定时任务按标签页可见性暂停/放慢
+            shared::scheduler::install();
+
+            // 切回标签页：先按离开时长判断是否需要锁定，再计为活动
+            if let Some(document) = window.document() {
+                let doc = document.clone();
+                let on_visibility = Closure::wrap(Box::new(move || {
+                    if !doc.hidden() {
+                        wallet_controller.check_auto_lock();
+                        let now = shared::time::now_unix();
+                        *app_state_clone.last_active.write() = now;
+                        *app_state_clone.resume_tick.write() += 1;
+                    }
+                }) as Box<dyn FnMut()>);
+                let _ = document.add_event_listener_with_callback(
+                    "visibilitychange",
+                    on_visibility.as_ref().unchecked_ref::<js_sys::Function>(),
+                );
+                on_visibility.forget();
+            }
+
+            on_activity.forget();
+        }
+    });
+
+    // 隐私模式快捷键：Ctrl/Cmd + Shift + H
+    use_effect(move || {
+        if let Some(window) = web_sys::window() {
+            let on_keydown = Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                if (e.ctrl_key() || e.meta_key())
+                    && e.shift_key()
+                    && e.key().eq_ignore_ascii_case("h")
+                    && app_state.user.peek().is_authenticated
+                {
+                    e.prevent_default();
+                    app_state.toggle_privacy_mode();
+                }
+            })
+                as Box<dyn FnMut(web_sys::KeyboardEvent)>);
             let _ = window.add_event_listener_with_callback(
                 "keydown",
-                on_activity.as_ref().unchecked_ref::<js_sys::Function>(),
-            );
-            let _ = window.add_event_listener_with_callback(
-                "click",
-                on_activity.as_ref().unchecked_ref::<js_sys::Function>(),
+                on_keydown.as_ref().unchecked_ref::<js_sys::Function>(),
             );
-            let _ = window.add_event_listener_with_callback(
-                "touchstart",
-                on_activity.as_ref().unchecked_ref::<js_sys::Function>(),
-            );
-
-            on_activity.forget();
+            on_keydown.forget();
         }
     });
 
@@ -111,12 +219,12 @@ fn App() -> Element {
         if let Some(window) = web_sys::window() {
             let on_online = Closure::wrap(Box::new(move || {
                 *is_online_signal.write() = true;
-                tracing::info!("Network is Online");
+                log::info!("Network is Online");
             }) as Box<dyn FnMut()>);
 
             let on_offline = Closure::wrap(Box::new(move || {
                 *is_online_signal.write() = false;
-                tracing::warn!("Network is Offline");
+                log::warn!("Network is Offline");
             }) as Box<dyn FnMut()>);
 
             let _ = window.add_event_listener_with_callback(
@@ -138,13 +246,186 @@ fn App() -> Element {
         let wallet = WalletState::load().await;
         let mut wallet_signal = app_state.wallet;
         *wallet_signal.write() = wallet;
+
+        // 永久清除超过保留期的已删除钱包
+        if let Err(e) = wallet_controller.purge_expired_wallets().await {
+            log::warn!("Failed to purge deleted wallets: {}", e);
+        }
+    });
+
+    // 邀请奖励到账提醒（登录后每5分钟查询一次，每笔奖励只提醒一次）
+    use_future(move || async move {
+        loop {
+            if app_state.user.peek().is_authenticated {
+                match services::referral::ReferralService::new(app_state)
+                    .get_referral_stats()
+                    .await
+                {
+                    Ok(stats) => {
+                        for reward in
+                            features::marketing::referral::take_new_rewards(&stats.rewards)
+                        {
+                            AppState::show_success(
+                                app_state.toasts,
+                                format!("邀请奖励到账：+{} {}", reward.amount, reward.asset),
+                            );
+                        }
+                    }
+                    Err(e) => log::debug!("Referral stats check failed: {}", e),
+                }
+            }
+            gloo_timers::future::TimeoutFuture::new(300_000).await;
+        }
+    });
+
+    // 自动复投提醒：开启自动复投的持仓奖励超过阈值时提醒（每10分钟检查一次）
+    use_future(move || async move {
+        loop {
+            if app_state.user.peek().is_authenticated {
+                match services::earn::EarnService::new(app_state)
+                    .get_positions()
+                    .await
+                {
+                    Ok(positions) => {
+                        for position in features::earn::take_compound_reminders(&positions) {
+                            AppState::show_info(
+                                app_state.toasts,
+                                format!(
+                                    "{} 待领取奖励约 ${:.2}，已达到复投提醒阈值",
+                                    position.protocol, position.pending_rewards_usd
+                                ),
+                            );
+                        }
+                    }
+                    Err(e) => log::debug!("Earn positions check failed: {}", e),
+                }
+            }
+            gloo_timers::future::TimeoutFuture::new(600_000).await;
+        }
+    });
+
+    // 提现到账跟进：到预计到账时间仍未完成的提现订单提醒一次，可直接报告延迟到账
+    use_future(move || async move {
+        use features::swap::withdrawal_eta;
+        loop {
+            if app_state.user.peek().is_authenticated {
+                let now = chrono::Utc::now().timestamp();
+                for eta in withdrawal_eta::due_follow_ups(&withdrawal_eta::load(), now) {
+                    let status = match services::fiat_offramp::FiatOfframpService::new(app_state)
+                        .get_order_status(&eta.order_id)
+                        .await
+                    {
+                        Ok(status) => status,
+                        Err(e) => {
+                            log::debug!("Withdrawal follow-up check failed: {}", e);
+                            continue;
+                        }
+                    };
+                    withdrawal_eta::mark_followed_up(&eta.order_id);
+                    let delivers = app_state.preferences.peek().notifications.delivers(
+                        services::user::NotificationCategory::Transactions,
+                        services::user::NotificationChannel::InApp,
+                    );
+                    if withdrawal_eta::is_settled(&status.status) || !delivers {
+                        continue;
+                    }
+                    let order_id = eta.order_id.clone();
+                    let expected_by = eta.expected_by;
+                    AppState::show_toast_with_action(
+                        app_state.toasts,
+                        format!(
+                            "提现 {} {} 已过预计到账时间仍未完成",
+                            eta.fiat_amount, eta.fiat_currency
+                        ),
+                        components::molecules::toast::ToastType::Warning,
+                        Some(15_000),
+                        Some(components::molecules::toast::ToastAction::callback(
+                            "报告延迟到账",
+                            move || {
+                                app_state.open_help_with(features::support::HelpPanelRequest {
+                                    order_id: Some(order_id.clone()),
+                                    draft: Some(withdrawal_eta::delay_report(
+                                        &order_id,
+                                        expected_by,
+                                    )),
+                                })
+                            },
+                        )),
+                    );
+                }
+            }
+            gloo_timers::future::TimeoutFuture::new(withdrawal_eta::FOLLOW_UP_POLL_MS).await;
+        }
+    });
+
+    // 充值支付超时：超过服务商支付时限仍未支付的订单提醒一次（订单列表中显示为已过期）
+    use_future(move || async move {
+        use features::swap::onramp_orders;
+        loop {
+            if app_state.user.peek().is_authenticated {
+                let now = chrono::Utc::now().timestamp();
+                for window in onramp_orders::due_expiries(&onramp_orders::load(), now) {
+                    let status = match services::fiat_onramp::FiatOnrampService::new(app_state)
+                        .get_order_status(&window.order_id)
+                        .await
+                    {
+                        Ok(status) => status,
+                        Err(e) => {
+                            log::debug!("Onramp expiry check failed: {}", e);
+                            continue;
+                        }
+                    };
+                    // 已支付或已结束的订单不再跟踪
+                    if status.status != "pending" {
+                        onramp_orders::forget(&window.order_id);
+                        continue;
+                    }
+                    onramp_orders::mark_notified(&window.order_id);
+                    let delivers = app_state.preferences.peek().notifications.delivers(
+                        services::user::NotificationCategory::Transactions,
+                        services::user::NotificationChannel::InApp,
+                    );
+                    if !delivers {
+                        continue;
+                    }
+                    AppState::show_toast_with_action(
+                        app_state.toasts,
+                        format!(
+                            "充值订单 {}（{} USD）超过支付时限未支付，已过期",
+                            window.order_id, window.fiat_amount
+                        ),
+                        components::molecules::toast::ToastType::Warning,
+                        Some(15_000),
+                        Some(components::molecules::toast::ToastAction::route(
+                            "查看订单",
+                            router::Route::Orders {},
+                        )),
+                    );
+                }
+            }
+            gloo_timers::future::TimeoutFuture::new(onramp_orders::EXPIRY_POLL_MS).await;
+        }
+    });
+
+    // 性能指标批量上报（仅在用户同意后，每60秒一次）
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(60_000).await;
+            if !shared::metrics::has_reporting_consent() {
+                continue;
+            }
+            shared::metrics::start_frame_sampler();
+            if let Err(e) = shared::metrics::report_snapshot(&app_state.get_api_client()).await {
+                log::debug!("Metrics report failed: {}", e);
+            }
+        }
     });
 
-    // 使用路由系统和Toast容器
+    // 使用路由系统（Toast容器在路由布局内，操作按钮可跳转页面）
     rsx! {
-        router::AppRouter {}
-        ToastContainer {
-            messages: app_state.toasts
+        if session_ready() {
+            router::AppRouter {}
         }
+        PerfOverlay {}
     }
 }
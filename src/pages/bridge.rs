@@ -4,18 +4,58 @@
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::{Card, CardVariant};
+use crate::components::atoms::copy_button::CopyButton;
 use crate::components::atoms::input::{Input, InputType};
-use crate::components::atoms::select::{Select, SelectOption};
 use crate::components::molecules::error_message::ErrorMessage;
-use crate::components::molecules::ChainSelector;
+use crate::components::molecules::toast::{ToastAction, ToastType};
+use crate::components::molecules::{BackupGate, BridgeFeeSummary, ChainSelector};
+use crate::features::bridge::gas_on_arrival::{self, GasSwapJob, GasSwapStatus};
+use crate::features::bridge::prefill;
+use crate::services::address_detector::ChainType;
 use crate::services::bridge::{
     BridgeHistoryItem, BridgeResponse, BridgeService, BridgeStatusResponse,
 };
+use crate::services::bridge_fee::{trim_amount, BridgeFeeQuote, BridgeFeeService};
+use crate::services::chain_config::{confirmation_progress, ChainConfigManager, ChainFeature};
+use crate::services::price::PriceService;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
 use std::sync::Arc;
 
+/// 数量或链变化后等待该时长再刷新费用报价
+const FEE_QUOTE_DEBOUNCE_MS: u32 = 500;
+
+/// 执行到账后的 Gas 兑换，失败时提示并提供重试
+fn run_gas_swap(app_state: AppState, bridge_id: String) {
+    let key = format!("gas-on-arrival:{}", bridge_id);
+    AppState::show_progress(app_state.toasts, &key, "正在兑换目标链 Gas...".to_string());
+    spawn(async move {
+        match gas_on_arrival::run_swap(app_state, &bridge_id).await {
+            Ok(_) => AppState::finish_progress(
+                app_state.toasts,
+                &key,
+                ToastType::Success,
+                "目标链 Gas 已到账".to_string(),
+                None,
+            ),
+            Err(error) => {
+                let retry_id = bridge_id.clone();
+                AppState::finish_progress(
+                    app_state.toasts,
+                    &key,
+                    ToastType::Error,
+                    format!("目标链 Gas 兑换失败: {}", error),
+                    Some(ToastAction::callback("重试", move || {
+                        run_gas_swap(app_state, retry_id.clone())
+                    })),
+                );
+            }
+        }
+    });
+}
+
 /// Bridge Page
 #[component]
 pub fn Bridge() -> Element {
@@ -31,21 +71,139 @@ pub fn Bridge() -> Element {
         format!("{}…{}", prefix, suffix)
     }
 
-    // 表单状态
-    let mut from_chain = use_signal(|| "ethereum".to_string());
-    let mut to_chain = use_signal(|| "polygon".to_string());
-    let token = use_signal(|| "ETH".to_string());
-    let amount = use_signal(String::new);
+    // 表单状态（从稳定币卡片等入口跳转时按预填参数初始化）
+    let prefill = use_hook(prefill::take_pending_prefill);
+    let initial_from = prefill
+        .as_ref()
+        .map(|p| p.from_chain.clone())
+        .unwrap_or_else(|| "ethereum".to_string());
+    let initial_to = prefill
+        .as_ref()
+        .and_then(|p| p.to_chain.clone())
+        .filter(|to| *to != initial_from)
+        .unwrap_or_else(|| {
+            if initial_from == "polygon" {
+                "ethereum".to_string()
+            } else {
+                "polygon".to_string()
+            }
+        });
+    let mut from_chain = use_signal(|| initial_from);
+    let mut to_chain = use_signal(|| initial_to);
+    let token = use_signal(|| {
+        prefill
+            .as_ref()
+            .map(|p| p.token.clone())
+            .unwrap_or_else(|| "ETH".to_string())
+    });
+    let amount = use_signal(|| {
+        prefill
+            .as_ref()
+            .and_then(|p| p.amount.clone())
+            .unwrap_or_default()
+    });
+    // 内部转账：发送到自有的其他账户（为空时发送到当前钱包在目标链的账户）
+    let mut destination_address =
+        use_signal(|| prefill.as_ref().and_then(|p| p.destination_address.clone()));
 
     // UI状态
     let error_message = use_signal(|| Option::<String>::None);
     let is_loading = use_signal(|| false);
     let bridge_response = use_signal(|| Option::<BridgeResponse>::None);
     let bridge_status = use_signal(|| Option::<BridgeStatusResponse>::None);
+    // 桥接金额的美元价值（大额桥接需要更多确认）
+    let bridge_value_usd = use_signal(|| Option::<f64>::None);
     let is_polling = use_signal(|| false);
     let bridge_history = use_signal(Vec::<BridgeHistoryItem>::new);
     let mut show_history = use_signal(|| false);
 
+    // 费用报价（分项费用与服务商限额），输入变化后防抖刷新，只采用最后一次的结果
+    let mut fee_quote = use_signal(|| Option::<BridgeFeeQuote>::None);
+    let mut fee_loading = use_signal(|| false);
+    let mut fee_generation = use_signal(|| 0u64);
+    use_effect(move || {
+        let amount_val = amount.read().trim().parse::<f64>().unwrap_or(0.0);
+        let from = ChainType::from_str(&from_chain.read());
+        let to = ChainType::from_str(&to_chain.read());
+        let token_val = token.read().trim().to_uppercase();
+        let generation = *fee_generation.peek() + 1;
+        fee_generation.set(generation);
+
+        let (Some(from), Some(to)) = (from, to) else {
+            fee_quote.set(None);
+            fee_loading.set(false);
+            return;
+        };
+        if from == to || token_val.is_empty() || !amount_val.is_finite() || amount_val <= 0.0 {
+            fee_quote.set(None);
+            fee_loading.set(false);
+            return;
+        }
+        fee_loading.set(true);
+        spawn(async move {
+            TimeoutFuture::new(FEE_QUOTE_DEBOUNCE_MS).await;
+            if *fee_generation.peek() != generation {
+                return;
+            }
+            let result = BridgeFeeService::new(app_state)
+                .get_bridge_fee(from, to, amount_val, Some(&token_val))
+                .await;
+            if *fee_generation.peek() != generation {
+                return;
+            }
+            match result {
+                Ok(quote) => fee_quote.set(Some(quote)),
+                Err(e) => {
+                    log::warn!("跨链费用报价失败: {}", e);
+                    fee_quote.set(None);
+                }
+            }
+            fee_loading.set(false);
+        });
+    });
+
+    // 到账兑换 Gas（按比例预留，服务商支持时由服务商兑换，否则到账后自动兑换）
+    let mut include_gas = use_signal(|| false);
+    let mut reserve_share = use_signal(gas_on_arrival::reserve_share);
+    let gas_plan = use_memo(move || {
+        if !include_gas() || destination_address.read().is_some() {
+            return None;
+        }
+        let destination = ChainType::from_str(&to_chain.read())?;
+        gas_on_arrival::plan(
+            amount.read().trim().parse::<f64>().unwrap_or(0.0),
+            reserve_share(),
+            token.read().trim(),
+            destination,
+            fee_quote.read().as_ref(),
+        )
+    });
+
+    // 上次离开页面时仍在排队的兑换：桥接已到账则立即执行
+    use_future(move || async move {
+        let service = BridgeService::new(app_state);
+        let jobs = gas_on_arrival::load_jobs();
+        for job in gas_on_arrival::unfinished(&jobs) {
+            if job.status != GasSwapStatus::Queued {
+                continue;
+            }
+            if let Ok(status) = service.get_status(&job.bridge_id).await {
+                if status.status == "DestinationConfirmed" {
+                    run_gas_swap(app_state, job.bridge_id.clone());
+                }
+            }
+        }
+    });
+
+    // 超出服务商单笔限额时的提示
+    let amount_limit_error = use_memo(move || {
+        let amount_val = amount.read().trim().parse::<f64>().ok()?;
+        fee_quote
+            .read()
+            .as_ref()?
+            .amount_error(amount_val, &token.read().trim().to_uppercase())
+    });
+
     // 执行桥接
     let execute_bridge = move || {
         let amount_val = amount.read().clone();
@@ -59,6 +217,9 @@ pub fn Bridge() -> Element {
         let mut err = error_message;
         let mut response_sig = bridge_response;
         let mut status_sig = bridge_status;
+        let mut value_usd_sig = bridge_value_usd;
+        let plan = gas_plan.peek().clone();
+        let destination = destination_address.peek().clone();
 
         if amount_val.is_empty() || amount_val.parse::<f64>().unwrap_or(0.0) <= 0.0 {
             err.set(Some("请输入有效的桥接数量".to_string()));
@@ -70,6 +231,11 @@ pub fn Bridge() -> Element {
             return;
         }
 
+        if let Some(limit_error) = amount_limit_error.peek().clone() {
+            err.set(Some(limit_error));
+            return;
+        }
+
         let wallet_id = match wallet_opt {
             Some(w) => w.id.to_string(),
             None => {
@@ -82,19 +248,74 @@ pub fn Bridge() -> Element {
             loading.set(true);
             err.set(None);
             status_sig.set(None);
+            value_usd_sig.set(None);
 
             let bridge_service = BridgeService::new(app_state_clone);
-            match bridge_service
-                .bridge_assets(&wallet_id, &from, &to, &token_val, &amount_val)
-                .await
-            {
+            let provider_gas = plan
+                .as_ref()
+                .filter(|p| p.via_provider)
+                .map(|p| trim_amount(p.reserve_amount));
+            let result = match destination.as_deref() {
+                Some(address) => {
+                    bridge_service
+                        .bridge_assets_to_address(
+                            &wallet_id,
+                            &from,
+                            &to,
+                            &token_val,
+                            &amount_val,
+                            address,
+                        )
+                        .await
+                }
+                None => {
+                    bridge_service
+                        .bridge_assets(
+                            &wallet_id,
+                            &from,
+                            &to,
+                            &token_val,
+                            &amount_val,
+                            provider_gas.as_deref(),
+                        )
+                        .await
+                }
+            };
+            match result {
                 Ok(resp) => {
                     log::info!(
                         "Bridge执行成功: bridge_id={}, status={}",
                         resp.bridge_id,
                         resp.status
                     );
+                    // 服务商不支持 gas drop-off 时排队到账后的兑换
+                    if let Some(plan) = plan.as_ref().filter(|p| !p.via_provider) {
+                        gas_on_arrival::queue(GasSwapJob {
+                            bridge_id: resp.bridge_id.clone(),
+                            wallet_id: wallet_id.clone(),
+                            network: to.clone(),
+                            token: token_val.trim().to_uppercase(),
+                            amount: trim_amount(plan.reserve_amount),
+                            native_symbol: plan.native_symbol.to_string(),
+                            status: GasSwapStatus::Queued,
+                        });
+                        if resp.status == "DestinationConfirmed" {
+                            run_gas_swap(app_state_clone, resp.bridge_id.clone());
+                        }
+                    }
                     response_sig.set(Some(resp.clone()));
+                    let (amount_for_value, token_for_value) =
+                        (amount_val.clone(), token_val.clone());
+                    spawn(async move {
+                        if let Ok(amount_f64) = amount_for_value.parse::<f64>() {
+                            if let Ok(price) = PriceService::new(app_state_clone)
+                                .get_price(&token_for_value)
+                                .await
+                            {
+                                value_usd_sig.set(Some(amount_f64 * price.usd));
+                            }
+                        }
+                    });
 
                     // 如果状态是pending或processing，开始轮询
                     if resp.status != "DestinationConfirmed" && resp.status != "Failed" {
@@ -123,7 +344,16 @@ pub fn Bridge() -> Element {
                                         }
 
                                         match status.status.as_str() {
-                                            "DestinationConfirmed" | "Failed" | "Cancelled" => {
+                                            "DestinationConfirmed" => {
+                                                if gas_on_arrival::job_for(&bridge_id).is_some() {
+                                                    run_gas_swap(
+                                                        app_state_clone,
+                                                        bridge_id.clone(),
+                                                    );
+                                                }
+                                                break;
+                                            }
+                                            "Failed" | "Cancelled" => {
                                                 break;
                                             }
                                             _ => {
@@ -190,6 +420,8 @@ pub fn Bridge() -> Element {
             div {
                 class: "container mx-auto max-w-2xl px-4 sm:px-6",
 
+                BackupGate {}
+
                 // 页面标题 - 响应式优化
                 div {
                     class: "mb-4 sm:mb-6",
@@ -221,6 +453,8 @@ pub fn Bridge() -> Element {
                                 }
                                 ChainSelector {
                                     selected_chain: from_chain,
+                                    feature: ChainFeature::Bridge,
+                                    label: None,
                                 }
                             }
 
@@ -241,21 +475,17 @@ pub fn Bridge() -> Element {
                             }
 
                             // 到链
-                            Select {
-                                label: Some("到链".to_string()),
-                                value: Some(to_chain.read().clone()),
-                                options: vec![
-                                    SelectOption::new("ethereum", "Ethereum"),
-                                    SelectOption::new("polygon", "Polygon"),
-                                    SelectOption::new("bsc", "BSC"),
-                                    SelectOption::new("arbitrum", "Arbitrum"),
-                                    SelectOption::new("solana", "Solana"),
-                                    SelectOption::new("ton", "TON"),
-                                ],
-                                onchange: {
-                                    let mut to_chain_sig = to_chain;
-                                    move |e: FormEvent| to_chain_sig.set(e.value())
-                                },
+                            div {
+                                label {
+                                    class: "block text-sm font-medium mb-2",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "到链"
+                                }
+                                ChainSelector {
+                                    selected_chain: to_chain,
+                                    feature: ChainFeature::Bridge,
+                                    label: None,
+                                }
                             }
 
                             // 代币
@@ -276,11 +506,99 @@ pub fn Bridge() -> Element {
                                 label: Some("数量".to_string()),
                                 value: Some(amount.read().clone()),
                                 placeholder: Some("0.0".to_string()),
+                                error: amount_limit_error(),
                                 onchange: {
                                     let mut amount_sig = amount;
                                     move |e: FormEvent| amount_sig.set(e.value())
                                 },
                             }
+
+                            // 内部转账的接收账户（发送到自有的其他钱包）
+                            if let Some(address) = destination_address() {
+                                div {
+                                    class: "p-3 rounded-lg flex items-center justify-between gap-2",
+                                    style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+                                    div {
+                                        class: "min-w-0",
+                                        p {
+                                            class: "text-xs",
+                                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                            "内部转账 · 接收地址"
+                                        }
+                                        p {
+                                            class: "text-sm font-mono truncate",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "{address}"
+                                        }
+                                    }
+                                    button {
+                                        class: "text-xs shrink-0",
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        onclick: move |_| destination_address.set(None),
+                                        "改为发送到本钱包"
+                                    }
+                                }
+                            }
+
+                            // 到账兑换 Gas（发送到其他钱包时不适用）
+                            if destination_address.read().is_none() {
+                                div {
+                                    class: "p-3 rounded-lg space-y-2",
+                                    style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+                                    label {
+                                        class: "flex items-center gap-2 text-sm cursor-pointer",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: include_gas(),
+                                            onchange: move |e| include_gas.set(e.checked()),
+                                        }
+                                        "到账时兑换部分为目标链 Gas"
+                                    }
+                                    p {
+                                        class: "text-xs",
+                                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                        "新链上没有原生币时无法转出资产，开启后预留一小部分桥接数量兑换为 Gas"
+                                    }
+                                    if include_gas() {
+                                        div {
+                                            class: "flex gap-2",
+                                            for share in gas_on_arrival::RESERVE_SHARE_OPTIONS {
+                                                button {
+                                                    class: "px-3 py-1 text-xs rounded-full",
+                                                    style: format!(
+                                                        "background: {}; color: {}; border: 1px solid {};",
+                                                        if reserve_share() == share { Colors::TECH_PRIMARY } else { Colors::BG_SECONDARY },
+                                                        if reserve_share() == share { "white" } else { Colors::TEXT_SECONDARY },
+                                                        Colors::BORDER_PRIMARY,
+                                                    ),
+                                                    onclick: move |_| {
+                                                        gas_on_arrival::set_reserve_share(share);
+                                                        reserve_share.set(share);
+                                                    },
+                                                    {format!("{}%", share * 100.0)}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // 费用明细（源链 Gas / 跨链桥费用 / 目标链 Gas）
+                            if let (Some(source), Some(destination)) = (
+                                ChainType::from_str(&from_chain.read()),
+                                ChainType::from_str(&to_chain.read()),
+                            ) {
+                                BridgeFeeSummary {
+                                    quote: fee_quote(),
+                                    is_loading: fee_loading(),
+                                    token: token.read().trim().to_uppercase(),
+                                    amount: amount.read().trim().parse::<f64>().unwrap_or(0.0),
+                                    source_chain: source,
+                                    destination_chain: destination,
+                                    gas_reserve: gas_plan(),
+                                }
+                            }
                         }
                     }
                 }
@@ -326,6 +644,49 @@ pub fn Bridge() -> Element {
                                 }
 
                                 if let Some(st) = bridge_status.read().as_ref() {
+                                    {
+                                        let overrides = app_state.preferences.read().confirmation_overrides.clone();
+                                        let value_usd = *bridge_value_usd.read();
+                                        let progress = |chain: &str, current: u32| {
+                                            match ChainType::from_str(chain) {
+                                                Some(chain) => confirmation_progress(
+                                                    current as u64,
+                                                    ChainConfigManager::new().required_confirmations(chain, value_usd, &overrides),
+                                                ),
+                                                None => current.to_string(),
+                                            }
+                                        };
+                                        let source_progress = progress(&resp.source_chain, st.source_confirmations);
+                                        let destination_progress = progress(&resp.destination_chain, st.destination_confirmations);
+                                        rsx! {
+                                            div {
+                                                class: "flex justify-between items-center",
+                                                span {
+                                                    class: "text-sm",
+                                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                    "源链确认"
+                                                }
+                                                span {
+                                                    class: "text-sm",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    "{source_progress}"
+                                                }
+                                            }
+                                            div {
+                                                class: "flex justify-between items-center",
+                                                span {
+                                                    class: "text-sm",
+                                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                    "目标链确认"
+                                                }
+                                                span {
+                                                    class: "text-sm",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    "{destination_progress}"
+                                                }
+                                            }
+                                        }
+                                    }
                                     div {
                                         class: "flex justify-between items-center",
                                         span {
@@ -339,32 +700,6 @@ pub fn Bridge() -> Element {
                                             {format!("{}%", st.progress_percentage)}
                                         }
                                     }
-                                    div {
-                                        class: "flex justify-between items-center",
-                                        span {
-                                            class: "text-sm",
-                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                            "源确认数"
-                                        }
-                                        span {
-                                            class: "text-sm",
-                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                            "{st.source_confirmations}"
-                                        }
-                                    }
-                                    div {
-                                        class: "flex justify-between items-center",
-                                        span {
-                                            class: "text-sm",
-                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                            "目标确认数"
-                                        }
-                                        span {
-                                            class: "text-sm",
-                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                            "{st.destination_confirmations}"
-                                        }
-                                    }
 
                                     if let Some(hash) = st.source_tx_hash.as_ref() {
                                         div {
@@ -374,10 +709,14 @@ pub fn Bridge() -> Element {
                                                 style: format!("color: {};", Colors::TEXT_SECONDARY),
                                                 "源交易"
                                             }
-                                            span {
-                                                class: "text-sm font-mono",
-                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                {short_hash(hash)}
+                                            div {
+                                                class: "flex items-center gap-2",
+                                                span {
+                                                    class: "text-sm font-mono",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {short_hash(hash)}
+                                                }
+                                                CopyButton { value: hash.clone(), compact: true }
                                             }
                                         }
                                     }
@@ -389,10 +728,14 @@ pub fn Bridge() -> Element {
                                                 style: format!("color: {};", Colors::TEXT_SECONDARY),
                                                 "Approve"
                                             }
-                                            span {
-                                                class: "text-sm font-mono",
-                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                {short_hash(hash)}
+                                            div {
+                                                class: "flex items-center gap-2",
+                                                span {
+                                                    class: "text-sm font-mono",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {short_hash(hash)}
+                                                }
+                                                CopyButton { value: hash.clone(), compact: true }
                                             }
                                         }
                                     }
@@ -404,10 +747,14 @@ pub fn Bridge() -> Element {
                                                 style: format!("color: {};", Colors::TEXT_SECONDARY),
                                                 "Swap"
                                             }
-                                            span {
-                                                class: "text-sm font-mono",
-                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                {short_hash(hash)}
+                                            div {
+                                                class: "flex items-center gap-2",
+                                                span {
+                                                    class: "text-sm font-mono",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {short_hash(hash)}
+                                                }
+                                                CopyButton { value: hash.clone(), compact: true }
                                             }
                                         }
                                     }
@@ -419,10 +766,14 @@ pub fn Bridge() -> Element {
                                                 style: format!("color: {};", Colors::TEXT_SECONDARY),
                                                 "目标交易"
                                             }
-                                            span {
-                                                class: "text-sm font-mono",
-                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                {short_hash(hash)}
+                                            div {
+                                                class: "flex items-center gap-2",
+                                                span {
+                                                    class: "text-sm font-mono",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {short_hash(hash)}
+                                                }
+                                                CopyButton { value: hash.clone(), compact: true }
                                             }
                                         }
                                     }
@@ -546,7 +897,7 @@ pub fn Bridge() -> Element {
                         variant: ButtonVariant::Primary,
                         size: ButtonSize::Large,
                         onclick: move |_| execute_bridge(),
-                        disabled: *is_loading.read() || *is_polling.read(),
+                        disabled: *is_loading.read() || *is_polling.read() || amount_limit_error.read().is_some(),
                         loading: *is_loading.read(),
                         class: Some("w-full mt-4".to_string()),
                         if *is_loading.read() { "执行中..." } else { "执行桥接" }
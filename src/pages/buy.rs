@@ -11,7 +11,7 @@
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
-use crate::components::molecules::ErrorMessage;
+use crate::components::molecules::{EmailVerificationRequired, ErrorMessage};
 use crate::features::wallet::unlock::ensure_wallet_unlocked;
 use crate::router::Route;
 use crate::services::fiat_onramp::{FiatOnrampService, FiatQuoteResponse};
@@ -157,6 +157,17 @@ pub fn Buy() -> Element {
         };
     }
 
+    // 法币功能需先验证邮箱（钱包功能不受影响）
+    if app_state.user.read().needs_email_verification() {
+        return rsx! {
+            div { class: "min-h-screen p-4", style: format!("background: {};", Colors::BG_PRIMARY),
+                div { class: "container mx-auto max-w-3xl px-4 sm:px-6 flex items-center justify-center h-[70vh]",
+                    EmailVerificationRequired {}
+                }
+            }
+        };
+    }
+
     // 当前选中钱包（入口级安全门）
     let current_wallet = use_memo(move || {
         let wallet_state = app_state.wallet.read();
@@ -280,9 +291,7 @@ pub fn Buy() -> Element {
                     None => {
                         error_message.set(Some("请先点击【获取报价】按钮获取实时报价".to_string()));
                         is_loading.set(false);
-                        tracing::warn!(
-                            "[Buy] Attempted to create order without getting quote first"
-                        );
+                        log::warn!("[Buy] Attempted to create order without getting quote first");
                         return;
                     }
                 };
@@ -293,7 +302,7 @@ pub fn Buy() -> Element {
                     Some(wallet_addr.as_str())
                 };
 
-                tracing::info!("[Buy] Creating order: amount={}, currency={}, token={}, payment_method={}, quote_id={}", 
+                log::info!("[Buy] Creating order: amount={}, currency={}, token={}, payment_method={}, quote_id={}", 
                     amount, currency, token, payment_method, quote_id);
 
                 let service = FiatOnrampService::new(app_state);
@@ -305,11 +314,12 @@ pub fn Buy() -> Element {
                         payment_method,
                         &quote_id,
                         wallet_address_opt,
+                        None,
                     )
                     .await
                 {
                     Ok(order) => {
-                        tracing::info!(
+                        log::info!(
                             "[Buy] Order created successfully: order_id={}, payment_url={:?}",
                             order.order_id,
                             order.payment_url
@@ -319,7 +329,7 @@ pub fn Buy() -> Element {
                         is_loading.set(false);
                     }
                     Err(e) => {
-                        tracing::error!("[Buy] Failed to create order: {}", e);
+                        log::error!("[Buy] Failed to create order: {}", e);
                         error_message.set(Some(format!("创建订单失败：{}", e)));
                         is_loading.set(false);
                     }
@@ -353,14 +363,14 @@ pub fn Buy() -> Element {
                                             variant: ButtonVariant::Primary,
                                             size: ButtonSize::Large,
                                             onclick: move |_| {
-                                                tracing::info!("[Buy] Opening payment URL: {}", url);
+                                                log::info!("[Buy] Opening payment URL: {}", url);
                                                 if let Some(window) = web_sys::window() {
                                                     match window.open_with_url_and_target(&url, "_blank") {
-                                                        Ok(_) => tracing::info!("[Buy] Payment window opened successfully"),
-                                                        Err(e) => tracing::error!("[Buy] Failed to open payment window: {:?}", e),
+                                                        Ok(_) => log::info!("[Buy] Payment window opened successfully"),
+                                                        Err(e) => log::error!("[Buy] Failed to open payment window: {:?}", e),
                                                     }
                                                 } else {
-                                                    tracing::error!("[Buy] window object not available");
+                                                    log::error!("[Buy] window object not available");
                                                 }
                                             },
                                             "🔗 前往支付页面"
@@ -0,0 +1,401 @@
+//! DApps Page - dApp 浏览页面
+//! 按链浏览精选 dApp（分类、风险标签），查看已连接 dApp 的授权并断开或限制账户
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::Card;
+use crate::components::atoms::copy_button::copy_text;
+use crate::components::molecules::{EmptyState, LoadFailedState};
+use crate::features::dapps::{self, DappSession};
+use crate::services::dapps::{
+    chains_and_categories, filter_catalog, DappEntry, DappRisk, DappService,
+};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use dioxus::prelude::*;
+
+fn format_date(timestamp: u64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64((timestamp * 1000) as f64));
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}
+
+fn short_address(address: &str) -> String {
+    if address.len() > 12 {
+        format!("{}...{}", &address[..6], &address[address.len() - 4..])
+    } else {
+        address.to_string()
+    }
+}
+
+fn risk_color(risk: DappRisk) -> &'static str {
+    match risk {
+        DappRisk::Low => Colors::PAYMENT_SUCCESS,
+        DappRisk::Medium => Colors::PAYMENT_WARNING,
+        DappRisk::High => Colors::PAYMENT_ERROR,
+        DappRisk::Unknown => Colors::TEXT_TERTIARY,
+    }
+}
+
+fn chip_style(active: bool) -> String {
+    format!(
+        "background: {}; color: {}; border: 1px solid {};",
+        if active {
+            Colors::TECH_PRIMARY
+        } else {
+            Colors::BG_PRIMARY
+        },
+        if active {
+            "white"
+        } else {
+            Colors::TEXT_SECONDARY
+        },
+        Colors::BORDER_PRIMARY
+    )
+}
+
+/// DApps Page - dApp 浏览页面
+#[component]
+pub fn Dapps() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut catalog = use_signal(|| Option::<Result<Vec<DappEntry>, String>>::None);
+    let mut reload = use_signal(|| 0u32);
+    let mut chain_filter = use_signal(|| Option::<String>::None);
+    let mut category_filter = use_signal(|| Option::<String>::None);
+    let mut sessions = use_signal(dapps::load_sessions);
+
+    use_effect(move || {
+        let _ = reload();
+        spawn(async move {
+            catalog.set(None);
+            let result = DappService::new(app_state)
+                .get_catalog()
+                .await
+                .map_err(|e| e.to_string());
+            catalog.set(Some(result));
+        });
+    });
+
+    let now = time::now_unix();
+
+    rsx! {
+        div {
+            class: "min-h-screen pt-20 pb-8 px-4",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+
+            div {
+                class: "container mx-auto max-w-4xl px-4 sm:px-6 space-y-6",
+
+                div {
+                    h1 {
+                        class: "text-2xl font-bold mb-2 flex items-center gap-2",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        span { "🧭" }
+                        span { "dApp 浏览" }
+                    }
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "精选 dApp 与已连接 dApp 的授权管理"
+                    }
+                }
+
+                // 已连接的 dApp
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    children: rsx! {
+                        h2 {
+                            class: "text-lg font-semibold mb-4",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "最近连接"
+                        }
+                        if sessions.read().is_empty() {
+                            p {
+                                class: "text-sm",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                "还没有通过 WalletConnect 连接过 dApp"
+                            }
+                        } else {
+                            div {
+                                class: "space-y-3",
+                                for session in sessions.read().iter().cloned() {
+                                    SessionCard {
+                                        key: "{session.topic}",
+                                        session: session.clone(),
+                                        now,
+                                        on_change: move |updated| sessions.set(updated),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 精选 dApp
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    children: rsx! {
+                        h2 {
+                            class: "text-lg font-semibold mb-4",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "精选 dApp"
+                        }
+                        match catalog.read().clone() {
+                            None => rsx! {
+                                p {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "加载中..."
+                                }
+                            },
+                            Some(Err(error)) => rsx! {
+                                LoadFailedState {
+                                    title: "dApp 目录加载失败".to_string(),
+                                    message: Some(error),
+                                    retry_label: "重试".to_string(),
+                                    on_retry: move |_| reload += 1,
+                                }
+                            },
+                            Some(Ok(entries)) => {
+                                let (chains, categories) = chains_and_categories(&entries);
+                                let chain = chain_filter.read().clone();
+                                let category = category_filter.read().clone();
+                                let visible: Vec<DappEntry> =
+                                    filter_catalog(&entries, chain.as_deref(), category.as_deref())
+                                        .into_iter()
+                                        .cloned()
+                                        .collect();
+                                rsx! {
+                                    div {
+                                        class: "flex gap-2 flex-wrap mb-3",
+                                        button {
+                                            class: "px-3 py-1 text-sm rounded-full transition-all",
+                                            style: chip_style(chain.is_none()),
+                                            onclick: move |_| chain_filter.set(None),
+                                            "全部链"
+                                        }
+                                        for c in chains {
+                                            button {
+                                                class: "px-3 py-1 text-sm rounded-full transition-all",
+                                                style: chip_style(chain.as_deref() == Some(c.as_str())),
+                                                onclick: {
+                                                    let c = c.clone();
+                                                    move |_| chain_filter.set(Some(c.clone()))
+                                                },
+                                                "{c}"
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "flex gap-2 flex-wrap mb-4",
+                                        button {
+                                            class: "px-3 py-1 text-sm rounded-full transition-all",
+                                            style: chip_style(category.is_none()),
+                                            onclick: move |_| category_filter.set(None),
+                                            "全部分类"
+                                        }
+                                        for c in categories {
+                                            button {
+                                                class: "px-3 py-1 text-sm rounded-full transition-all",
+                                                style: chip_style(category.as_deref() == Some(c.as_str())),
+                                                onclick: {
+                                                    let c = c.clone();
+                                                    move |_| category_filter.set(Some(c.clone()))
+                                                },
+                                                "{c}"
+                                            }
+                                        }
+                                    }
+                                    if visible.is_empty() {
+                                        EmptyState {
+                                            icon: "🧭".to_string(),
+                                            title: "没有符合条件的 dApp".to_string(),
+                                        }
+                                    } else {
+                                        div {
+                                            class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                                            for entry in visible {
+                                                DappCard {
+                                                    key: "{entry.id}",
+                                                    live: dapps::live_session_for(&sessions.read(), &entry.url, now).is_some(),
+                                                    entry: entry.clone(),
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 目录中的 dApp 卡片
+#[component]
+fn DappCard(entry: DappEntry, live: bool) -> Element {
+    let app_state = use_context::<AppState>();
+    let url = entry.safe_url().map(str::to_string);
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex items-start justify-between gap-2 mb-2",
+                div {
+                    class: "flex items-center gap-2 min-w-0",
+                    if let Some(icon) = entry.icon_url.as_ref().filter(|i| i.starts_with("https://")) {
+                        img { class: "w-6 h-6 rounded", src: "{icon}", alt: "" }
+                    }
+                    span {
+                        class: "font-semibold truncate",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "{entry.name}"
+                    }
+                    if live {
+                        span {
+                            class: "flex items-center gap-1 text-xs",
+                            style: format!("color: {};", Colors::PAYMENT_SUCCESS),
+                            title: "WalletConnect 会话有效",
+                            span {
+                                class: "inline-block w-2 h-2 rounded-full animate-pulse",
+                                style: format!("background: {};", Colors::PAYMENT_SUCCESS),
+                            }
+                            "已连接"
+                        }
+                    }
+                }
+                span {
+                    class: "text-xs px-2 py-0.5 rounded-full shrink-0",
+                    style: format!("color: {}; border: 1px solid {};", risk_color(entry.risk), risk_color(entry.risk)),
+                    {entry.risk.label()}
+                }
+            }
+            if !entry.description.is_empty() {
+                p {
+                    class: "text-sm mb-2",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "{entry.description}"
+                }
+            }
+            p {
+                class: "text-xs mb-3",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                "{entry.category} · {entry.chains.join(\", \")}"
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                disabled: url.is_none(),
+                onclick: move |_| {
+                    let Some(url) = url.clone() else {
+                        return;
+                    };
+                    spawn(async move {
+                        if copy_text(&url).await.is_ok() {
+                            AppState::show_info(app_state.toasts, "已复制 dApp 链接".to_string());
+                        }
+                        if let Some(window) = web_sys::window() {
+                            let _ = window.open_with_url_and_target(&url, "_blank");
+                        }
+                    });
+                },
+                "打开"
+            }
+        }
+    }
+}
+
+/// 已连接 dApp 的授权与操作
+#[component]
+fn SessionCard(
+    session: DappSession,
+    now: u64,
+    on_change: EventHandler<Vec<DappSession>>,
+) -> Element {
+    let live = session.is_live(now);
+    let topic = session.topic.clone();
+    let restrict_topic = session.topic.clone();
+    let mut keep_account = use_signal(|| session.accounts.first().cloned().unwrap_or_default());
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex items-center justify-between gap-2 mb-2",
+                div {
+                    class: "min-w-0",
+                    div {
+                        class: "font-semibold truncate",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "{session.name}"
+                    }
+                    div {
+                        class: "text-xs truncate",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "{session.url}"
+                    }
+                }
+                span {
+                    class: "text-xs shrink-0",
+                    style: format!(
+                        "color: {};",
+                        if live { Colors::PAYMENT_SUCCESS } else { Colors::TEXT_TERTIARY },
+                    ),
+                    if live { "● 会话有效" } else { "会话已过期" }
+                }
+            }
+            div {
+                class: "text-xs space-y-1 mb-3",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                div {
+                    "账户："
+                    {session.accounts.iter().map(|a| short_address(a)).collect::<Vec<_>>().join("、")}
+                }
+                div { "链：{session.chains.join(\"、\")}" }
+                div { "方法：{session.methods.join(\"、\")}" }
+                div { "最近活动：{format_date(session.last_active)}" }
+            }
+            div {
+                class: "flex flex-wrap items-center gap-2",
+                if session.accounts.len() > 1 {
+                    select {
+                        class: "px-2 py-1 text-sm rounded",
+                        style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
+                        value: "{keep_account}",
+                        onchange: move |e| keep_account.set(e.value()),
+                        for account in session.accounts.iter().cloned() {
+                            option { value: "{account}", {short_address(&account)} }
+                        }
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        onclick: move |_| {
+                            on_change.call(dapps::restrict_to_account(&restrict_topic, &keep_account.read()));
+                        },
+                        "仅限此账户"
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Error,
+                    size: ButtonSize::Small,
+                    onclick: move |_| on_change.call(dapps::disconnect(&topic)),
+                    "断开连接"
+                }
+            }
+        }
+    }
+}
@@ -5,14 +5,15 @@
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
-use crate::components::molecules::WalletDeleteModal;
+use crate::components::molecules::{BackupReminderBanner, EmptyState, WalletDeleteModal};
 use crate::components::route_guard::AuthGuard;
 use crate::components::wallet_unlock_modal::WalletUnlockModal;
 use crate::features::auth::hooks::use_auth;
+use crate::features::dashboard::loader::use_dashboard_loader;
 use crate::features::wallet::hooks::use_wallet;
 use crate::features::wallet::state::Wallet;
-use crate::pages::dashboard_balance::BalanceOverview;
-use crate::pages::dashboard_transactions::TransactionHistoryPreview;
+use crate::pages::dashboard_funding::FundWalletFlow;
+use crate::pages::dashboard_widgets::DashboardWidgets;
 use crate::router::Route;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
@@ -50,43 +51,50 @@ fn DashboardContent() -> Element {
     let navigator = use_navigator();
     let auth_controller = use_auth();
     let t = crate::i18n::use_translation();
+    // 分级加载：选中钱包余额优先，价格/历史其次，其他钱包空闲预取
+    let dashboard_data = use_dashboard_loader();
 
     // 登录后从后端同步钱包列表
     // 使用use_future确保在组件渲染时立即执行，而不是等待use_effect
     use_future(move || {
         let auth_ctrl = auth_controller;
-        let mut app_state = app_state;
         async move {
-            // 如果已登录且有token，从后端同步钱包
-            let user_state = app_state.user.read();
-            let is_authenticated = user_state.is_authenticated;
-            let has_token = user_state
-                .access_token
-                .as_ref()
-                .map(|t| !t.is_empty())
-                .unwrap_or(false);
+            // 如果已登录且有token，从后端同步钱包（同步期间可能写入用户状态，先释放读锁）
+            let (is_authenticated, has_token) = {
+                let user_state = app_state.user.read();
+                let has_token = user_state
+                    .access_token
+                    .as_ref()
+                    .map(|t| !t.is_empty())
+                    .unwrap_or(false);
+                (user_state.is_authenticated, has_token)
+            };
 
             if is_authenticated && has_token {
                 // 同步钱包（如果失败，会保留本地钱包）
                 if let Err(e) = auth_ctrl.sync_wallets_from_backend().await {
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::warn;
+                        use log::warn;
                         warn!("Failed to sync wallets from backend: {:?}", e);
                     }
                     // 错误已在sync_wallets_from_backend中处理，这里不需要额外处理
                 }
             } else if !is_authenticated {
                 // 如果未登录，尝试从本地存储加载钱包（用于离线查看）
-                let mut wallet_state = app_state.wallet.write();
-                if wallet_state.wallets.is_empty() {
-                    // 使用WalletState::load()方法加载钱包
+                if app_state.wallet.peek().wallets.is_empty() {
+                    // 使用WalletState::load()方法加载钱包（不跨 await 持有写锁）
                     use crate::features::wallet::state::WalletState;
                     let local_wallet_state = WalletState::load().await;
                     if !local_wallet_state.wallets.is_empty() {
-                        wallet_state.wallets = local_wallet_state.wallets;
-                        wallet_state.selected_wallet_id = local_wallet_state.selected_wallet_id;
-                        let _ = wallet_state.save();
+                        app_state.update_wallet(move |wallet_state| {
+                            if wallet_state.wallets.is_empty() {
+                                wallet_state.wallets = local_wallet_state.wallets;
+                                wallet_state.selected_wallet_id =
+                                    local_wallet_state.selected_wallet_id;
+                                wallet_state.deleted_wallets = local_wallet_state.deleted_wallets;
+                            }
+                        });
                     }
                 }
             }
@@ -104,7 +112,7 @@ fn DashboardContent() -> Element {
 
                 let wallet_state = app_state_for_timer.wallet.read();
                 let unlock_times = app_state_for_timer.wallet_unlock_time.read();
-                let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+                let now = crate::shared::time::now_unix();
 
                 // 检查所有钱包的解锁状态
                 for wallet in wallet_state.wallets.iter() {
@@ -114,7 +122,7 @@ fn DashboardContent() -> Element {
                             if now - unlock_time > 300 {
                                 #[cfg(debug_assertions)]
                                 {
-                                    use tracing::info;
+                                    use log::info;
                                     info!("🔒 钱包 '{}' 自动锁定（已解锁超过5分钟）", wallet.name);
                                 }
                                 let wallet_id = wallet.id.clone();
@@ -170,61 +178,32 @@ fn DashboardContent() -> Element {
                     }
                 }
 
+                // 推迟验证的助记词备份提醒
+                BackupReminderBanner {}
+
                 // 钱包列表或空状态
                 if wallet_state.wallets.is_empty() {
                     Card {
                         variant: crate::components::atoms::card::CardVariant::Base,
                         padding: Some("48px".to_string()),
                         children: rsx! {
-                            div {
-                                class: "text-center",
-                                crate::components::atoms::icon::Icon {
-                                    name: "wallet".to_string(),
-                                    size: crate::components::atoms::icon::IconSize::XXL,
-                                }
-                                h2 {
-                                    class: "text-2xl font-bold mt-4 mb-2",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "还没有钱包"
-                                }
-                                p {
-                                    class: "text-sm mb-6",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "创建或导入您的第一个钱包"
-                                }
-                                div {
-                                    class: "flex gap-4 justify-center",
-                                    Button {
-                                        variant: ButtonVariant::Primary,
-                                        size: ButtonSize::Large,
-                                        onclick: move |_| {
-                                            navigator.push(Route::CreateWallet {});
-                                        },
-                                        {t("dashboard.create_wallet")}
-                                    }
-                                    Button {
-                                        variant: ButtonVariant::Secondary,
-                                        size: ButtonSize::Large,
-                                        onclick: move |_| {
-                                            navigator.push(Route::ImportWallet {});
-                                        },
-                                        "导入/恢复钱包"
+                            EmptyState {
+                                illustration: rsx! {
+                                    crate::components::atoms::icon::Icon {
+                                        name: "wallet".to_string(),
+                                        size: crate::components::atoms::icon::IconSize::XXL,
                                     }
-                                }
-                                div {
-                                    class: "mt-4 text-center text-sm",
-                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                    p {
-                                        class: "mb-2",
-                                        "💡 提示："
-                                    }
-                                    p {
-                                        "• 创建钱包：生成新钱包和助记词"
-                                    }
-                                    p {
-                                        "• 导入/恢复钱包：使用助记词或私钥在新设备上恢复钱包"
-                                    }
-                                }
+                                },
+                                title: t("empty.wallets.title"),
+                                description: t("empty.wallets.description"),
+                                cta_label: t("empty.wallets.cta"),
+                                on_cta: move |_| {
+                                    navigator.push(Route::CreateWallet {});
+                                },
+                                secondary_label: t("empty.wallets.secondary"),
+                                on_secondary: move |_| {
+                                    navigator.push(Route::ImportWallet {});
+                                },
                             }
                         }
                     }
@@ -232,20 +211,24 @@ fn DashboardContent() -> Element {
                     // 选中的钱包余额聚合显示和交易历史
                     if let Some(selected_wallet_id) = &wallet_state.selected_wallet_id {
                         if let Some(selected_wallet) = wallet_state.wallets.iter().find(|w| &w.id == selected_wallet_id) {
-                            BalanceOverview {
+                            // 空钱包的首次充值引导（按钱包保存进度）
+                            FundWalletFlow {
+                                key: "{selected_wallet.id}",
                                 wallet: selected_wallet.clone(),
+                                data: dashboard_data,
                             }
 
-                            // 交易历史预览
-                            TransactionHistoryPreview {
-                                wallet_id: selected_wallet_id.clone(),
-                                accounts: selected_wallet.accounts.clone(),
+                            // 可自定义顺序与显示的小组件（资产概览、最近交易等）
+                            DashboardWidgets {
+                                wallet: selected_wallet.clone(),
+                                data: dashboard_data,
                             }
                         }
                     }
 
                     // 钱包列表
                     div {
+                        id: "dashboard-wallet-list",
                         class: "mb-6 flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4",
                         h2 {
                             class: "text-xl sm:text-2xl font-bold",
@@ -296,21 +279,21 @@ fn WalletCard(wallet: Wallet, is_selected: bool) -> Element {
 
     let wallet_id_clone = wallet.id.clone();
     let handle_select_1 = {
-        let mut app_state = app_state;
         let wallet_id = wallet_id_clone.clone();
         move |_| {
-            let mut wallet_state = app_state.wallet.write();
-            wallet_state.selected_wallet_id = Some(wallet_id.clone());
-            wallet_state.save().ok();
+            let wallet_id = wallet_id.clone();
+            app_state.update_wallet(move |wallet_state| {
+                wallet_state.selected_wallet_id = Some(wallet_id);
+            });
         }
     };
     let handle_select_2 = {
-        let mut app_state = app_state;
         let wallet_id = wallet_id_clone.clone();
         move |_| {
-            let mut wallet_state = app_state.wallet.write();
-            wallet_state.selected_wallet_id = Some(wallet_id.clone());
-            wallet_state.save().ok();
+            let wallet_id = wallet_id.clone();
+            app_state.update_wallet(move |wallet_state| {
+                wallet_state.selected_wallet_id = Some(wallet_id);
+            });
         }
     };
 
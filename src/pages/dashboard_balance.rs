@@ -5,123 +5,30 @@
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
-use crate::components::molecules::ErrorMessage;
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::skeleton::{SkeletonTableRow, SkeletonText};
+use crate::components::molecules::{StaleData, TokenVisibilityManager};
+use crate::features::dashboard::loader::DashboardData;
 use crate::features::wallet::state::Wallet;
 use crate::router::Route;
-use crate::services::balance::BalanceService;
-use crate::services::price::PriceService;
+use crate::services::balance::native_symbol;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
 
-/// 链ID映射（用于API调用）
-///
-/// 注意：此函数当前未使用，但保留用于未来扩展
-#[allow(dead_code)]
-fn get_chain_id(chain: &str) -> u64 {
-    match chain.to_lowercase().as_str() {
-        "ethereum" | "eth" => 1,
-        "bitcoin" | "btc" => 0,
-        "solana" | "sol" => 101,
-        "ton" => 0,
-        _ => 1,
-    }
-}
-
 /// 余额概览组件 - 显示选中钱包的多链余额聚合
+///
+/// 数据由 `use_dashboard_loader` 分阶段加载：余额就绪前显示整体骨架屏，
+/// 价格就绪前法币估值处显示骨架条
 #[component]
-pub fn BalanceOverview(wallet: Wallet) -> Element {
+pub fn BalanceOverview(wallet: Wallet, data: DashboardData) -> Element {
     let app_state = use_context::<AppState>();
     let navigator = use_navigator();
     let t = crate::i18n::use_translation();
 
-    // 余额状态
-    let balances = use_signal(|| std::collections::HashMap::<String, String>::new());
-    let prices = use_signal(|| std::collections::HashMap::<String, f64>::new());
-    let total_usd = use_signal(|| 0.0);
-    let mut is_loading = use_signal(|| true);
-    let error_message = use_signal(|| Option::<String>::None);
-
-    // 自动刷新余额和价格
-    let wallet_clone = wallet.clone();
-    use_effect(move || {
-        let app_state = app_state;
-        // 在闭包内部克隆wallet，避免移动问题
-        let wallet = wallet_clone.clone();
-        let mut balances = balances;
-        let mut prices = prices;
-        let mut total_usd = total_usd;
-        let mut is_loading = is_loading;
-        let mut error_message = error_message;
-
-        spawn(async move {
-            loop {
-                is_loading.set(true);
-                error_message.set(None);
-
-                let balance_service = BalanceService::new(app_state);
-                let price_service = PriceService::new(app_state);
-
-                let mut balance_map = std::collections::HashMap::new();
-                let mut price_map = std::collections::HashMap::new();
-                let mut total = 0.0;
-
-                // 查询所有账户的余额
-                for account in &wallet.accounts {
-                    let chain_id = get_chain_id(&account.chain);
-                    let chain_symbol = match account.chain.to_lowercase().as_str() {
-                        "ethereum" | "eth" => "ETH",
-                        "bitcoin" | "btc" => "BTC",
-                        "solana" | "sol" => "SOL",
-                        "ton" => "TON",
-                        _ => "ETH",
-                    };
-
-                    match balance_service
-                        .get_balance(&account.address, chain_id)
-                        .await
-                    {
-                        Ok(balance_resp) => {
-                            balance_map
-                                .insert(chain_symbol.to_string(), balance_resp.balance.clone());
+    let mut show_token_manager = use_signal(|| false);
 
-                            // 获取价格
-                            match price_service.get_price(chain_symbol).await {
-                                Ok(price) => {
-                                    price_map.insert(chain_symbol.to_string(), price.usd);
-                                    // 计算USD价值（余额需要转换为正确的单位）
-                                    let balance_val: f64 =
-                                        balance_resp.balance.parse().unwrap_or(0.0);
-                                    let usd_value = match chain_symbol {
-                                        "ETH" => balance_val * price.usd / 1e18,
-                                        "BTC" => balance_val * price.usd / 1e8,
-                                        "SOL" => balance_val * price.usd / 1e9,
-                                        "TON" => balance_val * price.usd / 1e9,
-                                        _ => balance_val * price.usd / 1e18,
-                                    };
-                                    total += usd_value;
-                                }
-                                Err(_) => {
-                                    // 价格获取失败，继续
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            log::warn!("Failed to get balance for {}: {}", account.address, e);
-                        }
-                    }
-                }
-
-                balances.set(balance_map);
-                prices.set(price_map);
-                total_usd.set(total);
-                is_loading.set(false);
-
-                // 每30秒刷新一次
-                gloo_timers::future::TimeoutFuture::new(30000).await;
-            }
-        });
-    });
+    let phase = (data.phase)();
 
     rsx! {
         Card {
@@ -139,7 +46,7 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                             span { "💼" }
                             span { {format!("资产概览 - {}", wallet.name.clone())} }
                         }
-                        if !is_loading() {
+                        if phase.prices_ready() && *app_state.is_online.read() {
                             span {
                                 class: "text-xs px-2 py-1 rounded-full",
                                 style: format!("background: {}; color: white;", "rgba(34, 197, 94, 0.8)"),
@@ -149,16 +56,13 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                     }
                 }
 
-                if is_loading() {
+                if !phase.balances_ready() {
                     div {
-                        class: "text-center py-8",
-                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        "正在加载余额..."
-                    }
-                } else if error_message.read().is_some() {
-                    ErrorMessage {
-                        message: error_message.read().clone(),
-                        class: Some("p-4".to_string())
+                        class: "space-y-3 py-2",
+                        SkeletonText { height: 112 }
+                        for account in wallet.accounts.iter() {
+                            SkeletonTableRow { key: "{account.address}", icon: false }
+                        }
                     }
                 } else {
                     // 总资产价值 - 增强视觉
@@ -174,15 +78,37 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                                 "总资产价值"
                             }
                         }
-                        div {
-                            class: "text-4xl font-bold",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {format!("${:.2}", total_usd())}
+                        if phase.prices_ready() {
+                            StaleData {
+                                fetched_at: (data.updated_at)(),
+                                on_refresh: move |_| data.force_refresh(app_state),
+                                div {
+                                    class: "text-4xl font-bold",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    MaskedAmount { value: format!("${:.2}", data.total_usd()) }
+                                }
+                            }
+                        } else {
+                            SkeletonText { width: "12rem".to_string(), height: 40 }
+                        }
+                        if data.stablecoin_usd() > 0.0 {
+                            div {
+                                class: "mt-1 text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "含稳定币 "
+                                MaskedAmount { value: format!("${:.2}", data.stablecoin_usd()) }
+                            }
                         }
                         div {
-                            class: "mt-2 text-xs",
+                            class: "mt-2 flex items-center justify-between text-xs",
                             style: format!("color: {};", Colors::TEXT_TERTIARY),
-                            "≈ 实时汇率，每30秒更新"
+                            span { "≈ 实时汇率，每30秒更新" }
+                            button {
+                                class: "font-medium underline",
+                                style: format!("color: {};", Colors::TECH_PRIMARY),
+                                onclick: move |_| show_token_manager.set(true),
+                                "管理代币"
+                            }
                         }
                     }
 
@@ -193,6 +119,15 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                             div {
                                 class: "flex justify-between items-center p-4 rounded-xl border transition-all hover:scale-[1.01] hover:shadow-md cursor-pointer",
                                 style: format!("background: {}; border-color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                onclick: {
+                                    let chain = account.chain.to_lowercase();
+                                    move |_| {
+                                        navigator.push(Route::TokenDetail {
+                                            chain: chain.clone(),
+                                            address: "native".to_string(),
+                                        });
+                                    }
+                                },
                                 div {
                                     span {
                                         class: "font-semibold",
@@ -211,57 +146,34 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                                         class: "font-semibold",
                                         style: format!("color: {};", Colors::TEXT_PRIMARY),
                                         {
-                                            let account_chain = account.chain.clone();
-                                            let chain_symbol = match account_chain.to_lowercase().as_str() {
-                                                "ethereum" | "eth" => "ETH",
-                                                "bitcoin" | "btc" => "BTC",
-                                                "solana" | "sol" => "SOL",
-                                                "ton" => "TON",
-                                                _ => "ETH",
-                                            };
-                                            let balance = balances.read().get(chain_symbol).cloned().unwrap_or_else(|| "0".to_string());
-                                            let balance_val: f64 = balance.parse().unwrap_or(0.0);
-                                            let display_balance = match chain_symbol {
-                                                "ETH" => balance_val / 1e18,
-                                                "BTC" => balance_val / 1e8,
-                                                "SOL" => balance_val / 1e9,
-                                                "TON" => balance_val / 1e9,
-                                                _ => balance_val / 1e18,
-                                            };
-                                            format!("{:.6} {}", display_balance, chain_symbol)
+                                            let chain_symbol = native_symbol(&account.chain);
+                                            let balance = data
+                                                .native_balance(chain_symbol)
+                                                .map(|b| b.format(6))
+                                                .unwrap_or_else(|| "0".to_string());
+                                            rsx! {
+                                                MaskedAmount { value: balance }
+                                                " {chain_symbol}"
+                                            }
                                         }
                                     }
                                     {
-                                        let account_chain = account.chain.clone();
-                                        let chain_symbol = match account_chain.to_lowercase().as_str() {
-                                            "ethereum" | "eth" => "ETH",
-                                            "bitcoin" | "btc" => "BTC",
-                                            "solana" | "sol" => "SOL",
-                                            "ton" => "TON",
-                                            _ => "ETH",
-                                        };
-                                        let balance = balances.read().get(chain_symbol).cloned().unwrap_or_else(|| "0".to_string());
-                                        let price = prices.read().get(chain_symbol).copied().unwrap_or(0.0);
-                                        if price > 0.0 {
-                                            rsx! {
+                                        let chain_symbol = native_symbol(&account.chain);
+                                        match data.native_usd(chain_symbol) {
+                                            Some(usd_value) => rsx! {
                                                 p {
                                                     class: "text-xs mt-1",
                                                     style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                                    {
-                                                        let balance_val: f64 = balance.parse().unwrap_or(0.0);
-                                                        let usd_value = match chain_symbol {
-                                                            "ETH" => balance_val * price / 1e18,
-                                                            "BTC" => balance_val * price / 1e8,
-                                                            "SOL" => balance_val * price / 1e9,
-                                                            "TON" => balance_val * price / 1e9,
-                                                            _ => balance_val * price / 1e18,
-                                                        };
-                                                        format!("${:.2}", usd_value)
-                                                    }
+                                                    MaskedAmount { value: format!("${:.2}", usd_value) }
                                                 }
-                                            }
-                                        } else {
-                                            rsx! { div {} }
+                                            },
+                                            None if !phase.prices_ready() => rsx! {
+                                                div {
+                                                    class: "mt-1 flex justify-end",
+                                                    SkeletonText { width: "4rem".to_string(), height: 12 }
+                                                }
+                                            },
+                                            None => rsx! { div {} },
                                         }
                                     }
                                 }
@@ -271,6 +183,7 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
 
                     // 快速操作 - 优化：不重复顶部导航，提供更有价值的操作
                     div {
+                        id: "dashboard-quick-actions",
                         class: "mt-6 pt-6 border-t",
                         style: format!("border-color: {};", Colors::BORDER_PRIMARY),
                         div {
@@ -285,7 +198,7 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                                 style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
                                 onclick: move |_| {
                                     // 手动刷新余额
-                                    is_loading.set(true);
+                                    data.refresh();
                                 },
                                 "🔄 刷新余额"
                             }
@@ -346,5 +259,12 @@ pub fn BalanceOverview(wallet: Wallet) -> Element {
                 }
             }
         }
+        TokenVisibilityManager {
+            open: show_token_manager(),
+            on_close: move |_| {
+                show_token_manager.set(false);
+                data.reload_token_prefs();
+            },
+        }
     }
 }
@@ -0,0 +1,312 @@
+//! Dashboard Funding Component
+//! 首次充值引导 - 空钱包的充值入口，以及“选择方式 → 完成充值 → 检测入账”三步流程；
+//! 完成后可选为交易做准备（预先授权兑换路由合约）
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::Card;
+use crate::components::molecules::{
+    EmptyState, LoadFailedState, PrepareTradingPanel, QrCodeDisplay,
+};
+use crate::features::dashboard::first_deposit::{
+    FirstDepositProgress, FirstDepositStep, FundingMethod,
+};
+use crate::features::dashboard::loader::DashboardData;
+use crate::features::wallet::state::Wallet;
+use crate::pages::bridge::Bridge;
+use crate::pages::buy::Buy;
+use crate::router::Route;
+use crate::shared::design_tokens::Colors;
+use dioxus::prelude::*;
+
+/// 首次充值引导
+///
+/// 余额为空时显示充值入口；引导进行中时余额轮询检测到入账即进入完成步骤。
+/// 切换钱包时请以钱包ID作为 key 重新挂载，以读取对应钱包的进度。
+#[component]
+pub fn FundWalletFlow(wallet: Wallet, data: DashboardData) -> Element {
+    let navigator = use_navigator();
+    let t = crate::i18n::use_translation();
+    let wallet_id = wallet.id.clone();
+    let mut progress = use_signal({
+        let wallet_id = wallet_id.clone();
+        move || FirstDepositProgress::load(&wallet_id)
+    });
+    let mut receive_index = use_signal(|| 0usize);
+    // 完成步骤中的可选“为交易做准备”（预先授权）
+    let mut preparing = use_signal(|| false);
+
+    let update = {
+        let wallet_id = wallet_id.clone();
+        move |f: &dyn Fn(&mut FirstDepositProgress)| {
+            let mut current = progress.write();
+            f(&mut current);
+            current.save(&wallet_id);
+        }
+    };
+
+    // 余额轮询（仪表盘每30秒刷新）检测到入账时完成引导
+    use_effect({
+        let wallet_id = wallet_id.clone();
+        move || {
+            let funded = (data.phase)().balances_ready() && data.has_funds();
+            if funded && progress.peek().step == FirstDepositStep::Execute {
+                let mut current = progress.write();
+                if current.observe_balance(true) {
+                    current.save(&wallet_id);
+                }
+            }
+        }
+    });
+
+    if !(data.phase)().balances_ready() {
+        return rsx! {};
+    }
+
+    let current = progress.read().clone();
+
+    // 空钱包入口
+    if !current.is_visible() {
+        if current.finished || data.has_funds() {
+            return rsx! {};
+        }
+        // 余额全部查询失败时不能断定钱包为空，提示重试而非引导充值
+        if !wallet.accounts.is_empty() && data.balances_failed() {
+            return rsx! {
+                div {
+                    class: "mb-6",
+                    LoadFailedState {
+                        title: t("empty.load_failed.title"),
+                        message: t("empty.balances.failed"),
+                        retry_label: t("empty.load_failed.retry"),
+                        on_retry: move |_| data.refresh(),
+                    }
+                }
+            };
+        }
+        return rsx! {
+            Card {
+                variant: crate::components::atoms::card::CardVariant::Base,
+                padding: Some("24px".to_string()),
+                class: Some("mb-6".to_string()),
+                children: rsx! {
+                    EmptyState {
+                        icon: "🚀".to_string(),
+                        title: t("empty.balances.title"),
+                        description: t("empty.balances.description"),
+                        cta_label: t("empty.balances.cta"),
+                        on_cta: {
+                            let mut update = update.clone();
+                            move |_| update(&|p| p.start())
+                        },
+                        secondary_label: t("empty.balances.dismiss"),
+                        on_secondary: {
+                            let mut update = update.clone();
+                            move |_| update(&|p| p.finish())
+                        },
+                    }
+                }
+            }
+        };
+    }
+
+    let step_number = current.step.number();
+    let accounts = wallet.accounts.clone();
+    let receive_account = accounts
+        .get(*receive_index.read())
+        .or(accounts.first())
+        .cloned();
+
+    rsx! {
+        Card {
+            variant: crate::components::atoms::card::CardVariant::Strong,
+            padding: Some("24px".to_string()),
+            class: Some("mb-6".to_string()),
+            children: rsx! {
+                // 标题与步骤进度
+                div {
+                    class: "flex items-center justify-between mb-4",
+                    div {
+                        h3 {
+                            class: "text-lg font-bold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "首次充值"
+                        }
+                        p {
+                            class: "text-xs mt-1",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!("第 {} / 3 步", step_number)}
+                        }
+                    }
+                    button {
+                        class: "text-xl leading-none",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        onclick: {
+                            let mut update = update.clone();
+                            move |_| update(&|p| p.finish())
+                        },
+                        "×"
+                    }
+                }
+                div {
+                    class: "flex gap-2 mb-6",
+                    for n in 1..=3usize {
+                        div {
+                            class: "flex-1 h-1 rounded-full",
+                            style: format!(
+                                "background: {};",
+                                if n <= step_number { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY }
+                            ),
+                        }
+                    }
+                }
+
+                match (current.step, current.method) {
+                    (FirstDepositStep::ChooseMethod, _) | (FirstDepositStep::Execute, None) => rsx! {
+                        div {
+                            class: "grid grid-cols-1 sm:grid-cols-3 gap-3",
+                            for method in FundingMethod::ALL {
+                                button {
+                                    class: "p-4 rounded-xl text-left transition-all hover:scale-[1.02]",
+                                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                    onclick: {
+                                        let mut update = update.clone();
+                                        move |_| update(&move |p| p.choose(method))
+                                    },
+                                    div { class: "text-2xl mb-2", "{method.icon()}" }
+                                    div {
+                                        class: "font-semibold mb-1",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        "{method.label()}"
+                                    }
+                                    div {
+                                        class: "text-xs",
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        "{method.description()}"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    (FirstDepositStep::Execute, Some(method)) => rsx! {
+                        div {
+                            class: "flex items-center justify-between mb-4",
+                            span {
+                                class: "font-semibold",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "{method.icon()} {method.label()}"
+                            }
+                            button {
+                                class: "text-sm",
+                                style: format!("color: {};", Colors::TECH_PRIMARY),
+                                onclick: {
+                                    let mut update = update.clone();
+                                    move |_| update(&|p| p.back())
+                                },
+                                "← 更换方式"
+                            }
+                        }
+                        match method {
+                            FundingMethod::Buy => rsx! { Buy {} },
+                            FundingMethod::Bridge => rsx! { Bridge {} },
+                            FundingMethod::Receive => rsx! {
+                                div {
+                                    class: "flex gap-2 flex-wrap mb-4",
+                                    for (idx, account) in accounts.iter().enumerate() {
+                                        button {
+                                            class: "px-3 py-1 text-sm rounded-full",
+                                            style: format!(
+                                                "background: {}; color: {}; border: 1px solid {};",
+                                                if receive_account.as_ref().map(|a| &a.address) == Some(&account.address) { Colors::TECH_PRIMARY } else { Colors::BG_PRIMARY },
+                                                Colors::TEXT_PRIMARY,
+                                                Colors::BORDER_PRIMARY
+                                            ),
+                                            onclick: move |_| receive_index.set(idx),
+                                            "{account.chain_label()}"
+                                        }
+                                    }
+                                }
+                                if let Some(account) = receive_account.clone() {
+                                    p {
+                                        class: "text-xs mb-3",
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        {format!("仅向此地址转入 {} 网络的资产，其他网络的资产可能无法找回。", account.chain_label())}
+                                    }
+                                    QrCodeDisplay { address: account.address.clone(), show_copy_button: Some(true) }
+                                }
+                            },
+                        }
+                        div {
+                            class: "mt-4 p-3 rounded-lg flex items-center justify-between gap-3 text-sm",
+                            style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
+                            span { "⏳ 正在等待入账，余额每30秒自动刷新" }
+                            button {
+                                class: "text-xs px-3 py-1 rounded-full",
+                                style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                onclick: move |_| data.refresh(),
+                                "立即检查"
+                            }
+                        }
+                    },
+                    (FirstDepositStep::Complete, _) => rsx! {
+                        div {
+                            class: "text-center py-4",
+                            div { class: "text-5xl mb-3", "🎉" }
+                            h3 {
+                                class: "text-xl font-bold mb-2",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "充值已到账！"
+                            }
+                            p {
+                                class: "text-sm mb-6",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "您的钱包已准备就绪。试试第一次兑换，把资产换成您需要的代币。"
+                            }
+                            if preparing() {
+                                div {
+                                    class: "mb-6 p-4 rounded-xl",
+                                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                    PrepareTradingPanel {
+                                        wallet: wallet.clone(),
+                                        on_done: move |_| preparing.set(false),
+                                    }
+                                }
+                            }
+                            div {
+                                class: "flex gap-3 justify-center flex-wrap",
+                                Button {
+                                    variant: ButtonVariant::Primary,
+                                    size: ButtonSize::Medium,
+                                    onclick: {
+                                        let mut update = update.clone();
+                                        move |_| {
+                                            update(&|p| p.finish());
+                                            navigator.push(Route::Swap {});
+                                        }
+                                    },
+                                    "去兑换"
+                                }
+                                if !preparing() {
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        size: ButtonSize::Medium,
+                                        onclick: move |_| preparing.set(true),
+                                        "为交易做准备"
+                                    }
+                                }
+                                Button {
+                                    variant: ButtonVariant::Secondary,
+                                    size: ButtonSize::Medium,
+                                    onclick: {
+                                        let mut update = update.clone();
+                                        move |_| update(&|p| p.finish())
+                                    },
+                                    "完成"
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
@@ -4,7 +4,15 @@
 #![allow(clippy::redundant_closure)]
 
 use crate::components::atoms::card::Card;
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::skeleton::SkeletonTableRow;
+use crate::components::molecules::{EmptyState, LoadFailedState};
+use crate::features::dashboard::loader::DashboardLoadPhase;
+use crate::features::wallet::address_poisoning::PoisoningIndex;
+use crate::features::wallet::fee_bump::collapse_replacements;
+use crate::features::wallet::internal_transfer;
 use crate::features::wallet::state::Account;
+use crate::pages::token_detail::{tx_token_route, TokenLink};
 use crate::router::Route;
 use crate::services::transaction::{TransactionHistoryItem, TransactionService};
 use crate::shared::design_tokens::Colors;
@@ -12,24 +20,54 @@ use crate::shared::state::AppState;
 use dioxus::prelude::*;
 
 /// 交易历史预览组件
+///
+/// 属于次要数据：等待仪表盘关键数据（余额）就绪后才开始加载
 #[component]
-pub fn TransactionHistoryPreview(wallet_id: String, accounts: Vec<Account>) -> Element {
+pub fn TransactionHistoryPreview(
+    wallet_id: String,
+    accounts: Vec<Account>,
+    phase: Signal<DashboardLoadPhase>,
+) -> Element {
     let app_state = use_context::<AppState>();
     let navigator = use_navigator();
 
+    let t = crate::i18n::use_translation();
+
     let transactions = use_signal(|| Vec::<TransactionHistoryItem>::new());
     let is_loading = use_signal(|| true);
+    let row_accounts = accounts.clone();
+    // 所有账户的查询都失败时视为加载失败（区别于确实没有交易）
+    let load_failed = use_signal(|| false);
+    let mut has_started = use_signal(|| false);
+    // 手动重试计数，变化时重新加载
+    let mut reload = use_signal(|| 0u32);
+    // 自有地址与已标记的内部转账（用于标注内部转账）
+    let internal_refs = use_memo(move || {
+        (
+            internal_transfer::owned_addresses(&app_state.wallet.read().wallets),
+            internal_transfer::tagged_hashes(),
+        )
+    });
 
     use_effect(move || {
+        let _ = reload();
+        if !phase().balances_ready() || *has_started.peek() {
+            return;
+        }
+        has_started.set(true);
+
         let app_state = app_state;
         let accounts = accounts.clone();
         let mut transactions = transactions;
         let mut is_loading = is_loading;
+        let mut load_failed = load_failed;
 
         spawn(async move {
             is_loading.set(true);
+            load_failed.set(false);
             let tx_service = TransactionService::new(app_state);
             let mut all_txs = Vec::new();
+            let mut failures = 0;
 
             // 查询所有账户的交易历史
             for account in &accounts {
@@ -42,14 +80,20 @@ pub fn TransactionHistoryPreview(wallet_id: String, accounts: Vec<Account>) -> E
                     }
                     Err(_) => {
                         // 忽略错误，继续查询其他账户
+                        failures += 1;
                     }
                 }
             }
 
-            // 按时间戳排序（最新的在前），只取前5条
-            all_txs.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+            // 按时间戳排序（最新的在前），隐藏疑似地址投毒的转入后只取前5条
+            all_txs.sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+            let owned = internal_transfer::owned_addresses(&app_state.wallet.peek().wallets);
+            let poisoning = PoisoningIndex::sync(&all_txs, &owned);
+            let mut all_txs = collapse_replacements(all_txs);
+            all_txs.retain(|tx| !poisoning.is_suspected(tx));
             all_txs.truncate(5);
 
+            load_failed.set(!accounts.is_empty() && failures == accounts.len());
             transactions.set(all_txs);
             is_loading.set(false);
         });
@@ -82,15 +126,29 @@ pub fn TransactionHistoryPreview(wallet_id: String, accounts: Vec<Account>) -> E
 
                 if is_loading() {
                     div {
-                        class: "text-center py-8",
-                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        "正在加载交易历史..."
+                        class: "space-y-3",
+                        for i in 0..3 {
+                            SkeletonTableRow { key: "{i}" }
+                        }
+                    }
+                } else if load_failed() {
+                    LoadFailedState {
+                        title: t("empty.history.failed"),
+                        retry_label: t("empty.load_failed.retry"),
+                        on_retry: move |_| {
+                            has_started.set(false);
+                            reload += 1;
+                        },
                     }
                 } else if transactions.read().is_empty() {
-                    div {
-                        class: "text-center py-8",
-                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        "暂无交易记录"
+                    EmptyState {
+                        icon: "🔄".to_string(),
+                        title: t("empty.history.title"),
+                        description: t("empty.history.description"),
+                        cta_label: t("empty.history.cta"),
+                        on_cta: move |_| {
+                            navigator.push(Route::Swap {});
+                        },
                     }
                 } else {
                     div {
@@ -98,6 +156,11 @@ pub fn TransactionHistoryPreview(wallet_id: String, accounts: Vec<Account>) -> E
                         for tx in transactions.read().iter() {
                             TransactionRowPreview {
                                 transaction: tx.clone(),
+                                token_route: tx_token_route(&row_accounts, tx),
+                                internal: {
+                                    let (owned, tagged) = &*internal_refs.read();
+                                    internal_transfer::is_internal(tx, owned, tagged)
+                                },
                             }
                         }
                     }
@@ -109,7 +172,11 @@ pub fn TransactionHistoryPreview(wallet_id: String, accounts: Vec<Account>) -> E
 
 /// 交易行预览组件（简化版）
 #[component]
-fn TransactionRowPreview(transaction: TransactionHistoryItem) -> Element {
+fn TransactionRowPreview(
+    transaction: TransactionHistoryItem,
+    token_route: Option<Route>,
+    internal: bool,
+) -> Element {
     let status_color = match transaction.status.to_lowercase().as_str() {
         "confirmed" => Colors::PAYMENT_SUCCESS,
         "pending" => Colors::PAYMENT_WARNING,
@@ -118,6 +185,7 @@ fn TransactionRowPreview(transaction: TransactionHistoryItem) -> Element {
     };
 
     let tx_type_icon = match transaction.tx_type.to_lowercase().as_str() {
+        _ if internal => "🔁",
         "send" => "📤",
         "receive" => "📥",
         _ => "📋",
@@ -138,6 +206,13 @@ fn TransactionRowPreview(transaction: TransactionHistoryItem) -> Element {
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
                             {transaction.tx_type.clone()}
                         }
+                        if internal {
+                            span {
+                                class: "text-xs ml-2 px-2 py-0.5 rounded",
+                                style: format!("background: {}; color: {};", Colors::BG_PRIMARY, Colors::TEXT_SECONDARY),
+                                "内部转账"
+                            }
+                        }
                         p {
                             class: "text-xs font-mono mt-1",
                             style: format!("color: {};", Colors::TEXT_TERTIARY),
@@ -150,7 +225,9 @@ fn TransactionRowPreview(transaction: TransactionHistoryItem) -> Element {
                     div {
                         class: "font-semibold text-sm",
                         style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        {transaction.amount.clone()} " {transaction.token.clone()}"
+                        MaskedAmount { value: transaction.amount.clone() }
+                        " "
+                        TokenLink { symbol: transaction.token.clone(), route: token_route }
                     }
                     span {
                         class: "text-xs px-2 py-1 rounded mt-1 inline-block",
@@ -0,0 +1,467 @@
+//! Dashboard Watchlist Component
+//! 价格关注小组件 - 关注代币的实时价格、24 小时涨跌与走势，点击查看详情并快捷买入/兑换
+
+use crate::components::atoms::card::Card;
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::atoms::modal::Modal;
+use crate::components::atoms::skeleton::SkeletonText;
+use crate::components::molecules::{
+    PriceChangeIndicator, PriceChangeInfo, PriceChart, PriceDataPoint, Sparkline,
+};
+use crate::features::dashboard::watchlist::{self, Watchlist, WatchlistEntry, MAX_WATCHLIST};
+use crate::features::dashboard::widgets::DashboardWidget;
+use crate::features::swap::history::{self, SwapRetryRequest};
+use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::services::price::PriceService;
+use crate::services::token::TokenService;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 价格刷新间隔（价格服务自带 5 分钟缓存，过期后才会真正请求后端）
+const WATCHLIST_REFRESH_MS: u32 = 60_000;
+/// 列表行走势线的时间范围（小时）
+const SPARKLINE_HOURS: u32 = 24;
+/// 详情图表可选的时间范围（小时）
+const DETAIL_RANGES: [(u32, &str); 3] = [(24, "24小时"), (168, "7天"), (720, "30天")];
+/// 可搜索添加的链
+const SEARCH_CHAINS: [ChainType; 6] = [
+    ChainType::Ethereum,
+    ChainType::BSC,
+    ChainType::Polygon,
+    ChainType::Solana,
+    ChainType::Bitcoin,
+    ChainType::TON,
+];
+
+/// 以首尾两个采样点计算区间涨跌
+pub(crate) fn change_info(points: &[PriceDataPoint]) -> Option<PriceChangeInfo> {
+    let (first, last) = (points.first()?, points.last()?);
+    Some(PriceChangeInfo::new(
+        first.price,
+        last.price,
+        last.timestamp,
+    ))
+}
+
+pub(crate) fn format_price(price: f64) -> String {
+    if price >= 1.0 {
+        format!("${:.2}", price)
+    } else {
+        format!("${:.6}", price)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+}
+
+/// 价格关注小组件
+#[component]
+pub fn PriceWatchlistWidget() -> Element {
+    let app_state = use_context::<AppState>();
+    let user_id = app_state.user.read().user_id.clone().unwrap_or_default();
+
+    let mut watchlist = use_signal(|| Option::<Watchlist>::None);
+    let mut adding = use_signal(|| false);
+    let mut selected = use_signal(|| Option::<WatchlistEntry>::None);
+    let mut refresh_tick = use_signal(|| 0u32);
+
+    // 读取当前用户的关注列表
+    let load_user_id = user_id.clone();
+    use_future(move || {
+        let user_id = load_user_id.clone();
+        async move {
+            watchlist.set(Some(watchlist::load(&user_id).await));
+        }
+    });
+
+    // 定时刷新价格
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(WATCHLIST_REFRESH_MS).await;
+            refresh_tick += 1;
+        }
+    });
+
+    // 当前价格与 24 小时走势（按符号）
+    let quotes = use_resource(move || async move {
+        let _ = refresh_tick();
+        let symbols = watchlist.read().as_ref().map(Watchlist::symbols)?;
+        let symbol_refs: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        let prices = PriceService::new(app_state)
+            .get_prices(&symbol_refs)
+            .await
+            .unwrap_or_default();
+        let mut histories: HashMap<String, Vec<PriceDataPoint>> = HashMap::new();
+        for symbol in &symbols {
+            match PriceService::new(app_state)
+                .get_price_history(symbol, SPARKLINE_HOURS)
+                .await
+            {
+                Ok(points) => {
+                    histories.insert(
+                        symbol.clone(),
+                        points.into_iter().map(PriceDataPoint::from).collect(),
+                    );
+                }
+                Err(e) => log::warn!("获取 {} 价格走势失败: {}", symbol, e),
+            }
+        }
+        Some((prices, histories))
+    });
+
+    let persist = move |next: Watchlist| {
+        let mut watchlist = watchlist;
+        watchlist.set(Some(next.clone()));
+        let user_id = user_id.clone();
+        spawn(async move {
+            if let Err(e) = watchlist::save(&user_id, &next).await {
+                log::warn!("保存关注列表失败: {}", e);
+            }
+        });
+    };
+    let persist_add = persist.clone();
+
+    let entries = watchlist
+        .read()
+        .as_ref()
+        .map(|w| w.entries.clone())
+        .unwrap_or_default();
+    let quote_data = quotes.read().clone().flatten();
+
+    rsx! {
+        Card {
+            variant: crate::components::atoms::card::CardVariant::Base,
+            padding: Some("24px".to_string()),
+            class: Some("mb-6".to_string()),
+            children: rsx! {
+                div {
+                    class: "flex justify-between items-center mb-4",
+                    h2 {
+                        class: "text-xl font-bold",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {DashboardWidget::PriceWatchlist.label()}
+                    }
+                    button {
+                        class: "text-xs px-3 py-1 rounded-full",
+                        style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY),
+                        onclick: move |_| adding.set(!adding()),
+                        if adding() { "完成" } else { "＋ 添加代币" }
+                    }
+                }
+
+                if adding() {
+                    if let Some(list) = watchlist.read().clone() {
+                        WatchlistSearch {
+                            watchlist: list,
+                            on_add: move |entry: WatchlistEntry| {
+                                let mut next = watchlist.peek().clone().unwrap_or_default();
+                                if next.add(entry) {
+                                    persist_add(next);
+                                }
+                            },
+                        }
+                    }
+                }
+
+                if watchlist.read().is_none() || (quote_data.is_none() && !entries.is_empty()) {
+                    div {
+                        class: "space-y-3",
+                        for i in 0..3 {
+                            SkeletonText { key: "{i}", width: "100%".to_string(), height: 16 }
+                        }
+                    }
+                } else if entries.is_empty() {
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "还没有关注的代币，点击右上角添加"
+                    }
+                } else {
+                    div {
+                        class: "space-y-2",
+                        for entry in entries {
+                            {
+                                let symbol = entry.symbol.to_uppercase();
+                                let (price, history) = quote_data
+                                    .as_ref()
+                                    .map(|(prices, histories)| {
+                                        (
+                                            prices.get(&symbol).map(|p| p.usd),
+                                            histories.get(&symbol).cloned().unwrap_or_default(),
+                                        )
+                                    })
+                                    .unwrap_or_default();
+                                let row_entry = entry.clone();
+                                rsx! {
+                                    div {
+                                        key: "{entry.key()}",
+                                        class: "flex items-center justify-between gap-3 p-3 rounded-lg cursor-pointer",
+                                        style: format!("background: {};", Colors::BG_SECONDARY),
+                                        onclick: move |_| selected.set(Some(row_entry.clone())),
+                                        div {
+                                            class: "min-w-0",
+                                            div {
+                                                class: "text-sm font-semibold",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                {entry.symbol.clone()}
+                                            }
+                                            div {
+                                                class: "text-xs truncate",
+                                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                                {format!("{} · {}", entry.name, entry.chain.label())}
+                                            }
+                                        }
+                                        Sparkline { data: history.clone() }
+                                        div {
+                                            class: "text-right",
+                                            div {
+                                                class: "text-sm font-semibold",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                {price.map(format_price).unwrap_or_else(|| "--".to_string())}
+                                            }
+                                            PriceChangeIndicator {
+                                                change_info: change_info(&history),
+                                                compact: true,
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(entry) = selected() {
+            WatchlistDetail {
+                entry,
+                on_close: move |_| selected.set(None),
+                on_remove: move |key: String| {
+                    let mut next = watchlist.peek().clone().unwrap_or_default();
+                    next.remove(&key);
+                    persist(next);
+                    selected.set(None);
+                },
+            }
+        }
+    }
+}
+
+/// 搜索并添加关注代币（代币列表来自 TokenService）
+#[component]
+fn WatchlistSearch(watchlist: Watchlist, on_add: EventHandler<WatchlistEntry>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut chain = use_signal(|| ChainType::Ethereum);
+    let mut query = use_signal(String::new);
+
+    let tokens = use_resource(move || async move {
+        TokenService::new(app_state)
+            .get_token_list(chain())
+            .await
+            .unwrap_or_default()
+    });
+
+    let keyword = query.read().trim().to_lowercase();
+    let results: Vec<WatchlistEntry> = tokens
+        .read()
+        .clone()
+        .unwrap_or_default()
+        .iter()
+        .filter(|t| {
+            keyword.is_empty()
+                || t.symbol.to_lowercase().contains(&keyword)
+                || t.name.to_lowercase().contains(&keyword)
+        })
+        .take(8)
+        .map(WatchlistEntry::from)
+        .collect();
+    let is_full = watchlist.entries.len() >= MAX_WATCHLIST;
+
+    rsx! {
+        div {
+            class: "mb-4 p-3 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex flex-wrap gap-2",
+                for option in SEARCH_CHAINS {
+                    button {
+                        key: "{option.as_str()}",
+                        class: "px-3 py-1 rounded text-xs",
+                        style: if option == chain() {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                        },
+                        onclick: move |_| chain.set(option),
+                        {option.label()}
+                    }
+                }
+            }
+            Input {
+                input_type: InputType::Search,
+                placeholder: Some("搜索代币名称或符号".to_string()),
+                value: Some(query.read().clone()),
+                onchange: Some(EventHandler::new(move |e: FormEvent| query.set(e.value()))),
+            }
+            if is_full {
+                p {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::PAYMENT_WARNING),
+                    {format!("最多关注 {} 个代币", MAX_WATCHLIST)}
+                }
+            }
+            if tokens.read().is_none() {
+                SkeletonText { width: "100%".to_string(), height: 16 }
+            } else if results.is_empty() {
+                p {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "未找到匹配的代币"
+                }
+            } else {
+                div {
+                    class: "space-y-1",
+                    for entry in results {
+                        {
+                            let added = watchlist.contains(&entry.key());
+                            let add_entry = entry.clone();
+                            rsx! {
+                                div {
+                                    key: "{entry.key()}",
+                                    class: "flex items-center justify-between text-sm",
+                                    span {
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {format!("{} · {}", entry.symbol, entry.name)}
+                                    }
+                                    button {
+                                        class: "px-2 py-1 text-xs rounded disabled:opacity-40",
+                                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                                        disabled: added || is_full,
+                                        onclick: move |_| on_add.call(add_entry.clone()),
+                                        if added { "已关注" } else { "＋ 关注" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 关注代币详情：完整价格图表与快捷买入/兑换
+#[component]
+fn WatchlistDetail(
+    entry: WatchlistEntry,
+    on_close: EventHandler<()>,
+    on_remove: EventHandler<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let mut hours = use_signal(|| DETAIL_RANGES[0].0);
+
+    let symbol = entry.symbol.to_uppercase();
+    let history_symbol = symbol.clone();
+    let chart_data = use_resource(move || {
+        let symbol = history_symbol.clone();
+        async move {
+            PriceService::new(app_state)
+                .get_price_history(&symbol, hours())
+                .await
+                .map(|points| {
+                    points
+                        .into_iter()
+                        .map(PriceDataPoint::from)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        }
+    });
+
+    // 跳转兑换页并预填参数（买入：USDT → 该代币；兑换：该代币 → USDT）
+    let open_swap = move |from_token: String, to_token: String| {
+        history::set_pending_prefill(&SwapRetryRequest {
+            from_token,
+            to_token,
+            amount: String::new(),
+            network: Some(entry.chain.as_str().to_string()),
+        });
+        navigator.push(Route::Swap {});
+    };
+    let buy_symbol = symbol.clone();
+    let swap_symbol = symbol.clone();
+    let remove_key = entry.key();
+
+    rsx! {
+        Modal {
+            open: true,
+            onclose: move |_| on_close.call(()),
+            title: Some(format!("{} · {}", entry.symbol, entry.name)),
+            div {
+                class: "space-y-4",
+                div {
+                    class: "flex gap-2",
+                    for (range, label) in DETAIL_RANGES {
+                        button {
+                            key: "{range}",
+                            class: "px-3 py-1 rounded text-xs",
+                            style: if range == hours() {
+                                format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                            } else {
+                                format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                            },
+                            onclick: move |_| hours.set(range),
+                            {label}
+                        }
+                    }
+                }
+                PriceChart {
+                    token_symbol: symbol.clone(),
+                    data: chart_data.read().clone().unwrap_or_default(),
+                    time_range_hours: Some(hours()),
+                    loading: chart_data.read().is_none(),
+                }
+                div {
+                    class: "grid grid-cols-2 gap-3",
+                    button {
+                        class: "py-2 rounded-lg text-sm font-semibold",
+                        style: format!("background: {}; color: white;", Colors::PAYMENT_SUCCESS),
+                        onclick: move |_| open_swap("USDT".to_string(), buy_symbol.clone()),
+                        {format!("买入 {}", entry.symbol)}
+                    }
+                    button {
+                        class: "py-2 rounded-lg text-sm font-semibold",
+                        style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                        onclick: move |_| open_swap(swap_symbol.clone(), "USDT".to_string()),
+                        {format!("兑换 {}", entry.symbol)}
+                    }
+                }
+                div {
+                    class: "flex justify-center gap-6",
+                    button {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                        onclick: move |_| {
+                            navigator.push(Route::TokenDetail {
+                                chain: entry.chain.as_str().to_string(),
+                                address: entry.address.clone(),
+                            });
+                        },
+                        "查看代币详情"
+                    }
+                    button {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        onclick: move |_| on_remove.call(remove_key.clone()),
+                        "取消关注"
+                    }
+                }
+            }
+        }
+    }
+}
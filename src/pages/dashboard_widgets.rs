@@ -0,0 +1,482 @@
+//! Dashboard Widgets Component
+//! 仪表盘小组件区 - 按用户布局渲染各区块，支持编辑模式调整顺序与显示/隐藏
+
+use crate::components::atoms::card::Card;
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::skeleton::{SkeletonTableRow, SkeletonText};
+use crate::components::molecules::order_tracking::OrderStatus;
+use crate::components::molecules::StablecoinBalanceCard;
+use crate::features::activity::labels;
+use crate::features::dashboard::loader::DashboardData;
+use crate::features::dashboard::widgets::{DashboardWidget, WidgetLayout};
+use crate::features::wallet::internal_transfer;
+use crate::features::wallet::state::{Account, Wallet};
+use crate::pages::dashboard_balance::BalanceOverview;
+use crate::pages::dashboard_transactions::TransactionHistoryPreview;
+use crate::pages::dashboard_watchlist::PriceWatchlistWidget;
+use crate::router::Route;
+use crate::services::fiat_onramp::{FiatOnrampService, FiatOrderStatus};
+use crate::services::price::PriceService;
+use crate::services::transaction::TransactionService;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 仪表盘小组件区
+///
+/// 未自定义布局时按钱包是否有资产选择默认布局；编辑后写入用户偏好设置
+#[component]
+pub fn DashboardWidgets(wallet: Wallet, data: DashboardData) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut editing = use_signal(|| false);
+
+    let layout = app_state
+        .preferences
+        .read()
+        .dashboard_widgets
+        .clone()
+        .map(WidgetLayout::normalized)
+        .unwrap_or_else(|| WidgetLayout::default_for(data.has_funds()));
+
+    let update_layout = move |change: &dyn Fn(&mut WidgetLayout)| {
+        let mut preferences = app_state.preferences;
+        let mut prefs = preferences.write();
+        let mut next = prefs
+            .dashboard_widgets
+            .clone()
+            .map(WidgetLayout::normalized)
+            .unwrap_or_else(|| WidgetLayout::default_for(data.has_funds()));
+        change(&mut next);
+        prefs.dashboard_widgets = Some(next);
+        prefs.save();
+    };
+
+    rsx! {
+        div {
+            class: "mb-4 flex justify-end",
+            button {
+                class: "text-xs px-3 py-1 rounded-full",
+                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY),
+                onclick: move |_| editing.set(!editing()),
+                if editing() { "✓ 完成" } else { "⚙ 自定义仪表盘" }
+            }
+        }
+
+        if editing() {
+            Card {
+                variant: crate::components::atoms::card::CardVariant::Base,
+                padding: Some("16px".to_string()),
+                class: Some("mb-6".to_string()),
+                children: rsx! {
+                    div {
+                        class: "flex items-center justify-between mb-3",
+                        h3 {
+                            class: "text-base font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "仪表盘布局"
+                        }
+                        button {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            onclick: move |_| {
+                                let mut preferences = app_state.preferences;
+                                let mut prefs = preferences.write();
+                                prefs.dashboard_widgets = None;
+                                prefs.save();
+                            },
+                            "恢复默认"
+                        }
+                    }
+                    div {
+                        class: "space-y-2",
+                        for (index, widget) in layout.order.iter().copied().enumerate() {
+                            div {
+                                key: "{widget:?}",
+                                class: "flex items-center justify-between gap-2 p-2 rounded-lg",
+                                style: format!("background: {};", Colors::BG_SECONDARY),
+                                span {
+                                    class: "text-sm",
+                                    style: format!(
+                                        "color: {};",
+                                        if layout.is_hidden(widget) { Colors::TEXT_TERTIARY } else { Colors::TEXT_PRIMARY }
+                                    ),
+                                    {widget.label()}
+                                }
+                                div {
+                                    class: "flex items-center gap-1",
+                                    button {
+                                        class: "px-2 py-1 text-xs rounded disabled:opacity-30",
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        disabled: index == 0,
+                                        aria_label: "上移",
+                                        onclick: move |_| update_layout(&|l| l.move_up(widget)),
+                                        "↑"
+                                    }
+                                    button {
+                                        class: "px-2 py-1 text-xs rounded disabled:opacity-30",
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        disabled: index + 1 == layout.order.len(),
+                                        aria_label: "下移",
+                                        onclick: move |_| update_layout(&|l| l.move_down(widget)),
+                                        "↓"
+                                    }
+                                    {
+                                        let hidden = layout.is_hidden(widget);
+                                        rsx! {
+                                            button {
+                                                class: "px-2 py-1 text-xs rounded",
+                                                style: format!("color: {};", Colors::TECH_PRIMARY),
+                                                onclick: move |_| update_layout(&|l| l.set_hidden(widget, !hidden)),
+                                                if hidden { "显示" } else { "隐藏" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for widget in layout.visible() {
+            div {
+                key: "{widget:?}",
+                id: if widget == DashboardWidget::BalanceSummary { "dashboard-balance-overview" },
+                WidgetView { widget, wallet: wallet.clone(), data }
+            }
+        }
+    }
+}
+
+/// 渲染单个小组件
+#[component]
+fn WidgetView(widget: DashboardWidget, wallet: Wallet, data: DashboardData) -> Element {
+    let navigator = use_navigator();
+    match widget {
+        DashboardWidget::BalanceSummary => rsx! {
+            BalanceOverview { wallet, data }
+        },
+        DashboardWidget::PortfolioChart => rsx! {
+            PortfolioChartWidget { data }
+        },
+        DashboardWidget::StablecoinCard => rsx! {
+            StablecoinBalanceCard {
+                show_buy_button: Some(true),
+                on_buy_click: move |_| {
+                    navigator.push(Route::Buy {});
+                },
+            }
+        },
+        DashboardWidget::RecentTransactions => rsx! {
+            TransactionHistoryPreview {
+                wallet_id: wallet.id.clone(),
+                accounts: wallet.accounts.clone(),
+                phase: data.phase,
+            }
+        },
+        DashboardWidget::PendingOrders => rsx! {
+            PendingOrdersWidget {}
+        },
+        DashboardWidget::PriceWatchlist => rsx! {
+            PriceWatchlistWidget {}
+        },
+        DashboardWidget::SpendByCategory => rsx! {
+            SpendByCategoryWidget { accounts: wallet.accounts.clone() }
+        },
+    }
+}
+
+/// 小组件标题
+#[component]
+fn WidgetTitle(title: String) -> Element {
+    rsx! {
+        h2 {
+            class: "text-xl font-bold mb-4",
+            style: format!("color: {};", Colors::TEXT_PRIMARY),
+            {title}
+        }
+    }
+}
+
+/// 资产分布：各资产占总价值的比例条
+#[component]
+fn PortfolioChartWidget(data: DashboardData) -> Element {
+    let phase = (data.phase)();
+    let total = data.total_usd();
+    let mut slices: Vec<(String, f64)> = data
+        .portfolio
+        .read()
+        .native_symbols()
+        .into_iter()
+        .filter_map(|symbol| data.native_usd(&symbol).map(|usd| (symbol, usd)))
+        .filter(|(_, usd)| *usd > 0.0)
+        .collect();
+    if data.stablecoin_usd() > 0.0 {
+        slices.push(("USDT/USDC".to_string(), data.stablecoin_usd()));
+    }
+    slices.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    rsx! {
+        Card {
+            variant: crate::components::atoms::card::CardVariant::Base,
+            padding: Some("24px".to_string()),
+            class: Some("mb-6".to_string()),
+            children: rsx! {
+                WidgetTitle { title: DashboardWidget::PortfolioChart.label().to_string() }
+                if !phase.prices_ready() {
+                    div {
+                        class: "space-y-3",
+                        for i in 0..3 {
+                            SkeletonText { key: "{i}", width: "100%".to_string(), height: 16 }
+                        }
+                    }
+                } else if slices.is_empty() || total <= 0.0 {
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "暂无资产"
+                    }
+                } else {
+                    div {
+                        class: "space-y-3",
+                        for (symbol, usd) in slices {
+                            div {
+                                key: "{symbol}",
+                                div {
+                                    class: "flex justify-between text-sm mb-1",
+                                    span {
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {symbol.clone()}
+                                    }
+                                    span {
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        MaskedAmount { value: format!("${:.2}", usd) }
+                                        {format!(" · {:.1}%", usd / total * 100.0)}
+                                    }
+                                }
+                                div {
+                                    class: "h-2 rounded-full overflow-hidden",
+                                    style: format!("background: {};", Colors::BG_SECONDARY),
+                                    div {
+                                        class: "h-full rounded-full",
+                                        style: format!("width: {:.1}%; background: {};", usd / total * 100.0, Colors::TECH_PRIMARY),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 进行中的法币订单（最多5条）
+#[component]
+fn PendingOrdersWidget() -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+
+    let orders = use_resource(move || async move {
+        FiatOnrampService::new(app_state)
+            .get_orders(None, Some(1), Some(20), Some("created_at"), Some("desc"))
+            .await
+            .map(|resp| {
+                resp.orders
+                    .into_iter()
+                    .filter(|o| {
+                        matches!(
+                            OrderStatus::from_str(&o.status),
+                            OrderStatus::Pending | OrderStatus::Processing
+                        )
+                    })
+                    .take(5)
+                    .collect::<Vec<FiatOrderStatus>>()
+            })
+    });
+
+    rsx! {
+        Card {
+            variant: crate::components::atoms::card::CardVariant::Base,
+            padding: Some("24px".to_string()),
+            class: Some("mb-6".to_string()),
+            children: rsx! {
+                WidgetTitle { title: DashboardWidget::PendingOrders.label().to_string() }
+                match &*orders.read() {
+                    None => rsx! {
+                        div {
+                            class: "space-y-3",
+                            for i in 0..2 {
+                                SkeletonTableRow { key: "{i}" }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {crate::shared::ui_error::sanitize_user_message(e.clone())}
+                        }
+                    },
+                    Some(Ok(list)) if list.is_empty() => rsx! {
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            "没有进行中的订单"
+                        }
+                    },
+                    Some(Ok(list)) => rsx! {
+                        div {
+                            class: "space-y-2",
+                            for order in list.clone() {
+                                {
+                                    let status = OrderStatus::from_str(&order.status);
+                                    rsx! {
+                                        div {
+                                            key: "{order.order_id}",
+                                            class: "flex justify-between items-center p-3 rounded-lg cursor-pointer",
+                                            style: format!("background: {};", Colors::BG_SECONDARY),
+                                            onclick: move |_| {
+                                                navigator.push(Route::Orders {});
+                                            },
+                                            span {
+                                                class: "text-sm",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                "充值 "
+                                                MaskedAmount { value: order.fiat_amount.clone() }
+                                            }
+                                            span {
+                                                class: "text-xs px-2 py-1 rounded",
+                                                style: format!("background: {}; color: {};", status.bg_color(), status.color()),
+                                                {status.label()}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// 本月分类支出：已标记分类的转出交易按当前价格折算为偏好法币
+#[component]
+fn SpendByCategoryWidget(accounts: Vec<Account>) -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let currency = app_state.preferences.read().currency.clone();
+    let currency_symbol = currency.symbol();
+
+    let spend = use_resource(use_reactive!(|accounts| async move {
+        let user_id = app_state.user.peek().user_id.clone().unwrap_or_default();
+        let tx_labels = labels::load(&user_id).await;
+        let tx_service = TransactionService::new(app_state);
+        let mut txs = Vec::new();
+        for account in &accounts {
+            if let Ok(history) = tx_service
+                .get_history(&account.address, &account.chain)
+                .await
+            {
+                txs.extend(history);
+            }
+        }
+        // 自有账户之间的内部转账只是挪动资金，不计入支出
+        let owned = internal_transfer::owned_addresses(&app_state.wallet.peek().wallets);
+        let tagged = internal_transfer::tagged_hashes();
+        txs.retain(|tx| !internal_transfer::is_internal(tx, &owned, &tagged));
+
+        let mut symbols: Vec<String> = txs.iter().map(|tx| tx.token.to_uppercase()).collect();
+        symbols.sort();
+        symbols.dedup();
+        let symbol_refs: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        let currency = app_state.preferences.peek().currency.clone();
+        let rate = PriceService::new(app_state)
+            .get_fiat_rate(currency.code())
+            .await
+            .unwrap_or(1.0);
+        let prices = PriceService::new(app_state)
+            .get_prices(&symbol_refs)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(symbol, price)| (symbol.to_uppercase(), price.usd * rate))
+            .collect();
+
+        let now = crate::shared::time::now_unix();
+        labels::spend_by_category(&txs, &tx_labels, labels::month_range(now), &prices)
+    }));
+
+    rsx! {
+        Card {
+            variant: crate::components::atoms::card::CardVariant::Base,
+            padding: Some("24px".to_string()),
+            class: Some("mb-6".to_string()),
+            children: rsx! {
+                WidgetTitle { title: DashboardWidget::SpendByCategory.label().to_string() }
+                match &*spend.read() {
+                    None => rsx! {
+                        div {
+                            class: "space-y-3",
+                            for i in 0..2 {
+                                SkeletonTableRow { key: "{i}" }
+                            }
+                        }
+                    },
+                    Some(totals) if totals.is_empty() => rsx! {
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            "本月还没有已分类的转出交易，可在钱包交易历史中为交易添加分类"
+                        }
+                    },
+                    Some(totals) => {
+                        let max = totals.first().map(|(_, v)| *v).unwrap_or(0.0).max(f64::EPSILON);
+                        rsx! {
+                            div {
+                                class: "space-y-3",
+                                for (category, value) in totals.clone() {
+                                    div {
+                                        key: "{category}",
+                                        div {
+                                            class: "flex justify-between text-sm mb-1",
+                                            span {
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                {labels::category_label(&category)}
+                                            }
+                                            span {
+                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                MaskedAmount { value: format!("{}{:.2}", currency_symbol, value) }
+                                            }
+                                        }
+                                        div {
+                                            class: "h-2 rounded-full",
+                                            style: format!("background: {};", Colors::BG_SECONDARY),
+                                            div {
+                                                class: "h-2 rounded-full",
+                                                style: format!("width: {:.1}%; background: {};", value / max * 100.0, Colors::TECH_PRIMARY),
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if let Some(wallet_id) = app_state.wallet.read().selected_wallet_id.clone() {
+                    button {
+                        class: "text-xs mt-4",
+                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                        onclick: move |_| {
+                            navigator.push(Route::WalletDetail { id: wallet_id.clone() });
+                        },
+                        "管理交易分类 →"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Forgot Password Page - 找回密码页面
+//! 邮箱验证码重置账户密码；本地钱包仍使用各自的钱包密码解锁
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::Card;
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::molecules::{ErrorMessage, PasswordStrengthMeter};
+use crate::features::auth::password_strength::estimate;
+use crate::router::Route;
+use crate::services::auth::{AuthService, ResetPasswordReq};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::events::FormEvent;
+use dioxus::prelude::*;
+
+/// Forgot Password Page - 找回密码页面
+#[component]
+pub fn ForgotPassword() -> Element {
+    let navigator = use_navigator();
+    let app_state = use_context::<AppState>();
+
+    let mut email = use_signal(String::new);
+    let mut code = use_signal(String::new);
+    let mut new_password = use_signal(String::new);
+    let mut confirm_password = use_signal(String::new);
+    let mut revoke_other_sessions = use_signal(|| true);
+    // 验证码是否已发送（决定显示第一步还是第二步）
+    let mut code_sent = use_signal(|| false);
+    let mut error_message = use_signal(|| None::<String>);
+    let mut is_loading = use_signal(|| false);
+
+    let strength = estimate(&new_password.read(), &[email.read().as_str()]);
+
+    let send_code = move |_| {
+        let email_val = email.peek().trim().to_string();
+        if email_val.is_empty() || !email_val.contains('@') {
+            error_message.set(Some("请输入有效的邮箱地址".to_string()));
+            return;
+        }
+        error_message.set(None);
+        is_loading.set(true);
+        spawn(async move {
+            match AuthService::new(app_state)
+                .request_password_reset(&email_val)
+                .await
+            {
+                Ok(()) => {
+                    code_sent.set(true);
+                    AppState::show_success(
+                        app_state.toasts,
+                        format!("如果 {} 已注册，验证码已发送至该邮箱", email_val),
+                    );
+                }
+                Err(e) => error_message.set(Some(format!("发送验证码失败: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    let reset = move |_| {
+        let req = ResetPasswordReq {
+            email: email.peek().trim().to_string(),
+            code: code.peek().trim().to_string(),
+            new_password: new_password.peek().clone(),
+            confirm_password: confirm_password.peek().clone(),
+            revoke_other_sessions: revoke_other_sessions(),
+        };
+        if req.code.is_empty() {
+            error_message.set(Some("请输入邮箱验证码".to_string()));
+            return;
+        }
+        if req.new_password != req.confirm_password {
+            error_message.set(Some("两次输入的密码不一致".to_string()));
+            return;
+        }
+        if !estimate(&req.new_password, &[req.email.as_str()]).is_acceptable() {
+            error_message.set(Some("密码强度太弱，请参考提示修改密码".to_string()));
+            return;
+        }
+        error_message.set(None);
+        is_loading.set(true);
+        spawn(async move {
+            match AuthService::new(app_state).reset_password(&req).await {
+                Ok(()) => {
+                    AppState::show_success(
+                        app_state.toasts,
+                        "密码已重置，请使用新密码登录".to_string(),
+                    );
+                    navigator.push(Route::Login {});
+                }
+                Err(e) => error_message.set(Some(format!("重置密码失败: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "min-h-screen flex items-center justify-center p-4",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+
+            Card {
+                variant: crate::components::atoms::card::CardVariant::Base,
+                padding: Some("32px".to_string()),
+                class: Some("max-w-md w-full".to_string()),
+                children: rsx! {
+                    div {
+                        class: "text-center mb-8",
+                        h1 {
+                            class: "text-3xl font-bold mb-2",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "找回密码"
+                        }
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            if code_sent() {
+                                "输入邮件中的验证码并设置新密码"
+                            } else {
+                                "我们将向您的注册邮箱发送验证码"
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "mb-6",
+                        Input {
+                            input_type: InputType::Text,
+                            label: Some("邮箱".to_string()),
+                            placeholder: Some("请输入邮箱地址".to_string()),
+                            value: Some(email.read().clone()),
+                            disabled: code_sent(),
+                            onchange: move |e: FormEvent| {
+                                email.set(e.value());
+                                error_message.set(None);
+                            },
+                        }
+                    }
+
+                    if code_sent() {
+                        div {
+                            class: "mb-6",
+                            Input {
+                                input_type: InputType::Text,
+                                label: Some("验证码".to_string()),
+                                placeholder: Some("请输入邮件中的验证码".to_string()),
+                                value: Some(code.read().clone()),
+                                onchange: move |e: FormEvent| {
+                                    code.set(e.value());
+                                    error_message.set(None);
+                                },
+                            }
+                        }
+                        div {
+                            class: "mb-6",
+                            Input {
+                                input_type: InputType::Password,
+                                label: Some("新密码".to_string()),
+                                value: Some(new_password.read().clone()),
+                                onchange: move |e: FormEvent| {
+                                    new_password.set(e.value());
+                                    error_message.set(None);
+                                },
+                            }
+                            if !new_password.read().is_empty() {
+                                PasswordStrengthMeter { strength: strength }
+                            }
+                        }
+                        div {
+                            class: "mb-6",
+                            Input {
+                                input_type: InputType::Password,
+                                label: Some("确认新密码".to_string()),
+                                value: Some(confirm_password.read().clone()),
+                                onchange: move |e: FormEvent| {
+                                    confirm_password.set(e.value());
+                                    error_message.set(None);
+                                },
+                            }
+                        }
+                        label {
+                            class: "mb-4 flex items-start gap-2 cursor-pointer",
+                            input {
+                                r#type: "checkbox",
+                                class: "mt-0.5",
+                                checked: revoke_other_sessions(),
+                                onchange: move |_| revoke_other_sessions.set(!revoke_other_sessions()),
+                            }
+                            span {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "退出所有设备上的登录"
+                            }
+                        }
+                        p {
+                            class: "mb-4 text-xs p-3 rounded",
+                            style: format!("color: {}; background: {};", Colors::TEXT_SECONDARY, Colors::BG_SECONDARY),
+                            "重置账户密码不会改动本设备上的钱包：钱包仍使用原来的钱包密码解锁。如果也忘记了钱包密码，请使用助记词或私钥重新导入钱包。"
+                        }
+                    }
+
+                    ErrorMessage {
+                        message: error_message.read().clone()
+                    }
+
+                    if code_sent() {
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Large,
+                            class: Some("w-full mb-4".to_string()),
+                            disabled: is_loading(),
+                            loading: is_loading(),
+                            onclick: reset,
+                            "重置密码"
+                        }
+                        button {
+                            class: "w-full mb-4 text-sm",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            disabled: is_loading(),
+                            onclick: send_code,
+                            "重新发送验证码"
+                        }
+                    } else {
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Large,
+                            class: Some("w-full mb-4".to_string()),
+                            disabled: is_loading(),
+                            loading: is_loading(),
+                            onclick: send_code,
+                            "发送验证码"
+                        }
+                    }
+
+                    div {
+                        class: "text-center",
+                        button {
+                            class: "text-sm font-medium",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            onclick: move |_| {
+                                navigator.push(Route::Login {});
+                            },
+                            "返回登录"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
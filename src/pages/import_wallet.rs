@@ -11,12 +11,21 @@ use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
 use crate::components::molecules::ErrorMessage;
+use crate::crypto::derivation::DerivationPath;
+use crate::crypto::key_manager::KeyManager;
+use crate::features::wallet::chain_accounts::{self, DERIVED_CHAINS};
+use crate::features::wallet::derivation_paths::{self, PathChoice};
+use crate::features::wallet::discovery::{self, ScanStatus};
 use crate::features::wallet::hooks::use_wallet;
+use crate::features::wallet::hooks::WalletController;
 use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::services::balance::{native_symbol, BalanceService};
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
 /// 导入方式
 #[derive(Clone, Copy, PartialEq)]
@@ -36,6 +45,8 @@ pub fn ImportWallet() -> Element {
 
     // 助记词相关
     let mnemonic_phrase = use_signal(|| String::new());
+    // 高级：各链选定的派生路径（未选择的链使用默认路径）
+    let selected_paths = use_signal(HashMap::<ChainType, DerivationPath>::new);
 
     // 私钥相关
     let private_key = use_signal(|| String::new());
@@ -47,6 +58,8 @@ pub fn ImportWallet() -> Element {
     // UI状态
     let error_message = use_signal(|| Option::<String>::None);
     let is_loading = use_signal(|| false);
+    // 助记词导入完成后进入账户发现步骤
+    let mut imported_wallet = use_signal(|| Option::<String>::None);
 
     let wallet_controller = use_wallet();
     let navigator = use_navigator();
@@ -124,7 +137,7 @@ pub fn ImportWallet() -> Element {
             let keystore_password = keystore_password;
 
             spawn(async move {
-                let result = match method {
+                let result: anyhow::Result<()> = match method {
                     ImportMethod::Mnemonic => {
                         let phrase = mnemonic_phrase.read().trim().to_string();
                         if phrase.is_empty() {
@@ -138,7 +151,28 @@ pub fn ImportWallet() -> Element {
                             loading.set(false);
                             return;
                         }
-                        wallet_ctrl.recover_wallet(&name, &phrase, &pwd).await
+                        let paths = selected_paths.read().clone();
+                        match wallet_ctrl
+                            .recover_wallet_with_paths(&name, &phrase, &pwd, &paths)
+                            .await
+                        {
+                            Ok(wallet_id) => {
+                                loading.set(false);
+                                AppState::show_success(toasts, "钱包导入成功".to_string());
+                                // 后台扫描其余已使用的账户，导入本身已完成
+                                start_discovery(
+                                    app_state,
+                                    wallet_ctrl,
+                                    &wallet_id,
+                                    &phrase,
+                                    &paths,
+                                )
+                                .await;
+                                imported_wallet.set(Some(wallet_id));
+                                return;
+                            }
+                            Err(e) => Err(e),
+                        }
                     }
                     ImportMethod::PrivateKey => {
                         let key = private_key.read().trim().to_string();
@@ -222,6 +256,28 @@ pub fn ImportWallet() -> Element {
         }
     };
 
+    if imported_wallet.read().is_some() {
+        return rsx! {
+            div {
+                class: "min-h-screen flex items-center justify-center p-4",
+                style: format!("background: {};", Colors::BG_PRIMARY),
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("32px".to_string()),
+                    class: Some("max-w-2xl w-full".to_string()),
+                    children: rsx! {
+                        AccountDiscoveryStep {
+                            on_finish: move |_| {
+                                imported_wallet.set(None);
+                                navigator.push(Route::Dashboard {});
+                            },
+                        }
+                    },
+                }
+            }
+        };
+    }
+
     rsx! {
         div {
             class: "min-h-screen flex items-center justify-center p-4",
@@ -340,6 +396,10 @@ pub fn ImportWallet() -> Element {
                                     style: format!("color: {};", Colors::TEXT_TERTIARY),
                                     "💡 导入后将自动恢复4种链的地址（ETH, BTC, SOL, TON）"
                                 }
+                                AdvancedDerivationPaths {
+                                    phrase: mnemonic_phrase.read().clone(),
+                                    selected_paths,
+                                }
                             }
                         },
                         ImportMethod::PrivateKey => rsx! {
@@ -481,3 +541,466 @@ pub fn ImportWallet() -> Element {
         }
     }
 }
+
+/// 预览中的一个地址
+#[derive(Clone, PartialEq)]
+struct PreviewAddress {
+    path: DerivationPath,
+    address: String,
+    /// None 表示余额查询中
+    balance: Option<String>,
+}
+
+/// 高级：派生路径——选择模板或输入自定义路径，预览前几个地址及余额后选定
+#[component]
+fn AdvancedDerivationPaths(
+    phrase: String,
+    selected_paths: Signal<HashMap<ChainType, DerivationPath>>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut expanded = use_signal(|| false);
+    let mut chain = use_signal(|| ChainType::Ethereum);
+    let mut choice = use_signal(|| {
+        PathChoice::Template(derivation_paths::default_template(ChainType::Ethereum).id)
+    });
+    let mut custom_input = use_signal(String::new);
+    let mut previews = use_signal(Vec::<PreviewAddress>::new);
+    let mut preview_error = use_signal(|| Option::<String>::None);
+    let mut previewing = use_signal(|| false);
+
+    let active_chain = *chain.read();
+    let selected = selected_paths.read().get(&active_chain).cloned();
+    let default_path = chain_accounts::derivation_path(active_chain);
+
+    let run_preview = move |_| {
+        let chain_now = *chain.read();
+        let choice_now = choice.read().clone();
+        let phrase = phrase.clone();
+        let paths = match derivation_paths::preview_paths(chain_now, &choice_now) {
+            Ok(paths) => paths,
+            Err(e) => {
+                preview_error.set(Some(e));
+                return;
+            }
+        };
+        let phrase = phrase
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if bip39::Mnemonic::parse_in(bip39::Language::English, &phrase).is_err() {
+            preview_error.set(Some("请先输入有效的助记词".to_string()));
+            return;
+        }
+        preview_error.set(None);
+        previewing.set(true);
+
+        spawn(async move {
+            let mnemonic = crate::crypto::bip39::MnemonicSecret::new(phrase);
+            let seed = match crate::crypto::worker::derive_seed(&mnemonic).await {
+                Ok(seed) => seed,
+                Err(e) => {
+                    preview_error.set(Some(format!("派生失败: {}", e)));
+                    previewing.set(false);
+                    return;
+                }
+            };
+            let key_manager = KeyManager::new(seed);
+            let mut accounts = Vec::new();
+            for path in &paths {
+                match chain_accounts::derive_account_at(&key_manager, chain_now, path) {
+                    Ok(account) => accounts.push(account),
+                    Err(e) => {
+                        preview_error.set(Some(format!("派生失败: {}", e)));
+                        previewing.set(false);
+                        return;
+                    }
+                }
+            }
+            previews.set(
+                paths
+                    .into_iter()
+                    .zip(&accounts)
+                    .map(|(path, account)| PreviewAddress {
+                        path,
+                        address: account.address.clone(),
+                        balance: None,
+                    })
+                    .collect(),
+            );
+            previewing.set(false);
+
+            // 逐个查询余额（查询失败显示“—”，不影响选择）
+            let balances = BalanceService::new(app_state);
+            for (index, account) in accounts.iter().enumerate() {
+                let balance = match balances.get_native_balance(account).await {
+                    Ok(amount) => format!("{} {}", amount.format(6), native_symbol(&account.chain)),
+                    Err(_) => "—".to_string(),
+                };
+                if let Some(row) = previews.write().get_mut(index) {
+                    if row.address == account.address {
+                        row.balance = Some(balance);
+                    }
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "mt-4",
+            button {
+                class: "text-xs underline",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                onclick: move |_| {
+                    let open = *expanded.read();
+                    expanded.set(!open);
+                },
+                if *expanded.read() { "▾ 高级：派生路径" } else { "▸ 高级：派生路径" }
+            }
+
+            if *expanded.read() {
+                div {
+                    class: "mt-3 p-4 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    p {
+                        class: "text-xs mb-3",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "从其他钱包迁移时，如导入后地址不一致，可选择原钱包使用的派生路径。未修改的链使用默认路径。"
+                    }
+
+                    // 链选择
+                    div {
+                        class: "flex gap-2 mb-3",
+                        for c in DERIVED_CHAINS {
+                            Button {
+                                key: "{c.as_str()}",
+                                variant: if c == active_chain { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    chain.set(c);
+                                    choice.set(PathChoice::Template(derivation_paths::default_template(c).id));
+                                    custom_input.set(String::new());
+                                    previews.set(Vec::new());
+                                    preview_error.set(None);
+                                },
+                                "{c.label()}"
+                            }
+                        }
+                    }
+
+                    // 模板
+                    div {
+                        class: "flex flex-wrap gap-2 mb-3",
+                        for template in derivation_paths::templates_for(active_chain) {
+                            Button {
+                                key: "{template.id}",
+                                variant: if *choice.read() == PathChoice::Template(template.id) { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    choice.set(PathChoice::Template(template.id));
+                                    previews.set(Vec::new());
+                                },
+                                "{template.label}"
+                            }
+                        }
+                    }
+
+                    // 自定义路径
+                    Input {
+                        input_type: InputType::Text,
+                        label: Some("自定义路径".to_string()),
+                        placeholder: Some(format!("如 {}", default_path)),
+                        value: Some(custom_input.read().clone()),
+                        onchange: Some(EventHandler::new(move |e: FormEvent| {
+                            let value = e.value();
+                            choice.set(if value.trim().is_empty() {
+                                PathChoice::Template(derivation_paths::default_template(*chain.read()).id)
+                            } else {
+                                PathChoice::Custom(value.clone())
+                            });
+                            custom_input.set(value);
+                            previews.set(Vec::new());
+                            preview_error.set(None);
+                        })),
+                    }
+
+                    div {
+                        class: "flex items-center justify-between mt-3",
+                        span {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!(
+                                "{} 当前路径：{}",
+                                active_chain.label(),
+                                selected.as_ref().map(|p| p.to_string()).unwrap_or_else(|| format!("{}（默认）", default_path)),
+                            )}
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            loading: previewing(),
+                            disabled: previewing(),
+                            onclick: run_preview,
+                            "预览地址"
+                        }
+                    }
+
+                    if let Some(e) = preview_error.read().clone() {
+                        p {
+                            class: "text-xs mt-2",
+                            style: format!("color: {};", Colors::PAYMENT_ERROR),
+                            "{e}"
+                        }
+                    }
+
+                    if !previews.read().is_empty() {
+                        div {
+                            class: "mt-3 space-y-2",
+                            p {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                "点击地址以使用该路径导入"
+                            }
+                            for row in previews.read().iter().cloned() {
+                                {
+                                    let is_selected = selected.as_ref() == Some(&row.path);
+                                    let path_for_click = row.path.clone();
+                                    rsx! {
+                                        button {
+                                            key: "{row.path}",
+                                            class: "w-full text-left p-2 rounded-lg text-xs",
+                                            style: format!(
+                                                "background: {}; border: 1px solid {};",
+                                                Colors::BG_PRIMARY,
+                                                if is_selected { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY },
+                                            ),
+                                            onclick: move |_| {
+                                                selected_paths.write().insert(active_chain, path_for_click.clone());
+                                            },
+                                            div {
+                                                class: "flex justify-between",
+                                                span {
+                                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                    "{row.path}"
+                                                }
+                                                span {
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {row.balance.clone().unwrap_or_else(|| "查询中…".to_string())}
+                                                }
+                                            }
+                                            div {
+                                                class: "font-mono break-all mt-1",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                "{row.address}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if selected.is_some() {
+                        button {
+                            class: "text-xs underline mt-3",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            onclick: move |_| {
+                                selected_paths.write().remove(&active_chain);
+                            },
+                            "恢复默认路径"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 导入完成后开始账户发现（派生失败只记录日志，不影响导入结果）
+async fn start_discovery(
+    app_state: AppState,
+    wallet_ctrl: WalletController,
+    wallet_id: &str,
+    phrase: &str,
+    paths: &HashMap<ChainType, DerivationPath>,
+) {
+    let phrase = phrase
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    let mnemonic = crate::crypto::bip39::MnemonicSecret::new(phrase);
+    let seed = match crate::crypto::worker::derive_seed(&mnemonic).await {
+        Ok(seed) => seed,
+        Err(e) => {
+            log::warn!("账户发现未启动: {}", e);
+            return;
+        }
+    };
+    let bases = DERIVED_CHAINS
+        .into_iter()
+        .filter_map(|chain| {
+            let base = match paths.get(&chain) {
+                Some(path) => path.clone(),
+                None => chain_accounts::derivation_path(chain).parse().ok()?,
+            };
+            Some((chain, base))
+        })
+        .collect();
+    discovery::start(
+        app_state,
+        wallet_ctrl,
+        wallet_id.to_string(),
+        KeyManager::new(seed),
+        bases,
+    );
+}
+
+/// 账户发现步骤：按链显示扫描进度与发现的账户（默认勾选），可随时完成导入
+#[component]
+fn AccountDiscoveryStep(on_finish: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let wallet_ctrl = use_wallet();
+    let mut saving = use_signal(|| false);
+    let mut discovery_state = app_state.account_discovery;
+
+    let state = app_state.account_discovery.read().clone();
+    let running = state.is_running();
+    let pending = state
+        .chains
+        .iter()
+        .flat_map(|c| c.found.iter())
+        .filter(|f| f.include && !f.added)
+        .count();
+
+    let finish = move |_| {
+        saving.set(true);
+        spawn(async move {
+            match discovery::commit(app_state, wallet_ctrl, true).await {
+                Ok(0) => {}
+                Ok(added) => {
+                    AppState::show_success(app_state.toasts, format!("已添加 {} 个账户", added))
+                }
+                Err(e) => AppState::show_error(app_state.toasts, format!("添加账户失败: {}", e)),
+            }
+            if app_state.account_discovery.peek().is_running() {
+                AppState::show_info(
+                    app_state.toasts,
+                    "账户发现仍在后台进行，发现的账户将自动添加".to_string(),
+                );
+            }
+            saving.set(false);
+            on_finish.call(());
+        });
+    };
+
+    rsx! {
+        h1 {
+            class: "text-2xl font-bold mb-2",
+            style: format!("color: {};", Colors::TEXT_PRIMARY),
+            "发现已使用的账户"
+        }
+        p {
+            class: "text-sm mb-6",
+            style: format!("color: {};", Colors::TEXT_SECONDARY),
+            {format!(
+                "钱包已导入。正在检查各链后续账户的余额与交易记录（连续 {} 个未使用账户后停止），发现的账户默认勾选。",
+                discovery::GAP_LIMIT
+            )}
+        }
+
+        div {
+            class: "space-y-4 mb-6",
+            for scan in state.chains.iter().cloned() {
+                div {
+                    key: "{scan.chain.as_str()}",
+                    class: "p-4 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    div {
+                        class: "flex items-center justify-between mb-2",
+                        span {
+                            class: "font-medium",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{scan.chain.label()}"
+                        }
+                        span {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {match &scan.status {
+                                ScanStatus::Scanning => format!("扫描中… 已检查 {} 个账户", scan.scanned()),
+                                ScanStatus::Done => format!("已完成，检查了 {} 个账户", scan.scanned()),
+                                ScanStatus::Cancelled => "已取消".to_string(),
+                                ScanStatus::Failed(e) => format!("扫描失败：{}", e),
+                            }}
+                        }
+                    }
+                    for found in scan.found.iter().cloned() {
+                        label {
+                            key: "{found.index}",
+                            class: "flex items-center gap-3 py-1 text-xs",
+                            input {
+                                r#type: "checkbox",
+                                checked: found.include || found.added,
+                                disabled: found.added,
+                                onchange: move |_| discovery_state.write().toggle(scan.chain, found.index),
+                            }
+                            span {
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "#{found.index}"
+                            }
+                            span {
+                                class: "font-mono break-all flex-1",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "{found.account.address}"
+                            }
+                            span {
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                {if found.manual {
+                                    "手动添加".to_string()
+                                } else {
+                                    format!(
+                                        "{} · {} 笔交易",
+                                        found.balance.clone().unwrap_or_else(|| "—".to_string()),
+                                        found.tx_count
+                                    )
+                                }}
+                            }
+                        }
+                    }
+                    button {
+                        class: "text-xs underline mt-2",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        onclick: move |_| {
+                            if let Err(e) = discovery::add_manual(app_state, scan.chain) {
+                                AppState::show_error(app_state.toasts, e);
+                            }
+                        },
+                        "+ 手动添加下一个账户"
+                    }
+                }
+            }
+        }
+
+        div {
+            class: "flex gap-4",
+            Button {
+                variant: ButtonVariant::Primary,
+                size: ButtonSize::Large,
+                class: Some("flex-1".to_string()),
+                disabled: saving(),
+                loading: saving(),
+                onclick: finish,
+                if pending > 0 { "添加所选账户并完成" } else { "完成" }
+            }
+            if running {
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    size: ButtonSize::Large,
+                    onclick: move |_| discovery::cancel(app_state),
+                    "取消扫描"
+                }
+            }
+        }
+    }
+}
@@ -5,6 +5,11 @@
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::logo::LogoPlanet;
+use crate::features::landing::stats::{
+    self, format_rate, format_usd_compact, method_label, ChainAssets, ExampleRate, PublicStats,
+    StatsSource,
+};
+use crate::features::marketing::referral;
 use crate::router::Route;
 use crate::shared::design_tokens::{Colors, Glass, Gradients};
 use crate::shared::state::AppState;
@@ -15,6 +20,8 @@ use dioxus::prelude::*;
 pub fn Landing() -> Element {
     let navigator = use_navigator();
     let app_state = use_context::<AppState>();
+    // 邀请链接（?ref=）中的邀请码保存到注册时使用
+    use_hook(referral::capture_from_location);
 
     rsx! {
         div {
@@ -365,6 +372,9 @@ pub fn Landing() -> Element {
                 }
             }
 
+            // 实时数据 Section - 支持的资产、兑换量与支付方式（接口不可用时显示静态内容）
+            LiveStatsSection {}
+
             // 技术优势 Section
             section {
                 class: "container mx-auto px-4 sm:px-6 py-12 sm:py-16",
@@ -890,6 +900,193 @@ fn ChainCard(name: String, symbol: String, color: String) -> Element {
     }
 }
 
+/// 实时数据区块（无需登录；三类数据分别加载，互不阻塞）
+#[component]
+fn LiveStatsSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut public_stats = use_signal(|| (PublicStats::fallback(), StatsSource::Static));
+    let mut assets = use_signal(Vec::<ChainAssets>::new);
+    let mut rates = use_signal(Vec::<ExampleRate>::new);
+
+    use_effect(move || {
+        spawn(async move {
+            public_stats.set(stats::load_public_stats(app_state).await);
+        });
+        spawn(async move {
+            assets.set(stats::load_supported_assets(app_state).await);
+        });
+        spawn(async move {
+            rates.set(stats::load_example_rates(app_state).await);
+        });
+    });
+
+    let (summary, source) = public_stats();
+    let volume = summary
+        .swap_volume_24h_usd
+        .filter(|_| source != StatsSource::Static)
+        .map(format_usd_compact);
+    let chain_count = if assets.read().is_empty() {
+        stats::LANDING_CHAINS.len()
+    } else {
+        assets.read().len()
+    };
+    let token_count: usize = assets.read().iter().map(|a| a.tokens.len()).sum();
+
+    rsx! {
+        section {
+            class: "container mx-auto px-4 sm:px-6 py-12 sm:py-16",
+            div {
+                class: "max-w-6xl mx-auto",
+                div {
+                    class: "text-center mb-8 sm:mb-12",
+                    h2 {
+                        class: "text-2xl sm:text-3xl md:text-4xl font-bold mb-3 sm:mb-4",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "实时数据"
+                    }
+                    p {
+                        class: "text-base sm:text-lg",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        if source == StatsSource::Stale {
+                            "支持的资产与服务（数据可能不是最新）"
+                        } else {
+                            "支持的资产与服务"
+                        }
+                    }
+                }
+
+                // 汇总数字
+                div {
+                    class: "grid grid-cols-2 md:grid-cols-4 gap-3 sm:gap-4 mb-6",
+                    StatTile { label: "支持的链", value: chain_count.to_string() }
+                    StatTile {
+                        label: "支持的代币",
+                        value: if token_count > 0 { format!("{}+", token_count) } else { "主流代币".to_string() },
+                    }
+                    StatTile {
+                        label: "24h 兑换量",
+                        value: volume.unwrap_or_else(|| "实时报价".to_string()),
+                    }
+                    StatTile {
+                        label: "法币支付地区",
+                        value: summary.payment_methods_by_region.len().to_string(),
+                    }
+                }
+
+                // 示例汇率
+                if !rates.read().is_empty() {
+                    div {
+                        class: "flex flex-wrap justify-center gap-2 sm:gap-3 mb-6",
+                        for rate in rates.read().iter() {
+                            span {
+                                key: "{rate.symbol}",
+                                class: "text-xs sm:text-sm px-3 py-1 rounded-full",
+                                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY),
+                                {format_rate(rate)}
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "grid grid-cols-1 md:grid-cols-2 gap-4 sm:gap-6",
+                    // 资产矩阵
+                    Card {
+                        variant: crate::components::atoms::card::CardVariant::Base,
+                        padding: Some("24px".to_string()),
+                        children: rsx! {
+                            h3 {
+                                class: "text-lg font-semibold mb-4",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "支持的资产"
+                            }
+                            if assets.read().is_empty() {
+                                p {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "Bitcoin · Ethereum · BSC · Polygon · Solana · TON"
+                                }
+                            } else {
+                                div {
+                                    class: "space-y-3",
+                                    for entry in assets.read().iter() {
+                                        div {
+                                            key: "{entry.chain.as_str()}",
+                                            class: "flex items-start justify-between gap-3 text-sm",
+                                            span {
+                                                class: "font-medium shrink-0",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                {entry.chain.label()}
+                                            }
+                                            span {
+                                                class: "text-right",
+                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                {entry.tokens.join(" · ")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    // 各地区支付方式
+                    Card {
+                        variant: crate::components::atoms::card::CardVariant::Base,
+                        padding: Some("24px".to_string()),
+                        children: rsx! {
+                            h3 {
+                                class: "text-lg font-semibold mb-4",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "支付方式"
+                            }
+                            div {
+                                class: "space-y-3",
+                                for region in summary.payment_methods_by_region.iter() {
+                                    div {
+                                        key: "{region.region}",
+                                        class: "flex items-start justify-between gap-3 text-sm",
+                                        span {
+                                            class: "font-medium shrink-0",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {region.region.clone()}
+                                        }
+                                        span {
+                                            class: "text-right",
+                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                            {region.methods.iter().map(|m| method_label(m)).collect::<Vec<_>>().join(" · ")}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 汇总数字卡片
+#[component]
+fn StatTile(label: String, value: String) -> Element {
+    rsx! {
+        div {
+            class: "rounded-xl p-4 text-center",
+            style: format!("{}", Glass::strong()),
+            div {
+                class: "text-xl sm:text-2xl font-bold mb-1",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                {value}
+            }
+            div {
+                class: "text-xs sm:text-sm",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                {label}
+            }
+        }
+    }
+}
+
 /// 技术特性卡片组件
 #[component]
 fn TechFeatureCard(title: String, description: String) -> Element {
@@ -12,17 +12,40 @@ use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
 use crate::components::molecules::ErrorMessage;
 use crate::features::auth::hooks::use_auth;
+use crate::features::auth::login_guard::{caps_lock_from_key, format_countdown, LoginThrottle};
 use crate::router::Route;
+use crate::services::audit_log::{AuditEventRequest, AuditLogService};
+use crate::services::auth::AuthService;
 use crate::shared::design_tokens::Colors;
-use crate::shared::error::{ApiError, AppError};
+use crate::shared::error::{ApiError, AppError, AuthError};
 use crate::shared::state::AppState;
+use crate::shared::time;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
 
 fn friendly_login_error(err: &anyhow::Error) -> String {
     // 优先基于强类型错误做映射（比字符串 contains 更稳定）
     if let Some(app_err) = err.downcast_ref::<AppError>() {
         match app_err {
+            AppError::Auth(AuthError::InvalidCredentials {
+                remaining_attempts: Some(0),
+            }) => "邮箱或密码错误，账户即将被临时锁定".to_string(),
+            AppError::Auth(AuthError::InvalidCredentials {
+                remaining_attempts: Some(n),
+            }) => format!("邮箱或密码错误，还可尝试 {} 次，之后账户将被临时锁定", n),
+            AppError::Auth(AuthError::InvalidCredentials { .. }) => {
+                "邮箱或密码错误，或账号未注册".to_string()
+            }
+            AppError::Auth(AuthError::AccountLocked { .. }) => {
+                "连续登录失败次数过多，账户已被临时锁定".to_string()
+            }
+            AppError::Auth(AuthError::RateLimited { .. }) => {
+                "登录尝试过于频繁，请稍后再试".to_string()
+            }
+            AppError::Auth(AuthError::EmailNotVerified) => {
+                "邮箱尚未验证，请先点击验证邮件中的链接".to_string()
+            }
             AppError::Api(ApiError::Unauthorized) => "邮箱或密码错误，或账号未注册".to_string(),
             AppError::Api(ApiError::Timeout) => "请求超时，请稍后再试".to_string(),
             AppError::Api(ApiError::RequestFailed(_)) => {
@@ -49,6 +72,21 @@ fn friendly_login_error(err: &anyhow::Error) -> String {
     }
 }
 
+/// 记录账户锁定审计事件（尽力而为，失败不影响页面）
+fn record_lockout(app_state: AppState, email: &str, retry_after_secs: u64) {
+    let event = AuditEventRequest {
+        action: "login_lockout".to_string(),
+        resource_type: "user".to_string(),
+        resource_id: email.to_string(),
+        details: serde_json::json!({ "retry_after_secs": retry_after_secs }),
+    };
+    spawn(async move {
+        if let Err(e) = AuditLogService::new(app_state).record_event(event).await {
+            log::warn!("记录登录锁定审计事件失败: {}", e);
+        }
+    });
+}
+
 /// Login Page - 登录页面
 #[component]
 pub fn Login() -> Element {
@@ -60,6 +98,45 @@ pub fn Login() -> Element {
     let password = use_signal(|| String::new());
     let error_message = use_signal(|| Option::<String>::None);
     let is_loading = use_signal(|| false);
+    let mut throttle = use_signal(LoginThrottle::load);
+    let mut now_ms = use_signal(js_sys::Date::now);
+    let mut caps_lock_on = use_signal(|| false);
+    let mut remember_device = use_signal(|| false);
+    // 邮箱未验证时记录邮箱，用于重新发送验证邮件
+    let mut unverified_email = use_signal(|| Option::<String>::None);
+    let mut resend_loading = use_signal(|| false);
+
+    // 锁定期间每秒刷新倒计时
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(1000).await;
+            if throttle.peek().blocked_until_ms() > *now_ms.peek() {
+                now_ms.set(time::now_ms());
+            }
+        }
+    });
+
+    let wait_secs = throttle.read().remaining_secs(now_ms());
+    let resend_email = move |_| {
+        let Some(target) = unverified_email() else {
+            return;
+        };
+        resend_loading.set(true);
+        spawn(async move {
+            match AuthService::new(app_state)
+                .resend_verification_email(&target)
+                .await
+            {
+                Ok(()) => {
+                    AppState::show_success(app_state.toasts, format!("验证邮件已发送至 {}", target))
+                }
+                Err(e) => {
+                    AppState::show_error(app_state.toasts, format!("发送验证邮件失败: {}", e))
+                }
+            }
+            resend_loading.set(false);
+        });
+    };
 
     let handle_login = {
         let email = email;
@@ -84,28 +161,58 @@ pub fn Login() -> Element {
                 return;
             }
 
+            let now = time::now_ms();
+            now_ms.set(now);
+            if throttle.peek().remaining_secs(now) > 0 {
+                return;
+            }
+
             is_loading.set(true);
             error_message.set(None);
+            unverified_email.set(None);
 
             let auth_ctrl = auth_controller;
+            let remember = remember_device();
             let mut loading = is_loading;
             let mut error = error_message;
             let nav = navigator.clone();
 
             spawn(async move {
-                match auth_ctrl.login(&email_val, &pwd).await {
+                match auth_ctrl.login(&email_val, &pwd, remember).await {
                     Ok(_) => {
                         loading.set(false);
+                        throttle.write().record_success();
+                        throttle.peek().save();
                         // 登录成功，显示Toast并跳转到Dashboard
                         AppState::show_success(app_state.toasts, "登录成功".to_string());
                         nav.push(Route::Dashboard {});
                     }
                     Err(e) => {
                         loading.set(false);
+                        let now = time::now_ms();
+                        match e.downcast_ref::<AppError>() {
+                            Some(AppError::Auth(AuthError::AccountLocked { retry_after_secs })) => {
+                                throttle.write().block_for(*retry_after_secs, now);
+                                record_lockout(app_state, &email_val, *retry_after_secs);
+                            }
+                            Some(AppError::Auth(AuthError::RateLimited { retry_after_secs })) => {
+                                throttle.write().block_for(*retry_after_secs, now);
+                            }
+                            Some(AppError::Auth(AuthError::EmailNotVerified)) => {
+                                unverified_email.set(Some(email_val.clone()));
+                            }
+                            Some(AppError::Auth(AuthError::InvalidCredentials { .. }))
+                            | Some(AppError::Api(ApiError::Unauthorized)) => {
+                                throttle.write().record_failure(now);
+                            }
+                            _ => {}
+                        }
+                        throttle.peek().save();
+                        now_ms.set(now);
                         let err_msg = friendly_login_error(&e);
                         #[cfg(debug_assertions)]
                         {
-                            use tracing::warn;
+                            use log::warn;
                             warn!("Login failed (raw): {:#}", e);
                         }
                         AppState::show_error(app_state.toasts, err_msg.clone());
@@ -176,6 +283,44 @@ pub fn Login() -> Element {
                                     error_message.set(None);
                                 }))
                             },
+                            onkeydown: move |e: KeyboardEvent| {
+                                let shift = e.modifiers().contains(Modifiers::SHIFT);
+                                if let Some(on) = caps_lock_from_key(&e.key().to_string(), shift) {
+                                    caps_lock_on.set(on);
+                                }
+                            },
+                        }
+                        if caps_lock_on() {
+                            p {
+                                class: "mt-2 text-xs",
+                                style: format!("color: {};", Colors::PAYMENT_WARNING),
+                                "⚠️ 大写锁定已开启"
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "-mt-4 mb-4 flex items-center justify-between gap-2",
+                        label {
+                            class: "flex items-center gap-2 cursor-pointer",
+                            input {
+                                r#type: "checkbox",
+                                checked: remember_device(),
+                                onchange: move |_| remember_device.set(!remember_device()),
+                            }
+                            span {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "记住此设备（30 天内免登录）"
+                            }
+                        }
+                        button {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TECH_PRIMARY),
+                            onclick: move |_| {
+                                navigator.push(Route::ForgotPassword {});
+                            },
+                            "忘记密码？"
                         }
                     }
 
@@ -184,15 +329,41 @@ pub fn Login() -> Element {
                         message: error_message.read().clone()
                     }
 
+                    if wait_secs > 0 {
+                        p {
+                            class: "mb-4 text-sm text-center",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!("为保护账户安全，请在 {} 后重试", format_countdown(wait_secs))}
+                        }
+                    }
+
+                    if unverified_email().is_some() {
+                        div {
+                            class: "mb-4 text-center",
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                disabled: resend_loading(),
+                                loading: resend_loading(),
+                                onclick: resend_email,
+                                "重新发送验证邮件"
+                            }
+                        }
+                    }
+
                     // 登录按钮
                     Button {
                         variant: ButtonVariant::Primary,
                         size: ButtonSize::Large,
                         class: Some("w-full mb-4".to_string()),
-                        disabled: is_loading(),
+                        disabled: is_loading() || wait_secs > 0,
                         loading: is_loading(),
                         onclick: handle_login,
-                        "登录"
+                        if wait_secs > 0 {
+                            {format!("请等待 {}", format_countdown(wait_secs))}
+                        } else {
+                            "登录"
+                        }
                     }
 
                     // 注册链接
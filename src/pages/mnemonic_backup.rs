@@ -3,15 +3,46 @@
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
+use crate::components::atoms::copy_button::CopyButton;
+use crate::features::wallet::backup_reminder;
+use crate::features::wallet::hooks::{pending_wallet_name, use_wallet};
 use crate::router::Route;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
+use crate::shared::time;
 use dioxus::prelude::*;
 use js_sys;
 use wasm_bindgen::JsCast;
-use wasm_bindgen_futures::JsFuture;
 use web_sys::{window, Blob, HtmlElement, Url};
 
+/// 打印时只显示助记词打印页（屏幕上不显示）
+const PRINT_CSS: &str = "#mnemonic-print-sheet { display: none; }
+@media print {
+  body * { visibility: hidden !important; }
+  #mnemonic-print-sheet { display: block !important; position: absolute; left: 0; top: 0; width: 100%; color: #111827; background: #FFFFFF; }
+  #mnemonic-print-sheet, #mnemonic-print-sheet * { visibility: visible !important; }
+}";
+
+/// 备份指引步骤（按顺序）
+const GUIDE_STEPS: [&str; 5] = [
+    "backup.guide.step1",
+    "backup.guide.step2",
+    "backup.guide.step3",
+    "backup.guide.step4",
+    "backup.guide.step5",
+];
+
+/// 页面是否正在被屏幕共享
+///
+/// 浏览器没有让页面得知自身正被共享的标准接口，这里读取宿主环境（如桌面客户端封装）
+/// 提供的 `window.__ironforgeScreenSharing` 标记；宿主未提供时返回 None，不阻止打印
+fn screen_sharing_active() -> Option<bool> {
+    let window = window()?;
+    js_sys::Reflect::get(&window, &"__ironforgeScreenSharing".into())
+        .ok()?
+        .as_bool()
+}
+
 /// Mnemonic Backup Page - 助记词备份页面
 ///
 /// 显示助记词，要求用户：
@@ -27,11 +58,21 @@ pub fn MnemonicBackup(
     let is_confirmed = use_signal(|| false);
     let navigator = use_navigator();
     let app_state = use_context::<AppState>();
+    let wallet_controller = use_wallet();
+    let t = crate::i18n::use_translation();
+    let mut deferring = use_signal(|| false);
+    // 开始新的备份流程时丢弃未完成的补验证，避免验证页误判
+    use_hook(backup_reminder::finish_verification);
 
     // 将助记词分割成单词数组
     let words: Vec<String> = phrase.split_whitespace().map(|s| s.to_string()).collect();
+    let word_count = words.len();
+    let lang = app_state.language.read().clone();
+    let sheet_wallet = pending_wallet_name().unwrap_or_default();
+    let sheet_date = time::format_date(time::now_unix() as i64, &lang);
 
     rsx! {
+        style { {PRINT_CSS} }
         div {
             class: "min-h-screen flex items-center justify-center p-4",
             style: format!("background: {};", Colors::BG_PRIMARY),
@@ -51,7 +92,7 @@ pub fn MnemonicBackup(
                         p {
                             class: "text-sm",
                             style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "请按顺序抄写以下12个单词，并妥善保管"
+                            "请按顺序抄写以下{word_count}个单词，并妥善保管"
                         }
                     }
 
@@ -84,6 +125,24 @@ pub fn MnemonicBackup(
                         }
                     }
 
+                    // 备份指引
+                    div {
+                        class: "mb-6 p-4 rounded-lg",
+                        style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                        p {
+                            class: "font-semibold mb-2",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {t("backup.guide.title")}
+                        }
+                        ol {
+                            class: "text-sm space-y-1 list-decimal list-inside",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            for key in GUIDE_STEPS {
+                                li { {t(key)} }
+                            }
+                        }
+                    }
+
                     // 助记词网格
                     if is_revealed() {
                         div {
@@ -114,7 +173,7 @@ pub fn MnemonicBackup(
                             class: "mb-6",
                             div {
                                 class: "grid grid-cols-3 gap-3",
-                                for i in 0..12 {
+                                for i in 0..word_count {
                                     div {
                                         class: "p-3 rounded-lg border",
                                         style: format!("background: {}; border-color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
@@ -156,30 +215,11 @@ pub fn MnemonicBackup(
                             class: "mb-6 flex flex-col gap-3",
                             div {
                                 class: "flex gap-2",
-                                Button {
-                                    variant: ButtonVariant::Secondary,
-                                    size: ButtonSize::Medium,
+                                CopyButton {
+                                    value: phrase.clone(),
+                                    label: "复制助记词".to_string(),
+                                    sensitive: true,
                                     class: Some("flex-1".to_string()),
-                                    onclick: {
-                                        let phrase = phrase.clone();
-                                        move |_| {
-                                            let phrase_clone = phrase.clone();
-                                            let toasts = app_state.toasts;
-                                            spawn(async move {
-                                                // 复制到剪贴板
-                                                if let Some(window) = window() {
-                                                    let clipboard = window.navigator().clipboard();
-                                                    let promise = clipboard.write_text(&phrase_clone);
-                                                    if JsFuture::from(promise).await.is_ok() {
-                                                        AppState::show_success(toasts, "助记词已复制到剪贴板".to_string());
-                                                    } else {
-                                                        AppState::show_error(toasts, "复制失败，请手动复制".to_string());
-                                                    }
-                                                }
-                                            });
-                                        }
-                                    },
-                                    "📋 复制助记词"
                                 }
                                 Button {
                                     variant: ButtonVariant::Secondary,
@@ -211,12 +251,13 @@ pub fn MnemonicBackup(
                                                         - 不要截图或拍照保存\n\
                                                         - 丢失助记词将无法恢复钱包资产\n\
                                                         \n\
-                                                        助记词（12个单词）：\n\
+                                                        助记词（{}个单词）：\n\
                                                         {}\n\
                                                         \n\
                                                         ======================\n\
                                                         请妥善保管此文件，建议打印后存放在安全的地方。\n",
                                                         Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+                                                        word_count,
                                                         phrase_clone
                                                     );
 
@@ -281,6 +322,50 @@ pub fn MnemonicBackup(
                                     "📥 下载备份文件"
                                 }
                             }
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Medium,
+                                class: Some("w-full".to_string()),
+                                onclick: move |_| {
+                                    // 检测到屏幕共享时禁止打印
+                                    if screen_sharing_active() == Some(true) {
+                                        AppState::show_error(app_state.toasts, crate::i18n::translations::get_text("backup.print_blocked", &app_state.language.read()));
+                                        return;
+                                    }
+                                    if let Some(window) = window() {
+                                        let _ = window.print();
+                                    }
+                                },
+                                {format!("🖨️ {}", t("backup.print"))}
+                            }
+                        }
+
+                        // 打印页：仅包含助记词、钱包名称和日期
+                        div {
+                            id: "mnemonic-print-sheet",
+                            class: "p-8",
+                            h1 {
+                                class: "text-2xl font-bold mb-4",
+                                {t("backup.sheet.title")}
+                            }
+                            p {
+                                class: "text-sm mb-1",
+                                {format!("{}: {}", t("backup.sheet.wallet"), sheet_wallet)}
+                            }
+                            p {
+                                class: "text-sm mb-6",
+                                {format!("{}: {}", t("backup.sheet.date"), sheet_date)}
+                            }
+                            div {
+                                class: "grid grid-cols-3 gap-4",
+                                for (index, word) in words.iter().enumerate() {
+                                    div {
+                                        class: "text-lg",
+                                        style: "border-bottom: 1px solid #D1D5DB; padding: 8px 0;",
+                                        {format!("{}. {}", index + 1, word)}
+                                    }
+                                }
+                            }
                         }
                     }
 
@@ -336,6 +421,46 @@ pub fn MnemonicBackup(
                             }
                         }
                     }
+
+                    // 稍后提醒：先创建钱包，推迟助记词验证
+                    if is_revealed() {
+                        div {
+                            class: "mt-4 text-center",
+                            Button {
+                                variant: ButtonVariant::Text,
+                                size: ButtonSize::Small,
+                                disabled: deferring(),
+                                loading: deferring(),
+                                onclick: move |_| {
+                                    if *deferring.peek() {
+                                        return;
+                                    }
+                                    deferring.set(true);
+                                    spawn(async move {
+                                        match wallet_controller.finalize_wallet_creation(false).await {
+                                            Ok(_) => {
+                                                AppState::show_info(app_state.toasts, crate::i18n::translations::get_text("backup.remind_later_hint", &app_state.language.read()));
+                                                navigator.push(Route::WalletCreated {});
+                                            }
+                                            Err(e) => {
+                                                deferring.set(false);
+                                                AppState::show_error(
+                                                    app_state.toasts,
+                                                    format!("钱包创建失败: {}", e)
+                                                );
+                                            }
+                                        }
+                                    });
+                                },
+                                {t("backup.remind_later")}
+                            }
+                            p {
+                                class: "text-xs mt-1",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                {t("backup.remind_later_hint")}
+                            }
+                        }
+                    }
                 }
             }
         }
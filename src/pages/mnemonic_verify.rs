@@ -10,6 +10,7 @@
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::molecules::ErrorMessage;
+use crate::features::wallet::backup_reminder;
 use crate::router::Route;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
@@ -62,7 +63,7 @@ pub fn MnemonicVerify(
     let correct_words_for_check = correct_words.clone();
 
     // 随机选择3个位置进行验证（提升用户体验）
-    // 注意：固定为3个位置，而不是全部12个
+    // 注意：固定为3个位置，而不是全部单词
     let verify_positions: Memo<Vec<usize>> = use_memo(move || {
         let mut positions: Vec<usize> = (0..correct_words_len).collect();
         // Fisher-Yates 洗牌算法
@@ -131,6 +132,10 @@ pub fn MnemonicVerify(
     // 存储已选择的单词（按验证顺序）
     let selected_words = use_signal(|| Vec::<(usize, String)>::new()); // (位置, 单词)
     let error_message = use_signal(|| Option::<String>::None);
+    // 完成创建进行中（防止重复点击生成两个钱包）
+    let finalizing = use_signal(|| false);
+    // 为“稍后提醒”的钱包补验证（否则为创建流程中的验证）
+    let deferred_wallet = use_signal(backup_reminder::verifying_wallet);
 
     let verify_count = verify_positions.read().len();
     let is_complete = selected_words.read().len() == verify_count;
@@ -298,19 +303,44 @@ pub fn MnemonicVerify(
                             Button {
                                 variant: ButtonVariant::Primary,
                                 size: ButtonSize::Large,
+                                disabled: finalizing(),
+                                loading: finalizing(),
                                 onclick: {
                                     let navigator = navigator;
                                     let app_state = app_state;
                                     let wallet_controller = wallet_controller;
+                                    let mut finalizing = finalizing;
                                     move |_| {
+                                        if *finalizing.peek() {
+                                            return;
+                                        }
+                                        finalizing.set(true);
+                                        // 补验证：标记已备份后回到仪表盘
+                                        if let Some(wallet_id) = deferred_wallet.peek().clone() {
+                                            match wallet_controller.complete_backup_verification(&wallet_id) {
+                                                Ok(()) => {
+                                                    AppState::show_success(
+                                                        app_state.toasts,
+                                                        "助记词验证成功，备份提醒已关闭".to_string(),
+                                                    );
+                                                    navigator.push(Route::Dashboard {});
+                                                }
+                                                Err(e) => {
+                                                    finalizing.set(false);
+                                                    AppState::show_error(app_state.toasts, e.to_string());
+                                                }
+                                            }
+                                            return;
+                                        }
                                         // 验证通过后，完成钱包创建
                                         spawn(async move {
-                                            match wallet_controller.finalize_wallet_creation().await {
+                                            match wallet_controller.finalize_wallet_creation(true).await {
                                                 Ok(_) => {
                                                     // 创建成功，导航到成功页面
                                                     navigator.push(Route::WalletCreated {});
                                                 }
                                                 Err(e) => {
+                                                    finalizing.set(false);
                                                     // 创建失败，显示错误
                                                     AppState::show_error(
                                                         app_state.toasts,
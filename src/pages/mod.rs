@@ -3,9 +3,14 @@
 
 pub mod bridge;
 pub mod buy;
+pub mod dapps;
 pub mod dashboard;
 pub mod dashboard_balance;
+pub mod dashboard_funding;
 pub mod dashboard_transactions;
+pub mod dashboard_watchlist;
+pub mod dashboard_widgets;
+pub mod forgot_password;
 pub mod import_wallet;
 pub mod landing;
 pub mod login;
@@ -13,12 +18,21 @@ pub mod mnemonic_backup;
 pub mod mnemonic_verify;
 pub mod not_found;
 pub mod orders;
+pub mod receipt;
 pub mod receive;
 pub mod register;
 pub mod sell;
+pub mod sell_flow;
 pub mod send;
 pub mod settings;
+pub mod simulate_tx;
+pub mod status;
 pub mod swap;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod swap_e2e_tests;
+pub mod swap_split;
+pub mod token_detail;
+pub mod verify_email;
 pub mod wallet;
 pub mod wallet_created;
 pub mod wallet_detail;
@@ -26,7 +40,9 @@ pub mod wallet_detail;
 // 路由页面导出
 pub use bridge::Bridge;
 pub use buy::Buy;
+pub use dapps::Dapps;
 pub use dashboard::Dashboard;
+pub use forgot_password::ForgotPassword;
 pub use import_wallet::ImportWallet;
 pub use landing::Landing;
 pub use login::Login;
@@ -34,12 +50,17 @@ pub use mnemonic_backup::MnemonicBackup;
 pub use mnemonic_verify::MnemonicVerify;
 pub use not_found::NotFound;
 pub use orders::Orders;
+pub use receipt::TxReceipt;
 pub use receive::Receive;
 pub use register::Register;
 pub use sell::Sell;
 pub use send::Send;
 pub use settings::Settings;
+pub use simulate_tx::SimulateTx;
+pub use status::Status;
 pub use swap::Swap;
+pub use token_detail::TokenDetail;
+pub use verify_email::VerifyEmail;
 pub use wallet::CreateWallet;
 pub use wallet_created::WalletCreated;
 pub use wallet_detail::WalletDetail;
@@ -9,12 +9,19 @@
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
+use crate::components::atoms::copy_button::CopyButton;
+use crate::components::atoms::skeleton::SkeletonTableRow;
 use crate::components::molecules::limit_display::{KycLevel, LimitDisplay, LimitInfo};
+use crate::components::molecules::{ConfirmAction, EmptyState, LoadFailedState, RiskLevel};
+use crate::features::support::HelpPanelRequest;
+use crate::features::swap::onramp_orders::{self, CancelSafety};
+use crate::features::swap::withdrawal_eta::{self, WithdrawalEta};
 use crate::services::fiat_offramp::FiatOfframpService;
 use crate::services::fiat_onramp::FiatOnrampService;
 use crate::services::user::UserService;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
+use crate::shared::time;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -34,6 +41,9 @@ pub struct OrderItem {
     pub payment_url: Option<String>,
     pub tx_hash: Option<String>,
     pub error_message: Option<String>,
+    /// 提现预计到账时间（Unix 秒）
+    #[serde(default)]
+    pub expected_by: Option<i64>,
 }
 
 /// 订单统计信息
@@ -49,18 +59,20 @@ pub struct OrderStats {
 pub fn Orders() -> Element {
     let app_state = use_context::<Signal<AppState>>();
     let navigator = use_navigator();
+    let t = crate::i18n::use_translation();
 
     // 订单状态
     let onramp_orders = use_signal(|| Vec::<OrderItem>::new());
     let offramp_orders = use_signal(|| Vec::<OrderItem>::new());
     let loading = use_signal(|| false);
-    let mut refreshing = use_signal(|| false);
+    // 手动刷新/重试计数，变化时重新加载订单
+    let mut reload = use_signal(|| 0u32);
     let error_message = use_signal(|| Option::<String>::None);
     let mut active_tab = use_signal(|| "onramp".to_string()); // "onramp" or "offramp"
 
     // 搜索和筛选状态
     let mut search_query = use_signal(|| String::new());
-    let mut status_filter = use_signal(|| "all".to_string()); // "all", "pending", "completed", "failed"
+    let mut status_filter = use_signal(|| "all".to_string()); // "all", "pending", "completed", "failed", "closed"
     let expanded_order = use_signal(|| Option::<String>::None); // 展开的订单ID
 
     // 统计信息
@@ -114,10 +126,10 @@ pub fn Orders() -> Element {
                             monthly_limit: kyc_status.monthly_limit,
                         });
 
-                        tracing::info!("[Orders] KYC status loaded: {:?}", kyc_status.kyc_status);
+                        log::info!("[Orders] KYC status loaded: {:?}", kyc_status.kyc_status);
                     }
                     Err(e) => {
-                        tracing::error!("[Orders] Failed to load KYC status: {}", e);
+                        log::error!("[Orders] Failed to load KYC status: {}", e);
                         // 保持默认的未认证状态
                     }
                 }
@@ -136,6 +148,7 @@ pub fn Orders() -> Element {
         let mut offramp_stats_sig = offramp_stats;
 
         move || {
+            let _ = reload();
             spawn(async move {
                 loading_sig.set(true);
                 error_sig.set(None);
@@ -152,15 +165,24 @@ pub fn Orders() -> Element {
 
                 // 加载充值订单
                 let onramp_service = FiatOnrampService::new(*app_state_clone.read());
-                match onramp_service.get_orders(None, None, None).await {
+                match onramp_service
+                    .get_orders(None, None, None, None, None)
+                    .await
+                {
                     Ok(orders) => {
+                        // 超过支付时限仍未支付的订单显示为已过期
+                        let now = chrono::Utc::now().timestamp();
                         let order_items: Vec<OrderItem> = orders
                             .orders
                             .into_iter()
                             .map(|o| OrderItem {
                                 order_id: o.order_id.clone(),
                                 order_type: "onramp".to_string(),
-                                status: o.status.clone(),
+                                status: onramp_orders::effective_status(
+                                    &o.order_id,
+                                    &o.status,
+                                    now,
+                                ),
                                 fiat_amount: o.fiat_amount.clone(),
                                 crypto_amount: o.crypto_amount.clone(),
                                 currency: "USD".to_string(), // 从后端订单不包含这些字段，使用默认值
@@ -170,6 +192,7 @@ pub fn Orders() -> Element {
                                 payment_url: o.payment_url.clone(),
                                 tx_hash: o.tx_hash.clone(),
                                 error_message: o.error_message.clone(),
+                                expected_by: None,
                             })
                             .collect();
 
@@ -193,7 +216,7 @@ pub fn Orders() -> Element {
                         onramp_orders_sig.set(order_items);
                     }
                     Err(e) => {
-                        tracing::error!("Failed to load onramp orders: {}", e);
+                        log::error!("Failed to load onramp orders: {}", e);
                         error_sig.set(Some(crate::shared::ui_error::sanitize_user_message(
                             format!("加载充值订单失败: {}", e),
                         )));
@@ -202,7 +225,10 @@ pub fn Orders() -> Element {
 
                 // 加载提现订单
                 let offramp_service = FiatOfframpService::new(*app_state_clone.read());
-                match offramp_service.get_orders(None, None, None).await {
+                match offramp_service
+                    .get_orders(None, None, None, None, None)
+                    .await
+                {
                     Ok(orders) => {
                         let order_items: Vec<OrderItem> = orders
                             .orders
@@ -220,6 +246,7 @@ pub fn Orders() -> Element {
                                 payment_url: None, // offramp没有支付URL
                                 tx_hash: o.withdrawal_tx_hash.clone(),
                                 error_message: o.error_message.clone(),
+                                expected_by: withdrawal_eta::expected_by_for(&o),
                             })
                             .collect();
 
@@ -243,7 +270,7 @@ pub fn Orders() -> Element {
                         offramp_orders_sig.set(order_items);
                     }
                     Err(e) => {
-                        tracing::error!("Failed to load offramp orders: {}", e);
+                        log::error!("Failed to load offramp orders: {}", e);
                         error_sig.set(Some(crate::shared::ui_error::sanitize_user_message(
                             format!("加载提现订单失败: {}", e),
                         )));
@@ -280,19 +307,9 @@ pub fn Orders() -> Element {
                     Button {
                         variant: ButtonVariant::Secondary,
                         size: ButtonSize::Small,
-                        disabled: *loading.read() || *refreshing.read(),
-                        onclick: move |_| {
-                            refreshing.set(true);
-                            // 触发重新加载（通过改变依赖来触发use_effect）
-                            let app_state_clone = app_state.clone();
-                            spawn(async move {
-                                // 简单延迟模拟刷新
-                                gloo_timers::future::TimeoutFuture::new(500).await;
-                                refreshing.set(false);
-                                // 实际应该触发重新加载，这里简化处理
-                            });
-                        },
-                        if *refreshing.read() { "刷新中..." } else { "🔄 刷新" }
+                        disabled: *loading.read(),
+                        onclick: move |_| reload += 1,
+                        if *loading.read() { "刷新中..." } else { "🔄 刷新" }
                     }
                 }
 
@@ -410,6 +427,7 @@ pub fn Orders() -> Element {
                                             Colors::BG_PRIMARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
                                         r#type: "text",
                                         placeholder: "搜索订单ID...",
+                                        "data-shortcut-search": "true",
                                         value: "{search_query.read()}",
                                         oninput: move |evt| search_query.set(evt.value().clone()),
                                     }
@@ -440,6 +458,12 @@ pub fn Orders() -> Element {
                                         onclick: move |_| status_filter.set("failed".to_string()),
                                         "失败"
                                     }
+                                    Button {
+                                        variant: if *status_filter.read() == "closed" { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+                                        size: ButtonSize::Small,
+                                        onclick: move |_| status_filter.set("closed".to_string()),
+                                        "已取消/过期"
+                                    }
                                 }
                             }
                         }
@@ -463,18 +487,10 @@ pub fn Orders() -> Element {
 
                 // 加载中
                 if *loading.read() {
-                    Card {
-                        variant: crate::components::atoms::card::CardVariant::Base,
-                        padding: Some("32px".to_string()),
-                        children: rsx! {
-                            div { class: "text-center py-12",
-                                div { class: "text-4xl mb-4", "⏳" }
-                                p {
-                                    class: "text-sm",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "加载订单中..."
-                                }
-                            }
+                    div {
+                        class: "space-y-3",
+                        for i in 0..4 {
+                            SkeletonTableRow { key: "{i}", icon: false }
                         }
                     }
                 } else {
@@ -492,52 +508,40 @@ pub fn Orders() -> Element {
                             orders.retain(|o| o.order_id.to_lowercase().contains(&search));
                         }
 
-                        // 应用状态筛选
+                        // 应用状态筛选（“全部”不含已取消、已过期的订单）
                         let filter = status_filter.read().clone();
-                        if filter != "all" {
-                            orders.retain(|o| {
-                                match filter.as_str() {
-                                    "pending" => o.status == "pending",
-                                    "completed" => o.status == "completed",
-                                    "failed" => o.status == "failed" || o.status == "cancelled",
-                                    _ => true,
-                                }
-                            });
-                        }
+                        orders.retain(|o| {
+                            match filter.as_str() {
+                                "pending" => o.status == "pending",
+                                "completed" => o.status == "completed",
+                                "failed" => o.status == "failed",
+                                "closed" => onramp_orders::is_closed(&o.status),
+                                _ => !onramp_orders::is_closed(&o.status),
+                            }
+                        });
 
-                        if orders.is_empty() {
+                        if orders.is_empty() && error_message.read().is_some() {
+                            rsx! {
+                                LoadFailedState {
+                                    title: t("empty.load_failed.title"),
+                                    retry_label: t("empty.load_failed.retry"),
+                                    on_retry: move |_| reload += 1,
+                                }
+                            }
+                        } else if orders.is_empty() {
                             rsx! {
                                 Card {
                                     variant: crate::components::atoms::card::CardVariant::Base,
-                                    padding: Some("32px".to_string()),
+                                    padding: Some("16px".to_string()),
                                     children: rsx! {
-                                        div { class: "text-center py-12",
-                                            div { class: "text-6xl mb-4", "📋" }
-                                            h3 {
-                                                class: "text-xl font-semibold mb-2",
-                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                "暂无订单"
-                                            }
-                                            p {
-                                                class: "text-sm mb-6",
-                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                                if *active_tab.read() == "onramp" {
-                                                    "您还没有充值订单，去充值页面创建第一笔订单吧！"
-                                                } else {
-                                                    "您还没有提现订单"
-                                                }
-                                            }
-                                            if *active_tab.read() == "onramp" {
-                                                Button {
-                                                    variant: ButtonVariant::Primary,
-                                                    size: ButtonSize::Medium,
-                                                    onclick: move |_| {
-                                    let nav = navigator.clone();
-                                    nav.push(crate::router::Route::Buy {});
-                                },
-                                                    "去充值"
-                                                }
-                                            }
+                                        EmptyState {
+                                            icon: "📋".to_string(),
+                                            title: t("empty.orders.title"),
+                                            description: t("empty.orders.description"),
+                                            cta_label: t("empty.orders.cta"),
+                                            on_cta: move |_| {
+                                                navigator.push(crate::router::Route::Buy {});
+                                            },
                                         }
                                     }
                                 }
@@ -549,6 +553,7 @@ pub fn Orders() -> Element {
                                         EnhancedOrderCard {
                                             order: order.clone(),
                                             expanded_order: expanded_order,
+                                            on_changed: move |_| reload += 1,
                                         }
                                     }
                                 }
@@ -563,9 +568,16 @@ pub fn Orders() -> Element {
 
 /// 增强订单卡片组件（企业级）
 #[component]
-fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -> Element {
+fn EnhancedOrderCard(
+    order: OrderItem,
+    expanded_order: Signal<Option<String>>,
+    /// 订单被取消等状态变化后刷新列表
+    on_changed: EventHandler<()>,
+) -> Element {
     // 企业级最佳实践：使用Arc共享所有权，避免多次clone的内存开销
     // 在组件初始化时创建Arc，后续所有闭包共享同一个Arc引用
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
     let order_arc = Arc::new(order);
     let is_expanded = expanded_order
         .read()
@@ -576,7 +588,7 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
         "pending" => "rgba(251, 191, 36, 1)",
         "processing" => "rgba(59, 130, 246, 1)",
         "completed" => "rgba(34, 197, 94, 1)",
-        "failed" | "cancelled" => "rgba(239, 68, 68, 1)",
+        "failed" | "cancelled" | "expired" => "rgba(239, 68, 68, 1)",
         _ => Colors::TEXT_SECONDARY,
     };
 
@@ -584,7 +596,7 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
         "pending" => "rgba(251, 191, 36, 0.1)",
         "processing" => "rgba(59, 130, 246, 0.1)",
         "completed" => "rgba(34, 197, 94, 0.1)",
-        "failed" | "cancelled" => "rgba(239, 68, 68, 0.1)",
+        "failed" | "cancelled" | "expired" => "rgba(239, 68, 68, 0.1)",
         _ => Colors::BG_SECONDARY,
     };
 
@@ -594,9 +606,29 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
         "completed" => "已完成",
         "failed" => "失败",
         "cancelled" => "已取消",
+        "expired" => "已过期",
         _ => "未知",
     };
 
+    // 充值订单在扣款前可取消
+    let cancel_safety = (order_arc.order_type == "onramp")
+        .then(|| CancelSafety::for_status(&order_arc.status))
+        .flatten();
+    let mut confirm_cancel = use_signal(|| false);
+    let mut cancelling = use_signal(|| false);
+
+    // 未完成提现的预计到账时间
+    let eta_pending = order_arc
+        .expected_by
+        .filter(|_| !withdrawal_eta::is_settled(&order_arc.status));
+    let overdue = eta_pending.is_some_and(|expected_by| {
+        withdrawal_eta::is_overdue(
+            expected_by,
+            &order_arc.status,
+            chrono::Utc::now().timestamp(),
+        )
+    });
+
     rsx! {
         Card {
             variant: crate::components::atoms::card::CardVariant::Base,
@@ -685,7 +717,20 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
                         div {
                             class: "text-xs",
                             style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            {order_arc.created_at.chars().take(16).collect::<String>()}
+                            {time::format_api_datetime(&order_arc.created_at, &app_state.language.read())}
+                        }
+                    }
+
+                    // 提现预计到账（逾期未到账时突出显示）
+                    if let Some(expected_by) = eta_pending {
+                        div {
+                            class: "text-xs",
+                            style: format!("color: {};", if overdue { Colors::PAYMENT_WARNING } else { Colors::TEXT_SECONDARY }),
+                            if overdue {
+                                {format!("{}，目前仍未到账", withdrawal_eta::expected_label(expected_by))}
+                            } else {
+                                {withdrawal_eta::expected_label(expected_by)}
+                            }
                         }
                     }
 
@@ -742,6 +787,22 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
 
                             // 操作按钮
                             div { class: "flex gap-2 pt-2",
+                                // 继续支付：回到兑换页的购买标签，按订单当前状态恢复支付弹窗
+                                if order_arc.status == "pending" && order_arc.order_type == "onramp" {
+                                    Button {
+                                        variant: ButtonVariant::Primary,
+                                        size: ButtonSize::Small,
+                                        onclick: {
+                                            let order_id = order_arc.order_id.clone();
+                                            move |_| {
+                                                onramp_orders::request_resume(&order_id);
+                                                navigator.push(crate::router::Route::Swap {});
+                                            }
+                                        },
+                                        "继续支付"
+                                    }
+                                }
+
                                 // 支付按钮（仅pending状态的onramp订单）
                                 if order_arc.status == "pending" && order_arc.order_type == "onramp" {
                                     if let Some(ref payment_url) = order_arc.payment_url {
@@ -762,27 +823,70 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
                                     }
                                 }
 
+                                // 取消订单（待支付/支付处理中的充值订单）
+                                if cancel_safety.is_some() {
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        size: ButtonSize::Small,
+                                        onclick: move |_| confirm_cancel.set(true),
+                                        "取消订单"
+                                    }
+                                }
+
                                 // 复制订单ID按钮
+                                CopyButton {
+                                    value: order_arc.order_id.clone(),
+                                    label: "复制ID".to_string(),
+                                }
+
+                                // 预计到账日加入日历
+                                if let Some(expected_by) = eta_pending {
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        size: ButtonSize::Small,
+                                        onclick: {
+                                            let order = order_arc.clone();
+                                            move |_| {
+                                                download_eta_ics(app_state, &WithdrawalEta {
+                                                    order_id: order.order_id.clone(),
+                                                    expected_by,
+                                                    fiat_amount: order.fiat_amount.clone(),
+                                                    fiat_currency: order.currency.clone(),
+                                                    followed_up: false,
+                                                });
+                                            }
+                                        },
+                                        "📅 添加到日历"
+                                    }
+                                }
+
+                                // 逾期未到账：提交工单并附带订单号
+                                if let Some(expected_by) = eta_pending.filter(|_| overdue) {
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        size: ButtonSize::Small,
+                                        onclick: {
+                                            let order_id = order_arc.order_id.clone();
+                                            move |_| {
+                                                app_state.open_help_with(HelpPanelRequest {
+                                                    order_id: Some(order_id.clone()),
+                                                    draft: Some(withdrawal_eta::delay_report(&order_id, expected_by)),
+                                                })
+                                            }
+                                        },
+                                        "报告延迟到账"
+                                    }
+                                }
+
+                                // 联系客服（工单附带该订单号）
                                 Button {
                                     variant: ButtonVariant::Secondary,
                                     size: ButtonSize::Small,
                                     onclick: {
                                         let order_id = order_arc.order_id.clone();
-                                        move |_| {
-                                            // 复制到剪贴板
-                                            if let Some(window) = web_sys::window() {
-                                                let navigator = window.navigator();
-                                                let clipboard = navigator.clipboard();
-                                                let id = order_id.clone();
-                                                wasm_bindgen_futures::spawn_local(async move {
-                                                    let _ = wasm_bindgen_futures::JsFuture::from(
-                                                        clipboard.write_text(&id)
-                                                    ).await;
-                                                });
-                                            }
-                                        }
+                                        move |_| app_state.open_help(Some(order_id.clone()))
                                     },
-                                    "📋 复制ID"
+                                    "联系客服"
                                 }
                             }
                         }
@@ -790,6 +894,49 @@ fn EnhancedOrderCard(order: OrderItem, expanded_order: Signal<Option<String>>) -
                 }
             }
         }
+        if let Some(safety) = cancel_safety {
+            ConfirmAction {
+                open: confirm_cancel(),
+                risk: if safety.is_safe() { RiskLevel::Caution } else { RiskLevel::Destructive },
+                title: safety.title().to_string(),
+                message: safety.message(&order_arc.order_id),
+                details: safety.details(),
+                confirm_text: "确认取消".to_string(),
+                cancel_text: "保留订单".to_string(),
+                busy: cancelling(),
+                on_cancel: move |_| confirm_cancel.set(false),
+                on_confirm: {
+                    let order_id = order_arc.order_id.clone();
+                    move |_| {
+                        let order_id = order_id.clone();
+                        cancelling.set(true);
+                        spawn(async move {
+                            match onramp_orders::cancel(app_state, &order_id).await {
+                                Ok(()) => {
+                                    AppState::show_success(
+                                        app_state.toasts,
+                                        format!("订单 {} 已取消", order_id),
+                                    );
+                                    confirm_cancel.set(false);
+                                    on_changed.call(());
+                                }
+                                Err(e) => AppState::show_error(app_state.toasts, e),
+                            }
+                            cancelling.set(false);
+                        });
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 下载提现预计到账的日历提醒（ICS）
+pub(crate) fn download_eta_ics(app_state: AppState, eta: &WithdrawalEta) {
+    let ics = withdrawal_eta::to_ics(eta, chrono::Utc::now());
+    let filename = format!("withdrawal-{}.ics", eta.order_id);
+    if let Err(e) = crate::pages::receipt::download_bytes(ics.as_bytes(), &filename) {
+        AppState::show_error(app_state.toasts, e);
     }
 }
 
@@ -800,7 +947,7 @@ fn BasicOrderCard(order: OrderItem) -> Element {
         "pending" => "rgba(251, 191, 36, 1)",
         "processing" => "rgba(59, 130, 246, 1)",
         "completed" => "rgba(34, 197, 94, 1)",
-        "failed" | "cancelled" => "rgba(239, 68, 68, 1)",
+        "failed" | "cancelled" | "expired" => "rgba(239, 68, 68, 1)",
         _ => Colors::TEXT_SECONDARY,
     };
 
@@ -808,7 +955,7 @@ fn BasicOrderCard(order: OrderItem) -> Element {
         "pending" => "rgba(251, 191, 36, 0.1)",
         "processing" => "rgba(59, 130, 246, 0.1)",
         "completed" => "rgba(34, 197, 94, 0.1)",
-        "failed" | "cancelled" => "rgba(239, 68, 68, 0.1)",
+        "failed" | "cancelled" | "expired" => "rgba(239, 68, 68, 0.1)",
         _ => Colors::BG_SECONDARY,
     };
 
@@ -818,6 +965,7 @@ fn BasicOrderCard(order: OrderItem) -> Element {
         "completed" => "已完成",
         "failed" => "失败",
         "cancelled" => "已取消",
+        "expired" => "已过期",
         _ => "未知",
     };
 
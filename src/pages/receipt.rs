@@ -0,0 +1,453 @@
+//! Transaction Receipt Page - 交易收据页
+//! 转账或兑换完成后的付款凭证：可复制链接、下载 PNG / PDF 或直接打印，
+//! 全部在客户端生成；打印时仅保留收据本身，不包含其他钱包数据
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::copy_button::copy_text;
+use crate::components::molecules::toast::ToastType;
+use crate::components::route_guard::AuthGuard;
+use crate::features::activity::receipt::{self, FiatValue, Receipt};
+use crate::services::address_detector::ChainType;
+use crate::services::price::PriceService;
+use crate::services::transaction::TransactionService;
+use crate::services::transaction_history::{TransactionHistoryQuery, TransactionHistoryService};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// 打印时隐藏收据以外的所有内容
+const PRINT_CSS: &str = "@media print {
+  body * { visibility: hidden !important; }
+  #tx-receipt, #tx-receipt * { visibility: visible !important; }
+  #tx-receipt { position: absolute; left: 0; top: 0; width: 100%; border: none !important; box-shadow: none !important; }
+  .receipt-actions { display: none !important; }
+}";
+
+/// 收据固定为白底深色字，屏幕与打印一致
+const PAPER_TEXT: &str = "#111827";
+const PAPER_MUTED: &str = "#6B7280";
+const PAPER_BORDER: &str = "#E5E7EB";
+
+/// PNG 画布宽度与每行最多字符数
+const PNG_WIDTH: u32 = 800;
+const PNG_WRAP_CHARS: usize = 64;
+const PNG_QR_SIZE: f64 = 160.0;
+/// 字段区域起始高度（标题与二维码下方）
+const PNG_BODY_TOP: f64 = 30.0 + PNG_QR_SIZE + 20.0;
+
+/// 交易收据页面
+#[component]
+pub fn TxReceipt(chain: String, hash: String) -> Element {
+    rsx! {
+        AuthGuard {
+            TxReceiptContent { chain, hash }
+        }
+    }
+}
+
+#[component]
+fn TxReceiptContent(chain: String, hash: String) -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+
+    let loaded = use_resource(use_reactive!(|(chain, hash)| async move {
+        load_receipt(app_state, &chain, &hash).await
+    }));
+
+    let content = match &*loaded.read() {
+        None => rsx! {
+            div {
+                class: "text-center py-12",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "正在生成收据..."
+            }
+        },
+        Some(Err(e)) => rsx! {
+            div {
+                class: "p-6 rounded-lg text-center",
+                style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::PAYMENT_ERROR),
+                {e.clone()}
+            }
+        },
+        Some(Ok(receipt)) => rsx! {
+            ReceiptView { receipt: receipt.clone() }
+        },
+    };
+
+    rsx! {
+        style { {PRINT_CSS} }
+        div {
+            class: "max-w-2xl mx-auto px-4 py-8",
+            div {
+                class: "receipt-actions mb-4",
+                button {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    onclick: move |_| {
+                        navigator.go_back();
+                    },
+                    "← 返回"
+                }
+            }
+            {content}
+        }
+    }
+}
+
+/// 收据卡片与导出操作
+#[component]
+fn ReceiptView(receipt: Receipt) -> Element {
+    let app_state = use_context::<AppState>();
+    let qr_svg = use_memo({
+        let url = receipt.explorer_url.clone();
+        move || url.as_deref().map(qr_svg).unwrap_or_default()
+    });
+
+    let link_receipt = receipt.clone();
+    let on_copy_link = move |_| {
+        // 优先分享区块浏览器链接，对方无需登录即可核验
+        let link = link_receipt
+            .explorer_url
+            .clone()
+            .or_else(|| web_sys::window().and_then(|w| w.location().href().ok()))
+            .unwrap_or_default();
+        spawn(async move {
+            let (message, toast_type) = match copy_text(&link).await {
+                Ok(()) => ("链接已复制".to_string(), ToastType::Success),
+                Err(e) => (format!("复制失败：{}", e), ToastType::Error),
+            };
+            AppState::show_toast(app_state.toasts, message, toast_type, Some(2500));
+        });
+    };
+
+    let png_receipt = receipt.clone();
+    let on_png = move |_| {
+        if let Err(e) = download_png(&png_receipt) {
+            AppState::show_toast(
+                app_state.toasts,
+                format!("生成图片失败：{}", e),
+                ToastType::Error,
+                Some(4000),
+            );
+        }
+    };
+
+    let pdf_receipt = receipt.clone();
+    let on_pdf = move |_| {
+        let bytes = receipt::render_pdf(&pdf_receipt);
+        if let Err(e) = download_bytes(&bytes, &format!("{}.pdf", pdf_receipt.file_stem())) {
+            AppState::show_toast(
+                app_state.toasts,
+                format!("生成 PDF 失败：{}", e),
+                ToastType::Error,
+                Some(4000),
+            );
+        }
+    };
+
+    rsx! {
+        div {
+            id: "tx-receipt",
+            class: "p-8 rounded-xl",
+            style: format!("background: white; color: {}; border: 1px solid {};", PAPER_TEXT, PAPER_BORDER),
+            div {
+                class: "flex justify-between items-start gap-6 mb-6",
+                div {
+                    h1 { class: "text-2xl font-bold", "交易收据" }
+                    p {
+                        class: "text-sm mt-1",
+                        style: format!("color: {};", PAPER_MUTED),
+                        {format!("{} · {}", receipt.kind.label(), receipt::format_utc(receipt.timestamp))}
+                    }
+                }
+                if !qr_svg.read().is_empty() {
+                    div {
+                        class: "w-32 h-32 shrink-0",
+                        title: "扫码在区块浏览器中核验",
+                        dangerous_inner_html: qr_svg.read().clone(),
+                    }
+                }
+            }
+            div {
+                class: "divide-y",
+                style: format!("border-color: {};", PAPER_BORDER),
+                for field in receipt.fields() {
+                    div {
+                        key: "{field.label}",
+                        class: "flex justify-between gap-6 py-2 text-sm",
+                        style: format!("border-color: {};", PAPER_BORDER),
+                        span { style: format!("color: {};", PAPER_MUTED), {field.label} }
+                        span { class: "text-right font-mono break-all", {field.value} }
+                    }
+                }
+            }
+            if let Some(url) = receipt.explorer_url.clone() {
+                p {
+                    class: "text-xs mt-4 break-all",
+                    style: format!("color: {};", PAPER_MUTED),
+                    {format!("区块浏览器：{}", url)}
+                }
+            }
+        }
+
+        div {
+            class: "receipt-actions flex flex-wrap gap-3 mt-6",
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: on_copy_link,
+                "🔗 复制链接"
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: on_png,
+                "🖼️ 下载 PNG"
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: on_pdf,
+                "📄 下载 PDF"
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: move |_| {
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.print();
+                    }
+                },
+                "🖨️ 打印"
+            }
+        }
+    }
+}
+
+/// 查找交易并补全区块高度与确认时法币价值
+async fn load_receipt(app_state: AppState, chain: &str, hash: &str) -> Result<Receipt, String> {
+    let chain_type =
+        ChainType::from_str(chain).ok_or_else(|| format!("不支持的网络：{}", chain))?;
+    let tx_service = TransactionService::new(app_state);
+
+    let addresses: Vec<String> = app_state
+        .wallet
+        .read()
+        .wallets
+        .iter()
+        .flat_map(|w| w.accounts.iter())
+        .filter(|a| ChainType::from_str(&a.chain) == Some(chain_type))
+        .map(|a| a.address.clone())
+        .collect();
+
+    let mut found = None;
+    for address in &addresses {
+        if let Ok(txs) = tx_service.get_history(address, chain_type.as_str()).await {
+            if let Some(tx) = txs.iter().find(|t| t.hash.eq_ignore_ascii_case(hash)) {
+                found = Some(Receipt::from_transfer(chain_type.as_str(), tx));
+                break;
+            }
+        }
+    }
+    if found.is_none() {
+        let query = TransactionHistoryQuery {
+            tx_type: Some("swap".to_string()),
+            status: None,
+            page: Some(1),
+            page_size: Some(100),
+            start_date: None,
+            end_date: None,
+        };
+        if let Ok(history) = TransactionHistoryService::new(app_state)
+            .get_history(Some(query))
+            .await
+        {
+            found = history
+                .transactions
+                .iter()
+                .filter(|t| {
+                    t.tx_hash
+                        .as_deref()
+                        .is_some_and(|h| h.eq_ignore_ascii_case(hash))
+                })
+                .find_map(|t| Receipt::from_swap(chain_type.as_str(), t));
+        }
+    }
+    let mut receipt = found.ok_or_else(|| "未找到该交易，无法生成收据".to_string())?;
+
+    if let Ok(status) = tx_service.status_on_chain(hash, chain_type.as_str()).await {
+        receipt.block_number = status.block_number;
+    }
+    receipt.fiat_value = fiat_value_at_confirmation(app_state, &receipt).await;
+    Ok(receipt)
+}
+
+/// 按确认时的历史价格与当前汇率折算为偏好法币
+async fn fiat_value_at_confirmation(app_state: AppState, receipt: &Receipt) -> Option<FiatValue> {
+    let amount = receipt.amount.trim().parse::<f64>().ok()?;
+    let now = crate::shared::time::now_unix();
+    let points = PriceService::new(app_state)
+        .get_price_history(
+            &receipt.token,
+            receipt::lookback_hours(receipt.timestamp, now),
+        )
+        .await
+        .ok()?;
+    let usd_price = receipt::price_at(&points, receipt.timestamp)?;
+    let currency = app_state.preferences.read().currency.clone();
+    let rate = PriceService::new(app_state)
+        .get_fiat_rate(currency.code())
+        .await
+        .ok()?;
+    Some(FiatValue {
+        amount: amount * usd_price * rate,
+        currency: currency.code().to_string(),
+    })
+}
+
+fn qr_svg(data: &str) -> String {
+    use qrcode::render::svg;
+    qrcode::QrCode::new(data)
+        .map(|qr| {
+            qr.render::<svg::Color>()
+                .min_dimensions(128, 128)
+                .max_dimensions(128, 128)
+                .build()
+        })
+        .unwrap_or_default()
+}
+
+/// 在离屏画布上绘制收据并下载为 PNG
+fn download_png(receipt: &Receipt) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let canvas = document
+        .create_element("canvas")
+        .map_err(|_| "无法创建画布".to_string())?
+        .dyn_into::<web_sys::HtmlCanvasElement>()
+        .map_err(|_| "无法创建画布".to_string())?;
+
+    // 每个字段：标签一行 + 值按宽度折行
+    let fields = receipt.fields();
+    let rows: Vec<(&str, Vec<String>)> = fields
+        .iter()
+        .map(|f| {
+            let chars: Vec<char> = f.value.chars().collect();
+            let lines = chars
+                .chunks(PNG_WRAP_CHARS)
+                .map(|c| c.iter().collect())
+                .collect();
+            (f.label, lines)
+        })
+        .collect();
+    let body_height: usize = rows
+        .iter()
+        .map(|(_, lines)| 22 + lines.len() * 20 + 12)
+        .sum();
+    let height = PNG_BODY_TOP as u32 + body_height as u32 + 40;
+    canvas.set_width(PNG_WIDTH);
+    canvas.set_height(height);
+
+    let ctx = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|c| c.dyn_into::<web_sys::CanvasRenderingContext2d>().ok())
+        .ok_or("无法获取绘图上下文")?;
+
+    ctx.set_fill_style_str("#FFFFFF");
+    ctx.fill_rect(0.0, 0.0, PNG_WIDTH as f64, height as f64);
+
+    ctx.set_fill_style_str(PAPER_TEXT);
+    ctx.set_font("bold 28px sans-serif");
+    let _ = ctx.fill_text("交易收据", 40.0, 60.0);
+    ctx.set_fill_style_str(PAPER_MUTED);
+    ctx.set_font("16px sans-serif");
+    let _ = ctx.fill_text(
+        &format!(
+            "{} · {}",
+            receipt.kind.label(),
+            receipt::format_utc(receipt.timestamp)
+        ),
+        40.0,
+        92.0,
+    );
+
+    if let Some((width, modules)) = receipt
+        .explorer_url
+        .as_deref()
+        .and_then(receipt::qr_modules)
+    {
+        let cell = PNG_QR_SIZE / width as f64;
+        let left = PNG_WIDTH as f64 - 40.0 - PNG_QR_SIZE;
+        ctx.set_fill_style_str("#000000");
+        for (i, dark) in modules.iter().enumerate() {
+            if *dark {
+                let (row, col) = (i / width, i % width);
+                ctx.fill_rect(
+                    left + col as f64 * cell,
+                    30.0 + row as f64 * cell,
+                    cell.ceil(),
+                    cell.ceil(),
+                );
+            }
+        }
+    }
+
+    let mut y = PNG_BODY_TOP;
+    for (label, lines) in rows {
+        ctx.set_fill_style_str(PAPER_MUTED);
+        ctx.set_font("14px sans-serif");
+        let _ = ctx.fill_text(label, 40.0, y);
+        y += 22.0;
+        ctx.set_fill_style_str(PAPER_TEXT);
+        ctx.set_font("16px monospace");
+        for line in lines {
+            let _ = ctx.fill_text(&line, 40.0, y);
+            y += 20.0;
+        }
+        y += 12.0;
+    }
+
+    let data_url = canvas
+        .to_data_url_with_type("image/png")
+        .map_err(|_| "导出图片失败".to_string())?;
+    trigger_download(&data_url, &format!("{}.png", receipt.file_stem()))
+}
+
+/// 以 Blob 下载二进制内容
+pub(crate) fn download_bytes(bytes: &[u8], filename: &str) -> Result<(), String> {
+    let parts = js_sys::Array::new();
+    parts.push(&js_sys::Uint8Array::from(bytes));
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&parts).map_err(|_| "创建文件失败")?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(|_| "创建文件失败")?;
+    let result = trigger_download(&url, filename);
+    spawn(async move {
+        gloo_timers::future::TimeoutFuture::new(200).await;
+        let _ = web_sys::Url::revoke_object_url(&url);
+    });
+    result
+}
+
+/// 通过临时链接触发浏览器下载
+fn trigger_download(href: &str, filename: &str) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let link = document
+        .create_element("a")
+        .map_err(|_| "创建下载链接失败".to_string())?
+        .dyn_into::<web_sys::HtmlElement>()
+        .map_err(|_| "创建下载链接失败".to_string())?;
+    let _ = link.set_attribute("href", href);
+    let _ = link.set_attribute("download", filename);
+    let _ = link.set_attribute("style", "display: none");
+    let body = document.body().ok_or("No body")?;
+    body.append_child(&link)
+        .map_err(|_| "创建下载链接失败".to_string())?;
+    link.click();
+    link.remove();
+    Ok(())
+}
@@ -1,10 +1,13 @@
 //! Receive Page - 接收页面
-//! 显示接收地址和二维码，支持多链
+//! 显示接收地址和二维码，支持多链；按钱包记住上次收款的链与账户
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
-use crate::components::molecules::{ChainSelector, QrCodeDisplay};
+use crate::components::molecules::{ChainAccountPrompt, ChainSelector, QrCodeDisplay};
+use crate::features::wallet::receive_prefs;
 use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainFeature;
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
@@ -15,7 +18,17 @@ use dioxus::prelude::*;
 pub fn Receive() -> Element {
     let app_state = use_context::<AppState>();
     let navigator = use_navigator();
-    let mut selected_chain = use_signal(|| "ethereum".to_string());
+    // 初始为设置中选择的网络，钱包确定后恢复该钱包上次收款的链
+    let mut selected_chain = use_signal(|| {
+        app_state
+            .preferences
+            .peek()
+            .default_chain
+            .clone()
+            .unwrap_or_else(|| "ethereum".to_string())
+    });
+    // 该链有多个账户时选中的地址（None 表示第一个）
+    let mut selected_address = use_signal(|| Option::<String>::None);
 
     // 企业级：获取钱包状态并进行完整性检查
     let wallet_state_check = use_memo(move || {
@@ -24,17 +37,37 @@ pub fn Receive() -> Element {
         let selected_wallet = wallet_state.get_selected_wallet();
         (has_wallet, selected_wallet.cloned())
     });
+    let selected_wallet_id =
+        use_memo(move || wallet_state_check.read().1.as_ref().map(|w| w.id.clone()));
 
-    // 获取当前选中链的账户
-    let current_account = use_memo(move || {
+    // 切换钱包时恢复该钱包上次收款的链与账户
+    use_effect(move || {
+        let Some(wallet_id) = selected_wallet_id() else {
+            return;
+        };
+        let last = receive_prefs::last_choice(&wallet_id);
+        let default_chain = app_state.preferences.peek().default_chain.clone();
+        let chain = receive_prefs::initial_chain(last.as_ref(), default_chain.as_deref());
+        selected_chain.set(chain.as_str().to_string());
+        selected_address.set(last.and_then(|c| c.address));
+    });
+
+    let chain = use_memo(move || {
+        ChainType::from_str(&selected_chain.read()).unwrap_or(ChainType::Ethereum)
+    });
+
+    // 可用于所选链收款的账户（EVM 链共用同一地址；没有该链账户时为空，绝不显示其他链的地址）
+    let chain_accounts = use_memo(move || {
         let state_check = wallet_state_check.read();
-        let (_, wallet_opt) = &*state_check;
-        wallet_opt.as_ref().and_then(|w| {
-            w.accounts
-                .iter()
-                .find(|acc| acc.chain.to_lowercase() == selected_chain.read().to_lowercase())
-                .cloned()
-        })
+        state_check
+            .1
+            .as_ref()
+            .map(|w| receive_prefs::receive_accounts(w, chain()))
+            .unwrap_or_default()
+    });
+    let current_account = use_memo(move || {
+        receive_prefs::pick_account(&chain_accounts.read(), selected_address.read().as_deref())
+            .cloned()
     });
 
     rsx! {
@@ -98,7 +131,44 @@ pub fn Receive() -> Element {
                 } else {
                     // 链选择器
                     ChainSelector {
-                        selected_chain: selected_chain
+                        selected_chain: selected_chain,
+                        feature: ChainFeature::Receive,
+                        onselect: move |picked: ChainType| {
+                            selected_address.set(None);
+                            if let Some(wallet_id) = selected_wallet_id() {
+                                receive_prefs::remember(&wallet_id, picked, None);
+                            }
+                        },
+                    }
+
+                    // 账户选择（该链有多个账户时）
+                    if chain_accounts.read().len() > 1 {
+                        div {
+                            class: "mb-4 flex items-center gap-3",
+                            span {
+                                class: "text-sm",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "收款账户"
+                            }
+                            select {
+                                class: "flex-1 px-3 py-2 text-sm rounded-lg",
+                                style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
+                                value: current_account.read().as_ref().map(|a| a.address.clone()).unwrap_or_default(),
+                                onchange: move |e| {
+                                    let address = e.value();
+                                    if let Some(wallet_id) = selected_wallet_id() {
+                                        receive_prefs::remember(&wallet_id, chain(), Some(&address));
+                                    }
+                                    selected_address.set(Some(address));
+                                },
+                                for (index, account) in chain_accounts.read().iter().enumerate() {
+                                    option {
+                                        value: "{account.address}",
+                                        {format!("账户 {} · {}", index + 1, account.short_address())}
+                                    }
+                                }
+                            }
+                        }
                     }
 
                     if let Some(account) = current_account.as_ref() {
@@ -119,7 +189,7 @@ pub fn Receive() -> Element {
                                         span {
                                             class: "text-lg font-bold",
                                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                            {account.chain_label()}
+                                            {chain().label()}
                                         }
                                     }
                                     span {
@@ -131,9 +201,11 @@ pub fn Receive() -> Element {
                             }
 
                             // 二维码显示组件
+                            // 二维码显示组件（边框上标注网络）
                             QrCodeDisplay {
                                 address: account.address.clone(),
-                                show_copy_button: Some(true)
+                                show_copy_button: Some(true),
+                                badge: Some(chain().label().to_string()),
                             }
 
                             // 安全提示 - 更醒目的警告样式
@@ -160,15 +232,12 @@ pub fn Receive() -> Element {
                                         div {
                                             class: "text-sm space-y-2",
                                             style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                            p {
-                                                class: "flex items-start gap-2",
-                                                span { "•" }
-                                                span { {format!("仅向此地址发送 {} 网络的资产", account.chain_label())} }
-                                            }
-                                            p {
-                                                class: "flex items-start gap-2",
-                                                span { "•" }
-                                                span { "跨链转账将导致资产永久丢失，无法找回" }
+                                            for warning in receive_prefs::chain_warnings(chain()) {
+                                                p {
+                                                    class: "flex items-start gap-2",
+                                                    span { "•" }
+                                                    span { {warning} }
+                                                }
                                             }
                                             p {
                                                 class: "flex items-start gap-2",
@@ -182,13 +251,13 @@ pub fn Receive() -> Element {
                         }
                     }
                     } else {
-                        // 企业级：链未支持的友好提示
+                        // 钱包没有该链账户：提示添加，不显示其他链的地址
                         Card {
                             variant: crate::components::atoms::card::CardVariant::Base,
                             padding: Some("32px".to_string()),
                             children: rsx! {
                                 div {
-                                    class: "text-center py-8",
+                                    class: "text-center py-4",
                                     div {
                                         class: "text-5xl mb-4",
                                         "⚠️"
@@ -196,22 +265,19 @@ pub fn Receive() -> Element {
                                     p {
                                         class: "text-lg mb-2",
                                         style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                        "未找到 {selected_chain.read()} 链的账户"
+                                        {format!("当前钱包还没有 {} 账户", chain().label())}
                                     }
                                     p {
-                                        class: "text-sm mb-4",
+                                        class: "text-sm",
                                         style: format!("color: {};", Colors::TEXT_TERTIARY),
-                                        "该钱包暂不支持此链，请选择其他链"
-                                    }
-                                    Button {
-                                        variant: ButtonVariant::Secondary,
-                                        size: ButtonSize::Medium,
-                                        onclick: move |_| {
-                                            selected_chain.set("ethereum".to_string());
-                                        },
-                                        "切换到以太坊"
+                                        "为避免资产发到错误的链，这里不会显示其他链的地址"
                                     }
                                 }
+                                ChainAccountPrompt {
+                                    chain: chain(),
+                                    token_symbol: None,
+                                    unavailable_label: Some(format!("无法在 {} 上收款：当前钱包没有该链账户，请选择其他链", chain().label())),
+                                }
                             }
                         }
                     }
@@ -10,14 +10,22 @@
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
-use crate::components::molecules::ErrorMessage;
+use crate::components::molecules::{BreachCheck, ErrorMessage, PasswordStrengthMeter};
 use crate::features::auth::hooks::use_auth;
+use crate::features::auth::password_strength::estimate;
+use crate::features::marketing::referral;
 use crate::router::Route;
+use crate::services::password_breach;
+use crate::services::referral::{normalize_code, ReferralService};
 use crate::shared::design_tokens::Colors;
 use crate::shared::error::{ApiError, AppError};
 use crate::shared::state::AppState;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+/// 输入停止多久后再做泄露检查（毫秒）
+const BREACH_CHECK_DEBOUNCE_MS: u32 = 600;
 
 fn friendly_register_error(err: &anyhow::Error) -> String {
     if let Some(app_err) = err.downcast_ref::<AppError>() {
@@ -77,8 +85,46 @@ pub fn Register() -> Element {
     let email = use_signal(|| String::new());
     let password = use_signal(|| String::new());
     let confirm_password = use_signal(|| String::new());
+    // 邀请码：落地页链接带入或手动填写，注册成功后绑定
+    let referral_code = use_signal(|| referral::pending_code().unwrap_or_default());
     let error_message = use_signal(|| Option::<String>::None);
     let is_loading = use_signal(|| false);
+    // 泄露检查为可选项，默认关闭；开启后只发送密码哈希前 5 位
+    let mut breach_check_enabled = use_signal(|| false);
+    let mut breach = use_signal(BreachCheck::default);
+    let mut breach_generation = use_signal(|| 0u64);
+
+    let strength = use_memo(move || estimate(&password.read(), &[email.read().as_str()]));
+
+    // 密码变化后防抖查询泄露次数，只采用最后一次输入的结果
+    use_effect(move || {
+        let pwd = password.read().clone();
+        let enabled = breach_check_enabled();
+        let generation = *breach_generation.peek() + 1;
+        breach_generation.set(generation);
+        if !enabled || pwd.chars().count() < 8 {
+            breach.set(BreachCheck::Idle);
+            return;
+        }
+        breach.set(BreachCheck::Checking);
+        spawn(async move {
+            TimeoutFuture::new(BREACH_CHECK_DEBOUNCE_MS).await;
+            if *breach_generation.peek() != generation {
+                return;
+            }
+            let result = match password_breach::check_password(&pwd).await {
+                Ok(0) => BreachCheck::Clean,
+                Ok(count) => BreachCheck::Breached(count),
+                Err(e) => {
+                    log::warn!("泄露密码检查失败: {}", e);
+                    BreachCheck::Unavailable
+                }
+            };
+            if *breach_generation.peek() == generation {
+                breach.set(result);
+            }
+        });
+    });
 
     let handle_register = {
         let email = email;
@@ -105,11 +151,29 @@ pub fn Register() -> Element {
                 return;
             }
 
+            if !strength.peek().is_acceptable() {
+                error_message.set(Some("密码强度太弱，请参考提示修改密码".to_string()));
+                return;
+            }
+
             if pwd != confirm_pwd {
                 error_message.set(Some("两次输入的密码不一致".to_string()));
                 return;
             }
 
+            let code = referral_code.read().trim().to_string();
+            let code = if code.is_empty() {
+                None
+            } else {
+                match normalize_code(&code) {
+                    Some(code) => Some(code),
+                    None => {
+                        error_message.set(Some("邀请码格式不正确".to_string()));
+                        return;
+                    }
+                }
+            };
+
             is_loading.set(true);
             error_message.set(None);
 
@@ -121,17 +185,30 @@ pub fn Register() -> Element {
             spawn(async move {
                 match auth_ctrl.register(&email_val, &pwd, &confirm_pwd).await {
                     Ok(_) => {
+                        // 邀请码绑定失败不影响注册
+                        if let Some(code) = code {
+                            match ReferralService::new(app_state).apply_code(&code).await {
+                                Ok(()) => referral::set_pending_code(None),
+                                Err(e) => {
+                                    log::warn!("{}", e);
+                                    AppState::show_warning(
+                                        app_state.toasts,
+                                        "邀请码未能绑定，请确认邀请码是否有效".to_string(),
+                                    );
+                                }
+                            }
+                        }
                         loading.set(false);
-                        // 注册成功，显示Toast并跳转到Dashboard
+                        // 注册成功，进入邮箱验证页
                         AppState::show_success(app_state.toasts, "注册成功".to_string());
-                        nav.push(Route::Dashboard {});
+                        nav.push(Route::VerifyEmail {});
                     }
                     Err(e) => {
                         loading.set(false);
                         let err_msg = friendly_register_error(&e);
                         #[cfg(debug_assertions)]
                         {
-                            use tracing::warn;
+                            use log::warn;
                             warn!("Register failed (raw): {:#}", e);
                         }
                         AppState::show_error(app_state.toasts, err_msg.clone());
@@ -203,6 +280,26 @@ pub fn Register() -> Element {
                                 }))
                             },
                         }
+                        if !password.read().is_empty() {
+                            PasswordStrengthMeter {
+                                strength: strength(),
+                                breach: breach(),
+                            }
+                        }
+                        label {
+                            class: "mt-3 flex items-start gap-2 cursor-pointer",
+                            input {
+                                r#type: "checkbox",
+                                class: "mt-0.5",
+                                checked: breach_check_enabled(),
+                                onchange: move |_| breach_check_enabled.set(!breach_check_enabled()),
+                            }
+                            span {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "检查密码是否出现在已知数据泄露中（仅发送密码哈希的前 5 位，密码本身不会离开本设备）"
+                            }
+                        }
                     }
 
                     // 确认密码
@@ -224,6 +321,29 @@ pub fn Register() -> Element {
                         }
                     }
 
+                    // 邀请码（可选）
+                    div {
+                        class: "mb-6",
+                        Input {
+                            input_type: InputType::Text,
+                            label: Some("邀请码（可选）".to_string()),
+                            placeholder: Some("填写好友的邀请码".to_string()),
+                            value: Some(referral_code.read().clone()),
+                            onchange: {
+                                let mut referral_code = referral_code;
+                                let mut error_message = error_message;
+                                Some(EventHandler::new(move |e: FormEvent| {
+                                    let value = e.value();
+                                    referral::set_pending_code(
+                                        normalize_code(&value).as_deref(),
+                                    );
+                                    referral_code.set(value);
+                                    error_message.set(None);
+                                }))
+                            },
+                        }
+                    }
+
                     // 错误提示
                     ErrorMessage {
                         message: error_message.read().clone()
@@ -0,0 +1,1270 @@
+//! Sell Flow - 法币提现流程
+//! 代币 → 稳定币 → 法币的两步提现：选择代币与数量（显示可用余额）、提现方式与已保存的收款方式、
+//! 报价与费用明细、创建订单并跟踪处理进度。卖出页（/sell）与兑换页「提现」标签共用
+
+#![allow(clippy::clone_on_copy, clippy::redundant_closure)]
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::input::FieldError;
+use crate::components::atoms::skeleton::SkeletonCard;
+use crate::components::molecules::order_tracking::{OrderStatus, OrderTracking, OrderTrackingInfo};
+use crate::components::molecules::user_feedback::{FeedbackType, UserFeedback};
+use crate::components::molecules::{
+    use_step_wizard, AmountInput, BankDetailsForm, ChainAccountPrompt, DeviceStepUpModal,
+    ErrorMessage, ProviderStatusInfo, ProviderStatusList, SavedPayoutMethods, SlowRequestHint,
+    StepWizard, TokenSelector, WizardReviewRow, WizardStep,
+};
+use crate::components::payment_method_selector::{PaymentDirection, PaymentMethodSelector};
+use crate::features::fees::estimate::{refresh_platform_fee, FeeBasis, PlatformFeeEstimate};
+use crate::features::security::device_binding::{self, DeviceAuthError, HighValueOp};
+use crate::features::swap::payout::{self, BankDetails, PayoutAccount, SavedPayoutMethod};
+use crate::features::swap::withdrawal_eta;
+use crate::features::wallet::chain_accounts::account_for_chain;
+use crate::features::wallet::token_preferences;
+use crate::pages::orders::download_eta_ics;
+use crate::pages::swap::{format_currency, token_account, DuplicateOrderWarning};
+use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::services::balance::BalanceService;
+use crate::services::cache::MemoryCache;
+use crate::services::country_support::{MethodAvailability, PaymentMethodMatrix};
+use crate::services::error_logger::{ErrorLevel, ErrorLogger};
+use crate::services::fiat_offramp::{
+    FiatOfframpOrderStatus, FiatOfframpQuoteResponse, FiatOfframpService,
+};
+use crate::services::token::TokenInfo;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use crate::shared::submission_guard::{self, OrderFingerprint};
+use crate::shared::validation::{use_validated_field, Validator};
+use dioxus::prelude::*;
+use std::time::Duration;
+
+/// 订单状态轮询间隔（毫秒）
+const ORDER_POLL_MS: u32 = 10_000;
+
+/// 提现订单状态 → 订单跟踪信息
+pub fn offramp_tracking_info(status: FiatOfframpOrderStatus) -> OrderTrackingInfo {
+    let short_id: String = status.order_id.chars().take(8).collect();
+    OrderTrackingInfo {
+        title: format!("法币提现订单 {}", short_id),
+        description: Some(format!(
+            "金额: {} {}",
+            status.fiat_amount, status.fiat_currency
+        )),
+        order_id: status.order_id,
+        status: OrderStatus::from_str(&status.status),
+        created_at: status.created_at,
+        updated_at: Some(status.updated_at),
+        completed_at: status.completed_at,
+        error_message: status.error_message,
+        payment_url: None,
+        tx_hash: status.withdrawal_tx_hash.or(status.swap_tx_hash),
+    }
+}
+
+/// 法币提现流程 - 卖出页与兑换页「提现」标签共用
+///
+/// 分步：代币与数量 → 提现方式 → 收款信息 → 报价确认；下单后显示订单处理进度
+#[component]
+pub fn SellFlow(region: Signal<Option<PaymentMethodMatrix>>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    // 缓存和错误日志服务
+    let cache = use_signal(|| MemoryCache::new(Duration::from_secs(30)));
+    let error_logger = use_signal(|| ErrorLogger::new(100));
+
+    // 用户反馈状态
+    let show_feedback = use_signal(|| false);
+    let feedback_type = use_signal(|| FeedbackType::Info);
+    let feedback_message = use_signal(|| String::new());
+
+    // ✅ 先定义from_token，然后才能在selected_chain中使用
+    // 从稳定币卡片等入口跳转时预选代币
+    let from_token = use_signal(token_preferences::take_pending_sell_token); // 提现代币
+
+    // ✅ 智能链选择：自动从from_token提取链类型，无需用户手动选择
+    let selected_chain = use_memo(move || {
+        from_token
+            .read()
+            .as_ref()
+            .map(|t| t.chain.as_str().to_string())
+            .unwrap_or("ethereum".to_string())
+    });
+    let chain_type = use_memo(move || match selected_chain.read().as_str() {
+        "ethereum" => ChainType::Ethereum,
+        "bitcoin" => ChainType::Bitcoin,
+        "solana" => ChainType::Solana,
+        "ton" => ChainType::TON,
+        "bsc" => ChainType::BSC,
+        "polygon" => ChainType::Polygon,
+        _ => ChainType::Ethereum,
+    });
+
+    // 服务商状态列表（从后端API获取）
+    // API: GET /api/providers (已实现)
+    let provider_status_list = use_signal(|| Vec::<ProviderStatusInfo>::new());
+    let mut amount_field = use_validated_field(|| {
+        vec![
+            Validator::required("请输入有效的提现数量"),
+            Validator::amount(18),
+        ]
+    });
+    let amount = amount_field.value; // 提现数量
+    let mut withdraw_method = use_signal(|| "bank_card".to_string()); // 提现方式
+
+    // 地区确定后，若当前提现方式不可用则切换到第一个可用方式
+    use_effect(move || {
+        if let Some(matrix) = region.read().as_ref() {
+            if !matrix
+                .withdraw_availability(&withdraw_method.peek())
+                .is_available()
+            {
+                if let Some(first) = matrix.withdraw_methods.first() {
+                    withdraw_method.set(first.clone());
+                }
+            }
+        }
+    });
+    let mut recipient_field = use_validated_field(Vec::new);
+    let mut recipient_info = recipient_field.value; // 收款账户信息（非银行方式）
+
+    // 验证规则随提现代币精度和提现方式变化
+    use_effect(move || {
+        let decimals = from_token.read().as_ref().map_or(18, |t| t.decimals);
+        amount_field.set_rules(vec![
+            Validator::required("请输入有效的提现数量"),
+            Validator::amount(decimals),
+            Validator::range(decimals, None, Some("1000000000000000"))
+                .with_message("金额过大，请输入有效金额"),
+        ]);
+    });
+    use_effect(move || {
+        let rules = match withdraw_method.read().as_str() {
+            "bank_card" => Vec::new(),
+            "paypal" => vec![
+                Validator::required("请输入收款账户信息"),
+                Validator::email().with_message("PayPal账户必须是有效的邮箱地址"),
+            ],
+            _ => vec![Validator::required("请输入收款账户信息")],
+        };
+        recipient_field.set_rules(rules);
+    });
+
+    // 银行提现的结构化收款信息（收款国家默认取检测到的地区）
+    let mut bank_details = use_signal(BankDetails::default);
+    let saved_payout_methods = use_signal(payout::load_saved_methods);
+    use_effect(move || {
+        if let Some(matrix) = region.read().as_ref() {
+            if bank_details.peek().country.is_empty() {
+                bank_details.write().country = matrix.country_code.clone();
+            }
+        }
+    });
+
+    // 选用已保存的收款方式；切换提现方式时自动填入该方式的默认账户
+    let mut apply_saved_method = move |saved: SavedPayoutMethod| match saved.account {
+        PayoutAccount::Bank(details) => bank_details.set(details),
+        PayoutAccount::Identifier { value } => recipient_info.set(value),
+    };
+    use_effect(move || {
+        let method = withdraw_method.read().clone();
+        let default = saved_payout_methods
+            .peek()
+            .iter()
+            .find(|m| m.method == method && m.is_default)
+            .cloned();
+        if let Some(saved) = default {
+            apply_saved_method(saved);
+        }
+    });
+
+    // 收款信息是否完整（银行提现需通过结构化校验）
+    let recipient_ready = use_memo(move || {
+        if withdraw_method.read().as_str() == "bank_card" {
+            bank_details.read().validate().is_ok()
+        } else {
+            recipient_field.is_valid()
+        }
+    });
+    let error_message = use_signal(|| Option::<String>::None);
+    let loading = use_signal(|| false);
+    let quote_loading = use_signal(|| false);
+    let quote = use_signal(|| Option::<FiatOfframpQuoteResponse>::None);
+    let platform_fee = use_signal(|| Option::<PlatformFeeEstimate>::None); // ✅ 平台服务费
+                                                                           // 本次创建的订单（显示处理进度）
+    let mut created_order = use_signal(|| Option::<String>::None);
+
+    // 防重复提交：报价就绪（进入确认阶段）时生成幂等键；近期相同订单需二次确认
+    let mut submit_key = use_signal(|| Option::<String>::None);
+    let mut duplicate_warning = use_signal(|| Option::<String>::None);
+    let mut allow_duplicate = use_signal(|| false);
+    // 大额提现时本设备尚未信任：待追加验证的设备ID
+    let mut step_up_device = use_signal(|| Option::<String>::None);
+    use_effect(move || {
+        let has_quote = quote.read().is_some();
+        submit_key.set(has_quote.then(submission_guard::new_idempotency_key));
+        duplicate_warning.set(None);
+        allow_duplicate.set(false);
+    });
+
+    // 获取当前钱包
+    let current_wallet = use_memo(move || {
+        let wallet_state = app_state.wallet.read();
+        wallet_state.get_selected_wallet().cloned()
+    });
+    // 提现代币所在链的账户（余额与快捷比例）
+    let from_account =
+        use_memo(move || token_account(current_wallet.read().as_ref(), from_token.read().as_ref()));
+
+    // 自动获取报价（当代币、金额、链或提现方式改变时）
+    use_effect({
+        let app_state_clone = app_state.clone();
+        let amount_sig = amount;
+        let token_sig = from_token;
+        let chain_sig = selected_chain;
+        let withdraw_method_sig = withdraw_method;
+        let mut quote_sig = quote;
+        let quote_load_sig = quote_loading;
+        let err_sig = error_message;
+
+        move || {
+            let amount_val = amount_sig.read().clone();
+            let token_opt = token_sig.read().clone();
+            let chain_val = chain_sig.read().clone();
+            let withdraw_val = withdraw_method_sig.read().clone();
+
+            // 企业级输入验证
+            if !amount_field.is_valid() || token_opt.is_none() {
+                quote_sig.set(None);
+                return;
+            }
+
+            let token_symbol = match token_opt.as_ref() {
+                Some(t) => {
+                    if t.symbol.is_empty() {
+                        quote_sig.set(None);
+                        return;
+                    }
+                    t.symbol.clone()
+                }
+                None => {
+                    quote_sig.set(None);
+                    return;
+                }
+            };
+
+            // 延迟500ms后获取报价，避免频繁请求
+            let amount_clone = amount_val.clone();
+            let token_clone = token_symbol.clone();
+            let chain_clone = chain_val.clone();
+            let withdraw_clone = withdraw_val.clone();
+            let app_state_for_spawn = app_state_clone.clone();
+            let amount_sig_for_check = amount_sig;
+            let mut quote_sig_for_spawn = quote_sig;
+            let mut quote_load_sig_for_spawn = quote_load_sig;
+            let mut err_sig_for_spawn = err_sig;
+            let mut cache_sig = cache;
+            let mut error_logger_sig = error_logger;
+
+            spawn(async move {
+                // 等待500ms防抖
+                gloo_timers::future::TimeoutFuture::new(500).await;
+
+                // 检查金额是否还是同一个（防止过期请求）
+                if amount_sig_for_check.read().as_str() != amount_clone.as_str() {
+                    return;
+                }
+
+                quote_load_sig_for_spawn.set(true);
+                err_sig_for_spawn.set(None);
+
+                // 检查缓存
+                let cache_key = format!(
+                    "offramp_quote:{}:{}:{}:{}",
+                    token_clone, amount_clone, chain_clone, withdraw_clone
+                );
+                if let Some(cached_quote) =
+                    cache_sig.read().get::<FiatOfframpQuoteResponse>(&cache_key)
+                {
+                    quote_sig_for_spawn.set(Some(cached_quote));
+                    quote_load_sig_for_spawn.set(false);
+                    return;
+                }
+
+                // 缓存未命中，从API获取
+                let offramp_service = FiatOfframpService::new(app_state_for_spawn);
+                match offramp_service
+                    .get_quote(
+                        &token_clone,
+                        &amount_clone,
+                        &chain_clone,
+                        "USD",
+                        &withdraw_clone,
+                    )
+                    .await
+                {
+                    Ok(q) => {
+                        // 保存到缓存
+                        cache_sig
+                            .write()
+                            .set(cache_key, q.clone(), Some(Duration::from_secs(30)));
+                        quote_sig_for_spawn.set(Some(q.clone()));
+
+                        // ✅ 计算平台服务费（Fiat Offramp操作，使用代币的美元价值）
+                        refresh_platform_fee(
+                            app_state_for_spawn,
+                            platform_fee,
+                            chain_clone.clone(),
+                            "fiat_offramp",
+                            amount_clone
+                                .parse::<f64>()
+                                .ok()
+                                .map(|amount| FeeBasis::Token {
+                                    symbol: token_clone.clone(),
+                                    amount,
+                                }),
+                        );
+                    }
+                    Err(e) => {
+                        // 企业级：根据错误类型提供友好提示
+                        let error_str = e.to_string();
+                        let friendly_error = if error_str.contains("404")
+                            || error_str.contains("not found")
+                        {
+                            "该提现方式暂不支持，请选择其他方式".to_string()
+                        } else if error_str.contains("500")
+                            || error_str.contains("Internal Server Error")
+                        {
+                            "提现服务暂时不可用，请稍后再试".to_string()
+                        } else if error_str.contains("timeout") || error_str.contains("timed out") {
+                            "网络请求超时，请检查网络连接".to_string()
+                        } else if error_str.contains("minimum amount")
+                            || error_str.contains("too small")
+                        {
+                            "提现金额过小，请增加金额后重试".to_string()
+                        } else if error_str.contains("maximum amount")
+                            || error_str.contains("too large")
+                        {
+                            "提现金额超出限额，请减少金额后重试".to_string()
+                        } else if error_str.contains("country") || error_str.contains("region") {
+                            "该地区暂不支持此提现方式".to_string()
+                        } else {
+                            format!("获取提现报价失败: {}", error_str)
+                        };
+
+                        err_sig_for_spawn.set(Some(friendly_error.clone()));
+                        quote_sig_for_spawn.set(None);
+                        // 记录错误日志
+                        error_logger_sig.write().log(
+                            ErrorLevel::Error,
+                            error_str,
+                            Some(serde_json::json!({
+                                "token": token_clone,
+                                "amount": amount_clone,
+                                "chain": chain_clone,
+                                "withdraw_method": withdraw_clone,
+                                "friendly_message": friendly_error,
+                            })),
+                        );
+                    }
+                }
+                quote_load_sig_for_spawn.set(false);
+            });
+        }
+    });
+
+    // 创建提现订单函数
+    let create_withdraw_order_handler = {
+        let app_state_clone = app_state.clone();
+        let amount_sig = amount;
+        let token_sig = from_token;
+        let chain_sig = selected_chain;
+        let withdraw_method_sig = withdraw_method;
+        let recipient_sig = recipient_info;
+        let bank_details_sig = bank_details;
+        let saved_methods_sig = saved_payout_methods;
+        let quote_sig = quote;
+        let loading_sig = loading;
+        let mut err_sig = error_message;
+        let toasts = app_state.toasts;
+        let error_logger_sig = error_logger;
+        let show_feedback_sig = show_feedback;
+        let feedback_type_sig = feedback_type;
+        let feedback_message_sig = feedback_message;
+
+        move || {
+            // 上一次提交尚未返回时直接忽略
+            if *loading_sig.peek() {
+                return;
+            }
+            let amount_val = amount_sig.read().clone();
+            let token_opt = token_sig.read().clone();
+            let chain_val = chain_sig.read().clone();
+            let withdraw_val = withdraw_method_sig.read().clone();
+            let recipient_val = recipient_sig.read().clone();
+            let wallet_opt = current_wallet.read().clone();
+
+            // 检查是否有报价
+            let quote_opt = quote_sig.read().clone();
+            let quote_id_val = match quote_opt.as_ref() {
+                Some(q) => q.quote_id.clone(),
+                None => {
+                    err_sig.set(Some("请先获取报价".to_string()));
+                    return;
+                }
+            };
+
+            // 企业级输入验证
+            if let Some(e) = amount_field.error() {
+                err_sig.set(Some(e));
+                return;
+            }
+
+            // 金额验证通过，继续验证其他字段
+            // 验证代币选择
+            let token_symbol = match token_opt.as_ref() {
+                Some(t) => {
+                    if t.symbol.is_empty() {
+                        err_sig.set(Some("请选择有效的提现代币".to_string()));
+                        return;
+                    }
+                    t.symbol.clone()
+                }
+                None => {
+                    err_sig.set(Some("请选择提现代币".to_string()));
+                    return;
+                }
+            };
+
+            // 验证收款账户信息并构建 recipient_info JSON 对象
+            let (recipient_info_json, payout_account) = if withdraw_val == "bank_card" {
+                match bank_details_sig.read().validate() {
+                    Ok(details) => (details.to_recipient_json(), PayoutAccount::Bank(details)),
+                    Err(e) => {
+                        err_sig.set(Some(e));
+                        return;
+                    }
+                }
+            } else {
+                let identifier = recipient_val.trim().to_string();
+                if let Some(e) = recipient_field.error() {
+                    err_sig.set(Some(e));
+                    return;
+                }
+                let json = if withdraw_val == "paypal" {
+                    serde_json::json!({
+                        "paypal_email": identifier,
+                        "account_type": "paypal"
+                    })
+                } else {
+                    serde_json::json!({
+                        "account": identifier
+                    })
+                };
+                (json, PayoutAccount::Identifier { value: identifier })
+            };
+
+            let amount_clone = amount_val.clone();
+            let token_clone = token_symbol.clone();
+            let chain_clone = chain_val.clone();
+            let withdraw_clone = withdraw_val.clone();
+            let recipient_info_str = recipient_info_json.to_string();
+
+            // 两分钟内创建过相同订单时先提示确认
+            let fingerprint =
+                OrderFingerprint::new("offramp", &token_symbol, &amount_val, &withdraw_val);
+            if !*allow_duplicate.peek() {
+                if let Some(elapsed) = submission_guard::recent_duplicate(&fingerprint) {
+                    duplicate_warning.set(Some(submission_guard::duplicate_warning(elapsed)));
+                    return;
+                }
+            }
+            duplicate_warning.set(None);
+            allow_duplicate.set(false);
+            let idempotency_key = submit_key
+                .peek()
+                .clone()
+                .unwrap_or_else(submission_guard::new_idempotency_key);
+
+            let quote_id_clone = quote_id_val.clone();
+            let fiat_amount_usd = quote_opt
+                .as_ref()
+                .and_then(|q| q.fiat_amount.parse::<f64>().ok())
+                .unwrap_or(0.0);
+            let app_state_for_spawn = app_state_clone.clone();
+            let mut loading_sig_for_spawn = loading_sig;
+            let mut err_sig_for_spawn = err_sig;
+
+            let mut error_logger_sig_for_spawn = error_logger_sig;
+            let mut show_feedback_sig_for_spawn = show_feedback_sig;
+            let mut feedback_type_sig_for_spawn = feedback_type_sig;
+            let mut feedback_message_sig_for_spawn = feedback_message_sig;
+
+            // 在发起请求前同步置为加载中，避免重新渲染前的重复点击
+            loading_sig_for_spawn.set(true);
+            spawn(async move {
+                err_sig_for_spawn.set(None);
+
+                // 大额提现需附带本设备签名；未信任的设备先完成追加验证再重试
+                let device_proof = if device_binding::withdrawal_requires_proof(fiat_amount_usd) {
+                    match device_binding::authorize(app_state_for_spawn, HighValueOp::Withdrawal)
+                        .await
+                    {
+                        Ok(proof) => Some(proof),
+                        Err(DeviceAuthError::StepUpRequired { device_id }) => {
+                            step_up_device.set(Some(device_id));
+                            loading_sig_for_spawn.set(false);
+                            return;
+                        }
+                        Err(DeviceAuthError::Failed(e)) => {
+                            err_sig_for_spawn.set(Some(format!("设备验证失败: {}", e)));
+                            loading_sig_for_spawn.set(false);
+                            return;
+                        }
+                    }
+                } else {
+                    None
+                };
+
+                let offramp_service = FiatOfframpService::new(app_state_for_spawn);
+                match offramp_service
+                    .create_order(
+                        &token_clone,
+                        &amount_clone,
+                        &chain_clone,
+                        "USD",
+                        &withdraw_clone,
+                        &recipient_info_str,
+                        quote_id_clone.as_str().into(), // 转换为Option<&str>
+                        Some(&idempotency_key),
+                        device_proof.as_ref(),
+                    )
+                    .await
+                {
+                    Ok(order) => {
+                        submission_guard::record_order(fingerprint);
+                        // 下一笔订单使用新的幂等键
+                        submit_key.set(Some(submission_guard::new_idempotency_key()));
+                        created_order.set(Some(order.order_id.clone()));
+                        withdrawal_eta::record(&order, &withdraw_clone);
+                        // 下单成功的收款方式保存以便下次选用
+                        {
+                            let mut saved = saved_methods_sig;
+                            let mut list = saved.write();
+                            payout::upsert_method(&mut list, &withdraw_clone, payout_account);
+                            payout::store_saved_methods(&list);
+                        }
+                        log::info!("提现订单创建成功: order_id={}", order.order_id);
+                        BalanceService::new(app_state_for_spawn)
+                            .refresh_committed()
+                            .await;
+
+                        // 记录成功日志
+                        error_logger_sig_for_spawn.write().log(
+                            ErrorLevel::Info,
+                            format!("提现订单创建成功: order_id={}", order.order_id),
+                            Some(serde_json::json!({
+                                "order_id": order.order_id,
+                                "token": token_clone,
+                                "amount": amount_clone,
+                                "chain": chain_clone,
+                                "withdraw_method": withdraw_clone,
+                            })),
+                        );
+
+                        // 显示成功反馈
+                        feedback_type_sig_for_spawn.set(FeedbackType::Success);
+                        feedback_message_sig_for_spawn
+                            .set(format!("提现订单已创建，订单号: {}", order.order_id));
+                        show_feedback_sig_for_spawn.set(true);
+
+                        AppState::show_success(
+                            toasts,
+                            format!("提现订单已创建，订单号: {}", order.order_id),
+                        );
+                        // 可以跳转到订单详情页面或历史页面
+                    }
+                    Err(e) => {
+                        let error_msg = format!("创建提现订单失败: {}", e);
+
+                        // 记录错误日志
+                        error_logger_sig_for_spawn.write().log(
+                            ErrorLevel::Error,
+                            error_msg.clone(),
+                            Some(serde_json::json!({
+                                "token": token_clone,
+                                "amount": amount_clone,
+                                "chain": chain_clone,
+                                "withdraw_method": withdraw_clone,
+                            })),
+                        );
+
+                        // 显示错误反馈
+                        feedback_type_sig_for_spawn.set(FeedbackType::Error);
+                        feedback_message_sig_for_spawn.set(error_msg.clone());
+                        show_feedback_sig_for_spawn.set(true);
+
+                        err_sig_for_spawn.set(Some(error_msg));
+                    }
+                }
+                loading_sig_for_spawn.set(false);
+            });
+        }
+    };
+
+    // 分步流程：代币 → 提现方式 → 收款信息 → 确认
+    let wizard = use_step_wizard(4);
+    let lang = app_state.language.read().clone();
+    let tr = |key: &str| crate::i18n::translations::get_text(key, &lang);
+    let method_available = region
+        .read()
+        .as_ref()
+        .map(|m| {
+            m.withdraw_availability(&withdraw_method.read())
+                .is_available()
+        })
+        .unwrap_or(true);
+    let recipient_blocker = if withdraw_method.read().as_str() == "bank_card" {
+        bank_details.read().validate().err()
+    } else {
+        recipient_field.error()
+    };
+    let wizard_steps = vec![
+        WizardStep::new(tr("withdraw.step1_select")).blocked_by(if from_token.read().is_none() {
+            Some(tr("withdraw.wizard.token_required"))
+        } else {
+            amount_field.error()
+        }),
+        WizardStep::new(tr("withdraw.step2_method"))
+            .blocked_by((!method_available).then(|| tr("buy.wizard.method_unavailable"))),
+        WizardStep::new(tr("withdraw.step3_info")).blocked_by(recipient_blocker),
+        WizardStep::new(tr("withdraw.step4_confirm")),
+    ];
+    let method_label = PaymentDirection::Withdraw.method_name(&withdraw_method.read(), &lang);
+    let recipient_summary = if withdraw_method.read().as_str() == "bank_card" {
+        bank_details.read().masked()
+    } else {
+        recipient_info.read().clone()
+    };
+    let token_summary = format!(
+        "{} {}",
+        amount.read(),
+        from_token
+            .read()
+            .as_ref()
+            .map(|t| t.symbol.clone())
+            .unwrap_or_default()
+    );
+
+    // 提示信息：系统将自动执行代币→稳定币交换
+    rsx! {
+        div {
+            class: "space-y-4",
+
+            // 服务商状态显示（如果有数据）
+            if !provider_status_list.read().is_empty() {
+                div {
+                    class: "p-4 rounded-lg",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    h4 {
+                        class: "text-sm font-medium mb-3",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "服务商状态"
+                    }
+                    ProviderStatusList {
+                        providers: provider_status_list.read().clone(),
+                    }
+                }
+            }
+
+            // 提示卡片
+            div {
+                class: "p-4 rounded-lg",
+                style: format!("background: rgba(59, 130, 246, 0.1); border: 1px solid rgba(59, 130, 246, 0.3);"),
+                div {
+                    class: "flex items-start gap-2",
+                    span { "💡" }
+                    div {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {crate::i18n::translations::get_text("withdraw.two_step_hint", &app_state.language.read())}
+                    }
+                }
+            }
+
+            StepWizard {
+                state: wizard,
+                steps: wizard_steps,
+                back_label: tr("wizard.back"),
+                next_label: tr("wizard.next"),
+                {match wizard.current() {
+                    0 => rsx! {
+                        div {
+                            class: "p-6 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+
+                            h3 {
+                                class: "text-lg font-semibold mb-4",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "提现到法币"
+                            }
+
+                            div {
+                                class: "space-y-4",
+
+                                // ✅ 移除链选择器：智能自动选择，提升用户体验
+                                // 链会根据 from_token 自动适配（ETH→ethereum, BTC→bitcoin等）
+
+                                // 代币选择（From）
+                                div {
+                                    label {
+                                        class: "block text-sm font-medium mb-2",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {crate::i18n::translations::get_text("withdraw.select_token", &app_state.language.read())}
+                                    }
+                                    TokenSelector {
+                                        chain: *chain_type.read(),
+                                        selected_token: from_token,
+                                        wallet_address: current_wallet
+                                            .read()
+                                            .as_ref()
+                                            .and_then(|w| account_for_chain(w, *chain_type.read()))
+                                            .map(|a| a.address.clone()),
+                                    }
+                                    ChainAccountPrompt {
+                                        chain: *chain_type.read(),
+                                        token_symbol: from_token.read().as_ref().map(|t| t.symbol.clone()),
+                                    }
+                                    div {
+                                        class: "text-xs mt-1",
+                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                        "系统将自动将代币兑换为稳定币，然后提现为法币"
+                                    }
+                                }
+
+                                // 数量输入
+                                AmountInput {
+                                    value: amount,
+                                    token: from_token,
+                                    account: from_account,
+                                    label: crate::i18n::translations::get_text("withdraw.amount_label", &app_state.language.read()),
+                                    placeholder: "0.0",
+                                    error: amount_field.visible_error(),
+                                    onblur: move |_| amount_field.touch(),
+                                }
+                            }
+                        }
+                    },
+                    1 => rsx! {
+                        div {
+                            class: "p-6 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                            // 提现方式选择（6个国际标准方式）
+                            div {
+                                label {
+                                    class: "block text-sm font-medium mb-2",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    {crate::i18n::translations::get_text("withdraw.method", &app_state.language.read())}
+                                }
+                                PaymentMethodSelector {
+                                    direction: PaymentDirection::Withdraw,
+                                    selected: withdraw_method.read().clone(),
+                                    region,
+                                    onselect: move |method: String| withdraw_method.set(method),
+                                }
+                            }
+                        }
+                    },
+                    2 => rsx! {
+                        div {
+                            class: "p-6 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                            // 收款账户信息输入
+                            div {
+                                class: "space-y-3",
+                                label {
+                                    class: "block text-sm font-medium",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    match withdraw_method.read().as_str() {
+                                        "bank_card" => "银行收款信息",
+                                        "paypal" => "PayPal账户",
+                                        "apple_pay" => "Apple ID",
+                                        "google_pay" => "Google账户",
+                                        "alipay" => "支付宝账号",
+                                        "wechat_pay" => "微信账号",
+                                        _ => "收款账户信息"
+                                    }
+                                }
+                                SavedPayoutMethods {
+                                    methods: saved_payout_methods,
+                                    method: withdraw_method.read().clone(),
+                                    on_select: move |saved| apply_saved_method(saved),
+                                }
+                                if withdraw_method.read().as_str() == "bank_card" {
+                                    BankDetailsForm { details: bank_details }
+                                } else {
+                                    input {
+                                        class: "w-full p-3 rounded-lg",
+                                        style: format!("background: {}; border: 1px solid {}; color: {};",
+                                            Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                        r#type: "text",
+                                        value: "{recipient_info.read()}",
+                                        oninput: move |e| recipient_info.set(e.value()),
+                                        onblur: move |_| recipient_field.touch(),
+                                        placeholder: match withdraw_method.read().as_str() {
+                                            "paypal" => "PayPal账号 (例: your@email.com)",
+                                            "apple_pay" => "Apple ID (例: your@icloud.com)",
+                                            "google_pay" => "Google账号 (例: your@gmail.com)",
+                                            "alipay" => "支付宝账号 (手机号或邮箱)",
+                                            "wechat_pay" => "微信账号 (微信ID或手机号)",
+                                            _ => "请输入收款账户信息"
+                                        }
+                                    }
+                                    FieldError { error: recipient_field.visible_error() }
+                                }
+                                div {
+                                    class: "text-xs mt-1",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    match withdraw_method.read().as_str() {
+                                        "bank_card" => "⚠️ 银行提现需1-3工作日，请确保开户人姓名与账户信息准确",
+                                        "paypal" => "✅ PayPal即时到账，支持全球200+国家",
+                                        "apple_pay" => "✅ Apple Pay即时到账，需iOS设备绑定",
+                                        "google_pay" => "✅ Google Pay即时到账，需Android设备绑定",
+                                        "alipay" => "✅ 支付宝即时到账，中国地区首选",
+                                        "wechat_pay" => "✅ 微信支付即时到账，中国地区首选",
+                                        _ => "请确保账户信息准确，错误信息可能导致提现失败"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    _ => rsx! {
+                        div {
+                            class: "p-6 rounded-lg space-y-4",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                            h3 {
+                                class: "text-lg font-semibold",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {tr("wizard.review_title")}
+                            }
+                            div {
+                                WizardReviewRow {
+                                    label: "提现代币".to_string(),
+                                    value: token_summary.clone(),
+                                    step: 0,
+                                    state: wizard,
+                                    edit_label: tr("wizard.edit"),
+                                }
+                                WizardReviewRow {
+                                    label: "提现方式".to_string(),
+                                    value: method_label.clone(),
+                                    step: 1,
+                                    state: wizard,
+                                    edit_label: tr("wizard.edit"),
+                                }
+                                WizardReviewRow {
+                                    label: "收款账户".to_string(),
+                                    value: recipient_summary.clone(),
+                                    step: 2,
+                                    state: wizard,
+                                    edit_label: tr("wizard.edit"),
+                                }
+                            }
+                        }
+
+                        // 报价显示区域
+                        if *quote_loading.read() && !amount.read().is_empty() {
+                            SkeletonCard { lines: 4 }
+                            SlowRequestHint {
+                                path: "/api/v1/fiat/offramp/quote",
+                                message: "报价比平时慢，正在比较各服务商…",
+                            }
+                        } else if let Some(q) = quote.read().as_ref() {
+                            div {
+                                class: "p-6 rounded-lg",
+                                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                h3 {
+                                    class: "text-lg font-semibold mb-4",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "💰 提现详情"
+                                }
+                                div {
+                                    class: "space-y-2",
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "提现代币" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "{q.token_amount} {q.token_symbol}"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "中间稳定币" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {
+                                                let amount = q.stablecoin_amount.parse::<f64>().unwrap_or(0.0);
+                                                format!("{} {}", format_currency(amount, 2), q.stablecoin_symbol)
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "最终法币金额" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {
+                                                let amount = q.fiat_amount.parse::<f64>().unwrap_or(0.0);
+                                                format!("${} {}", format_currency(amount, 2), q.fiat_currency)
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "代币→稳定币汇率" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {
+                                                let rate = q.exchange_rate_token_to_stable.parse::<f64>().unwrap_or(0.0);
+                                                format!("1 {} = {} {}", q.token_symbol, format_currency(rate, 2), q.stablecoin_symbol)
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "稳定币→法币汇率" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {
+                                                let rate = q.exchange_rate_stable_to_fiat.parse::<f64>().unwrap_or(1.0);
+                                                format!("1 {} = ${:.2}", q.stablecoin_symbol, rate)
+                                            }
+                                        }
+                                    }
+                                    // ✅ 费用明细（修正后，行业标准透明度）
+                                    div {
+                                        class: "mt-4 pt-4",
+                                        style: format!("border-top: 1px solid {};", Colors::BORDER_PRIMARY),
+                                        div {
+                                            class: "text-sm font-medium mb-3",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "💰 费用明细"
+                                        }
+
+                                        // 1. 提现手续费（第三方服务商：Banxa/MoonPay）
+                                        if !q.withdrawal_fee.is_empty() {
+                                            div {
+                                                class: "flex justify-between items-center py-1",
+                                                span {
+                                                    class: "text-sm",
+                                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                    "🏦 提现手续费 (Banxa)"
+                                                }
+                                                span {
+                                                    class: "text-sm font-medium",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {
+                                                        // 格式化为美元金额（2位小数）
+                                                        let fee = q.withdrawal_fee.parse::<f64>().unwrap_or(0.0);
+                                                        format!("${:.2}", fee)
+                                                    }
+                                                }
+                                            }
+                                        }
+
+                                        // 2. 平台服务费（行业标准：完全免费！）
+                                        div {
+                                            class: "flex justify-between items-center py-1",
+                                            span {
+                                                class: "text-sm",
+                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                "平台服务费 (IronCore)"
+                                            }
+                                            span {
+                                                class: "text-sm font-bold",
+                                                style: "color: #22c55e;",  // 绿色强调免费
+                                                "$0.00 免费!"
+                                            }
+                                        }
+
+                                        // 3. 交换手续费（如果涉及代币→稳定币转换）
+                                        if !q.swap_fee.is_empty() {
+                                            div {
+                                                class: "flex justify-between items-center py-1",
+                                                span {
+                                                    class: "text-sm",
+                                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                    "🔄 交换手续费"
+                                                }
+                                                span {
+                                                    class: "text-sm font-medium",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {
+                                                        // 格式化为美元金额（2位小数）
+                                                        let fee = q.swap_fee.parse::<f64>().unwrap_or(0.0);
+                                                        format!("${:.2}", fee)
+                                                    }
+                                                }
+                                            }
+                                        }
+
+                                        // 总手续费（加粗显示）
+                                        div {
+                                            class: "flex justify-between items-center py-2 mt-2 pt-2",
+                                            style: format!("border-top: 1px dashed {};", Colors::BORDER_PRIMARY),
+                                            span {
+                                                class: "text-sm font-semibold",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                "💰 总手续费"
+                                            }
+                                            span {
+                                                class: "text-base font-bold",
+                                                style: format!("color: {};", Colors::TECH_PRIMARY),
+                                                {
+                                                    // ✅ 格式化为美元金额（千位分隔符 + 2位小数）
+                                                    let fee = q.fee_amount.parse::<f64>().unwrap_or(0.0);
+                                                    format!("${}", format_currency(fee, 2))
+                                                }
+                                            }
+                                        }
+
+                                        // ✅ 预计到账金额（行业最佳实践：必须显示）
+                                        div {
+                                            class: "flex justify-between items-center py-3 mt-2",
+                                            style: format!("background: rgba(34, 197, 94, 0.1); border-radius: 8px; padding: 12px; border: 2px solid rgba(34, 197, 94, 0.3);"),
+                                            span {
+                                                class: "text-base font-bold",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                "💵 您将收到"
+                                            }
+                                            span {
+                                                class: "text-lg font-bold",
+                                                style: "color: #22c55e;", // 绿色，强调到账金额
+                                                {
+                                                    // ✅ 计算净收入：最终法币金额 - 总手续费（千位分隔符）
+                                                    let fiat_amount = q.fiat_amount.parse::<f64>().unwrap_or(0.0);
+                                                    let fee = q.fee_amount.parse::<f64>().unwrap_or(0.0);
+                                                    let net_amount = fiat_amount - fee;
+                                                    format!("${} {}", format_currency(net_amount, 2), q.fiat_currency)
+                                                }
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计到账时间" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "{q.estimated_arrival}"
+                                        }
+                                    }
+                                    div {
+                                        class: "p-3 mt-4 rounded",
+                                        style: "background: rgba(34, 197, 94, 0.1); border: 1px solid rgba(34, 197, 94, 0.3);",  // 绿色强调免费
+                                        div {
+                                            class: "text-xs font-semibold mb-1",
+                                            style: "color: #22c55e;",
+                                            "🎉 IronCore平台费永久免费！"
+                                        }
+                                        div {
+                                            class: "text-xs",
+                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                            "💡 系统将自动执行两步流程："
+                                        }
+                                        div {
+                                            class: "text-xs mt-1",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "1. {q.token_symbol} → {q.stablecoin_symbol}（自动交换）"
+                                        }
+                                        div {
+                                            class: "text-xs",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "2. {q.stablecoin_symbol} → {q.fiat_currency}（提现到账）"
+                                        }
+                                    }
+                                }
+                            }
+                        } else if !amount.read().is_empty() && from_token.read().is_some() {
+                            div {
+                                class: "p-6 rounded-lg",
+                                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                h3 {
+                                    class: "text-lg font-semibold mb-4",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "💰 提现详情"
+                                }
+                                div {
+                                    class: "text-sm text-center py-4",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    "正在计算报价..."
+                                }
+                            }
+                        }
+
+                        if let Some(device_id) = step_up_device.read().clone() {
+                            DeviceStepUpModal {
+                                open: true,
+                                device_id: device_id,
+                                on_verified: {
+                                    let mut create_withdraw_order_handler = create_withdraw_order_handler;
+                                    move |_| {
+                                        step_up_device.set(None);
+                                        create_withdraw_order_handler();
+                                    }
+                                },
+                                on_cancel: move |_| step_up_device.set(None),
+                            }
+                        }
+
+                        if let Some(warning) = duplicate_warning.read().clone() {
+                            DuplicateOrderWarning {
+                                message: warning,
+                                on_confirm: {
+                                    let mut create_withdraw_order_handler = create_withdraw_order_handler;
+                                    move |_| {
+                                        allow_duplicate.set(true);
+                                        create_withdraw_order_handler();
+                                    }
+                                },
+                                on_cancel: move |_| duplicate_warning.set(None),
+                            }
+                        }
+
+                        // 提现按钮
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Large,
+                            cooldown_ms: submission_guard::SUBMIT_COOLDOWN_MS,
+                            onclick: {
+                                let mut create_withdraw_order_handler = create_withdraw_order_handler;
+                                move |_| create_withdraw_order_handler()
+                            },
+                            disabled: !amount_field.is_valid()
+                                || from_token.read().is_none()
+                                || !recipient_ready()
+                                || quote.read().is_none()
+                                || *loading.read(),
+                            loading: *loading.read(),
+                            class: "w-full",
+                            if *loading.read() {
+                                "创建提现订单中..."
+                            } else {
+                                "提交提现申请"
+                            }
+                        }
+                    },
+                }}
+            }
+
+            // 错误消息
+            ErrorMessage {
+                message: error_message.read().clone(),
+            }
+
+            // 已创建订单的处理进度
+            if let Some(order_id) = created_order.read().clone() {
+                OfframpOrderTracker { key: "{order_id}", order_id }
+            }
+        }
+
+        // 用户反馈
+        UserFeedback {
+            feedback_type: *feedback_type.read(),
+            message: feedback_message.read().clone(),
+            visible: *show_feedback.read(),
+            auto_hide_ms: 5000,
+            on_close: Some(EventHandler::new({
+                let mut show_feedback = show_feedback;
+                move |_| show_feedback.set(false)
+            })),
+        }
+    }
+}
+
+/// 提现订单处理进度：定期查询订单状态，进入终态后停止
+#[component]
+fn OfframpOrderTracker(order_id: String) -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let mut tracking = use_signal(|| Option::<OrderTrackingInfo>::None);
+    let eta = use_hook(|| withdrawal_eta::find(&order_id));
+
+    use_future({
+        let order_id = order_id.clone();
+        move || {
+            let order_id = order_id.clone();
+            async move {
+                let service = FiatOfframpService::new(app_state);
+                loop {
+                    match service.get_order_status(&order_id).await {
+                        Ok(status) => {
+                            let info = offramp_tracking_info(status);
+                            let finished = !matches!(
+                                info.status,
+                                OrderStatus::Pending | OrderStatus::Processing
+                            );
+                            tracking.set(Some(info));
+                            if finished {
+                                break;
+                            }
+                        }
+                        Err(e) => log::warn!("查询提现订单状态失败: {}", e),
+                    }
+                    gloo_timers::future::TimeoutFuture::new(ORDER_POLL_MS).await;
+                }
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            class: "p-6 rounded-lg space-y-4",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            match tracking.read().clone() {
+                Some(info) => rsx! {
+                    OrderTracking { order: info, show_actions: false }
+                },
+                None => rsx! {
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {format!("提现订单 {} 已创建，正在查询处理进度…", order_id)}
+                    }
+                },
+            }
+            if let Some(eta) = eta.clone().filter(|_| {
+                tracking
+                    .read()
+                    .as_ref()
+                    .is_none_or(|info| matches!(info.status, OrderStatus::Pending | OrderStatus::Processing))
+            }) {
+                div {
+                    class: "flex items-center justify-between gap-2 text-sm",
+                    span {
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {withdrawal_eta::expected_label(eta.expected_by)}
+                    }
+                    button {
+                        class: "text-xs underline",
+                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                        onclick: {
+                            let eta = eta.clone();
+                            move |_| download_eta_ics(app_state, &eta)
+                        },
+                        "📅 添加到日历"
+                    }
+                }
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: move |_| {
+                    navigator.push(Route::Orders {});
+                },
+                "查看全部订单"
+            }
+        }
+    }
+}
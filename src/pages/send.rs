@@ -4,28 +4,56 @@
 
 #![allow(clippy::clone_on_copy, clippy::redundant_closure)]
 
+use crate::blockchain::solana::{
+    ComputeBudget, PriorityFeeEstimate, SolanaAdapter, LAMPORTS_PER_SOL,
+};
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
 use crate::components::atoms::modal::Modal;
-use crate::components::molecules::{ErrorMessage, GasFeeCard, TokenSelector};
-use crate::features::wallet::hooks::use_wallet;
-use crate::features::wallet::state::Account;
+use crate::components::molecules::toast::{ToastAction, ToastType};
+use crate::components::molecules::{
+    BackupGate, ChainAccountPrompt, ErrorMessage, GasFeeCard, GasReadinessBanner,
+    RebroadcastPrompt, SolanaFeeCard, TokenSelector,
+};
+use crate::features::bridge::prefill::{self as bridge_prefill, BridgePrefill};
+use crate::features::gas::hooks::use_gas_readiness;
+use crate::features::gas::out_of_gas;
+use crate::features::gas::readiness::{self, BuyPrefill};
+use crate::features::wallet::address_poisoning::{self, PoisoningIndex, PoisoningMatch};
+use crate::features::wallet::chain_accounts::{account_for_chain, signing_key};
+use crate::features::wallet::hooks::{use_wallet, WalletController};
+use crate::features::wallet::internal_transfer::{self, InternalTarget};
+use crate::features::wallet::send_safeguards::{
+    self, SafeguardConfig, SendCheck, SendHistory, SendWarning,
+};
+use crate::features::wallet::state::{Account, Wallet};
+use crate::features::wallet::token_preferences;
 use crate::features::wallet::unlock::ensure_wallet_unlocked;
 use crate::router::Route;
 use crate::services::address_detector::{AddressDetector, ChainType};
+use crate::services::address_validation::{self, AddressError};
+use crate::services::audit_log::{AuditEventRequest, AuditLogService};
+use crate::services::balance::{native_symbol, BalanceService};
 use crate::services::chain_config::ChainConfigManager;
 use crate::services::fee::FeeService;
-use crate::services::gas::{GasEstimate, GasService};
+use crate::services::gas::{FeeEstimate, GasEstimate, GasService};
 use crate::services::payment_router_enterprise::{
-    PaymentRouterEnterprise, PaymentStrategy, SpeedTier,
+    FeeBreakdown, PaymentRouterEnterprise, PaymentStrategy, SpeedTier,
 };
+use crate::services::price::PriceService;
 use crate::services::token::{TokenInfo, TokenService};
-use crate::services::validation::PaymentValidator;
+use crate::services::transaction::{BroadcastReceipt, SimulationRequest};
+use crate::services::tx_propagation::PendingBroadcast;
 use crate::shared::design_tokens::Colors;
+use crate::shared::kill_switch::{self, MoneyAction};
+use crate::shared::logging;
+use crate::shared::navigation_guard::{self, BusyReason};
 use crate::shared::state::AppState;
+use crate::shared::validation::{all_valid, use_validated_field, Validator};
 use anyhow::{anyhow, Result};
 use dioxus::prelude::*;
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 fn is_evm_chain(chain: ChainType) -> bool {
@@ -72,6 +100,46 @@ fn decide_auto_strategy(
     AutoStrategyDecision::Bridge
 }
 
+/// 接收地址验证规则：按所选代币的链严格校验（含校验和与网络），
+/// 其他链地址仅在属于支持跨链的 EVM 组合时放行
+fn recipient_rules(token: Option<TokenInfo>) -> Vec<Validator> {
+    vec![
+        Validator::required("请输入接收地址"),
+        Validator::custom(move |addr| {
+            let Some(token) = token.as_ref() else {
+                return address_validation::detect_and_validate(addr)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string());
+            };
+            match address_validation::validate_address(token.chain, addr) {
+                Ok(_) => Ok(()),
+                Err(AddressError::WrongChain { found, .. })
+                    if is_bridge_supported(token.chain, found) =>
+                {
+                    Ok(())
+                }
+                Err(AddressError::WrongChain { found, .. }) => Err(format!(
+                    "⚠️ 地址错误：该地址看起来属于 {}，但您选择的代币 {} 在 {} 上",
+                    found.label(),
+                    token.symbol,
+                    token.chain.label()
+                )),
+                Err(e) => Err(e.to_string()),
+            }
+        }),
+    ]
+}
+
+/// 金额验证规则（按代币精度解析）
+fn amount_rules(decimals: u8) -> Vec<Validator> {
+    vec![
+        Validator::required("请输入金额"),
+        Validator::amount(decimals),
+        Validator::range(decimals, None, Some("1000000000"))
+            .with_message("金额过大，请检查输入（最大: 1,000,000,000）"),
+    ]
+}
+
 /// 解析十六进制字符串为u64（辅助函数）
 fn parse_hex_u64(hex: &str) -> Result<u64> {
     let hex_clean = hex.trim_start_matches("0x");
@@ -151,7 +219,6 @@ async fn estimate_gas_limit(
         }
         Err(e) => {
             // 降级策略：API失败时使用保守估算
-            log::warn!("Gas Limit估算API失败: {}，使用保守估算", e);
             let default_gas = if data.is_some() {
                 150_000u64 // 合约调用
             } else {
@@ -160,11 +227,42 @@ async fn estimate_gas_limit(
                 // 这是以太坊协议标准，所有标准ETH转账都使用此值
                 21_000u64 // 标准ETH转账（协议规定）
             };
-            Ok(default_gas)
+            GasLimitService::record_fallback(chain_id, default_gas, &e.to_string());
+            Ok(gas_limit_service.with_margin(chain_id, default_gas))
         }
     }
 }
 
+/// 用于估算网络费的转账交易（地址或金额无效、钱包没有该链账户时为 None）
+fn fee_simulation_request(
+    token: &TokenInfo,
+    wallet: &Wallet,
+    recipient: &str,
+    amount: f64,
+) -> Option<SimulationRequest> {
+    use crate::services::erc20::Erc20Encoder;
+
+    let from = account_for_chain(wallet, token.chain)?.address.clone();
+    let recipient = address_validation::validate_address(token.chain, recipient)
+        .ok()?
+        .address;
+    let (to, value, data) = if token.is_native {
+        let value = amount_to_wei(amount).ok()?.to_string();
+        (recipient, value, "0x".to_string())
+    } else {
+        let raw = Erc20Encoder::calculate_token_amount(amount, token.decimals).ok()?;
+        let data = Erc20Encoder::encode_transfer(&recipient, &raw).ok()?;
+        (token.address.clone(), "0".to_string(), data)
+    };
+    Some(SimulationRequest {
+        chain: token.chain.as_str().to_string(),
+        from,
+        to,
+        value,
+        data,
+    })
+}
+
 #[cfg(test)]
 mod auto_switch_tests {
     use super::*;
@@ -191,6 +289,86 @@ mod auto_switch_tests {
 // ✅ 删除 PaymentMode 枚举：采用单一智能流程
 // 流程：选择代币 → 输入地址（自动验证）→ 输入金额 → 确认发送
 
+/// EVM 直接转账的参数（因 Gas 不足失败时按更高的上限重新构建）
+#[derive(Clone)]
+struct DirectTransfer {
+    recipient: String,
+    amount: f64,
+    chain: ChainType,
+    account: Account,
+    fee_breakdown: FeeBreakdown,
+    token: Option<TokenInfo>,
+}
+
+/// EVM 转账广播后在后台等待结果；因 Gas 不足失败时提示以更高的上限重试
+fn watch_out_of_gas(
+    app_state: AppState,
+    wallet_ctrl: WalletController,
+    transfer: DirectTransfer,
+    tx_hash: String,
+) {
+    if !is_evm_chain(transfer.chain) {
+        return;
+    }
+    let Ok(chain_id) = ChainConfigManager::new().get_chain_id(transfer.chain) else {
+        return;
+    };
+    // 发送成功后会离开本页，等待任务不能随页面一起销毁
+    dioxus::core::spawn_forever(async move {
+        let Some(retry_limit) =
+            out_of_gas::detect(app_state, transfer.chain.as_str(), chain_id, &tx_hash).await
+        else {
+            return;
+        };
+        AppState::show_toast_with_action(
+            app_state.toasts,
+            format!(
+                "交易因 Gas 不足失败，是否将 Gas 上限提高到 {} 后重试？",
+                retry_limit
+            ),
+            ToastType::Error,
+            Some(15_000),
+            Some(ToastAction::callback("提高上限重试", move || {
+                retry_with_gas_limit(app_state, wallet_ctrl, transfer.clone(), retry_limit)
+            })),
+        );
+    });
+}
+
+/// 以指定的 Gas 上限重新构建、签名并广播转账
+fn retry_with_gas_limit(
+    app_state: AppState,
+    wallet_ctrl: WalletController,
+    transfer: DirectTransfer,
+    gas_limit: u64,
+) {
+    dioxus::core::spawn_forever(async move {
+        match execute_direct_transfer(
+            &app_state,
+            &wallet_ctrl,
+            &transfer.recipient,
+            transfer.amount,
+            &transfer.chain,
+            &transfer.account,
+            &transfer.fee_breakdown,
+            transfer.token.as_ref(),
+            None,
+            Some(gas_limit),
+        )
+        .await
+        {
+            Ok(receipt) => {
+                AppState::show_success(app_state.toasts, "已提高 Gas 上限重新发送".to_string());
+                watch_out_of_gas(app_state, wallet_ctrl, transfer, receipt.pending.tx_hash);
+            }
+            Err(e) => AppState::show_error(
+                app_state.toasts,
+                crate::shared::ui_error::sanitize_user_message(format!("重新发送失败: {}", e)),
+            ),
+        }
+    });
+}
+
 /// 执行直接转账（✅ 使用真实Gas费用，移除硬编码）
 /// 执行直接转账（✅ 使用真实Gas费用，移除硬编码，✅ P0问题修复：余额检查、金额精度、Gas Limit动态估算，✅ 支持多币种）
 #[allow(clippy::too_many_arguments)]
@@ -203,10 +381,19 @@ async fn execute_direct_transfer(
     account: &Account,
     fee_breakdown: &crate::services::payment_router_enterprise::FeeBreakdown, // ✅ 接收费用明细
     token_info: Option<&crate::services::token::TokenInfo>, // ✅ 代币信息（None表示原生代币）
-) -> Result<()> {
+    compute_budget: Option<ComputeBudget>,                  // Solana 计算单元预算
+    gas_limit_override: Option<u64>, // Gas 不足失败后重试时指定的 EVM Gas 上限
+) -> Result<BroadcastReceipt> {
     use crate::crypto::tx_signer::EthereumTxSigner;
     use crate::services::transaction::TransactionService;
 
+    // 签名、广播期间离开页面需要确认（函数返回时释放）
+    let _busy = navigation_guard::hold(BusyReason::Signing);
+    // 紧急开关：转账被暂停时不签名
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Send)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
     // 1. 获取钱包ID和账户索引
     let wallet_state = app_state.wallet.read();
     let wallet_id = wallet_state
@@ -224,13 +411,19 @@ async fn execute_direct_transfer(
         .clone()
         .ok_or_else(|| anyhow!("钱包未解锁，无法签名交易"))?;
 
-    // 3. 获取账户索引（改进错误处理）
-    let account_index = wallet_state
+    // 3. 获取账户索引与账户记录（签名按账户记录的派生路径派生私钥）
+    let (account_index, stored_account) = wallet_state
         .wallets
         .iter()
         .find(|w| w.id == *wallet_id)
-        .and_then(|w| w.accounts.iter().position(|a| a.address == account.address))
-        .ok_or_else(|| anyhow!("未找到账户: {}", account.address))? as u32;
+        .and_then(|w| {
+            w.accounts
+                .iter()
+                .enumerate()
+                .find(|(_, a)| a.address == account.address)
+        })
+        .map(|(i, a)| (i as u32, a.clone()))
+        .ok_or_else(|| anyhow!("未找到账户: {}", account.address))?;
 
     // 4. 根据链类型处理
     match chain {
@@ -250,6 +443,9 @@ async fn execute_direct_transfer(
                 .map_err(|e| anyhow!("获取nonce失败: {}", e))?;
 
             // ✅ P0问题1修复：余额检查 - 在执行转账前检查账户余额是否足够
+            // 挂单与进行中订单占用的数量不可用于转账
+            let committed = app_state.committed_balances.read().clone();
+            let native = native_symbol(&account.chain);
             // ✅ 多币种支持：区分原生代币和ERC-20代币的余额检查
             if let Some(token) = token_info {
                 if token.is_native {
@@ -258,10 +454,11 @@ async fn execute_direct_transfer(
                     let account_balance: f64 = account_balance_str
                         .parse()
                         .map_err(|e| anyhow!("解析账户余额失败: {}", e))?;
+                    let account_balance = committed.available_f64(native, account_balance);
                     let total_cost = amount + fee_breakdown.total_fee;
                     if account_balance < total_cost {
                         return Err(anyhow!(
-                            "余额不足：需要 {} {}，但账户余额只有 {} {}",
+                            "余额不足：需要 {} {}，但可用余额只有 {} {}",
                             total_cost,
                             config_manager
                                 .get_native_token(*chain)
@@ -279,9 +476,10 @@ async fn execute_direct_transfer(
                     let account_balance: f64 = account_balance_str
                         .parse()
                         .map_err(|e| anyhow!("解析账户余额失败: {}", e))?;
+                    let account_balance = committed.available_f64(native, account_balance);
                     if account_balance < fee_breakdown.total_fee {
                         return Err(anyhow!(
-                            "原生代币余额不足：需要 {} {} 支付Gas费，但账户余额只有 {} {}",
+                            "原生代币余额不足：需要 {} {} 支付Gas费，但可用余额只有 {} {}",
                             fee_breakdown.total_fee,
                             config_manager
                                 .get_native_token(*chain)
@@ -300,12 +498,14 @@ async fn execute_direct_transfer(
                         .await
                     {
                         Ok(token_balance) => {
-                            if token_balance.balance_formatted < amount {
+                            let available = committed
+                                .available_f64(&token.symbol, token_balance.balance_formatted);
+                            if available < amount {
                                 return Err(anyhow!(
-                                    "代币余额不足：需要 {} {}，但账户余额只有 {} {}",
+                                    "代币余额不足：需要 {} {}，但可用余额只有 {} {}",
                                     amount,
                                     token.symbol,
-                                    token_balance.balance_formatted,
+                                    available,
                                     token.symbol
                                 ));
                             }
@@ -322,10 +522,11 @@ async fn execute_direct_transfer(
                 let account_balance: f64 = account_balance_str
                     .parse()
                     .map_err(|e| anyhow!("解析账户余额失败: {}", e))?;
+                let account_balance = committed.available_f64(native, account_balance);
                 let total_cost = amount + fee_breakdown.total_fee;
                 if account_balance < total_cost {
                     return Err(anyhow!(
-                        "余额不足：需要 {} {}，但账户余额只有 {} {}",
+                        "余额不足：需要 {} {}，但可用余额只有 {} {}",
                         total_cost,
                         config_manager
                             .get_native_token(*chain)
@@ -447,6 +648,7 @@ async fn execute_direct_transfer(
                 });
                 (gas_price, gas_limit)
             };
+            let gas_limit = gas_limit_override.unwrap_or(gas_limit);
 
             // ✅ 多币种支持：判断是原生代币还是ERC-20代币
             let (value_str, data_hex) = if let Some(token) = token_info {
@@ -478,8 +680,7 @@ async fn execute_direct_transfer(
             };
 
             // 派生私钥
-            let private_key_hex = key_manager
-                .derive_eth_private_key(account_index)
+            let private_key_hex = signing_key(&key_manager, &stored_account, account_index)
                 .map_err(|e| anyhow!("获取私钥失败: {}", e))?;
 
             // 签名交易
@@ -512,21 +713,20 @@ async fn execute_direct_transfer(
 
             // 广播交易
             let chain_str = chain.as_str();
-            let response = tx_service
-                .broadcast(chain_str, &signed_tx)
+            let receipt = tx_service
+                .broadcast_verified(chain_str, &signed_tx)
                 .await
                 .map_err(|e| anyhow!("广播失败: {}", e))?;
 
-            log::info!("交易已广播: tx_hash={}", response.tx_hash);
-            Ok(())
+            log::info!(target: logging::SIGNING, "交易已广播: tx_hash={}", receipt.pending.tx_hash);
+            Ok(receipt)
         }
         ChainType::Bitcoin => {
             // Bitcoin交易
             use crate::crypto::tx_signer::BitcoinTxSigner;
 
-            // 派生私钥（Bitcoin使用与Ethereum相同的secp256k1）
-            let private_key_hex = key_manager
-                .derive_eth_private_key(account_index)
+            // 派生私钥（按账户的 BIP84 派生路径）
+            let private_key_hex = signing_key(&key_manager, &stored_account, account_index)
                 .map_err(|e| anyhow!("获取私钥失败: {}", e))?;
 
             // 获取Bitcoin费率（从后端API获取，移除硬编码）
@@ -555,21 +755,20 @@ async fn execute_direct_transfer(
 
             // 广播交易
             let chain_str = "bitcoin";
-            let response = tx_service
-                .broadcast(chain_str, &signed_tx)
+            let receipt = tx_service
+                .broadcast_verified(chain_str, &signed_tx)
                 .await
                 .map_err(|e| anyhow!("Bitcoin广播失败: {}", e))?;
 
-            log::info!("Bitcoin交易已广播: tx_hash={}", response.tx_hash);
-            Ok(())
+            log::info!(target: logging::SIGNING, "Bitcoin交易已广播: tx_hash={}", receipt.pending.tx_hash);
+            Ok(receipt)
         }
         ChainType::Solana => {
             // Solana交易
             use crate::crypto::tx_signer::SolanaTxSigner;
 
-            // 派生私钥（Solana使用ed25519，这里使用相同的派生方法）
-            let private_key_hex = key_manager
-                .derive_eth_private_key(account_index)
+            // 派生私钥（Solana使用ed25519，按账户的 SLIP-0010 派生路径）
+            let private_key_hex = signing_key(&key_manager, &stored_account, account_index)
                 .map_err(|e| anyhow!("获取私钥失败: {}", e))?;
 
             // 创建TransactionService
@@ -584,32 +783,37 @@ async fn execute_direct_transfer(
             // 转换金额为lamports
             let amount_lamports = (amount * 1_000_000_000.0) as u64;
 
-            // 签名交易
+            // 未估算到优先费时使用标准档位的最低单价
+            let compute_budget = compute_budget.unwrap_or_else(|| {
+                ComputeBudget::transfer(PriorityFeeEstimate::from_recent_fees(&[]).average)
+            });
+
+            // 签名交易（包含 ComputeBudget 指令）
             let signed_tx = SolanaTxSigner::sign_transaction(
                 &private_key_hex,
                 recipient,
                 &amount_lamports.to_string(),
                 &recent_blockhash,
+                &compute_budget.instructions(),
             )
             .map_err(|e| anyhow!("Solana签名失败: {}", e))?;
 
             // 广播交易
             let chain_str = "solana";
-            let response = tx_service
-                .broadcast(chain_str, &signed_tx)
+            let receipt = tx_service
+                .broadcast_verified(chain_str, &signed_tx)
                 .await
                 .map_err(|e| anyhow!("Solana广播失败: {}", e))?;
 
-            log::info!("Solana交易已广播: tx_hash={}", response.tx_hash);
-            Ok(())
+            log::info!(target: logging::SIGNING, "Solana交易已广播: tx_hash={}", receipt.pending.tx_hash);
+            Ok(receipt)
         }
         ChainType::TON => {
             // TON交易
             use crate::crypto::tx_signer::TonTxSigner;
 
-            // 派生私钥（TON使用ed25519，这里使用相同的派生方法）
-            let private_key_hex = key_manager
-                .derive_eth_private_key(account_index)
+            // 派生私钥（TON使用ed25519，按账户的 SLIP-0010 派生路径）
+            let private_key_hex = signing_key(&key_manager, &stored_account, account_index)
                 .map_err(|e| anyhow!("获取私钥失败: {}", e))?;
 
             // 创建TransactionService
@@ -635,13 +839,13 @@ async fn execute_direct_transfer(
 
             // 广播交易（TON使用特殊的BOC格式）
             let chain_str = "ton";
-            let response = tx_service
-                .broadcast(chain_str, &signed_tx)
+            let receipt = tx_service
+                .broadcast_verified(chain_str, &signed_tx)
                 .await
                 .map_err(|e| anyhow!("TON广播失败: {}", e))?;
 
-            log::info!("TON交易已广播: tx_hash={}", response.tx_hash);
-            Ok(())
+            log::info!(target: logging::SIGNING, "TON交易已广播: tx_hash={}", receipt.pending.tx_hash);
+            Ok(receipt)
         }
     }
 }
@@ -659,6 +863,13 @@ async fn execute_bridge_transfer(
     selected_token: Option<TokenInfo>,
 ) -> Result<()> {
     use crate::services::bridge::BridgeService;
+    // 签名、广播期间离开页面需要确认（函数返回时释放）
+    let _busy = navigation_guard::hold(BusyReason::Signing);
+    // 紧急开关：转账被暂停时不签名
+    kill_switch::ensure_allowed(&app_state.get_api_client(), MoneyAction::Send)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
     // 1. 获取钱包ID
     let wallet_state = app_state.wallet.read();
     let wallet_id = wallet_state
@@ -719,27 +930,67 @@ pub fn Send() -> Element {
     let wallet_controller = use_wallet();
 
     // 表单状态
-    let recipient_address = use_signal(|| String::new());
-    let amount = use_signal(|| String::new());
+    let mut recipient_field = use_validated_field(|| recipient_rules(None));
+    let mut amount_field = use_validated_field(|| amount_rules(18));
+    let recipient_address = recipient_field.value;
+    let amount = amount_field.value;
     let speed_tier = use_signal(|| SpeedTier::Medium); // 交易速度等级（默认中速）
 
     // 检测结果
     let detected_chain = use_signal(|| Option::<ChainType>::None);
+    // 广播后节点中查不到的交易（等待用户决定是否重新广播）
+    let unpropagated = use_signal(|| Option::<PendingBroadcast>::None);
     let payment_strategy = use_signal(|| Option::<PaymentStrategy>::None);
-    let address_validation_error = use_signal(|| Option::<String>::None); // ✅ 地址验证错误
 
-    // ✅ 多币种支持：代币选择
-    let selected_token = use_signal(|| Option::<TokenInfo>::None);
+    // ✅ 多币种支持：代币选择（从代币详情页跳转时预选该代币）
+    let selected_token = use_signal(token_preferences::take_pending_send_token);
 
     // UI状态
     let error_message = use_signal(|| Option::<String>::None);
     let is_loading = use_signal(|| false);
     let show_confirm_modal = use_signal(|| false);
     let gas_estimate = use_signal(|| Option::<GasEstimate>::None);
+    // 按实际交易估算的网络费（含 gas limit 与 L2 链的 L1 数据费）
+    let fee_estimate = use_signal(|| Option::<FeeEstimate>::None);
     let gas_loading = use_signal(|| false);
     let fee_calculating = use_signal(|| false); // ✅ 费用计算加载状态
     let platform_fee = use_signal(|| Option::<f64>::None); // ✅ 平台服务费
 
+    // Solana 优先费：按档位估算的单价、手动单价，以及按上限截断后的生效预算
+    let solana_fees = use_signal(|| Option::<PriorityFeeEstimate>::None);
+    let solana_fees_loading = use_signal(|| false);
+    let solana_price_override = use_signal(|| Option::<u64>::None);
+    let solana_budget = use_memo(move || {
+        let is_solana = selected_token
+            .read()
+            .as_ref()
+            .is_some_and(|t| t.chain == ChainType::Solana);
+        if !is_solana {
+            return None;
+        }
+        let price = solana_price_override()
+            .or_else(|| solana_fees().map(|f| f.unit_price(speed_tier.read().to_gas_speed())))?;
+        let max = app_state
+            .preferences
+            .read()
+            .solana_max_priority_fee_lamports;
+        Some(ComputeBudget::transfer(price).capped(max))
+    });
+
+    // ✅ 发送前安全检查：金额占比、合约地址、历史发送记录
+    let safeguard_config = use_signal(SafeguardConfig::load);
+    let send_history = use_signal(SendHistory::load);
+    let token_balance = use_signal(|| Option::<f64>::None);
+    let token_price_usd = use_signal(|| Option::<f64>::None);
+    let native_value_usd = use_signal(|| Option::<f64>::None);
+    let acknowledged_warnings = use_signal(BTreeSet::<&'static str>::new);
+    // 地址投毒检测：粘贴的地址命中疑似投毒地址时阻止发送
+    let poisoning_index = use_signal(PoisoningIndex::load);
+
+    // ✅ 内部转账：接收方为当前钱包的其他账户或其他钱包
+    let mut internal_mode = use_signal(|| false);
+    let mut internal_target = use_signal(|| Option::<InternalTarget>::None);
+
     // 获取当前钱包
     let current_wallet = use_memo(move || {
         let wallet_state = app_state.wallet.read();
@@ -772,57 +1023,40 @@ pub fn Send() -> Element {
         };
     }
 
-    // ✅ 智能地址验证：检测地址格式并与选择的代币链进行匹配
+    // ✅ 智能地址验证：验证规则随所选代币（链与精度）更新
     use_effect(move || {
-        let addr = recipient_address.read().clone();
         let token = selected_token.read().clone();
+        amount_field.set_rules(amount_rules(token.as_ref().map_or(18, |t| t.decimals)));
+        recipient_field.set_rules(recipient_rules(token));
+    });
+
+    // 检测接收地址所属链（用于选择支付策略）
+    // 内部转账已知接收链（EVM 地址无法从地址本身区分 Ethereum/BSC/Polygon）
+    use_effect(move || {
+        let addr = recipient_address.read().clone();
+        let internal_chain = internal_target
+            .read()
+            .as_ref()
+            .filter(|t| internal_mode() && t.account.address == addr)
+            .map(|t| t.chain);
         let mut detected_chain_mut = detected_chain;
-        let mut address_validation_error_mut = address_validation_error;
-
-        if !addr.trim().is_empty() {
-            match AddressDetector::detect_chain(&addr) {
-                Ok(detected) => {
-                    detected_chain_mut.set(Some(detected));
-
-                    // ✅ 如果用户已选择代币，验证地址是否匹配代币的链
-                    if let Some(ref token_info) = token {
-                        if detected != token_info.chain {
-                            // ✅ 跨链场景：只要是支持的 EVM↔EVM 组合就允许继续（资产类型交由后端 quote 校验）
-                            if is_bridge_supported(token_info.chain, detected) {
-                                address_validation_error_mut.set(None);
-                            } else {
-                                address_validation_error_mut.set(Some(format!(
-                                    "⚠️ 地址错误：该地址属于 {}，但您选择的代币 {} 在 {} 上",
-                                    detected.label(),
-                                    token_info.symbol,
-                                    token_info.chain.label()
-                                )));
-                            }
-                        } else {
-                            address_validation_error_mut.set(None); // ✅ 验证通过
-                        }
-                    } else {
-                        // 未选择代币，只显示检测结果
-                        address_validation_error_mut.set(None);
-                    }
-                }
-                Err(e) => {
-                    detected_chain_mut.set(None);
-                    if addr.len() > 5 {
-                        #[cfg(debug_assertions)]
-                        tracing::debug!("address_detect_error={}", e);
+        detected_chain_mut.set(internal_chain.or_else(|| {
+            address_validation::detect_and_validate(&addr)
+                .ok()
+                .map(|a| a.chain)
+        }));
+    });
 
-                        address_validation_error_mut
-                            .set(Some("地址格式无效，请检查后重试".to_string()));
-                    } else {
-                        address_validation_error_mut.set(None);
-                    }
-                }
-            }
-        } else {
-            detected_chain_mut.set(None);
-            address_validation_error_mut.set(None);
+    // 跨链的内部转账改走跨链页（两侧预填），不在发送页自动桥接
+    let internal_cross_chain = use_memo(move || {
+        if !internal_mode() {
+            return None;
         }
+        let token_chain = selected_token.read().as_ref()?.chain;
+        internal_target
+            .read()
+            .clone()
+            .filter(|t| t.chain != token_chain)
     });
 
     // ✅ 自动选择支付策略：同链直发 / 跨链桥（EVM↔EVM）/ 不支持
@@ -832,12 +1066,20 @@ pub fn Send() -> Element {
         let wallet = current_wallet.read().clone();
         let amt_str = amount.read().clone();
         let gas = gas_estimate.read().clone();
+        let network_fee = fee_estimate.read().as_ref().map(|f| f.breakdown().total);
+        let solana_fee = solana_budget().map(|(budget, _)| budget.total_fee_lamports());
         let platform_fee_val = platform_fee.read().unwrap_or(0.0);
         let mut strategy_mut = payment_strategy;
         let mut err_mut = error_message;
         let app_state_clone = app_state.clone();
+        let redirect_to_bridge = internal_cross_chain().is_some();
 
         spawn(async move {
+            if redirect_to_bridge {
+                err_mut.set(None);
+                strategy_mut.set(None);
+                return;
+            }
             let (Some(token), Some(target_chain), Some(wallet)) = (token, detected, wallet) else {
                 strategy_mut.set(None);
                 return;
@@ -898,16 +1140,21 @@ pub fn Send() -> Element {
                 }
             };
 
-            // 计算 gas_fee（用于费用明细展示与余额校验）
-            let gas_fee = gas
-                .as_ref()
-                .map(|g| {
-                    crate::services::gas::gas_fee_eth_from_max_fee_per_gas_gwei(
-                        g.max_fee_per_gas_gwei,
-                        21_000,
-                    )
-                })
-                .unwrap_or(0.0);
+            // 计算 gas_fee（用于费用明细展示与余额校验；Solana 为基础费 + 优先费）
+            let gas_fee = match (from_chain, solana_fee) {
+                (ChainType::Solana, Some(lamports)) => lamports as f64 / LAMPORTS_PER_SOL as f64,
+                // 按实际交易估算的网络费（L2 链含 L1 数据费）优先
+                _ => network_fee
+                    .or_else(|| {
+                        gas.as_ref().map(|g| {
+                            crate::services::gas::gas_fee_eth_from_max_fee_per_gas_gwei(
+                                g.max_fee_per_gas_gwei,
+                                21_000,
+                            )
+                        })
+                    })
+                    .unwrap_or(0.0),
+            };
 
             // 组装 gas_details（直接转账需要）
             let gas_details =
@@ -984,9 +1231,12 @@ pub fn Send() -> Element {
     // ✅ 金额或速度等级变化时自动计算Gas费用
     use_effect(move || {
         let mut fee_calculating_mut = fee_calculating;
-        let mut error_message_mut = error_message;
+        let error_message_mut = error_message;
         let gas_est_mut = gas_estimate;
+        let fee_est_mut = fee_estimate;
         let mut gas_loading_mut = gas_loading;
+        let recipient = recipient_address.read().trim().to_string();
+        let amount_val = amount.read().trim().parse::<f64>().unwrap_or(0.0);
 
         // 当选择了代币、输入了地址和金额后，自动计算费用
         if let (Some(token), Some(_detected), Some(wallet)) = (
@@ -994,53 +1244,114 @@ pub fn Send() -> Element {
             detected_chain.read().as_ref(),
             current_wallet.read().as_ref(),
         ) {
-            match PaymentValidator::validate_amount(&amount.read()) {
-                Ok(amount_val) => {
-                    fee_calculating_mut.set(true);
-                    gas_loading_mut.set(true);
-                    let app_state_clone = app_state.clone();
-                    let chain_clone = token.chain; // ✅ 使用代币的链
-                    let wallet_clone = wallet.clone();
-                    let speed_tier_clone = *speed_tier.read();
-
-                    let mut fee_calculating_clone = fee_calculating_mut;
-                    let mut error_message_clone = error_message_mut;
-                    let mut gas_est_clone = gas_est_mut;
-                    let mut gas_loading_clone = gas_loading_mut;
-                    spawn(async move {
-                        // ✅ 按速度档位获取 Gas 估算：Slow/Medium/Fast
-                        let gas_service = GasService::new(app_state_clone);
-                        match gas_service
-                            .estimate(chain_clone.as_str(), speed_tier_clone.to_gas_speed())
+            // Solana 使用优先费估算，不走 EVM Gas 接口
+            if amount_field.is_valid() && token.chain != ChainType::Solana {
+                fee_calculating_mut.set(true);
+                gas_loading_mut.set(true);
+                let app_state_clone = app_state.clone();
+                let chain_clone = token.chain; // ✅ 使用代币的链
+                let wallet_clone = wallet.clone();
+                let speed_tier_clone = *speed_tier.read();
+                let fee_tx = fee_simulation_request(token, wallet, &recipient, amount_val);
+                let is_native = token.is_native;
+
+                let mut fee_calculating_clone = fee_calculating_mut;
+                let mut error_message_clone = error_message_mut;
+                let mut gas_est_clone = gas_est_mut;
+                let mut fee_est_clone = fee_est_mut;
+                let mut gas_loading_clone = gas_loading_mut;
+                spawn(async move {
+                    // ✅ 按速度档位获取 Gas 估算：Slow/Medium/Fast
+                    let gas_service = GasService::new(app_state_clone);
+                    let speed = speed_tier_clone.to_gas_speed();
+                    // 交易完整时按模拟估算的 gas limit 计费，L2 链的 L1 数据费也随 calldata 计算
+                    let result = match fee_tx {
+                        Some(tx) => {
+                            let chain_id = ChainConfigManager::new()
+                                .get_chain_id(chain_clone)
+                                .unwrap_or(0);
+                            let gas_limit = estimate_gas_limit(
+                                app_state_clone,
+                                chain_id,
+                                &tx.from,
+                                &tx.to,
+                                if is_native { amount_val } else { 0.0 },
+                                (!is_native).then_some(tx.data.as_str()),
+                            )
                             .await
-                        {
-                            Ok(gas_est) => {
-                                gas_est_clone.set(Some(gas_est));
-                                fee_calculating_clone.set(false);
-                                gas_loading_clone.set(false);
-                            }
-                            Err(e) => {
-                                error_message_clone.set(Some(
-                                    crate::shared::ui_error::sanitize_user_message(format!(
-                                        "计算Gas费用失败: {}",
-                                        e
-                                    )),
-                                ));
-                                fee_calculating_clone.set(false);
-                                gas_loading_clone.set(false);
-                            }
+                            .unwrap_or(21_000);
+                            gas_service
+                                .estimate_fee(&tx, speed, gas_limit)
+                                .await
+                                .map(|fee| (fee.gas.clone(), Some(fee)))
+                        }
+                        None => gas_service
+                            .estimate(chain_clone.as_str(), speed)
+                            .await
+                            .map(|gas| (gas, None)),
+                    };
+                    match result {
+                        Ok((gas_est, fee)) => {
+                            gas_est_clone.set(Some(gas_est));
+                            fee_est_clone.set(fee);
+                            fee_calculating_clone.set(false);
+                            gas_loading_clone.set(false);
+                        }
+                        Err(e) => {
+                            fee_est_clone.set(None);
+                            error_message_clone.set(Some(
+                                crate::shared::ui_error::sanitize_user_message(format!(
+                                    "计算Gas费用失败: {}",
+                                    e
+                                )),
+                            ));
+                            fee_calculating_clone.set(false);
+                            gas_loading_clone.set(false);
                         }
-                    });
-                }
-                Err(e) => {
-                    if !amount.read().is_empty() {
-                        error_message_mut.set(Some(e.to_string()));
                     }
-                }
+                });
             }
         }
     });
 
+    // Solana：按发送方与接收方账户的最近优先费估算各档位单价
+    use_effect(move || {
+        let token = selected_token.read().clone();
+        let recipient = recipient_address.read().trim().to_string();
+        let from_address = current_wallet.read().as_ref().and_then(|w| {
+            w.accounts
+                .iter()
+                .find(|acc| ChainType::from_str(&acc.chain) == Some(ChainType::Solana))
+                .map(|acc| acc.address.clone())
+        });
+        let mut fees_mut = solana_fees;
+        let mut loading_mut = solana_fees_loading;
+        if !token.is_some_and(|t| t.chain == ChainType::Solana) {
+            fees_mut.set(None);
+            return;
+        }
+        let mut accounts: Vec<String> = from_address.into_iter().collect();
+        if let Ok(normalized) = address_validation::validate_address(ChainType::Solana, &recipient)
+        {
+            accounts.push(normalized.address);
+        }
+        spawn(async move {
+            loading_mut.set(true);
+            match SolanaAdapter::mainnet()
+                .estimate_priority_fees(&accounts)
+                .await
+            {
+                Ok(estimate) => fees_mut.set(Some(estimate)),
+                Err(e) => {
+                    // 估算失败时退回默认最低单价，不阻止发送
+                    log::warn!("估算Solana优先费失败: {}", e);
+                    fees_mut.set(Some(PriorityFeeEstimate::from_recent_fees(&[])));
+                }
+            }
+            loading_mut.set(false);
+        });
+    });
+
     // ✅ 计算平台服务费（基于选择的代币链）
     use_effect(move || {
         let chain_str = if let Some(token) = selected_token.read().as_ref() {
@@ -1099,47 +1410,180 @@ pub fn Send() -> Element {
             .unwrap_or(ChainType::Ethereum)
     });
 
-    // 🔧 修复：使用use_memo使wallet_addr响应式更新，并添加fallback逻辑
+    // 原生币手续费余量（不足时提示先补充原生币）
+    let readiness_chain = use_memo(move || Some(target_chain.read().as_str().to_string()));
+    let gas_readiness = use_gas_readiness(readiness_chain, readiness::TRANSFER_GAS_UNITS);
+
+    // 目标链的账户地址（钱包没有该链账户时为 None，不用其他链的地址查询余额）
     let wallet_addr = use_memo(move || {
-        current_wallet.read().as_ref().and_then(|wallet| {
-            let target = *target_chain.read();
+        current_wallet
+            .read()
+            .as_ref()
+            .and_then(|wallet| account_for_chain(wallet, *target_chain.read()))
+            .map(|acc| acc.address.clone())
+    });
+
+    // 内部转账的接收方（按链分组，仅保留同链或支持跨链的组合）
+    let internal_groups = use_memo(move || {
+        let source_chain = *target_chain.read();
+        let source_address = wallet_addr.read().clone().unwrap_or_default();
+        let current_id = current_wallet
+            .read()
+            .as_ref()
+            .map(|w| w.id.clone())
+            .unwrap_or_default();
+        let wallet_state = app_state.wallet.read();
+        internal_transfer::internal_targets(
+            &wallet_state.wallets,
+            &current_id,
+            source_chain,
+            &source_address,
+        )
+        .into_iter()
+        .filter(|(chain, _)| *chain == source_chain || is_bridge_supported(source_chain, *chain))
+        .collect::<Vec<_>>()
+    });
+
+    // ✅ 加载所选代币的可用余额（用于金额占比检查）
+    use_effect(move || {
+        let token = selected_token.read().clone();
+        let addr = wallet_addr.read().clone();
+        let mut token_balance_mut = token_balance;
+        token_balance_mut.set(None);
 
-            #[cfg(debug_assertions)]
+        let account = current_wallet.read().as_ref().and_then(|w| {
+            w.accounts
+                .iter()
+                .find(|acc| Some(&acc.address) == addr.as_ref())
+                .cloned()
+        });
+        let (Some(token), Some(account)) = (token, account) else {
+            return;
+        };
+        spawn(async move {
+            match BalanceService::new(app_state)
+                .get_asset_balance(&account, &token)
+                .await
             {
-                use tracing::info;
-                info!("[Send] Matching wallet account for chain: {:?}", target);
-                info!(
-                    "[Send] Available accounts: {:?}",
-                    wallet.accounts.iter().map(|a| &a.chain).collect::<Vec<_>>()
-                );
+                Ok(balance) => {
+                    let available = app_state
+                        .committed_balances
+                        .read()
+                        .available(&token.symbol, balance);
+                    token_balance_mut.set(Some(available.to_f64_lossy()));
+                }
+                Err(e) => log::warn!("加载代币余额失败: {}", e),
             }
+        });
+    });
 
-            // 尝试匹配目标链
-            let matched = wallet
-                .accounts
-                .iter()
-                .find(|acc| {
-                    let acc_chain = match acc.chain.to_lowercase().as_str() {
-                        "ethereum" => ChainType::Ethereum,
-                        "bitcoin" => ChainType::Bitcoin,
-                        "solana" => ChainType::Solana,
-                        "ton" => ChainType::TON,
-                        _ => ChainType::Ethereum,
-                    };
-                    acc_chain == target
-                })
-                .map(|acc| acc.address.clone());
+    // ✅ 加载价格：代币单价 + 钱包原生币总价值（用于总价值占比检查）
+    use_effect(move || {
+        let token_symbol = selected_token
+            .read()
+            .as_ref()
+            .map(|t| t.symbol.to_uppercase());
+        let accounts = current_wallet
+            .read()
+            .as_ref()
+            .map(|w| w.accounts.clone())
+            .unwrap_or_default();
+        let mut token_price_mut = token_price_usd;
+        let mut native_value_mut = native_value_usd;
 
-            // 如果没有匹配到，fallback到第一个账户
-            matched.or_else(|| {
-                #[cfg(debug_assertions)]
-                {
-                    use tracing::warn;
-                    warn!("[Send] No matching account found, using first account as fallback");
+        spawn(async move {
+            let mut symbols: Vec<&str> = accounts.iter().map(|a| native_symbol(&a.chain)).collect();
+            if let Some(symbol) = token_symbol.as_deref() {
+                symbols.push(symbol);
+            }
+            symbols.sort_unstable();
+            symbols.dedup();
+
+            match PriceService::new(app_state).get_prices(&symbols).await {
+                Ok(prices) => {
+                    token_price_mut.set(
+                        token_symbol
+                            .as_deref()
+                            .and_then(|s| prices.get(s))
+                            .map(|p| p.usd),
+                    );
+                    let native_total: f64 = accounts
+                        .iter()
+                        .filter_map(|a| {
+                            let price = prices.get(native_symbol(&a.chain))?.usd;
+                            Some(a.balance.parse::<f64>().unwrap_or(0.0) * price)
+                        })
+                        .sum();
+                    native_value_mut.set(Some(native_total));
                 }
-                wallet.accounts.first().map(|acc| acc.address.clone())
-            })
-        })
+                Err(e) => {
+                    log::warn!("加载价格失败: {}", e);
+                    token_price_mut.set(None);
+                    native_value_mut.set(None);
+                }
+            }
+        });
+    });
+
+    let send_warnings = use_memo(move || {
+        let token = selected_token.read().clone();
+        let recipient = recipient_address.read().clone();
+        let amount_val = amount.read().trim().parse::<f64>().unwrap_or(0.0);
+        let balance = *token_balance.read();
+        let price = *token_price_usd.read();
+        let native_value = *native_value_usd.read();
+
+        let Some(token) = token else {
+            return Vec::new();
+        };
+        // 总价值 = 原生币持仓 + 非原生代币持仓（原生币已计入）
+        let portfolio_usd = native_value.map(|native| {
+            if token.is_native {
+                native
+            } else {
+                native + balance.zip(price).map(|(b, p)| b * p).unwrap_or(0.0)
+            }
+        });
+        send_safeguards::evaluate(
+            &SendCheck {
+                recipient: &recipient,
+                amount: amount_val,
+                token_symbol: &token.symbol,
+                token_address: (!token.is_native).then_some(token.address.as_str()),
+                token_balance: balance,
+                amount_usd: price.map(|p| p * amount_val),
+                portfolio_usd,
+                internal: internal_mode(),
+            },
+            &safeguard_config.read(),
+        )
+    });
+
+    let poisoning_match = use_memo(move || {
+        if internal_mode() {
+            return None;
+        }
+        poisoning_index
+            .read()
+            .check_recipient(&recipient_address.read())
+    });
+
+    // 提示变化（修改地址/金额）后需要重新确认
+    use_effect(move || {
+        let codes: BTreeSet<&'static str> = send_warnings.read().iter().map(|w| w.code()).collect();
+        let mut acknowledged_mut = acknowledged_warnings;
+        let stale = acknowledged_mut.peek().iter().any(|c| !codes.contains(c));
+        if stale {
+            acknowledged_mut.write().retain(|c| codes.contains(c));
+        }
+    });
+
+    let warnings_acknowledged = use_memo(move || {
+        let acknowledged = acknowledged_warnings.read();
+        send_warnings
+            .read()
+            .iter()
+            .all(|w| acknowledged.contains(w.code()))
     });
 
     rsx! {
@@ -1165,12 +1609,27 @@ pub fn Send() -> Element {
                     }
                 }
 
+                // 推迟验证助记词超过 7 天时，转出前需先完成验证
+                BackupGate {}
+
+                div {
+                    class: "mb-4",
+                    GasReadinessBanner {
+                        readiness: gas_readiness,
+                        on_buy: move |prefill: BuyPrefill| {
+                            readiness::set_pending_buy(&prefill);
+                            navigator.push(Route::Swap {});
+                        },
+                    }
+                }
+
                 Card {
                     variant: crate::components::atoms::card::CardVariant::Base,
                     padding: Some("24px".to_string()),
                     children: rsx! {
                         // ✅ 步骤1：选择代币（从钱包余额中智能过滤）
                         div {
+                            id: "send-token-step",
                             class: "mb-6",
                             label {
                                 class: "block text-sm font-medium mb-2",
@@ -1184,41 +1643,73 @@ pub fn Send() -> Element {
                                 selected_token: selected_token,
                                 wallet_address: wallet_addr.read().clone(),
                             }
+                            ChainAccountPrompt {
+                                chain: *target_chain.read(),
+                                token_symbol: selected_token.read().as_ref().map(|t| t.symbol.clone()),
+                            }
                         }
 
                         // ✅ 步骤2：接收地址输入
                         div {
+                            id: "send-recipient-step",
                             class: "mb-6",
                             label {
                                 class: "block text-sm font-medium mb-2",
                                 style: format!("color: {};", Colors::TEXT_PRIMARY),
                                 "2️⃣ 接收地址"
                             }
-                            Input {
-                                input_type: InputType::Text,
-                                placeholder: Some("请输入接收地址".to_string()),
-                                value: Some(recipient_address.read().clone()),
-                                onchange: {
-                                    let mut recipient_address = recipient_address;
-                                    Some(EventHandler::new(move |e: FormEvent| {
-                                        recipient_address.set(e.value());
-                                    }))
-                                },
-                            }
-
-                            // ✅ 地址验证结果：成功或错误
-                            if let Some(error) = address_validation_error.read().as_ref() {
-                                // 显示验证错误
-                                div {
-                                    class: "mt-2 p-3 rounded-lg",
-                                    style: format!("background: rgba(239, 68, 68, 0.1); border: 1px solid {};", Colors::PAYMENT_ERROR),
-                                    span {
-                                        class: "text-sm",
-                                        style: format!("color: {};", Colors::PAYMENT_ERROR),
-                                        {error.clone()}
+                            div {
+                                class: "flex gap-2 mb-3",
+                                for (internal, text) in [(false, "外部地址"), (true, "内部转账")] {
+                                    button {
+                                        key: "{text}",
+                                        class: "flex-1 px-3 py-2 rounded-lg text-sm",
+                                        style: if internal_mode() == internal {
+                                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                                        } else {
+                                            format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY)
+                                        },
+                                        onclick: move |_| {
+                                            if internal_mode() != internal {
+                                                internal_mode.set(internal);
+                                                internal_target.set(None);
+                                                let mut recipient_address = recipient_address;
+                                                recipient_address.set(String::new());
+                                            }
+                                        },
+                                        "{text}"
                                     }
                                 }
-                            } else if let Some(chain) = detected_chain.read().as_ref() {
+                            }
+                            if internal_mode() {
+                                InternalTargetList {
+                                    groups: internal_groups(),
+                                    selected: internal_target.read().as_ref().map(|t| t.key()),
+                                    on_select: move |target: InternalTarget| {
+                                        let mut recipient_address = recipient_address;
+                                        recipient_address.set(target.account.address.clone());
+                                        internal_target.set(Some(target));
+                                        recipient_field.touch();
+                                    },
+                                }
+                            } else {
+                                Input {
+                                    input_type: InputType::Text,
+                                    placeholder: Some("请输入接收地址".to_string()),
+                                    value: Some(recipient_address.read().clone()),
+                                    error: recipient_field.visible_error(),
+                                    onchange: {
+                                        let mut recipient_address = recipient_address;
+                                        Some(EventHandler::new(move |e: FormEvent| {
+                                            recipient_address.set(e.value());
+                                        }))
+                                    },
+                                    onblur: move |_| recipient_field.touch(),
+                                }
+                            }
+
+                            // ✅ 地址验证通过时显示检测到的链（错误显示在输入框下方）
+                            if let Some(chain) = detected_chain.read().filter(|_| recipient_field.is_valid()) {
                                 // 显示检测成功
                                 div {
                                     class: "mt-2 p-2 rounded-lg",
@@ -1230,10 +1721,23 @@ pub fn Send() -> Element {
                                     }
                                 }
                             }
+
+                            // 疑似地址投毒：与常用收款方并排对比
+                            if let Some(hit) = poisoning_match() {
+                                PoisoningComparison {
+                                    hit: hit,
+                                    on_use_legitimate: move |address: String| {
+                                        let mut recipient_address = recipient_address;
+                                        recipient_address.set(address);
+                                        recipient_field.touch();
+                                    },
+                                }
+                            }
                         }
 
                         // ✅ 步骤3：金额输入
                         div {
+                            id: "send-amount-step",
                             class: "mb-6",
                             label {
                                 class: "block text-sm font-medium mb-2",
@@ -1244,6 +1748,8 @@ pub fn Send() -> Element {
                                 input_type: InputType::Text,
                                 placeholder: Some("0.0".to_string()),
                                 value: Some(amount.read().clone()),
+                                error: amount_field.visible_error(),
+                                onblur: move |_| amount_field.touch(),
                                 onchange: {
                                     let mut amount = amount;
                                     Some(EventHandler::new(move |e: FormEvent| {
@@ -1255,7 +1761,7 @@ pub fn Send() -> Element {
                             // 显示可用余额（基于选择的代币）
                             if let Some(token) = selected_token.read().as_ref() {
                                 if let Some(wallet) = current_wallet.read().as_ref() {
-                                    if let Some(acc) = wallet.accounts.first() {
+                                    if let Some(acc) = account_for_chain(wallet, token.chain) {
                                         div {
                                             class: "mt-2 text-sm",
                                             style: format!("color: {};", Colors::TEXT_TERTIARY),
@@ -1355,44 +1861,108 @@ pub fn Send() -> Element {
                             }
                         }
 
-                        // ✅ Gas费用显示（含平台服务费）
-                        GasFeeCard {
-                            gas_estimate: gas_estimate.read().clone(),
-                            platform_fee: platform_fee.read().clone(),
-                            is_loading: *gas_loading.read(),
+                        // ✅ Gas费用显示（含平台服务费；Solana 显示优先费）
+                        if selected_token.read().as_ref().is_some_and(|t| t.chain == ChainType::Solana) {
+                            SolanaFeeCard {
+                                budget: solana_budget().map(|(budget, _)| budget),
+                                capped: solana_budget().is_some_and(|(_, capped)| capped),
+                                max_priority_fee_lamports: app_state.preferences.read().solana_max_priority_fee_lamports,
+                                platform_fee: platform_fee.read().clone(),
+                                is_loading: solana_fees_loading(),
+                                unit_price_override: solana_price_override,
+                            }
+                        } else {
+                            GasFeeCard {
+                                gas_estimate: gas_estimate.read().clone(),
+                                platform_fee: platform_fee.read().clone(),
+                                is_loading: *gas_loading.read(),
+                                gas_limit: fee_estimate.read().as_ref().map_or(21_000, |f| f.gas_limit),
+                                data_fee: fee_estimate.read().as_ref().and_then(|f| f.data_fee),
+                                data_fee_unavailable: fee_estimate.read().as_ref().is_some_and(|f| f.data_fee_unavailable),
+                            }
                         }
 
 
 
+                        // ✅ 发送前安全检查
+                        if recipient_field.is_valid() {
+                            SendSafeguardPanel {
+                                warnings: send_warnings.read().clone(),
+                                acknowledged: acknowledged_warnings,
+                                previous_sends: send_history.read().count(&recipient_address.read()),
+                                internal: internal_mode(),
+                            }
+                        }
+
                         // 错误提示
                         ErrorMessage {
                             message: error_message.read().clone(),
                         }
 
+                        // 广播后节点中查不到交易时提示重新广播
+                        RebroadcastPrompt {
+                            pending: unpropagated,
+                            on_done: move |_| {
+                                navigator.push(Route::Dashboard {});
+                            },
+                        }
+
                         // ✅ 步骤4：确认发送按钮
                         div {
+                            id: "send-submit-step",
                             class: "flex gap-4 mt-6",
-                            Button {
-                                variant: ButtonVariant::Primary,
-                                size: ButtonSize::Large,
-                                class: Some("flex-1".to_string()),
-                                disabled: {
-                                    // ✅ 验证条件：选择代币 + 输入地址（无验证错误）+ 输入金额
-                                    selected_token.read().is_none() ||
-                                    recipient_address.read().trim().is_empty() ||
-                                    amount.read().trim().is_empty() ||
-                                    address_validation_error.read().is_some() ||
-                                    error_message.read().is_some() ||
-                                    *is_loading.read()
-                                },
-                                loading: *is_loading.read(),
-                                onclick: {
-                                    let mut show_confirm_modal_mut = show_confirm_modal;
-                                    move |_| {
-                                        show_confirm_modal_mut.set(true);
-                                    }
-                                },
-                                "4️⃣ 确认发送"
+                            if let Some(target) = internal_cross_chain() {
+                                Button {
+                                    variant: ButtonVariant::Primary,
+                                    size: ButtonSize::Large,
+                                    class: Some("flex-1".to_string()),
+                                    disabled: selected_token.read().is_none() || !amount_field.is_valid(),
+                                    onclick: move |_| {
+                                        let (Some(token), Some(target)) =
+                                            (selected_token.read().clone(), internal_cross_chain())
+                                        else {
+                                            return;
+                                        };
+                                        // 发送到本钱包时由跨链页发往本钱包在目标链的账户
+                                        let own_wallet = current_wallet
+                                            .read()
+                                            .as_ref()
+                                            .is_some_and(|w| w.id == target.wallet_id);
+                                        bridge_prefill::set_pending_prefill(&BridgePrefill {
+                                            from_chain: token.chain.as_str().to_string(),
+                                            token: token.symbol.clone(),
+                                            to_chain: Some(target.chain.as_str().to_string()),
+                                            amount: Some(amount.read().trim().to_string()),
+                                            destination_address: (!own_wallet)
+                                                .then(|| target.account.address.clone()),
+                                        });
+                                        navigator.push(Route::Bridge {});
+                                    },
+                                    {format!("前往跨链：{} → {}", selected_token.read().as_ref().map_or("", |t| t.chain.label()), target.chain.label())}
+                                }
+                            } else {
+                                Button {
+                                    variant: ButtonVariant::Primary,
+                                    size: ButtonSize::Large,
+                                    class: Some("flex-1".to_string()),
+                                    disabled: {
+                                        // ✅ 验证条件：选择代币 + 地址与金额字段均通过验证
+                                        selected_token.read().is_none() ||
+                                        !all_valid(&[recipient_field, amount_field]) ||
+                                        error_message.read().is_some() ||
+                                        !*warnings_acknowledged.read() ||
+                                        poisoning_match.read().is_some() ||
+                                        *is_loading.read()
+                                    },
+                                    loading: *is_loading.read(),
+                                    onclick: {
+                                        let mut show_confirm_modal_mut = show_confirm_modal;
+                                        move |_| {
+                                            show_confirm_modal_mut.set(true);
+                                        }
+                                    },
+                                    "4️⃣ 确认发送"
+                                }
                             }
                             Button {
                                 variant: ButtonVariant::Secondary,
@@ -1427,14 +1997,34 @@ pub fn Send() -> Element {
                         let mut modal_signal = show_confirm_modal;
                         let err_signal = error_message;
                         let toasts = app_state.toasts;
+                        let mut history_signal = send_history;
+                        let mut unpropagated_signal = unpropagated;
                         move |_| {
                             loading_signal.set(true);
                             modal_signal.set(false);
 
+                            // 记录用户确认忽略的风险提示
+                            let overridden = send_warnings.read().clone();
+                            if !overridden.is_empty() {
+                                let event = overridden_warnings_event(
+                                    &overridden,
+                                    &recipient_address_clone.read(),
+                                    &amount_clone.read(),
+                                    selected_token_signal.read().as_ref(),
+                                );
+                                spawn(async move {
+                                    if let Err(e) = AuditLogService::new(app_state).record_event(event).await {
+                                        log::warn!("记录风险提示确认失败: {}", e);
+                                    }
+                                });
+                            }
+
                             let recipient = recipient_address_clone.read().clone();
                             let amt = amount_clone.read().clone();
                             let strategy_clone = payment_strategy_signal.read().clone();
                             let token_clone = selected_token_signal.read().clone();
+                            let solana_budget_val = solana_budget().map(|(budget, _)| budget);
+                            let internal = internal_mode();
 
                             let mut loading_clone = loading_signal;
                             let mut err_clone = err_signal;
@@ -1447,6 +2037,15 @@ pub fn Send() -> Element {
                                     err_clone.set(Some("请输入接收地址".to_string()));
                                     return;
                                 }
+                                // 统一使用规范化地址（EIP-55 校验和 / Bech32 小写）
+                                let recipient = match address_validation::detect_and_validate(&recipient) {
+                                    Ok(normalized) => normalized.address,
+                                    Err(e) => {
+                                        loading_clone.set(false);
+                                        err_clone.set(Some(e.to_string()));
+                                        return;
+                                    }
+                                };
 
                                 let amount_val: f64 = match amt.parse() {
                                     Ok(v) if v > 0.0 => v,
@@ -1472,11 +2071,38 @@ pub fn Send() -> Element {
                                             &account,
                                             &fee_breakdown, // ✅ 传递费用明细
                                             token_info_ref, // ✅ 传递代币信息
+                                            solana_budget_val,
+                                            None,
                                         ).await {
-                                            Ok(_) => {
-                                                AppState::show_success(toasts, "交易发送成功".to_string());
+                                            Ok(receipt) => {
+                                                if internal {
+                                                    // 内部转账：标记交易，统计支出时不计入
+                                                    internal_transfer::tag(&receipt.pending.tx_hash);
+                                                } else {
+                                                    SendHistory::record(&recipient);
+                                                    history_signal.set(SendHistory::load());
+                                                }
                                                 loading_clone.set(false);
-                                                nav_clone.push(Route::Dashboard {});
+                                                watch_out_of_gas(
+                                                    app_state,
+                                                    wallet_ctrl,
+                                                    DirectTransfer {
+                                                        recipient: recipient.clone(),
+                                                        amount: amount_val,
+                                                        chain,
+                                                        account: account.clone(),
+                                                        fee_breakdown: fee_breakdown.clone(),
+                                                        token: token_clone.clone(),
+                                                    },
+                                                    receipt.pending.tx_hash.clone(),
+                                                );
+                                                if receipt.propagation.is_not_found() {
+                                                    // 节点中查不到交易：留在本页，由用户决定是否重新广播
+                                                    unpropagated_signal.set(Some(receipt.pending));
+                                                } else {
+                                                    AppState::show_success(toasts, "交易发送成功".to_string());
+                                                    nav_clone.push(Route::Dashboard {});
+                                                }
                                             }
                                             Err(e) => {
                                                 err_clone.set(Some(
@@ -1495,8 +2121,10 @@ pub fn Send() -> Element {
                                         // 为了简化，我们假设跨链桥服务会处理完整的流程
 
                                         // 验证余额是否足够（包括跨链费用）
-                                        let from_balance: f64 = from_account.balance.parse()
-                                            .unwrap_or(0.0);
+                                        let from_balance = app_state.committed_balances.read().available_f64(
+                                            native_symbol(&from_account.chain),
+                                            from_account.balance.parse().unwrap_or(0.0),
+                                        );
                                         if from_balance < amount_val + fee_breakdown.total_fee {
                                             err_clone.set(Some(format!(
                                                 "{}链余额不足：需要 {:.6}，当前余额 {}",
@@ -1519,6 +2147,8 @@ pub fn Send() -> Element {
                                             token_clone.clone(),
                                         ).await {
                                             Ok(_) => {
+                                                SendHistory::record(&recipient);
+                                                history_signal.set(SendHistory::load());
                                                 AppState::show_success(toasts, format!(
                                                     "跨链转账已发起：从{}链到{}链",
                                                     from_chain.label(),
@@ -1561,6 +2191,224 @@ pub fn Send() -> Element {
     }
 }
 
+/// 被用户确认忽略的风险提示审计事件
+fn overridden_warnings_event(
+    warnings: &[SendWarning],
+    recipient: &str,
+    amount: &str,
+    token: Option<&TokenInfo>,
+) -> AuditEventRequest {
+    AuditEventRequest {
+        action: "send_warnings_overridden".to_string(),
+        resource_type: "transaction".to_string(),
+        resource_id: recipient.to_string(),
+        details: serde_json::json!({
+            "warnings": warnings.iter().map(|w| w.code()).collect::<Vec<_>>(),
+            "messages": warnings.iter().map(|w| w.message()).collect::<Vec<_>>(),
+            "recipient": recipient,
+            "amount": amount,
+            "token": token.map(|t| t.symbol.clone()),
+            "chain": token.map(|t| t.chain.label()),
+        }),
+    }
+}
+
+/// 发送前安全检查面板：逐条确认风险提示，并显示历史发送次数（内部转账不显示）
+#[component]
+fn SendSafeguardPanel(
+    warnings: Vec<SendWarning>,
+    acknowledged: Signal<BTreeSet<&'static str>>,
+    previous_sends: u32,
+    internal: bool,
+) -> Element {
+    let history_color = if previous_sends == 0 && !internal {
+        "rgba(234, 179, 8, 1)"
+    } else {
+        Colors::TEXT_SECONDARY
+    };
+
+    rsx! {
+        div {
+            class: "mb-6 space-y-2",
+            div {
+                class: "text-xs",
+                style: format!("color: {};", history_color),
+                if internal {
+                    "🔁 内部转账：接收方是您自己的账户"
+                } else {
+                    {format!("🕘 {}", SendHistory::hint(previous_sends))}
+                }
+            }
+            for warning in warnings {
+                {
+                    let code = warning.code();
+                    let checked = acknowledged.read().contains(code);
+                    rsx! {
+                        label {
+                            key: "{code}",
+                            class: "flex items-start gap-2 p-3 rounded-lg text-sm cursor-pointer",
+                            style: "background: rgba(239, 68, 68, 0.08); border: 1px solid rgba(239, 68, 68, 0.3); color: rgba(239, 68, 68, 1);",
+                            input {
+                                r#type: "checkbox",
+                                class: "mt-1",
+                                checked: checked,
+                                onchange: move |e: Event<FormData>| {
+                                    if e.checked() {
+                                        acknowledged.write().insert(code);
+                                    } else {
+                                        acknowledged.write().remove(code);
+                                    }
+                                },
+                            }
+                            div {
+                                div { {format!("⚠️ {}", warning.message())} }
+                                div {
+                                    class: "text-xs mt-1",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    "我已了解风险，仍要继续"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 疑似投毒地址与常用收款方的逐段对比（不同的字符高亮）
+#[component]
+fn PoisoningComparison(hit: PoisoningMatch, on_use_legitimate: EventHandler<String>) -> Element {
+    let rows = [
+        (
+            "您输入的地址（疑似投毒）",
+            address_poisoning::diff_segments(&hit.suspect, &hit.legitimate),
+            "rgba(239, 68, 68, 1)",
+        ),
+        (
+            "您常用的收款地址",
+            address_poisoning::diff_segments(&hit.legitimate, &hit.suspect),
+            "rgba(34, 197, 94, 1)",
+        ),
+    ];
+    let legitimate = hit.legitimate.clone();
+    let exact_case = hit.exact_case;
+
+    rsx! {
+        div {
+            class: "mt-3 p-3 rounded-lg space-y-3",
+            style: "background: rgba(239, 68, 68, 0.08); border: 1px solid rgba(239, 68, 68, 0.3);",
+            div {
+                class: "text-sm font-medium",
+                style: "color: rgba(239, 68, 68, 1);",
+                "⛔ 该地址疑似地址投毒，已阻止发送"
+            }
+            div {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "该地址曾向您转入 0 或极小金额，且首尾字符与您常用的收款地址相同。攻击者借此诱导您从交易历史中复制错误的地址。"
+            }
+            for (title, segments, highlight) in rows {
+                div {
+                    key: "{title}",
+                    div {
+                        class: "text-xs mb-1",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "{title}"
+                    }
+                    div {
+                        class: "font-mono text-xs break-all p-2 rounded",
+                        style: format!("background: {}; color: {};", Colors::BG_PRIMARY, Colors::TEXT_PRIMARY),
+                        for (i, (text, same)) in segments.into_iter().enumerate() {
+                            span {
+                                key: "{i}",
+                                style: if same {
+                                    String::new()
+                                } else {
+                                    format!("color: {}; font-weight: 700; text-decoration: underline;", highlight)
+                                },
+                                "{text}"
+                            }
+                        }
+                    }
+                }
+            }
+            if exact_case {
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    size: ButtonSize::Small,
+                    onclick: move |_| on_use_legitimate.call(legitimate.clone()),
+                    "改用常用收款地址"
+                }
+            }
+        }
+    }
+}
+
+/// 内部转账的接收方列表（按链分组）
+#[component]
+fn InternalTargetList(
+    groups: Vec<(ChainType, Vec<InternalTarget>)>,
+    selected: Option<String>,
+    on_select: EventHandler<InternalTarget>,
+) -> Element {
+    if groups.is_empty() {
+        return rsx! {
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                "没有可用于内部转账的其他账户"
+            }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "space-y-3",
+            for (chain, targets) in groups {
+                div {
+                    key: "{chain.as_str()}",
+                    p {
+                        class: "text-xs font-medium mb-1",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {chain.label()}
+                    }
+                    div {
+                        class: "space-y-1",
+                        for target in targets {
+                            {
+                                let key = target.key();
+                                let active = selected.as_deref() == Some(key.as_str());
+                                let short = target.account.short_address();
+                                let name = target.wallet_name.clone();
+                                rsx! {
+                                    button {
+                                        key: "{key}",
+                                        class: "w-full flex justify-between items-center px-3 py-2 rounded-lg text-sm",
+                                        style: format!(
+                                            "background: {}; border: 1px solid {}; color: {};",
+                                            Colors::BG_SECONDARY,
+                                            if active { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY },
+                                            Colors::TEXT_PRIMARY,
+                                        ),
+                                        onclick: move |_| on_select.call(target.clone()),
+                                        span { "{name}" }
+                                        span {
+                                            class: "font-mono text-xs",
+                                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                            "{short}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// 支付策略预览组件
 #[component]
 fn PaymentStrategyPreview(strategy: PaymentStrategy) -> Element {
@@ -124,7 +124,7 @@ pub fn Send() -> Element {
                     if addr.len() > 5 {
                         // 只有地址足够长时才显示错误
                         #[cfg(debug_assertions)]
-                        tracing::debug!("address_detect_error={}", e);
+                        log::debug!("address_detect_error={}", e);
 
                         error_message.set(Some("无法识别地址格式，请检查后重试".to_string()));
                     }
@@ -1,16 +1,1881 @@
 //! Settings Page - 设置页面（已废弃）
 //! 为了简化用户体验，设置页已从导航中移除。
-//! 保留一个空组件占位，避免旧链接导致编译错误。
+//! 保留一个空组件占位，避免旧链接导致编译错误；仅保留服务状态入口、修改密码、可信设备、代币授权、通知偏好、邀请好友、已保存支付方式、默认网络、Solana 优先费上限、Gas 上限余量、确认数要求、发送风险提示阈值、偏好同步、设置导出/导入、最近删除的钱包、新手引导重播、重置偏好、演示模式与交易模拟入口。
 
+use crate::blockchain::solana::{LAMPORTS_PER_SOL, MAX_PRIORITY_FEE_OPTIONS_LAMPORTS};
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::copy_button::CopyButton;
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::molecules::onboarding_tour::TourProgress;
+use crate::components::molecules::{
+    ChainSelector, ConfirmAction, DeviceStepUpModal, ErrorMessage, OnboardingManager,
+    PasswordStrengthMeter, RiskLevel, RoutePreferenceSelector, SavedCardsManager,
+};
+use crate::features::auth::hooks::use_auth;
+use crate::features::auth::password_strength::estimate;
+use crate::features::security::app_lock::AUTO_LOCK_MINUTE_OPTIONS;
+use crate::features::security::device_binding::{self, DeviceAuthError, HighValueOp};
+use crate::features::settings::export::{self, ImportMode, PreviewItem, SettingsExport};
+use crate::features::swap::approvals::{self, GrantedApproval};
+use crate::features::wallet::hooks::use_wallet;
+use crate::features::wallet::send_safeguards::SafeguardConfig;
+use crate::features::wallet::simulate;
+use crate::pages::status::StatusIndicator;
+use crate::router::Route;
+use crate::services::chain_config::{ChainConfigManager, ChainFeature, ALL_CHAINS};
+use crate::services::device::{DeviceRecord, DeviceService, DeviceStatus};
+use crate::services::gas_limit::GAS_LIMIT_BUFFER_OPTIONS;
+use crate::services::preferences::PreferencesService;
+use crate::services::referral::{
+    anonymize, referral_link, ReferralCode, ReferralService, ReferralStats,
+};
+use crate::services::user::{
+    DigestFrequency, NotificationCategory, NotificationChannel, NotificationPreferences,
+    UserService,
+};
+use crate::shared::demo;
+use crate::shared::design_tokens::Colors;
+use crate::shared::feature_flags::use_feature;
+use crate::shared::freshness::{self, STALE_AFTER_OPTIONS};
+use crate::shared::navigation_guard::{self, BusyReason};
+use crate::shared::state::AppState;
+use crate::shared::time::now_unix;
 use dioxus::prelude::*;
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// Deprecated Settings Page
 #[component]
 pub fn Settings() -> Element {
     rsx! {
         div {
-            class: "min-h-screen flex items-center justify-center",
+            class: "min-h-screen flex flex-col items-center justify-center gap-6 p-4",
             p { "此版本中设置页面已移除。" }
+            div {
+                class: "w-full max-w-md space-y-6",
+                StatusIndicator {}
+                ChangePasswordSection {}
+                TrustedDevicesSection {}
+                TokenApprovalsSection {}
+                NotificationSection {}
+                ReferralSection {}
+                SavedCardsManager {}
+                DefaultChainSection {}
+                AutoLockSection {}
+                StaleDataSection {}
+                SolanaPriorityFeeSection {}
+                GasLimitBufferSection {}
+                SwapRouteSection {}
+                ConfirmationSection {}
+                TxLabelSyncSection {}
+                PreferencesSyncSection {}
+                KeyboardShortcutsSection {}
+                SendSafeguardSection {}
+                SettingsTransferSection {}
+                RecentlyDeletedWalletsSection {}
+                TourReplaySection {}
+                DebugLogSection {}
+                ResetPreferencesSection {}
+                if use_feature(demo::FEATURE_FLAG) {
+                    DemoModeSection {}
+                }
+                if use_feature(simulate::FEATURE_FLAG) {
+                    TxSimulatorSection {}
+                }
+            }
+        }
+    }
+}
+
+/// 上传通知偏好（仅登录用户；失败时保留本地设置，下次打开设置页再同步）
+fn push_notification_preferences(app_state: AppState, preferences: NotificationPreferences) {
+    if !app_state.user.read().is_authenticated {
+        return;
+    }
+    spawn(async move {
+        let service = UserService::new(Arc::new(app_state.get_api_client()));
+        if let Err(e) = service.update_notification_preferences(&preferences).await {
+            log::warn!("上传通知偏好失败: {}", e);
+        }
+    });
+}
+
+/// 修改通知偏好：保存到本地并上传
+fn update_notifications(app_state: AppState, f: impl FnOnce(&mut NotificationPreferences)) {
+    let mut updated = app_state.preferences.peek().notifications.clone();
+    f(&mut updated);
+    updated.updated_at = now_unix();
+    let pushed = updated.clone();
+    PreferencesService::new(app_state).update_settings(|prefs| prefs.notifications = updated);
+    push_notification_preferences(app_state, pushed);
+}
+
+/// 通知偏好：按类别与渠道开关、邮件摘要频率（安全事件不可关闭）
+#[component]
+fn NotificationSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let prefs = app_state.preferences.read().notifications.clone();
+
+    // 打开时与后端合并：后端较新则应用到本地，本地较新则上传
+    use_effect(move || {
+        if !app_state.user.read().is_authenticated {
+            return;
+        }
+        spawn(async move {
+            let service = UserService::new(Arc::new(app_state.get_api_client()));
+            let local = app_state.preferences.peek().notifications.clone();
+            match service.get_notification_preferences().await {
+                Ok(Some(remote)) if remote.updated_at > local.updated_at => {
+                    PreferencesService::new(app_state)
+                        .update_settings(|prefs| prefs.notifications = remote);
+                }
+                Ok(Some(remote)) if remote.updated_at == local.updated_at => {}
+                Ok(_) => push_notification_preferences(app_state, local),
+                Err(e) => log::warn!("获取通知偏好失败: {}", e),
+            }
+        });
+    });
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "通知偏好"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "选择接收哪些通知以及接收方式；安全事件始终通知，无法关闭"
+            }
+            div {
+                class: "space-y-2",
+                for category in NotificationCategory::ALL {
+                    label {
+                        key: "{category.label()}",
+                        class: "flex items-start gap-2 text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        input {
+                            r#type: "checkbox",
+                            class: "mt-1",
+                            checked: prefs.category_enabled(category),
+                            disabled: category.is_mandatory(),
+                            onchange: move |e| {
+                                let on = e.checked();
+                                update_notifications(app_state, |n| n.set_category(category, on));
+                            },
+                        }
+                        div {
+                            div { {category.label()} }
+                            div {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                {category.description()}
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "text-sm font-medium",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "接收方式"
+            }
+            div {
+                class: "flex flex-wrap gap-4",
+                for channel in NotificationChannel::ALL {
+                    label {
+                        key: "{channel.label()}",
+                        class: "flex items-center gap-2 text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        input {
+                            r#type: "checkbox",
+                            checked: prefs.channel_enabled(channel),
+                            onchange: move |e| {
+                                let on = e.checked();
+                                update_notifications(app_state, |n| n.set_channel(channel, on));
+                            },
+                        }
+                        {channel.label()}
+                    }
+                }
+            }
+            div {
+                class: "text-sm font-medium",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "邮件摘要"
+            }
+            div {
+                class: "flex gap-2",
+                for frequency in DigestFrequency::ALL {
+                    button {
+                        key: "{frequency.label()}",
+                        class: "px-3 py-1 rounded text-xs",
+                        disabled: !prefs.email,
+                        style: if frequency == prefs.digest {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                        },
+                        onclick: move |_| update_notifications(app_state, |n| n.digest = frequency),
+                        {frequency.label()}
+                    }
+                }
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                if prefs.email {
+                    "摘要汇总所选类别的动态（如收款、兑换确认、资产变化），由服务端按频率发送；安全事件仍会即时发送"
+                } else {
+                    "已关闭邮件通知；安全事件仍会通过邮件即时发送"
+                }
+            }
+        }
+    }
+}
+
+/// 邀请好友：邀请码/链接、分享、邀请记录与奖励
+#[component]
+fn ReferralSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut code = use_signal(|| None::<ReferralCode>);
+    let mut stats = use_signal(ReferralStats::default);
+    let mut error_message = use_signal(|| None::<String>);
+
+    let authenticated = app_state.user.read().is_authenticated;
+    use_effect(move || {
+        if !app_state.user.read().is_authenticated {
+            return;
+        }
+        spawn(async move {
+            let service = ReferralService::new(app_state);
+            match service.get_my_code().await {
+                Ok(c) => code.set(Some(c)),
+                Err(e) => error_message.set(Some(e.to_string())),
+            }
+            match service.get_referral_stats().await {
+                Ok(s) => stats.set(s),
+                Err(e) => log::warn!("{}", e),
+            }
+        });
+    });
+
+    if !authenticated {
+        return rsx! {};
+    }
+
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    let link = code.read().as_ref().map(|c| referral_link(&origin, c));
+    let share_text = "我在用 IronForge 非托管钱包，通过我的邀请链接注册：";
+    let share_links = link
+        .as_ref()
+        .map(|link| {
+            let url = urlencoding::encode(link);
+            let text = urlencoding::encode(share_text);
+            vec![
+                (
+                    "Telegram",
+                    format!("https://t.me/share/url?url={}&text={}", url, text),
+                ),
+                (
+                    "X",
+                    format!("https://twitter.com/intent/tweet?url={}&text={}", url, text),
+                ),
+                (
+                    "WhatsApp",
+                    format!("https://wa.me/?text={}%20{}", text, url),
+                ),
+            ]
+        })
+        .unwrap_or_default();
+    let current = stats.read().clone();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "邀请好友"
+            }
+            ErrorMessage { message: error_message.read().clone() }
+            if let (Some(c), Some(link)) = (code.read().clone(), link.clone()) {
+                div {
+                    class: "flex items-center justify-between gap-2",
+                    span {
+                        class: "text-lg font-mono font-semibold",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {c.code.clone()}
+                    }
+                    CopyButton { value: c.code.clone(), label: "复制邀请码".to_string() }
+                }
+                div {
+                    class: "flex items-center justify-between gap-2",
+                    span {
+                        class: "text-xs font-mono truncate",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {link.clone()}
+                    }
+                    CopyButton { value: link.clone(), label: "复制链接".to_string() }
+                }
+                div {
+                    class: "flex flex-wrap gap-2",
+                    for (name, href) in share_links {
+                        a {
+                            key: "{name}",
+                            class: "text-xs px-3 py-1 rounded-full",
+                            style: format!("border: 1px solid {}; color: {};", Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                            href: "{href}",
+                            target: "_blank",
+                            rel: "noopener noreferrer",
+                            "分享到 {name}"
+                        }
+                    }
+                }
+            }
+            div {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {format!(
+                    "已邀请 {} 人 · 累计奖励 ≈ ${:.2}",
+                    current.referred_users.len(),
+                    current.total_rewards_usd
+                )}
+            }
+            if !current.referred_users.is_empty() {
+                div {
+                    class: "space-y-1",
+                    for (i, user) in current.referred_users.iter().enumerate() {
+                        div {
+                            key: "{i}",
+                            class: "flex items-center justify-between text-xs",
+                            span {
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {anonymize(&user.display_name)}
+                            }
+                            span {
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                if user.qualified {
+                                    "已完成首笔交易"
+                                } else {
+                                    "已注册"
+                                }
+                                " · {user.joined_at.get(..10).unwrap_or(&user.joined_at)}"
+                            }
+                        }
+                    }
+                }
+            }
+            if !current.rewards.is_empty() {
+                div {
+                    class: "pt-2 border-t space-y-1",
+                    style: format!("border-color: {};", Colors::BORDER_PRIMARY),
+                    for reward in current.rewards.iter() {
+                        div {
+                            key: "{reward.id}",
+                            class: "flex items-center justify-between text-xs",
+                            span {
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                if reward.description.is_empty() {
+                                    "邀请奖励"
+                                } else {
+                                    "{reward.description}"
+                                }
+                            }
+                            span {
+                                style: format!("color: {};", Colors::PAYMENT_SUCCESS),
+                                "+{reward.amount} {reward.asset}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 修改账户密码
+#[component]
+fn ChangePasswordSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let auth_controller = use_auth();
+    let mut current_password = use_signal(String::new);
+    let mut new_password = use_signal(String::new);
+    let mut confirm_password = use_signal(String::new);
+    let mut revoke_other_sessions = use_signal(|| true);
+    let mut rekey_wallets = use_signal(|| true);
+    let mut error_message = use_signal(|| None::<String>);
+    let mut saving = use_signal(|| false);
+
+    if !app_state.user.read().is_authenticated {
+        return rsx! {};
+    }
+
+    let strength = estimate(
+        &new_password.read(),
+        &[app_state.user.read().email.as_deref().unwrap_or_default()],
+    );
+
+    let submit = move |_| {
+        let current = current_password.peek().clone();
+        let new = new_password.peek().clone();
+        let confirm = confirm_password.peek().clone();
+        if current.is_empty() {
+            error_message.set(Some("请输入当前密码".to_string()));
+            return;
+        }
+        if new != confirm {
+            error_message.set(Some("两次输入的新密码不一致".to_string()));
+            return;
+        }
+        if new == current {
+            error_message.set(Some("新密码不能与当前密码相同".to_string()));
+            return;
+        }
+        let email = app_state.user.peek().email.clone().unwrap_or_default();
+        if !estimate(&new, &[email.as_str()]).is_acceptable() {
+            error_message.set(Some("新密码强度太弱，请参考提示修改".to_string()));
+            return;
+        }
+
+        error_message.set(None);
+        saving.set(true);
+        let revoke = revoke_other_sessions();
+        let rekey = rekey_wallets();
+        spawn(async move {
+            match auth_controller
+                .change_password(&current, &new, &confirm, revoke, rekey)
+                .await
+            {
+                Ok((rekeyed, skipped)) => {
+                    let mut message = "密码已修改".to_string();
+                    if rekeyed > 0 {
+                        message.push_str(&format!("，{} 个钱包已改用新密码解锁", rekeyed));
+                    }
+                    if skipped > 0 {
+                        message.push_str(&format!("，{} 个钱包使用独立密码，保持不变", skipped));
+                    }
+                    AppState::show_success(app_state.toasts, message);
+                    current_password.set(String::new());
+                    new_password.set(String::new());
+                    confirm_password.set(String::new());
+                }
+                Err(e) => error_message.set(Some(format!("修改密码失败: {}", e))),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "修改密码"
+            }
+            Input {
+                input_type: InputType::Password,
+                label: Some("当前密码".to_string()),
+                value: Some(current_password.read().clone()),
+                onchange: move |e: FormEvent| current_password.set(e.value()),
+            }
+            div {
+                Input {
+                    input_type: InputType::Password,
+                    label: Some("新密码".to_string()),
+                    value: Some(new_password.read().clone()),
+                    onchange: move |e: FormEvent| new_password.set(e.value()),
+                }
+                if !new_password.read().is_empty() {
+                    PasswordStrengthMeter { strength: strength }
+                }
+            }
+            Input {
+                input_type: InputType::Password,
+                label: Some("确认新密码".to_string()),
+                value: Some(confirm_password.read().clone()),
+                onchange: move |e: FormEvent| confirm_password.set(e.value()),
+            }
+            label {
+                class: "flex items-start gap-2 cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    class: "mt-0.5",
+                    checked: rekey_wallets(),
+                    onchange: move |_| rekey_wallets.set(!rekey_wallets()),
+                }
+                span {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "本设备上与当前密码相同的钱包密码一并更新为新密码（使用其他密码的钱包不受影响）"
+                }
+            }
+            label {
+                class: "flex items-start gap-2 cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    class: "mt-0.5",
+                    checked: revoke_other_sessions(),
+                    onchange: move |_| revoke_other_sessions.set(!revoke_other_sessions()),
+                }
+                span {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "退出其他设备上的登录"
+                }
+            }
+            ErrorMessage { message: error_message.read().clone() }
+            Button {
+                variant: ButtonVariant::Primary,
+                size: ButtonSize::Medium,
+                class: Some("w-full".to_string()),
+                disabled: saving(),
+                loading: saving(),
+                onclick: submit,
+                "修改密码"
+            }
+        }
+    }
+}
+
+/// 默认网络
+#[component]
+fn DefaultChainSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let default_chain = use_signal(|| {
+        app_state
+            .preferences
+            .peek()
+            .default_chain
+            .clone()
+            .unwrap_or_else(|| "ethereum".to_string())
+    });
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "默认网络"
+            }
+            ChainSelector {
+                selected_chain: default_chain,
+                feature: ChainFeature::Receive,
+                label: None,
+                hint: Some("收款页面默认显示该网络的地址".to_string()),
+                onselect: move |chain: crate::services::address_detector::ChainType| {
+                    let mut preferences = app_state.preferences;
+                    let mut prefs = preferences.write();
+                    prefs.default_chain = Some(chain.as_str().to_string());
+                    prefs.save();
+                },
+            }
+        }
+    }
+}
+
+/// 自动锁定时长
+#[component]
+fn AutoLockSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let current = app_state.preferences.read().auto_lock_minutes;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "自动锁定"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "无操作超过所选时长后锁定应用并清除内存中的密钥，登录状态保留"
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                for minutes in AUTO_LOCK_MINUTE_OPTIONS {
+                    button {
+                        key: "{minutes}",
+                        class: "px-3 py-1 rounded text-sm",
+                        style: if minutes == current {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                        },
+                        onclick: move |_| {
+                            let mut preferences = app_state.preferences;
+                            let mut prefs = preferences.write();
+                            prefs.auto_lock_minutes = minutes;
+                            prefs.save();
+                        },
+                        {format!("{} 分钟", minutes)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 价格与余额过期提示
+#[component]
+fn StaleDataSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let current = app_state.preferences.read().stale_after_secs;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "数据过期提示"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "价格、报价与余额超过所选时长未更新（或网络离线）时变暗显示，并提供刷新按钮"
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                for secs in STALE_AFTER_OPTIONS {
+                    button {
+                        key: "{secs}",
+                        class: "px-3 py-1 rounded text-sm",
+                        style: if secs == current {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                        },
+                        onclick: move |_| {
+                            let mut preferences = app_state.preferences;
+                            let mut prefs = preferences.write();
+                            prefs.stale_after_secs = secs;
+                            prefs.save();
+                        },
+                        {freshness::stale_after_label(secs)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// EVM 交易 Gas 上限安全余量
+#[component]
+fn GasLimitBufferSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let current = app_state.preferences.read().gas_limit_buffer_percent;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "Gas 上限余量"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "在估算的 Gas 上限基础上多预留一部分，减少交易因 Gas 不足失败；未用完的 Gas 不会扣费"
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                for percent in GAS_LIMIT_BUFFER_OPTIONS {
+                    button {
+                        key: "{percent}",
+                        class: "px-3 py-1 rounded text-sm",
+                        style: if percent == current {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                        },
+                        onclick: move |_| {
+                            let mut preferences = app_state.preferences;
+                            let mut prefs = preferences.write();
+                            prefs.gas_limit_buffer_percent = percent;
+                            prefs.save();
+                        },
+                        {format!("{:.1}×", 1.0 + percent as f64 / 100.0)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Solana 优先费上限
+#[component]
+fn SolanaPriorityFeeSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let current = app_state
+        .preferences
+        .read()
+        .solana_max_priority_fee_lamports;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "Solana 优先费上限"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "估算或手动设置的优先费超过上限时按上限支付"
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                for lamports in MAX_PRIORITY_FEE_OPTIONS_LAMPORTS {
+                    button {
+                        key: "{lamports}",
+                        class: "px-3 py-1 rounded text-sm",
+                        style: if lamports == current {
+                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                        } else {
+                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                        },
+                        onclick: move |_| {
+                            let mut preferences = app_state.preferences;
+                            let mut prefs = preferences.write();
+                            prefs.solana_max_priority_fee_lamports = lamports;
+                            prefs.save();
+                        },
+                        {format!("{} SOL", lamports as f64 / LAMPORTS_PER_SOL as f64)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 按链自定义确认数（高级设置）
+#[component]
+fn ConfirmationSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let overrides = app_state.preferences.read().confirmation_overrides.clone();
+    let manager = ChainConfigManager::new();
+
+    let set_override = move |key: &'static str, value: Option<u32>| {
+        let mut preferences = app_state.preferences;
+        let mut prefs = preferences.write();
+        match value {
+            Some(v) => prefs.confirmation_overrides.insert(key.to_string(), v),
+            None => prefs.confirmation_overrides.remove(key),
+        };
+        prefs.save();
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "确认数要求（高级）"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "交易达到所需确认数后才视为完成；大额交易会自动提高要求"
+            }
+            for chain in ALL_CHAINS {
+                {
+                    let policy = manager.confirmation_policy(chain);
+                    let key = chain.as_str();
+                    let custom = overrides.get(key).map(|v| policy.clamp(*v));
+                    let current = custom.unwrap_or(policy.default);
+                    let (min, max, default) = (policy.min, policy.max, policy.default);
+                    rsx! {
+                        div {
+                            key: "{key}",
+                            class: "flex items-center justify-between gap-2 text-sm",
+                            span {
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {chain.label()}
+                            }
+                            div {
+                                class: "flex items-center gap-2",
+                                button {
+                                    class: "w-7 h-7 rounded",
+                                    style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                    disabled: current <= min,
+                                    onclick: move |_| set_override(key, Some(current.saturating_sub(1).max(min))),
+                                    "−"
+                                }
+                                span {
+                                    class: "w-10 text-center font-mono",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "{current}"
+                                }
+                                button {
+                                    class: "w-7 h-7 rounded",
+                                    style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                    disabled: current >= max,
+                                    onclick: move |_| set_override(key, Some((current + 1).min(max))),
+                                    "+"
+                                }
+                                if custom.is_some() {
+                                    button {
+                                        class: "text-xs underline",
+                                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                                        onclick: move |_| set_override(key, None),
+                                        {format!("恢复默认 {}", default)}
+                                    }
+                                } else {
+                                    span {
+                                        class: "text-xs",
+                                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                        {format!("范围 {}-{}", min, max)}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 交易分类与备注同步
+#[component]
+fn TxLabelSyncSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let enabled = app_state.preferences.read().sync_tx_labels;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "交易分类与备注"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "默认仅加密保存在本设备；开启后同步到账户，可在其他设备查看"
+            }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                input {
+                    r#type: "checkbox",
+                    checked: enabled,
+                    onchange: move |e| {
+                        let mut preferences = app_state.preferences;
+                        let mut prefs = preferences.write();
+                        prefs.sync_tx_labels = e.checked();
+                        prefs.save();
+                    },
+                }
+                "同步到账户"
+            }
+        }
+    }
+}
+
+/// 键盘快捷键
+#[component]
+fn KeyboardShortcutsSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let enabled = app_state.preferences.read().keyboard_shortcuts;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "键盘快捷键"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "按 ? 查看全部快捷键；输入文字时不会触发"
+            }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                input {
+                    r#type: "checkbox",
+                    checked: enabled,
+                    onchange: move |e| {
+                        let mut preferences = app_state.preferences;
+                        let mut prefs = preferences.write();
+                        prefs.keyboard_shortcuts = e.checked();
+                        prefs.save();
+                    },
+                }
+                "启用键盘快捷键"
+            }
+        }
+    }
+}
+
+/// 高级：演示模式（客服演示与 QA 回归使用）
+#[component]
+fn DemoModeSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let enabled = app_state.preferences.read().demo_mode;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "高级 · 演示模式"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "开启后报价、余额、下单与订单状态均使用演示数据，交易不会广播到链上"
+            }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                input {
+                    r#type: "checkbox",
+                    checked: enabled,
+                    onchange: move |e| demo::set_enabled(app_state, e.checked()),
+                }
+                "开启演示模式"
+            }
+        }
+    }
+}
+
+/// 高级 · 交易模拟入口（功能开关开启时显示）
+#[component]
+fn TxSimulatorSection() -> Element {
+    let navigator = use_navigator();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "高级 · 交易模拟"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "输入任意合约调用数据，签名前查看解码结果、回滚原因与预估手续费"
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                onclick: move |_| { navigator.push(Route::SimulateTx {}); },
+                "打开交易模拟"
+            }
+        }
+    }
+}
+
+/// 发送风险提示阈值
+#[component]
+fn SendSafeguardSection() -> Element {
+    let mut config = use_signal(SafeguardConfig::load);
+    let current = *config.read();
+
+    // (说明, 当前值, 是否为代币余额阈值)
+    let fields = [
+        (
+            "金额占代币余额超过（%）时提示",
+            current.balance_warn_percent,
+            true,
+        ),
+        (
+            "金额占钱包总价值超过（%）时提示",
+            current.portfolio_warn_percent,
+            false,
+        ),
+    ];
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "发送风险提示"
+            }
+            for (label, value, is_balance) in fields {
+                div {
+                    key: "{label}",
+                    class: "flex items-center justify-between gap-2",
+                    span {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {label}
+                    }
+                    input {
+                        class: "w-20 p-1 rounded text-sm text-right",
+                        style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                        r#type: "number",
+                        min: "1",
+                        max: "100",
+                        value: "{value}",
+                        onchange: move |e| {
+                            if let Ok(v) = e.value().parse::<f64>() {
+                                let mut next = *config.peek();
+                                if is_balance {
+                                    next.balance_warn_percent = v;
+                                } else {
+                                    next.portfolio_warn_percent = v;
+                                }
+                                next.save();
+                                config.set(SafeguardConfig::load());
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 设置导出/导入：口令加密的设置文件，用于在另一台浏览器上恢复设置（不含任何密钥）
+#[component]
+fn SettingsTransferSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut export_passphrase = use_signal(String::new);
+    let mut import_passphrase = use_signal(String::new);
+    let mut file_bytes = use_signal(|| None::<Vec<u8>>);
+    let mut pending = use_signal(|| None::<(SettingsExport, Vec<PreviewItem>)>);
+    let mut busy = use_signal(|| false);
+    let mut error_message = use_signal(|| None::<String>);
+
+    let handle_export = move |_| {
+        let passphrase = export_passphrase.read().clone();
+        error_message.set(None);
+        busy.set(true);
+        spawn(async move {
+            let current = export::snapshot(app_state).await;
+            let result = export::encrypt_export(&current, &passphrase)
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| {
+                    let filename = format!(
+                        "ironforge-settings-{}.json",
+                        chrono::Utc::now().format("%Y%m%d")
+                    );
+                    crate::pages::receipt::download_bytes(&bytes, &filename)
+                });
+            match result {
+                Ok(()) => {
+                    export_passphrase.set(String::new());
+                    AppState::show_success(app_state.toasts, "设置已导出".to_string());
+                }
+                Err(e) => error_message.set(Some(e)),
+            }
+            busy.set(false);
+        });
+    };
+
+    let handle_preview = move |_| {
+        let Some(bytes) = file_bytes.read().clone() else {
+            error_message.set(Some("请先选择导出文件".to_string()));
+            return;
+        };
+        let passphrase = import_passphrase.read().clone();
+        error_message.set(None);
+        busy.set(true);
+        spawn(async move {
+            let _importing = navigation_guard::hold(BusyReason::FileImport);
+            match export::decrypt_export(&bytes, &passphrase).await {
+                Ok(incoming) => {
+                    let current = export::snapshot(app_state).await;
+                    pending.set(Some(export::plan_import(&current, &incoming)));
+                }
+                Err(e) => error_message.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    let handle_apply = move |_| {
+        let Some((merged, _)) = pending.read().clone() else {
+            return;
+        };
+        busy.set(true);
+        spawn(async move {
+            let _importing = navigation_guard::hold(BusyReason::FileImport);
+            match export::apply(app_state, merged).await {
+                Ok(()) => {
+                    pending.set(None);
+                    file_bytes.set(None);
+                    import_passphrase.set(String::new());
+                    AppState::show_success(app_state.toasts, "设置已导入".to_string());
+                }
+                Err(e) => error_message.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    let is_busy = *busy.read();
+    let preview = pending.read().as_ref().map(|(_, items)| items.clone());
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "导出/导入设置"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "导出主题、货币、网络偏好、代币收藏与关注列表等设置，用口令加密。文件不包含私钥、助记词或登录信息。"
+            }
+            Input {
+                input_type: InputType::Password,
+                label: Some(format!("导出口令（至少 {} 个字符）", export::MIN_PASSPHRASE_LEN)),
+                value: Some(export_passphrase.read().clone()),
+                onchange: move |e: FormEvent| export_passphrase.set(e.value()),
+            }
+            Button {
+                variant: ButtonVariant::Secondary,
+                size: ButtonSize::Small,
+                disabled: is_busy,
+                onclick: handle_export,
+                "导出设置"
+            }
+            div {
+                class: "pt-3 space-y-3",
+                style: format!("border-top: 1px solid {};", Colors::BORDER_PRIMARY),
+                input {
+                    r#type: "file",
+                    accept: ".json,application/json",
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    onchange: move |e: FormEvent| {
+                        pending.set(None);
+                        let Some(file) = e.files().into_iter().next() else {
+                            file_bytes.set(None);
+                            return;
+                        };
+                        spawn(async move {
+                            let _importing = navigation_guard::hold(BusyReason::FileImport);
+                            match file.read_bytes().await {
+                                Ok(bytes) => file_bytes.set(Some(bytes.to_vec())),
+                                Err(e) => error_message.set(Some(format!("读取文件失败: {}", e))),
+                            }
+                        });
+                    },
+                }
+                Input {
+                    input_type: InputType::Password,
+                    label: Some("导入口令".to_string()),
+                    value: Some(import_passphrase.read().clone()),
+                    onchange: move |e: FormEvent| import_passphrase.set(e.value()),
+                }
+                if let Some(items) = preview {
+                    div {
+                        class: "space-y-1",
+                        for item in items {
+                            div {
+                                key: "{item.label}",
+                                class: "flex items-center justify-between gap-2 text-sm",
+                                span {
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    {item.label}
+                                }
+                                span {
+                                    class: "text-xs",
+                                    style: format!(
+                                        "color: {};",
+                                        if item.mode == ImportMode::Overwrite { Colors::PAYMENT_WARNING } else { Colors::TEXT_SECONDARY }
+                                    ),
+                                    {format!("{}：{}", item.mode.label(), item.detail)}
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex gap-2",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Small,
+                            disabled: is_busy,
+                            onclick: handle_apply,
+                            "确认导入"
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            onclick: move |_| pending.set(None),
+                            "取消"
+                        }
+                    }
+                } else {
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        disabled: is_busy,
+                        onclick: handle_preview,
+                        "预览导入"
+                    }
+                }
+            }
+            ErrorMessage { message: error_message.read().clone() }
+        }
+    }
+}
+
+/// 最近删除的钱包（保留期内可恢复或立即永久删除）
+#[component]
+fn RecentlyDeletedWalletsSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let wallet_controller = use_wallet();
+    let mut pending_purge = use_signal(|| None::<(String, String)>);
+    let mut purging = use_signal(|| false);
+
+    let now = chrono::Utc::now();
+    let deleted = app_state.wallet.read().deleted_wallets.clone();
+    if deleted.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "最近删除的钱包"
+            }
+            for entry in deleted {
+                div {
+                    key: "{entry.wallet.id}",
+                    class: "flex items-center justify-between gap-2",
+                    div {
+                        span {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{entry.wallet.name}"
+                        }
+                        p {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {
+                                let days = entry
+                                    .purge_at()
+                                    .map(|t| (t - now).num_days().max(0))
+                                    .unwrap_or(0);
+                                format!("{} 天后永久删除", days)
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex gap-2",
+                        button {
+                            class: "text-xs px-3 py-1 rounded-full",
+                            style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                            onclick: {
+                                let wallet_id = entry.wallet.id.clone();
+                                let wallet_name = entry.wallet.name.clone();
+                                move |_| match wallet_controller.restore_wallet(&wallet_id) {
+                                    Ok(()) => AppState::show_success(
+                                        app_state.toasts,
+                                        format!("钱包 \"{}\" 已恢复", wallet_name),
+                                    ),
+                                    Err(e) => AppState::show_error(
+                                        app_state.toasts,
+                                        format!("恢复钱包失败: {}", e),
+                                    ),
+                                }
+                            },
+                            "恢复"
+                        }
+                        button {
+                            class: "text-xs px-3 py-1 rounded-full",
+                            style: format!("color: {}; border: 1px solid {};", Colors::PAYMENT_ERROR, Colors::PAYMENT_ERROR),
+                            onclick: {
+                                let wallet_id = entry.wallet.id.clone();
+                                let wallet_name = entry.wallet.name.clone();
+                                move |_| pending_purge.set(Some((wallet_id.clone(), wallet_name.clone())))
+                            },
+                            "永久删除"
+                        }
+                    }
+                }
+            }
+        }
+
+        ConfirmAction {
+            open: pending_purge.read().is_some(),
+            risk: RiskLevel::Destructive,
+            title: "永久删除钱包".to_string(),
+            message: format!(
+                "钱包 \"{}\" 将被立即永久删除，本设备上的加密密钥一并清除，此操作无法撤销。",
+                pending_purge.read().as_ref().map(|(_, name)| name.clone()).unwrap_or_default()
+            ),
+            confirm_text: "永久删除".to_string(),
+            busy: purging(),
+            on_cancel: move |_| pending_purge.set(None),
+            on_confirm: move |_| {
+                let Some((wallet_id, wallet_name)) = pending_purge.peek().clone() else {
+                    return;
+                };
+                purging.set(true);
+                spawn(async move {
+                    match wallet_controller.purge_wallet(&wallet_id).await {
+                        Ok(()) => AppState::show_success(
+                            app_state.toasts,
+                            format!("钱包 \"{}\" 已永久删除", wallet_name),
+                        ),
+                        Err(e) => AppState::show_error(
+                            app_state.toasts,
+                            format!("永久删除钱包失败: {}", e),
+                        ),
+                    }
+                    purging.set(false);
+                    pending_purge.set(None);
+                });
+            },
+        }
+    }
+}
+
+/// 可信设备：列出本账户登记的设备，可移除；本设备待验证时可发起追加验证
+#[component]
+fn TrustedDevicesSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut devices = use_signal(|| None::<Vec<DeviceRecord>>);
+    let mut error_message = use_signal(|| None::<String>);
+    let mut pending_revoke = use_signal(|| None::<DeviceRecord>);
+    let mut revoking = use_signal(|| false);
+    let mut step_up_device = use_signal(|| None::<String>);
+    let mut reload = use_signal(|| 0u32);
+
+    use_effect(move || {
+        let _ = reload();
+        if !app_state.user.read().is_authenticated {
+            return;
+        }
+        spawn(async move {
+            match DeviceService::new(app_state).list().await {
+                Ok(list) => {
+                    devices.set(Some(list));
+                    error_message.set(None);
+                }
+                Err(e) => error_message.set(Some(e.to_string())),
+            }
+        });
+    });
+
+    let user_id = app_state.user.read().user_id.clone();
+    let Some(user_id) = user_id else {
+        return rsx! {};
+    };
+    let current_id = device_binding::current_device_id(&user_id);
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "可信设备"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "大额提现等高风险操作需由可信设备自动签名确认。新设备首次操作时需通过邮箱或身份验证器验证。"
+            }
+            ErrorMessage { message: error_message() }
+            if let Some(list) = devices.read().clone() {
+                if list.is_empty() {
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "暂无登记的设备"
+                    }
+                }
+                for device in list {
+                    div {
+                        key: "{device.id}",
+                        class: "flex items-center justify-between gap-2",
+                        div {
+                            span {
+                                class: "text-sm",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "{device.name}"
+                                if current_id.as_deref() == Some(device.id.as_str()) {
+                                    span {
+                                        class: "ml-2 text-xs",
+                                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                                        "本设备"
+                                    }
+                                }
+                            }
+                            p {
+                                class: "text-xs",
+                                style: format!(
+                                    "color: {};",
+                                    if device.status == DeviceStatus::Trusted { Colors::TEXT_SECONDARY } else { Colors::PAYMENT_WARNING }
+                                ),
+                                {
+                                    let added = chrono::DateTime::from_timestamp(device.created_at as i64, 0)
+                                        .map(|t| t.format("%Y-%m-%d").to_string())
+                                        .unwrap_or_default();
+                                    format!("{} · 登记于 {}", device.status.label(), added)
+                                }
+                            }
+                        }
+                        div {
+                            class: "flex gap-2",
+                            if device.status == DeviceStatus::Pending
+                                && current_id.as_deref() == Some(device.id.as_str())
+                            {
+                                button {
+                                    class: "text-xs px-3 py-1 rounded-full",
+                                    style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                    onclick: {
+                                        let device_id = device.id.clone();
+                                        move |_| step_up_device.set(Some(device_id.clone()))
+                                    },
+                                    "验证此设备"
+                                }
+                            }
+                            button {
+                                class: "text-xs px-3 py-1 rounded-full",
+                                style: format!("color: {}; border: 1px solid {};", Colors::PAYMENT_ERROR, Colors::PAYMENT_ERROR),
+                                onclick: {
+                                    let device = device.clone();
+                                    move |_| pending_revoke.set(Some(device.clone()))
+                                },
+                                "移除"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(device_id) = step_up_device.read().clone() {
+            DeviceStepUpModal {
+                open: true,
+                device_id: device_id,
+                on_verified: move |_| {
+                    step_up_device.set(None);
+                    reload += 1;
+                },
+                on_cancel: move |_| step_up_device.set(None),
+            }
+        }
+
+        ConfirmAction {
+            open: pending_revoke.read().is_some(),
+            risk: RiskLevel::Destructive,
+            title: "移除设备".to_string(),
+            message: match pending_revoke.read().as_ref() {
+                Some(device) if current_id.as_deref() == Some(device.id.as_str()) => {
+                    "移除本设备后，下次登录将重新登记，并需要重新验证才能进行高风险操作。".to_string()
+                }
+                Some(device) => format!(
+                    "设备 \"{}\" 将无法再确认高风险操作，如需恢复须在该设备上重新验证。",
+                    device.name
+                ),
+                None => String::new(),
+            },
+            confirm_text: "移除".to_string(),
+            busy: revoking(),
+            on_cancel: move |_| pending_revoke.set(None),
+            on_confirm: {
+                let user_id = user_id.clone();
+                move |_| {
+                    let Some(device) = pending_revoke.peek().clone() else {
+                        return;
+                    };
+                    let user_id = user_id.clone();
+                    let is_current = device_binding::current_device_id(&user_id).as_deref()
+                        == Some(device.id.as_str());
+                    revoking.set(true);
+                    spawn(async move {
+                        // 移除其他设备需本设备签名；移除本设备不需要
+                        let proof = if is_current {
+                            None
+                        } else {
+                            match device_binding::authorize(app_state, HighValueOp::DeviceRevoke).await {
+                                Ok(proof) => Some(proof),
+                                Err(DeviceAuthError::StepUpRequired { device_id }) => {
+                                    revoking.set(false);
+                                    pending_revoke.set(None);
+                                    step_up_device.set(Some(device_id));
+                                    return;
+                                }
+                                Err(e) => {
+                                    AppState::show_error(app_state.toasts, e.to_string());
+                                    revoking.set(false);
+                                    pending_revoke.set(None);
+                                    return;
+                                }
+                            }
+                        };
+                        match DeviceService::new(app_state).revoke(&device.id, proof.as_ref()).await {
+                            Ok(()) => {
+                                if is_current {
+                                    device_binding::forget(&user_id).await;
+                                }
+                                AppState::show_success(
+                                    app_state.toasts,
+                                    format!("设备 \"{}\" 已移除", device.name),
+                                );
+                                reload += 1;
+                            }
+                            Err(e) => AppState::show_error(app_state.toasts, e.to_string()),
+                        }
+                        revoking.set(false);
+                        pending_revoke.set(None);
+                    });
+                }
+            },
+        }
+    }
+}
+
+/// 代币授权：列出在本应用中授予兑换路由合约的授权，可撤销（额度设为 0）
+#[component]
+fn TokenApprovalsSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut granted = use_signal(approvals::load);
+    let mut pending_revoke = use_signal(|| None::<GrantedApproval>);
+    let mut revoking = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "代币授权"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "授权允许合约在您发起兑换时转走对应代币。不再需要时可撤销，撤销是一笔链上交易。"
+            }
+            if granted.read().is_empty() {
+                p {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    "暂无授权记录"
+                }
+            }
+            for approval in granted.read().clone() {
+                div {
+                    key: "{approval.chain.as_str()}:{approval.owner}:{approval.token_address}",
+                    class: "flex items-center justify-between gap-2",
+                    div {
+                        span {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {format!("{} · {}", approval.amount_label(), approval.chain.label())}
+                        }
+                        p {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {
+                                let granted_at = chrono::DateTime::from_timestamp(approval.granted_at as i64, 0)
+                                    .map(|t| t.format("%Y-%m-%d").to_string())
+                                    .unwrap_or_default();
+                                format!("授权给 {} · {}", approval.spender_label, granted_at)
+                            }
+                        }
+                    }
+                    button {
+                        class: "text-xs px-3 py-1 rounded-full",
+                        style: format!("color: {}; border: 1px solid {};", Colors::PAYMENT_ERROR, Colors::PAYMENT_ERROR),
+                        onclick: {
+                            let approval = approval.clone();
+                            move |_| pending_revoke.set(Some(approval.clone()))
+                        },
+                        "撤销"
+                    }
+                }
+            }
+        }
+
+        ConfirmAction {
+            open: pending_revoke.read().is_some(),
+            risk: RiskLevel::Caution,
+            title: "撤销授权".to_string(),
+            message: pending_revoke
+                .read()
+                .as_ref()
+                .map(|a| format!(
+                    "将发起一笔链上交易，把 {} 对 {} 的授权额度设为 0，需要支付少量网络费。",
+                    a.token_symbol, a.spender_label
+                ))
+                .unwrap_or_default(),
+            confirm_text: "撤销".to_string(),
+            busy: revoking(),
+            on_cancel: move |_| pending_revoke.set(None),
+            on_confirm: move |_| {
+                let Some(approval) = pending_revoke.peek().clone() else {
+                    return;
+                };
+                revoking.set(true);
+                spawn(async move {
+                    match approvals::revoke(app_state, &approval).await {
+                        Ok(_) => {
+                            AppState::show_success(
+                                app_state.toasts,
+                                format!("已发起撤销 {} 授权的交易", approval.token_symbol),
+                            );
+                            granted.set(approvals::load());
+                        }
+                        Err(e) => AppState::show_error(app_state.toasts, e),
+                    }
+                    revoking.set(false);
+                    pending_revoke.set(None);
+                });
+            },
+        }
+    }
+}
+
+/// 重新播放新手引导
+#[component]
+fn TourReplaySection() -> Element {
+    let t = crate::i18n::use_translation();
+    let navigator = use_navigator();
+    let progress = TourProgress::load();
+    let tours = OnboardingManager::new().tours().to_vec();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                {t("tour.replay_title")}
+            }
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {t("tour.replay_hint")}
+            }
+            for tour in tours {
+                div {
+                    key: "{tour.id}",
+                    class: "flex items-center justify-between gap-2",
+                    span {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        {t(tour.name_key)}
+                        if progress.is_completed(tour.id) {
+                            span {
+                                class: "ml-2 text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                {format!("✓ {}", t("tour.completed"))}
+                            }
+                        }
+                    }
+                    button {
+                        class: "text-xs px-3 py-1 rounded-full",
+                        style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                        onclick: move |_| {
+                            OnboardingManager::reset_tour(tour.id);
+                            if let Ok(route) = Route::from_str(tour.route) {
+                                navigator.push(route);
+                            }
+                        },
+                        {t("tour.replay")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 偏好设置同步到账户
+#[component]
+fn PreferencesSyncSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let enabled = app_state.preferences.read().sync_preferences;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "偏好设置同步"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "开启后设置与界面语言同步到账户，登录其他设备时自动应用；隐私模式与新手引导记录仅保存在本设备"
+            }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                input {
+                    r#type: "checkbox",
+                    checked: enabled,
+                    onchange: move |e| {
+                        let enabled = e.checked();
+                        PreferencesService::new(app_state)
+                            .update_settings(|prefs| prefs.sync_preferences = enabled);
+                    },
+                }
+                "同步到账户"
+            }
+        }
+    }
+}
+
+/// 兑换默认路由偏好
+#[component]
+fn SwapRouteSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let selected = app_state.preferences.read().swap_route_preference;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "兑换路由偏好"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "兑换报价默认使用的路由方式；单次兑换可在兑换页的高级设置中临时更改"
+            }
+            RoutePreferenceSelector {
+                selected,
+                onselect: move |preference| {
+                    PreferencesService::new(app_state)
+                        .update_settings(|prefs| prefs.swap_route_preference = preference);
+                },
+            }
+        }
+    }
+}
+
+/// 下载调试日志（反馈问题时附上）
+#[component]
+fn DebugLogSection() -> Element {
+    let app_state = use_context::<AppState>();
+
+    let handle_download = move |_| {
+        let text = crate::shared::logging::export_text();
+        let filename = format!(
+            "ironforge-debug-{}.log",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        );
+        match crate::pages::receipt::download_bytes(text.as_bytes(), &filename) {
+            Ok(()) => AppState::show_success(app_state.toasts, "调试日志已下载".to_string()),
+            Err(e) => AppState::show_error(app_state.toasts, e),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "调试日志"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "下载本次会话最近的应用日志，反馈问题时附上可帮助我们排查；默认级别下地址、金额与交易哈希已脱敏，日志从不包含私钥或助记词"
+            }
+            button {
+                class: "text-xs px-3 py-1 rounded-full",
+                style: format!("color: {}; border: 1px solid {};", Colors::TECH_PRIMARY, Colors::TECH_PRIMARY),
+                onclick: handle_download,
+                "下载调试日志"
+            }
+        }
+    }
+}
+
+/// 恢复默认偏好设置
+#[component]
+fn ResetPreferencesSection() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut confirming = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            h3 {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "重置偏好设置"
+            }
+            p {
+                class: "text-xs",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                "将本页所有偏好、界面语言与隐私模式恢复为默认值；钱包、交易记录与新手引导完成记录不受影响"
+            }
+            button {
+                class: "text-xs px-3 py-1 rounded-full",
+                style: format!("color: {}; border: 1px solid {};", Colors::PAYMENT_ERROR, Colors::PAYMENT_ERROR),
+                onclick: move |_| confirming.set(true),
+                "重置为默认"
+            }
+        }
+
+        ConfirmAction {
+            open: confirming(),
+            risk: RiskLevel::Caution,
+            title: "重置偏好设置".to_string(),
+            message: "所有偏好设置将恢复为默认值，确定继续吗？".to_string(),
+            confirm_text: "重置".to_string(),
+            on_cancel: move |_| confirming.set(false),
+            on_confirm: move |_| {
+                let service = PreferencesService::new(app_state);
+                service.reset();
+                confirming.set(false);
+                AppState::show_success(app_state.toasts, "偏好设置已恢复默认".to_string());
+            },
         }
     }
 }
@@ -0,0 +1,432 @@
+//! Simulate Tx Page - 高级 · 交易模拟
+//! 输入任意 to/value/data，查看解码后的函数、模拟结果或回滚原因与预估手续费；
+//! 模拟通过后可将同一份交易确认、签名并广播。需开启功能开关并确认了解风险。
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::{Card, CardVariant};
+use crate::components::atoms::input::{Input, InputType};
+use crate::components::molecules::toast::ToastType;
+use crate::components::molecules::{ChainSelector, ConfirmAction, ErrorMessage, RiskLevel};
+use crate::features::wallet::chain_accounts::account_for_chain;
+use crate::features::wallet::simulate::{
+    self, DecodedCall, SimOutcome, SimulationReport, TxPayload, FEATURE_FLAG, SIMULATABLE_CHAINS,
+};
+use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainConfigManager;
+use crate::shared::design_tokens::Colors;
+use crate::shared::feature_flags::use_feature;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// Simulate Tx Page - 交易模拟页面
+#[component]
+pub fn SimulateTx() -> Element {
+    let enabled = use_feature(FEATURE_FLAG);
+    let mut acknowledged = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "min-h-screen pt-20 pb-8 px-4",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+            div {
+                class: "container mx-auto max-w-2xl px-4 sm:px-6 space-y-6",
+                div {
+                    h1 {
+                        class: "text-2xl font-bold mb-2 flex items-center gap-2",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        span { "🧪" }
+                        span { "交易模拟" }
+                    }
+                    p {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "在签名前模拟任意合约调用：查看解码结果、是否会回滚以及预估手续费"
+                    }
+                }
+                if !enabled {
+                    Card {
+                        variant: CardVariant::Base,
+                        padding: Some("24px".to_string()),
+                        children: rsx! {
+                            p {
+                                class: "text-sm",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "交易模拟为高级功能，当前未开启"
+                            }
+                        }
+                    }
+                } else if !acknowledged() {
+                    AcknowledgeCard { on_accept: move |_| acknowledged.set(true) }
+                } else {
+                    SimulatorForm {}
+                }
+            }
+        }
+    }
+}
+
+/// 风险确认：直接签名任意 calldata 可能导致资产被转走
+#[component]
+fn AcknowledgeCard(on_accept: EventHandler<()>) -> Element {
+    let mut checked = use_signal(|| false);
+
+    rsx! {
+        Card {
+            variant: CardVariant::Base,
+            padding: Some("24px".to_string()),
+            children: rsx! {
+                div {
+                    class: "space-y-4",
+                    h2 {
+                        class: "text-lg font-semibold",
+                        style: format!("color: {};", Colors::PAYMENT_WARNING),
+                        "⚠️ 仅供熟悉合约交互的用户使用"
+                    }
+                    ul {
+                        class: "text-sm space-y-1 list-disc pl-5",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        li { "这里签名的交易不经过兑换、发送等页面的安全检查" }
+                        li { "未知的 calldata 可能授权或转走你的全部资产" }
+                        li { "模拟结果基于当前链上状态，实际执行时可能不同" }
+                    }
+                    label {
+                        class: "flex items-center gap-2 text-sm",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        input {
+                            r#type: "checkbox",
+                            checked: checked(),
+                            onchange: move |e| checked.set(e.checked()),
+                        }
+                        "我了解自己在做什么"
+                    }
+                    Button {
+                        variant: ButtonVariant::Warning,
+                        size: ButtonSize::Medium,
+                        disabled: !checked(),
+                        onclick: move |_| on_accept.call(()),
+                        "继续"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SimulatorForm() -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let selected_chain = use_signal(|| ChainType::Ethereum.as_str().to_string());
+    let mut to = use_signal(String::new);
+    let mut value = use_signal(String::new);
+    let mut data = use_signal(String::new);
+    let mut report = use_signal(|| Option::<SimulationReport>::None);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut simulating = use_signal(|| false);
+    let mut confirming = use_signal(|| false);
+    let mut signing = use_signal(|| false);
+
+    let chain = ChainType::from_str(&selected_chain.read()).unwrap_or(ChainType::Ethereum);
+    let from = app_state
+        .wallet
+        .read()
+        .get_selected_wallet()
+        .and_then(|w| account_for_chain(w, chain))
+        .map(|a| a.address.clone());
+
+    let native_symbol = ChainConfigManager::new()
+        .get_native_token(chain)
+        .unwrap_or_else(|_| "ETH".to_string());
+    let from_label = match &from {
+        Some(address) => format!("发起地址：{}", address),
+        None => "当前钱包没有该链的账户".to_string(),
+    };
+    let can_simulate = from.is_some();
+
+    // 输入变化后旧的模拟结果不再有效
+    let mut invalidate = move || {
+        report.set(None);
+        error.set(None);
+    };
+
+    let run_simulation = move |_| {
+        let Some(from) = from.clone() else {
+            return;
+        };
+        let payload =
+            match TxPayload::from_input(chain, &from, &to.read(), &value.read(), &data.read()) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    error.set(Some(e));
+                    return;
+                }
+            };
+        invalidate();
+        simulating.set(true);
+        spawn(async move {
+            match simulate::simulate(app_state, payload).await {
+                Ok(result) => report.set(Some(result)),
+                Err(e) => error.set(Some(e)),
+            }
+            simulating.set(false);
+        });
+    };
+
+    let sign = move |_| {
+        let Some(current) = report.read().clone() else {
+            return;
+        };
+        signing.set(true);
+        spawn(async move {
+            let result = simulate::sign_and_broadcast(app_state, &current).await;
+            signing.set(false);
+            confirming.set(false);
+            match result {
+                Ok(receipt) => {
+                    AppState::show_toast(
+                        app_state.toasts,
+                        format!("交易已广播：{}", receipt.pending.tx_hash),
+                        ToastType::Success,
+                        Some(4000),
+                    );
+                    navigator.push(Route::TxReceipt {
+                        chain: receipt.pending.chain.clone(),
+                        hash: receipt.pending.tx_hash.clone(),
+                    });
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    };
+
+    let current = report.read().clone();
+    let confirm_details = current
+        .as_ref()
+        .and_then(|r| {
+            r.fee.as_ref().map(|fee| {
+                vec![
+                    format!("链：{}", r.payload.chain.label()),
+                    format!(
+                        "调用：{}",
+                        r.decoded.as_ref().map(|d| d.title()).unwrap_or_default()
+                    ),
+                    format!("目标：{}", r.payload.to),
+                    format!("预估手续费：{:.6} {}", fee.native, fee.native_symbol),
+                ]
+            })
+        })
+        .unwrap_or_default();
+
+    rsx! {
+        Card {
+            variant: CardVariant::Base,
+            padding: Some("24px".to_string()),
+            children: rsx! {
+                div {
+                    class: "space-y-4",
+                    ChainSelector {
+                        selected_chain,
+                        allowed_chains: Some(SIMULATABLE_CHAINS.to_vec()),
+                        onselect: move |_| invalidate(),
+                    }
+                    div {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        "{from_label}"
+                    }
+                    Input {
+                        input_type: InputType::Text,
+                        label: Some("目标地址（to）".to_string()),
+                        placeholder: Some("0x...".to_string()),
+                        value: Some(to.read().clone()),
+                        onchange: move |e: FormEvent| {
+                            to.set(e.value());
+                            invalidate();
+                        },
+                    }
+                    Input {
+                        input_type: InputType::Text,
+                        label: Some(format!("金额（{}，可为 0）", native_symbol)),
+                        placeholder: Some("0".to_string()),
+                        value: Some(value.read().clone()),
+                        onchange: move |e: FormEvent| {
+                            value.set(e.value());
+                            invalidate();
+                        },
+                    }
+                    div {
+                        class: "space-y-1",
+                        label {
+                            class: "text-sm font-medium",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "调用数据（data）"
+                        }
+                        textarea {
+                            class: "w-full p-3 rounded-lg text-xs font-mono",
+                            style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
+                            rows: 4,
+                            placeholder: "0x（留空表示原生币转账）",
+                            value: "{data}",
+                            oninput: move |e| {
+                                data.set(e.value());
+                                invalidate();
+                            },
+                        }
+                    }
+                    ErrorMessage { message: error() }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        size: ButtonSize::Large,
+                        class: Some("w-full".to_string()),
+                        disabled: !can_simulate || simulating(),
+                        loading: simulating(),
+                        onclick: run_simulation,
+                        "模拟执行"
+                    }
+                }
+            }
+        }
+
+        if let Some(current) = current {
+            SimulationResultCard {
+                report: current.clone(),
+                on_proceed: move |_| confirming.set(true),
+            }
+        }
+
+        ConfirmAction {
+            open: confirming(),
+            risk: RiskLevel::Caution,
+            title: "签名并广播".to_string(),
+            message: "将按模拟时的参数签名这笔交易并广播到链上".to_string(),
+            details: confirm_details,
+            confirm_text: "签名并广播".to_string(),
+            busy: signing(),
+            on_confirm: sign,
+            on_cancel: move |_| confirming.set(false),
+        }
+    }
+}
+
+#[component]
+fn SimulationResultCard(report: SimulationReport, on_proceed: EventHandler<()>) -> Element {
+    let row = |label: String, value: String| {
+        rsx! {
+            div {
+                class: "flex justify-between gap-4 text-sm",
+                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{label}" }
+                span { class: "text-right break-all font-mono", style: format!("color: {};", Colors::TEXT_PRIMARY), "{value}" }
+            }
+        }
+    };
+
+    rsx! {
+        Card {
+            variant: CardVariant::Base,
+            padding: Some("24px".to_string()),
+            children: rsx! {
+                div {
+                    class: "space-y-5",
+                    // 解码结果
+                    div {
+                        class: "space-y-2",
+                        h3 {
+                            class: "text-sm font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "解码"
+                        }
+                        match &report.decoded {
+                            Ok(decoded) => rsx! {
+                                {row("函数".to_string(), decoded.title())}
+                                if let DecodedCall::Known { params, warnings, .. } = decoded {
+                                    for param in params.iter() {
+                                        {row(param.name.to_string(), param.value.clone())}
+                                    }
+                                    for warning in warnings.iter() {
+                                        p {
+                                            class: "text-xs",
+                                            style: format!("color: {};", Colors::PAYMENT_ERROR),
+                                            "⚠️ {warning}"
+                                        }
+                                    }
+                                }
+                            },
+                            Err(e) => rsx! {
+                                p {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::PAYMENT_WARNING),
+                                    "无法解码：{e}"
+                                }
+                            },
+                        }
+                    }
+
+                    // 模拟结果
+                    div {
+                        class: "space-y-2",
+                        h3 {
+                            class: "text-sm font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "模拟结果"
+                        }
+                        match &report.outcome {
+                            SimOutcome::Success { return_data } => rsx! {
+                                p {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::PAYMENT_SUCCESS),
+                                    "✅ 执行成功"
+                                }
+                                if let Some(data) = return_data {
+                                    {row("返回数据".to_string(), data.clone())}
+                                }
+                            },
+                            SimOutcome::Reverted { reason } => rsx! {
+                                p {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::PAYMENT_ERROR),
+                                    "❌ 交易会回滚：{reason}"
+                                }
+                            },
+                        }
+                    }
+
+                    // 手续费
+                    if let Some(fee) = &report.fee {
+                        div {
+                            class: "space-y-2",
+                            h3 {
+                                class: "text-sm font-semibold",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                "预估手续费"
+                            }
+                            {row("Gas 上限".to_string(), fee.gas_limit.to_string())}
+                            {row("Gas 价格".to_string(), format!("{:.2} gwei", fee.max_fee_per_gas_gwei))}
+                            {row("手续费".to_string(), format!("{:.6} {}", fee.native, fee.native_symbol))}
+                            {row(
+                                "约合".to_string(),
+                                fee.usd.map(|usd| format!("${:.2}", usd)).unwrap_or_else(|| "价格暂不可用".to_string()),
+                            )}
+                        }
+                    } else if matches!(report.outcome, SimOutcome::Success { .. }) {
+                        p {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::PAYMENT_WARNING),
+                            "暂时无法估算手续费，请稍后重新模拟"
+                        }
+                    }
+
+                    if report.can_proceed() {
+                        Button {
+                            variant: ButtonVariant::Warning,
+                            size: ButtonSize::Large,
+                            class: Some("w-full".to_string()),
+                            onclick: move |_| on_proceed.call(()),
+                            "继续签名"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
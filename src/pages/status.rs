@@ -0,0 +1,405 @@
+//! Status Page - 服务状态页面
+//! 汇总后端 API、各链 RPC 节点池、WebSocket 连接与法币服务商状态及故障公告；无需登录即可访问
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::Card;
+use crate::components::molecules::ProviderStatus;
+use crate::services::status::{ServiceHealth, StatusReport, StatusService};
+use crate::shared::design_tokens::Colors;
+use crate::shared::scheduler::{self, TickPolicy};
+use crate::shared::state::AppState;
+use crate::shared::time;
+use crate::shared::websocket::{self, ConnectionState};
+use dioxus::prelude::*;
+
+/// 自动刷新间隔（毫秒）
+const REFRESH_INTERVAL_MS: u32 = 60_000;
+
+/// 复用服务商状态徽章的颜色与图标
+fn badge(health: ServiceHealth) -> ProviderStatus {
+    match health {
+        ServiceHealth::Operational => ProviderStatus::Healthy,
+        ServiceHealth::Degraded => ProviderStatus::Degraded,
+        ServiceHealth::Outage => ProviderStatus::Down,
+        ServiceHealth::Unknown => ProviderStatus::Unknown,
+    }
+}
+
+fn websocket_health(state: ConnectionState) -> ServiceHealth {
+    match state {
+        ConnectionState::Connected => ServiceHealth::Operational,
+        ConnectionState::Connecting | ConnectionState::Reconnecting => ServiceHealth::Degraded,
+        ConnectionState::Disconnected | ConnectionState::Failed => ServiceHealth::Outage,
+    }
+}
+
+/// 检查时间（如"2分钟前检查"）
+fn checked_ago(timestamp: u64, now: u64) -> String {
+    let diff = now.saturating_sub(timestamp);
+    if diff < 60 {
+        "刚刚检查".to_string()
+    } else if diff < 3600 {
+        format!("{}分钟前检查", diff / 60)
+    } else {
+        format!("{}小时前检查", diff / 3600)
+    }
+}
+
+/// 执行一次状态检查（页面与设置页指示器共用）
+pub fn use_status_report() -> (Signal<Option<StatusReport>>, Signal<u32>) {
+    let app_state = use_context::<AppState>();
+    let mut report = use_signal(|| None::<StatusReport>);
+    let reload = use_signal(|| 0u32);
+
+    use_effect(move || {
+        let _ = reload();
+        spawn(async move {
+            report.set(Some(StatusService::new(app_state).check().await));
+        });
+    });
+
+    (report, reload)
+}
+
+/// Status Page - 服务状态页面
+#[component]
+pub fn Status() -> Element {
+    let (report, mut reload) = use_status_report();
+
+    use_future(move || async move {
+        loop {
+            scheduler::next_tick(TickPolicy::PauseWhenHidden, REFRESH_INTERVAL_MS).await;
+            reload += 1;
+        }
+    });
+
+    let now = time::now_unix();
+    let current = report.read().clone();
+
+    rsx! {
+        div {
+            class: "min-h-screen pt-20 pb-8 px-4",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+
+            div {
+                class: "container mx-auto max-w-4xl px-4 sm:px-6 space-y-6",
+
+                div {
+                    class: "flex items-start justify-between gap-4",
+                    div {
+                        h1 {
+                            class: "text-2xl font-bold mb-2 flex items-center gap-2",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            span { "📡" }
+                            span { "服务状态" }
+                        }
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            "后端服务、区块链节点与支付服务商的实时可用性"
+                        }
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        onclick: move |_| reload += 1,
+                        "🔄 刷新"
+                    }
+                }
+
+                match current {
+                    None => rsx! {
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "正在检查..."
+                        }
+                    },
+                    Some(report) => rsx! {
+                        StatusOverview { report: report.clone() }
+                        StatusReportView { report, now }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// 总体状态横幅
+#[component]
+fn StatusOverview(report: StatusReport) -> Element {
+    let overall = report.overall();
+    let status = badge(overall);
+    let headline = match overall {
+        ServiceHealth::Operational => "所有服务运行正常",
+        ServiceHealth::Degraded => "部分服务性能下降",
+        ServiceHealth::Outage => "部分服务不可用",
+        ServiceHealth::Unknown => "部分服务状态未知",
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg flex items-center gap-3",
+            style: format!("background: {}; border: 1px solid {};", status.bg_color(), status.color()),
+            span { class: "text-2xl", {status.icon()} }
+            span {
+                class: "text-base font-semibold",
+                style: format!("color: {};", status.color()),
+                {headline}
+            }
+        }
+    }
+}
+
+#[component]
+fn StatusReportView(report: StatusReport, now: u64) -> Element {
+    let backend = report.backend.clone();
+    let backend_detail = match (backend.latency_ms, &backend.error) {
+        (Some(ms), _) => format!("延迟 {:.0} ms", ms),
+        (None, Some(_)) => "无法连接".to_string(),
+        (None, None) => String::new(),
+    };
+    let ws = websocket::last_known_state();
+    let summary = report.summary.clone().unwrap_or_default();
+    let mut incidents = summary.incidents.clone();
+    incidents.sort_by_key(|i| (i.resolved_at.is_some(), std::cmp::Reverse(i.started_at)));
+
+    rsx! {
+        Card {
+            variant: crate::components::atoms::card::CardVariant::Base,
+            padding: Some("24px".to_string()),
+            children: rsx! {
+                h2 {
+                    class: "text-lg font-semibold mb-4",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "核心服务"
+                }
+                div {
+                    class: "space-y-3",
+                    StatusRow {
+                        name: "后端 API".to_string(),
+                        health: backend.status,
+                        detail: backend_detail,
+                        checked: checked_ago(backend.checked_at, now),
+                        note: None,
+                    }
+                    match ws {
+                        Some((state, at)) => rsx! {
+                            StatusRow {
+                                name: "实时推送（WebSocket）".to_string(),
+                                health: websocket_health(state),
+                                detail: state.label().to_string(),
+                                checked: checked_ago(at, now),
+                                note: None,
+                            }
+                        },
+                        None => rsx! {
+                            StatusRow {
+                                name: "实时推送（WebSocket）".to_string(),
+                                health: ServiceHealth::Unknown,
+                                detail: "本次会话未建立连接".to_string(),
+                                checked: String::new(),
+                                note: None,
+                            }
+                        },
+                    }
+                }
+            }
+        }
+
+        if report.summary.is_some() {
+            Card {
+                variant: crate::components::atoms::card::CardVariant::Base,
+                padding: Some("24px".to_string()),
+                children: rsx! {
+                    h2 {
+                        class: "text-lg font-semibold mb-4",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "区块链节点"
+                    }
+                    if summary.rpc.is_empty() {
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "暂无节点数据"
+                        }
+                    }
+                    div {
+                        class: "space-y-3",
+                        for pool in summary.rpc.iter().cloned() {
+                            StatusRow {
+                                key: "{pool.chain}",
+                                name: pool.chain.clone(),
+                                health: pool.status,
+                                detail: match (pool.total_endpoints, pool.latency_ms) {
+                                    (0, Some(ms)) => format!("延迟 {} ms", ms),
+                                    (0, None) => String::new(),
+                                    (total, Some(ms)) => format!("{}/{} 节点可用 · 延迟 {} ms", pool.healthy_endpoints, total, ms),
+                                    (total, None) => format!("{}/{} 节点可用", pool.healthy_endpoints, total),
+                                },
+                                checked: checked_ago(pool.last_checked, now),
+                                note: pool.note.clone(),
+                            }
+                        }
+                    }
+                }
+            }
+
+            Card {
+                variant: crate::components::atoms::card::CardVariant::Base,
+                padding: Some("24px".to_string()),
+                children: rsx! {
+                    h2 {
+                        class: "text-lg font-semibold mb-4",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "支付服务商"
+                    }
+                    if summary.providers.is_empty() {
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "暂无服务商数据"
+                        }
+                    }
+                    div {
+                        class: "space-y-3",
+                        for provider in summary.providers.iter().cloned() {
+                            StatusRow {
+                                key: "{provider.provider}",
+                                name: provider.provider.clone(),
+                                health: provider.status,
+                                detail: provider.latency_ms.map(|ms| format!("延迟 {} ms", ms)).unwrap_or_default(),
+                                checked: checked_ago(provider.last_checked, now),
+                                note: provider.note.clone(),
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !incidents.is_empty() {
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    children: rsx! {
+                        h2 {
+                            class: "text-lg font-semibold mb-4",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "故障公告"
+                        }
+                        div {
+                            class: "space-y-3",
+                            for incident in incidents {
+                                div {
+                                    key: "{incident.id}",
+                                    class: "p-3 rounded-lg",
+                                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                    div {
+                                        class: "flex items-center justify-between gap-2",
+                                        span {
+                                            class: "text-sm font-medium",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {incident.title.clone()}
+                                        }
+                                        span {
+                                            class: "text-xs",
+                                            style: format!(
+                                                "color: {};",
+                                                if incident.resolved_at.is_some() { Colors::TEXT_TERTIARY } else { badge(incident.status).color() }
+                                            ),
+                                            if incident.resolved_at.is_some() { "已解决" } else { {incident.status.label()} }
+                                        }
+                                    }
+                                    if !incident.message.is_empty() {
+                                        p {
+                                            class: "text-xs mt-1 whitespace-pre-line",
+                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                            {incident.message.clone()}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 单个组件的状态行
+#[component]
+fn StatusRow(
+    name: String,
+    health: ServiceHealth,
+    detail: String,
+    checked: String,
+    note: Option<String>,
+) -> Element {
+    let status = badge(health);
+
+    rsx! {
+        div {
+            class: "flex items-start justify-between gap-3",
+            div {
+                class: "min-w-0",
+                div {
+                    class: "text-sm font-medium",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {name}
+                }
+                if !detail.is_empty() || !checked.is_empty() {
+                    div {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        {[detail, checked].into_iter().filter(|s| !s.is_empty()).collect::<Vec<_>>().join(" · ")}
+                    }
+                }
+                if let Some(note) = note {
+                    div {
+                        class: "text-xs mt-1",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {note}
+                    }
+                }
+            }
+            span {
+                class: "shrink-0 inline-flex items-center gap-1 px-2 py-0.5 rounded-full text-xs",
+                style: format!("background: {}; color: {};", status.bg_color(), status.color()),
+                {status.icon()}
+                {health.label()}
+            }
+        }
+    }
+}
+
+/// 设置页中的服务状态指示器
+#[component]
+pub fn StatusIndicator() -> Element {
+    let (report, _) = use_status_report();
+    let overall = report.read().as_ref().map(StatusReport::overall);
+    let (icon, label) = match overall {
+        Some(health) => (badge(health).icon(), health.label()),
+        None => ("⏳", "检查中"),
+    };
+
+    rsx! {
+        Link {
+            to: crate::router::Route::Status {},
+            class: "flex items-center justify-between p-4 rounded-lg",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            span {
+                class: "text-base font-semibold",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "服务状态"
+            }
+            span {
+                class: "text-sm flex items-center gap-1",
+                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                {icon}
+                {label}
+                " ›"
+            }
+        }
+    }
+}
@@ -8,51 +8,103 @@
 )]
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
-use crate::components::molecules::onboarding_tour::OnboardingTour;
+use crate::components::atoms::copy_button::CopyButton;
+use crate::components::atoms::input::FieldError;
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::modal::Modal;
+use crate::components::atoms::skeleton::{SkeletonCard, SkeletonTableRow};
 use crate::components::molecules::user_feedback::{FeedbackType, UserFeedback};
 use crate::components::molecules::{
     kyc_verification::{KycVerificationInfo, KycVerificationStatus},
     order_tracking::{OrderStatus, OrderTracking, OrderTrackingInfo},
-    ChainSelector, ErrorMessage, ExchangeRateLockCountdown, LimitDisplay, LimitInfo,
-    LimitOrderForm, LimitOrderType, LoadingState, NotificationType, OnboardingManager, OrderList,
-    OrderListItem, OrderType, PriceChangeDirection, PriceChangeIndicator, PriceChangeInfo,
-    PriceChart, PriceDataPoint, ProcessSteps, ProviderStatusInfo, ProviderStatusList,
-    StablecoinBalanceCard, SwapConfirmDialog, SwapConfirmInfo, TokenSelector,
-    TransactionNotification, TransactionNotificationContainer,
+    saved_cards::use_saved_cards,
+    toast::{ToastAction, ToastType},
+    use_step_wizard, AmountInput, BackupGate, BankDetailsForm, CardForm, ChainAccountPrompt,
+    ChainSelector, ConfirmAction, DustSweepPanel, EmailVerificationRequired, EmptyState,
+    ErrorMessage, ExchangeRateLockCountdown, FiatRegionBlockedNotice, FilteredEmptyState,
+    GasFeeCard, GasReadinessBanner, LimitDisplay, LimitInfo, LimitOrderForm, LimitOrderType,
+    LoadFailedState, NotificationType, OnboardingManager, OnboardingTour, OrderList, OrderListItem,
+    OrderType, PaginationControls, PaymentRegionBanner, PendingSwapBanner, PriceChangeDirection,
+    PriceChangeIndicator, PriceChangeInfo, PriceChart, PriceDataPoint, ProcessSteps,
+    ProviderStatusInfo, ProviderStatusList, RiskLevel, RoutePreferenceSelector, SavedCardList,
+    SavedPayoutMethods, SlowRequestHint, SortControls, StablecoinBalanceCard, StaleData,
+    StatusFilterChips, StepWizard, SwapConfirmDialog, SwapConfirmInfo, TokenSelector,
+    TransactionNotification, TransactionNotificationContainer, WizardReviewRow, WizardStep,
 };
+use crate::components::payment_method_selector::{PaymentDirection, PaymentMethodSelector};
 use crate::crypto::tx_signer::EthereumTxSigner;
+use crate::features::activity::receipt::explorer_tx_url;
+use crate::features::fees::estimate::{refresh_platform_fee, FeeBasis, PlatformFeeEstimate};
+use crate::features::gas::hooks::use_gas_readiness;
+use crate::features::gas::readiness::{self, BuyPrefill};
+use crate::features::market::hooks::{use_gas, use_gas_unavailable};
+use crate::features::swap::approvals;
+use crate::features::swap::confirm_recheck::{self, GAS_REFRESH_AFTER_MS};
+use crate::features::swap::execution;
+use crate::features::swap::history::{self, HistoryQuery, SwapHistoryStatus, SwapRetryRequest};
+use crate::features::swap::limit_edit::{self, EditOutcome, LimitOrderEvent, LimitOrderEventKind};
+use crate::features::swap::limit_expiry::{self, ExpiryUrgency};
+use crate::features::swap::onramp_orders::{self, CancelSafety};
+use crate::features::swap::payout::{self, BankDetails, PayoutAccount, SavedPayoutMethod};
+use crate::features::swap::pending::{PendingKind, PendingSwapTx};
+use crate::features::swap::region::use_payment_region;
+use crate::features::swap::routing::RoutePreference;
+use crate::features::swap::trade_size::{self, SizeSuggestion, SplitPlan};
+use crate::features::swap::wrap::{self, WrapDirection};
+use crate::features::wallet::chain_accounts::{self, account_for_chain};
+use crate::features::wallet::state::{Account, Wallet};
+use crate::features::wallet::unlock::ensure_wallet_unlocked;
+use crate::pages::sell_flow::{offramp_tracking_info, SellFlow};
+use crate::pages::swap_split::{SplitSwapTracker, TradeSizeChip};
 use crate::router::Route;
 use crate::services::address_detector::ChainType;
+use crate::services::balance::BalanceService;
 use crate::services::cache::{CacheKey, MemoryCache};
 use crate::services::chain_config::{
-    network_to_chain_id as network_to_chain_id_helper, ChainConfigManager,
+    confirmation_progress, network_to_chain_id as network_to_chain_id_helper, token_chains,
+    ChainConfigManager, ChainFeature,
 };
+use crate::services::country_support::{MethodAvailability, PaymentMethodMatrix};
 use crate::services::error_logger::{ErrorLevel, ErrorLogger};
-use crate::services::fee::FeeService;
 use crate::services::fiat_offramp::{FiatOfframpQuoteResponse, FiatOfframpService};
 use crate::services::fiat_onramp::{FiatOnrampService, FiatQuoteResponse};
-use crate::services::gas::{GasService, GasSpeed};
-use crate::services::price::PriceService; // ✅ 添加PriceService用于获取代币美元价格
-                                          // use crate::services::payment_gateway::{PaymentGatewayService, PaymentRequest}; // TODO: 实现后取消注释
-use crate::features::wallet::unlock::ensure_wallet_unlocked;
+use crate::services::gas::{GasService, GasSpeed, ManualGasFee};
 use crate::services::gas_limit::GasLimitService;
 use crate::services::limit_order::{
     LimitOrderQuery, LimitOrderResponse, LimitOrderService, LimitOrderType as ServiceLimitOrderType,
 };
+use crate::services::payment_gateway::{
+    self as payment_gateway, CardDetailsInput, CardToken, PaymentGatewayService,
+};
+use crate::services::price::PriceService; // ✅ 添加PriceService用于获取代币美元价格
 use crate::services::swap::{SwapQuoteResponse, SwapService};
 use crate::services::token::{TokenInfo, TokenService};
 use crate::services::transaction::TransactionService;
 use crate::services::transaction_history::{
     TransactionHistoryItem, TransactionHistoryQuery, TransactionHistoryService,
 };
+use crate::services::user::{NotificationCategory, NotificationChannel};
 use crate::shared::design_tokens::Colors;
+use crate::shared::list_query::{use_list_query, ListQueryState, SortField, SortOrder};
+use crate::shared::logging;
+use crate::shared::navigation_guard::{self, BusyReason};
+use crate::shared::paginated_query::{use_paginated_query, Page, PageCache};
+use crate::shared::scheduler::{self, TickPolicy};
 use crate::shared::state::AppState;
+use crate::shared::state_updates;
+use crate::shared::submission_guard::{self, OrderFingerprint};
+use crate::shared::time::{self, Countdown};
+use crate::shared::validation::{
+    all_valid, slippage_percent_to_bps, use_validated_field, TokenAmount, Validator,
+};
+use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
+use futures::FutureExt;
 use std::sync::Arc;
 use std::time::Duration;
 
 // ✅ 数值格式化辅助函数（千位分隔符 + 小数位控制）
-fn format_currency(amount: f64, decimals: usize) -> String {
+pub(crate) fn format_currency(amount: f64, decimals: usize) -> String {
     let formatted_number = format!("{:.decimals$}", amount, decimals = decimals);
     let parts: Vec<&str> = formatted_number.split('.').collect();
 
@@ -112,6 +164,244 @@ fn parse_hex_u64(hex: &str) -> Result<u64, String> {
     u64::from_str_radix(hex_clean, 16).map_err(|e| format!("Failed to parse hex: {} ({})", hex, e))
 }
 
+/// 钱包中持有该代币的账户（按代币所在链匹配；钱包没有该链账户时返回 None）
+pub(crate) fn token_account(wallet: Option<&Wallet>, token: Option<&TokenInfo>) -> Option<Account> {
+    account_for_chain(wallet?, token?.chain).cloned()
+}
+
+/// 链的原生币符号（未知链按 ETH）
+fn chain_native_symbol(chain: &str) -> &'static str {
+    ChainType::from_str(chain)
+        .map(|c| c.native_token_symbol())
+        .unwrap_or("ETH")
+}
+
+/// 当前 gas 价格（gwei）：手动费用优先，否则按所选档位实时查询
+async fn current_gas_gwei(
+    app_state: AppState,
+    chain: &str,
+    speed: GasSpeed,
+    manual: Option<ManualGasFee>,
+) -> Option<f64> {
+    if let Some(manual) = manual {
+        return Some(manual.max_fee_per_gas_gwei);
+    }
+    match GasService::new(app_state).estimate(chain, speed).await {
+        Ok(estimate) => Some(estimate.max_fee_per_gas_gwei),
+        Err(e) => {
+            log::warn!("复核时获取gas价格失败: {:?}", e);
+            None
+        }
+    }
+}
+
+/// 确认时复核余额与 gas（跳过缓存重新查询），未通过时返回含差额的说明
+///
+/// `gas_required` 为按当前 gas 价格估算的网络费用；原生币余额接口只支持部分链，
+/// 与手续费余量检查一致，其他链只复核支付代币余额。
+async fn recheck_before_execute(
+    app_state: AppState,
+    wallet: Option<Wallet>,
+    from: TokenInfo,
+    amount: TokenAmount,
+    chain: &str,
+    gas_required: Option<f64>,
+) -> Result<(), String> {
+    let Some(account) = token_account(wallet.as_ref(), Some(&from)) else {
+        return Ok(());
+    };
+    let balance_service = BalanceService::new(app_state);
+    let total = balance_service
+        .get_asset_balance_fresh(&account, &from)
+        .await
+        .map_err(|_| "无法复核余额，请稍后重试".to_string())?;
+    let available = app_state
+        .committed_balances
+        .peek()
+        .available(&from.symbol, total);
+
+    let native_symbol = chain_native_symbol(chain);
+    let native_available = match gas_required {
+        Some(_) if readiness::supports_chain(chain) => {
+            if from.is_native {
+                Some(available.to_f64_lossy())
+            } else {
+                balance_service
+                    .get_native_balance_fresh(&account)
+                    .await
+                    .ok()
+                    .map(|native| {
+                        app_state
+                            .committed_balances
+                            .peek()
+                            .available_f64(native_symbol, native.to_f64_lossy())
+                    })
+            }
+        }
+        _ => None,
+    };
+
+    confirm_recheck::recheck(
+        &amount,
+        &available,
+        &from.symbol,
+        from.is_native,
+        native_symbol,
+        native_available,
+        gas_required.unwrap_or(0.0),
+    )
+    .map_err(|failure| failure.message())
+}
+
+/// 构建交换确认信息（汇率、手续费、滑点与最少收到数量）
+fn build_swap_confirm_info(
+    from_token: &TokenInfo,
+    to_token: &TokenInfo,
+    amount: &TokenAmount,
+    quote: &SwapQuoteResponse,
+    slippage: f64,
+    platform_fee: Option<PlatformFeeEstimate>,
+) -> SwapConfirmInfo {
+    // 汇率仅用于展示（企业级实现：验证NaN和Infinity）
+    let exchange_rate = {
+        let from_amt = quote.from_amount.parse::<f64>().unwrap_or(0.0);
+        let to_amt = quote.to_amount.parse::<f64>().unwrap_or(0.0);
+        let rate = to_amt / from_amt;
+        if from_amt > 0.0 && to_amt > 0.0 && rate.is_finite() && rate > 0.0 {
+            format!("1 {} = {:.6} {}", from_token.symbol, rate, to_token.symbol)
+        } else {
+            "计算中...".to_string()
+        }
+    };
+
+    // 包装/解包按 1:1 兑换：没有滑点，也没有兑换手续费
+    let wrap_direction = wrap::wrap_pair(from_token, to_token);
+
+    // 最少收到 = 报价数量扣除滑点（按最小单位向下取整）
+    let min_received = match wrap_direction {
+        Some(_) => Some(format!("{} {}", amount.format(6), to_token.symbol)),
+        None => TokenAmount::from_units(&quote.to_amount, to_token.decimals)
+            .zip(slippage_percent_to_bps(slippage))
+            .map(|(to_amount, bps)| {
+                format!(
+                    "{} {}",
+                    to_amount.apply_slippage_bps(bps).format(6),
+                    to_token.symbol
+                )
+            }),
+    };
+
+    // 企业级实现：区分三种费用
+    // 1. protocol_fee: 协议手续费（1inch等DEX协议的费用）
+    // 2. gas_fee: Gas费用（区块链网络收取的交易执行费用）
+    // 3. platform_service_fee: 平台服务费（钱包服务商收取的服务费用，客户端估算失败时由服务端在执行时计算）
+    SwapConfirmInfo {
+        from_token: from_token.symbol.clone(),
+        to_token: to_token.symbol.clone(),
+        from_amount: amount.to_string(),
+        to_amount: quote.to_amount.clone(),
+        exchange_rate,
+        protocol_fee: quote
+            .protocol_fee
+            .clone()
+            .map(|f| format!("{} {}", f, from_token.symbol)),
+        gas_fee: quote.estimated_gas.clone(),
+        platform_service_fee: match wrap_direction {
+            Some(direction) => Some(format!("无（{}不收兑换手续费）", direction.label())),
+            None => platform_fee.map(|f| f.label()),
+        },
+        platform_fee_at_execution: wrap_direction.is_none()
+            && !matches!(platform_fee, Some(PlatformFeeEstimate::Quoted { .. })),
+        slippage,
+        min_received,
+        needs_approval: None, // 确认前按链上额度判断
+        router_address: None, // 在执行时从SwapExecuteResponse获取
+    }
+}
+
+/// 直接调用包装合约完成包装/解包，广播后在后台等待链上确认
+#[allow(clippy::too_many_arguments)]
+fn spawn_direct_wrap(
+    app_state: AppState,
+    wallet_id: String,
+    account: Account,
+    direction: WrapDirection,
+    from: TokenInfo,
+    to: TokenInfo,
+    amount: TokenAmount,
+    mut loading: Signal<bool>,
+    mut error: Signal<Option<String>>,
+    mut cache: Signal<MemoryCache>,
+) {
+    spawn(async move {
+        loading.set(true);
+        error.set(None);
+        let toasts = app_state.toasts;
+        let progress_key = format!("wrap:{}:{}", from.symbol, to.symbol);
+        AppState::show_progress(
+            toasts,
+            &progress_key,
+            format!("正在{} {}…", direction.label(), from.symbol),
+        );
+
+        match wrap::execute(
+            app_state, &wallet_id, &account, from.chain, direction, &amount,
+        )
+        .await
+        {
+            Ok(tx_hash) => {
+                let amount_label = amount.to_string();
+                wrap::record(from.chain, direction, &from, &to, &amount_label, &tx_hash);
+                AppState::finish_progress(
+                    toasts,
+                    &progress_key,
+                    ToastType::Success,
+                    format!("{}交易已广播，等待链上确认", direction.label()),
+                    Some(ToastAction::route("查看记录", Route::Swap {})),
+                );
+                cache.write().remove_by_prefix("balance:");
+                loading.set(false);
+
+                // 等待确认后更新本地记录（最多约 5 分钟）
+                let chain = from.chain.as_str();
+                match TransactionService::new(app_state)
+                    .wait_for_confirmation(&tx_hash, chain, 60, 5_000)
+                    .await
+                {
+                    Ok(status) if status.status == "confirmed" || status.status == "failed" => {
+                        wrap::set_status(&tx_hash, &status.status);
+                        if status.status == "confirmed" {
+                            AppState::show_success(
+                                toasts,
+                                format!(
+                                    "已{} {} {} → {}",
+                                    direction.label(),
+                                    amount_label,
+                                    from.symbol,
+                                    to.symbol
+                                ),
+                            );
+                        } else {
+                            AppState::show_error(
+                                toasts,
+                                format!("{}交易失败，请在历史记录中查看详情", direction.label()),
+                            );
+                        }
+                        cache.write().remove_by_prefix("balance:");
+                    }
+                    Ok(_) => {}
+                    Err(e) => log::warn!("查询{}交易状态失败: {}", direction.label(), e),
+                }
+            }
+            Err(e) => {
+                AppState::finish_progress(toasts, &progress_key, ToastType::Error, e.clone(), None);
+                error.set(Some(crate::shared::ui_error::sanitize_user_message(e)));
+                loading.set(false);
+            }
+        }
+    });
+}
+
 /// 标签页类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum SwapTab {
@@ -172,8 +462,27 @@ pub fn Swap() -> Element {
             }
         };
     }
-    // 当前标签页
-    let active_tab = use_signal(|| SwapTab::Swap);
+    // 购买预填：手续费不足提示中点击“购买原生币”（可能来自发送页）
+    let mut buy_prefill = use_signal(readiness::take_pending_buy);
+
+    // 当前标签页（订单列表中点击“继续支付”跳转而来时打开购买标签）
+    let active_tab = use_signal(|| {
+        if buy_prefill.peek().is_some() || onramp_orders::has_resume_request() {
+            SwapTab::Buy
+        } else {
+            SwapTab::Swap
+        }
+    });
+
+    // 用户所在地区及可用法币支付方式（受限地区屏蔽充值/提现，兑换不受影响）
+    let region = use_payment_region();
+    let fiat_blocked = use_memo(move || region.read().as_ref().filter(|m| m.restricted).cloned());
+
+    // 小额余额归集面板
+    let mut show_dust_sweep = use_signal(|| false);
+
+    // 预填参数：从历史记录重试失败兑换，或从其他页面（如价格关注列表）跳转而来
+    let mut swap_prefill = use_signal(history::take_pending_prefill);
 
     // 标签页加载状态（懒加载优化）
     let tabs_loaded = use_signal(|| {
@@ -185,132 +494,61 @@ pub fn Swap() -> Element {
     // 链选择
     let selected_chain = use_signal(|| "ethereum".to_string());
 
-    // 新手引导
-    let show_tour = use_signal(|| false);
-    let tour_step = use_signal(|| 0);
-    let onboarding_manager = OnboardingManager::new();
-    let tour_steps = use_signal(|| onboarding_manager.get_steps().to_vec());
+    // 原生币手续费余量（不足时提示先补充原生币）
+    let readiness_chain = use_memo(move || Some(selected_chain()));
+    let gas_readiness = use_gas_readiness(readiness_chain, readiness::SWAP_GAS_UNITS);
+
+    // 新手引导（首次访问由应用布局中的 PageTour 自动播放，这里仅处理手动重播）
+    let mut show_tour = use_signal(|| false);
 
     // 交易通知
     let notifications = use_signal(|| Vec::<TransactionNotification>::new());
 
-    // 检查是否首次访问（从localStorage读取）
-    use_effect({
-        let mut show_tour_sig = show_tour;
-        move || {
-            // 检查localStorage中是否已有标记
-            if let Some(window) = web_sys::window() {
-                if let Ok(Some(storage)) = window.local_storage() {
-                    if let Ok(Some(has_seen_tour)) = storage.get_item("has_seen_swap_tour") {
-                        if has_seen_tour == "true" {
-                            return; // 已经看过引导，不显示
-                        }
-                    }
-                    // 首次访问，显示引导
-                    show_tour_sig.set(true);
-                }
-            }
-        }
-    });
-
-    // 新手引导事件处理
-    let mut handle_tour_close = {
-        let mut show_tour_sig = show_tour;
-        let mut tour_step_sig = tour_step;
-        move || {
-            show_tour_sig.set(false);
-            tour_step_sig.set(0);
-            // 保存到localStorage，标记已看过引导
-            if let Some(window) = web_sys::window() {
-                if let Ok(Some(storage)) = window.local_storage() {
-                    let _ = storage.set_item("has_seen_swap_tour", "true");
-                }
-            }
-        }
-    };
-
-    let mut handle_tour_next = {
-        let mut tour_step_sig = tour_step;
-        let steps_len = tour_steps.read().len();
-        let mut handle_close = {
-            let mut show_tour_sig = show_tour;
-            let mut tour_step_sig = tour_step;
-            move || {
-                show_tour_sig.set(false);
-                tour_step_sig.set(0);
-                if let Some(window) = web_sys::window() {
-                    if let Ok(Some(storage)) = window.local_storage() {
-                        let _ = storage.set_item("has_seen_swap_tour", "true");
-                    }
-                }
-            }
-        };
-        move || {
-            let current = *tour_step_sig.read();
-            if current < steps_len - 1 {
-                tour_step_sig.set(current + 1);
-            } else {
-                handle_close();
-            }
-        }
-    };
-
-    let mut handle_tour_prev = {
-        let mut tour_step_sig = tour_step;
-        move || {
-            let current = *tour_step_sig.read();
-            if current > 0 {
-                tour_step_sig.set(current - 1);
-            }
-        }
-    };
-
-    let mut handle_tour_skip = {
-        let mut show_tour_sig = show_tour;
-        let mut tour_step_sig = tour_step;
-        move || {
-            show_tour_sig.set(false);
-            tour_step_sig.set(0);
-            if let Some(window) = web_sys::window() {
-                if let Ok(Some(storage)) = window.local_storage() {
-                    let _ = storage.set_item("has_seen_swap_tour", "true");
-                }
-            }
-        }
-    };
-
-    // 添加通知函数
-    let mut add_notification = {
-        let mut notifications_sig = notifications;
-        move |notification_type: NotificationType,
-              title: String,
-              message: String,
-              transaction_id: Option<String>| {
-            let mut notifs = notifications_sig.read().clone();
-            let id = format!("notif_{}", js_sys::Date::now() as u64);
-            let timestamp = (js_sys::Date::now() / 1000.0) as u64;
-
-            notifs.push(TransactionNotification {
-                id,
-                notification_type,
-                title,
-                message,
-                timestamp,
-                transaction_id,
-            });
-
-            notifications_sig.set(notifs);
+    // 添加通知函数（成功/信息类按“交易动态”通知偏好过滤，错误与警告始终显示）
+    // 通知可能由多个进行中的订单任务同时产生，经修改队列写入
+    let add_notification = move |notification_type: NotificationType,
+                                 title: String,
+                                 message: String,
+                                 transaction_id: Option<String>| {
+        let informational = matches!(
+            notification_type,
+            NotificationType::Success | NotificationType::Info
+        );
+        if informational
+            && !app_state.preferences.read().notifications.delivers(
+                NotificationCategory::Transactions,
+                NotificationChannel::InApp,
+            )
+        {
+            return;
         }
+        let now = time::now_ms();
+        let timestamp = (now / 1000.0) as u64;
+        state_updates::enqueue(
+            notifications,
+            move |notifs: &mut Vec<TransactionNotification>| {
+                // 同一毫秒内的多条通知追加序号，避免关闭时误删
+                let id = format!("notif_{}_{}", now as u64, notifs.len());
+                notifs.push(TransactionNotification {
+                    id,
+                    notification_type,
+                    title,
+                    message,
+                    timestamp,
+                    transaction_id,
+                });
+            },
+        );
     };
 
     // 关闭通知函数
-    let mut handle_notification_close = {
-        let mut notifications_sig = notifications;
-        move |id: String| {
-            let mut notifs = notifications_sig.read().clone();
-            notifs.retain(|n| n.id != id);
-            notifications_sig.set(notifs);
-        }
+    let handle_notification_close = move |id: String| {
+        state_updates::enqueue(
+            notifications,
+            move |notifs: &mut Vec<TransactionNotification>| {
+                notifs.retain(|n| n.id != id);
+            },
+        );
     };
 
     rsx! {
@@ -343,21 +581,53 @@ pub fn Swap() -> Element {
                         button {
                             class: "text-sm px-3 py-1 rounded transition-all",
                             style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
-                            onclick: {
-                                let mut show_tour = show_tour;
-                                let mut tour_step = tour_step;
-                                move |_| {
-                                    show_tour.set(true);
-                                    tour_step.set(0);
-                                }
-                            },
+                            onclick: move |_| show_tour.set(true),
                             {format!("❓ {}", crate::i18n::translations::get_text("swap.beginner_guide", &app_state.language.read()))}
                         }
                     }
                 }
 
+                // 推迟验证助记词超过 7 天时，兑换前需先完成验证
+                BackupGate {}
+
                 // 稳定币余额卡片（始终可见）
-                StablecoinBalanceCard {}
+                StablecoinBalanceCard {
+                    on_swap: {
+                        let mut active_tab = active_tab;
+                        move |request: SwapRetryRequest| {
+                            swap_prefill.set(Some(request));
+                            active_tab.set(SwapTab::Swap);
+                        }
+                    },
+                }
+
+                if *active_tab.read() != SwapTab::Buy {
+                    GasReadinessBanner {
+                        readiness: gas_readiness,
+                        on_buy: {
+                            let mut active_tab = active_tab;
+                            move |prefill: BuyPrefill| {
+                                buy_prefill.set(Some(prefill));
+                                active_tab.set(SwapTab::Buy);
+                            }
+                        },
+                    }
+                }
+
+                // 小额余额归集
+                if show_dust_sweep() {
+                    DustSweepPanel { on_close: move |_| show_dust_sweep.set(false) }
+                } else {
+                    div {
+                        class: "flex justify-end",
+                        button {
+                            class: "text-sm px-3 py-1 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY),
+                            onclick: move |_| show_dust_sweep.set(true),
+                            "🧹 转换小额余额"
+                        }
+                    }
+                }
 
                 // 标签页导航
                 div {
@@ -417,17 +687,33 @@ pub fn Swap() -> Element {
                             rsx! {
                                 SwapTabContent {
                                     selected_chain: selected_chain,
+                                    prefill: swap_prefill,
+                                    on_view_history: move |_| {
+                                        let mut active_tab = active_tab;
+                                        active_tab.set(SwapTab::History);
+                                    },
                                     on_notification: Some(EventHandler::new(move |(notif_type, title, message, tx_id)| {
                                         add_notification(notif_type, title, message, tx_id);
                                     })),
                                 }
                             }
                         },
-                        SwapTab::Buy => {
-                            rsx! { BuyStablecoinTab {} }
-                        },
-                        SwapTab::Withdraw => {
-                            rsx! { WithdrawTab {} }
+                        SwapTab::Buy | SwapTab::Withdraw => {
+                            rsx! {
+                                div {
+                                    class: "space-y-4",
+                                    PaymentRegionBanner { region: region }
+                                    if app_state.user.read().needs_email_verification() {
+                                        EmailVerificationRequired {}
+                                    } else if let Some(matrix) = fiat_blocked() {
+                                        FiatRegionBlockedNotice { matrix: matrix }
+                                    } else if current_tab == SwapTab::Buy {
+                                        BuyStablecoinTab { region: region, prefill: buy_prefill }
+                                    } else {
+                                        SellFlow { region: region }
+                                    }
+                                }
+                            }
                         },
                         SwapTab::LimitOrder => {
                             rsx! {
@@ -440,28 +726,31 @@ pub fn Swap() -> Element {
                             }
                         },
                         SwapTab::History => {
-                            rsx! { HistoryTab {} }
+                            let mut active_tab = active_tab;
+                            rsx! {
+                                HistoryTab {
+                                    on_retry: move |request: SwapRetryRequest| {
+                                        swap_prefill.set(Some(request));
+                                        active_tab.set(SwapTab::Swap);
+                                    },
+                                    on_switch_tab: move |tab: SwapTab| active_tab.set(tab),
+                                }
+                            }
                         },
                     }
                 }
 
-                // 新手引导组件
-                OnboardingTour {
-                    show: show_tour,
-                    current_step: tour_step,
-                    steps: tour_steps,
-                    on_close: Some(EventHandler::new(move |_| {
-                        handle_tour_close();
-                    })),
-                    on_next: Some(EventHandler::new(move |_| {
-                        handle_tour_next();
-                    })),
-                    on_prev: Some(EventHandler::new(move |_| {
-                        handle_tour_prev();
-                    })),
-                    on_skip: Some(EventHandler::new(move |_| {
-                        handle_tour_skip();
-                    })),
+                // 新手引导组件（手动重播）
+                if show_tour() {
+                    if let Some(tour) = OnboardingManager::new().tour_for_route("/swap").cloned() {
+                        OnboardingTour {
+                            tour: tour.clone(),
+                            on_finish: move |_| {
+                                OnboardingManager::mark_completed(tour.id);
+                                show_tour.set(false);
+                            },
+                        }
+                    }
                 }
 
                 // 交易通知容器
@@ -485,8 +774,12 @@ pub fn Swap() -> Element {
 #[component]
 fn SwapTabContent(
     selected_chain: Signal<String>,
+    /// 重试失败兑换时预填的参数（消费后清空）
+    prefill: Signal<Option<SwapRetryRequest>>,
     /// 添加通知回调（可选）
     on_notification: Option<EventHandler<(NotificationType, String, String, Option<String>)>>,
+    /// 打开兑换记录
+    on_view_history: EventHandler<()>,
 ) -> Element {
     let app_state = use_context::<AppState>();
     let navigator = use_navigator();
@@ -498,6 +791,11 @@ fn SwapTabContent(
     // 代币选择（使用TokenInfo）
     let from_token = use_signal(|| Option::<TokenInfo>::None);
     let to_token = use_signal(|| Option::<TokenInfo>::None);
+    // 原生币 ↔ 包装币（如 ETH ↔ WETH）按 1:1 直接包装/解包
+    let wrap_direction = use_memo(move || match (from_token(), to_token()) {
+        (Some(from), Some(to)) => wrap::wrap_pair(&from, &to),
+        _ => None,
+    });
     let mut amount = use_signal(|| String::new());
     let mut slippage = use_signal(|| 0.5);
 
@@ -506,7 +804,55 @@ fn SwapTabContent(
     let is_loading = use_signal(|| false);
     let mut quote = use_signal(|| Option::<SwapQuoteResponse>::None);
     let quote_loading = use_signal(|| false);
-    let platform_fee = use_signal(|| Option::<f64>::None); // ✅ 平台服务费
+    let platform_fee = use_signal(|| Option::<PlatformFeeEstimate>::None); // ✅ 平台服务费
+                                                                           // 报价查询时间（缓存命中时为写入缓存的时间）与强制刷新计数
+    let quote_fetched_at = use_signal(|| Option::<u64>::None);
+    let mut quote_refresh = use_signal(|| 0u32);
+    // 路由偏好：默认取设置中的全局偏好，可在高级设置或报价卡片中临时切换
+    let mut route_preference = use_signal(|| app_state.preferences.peek().swap_route_preference);
+    let mut show_advanced = use_signal(|| false);
+
+    // Gas档位（签名时使用所选档位，手动费用优先）
+    let gas_speed = use_signal(|| GasSpeed::Average);
+    let manual_gas = use_signal(|| Option::<ManualGasFee>::None);
+    // Gas档位来自共享行情（后台统一轮询）
+    let gas_chain = use_memo(move || Some(selected_chain()));
+    let gas_quote = use_gas(gas_chain);
+    let gas_unavailable = use_gas_unavailable(gas_chain);
+    let gas_options = use_memo(move || gas_quote().map(|q| q.estimates));
+    let gas_options_loading = use_memo(move || gas_options.read().is_none() && !gas_unavailable());
+
+    // 最近一次成功的报价：刷新期间继续显示（变暗），避免报价区域塌陷
+    let mut last_quote = use_signal(|| Option::<SwapQuoteResponse>::None);
+    use_effect(move || {
+        if let Some(q) = quote.read().clone() {
+            last_quote.set(Some(q));
+        }
+    });
+
+    // 跳过缓存重新报价（报价过期后手动刷新、切回标签页时使用）
+    let mut refresh_quote = move || {
+        if let (Some(from), Some(to)) = (from_token.peek().as_ref(), to_token.peek().as_ref()) {
+            let mut cache = cache;
+            cache.write().remove(&CacheKey::routed_quote(
+                &from.symbol,
+                &to.symbol,
+                &amount.peek(),
+                route_preference.peek().as_str(),
+            ));
+        }
+        *quote_refresh.write() += 1;
+    };
+    let mut seen_resume = use_signal(|| *app_state.resume_tick.peek());
+    use_effect(move || {
+        let tick = (app_state.resume_tick)();
+        if tick != *seen_resume.peek() {
+            seen_resume.set(tick);
+            if quote.peek().is_some() {
+                refresh_quote();
+            }
+        }
+    });
 
     // 价格变化跟踪（价格变化提示功能）
     let previous_quote = use_signal(|| Option::<SwapQuoteResponse>::None);
@@ -526,7 +872,7 @@ fn SwapTabContent(
                 Some(PriceChangeInfo::new(
                     prev_price,
                     current_price,
-                    js_sys::Date::now() as u64 / 1000,
+                    crate::shared::time::now_unix(),
                 ))
             } else {
                 None
@@ -536,10 +882,93 @@ fn SwapTabContent(
         }
     });
 
+    // 交易规模建议：价格影响超过阈值时查找可接受的最大金额
+    let mut size_suggestion = use_signal(|| Option::<SizeSuggestion>::None);
+    let mut split_plan = use_signal(|| Option::<SplitPlan>::None);
+    use_effect(move || {
+        let current = quote.read().clone();
+        size_suggestion.set(None);
+        let Some(q) = current.filter(trade_size::exceeds_threshold) else {
+            return;
+        };
+        let amount_str = amount.peek().clone();
+        let (Ok(total), Some(from), Some(to)) = (
+            amount_str.parse::<f64>(),
+            from_token.peek().clone(),
+            to_token.peek().clone(),
+        ) else {
+            return;
+        };
+        let chain = selected_chain.peek().clone();
+        spawn(async move {
+            let suggestion =
+                trade_size::suggest_size(app_state, cache, &from.symbol, &to.symbol, total, &chain)
+                    .await;
+            // 查找期间金额或报价已变化时丢弃结果
+            if *amount.peek() == amount_str
+                && quote.peek().as_ref().map(|c| &c.to_amount) == Some(&q.to_amount)
+            {
+                size_suggestion.set(suggestion);
+            }
+        });
+    });
+
     // 确认对话框状态
     let show_confirm_dialog = use_signal(|| false);
     let confirm_info = use_signal(|| Option::<SwapConfirmInfo>::None);
 
+    // 点击确认时的复核状态，以及对话框内 gas 费用上次刷新的时间
+    let mut confirm_rechecking = use_signal(|| false);
+    let mut confirm_recheck_error = use_signal(|| Option::<String>::None);
+    let mut confirm_gas_checked_at = use_signal(|| 0.0f64);
+
+    // 确认对话框打开时生成本次兑换的幂等键（重复确认只会执行一次）
+    let mut swap_submit_key = use_signal(|| Option::<String>::None);
+    use_effect(move || {
+        if show_confirm_dialog() {
+            swap_submit_key.set(Some(submission_guard::new_idempotency_key()));
+            confirm_recheck_error.set(None);
+            confirm_gas_checked_at.set(time::now_ms());
+        }
+    });
+
+    // 对话框打开超过 30 秒后按当前网络刷新显示的 gas 费用
+    use_future(move || async move {
+        let mut confirm_info = confirm_info;
+        loop {
+            scheduler::next_tick(TickPolicy::PauseWhenHidden, 5_000).await;
+            if !*show_confirm_dialog.peek()
+                || time::now_ms() - *confirm_gas_checked_at.peek() < GAS_REFRESH_AFTER_MS as f64
+            {
+                continue;
+            }
+            confirm_gas_checked_at.set(time::now_ms());
+            let chain = selected_chain.peek().clone();
+            let units = confirm_recheck::swap_gas_units(
+                quote
+                    .peek()
+                    .as_ref()
+                    .and_then(|q| q.estimated_gas.as_deref()),
+            );
+            let Some(gwei) =
+                current_gas_gwei(app_state, &chain, *gas_speed.peek(), *manual_gas.peek()).await
+            else {
+                continue;
+            };
+            if !*show_confirm_dialog.peek() {
+                continue;
+            }
+            if let Some(info) = confirm_info.write().as_mut() {
+                info.gas_fee = Some(format!(
+                    "≈ {:.6} {}（{:.2} Gwei，已刷新）",
+                    confirm_recheck::gas_cost(gwei, units),
+                    chain_native_symbol(&chain),
+                    gwei
+                ));
+            }
+        }
+    });
+
     // 用户反馈状态
     let show_feedback = use_signal(|| false);
     let feedback_type = use_signal(|| FeedbackType::Info);
@@ -594,6 +1023,29 @@ fn SwapTabContent(
         wallet_state.get_selected_wallet().cloned()
     });
 
+    // 同一交易对有兑换未确认时默认阻止再次提交（用户可勾选仍要提交）
+    let mut allow_duplicate = use_signal(|| false);
+    let duplicate_pending = use_memo(move || {
+        let wallet = current_wallet.read();
+        let from = from_token.read();
+        let to = to_token.read();
+        match (wallet.as_ref(), from.as_ref(), to.as_ref()) {
+            (Some(wallet), Some(from), Some(to)) => app_state
+                .pending_swaps
+                .read()
+                .has_pending_pair(&wallet.id, &selected_chain.read(), &from.symbol, &to.symbol),
+            _ => false,
+        }
+    });
+    // 更换交易对后需重新确认
+    use_effect(move || {
+        let _pair = (from_token.read().clone(), to_token.read().clone());
+        allow_duplicate.set(false);
+    });
+    // 支付代币所在链的账户（余额与快捷比例）
+    let from_account =
+        use_memo(move || token_account(current_wallet.read().as_ref(), from_token.read().as_ref()));
+
     // 获取当前链类型（从selected_chain字符串转换）
     let chain_type = use_memo(move || {
         ChainType::from_str(&selected_chain.read()).unwrap_or(ChainType::Ethereum)
@@ -616,13 +1068,92 @@ fn SwapTabContent(
             if let Some(chain) = chain_to_set {
                 let chain_str = chain.as_str().to_string();
                 // 只有当链不同时才更新，避免不必要的更新
-                if *selected_chain_mut.read() != chain_str {
+                // （不追踪 selected_chain，手动切换多链代币的网络时不会被覆盖）
+                if *selected_chain_mut.peek() != chain_str {
                     selected_chain_mut.set(chain_str);
                 }
             }
         }
     });
 
+    // 多链代币手动切换网络：在新网络上重新匹配同符号的代币
+    let switch_token_chain = move |chain: ChainType| {
+        let from_symbol = from_token.peek().as_ref().map(|t| t.symbol.clone());
+        let to_symbol = to_token.peek().as_ref().map(|t| t.symbol.clone());
+        let detected_chain = from_token.peek().as_ref().map(|t| t.chain);
+        let mut selected_chain = selected_chain;
+        let mut from_token = from_token;
+        let mut to_token = to_token;
+        quote.set(None);
+        spawn(async move {
+            let tokens = TokenService::new(app_state)
+                .get_token_list(chain)
+                .await
+                .unwrap_or_else(|e| {
+                    log::warn!("切换网络时获取代币列表失败: {}", e);
+                    Vec::new()
+                });
+            let find = |symbol: Option<&String>| {
+                symbol.and_then(|symbol| {
+                    tokens
+                        .iter()
+                        .find(|t| t.symbol.eq_ignore_ascii_case(symbol))
+                        .cloned()
+                })
+            };
+            match find(from_symbol.as_ref()) {
+                Some(token) => {
+                    from_token.set(Some(token));
+                    to_token.set(find(to_symbol.as_ref()));
+                }
+                // 新网络上没有该代币：退回自动识别的网络
+                None => {
+                    if let Some(detected) = detected_chain {
+                        selected_chain.set(detected.as_str().to_string());
+                    }
+                }
+            }
+        });
+    };
+
+    // 重试失败兑换：按原参数预填表单
+    use_effect(move || {
+        let Some(request) = prefill() else {
+            return;
+        };
+        let mut selected_chain = selected_chain;
+        let mut from_token = from_token;
+        let mut to_token = to_token;
+        let mut prefill = prefill;
+        let chain = request
+            .network
+            .as_deref()
+            .and_then(ChainType::from_str)
+            .unwrap_or_else(|| *chain_type.peek());
+        selected_chain.set(chain.as_str().to_string());
+        amount.set(request.amount.clone());
+        quote.set(None);
+        spawn(async move {
+            let token_service = TokenService::new(app_state);
+            match token_service.get_token_list(chain).await {
+                Ok(tokens) => {
+                    let find = |symbol: &str| {
+                        tokens
+                            .iter()
+                            .find(|t| t.symbol.eq_ignore_ascii_case(symbol))
+                            .cloned()
+                    };
+                    from_token.set(find(&request.from_token));
+                    to_token.set(find(&request.to_token));
+                }
+                Err(e) => {
+                    log::warn!("重试兑换时获取代币列表失败: {}", e);
+                }
+            }
+            prefill.set(None);
+        });
+    });
+
     // 初始化：默认选择稳定币（USDT优先，如果余额为0则USDC）
     use_effect({
         let mut from_token_mut = from_token;
@@ -631,8 +1162,8 @@ fn SwapTabContent(
         let wallet_opt = current_wallet.read().clone();
 
         move || {
-            if from_token_mut.read().is_some() {
-                return; // 已经选择过，不再自动选择
+            if from_token_mut.read().is_some() || prefill.peek().is_some() {
+                return; // 已经选择过（或正在预填重试参数），不再自动选择
             }
 
             let wallet = match wallet_opt.clone() {
@@ -728,6 +1259,8 @@ fn SwapTabContent(
         let mut err_sig = error_message;
 
         move || {
+            let _ = quote_refresh();
+            let route = route_preference();
             let amount_val = amount_sig.read().clone();
             let from = from_token_sig.read().clone();
             let to = to_token_sig.read().clone();
@@ -782,6 +1315,19 @@ fn SwapTabContent(
                 return;
             }
 
+            // 原生币 ↔ 包装币按 1:1 兑换，不请求聚合器报价，也不收兑换手续费
+            if let (Some(from_info), Some(to_info)) = (from.as_ref(), to.as_ref()) {
+                if wrap::wrap_pair(from_info, to_info).is_some() {
+                    err_sig.set(None);
+                    quote_sig.set(Some(wrap::quote(from_info, to_info, &amount_val)));
+                    let mut fetched_at_sig = quote_fetched_at;
+                    fetched_at_sig.set(Some(crate::shared::time::now_unix()));
+                    let mut platform_fee_sig = platform_fee;
+                    platform_fee_sig.set(None);
+                    return;
+                }
+            }
+
             let amount_clone = amount_val.clone();
             let from_clone = from_symbol.clone();
             let to_clone = to_symbol.clone();
@@ -794,20 +1340,28 @@ fn SwapTabContent(
             let mut cache_sig = cache;
             let mut error_logger_sig = error_logger;
             let mut previous_quote_for_spawn = previous_quote;
+            let mut fetched_at_sig = quote_fetched_at;
 
             spawn(async move {
                 quote_load_sig_for_spawn.set(true);
                 err_sig_for_spawn.set(None);
 
-                // 检查缓存
-                let cache_key = CacheKey::quote(&from_clone, &to_clone, &amount_clone);
-                if let Some(cached_quote) = cache_sig.read().get::<SwapQuoteResponse>(&cache_key) {
-                    // 价格变化跟踪：保存上一次报价（缓存命中时也需要）
+                // 检查缓存（按路由偏好分别缓存，切换偏好对比时直接命中）
+                let cache_key =
+                    CacheKey::routed_quote(&from_clone, &to_clone, &amount_clone, route.as_str());
+                let cached = cache_sig
+                    .read()
+                    .get_with_time::<SwapQuoteResponse>(&cache_key);
+                if let Some((cached_quote, cached_at)) = cached {
+                    // 价格变化跟踪：保存上一次报价（缓存命中时也需要；切换路由偏好不算价格变化）
                     let current_quote = quote_sig_for_spawn.read().clone();
-                    if let Some(prev_quote) = current_quote {
+                    if let Some(prev_quote) = current_quote
+                        .filter(|prev| prev.route_preference == cached_quote.route_preference)
+                    {
                         previous_quote_for_spawn.set(Some(prev_quote));
                     }
                     quote_sig_for_spawn.set(Some(cached_quote));
+                    fetched_at_sig.set(Some(cached_at));
                     quote_load_sig_for_spawn.set(false);
                     return;
                 }
@@ -815,13 +1369,15 @@ fn SwapTabContent(
                 // 缓存未命中，从API获取
                 let swap_service = SwapService::new(app_state_for_spawn);
                 match swap_service
-                    .get_quote(&from_clone, &to_clone, &amount_clone, &chain_clone)
+                    .get_routed_quote(&from_clone, &to_clone, &amount_clone, &chain_clone, route)
                     .await
                 {
                     Ok(q) => {
-                        // 价格变化跟踪：保存上一次报价（在设置新报价前）
+                        // 价格变化跟踪：保存上一次报价（在设置新报价前；切换路由偏好不算价格变化）
                         let current_quote = quote_sig_for_spawn.read().clone();
-                        if let Some(prev_quote) = current_quote {
+                        if let Some(prev_quote) =
+                            current_quote.filter(|prev| prev.route_preference == q.route_preference)
+                        {
                             previous_quote_for_spawn.set(Some(prev_quote));
                         }
                         // 保存到缓存
@@ -829,60 +1385,22 @@ fn SwapTabContent(
                             .write()
                             .set(cache_key, q.clone(), Some(Duration::from_secs(30)));
                         quote_sig_for_spawn.set(Some(q.clone()));
+                        fetched_at_sig.set(Some(crate::shared::time::now_unix()));
 
                         // ✅ 计算平台服务费（Swap操作，按交易金额美元价值百分比）
-                        if let Ok(amount_f64) = amount_clone.parse::<f64>() {
-                            if amount_f64 > 0.0 {
-                                // 获取from_token的美元价格
-                                let price_service = PriceService::new(app_state_for_spawn.clone());
-                                let fee_service = FeeService::new(app_state_for_spawn.clone());
-                                let mut platform_fee_sig = platform_fee;
-                                let token_symbol = from_clone.clone(); // from_clone是token的symbol字符串
-
-                                spawn(async move {
-                                    // 获取代币美元价格
-                                    match price_service.get_price(&token_symbol).await {
-                                        Ok(price_data) => {
-                                            let usd_value = amount_f64 * price_data.usd;
-                                            log::info!(
-                                                "Swap金额: {} {}, 美元价值: ${:.2}",
-                                                amount_f64,
-                                                token_symbol,
-                                                usd_value
-                                            );
-
-                                            // 使用美元价值计算平台服务费
-                                            match fee_service
-                                                .calculate(
-                                                    &chain_clone,
-                                                    "swap",
-                                                    usd_value, // 传递美元价值而不是代币数量
-                                                )
-                                                .await
-                                            {
-                                                Ok(fee_quote) => {
-                                                    platform_fee_sig
-                                                        .set(Some(fee_quote.platform_fee));
-                                                    log::info!(
-                                                        "平台服务费(Swap): ${:.2} (规则ID: {})",
-                                                        fee_quote.platform_fee,
-                                                        fee_quote.applied_rule_id
-                                                    );
-                                                }
-                                                Err(e) => {
-                                                    log::error!("计算平台服务费失败: {}", e);
-                                                    platform_fee_sig.set(None);
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            log::error!("获取{}价格失败: {}", token_symbol, e);
-                                            platform_fee_sig.set(None);
-                                        }
-                                    }
-                                });
-                            }
-                        }
+                        refresh_platform_fee(
+                            app_state_for_spawn,
+                            platform_fee,
+                            chain_clone.clone(),
+                            "swap",
+                            amount_clone
+                                .parse::<f64>()
+                                .ok()
+                                .map(|amount| FeeBasis::Token {
+                                    symbol: from_clone.clone(),
+                                    amount,
+                                }),
+                        );
                     }
                     Err(e) => {
                         // 增强错误处理 - 识别不同类型的错误并给出友好提示
@@ -969,25 +1487,6 @@ fn SwapTabContent(
             let quote_opt = quote_sig.read().clone();
             let slippage_val = *slippage_sig.read();
 
-            // 边界情况处理：金额验证
-            let amount_parsed = match amount_val.parse::<f64>() {
-                Ok(v) => {
-                    if v.is_nan() || v.is_infinite() || v <= 0.0 {
-                        err_sig.set(Some("请输入有效的交换数量".to_string()));
-                        return;
-                    }
-                    if v > 1e15 {
-                        err_sig.set(Some("金额过大，请输入有效金额".to_string()));
-                        return;
-                    }
-                    v
-                }
-                Err(_) => {
-                    err_sig.set(Some("请输入有效的交换数量".to_string()));
-                    return;
-                }
-            };
-
             let from_token_info = match from {
                 Some(t) => t,
                 None => {
@@ -1004,6 +1503,24 @@ fn SwapTabContent(
                 }
             };
 
+            // 边界情况处理：金额按支付代币精度精确解析
+            let amount_parsed =
+                match TokenAmount::parse_input(&amount_val, from_token_info.decimals) {
+                    Ok(v) if v.is_zero() => {
+                        err_sig.set(Some("请输入有效的交换数量".to_string()));
+                        return;
+                    }
+                    Ok(v) if v.to_f64_lossy() > 1e15 => {
+                        err_sig.set(Some("金额过大，请输入有效金额".to_string()));
+                        return;
+                    }
+                    Ok(v) => v,
+                    Err(e) => {
+                        err_sig.set(Some(e));
+                        return;
+                    }
+                };
+
             let quote_response = match quote_opt {
                 Some(q) => q,
                 None => {
@@ -1012,151 +1529,85 @@ fn SwapTabContent(
                 }
             };
 
+            let mut confirm_info_val = build_swap_confirm_info(
+                &from_token_info,
+                &to_token_info,
+                &amount_parsed,
+                &quote_response,
+                slippage_val,
+                *platform_fee.peek(),
+            );
+
+            // 包装/解包直接调用包装合约，不需要授权兑换路由合约
+            let is_wrap = wrap::wrap_pair(&from_token_info, &to_token_info).is_some();
+
             // 企业级验证：检查余额是否充足（异步检查，余额不足时显示友好提示）
             let wallet_opt = current_wallet_sig.read().clone();
-            let chain_type_val = *chain_type_sig.read();
-            let from_token_info_clone = from_token_info.clone();
-            let amount_to_check = amount_parsed;
-            let mut show_confirm_sig_for_check = show_confirm_sig;
-            let mut confirm_info_sig_for_check = confirm_info_sig;
-            let mut err_sig_for_check = err_sig;
-            let amount_val_clone = amount_val.clone();
-            let quote_response_clone = quote_response.clone();
-            let to_token_info_clone = to_token_info.clone();
-            let slippage_val_clone = slippage_val;
-
-            if let Some(wallet) = wallet_opt {
-                if let Some(account) = wallet.accounts.first() {
-                    let token_service = TokenService::new(app_state_clone.clone());
-                    let wallet_addr = account.address.clone();
-                    let token_addr = from_token_info_clone.address.clone();
-
-                    // 异步检查余额
-                    spawn(async move {
-                        match token_service
-                            .get_token_balance(chain_type_val, &token_addr, &wallet_addr)
-                            .await
-                        {
-                            Ok(balance) => {
-                                if balance.balance_formatted < amount_to_check {
-                                    let shortfall = amount_to_check - balance.balance_formatted;
-                                    err_sig_for_check.set(Some(format!(
-                                        "余额不足！当前余额：{:.6} {}，需要：{:.6} {}，缺少：{:.6} {}。请减少交换数量或先充值。",
-                                        balance.balance_formatted,
-                                        from_token_info_clone.symbol,
-                                        amount_to_check,
-                                        from_token_info_clone.symbol,
-                                        shortfall,
-                                        from_token_info_clone.symbol
-                                    )));
-                                    return;
-                                }
-                                // 余额充足，显示确认对话框（企业级实现：验证NaN和Infinity）
-                                let exchange_rate = {
-                                    let from_amt = quote_response_clone
-                                        .from_amount
-                                        .parse::<f64>()
-                                        .unwrap_or(0.0);
-                                    let to_amt = quote_response_clone
-                                        .to_amount
-                                        .parse::<f64>()
-                                        .unwrap_or(0.0);
-                                    if from_amt > 0.0
-                                        && to_amt > 0.0
-                                        && from_amt.is_finite()
-                                        && to_amt.is_finite()
-                                    {
-                                        let rate = to_amt / from_amt;
-                                        if rate.is_finite() && rate > 0.0 {
-                                            format!(
-                                                "1 {} = {:.6} {}",
-                                                from_token_info_clone.symbol,
-                                                rate,
-                                                to_token_info_clone.symbol
-                                            )
-                                        } else {
-                                            "计算中...".to_string()
-                                        }
-                                    } else {
-                                        "计算中...".to_string()
-                                    }
-                                };
-
-                                // 企业级实现：区分三种费用
-                                // 1. protocol_fee: 协议手续费（1inch等DEX协议的费用）
-                                // 2. gas_fee: Gas费用（区块链网络收取的交易执行费用）
-                                // 3. platform_service_fee: 平台服务费（钱包服务商收取的服务费用，在执行时获取）
-                                let protocol_fee = quote_response_clone.protocol_fee.clone();
-
-                                let confirm_info_val = SwapConfirmInfo {
-                                    from_token: from_token_info_clone.symbol.clone(),
-                                    to_token: to_token_info_clone.symbol.clone(),
-                                    from_amount: amount_val_clone.clone(),
-                                    to_amount: quote_response_clone.to_amount.clone(),
-                                    exchange_rate,
-                                    protocol_fee: protocol_fee
-                                        .map(|f| format!("{} {}", f, from_token_info_clone.symbol)),
-                                    gas_fee: quote_response_clone.estimated_gas.clone(),
-                                    platform_service_fee: None, // 在执行时从SwapExecuteResponse获取
-                                    slippage: slippage_val_clone,
-                                    needs_approval: None, // 在执行时从SwapExecuteResponse获取
-                                    router_address: None, // 在执行时从SwapExecuteResponse获取
-                                };
-
-                                confirm_info_sig_for_check.set(Some(confirm_info_val));
-                                show_confirm_sig_for_check.set(true);
+            if let Some(account) = token_account(wallet_opt.as_ref(), Some(&from_token_info)) {
+                let balance_service = BalanceService::new(app_state_clone);
+                let mut show_confirm_sig_for_check = show_confirm_sig;
+                let mut confirm_info_sig_for_check = confirm_info_sig;
+                let mut err_sig_for_check = err_sig;
+
+                // 异步检查余额（按最小单位精确比较）
+                spawn(async move {
+                    match balance_service
+                        .get_asset_balance(&account, &from_token_info)
+                        .await
+                    {
+                        Ok(total) => {
+                            // 挂单与进行中订单占用的数量不可用于兑换
+                            let balance = app_state_clone
+                                .committed_balances
+                                .read()
+                                .available(&from_token_info.symbol, total);
+                            if amount_parsed > balance {
+                                let shortfall = amount_parsed
+                                    .checked_sub(&balance)
+                                    .map(|s| s.format(6))
+                                    .unwrap_or_else(|| amount_parsed.format(6));
+                                let symbol = &from_token_info.symbol;
+                                err_sig_for_check.set(Some(format!(
+                                    "余额不足！可用余额：{} {}，需要：{} {}，缺少：{} {}。请减少交换数量或先充值。",
+                                    balance.format(6),
+                                    symbol,
+                                    amount_parsed.format(6),
+                                    symbol,
+                                    shortfall,
+                                    symbol
+                                )));
+                                return;
                             }
-                            Err(e) => {
-                                #[cfg(debug_assertions)]
-                                log::debug!("balance_check_error={}", e);
-
-                                err_sig_for_check.set(Some("无法获取余额，请稍后重试".to_string()));
+                            // 链上额度已足够（如新手引导中预先授权过）时不提示授权步骤
+                            let needs_approval = if is_wrap {
+                                None
+                            } else {
+                                approvals::swap_needs_approval(
+                                    app_state_clone,
+                                    &account,
+                                    &from_token_info,
+                                    &amount_parsed,
+                                )
+                                .await
+                            };
+                            if let Some(needs) = needs_approval {
+                                confirm_info_val.needs_approval = Some(needs);
                             }
+                            confirm_info_sig_for_check.set(Some(confirm_info_val));
+                            show_confirm_sig_for_check.set(true);
                         }
-                    });
-                    return; // 等待异步余额检查完成
-                }
-            }
+                        Err(e) => {
+                            #[cfg(debug_assertions)]
+                            log::debug!("balance_check_error={}", e);
 
-            // 如果没有钱包，直接显示确认对话框（后端会处理验证）（企业级实现：验证NaN和Infinity）
-            let exchange_rate = {
-                let from_amt = quote_response.from_amount.parse::<f64>().unwrap_or(0.0);
-                let to_amt = quote_response.to_amount.parse::<f64>().unwrap_or(0.0);
-                if from_amt > 0.0 && to_amt > 0.0 && from_amt.is_finite() && to_amt.is_finite() {
-                    let rate = to_amt / from_amt;
-                    if rate.is_finite() && rate > 0.0 {
-                        format!(
-                            "1 {} = {:.6} {}",
-                            from_token_info.symbol, rate, to_token_info.symbol
-                        )
-                    } else {
-                        "计算中...".to_string()
+                            err_sig_for_check.set(Some("无法获取余额，请稍后重试".to_string()));
+                        }
                     }
-                } else {
-                    "计算中...".to_string()
-                }
-            };
-
-            // 企业级实现：区分三种费用
-            // 1. protocol_fee: 协议手续费（1inch等DEX协议的费用）
-            // 2. gas_fee: Gas费用（区块链网络收取的交易执行费用）
-            // 3. platform_service_fee: 平台服务费（钱包服务商收取的服务费用，在执行时获取）
-            let protocol_fee = quote_response.protocol_fee.clone();
-
-            let confirm_info_val = SwapConfirmInfo {
-                from_token: from_token_info.symbol.clone(),
-                to_token: to_token_info.symbol.clone(),
-                from_amount: amount_val.clone(),
-                to_amount: quote_response.to_amount.clone(),
-                exchange_rate,
-                protocol_fee: protocol_fee.map(|f| format!("{} {}", f, from_token_info.symbol)),
-                gas_fee: quote_response.estimated_gas.clone(),
-                platform_service_fee: None, // 在执行时从SwapExecuteResponse获取
-                slippage: slippage_val,
-                needs_approval: None, // 在执行时从SwapExecuteResponse获取
-                router_address: None, // 在执行时从SwapExecuteResponse获取
-            };
+                });
+                return; // 等待异步余额检查完成
+            }
 
+            // 如果没有钱包，直接显示确认对话框（后端会处理验证）
             confirm_info_sig.set(Some(confirm_info_val));
             show_confirm_sig.set(true);
         }
@@ -1194,6 +1645,8 @@ fn SwapTabContent(
             let chain = chain_sig.read().clone();
             let slippage_val = *slippage_sig.read();
             let wallet_opt = current_wallet_sig.read().clone();
+            let gas_speed_val = *gas_speed.read();
+            let manual_gas_val = *manual_gas.read();
 
             let from_symbol = from
                 .as_ref()
@@ -1223,7 +1676,46 @@ fn SwapTabContent(
             // 关闭确认对话框
             show_confirm_sig.set(false);
 
-            let amount_clone = amount_val.clone();
+            // EVM 链上的包装/解包直接调用包装合约，不经过兑换接口
+            if let (Some(from_info), Some(to_info)) = (from.clone(), to.clone()) {
+                if let Some(direction) = wrap::wrap_pair(&from_info, &to_info)
+                    .filter(|_| wrap::is_direct(from_info.chain))
+                {
+                    let account = token_account(wallet_opt.as_ref(), Some(&from_info));
+                    let amount = TokenAmount::parse_input(&amount_val, from_info.decimals);
+                    match (account, amount) {
+                        (Some(account), Ok(amount)) => spawn_direct_wrap(
+                            app_state_clone,
+                            wallet_identifier,
+                            account,
+                            direction,
+                            from_info,
+                            to_info,
+                            amount,
+                            loading_sig,
+                            err_sig,
+                            cache,
+                        ),
+                        (None, _) => {
+                            err_sig.set(Some("当前钱包没有该链的账户，请先添加".to_string()))
+                        }
+                        (_, Err(e)) => err_sig.set(Some(e)),
+                    }
+                    return;
+                }
+            }
+
+            let idempotency_key = swap_submit_key
+                .peek()
+                .clone()
+                .unwrap_or_else(submission_guard::new_idempotency_key);
+
+            // 规范化用户输入（如 "1,5" → "1.5"），金额已在确认前校验
+            let amount_clone = from
+                .as_ref()
+                .and_then(|t| TokenAmount::parse_input(&amount_val, t.decimals).ok())
+                .map(|a| a.to_string())
+                .unwrap_or_else(|| amount_val.clone());
             let from_clone = from_symbol.clone();
             let to_clone = to_symbol.clone();
             let chain_clone = chain.clone();
@@ -1236,23 +1728,35 @@ fn SwapTabContent(
             let mut cache_sig_for_spawn = cache;
 
             spawn(async move {
+                // 签名、广播期间离开页面需要确认；成功跳转前提前释放
+                let busy = navigation_guard::hold(BusyReason::Signing);
                 loading_sig_for_spawn.set(true);
                 err_sig_for_spawn.set(None);
 
                 let swap_service = SwapService::new(app_state_for_spawn);
                 match swap_service
-                    .execute(
+                    .execute_with_request_id(
                         &wallet_identifier,
                         &from_clone,
                         &to_clone,
                         &amount_clone,
                         &chain_clone,
                         Some(slippage_val),
+                        Some(idempotency_key),
                     )
                     .await
                 {
                     Ok(response) => {
                         log::info!("Swap执行成功: swap_id={}", response.swap_id);
+                        // 记录报价，确认后据此生成执行质量报告
+                        execution::record_quote(
+                            &response.swap_id,
+                            execution::QuoteSnapshot {
+                                expected_output: response.to_amount.clone(),
+                                slippage_pct: slippage_val,
+                                mev_protected: response.mev_protected,
+                            },
+                        );
 
                         // 企业级实现：记录费用信息（用于后续显示和审计）
                         // 注意：三种费用完全独立
@@ -1274,7 +1778,9 @@ fn SwapTabContent(
                         if let Some(tx_data) = &response.transaction {
                             // 获取钱包信息用于签名
                             if let Some(wallet) = wallet_opt_clone.as_ref() {
-                                if let Some(account) = wallet.accounts.first() {
+                                if let Some(account) = ChainType::from_str(&chain_clone)
+                                    .and_then(|c| account_for_chain(wallet, c))
+                                {
                                     // 企业级实现：获取链ID - 优先使用统一的网络配置函数，降级到ChainConfigManager
                                     let chain_id = match network_to_chain_id_helper(&chain_clone) {
                                         Some(id) => id,
@@ -1333,12 +1839,13 @@ fn SwapTabContent(
                                         None
                                     };
 
+                                    // 所有来源的估算值都加上安全余量（GasLimitService::estimate 内部已处理）
+                                    let gas_limit_service =
+                                        GasLimitService::new(app_state_for_spawn.clone());
                                     let gas_limit = match gas_limit {
-                                        Some(gl) => gl,
+                                        Some(gl) => gas_limit_service.with_margin(chain_id, gl),
                                         None => {
                                             // 从GasLimitService获取gas limit估算
-                                            let gas_limit_service =
-                                                GasLimitService::new(app_state_for_spawn.clone());
                                             match gas_limit_service
                                                 .estimate(
                                                     chain_id,
@@ -1351,45 +1858,47 @@ fn SwapTabContent(
                                             {
                                                 Ok(gl) => gl,
                                                 Err(e) => {
-                                                    log::warn!(
-                                                        "获取gas limit失败: {:?}，使用fallback值",
-                                                        e
-                                                    );
                                                     // 企业级实现：Fallback值（仅在无法获取时使用）
-                                                    get_fallback_gas_limit_swap()
+                                                    let fallback = get_fallback_gas_limit_swap();
+                                                    GasLimitService::record_fallback(
+                                                        chain_id,
+                                                        fallback,
+                                                        &format!("{:?}", e),
+                                                    );
+                                                    gas_limit_service
+                                                        .with_margin(chain_id, fallback)
                                                 }
                                             }
                                         }
                                     };
 
-                                    // 获取gas_price：优先使用1inch返回的，否则从GasService获取
-                                    let gas_price = if let Some(gp) = tx_data.gas_price.as_ref() {
-                                        parse_hex_u64(gp).ok()
-                                    } else {
-                                        None
-                                    };
-
-                                    let gas_price = match gas_price {
-                                        Some(gp) => gp,
+                                    // 获取gas_price：手动费用 > 用户所选档位 > 1inch返回值 > fallback
+                                    let gas_price = match manual_gas_val {
+                                        Some(manual) => {
+                                            // 将gwei转换为wei
+                                            (manual.max_fee_per_gas_gwei * 1e9) as u64
+                                        }
                                         None => {
-                                            // 从GasService获取当前gas price
                                             let gas_service =
                                                 GasService::new(app_state_for_spawn.clone());
                                             match gas_service
-                                                .estimate(&chain_clone, GasSpeed::Average)
+                                                .estimate(&chain_clone, gas_speed_val)
                                                 .await
                                             {
                                                 Ok(estimate) => {
-                                                    // 将gwei转换为wei
                                                     (estimate.max_fee_per_gas_gwei * 1e9) as u64
                                                 }
                                                 Err(e) => {
                                                     log::warn!(
-                                                        "获取gas price失败: {:?}，使用fallback值",
+                                                        "获取gas price失败: {:?}，尝试使用1inch返回值",
                                                         e
                                                     );
-                                                    // 企业级实现：Fallback值（仅在无法获取时使用）
-                                                    get_fallback_gas_price_wei()
+                                                    tx_data
+                                                        .gas_price
+                                                        .as_ref()
+                                                        .and_then(|gp| parse_hex_u64(gp).ok())
+                                                        // 企业级实现：Fallback值（仅在无法获取时使用）
+                                                        .unwrap_or_else(get_fallback_gas_price_wei)
                                                 }
                                             }
                                         }
@@ -1405,7 +1914,7 @@ fn SwapTabContent(
                                     let key_manager = match key_manager {
                                         Ok(km) => km,
                                         Err(e) => {
-                                            log::error!("获取KeyManager失败: {}", e);
+                                            log::error!(target: logging::SIGNING, "获取KeyManager失败: {}", e);
                                             err_sig_for_spawn.set(Some(e));
                                             loading_sig_for_spawn.set(false);
                                             return;
@@ -1418,29 +1927,40 @@ fn SwapTabContent(
                                         .iter()
                                         .position(|a| a.address == account.address)
                                         .unwrap_or_else(|| {
-                                            log::warn!("未找到匹配的账户地址，使用第一个账户");
+                                            log::warn!(target: logging::SIGNING, "未找到匹配的账户地址，使用第一个账户");
                                             0
                                         })
                                         as u32;
 
-                                    let private_key_hex =
-                                        match key_manager.derive_eth_private_key(account_index) {
-                                            Ok(key) => key,
-                                            Err(e) => {
-                                                log::error!("获取私钥失败: {:?}", e);
-                                                err_sig_for_spawn.set(Some(
-                                                    crate::shared::ui_error::sanitize_user_message(
-                                                        format!("获取私钥失败: {}", e),
-                                                    ),
-                                                ));
-                                                loading_sig_for_spawn.set(false);
-                                                return;
-                                            }
-                                        };
+                                    let private_key_hex = match chain_accounts::signing_key(
+                                        &key_manager,
+                                        account,
+                                        account_index,
+                                    ) {
+                                        Ok(key) => key,
+                                        Err(e) => {
+                                            log::error!(target: logging::SIGNING, "获取私钥失败: {:?}", e);
+                                            err_sig_for_spawn.set(Some(
+                                                crate::shared::ui_error::sanitize_user_message(
+                                                    format!("获取私钥失败: {}", e),
+                                                ),
+                                            ));
+                                            loading_sig_for_spawn.set(false);
+                                            return;
+                                        }
+                                    };
 
                                     // 签名swap交易（使用1inch返回的交易数据）
+                                    // 同一条进度提示：签名 → 广播 → 已广播/确认
+                                    let progress_key = format!("swap:{}", response.swap_id);
+                                    let toasts = app_state_for_spawn.toasts;
+                                    AppState::show_progress(
+                                        toasts,
+                                        &progress_key,
+                                        "正在签名交易…".to_string(),
+                                    );
                                     let signed_tx =
-                                        match EthereumTxSigner::sign_transaction_with_data(
+                                        match crate::crypto::worker::sign_eth_transaction_with_data(
                                             &private_key_hex,
                                             &tx_data.to,
                                             &tx_data.value,
@@ -1449,10 +1969,19 @@ fn SwapTabContent(
                                             gas_price,
                                             gas_limit,
                                             chain_id,
-                                        ) {
+                                        )
+                                        .await
+                                        {
                                             Ok(tx) => tx,
                                             Err(e) => {
-                                                log::error!("签名交易失败: {:?}", e);
+                                                log::error!(target: logging::SIGNING, "签名交易失败: {:?}", e);
+                                                AppState::finish_progress(
+                                                    toasts,
+                                                    &progress_key,
+                                                    ToastType::Error,
+                                                    format!("签名交易失败: {}", e),
+                                                    None,
+                                                );
                                                 err_sig_for_spawn.set(Some(
                                                     crate::shared::ui_error::sanitize_user_message(
                                                         format!("签名交易失败: {}", e),
@@ -1464,12 +1993,45 @@ fn SwapTabContent(
                                         };
 
                                     // 广播交易
+                                    AppState::show_progress(
+                                        toasts,
+                                        &progress_key,
+                                        "正在广播交易…".to_string(),
+                                    );
                                     match tx_service.broadcast(&chain_clone, &signed_tx).await {
                                         Ok(broadcast_response) => {
                                             log::info!(
                                                 "交易已广播: tx_hash={}",
                                                 broadcast_response.tx_hash
                                             );
+                                            AppState::finish_progress(
+                                                toasts,
+                                                &progress_key,
+                                                ToastType::Success,
+                                                "交易已广播，等待链上确认".to_string(),
+                                                Some(ToastAction::route(
+                                                    "查看记录",
+                                                    Route::Swap {},
+                                                )),
+                                            );
+
+                                            // 记录进行中的授权/兑换，表单据此提示并阻止重复提交
+                                            let mut pending_swaps =
+                                                app_state_for_spawn.pending_swaps;
+                                            pending_swaps.write().add(PendingSwapTx {
+                                                swap_id: response.swap_id.clone(),
+                                                kind: if response.needs_approval == Some(true) {
+                                                    PendingKind::Approval
+                                                } else {
+                                                    PendingKind::Swap
+                                                },
+                                                wallet_id: wallet_identifier.clone(),
+                                                network: chain_clone.clone(),
+                                                from_token: from_clone.clone(),
+                                                to_token: to_clone.clone(),
+                                                tx_hash: Some(broadcast_response.tx_hash.clone()),
+                                                created_at: crate::shared::time::now_unix(),
+                                            });
 
                                             // 企业级实现：更新swap_transactions表的状态和tx_hash
                                             let swap_id_clone = response.swap_id.clone();
@@ -1506,21 +2068,46 @@ fn SwapTabContent(
                                                 SwapService::new(app_state_for_spawn);
                                             let notif_handler_for_polling =
                                                 notif_handler_for_spawn.clone();
+                                            let progress_key_for_polling = progress_key.clone();
+                                            let chain_for_polling = chain_clone.clone();
+                                            let from_for_polling = from_clone.clone();
+                                            let amount_for_polling = amount_clone.clone();
 
                                             spawn(async move {
                                                 // 轮询交易确认状态（最多轮询60次，每次间隔5秒，总共5分钟）
                                                 let max_polls = 60;
-                                                let poll_interval_secs = 5;
-                                                let required_confirmations = 12; // 标准确认数
+                                                let poll_interval_ms = 5_000;
+                                                // 所需确认数：链默认值或用户自定义值，大额兑换按档位提高
+                                                let value_usd =
+                                                    match amount_for_polling.parse::<f64>() {
+                                                        Ok(amount_f64) => {
+                                                            PriceService::new(app_state_for_spawn)
+                                                                .get_price(&from_for_polling)
+                                                                .await
+                                                                .ok()
+                                                                .map(|price| amount_f64 * price.usd)
+                                                        }
+                                                        Err(_) => None,
+                                                    };
+                                                let required_confirmations =
+                                                    ChainConfigManager::new()
+                                                        .required_confirmations(
+                                                            ChainType::from_str(&chain_for_polling)
+                                                                .unwrap_or(ChainType::Ethereum),
+                                                            value_usd,
+                                                            &app_state_for_spawn
+                                                                .preferences
+                                                                .peek()
+                                                                .confirmation_overrides,
+                                                        );
 
                                                 for poll_count in 1..=max_polls {
-                                                    // 等待轮询间隔（企业级实现：使用gloo-timers，WASM兼容）
+                                                    // 等待轮询间隔（标签页隐藏时放慢，切回前台立即查询一次）
                                                     if poll_count > 1 {
-                                                        use gloo_timers::future::sleep;
-                                                        use std::time::Duration;
-                                                        sleep(Duration::from_secs(
-                                                            poll_interval_secs,
-                                                        ))
+                                                        scheduler::next_tick(
+                                                            TickPolicy::SlowWhenHidden,
+                                                            poll_interval_ms,
+                                                        )
                                                         .await;
                                                     }
 
@@ -1534,9 +2121,29 @@ fn SwapTabContent(
                                                                 swap_id_for_polling, status.status, status.confirmations);
 
                                                             // 如果状态已经是confirmed或failed，停止轮询
+                                                            if status.status == "confirmed"
+                                                                || status.status == "failed"
+                                                            {
+                                                                let mut pending_swaps =
+                                                                    app_state_for_spawn
+                                                                        .pending_swaps;
+                                                                pending_swaps
+                                                                    .write()
+                                                                    .resolve(&swap_id_for_polling);
+                                                            }
                                                             if status.status == "confirmed" {
                                                                 log::info!("Swap交易已确认: swap_id={}, confirmations={}", 
                                                                     swap_id_for_polling, status.confirmations);
+                                                                AppState::finish_progress(
+                                                                    toasts,
+                                                                    &progress_key_for_polling,
+                                                                    ToastType::Success,
+                                                                    "交换交易已确认".to_string(),
+                                                                    Some(ToastAction::route(
+                                                                        "查看",
+                                                                        Route::Swap {},
+                                                                    )),
+                                                                );
 
                                                                 // 发送成功通知
                                                                 if let Some(handler) =
@@ -1546,9 +2153,9 @@ fn SwapTabContent(
                                                                     handler.call((
                                                                         NotificationType::Success,
                                                                         "交换交易已确认".to_string(),
-                                                                        format!("交易哈希: {}\n确认数: {}", 
+                                                                        format!("交易哈希: {}\n确认进度: {}", 
                                                                             status.tx_hash.as_ref().unwrap_or(&"未知".to_string()), 
-                                                                            status.confirmations),
+                                                                            confirmation_progress(status.confirmations as u64, required_confirmations)),
                                                                         status.tx_hash.clone(),
                                                                     ));
                                                                 }
@@ -1558,6 +2165,16 @@ fn SwapTabContent(
                                                                     "Swap交易失败: swap_id={}",
                                                                     swap_id_for_polling
                                                                 );
+                                                                AppState::finish_progress(
+                                                                    toasts,
+                                                                    &progress_key_for_polling,
+                                                                    ToastType::Error,
+                                                                    "交换交易失败".to_string(),
+                                                                    Some(ToastAction::route(
+                                                                        "查看",
+                                                                        Route::Swap {},
+                                                                    )),
+                                                                );
 
                                                                 // 发送失败通知
                                                                 if let Some(handler) =
@@ -1610,7 +2227,7 @@ fn SwapTabContent(
                                                                             handler.call((
                                                                                 NotificationType::Success,
                                                                                 "交换交易已确认".to_string(),
-                                                                                format!("交易哈希: {}\n确认数: {}", tx_hash, status.confirmations),
+                                                                                format!("交易哈希: {}\n确认进度: {}", tx_hash, confirmation_progress(status.confirmations as u64, required_confirmations)),
                                                                                 Some(tx_hash.clone()),
                                                                             ));
                                                                         }
@@ -1621,6 +2238,18 @@ fn SwapTabContent(
                                                                 && status.status == "executing"
                                                             {
                                                                 // 更新确认数（即使未达到要求，但状态为executing时）
+                                                                AppState::show_progress(
+                                                                    toasts,
+                                                                    &progress_key_for_polling,
+                                                                    format!(
+                                                                        "等待链上确认（{}）",
+                                                                        confirmation_progress(
+                                                                            status.confirmations
+                                                                                as u64,
+                                                                            required_confirmations,
+                                                                        )
+                                                                    ),
+                                                                );
                                                                 if let Some(tx_hash) =
                                                                     &status.tx_hash
                                                                 {
@@ -1661,13 +2290,30 @@ fn SwapTabContent(
                                                         {
                                                             log::warn!("Swap交易轮询达到最大次数仍未确认: swap_id={}, 当前状态={}, 确认数={}", 
                                                                 swap_id_for_polling, final_status.status, final_status.confirmations);
+                                                            AppState::finish_progress(
+                                                                toasts,
+                                                                &progress_key_for_polling,
+                                                                ToastType::Info,
+                                                                format!(
+                                                                    "交易仍在确认中（{}）",
+                                                                    confirmation_progress(
+                                                                        final_status.confirmations
+                                                                            as u64,
+                                                                        required_confirmations,
+                                                                    )
+                                                                ),
+                                                                Some(ToastAction::route(
+                                                                    "查看",
+                                                                    Route::Swap {},
+                                                                )),
+                                                            );
                                                             if let Some(handler) =
                                                                 notif_handler_for_polling.as_ref()
                                                             {
                                                                 handler.call((
                                                                     NotificationType::Info,
                                                                     "交易确认中".to_string(),
-                                                                    format!("交易仍在确认中（当前确认数: {}），请稍后在历史记录中查看最新状态", final_status.confirmations),
+                                                                    format!("交易仍在确认中（{}），请稍后在历史记录中查看最新状态", confirmation_progress(final_status.confirmations as u64, required_confirmations)),
                                                                     Some(swap_id_for_polling.clone()),
                                                                 ));
                                                             }
@@ -1677,20 +2323,39 @@ fn SwapTabContent(
                                                         log::warn!("最后一次查询swap状态失败: swap_id={}, error={}", swap_id_for_polling, e);
                                                     }
                                                 }
+
+                                                // 兑换结束后释放占用的卖出代币
+                                                BalanceService::new(app_state_for_spawn)
+                                                    .refresh_committed()
+                                                    .await;
+                                            });
+
+                                            // 兑换已广播：卖出代币计为占用
+                                            spawn(async move {
+                                                BalanceService::new(app_state_for_spawn)
+                                                    .refresh_committed()
+                                                    .await;
                                             });
 
                                             // 清除相关缓存
-                                            let cache_key = CacheKey::quote(
-                                                &from_clone,
-                                                &to_clone,
-                                                &amount_clone,
+                                            let cache_prefix = format!(
+                                                "{}:",
+                                                CacheKey::quote(
+                                                    &from_clone,
+                                                    &to_clone,
+                                                    &amount_clone,
+                                                )
                                             );
-                                            cache_sig_for_spawn.write().remove(&cache_key);
+                                            cache_sig_for_spawn
+                                                .write()
+                                                .remove_by_prefix(&cache_prefix);
 
                                             // 清除余额相关缓存，触发自动刷新
                                             cache_sig_for_spawn
                                                 .write()
                                                 .remove_by_prefix("balance:");
+                                            BalanceService::new(app_state_for_spawn)
+                                                .invalidate_cache();
 
                                             // 记录成功日志
                                             error_logger_sig.write().log(
@@ -1741,10 +2406,18 @@ fn SwapTabContent(
                                             }
 
                                             loading_sig_for_spawn.set(false);
+                                            busy.release();
                                             nav_for_spawn.push(Route::Dashboard {});
                                         }
                                         Err(e) => {
                                             log::error!("广播交易失败: {:?}", e);
+                                            AppState::finish_progress(
+                                                toasts,
+                                                &progress_key,
+                                                ToastType::Error,
+                                                format!("广播交易失败: {}", e),
+                                                None,
+                                            );
 
                                             // 企业级实现：更新swap状态为失败
                                             let swap_id_clone = response.swap_id.clone();
@@ -1772,7 +2445,8 @@ fn SwapTabContent(
                                         }
                                     }
                                 } else {
-                                    err_sig_for_spawn.set(Some("钱包账户不存在".to_string()));
+                                    err_sig_for_spawn
+                                        .set(Some("当前钱包没有该链的账户，请先添加".to_string()));
                                     loading_sig_for_spawn.set(false);
                                 }
                             } else {
@@ -1782,11 +2456,15 @@ fn SwapTabContent(
                         } else {
                             // 如果没有交易数据，说明后端已经处理了交易
                             // 清除相关缓存
-                            let cache_key = CacheKey::quote(&from_clone, &to_clone, &amount_clone);
-                            cache_sig_for_spawn.write().remove(&cache_key);
+                            let cache_prefix = format!(
+                                "{}:",
+                                CacheKey::quote(&from_clone, &to_clone, &amount_clone)
+                            );
+                            cache_sig_for_spawn.write().remove_by_prefix(&cache_prefix);
 
                             // 清除余额相关缓存
                             cache_sig_for_spawn.write().remove_by_prefix("balance:");
+                            BalanceService::new(app_state_for_spawn).invalidate_cache();
 
                             // 记录成功日志
                             error_logger_sig.write().log(
@@ -1827,8 +2505,9 @@ fn SwapTabContent(
                                 ));
                             }
 
-                            nav_for_spawn.push(Route::Dashboard {});
                             loading_sig_for_spawn.set(false);
+                            busy.release();
+                            nav_for_spawn.push(Route::Dashboard {});
                         }
                     }
                     Err(e) => {
@@ -1870,10 +2549,27 @@ fn SwapTabContent(
         }
     };
 
+    let quote_is_stale = quote.read().is_none() && quote_loading();
+    let displayed_quote = if quote_is_stale {
+        last_quote.read().clone()
+    } else {
+        quote.read().clone()
+    };
+
     rsx! {
         div {
             class: "space-y-4",
 
+            // 支付代币有未确认的授权/兑换时提示
+            PendingSwapBanner {
+                wallet_id: current_wallet.read().as_ref().map(|w| w.id.clone()),
+                network: selected_chain.read().clone(),
+                from_token: from_token.read().as_ref().map(|t| t.symbol.clone()),
+                duplicate: duplicate_pending(),
+                allow_duplicate,
+                on_view: move |_| on_view_history.call(()),
+            }
+
             // 交换表单卡片
             div {
                 class: "p-6 rounded-lg",
@@ -1896,27 +2592,28 @@ fn SwapTabContent(
                         TokenSelector {
                             chain: *chain_type.read(),
                             selected_token: from_token,
-                            wallet_address: {
-                                let wallet_opt = current_wallet.read();
-                                wallet_opt.as_ref().and_then(|w| {
-                                    let target = *chain_type.read();
-                                    // 尝试匹配链
-                                    let matched = w.accounts.iter()
-                                        .find(|acc| {
-                                            let acc_chain = match acc.chain.to_lowercase().as_str() {
-                                                "ethereum" => ChainType::Ethereum,
-                                                "bitcoin" => ChainType::Bitcoin,
-                                                "solana" => ChainType::Solana,
-                                                "ton" => ChainType::TON,
-                                                _ => ChainType::Ethereum,
-                                            };
-                                            acc_chain == target
-                                        })
-                                        .map(|a| a.address.clone());
-                                    // Fallback到第一个账户
-                                    matched.or_else(|| w.accounts.first().map(|a| a.address.clone()))
-                                })
-                            },
+                            wallet_address: current_wallet
+                                .read()
+                                .as_ref()
+                                .and_then(|w| account_for_chain(w, *chain_type.read()))
+                                .map(|a| a.address.clone()),
+                        }
+                        // 钱包缺少该链账户时提示添加，不用其他链的地址查询余额
+                        ChainAccountPrompt {
+                            chain: *chain_type.read(),
+                            token_symbol: from_token.read().as_ref().map(|t| t.symbol.clone()),
+                        }
+                    }
+
+                    // 多链代币（如 USDT）：允许覆盖自动识别的网络
+                    if let Some(token) = from_token.read().as_ref().filter(|t| token_chains(&t.symbol).len() > 1) {
+                        ChainSelector {
+                            selected_chain,
+                            allowed_chains: Some(token_chains(&token.symbol).to_vec()),
+                            feature: ChainFeature::Swap,
+                            label: Some(format!("{} 所在网络", token.symbol)),
+                            hint: Some("默认使用自动识别的网络".to_string()),
+                            onselect: switch_token_chain,
                         }
                     }
 
@@ -1937,103 +2634,12 @@ fn SwapTabContent(
                     }
 
                     // 数量输入
-                    div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {crate::i18n::translations::get_text("swap.amount_label", &app_state.language.read())}
-                        }
-                        div {
-                            class: "flex gap-2",
-                            input {
-                                class: "flex-1 p-3 rounded-lg",
-                                style: format!("background: {}; border: 1px solid {}; color: {};",
-                                    Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
-                                r#type: "number",
-                                value: "{amount.read()}",
-                                oninput: move |e| {
-                                    amount.set(e.value());
-                                    quote.set(None);
-                                },
-                                placeholder: "0.0",
-                                step: "0.000001"
-                            }
-                            // 快速金额选择按钮
-                            if let Some(token) = from_token.read().as_ref() {
-                                div {
-                                    class: "flex gap-1 mt-2",
-                                    button {
-                                        class: "px-3 py-1 text-xs rounded",
-                                        style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
-                                        onclick: {
-                                            let amount_sig = amount;
-                                            let app_state_clone = app_state.clone();
-                                            let token_clone = token.clone();
-                                            let wallet_opt = current_wallet.read().clone();
-                                            let chain_type_val = *chain_type.read();
-
-                                            move |_| {
-                                                if let Some(wallet) = wallet_opt.as_ref() {
-                                                    if let Some(account) = wallet.accounts.first() {
-                                                        let token_service = TokenService::new(app_state_clone.clone());
-                                                        let wallet_addr = account.address.clone();
-                                                        let token_addr = token_clone.address.clone();
-
-                                                        let mut amount_sig_for_spawn = amount_sig;
-                                                        spawn(async move {
-                                                            if let Ok(balance) = token_service.get_token_balance(
-                                                                chain_type_val,
-                                                                &token_addr,
-                                                                &wallet_addr
-                                                            ).await {
-                                                                let use_amount = balance.balance_formatted * 0.5;
-                                                                amount_sig_for_spawn.set(format!("{:.6}", use_amount));
-                                                                // use_effect会自动触发报价获取
-                                                            }
-                                                        });
-                                                    }
-                                                }
-                                            }
-                                        },
-                                        "50%"
-                                    }
-                                    button {
-                                        class: "px-3 py-1 text-xs rounded",
-                                        style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
-                                        onclick: {
-                                            let amount_sig = amount;
-                                            let app_state_clone = app_state.clone();
-                                            let token_clone = token.clone();
-                                            let wallet_opt = current_wallet.read().clone();
-                                            let chain_type_val = *chain_type.read();
-
-                                            move |_| {
-                                                if let Some(wallet) = wallet_opt.as_ref() {
-                                                    if let Some(account) = wallet.accounts.first() {
-                                                        let token_service = TokenService::new(app_state_clone.clone());
-                                                        let wallet_addr = account.address.clone();
-                                                        let token_addr = token_clone.address.clone();
-
-                                                        let mut amount_sig_for_spawn2 = amount_sig;
-                                                        spawn(async move {
-                                                            if let Ok(balance) = token_service.get_token_balance(
-                                                                chain_type_val,
-                                                                &token_addr,
-                                                                &wallet_addr
-                                                            ).await {
-                                                                amount_sig_for_spawn2.set(format!("{:.6}", balance.balance_formatted));
-                                                                // use_effect会自动触发报价获取
-                                                            }
-                                                        });
-                                                    }
-                                                }
-                                            }
-                                        },
-                                        "最大"
-                                    }
-                                }
-                            }
-                        }
+                    AmountInput {
+                        value: amount,
+                        token: from_token,
+                        account: from_account,
+                        label: crate::i18n::translations::get_text("swap.amount_label", &app_state.language.read()),
+                        oninput: move |_| quote.set(None),
                     }
 
                     // 滑点设置
@@ -2059,6 +2665,31 @@ fn SwapTabContent(
                             max: "5.0"
                         }
                     }
+
+                    // 高级设置：本次兑换的路由偏好
+                    div {
+                        button {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            aria_expanded: "{show_advanced()}",
+                            onclick: move |_| show_advanced.set(!show_advanced()),
+                            if show_advanced() { "▾ 高级设置" } else { "▸ 高级设置" }
+                        }
+                        if show_advanced() {
+                            div {
+                                class: "mt-2 space-y-2",
+                                div {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    "路由偏好（仅本次兑换，默认值可在设置中修改）"
+                                }
+                                RoutePreferenceSelector {
+                                    selected: route_preference(),
+                                    onselect: move |preference| route_preference.set(preference),
+                                }
+                            }
+                        }
+                    }
                 }
             }
 
@@ -2120,25 +2751,31 @@ fn SwapTabContent(
                 }
             }
 
-            // 报价显示
-            if quote_loading() {
+            // 报价显示：刷新时保留上一次报价并变暗，首次加载显示骨架
+            if let Some(q) = displayed_quote.as_ref() {
                 div {
-                    class: "p-6 rounded-lg",
-                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    class: "p-6 rounded-lg transition-opacity",
+                    style: format!(
+                        "background: {}; border: 1px solid {}; opacity: {};",
+                        Colors::BG_SECONDARY,
+                        Colors::BORDER_PRIMARY,
+                        if quote_is_stale { "0.5" } else { "1" }
+                    ),
+                    aria_busy: "{quote_is_stale}",
                     div {
-                        class: "text-center",
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "正在获取报价..."
-                    }
-                }
-            } else if let Some(q) = quote.read().as_ref() {
-                div {
-                    class: "p-6 rounded-lg",
-                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                    h3 {
-                        class: "text-lg font-semibold mb-4",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        "💱 交换详情"
+                        class: "flex items-center justify-between mb-4",
+                        h3 {
+                            class: "text-lg font-semibold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "💱 交换详情"
+                        }
+                        if quote_is_stale {
+                            span {
+                                class: "text-xs",
+                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                "正在更新报价..."
+                            }
+                        }
                     }
                     // 价格变化提示（价格变化提示功能）
                     if let Some(change_info) = price_change.read().clone() {
@@ -2154,12 +2791,16 @@ fn SwapTabContent(
                     }
                     div {
                         class: "space-y-2",
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), {crate::i18n::translations::get_text("swap.rate", &app_state.language.read())} }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "1 {q.from_token} = {q.to_amount.parse::<f64>().unwrap_or(0.0) / q.from_amount.parse::<f64>().unwrap_or(1.0):.6} {q.to_token}"
+                        StaleData {
+                            fetched_at: quote_fetched_at(),
+                            on_refresh: move |_| refresh_quote(),
+                            div {
+                                class: "flex justify-between",
+                                span { style: format!("color: {};", Colors::TEXT_SECONDARY), {crate::i18n::translations::get_text("swap.rate", &app_state.language.read())} }
+                                span {
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "1 {q.from_token} = {q.to_amount.parse::<f64>().unwrap_or(0.0) / q.from_amount.parse::<f64>().unwrap_or(1.0):.6} {q.to_token}"
+                                }
                             }
                         }
                         div {
@@ -2175,7 +2816,7 @@ fn SwapTabContent(
                             }
                         }
                         // ✅ 平台服务费显示
-                        if let Some(fee) = platform_fee.read().clone() {
+                        if let Some(fee) = platform_fee() {
                             div {
                                 class: "flex justify-between",
                                 span {
@@ -2184,7 +2825,7 @@ fn SwapTabContent(
                                 }
                                 span {
                                     style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    {format!("{:.6} ETH", fee)}
+                                    {fee.label()}
                                 }
                             }
                         }
@@ -2193,8 +2834,122 @@ fn SwapTabContent(
                             span { style: format!("color: {};", Colors::TEXT_SECONDARY), {crate::i18n::translations::get_text("swap.slippage", &app_state.language.read())} }
                             span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{slippage.read():.1}%" }
                         }
+                        // 路由偏好：显示当前报价对应的偏好，点选其他偏好即时对比（已缓存的直接显示预计收到数量）
+                        div {
+                            class: "pt-2 space-y-2",
+                            div {
+                                class: "flex justify-between",
+                                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "路由偏好" }
+                                span {
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    {q.route_preference.unwrap_or_default().label()}
+                                }
+                            }
+                            div {
+                                class: "grid grid-cols-3 gap-2",
+                                for preference in RoutePreference::ALL {
+                                    {
+                                        let cached_amount = from_token
+                                            .read()
+                                            .as_ref()
+                                            .zip(to_token.read().as_ref())
+                                            .and_then(|(from, to)| {
+                                                cache.read().get::<SwapQuoteResponse>(&CacheKey::routed_quote(
+                                                    &from.symbol,
+                                                    &to.symbol,
+                                                    &amount.read(),
+                                                    preference.as_str(),
+                                                ))
+                                            })
+                                            .map(|cached| cached.to_amount);
+                                        let active = q.route_preference.unwrap_or_default() == preference;
+                                        rsx! {
+                                            button {
+                                                key: "{preference.as_str()}",
+                                                class: "p-2 rounded-lg text-xs text-left",
+                                                style: format!(
+                                                    "border: 1px solid {}; color: {};",
+                                                    if active { Colors::TECH_PRIMARY } else { Colors::BORDER_PRIMARY },
+                                                    Colors::TEXT_PRIMARY
+                                                ),
+                                                disabled: active,
+                                                onclick: move |_| route_preference.set(preference),
+                                                div { class: "font-medium", {preference.label()} }
+                                                div {
+                                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                    {cached_amount.map(|a| format!("{} {}", a, q.to_token)).unwrap_or_else(|| "点击比较".to_string())}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
+            } else if quote_loading() {
+                SkeletonCard { lines: 5 }
+                SlowRequestHint {
+                    path: "/api/v1/swap/quote",
+                    message: "报价比平时慢，仍在寻找最优路线…",
+                }
+            }
+
+            // 交易规模建议与拆分兑换进度
+            if split_plan.read().is_some() {
+                SplitSwapTracker { plan: split_plan }
+            } else if let Some(suggestion) = size_suggestion.read().clone() {
+                TradeSizeChip {
+                    suggestion,
+                    from_symbol: from_token.read().as_ref().map(|t| t.symbol.clone()).unwrap_or_default(),
+                    on_split: move |_| {
+                        let Some(suggestion) = size_suggestion.peek().clone() else {
+                            return;
+                        };
+                        let wallet_id = app_state.wallet.read().selected_wallet_id.clone();
+                        let (Some(wallet_id), Some(from), Some(to)) = (
+                            wallet_id,
+                            from_token.peek().clone(),
+                            to_token.peek().clone(),
+                        ) else {
+                            let mut err = error_message;
+                            err.set(Some("请先选择钱包".to_string()));
+                            return;
+                        };
+                        split_plan.set(Some(SplitPlan::new(
+                            &wallet_id,
+                            &selected_chain.peek(),
+                            &from.symbol,
+                            &to.symbol,
+                            &suggestion.legs,
+                        )));
+                    },
+                    on_reduce: move |max: String| amount.set(max),
+                }
+            }
+
+            // 包装/解包说明（不经过聚合器）
+            if let Some(direction) = wrap_direction() {
+                div {
+                    class: "p-3 rounded-lg text-sm",
+                    style: format!("background: rgba(99, 102, 241, 0.1); border: 1px solid {}; color: {};", Colors::TECH_PRIMARY, Colors::TEXT_SECONDARY),
+                    {format!("{}：按 1:1 直接调用包装合约，不收兑换手续费，只需支付网络费", direction.label())}
+                }
+            }
+
+            // Gas档位与费用（法币换算）
+            if quote.read().is_some() {
+                GasFeeCard {
+                    gas_estimate: gas_options.read().as_ref().map(|all| all.average.clone()),
+                    platform_fee: platform_fee().and_then(|f| f.fee()),
+                    platform_fee_at_execution: platform_fee().is_some_and(|f| f.at_execution()),
+                    is_loading: gas_options_loading(),
+                    chain: Some(selected_chain.read().clone()),
+                    gas_options: gas_options.read().clone(),
+                    selected_speed: Some(gas_speed),
+                    manual_fee: Some(manual_gas),
+                    gas_limit: get_fallback_gas_limit_swap(),
+                }
             }
 
             // 错误消息
@@ -2210,7 +2965,8 @@ fn SwapTabContent(
                     let mut show_confirm_handler = show_confirm_handler;
                     move |_| show_confirm_handler()
                 },
-                disabled: *is_loading.read() || quote.read().is_none() || from_token.read().is_none() || to_token.read().is_none(),
+                disabled: *is_loading.read() || quote.read().is_none() || from_token.read().is_none() || to_token.read().is_none()
+                    || (duplicate_pending() && !allow_duplicate()),
                 loading: *is_loading.read(),
                 class: "w-full",
                 if *is_loading.read() {
@@ -2224,9 +2980,50 @@ fn SwapTabContent(
             SwapConfirmDialog {
                 show: show_confirm_dialog,
                 confirm_info: confirm_info.read().clone(),
+                rechecking: confirm_rechecking(),
+                recheck_error: confirm_recheck_error.read().clone(),
                 on_confirm: Some(EventHandler::new({
                     let mut execute_swap_actual = execute_swap_actual;
-                    move |_| execute_swap_actual()
+                    move |_| {
+                        if *confirm_rechecking.peek() {
+                            return;
+                        }
+                        // 支付代币或金额无效时由执行流程给出错误
+                        let Some(from) = from_token.peek().clone() else {
+                            execute_swap_actual();
+                            return;
+                        };
+                        let Ok(amount_parsed) = TokenAmount::parse_input(&amount.peek(), from.decimals) else {
+                            execute_swap_actual();
+                            return;
+                        };
+                        let wallet = current_wallet.peek().clone();
+                        let chain = selected_chain.peek().clone();
+                        let speed = *gas_speed.peek();
+                        let manual = *manual_gas.peek();
+                        let units = confirm_recheck::swap_gas_units(
+                            quote.peek().as_ref().and_then(|q| q.estimated_gas.as_deref()),
+                        );
+                        confirm_rechecking.set(true);
+                        confirm_recheck_error.set(None);
+                        spawn(async move {
+                            let gwei = current_gas_gwei(app_state, &chain, speed, manual).await;
+                            let result = recheck_before_execute(
+                                app_state,
+                                wallet,
+                                from,
+                                amount_parsed,
+                                &chain,
+                                gwei.map(|g| confirm_recheck::gas_cost(g, units)),
+                            )
+                            .await;
+                            confirm_rechecking.set(false);
+                            match result {
+                                Ok(()) => execute_swap_actual(),
+                                Err(message) => confirm_recheck_error.set(Some(message)),
+                            }
+                        });
+                    }
                 })),
                 on_cancel: Some(EventHandler::new({
                     let mut show_confirm_dialog = show_confirm_dialog;
@@ -2257,9 +3054,16 @@ fn SwapTabContent(
 // 功能: 法币入金,集成MoonPay/Simplex/Transak等支付提供商
 // =============================================================================
 
+/// 法币报价锁定时长（秒）
+const QUOTE_LOCK_SECS: u64 = 30;
+
 /// 购买稳定币标签页
 #[component]
-fn BuyStablecoinTab() -> Element {
+fn BuyStablecoinTab(
+    region: Signal<Option<PaymentMethodMatrix>>,
+    /// 手续费不足时预填的原生币购买参数（消费后清空）
+    prefill: Signal<Option<BuyPrefill>>,
+) -> Element {
     let app_state = use_context::<AppState>();
 
     // 缓存和错误日志服务
@@ -2267,14 +3071,61 @@ fn BuyStablecoinTab() -> Element {
     let error_logger = use_signal(|| ErrorLogger::new(100));
 
     let mut selected_stablecoin = use_signal(|| "USDT".to_string());
-    let mut amount = use_signal(|| String::new());
+    let mut amount_field = use_validated_field(|| {
+        vec![
+            Validator::required("请输入购买金额"),
+            Validator::amount(2),
+            Validator::range(2, Some("10"), None).with_message("购买金额至少为 $10"),
+            Validator::range(2, None, Some("1000000000000000"))
+                .with_message("金额过大，请输入有效金额"),
+        ]
+    });
+    let mut amount = amount_field.value;
     let mut payment_method = use_signal(|| "credit_card".to_string());
+
+    // 消费预填参数：购买少量原生币用于支付手续费
+    use_effect(move || {
+        let Some(p) = prefill.read().clone() else {
+            return;
+        };
+        let mut prefill = prefill;
+        selected_stablecoin.set(p.token);
+        amount.set(p.amount_usd);
+        prefill.set(None);
+    });
+
+    // 地区确定后，若当前支付方式不可用则切换到第一个可用方式
+    use_effect(move || {
+        if let Some(matrix) = region.read().as_ref() {
+            if !matrix
+                .buy_availability(&payment_method.peek())
+                .is_available()
+            {
+                if let Some(first) = matrix.buy_methods.first() {
+                    payment_method.set(first.clone());
+                }
+            }
+        }
+    });
     let error_message = use_signal(|| Option::<String>::None);
     let loading = use_signal(|| false);
     let quote_loading = use_signal(|| false);
     let mut quote = use_signal(|| Option::<FiatQuoteResponse>::None);
-    let mut quote_lock_start = use_signal(|| Option::<u64>::None);
-    let platform_fee = use_signal(|| Option::<f64>::None); // ✅ 平台服务费
+    let mut quote_lock_start = use_signal(|| Option::<Countdown>::None);
+    let platform_fee = use_signal(|| Option::<PlatformFeeEstimate>::None); // ✅ 平台服务费
+                                                                           // 报价过期后手动重新获取
+    let mut quote_refresh = use_signal(|| 0u32);
+
+    // 防重复提交：报价就绪（进入确认阶段）时生成幂等键；近期相同订单需二次确认
+    let mut submit_key = use_signal(|| Option::<String>::None);
+    let mut duplicate_warning = use_signal(|| Option::<String>::None);
+    let mut allow_duplicate = use_signal(|| false);
+    use_effect(move || {
+        let has_quote = quote.read().is_some();
+        submit_key.set(has_quote.then(submission_guard::new_idempotency_key));
+        duplicate_warning.set(None);
+        allow_duplicate.set(false);
+    });
 
     // 用户反馈状态
     let show_feedback = use_signal(|| false);
@@ -2301,26 +3152,132 @@ fn BuyStablecoinTab() -> Element {
     let card_cvv = use_signal(|| String::new());
     let card_holder_name = use_signal(|| String::new());
     let payment_processing = use_signal(|| false);
+    // 选中的已保存银行卡（None 表示使用新卡）与支付成功后待保存的新卡令牌
+    let selected_card = use_signal(|| None::<String>);
+    let card_save_offer = use_signal(|| None::<CardToken>);
     let _kyc_verification_info = use_signal(|| Option::<KycVerificationInfo>::None);
 
-    // 服务商状态列表（从后端API获取）
-    // API: GET /api/providers (已实现)
-    let provider_status_list = use_signal(|| Vec::<ProviderStatusInfo>::new());
-
-    // 常用金额快速选择
-    let quick_amounts = vec!["100", "500", "1000"];
-
-    // 获取当前钱包地址
-    let current_wallet = use_memo(move || {
-        let wallet_state = app_state.wallet.read();
-        wallet_state.get_selected_wallet().cloned()
-    });
-
-    // 自动获取报价（当金额、稳定币或支付方式改变时）
-    use_effect({
-        let app_state_clone = app_state.clone();
-        let amount_sig = amount;
-        let stablecoin_sig = selected_stablecoin;
+    // 已过期的未支付订单（可按原参数重新下单）
+    let mut expired_session = use_signal(|| None::<onramp_orders::PaymentSession>);
+
+    // 恢复未完成的支付：订单列表中点击“继续支付”，或回到本标签时仍有未完成的支付会话。
+    // 先查询订单当前状态，再决定重新打开支付弹窗、继续跟踪处理结果还是提示已过期
+    use_effect(move || {
+        let requested = onramp_orders::take_resume_request();
+        let saved = onramp_orders::active_session();
+        let Some(order_id) = requested
+            .clone()
+            .or_else(|| saved.as_ref().map(|s| s.order_id.clone()))
+        else {
+            return;
+        };
+        let mut show_modal_sig = show_payment_modal;
+        let mut order_id_sig = payment_order_id;
+        let mut amount_sig = payment_amount;
+        let mut currency_sig = payment_currency;
+        let mut processing_sig = payment_processing;
+        let toasts = app_state.toasts;
+        spawn(async move {
+            let status = match FiatOnrampService::new(app_state)
+                .get_order_status(&order_id)
+                .await
+            {
+                Ok(status) => status,
+                Err(e) => {
+                    // 自动恢复失败时不打扰用户，下次进入时再试
+                    if requested.is_some() {
+                        AppState::show_error(toasts, format!("查询订单状态失败: {}", e));
+                    }
+                    return;
+                }
+            };
+            let previous = saved.filter(|s| s.order_id == order_id);
+            let session = onramp_orders::session_from_status(&status, previous.as_ref());
+            let stage = onramp_orders::resume_stage(
+                &status.status,
+                session.expires_at,
+                time::now_unix() as i64,
+            );
+            match stage {
+                onramp_orders::ResumeStage::AwaitingPayment
+                | onramp_orders::ResumeStage::Processing => {
+                    onramp_orders::save_session(&session);
+                    order_id_sig.set(session.order_id.clone());
+                    amount_sig.set(session.fiat_amount.clone());
+                    currency_sig.set(session.fiat_currency.clone());
+                    payment_method.set(session.payment_method.clone());
+                    selected_stablecoin.set(session.stablecoin.clone());
+                    processing_sig.set(stage == onramp_orders::ResumeStage::Processing);
+                    show_modal_sig.set(true);
+                    if stage == onramp_orders::ResumeStage::AwaitingPayment {
+                        AppState::show_info(
+                            toasts,
+                            format!("已恢复订单 {} 的支付", session.order_id),
+                        );
+                        return;
+                    }
+                    // 支付已提交、服务商处理中：等待结果后关闭弹窗
+                    match onramp_orders::wait_until_settled(app_state, &session.order_id).await {
+                        Ok(final_status) if final_status == "completed" => {
+                            AppState::show_success(
+                                toasts,
+                                format!("订单 {} 已完成", session.order_id),
+                            );
+                            show_modal_sig.set(false);
+                        }
+                        Ok(final_status) if final_status == "processing" => {
+                            AppState::show_info(
+                                toasts,
+                                "订单仍在处理中，可稍后在订单页查看结果".to_string(),
+                            );
+                            show_modal_sig.set(false);
+                        }
+                        Ok(_) => {
+                            AppState::show_error(
+                                toasts,
+                                format!("订单 {} 支付未成功", session.order_id),
+                            );
+                            show_modal_sig.set(false);
+                        }
+                        Err(e) => AppState::show_error(toasts, e),
+                    }
+                    processing_sig.set(false);
+                }
+                onramp_orders::ResumeStage::Expired => {
+                    onramp_orders::clear_session(&session.order_id);
+                    expired_session.set(Some(session));
+                }
+                onramp_orders::ResumeStage::Closed => {
+                    onramp_orders::clear_session(&session.order_id);
+                    if requested.is_some() {
+                        AppState::show_info(
+                            toasts,
+                            format!("订单 {} 已结束，无需继续支付", session.order_id),
+                        );
+                    }
+                }
+            }
+        });
+    });
+
+    // 服务商状态列表（从后端API获取）
+    // API: GET /api/providers (已实现)
+    let provider_status_list = use_signal(|| Vec::<ProviderStatusInfo>::new());
+
+    // 常用金额快速选择
+    let quick_amounts = vec!["100", "500", "1000"];
+
+    // 获取当前钱包地址
+    let current_wallet = use_memo(move || {
+        let wallet_state = app_state.wallet.read();
+        wallet_state.get_selected_wallet().cloned()
+    });
+
+    // 自动获取报价（当金额、稳定币或支付方式改变时）
+    use_effect({
+        let app_state_clone = app_state.clone();
+        let amount_sig = amount;
+        let stablecoin_sig = selected_stablecoin;
         let payment_sig = payment_method;
         let mut quote_sig = quote;
         let quote_load_sig = quote_loading;
@@ -2330,8 +3287,9 @@ fn BuyStablecoinTab() -> Element {
             let amount_val = amount_sig.read().clone();
             let stablecoin_val = stablecoin_sig.read().clone();
             let payment_val = payment_sig.read().clone();
+            let _ = quote_refresh();
 
-            if amount_val.is_empty() || amount_val.parse::<f64>().unwrap_or(0.0) < 10.0 {
+            if !amount_field.is_valid() {
                 quote_sig.set(None);
                 return;
             }
@@ -2384,41 +3342,17 @@ fn BuyStablecoinTab() -> Element {
                             .write()
                             .set(cache_key, q.clone(), Some(Duration::from_secs(30)));
                         quote_sig_for_spawn.set(Some(q.clone()));
-                        // 记录报价锁定开始时间（30秒有效期）
-                        let now = js_sys::Date::now() as u64 / 1000;
-                        quote_lock_start_sig.set(Some(now));
-
-                        // ✅ 计算平台服务费（Fiat Onramp操作，金额已是美元）
-                        if let Ok(amount_f64) = amount_clone.parse::<f64>() {
-                            if amount_f64 > 0.0 {
-                                let fee_service = FeeService::new(app_state_for_spawn.clone());
-                                let mut platform_fee_sig = platform_fee;
-                                spawn(async move {
-                                    // 法币入金的amount已经是美元金额，直接使用
-                                    match fee_service
-                                        .calculate(
-                                            "ethereum",    // 默认以太坊链
-                                            "fiat_onramp", // 法币入金操作
-                                            amount_f64,    // 金额已是美元价值
-                                        )
-                                        .await
-                                    {
-                                        Ok(fee_quote) => {
-                                            platform_fee_sig.set(Some(fee_quote.platform_fee));
-                                            log::info!(
-                                                "平台服务费(FiatOnramp): ${:.2} (规则ID: {})",
-                                                fee_quote.platform_fee,
-                                                fee_quote.applied_rule_id
-                                            );
-                                        }
-                                        Err(e) => {
-                                            log::error!("计算平台服务费失败: {}", e);
-                                            platform_fee_sig.set(None);
-                                        }
-                                    }
-                                });
-                            }
-                        }
+                        // 报价锁定 30 秒，开始倒计时
+                        quote_lock_start_sig.set(Some(Countdown::start(QUOTE_LOCK_SECS)));
+
+                        // ✅ 计算平台服务费（Fiat Onramp操作，金额已是美元，默认以太坊链）
+                        refresh_platform_fee(
+                            app_state_for_spawn,
+                            platform_fee,
+                            "ethereum".to_string(),
+                            "fiat_onramp",
+                            amount_clone.parse::<f64>().ok().map(FeeBasis::Usd),
+                        );
                     }
                     Err(e) => {
                         // 企业级：根据错误类型提供友好提示
@@ -2482,6 +3416,10 @@ fn BuyStablecoinTab() -> Element {
         let mut feedback_message_sig = feedback_message;
 
         move || {
+            // 上一次提交尚未返回时直接忽略
+            if *loading_sig.peek() {
+                return;
+            }
             let amount_val = amount_sig.read().clone();
             let stablecoin_val = stablecoin_sig.read().clone();
             let payment_val = payment_sig.read().clone();
@@ -2511,33 +3449,32 @@ fn BuyStablecoinTab() -> Element {
             }
 
             // 企业级输入验证
-            let _amount_parsed = match amount_val.parse::<f64>() {
-                Ok(v) => {
-                    if v.is_nan() || v.is_infinite() || v <= 0.0 {
-                        err_sig.set(Some("请输入有效的购买金额（必须大于0）".to_string()));
-                        return;
-                    }
-                    if v < 10.0 {
-                        err_sig.set(Some("购买金额至少为 $10".to_string()));
-                        return;
-                    }
-                    if v > 1e15 {
-                        err_sig.set(Some("金额过大，请输入有效金额".to_string()));
-                        return;
-                    }
-                    v
-                }
-                Err(_) => {
-                    err_sig.set(Some("请输入有效的购买金额".to_string()));
-                    return;
-                }
-            };
+            if let Some(e) = amount_field.error() {
+                err_sig.set(Some(e));
+                return;
+            }
 
             // 金额验证通过，继续处理
             let wallet_address = wallet_opt
                 .as_ref()
                 .and_then(|w| w.accounts.first().map(|a| a.address.clone()));
 
+            // 两分钟内创建过相同订单时先提示确认
+            let fingerprint =
+                OrderFingerprint::new("onramp", &stablecoin_val, &amount_val, &payment_val);
+            if !*allow_duplicate.peek() {
+                if let Some(elapsed) = submission_guard::recent_duplicate(&fingerprint) {
+                    duplicate_warning.set(Some(submission_guard::duplicate_warning(elapsed)));
+                    return;
+                }
+            }
+            duplicate_warning.set(None);
+            allow_duplicate.set(false);
+            let idempotency_key = submit_key
+                .peek()
+                .clone()
+                .unwrap_or_else(submission_guard::new_idempotency_key);
+
             let amount_clone = amount_val.clone();
             let stablecoin_clone = stablecoin_val.clone();
             let payment_clone = payment_val.clone();
@@ -2546,8 +3483,9 @@ fn BuyStablecoinTab() -> Element {
             let mut loading_sig_for_spawn = loading_sig;
             let mut err_sig_for_spawn = err_sig;
 
+            // 在发起请求前同步置为加载中，避免重新渲染前的重复点击
+            loading_sig_for_spawn.set(true);
             spawn(async move {
-                loading_sig_for_spawn.set(true);
                 err_sig_for_spawn.set(None);
 
                 let fiat_service = FiatOnrampService::new(app_state_for_spawn);
@@ -2559,11 +3497,29 @@ fn BuyStablecoinTab() -> Element {
                         &payment_clone,
                         &quote_id_clone,
                         wallet_address.as_deref(),
+                        Some(&idempotency_key),
                     )
                     .await
                 {
                     Ok(order) => {
-                        tracing::info!("[Swap/Buy] 订单创建成功: order_id={}", order.order_id);
+                        submission_guard::record_order(fingerprint);
+                        // 下一笔订单使用新的幂等键
+                        submit_key.set(Some(submission_guard::new_idempotency_key()));
+                        // 记录支付时限，超时未支付时提醒
+                        onramp_orders::record(&order);
+                        // 保存支付会话：关闭弹窗或离开页面后可继续支付
+                        onramp_orders::save_session(&onramp_orders::PaymentSession {
+                            order_id: order.order_id.clone(),
+                            fiat_amount: order.fiat_amount.clone(),
+                            fiat_currency: "USD".to_string(),
+                            stablecoin: stablecoin_clone.clone(),
+                            payment_method: payment_clone.clone(),
+                            expires_at: onramp_orders::payment_deadline(
+                                order.expires_at.as_deref(),
+                                &order.created_at,
+                            ),
+                        });
+                        log::info!("[Swap/Buy] 订单创建成功: order_id={}", order.order_id);
                         log::info!("订单创建成功: order_id={}", order.order_id);
 
                         // 记录成功日志
@@ -2622,16 +3578,24 @@ fn BuyStablecoinTab() -> Element {
         }
     };
 
-    // 计算当前步骤（1: 选择稳定币和金额, 2: 选择支付方式, 3: 查看报价, 4: 确认购买）
-    let current_step = use_memo(move || {
-        if quote.read().is_some() && !amount.read().is_empty() {
-            3
-        } else if !amount.read().is_empty() && !selected_stablecoin.read().is_empty() {
-            2
-        } else {
-            1
-        }
-    });
+    // 分步流程：金额 → 支付方式 → 报价 → 支付（报价过期时自动退回报价步骤）
+    let wizard = use_step_wizard(4);
+    let lang = app_state.language.read().clone();
+    let tr = |key: &str| crate::i18n::translations::get_text(key, &lang);
+    let method_available = region
+        .read()
+        .as_ref()
+        .map(|m| m.buy_availability(&payment_method.read()).is_available())
+        .unwrap_or(true);
+    let wizard_steps = vec![
+        WizardStep::new(tr("buy.step1_select")).blocked_by(amount_field.error()),
+        WizardStep::new(tr("buy.step2_amount"))
+            .blocked_by((!method_available).then(|| tr("buy.wizard.method_unavailable"))),
+        WizardStep::new(tr("buy.step3_quote"))
+            .blocked_by(quote.read().is_none().then(|| tr("wizard.quote_pending"))),
+        WizardStep::new(tr("buy.step4_confirm")),
+    ];
+    let method_label = PaymentDirection::Buy.method_name(&payment_method.read(), &lang);
 
     rsx! {
         div {
@@ -2670,465 +3634,460 @@ fn BuyStablecoinTab() -> Element {
                 }
             }
 
-            // 流程步骤指示器
-            div {
-                class: "p-4 rounded-lg",
-                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                ProcessSteps {
-                    current_step: *current_step.read(),
-                    total_steps: 4,
-                    steps: vec![
-                        crate::i18n::translations::get_text("buy.step1_select", &app_state.language.read()),
-                        crate::i18n::translations::get_text("buy.step2_amount", &app_state.language.read()),
-                        crate::i18n::translations::get_text("buy.step3_quote", &app_state.language.read()),
-                        crate::i18n::translations::get_text("buy.step4_confirm", &app_state.language.read()),
-                    ],
+            // 上次未支付的订单已过期：按原参数重新下单
+            if let Some(session) = expired_session.read().clone() {
+                div {
+                    class: "p-4 rounded-lg flex flex-col sm:flex-row sm:items-center gap-3",
+                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                    div {
+                        class: "flex-1 text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {format!(
+                            "订单 {}（{} {} 购买 {}）已超过支付时限，可按原参数创建新订单",
+                            session.order_id, session.fiat_amount, session.fiat_currency, session.stablecoin
+                        )}
+                    }
+                    div {
+                        class: "flex gap-2",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Small,
+                            onclick: move |_| {
+                                if let Some(session) = expired_session.peek().clone() {
+                                    selected_stablecoin.set(session.stablecoin);
+                                    amount.set(session.fiat_amount);
+                                    payment_method.set(session.payment_method);
+                                }
+                                expired_session.set(None);
+                            },
+                            "按原参数重新下单"
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            onclick: move |_| expired_session.set(None),
+                            "忽略"
+                        }
+                    }
                 }
             }
 
-            // 购买表单卡片
-            div {
-                class: "p-6 rounded-lg",
-                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            StepWizard {
+                state: wizard,
+                steps: wizard_steps,
+                back_label: tr("wizard.back"),
+                next_label: tr("wizard.next"),
+                {match wizard.current() {
+                    0 => rsx! {
+                        div {
+                            class: "p-6 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
 
-                h3 {
-                    class: "text-lg font-semibold mb-4",
-                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                    {crate::i18n::translations::get_text("buy.select_stablecoin", &app_state.language.read())}
-                }
+                            h3 {
+                                class: "text-lg font-semibold mb-4",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {tr("buy.select_stablecoin")}
+                            }
 
-                div {
-                    class: "space-y-4",
+                            div {
+                                class: "space-y-4",
 
-                    // 稳定币选择
-                    div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {crate::i18n::translations::get_text("buy.choose_stablecoin", &app_state.language.read())}
-                        }
-                        div {
-                            class: "grid grid-cols-1 sm:grid-cols-2 gap-2",
-                            button {
-                                class: "p-3 rounded-lg border transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *selected_stablecoin.read() == "USDT" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *selected_stablecoin.read() == "USDT" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    if *selected_stablecoin.read() == "USDT" {
-                                        "#FFFFFF"
-                                    } else {
-                                        Colors::TEXT_PRIMARY
-                                    }
-                                ),
-                                onclick: move |_| {
-                                    selected_stablecoin.set("USDT".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-semibold",
-                                    style: format!("color: {};", if *selected_stablecoin.read() == "USDT" { "#FFFFFF" } else { Colors::TEXT_PRIMARY }),
-                                    "USDT"
-                                }
+                                // 稳定币选择
                                 div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", if *selected_stablecoin.read() == "USDT" { "rgba(255, 255, 255, 0.9)" } else { Colors::TEXT_SECONDARY }),
-                                    "Tether USD"
-                                }
-                            }
-                            button {
-                                class: "p-3 rounded-lg border transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *selected_stablecoin.read() == "USDC" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *selected_stablecoin.read() == "USDC" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    if *selected_stablecoin.read() == "USDC" {
-                                        "#FFFFFF"
-                                    } else {
-                                        Colors::TEXT_PRIMARY
+                                    label {
+                                        class: "block text-sm font-medium mb-2",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {crate::i18n::translations::get_text("buy.choose_stablecoin", &app_state.language.read())}
+                                    }
+                                    div {
+                                        class: "grid grid-cols-1 sm:grid-cols-2 gap-2",
+                                        button {
+                                            class: "p-3 rounded-lg border transition-all hover:scale-105",
+                                            style: format!(
+                                                "background: {}; border-color: {}; color: {};",
+                                                if *selected_stablecoin.read() == "USDT" {
+                                                    Colors::TECH_PRIMARY
+                                                } else {
+                                                    Colors::BG_SECONDARY
+                                                },
+                                                if *selected_stablecoin.read() == "USDT" {
+                                                    Colors::TECH_PRIMARY
+                                                } else {
+                                                    Colors::BORDER_PRIMARY
+                                                },
+                                                if *selected_stablecoin.read() == "USDT" {
+                                                    "#FFFFFF"
+                                                } else {
+                                                    Colors::TEXT_PRIMARY
+                                                }
+                                            ),
+                                            onclick: move |_| {
+                                                selected_stablecoin.set("USDT".to_string());
+                                                quote.set(None);
+                                            },
+                                            div {
+                                                class: "font-semibold",
+                                                style: format!("color: {};", if *selected_stablecoin.read() == "USDT" { "#FFFFFF" } else { Colors::TEXT_PRIMARY }),
+                                                "USDT"
+                                            }
+                                            div {
+                                                class: "text-xs mt-1",
+                                                style: format!("color: {};", if *selected_stablecoin.read() == "USDT" { "rgba(255, 255, 255, 0.9)" } else { Colors::TEXT_SECONDARY }),
+                                                "Tether USD"
+                                            }
+                                        }
+                                        button {
+                                            class: "p-3 rounded-lg border transition-all hover:scale-105",
+                                            style: format!(
+                                                "background: {}; border-color: {}; color: {};",
+                                                if *selected_stablecoin.read() == "USDC" {
+                                                    Colors::TECH_PRIMARY
+                                                } else {
+                                                    Colors::BG_SECONDARY
+                                                },
+                                                if *selected_stablecoin.read() == "USDC" {
+                                                    Colors::TECH_PRIMARY
+                                                } else {
+                                                    Colors::BORDER_PRIMARY
+                                                },
+                                                if *selected_stablecoin.read() == "USDC" {
+                                                    "#FFFFFF"
+                                                } else {
+                                                    Colors::TEXT_PRIMARY
+                                                }
+                                            ),
+                                            onclick: move |_| {
+                                                selected_stablecoin.set("USDC".to_string());
+                                                quote.set(None);
+                                            },
+                                            div {
+                                                class: "font-semibold",
+                                                style: format!("color: {};", if *selected_stablecoin.read() == "USDC" { "#FFFFFF" } else { Colors::TEXT_PRIMARY }),
+                                                "USDC"
+                                            }
+                                            div {
+                                                class: "text-xs mt-1",
+                                                style: format!("color: {};", if *selected_stablecoin.read() == "USDC" { "rgba(255, 255, 255, 0.9)" } else { Colors::TEXT_SECONDARY }),
+                                                "USD Coin"
+                                            }
+                                        }
+                                        // 手续费不足时预填的原生币
+                                        if !matches!(selected_stablecoin.read().as_str(), "USDT" | "USDC") {
+                                            div {
+                                                class: "p-3 rounded-lg border",
+                                                style: format!("background: {}; border-color: {};", Colors::TECH_PRIMARY, Colors::TECH_PRIMARY),
+                                                div {
+                                                    class: "font-semibold",
+                                                    style: "color: #FFFFFF;",
+                                                    "{selected_stablecoin.read()}"
+                                                }
+                                                div {
+                                                    class: "text-xs mt-1",
+                                                    style: "color: rgba(255, 255, 255, 0.9);",
+                                                    "用于支付网络手续费"
+                                                }
+                                            }
+                                        }
                                     }
-                                ),
-                                onclick: move |_| {
-                                    selected_stablecoin.set("USDC".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-semibold",
-                                    style: format!("color: {};", if *selected_stablecoin.read() == "USDC" { "#FFFFFF" } else { Colors::TEXT_PRIMARY }),
-                                    "USDC"
                                 }
+
+                                // 金额输入
                                 div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", if *selected_stablecoin.read() == "USDC" { "rgba(255, 255, 255, 0.9)" } else { Colors::TEXT_SECONDARY }),
-                                    "USD Coin"
+                                    label {
+                                        class: "block text-sm font-medium mb-2",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {crate::i18n::translations::get_text("buy.purchase_amount", &app_state.language.read())}
+                                    }
+                                    input {
+                                        class: "w-full p-3 rounded-lg",
+                                        style: format!("background: {}; border: 1px solid {}; color: {};",
+                                            Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                        r#type: "number",
+                                        value: "{amount.read()}",
+                                        oninput: move |e| {
+                                            amount.set(e.value());
+                                            quote.set(None);
+                                        },
+                                        onblur: move |_| amount_field.touch(),
+                                        placeholder: "{crate::i18n::translations::get_text(\"buy.enter_amount_placeholder\", &app_state.language.read())}",
+                                        min: "10",
+                                        step: "0.01"
+                                    }
+                                    FieldError { error: amount_field.visible_error() }
+
+                                    // 快速金额选择
+                                    div {
+                                        class: "flex gap-2 mt-2",
+                                        for quick_amount in quick_amounts {
+                                            button {
+                                                class: "px-4 py-1 text-sm rounded transition-all hover:scale-105 border",
+                                                style: format!("background: {}; color: {}; border-color: {};",
+                                                    Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
+                                                onclick: move |_| amount.set(quick_amount.to_string()),
+                                                "${quick_amount}"
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
-                    }
-
-                    // 金额输入
-                    div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {crate::i18n::translations::get_text("buy.purchase_amount", &app_state.language.read())}
-                        }
-                        input {
-                            class: "w-full p-3 rounded-lg",
-                            style: format!("background: {}; border: 1px solid {}; color: {};",
-                                Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
-                            r#type: "number",
-                            value: "{amount.read()}",
-                            oninput: move |e| {
-                                amount.set(e.value());
-                                quote.set(None);
-                            },
-                            placeholder: "{crate::i18n::translations::get_text(\"buy.enter_amount_placeholder\", &app_state.language.read())}",
-                            min: "10",
-                            step: "0.01"
-                        }
-
-                        // 快速金额选择
+                    },
+                    1 => rsx! {
                         div {
-                            class: "flex gap-2 mt-2",
-                            for quick_amount in quick_amounts {
-                                button {
-                                    class: "px-4 py-1 text-sm rounded transition-all hover:scale-105 border",
-                                    style: format!("background: {}; color: {}; border-color: {};",
-                                        Colors::BG_SECONDARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
-                                    onclick: move |_| amount.set(quick_amount.to_string()),
-                                    "${quick_amount}"
+                            class: "p-6 rounded-lg",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                            // 支付方式选择
+                            div {
+                                label {
+                                    class: "block text-sm font-medium mb-2",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    {tr("buy.payment_method")}
+                                }
+                                // 按用户所在地区分组并禁用不可用的支付方式
+                                PaymentMethodSelector {
+                                    direction: PaymentDirection::Buy,
+                                    selected: payment_method.read().clone(),
+                                    region,
+                                    onselect: move |method: String| {
+                                        payment_method.set(method);
+                                        quote.set(None);
+                                    },
                                 }
                             }
                         }
-                    }
-
-                    // 支付方式选择
-                    div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "支付方式"
-                        }
-                        div {
-                            class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-2",
-                            // 1. 信用卡/借记卡（推荐）
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *payment_method.read() == "credit_card" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *payment_method.read() == "credit_card" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    payment_method.set("credit_card".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-medium flex items-center gap-2",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    span { "💳 信用卡/借记卡" }
-                                    span {
-                                        class: "text-xs px-2 py-0.5 rounded",
-                                        style: "background: rgba(99, 102, 241, 0.2); color: rgb(99, 102, 241);",
-                                        "推荐"
+                    },
+                    2 => rsx! {
+                        // 报价显示
+                        if *quote_loading.read() && !amount.read().is_empty() {
+                            SkeletonCard { lines: 4 }
+                            SlowRequestHint {
+                                path: "/api/v1/fiat/onramp/quote",
+                                message: "报价比平时慢，正在比较各服务商…",
+                            }
+                        } else if let Some(q) = quote.read().as_ref() {
+                            div {
+                                class: "space-y-4",
+                                // 汇率锁定倒计时
+                                if let Some(lock_start) = quote_lock_start.read().as_ref() {
+                                    ExchangeRateLockCountdown {
+                                        countdown: *lock_start,
+                                        on_expired: Some(EventHandler::new(move |_| {
+                                            quote.set(None);
+                                            quote_lock_start.set(None);
+                                        })),
                                     }
                                 }
+
+                                // 购买详情卡片
                                 div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 支持Visa/Mastercard"
+                                    class: "p-6 rounded-lg",
+                                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                    h3 {
+                                        class: "text-lg font-semibold mb-4",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        "💰 购买详情"
+                                    }
+                                    div {
+                                        class: "space-y-2",
+                                        div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "购买金额" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "${amount.read()}"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计收到" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "{q.crypto_amount} {selected_stablecoin.read()}"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "汇率" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "1 USD = {q.exchange_rate} {selected_stablecoin.read()}"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "手续费" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "${q.fee_amount} ({q.fee_percentage:.2}%)"
+                                        }
+                                    }
+                                    // ✅ 平台服务费显示（费率为 0 时显示免费；无法预估时由服务端执行时计算）
+                                    div {
+                                        class: "flex justify-between items-center",
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                            "平台服务费 (IronCore)"
+                                        }
+                                        match platform_fee() {
+                                            Some(PlatformFeeEstimate::Quoted { fee, .. }) if fee <= 0.0 => rsx! {
+                                                span {
+                                                    class: "font-bold",
+                                                    style: format!("color: {};", Colors::PAYMENT_SUCCESS),
+                                                    "$0.00 免费!"
+                                                }
+                                            },
+                                            Some(fee) => rsx! {
+                                                span {
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {fee.label()}
+                                                }
+                                            },
+                                            None => rsx! {
+                                                span {
+                                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                                    "计算中..."
+                                                }
+                                            },
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计到账时间" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "{q.estimated_arrival}"
+                                        }
+                                    }
+                                    }
                                 }
-                            }
 
-                            // 2. PayPal
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *payment_method.read() == "paypal" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *payment_method.read() == "paypal" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    payment_method.set("paypal".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "📱 PayPal"
-                                }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 全球支付"
+                                // 限额显示
+                                LimitDisplay {
+                                    limit_info: limit_info.read().clone(),
                                 }
                             }
-
-                            // 3. Apple Pay
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *payment_method.read() == "apple_pay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *payment_method.read() == "apple_pay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    payment_method.set("apple_pay".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "🍎 Apple Pay"
-                                }
-                                div {
-                                    class: "text-xs mt-1",
+                        } else if !*quote_loading.read() {
+                            div {
+                                class: "p-6 rounded-lg text-center space-y-3",
+                                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                                p {
+                                    class: "text-sm",
                                     style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · iOS设备"
+                                    {tr("wizard.quote_pending")}
+                                }
+                                Button {
+                                    variant: ButtonVariant::Secondary,
+                                    size: ButtonSize::Small,
+                                    onclick: move |_| quote_refresh += 1,
+                                    {tr("wizard.refresh_quote")}
                                 }
                             }
-
-                            // 4. Google Pay
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *payment_method.read() == "google_pay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *payment_method.read() == "google_pay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    payment_method.set("google_pay".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "📱 Google Pay"
+                        }
+                    },
+                    _ => rsx! {
+                        div {
+                            class: "p-6 rounded-lg space-y-4",
+                            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                            h3 {
+                                class: "text-lg font-semibold",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {tr("wizard.review_title")}
+                            }
+                            div {
+                                WizardReviewRow {
+                                    label: "购买币种".to_string(),
+                                    value: selected_stablecoin.read().clone(),
+                                    step: 0,
+                                    state: wizard,
+                                    edit_label: tr("wizard.edit"),
                                 }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · Android设备"
+                                WizardReviewRow {
+                                    label: "购买金额".to_string(),
+                                    value: format!("${}", amount.read()),
+                                    step: 0,
+                                    state: wizard,
+                                    edit_label: tr("wizard.edit"),
                                 }
-                            }
-
-                            // 5. 支付宝
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *payment_method.read() == "alipay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *payment_method.read() == "alipay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    payment_method.set("alipay".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "💰 支付宝 Alipay"
+                                WizardReviewRow {
+                                    label: "支付方式".to_string(),
+                                    value: method_label.clone(),
+                                    step: 1,
+                                    state: wizard,
+                                    edit_label: tr("wizard.edit"),
                                 }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 中国地区"
+                                if let Some(q) = quote.read().as_ref() {
+                                    WizardReviewRow {
+                                        label: "预计收到".to_string(),
+                                        value: format!("{} {}", q.crypto_amount, selected_stablecoin.read()),
+                                        step: 2,
+                                        state: wizard,
+                                        edit_label: tr("wizard.edit"),
+                                    }
+                                    div {
+                                        class: "flex justify-between py-2 text-sm",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "手续费" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "${q.fee_amount} ({q.fee_percentage:.2}%)"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex justify-between py-2 text-sm",
+                                        span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计到账时间" }
+                                        span {
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            "{q.estimated_arrival}"
+                                        }
+                                    }
                                 }
                             }
-
-                            // 6. 微信支付
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *payment_method.read() == "wechat_pay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *payment_method.read() == "wechat_pay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| {
-                                    payment_method.set("wechat_pay".to_string());
-                                    quote.set(None);
-                                },
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "💬 微信支付 WeChat Pay"
-                                }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 中国地区"
+                            if let Some(lock_start) = quote_lock_start.read().as_ref() {
+                                ExchangeRateLockCountdown {
+                                    countdown: *lock_start,
+                                    on_expired: Some(EventHandler::new(move |_| {
+                                        quote.set(None);
+                                        quote_lock_start.set(None);
+                                    })),
                                 }
                             }
                         }
-                    }
-                }
-            }
-
-            // 报价显示
-            if *quote_loading.read() && !amount.read().is_empty() {
-                LoadingState {
-                    message: Some("正在获取最佳报价...".to_string()),
-                    progress: None,
-                    estimated_time: Some(3),
-                }
-            } else if let Some(q) = quote.read().as_ref() {
-                div {
-                    class: "space-y-4",
-                    // 汇率锁定倒计时
-                    if let Some(lock_start) = quote_lock_start.read().as_ref() {
-                        ExchangeRateLockCountdown {
-                            lock_start_time: *lock_start,
-                            lock_duration: 30,
-                            on_expired: Some(EventHandler::new(move |_| {
-                                quote.set(None);
-                                quote_lock_start.set(None);
-                            })),
-                        }
-                    }
 
-                    // 购买详情卡片
-                    div {
-                        class: "p-6 rounded-lg",
-                        style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                        h3 {
-                            class: "text-lg font-semibold mb-4",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "💰 购买详情"
-                        }
-                        div {
-                            class: "space-y-2",
-                            div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "购买金额" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "${amount.read()}"
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计收到" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "{q.crypto_amount} {selected_stablecoin.read()}"
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "汇率" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "1 USD = {q.exchange_rate} {selected_stablecoin.read()}"
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "手续费" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "${q.fee_amount} ({q.fee_percentage:.2}%)"
-                            }
-                        }
-                        // ✅ 平台服务费显示（行业标准：完全免费！）
-                        div {
-                            class: "flex justify-between items-center",
-                            span {
-                                style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                "平台服务费 (IronCore)"
-                            }
-                            span {
-                                class: "font-bold",
-                                style: format!("color: {};", Colors::PAYMENT_SUCCESS),
-                                "$0.00 免费!"
+                        if let Some(warning) = duplicate_warning.read().clone() {
+                            DuplicateOrderWarning {
+                                message: warning,
+                                on_confirm: {
+                                    let mut create_order_handler = create_order_handler;
+                                    move |_| {
+                                        allow_duplicate.set(true);
+                                        create_order_handler();
+                                    }
+                                },
+                                on_cancel: move |_| duplicate_warning.set(None),
                             }
                         }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计到账时间" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "{q.estimated_arrival}"
+
+                        // 购买按钮
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Large,
+                            cooldown_ms: submission_guard::SUBMIT_COOLDOWN_MS,
+                            onclick: {
+                                let mut create_order_handler = create_order_handler;
+                                move |_| create_order_handler()
+                            },
+                            disabled: !amount_field.is_valid()
+                                || quote.read().is_none()
+                                || *loading.read(),
+                            loading: *loading.read(),
+                            class: "w-full",
+                            if *loading.read() {
+                                "创建订单中..."
+                            } else {
+                                "购买 {selected_stablecoin.read()}"
                             }
                         }
-                        }
-                    }
-
-                    // 限额显示
-                    LimitDisplay {
-                        limit_info: limit_info.read().clone(),
-                    }
-                }
+                    },
+                }}
             }
 
             // 错误消息
@@ -3136,27 +4095,6 @@ fn BuyStablecoinTab() -> Element {
                 message: error_message.read().clone(),
             }
 
-            // 购买按钮
-            Button {
-                variant: ButtonVariant::Primary,
-                size: ButtonSize::Large,
-                onclick: {
-                    let mut create_order_handler = create_order_handler;
-                    move |_| create_order_handler()
-                },
-                disabled: amount.read().is_empty()
-                    || amount.read().parse::<f64>().unwrap_or(0.0) < 10.0
-                    || quote.read().is_none()
-                    || *loading.read(),
-                loading: *loading.read(),
-                class: "w-full",
-                if *loading.read() {
-                    "创建订单中..."
-                } else {
-                    "购买 {selected_stablecoin.read()}"
-                }
-            }
-
             // 用户反馈
             UserFeedback {
                 feedback_type: *feedback_type.read(),
@@ -3178,6 +4116,7 @@ fn BuyStablecoinTab() -> Element {
                     let mut card_cvv_sig = card_cvv;
                     let mut card_holder_sig = card_holder_name;
                     let mut processing_sig = payment_processing;
+                    let mut save_offer_sig = card_save_offer;
                     let toasts = app_state.toasts;
 
                     rsx! {
@@ -3190,22 +4129,61 @@ fn BuyStablecoinTab() -> Element {
                             card_expiry: card_expiry,
                             card_cvv: card_cvv,
                             card_holder_name: card_holder_name,
+                            selected_card: selected_card,
+                            save_offer: card_save_offer,
                             processing: payment_processing,
                             on_close: move |_| {
                                 show_modal_sig.set(false);
+                                save_offer_sig.set(None);
                                 card_num_sig.set(String::new());
                                 card_exp_sig.set(String::new());
                                 card_cvv_sig.set(String::new());
                                 card_holder_sig.set(String::new());
                             },
                             on_submit: move |_| {
+                                // 选择“使用新卡”时先令牌化，卡号不离开本次请求
+                                let new_card = (PaymentMethodType::from_string(&payment_method.peek())
+                                    == PaymentMethodType::CreditCard
+                                    && selected_card.peek().is_none())
+                                .then(|| CardDetailsInput {
+                                    number: card_num_sig.peek().clone(),
+                                    expiry: card_exp_sig.peek().clone(),
+                                    cvv: card_cvv_sig.peek().clone(),
+                                    holder_name: card_holder_sig.peek().clone(),
+                                });
                                 processing_sig.set(true);
 
                                 spawn(async move {
+                                    // 授权期间离开页面需要确认（任务结束时释放）
+                                    let _authorizing =
+                                        navigation_guard::hold(BusyReason::PaymentAuthorizing);
+                                    let token = match new_card {
+                                        Some(card) => match PaymentGatewayService::new(app_state)
+                                            .tokenize_card(&card)
+                                            .await
+                                        {
+                                            Ok(token) => Some(token),
+                                            Err(e) => {
+                                                processing_sig.set(false);
+                                                AppState::show_error(toasts, e);
+                                                return;
+                                            }
+                                        },
+                                        None => None,
+                                    };
+
                                     gloo_timers::future::TimeoutFuture::new(2000).await;
                                     processing_sig.set(false);
-                                    show_modal_sig.set(false);
+                                    card_num_sig.set(String::new());
+                                    card_cvv_sig.set(String::new());
+                                    onramp_orders::clear_session(&payment_order_id.peek());
                                     AppState::show_success(toasts, "支付成功！正在处理您的订单...".to_string());
+
+                                    // 新卡支付成功后询问是否保存，否则直接关闭
+                                    match token {
+                                        Some(token) => save_offer_sig.set(Some(token)),
+                                        None => show_modal_sig.set(false),
+                                    }
                                 });
                             },
                         }
@@ -3217,2105 +4195,1498 @@ fn BuyStablecoinTab() -> Element {
 }
 
 // =============================================================================
-// COMPONENT: WithdrawTab - 提现标签页 (~850行)
-// 功能: 法币出金,稳定币兑换为法币
+// COMPONENT: LimitOrderTab - 限价单标签页 (~600行)
+// 功能: 设置限价单,自动执行交易
+// =============================================================================
+
+// =============================================================================
+// COMPONENT: LimitOrderTab - 限价单标签页 (~600行)
+// 功能: 设置限价单,自动执行交易
 // =============================================================================
 
-/// 提现标签页 - 企业级法币提现功能
+/// 限价单状态筛选（状态值, 显示名称）
+const LIMIT_ORDER_STATUS_FILTERS: [(&str, &str); 4] = [
+    ("pending", "挂单中"),
+    ("filled", "已成交"),
+    ("cancelled", "已取消"),
+    ("expired", "已过期"),
+];
+
+/// 法币订单状态筛选（状态值, 显示名称；“全部”不含已取消、已过期的订单）
+const FIAT_ORDER_STATUS_FILTERS: [(&str, &str); 6] = [
+    ("pending", "待处理"),
+    ("processing", "处理中"),
+    ("completed", "已完成"),
+    ("failed", "失败"),
+    ("cancelled", "已取消"),
+    ("expired", "已过期"),
+];
+
+/// 限价单标签页
 #[component]
-fn WithdrawTab() -> Element {
+fn LimitOrderTab(
+    /// 选中的链
+    selected_chain: Signal<String>,
+    /// 通知回调
+    on_notification: Option<EventHandler<(NotificationType, String, String, Option<String>)>>,
+) -> Element {
     let app_state = use_context::<AppState>();
+    let t = crate::i18n::use_translation();
 
-    // 缓存和错误日志服务
-    let cache = use_signal(|| MemoryCache::new(Duration::from_secs(30)));
+    // 错误日志服务
     let error_logger = use_signal(|| ErrorLogger::new(100));
 
-    // 用户反馈状态
-    let show_feedback = use_signal(|| false);
-    let feedback_type = use_signal(|| FeedbackType::Info);
-    let feedback_message = use_signal(|| String::new());
-
-    // ✅ 先定义from_token，然后才能在selected_chain中使用
-    let from_token = use_signal(|| Option::<TokenInfo>::None); // 提现代币
-
-    // ✅ 智能链选择：自动从from_token提取链类型，无需用户手动选择
-    let selected_chain = use_memo(move || {
-        from_token
-            .read()
-            .as_ref()
-            .map(|t| t.chain.as_str().to_string())
-            .unwrap_or("ethereum".to_string())
+    let chain_type = use_memo(move || {
+        ChainType::from_str(&selected_chain.read()).unwrap_or(ChainType::Ethereum)
     });
-    let chain_type = use_memo(move || match selected_chain.read().as_str() {
-        "ethereum" => ChainType::Ethereum,
-        "bitcoin" => ChainType::Bitcoin,
-        "solana" => ChainType::Solana,
-        "ton" => ChainType::TON,
-        "bsc" => ChainType::BSC,
-        "polygon" => ChainType::Polygon,
-        _ => ChainType::Ethereum,
+    let limit_order_type = use_signal(|| LimitOrderType::Buy);
+    let from_token = use_signal(|| Option::<TokenInfo>::None);
+    let to_token = use_signal(|| Option::<TokenInfo>::None);
+    let amount = use_validated_field(|| {
+        vec![
+            Validator::required("请输入有效的数量"),
+            Validator::amount(18),
+        ]
     });
-
-    // 服务商状态列表（从后端API获取）
-    // API: GET /api/providers (已实现)
-    let provider_status_list = use_signal(|| Vec::<ProviderStatusInfo>::new());
-    let mut amount = use_signal(|| String::new()); // 提现数量
-    let mut withdraw_method = use_signal(|| "bank_card".to_string()); // 提现方式
-    let mut recipient_info = use_signal(|| String::new()); // 收款账户信息
+    let limit_price = use_validated_field(|| {
+        vec![
+            Validator::required("请输入有效的限价"),
+            Validator::amount(18),
+        ]
+    });
+    let expiry_days = use_signal(|| 7u32);
     let error_message = use_signal(|| Option::<String>::None);
     let loading = use_signal(|| false);
-    let quote_loading = use_signal(|| false);
-    let quote = use_signal(|| Option::<FiatOfframpQuoteResponse>::None);
-    let platform_fee = use_signal(|| Option::<f64>::None); // ✅ 平台服务费
+    let platform_fee = use_signal(|| Option::<PlatformFeeEstimate>::None); // ✅ 平台服务费
 
-    // 获取当前钱包
-    let current_wallet = use_memo(move || {
-        let wallet_state = app_state.wallet.read();
-        wallet_state.get_selected_wallet().cloned()
-    });
+    // 限价单列表（页码、排序、状态筛选在会话内保持）
+    let mut order_query = use_list_query("limit_orders");
 
-    // 自动获取报价（当代币、金额、链或提现方式改变时）
+    // 数量按支付代币精度校验
     use_effect({
-        let app_state_clone = app_state.clone();
-        let amount_sig = amount;
-        let token_sig = from_token;
-        let chain_sig = selected_chain;
-        let withdraw_method_sig = withdraw_method;
-        let mut quote_sig = quote;
-        let quote_load_sig = quote_loading;
-        let err_sig = error_message;
-
+        let mut amount_field = amount;
         move || {
-            let amount_val = amount_sig.read().clone();
-            let token_opt = token_sig.read().clone();
-            let chain_val = chain_sig.read().clone();
-            let withdraw_val = withdraw_method_sig.read().clone();
+            let decimals = from_token.read().as_ref().map_or(18, |t| t.decimals);
+            amount_field.set_rules(vec![
+                Validator::required("请输入有效的数量"),
+                Validator::amount(decimals),
+            ]);
+        }
+    });
 
-            // 企业级输入验证
-            let _amount_parsed = match amount_val.parse::<f64>() {
-                Ok(v) => {
-                    if v.is_nan() || v.is_infinite() || v <= 0.0 {
-                        quote_sig.set(None);
-                        return;
-                    }
-                    if v > 1e15 {
-                        quote_sig.set(None);
-                        return;
-                    }
-                    v
-                }
-                Err(_) => {
-                    quote_sig.set(None);
-                    return;
-                }
-            };
+    // ✅ 计算平台服务费（当金额变化时，使用from_token的美元价值）
+    use_effect(move || {
+        let basis = amount
+            .get()
+            .parse::<f64>()
+            .ok()
+            .zip(from_token.read().as_ref().map(|t| t.symbol.clone()))
+            .map(|(amount, symbol)| FeeBasis::Token { symbol, amount });
+        refresh_platform_fee(
+            app_state,
+            platform_fee,
+            selected_chain.read().clone(),
+            "limit_order",
+            basis,
+        );
+    });
 
-            // 金额验证通过，继续检查其他条件
-            if amount_val.is_empty() || token_opt.is_none() {
-                quote_sig.set(None);
-                return;
+    // 加载限价单列表
+    let mut limit_orders = use_paginated_query(
+        PageCache {
+            prefix: "limit_orders",
+            ttl: Some(Duration::from_secs(60)),
+        },
+        order_query,
+        move |list_query: ListQueryState| {
+            // 检查用户是否已登录，并验证token是否存在
+            let user_state = app_state.user.read();
+            let is_authenticated = user_state.is_authenticated;
+            let token_len = user_state
+                .access_token
+                .as_ref()
+                .map(|t| t.len())
+                .unwrap_or(0);
+            drop(user_state);
+
+            if !is_authenticated || token_len == 0 {
+                let error_msg = if !is_authenticated {
+                    "请先登录以查看限价单".to_string()
+                } else {
+                    "Token已失效，请重新登录以查看限价单".to_string()
+                };
+                return Some(async move { Err(error_msg) }.boxed_local());
+            }
+            // 验证token是否有效（通过检查长度，JWT token通常较长）
+            if token_len < 20 {
+                return Some(
+                    async move { Err("Token格式无效，请重新登录".to_string()) }.boxed_local(),
+                );
             }
 
-            let token_symbol = match token_opt.as_ref() {
-                Some(t) => {
-                    if t.symbol.is_empty() {
-                        quote_sig.set(None);
-                        return;
+            let mut error_logger_sig = error_logger;
+            Some(
+                async move {
+                    let page = list_query.page;
+                    let limit_order_service = LimitOrderService::new(app_state);
+                    let query = LimitOrderQuery {
+                        order_type: None,
+                        status: list_query.status.clone(),
+                        page: Some(page),
+                        page_size: Some(list_query.page_size),
+                        sort_by: Some(list_query.sort_by.param().to_string()),
+                        sort_order: Some(list_query.sort_order.param().to_string()),
+                    };
+
+                    match limit_order_service.get_orders(Some(query)).await {
+                        Ok(response) => Ok(Page {
+                            items: response.orders,
+                            total_pages: response.total_pages,
+                        }),
+                        Err(e) => {
+                            // 检查是否是401错误（未授权）
+                            let is_unauthorized = e.to_string().contains("401")
+                                || e.to_string().to_lowercase().contains("unauthorized");
+
+                            // 不要自动清除认证状态，只显示错误消息
+                            // 让用户自己决定是否要重新登录
+                            // 这样可以避免在token暂时失效时强制登出用户
+
+                            let error_msg = if is_unauthorized {
+                                "⚠️ 认证已过期，请重新登录以查看限价单\n\n提示：点击右上角\"登出\"按钮，然后重新登录即可解决此问题。".to_string()
+                            } else {
+                                format!("加载限价单列表失败: {}", e)
+                            };
+                            // 记录错误日志
+                            error_logger_sig.write().log(
+                                ErrorLevel::Error,
+                                error_msg.clone(),
+                                Some(serde_json::json!({
+                                    "page": page,
+                                })),
+                            );
+                            Err(error_msg)
+                        }
                     }
-                    t.symbol.clone()
                 }
-                None => {
-                    quote_sig.set(None);
-                    return;
-                }
-            };
+                .boxed_local(),
+            )
+        },
+    );
+
+    // 待确认取消的限价单及取消请求进行中状态
+    let mut pending_cancel_order = use_signal(|| None::<String>);
+    let mut cancelling_order = use_signal(|| false);
+    // 正在修改的限价单、修改请求进行中状态与本设备记录的订单事件
+    let mut editing_order = use_signal(|| None::<LimitOrderResponse>);
+    let mut saving_edit = use_signal(|| false);
+    let mut timelines = use_signal(limit_edit::OrderTimelines::load);
+
+    // 取消限价单处理（先从列表中移除，失败时重新加载恢复）
+    let mut cancel_order_handler = {
+        let app_state_clone = app_state.clone();
+        let notif_handler = on_notification.clone();
 
-            // 延迟500ms后获取报价，避免频繁请求
-            let amount_clone = amount_val.clone();
-            let token_clone = token_symbol.clone();
-            let chain_clone = chain_val.clone();
-            let withdraw_clone = withdraw_val.clone();
-            let app_state_for_spawn = app_state_clone.clone();
-            let amount_sig_for_check = amount_sig;
-            let mut quote_sig_for_spawn = quote_sig;
-            let mut quote_load_sig_for_spawn = quote_load_sig;
-            let mut err_sig_for_spawn = err_sig;
-            let mut cache_sig = cache;
-            let mut error_logger_sig = error_logger;
+        move |order_id: String| {
+            let app_state_for_spawn = app_state_clone;
+            let mut limit_orders = limit_orders;
+            let notif_handler_for_spawn = notif_handler.clone();
+            let order_id_clone = order_id.clone();
 
+            cancelling_order.set(true);
+            limit_orders.remove_where(|o| o.order_id == order_id);
             spawn(async move {
-                // 等待500ms防抖
-                gloo_timers::future::TimeoutFuture::new(500).await;
+                let limit_order_service = LimitOrderService::new(app_state_for_spawn);
 
-                // 检查金额是否还是同一个（防止过期请求）
-                if amount_sig_for_check.read().as_str() != amount_clone.as_str() {
-                    return;
+                match limit_order_service.cancel_order(&order_id_clone).await {
+                    Ok(_) => {
+                        timelines.write().record(
+                            &order_id_clone,
+                            LimitOrderEvent::new(LimitOrderEventKind::Cancelled, Utc::now(), ""),
+                        );
+                        timelines.peek().save();
+                        BalanceService::new(app_state_for_spawn)
+                            .refresh_committed()
+                            .await;
+
+                        if let Some(handler) = notif_handler_for_spawn {
+                            handler.call((
+                                NotificationType::Success,
+                                "限价单已取消".to_string(),
+                                format!("订单 {} 已成功取消", order_id_clone),
+                                Some(order_id_clone),
+                            ));
+                        }
+                    }
+                    Err(e) => {
+                        // 订单未取消：重新加载恢复列表
+                        limit_orders.reload();
+                        if let Some(handler) = notif_handler_for_spawn {
+                            handler.call((
+                                NotificationType::Error,
+                                "取消限价单失败".to_string(),
+                                e,
+                                None,
+                            ));
+                        }
+                    }
                 }
 
-                quote_load_sig_for_spawn.set(true);
-                err_sig_for_spawn.set(None);
+                cancelling_order.set(false);
+                pending_cancel_order.set(None);
+            });
+        }
+    };
 
-                // 检查缓存
-                let cache_key = format!(
-                    "offramp_quote:{}:{}:{}:{}",
-                    token_clone, amount_clone, chain_clone, withdraw_clone
-                );
-                if let Some(cached_quote) =
-                    cache_sig.read().get::<FiatOfframpQuoteResponse>(&cache_key)
-                {
-                    quote_sig_for_spawn.set(Some(cached_quote));
-                    quote_load_sig_for_spawn.set(false);
-                    return;
-                }
+    // 延长有效期处理（乐观更新，失败时回滚）
+    let extend_expiry_handler = {
+        let app_state_clone = app_state;
+        let notif_handler = on_notification;
 
-                // 缓存未命中，从API获取
-                let offramp_service = FiatOfframpService::new(app_state_for_spawn);
-                match offramp_service
-                    .get_quote(
-                        &token_clone,
-                        &amount_clone,
-                        &chain_clone,
-                        "USD",
-                        &withdraw_clone,
-                    )
+        move |order_id: String| {
+            let mut limit_orders = limit_orders;
+            let previous_expiry = match limit_orders
+                .state
+                .peek()
+                .items
+                .iter()
+                .find(|o| o.order_id == order_id)
+            {
+                Some(order) => order.expires_at.clone(),
+                None => return,
+            };
+            if let Some(order) = limit_orders
+                .state
+                .write()
+                .items
+                .iter_mut()
+                .find(|o| o.order_id == order_id)
+            {
+                order.expires_at = Some(limit_expiry::extended_expires_at(
+                    order,
+                    limit_expiry::EXTEND_DAYS,
+                    Utc::now(),
+                ));
+            }
+
+            spawn(async move {
+                let limit_order_service = LimitOrderService::new(app_state_clone);
+                match limit_order_service
+                    .update_expiry(&order_id, limit_expiry::EXTEND_DAYS)
                     .await
                 {
-                    Ok(q) => {
-                        // 保存到缓存
-                        cache_sig
+                    Ok(updated) => {
+                        if let Some(order) = limit_orders
+                            .state
                             .write()
-                            .set(cache_key, q.clone(), Some(Duration::from_secs(30)));
-                        quote_sig_for_spawn.set(Some(q.clone()));
-
-                        // ✅ 计算平台服务费（Fiat Offramp操作，使用代币的美元价值）
-                        if let Ok(amount_f64) = amount_clone.parse::<f64>() {
-                            if amount_f64 > 0.0 {
-                                // 获取token的美元价格
-                                let price_service = PriceService::new(app_state_for_spawn.clone());
-                                let fee_service = FeeService::new(app_state_for_spawn.clone());
-                                let mut platform_fee_sig = platform_fee;
-                                let token_symbol = token_clone.clone(); // token_clone是token的symbol字符串
-
-                                spawn(async move {
-                                    // 获取代币美元价格
-                                    match price_service.get_price(&token_symbol).await {
-                                        Ok(price_data) => {
-                                            let usd_value = amount_f64 * price_data.usd;
-                                            log::info!(
-                                                "提现金额: {} {}, 美元价值: ${:.2}",
-                                                amount_f64,
-                                                token_symbol,
-                                                usd_value
-                                            );
-
-                                            // 使用美元价值计算平台服务费
-                                            match fee_service
-                                                .calculate(
-                                                    &chain_clone,
-                                                    "fiat_offramp",
-                                                    usd_value, // 传递美元价值而不是代币数量
-                                                )
-                                                .await
-                                            {
-                                                Ok(fee_quote) => {
-                                                    platform_fee_sig
-                                                        .set(Some(fee_quote.platform_fee));
-                                                    log::info!("平台服务费(FiatOfframp): ${:.2} (规则ID: {})", 
-                                                        fee_quote.platform_fee, fee_quote.applied_rule_id);
-                                                }
-                                                Err(e) => {
-                                                    log::error!("计算平台服务费失败: {}", e);
-                                                    platform_fee_sig.set(None);
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            log::error!("获取{}价格失败: {}", token_symbol, e);
-                                            platform_fee_sig.set(None);
-                                        }
-                                    }
-                                });
-                            }
+                            .items
+                            .iter_mut()
+                            .find(|o| o.order_id == order_id)
+                        {
+                            *order = updated;
+                        }
+                        limit_orders.invalidate();
+                        if let Some(handler) = notif_handler {
+                            handler.call((
+                                NotificationType::Success,
+                                "有效期已延长".to_string(),
+                                format!(
+                                    "订单 {} 有效期已延长 {} 天",
+                                    order_id,
+                                    limit_expiry::EXTEND_DAYS
+                                ),
+                                Some(order_id),
+                            ));
                         }
                     }
                     Err(e) => {
-                        // 企业级：根据错误类型提供友好提示
-                        let error_str = e.to_string();
-                        let friendly_error = if error_str.contains("404")
-                            || error_str.contains("not found")
-                        {
-                            "该提现方式暂不支持，请选择其他方式".to_string()
-                        } else if error_str.contains("500")
-                            || error_str.contains("Internal Server Error")
-                        {
-                            "提现服务暂时不可用，请稍后再试".to_string()
-                        } else if error_str.contains("timeout") || error_str.contains("timed out") {
-                            "网络请求超时，请检查网络连接".to_string()
-                        } else if error_str.contains("minimum amount")
-                            || error_str.contains("too small")
-                        {
-                            "提现金额过小，请增加金额后重试".to_string()
-                        } else if error_str.contains("maximum amount")
-                            || error_str.contains("too large")
+                        // 回滚乐观更新
+                        if let Some(order) = limit_orders
+                            .state
+                            .write()
+                            .items
+                            .iter_mut()
+                            .find(|o| o.order_id == order_id)
                         {
-                            "提现金额超出限额，请减少金额后重试".to_string()
-                        } else if error_str.contains("country") || error_str.contains("region") {
-                            "该地区暂不支持此提现方式".to_string()
-                        } else {
-                            format!("获取提现报价失败: {}", error_str)
-                        };
-
-                        err_sig_for_spawn.set(Some(friendly_error.clone()));
-                        quote_sig_for_spawn.set(None);
-                        // 记录错误日志
-                        error_logger_sig.write().log(
-                            ErrorLevel::Error,
-                            error_str,
-                            Some(serde_json::json!({
-                                "token": token_clone,
-                                "amount": amount_clone,
-                                "chain": chain_clone,
-                                "withdraw_method": withdraw_clone,
-                                "friendly_message": friendly_error,
-                            })),
-                        );
+                            order.expires_at = previous_expiry;
+                        }
+                        if let Some(handler) = notif_handler {
+                            handler.call((
+                                NotificationType::Error,
+                                "延长有效期失败".to_string(),
+                                e,
+                                None,
+                            ));
+                        }
                     }
                 }
-                quote_load_sig_for_spawn.set(false);
             });
         }
-    });
+    };
 
-    // 创建提现订单函数
-    let create_withdraw_order_handler = {
-        let app_state_clone = app_state.clone();
-        let amount_sig = amount;
-        let token_sig = from_token;
-        let chain_sig = selected_chain;
-        let withdraw_method_sig = withdraw_method;
-        let recipient_sig = recipient_info;
-        let quote_sig = quote;
-        let loading_sig = loading;
-        let mut err_sig = error_message;
-        let toasts = app_state.toasts;
-        let error_logger_sig = error_logger;
-        let show_feedback_sig = show_feedback;
-        let feedback_type_sig = feedback_type;
-        let feedback_message_sig = feedback_message;
+    // 到期倒计时（每秒刷新）；页面打开期间到期的挂单移入“已过期”分组并通知
+    let expiry_now = use_signal(Utc::now);
+    use_future(move || async move {
+        let mut expiry_now = expiry_now;
+        let mut limit_orders = limit_orders;
+        loop {
+            scheduler::next_tick(TickPolicy::PauseWhenHidden, 1_000).await;
+            let now = Utc::now();
+            expiry_now.set(now);
+            let has_due = limit_orders.state.peek().items.iter().any(|o| {
+                limit_expiry::urgency(o, now) == Some(limit_expiry::ExpiryUrgency::Expired)
+            });
+            if !has_due {
+                continue;
+            }
+            let expired =
+                limit_expiry::expire_due_orders(&mut limit_orders.state.write().items, now);
+            limit_orders.invalidate();
+            for order_id in &expired {
+                timelines.write().record(
+                    order_id,
+                    LimitOrderEvent::new(LimitOrderEventKind::Expired, now, ""),
+                );
+            }
+            timelines.peek().save();
+            // 切回前台补跑时可能一次到期多笔，合并为一条通知
+            if let Some(handler) = on_notification {
+                match expired.as_slice() {
+                    [] => {}
+                    [order_id] => handler.call((
+                        NotificationType::Warning,
+                        "限价单已过期".to_string(),
+                        format!("订单 {} 已到期，未成交部分不会再执行", order_id),
+                        Some(order_id.clone()),
+                    )),
+                    _ => handler.call((
+                        NotificationType::Warning,
+                        "限价单已过期".to_string(),
+                        format!("{} 笔限价单已到期，未成交部分不会再执行", expired.len()),
+                        None,
+                    )),
+                }
+            }
+        }
+    });
 
-        move || {
-            let amount_val = amount_sig.read().clone();
-            let token_opt = token_sig.read().clone();
-            let chain_val = chain_sig.read().clone();
-            let withdraw_val = withdraw_method_sig.read().clone();
-            let recipient_val = recipient_sig.read().clone();
-            let wallet_opt = current_wallet.read().clone();
+    // 获取当前钱包地址
+    let current_wallet = use_memo(move || {
+        let wallet_state = app_state.wallet.read();
+        wallet_state.get_selected_wallet().cloned()
+    });
 
-            // 检查是否有报价
-            let quote_opt = quote_sig.read().clone();
-            let quote_id_val = match quote_opt.as_ref() {
-                Some(q) => q.quote_id.clone(),
-                None => {
-                    err_sig.set(Some("请先获取报价".to_string()));
-                    return;
-                }
-            };
+    // 修改限价单处理：服务端支持时原子修改，否则取消确认后重建
+    let edit_order_handler = {
+        let app_state_clone = app_state;
+        let notif_handler = on_notification;
+        let mut amount_sig = amount;
+        let mut limit_price_sig = limit_price;
+        let mut order_type_sig = limit_order_type;
+
+        move |(order, new_price, new_amount): (LimitOrderResponse, String, String)| {
+            let mut limit_orders = limit_orders;
+            let network = selected_chain.read().clone();
+            let wallet_id = current_wallet
+                .read()
+                .as_ref()
+                .and_then(|w| account_for_chain(w, *chain_type.read()))
+                .map(|a| a.address.clone());
+            let summary = limit_edit::change_summary(&order, &new_price, &new_amount);
 
-            // 企业级输入验证
-            // 验证金额
-            let _amount_parsed = match amount_val.parse::<f64>() {
-                Ok(v) => {
-                    if v.is_nan() || v.is_infinite() || v <= 0.0 {
-                        err_sig.set(Some("请输入有效的提现数量（必须大于0）".to_string()));
+            saving_edit.set(true);
+            spawn(async move {
+                let limit_order_service = LimitOrderService::new(app_state_clone);
+                let result = limit_edit::edit_order(
+                    &limit_order_service,
+                    &order,
+                    &new_price,
+                    &new_amount,
+                    &network,
+                    wallet_id.as_deref(),
+                )
+                .await;
+                saving_edit.set(false);
+
+                let outcome = match result {
+                    Ok(outcome) => outcome,
+                    Err(e) => {
+                        // 原订单未变动，保留修改窗口以便调整后重试
+                        if let Some(handler) = notif_handler {
+                            handler.call((
+                                NotificationType::Error,
+                                "修改限价单失败".to_string(),
+                                e,
+                                None,
+                            ));
+                        }
                         return;
                     }
-                    if v > 1e15 {
-                        err_sig.set(Some("金额过大，请输入有效金额".to_string()));
-                        return;
-                    }
-                    v
-                }
-                Err(_) => {
-                    err_sig.set(Some("请输入有效的提现数量".to_string()));
-                    return;
-                }
-            };
+                };
 
-            // 金额验证通过，继续验证其他字段
-            // 验证代币选择
-            let token_symbol = match token_opt.as_ref() {
-                Some(t) => {
-                    if t.symbol.is_empty() {
-                        err_sig.set(Some("请选择有效的提现代币".to_string()));
-                        return;
+                timelines
+                    .write()
+                    .record_outcome(&order, &outcome, &summary, Utc::now());
+                timelines.peek().save();
+                limit_orders.invalidate();
+                editing_order.set(None);
+
+                match outcome {
+                    EditOutcome::Updated(updated) => {
+                        if let Some(slot) = limit_orders
+                            .state
+                            .write()
+                            .items
+                            .iter_mut()
+                            .find(|o| o.order_id == order.order_id)
+                        {
+                            *slot = updated;
+                        }
+                        if let Some(handler) = notif_handler {
+                            handler.call((
+                                NotificationType::Success,
+                                "限价单已修改".to_string(),
+                                format!("订单 {}：{}", order.order_id, summary),
+                                Some(order.order_id.clone()),
+                            ));
+                        }
+                    }
+                    EditOutcome::Replaced {
+                        previous_id,
+                        order: created,
+                    } => {
+                        let new_id = created.order_id.clone();
+                        if let Some(slot) = limit_orders
+                            .state
+                            .write()
+                            .items
+                            .iter_mut()
+                            .find(|o| o.order_id == previous_id)
+                        {
+                            *slot = created;
+                        }
+                        if let Some(handler) = notif_handler {
+                            handler.call((
+                                NotificationType::Success,
+                                "限价单已修改".to_string(),
+                                format!(
+                                    "订单 {} 已取消并按新参数重建为订单 {}：{}",
+                                    previous_id, new_id, summary
+                                ),
+                                Some(new_id),
+                            ));
+                        }
+                    }
+                    EditOutcome::RecreateFailed { previous_id, error } => {
+                        // 原订单已取消：移出列表，并把新参数预填到下单表单供用户重新提交
+                        limit_orders.remove_where(|o| o.order_id == previous_id);
+                        order_type_sig.set(if order.order_type.eq_ignore_ascii_case("sell") {
+                            LimitOrderType::Sell
+                        } else {
+                            LimitOrderType::Buy
+                        });
+                        amount_sig.set(new_amount);
+                        limit_price_sig.set(new_price);
+                        if let Some(form) = web_sys::window()
+                            .and_then(|w| w.document())
+                            .and_then(|d| d.get_element_by_id("limit-order-form"))
+                        {
+                            form.scroll_into_view();
+                        }
+                        BalanceService::new(app_state_clone)
+                            .refresh_committed()
+                            .await;
+                        if let Some(handler) = notif_handler {
+                            handler.call((
+                                NotificationType::Error,
+                                "限价单重建失败".to_string(),
+                                format!(
+                                    "原订单 {} 已取消，但新订单创建失败：{}。已将新参数填入下单表单（{} → {}），请确认后重新提交",
+                                    previous_id, error, order.from_token, order.to_token
+                                ),
+                                Some(previous_id),
+                            ));
+                        }
                     }
-                    t.symbol.clone()
-                }
-                None => {
-                    err_sig.set(Some("请选择提现代币".to_string()));
-                    return;
                 }
-            };
+            });
+        }
+    };
+
+    // 创建限价单处理
+    let create_limit_order_handler = {
+        let app_state_clone = app_state.clone();
+        let amount_sig = amount;
+        let limit_price_sig = limit_price;
+        let from_token_sig = from_token;
+        let to_token_sig = to_token;
+        let chain_sig = selected_chain;
+        let loading_sig = loading;
+        let mut err_sig = error_message;
+        let notif_handler = on_notification.clone();
+        let current_wallet_sig = current_wallet;
 
-            // 验证收款账户信息
-            if recipient_val.is_empty() {
-                err_sig.set(Some("请输入收款账户信息".to_string()));
+        move |order_type: LimitOrderType,
+              amount_val: String,
+              price_val: String,
+              _token_pair: String,
+              expiry: u32| {
+            if let Some(e) = amount_sig.error().or_else(|| limit_price_sig.error()) {
+                err_sig.set(Some(e));
                 return;
             }
 
-            // 根据提现方式验证收款账户格式
-            let withdraw_method_val = withdraw_val.clone();
-            if withdraw_method_val == "bank_card" {
-                // 银行卡号验证（基本格式检查）
-                let card_number = recipient_val.trim().replace(" ", "").replace("-", "");
-                if card_number.len() < 13 || card_number.len() > 19 {
-                    err_sig.set(Some("银行卡号格式不正确，请输入13-19位数字".to_string()));
-                    return;
-                }
-                if !card_number.chars().all(|c| c.is_ascii_digit()) {
-                    err_sig.set(Some("银行卡号只能包含数字".to_string()));
-                    return;
-                }
-            } else if withdraw_method_val == "bank_account" {
-                // 银行账户验证（基本格式检查）
-                if recipient_val.trim().len() < 8 {
-                    err_sig.set(Some("银行账户信息格式不正确，请检查后重试".to_string()));
-                    return;
-                }
-            } else if withdraw_method_val == "paypal" {
-                // PayPal账户验证（邮箱格式检查）
-                if !recipient_val.contains('@') || !recipient_val.contains('.') {
-                    err_sig.set(Some("PayPal账户必须是有效的邮箱地址".to_string()));
-                    return;
-                }
+            let from = from_token_sig.read().clone();
+            let to = to_token_sig.read().clone();
+
+            if from.is_none() || to.is_none() {
+                err_sig.set(Some("请选择代币".to_string()));
+                return;
             }
 
             let amount_clone = amount_val.clone();
-            let token_clone = token_symbol.clone();
-            let chain_clone = chain_val.clone();
-            let withdraw_clone = withdraw_val.clone();
-            // 根据提现方式构建recipient_info JSON对象
-            let recipient_info_json = match withdraw_method_val.as_str() {
-                "bank_card" => {
-                    let card_number = recipient_val.trim().replace(" ", "").replace("-", "");
-                    serde_json::json!({
-                        "bank_account": card_number,
-                        "account_type": "card"
-                    })
-                }
-                "bank_account" => {
-                    serde_json::json!({
-                        "bank_account": recipient_val.trim(),
-                        "account_type": "account"
-                    })
-                }
-                "paypal" => {
-                    serde_json::json!({
-                        "paypal_email": recipient_val.trim(),
-                        "account_type": "paypal"
-                    })
-                }
-                _ => {
-                    serde_json::json!({
-                        "account": recipient_val.trim()
-                    })
-                }
-            };
-            let recipient_info_str = recipient_info_json.to_string();
-            let quote_id_clone = quote_id_val.clone();
+            let price_clone = price_val.clone();
+            let chain_clone = chain_sig.read().clone();
             let app_state_for_spawn = app_state_clone.clone();
             let mut loading_sig_for_spawn = loading_sig;
             let mut err_sig_for_spawn = err_sig;
+            let notif_handler_for_spawn = notif_handler.clone();
+            let mut amount_sig_for_spawn = amount_sig;
+            let mut limit_price_sig_for_spawn = limit_price_sig;
 
-            let mut error_logger_sig_for_spawn = error_logger_sig;
-            let mut show_feedback_sig_for_spawn = show_feedback_sig;
-            let mut feedback_type_sig_for_spawn = feedback_type_sig;
-            let mut feedback_message_sig_for_spawn = feedback_message_sig;
+            let from_token_info_clone = from_token_sig.read().clone();
+            let to_token_info_clone = to_token_sig.read().clone();
+            let wallet_id_opt = current_wallet_sig
+                .read()
+                .as_ref()
+                .and_then(|w| account_for_chain(w, *chain_type.read()))
+                .map(|a| a.address.clone());
 
             spawn(async move {
                 loading_sig_for_spawn.set(true);
                 err_sig_for_spawn.set(None);
 
-                let offramp_service = FiatOfframpService::new(app_state_for_spawn);
-                match offramp_service
+                // 获取代币信息
+                let from_token_info = from_token_info_clone;
+                let to_token_info = to_token_info_clone;
+
+                let from_symbol = from_token_info
+                    .as_ref()
+                    .map(|t| t.symbol.clone())
+                    .unwrap_or_default();
+                let to_symbol = to_token_info
+                    .as_ref()
+                    .map(|t| t.symbol.clone())
+                    .unwrap_or_default();
+
+                // 转换订单类型
+                let service_order_type = match order_type {
+                    LimitOrderType::Buy => ServiceLimitOrderType::Buy,
+                    LimitOrderType::Sell => ServiceLimitOrderType::Sell,
+                };
+
+                // 创建限价单服务实例
+                let limit_order_service = LimitOrderService::new(app_state_for_spawn);
+
+                // 调用后端API创建限价单
+                match limit_order_service
                     .create_order(
-                        &token_clone,
+                        service_order_type,
+                        &from_symbol,
+                        &to_symbol,
                         &amount_clone,
+                        &price_clone,
                         &chain_clone,
-                        "USD",
-                        &withdraw_clone,
-                        &recipient_info_str,
-                        quote_id_clone.as_str().into(), // 转换为Option<&str>
+                        expiry,
+                        wallet_id_opt.as_deref(),
                     )
                     .await
                 {
-                    Ok(order) => {
-                        log::info!("提现订单创建成功: order_id={}", order.order_id);
-
-                        // 记录成功日志
-                        error_logger_sig_for_spawn.write().log(
-                            ErrorLevel::Info,
-                            format!("提现订单创建成功: order_id={}", order.order_id),
-                            Some(serde_json::json!({
-                                "order_id": order.order_id,
-                                "token": token_clone,
-                                "amount": amount_clone,
-                                "chain": chain_clone,
-                                "withdraw_method": withdraw_clone,
-                            })),
-                        );
-
-                        // 显示成功反馈
-                        feedback_type_sig_for_spawn.set(FeedbackType::Success);
-                        feedback_message_sig_for_spawn
-                            .set(format!("提现订单已创建，订单号: {}", order.order_id));
-                        show_feedback_sig_for_spawn.set(true);
+                    Ok(response) => {
+                        // 创建成功
+                        if let Some(handler) = notif_handler_for_spawn {
+                            handler.call((
+                                NotificationType::Success,
+                                "限价单创建成功".to_string(),
+                                format!(
+                                    "限价单已创建（订单ID: {}）：{} {} @ {}",
+                                    response.order_id,
+                                    amount_clone,
+                                    if order_type == LimitOrderType::Buy {
+                                        "买入"
+                                    } else {
+                                        "卖出"
+                                    },
+                                    price_clone
+                                ),
+                                Some(response.order_id),
+                            ));
+                        }
 
-                        AppState::show_success(
-                            toasts,
-                            format!("提现订单已创建，订单号: {}", order.order_id),
-                        );
-                        // 可以跳转到订单详情页面或历史页面
+                        // 清空表单
+                        amount_sig_for_spawn.reset();
+                        limit_price_sig_for_spawn.reset();
+                        BalanceService::new(app_state_for_spawn)
+                            .refresh_committed()
+                            .await;
                     }
                     Err(e) => {
-                        let error_msg = format!("创建提现订单失败: {}", e);
-
-                        // 记录错误日志
-                        error_logger_sig_for_spawn.write().log(
-                            ErrorLevel::Error,
-                            error_msg.clone(),
-                            Some(serde_json::json!({
-                                "token": token_clone,
-                                "amount": amount_clone,
-                                "chain": chain_clone,
-                                "withdraw_method": withdraw_clone,
-                            })),
-                        );
-
-                        // 显示错误反馈
-                        feedback_type_sig_for_spawn.set(FeedbackType::Error);
-                        feedback_message_sig_for_spawn.set(error_msg.clone());
-                        show_feedback_sig_for_spawn.set(true);
-
-                        err_sig_for_spawn.set(Some(error_msg));
+                        // 创建失败
+                        err_sig_for_spawn.set(Some(e.clone()));
+                        if let Some(handler) = notif_handler_for_spawn {
+                            handler.call((
+                                NotificationType::Error,
+                                "限价单创建失败".to_string(),
+                                e,
+                                None,
+                            ));
+                        }
                     }
                 }
+
                 loading_sig_for_spawn.set(false);
             });
         }
     };
 
-    // 计算当前步骤（1: 选择代币和金额, 2: 选择提现方式, 3: 输入收款信息, 4: 确认提现）
-    let current_step = use_memo(move || {
-        if !recipient_info.read().is_empty() && quote.read().is_some() {
-            4
-        } else if !recipient_info.read().is_empty() {
-            3
-        } else if !amount.read().is_empty() && from_token.read().is_some() {
-            2
-        } else {
-            1
-        }
-    });
-
-    // 提示信息：系统将自动执行代币→稳定币交换
     rsx! {
         div {
+            id: "limit-order-form",
             class: "space-y-4",
-
-            // 服务商状态显示（如果有数据）
-            if !provider_status_list.read().is_empty() {
-                div {
-                    class: "p-4 rounded-lg",
-                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                    h4 {
-                        class: "text-sm font-medium mb-3",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        "服务商状态"
-                    }
-                    ProviderStatusList {
-                        providers: provider_status_list.read().clone(),
-                    }
-                }
-            }
-
-            // 流程步骤指示器
-            div {
-                class: "p-4 rounded-lg",
-                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                ProcessSteps {
-                    current_step: *current_step.read(),
-                    total_steps: 4,
-                    steps: vec![
-                        crate::i18n::translations::get_text("withdraw.step1_select", &app_state.language.read()),
-                        crate::i18n::translations::get_text("withdraw.step2_method", &app_state.language.read()),
-                        crate::i18n::translations::get_text("withdraw.step3_info", &app_state.language.read()),
-                        crate::i18n::translations::get_text("withdraw.step4_confirm", &app_state.language.read()),
-                    ],
-                }
-            }
-
-            // 提示卡片
-            div {
-                class: "p-4 rounded-lg",
-                style: format!("background: rgba(59, 130, 246, 0.1); border: 1px solid rgba(59, 130, 246, 0.3);"),
-                div {
-                    class: "flex items-start gap-2",
-                    span { "💡" }
-                    div {
-                        class: "text-sm",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        {crate::i18n::translations::get_text("withdraw.two_step_hint", &app_state.language.read())}
-                    }
-                }
+            LimitOrderForm {
+                order_type: limit_order_type,
+                from_token: from_token,
+                to_token: to_token,
+                amount: amount,
+                limit_price: limit_price,
+                expiry_days: expiry_days,
+                error_message: error_message,
+                loading: loading,
+                platform_fee: platform_fee(),
+                on_submit: {
+                    let mut handler = create_limit_order_handler;
+                    Some(EventHandler::new(move |(order_type, amount_val, price_val, _token_pair, expiry)| {
+                        handler(order_type, amount_val, price_val, String::new(), expiry);
+                    }))
+                },
             }
 
-            // 提现表单卡片
+            // 代币选择器
             div {
                 class: "p-6 rounded-lg",
                 style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-
-                h3 {
-                    class: "text-lg font-semibold mb-4",
-                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                    "提现到法币"
-                }
-
                 div {
-                    class: "space-y-4",
-
-                    // ✅ 移除链选择器：智能自动选择，提升用户体验
-                    // 链会根据 from_token 自动适配（ETH→ethereum, BTC→bitcoin等）
-
-                    // 代币选择（From）
+                    class: "grid grid-cols-1 md:grid-cols-2 gap-4",
                     div {
                         label {
                             class: "block text-sm font-medium mb-2",
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {crate::i18n::translations::get_text("withdraw.select_token", &app_state.language.read())}
+                            "From (支付代币)"
                         }
                         TokenSelector {
                             chain: *chain_type.read(),
                             selected_token: from_token,
-                            wallet_address: current_wallet.read().as_ref().and_then(|w| w.accounts.first().map(|a| a.address.clone())),
+                            wallet_address: current_wallet
+                                .read()
+                                .as_ref()
+                                .and_then(|w| account_for_chain(w, *chain_type.read()))
+                                .map(|a| a.address.clone()),
                         }
-                        div {
-                            class: "text-xs mt-1",
-                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "系统将自动将代币兑换为稳定币，然后提现为法币"
+                        ChainAccountPrompt {
+                            chain: *chain_type.read(),
+                            token_symbol: from_token.read().as_ref().map(|t| t.symbol.clone()),
                         }
                     }
-
-                    // 数量输入
                     div {
                         label {
                             class: "block text-sm font-medium mb-2",
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {crate::i18n::translations::get_text("withdraw.amount_label", &app_state.language.read())}
+                            "To (接收代币)"
                         }
-                        input {
-                            class: "w-full p-3 rounded-lg",
-                            style: format!("background: {}; border: 1px solid {}; color: {};",
-                                Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
-                            r#type: "number",
-                            value: "{amount.read()}",
-                            oninput: move |e| amount.set(e.value()),
-                            placeholder: "0.0",
-                            step: "0.000001"
-                        }
-                        div {
-                            class: "text-xs mt-1",
-                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            "预计法币金额将在下方显示"
+                        TokenSelector {
+                            chain: *chain_type.read(),
+                            selected_token: to_token,
+                            wallet_address: None,
                         }
                     }
+                }
+            }
+
+            // 限价单列表
+            div {
+                class: "p-6 rounded-lg mt-6",
+                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                h3 {
+                    class: "text-lg font-semibold mb-4",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    "📋 我的限价单"
+                }
+
+                // 状态筛选与排序（服务端处理）
+                div {
+                    class: "flex flex-col sm:flex-row justify-between items-start sm:items-center gap-3 mb-4",
+                    StatusFilterChips {
+                        state: order_query,
+                        options: LIMIT_ORDER_STATUS_FILTERS.to_vec(),
+                    }
+                    SortControls { state: order_query }
+                }
 
-                    // 提现方式选择（6个国际标准方式）
+                if limit_orders.state.read().loading {
                     div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            {crate::i18n::translations::get_text("withdraw.method", &app_state.language.read())}
+                        class: "space-y-3",
+                        for i in 0..3 {
+                            SkeletonTableRow { key: "{i}" }
                         }
-                        div {
-                            class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-2",
-                            // 1. 银行卡/借记卡（推荐）
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *withdraw_method.read() == "bank_card" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *withdraw_method.read() == "bank_card" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| withdraw_method.set("bank_card".to_string()),
-                                div {
-                                    class: "font-medium flex items-center gap-2",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    span { "💳 银行卡/借记卡" }
-                                    span {
-                                        class: "text-xs px-2 py-0.5 rounded",
-                                        style: "background: rgba(99, 102, 241, 0.2); color: rgb(99, 102, 241);",
-                                        "推荐"
+                    }
+                } else if let Some(err) = limit_orders.state.read().error.clone() {
+                    LoadFailedState {
+                        title: t("empty.load_failed.title"),
+                        message: err,
+                        retry_label: t("empty.load_failed.retry"),
+                        on_retry: move |_| limit_orders.reload(),
+                    }
+                } else if limit_orders.state.read().items.is_empty() && order_query.read().has_filters() {
+                    FilteredEmptyState {
+                        message: "当前筛选条件下没有限价单".to_string(),
+                        on_clear: move |_| order_query.write().clear_filters(),
+                    }
+                } else if limit_orders.state.read().items.is_empty() {
+                    EmptyState {
+                        icon: "🎯".to_string(),
+                        title: t("empty.limit_orders.title"),
+                        description: t("empty.limit_orders.description"),
+                        cta_label: t("empty.limit_orders.cta"),
+                        on_cta: move |_| {
+                            if let Some(form) = web_sys::window()
+                                .and_then(|w| w.document())
+                                .and_then(|d| d.get_element_by_id("limit-order-form"))
+                            {
+                                form.scroll_into_view();
+                            }
+                        },
+                    }
+                } else {
+                    {
+                        // 已过期的订单单独分组显示在下方
+                        let (expired_orders, active_orders): (Vec<_>, Vec<_>) = limit_orders
+                            .state
+                            .read()
+                            .items
+                            .iter()
+                            .cloned()
+                            .partition(|o| o.status == "expired");
+                        rsx! {
+                            div {
+                                class: "space-y-3",
+                                for order in active_orders {
+                                    LimitOrderRow {
+                                        key: "{order.order_id}",
+                                        order: order.clone(),
+                                        now: expiry_now,
+                                        events: limit_edit::timeline(&order, timelines.read().events(&order.order_id)),
+                                        on_cancel: move |order_id: String| pending_cancel_order.set(Some(order_id)),
+                                        on_extend: move |order_id: String| extend_expiry_handler(order_id),
+                                        on_edit: move |order: LimitOrderResponse| editing_order.set(Some(order)),
                                     }
                                 }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "1-3工作日 · 全球支持"
-                                }
                             }
-
-                            // 2. PayPal
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *withdraw_method.read() == "paypal" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *withdraw_method.read() == "paypal" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| withdraw_method.set("paypal".to_string()),
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "📱 PayPal"
-                                }
+                            if !expired_orders.is_empty() {
                                 div {
-                                    class: "text-xs mt-1",
+                                    class: "text-sm font-medium mt-6 mb-3",
                                     style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 全球支付"
-                                }
-                            }
-
-                            // 3. Apple Pay
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *withdraw_method.read() == "apple_pay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *withdraw_method.read() == "apple_pay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| withdraw_method.set("apple_pay".to_string()),
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "🍎 Apple Pay"
+                                    {format!("已过期 ({})", expired_orders.len())}
                                 }
                                 div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · iOS设备"
+                                    class: "space-y-3 opacity-75",
+                                    for order in expired_orders {
+                                        LimitOrderRow {
+                                            key: "{order.order_id}",
+                                            order: order.clone(),
+                                            now: expiry_now,
+                                            events: limit_edit::timeline(&order, timelines.read().events(&order.order_id)),
+                                            on_cancel: move |order_id: String| pending_cancel_order.set(Some(order_id)),
+                                            on_extend: move |order_id: String| extend_expiry_handler(order_id),
+                                            on_edit: move |order: LimitOrderResponse| editing_order.set(Some(order)),
+                                        }
+                                    }
                                 }
                             }
+                        }
+                    }
+                }
 
-                            // 4. Google Pay
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *withdraw_method.read() == "google_pay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *withdraw_method.read() == "google_pay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| withdraw_method.set("google_pay".to_string()),
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "📱 Google Pay"
-                                }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · Android设备"
-                                }
-                            }
+                // 分页与每页数量
+                PaginationControls {
+                    state: order_query,
+                    total_pages: limit_orders.state.read().total_pages,
+                }
+            }
 
-                            // 5. 支付宝
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *withdraw_method.read() == "alipay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *withdraw_method.read() == "alipay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| withdraw_method.set("alipay".to_string()),
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "💰 支付宝 Alipay"
-                                }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 中国地区"
-                                }
-                            }
+            // 取消限价单确认
+            ConfirmAction {
+                open: pending_cancel_order.read().is_some(),
+                risk: RiskLevel::Caution,
+                title: "取消限价单".to_string(),
+                message: format!(
+                    "确定要取消订单 {} 吗？取消后需重新下单。",
+                    pending_cancel_order.read().clone().unwrap_or_default()
+                ),
+                confirm_text: "确认取消".to_string(),
+                cancel_text: "保留订单".to_string(),
+                busy: cancelling_order(),
+                on_cancel: move |_| pending_cancel_order.set(None),
+                on_confirm: move |_| {
+                    if let Some(order_id) = pending_cancel_order.peek().clone() {
+                        cancel_order_handler(order_id);
+                    }
+                },
+            }
 
-                            // 6. 微信支付
-                            button {
-                                class: "p-3 rounded-lg border text-left transition-all hover:scale-105",
-                                style: format!(
-                                    "background: {}; border-color: {}; color: {};",
-                                    if *withdraw_method.read() == "wechat_pay" {
-                                        "rgba(99, 102, 241, 0.15)"
-                                    } else {
-                                        Colors::BG_SECONDARY
-                                    },
-                                    if *withdraw_method.read() == "wechat_pay" {
-                                        Colors::TECH_PRIMARY
-                                    } else {
-                                        Colors::BORDER_PRIMARY
-                                    },
-                                    Colors::TEXT_PRIMARY
-                                ),
-                                onclick: move |_| withdraw_method.set("wechat_pay".to_string()),
-                                div {
-                                    class: "font-medium",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "💬 微信支付 WeChat Pay"
-                                }
-                                div {
-                                    class: "text-xs mt-1",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "即时到账 · 中国地区"
-                                }
-                            }
+            // 修改限价单
+            if let Some(order) = editing_order.read().clone() {
+                EditLimitOrderDialog {
+                    key: "{order.order_id}",
+                    order: order,
+                    busy: saving_edit(),
+                    on_close: move |_| {
+                        if !*saving_edit.peek() {
+                            editing_order.set(None);
                         }
+                    },
+                    on_submit: edit_order_handler,
+                }
+            }
+        }
+    }
+}
+
+/// 修改限价单对话框（预填当前价格与数量）
+#[component]
+fn EditLimitOrderDialog(
+    order: LimitOrderResponse,
+    busy: bool,
+    on_close: EventHandler<()>,
+    /// (原订单, 新限价, 新数量)
+    on_submit: EventHandler<(LimitOrderResponse, String, String)>,
+) -> Element {
+    let mut price = use_signal(|| order.limit_price.clone());
+    let mut amount = use_signal(|| order.amount.clone());
+    let validation = limit_edit::validate_edit(&order, &price.read(), &amount.read());
+    let summary = limit_edit::change_summary(&order, &price.read(), &amount.read());
+    let input_style = format!(
+        "background: {}; border: 1px solid {}; color: {};",
+        Colors::BG_PRIMARY,
+        Colors::BORDER_PRIMARY,
+        Colors::TEXT_PRIMARY
+    );
+
+    rsx! {
+        Modal {
+            open: true,
+            onclose: move |_| on_close.call(()),
+            title: Some(format!("修改限价单 #{}", order.order_id)),
+            div {
+                class: "space-y-4",
+                div {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {format!("{} {} → {}", order.order_type, order.from_token, order.to_token)}
+                }
+                div {
+                    label {
+                        class: "block text-sm font-medium mb-2",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "限价"
+                    }
+                    input {
+                        class: "w-full p-3 rounded-lg",
+                        style: "{input_style}",
+                        r#type: "number",
+                        value: "{price}",
+                        oninput: move |e| price.set(e.value()),
+                        step: "0.000001"
+                    }
+                }
+                div {
+                    label {
+                        class: "block text-sm font-medium mb-2",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "数量"
+                    }
+                    input {
+                        class: "w-full p-3 rounded-lg",
+                        style: "{input_style}",
+                        r#type: "number",
+                        value: "{amount}",
+                        oninput: move |e| amount.set(e.value()),
+                        step: "0.000001"
+                    }
+                }
+                if !summary.is_empty() {
+                    div {
+                        class: "text-xs",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "{summary}"
+                    }
+                }
+                div {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                    "若服务端不支持直接修改，将先取消原订单，确认取消后再按新参数重新下单（订单号会变化）。"
+                }
+                // 未作修改时只禁用提交，不提示错误
+                FieldError { error: validation.clone().err().filter(|_| !summary.is_empty()) }
+                div {
+                    class: "flex justify-end gap-2",
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        disabled: busy,
+                        onclick: move |_| on_close.call(()),
+                        "取消"
+                    }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        size: ButtonSize::Small,
+                        disabled: busy || validation.is_err(),
+                        loading: busy,
+                        onclick: {
+                            let order = order.clone();
+                            move |_| {
+                                on_submit.call((
+                                    order.clone(),
+                                    price.peek().trim().to_string(),
+                                    amount.peek().trim().to_string(),
+                                ))
+                            }
+                        },
+                        "保存修改"
                     }
+                }
+            }
+        }
+    }
+}
+
+/// 限价单列表行（含到期倒计时、修改/延长有效期操作与事件时间线）
+#[component]
+fn LimitOrderRow(
+    order: LimitOrderResponse,
+    /// 倒计时使用的当前时间（每秒刷新）
+    now: Signal<DateTime<Utc>>,
+    /// 订单事件时间线
+    events: Vec<LimitOrderEvent>,
+    on_cancel: EventHandler<String>,
+    on_extend: EventHandler<String>,
+    on_edit: EventHandler<LimitOrderResponse>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut show_timeline = use_signal(|| false);
+    let now = now();
+    let urgency = limit_expiry::urgency(&order, now);
+    let expiry_label = limit_expiry::expiry_label(&order, now);
+    let border_color = if urgency == Some(ExpiryUrgency::Soon) {
+        "rgba(245, 158, 11, 1)"
+    } else {
+        Colors::BORDER_PRIMARY
+    };
+    let expiry_color = match urgency {
+        Some(ExpiryUrgency::Soon) | Some(ExpiryUrgency::Expired) => "rgba(245, 158, 11, 1)",
+        _ => Colors::TEXT_SECONDARY,
+    };
+    let short_id = if order.order_id.len() > 8 {
+        format!("订单 #{}...", &order.order_id[..8])
+    } else {
+        format!("订单 #{}", order.order_id)
+    };
+    let (status_bg, status_color) = match order.status.as_str() {
+        "pending" => ("rgba(59, 130, 246, 0.1)", "rgba(59, 130, 246, 1)"),
+        "partially_filled" => ("rgba(234, 179, 8, 0.1)", "rgba(234, 179, 8, 1)"),
+        "filled" => ("rgba(34, 197, 94, 0.1)", "rgba(34, 197, 94, 1)"),
+        "cancelled" | "expired" | "failed" => ("rgba(239, 68, 68, 0.1)", "rgba(239, 68, 68, 1)"),
+        _ => (Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
+    };
+    let status_label = match order.status.as_str() {
+        "pending" => "待执行",
+        "partially_filled" => "部分执行",
+        "filled" => "已完成",
+        "cancelled" => "已取消",
+        "expired" => "已过期",
+        "failed" => "失败",
+        other => other,
+    };
+    let can_extend = limit_expiry::is_open(&order) && order.expires_at.is_some();
 
-                    // 收款账户信息输入
+    rsx! {
+        div {
+            class: "p-4 rounded-lg",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, border_color),
+            div {
+                class: "flex items-start justify-between mb-2",
+                div {
+                    class: "flex-1",
                     div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
+                        class: "flex items-center gap-2 mb-1",
+                        span {
+                            class: "text-sm font-semibold",
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            match withdraw_method.read().as_str() {
-                                "bank_card" => "银行卡号",
-                                "paypal" => "PayPal账户",
-                                "apple_pay" => "Apple ID",
-                                "google_pay" => "Google账户",
-                                "alipay" => "支付宝账号",
-                                "wechat_pay" => "微信账号",
-                                _ => "收款账户信息"
-                            }
+                            "{short_id}"
                         }
-                        input {
-                            class: "w-full p-3 rounded-lg",
-                            style: format!("background: {}; border: 1px solid {}; color: {};",
-                                Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
-                            r#type: "text",
-                            value: "{recipient_info.read()}",
-                            oninput: move |e| recipient_info.set(e.value()),
-                            placeholder: match withdraw_method.read().as_str() {
-                                "bank_card" => "银行卡号 (例: 6222 0000 0000 0000)",
-                                "paypal" => "PayPal账号 (例: your@email.com)",
-                                "apple_pay" => "Apple ID (例: your@icloud.com)",
-                                "google_pay" => "Google账号 (例: your@gmail.com)",
-                                "alipay" => "支付宝账号 (手机号或邮箱)",
-                                "wechat_pay" => "微信账号 (微信ID或手机号)",
-                                _ => "请输入收款账户信息"
-                            }
+                        span {
+                            class: "px-2 py-1 rounded text-xs",
+                            style: format!("background: {}; color: {};", status_bg, status_color),
+                            "{status_label}"
+                        }
+                    }
+                    div {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        {
+                            format!(
+                                "{} {} {} @ {} {}",
+                                order.order_type,
+                                order.amount,
+                                order.from_token,
+                                order.limit_price,
+                                order.to_token
+                            )
                         }
+                    }
+                    if let Some(filled) = &order.filled_amount {
                         div {
                             class: "text-xs mt-1",
                             style: format!("color: {};", Colors::TEXT_SECONDARY),
-                            match withdraw_method.read().as_str() {
-                                "bank_card" => "⚠️ 银行卡提现需1-3工作日，请确保卡号准确",
-                                "paypal" => "✅ PayPal即时到账，支持全球200+国家",
-                                "apple_pay" => "✅ Apple Pay即时到账，需iOS设备绑定",
-                                "google_pay" => "✅ Google Pay即时到账，需Android设备绑定",
-                                "alipay" => "✅ 支付宝即时到账，中国地区首选",
-                                "wechat_pay" => "✅ 微信支付即时到账，中国地区首选",
-                                _ => "请确保账户信息准确，错误信息可能导致提现失败"
-                            }
+                            {format!("已执行: {}", filled)}
                         }
                     }
                 }
-            }
-
-            // 报价显示区域
-            if *quote_loading.read() && !amount.read().is_empty() {
-                LoadingState {
-                    message: Some("正在计算提现报价...".to_string()),
-                    progress: None,
-                    estimated_time: Some(3),
-                }
-            } else if let Some(q) = quote.read().as_ref() {
                 div {
-                    class: "p-6 rounded-lg",
-                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                    h3 {
-                        class: "text-lg font-semibold mb-4",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        "💰 提现详情"
+                    class: "flex gap-2",
+                    if can_extend {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            onclick: {
+                                let order_id = order.order_id.clone();
+                                move |_| on_extend.call(order_id.clone())
+                            },
+                            {format!("延长 {} 天", limit_expiry::EXTEND_DAYS)}
+                        }
                     }
-                    div {
-                        class: "space-y-2",
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "提现代币" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "{q.token_amount} {q.token_symbol}"
-                            }
+                    if limit_edit::can_edit(&order) {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            onclick: {
+                                let order = order.clone();
+                                move |_| on_edit.call(order.clone())
+                            },
+                            "编辑"
                         }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "中间稳定币" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                {
-                                    let amount = q.stablecoin_amount.parse::<f64>().unwrap_or(0.0);
-                                    format!("{} {}", format_currency(amount, 2), q.stablecoin_symbol)
-                                }
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "最终法币金额" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                {
-                                    let amount = q.fiat_amount.parse::<f64>().unwrap_or(0.0);
-                                    format!("${} {}", format_currency(amount, 2), q.fiat_currency)
-                                }
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "代币→稳定币汇率" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                {
-                                    let rate = q.exchange_rate_token_to_stable.parse::<f64>().unwrap_or(0.0);
-                                    format!("1 {} = {} {}", q.token_symbol, format_currency(rate, 2), q.stablecoin_symbol)
-                                }
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "稳定币→法币汇率" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                {
-                                    let rate = q.exchange_rate_stable_to_fiat.parse::<f64>().unwrap_or(1.0);
-                                    format!("1 {} = ${:.2}", q.stablecoin_symbol, rate)
-                                }
-                            }
-                        }
-                        // ✅ 费用明细（修正后，行业标准透明度）
-                        div {
-                            class: "mt-4 pt-4",
-                            style: format!("border-top: 1px solid {};", Colors::BORDER_PRIMARY),
-                            div {
-                                class: "text-sm font-medium mb-3",
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "💰 费用明细"
-                            }
-
-                            // 1. 提现手续费（第三方服务商：Banxa/MoonPay）
-                            if !q.withdrawal_fee.is_empty() {
-                                div {
-                                    class: "flex justify-between items-center py-1",
-                                    span {
-                                        class: "text-sm",
-                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                        "🏦 提现手续费 (Banxa)"
-                                    }
-                                    span {
-                                        class: "text-sm font-medium",
-                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                        {
-                                            // 格式化为美元金额（2位小数）
-                                            let fee = q.withdrawal_fee.parse::<f64>().unwrap_or(0.0);
-                                            format!("${:.2}", fee)
-                                        }
-                                    }
-                                }
-                            }
-
-                            // 2. 平台服务费（行业标准：完全免费！）
-                            div {
-                                class: "flex justify-between items-center py-1",
-                                span {
-                                    class: "text-sm",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    "平台服务费 (IronCore)"
-                                }
-                                span {
-                                    class: "text-sm font-bold",
-                                    style: "color: #22c55e;",  // 绿色强调免费
-                                    "$0.00 免费!"
-                                }
-                            }
-
-                            // 3. 交换手续费（如果涉及代币→稳定币转换）
-                            if !q.swap_fee.is_empty() {
-                                div {
-                                    class: "flex justify-between items-center py-1",
-                                    span {
-                                        class: "text-sm",
-                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                        "🔄 交换手续费"
-                                    }
-                                    span {
-                                        class: "text-sm font-medium",
-                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                        {
-                                            // 格式化为美元金额（2位小数）
-                                            let fee = q.swap_fee.parse::<f64>().unwrap_or(0.0);
-                                            format!("${:.2}", fee)
-                                        }
-                                    }
-                                }
-                            }
-
-                            // 总手续费（加粗显示）
-                            div {
-                                class: "flex justify-between items-center py-2 mt-2 pt-2",
-                                style: format!("border-top: 1px dashed {};", Colors::BORDER_PRIMARY),
-                                span {
-                                    class: "text-sm font-semibold",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "💰 总手续费"
-                                }
-                                span {
-                                    class: "text-base font-bold",
-                                    style: format!("color: {};", Colors::TECH_PRIMARY),
-                                    {
-                                        // ✅ 格式化为美元金额（千位分隔符 + 2位小数）
-                                        let fee = q.fee_amount.parse::<f64>().unwrap_or(0.0);
-                                        format!("${}", format_currency(fee, 2))
-                                    }
-                                }
-                            }
-
-                            // ✅ 预计到账金额（行业最佳实践：必须显示）
-                            div {
-                                class: "flex justify-between items-center py-3 mt-2",
-                                style: format!("background: rgba(34, 197, 94, 0.1); border-radius: 8px; padding: 12px; border: 2px solid rgba(34, 197, 94, 0.3);"),
-                                span {
-                                    class: "text-base font-bold",
-                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                    "💵 您将收到"
-                                }
-                                span {
-                                    class: "text-lg font-bold",
-                                    style: "color: #22c55e;", // 绿色，强调到账金额
-                                    {
-                                        // ✅ 计算净收入：最终法币金额 - 总手续费（千位分隔符）
-                                        let fiat_amount = q.fiat_amount.parse::<f64>().unwrap_or(0.0);
-                                        let fee = q.fee_amount.parse::<f64>().unwrap_or(0.0);
-                                        let net_amount = fiat_amount - fee;
-                                        format!("${} {}", format_currency(net_amount, 2), q.fiat_currency)
-                                    }
-                                }
-                            }
-                        }
-                        div {
-                            class: "flex justify-between",
-                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "预计到账时间" }
-                            span {
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "{q.estimated_arrival}"
-                            }
-                        }
-                        div {
-                            class: "p-3 mt-4 rounded",
-                            style: "background: rgba(34, 197, 94, 0.1); border: 1px solid rgba(34, 197, 94, 0.3);",  // 绿色强调免费
-                            div {
-                                class: "text-xs font-semibold mb-1",
-                                style: "color: #22c55e;",
-                                "🎉 IronCore平台费永久免费！"
-                            }
-                            div {
-                                class: "text-xs",
-                                style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                "💡 系统将自动执行两步流程："
-                            }
-                            div {
-                                class: "text-xs mt-1",
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "1. {q.token_symbol} → {q.stablecoin_symbol}（自动交换）"
-                            }
-                            div {
-                                class: "text-xs",
-                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                "2. {q.stablecoin_symbol} → {q.fiat_currency}（提现到账）"
-                            }
-                        }
-                    }
-                }
-            } else if !amount.read().is_empty() && from_token.read().is_some() {
-                div {
-                    class: "p-6 rounded-lg",
-                    style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                    h3 {
-                        class: "text-lg font-semibold mb-4",
-                        style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        "💰 提现详情"
-                    }
-                    div {
-                        class: "text-sm text-center py-4",
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "正在计算报价..."
-                    }
-                }
-            }
-
-            // 错误消息
-            ErrorMessage {
-                message: error_message.read().clone(),
-            }
-
-            // 提现按钮
-            Button {
-                variant: ButtonVariant::Primary,
-                size: ButtonSize::Large,
-                onclick: {
-                    let mut create_withdraw_order_handler = create_withdraw_order_handler;
-                    move |_| create_withdraw_order_handler()
-                },
-                disabled: amount.read().is_empty()
-                    || amount.read().parse::<f64>().unwrap_or(0.0) <= 0.0
-                    || from_token.read().is_none()
-                    || recipient_info.read().is_empty()
-                    || quote.read().is_none()
-                    || *loading.read(),
-                loading: *loading.read(),
-                class: "w-full",
-                if *loading.read() {
-                    "创建提现订单中..."
-                } else {
-                    "提交提现申请"
-                }
-            }
-        }
-
-        // 用户反馈
-        UserFeedback {
-            feedback_type: *feedback_type.read(),
-            message: feedback_message.read().clone(),
-            visible: *show_feedback.read(),
-            auto_hide_ms: 5000,
-            on_close: Some(EventHandler::new({
-                let mut show_feedback = show_feedback;
-                move |_| show_feedback.set(false)
-            })),
-        }
-    }
-}
-
-// =============================================================================
-// COMPONENT: LimitOrderTab - 限价单标签页 (~600行)
-// 功能: 设置限价单,自动执行交易
-// =============================================================================
-
-// =============================================================================
-// COMPONENT: LimitOrderTab - 限价单标签页 (~600行)
-// 功能: 设置限价单,自动执行交易
-// =============================================================================
-
-/// 限价单标签页
-#[component]
-fn LimitOrderTab(
-    /// 选中的链
-    selected_chain: Signal<String>,
-    /// 通知回调
-    on_notification: Option<EventHandler<(NotificationType, String, String, Option<String>)>>,
-) -> Element {
-    let app_state = use_context::<AppState>();
-
-    // 缓存和错误日志服务
-    let cache = use_signal(|| MemoryCache::new(Duration::from_secs(30)));
-    let error_logger = use_signal(|| ErrorLogger::new(100));
-
-    let chain_type = use_memo(move || {
-        ChainType::from_str(&selected_chain.read()).unwrap_or(ChainType::Ethereum)
-    });
-    let limit_order_type = use_signal(|| LimitOrderType::Buy);
-    let from_token = use_signal(|| Option::<TokenInfo>::None);
-    let to_token = use_signal(|| Option::<TokenInfo>::None);
-    let amount = use_signal(|| String::new());
-    let limit_price = use_signal(|| String::new());
-    let expiry_days = use_signal(|| 7u32);
-    let error_message = use_signal(|| Option::<String>::None);
-    let loading = use_signal(|| false);
-    let platform_fee = use_signal(|| Option::<f64>::None); // ✅ 平台服务费
-
-    // 限价单列表
-    let orders = use_signal(|| Vec::<LimitOrderResponse>::new());
-    let orders_loading = use_signal(|| false);
-    let orders_error = use_signal(|| Option::<String>::None);
-    let current_page = use_signal(|| 1u32);
-    let total_pages = use_signal(|| 1u32);
-
-    // ✅ 计算平台服务费（当金额变化时，使用from_token的美元价值）
-    use_effect({
-        let app_state_clone = app_state.clone();
-        let amount_sig = amount;
-        let chain_sig = selected_chain;
-        let from_token_sig = from_token;
-        let mut platform_fee_sig = platform_fee;
-
-        move || {
-            let amount_val = amount_sig.read().clone();
-            let chain_val = chain_sig.read().clone();
-            let from_token_val = from_token_sig.read().clone();
-
-            if !amount_val.is_empty() {
-                if let Ok(amount_f64) = amount_val.parse::<f64>() {
-                    if amount_f64 > 0.0 {
-                        // 检查是否选择了代币
-                        if let Some(token_info) = from_token_val {
-                            let token_symbol = token_info.symbol.clone();
-                            let app_state_for_spawn = app_state_clone.clone();
-                            let mut platform_fee_sig_spawn = platform_fee_sig;
-
-                            spawn(async move {
-                                // 获取from_token的美元价格
-                                let price_service = PriceService::new(app_state_for_spawn.clone());
-                                match price_service.get_price(&token_symbol).await {
-                                    Ok(price_data) => {
-                                        let usd_value = amount_f64 * price_data.usd;
-                                        log::info!(
-                                            "限价单金额: {} {}, 美元价值: ${:.2}",
-                                            amount_f64,
-                                            token_symbol,
-                                            usd_value
-                                        );
-
-                                        // 使用美元价值计算平台服务费
-                                        let fee_service = FeeService::new(app_state_for_spawn);
-                                        match fee_service
-                                            .calculate(
-                                                &chain_val,
-                                                "limit_order",
-                                                usd_value, // 传递美元价值而不是代币数量
-                                            )
-                                            .await
-                                        {
-                                            Ok(fee_quote) => {
-                                                platform_fee_sig_spawn
-                                                    .set(Some(fee_quote.platform_fee));
-                                                log::info!(
-                                                    "平台服务费(LimitOrder): ${:.2} (规则ID: {})",
-                                                    fee_quote.platform_fee,
-                                                    fee_quote.applied_rule_id
-                                                );
-                                            }
-                                            Err(e) => {
-                                                log::error!("计算平台服务费失败: {}", e);
-                                                platform_fee_sig_spawn.set(None);
-                                            }
-                                        }
-                                    }
-                                    Err(e) => {
-                                        log::error!("获取{}价格失败: {}", token_symbol, e);
-                                        platform_fee_sig_spawn.set(None);
-                                    }
-                                }
-                            });
-                        } else {
-                            platform_fee_sig.set(None);
-                        }
-                    } else {
-                        platform_fee_sig.set(None);
-                    }
-                }
-            } else {
-                platform_fee_sig.set(None);
-            }
-        }
-    });
-
-    // 加载限价单列表
-    use_effect({
-        let app_state_clone = app_state.clone();
-        let mut orders_sig = orders;
-        let mut orders_loading_sig = orders_loading;
-        let mut orders_error_sig = orders_error;
-        let mut total_pages_sig = total_pages;
-        let current_page_sig = current_page;
-        let mut cache_sig = cache;
-        let mut error_logger_sig = error_logger;
-
-        move || {
-            let app_state_for_spawn = app_state_clone.clone();
-            let page = *current_page_sig.read();
-
-            // 检查用户是否已登录，并验证token是否存在
-            let user_state = app_state_for_spawn.user.read();
-            let is_authenticated = user_state.is_authenticated;
-            let has_token = user_state
-                .access_token
-                .as_ref()
-                .map(|t| !t.is_empty())
-                .unwrap_or(false);
-
-            if !is_authenticated || !has_token {
-                orders_loading_sig.set(false);
-                let error_msg = if !is_authenticated {
-                    "请先登录以查看限价单".to_string()
-                } else {
-                    "Token已失效，请重新登录以查看限价单".to_string()
-                };
-                orders_error_sig.set(Some(error_msg));
-                orders_sig.set(Vec::new());
-                return;
-            }
-
-            // 验证token是否有效（通过检查长度，JWT token通常较长）
-            let token_len = user_state
-                .access_token
-                .as_ref()
-                .map(|t| t.len())
-                .unwrap_or(0);
-            if token_len < 20 {
-                orders_loading_sig.set(false);
-                orders_error_sig.set(Some("Token格式无效，请重新登录".to_string()));
-                orders_sig.set(Vec::new());
-                return;
-            }
-
-            spawn(async move {
-                orders_loading_sig.set(true);
-                orders_error_sig.set(None);
-
-                // 检查缓存
-                let cache_key = format!("limit_orders:page:{}", page);
-                if let Some(cached_orders) =
-                    cache_sig.read().get::<Vec<LimitOrderResponse>>(&cache_key)
-                {
-                    orders_sig.set(cached_orders);
-                    orders_loading_sig.set(false);
-                    return;
-                }
-
-                // 确保在spawn之前获取最新的app_state，这样token是最新的
-                let limit_order_service = LimitOrderService::new(app_state_for_spawn);
-                let query = LimitOrderQuery {
-                    order_type: None,
-                    status: None,
-                    page: Some(page),
-                    page_size: Some(10),
-                };
-
-                match limit_order_service.get_orders(Some(query)).await {
-                    Ok(response) => {
-                        // 保存到缓存
-                        cache_sig.write().set(
-                            cache_key,
-                            response.orders.clone(),
-                            Some(Duration::from_secs(60)),
-                        );
-                        orders_sig.set(response.orders);
-                        total_pages_sig.set(response.total_pages);
-                    }
-                    Err(e) => {
-                        // 检查是否是401错误（未授权）
-                        let is_unauthorized = e.to_string().contains("401")
-                            || e.to_string().to_lowercase().contains("unauthorized");
-
-                        // 不要自动清除认证状态，只显示错误消息
-                        // 让用户自己决定是否要重新登录
-                        // 这样可以避免在token暂时失效时强制登出用户
-
-                        let error_msg = if is_unauthorized {
-                            "⚠️ 认证已过期，请重新登录以查看限价单\n\n提示：点击右上角\"登出\"按钮，然后重新登录即可解决此问题。".to_string()
-                        } else {
-                            format!("加载限价单列表失败: {}", e)
-                        };
-                        orders_error_sig.set(Some(error_msg.clone()));
-                        orders_sig.set(Vec::new());
-                        // 记录错误日志
-                        error_logger_sig.write().log(
-                            ErrorLevel::Error,
-                            error_msg,
-                            Some(serde_json::json!({
-                                "page": page,
-                            })),
-                        );
-                    }
-                }
-
-                orders_loading_sig.set(false);
-            });
-        }
-    });
-
-    // 取消限价单处理
-    let cancel_order_handler = {
-        let app_state_clone = app_state.clone();
-        let orders_sig = orders;
-        let notif_handler = on_notification.clone();
-
-        move |order_id: String| {
-            let app_state_for_spawn = app_state_clone;
-            let mut orders_sig_for_spawn = orders_sig;
-            let notif_handler_for_spawn = notif_handler.clone();
-            let order_id_clone = order_id.clone();
-
-            spawn(async move {
-                let limit_order_service = LimitOrderService::new(app_state_for_spawn);
-
-                match limit_order_service.cancel_order(&order_id_clone).await {
-                    Ok(_) => {
-                        // 从列表中移除已取消的订单
-                        let mut orders_list = orders_sig_for_spawn.read().clone();
-                        orders_list.retain(|o| o.order_id != order_id_clone);
-                        orders_sig_for_spawn.set(orders_list);
-
-                        if let Some(handler) = notif_handler_for_spawn {
-                            handler.call((
-                                NotificationType::Success,
-                                "限价单已取消".to_string(),
-                                format!("订单 {} 已成功取消", order_id_clone),
-                                Some(order_id_clone),
-                            ));
-                        }
-                    }
-                    Err(e) => {
-                        if let Some(handler) = notif_handler_for_spawn {
-                            handler.call((
-                                NotificationType::Error,
-                                "取消限价单失败".to_string(),
-                                e,
-                                None,
-                            ));
-                        }
-                    }
-                }
-            });
-        }
-    };
-
-    // 获取当前钱包地址
-    let current_wallet = use_memo(move || {
-        let wallet_state = app_state.wallet.read();
-        wallet_state.get_selected_wallet().cloned()
-    });
-
-    // 创建限价单处理
-    let create_limit_order_handler = {
-        let app_state_clone = app_state.clone();
-        let amount_sig = amount;
-        let limit_price_sig = limit_price;
-        let from_token_sig = from_token;
-        let to_token_sig = to_token;
-        let chain_sig = selected_chain;
-        let loading_sig = loading;
-        let mut err_sig = error_message;
-        let notif_handler = on_notification.clone();
-        let current_wallet_sig = current_wallet;
-
-        move |order_type: LimitOrderType,
-              amount_val: String,
-              price_val: String,
-              _token_pair: String,
-              expiry: u32| {
-            if amount_val.is_empty() || amount_val.parse::<f64>().unwrap_or(0.0) <= 0.0 {
-                err_sig.set(Some("请输入有效的数量".to_string()));
-                return;
-            }
-
-            if price_val.is_empty() || price_val.parse::<f64>().unwrap_or(0.0) <= 0.0 {
-                err_sig.set(Some("请输入有效的限价".to_string()));
-                return;
-            }
-
-            let from = from_token_sig.read().clone();
-            let to = to_token_sig.read().clone();
-
-            if from.is_none() || to.is_none() {
-                err_sig.set(Some("请选择代币".to_string()));
-                return;
-            }
-
-            let amount_clone = amount_val.clone();
-            let price_clone = price_val.clone();
-            let chain_clone = chain_sig.read().clone();
-            let app_state_for_spawn = app_state_clone.clone();
-            let mut loading_sig_for_spawn = loading_sig;
-            let mut err_sig_for_spawn = err_sig;
-            let notif_handler_for_spawn = notif_handler.clone();
-            let mut amount_sig_for_spawn = amount_sig;
-            let mut limit_price_sig_for_spawn = limit_price_sig;
-
-            let from_token_info_clone = from_token_sig.read().clone();
-            let to_token_info_clone = to_token_sig.read().clone();
-            let wallet_id_opt = current_wallet_sig
-                .read()
-                .as_ref()
-                .and_then(|w| w.accounts.first())
-                .map(|a| a.address.clone());
-
-            spawn(async move {
-                loading_sig_for_spawn.set(true);
-                err_sig_for_spawn.set(None);
-
-                // 获取代币信息
-                let from_token_info = from_token_info_clone;
-                let to_token_info = to_token_info_clone;
-
-                let from_symbol = from_token_info
-                    .as_ref()
-                    .map(|t| t.symbol.clone())
-                    .unwrap_or_default();
-                let to_symbol = to_token_info
-                    .as_ref()
-                    .map(|t| t.symbol.clone())
-                    .unwrap_or_default();
-
-                // 转换订单类型
-                let service_order_type = match order_type {
-                    LimitOrderType::Buy => ServiceLimitOrderType::Buy,
-                    LimitOrderType::Sell => ServiceLimitOrderType::Sell,
-                };
-
-                // 创建限价单服务实例
-                let limit_order_service = LimitOrderService::new(app_state_for_spawn);
-
-                // 调用后端API创建限价单
-                match limit_order_service
-                    .create_order(
-                        service_order_type,
-                        &from_symbol,
-                        &to_symbol,
-                        &amount_clone,
-                        &price_clone,
-                        &chain_clone,
-                        expiry,
-                        wallet_id_opt.as_deref(),
-                    )
-                    .await
-                {
-                    Ok(response) => {
-                        // 创建成功
-                        if let Some(handler) = notif_handler_for_spawn {
-                            handler.call((
-                                NotificationType::Success,
-                                "限价单创建成功".to_string(),
-                                format!(
-                                    "限价单已创建（订单ID: {}）：{} {} @ {}",
-                                    response.order_id,
-                                    amount_clone,
-                                    if order_type == LimitOrderType::Buy {
-                                        "买入"
-                                    } else {
-                                        "卖出"
-                                    },
-                                    price_clone
-                                ),
-                                Some(response.order_id),
-                            ));
-                        }
-
-                        // 清空表单
-                        amount_sig_for_spawn.set(String::new());
-                        limit_price_sig_for_spawn.set(String::new());
-                    }
-                    Err(e) => {
-                        // 创建失败
-                        err_sig_for_spawn.set(Some(e.clone()));
-                        if let Some(handler) = notif_handler_for_spawn {
-                            handler.call((
-                                NotificationType::Error,
-                                "限价单创建失败".to_string(),
-                                e,
-                                None,
-                            ));
+                    }
+                    if order.status == "pending" {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            onclick: {
+                                let order_id = order.order_id.clone();
+                                move |_| on_cancel.call(order_id.clone())
+                            },
+                            "取消"
                         }
                     }
                 }
-
-                loading_sig_for_spawn.set(false);
-            });
-        }
-    };
-
-    rsx! {
-        div {
-            class: "space-y-4",
-            LimitOrderForm {
-                order_type: limit_order_type,
-                from_token: from_token,
-                to_token: to_token,
-                amount: amount,
-                limit_price: limit_price,
-                expiry_days: expiry_days,
-                error_message: error_message,
-                loading: loading,
-                on_submit: {
-                    let mut handler = create_limit_order_handler;
-                    Some(EventHandler::new(move |(order_type, amount_val, price_val, _token_pair, expiry)| {
-                        handler(order_type, amount_val, price_val, String::new(), expiry);
-                    }))
-                },
             }
-
-            // 代币选择器
             div {
-                class: "p-6 rounded-lg",
-                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                div {
-                    class: "grid grid-cols-1 md:grid-cols-2 gap-4",
-                    div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "From (支付代币)"
-                        }
-                        TokenSelector {
-                            chain: *chain_type.read(),
-                            selected_token: from_token,
-                            wallet_address: current_wallet.read().as_ref().and_then(|w| w.accounts.first().map(|a| a.address.clone())),
-                        }
-                    }
-                    div {
-                        label {
-                            class: "block text-sm font-medium mb-2",
-                            style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "To (接收代币)"
-                        }
-                        TokenSelector {
-                            chain: *chain_type.read(),
-                            selected_token: to_token,
-                            wallet_address: None,
+                class: "flex flex-wrap justify-between gap-2 text-xs mt-2",
+                span {
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {format!("创建时间: {}", time::format_api_datetime(&order.created_at, &app_state.language.read()))}
+                }
+                if let Some(label) = expiry_label {
+                    span {
+                        class: if urgency == Some(ExpiryUrgency::Soon) { "font-semibold" } else { "" },
+                        style: format!("color: {};", expiry_color),
+                        if urgency == Some(ExpiryUrgency::Soon) {
+                            "⏰ {label}"
+                        } else {
+                            "{label}"
                         }
                     }
                 }
-            }
-
-            // 限价单列表
-            div {
-                class: "p-6 rounded-lg mt-6",
-                style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                h3 {
-                    class: "text-lg font-semibold mb-4",
-                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                    "📋 我的限价单"
+                button {
+                    class: "text-xs",
+                    style: format!("color: {};", Colors::TECH_PRIMARY),
+                    onclick: move |_| show_timeline.toggle(),
+                    if show_timeline() { "收起详情" } else { "查看详情" }
                 }
-
-                if *orders_loading.read() {
-                    div {
-                        class: "text-center py-8",
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "加载中..."
-                    }
-                } else if let Some(err) = orders_error.read().as_ref() {
-                    ErrorMessage {
-                        message: Some(err.clone()),
-                    }
-                } else if orders.read().is_empty() {
-                    div {
-                        class: "text-center py-8",
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "暂无限价单"
-                    }
-                } else {
-                    div {
-                        class: "space-y-3",
-                        for order in orders.read().iter() {
-                            div {
-                                class: "p-4 rounded-lg",
-                                style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
-                                div {
-                                    class: "flex items-start justify-between mb-2",
-                                    div {
-                                        class: "flex-1",
-                                        div {
-                                            class: "flex items-center gap-2 mb-1",
-                                            span {
-                                                class: "text-sm font-semibold",
-                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
-                                                {
-                                                    let order_id = &order.order_id;
-                                                    if order_id.len() > 8 {
-                                                        format!("订单 #{}...", &order_id[..8])
-                                                    } else {
-                                                        format!("订单 #{}", order_id)
-                                                    }
-                                                }
-                                            }
-                                            span {
-                                                class: "px-2 py-1 rounded text-xs",
-                                                style: format!(
-                                                    "background: {}; color: {};",
-                                                    match order.status.as_str() {
-                                                        "pending" => "rgba(59, 130, 246, 0.1)",
-                                                        "partially_filled" => "rgba(234, 179, 8, 0.1)",
-                                                        "filled" => "rgba(34, 197, 94, 0.1)",
-                                                        "cancelled" | "expired" | "failed" => "rgba(239, 68, 68, 0.1)",
-                                                        _ => Colors::BG_SECONDARY,
-                                                    },
-                                                    match order.status.as_str() {
-                                                        "pending" => "rgba(59, 130, 246, 1)",
-                                                        "partially_filled" => "rgba(234, 179, 8, 1)",
-                                                        "filled" => "rgba(34, 197, 94, 1)",
-                                                        "cancelled" | "expired" | "failed" => "rgba(239, 68, 68, 1)",
-                                                        _ => Colors::TEXT_SECONDARY,
-                                                    }
-                                                ),
-                                                match order.status.as_str() {
-                                                    "pending" => "待执行",
-                                                    "partially_filled" => "部分执行",
-                                                    "filled" => "已完成",
-                                                    "cancelled" => "已取消",
-                                                    "expired" => "已过期",
-                                                    "failed" => "失败",
-                                                    _ => order.status.as_str(),
-                                                }
-                                            }
-                                        }
-                                        div {
-                                            class: "text-sm",
-                                            style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                            {
-                                                format!(
-                                                    "{} {} {} @ {} {}",
-                                                    order.order_type,
-                                                    order.amount,
-                                                    order.from_token,
-                                                    order.limit_price,
-                                                    order.to_token
-                                                )
-                                            }
-                                        }
-                                        if let Some(filled) = &order.filled_amount {
-                                            div {
-                                                class: "text-xs mt-1",
-                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                                {
-                                                    format!("已执行: {}", filled)
-                                                }
-                                            }
-                                        }
-                                    }
-                                    if order.status == "pending" {
-                                        Button {
-                                            variant: ButtonVariant::Secondary,
-                                            size: ButtonSize::Small,
-                                            onclick: {
-                                                let order_id = order.order_id.clone();
-                                                let cancel_handler = cancel_order_handler;
-                                                move |_| {
-                                                    cancel_handler(order_id.clone());
-                                                }
-                                            },
-                                            "取消"
-                                        }
-                                    }
-                                }
-                                div {
-                                    class: "text-xs mt-2",
-                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                    {
-                                        format!("创建时间: {}", order.created_at)
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    // 分页
-                    if *total_pages.read() > 1 {
+            }
+            if show_timeline() {
+                div {
+                    class: "mt-3 pt-3 space-y-2",
+                    style: format!("border-top: 1px solid {};", Colors::BORDER_PRIMARY),
+                    for (i, event) in events.iter().enumerate() {
                         div {
-                            class: "flex items-center justify-center gap-2 mt-4",
-                            button {
-                                class: "px-3 py-1 text-sm rounded",
-                                style: format!(
-                                    "background: {}; color: {}; border: 1px solid {};",
-                                    if *current_page.read() > 1 { Colors::TECH_PRIMARY } else { Colors::BG_PRIMARY },
-                                    if *current_page.read() > 1 { "white" } else { Colors::TEXT_SECONDARY },
-                                    Colors::BORDER_PRIMARY
-                                ),
-                                disabled: *current_page.read() <= 1,
-                                onclick: {
-                                    let mut page_sig = current_page;
-                                    move |_| {
-                                        let current = *page_sig.read();
-                                        if current > 1 {
-                                            page_sig.set(current - 1);
-                                        }
-                                    }
-                                },
-                                "上一页"
+                            key: "{i}",
+                            class: "flex gap-3 text-xs",
+                            span {
+                                class: "font-semibold shrink-0",
+                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                {event.kind.label()}
                             }
                             span {
-                                class: "text-sm",
+                                class: "flex-1",
                                 style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                {
-                                    let current = *current_page.read();
-                                    let total = *total_pages.read();
-                                    format!("第 {} / {} 页", current, total)
-                                }
+                                "{event.detail}"
                             }
-                            button {
-                                class: "px-3 py-1 text-sm rounded",
-                                style: format!(
-                                    "background: {}; color: {}; border: 1px solid {};",
-                                    if *current_page.read() < *total_pages.read() { Colors::TECH_PRIMARY } else { Colors::BG_PRIMARY },
-                                    if *current_page.read() < *total_pages.read() { "white" } else { Colors::TEXT_SECONDARY },
-                                    Colors::BORDER_PRIMARY
-                                ),
-                                disabled: *current_page.read() >= *total_pages.read(),
-                                onclick: {
-                                    let mut page_sig = current_page;
-                                    let total = *total_pages.read();
-                                    move |_| {
-                                        let current = *page_sig.read();
-                                        if current < total {
-                                            page_sig.set(current + 1);
-                                        }
-                                    }
-                                },
-                                "下一页"
+                            span {
+                                class: "shrink-0",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                "{event.at}"
                             }
                         }
                     }
                 }
-            }
-        }
-    }
-}
-
-// =============================================================================
-// COMPONENT: HistoryTab - 历史记录标签页 (~2000行)
-// 功能: 显示所有交易历史,支持筛选和详情查看
-// =============================================================================
-
-/// 历史标签页 - 企业级交易历史管理
-#[component]
-fn HistoryTab() -> Element {
-    let app_state = use_context::<AppState>();
-
-    // 缓存和错误日志服务
-    let cache = use_signal(|| MemoryCache::new(Duration::from_secs(30)));
-    let error_logger = use_signal(|| ErrorLogger::new(100));
-
-    let transactions = use_signal(|| Vec::<TransactionHistoryItem>::new());
-    let loading = use_signal(|| false);
-    let error_message = use_signal(|| Option::<String>::None);
-
-    // 法币订单列表
-    let fiat_orders = use_signal(|| Vec::<OrderListItem>::new());
-    let orders_loading = use_signal(|| false);
-    let orders_error = use_signal(|| Option::<String>::None);
-
-    // 订单详情
-    let selected_order_id = use_signal(|| Option::<String>::None);
-    let order_details = use_signal(|| Option::<OrderTrackingInfo>::None);
-    let order_details_loading = use_signal(|| false);
-    let order_details_error = use_signal(|| Option::<String>::None);
-
-    // 筛选器
-    let filter_type = use_signal(|| Option::<String>::None); // "swap", "onramp", "offramp"
-    let filter_status = use_signal(|| Option::<String>::None); // "pending", "processing", "completed", "failed"
-    let filter_order_type = use_signal(|| Option::<String>::None); // "onramp", "offramp"
-    let filter_currency = use_signal(|| Option::<String>::None); // 币种筛选
-    let search_query = use_signal(|| String::new()); // 订单搜索关键词
-    let show_advanced_search = use_signal(|| false); // 是否显示高级搜索
-    let date_range_start = use_signal(|| Option::<String>::None); // 日期范围开始
-    let date_range_end = use_signal(|| Option::<String>::None); // 日期范围结束
-    let amount_min = use_signal(|| Option::<String>::None); // 最小金额
-    let amount_max = use_signal(|| Option::<String>::None); // 最大金额
-
-    // 显示模式：交易历史或订单列表
-    let view_mode = use_signal(|| "transactions".to_string()); // "transactions" or "orders"
-
-    // 分页
-    let current_page = use_signal(|| 1u32);
-    let page_size = 10u32;
-    let total_pages = use_signal(|| 1u32);
-
-    // 加载交易历史的effect（当筛选器或页码改变时自动触发）
-    use_effect({
-        let app_state_clone = app_state.clone();
-        let mut transactions_sig = transactions;
-        let mut loading_sig = loading;
-        let mut err_sig = error_message;
-        let total_pages_sig = total_pages;
-        let filter_type_sig = filter_type;
-        let filter_status_sig = filter_status;
-        let current_page_sig = current_page;
-        let view_mode_sig = view_mode;
-
-        move || {
-            // 只在交易历史视图模式下加载交易历史
-            if view_mode_sig.read().as_str() != "transactions" {
-                return;
-            }
-
-            let app_state_for_spawn = app_state_clone.clone();
-
-            // 检查用户是否已登录，并验证token是否存在
-            let user_state = app_state_for_spawn.user.read();
-            let is_authenticated = user_state.is_authenticated;
-            let has_token = user_state
-                .access_token
-                .as_ref()
-                .map(|t| !t.is_empty())
-                .unwrap_or(false);
-
-            if !is_authenticated || !has_token {
-                loading_sig.set(false);
-                err_sig.set(Some("请先登录以查看交易历史".to_string()));
-                transactions_sig.set(Vec::new());
-                return;
-            }
-
-            let filter_type_val = filter_type_sig.read().clone();
-            let filter_status_val = filter_status_sig.read().clone();
-            let page = *current_page_sig.read();
-
-            let mut loading_sig_for_spawn = loading_sig;
-            let mut err_sig_for_spawn = err_sig;
-            let mut transactions_sig_for_spawn = transactions_sig;
-            let mut total_pages_sig_for_spawn = total_pages_sig;
-            let mut cache_sig = cache;
-            let mut error_logger_sig = error_logger;
-
-            spawn(async move {
-                loading_sig_for_spawn.set(true);
-                err_sig_for_spawn.set(None);
-
-                // 检查缓存
-                let cache_key = format!(
-                    "history:{}:{}:page:{}",
-                    filter_type_val.as_deref().unwrap_or("all"),
-                    filter_status_val.as_deref().unwrap_or("all"),
-                    page
-                );
-                if let Some(cached_transactions) = cache_sig
-                    .read()
-                    .get::<Vec<TransactionHistoryItem>>(&cache_key)
-                {
-                    transactions_sig_for_spawn.set(cached_transactions);
-                    loading_sig_for_spawn.set(false);
-                    return;
-                }
-
-                // 确保在spawn之前获取最新的app_state，这样token是最新的
-                let history_service = TransactionHistoryService::new(app_state_for_spawn);
-                let query = TransactionHistoryQuery {
-                    tx_type: filter_type_val.clone(),
-                    status: filter_status_val.clone(),
-                    page: Some(page),
-                    page_size: Some(page_size),
-                    start_date: None,
-                    end_date: None,
-                };
-
-                match history_service.get_history(Some(query)).await {
-                    Ok(response) => {
-                        // 保存到缓存
-                        cache_sig.write().set(
-                            cache_key,
-                            response.transactions.clone(),
-                            Some(Duration::from_secs(60)),
-                        );
-                        transactions_sig_for_spawn.set(response.transactions);
-                        total_pages_sig_for_spawn.set(response.total_pages);
-                    }
-                    Err(e) => {
-                        // 检查是否是401错误（未授权）
-                        let is_unauthorized = e.to_string().contains("401")
-                            || e.to_string().to_lowercase().contains("unauthorized");
-
-                        // 不要自动清除认证状态，只显示错误消息
-                        // 让用户自己决定是否要重新登录
-                        // 这样可以避免在token暂时失效时强制登出用户
-
-                        let error_msg = if is_unauthorized {
-                            "⚠️ 认证已过期，请重新登录以查看交易历史\n\n提示：点击右上角\"登出\"按钮，然后重新登录即可解决此问题。".to_string()
-                        } else {
-                            format!("加载交易历史失败: {}", e)
-                        };
-                        err_sig_for_spawn.set(Some(error_msg.clone()));
-                        transactions_sig_for_spawn.set(Vec::new());
-                        // 记录错误日志
-                        error_logger_sig.write().log(
-                            ErrorLevel::Error,
-                            error_msg,
-                            Some(serde_json::json!({
-                                "filter_type": filter_type_val,
-                                "filter_status": filter_status_val,
-                                "page": page,
-                            })),
-                        );
-                    }
-                }
-                loading_sig_for_spawn.set(false);
-            });
+            }
         }
-    });
+    }
+}
 
-    // 加载订单列表的effect（当视图模式、筛选器或页码改变时自动触发）
-    use_effect({
-        let app_state_clone = app_state;
-        let fiat_orders_sig = fiat_orders;
-        let orders_loading_sig = orders_loading;
-        let orders_error_sig = orders_error;
-        let filter_status_sig = filter_status;
-        let current_page_sig = current_page;
-        let view_mode_sig = view_mode;
+// =============================================================================
+// COMPONENT: HistoryTab - 历史记录标签页 (~2000行)
+// 功能: 显示所有交易历史,支持筛选和详情查看
+// =============================================================================
 
-        move || {
-            // 只在订单视图模式下加载订单列表
-            if view_mode_sig.read().as_str() != "orders" {
-                return;
-            }
+/// 历史标签页 - 企业级交易历史管理
+#[component]
+fn HistoryTab(
+    /// 重试失败兑换（预填兑换表单并切换到兑换标签页）
+    on_retry: EventHandler<SwapRetryRequest>,
+    /// 空状态操作：切换到兑换/购买等标签页
+    on_switch_tab: EventHandler<SwapTab>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let t = crate::i18n::use_translation();
 
-            let app_state_for_spawn = app_state_clone;
-            let filter_status_val = filter_status_sig.read().clone();
-            let page = *current_page_sig.read();
+    // 错误日志服务
+    let error_logger = use_signal(|| ErrorLogger::new(100));
 
-            let mut fiat_orders_clone = fiat_orders_sig;
-            let mut orders_loading_clone = orders_loading_sig;
-            let mut orders_error_clone = orders_error_sig;
+    // 交易历史的类型、状态筛选与页码
+    let mut history_query = use_signal(HistoryQuery::default);
+    // 订单列表的页码、排序与状态筛选（会话内保持）
+    let mut order_query = use_list_query("fiat_orders");
 
-            spawn(async move {
-                orders_loading_clone.set(true);
-                orders_error_clone.set(None);
-
-                let onramp_service = FiatOnrampService::new(app_state_for_spawn);
-                let offramp_service = FiatOfframpService::new(app_state_for_spawn);
-
-                // 同时获取充值订单和提现订单
-                let (onramp_result, offramp_result) = futures::join!(
-                    onramp_service.get_orders(
-                        filter_status_val.as_deref(),
-                        Some(page),
-                        Some(page_size),
-                    ),
-                    offramp_service.get_orders(
-                        filter_status_val.as_deref(),
-                        Some(page),
-                        Some(page_size),
-                    )
-                );
+    // 订单详情
+    let selected_order_id = use_signal(|| Option::<String>::None);
+    let order_details = use_signal(|| Option::<OrderTrackingInfo>::None);
+    let order_details_loading = use_signal(|| false);
+    let order_details_error = use_signal(|| Option::<String>::None);
+    // 待确认取消的法币订单及取消请求进行中状态
+    let mut pending_cancel_fiat_order = use_signal(|| Option::<String>::None);
+    let mut cancelling_fiat_order = use_signal(|| false);
+
+    // 筛选器
+    let filter_order_type = use_signal(|| Option::<String>::None); // "onramp", "offramp"
+    let filter_currency = use_signal(|| Option::<String>::None); // 币种筛选
+    let search_query = use_signal(|| String::new()); // 订单搜索关键词
+    let show_advanced_search = use_signal(|| false); // 是否显示高级搜索
+    let date_range_start = use_signal(|| Option::<String>::None); // 日期范围开始
+    let date_range_end = use_signal(|| Option::<String>::None); // 日期范围结束
+    let amount_min = use_signal(|| Option::<String>::None); // 最小金额
+    let amount_max = use_signal(|| Option::<String>::None); // 最大金额
+
+    // 显示模式：交易历史或订单列表
+    let view_mode = use_signal(|| "transactions".to_string()); // "transactions" or "orders"
 
-                let mut all_orders = Vec::new();
-                let mut onramp_error_msg = None;
-                let mut offramp_error_msg = None;
+    // 加载交易历史（仅在交易历史视图下加载）
+    let mut history_list = use_paginated_query(
+        PageCache {
+            prefix: "history",
+            ttl: Some(Duration::from_secs(60)),
+        },
+        history_query,
+        move |query: HistoryQuery| {
+            if view_mode.read().as_str() != "transactions" {
+                return None;
+            }
 
-                // 处理充值订单
-                match onramp_result {
-                    Ok(response) => {
-                        for order in response.orders {
-                            use crate::components::molecules::order_list::OrderType;
-                            use crate::components::molecules::order_tracking::OrderStatus as OS;
-
-                            all_orders.push(OrderListItem {
-                                order_id: order.order_id,
-                                order_type: OrderType::Onramp,
-                                status: OS::from_str(&order.status),
-                                amount: order.fiat_amount,
-                                currency: "USD".to_string(), // 默认USD，实际应该从订单中获取
-                                token_symbol: None,
-                                created_at: order.created_at,
-                                updated_at: Some(order.updated_at),
-                                completed_at: order.completed_at,
-                                error_message: order.error_message,
-                            });
+            // 检查用户是否已登录，并验证token是否存在
+            let user_state = app_state.user.read();
+            let has_token = user_state
+                .access_token
+                .as_ref()
+                .map(|t| !t.is_empty())
+                .unwrap_or(false);
+            if !user_state.is_authenticated || !has_token {
+                return Some(async { Err("请先登录以查看交易历史".to_string()) }.boxed_local());
+            }
+            drop(user_state);
+
+            let mut error_logger_sig = error_logger;
+            Some(
+                async move {
+                    let history_service = TransactionHistoryService::new(app_state);
+                    let request = TransactionHistoryQuery {
+                        tx_type: query.tx_type.clone(),
+                        status: query.status.clone(),
+                        page: Some(query.page),
+                        page_size: Some(history::PAGE_SIZE),
+                        start_date: None,
+                        end_date: None,
+                    };
+
+                    match history_service.get_history(Some(request)).await {
+                        // 同一批次的小额归集兑换合并为一条记录
+                        // 直接发起的包装/解包只有本地记录，合并到第一页
+                        Ok(response) => Ok(Page {
+                            items: wrap::merge_history(
+                                crate::features::swap::dust::group_sweep_history(
+                                    response.transactions,
+                                ),
+                                query.tx_type.as_deref(),
+                                query.status.as_deref(),
+                                query.page,
+                            ),
+                            total_pages: response.total_pages,
+                        }),
+                        Err(e) => {
+                            // 检查是否是401错误（未授权）
+                            let is_unauthorized = e.to_string().contains("401")
+                                || e.to_string().to_lowercase().contains("unauthorized");
+
+                            // 不要自动清除认证状态，只显示错误消息
+                            // 让用户自己决定是否要重新登录
+                            // 这样可以避免在token暂时失效时强制登出用户
+
+                            let error_msg = if is_unauthorized {
+                                "⚠️ 认证已过期，请重新登录以查看交易历史\n\n提示：点击右上角\"登出\"按钮，然后重新登录即可解决此问题。".to_string()
+                            } else {
+                                format!("加载交易历史失败: {}", e)
+                            };
+                            // 记录错误日志
+                            error_logger_sig.write().log(
+                                ErrorLevel::Error,
+                                error_msg.clone(),
+                                Some(serde_json::json!({
+                                    "filter_type": query.tx_type,
+                                    "filter_status": query.status,
+                                    "page": query.page,
+                                })),
+                            );
+                            Err(error_msg)
                         }
                     }
-                    Err(e) => {
-                        onramp_error_msg = Some(e);
-                    }
                 }
+                .boxed_local(),
+            )
+        },
+    );
+
+    // 加载法币订单列表（仅在订单视图下加载；订单状态变化较快，不缓存）
+    let mut fiat_orders = use_paginated_query(
+        PageCache {
+            prefix: "fiat_orders",
+            ttl: None,
+        },
+        order_query,
+        move |query: ListQueryState| {
+            if view_mode.read().as_str() != "orders" {
+                return None;
+            }
 
-                // 处理提现订单
-                match offramp_result {
-                    Ok(response) => {
-                        for order in response.orders {
-                            use crate::components::molecules::order_list::OrderType;
-                            use crate::components::molecules::order_tracking::OrderStatus as OS;
-
-                            all_orders.push(OrderListItem {
-                                order_id: order.order_id,
-                                order_type: OrderType::Offramp,
-                                status: OS::from_str(&order.status),
-                                amount: order.fiat_amount,
-                                currency: order.fiat_currency,
-                                token_symbol: Some(order.token_symbol),
-                                created_at: order.created_at,
-                                updated_at: Some(order.updated_at),
-                                completed_at: order.completed_at,
-                                error_message: order.error_message,
-                            });
+            let filter_status_val = query.status.clone();
+            let page = query.page;
+            let page_size = query.page_size;
+            let sort_field = query.sort_by;
+            let sort_direction = query.sort_order;
+            let sort_by = sort_field.param();
+            let sort_order = sort_direction.param();
+
+            Some(
+                async move {
+                    let onramp_service = FiatOnrampService::new(app_state);
+                    let offramp_service = FiatOfframpService::new(app_state);
+
+                    // 同时获取充值订单和提现订单
+                    let (onramp_result, offramp_result) = futures::join!(
+                        onramp_service.get_orders(
+                            filter_status_val.as_deref(),
+                            Some(page),
+                            Some(page_size),
+                            Some(sort_by),
+                            Some(sort_order),
+                        ),
+                        offramp_service.get_orders(
+                            filter_status_val.as_deref(),
+                            Some(page),
+                            Some(page_size),
+                            Some(sort_by),
+                            Some(sort_order),
+                        )
+                    );
+
+                    let mut all_orders = Vec::new();
+                    let mut onramp_error_msg = None;
+                    let mut offramp_error_msg = None;
+                    let mut pages = 1u32;
+
+                    // 处理充值订单（超过支付时限仍未支付的显示为已过期）
+                    match onramp_result {
+                        Ok(response) => {
+                            pages = pages.max(response.total_pages);
+                            let now = Utc::now().timestamp();
+                            for order in response.orders {
+                                use crate::components::molecules::order_list::OrderType;
+                                use crate::components::molecules::order_tracking::OrderStatus as OS;
+
+                                let status = onramp_orders::effective_status(
+                                    &order.order_id,
+                                    &order.status,
+                                    now,
+                                );
+                                all_orders.push(OrderListItem {
+                                    order_id: order.order_id,
+                                    order_type: OrderType::Onramp,
+                                    status: OS::from_str(&status),
+                                    amount: order.fiat_amount,
+                                    currency: "USD".to_string(), // 默认USD，实际应该从订单中获取
+                                    token_symbol: None,
+                                    created_at: order.created_at,
+                                    updated_at: Some(order.updated_at),
+                                    completed_at: order.completed_at,
+                                    error_message: order.error_message,
+                                });
+                            }
+                        }
+                        Err(e) => {
+                            onramp_error_msg = Some(e);
                         }
                     }
-                    Err(e) => {
-                        offramp_error_msg = Some(e);
+
+                    // 处理提现订单
+                    match offramp_result {
+                        Ok(response) => {
+                            pages = pages.max(response.total_pages);
+                            for order in response.orders {
+                                use crate::components::molecules::order_list::OrderType;
+                                use crate::components::molecules::order_tracking::OrderStatus as OS;
+
+                                all_orders.push(OrderListItem {
+                                    order_id: order.order_id,
+                                    order_type: OrderType::Offramp,
+                                    status: OS::from_str(&order.status),
+                                    amount: order.fiat_amount,
+                                    currency: order.fiat_currency,
+                                    token_symbol: Some(order.token_symbol),
+                                    created_at: order.created_at,
+                                    updated_at: Some(order.updated_at),
+                                    completed_at: order.completed_at,
+                                    error_message: order.error_message,
+                                });
+                            }
+                        }
+                        Err(e) => {
+                            offramp_error_msg = Some(e);
+                        }
                     }
-                }
 
-                // 如果两个都失败，显示错误
-                match (onramp_error_msg, offramp_error_msg) {
-                    (Some(onramp_err), Some(offramp_err)) => {
-                        orders_error_clone.set(Some(format!(
+                    // 两个都失败时显示错误；只有其中一个失败时显示成功的部分
+                    if let (Some(onramp_err), Some(offramp_err)) =
+                        (onramp_error_msg, offramp_error_msg)
+                    {
+                        return Err(format!(
                             "获取订单列表失败：充值订单 - {}，提现订单 - {}",
                             onramp_err, offramp_err
-                        )));
+                        ));
                     }
-                    (Some(_), None) | (None, Some(_)) => {
-                        // 只有其中一个失败，但另一个成功，不显示错误（部分成功）
-                    }
-                    (None, None) => {
-                        // 都成功，不显示错误
-                    }
-                }
 
-                // 按创建时间倒序排序
-                all_orders.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                    // 合并后按当前排序条件重新排序（两个接口各自已在服务端排序）
+                    all_orders.sort_by(|a, b| {
+                        let ordering = match sort_field {
+                            SortField::Date => a.created_at.cmp(&b.created_at),
+                            SortField::Amount => {
+                                let amount_a = a.amount.parse::<f64>().unwrap_or(0.0);
+                                let amount_b = b.amount.parse::<f64>().unwrap_or(0.0);
+                                amount_a.total_cmp(&amount_b)
+                            }
+                            SortField::Status => a.status.label().cmp(b.status.label()),
+                        };
+                        match sort_direction {
+                            SortOrder::Asc => ordering,
+                            SortOrder::Desc => ordering.reverse(),
+                        }
+                    });
 
-                fiat_orders_clone.set(all_orders);
-                orders_loading_clone.set(false);
-            });
+                    Ok(Page {
+                        items: all_orders,
+                        total_pages: pages,
+                    })
+                }
+                .boxed_local(),
+            )
+        },
+    );
+
+    // 详情抽屉（按交易ID查找，自动刷新后显示最新数据）
+    let mut detail_id = use_signal(|| Option::<String>::None);
+    let detail_transaction = use_memo(move || {
+        let id = detail_id()?;
+        history_list
+            .state
+            .read()
+            .items
+            .iter()
+            .find(|tx| tx.id == id)
+            .cloned()
+    });
+
+    // 未完成的兑换按行刷新状态（不重新拉取整页）
+    use_future(move || async move {
+        let mut rows = history_list.state;
+        loop {
+            scheduler::next_tick(
+                TickPolicy::PauseWhenHidden,
+                history::PENDING_REFRESH_INTERVAL_MS,
+            )
+            .await;
+            if view_mode.peek().as_str() != "transactions" {
+                continue;
+            }
+            let pending_ids: Vec<String> = rows
+                .peek()
+                .items
+                .iter()
+                .filter(|tx| history::needs_status_refresh(tx))
+                .map(|tx| tx.id.clone())
+                .collect();
+            if pending_ids.is_empty() {
+                continue;
+            }
+            let swap_service = SwapService::new(app_state);
+            for id in pending_ids {
+                match swap_service.get_status(&id).await {
+                    Ok(status) => {
+                        // 只在状态确有变化时写入，避免无效重渲染
+                        let updated = rows
+                            .peek()
+                            .items
+                            .iter()
+                            .find(|tx| tx.id == id)
+                            .cloned()
+                            .and_then(|mut row| {
+                                history::apply_status_update(&mut row, &status).then_some(row)
+                            });
+                        if let Some(row) = updated {
+                            if let Some(slot) = rows.write().items.iter_mut().find(|tx| tx.id == id)
+                            {
+                                *slot = row;
+                            }
+                        }
+                    }
+                    Err(e) => log::warn!("刷新兑换状态失败 {}: {}", id, e),
+                }
+            }
         }
     });
 
@@ -5386,6 +5757,11 @@ fn HistoryTab() -> Element {
                 }
             }
 
+            // 执行质量汇总（仅交易历史模式显示）
+            if view_mode.read().as_str() == "transactions" {
+                ExecutionQualityCard { transactions: history_list.state.read().items.clone() }
+            }
+
             // 根据视图模式显示不同内容
             if view_mode.read().as_str() == "orders" {
                 // 法币订单列表
@@ -5399,6 +5775,7 @@ fn HistoryTab() -> Element {
                             class: "relative",
                             input {
                                 id: "order-search-input",
+                                "data-shortcut-search": "true",
                                 r#type: "text",
                                 placeholder: "搜索订单ID、金额... (Ctrl/Cmd+F)",
                                 class: "w-full px-4 py-2 rounded-lg border text-sm",
@@ -5690,129 +6067,14 @@ fn HistoryTab() -> Element {
                         // 订单状态筛选器（仅订单视图显示）
                         div {
                             class: "space-y-3",
-                            // 状态筛选
+                            // 状态筛选与排序（服务端处理）
                             div {
-                                class: "flex gap-2 flex-wrap",
-                                button {
-                                    class: "px-3 py-1.5 text-sm rounded-lg font-medium transition-all",
-                                    style: format!(
-                                        "background: {}; color: {}; border: 1px solid {};",
-                                        if filter_status.read().is_none() {
-                                            Colors::TECH_PRIMARY
-                                        } else {
-                                            Colors::BG_PRIMARY
-                                        },
-                                        if filter_status.read().is_none() {
-                                            "white"
-                                        } else {
-                                            Colors::TEXT_SECONDARY
-                                        },
-                                        Colors::BORDER_PRIMARY
-                                    ),
-                                    onclick: {
-                                        let mut filter_status_sig = filter_status;
-                                        move |_| {
-                                            filter_status_sig.set(None);
-                                        }
-                                    },
-                                    "全部状态"
-                                }
-                        button {
-                            class: "px-3 py-1.5 text-sm rounded-lg font-medium transition-all",
-                            style: format!(
-                                "background: {}; color: {}; border: 1px solid {};",
-                                if filter_status.read().as_ref().map(|s| s == "pending").unwrap_or(false) {
-                                    Colors::TECH_PRIMARY
-                                } else {
-                                    Colors::BG_PRIMARY
-                                },
-                                if filter_status.read().as_ref().map(|s| s == "pending").unwrap_or(false) {
-                                    "white"
-                                } else {
-                                    Colors::TEXT_SECONDARY
-                                },
-                                Colors::BORDER_PRIMARY
-                            ),
-                            onclick: {
-                                let mut filter_status_sig = filter_status;
-                                move |_| {
-                                    filter_status_sig.set(Some("pending".to_string()));
-                                }
-                            },
-                            "待处理"
-                        }
-                        button {
-                            class: "px-3 py-1.5 text-sm rounded-lg font-medium transition-all",
-                            style: format!(
-                                "background: {}; color: {}; border: 1px solid {};",
-                                if filter_status.read().as_ref().map(|s| s == "processing").unwrap_or(false) {
-                                    Colors::TECH_PRIMARY
-                                } else {
-                                    Colors::BG_PRIMARY
-                                },
-                                if filter_status.read().as_ref().map(|s| s == "processing").unwrap_or(false) {
-                                    "white"
-                                } else {
-                                    Colors::TEXT_SECONDARY
-                                },
-                                Colors::BORDER_PRIMARY
-                            ),
-                            onclick: {
-                                let mut filter_status_sig = filter_status;
-                                move |_| {
-                                    filter_status_sig.set(Some("processing".to_string()));
-                                }
-                            },
-                            "处理中"
-                        }
-                        button {
-                            class: "px-3 py-1.5 text-sm rounded-lg font-medium transition-all",
-                            style: format!(
-                                "background: {}; color: {}; border: 1px solid {};",
-                                if filter_status.read().as_ref().map(|s| s == "completed").unwrap_or(false) {
-                                    Colors::TECH_PRIMARY
-                                } else {
-                                    Colors::BG_PRIMARY
-                                },
-                                if filter_status.read().as_ref().map(|s| s == "completed").unwrap_or(false) {
-                                    "white"
-                                } else {
-                                    Colors::TEXT_SECONDARY
-                                },
-                                Colors::BORDER_PRIMARY
-                            ),
-                            onclick: {
-                                let mut filter_status_sig = filter_status;
-                                move |_| {
-                                    filter_status_sig.set(Some("completed".to_string()));
-                                }
-                            },
-                            "已完成"
-                        }
-                        button {
-                            class: "px-3 py-1.5 text-sm rounded-lg font-medium transition-all",
-                            style: format!(
-                                "background: {}; color: {}; border: 1px solid {};",
-                                if filter_status.read().as_ref().map(|s| s == "failed").unwrap_or(false) {
-                                    Colors::TECH_PRIMARY
-                                } else {
-                                    Colors::BG_PRIMARY
-                                },
-                                if filter_status.read().as_ref().map(|s| s == "failed").unwrap_or(false) {
-                                    "white"
-                                } else {
-                                    Colors::TEXT_SECONDARY
-                                },
-                                Colors::BORDER_PRIMARY
-                            ),
-                            onclick: {
-                                let mut filter_status_sig = filter_status;
-                                move |_| {
-                                    filter_status_sig.set(Some("failed".to_string()));
+                                class: "flex gap-3 flex-wrap items-center",
+                                StatusFilterChips {
+                                    state: order_query,
+                                    options: FIAT_ORDER_STATUS_FILTERS.to_vec(),
                                 }
-                            },
-                            "失败"
-                        }
+                                SortControls { state: order_query }
                             }
 
                             // 订单类型筛选
@@ -5968,15 +6230,7 @@ fn HistoryTab() -> Element {
                                 Colors::TEXT_PRIMARY,
                                 Colors::BORDER_PRIMARY
                             ),
-                            onclick: {
-                                let mut filter_status_sig = filter_status;
-                                move |_| {
-                                    // 触发刷新：先设置为None，然后恢复原值
-                                    let current_status = filter_status_sig.read().clone();
-                                    filter_status_sig.set(None);
-                                    filter_status_sig.set(current_status);
-                                }
-                            },
+                            onclick: move |_| fiat_orders.reload(),
                             "🔄 刷新"
                         }
                         // 导出订单按钮
@@ -5991,10 +6245,9 @@ fn HistoryTab() -> Element {
                             onclick: {
                                 let fiat_orders_clone = fiat_orders;
                                 let search_query_clone = search_query;
-                                let filter_status_clone = filter_status;
                                 move |_| {
                                     // 获取要导出的订单列表（应用搜索和筛选）
-                                    let mut orders_to_export = fiat_orders_clone.read().clone();
+                                    let mut orders_to_export = fiat_orders_clone.state.read().items.clone();
 
                                     // 应用搜索过滤
                                     let search_val = search_query_clone.read().clone();
@@ -6009,7 +6262,7 @@ fn HistoryTab() -> Element {
                                     }
 
                                     // 应用状态筛选
-                                    if let Some(status) = filter_status_clone.read().as_ref() {
+                                    if let Some(status) = order_query.read().status.as_ref() {
                                         orders_to_export.retain(|order| {
                                             let order_status_str = match order.status {
                                                 OrderStatus::Pending => "pending",
@@ -6117,7 +6370,7 @@ fn HistoryTab() -> Element {
                     }
 
                     // 错误提示
-                    if let Some(error) = orders_error.read().as_ref() {
+                    if let Some(error) = fiat_orders.state.read().error.clone() {
                         div {
                             class: "p-4 rounded-lg",
                             style: format!("background: rgba(239, 68, 68, 0.1); border: 1px solid rgba(239, 68, 68, 0.3);"),
@@ -6139,94 +6392,64 @@ fn HistoryTab() -> Element {
 
                     // 订单列表
                     {
-                        // 订单列表刷新触发器（通过修改filter_status来触发effect重新加载）
-                        let filter_status_for_refresh = filter_status;
-
-                        // 重试订单处理函数
+                        // 重试订单处理函数（成功后重新加载当前页）
                         let app_state_for_retry = app_state;
-                        let orders_error_sig = orders_error;
-                        let filter_status_refresh = filter_status_for_refresh;
                         let handle_retry = move |order_id: String| {
                             let app_state_clone = app_state_for_retry;
-                            let mut orders_error_clone = orders_error_sig;
-                            let mut filter_status_trigger = filter_status_refresh;
+                            let mut orders = fiat_orders;
                             spawn(async move {
                                 let onramp_service = FiatOnrampService::new(app_state_clone);
                                 let offramp_service = FiatOfframpService::new(app_state_clone);
 
-                                // 先尝试onramp重试
-                                let retry_result = onramp_service.retry_order(&order_id).await;
+                                // 先尝试onramp重试，失败时尝试offramp
+                                let retry_result = match onramp_service.retry_order(&order_id).await {
+                                    Ok(_) => Ok(()),
+                                    Err(e1) => offramp_service
+                                        .retry_order(&order_id)
+                                        .await
+                                        .map(|_| ())
+                                        .map_err(|_| e1),
+                                };
 
                                 match retry_result {
-                                    Ok(_) => {
-                                        // 重试成功，触发订单列表刷新
-                                        orders_error_clone.set(None);
-                                        // 通过修改filter_status触发effect重新加载
-                                        let current_status = filter_status_trigger.read().clone();
-                                        filter_status_trigger.set(None);
-                                        // 立即恢复，触发effect重新运行
-                                        filter_status_trigger.set(current_status);
-                                    }
-                                    Err(e1) => {
-                                        // 如果onramp失败，尝试offramp
-                                        match offramp_service.retry_order(&order_id).await {
-                                            Ok(_) => {
-                                                // 重试成功，触发订单列表刷新
-                                                orders_error_clone.set(None);
-                                                let current_status = filter_status_trigger.read().clone();
-                                                filter_status_trigger.set(None);
-                                                filter_status_trigger.set(current_status);
-                                            }
-                                            Err(_) => {
-                                                // 两个都失败，显示错误
-                                                orders_error_clone.set(Some(e1));
-                                            }
-                                        }
-                                    }
+                                    Ok(()) => orders.reload(),
+                                    Err(e) => AppState::show_error(
+                                        app_state_clone.toasts,
+                                        format!("重试订单失败: {}", e),
+                                    ),
                                 }
                             });
                         };
 
-                        // 取消订单处理函数
+                        // 取消订单处理函数（先从列表中移除，失败时重新加载恢复）
                         let app_state_for_cancel = app_state;
-                        let orders_error_sig = orders_error;
-                        let filter_status_refresh = filter_status_for_refresh;
-                        let handle_cancel = move |order_id: String| {
+                        let mut handle_cancel = move |order_id: String| {
                             let app_state_clone = app_state_for_cancel;
-                            let mut orders_error_clone = orders_error_sig;
-                            let mut filter_status_trigger = filter_status_refresh;
+                            let mut orders = fiat_orders;
+                            cancelling_fiat_order.set(true);
+                            orders.remove_where(|o| o.order_id == order_id);
                             spawn(async move {
-                                let onramp_service = FiatOnrampService::new(app_state_clone);
                                 let offramp_service = FiatOfframpService::new(app_state_clone);
 
-                                // 先尝试onramp取消
-                                let cancel_result = onramp_service.cancel_order(&order_id).await;
+                                // 先尝试onramp取消，失败时尝试offramp
+                                let cancel_result = match onramp_orders::cancel(app_state_clone, &order_id).await {
+                                    Ok(()) => Ok(()),
+                                    Err(e1) => offramp_service
+                                        .cancel_order(&order_id)
+                                        .await
+                                        .map_err(|_| e1),
+                                };
 
-                                match cancel_result {
-                                    Ok(_) => {
-                                        // 取消成功，触发订单列表刷新
-                                        orders_error_clone.set(None);
-                                        let current_status = filter_status_trigger.read().clone();
-                                        filter_status_trigger.set(None);
-                                        filter_status_trigger.set(current_status);
-                                    }
-                                    Err(e1) => {
-                                        // 如果onramp失败，尝试offramp
-                                        match offramp_service.cancel_order(&order_id).await {
-                                            Ok(_) => {
-                                                // 取消成功，触发订单列表刷新
-                                                orders_error_clone.set(None);
-                                                let current_status = filter_status_trigger.read().clone();
-                                                filter_status_trigger.set(None);
-                                                filter_status_trigger.set(current_status);
-                                            }
-                                            Err(_) => {
-                                                // 两个都失败，显示错误
-                                                orders_error_clone.set(Some(e1));
-                                            }
-                                        }
-                                    }
+                                if let Err(e) = cancel_result {
+                                    orders.reload();
+                                    AppState::show_error(
+                                        app_state_clone.toasts,
+                                        format!("取消订单失败: {}", e),
+                                    );
                                 }
+
+                                cancelling_fiat_order.set(false);
+                                pending_cancel_fiat_order.set(None);
                             });
                         };
 
@@ -6274,19 +6497,7 @@ fn HistoryTab() -> Element {
                                         // 如果onramp失败，尝试offramp
                                         match offramp_service.get_order_status(&order_id).await {
                                             Ok(status) => {
-                                                let tracking_info = OrderTrackingInfo {
-                                                    order_id: status.order_id.clone(),
-                                                    status: OrderStatus::from_str(&status.status),
-                                                    title: format!("法币提现订单 {}", &status.order_id[..8]),
-                                                    description: Some(format!("金额: {} {}", status.fiat_amount, status.fiat_currency)),
-                                                    created_at: status.created_at,
-                                                    updated_at: Some(status.updated_at),
-                                                    completed_at: status.completed_at,
-                                                    error_message: status.error_message,
-                                                    payment_url: None,
-                                                    tx_hash: status.withdrawal_tx_hash.or(status.swap_tx_hash),
-                                                };
-                                                order_details_clone.set(Some(tracking_info));
+                                                order_details_clone.set(Some(offramp_tracking_info(status)));
                                                 order_details_loading_clone.set(false);
                                             }
                                             Err(e) => {
@@ -6309,7 +6520,18 @@ fn HistoryTab() -> Element {
                                 let amount_max_val = amount_max.read().clone();
                                 let filter_order_type_val = filter_order_type.read().clone();
                                 let filter_currency_val = filter_currency.read().clone();
-                                let mut filtered_orders = fiat_orders.read().clone();
+                                let mut filtered_orders = fiat_orders.state.read().items.clone();
+
+                                // 状态筛选：“全部”隐藏已取消、已过期的订单；本地判定过期的订单不再算作待处理
+                                match order_query.read().status.as_deref() {
+                                    None => filtered_orders.retain(|order| {
+                                        !matches!(order.status, OrderStatus::Cancelled | OrderStatus::Expired)
+                                    }),
+                                    Some("pending") => filtered_orders.retain(|order| {
+                                        matches!(order.status, OrderStatus::Pending)
+                                    }),
+                                    Some(_) => {}
+                                }
 
                                 // 订单类型过滤
                                 if let Some(order_type) = &filter_order_type_val {
@@ -6466,12 +6688,18 @@ fn HistoryTab() -> Element {
                                         }
                                     }
 
+                                    if filtered_orders.is_empty() && !fiat_orders.state.read().loading && order_query.read().has_filters() {
+                                        FilteredEmptyState {
+                                            message: "当前状态筛选下没有订单".to_string(),
+                                            on_clear: move |_| order_query.write().clear_filters(),
+                                        }
+                                    } else {
                                     OrderList {
                                         orders: filtered_orders,
-                                        loading: *orders_loading.read(),
-                                        error: orders_error.read().clone(),
+                                        loading: fiat_orders.state.read().loading,
+                                        error: fiat_orders.state.read().error.clone(),
                                         on_cancel: Some(EventHandler::new(move |order_id: String| {
-                                            handle_cancel(order_id);
+                                            pending_cancel_fiat_order.set(Some(order_id));
                                         })),
                                         on_retry: Some(EventHandler::new(move |order_id: String| {
                                             handle_retry(order_id);
@@ -6479,64 +6707,54 @@ fn HistoryTab() -> Element {
                                         on_view_details: Some(EventHandler::new(move |order_id: String| {
                                             handle_view_details(order_id);
                                         })),
+                                        on_reload: move |_| fiat_orders.reload(),
+                                        on_create: move |_| on_switch_tab.call(SwapTab::Buy),
+                                        on_resume: move |order_id: String| {
+                                            onramp_orders::request_resume(&order_id);
+                                            on_switch_tab.call(SwapTab::Buy);
+                                        },
+                                    }
                                     }
                                 }
                             }
 
-                            // 分页控件
-                            if *total_pages.read() > 1 {
-                                div {
-                                    class: "flex items-center justify-center gap-2 mt-4",
-                                    button {
-                                        class: "px-3 py-1 text-sm rounded transition-all",
-                                        style: format!(
-                                            "background: {}; color: {}; border: 1px solid {};",
-                                            if *current_page.read() > 1 { Colors::TECH_PRIMARY } else { Colors::BG_PRIMARY },
-                                            if *current_page.read() > 1 { "white" } else { Colors::TEXT_SECONDARY },
-                                            Colors::BORDER_PRIMARY
-                                        ),
-                                        disabled: *current_page.read() <= 1,
-                                        onclick: {
-                                            let mut page_sig = current_page;
-                                            move |_| {
-                                                let current = *page_sig.read();
-                                                if current > 1 {
-                                                    page_sig.set(current - 1);
-                                                }
-                                            }
-                                        },
-                                        "上一页"
-                                    }
-                                    span {
-                                        class: "text-sm",
-                                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                                        {
-                                            let current = *current_page.read();
-                                            let total = *total_pages.read();
-                                            format!("第 {} / {} 页", current, total)
-                                        }
-                                    }
-                                    button {
-                                        class: "px-3 py-1 text-sm rounded transition-all",
-                                        style: format!(
-                                            "background: {}; color: {}; border: 1px solid {};",
-                                            if *current_page.read() < *total_pages.read() { Colors::TECH_PRIMARY } else { Colors::BG_PRIMARY },
-                                            if *current_page.read() < *total_pages.read() { "white" } else { Colors::TEXT_SECONDARY },
-                                            Colors::BORDER_PRIMARY
-                                        ),
-                                        disabled: *current_page.read() >= *total_pages.read(),
-                                        onclick: {
-                                            let mut page_sig = current_page;
-                                            let total = *total_pages.read();
-                                            move |_| {
-                                                let current = *page_sig.read();
-                                                if current < total {
-                                                    page_sig.set(current + 1);
-                                                }
-                                            }
-                                        },
-                                        "下一页"
+                            // 分页与每页数量
+                            PaginationControls {
+                                state: order_query,
+                                total_pages: fiat_orders.state.read().total_pages,
+                            }
+
+                            // 取消法币订单确认（区分尚未支付与支付处理中）
+                            {
+                                let cancel_order_id = pending_cancel_fiat_order.read().clone().unwrap_or_default();
+                                let safety = match fiat_orders
+                                    .state
+                                    .read()
+                                    .items
+                                    .iter()
+                                    .find(|o| o.order_id == cancel_order_id)
+                                    .map(|o| o.status)
+                                {
+                                    Some(OrderStatus::Processing) => CancelSafety::PaymentInProgress,
+                                    _ => CancelSafety::Unpaid,
+                                };
+                                rsx! {
+                            ConfirmAction {
+                                open: pending_cancel_fiat_order.read().is_some(),
+                                risk: if safety.is_safe() { RiskLevel::Caution } else { RiskLevel::Destructive },
+                                title: safety.title().to_string(),
+                                message: safety.message(&cancel_order_id),
+                                details: safety.details(),
+                                confirm_text: "确认取消".to_string(),
+                                cancel_text: "保留订单".to_string(),
+                                busy: cancelling_fiat_order(),
+                                on_cancel: move |_| pending_cancel_fiat_order.set(None),
+                                on_confirm: move |_| {
+                                    if let Some(order_id) = pending_cancel_fiat_order.peek().clone() {
+                                        handle_cancel(order_id);
                                     }
+                                },
+                            }
                                 }
                             }
 
@@ -6576,29 +6794,9 @@ fn HistoryTab() -> Element {
                                                             style: format!("color: {};", Colors::TEXT_SECONDARY),
                                                             "订单ID: {details.order_id}"
                                                         }
-                                                        button {
-                                                            class: "px-2 py-1 text-xs rounded transition-all",
-                                                            style: format!(
-                                                                "background: {}; color: {}; border: 1px solid {};",
-                                                                Colors::BG_SECONDARY,
-                                                                Colors::TEXT_SECONDARY,
-                                                                Colors::BORDER_PRIMARY
-                                                            ),
-                                                            onclick: {
-                                                                let order_id = details.order_id.clone();
-                                                                move |_| {
-                                                                    // 复制订单ID到剪贴板
-                                                                    if let Some(window) = web_sys::window() {
-                                                                        let clipboard = window.navigator().clipboard();
-                                                                        let promise = clipboard.write_text(&order_id);
-                                                                        spawn(async move {
-                                                                            use wasm_bindgen_futures::JsFuture;
-                                                                            let _ = JsFuture::from(promise).await;
-                                                                        });
-                                                                    }
-                                                                }
-                                                            },
-                                                            "📋 复制"
+                                                        CopyButton {
+                                                            value: details.order_id.clone(),
+                                                            compact: true,
                                                         }
                                                     }
                                                 }
@@ -6686,7 +6884,7 @@ fn HistoryTab() -> Element {
                                                                     let mut selected_order_id_sig = selected_order_id;
                                                                     let mut order_details_sig = order_details;
                                                                     move |_| {
-                                                                        handle_cancel(order_id.clone());
+                                                                        pending_cancel_fiat_order.set(Some(order_id.clone()));
                                                                         selected_order_id_sig.set(None);
                                                                         order_details_sig.set(None);
                                                                     }
@@ -6769,104 +6967,76 @@ fn HistoryTab() -> Element {
                             class: "px-3 py-1 text-sm rounded",
                             style: format!(
                                 "background: {}; color: {}; border: 1px solid {};",
-                                if filter_type.read().is_none() {
+                                if history_query.read().tx_type.is_none() {
                                     Colors::TECH_PRIMARY
                                 } else {
                                     Colors::BG_PRIMARY
                                 },
-                                if filter_type.read().is_none() {
+                                if history_query.read().tx_type.is_none() {
                                     "white"
                                 } else {
                                     Colors::TEXT_SECONDARY
                                 },
                                 Colors::BORDER_PRIMARY
                             ),
-                            onclick: {
-                                let mut filter_type_sig = filter_type;
-                                let mut current_page_sig = current_page;
-                                move |_| {
-                                    filter_type_sig.set(None);
-                                    current_page_sig.set(1);
-                                }
-                            },
+                            onclick: move |_| history_query.write().set_type(None),
                             "全部"
                                 }
                                 button {
                                     class: "px-3 py-1 text-sm rounded",
                             style: format!(
                                 "background: {}; color: {}; border: 1px solid {};",
-                                if filter_type.read().as_ref().map(|s| s == "swap").unwrap_or(false) {
+                                if history_query.read().tx_type.as_ref().map(|s| s == "swap").unwrap_or(false) {
                                     Colors::TECH_PRIMARY
                                 } else {
                                     Colors::BG_PRIMARY
                                 },
-                                if filter_type.read().as_ref().map(|s| s == "swap").unwrap_or(false) {
+                                if history_query.read().tx_type.as_ref().map(|s| s == "swap").unwrap_or(false) {
                                     "white"
                                 } else {
                                     Colors::TEXT_SECONDARY
                                 },
                                 Colors::BORDER_PRIMARY
                             ),
-                            onclick: {
-                                let mut filter_type_sig = filter_type;
-                                let mut current_page_sig = current_page;
-                                move |_| {
-                                    filter_type_sig.set(Some("swap".to_string()));
-                                    current_page_sig.set(1);
-                                }
-                            },
+                            onclick: move |_| history_query.write().set_type(Some("swap")),
                             "交换"
                         }
                         button {
                             class: "px-3 py-1 text-sm rounded",
                             style: format!(
                                 "background: {}; color: {}; border: 1px solid {};",
-                                if filter_type.read().as_ref().map(|s| s == "onramp").unwrap_or(false) {
+                                if history_query.read().tx_type.as_ref().map(|s| s == "onramp").unwrap_or(false) {
                                     Colors::TECH_PRIMARY
                                 } else {
                                     Colors::BG_PRIMARY
                                 },
-                                if filter_type.read().as_ref().map(|s| s == "onramp").unwrap_or(false) {
+                                if history_query.read().tx_type.as_ref().map(|s| s == "onramp").unwrap_or(false) {
                                     "white"
                                 } else {
                                     Colors::TEXT_SECONDARY
                                 },
                                 Colors::BORDER_PRIMARY
                             ),
-                            onclick: {
-                                let mut filter_type_sig = filter_type;
-                                let mut current_page_sig = current_page;
-                                move |_| {
-                                    filter_type_sig.set(Some("onramp".to_string()));
-                                    current_page_sig.set(1);
-                                }
-                            },
+                            onclick: move |_| history_query.write().set_type(Some("onramp")),
                             "充值"
                         }
                         button {
                             class: "px-3 py-1 text-sm rounded",
                             style: format!(
                                 "background: {}; color: {}; border: 1px solid {};",
-                                if filter_type.read().as_ref().map(|s| s == "offramp").unwrap_or(false) {
+                                if history_query.read().tx_type.as_ref().map(|s| s == "offramp").unwrap_or(false) {
                                     Colors::TECH_PRIMARY
                                 } else {
                                     Colors::BG_PRIMARY
                                 },
-                                if filter_type.read().as_ref().map(|s| s == "offramp").unwrap_or(false) {
+                                if history_query.read().tx_type.as_ref().map(|s| s == "offramp").unwrap_or(false) {
                                     "white"
                                 } else {
                                     Colors::TEXT_SECONDARY
                                 },
                                 Colors::BORDER_PRIMARY
                             ),
-                            onclick: {
-                                let mut filter_type_sig = filter_type;
-                                let mut current_page_sig = current_page;
-                                move |_| {
-                                    filter_type_sig.set(Some("offramp".to_string()));
-                                    current_page_sig.set(1);
-                                }
-                            },
+                            onclick: move |_| history_query.write().set_type(Some("offramp")),
                             "提现"
                         }
                             }
@@ -6878,148 +7048,134 @@ fn HistoryTab() -> Element {
                                     class: "px-3 py-1 text-sm rounded",
                                     style: format!(
                                         "background: {}; color: {}; border: 1px solid {};",
-                                        if filter_status.read().is_none() {
+                                        if history_query.read().status.is_none() {
                                             Colors::TECH_PRIMARY
                                         } else {
                                             Colors::BG_PRIMARY
                                         },
-                                        if filter_status.read().is_none() {
+                                        if history_query.read().status.is_none() {
                                             "white"
                                         } else {
                                             Colors::TEXT_SECONDARY
                                         },
                                         Colors::BORDER_PRIMARY
                                     ),
-                                    onclick: {
-                                        let mut filter_status_sig = filter_status;
-                                        let mut current_page_sig = current_page;
-                                        move |_| {
-                                            filter_status_sig.set(None);
-                                            current_page_sig.set(1);
-                                        }
-                                    },
+                                    onclick: move |_| history_query.write().set_status(None),
                                     "全部状态"
                                 }
                                 button {
                                     class: "px-3 py-1 text-sm rounded",
                                     style: format!(
                                         "background: {}; color: {}; border: 1px solid {};",
-                                        if filter_status.read().as_ref().map(|s| s == "completed").unwrap_or(false) {
+                                        if history_query.read().status.as_ref().map(|s| s == "completed").unwrap_or(false) {
                                             Colors::TECH_PRIMARY
                                         } else {
                                             Colors::BG_PRIMARY
                                         },
-                                        if filter_status.read().as_ref().map(|s| s == "completed").unwrap_or(false) {
+                                        if history_query.read().status.as_ref().map(|s| s == "completed").unwrap_or(false) {
                                             "white"
                                         } else {
                                             Colors::TEXT_SECONDARY
                                         },
                                         Colors::BORDER_PRIMARY
                                     ),
-                                    onclick: {
-                                        let mut filter_status_sig = filter_status;
-                                        let mut current_page_sig = current_page;
-                                        move |_| {
-                                            filter_status_sig.set(Some("completed".to_string()));
-                                            current_page_sig.set(1);
-                                        }
-                                    },
+                                    onclick: move |_| history_query.write().set_status(Some("completed")),
                                     "已完成"
                                 }
                                 button {
                                     class: "px-3 py-1 text-sm rounded",
                                     style: format!(
                                         "background: {}; color: {}; border: 1px solid {};",
-                                        if filter_status.read().as_ref().map(|s| s == "pending" || s == "processing").unwrap_or(false) {
+                                        if history_query.read().status.as_ref().map(|s| s == "pending" || s == "processing").unwrap_or(false) {
                                             Colors::TECH_PRIMARY
                                         } else {
                                             Colors::BG_PRIMARY
                                         },
-                                        if filter_status.read().as_ref().map(|s| s == "pending" || s == "processing").unwrap_or(false) {
+                                        if history_query.read().status.as_ref().map(|s| s == "pending" || s == "processing").unwrap_or(false) {
                                             "white"
                                         } else {
                                             Colors::TEXT_SECONDARY
                                         },
                                         Colors::BORDER_PRIMARY
                                     ),
-                                    onclick: {
-                                        let mut filter_status_sig = filter_status;
-                                        let mut current_page_sig = current_page;
-                                        move |_| {
-                                            filter_status_sig.set(Some("pending".to_string()));
-                                            current_page_sig.set(1);
-                                        }
-                                    },
+                                    onclick: move |_| history_query.write().set_status(Some("pending")),
                                     "处理中"
                                 }
                             }
                         }
                     }
 
-            // 错误消息
-            ErrorMessage {
-                message: error_message.read().clone(),
-            }
-
             // 交易列表
-            if *loading.read() {
+            if history_list.state.read().loading {
                 div {
-                    class: "p-12 text-center",
-                    style: format!("background: {}; border: 1px solid {}; border-radius: 8px;", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                    div {
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "正在加载交易历史..."
+                    class: "space-y-3",
+                    for i in 0..5 {
+                        SkeletonTableRow { key: "{i}" }
                     }
                 }
-            } else if transactions.read().is_empty() {
+            } else if let Some(err) = history_list.state.read().error.clone() {
+                LoadFailedState {
+                    title: t("empty.history.failed"),
+                    message: err,
+                    retry_label: t("empty.load_failed.retry"),
+                    on_retry: move |_| history_list.reload(),
+                }
+            } else if history_list.state.read().items.is_empty() {
                 div {
-                    class: "p-12 text-center",
                     style: format!("background: {}; border: 1px solid {}; border-radius: 8px;", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
-                    div {
-                        style: format!("color: {};", Colors::TEXT_SECONDARY),
-                        "暂无交易记录"
+                    EmptyState {
+                        icon: "🔄".to_string(),
+                        title: t("empty.history.title"),
+                        description: t("empty.history.description"),
+                        cta_label: t("empty.history.cta"),
+                        on_cta: move |_| on_switch_tab.call(SwapTab::Swap),
                     }
                 }
             } else {
                 div {
                     class: "space-y-3",
-                    for tx in transactions.read().iter() {
+                    for tx in history_list.state.read().items.iter() {
                         TransactionHistoryItemCard {
                             transaction: tx.clone(),
+                            on_details: move |id: String| detail_id.set(Some(id)),
+                            on_retry: move |request: SwapRetryRequest| on_retry.call(request),
                     }
                 }
             }
             }
 
+            // 交易详情抽屉
+            if let Some(tx) = detail_transaction() {
+                SwapDetailDrawer {
+                    transaction: tx,
+                    on_close: move |_| detail_id.set(None),
+                }
+            }
+
             // 分页控件（仅交易历史模式显示）
-            if view_mode.read().as_str() == "transactions" && *total_pages.read() > 1 {
+            if view_mode.read().as_str() == "transactions" && history_list.state.read().total_pages > 1 {
                 div {
                     class: "flex justify-center items-center gap-2 mt-6",
                     button {
                         class: "px-4 py-2 rounded",
                         style: format!(
                             "background: {}; color: {}; border: 1px solid {};",
-                            if *current_page.read() <= 1 {
+                            if !history_list.state.read().has_prev() {
                                 Colors::BG_SECONDARY
                             } else {
                                 Colors::BG_PRIMARY
                             },
-                            if *current_page.read() <= 1 {
+                            if !history_list.state.read().has_prev() {
                                 Colors::TEXT_TERTIARY
                             } else {
                                 Colors::TEXT_PRIMARY
                             },
                             Colors::BORDER_PRIMARY
                         ),
-                        disabled: *current_page.read() <= 1,
-                        onclick: {
-                            let mut current_page_sig = current_page;
-                            move |_| {
-                                let page = *current_page_sig.read();
-                                if page > 1 {
-                                    current_page_sig.set(page - 1);
-                                }
-                            }
+                        disabled: !history_list.state.read().has_prev(),
+                        onclick: move |_| {
+                            let page = history_list.state.peek().page;
+                            history_list.go_to(page.saturating_sub(1));
                         },
                         "上一页"
                     }
@@ -7027,38 +7183,30 @@ fn HistoryTab() -> Element {
                         class: "px-4",
                         style: format!("color: {};", Colors::TEXT_SECONDARY),
                         {
-                            let current = *current_page.read();
-                            let total = *total_pages.read();
-                            format!("第 {} / {} 页", current, total)
+                            let state = history_list.state.read();
+                            format!("第 {} / {} 页", state.page, state.total_pages)
                         }
                     }
                     button {
                         class: "px-4 py-2 rounded",
                         style: format!(
                             "background: {}; color: {}; border: 1px solid {};",
-                            if *current_page.read() >= *total_pages.read() {
+                            if !history_list.state.read().has_next() {
                                 Colors::BG_SECONDARY
                             } else {
                                 Colors::BG_PRIMARY
                             },
-                            if *current_page.read() >= *total_pages.read() {
+                            if !history_list.state.read().has_next() {
                                 Colors::TEXT_TERTIARY
                             } else {
                                 Colors::TEXT_PRIMARY
                             },
                             Colors::BORDER_PRIMARY
                         ),
-                        disabled: *current_page.read() >= *total_pages.read(),
-                        onclick: {
-                            let mut current_page_sig = current_page;
-                            let total_pages_sig = total_pages;
-                            move |_| {
-                                let page = *current_page_sig.read();
-                                let total = *total_pages_sig.read();
-                                if page < total {
-                                    current_page_sig.set(page + 1);
-                                }
-                            }
+                        disabled: !history_list.state.read().has_next(),
+                        onclick: move |_| {
+                            let page = history_list.state.peek().page;
+                            history_list.go_to(page + 1);
                         },
                         "下一页"
                             }
@@ -7082,32 +7230,45 @@ fn HistoryTab() -> Element {
 
 /// 交易历史项卡片组件
 #[component]
-fn TransactionHistoryItemCard(transaction: TransactionHistoryItem) -> Element {
-    // 获取交易类型标签
-    let tx_type_label = match transaction.tx_type.as_str() {
-        "swap" => "交换",
-        "onramp" => "充值",
-        "offramp" => "提现",
-        _ => "未知",
+fn TransactionHistoryItemCard(
+    transaction: TransactionHistoryItem,
+    /// 打开详情抽屉（参数为交易ID）
+    on_details: EventHandler<String>,
+    /// 重试失败的兑换
+    on_retry: EventHandler<SwapRetryRequest>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    // 获取交易类型标签（原生币与包装币之间的兑换标为包装/解包）
+    let tx_type_label = match wrap::history_direction(&transaction) {
+        Some(direction) => direction.label(),
+        None => match transaction.tx_type.as_str() {
+            "swap" => "交换",
+            "onramp" => "充值",
+            "offramp" => "提现",
+            _ => "未知",
+        },
     };
 
-    // 获取状态标签和颜色
-    let (status_label, status_color) = match transaction.status.as_str() {
-        "pending" => ("待处理".to_string(), "#F59E0B".to_string()),
-        "processing" => ("处理中".to_string(), "#3B82F6".to_string()),
-        "completed" => ("已完成".to_string(), "#10B981".to_string()),
-        "failed" => ("失败".to_string(), "#EF4444".to_string()),
-        "cancelled" => ("已取消".to_string(), "#6B7280".to_string()),
-        _ => ("未知".to_string(), Colors::TEXT_SECONDARY.to_string()),
+    // 获取状态标签和颜色（兑换使用 待处理/执行中/已确认/失败 四种状态）
+    let swap_status = (transaction.tx_type == "swap")
+        .then(|| SwapHistoryStatus::from_status(&transaction.status))
+        .flatten();
+    let retry = history::retry_request(&transaction);
+    let (status_label, status_color) = match swap_status {
+        Some(status) => (status.label().to_string(), status.color().to_string()),
+        None => match transaction.status.as_str() {
+            "pending" => ("待处理".to_string(), "#F59E0B".to_string()),
+            "processing" => ("处理中".to_string(), "#3B82F6".to_string()),
+            "completed" => ("已完成".to_string(), "#10B981".to_string()),
+            "failed" => ("失败".to_string(), "#EF4444".to_string()),
+            "cancelled" => ("已取消".to_string(), "#6B7280".to_string()),
+            _ => ("未知".to_string(), Colors::TEXT_SECONDARY.to_string()),
+        },
     };
 
-    // 格式化日期（简化处理，只显示日期部分）
-    let date_display = transaction
-        .created_at
-        .split('T')
-        .next()
-        .unwrap_or(&transaction.created_at)
-        .to_string();
+    // 按本地时区显示创建时间
+    let date_display =
+        time::format_api_datetime(&transaction.created_at, &app_state.language.read());
 
     // 处理交易哈希显示（如果有）
     let tx_hash_display = transaction.tx_hash.as_ref().map(|tx_hash| {
@@ -7181,7 +7342,8 @@ fn TransactionHistoryItemCard(transaction: TransactionHistoryItem) -> Element {
                         class: "flex flex-wrap items-center gap-2 text-sm",
                         span {
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "{transaction.from_amount} {transaction.from_token}"
+                            MaskedAmount { value: transaction.from_amount.clone() }
+                            " {transaction.from_token}"
                         }
                         span {
                             style: format!("color: {};", Colors::TEXT_SECONDARY),
@@ -7189,7 +7351,8 @@ fn TransactionHistoryItemCard(transaction: TransactionHistoryItem) -> Element {
                         }
                         span {
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
-                            "{transaction.to_amount} {transaction.to_token}"
+                            MaskedAmount { value: transaction.to_amount.clone() }
+                            " {transaction.to_token}"
                         }
                     }
 
@@ -7319,24 +7482,375 @@ fn TransactionHistoryItemCard(transaction: TransactionHistoryItem) -> Element {
                                 style: format!("color: {}; text-decoration: underline;", Colors::TECH_PRIMARY),
                                 "{tx_hash_display_val}"
                             }
+                            if let Some(tx_hash) = &transaction.tx_hash {
+                                CopyButton { value: tx_hash.clone(), compact: true }
+                            }
                         }
                     }
                 }
 
-                // 右侧：操作按钮（可选）
-                if transaction.status.as_str() == "pending" || transaction.status.as_str() == "processing" {
-                    div {
-                        class: "flex gap-2",
+                // 右侧：操作按钮
+                div {
+                    class: "flex gap-2",
+                    if let Some(request) = retry {
                         button {
                             class: "px-3 py-1 text-sm rounded",
-                            style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
-                            onclick: move |_| {
-                                log::info!("查看详情: {}", transaction.id);
-                            },
-                            "查看详情"
+                            style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                            onclick: move |_| on_retry.call(request.clone()),
+                            "重试"
+                        }
+                    }
+                    button {
+                        class: "px-3 py-1 text-sm rounded",
+                        style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::TEXT_PRIMARY, Colors::BORDER_PRIMARY),
+                        onclick: {
+                            let id = transaction.id.clone();
+                            move |_| on_details.call(id.clone())
+                        },
+                        "查看详情"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 交易详情抽屉（路由、费用、交易哈希与确认数）
+#[component]
+fn SwapDetailDrawer(transaction: TransactionHistoryItem, on_close: EventHandler<()>) -> Element {
+    let status = SwapHistoryStatus::from_status(&transaction.status);
+    let network = history::metadata_str(&transaction, "network");
+    let route = history::route_summary(&transaction);
+    let confirmations = history::confirmations(&transaction);
+    // 确认进度（按兑换所在链的确认数要求展示）
+    let app_state = use_context::<AppState>();
+    let confirmation_text = match (
+        confirmations,
+        network.as_deref().and_then(ChainType::from_str),
+    ) {
+        (Some(current), Some(chain)) => confirmation_progress(
+            current,
+            ChainConfigManager::new().required_confirmations(
+                chain,
+                None,
+                &app_state.preferences.read().confirmation_overrides,
+            ),
+        ),
+        (Some(current), None) => current.to_string(),
+        (None, _) => "-".to_string(),
+    };
+    let explorer_url = match (&network, &transaction.tx_hash) {
+        (Some(network), Some(tx_hash)) => explorer_tx_url(network, tx_hash),
+        _ => None,
+    };
+    // 已确认的兑换可生成收据
+    let navigator = use_navigator();
+    let receipt_route = match (&network, &transaction.tx_hash, status) {
+        (Some(network), Some(tx_hash), Some(SwapHistoryStatus::Confirmed)) => {
+            Some(Route::TxReceipt {
+                chain: network.to_lowercase(),
+                hash: tx_hash.clone(),
+            })
+        }
+        _ => None,
+    };
+
+    let row = |label: &'static str, value: String| {
+        rsx! {
+            div {
+                class: "flex justify-between gap-4 text-sm",
+                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{label}" }
+                span { class: "text-right break-all", style: format!("color: {};", Colors::TEXT_PRIMARY), "{value}" }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex justify-end",
+            style: "background: rgba(0, 0, 0, 0.5);",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "h-full w-full max-w-md p-6 overflow-y-auto space-y-4",
+                style: format!("background: {}; border-left: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                onclick: move |e| e.stop_propagation(),
+
+                div {
+                    class: "flex items-center justify-between",
+                    h3 {
+                        class: "text-lg font-semibold",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "交易详情"
+                    }
+                    button {
+                        class: "text-sm px-2",
+                        style: format!("color: {};", Colors::TEXT_SECONDARY),
+                        onclick: move |_| on_close.call(()),
+                        "✕"
+                    }
+                }
+
+                if let Some(status) = status {
+                    span {
+                        class: "inline-block px-2 py-1 text-xs font-medium rounded",
+                        style: format!("background: {}; color: white;", status.color()),
+                        "{status.label()}"
+                    }
+                }
+
+                div {
+                    class: "space-y-2 p-4 rounded-lg",
+                    style: format!("background: {};", Colors::BG_PRIMARY),
+                    {row("支付", format!("{} {}", transaction.from_amount, transaction.from_token))}
+                    {row("收到", format!("{} {}", transaction.to_amount, transaction.to_token))}
+                    {row("网络", network.clone().unwrap_or_else(|| "-".to_string()))}
+                    {row("路由", route.unwrap_or_else(|| "-".to_string()))}
+                }
+
+                if execution::is_analyzable(&transaction) {
+                    ExecutionQualitySection { transaction: transaction.clone() }
+                }
+
+                div {
+                    class: "space-y-2 p-4 rounded-lg",
+                    style: format!("background: {};", Colors::BG_PRIMARY),
+                    {row("⛽ Gas费", transaction.gas_fee.clone().unwrap_or_else(|| "-".to_string()))}
+                    {row("平台服务费", transaction.fee_amount.clone().unwrap_or_else(|| "-".to_string()))}
+                }
+
+                div {
+                    class: "space-y-2 p-4 rounded-lg",
+                    style: format!("background: {};", Colors::BG_PRIMARY),
+                    {row("确认数", confirmation_text)}
+                    {row("创建时间", time::format_api_datetime(&transaction.created_at, &app_state.language.read()))}
+                    if let Some(completed_at) = transaction.completed_at.as_deref() {
+                        {row("完成时间", time::format_api_datetime(completed_at, &app_state.language.read()))}
+                    }
+                    if let Some(tx_hash) = transaction.tx_hash.clone() {
+                        div {
+                            class: "text-sm space-y-1",
+                            span { style: format!("color: {};", Colors::TEXT_SECONDARY), "交易哈希" }
+                            if let Some(url) = explorer_url {
+                                a {
+                                    class: "block font-mono break-all hover:opacity-80",
+                                    style: format!("color: {}; text-decoration: underline;", Colors::TECH_PRIMARY),
+                                    href: "{url}",
+                                    target: "_blank",
+                                    rel: "noopener noreferrer",
+                                    "{tx_hash}"
+                                }
+                            } else {
+                                div {
+                                    class: "font-mono break-all",
+                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                    "{tx_hash}"
+                                }
+                            }
                         }
                     }
                 }
+
+                if let Some(receipt_route) = receipt_route {
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        onclick: move |_| {
+                            navigator.push(receipt_route.clone());
+                        },
+                        "🧾 查看收据"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 交易详情中的执行质量：预期与实际收到、滑点消耗、是否遭遇夹子攻击
+#[component]
+fn ExecutionQualitySection(transaction: TransactionHistoryItem) -> Element {
+    let app_state = use_context::<AppState>();
+    let report = use_resource(use_reactive!(|transaction| async move {
+        execution::analyze(app_state, &transaction).await
+    }));
+
+    let row = |label: &'static str, value: String, color: &'static str| {
+        rsx! {
+            div {
+                class: "flex justify-between gap-4 text-sm",
+                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "{label}" }
+                span { class: "text-right break-all", style: format!("color: {};", color), "{value}" }
+            }
+        }
+    };
+
+    let body = match &*report.read() {
+        None => rsx! {
+            div { class: "text-sm", style: format!("color: {};", Colors::TEXT_TERTIARY), "正在分析执行质量…" }
+        },
+        Some(None) => rsx! {
+            div {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                "没有这笔兑换提交时的报价记录（可能在其他设备上提交），无法评估执行质量"
+            }
+        },
+        Some(Some(report)) => {
+            let token = report.to_token.clone();
+            let mev_text = match (report.mev_checked, &report.sandwich) {
+                (_, Some(found)) => format!("是（发起方 {}）", found.attacker),
+                (true, None) => "否".to_string(),
+                (false, None) => "暂时无法检测".to_string(),
+            };
+            let mev_color = if report.mev_detected() {
+                Colors::PAYMENT_ERROR
+            } else {
+                Colors::TEXT_PRIMARY
+            };
+            rsx! {
+                {row("预期收到", format!("{} {}", report.expected_output, token), Colors::TEXT_PRIMARY)}
+                {row("实际收到", format!("{} {}", report.actual_output, token), Colors::TEXT_PRIMARY)}
+                {row(
+                    "滑点消耗",
+                    if report.shortfall_pct <= 0.0 {
+                        "未消耗（成交不差于报价）".to_string()
+                    } else {
+                        format!(
+                            "{:.2}%（占容忍度 {}% 的 {:.0}%）",
+                            report.shortfall_pct,
+                            report.slippage_pct,
+                            report.slippage_consumed_pct()
+                        )
+                    },
+                    Colors::TEXT_PRIMARY,
+                )}
+                {row(
+                    "MEV 保护",
+                    match report.mev_protected {
+                        Some(true) => "已启用（私有通道提交）".to_string(),
+                        Some(false) => "未启用".to_string(),
+                        None => "未知".to_string(),
+                    },
+                    Colors::TEXT_PRIMARY,
+                )}
+                {row("检测到夹子攻击", mev_text, mev_color)}
+                if let Some(loss) = report.estimated_mev_loss() {
+                    {row("估算损失（相对报价）", format!("约 {:.6} {}", loss, token), Colors::PAYMENT_ERROR)}
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "space-y-2 p-4 rounded-lg",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+            div {
+                class: "text-sm font-medium mb-1",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                "执行质量"
+            }
+            {body}
+        }
+    }
+}
+
+/// 历史标签页的执行质量汇总卡片（为当前页已确认的兑换补充生成报告）
+#[component]
+fn ExecutionQualityCard(transactions: Vec<TransactionHistoryItem>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut reports = use_signal(execution::all_reports);
+
+    use_effect(use_reactive!(|transactions| {
+        let pending: Vec<TransactionHistoryItem> = transactions
+            .into_iter()
+            .filter(|tx| {
+                execution::is_analyzable(tx)
+                    && execution::report_for(&tx.id).is_none()
+                    && execution::quote_for(&tx.id).is_some()
+            })
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+        spawn(async move {
+            for item in &pending {
+                execution::analyze(app_state, item).await;
+            }
+            reports.set(execution::all_reports());
+        });
+    }));
+
+    let stats = execution::stats(&reports.read());
+    if stats.analyzed == 0 {
+        return VNode::empty();
+    }
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg grid grid-cols-2 sm:grid-cols-4 gap-3 text-sm",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            div {
+                div { class: "text-xs", style: format!("color: {};", Colors::TEXT_SECONDARY), "已评估兑换" }
+                div { class: "font-semibold", style: format!("color: {};", Colors::TEXT_PRIMARY), "{stats.analyzed}" }
+            }
+            div {
+                div { class: "text-xs", style: format!("color: {};", Colors::TEXT_SECONDARY), "成交不差于报价" }
+                div { class: "font-semibold", style: format!("color: {};", Colors::TEXT_PRIMARY), "{stats.at_or_better}" }
+            }
+            div {
+                div { class: "text-xs", style: format!("color: {};", Colors::TEXT_SECONDARY), "平均滑点消耗" }
+                div {
+                    class: "font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {format!("{:.0}%", stats.avg_slippage_consumed_pct)}
+                }
+            }
+            div {
+                div { class: "text-xs", style: format!("color: {};", Colors::TEXT_SECONDARY), "遭遇夹子攻击" }
+                div {
+                    class: "font-semibold",
+                    style: format!(
+                        "color: {};",
+                        if stats.mev_detected > 0 { Colors::PAYMENT_ERROR } else { Colors::TEXT_PRIMARY }
+                    ),
+                    {format!("{} / {}", stats.mev_detected, stats.mev_checked)}
+                }
+            }
+        }
+    }
+}
+
+/// 重复订单提示（确认后仍可提交）
+#[component]
+pub(crate) fn DuplicateOrderWarning(
+    message: String,
+    on_confirm: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    rsx! {
+        div {
+            class: "p-4 rounded-lg",
+            style: "background: rgba(245, 158, 11, 0.1); border: 1px solid rgba(245, 158, 11, 0.4);",
+            div {
+                class: "text-sm font-medium mb-3",
+                style: "color: rgba(245, 158, 11, 1);",
+                "⚠️ {message}"
+            }
+            div {
+                class: "flex gap-2 justify-end",
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    size: ButtonSize::Small,
+                    onclick: move |_| on_cancel.call(()),
+                    "取消"
+                }
+                Button {
+                    variant: ButtonVariant::Warning,
+                    size: ButtonSize::Small,
+                    cooldown_ms: submission_guard::SUBMIT_COOLDOWN_MS,
+                    onclick: move |_| on_confirm.call(()),
+                    "仍然提交"
+                }
             }
         }
     }
@@ -7399,6 +7913,8 @@ fn PaymentModal(
     card_expiry: Signal<String>,
     card_cvv: Signal<String>,
     card_holder_name: Signal<String>,
+    selected_card: Signal<Option<String>>,
+    save_offer: Signal<Option<CardToken>>,
     processing: Signal<bool>,
     on_close: EventHandler<()>,
     on_submit: EventHandler<()>,
@@ -7407,6 +7923,46 @@ fn PaymentModal(
     let payment_type = PaymentMethodType::from_string(&payment_method.read());
     let mut payment_error = use_signal(|| None::<String>);
     let payment_success = use_signal(|| false);
+    let mut saving_card = use_signal(|| false);
+    let mut confirm_cancel = use_signal(|| false);
+    let mut cancelling = use_signal(|| false);
+
+    // 已保存的银行卡：首次加载后默认选中第一张未过期的卡
+    let saved_cards = use_saved_cards();
+    let mut default_card_applied = use_signal(|| false);
+    use_effect(move || {
+        let cards = saved_cards.read();
+        if cards.is_empty() || *default_card_applied.peek() {
+            return;
+        }
+        default_card_applied.set(true);
+        let (year, month) = payment_gateway::current_year_month();
+        if let Some(card) = cards.iter().find(|c| !c.is_expired_at(year, month)) {
+            let mut selected = selected_card;
+            selected.set(Some(card.id.clone()));
+        }
+    });
+
+    let save_card = move |_| {
+        let Some(token) = save_offer.peek().clone() else {
+            return;
+        };
+        saving_card.set(true);
+        spawn(async move {
+            match PaymentGatewayService::new(app_state)
+                .save_card(&token)
+                .await
+            {
+                Ok(card) => AppState::show_success(
+                    app_state.toasts,
+                    format!("{} 已保存，下次可一键支付", card.label()),
+                ),
+                Err(e) => AppState::show_error(app_state.toasts, e),
+            }
+            saving_card.set(false);
+            on_close.call(());
+        });
+    };
 
     // 获取当前钱包地址
     let wallet_address = use_memo(move || {
@@ -7418,10 +7974,32 @@ fn PaymentModal(
             .unwrap_or_default()
     });
 
-    // TODO: 真实支付处理函数 - 等待 payment_gateway 服务实现
+    // 放弃支付：取消充值订单（支付处理中时服务商仍可能扣款）
+    let cancel_safety = if *processing.read() {
+        CancelSafety::PaymentInProgress
+    } else {
+        CancelSafety::Unpaid
+    };
+    let cancel_order = move |_| {
+        let id = order_id.peek().clone();
+        cancelling.set(true);
+        spawn(async move {
+            match onramp_orders::cancel(app_state, &id).await {
+                Ok(()) => {
+                    AppState::show_success(app_state.toasts, format!("订单 {} 已取消", id));
+                    confirm_cancel.set(false);
+                    on_close.call(());
+                }
+                Err(e) => payment_error.set(Some(e)),
+            }
+            cancelling.set(false);
+        });
+    };
+
+    // TODO: 真实扣款处理函数 - 目前仅实现了银行卡令牌化，扣款接口待接入
     // 临时占位实现
     let _handle_payment = move || {
-        log::warn!("PaymentGatewayService 尚未实现，支付功能暂时不可用");
+        log::warn!("PaymentGatewayService 尚未实现扣款接口，支付功能暂时不可用");
         payment_error.set(Some("支付网关服务正在开发中，敬请期待".to_string()));
     };
 
@@ -7488,8 +8066,63 @@ fn PaymentModal(
                         style: format!("color: {};", Colors::TEXT_SECONDARY),
                         "支付方式: {payment_type.title()}"
                     }
+
+                    // 银行卡：已保存的卡一键选择，选择“使用新卡”时才显示卡表单
+                    if payment_type == PaymentMethodType::CreditCard && save_offer.read().is_none() {
+                        if !saved_cards.read().is_empty() {
+                            SavedCardList { cards: saved_cards, selected: Some(selected_card) }
+                        }
+                        if selected_card.read().is_none() {
+                            CardForm {
+                                card_number,
+                                card_expiry,
+                                card_cvv,
+                                card_holder_name,
+                            }
+                        }
+                    }
                 }
 
+                // 支付成功后询问是否保存新卡
+                if let Some(token) = save_offer.read().as_ref() {
+                    div {
+                        class: "mt-6 p-4 rounded-lg space-y-3",
+                        style: format!("background: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY),
+                        div {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {format!(
+                                "是否保存 {} •••• {}（{:02}/{:02}）以便下次一键支付？",
+                                token.brand,
+                                token.last4,
+                                token.exp_month,
+                                token.exp_year % 100
+                            )}
+                        }
+                        div {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            "仅保存卡品牌、末四位与有效期，完整卡号由支付网关加密保管。"
+                        }
+                        div {
+                            class: "flex gap-3",
+                            button {
+                                class: "flex-1 py-2 rounded-lg font-semibold",
+                                style: format!("background: {}; color: white;", Colors::TECH_PRIMARY),
+                                disabled: *saving_card.read(),
+                                onclick: save_card,
+                                if *saving_card.read() { "保存中..." } else { "保存此卡" }
+                            }
+                            button {
+                                class: "flex-1 py-2 rounded-lg",
+                                style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_SECONDARY),
+                                disabled: *saving_card.read(),
+                                onclick: move |_| on_close.call(()),
+                                "不用了"
+                            }
+                        }
+                    }
+                } else {
                 // 提交按钮
                 button {
                     class: "w-full mt-6 py-3 px-4 rounded-lg font-semibold transition-all hover:opacity-90",
@@ -7507,6 +8140,16 @@ fn PaymentModal(
                         "🚀 确认支付"
                     }
                 }
+                if !*payment_success.read() {
+                    button {
+                        class: "w-full mt-3 py-2 px-4 rounded-lg text-sm transition-all hover:opacity-80",
+                        style: format!("background: {}; color: {}; border: 1px solid {};", Colors::BG_PRIMARY, Colors::TEXT_SECONDARY, Colors::BORDER_PRIMARY),
+                        disabled: *cancelling.read(),
+                        onclick: move |_| confirm_cancel.set(true),
+                        "取消订单"
+                    }
+                }
+                }
 
                 // 错误提示
                 if let Some(err) = payment_error.read().as_ref() {
@@ -7527,5 +8170,18 @@ fn PaymentModal(
                 }
             }
         }
+
+        ConfirmAction {
+            open: confirm_cancel(),
+            risk: if cancel_safety.is_safe() { RiskLevel::Caution } else { RiskLevel::Destructive },
+            title: cancel_safety.title().to_string(),
+            message: cancel_safety.message(&order_id.read()),
+            details: cancel_safety.details(),
+            confirm_text: "确认取消".to_string(),
+            cancel_text: "继续支付".to_string(),
+            busy: cancelling(),
+            on_cancel: move |_| confirm_cancel.set(false),
+            on_confirm: cancel_order,
+        }
     }
 }
@@ -0,0 +1,117 @@
+//! 兑换页面端到端测试（浏览器中运行：`wasm-pack test --headless --firefox`）
+//!
+//! 挂载真实的兑换页面，API 由模拟后端拦截（默认返回演示数据：0.5 ETH 余额）。
+
+use crate::router::Route;
+use crate::shared::api_endpoints::{swap, transactions};
+use crate::shared::mock_api::MockApi;
+use crate::shared::test_harness::{seed_unlocked_wallet, wait_until, Harness};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// 挂载兑换页，选择 ETH → USDT 并输入数量，等待报价返回
+async fn open_swap_with_quote(mock: MockApi, amount: &str) -> Harness {
+    let h = Harness::mount(Route::Swap {}, mock, |state| {
+        seed_unlocked_wallet(state);
+    })
+    .await;
+
+    // 有钱包时支付代币默认选中 USDT，改为 ETH
+    h.click_text("button", "USDT (").await;
+    h.click_option("ETH").await;
+    h.click_text("button", "选择代币").await;
+    h.click_option("USDT").await;
+    h.type_into("input[inputmode=decimal]", amount).await;
+
+    let quote = h.wait_for_request("GET", swap::QUOTE).await;
+    assert!(quote.path.contains("from=ETH"), "{}", quote.path);
+    assert!(quote.path.contains("to=USDT"), "{}", quote.path);
+    h
+}
+
+#[wasm_bindgen_test]
+async fn swap_happy_path_executes_broadcasts_and_refreshes_balances() {
+    let h = open_swap_with_quote(MockApi::new(), "0.1").await;
+
+    h.click_text("button", "执行交换").await;
+    h.click_text("button", "确认交换").await;
+
+    let status = h.wait_for_request("PUT", "/api/v1/swap/").await;
+    h.mock
+        .check_sequence(&[
+            ("GET", swap::QUOTE),
+            ("POST", swap::EXECUTE),
+            ("GET", transactions::NONCE),
+            ("POST", transactions::BROADCAST),
+            ("PUT", "/api/v1/swap/"),
+        ])
+        .unwrap();
+
+    let execute = h.mock.last_request("POST", swap::EXECUTE).unwrap();
+    let body = execute.body.unwrap();
+    assert_eq!(body["wallet_name"], "e2e-wallet");
+    assert_eq!(body["from_token"], "ETH");
+    assert_eq!(body["to_token"], "USDT");
+    assert_eq!(body["amount"], "0.1");
+    assert_eq!(body["network"], "ethereum");
+    assert!(body["client_request_id"].is_string());
+
+    let broadcast = h
+        .mock
+        .last_request("POST", transactions::BROADCAST)
+        .unwrap();
+    let signed_tx = broadcast.body.unwrap()["signed_tx"].clone();
+    assert!(signed_tx.as_str().is_some_and(|tx| tx.starts_with("0x")));
+
+    assert!(status.path.ends_with("/status"), "{}", status.path);
+    let status_body = status.body.unwrap();
+    assert_eq!(status_body["status"], "executing");
+    assert!(status_body["tx_hash"]
+        .as_str()
+        .is_some_and(|hash| hash.starts_with("0xdemo")));
+
+    h.wait_for_toast("交易已广播，等待链上确认").await;
+    // 余额检查写入的缓存在广播后失效
+    wait_until("余额缓存已清除", || {
+        !h.state
+            .cache
+            .peek()
+            .keys()
+            .any(|k| k.starts_with("balance:"))
+    })
+    .await;
+    wait_until("跳转到资产页", || h.path() == "/dashboard").await;
+    h.unmount();
+}
+
+#[wasm_bindgen_test]
+async fn swap_with_insufficient_balance_is_not_submitted() {
+    let h = open_swap_with_quote(MockApi::new(), "5").await;
+
+    h.click_text("button", "执行交换").await;
+    h.wait_for_text("余额不足！可用余额：").await;
+
+    assert!(h.mock.requests_matching("POST", swap::EXECUTE).is_empty());
+    assert!(!h.text().contains("确认交换"));
+    h.unmount();
+}
+
+#[wasm_bindgen_test]
+async fn swap_with_expired_quote_shows_error_without_broadcast() {
+    let mock = MockApi::new();
+    mock.fail("POST", swap::EXECUTE, "quote expired");
+    let h = open_swap_with_quote(mock, "0.1").await;
+
+    h.click_text("button", "执行交换").await;
+    h.click_text("button", "确认交换").await;
+    h.wait_for_text("交换失败").await;
+
+    assert_eq!(h.mock.requests_matching("POST", swap::EXECUTE).len(), 1);
+    assert!(h
+        .mock
+        .requests_matching("POST", transactions::BROADCAST)
+        .is_empty());
+    assert_eq!(h.path(), "/swap");
+    h.unmount();
+}
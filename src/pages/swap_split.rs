@@ -0,0 +1,185 @@
+//! Swap Split - 交易规模建议与拆分兑换进度
+//! 价格影响过高时提示安全金额；用户接受拆分后逐笔确认执行并显示进度
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::features::swap::trade_size::{
+    self, format_amount, LegStatus, SizeSuggestion, SplitPlan, PRICE_IMPACT_WARN_PERCENT,
+};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 交易规模建议
+#[component]
+pub fn TradeSizeChip(
+    suggestion: SizeSuggestion,
+    from_symbol: String,
+    /// 接受拆分
+    on_split: EventHandler<()>,
+    /// 只兑换安全金额
+    on_reduce: EventHandler<String>,
+) -> Element {
+    let max_amount = format_amount(suggestion.max_amount);
+    let leg_count = suggestion.legs.len();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!(
+                "background: rgba(245, 158, 11, 0.08); border: 1px solid {};",
+                Colors::PAYMENT_WARNING
+            ),
+            p {
+                class: "text-sm",
+                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                if leg_count > 1 {
+                    {format!(
+                        "⚠️ 兑换不超过 {} {} 可将价格影响控制在 {}% 以内 — 拆分为 {} 笔交易？",
+                        max_amount, from_symbol, PRICE_IMPACT_WARN_PERCENT, leg_count
+                    )}
+                } else {
+                    {format!(
+                        "⚠️ 兑换不超过 {} {} 可将价格影响控制在 {}% 以内",
+                        max_amount, from_symbol, PRICE_IMPACT_WARN_PERCENT
+                    )}
+                }
+            }
+            div {
+                class: "flex gap-2 flex-wrap",
+                if leg_count > 1 {
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        size: ButtonSize::Small,
+                        onclick: move |_| on_split.call(()),
+                        {format!("拆分为 {} 笔", leg_count)}
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    size: ButtonSize::Small,
+                    onclick: {
+                        let max_amount = max_amount.clone();
+                        move |_| on_reduce.call(max_amount.clone())
+                    },
+                    {format!("只兑换 {} {}", max_amount, from_symbol)}
+                }
+            }
+        }
+    }
+}
+
+/// 拆分兑换进度：每笔单独确认，按顺序执行
+#[component]
+pub fn SplitSwapTracker(plan: Signal<Option<SplitPlan>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let Some(current) = plan.read().clone() else {
+        return rsx! {};
+    };
+    let total = current.legs.len();
+    let completed = current.completed();
+    let next = current.next_leg();
+    let finished = current.is_finished();
+    let progress = completed as f64 / total.max(1) as f64 * 100.0;
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg space-y-3",
+            style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+            div {
+                class: "flex items-center justify-between",
+                h3 {
+                    class: "text-base font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {format!("拆分兑换 {} → {}", current.from, current.to)}
+                }
+                span {
+                    class: "text-sm",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {format!("{}/{}", completed, total)}
+                }
+            }
+            div {
+                class: "h-2 rounded-full overflow-hidden",
+                style: format!("background: {};", Colors::BG_PRIMARY),
+                div {
+                    class: "h-full transition-all",
+                    style: format!("width: {:.0}%; background: {};", progress, Colors::TECH_PRIMARY),
+                }
+            }
+            div {
+                class: "space-y-2",
+                for (index, (amount, status)) in current.legs.iter().cloned().enumerate() {
+                    div {
+                        key: "{index}",
+                        class: "flex items-center justify-between gap-2 text-sm",
+                        span {
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            {format!("第 {} 笔 · {} {}", index + 1, amount, current.from)}
+                        }
+                        match status {
+                            LegStatus::Done { tx_hash } => rsx! {
+                                span {
+                                    class: "text-xs font-mono",
+                                    style: format!("color: {};", Colors::PAYMENT_SUCCESS),
+                                    {format!("✓ {}…", &tx_hash[..tx_hash.len().min(10)])}
+                                }
+                            },
+                            LegStatus::Running => rsx! {
+                                span {
+                                    class: "text-xs",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    "执行中..."
+                                }
+                            },
+                            status => rsx! {
+                                div {
+                                    class: "flex items-center gap-2",
+                                    if let LegStatus::Failed { error } = status {
+                                        span {
+                                            class: "text-xs",
+                                            style: format!("color: {};", Colors::PAYMENT_ERROR),
+                                            {error}
+                                        }
+                                    }
+                                    if next == Some(index) {
+                                        Button {
+                                            variant: ButtonVariant::Primary,
+                                            size: ButtonSize::Small,
+                                            onclick: move |_| {
+                                                spawn(trade_size::execute_leg(app_state, plan, index));
+                                            },
+                                            "确认"
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+            if finished {
+                div {
+                    class: "flex items-center justify-between",
+                    span {
+                        class: "text-sm",
+                        style: format!("color: {};", Colors::PAYMENT_SUCCESS),
+                        "全部兑换已提交"
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        onclick: move |_| plan.set(None),
+                        "关闭"
+                    }
+                }
+            } else if next.is_some() {
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    size: ButtonSize::Small,
+                    onclick: move |_| plan.set(None),
+                    if completed == 0 { "取消拆分" } else { "放弃剩余兑换" }
+                }
+            }
+        }
+    }
+}
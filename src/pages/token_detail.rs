@@ -0,0 +1,724 @@
+//! Token Detail Page - 代币详情页面
+//! 显示代币行情（价格、涨跌、市值）、用户在各账户的持仓、近期转账记录与合约信息，
+//! 并提供兑换、发送、接收、关注与隐藏等操作
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::Card;
+use crate::components::atoms::copy_button::CopyButton;
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::skeleton::{SkeletonTableRow, SkeletonText};
+use crate::components::molecules::{PriceChangeIndicator, PriceChart, PriceDataPoint};
+use crate::components::route_guard::AuthGuard;
+use crate::features::dashboard::watchlist::{self, Watchlist, WatchlistEntry};
+use crate::features::swap::history::{self, SwapRetryRequest};
+use crate::features::wallet::fee_bump::collapse_replacements;
+use crate::features::wallet::state::Account;
+use crate::features::wallet::token_preferences::{self, find_token, token_key, TokenPreferences};
+use crate::pages::dashboard_watchlist::{change_info, format_price};
+use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::services::chain_config::ChainConfigManager;
+use crate::services::price::PriceService;
+use crate::services::token::{TokenInfo, TokenService};
+use crate::services::token_metadata::{TokenMetadataService, TokenMetadataStore};
+use crate::services::transaction::{TransactionHistoryItem, TransactionService};
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+
+/// 近期转账最多显示的条数
+const RECENT_TRANSFER_LIMIT: usize = 10;
+
+/// Token Detail Page 组件
+///
+/// `address` 为合约地址；只有代币符号的入口（如交易记录）也可传符号，原生代币可传 `native`
+#[component]
+pub fn TokenDetail(chain: String, address: String) -> Element {
+    rsx! {
+        AuthGuard {
+            TokenDetailContent { chain, address }
+        }
+    }
+}
+
+/// 交易记录中代币的详情页（按交易双方匹配到的本钱包账户确定所在链）
+pub(crate) fn tx_token_route(accounts: &[Account], tx: &TransactionHistoryItem) -> Option<Route> {
+    let account = accounts.iter().find(|a| {
+        a.address.eq_ignore_ascii_case(&tx.from) || a.address.eq_ignore_ascii_case(&tx.to)
+    })?;
+    Some(Route::TokenDetail {
+        chain: account.chain.to_lowercase(),
+        address: tx.token.clone(),
+    })
+}
+
+/// 可点击的代币符号（无法确定详情页时显示为普通文本）
+#[component]
+pub(crate) fn TokenLink(symbol: String, route: Option<Route>) -> Element {
+    let navigator = use_navigator();
+    match route {
+        Some(route) => rsx! {
+            button {
+                class: "underline decoration-dotted underline-offset-2",
+                title: "查看代币详情",
+                onclick: move |e: MouseEvent| {
+                    e.stop_propagation();
+                    navigator.push(route.clone());
+                },
+                {symbol}
+            }
+        },
+        None => rsx! {
+            span { {symbol} }
+        },
+    }
+}
+
+/// 当前用户所有钱包在指定链上的账户：(钱包名称, 地址)，地址去重
+fn chain_accounts(app_state: AppState, chain: ChainType) -> Vec<(String, String)> {
+    let mut accounts: Vec<(String, String)> = Vec::new();
+    for wallet in app_state.wallet.read().wallets.iter() {
+        for account in wallet.accounts.iter() {
+            if ChainType::from_str(&account.chain) == Some(chain)
+                && !accounts
+                    .iter()
+                    .any(|(_, a)| a.eq_ignore_ascii_case(&account.address))
+            {
+                accounts.push((wallet.name.clone(), account.address.clone()));
+            }
+        }
+    }
+    accounts
+}
+
+/// 区块浏览器中的代币页面
+fn explorer_token_url(token: &TokenInfo) -> Option<String> {
+    if token.is_native {
+        return None;
+    }
+    let base = ChainConfigManager::new()
+        .get_config(token.chain)
+        .ok()?
+        .explorer_url
+        .clone()?;
+    let path = match token.chain {
+        ChainType::TON => "address",
+        _ => "token",
+    };
+    Some(format!("{}/{}/{}", base, path, token.address))
+}
+
+fn format_date(timestamp: u64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64((timestamp * 1000) as f64));
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}
+
+/// 代币详情内容：先解析代币，再渲染详情
+#[component]
+fn TokenDetailContent(chain: String, address: String) -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let chain_type = ChainType::from_str(&chain);
+
+    // (代币信息, 是否在已收录代币列表中)
+    let resolved = use_resource(use_reactive!(|(chain_type, address)| async move {
+        let chain = chain_type?;
+        let token_service = TokenService::new(app_state);
+        let list = token_service
+            .get_token_list(chain)
+            .await
+            .unwrap_or_default();
+        let (token, listed) = match find_token(&list, &address) {
+            Some(token) => (token.clone(), true),
+            None => (
+                token_service.get_token_info(chain, &address).await.ok()?,
+                false,
+            ),
+        };
+        // 会话中首次查看时核对链上精度与符号
+        let token = TokenMetadataService::new(app_state).verify(&token).await;
+        Some((token, listed))
+    }));
+
+    let resolved = resolved.read().clone();
+    match resolved {
+        None => rsx! {
+            div {
+                class: "min-h-screen p-4",
+                style: format!("background: {};", Colors::BG_PRIMARY),
+                div {
+                    class: "container mx-auto max-w-4xl px-4 sm:px-6 space-y-4",
+                    SkeletonText { width: "12rem".to_string(), height: 32 }
+                    SkeletonText { height: 160 }
+                    SkeletonTableRow {}
+                }
+            }
+        },
+        Some(None) => rsx! {
+            div {
+                class: "min-h-screen flex items-center justify-center",
+                style: format!("background: {};", Colors::BG_PRIMARY),
+                div {
+                    class: "text-center",
+                    h1 {
+                        class: "text-2xl font-bold mb-4",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "代币未找到"
+                    }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        size: ButtonSize::Medium,
+                        onclick: move |_| {
+                            navigator.push(Route::Dashboard {});
+                        },
+                        "返回Dashboard"
+                    }
+                }
+            }
+        },
+        Some(Some((token, listed))) => rsx! {
+            TokenDetailBody { key: "{token_key(&token)}", token, listed }
+        },
+    }
+}
+
+/// 代币详情主体
+#[component]
+fn TokenDetailBody(token: TokenInfo, listed: bool) -> Element {
+    let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let user_id = app_state.user.read().user_id.clone().unwrap_or_default();
+
+    let symbol = token.symbol.to_uppercase();
+    let key = token_key(&token);
+    let chain = token.chain;
+    let correction = TokenMetadataStore::load().correction(&token).cloned();
+
+    let mut prefs = use_signal(TokenPreferences::load);
+    let mut watchlist = use_signal(|| Option::<Watchlist>::None);
+    let load_user_id = user_id.clone();
+    use_future(move || {
+        let user_id = load_user_id.clone();
+        async move {
+            watchlist.set(Some(watchlist::load(&user_id).await));
+        }
+    });
+
+    // 行情：当前价格（含市值）与 7 天走势
+    let market_symbol = symbol.clone();
+    let market = use_resource(move || {
+        let symbol = market_symbol.clone();
+        async move {
+            let price = PriceService::new(app_state).get_price(&symbol).await.ok();
+            let week: Vec<PriceDataPoint> = PriceService::new(app_state)
+                .get_price_history(&symbol, 168)
+                .await
+                .map(|points| points.into_iter().map(PriceDataPoint::from).collect())
+                .unwrap_or_default();
+            (price, week)
+        }
+    });
+
+    // 持仓：当前用户所有钱包在该链上的账户余额
+    let holdings_address = token.address.clone();
+    let holdings = use_resource(move || {
+        let token_address = holdings_address.clone();
+        async move {
+            let token_service = TokenService::new(app_state);
+            let mut rows = Vec::new();
+            for (wallet_name, address) in chain_accounts(app_state, chain) {
+                let balance = token_service
+                    .get_token_balance(chain, &token_address, &address)
+                    .await
+                    .map(|b| b.balance_formatted)
+                    .ok();
+                rows.push((wallet_name, address, balance));
+            }
+            rows
+        }
+    });
+
+    // 近期转账：各账户交易记录中该代币的转账
+    let transfer_symbol = symbol.clone();
+    let transfers = use_resource(move || {
+        let symbol = transfer_symbol.clone();
+        async move {
+            let tx_service = TransactionService::new(app_state);
+            let mut all: Vec<TransactionHistoryItem> = Vec::new();
+            for (_, address) in chain_accounts(app_state, chain) {
+                if let Ok(txs) = tx_service.get_history(&address, chain.as_str()).await {
+                    all.extend(
+                        txs.into_iter()
+                            .filter(|tx| tx.token.eq_ignore_ascii_case(&symbol)),
+                    );
+                }
+            }
+            all.sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+            all.dedup_by(|a, b| a.hash == b.hash);
+            let mut all = collapse_replacements(all);
+            all.truncate(RECENT_TRANSFER_LIMIT);
+            all
+        }
+    });
+
+    let (price, week) = market.read().clone().unwrap_or_default();
+    let day: Vec<PriceDataPoint> = week
+        .last()
+        .map(|last| {
+            week.iter()
+                .filter(|p| p.timestamp + 24 * 3600 >= last.timestamp)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    let in_watchlist = watchlist.read().as_ref().is_some_and(|w| w.contains(&key));
+    let is_hidden = prefs.read().is_token_hidden(&token);
+    let explorer_url = explorer_token_url(&token);
+    let is_stablecoin = matches!(symbol.as_str(), "USDT" | "USDC");
+
+    let metric = |label: &'static str, value: Element| {
+        rsx! {
+            div {
+                class: "p-3 rounded-lg",
+                style: format!("background: {};", Colors::BG_SECONDARY),
+                div {
+                    class: "text-xs mb-1",
+                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                    {label}
+                }
+                {value}
+            }
+        }
+    };
+
+    let action_button = |label: &'static str, icon: &'static str| {
+        rsx! {
+            div {
+                class: "text-center",
+                div { class: "text-2xl mb-1", {icon} }
+                div {
+                    class: "text-xs font-semibold",
+                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                    {label}
+                }
+            }
+        }
+    };
+    let action_style = format!(
+        "background: {}; border: 1px solid {};",
+        Colors::BG_SECONDARY,
+        Colors::BORDER_PRIMARY
+    );
+
+    let swap_symbol = symbol.clone();
+    let send_token = token.clone();
+    let watch_entry = WatchlistEntry::from(&token);
+    let watch_user_id = user_id.clone();
+    let hide_key = key.clone();
+
+    rsx! {
+        div {
+            class: "min-h-screen p-4",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+
+            div {
+                class: "container mx-auto max-w-4xl px-4 sm:px-6",
+
+                // 页面标题
+                div {
+                    class: "mb-4 sm:mb-6 flex flex-col sm:flex-row items-start sm:items-center gap-3 sm:gap-4",
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: ButtonSize::Small,
+                        onclick: move |_| {
+                            navigator.go_back();
+                        },
+                        "← 返回"
+                    }
+                    div {
+                        class: "flex items-center gap-2 flex-wrap",
+                        h1 {
+                            class: "text-xl sm:text-2xl font-bold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "{token.symbol}"
+                        }
+                        span {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!("{} · {}", token.name, token.chain.label())}
+                        }
+                        if listed {
+                            span {
+                                class: "text-xs px-2 py-1 rounded-full",
+                                style: format!("background: rgba(52, 211, 153, 0.15); color: {};", Colors::PAYMENT_SUCCESS),
+                                "✓ 已验证"
+                            }
+                        } else {
+                            span {
+                                class: "text-xs px-2 py-1 rounded-full",
+                                style: format!("background: rgba(245, 158, 11, 0.15); color: {};", Colors::PAYMENT_WARNING),
+                                "⚠ 未收录，请核实合约地址"
+                            }
+                        }
+                        if is_hidden {
+                            span {
+                                class: "text-xs px-2 py-1 rounded-full",
+                                style: format!("background: {}; color: {};", Colors::BG_SECONDARY, Colors::TEXT_TERTIARY),
+                                "已隐藏"
+                            }
+                        }
+                    }
+                }
+
+                // 行情
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    class: Some("mb-6".to_string()),
+                    children: rsx! {
+                        if market.read().is_none() {
+                            div {
+                                class: "space-y-3",
+                                SkeletonText { width: "10rem".to_string(), height: 40 }
+                                SkeletonText { height: 200 }
+                            }
+                        } else {
+                            div {
+                                class: "grid grid-cols-2 sm:grid-cols-4 gap-3 mb-4",
+                                {metric("价格", rsx! {
+                                    div {
+                                        class: "text-lg font-bold",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {price.as_ref().map(|p| format_price(p.usd)).unwrap_or_else(|| "--".to_string())}
+                                    }
+                                })}
+                                {metric("24小时", rsx! {
+                                    PriceChangeIndicator { change_info: change_info(&day), compact: true }
+                                })}
+                                {metric("7天", rsx! {
+                                    PriceChangeIndicator { change_info: change_info(&week), compact: true }
+                                })}
+                                {metric("市值", rsx! {
+                                    div {
+                                        class: "text-sm font-semibold",
+                                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                        {
+                                            price
+                                                .as_ref()
+                                                .and_then(|p| p.market_cap)
+                                                .map(|cap| format!("${:.0}", cap))
+                                                .unwrap_or_else(|| "--".to_string())
+                                        }
+                                    }
+                                })}
+                            }
+                            PriceChart {
+                                token_symbol: token.symbol.clone(),
+                                data: week.clone(),
+                                time_range_hours: Some(168),
+                            }
+                        }
+                    }
+                }
+
+                // 操作
+                div {
+                    class: "grid grid-cols-3 sm:grid-cols-5 gap-3 mb-6",
+                    button {
+                        class: "p-4 rounded-xl transition-all hover:scale-105 active:scale-95",
+                        style: action_style.clone(),
+                        onclick: move |_| {
+                            // 稳定币默认兑换为原生币，其他代币默认兑换为 USDT
+                            let to_token = if is_stablecoin {
+                                chain.native_token_symbol().to_string()
+                            } else {
+                                "USDT".to_string()
+                            };
+                            history::set_pending_prefill(&SwapRetryRequest {
+                                from_token: swap_symbol.clone(),
+                                to_token,
+                                amount: String::new(),
+                                network: Some(chain.as_str().to_string()),
+                            });
+                            navigator.push(Route::Swap {});
+                        },
+                        {action_button("兑换", "🔄")}
+                    }
+                    button {
+                        class: "p-4 rounded-xl transition-all hover:scale-105 active:scale-95",
+                        style: action_style.clone(),
+                        onclick: move |_| {
+                            token_preferences::set_pending_send_token(&send_token);
+                            navigator.push(Route::Send {});
+                        },
+                        {action_button("发送", "📤")}
+                    }
+                    button {
+                        class: "p-4 rounded-xl transition-all hover:scale-105 active:scale-95",
+                        style: action_style.clone(),
+                        onclick: move |_| {
+                            navigator.push(Route::Receive {});
+                        },
+                        {action_button("接收", "📥")}
+                    }
+                    button {
+                        class: "p-4 rounded-xl transition-all hover:scale-105 active:scale-95 disabled:opacity-40",
+                        style: action_style.clone(),
+                        disabled: watchlist.read().is_none(),
+                        onclick: move |_| {
+                            let mut next = watchlist.peek().clone().unwrap_or_default();
+                            if next.contains(&watch_entry.key()) {
+                                next.remove(&watch_entry.key());
+                            } else if !next.add(watch_entry.clone()) {
+                                AppState::show_toast(
+                                    app_state.toasts,
+                                    format!("最多关注 {} 个代币", watchlist::MAX_WATCHLIST),
+                                    crate::components::molecules::toast::ToastType::Warning,
+                                    Some(3000),
+                                );
+                                return;
+                            }
+                            watchlist.set(Some(next.clone()));
+                            let user_id = watch_user_id.clone();
+                            spawn(async move {
+                                if let Err(e) = watchlist::save(&user_id, &next).await {
+                                    log::warn!("保存关注列表失败: {}", e);
+                                }
+                            });
+                        },
+                        if in_watchlist {
+                            {action_button("取消关注", "★")}
+                        } else {
+                            {action_button("关注", "☆")}
+                        }
+                    }
+                    button {
+                        class: "p-4 rounded-xl transition-all hover:scale-105 active:scale-95 disabled:opacity-40",
+                        style: action_style.clone(),
+                        // 原生代币始终显示，不可隐藏
+                        disabled: token.is_native,
+                        onclick: move |_| {
+                            prefs.write().set_hidden(&hide_key, !is_hidden);
+                            prefs.read().save();
+                        },
+                        if is_hidden {
+                            {action_button("取消隐藏", "👁")}
+                        } else {
+                            {action_button("隐藏", "🙈")}
+                        }
+                    }
+                }
+
+                // 持仓
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    class: Some("mb-6".to_string()),
+                    children: rsx! {
+                        h2 {
+                            class: "text-xl font-bold mb-4",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "我的持仓"
+                        }
+                        match &*holdings.read() {
+                            None => rsx! {
+                                div {
+                                    class: "space-y-3",
+                                    for i in 0..2 {
+                                        SkeletonTableRow { key: "{i}" }
+                                    }
+                                }
+                            },
+                            Some(rows) if rows.is_empty() => rsx! {
+                                p {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    {format!("没有 {} 网络的账户", chain.label())}
+                                }
+                            },
+                            Some(rows) => rsx! {
+                                div {
+                                    class: "space-y-2",
+                                    for (wallet_name, address, balance) in rows.clone() {
+                                        div {
+                                            key: "{address}",
+                                            class: "flex justify-between items-center p-3 rounded-lg",
+                                            style: format!("background: {};", Colors::BG_SECONDARY),
+                                            div {
+                                                div {
+                                                    class: "text-sm font-semibold",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    {wallet_name}
+                                                }
+                                                div {
+                                                    class: "text-xs font-mono",
+                                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                                    {format!("{}...{}", &address[..address.len().min(6)], &address[address.len().saturating_sub(4)..])}
+                                                }
+                                            }
+                                            div {
+                                                class: "text-right text-sm font-semibold",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                match balance {
+                                                    Some(amount) => rsx! {
+                                                        MaskedAmount { value: format!("{:.6}", amount) }
+                                                        " {token.symbol}"
+                                                        if let Some(p) = price.as_ref() {
+                                                            div {
+                                                                class: "text-xs font-normal",
+                                                                style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                                                MaskedAmount { value: format!("${:.2}", amount * p.usd) }
+                                                            }
+                                                        }
+                                                    },
+                                                    None => rsx! {
+                                                        span {
+                                                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                                            "查询失败"
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+
+                // 合约信息
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    class: Some("mb-6".to_string()),
+                    children: rsx! {
+                        h2 {
+                            class: "text-xl font-bold mb-4",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "合约信息"
+                        }
+                        div {
+                            class: "space-y-3 text-sm",
+                            div {
+                                class: "flex justify-between items-center gap-4",
+                                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "合约地址" }
+                                if token.is_native {
+                                    span { style: format!("color: {};", Colors::TEXT_PRIMARY), "原生代币" }
+                                } else {
+                                    div {
+                                        class: "flex items-center gap-2 min-w-0",
+                                        span {
+                                            class: "font-mono break-all text-right",
+                                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                            {token.address.clone()}
+                                        }
+                                        CopyButton { value: token.address.clone(), compact: true }
+                                    }
+                                }
+                            }
+                            div {
+                                class: "flex justify-between items-center",
+                                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "精度" }
+                                span { style: format!("color: {};", Colors::TEXT_PRIMARY), "{token.decimals}" }
+                            }
+                            if let Some(c) = correction {
+                                p {
+                                    class: "text-xs text-right",
+                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                    "代币信息已按链上数据校正（列表记录：精度 {c.listed_decimals} / 符号 {c.listed_symbol}）"
+                                }
+                            }
+                            div {
+                                class: "flex justify-between items-center",
+                                span { style: format!("color: {};", Colors::TEXT_SECONDARY), "网络" }
+                                span { style: format!("color: {};", Colors::TEXT_PRIMARY), {token.chain.label()} }
+                            }
+                            if let Some(url) = explorer_url {
+                                div {
+                                    class: "text-right",
+                                    a {
+                                        href: "{url}",
+                                        target: "_blank",
+                                        rel: "noopener noreferrer",
+                                        class: "text-xs",
+                                        style: format!("color: {};", Colors::TECH_PRIMARY),
+                                        "在区块浏览器中查看 ↗"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 近期转账
+                Card {
+                    variant: crate::components::atoms::card::CardVariant::Base,
+                    padding: Some("24px".to_string()),
+                    class: Some("mb-6".to_string()),
+                    children: rsx! {
+                        h2 {
+                            class: "text-xl font-bold mb-4",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "近期转账"
+                        }
+                        match &*transfers.read() {
+                            None => rsx! {
+                                div {
+                                    class: "space-y-3",
+                                    for i in 0..3 {
+                                        SkeletonTableRow { key: "{i}" }
+                                    }
+                                }
+                            },
+                            Some(list) if list.is_empty() => rsx! {
+                                p {
+                                    class: "text-sm",
+                                    style: format!("color: {};", Colors::TEXT_SECONDARY),
+                                    {format!("暂无 {} 转账记录", token.symbol)}
+                                }
+                            },
+                            Some(list) => rsx! {
+                                div {
+                                    class: "space-y-2",
+                                    for tx in list.clone() {
+                                        div {
+                                            key: "{tx.hash}",
+                                            class: "flex justify-between items-center p-3 rounded-lg",
+                                            style: format!("background: {};", Colors::BG_SECONDARY),
+                                            div {
+                                                span {
+                                                    class: "text-sm font-semibold",
+                                                    style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                    if tx.tx_type.eq_ignore_ascii_case("receive") { "📥 接收" } else { "📤 发送" }
+                                                }
+                                                p {
+                                                    class: "text-xs mt-1",
+                                                    style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                                    {format_date(tx.timestamp)}
+                                                }
+                                            }
+                                            div {
+                                                class: "text-sm font-semibold",
+                                                style: format!("color: {};", Colors::TEXT_PRIMARY),
+                                                MaskedAmount { value: tx.amount.clone() }
+                                                " {tx.token}"
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Verify Email Page - 邮箱验证等待页
+//! 注册后引导用户点击验证邮件，并定时轮询验证状态；可跳过先创建钱包
+
+use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::atoms::card::Card;
+use crate::components::route_guard::AuthGuard;
+use crate::features::auth::hooks::use_auth;
+use crate::router::Route;
+use crate::services::auth::AuthService;
+use crate::shared::design_tokens::Colors;
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+/// 轮询间隔（毫秒）
+const POLL_INTERVAL_MS: u32 = 5_000;
+/// 重新发送验证邮件的冷却时间（秒）
+const RESEND_COOLDOWN_SECS: u32 = 60;
+
+/// Verify Email Page - 邮箱验证等待页
+#[component]
+pub fn VerifyEmail() -> Element {
+    rsx! {
+        AuthGuard {
+            VerifyEmailContent {}
+        }
+    }
+}
+
+/// 邮箱验证内容组件（需要认证）
+#[component]
+fn VerifyEmailContent() -> Element {
+    let navigator = use_navigator();
+    let auth_controller = use_auth();
+    let app_state = use_context::<AppState>();
+
+    let email = app_state.user.read().email.clone().unwrap_or_default();
+    let mut resend_cooldown = use_signal(|| 0u32);
+    let mut resend_loading = use_signal(|| false);
+
+    // 轮询验证状态，验证完成后进入仪表盘
+    use_future(move || async move {
+        loop {
+            if !app_state.user.peek().needs_email_verification() {
+                break;
+            }
+            match auth_controller.refresh_email_verification().await {
+                Ok(true) => {
+                    AppState::show_success(app_state.toasts, "邮箱验证成功".to_string());
+                    navigator.push(Route::Dashboard {});
+                    break;
+                }
+                Ok(false) => {}
+                Err(e) => log::warn!("查询邮箱验证状态失败: {}", e),
+            }
+            TimeoutFuture::new(POLL_INTERVAL_MS).await;
+        }
+    });
+
+    // 重新发送冷却倒计时
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(1000).await;
+            if *resend_cooldown.peek() > 0 {
+                resend_cooldown -= 1;
+            }
+        }
+    });
+
+    let resend = {
+        let email = email.clone();
+        move |_| {
+            let email = email.clone();
+            resend_loading.set(true);
+            spawn(async move {
+                match AuthService::new(app_state)
+                    .resend_verification_email(&email)
+                    .await
+                {
+                    Ok(()) => {
+                        resend_cooldown.set(RESEND_COOLDOWN_SECS);
+                        AppState::show_success(
+                            app_state.toasts,
+                            format!("验证邮件已发送至 {}", email),
+                        );
+                    }
+                    Err(e) => {
+                        AppState::show_error(app_state.toasts, format!("发送验证邮件失败: {}", e))
+                    }
+                }
+                resend_loading.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "min-h-screen flex items-center justify-center p-4",
+            style: format!("background: {};", Colors::BG_PRIMARY),
+
+            Card {
+                variant: crate::components::atoms::card::CardVariant::Base,
+                padding: Some("32px".to_string()),
+                class: Some("max-w-md w-full".to_string()),
+                children: rsx! {
+                    div {
+                        class: "text-center space-y-4",
+                        div { class: "text-5xl", "📧" }
+                        h1 {
+                            class: "text-2xl font-bold",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "验证您的邮箱"
+                        }
+                        p {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!("我们已向 {} 发送了一封验证邮件，请点击邮件中的链接完成验证。", email)}
+                        }
+                        p {
+                            class: "text-xs",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "本页面会自动检测验证结果。验证前可以先创建钱包，法币买卖需验证后使用。"
+                        }
+
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Medium,
+                            class: Some("w-full".to_string()),
+                            disabled: resend_loading() || resend_cooldown() > 0,
+                            loading: resend_loading(),
+                            onclick: resend,
+                            if resend_cooldown() > 0 {
+                                {format!("重新发送（{}s）", resend_cooldown())}
+                            } else {
+                                "重新发送验证邮件"
+                            }
+                        }
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Medium,
+                            class: Some("w-full".to_string()),
+                            onclick: move |_| {
+                                navigator.push(Route::CreateWallet {});
+                            },
+                            "先创建钱包"
+                        }
+                        button {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            onclick: move |_| {
+                                navigator.push(Route::Dashboard {});
+                            },
+                            "稍后验证"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,12 +6,20 @@ use crate::components::atoms::card::Card;
 use crate::components::atoms::input::{Input, InputType};
 use crate::components::molecules::ErrorMessage;
 use crate::components::route_guard::AuthGuard;
+use crate::crypto::bip39::WORD_COUNTS;
 use crate::features::wallet::hooks::use_wallet;
 use crate::router::Route;
 use crate::shared::design_tokens::Colors;
+use crate::shared::metrics;
 use crate::shared::state::AppState;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use zeroize::Zeroize;
+
+/// 指针轨迹熵池上限（字节），足够覆盖数百个采样点
+const ENTROPY_POOL_MAX_BYTES: usize = 8 * 1024;
 
 /// Create Wallet Page - 创建钱包页面
 #[component]
@@ -31,6 +39,10 @@ fn CreateWalletContent() -> Element {
     let confirm_password = use_signal(String::new);
     let error_message = use_signal(|| Option::<String>::None);
     let is_loading = use_signal(|| false);
+    // 助记词长度（12 或 24 词）
+    let mut word_count = use_signal(|| WORD_COUNTS[0]);
+    // 页面停留期间收集的指针轨迹，作为额外熵与系统随机数混合（不触发重渲染）
+    let entropy_pool = use_hook(|| Rc::new(RefCell::new(Vec::<u8>::new())));
 
     let wallet_controller = use_wallet();
     let navigator = use_navigator();
@@ -40,6 +52,19 @@ fn CreateWalletContent() -> Element {
         div {
             class: "min-h-screen flex items-center justify-center",
             style: format!("background: {};", Colors::BG_PRIMARY),
+            onpointermove: {
+                let entropy_pool = entropy_pool.clone();
+                move |e: PointerEvent| {
+                    let mut pool = entropy_pool.borrow_mut();
+                    if pool.len() >= ENTROPY_POOL_MAX_BYTES {
+                        return;
+                    }
+                    let point = e.client_coordinates();
+                    pool.extend_from_slice(&point.x.to_le_bytes());
+                    pool.extend_from_slice(&point.y.to_le_bytes());
+                    pool.extend_from_slice(&metrics::perf_now().to_le_bytes());
+                }
+            },
 
             Card {
                 variant: crate::components::atoms::card::CardVariant::Base,
@@ -90,6 +115,47 @@ fn CreateWalletContent() -> Element {
                         },
                     }
 
+                    // 助记词长度
+                    div {
+                        class: "mt-4",
+                        label {
+                            class: "block text-sm font-medium mb-2",
+                            style: format!("color: {};", Colors::TEXT_PRIMARY),
+                            "助记词长度"
+                        }
+                        div {
+                            class: "flex gap-2",
+                            for count in WORD_COUNTS {
+                                button {
+                                    key: "{count}",
+                                    class: "flex-1 px-3 py-2 rounded-lg text-sm",
+                                    style: if word_count() == count {
+                                        format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                                    } else {
+                                        format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY)
+                                    },
+                                    disabled: is_loading(),
+                                    onclick: move |_| word_count.set(count),
+                                    {format!("{} 个单词", count)}
+                                }
+                            }
+                        }
+                        p {
+                            class: "text-xs mt-2",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            if word_count() == 24 {
+                                "24 词（256 位熵）安全性更高，抄写与恢复时需要更仔细"
+                            } else {
+                                "12 词（128 位熵）为大多数钱包的默认选择"
+                            }
+                        }
+                        p {
+                            class: "text-xs mt-1",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "在本页随意移动鼠标或滑动屏幕，可为助记词增加额外随机性"
+                        }
+                    }
+
                     ErrorMessage {
                         message: error_message.read().clone()
                     }
@@ -105,8 +171,13 @@ fn CreateWalletContent() -> Element {
                                 let mut error_message = error_message;
                                 let mut is_loading = is_loading;
                                 let toasts = app_state.toasts;
+                                let entropy_pool = entropy_pool.clone();
 
                                 move |_| {
+                                    // 生成进行中再次点击直接忽略（按钮禁用前的连击也会到达这里）
+                                    if *is_loading.peek() {
+                                        return;
+                                    }
                                     let name = wallet_name.read().clone();
                                     let pwd = password.read().clone();
                                     let confirm = confirm_password.read().clone();
@@ -133,9 +204,15 @@ fn CreateWalletContent() -> Element {
                                     let nav = navigator;
                                     let mut loading = is_loading;
                                     let mut error_msg = error_message;
+                                    let words = word_count();
+                                    let mut extra_entropy = entropy_pool.borrow().clone();
 
                                     spawn(async move {
-                                        match wallet_ctrl.create_wallet(&name, &pwd).await {
+                                        let result = wallet_ctrl
+                                            .create_wallet(&name, &pwd, words, &extra_entropy)
+                                            .await;
+                                        extra_entropy.zeroize();
+                                        match result {
                                             Ok(phrase) => {
                                                 loading.set(false);
                                                 AppState::show_success(toasts, "钱包创建成功，请备份助记词".to_string());
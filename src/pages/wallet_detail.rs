@@ -3,29 +3,28 @@
 
 use crate::components::atoms::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::atoms::card::Card;
+use crate::components::atoms::copy_button::CopyButton;
+use crate::components::atoms::masked_amount::MaskedAmount;
+use crate::components::atoms::skeleton::{SkeletonTableRow, SkeletonText};
 use crate::components::route_guard::AuthGuard;
+use crate::features::activity::labels::{
+    self, CategoryFilter, TxLabel, TxLabels, PRESET_CATEGORIES,
+};
+use crate::features::wallet::address_poisoning::PoisoningIndex;
+use crate::features::wallet::fee_bump::{self, collapse_replacements};
+use crate::features::wallet::internal_transfer;
 use crate::features::wallet::state::Account;
+use crate::pages::receipt::download_bytes;
+use crate::pages::token_detail::{tx_token_route, TokenLink};
 use crate::router::Route;
-use crate::services::balance::BalanceService;
+use crate::services::balance::{native_symbol, BalanceService};
 use crate::services::transaction::{TransactionHistoryItem, TransactionService};
 use crate::shared::design_tokens::Colors;
 use crate::shared::state::AppState;
+use crate::shared::time;
+use crate::shared::validation::TokenAmount;
 use dioxus::prelude::*;
 
-/// 链ID映射
-///
-/// 注意：此函数当前未使用，但保留用于未来扩展
-#[allow(dead_code)]
-fn get_chain_id(chain: &str) -> u64 {
-    match chain.to_lowercase().as_str() {
-        "ethereum" | "eth" => 1,
-        "bitcoin" | "btc" => 0,
-        "solana" | "sol" => 101,
-        "ton" => 0,
-        _ => 1,
-    }
-}
-
 /// Wallet Detail Page 组件
 #[component]
 pub fn WalletDetail(id: String) -> Element {
@@ -247,7 +246,7 @@ fn WalletDetailContent(wallet_id: String) -> Element {
 #[component]
 fn AccountCard(account: Account, wallet_id: String) -> Element {
     let app_state = use_context::<AppState>();
-    let balance = use_signal(|| "0".to_string());
+    let balance = use_signal(|| Option::<TokenAmount>::None);
     let is_loading = use_signal(|| true);
 
     let account_clone_for_effect = account.clone();
@@ -263,14 +262,10 @@ fn AccountCard(account: Account, wallet_id: String) -> Element {
 
         spawn(async move {
             let balance_service = BalanceService::new(app_state);
-            let chain_id = get_chain_id(&account.chain);
-
-            match balance_service
-                .get_balance(&account.address, chain_id)
-                .await
-            {
-                Ok(resp) => {
-                    balance.set(resp.balance);
+
+            match balance_service.get_native_balance(&account).await {
+                Ok(raw) => {
+                    balance.set(Some(raw));
                     is_loading.set(false);
                 }
                 Err(_) => {
@@ -291,35 +286,30 @@ fn AccountCard(account: Account, wallet_id: String) -> Element {
                         style: format!("color: {};", Colors::TEXT_PRIMARY),
                         {account_chain_label}
                     }
-                    p {
-                        class: "text-xs mt-1 font-mono",
-                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        {account_address_clone.clone()}
+                    div {
+                        class: "flex items-center gap-2 mt-1",
+                        p {
+                            class: "text-xs font-mono break-all",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            {account_address_clone.clone()}
+                        }
+                        CopyButton { value: account_address_clone.clone(), compact: true }
                     }
                 }
                 div {
                     class: "text-right",
                     if is_loading() {
-                        span {
-                            class: "text-sm",
-                            style: format!("color: {};", Colors::TEXT_TERTIARY),
-                            "加载中..."
-                        }
+                        SkeletonText { width: "6rem".to_string(), height: 16 }
                     } else {
                         span {
                             class: "font-semibold",
                             style: format!("color: {};", Colors::TEXT_PRIMARY),
                             {
-                                let balance_val: f64 = balance.read().parse().unwrap_or(0.0);
-                                let chain_lower = account_chain_clone.to_lowercase();
-                                let chain_symbol = match chain_lower.as_str() {
-                                    "ethereum" | "eth" => ("ETH", 1e18),
-                                    "bitcoin" | "btc" => ("BTC", 1e8),
-                                    "solana" | "sol" => ("SOL", 1e9),
-                                    "ton" => ("TON", 1e9),
-                                    _ => ("ETH", 1e18),
-                                };
-                                format!("{:.6} {}", balance_val / chain_symbol.1, chain_symbol.0)
+                                let symbol = native_symbol(&account_chain_clone);
+                                let formatted = balance()
+                                    .map(|b| b.format(6))
+                                    .unwrap_or_else(|| "0".to_string());
+                                format!("{} {}", formatted, symbol)
                             }
                         }
                     }
@@ -335,12 +325,64 @@ fn TransactionHistory(wallet_id: String, accounts: Vec<Account>) -> Element {
     let app_state = use_context::<AppState>();
     let transactions = use_signal(Vec::<TransactionHistoryItem>::new);
     let is_loading = use_signal(|| true);
+    let row_accounts = accounts.clone();
+    // 加速交易广播后递增，重新加载历史
+    let reload = use_signal(|| 0u32);
+
+    // 交易分类与备注（本设备加密存储，开启同步时与后端合并）
+    let user_id = app_state.user.read().user_id.clone().unwrap_or_default();
+    let mut labels = use_signal(TxLabels::default);
+    let load_user_id = user_id.clone();
+    use_future(move || {
+        let user_id = load_user_id.clone();
+        async move {
+            let local = labels::load(&user_id).await;
+            labels.set(local.clone());
+            let synced = labels::sync(app_state, &user_id, local).await;
+            if *labels.peek() != synced {
+                labels.set(synced);
+            }
+        }
+    });
+    let mut category_filter = use_signal(CategoryFilter::default);
+    let mut select_mode = use_signal(|| false);
+    let mut selected = use_signal(Vec::<String>::new);
+    let mut bulk_category = use_signal(String::new);
+    // 地址投毒检测：疑似投毒的粉尘转入默认折叠
+    let poisoning = use_signal(PoisoningIndex::load);
+    let mut show_suspected = use_signal(|| false);
+
+    // 修改标签后保存（并按设置同步）
+    let mut update_labels = move |change: &dyn Fn(&mut TxLabels, u64)| {
+        let mut next = labels.peek().clone();
+        change(&mut next, time::now_unix());
+        labels.set(next.clone());
+        let user_id = app_state.user.peek().user_id.clone().unwrap_or_default();
+        spawn(async move {
+            labels::save_and_push(app_state, &user_id, &next).await;
+        });
+    };
+
+    let (visible, suspected): (Vec<TransactionHistoryItem>, Vec<TransactionHistoryItem>) = {
+        let filter = category_filter.read();
+        let labels = labels.read();
+        let poisoning = poisoning.read();
+        transactions
+            .read()
+            .iter()
+            .filter(|tx| filter.matches(&labels, &tx.hash))
+            .cloned()
+            .partition(|tx| !poisoning.is_suspected(tx))
+    };
+    let export_rows = visible.clone();
 
     use_effect(move || {
+        let _ = reload();
         let app_state = app_state;
         let accounts = accounts.clone();
         let mut transactions = transactions;
         let mut is_loading = is_loading;
+        let mut poisoning = poisoning;
 
         spawn(async move {
             is_loading.set(true);
@@ -367,30 +409,166 @@ fn TransactionHistory(wallet_id: String, accounts: Vec<Account>) -> Element {
             }
 
             // 按时间戳排序（最新的在前）
-            all_txs.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+            all_txs.sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+
+            // 同步地址投毒索引
+            let owned = internal_transfer::owned_addresses(&app_state.wallet.peek().wallets);
+            poisoning.set(PoisoningIndex::sync(&all_txs, &owned));
 
-            transactions.set(all_txs);
+            transactions.set(collapse_replacements(all_txs));
             is_loading.set(false);
         });
     });
 
+    let render_row = move |tx: &TransactionHistoryItem| {
+        rsx! {
+            TransactionRow {
+                key: "{tx.hash}",
+                label: labels.read().get(&tx.hash).cloned(),
+                selected: select_mode().then(|| selected.read().contains(&tx.hash)),
+                on_select: move |hash: String| {
+                    let mut next = selected.peek().clone();
+                    if let Some(index) = next.iter().position(|h| *h == hash) {
+                        next.remove(index);
+                    } else {
+                        next.push(hash);
+                    }
+                    selected.set(next);
+                },
+                on_label: move |(hash, category, note): (String, Option<String>, String)| {
+                    update_labels(&|labels: &mut TxLabels, now| {
+                        labels.set_category(std::slice::from_ref(&hash), category.as_deref(), now);
+                        labels.set_note(&hash, &note, now);
+                    });
+                },
+                transaction: tx.clone(),
+                token_route: tx_token_route(&row_accounts, tx),
+                account: row_accounts
+                    .iter()
+                    .find(|a| a.address.eq_ignore_ascii_case(&tx.from) || a.address.eq_ignore_ascii_case(&tx.to))
+                    .cloned(),
+                reload,
+            }
+        }
+    };
+
     rsx! {
         Card {
             variant: crate::components::atoms::card::CardVariant::Base,
             padding: Some("24px".to_string()),
             class: Some("mb-6".to_string()),
             children: rsx! {
-                h2 {
-                    class: "text-xl font-bold mb-4",
-                    style: format!("color: {};", Colors::TEXT_PRIMARY),
-                    "交易历史"
+                div {
+                    class: "flex justify-between items-center mb-4 gap-2",
+                    h2 {
+                        class: "text-xl font-bold",
+                        style: format!("color: {};", Colors::TEXT_PRIMARY),
+                        "交易历史"
+                    }
+                    if !transactions.read().is_empty() {
+                        div {
+                            class: "flex gap-2",
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    select_mode.set(!select_mode());
+                                    selected.set(Vec::new());
+                                },
+                                if select_mode() { "取消选择" } else { "批量分类" }
+                            }
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    // 带 BOM，便于表格软件识别 UTF-8 中文
+                                    let csv = format!("\u{feff}{}", labels::to_csv(&export_rows, &labels.peek()));
+                                    let _ = download_bytes(csv.as_bytes(), "交易历史.csv");
+                                },
+                                "导出 CSV"
+                            }
+                        }
+                    }
+                }
+
+                // 分类筛选
+                if !transactions.read().is_empty() {
+                    div {
+                        class: "flex flex-wrap gap-2 mb-4",
+                        {
+                            let mut options = vec![
+                                (CategoryFilter::All, "全部".to_string()),
+                                (CategoryFilter::Unlabeled, "未分类".to_string()),
+                            ];
+                            options.extend(
+                                labels
+                                    .read()
+                                    .categories()
+                                    .into_iter()
+                                    .map(|c| (CategoryFilter::Category(c.clone()), labels::category_label(&c))),
+                            );
+                            rsx! {
+                                for (filter, name) in options {
+                                    button {
+                                        key: "{name}",
+                                        class: "px-3 py-1 rounded-full text-xs",
+                                        style: if *category_filter.read() == filter {
+                                            format!("background: {}; color: white;", Colors::TECH_PRIMARY)
+                                        } else {
+                                            format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY, Colors::TEXT_SECONDARY)
+                                        },
+                                        onclick: move |_| category_filter.set(filter.clone()),
+                                        {name}
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
 
+                // 批量分类
+                if select_mode() {
+                    div {
+                        class: "flex flex-wrap items-center gap-2 mb-4 p-3 rounded-lg",
+                        style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
+                        span {
+                            class: "text-sm",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!("已选 {} 笔", selected.read().len())}
+                        }
+                        input {
+                            class: "px-2 py-1 rounded text-sm",
+                            style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                            list: "tx-category-options",
+                            placeholder: "分类（如 工资、房租）",
+                            value: "{bulk_category}",
+                            oninput: move |e| bulk_category.set(e.value()),
+                        }
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: ButtonSize::Small,
+                            disabled: selected.read().is_empty(),
+                            onclick: move |_| {
+                                let hashes = selected.peek().clone();
+                                let category = labels::category_from_input(&bulk_category.peek());
+                                update_labels(&|labels: &mut TxLabels, now| {
+                                    labels.set_category(&hashes, category.as_deref(), now);
+                                });
+                                selected.set(Vec::new());
+                                select_mode.set(false);
+                            },
+                            "应用到所选"
+                        }
+                    }
+                }
+                CategoryOptions { labels: labels.read().clone() }
+
                 if is_loading() {
                     div {
-                        class: "text-center py-8",
-                        style: format!("color: {};", Colors::TEXT_TERTIARY),
-                        "正在加载交易历史..."
+                        class: "space-y-3",
+                        for i in 0..3 {
+                            SkeletonTableRow { key: "{i}" }
+                        }
                     }
                 } else if false {
                     div {
@@ -404,12 +582,44 @@ fn TransactionHistory(wallet_id: String, accounts: Vec<Account>) -> Element {
                         style: format!("color: {};", Colors::TEXT_TERTIARY),
                         "暂无交易记录"
                     }
+                } else if visible.is_empty() && suspected.is_empty() {
+                    div {
+                        class: "text-center py-8",
+                        style: format!("color: {};", Colors::TEXT_TERTIARY),
+                        "该分类下没有交易"
+                    }
                 } else {
                     div {
                         class: "space-y-3",
-                        for tx in transactions.read().iter() {
-                            TransactionRow {
-                                transaction: tx.clone(),
+                        for tx in visible.iter() {
+                            {render_row(tx)}
+                        }
+                        if visible.is_empty() {
+                            div {
+                                class: "text-center py-4 text-sm",
+                                style: format!("color: {};", Colors::TEXT_TERTIARY),
+                                "该分类下没有交易"
+                            }
+                        }
+
+                        // 疑似地址投毒的粉尘转入（默认折叠）
+                        if !suspected.is_empty() {
+                            button {
+                                class: "w-full flex justify-between items-center px-3 py-2 rounded-lg text-sm",
+                                style: format!("background: rgba(234, 179, 8, 0.08); border: 1px solid rgba(234, 179, 8, 0.3); color: {};", Colors::TEXT_SECONDARY),
+                                onclick: move |_| show_suspected.set(!show_suspected()),
+                                span { {format!("🛡️ 疑似垃圾交易（{}）", suspected.len())} }
+                                span { if show_suspected() { "收起" } else { "展开" } }
+                            }
+                            if show_suspected() {
+                                div {
+                                    class: "text-xs px-1",
+                                    style: "color: rgba(234, 179, 8, 1);",
+                                    "以下转账来自与您常用收款方首尾相同的地址，可能是地址投毒，请勿从这里复制地址"
+                                }
+                                for tx in suspected.iter() {
+                                    {render_row(tx)}
+                                }
                             }
                         }
                     }
@@ -419,9 +629,102 @@ fn TransactionHistory(wallet_id: String, accounts: Vec<Account>) -> Element {
     }
 }
 
+/// 分类输入框的候选项（预设分类 + 已使用的自定义分类）
+#[component]
+fn CategoryOptions(labels: TxLabels) -> Element {
+    let mut options: Vec<String> = PRESET_CATEGORIES
+        .iter()
+        .map(|(_, label)| label.to_string())
+        .collect();
+    for category in labels.categories() {
+        let name = labels::category_label(&category);
+        if !options.contains(&name) {
+            options.push(name);
+        }
+    }
+    rsx! {
+        datalist {
+            id: "tx-category-options",
+            for option in options {
+                option { key: "{option}", value: "{option}" }
+            }
+        }
+    }
+}
+
 /// 交易行组件
+///
+/// 未确认的 Bitcoin 交易提供加速操作：转出交易使用 RBF，转入交易使用 CPFP
 #[component]
-fn TransactionRow(transaction: TransactionHistoryItem) -> Element {
+fn TransactionRow(
+    transaction: TransactionHistoryItem,
+    token_route: Option<Route>,
+    account: Option<Account>,
+    reload: Signal<u32>,
+    /// 分类与备注
+    label: Option<TxLabel>,
+    /// 批量选择模式下是否已选中（None 表示不在选择模式）
+    selected: Option<bool>,
+    on_select: EventHandler<String>,
+    /// 保存标签：(交易哈希, 分类, 备注)
+    on_label: EventHandler<(String, Option<String>, String)>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut editing_label = use_signal(|| false);
+    let mut category_input = use_signal(String::new);
+    let mut note_input = use_signal(String::new);
+    let current_label = label.clone().unwrap_or_default();
+    let select_hash = transaction.hash.clone();
+    let label_hash = transaction.hash.clone();
+    let mut bumping = use_signal(|| false);
+    let can_rbf = account.is_some() && fee_bump::can_bump_fee(&transaction);
+    let can_cpfp = account.is_some() && fee_bump::can_cpfp(&transaction);
+    let navigator = use_navigator();
+    let receipt_route = account
+        .as_ref()
+        .filter(|_| transaction.status.eq_ignore_ascii_case("confirmed"))
+        .map(|a| Route::TxReceipt {
+            chain: a.chain.to_lowercase(),
+            hash: transaction.hash.clone(),
+        });
+
+    let bump_tx = transaction.clone();
+    let on_bump = move |_| {
+        let Some(account) = account.clone() else {
+            return;
+        };
+        let tx = bump_tx.clone();
+        let mut reload = reload;
+        bumping.set(true);
+        spawn(async move {
+            let result = if fee_bump::can_bump_fee(&tx) {
+                fee_bump::bump_fee(app_state, &account, &tx).await
+            } else {
+                fee_bump::child_pays_for_parent(app_state, &account, &tx).await
+            };
+            match result {
+                Ok(hash) => {
+                    AppState::show_toast(
+                        app_state.toasts,
+                        format!("加速交易已广播：{}", hash),
+                        crate::components::molecules::toast::ToastType::Success,
+                        Some(4000),
+                    );
+                    reload += 1;
+                }
+                Err(e) => {
+                    AppState::show_toast(
+                        app_state.toasts,
+                        format!("加速失败：{}", e),
+                        crate::components::molecules::toast::ToastType::Error,
+                        Some(5000),
+                    );
+                }
+            }
+            bumping.set(false);
+        });
+    };
+
     let status_color = match transaction.status.to_lowercase().as_str() {
         "confirmed" => Colors::PAYMENT_SUCCESS,
         "pending" => Colors::PAYMENT_WARNING,
@@ -441,6 +744,14 @@ fn TransactionRow(transaction: TransactionHistoryItem) -> Element {
             style: format!("background: {}; border: 1px solid {};", Colors::BG_SECONDARY, Colors::BORDER_PRIMARY),
             div {
                 class: "flex justify-between items-start",
+                if let Some(checked) = selected {
+                    input {
+                        class: "mt-1 mr-3",
+                        r#type: "checkbox",
+                        checked,
+                        onchange: move |_| on_select.call(select_hash.clone()),
+                    }
+                }
                 div {
                     class: "flex-1",
                     div {
@@ -456,12 +767,26 @@ fn TransactionRow(transaction: TransactionHistoryItem) -> Element {
                             style: format!("background: {}; color: white;", status_color),
                             {transaction.status.clone()}
                         }
+                        if let Some(category) = current_label.category.clone() {
+                            span {
+                                class: "text-xs px-2 py-1 rounded-full",
+                                style: format!("border: 1px solid {}; color: {};", Colors::TECH_PRIMARY, Colors::TECH_PRIMARY),
+                                {format!("🏷️ {}", labels::category_label(&category))}
+                            }
+                        }
                     }
                     div {
                         class: "text-xs font-mono",
                         style: format!("color: {};", Colors::TEXT_TERTIARY),
                         "Hash: {transaction.hash.clone()}"
                     }
+                    if !current_label.note.is_empty() {
+                        div {
+                            class: "text-xs mt-1",
+                            style: format!("color: {};", Colors::TEXT_SECONDARY),
+                            {format!("📝 {}", current_label.note)}
+                        }
+                    }
                     div {
                         class: "text-xs mt-1",
                         style: format!("color: {};", Colors::TEXT_SECONDARY),
@@ -472,13 +797,109 @@ fn TransactionRow(transaction: TransactionHistoryItem) -> Element {
                         style: format!("color: {};", Colors::TEXT_SECONDARY),
                         "To: {transaction.to.clone()}"
                     }
+                    if let Some(replaced) = transaction.replaces.clone() {
+                        div {
+                            class: "text-xs mt-1",
+                            style: format!("color: {};", Colors::TEXT_TERTIARY),
+                            "替换交易: {replaced}"
+                        }
+                    }
+                    if editing_label() {
+                        div {
+                            class: "mt-2 flex flex-wrap items-center gap-2",
+                            input {
+                                class: "px-2 py-1 rounded text-xs",
+                                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                list: "tx-category-options",
+                                placeholder: "分类",
+                                value: "{category_input}",
+                                oninput: move |e| category_input.set(e.value()),
+                            }
+                            input {
+                                class: "px-2 py-1 rounded text-xs flex-1",
+                                style: format!("background: {}; border: 1px solid {}; color: {};", Colors::BG_PRIMARY, Colors::BORDER_PRIMARY, Colors::TEXT_PRIMARY),
+                                placeholder: "备注",
+                                value: "{note_input}",
+                                oninput: move |e| note_input.set(e.value()),
+                            }
+                            Button {
+                                variant: ButtonVariant::Primary,
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    on_label.call((
+                                        label_hash.clone(),
+                                        labels::category_from_input(&category_input.peek()),
+                                        note_input.peek().clone(),
+                                    ));
+                                    editing_label.set(false);
+                                },
+                                "保存"
+                            }
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                onclick: move |_| editing_label.set(false),
+                                "取消"
+                            }
+                        }
+                    } else {
+                        div {
+                            class: "mt-2",
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    let label = label.clone().unwrap_or_default();
+                                    category_input.set(
+                                        label.category.as_deref().map(labels::category_label).unwrap_or_default(),
+                                    );
+                                    note_input.set(label.note);
+                                    editing_label.set(true);
+                                },
+                                "🏷️ 分类 / 备注"
+                            }
+                        }
+                    }
+                    if let Some(route) = receipt_route {
+                        div {
+                            class: "mt-2",
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    navigator.push(route.clone());
+                                },
+                                "🧾 收据"
+                            }
+                        }
+                    }
+                    if can_rbf || can_cpfp {
+                        div {
+                            class: "mt-2",
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                size: ButtonSize::Small,
+                                disabled: bumping(),
+                                onclick: on_bump,
+                                if bumping() {
+                                    "加速中..."
+                                } else if can_rbf {
+                                    "⚡ 加速（RBF）"
+                                } else {
+                                    "⚡ CPFP 加速"
+                                }
+                            }
+                        }
+                    }
                 }
                 div {
                     class: "text-right",
                     div {
                         class: "font-semibold",
                         style: format!("color: {};", Colors::TEXT_PRIMARY),
-                        {transaction.amount.clone()} " {transaction.token.clone()}"
+                        MaskedAmount { value: transaction.amount.clone() }
+                        " "
+                        TokenLink { symbol: transaction.token.clone(), route: token_route }
                     }
 
                     // ✅ 费用明细展示（显示真实的后端数据）
@@ -2,17 +2,29 @@
 //! 生产级路由实现，使用 Dioxus Router
 
 use dioxus::prelude::*;
+use dioxus::router::RouterConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 // 导入所有页面组件
 // Dioxus Router的Routable宏会自动匹配Route枚举变体名称到同名的组件函数
 // 组件必须在当前作用域中可见，所以需要显式导入
+use crate::components::help_panel::HelpWidget;
+use crate::components::lock_screen::LockScreen;
+use crate::components::molecules::{
+    DemoModeBanner, EmailVerificationBanner, PageTour, ToastContainer,
+};
 use crate::components::navbar::Navbar;
 use crate::components::route_guard::AuthGuard;
+use crate::components::shortcut_layer::ShortcutLayer;
 use crate::pages::{
-    Bridge, Buy, CreateWallet, Dashboard, ImportWallet, Landing, Login, MnemonicBackup,
-    MnemonicVerify, NotFound, Orders, Receive, Register, Sell, Send, Swap, WalletCreated,
-    WalletDetail,
+    Bridge, Buy, CreateWallet, Dapps, Dashboard, ForgotPassword, ImportWallet, Landing, Login,
+    MnemonicBackup, MnemonicVerify, NotFound, Orders, Receive, Register, Sell, Send, SimulateTx,
+    Status, Swap, TokenDetail, TxReceipt, VerifyEmail, WalletCreated, WalletDetail,
 };
+use crate::shared::metrics;
+use crate::shared::navigation_guard;
+use crate::shared::state::AppState;
 
 /// 路由定义
 /// 使用嵌套路由，所有路由都在AppLayout内部
@@ -29,6 +41,12 @@ pub enum Route {
     #[route("/register")]
     Register {},
     
+    #[route("/forgot-password")]
+    ForgotPassword {},
+    
+    #[route("/verify-email")]
+    VerifyEmail {},
+    
     #[route("/dashboard")]
     Dashboard {},
     
@@ -50,6 +68,9 @@ pub enum Route {
     #[route("/wallet/:id")]
     WalletDetail { id: String },
     
+    #[route("/token/:chain/:address")]
+    TokenDetail { chain: String, address: String },
+    
     #[route("/send")]
     Send {},
     
@@ -71,32 +92,100 @@ pub enum Route {
     #[route("/bridge")]
     Bridge {},
     
+    #[route("/dapps")]
+    Dapps {},
+    
+    #[route("/advanced/simulate")]
+    SimulateTx {},
+    
+    #[route("/status")]
+    Status {},
+    
+    #[route("/receipt/:chain/:hash")]
+    TxReceipt { chain: String, hash: String },
+    
     #[route("/..")]
     NotFound {},
 }
 
 /// 应用布局组件 - 包含Navbar和路由内容
-/// 这个组件作为所有路由的父组件，提供Navbar
+/// 这个组件作为所有路由的父组件，提供Navbar与全局Toast容器
 /// Navbar在Router内部，可以安全使用use_navigator()
 #[component]
 pub fn AppLayout() -> Element {
+    let app_state = use_context::<AppState>();
+    // 路由切换耗时：从检测到新路由开始，到下一帧绘制完成
+    let route = use_route::<Route>().to_string();
+    let last_route = use_hook(|| Rc::new(RefCell::new(Option::<String>::None)));
+    let previous = last_route.borrow_mut().replace(route.clone());
+    if previous.is_some_and(|prev| prev != route) {
+        let started = metrics::perf_now();
+        spawn(async move {
+            metrics::next_frame().await;
+            metrics::record_route_transition(metrics::perf_now() - started);
+        });
+    }
+
     rsx! {
         div {
             // 统一顶部导航栏（所有页面共享）
             Navbar {}
 
+            // 演示模式水印（开启时所有业务接口返回演示数据）
+            DemoModeBanner {}
+
+            // 邮箱未验证提示（可关闭）
+            EmailVerificationBanner {}
+
             // 路由内容
             Outlet::<Route> {}
+
+            // 当前页面的新手引导（首次访问时自动播放）
+            PageTour { key: "{route}", route: route.clone() }
+
+            // 全局提示（位于路由内，操作按钮可使用导航）
+            ToastContainer { messages: app_state.toasts }
+
+            // 帮助与客服（常见问题、提交工单、我的工单）
+            HelpWidget { route: route.clone() }
+
+            // 自动锁定后的锁屏（覆盖在当前页面之上，解锁后原样恢复）
+            LockScreen {}
+
+            // 全局键盘快捷键（按 ? 查看速查表）
+            ShortcutLayer {}
         }
     }
 }
 
+thread_local! {
+    /// 最近一次允许进入的路由（离开确认被取消时回到这里）
+    static LAST_ALLOWED: RefCell<Option<Route>> = const { RefCell::new(None) };
+}
+
+/// 路由配置：关键操作进行中切换路由（含浏览器后退）时先确认，取消则回到原页面
+fn router_config() -> RouterConfig<Route> {
+    RouterConfig::default().on_update(|ctx| {
+        let current = ctx.current();
+        let previous = LAST_ALLOWED.with(|last| last.borrow().clone());
+        match previous {
+            Some(previous) if previous != current && !navigation_guard::confirm_leave() => {
+                Some(NavigationTarget::Internal(previous))
+            }
+            _ => {
+                LAST_ALLOWED.with(|last| *last.borrow_mut() = Some(current));
+                None
+            }
+        }
+    })
+}
+
 /// Router 组件
 /// 在Dioxus Router 0.7中，Router会自动渲染匹配的路由组件
 #[component]
 pub fn AppRouter() -> Element {
     rsx! {
-        Router::<Route> {}
+        Router::<Route> { config: router_config }
     }
 }
 
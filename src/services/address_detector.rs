@@ -1,6 +1,7 @@
 //! Address Detector - 地址链类型检测服务
 //! 自动检测地址所属的区块链网络
 
+use crate::services::address_validation::validate_address;
 use anyhow::{anyhow, Result};
 use bs58;
 
@@ -127,28 +128,11 @@ impl AddressDetector {
         Err(anyhow!("无法识别的地址格式: {}", address))
     }
 
-    /// 验证地址格式是否正确
+    /// 验证地址格式是否正确（校验和、网络等严格校验见 `address_validation`）
     pub fn validate_address(address: &str, chain: ChainType) -> Result<()> {
-        let detected = Self::detect_chain(address)?;
-
-        // EVM链之间可以互相兼容（地址格式相同）
-        let evm_chains = [ChainType::Ethereum, ChainType::BSC, ChainType::Polygon];
-        let is_evm_chain = evm_chains.contains(&chain);
-        let is_evm_detected = evm_chains.contains(&detected);
-
-        if is_evm_chain && is_evm_detected {
-            return Ok(()); // EVM链地址格式兼容
-        }
-
-        if detected == chain {
-            Ok(())
-        } else {
-            Err(anyhow!(
-                "地址格式不匹配: 检测到 {}, 但期望 {}",
-                detected.label(),
-                chain.label()
-            ))
-        }
+        validate_address(chain, address)
+            .map(|_| ())
+            .map_err(|e| anyhow!(e.to_string()))
     }
 }
 
@@ -0,0 +1,561 @@
+//! Address Validation - 按链校验并规范化地址
+//! 所有地址输入（发送、地址校验工具等）统一经过 `validate_address`：
+//! - EVM：EIP-55 校验和（大小写混合时必须匹配），统一输出校验和格式
+//! - Bitcoin：Base58Check / Bech32(m) 解码并识别网络，主网模式拒绝测试网地址
+//! - Solana：Base58 解码后必须是 32 字节公钥
+//! - TON：原始格式 `workchain:hex` 或带 CRC16 的 48 字符友好格式
+//!
+//! 地址不属于所选链时，若能识别出其他链，返回“看起来是 X 链地址”的明确错误。
+
+use crate::services::address_detector::ChainType;
+use base64::Engine;
+use bech32::{FromBase32, Variant};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use thiserror::Error;
+
+/// 地址所属网络
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+/// 校验通过的地址
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedAddress {
+    pub chain: ChainType,
+    /// 规范形式：EVM 为 EIP-55 校验和格式，Bech32 为小写，TON 友好格式为 base64url
+    pub address: String,
+}
+
+/// 地址校验错误
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    #[error("请输入地址")]
+    Empty,
+    #[error("无法识别的地址格式，请检查后重试")]
+    Unrecognized,
+    #[error("{} 地址格式无效：{reason}", .chain.label())]
+    InvalidFormat { chain: ChainType, reason: String },
+    #[error("地址校验和不正确，可能存在输入错误，请从原处重新复制")]
+    ChecksumMismatch,
+    #[error("这是 {} 测试网地址，当前为主网，转账将无法到账", .chain.label())]
+    TestnetAddress { chain: ChainType },
+    #[error("这看起来是 {} 地址，而不是 {} 地址", .found.label(), .expected.label())]
+    WrongChain {
+        expected: ChainType,
+        found: ChainType,
+    },
+}
+
+fn invalid(chain: ChainType, reason: &str) -> AddressError {
+    AddressError::InvalidFormat {
+        chain,
+        reason: reason.to_string(),
+    }
+}
+
+fn is_evm(chain: ChainType) -> bool {
+    matches!(
+        chain,
+        ChainType::Ethereum | ChainType::BSC | ChainType::Polygon
+    )
+}
+
+/// 校验主网地址
+pub fn validate_address(chain: ChainType, input: &str) -> Result<NormalizedAddress, AddressError> {
+    validate_address_on(chain, input, Network::Mainnet)
+}
+
+/// 按指定网络校验地址
+pub fn validate_address_on(
+    chain: ChainType,
+    input: &str,
+    network: Network,
+) -> Result<NormalizedAddress, AddressError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(AddressError::Empty);
+    }
+
+    let err = match parse(chain, input) {
+        Ok((address, address_network)) => {
+            if network == Network::Mainnet && address_network == Network::Testnet {
+                return Err(AddressError::TestnetAddress { chain });
+            }
+            return Ok(NormalizedAddress { chain, address });
+        }
+        Err(err) => err,
+    };
+
+    // 格式不符时，检查是否为其他链的地址，给出更明确的提示
+    if matches!(err, AddressError::InvalidFormat { .. }) {
+        if let Some(found) = detect_strict(input)
+            .filter(|found| *found != chain && !(is_evm(*found) && is_evm(chain)))
+        {
+            return Err(AddressError::WrongChain {
+                expected: chain,
+                found,
+            });
+        }
+    }
+    Err(err)
+}
+
+/// 识别并校验地址（未选择链时使用）
+pub fn detect_and_validate(input: &str) -> Result<NormalizedAddress, AddressError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(AddressError::Empty);
+    }
+    match detect_strict(input) {
+        Some(chain) => validate_address(chain, input),
+        // 大小写混合但校验和错误的 EVM 地址，按 EVM 报告具体错误
+        None if input.starts_with("0x") => validate_address(ChainType::Ethereum, input),
+        None => Err(AddressError::Unrecognized),
+    }
+}
+
+/// 能完整解码为哪条链的地址（不区分网络）
+fn detect_strict(input: &str) -> Option<ChainType> {
+    [
+        ChainType::Ethereum,
+        ChainType::Bitcoin,
+        ChainType::TON,
+        ChainType::Solana,
+    ]
+    .into_iter()
+    .find(|chain| parse(*chain, input).is_ok())
+}
+
+fn parse(chain: ChainType, input: &str) -> Result<(String, Network), AddressError> {
+    match chain {
+        ChainType::Ethereum | ChainType::BSC | ChainType::Polygon => {
+            parse_evm(chain, input).map(|a| (a, Network::Mainnet))
+        }
+        ChainType::Bitcoin => parse_bitcoin(input),
+        ChainType::Solana => parse_solana(input).map(|a| (a, Network::Mainnet)),
+        ChainType::TON => parse_ton(input),
+    }
+}
+
+/// EIP-55 校验和格式
+pub fn to_checksum_address(hex_lower: &str) -> String {
+    let hash = Keccak256::digest(hex_lower.as_bytes());
+    let checksummed: String = hex_lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if c.is_ascii_alphabetic() && nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+fn parse_evm(chain: ChainType, input: &str) -> Result<String, AddressError> {
+    let hex_part = input
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid(chain, "必须以 0x 开头"))?;
+    if hex_part.len() != 40 {
+        return Err(invalid(chain, "0x 后应为 40 位十六进制字符"));
+    }
+    if !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid(chain, "包含非十六进制字符"));
+    }
+
+    let lower = hex_part.to_ascii_lowercase();
+    let checksummed = to_checksum_address(&lower);
+    // 全小写/全大写不含校验信息，直接规范化；大小写混合必须与校验和一致
+    let has_checksum = hex_part != lower && hex_part != hex_part.to_ascii_uppercase();
+    if has_checksum && checksummed[2..] != *hex_part {
+        return Err(AddressError::ChecksumMismatch);
+    }
+    Ok(checksummed)
+}
+
+fn parse_bitcoin(input: &str) -> Result<(String, Network), AddressError> {
+    let chain = ChainType::Bitcoin;
+    let lower = input.to_ascii_lowercase();
+    if lower.starts_with("bc1") || lower.starts_with("tb1") || lower.starts_with("bcrt1") {
+        return parse_segwit(input);
+    }
+
+    // Base58Check：版本号(1) + hash160(20) + 校验和(4)
+    let bytes = bs58::decode(input)
+        .into_vec()
+        .map_err(|_| invalid(chain, "包含非 Base58 字符"))?;
+    if bytes.len() != 25 {
+        return Err(invalid(chain, "地址长度不正确"));
+    }
+    let (payload, checksum) = bytes.split_at(21);
+    if Sha256::digest(Sha256::digest(payload))[..4] != *checksum {
+        return Err(AddressError::ChecksumMismatch);
+    }
+    let network = match payload[0] {
+        0x00 | 0x05 => Network::Mainnet,
+        0x6f | 0xc4 => Network::Testnet,
+        _ => return Err(invalid(chain, "未知的地址版本")),
+    };
+    Ok((input.to_string(), network))
+}
+
+fn parse_segwit(input: &str) -> Result<(String, Network), AddressError> {
+    let chain = ChainType::Bitcoin;
+    if input.len() > 90 {
+        return Err(invalid(chain, "地址过长"));
+    }
+    let (hrp, data, variant) = bech32::decode(input).map_err(|e| match e {
+        bech32::Error::InvalidChecksum => AddressError::ChecksumMismatch,
+        bech32::Error::MixedCase => invalid(chain, "不能混用大小写"),
+        _ => invalid(chain, "Bech32 编码无效"),
+    })?;
+    let network = match hrp.as_str() {
+        "bc" => Network::Mainnet,
+        "tb" | "bcrt" => Network::Testnet,
+        _ => return Err(invalid(chain, "网络前缀无效")),
+    };
+
+    let (version, program) = data
+        .split_first()
+        .ok_or_else(|| invalid(chain, "缺少见证版本"))?;
+    let version = version.to_u8();
+    let program = Vec::<u8>::from_base32(program).map_err(|_| invalid(chain, "见证程序无效"))?;
+    if version > 16 || !(2..=40).contains(&program.len()) {
+        return Err(invalid(chain, "见证程序无效"));
+    }
+    // BIP350：v0 使用 Bech32，v1+ 使用 Bech32m
+    let expected_variant = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    if variant != expected_variant {
+        return Err(AddressError::ChecksumMismatch);
+    }
+    if version == 0 && program.len() != 20 && program.len() != 32 {
+        return Err(invalid(chain, "见证程序长度不正确"));
+    }
+    Ok((input.to_ascii_lowercase(), network))
+}
+
+fn parse_solana(input: &str) -> Result<String, AddressError> {
+    let chain = ChainType::Solana;
+    if !(32..=44).contains(&input.len()) {
+        return Err(invalid(chain, "长度应为 32–44 个字符"));
+    }
+    let bytes = bs58::decode(input)
+        .into_vec()
+        .map_err(|_| invalid(chain, "包含非 Base58 字符"))?;
+    if bytes.len() != 32 {
+        return Err(invalid(chain, "解码后应为 32 字节公钥"));
+    }
+    Ok(input.to_string())
+}
+
+/// CRC16-XMODEM（TON 友好地址校验和）
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+fn parse_ton(input: &str) -> Result<(String, Network), AddressError> {
+    let chain = ChainType::TON;
+
+    // 原始格式：workchain:64位十六进制
+    if let Some((workchain, hash)) = input.split_once(':') {
+        if !matches!(workchain, "0" | "-1") {
+            return Err(invalid(chain, "workchain 只能是 0 或 -1"));
+        }
+        if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid(chain, "账户哈希应为 64 位十六进制字符"));
+        }
+        return Ok((
+            format!("{}:{}", workchain, hash.to_ascii_lowercase()),
+            Network::Mainnet,
+        ));
+    }
+
+    // 友好格式：标志(1) + workchain(1) + 哈希(32) + CRC16(2)，base64/base64url 编码为 48 字符
+    if input.len() != 48 {
+        return Err(invalid(chain, "友好格式地址应为 48 个字符"));
+    }
+    let standard = input.replace('-', "+").replace('_', "/");
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(standard)
+        .map_err(|_| invalid(chain, "Base64 编码无效"))?;
+    if bytes.len() != 36 {
+        return Err(invalid(chain, "地址长度不正确"));
+    }
+    let crc = crc16_xmodem(&bytes[..34]);
+    if bytes[34..] != crc.to_be_bytes() {
+        return Err(AddressError::ChecksumMismatch);
+    }
+    let network = match bytes[0] {
+        0x11 | 0x51 => Network::Mainnet,
+        0x91 | 0xd1 => Network::Testnet,
+        _ => return Err(invalid(chain, "地址标志无效")),
+    };
+    if !matches!(bytes[1], 0x00 | 0xff) {
+        return Err(invalid(chain, "workchain 只能是 0 或 -1"));
+    }
+    Ok((
+        base64::engine::general_purpose::URL_SAFE.encode(&bytes),
+        network,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bech32::ToBase32;
+
+    fn base58check(version: u8) -> String {
+        let mut bytes = vec![version];
+        bytes.extend_from_slice(&[0x42; 20]);
+        let checksum = Sha256::digest(Sha256::digest(&bytes));
+        bytes.extend_from_slice(&checksum[..4]);
+        bs58::encode(bytes).into_string()
+    }
+
+    fn segwit(hrp: &str, version: u8, program: &[u8], variant: Variant) -> String {
+        let mut data = vec![bech32::u5::try_from_u8(version).unwrap()];
+        data.extend(program.to_base32());
+        bech32::encode(hrp, data, variant).unwrap()
+    }
+
+    fn ton_friendly(flag: u8) -> String {
+        let mut bytes = vec![flag, 0x00];
+        bytes.extend_from_slice(&[0xab; 32]);
+        bytes.extend_from_slice(&crc16_xmodem(&bytes).to_be_bytes());
+        base64::engine::general_purpose::URL_SAFE.encode(&bytes)
+    }
+
+    #[test]
+    fn evm_checksum_is_verified_and_normalized() {
+        // EIP-55 标准测试向量
+        for addr in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let normalized = validate_address(ChainType::Ethereum, addr).unwrap();
+            assert_eq!(normalized.address, addr);
+            // 全小写输入规范化为校验和格式
+            let lower = validate_address(ChainType::Ethereum, &addr.to_lowercase()).unwrap();
+            assert_eq!(lower.address, addr);
+        }
+
+        // 改动一个字母的大小写 → 校验和错误
+        assert_eq!(
+            validate_address(
+                ChainType::Ethereum,
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+            ),
+            Err(AddressError::ChecksumMismatch)
+        );
+        assert!(validate_address(ChainType::Ethereum, "0x5aAeb6053F3E94C9").is_err());
+        assert!(validate_address(
+            ChainType::Ethereum,
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        )
+        .is_err());
+        assert_eq!(
+            validate_address(ChainType::Ethereum, "  "),
+            Err(AddressError::Empty)
+        );
+
+        // BSC/Polygon 与 Ethereum 共用地址格式
+        let bsc =
+            validate_address(ChainType::BSC, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+        assert_eq!(bsc.chain, ChainType::BSC);
+        assert_eq!(bsc.address, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    }
+
+    #[test]
+    fn bitcoin_base58check_and_network() {
+        // 创世区块地址
+        assert!(validate_address(ChainType::Bitcoin, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").is_ok());
+        assert!(validate_address(ChainType::Bitcoin, &base58check(0x05)).is_ok());
+        assert_eq!(
+            validate_address(ChainType::Bitcoin, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Err(AddressError::ChecksumMismatch)
+        );
+
+        let testnet = base58check(0x6f);
+        assert_eq!(
+            validate_address(ChainType::Bitcoin, &testnet),
+            Err(AddressError::TestnetAddress {
+                chain: ChainType::Bitcoin
+            })
+        );
+        assert!(validate_address_on(ChainType::Bitcoin, &testnet, Network::Testnet).is_ok());
+    }
+
+    #[test]
+    fn bitcoin_bech32_hrp_and_variant() {
+        let p2wpkh = segwit("bc", 0, &[0x11; 20], Variant::Bech32);
+        let normalized = validate_address(ChainType::Bitcoin, &p2wpkh.to_uppercase()).unwrap();
+        assert_eq!(normalized.address, p2wpkh);
+
+        let taproot = segwit("bc", 1, &[0x22; 32], Variant::Bech32m);
+        assert!(validate_address(ChainType::Bitcoin, &taproot).is_ok());
+
+        // v1 使用 Bech32（而非 Bech32m）编码 → 拒绝
+        let wrong_variant = segwit("bc", 1, &[0x22; 32], Variant::Bech32);
+        assert!(validate_address(ChainType::Bitcoin, &wrong_variant).is_err());
+        // v0 见证程序长度必须为 20 或 32
+        let bad_len = segwit("bc", 0, &[0x11; 25], Variant::Bech32);
+        assert!(validate_address(ChainType::Bitcoin, &bad_len).is_err());
+        // 错误的网络前缀
+        let wrong_hrp = segwit("ltc", 0, &[0x11; 20], Variant::Bech32);
+        assert!(validate_address(ChainType::Bitcoin, &wrong_hrp).is_err());
+
+        let testnet = segwit("tb", 0, &[0x11; 20], Variant::Bech32);
+        assert_eq!(
+            validate_address(ChainType::Bitcoin, &testnet),
+            Err(AddressError::TestnetAddress {
+                chain: ChainType::Bitcoin
+            })
+        );
+    }
+
+    #[test]
+    fn derived_bitcoin_addresses_are_standard_bech32() {
+        use crate::crypto::key_manager::KeyManager;
+
+        let key_manager = KeyManager::new(vec![7u8; 64]);
+        let private_key = key_manager.derive_btc_private_key(0).unwrap();
+        let address = key_manager.get_btc_address(&private_key).unwrap();
+        assert!(address.starts_with("bc1q"));
+        assert_eq!(address.len(), 42);
+        assert!(validate_address(ChainType::Bitcoin, &address).is_ok());
+
+        // 旧版把 5 位分组再次按字节转换；修复后与现在派生的地址一致
+        let (_, program, _) = bech32::decode(&address).unwrap();
+        let legacy_bytes: Vec<u8> = program.iter().map(|g| g.to_u8()).collect();
+        let legacy = bech32::encode("bc", legacy_bytes.to_base32(), Variant::Bech32).unwrap();
+        assert!(legacy.starts_with("bc1qq"));
+        assert!(validate_address(ChainType::Bitcoin, &legacy).is_err());
+        assert_eq!(
+            KeyManager::repair_legacy_btc_address(&legacy).as_deref(),
+            Some(address.as_str())
+        );
+        assert_eq!(KeyManager::repair_legacy_btc_address(&address), None);
+    }
+
+    #[test]
+    fn solana_requires_32_byte_key() {
+        assert!(validate_address(
+            ChainType::Solana,
+            "2DW3219WuFwqLQqdFmkPa6bFL9pKj4LeG2GG8gDsHcGn"
+        )
+        .is_ok());
+        assert!(validate_address(ChainType::Solana, "11111111111111111111111111111111").is_ok());
+        // 长度错误 / 非 Base58 字符
+        assert!(validate_address(
+            ChainType::Solana,
+            "2DW3219WuFwqLQqdFmkPa6bFL9pKj4LeG2GG8gDsH"
+        )
+        .is_err());
+        assert!(validate_address(
+            ChainType::Solana,
+            "0OIl219WuFwqLQqdFmkPa6bFL9pKj4LeG2GG8gDsHcGn"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn ton_raw_and_friendly_formats() {
+        let raw = "0:60BCB52D2C0E92EAB79DC0E5E9D1B6FB1DA2B45815E8136F56507AD3D33A081A";
+        assert_eq!(
+            validate_address(ChainType::TON, raw).unwrap().address,
+            raw.to_lowercase()
+        );
+        assert!(validate_address(
+            ChainType::TON,
+            "2:60bcb52d2c0e92eab79dc0e5e9d1b6fb1da2b45815e8136f56507ad3d33a081a"
+        )
+        .is_err());
+
+        let bounceable = ton_friendly(0x11);
+        assert!(bounceable.starts_with("EQ"));
+        assert!(validate_address(ChainType::TON, &bounceable).is_ok());
+        assert!(validate_address(ChainType::TON, &ton_friendly(0x51)).is_ok());
+
+        // 篡改一个字符 → CRC 校验失败
+        let mut tampered = bounceable.clone();
+        tampered.replace_range(10..11, if &bounceable[10..11] == "A" { "B" } else { "A" });
+        assert!(validate_address(ChainType::TON, &tampered).is_err());
+
+        assert_eq!(
+            validate_address(ChainType::TON, &ton_friendly(0x91)),
+            Err(AddressError::TestnetAddress {
+                chain: ChainType::TON
+            })
+        );
+    }
+
+    #[test]
+    fn reports_other_chain_addresses() {
+        assert_eq!(
+            validate_address(
+                ChainType::Solana,
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            ),
+            Err(AddressError::WrongChain {
+                expected: ChainType::Solana,
+                found: ChainType::Ethereum
+            })
+        );
+        assert_eq!(
+            validate_address(ChainType::Ethereum, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+            Err(AddressError::WrongChain {
+                expected: ChainType::Ethereum,
+                found: ChainType::Bitcoin
+            })
+        );
+        assert_eq!(
+            validate_address(
+                ChainType::Bitcoin,
+                "2DW3219WuFwqLQqdFmkPa6bFL9pKj4LeG2GG8gDsHcGn"
+            ),
+            Err(AddressError::WrongChain {
+                expected: ChainType::Bitcoin,
+                found: ChainType::Solana
+            })
+        );
+        assert!(validate_address(
+            ChainType::Polygon,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        )
+        .is_ok());
+
+        let detected = detect_and_validate("2DW3219WuFwqLQqdFmkPa6bFL9pKj4LeG2GG8gDsHcGn").unwrap();
+        assert_eq!(detected.chain, ChainType::Solana);
+        assert_eq!(
+            detect_and_validate("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(AddressError::ChecksumMismatch)
+        );
+        assert_eq!(
+            detect_and_validate("hello"),
+            Err(AddressError::Unrecognized)
+        );
+    }
+}
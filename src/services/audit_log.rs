@@ -83,6 +83,15 @@ pub struct ComplianceReportResponse {
     pub status: String, // "pending", "processing", "completed", "failed"
 }
 
+/// 客户端审计事件（由前端上报，如用户确认忽略的风险提示）
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEventRequest {
+    pub action: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub details: serde_json::Value,
+}
+
 /// 审计日志服务
 pub struct AuditLogService {
     api_client: Arc<ApiClient>,
@@ -122,6 +131,32 @@ impl AuditLogService {
             })
     }
 
+    /// 上报客户端审计事件
+    ///
+    /// # 参数
+    /// - `event`: 审计事件
+    ///
+    /// # 错误处理
+    /// 返回用户友好的错误消息
+    pub async fn record_event(&self, event: AuditEventRequest) -> Result<(), String> {
+        let url = "/api/v1/audit/events";
+
+        self.api_client
+            .post::<serde_json::Value, AuditEventRequest>(url, &event)
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                let error_msg = e.to_string().to_lowercase();
+                if error_msg.contains("timeout") || error_msg.contains("network") {
+                    "网络连接超时，请稍后重试".to_string()
+                } else if error_msg.contains("unauthorized") || error_msg.contains("401") {
+                    "请先登录账户".to_string()
+                } else {
+                    format!("记录审计日志失败：{}", e)
+                }
+            })
+    }
+
     /// 生成合规报告
     ///
     /// # 参数
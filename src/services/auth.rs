@@ -2,7 +2,8 @@
 // EIP-4361 compliant wallet authentication
 
 use crate::crypto::key_manager::KeyManager;
-use crate::shared::error::{ApiError, AppError};
+use crate::shared::api::ApiClient;
+use crate::shared::error::{ApiError, AppError, AuthError};
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -24,6 +25,8 @@ pub struct UserInfo {
     pub id: String,
     pub email: String,
     pub created_at: String,
+    #[serde(default)]
+    pub email_verified: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -158,11 +161,44 @@ impl AuthService {
             email: email.to_string(),
             password: password.to_string(),
         };
-        let resp: LoginResp = api
-            .post("/api/v1/auth/login", &payload)
-            .await
-            .map_err(AppError::from)?;
-        Ok(resp)
+        let (status, body) = api.post_with_status("/api/v1/auth/login", &payload).await?;
+        if (200..300).contains(&status) {
+            return Ok(ApiClient::deserialize(body)?);
+        }
+        if let Some(auth_err) = parse_auth_failure(status, &body) {
+            return Err(auth_err.into());
+        }
+        Err(match status {
+            401 => ApiError::Unauthorized,
+            _ => ApiError::ResponseError(format!("{} - {}", status, body)),
+        }
+        .into())
+    }
+
+    /// 重新发送邮箱验证邮件
+    pub async fn resend_verification_email(&self, email: &str) -> Result<(), AppError> {
+        let api = self.app_state.get_api_client();
+        let _: serde_json::Value = api
+            .post(
+                crate::shared::api_endpoints::auth::RESEND_VERIFICATION,
+                &serde_json::json!({ "email": email }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// 查询当前账户邮箱是否已验证
+    pub async fn email_verification_status(&self) -> Result<bool, AppError> {
+        #[derive(Debug, Deserialize)]
+        struct VerificationStatusResp {
+            email_verified: bool,
+        }
+
+        let api = self.app_state.get_api_client();
+        let resp: VerificationStatusResp = api
+            .get(crate::shared::api_endpoints::auth::VERIFICATION_STATUS)
+            .await?;
+        Ok(resp.email_verified)
     }
 
     /// Request authentication challenge from backend
@@ -319,18 +355,26 @@ impl AuthService {
     }
 
     /// Refresh access token using refresh token
-    /// 使用刷新令牌刷新访问令牌
-    #[allow(dead_code)]
+    /// 使用刷新令牌刷新访问令牌（后端每次返回新的刷新令牌，旧令牌随即失效）
     pub async fn refresh_token(&self, refresh_token: &str) -> Result<RefreshTokenResp, AppError> {
         let api = self.app_state.get_api_client();
         let payload = RefreshTokenReq {
             refresh_token: refresh_token.to_string(),
         };
-        let resp: RefreshTokenResp = api
-            .post("/api/v1/auth/refresh", &payload)
-            .await
-            .map_err(AppError::from)?;
-        Ok(resp)
+        let (status, body) = api
+            .post_with_status(crate::shared::api_endpoints::auth::REFRESH, &payload)
+            .await?;
+        if (200..300).contains(&status) {
+            return Ok(ApiClient::deserialize(body)?);
+        }
+        if let Some(AuthError::RefreshTokenReused) = parse_auth_failure(status, &body) {
+            return Err(AuthError::RefreshTokenReused.into());
+        }
+        Err(match status {
+            401 | 403 => ApiError::Unauthorized,
+            _ => ApiError::ResponseError(format!("{} - {}", status, body)),
+        }
+        .into())
     }
 
     /// Logout user and revoke refresh token
@@ -345,50 +389,87 @@ impl AuthService {
     }
 
     /// Change user password
-    /// 修改用户密码
-    #[allow(dead_code)]
+    /// 修改用户密码（可选择同时使其他设备上的登录失效）
     pub async fn change_password(
         &self,
         old_password: &str,
         new_password: &str,
         confirm_password: &str,
+        revoke_other_sessions: bool,
     ) -> Result<(), AppError> {
         let api = self.app_state.get_api_client();
         let payload = ChangePasswordReq {
             old_password: old_password.to_string(),
             new_password: new_password.to_string(),
             confirm_password: confirm_password.to_string(),
+            revoke_other_sessions,
         };
         // deserialize 方法已自动提取 data 字段
         // 后端返回: {code: 0, message: "success", data: {}}
         let _: crate::shared::api::EmptyResponse = api
-            .post("/api/v1/auth/change-password", &payload)
+            .post(
+                crate::shared::api_endpoints::auth::CHANGE_PASSWORD,
+                &payload,
+            )
             .await
             .map_err(AppError::from)?;
         Ok(())
     }
+
+    /// 发送重置密码验证码到邮箱
+    pub async fn request_password_reset(&self, email: &str) -> Result<(), AppError> {
+        let api = self.app_state.get_api_client();
+        let _: serde_json::Value = api
+            .post(
+                crate::shared::api_endpoints::auth::FORGOT_PASSWORD,
+                &serde_json::json!({ "email": email }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// 使用邮箱验证码重置密码
+    pub async fn reset_password(&self, req: &ResetPasswordReq) -> Result<(), AppError> {
+        let api = self.app_state.get_api_client();
+        let _: serde_json::Value = api
+            .post(crate::shared::api_endpoints::auth::RESET_PASSWORD, req)
+            .await?;
+        Ok(())
+    }
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RefreshTokenReq {
     pub refresh_token: String,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RefreshTokenResp {
     pub access_token: String,
     pub refresh_token: String,
+    #[serde(default)]
     pub expires_in: i64,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChangePasswordReq {
     pub old_password: String,
     pub new_password: String,
     pub confirm_password: String,
+    /// 使其他设备上的登录失效
+    #[serde(default)]
+    pub revoke_other_sessions: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResetPasswordReq {
+    pub email: String,
+    /// 邮箱验证码
+    pub code: String,
+    pub new_password: String,
+    pub confirm_password: String,
+    #[serde(default)]
+    pub revoke_other_sessions: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -396,6 +477,59 @@ pub struct LogoutResp {
     pub message: String,
 }
 
+/// 未提供重试时间时的默认等待（秒）
+const DEFAULT_RETRY_AFTER_SECS: u64 = 60;
+
+/// 从登录接口的错误响应解析具体失败原因
+///
+/// 兼容 `{ code, message, data: {...} }` 包装与扁平结构；无法识别时返回 None，由调用方按状态码兜底。
+pub fn parse_auth_failure(status: u16, body: &serde_json::Value) -> Option<AuthError> {
+    let detail = body.get("data").filter(|d| d.is_object()).unwrap_or(body);
+    let field_u64 = |names: &[&str]| {
+        names.iter().find_map(|name| {
+            detail
+                .get(*name)
+                .or_else(|| body.get(*name))
+                .and_then(|v| v.as_u64())
+        })
+    };
+    let code = ["error_code", "code", "error"]
+        .iter()
+        .find_map(|name| {
+            detail
+                .get(*name)
+                .or_else(|| body.get(*name))
+                .and_then(|v| v.as_str())
+        })
+        .unwrap_or_default()
+        .to_lowercase();
+    let retry_after = field_u64(&["retry_after", "retry_after_secs", "lockout_seconds"]);
+    let remaining = field_u64(&["remaining_attempts", "attempts_remaining"]).map(|n| n as u32);
+
+    if code.contains("reuse") {
+        return Some(AuthError::RefreshTokenReused);
+    }
+    if code.contains("locked") || status == 423 {
+        return Some(AuthError::AccountLocked {
+            retry_after_secs: retry_after.unwrap_or(DEFAULT_RETRY_AFTER_SECS),
+        });
+    }
+    if code.contains("unverified") || code.contains("not_verified") {
+        return Some(AuthError::EmailNotVerified);
+    }
+    if status == 429 || code.contains("rate_limit") || code.contains("too_many") {
+        return Some(AuthError::RateLimited {
+            retry_after_secs: retry_after.unwrap_or(DEFAULT_RETRY_AFTER_SECS),
+        });
+    }
+    if status == 401 || code.contains("invalid_credentials") {
+        return Some(AuthError::InvalidCredentials {
+            remaining_attempts: remaining,
+        });
+    }
+    None
+}
+
 /// Hook for using auth service in components
 /// 获取认证服务实例
 ///
@@ -410,6 +544,42 @@ pub fn use_auth_service() -> AuthService {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parses_structured_login_failures() {
+        let body = serde_json::json!({
+            "code": 1001,
+            "message": "invalid",
+            "data": { "error_code": "INVALID_CREDENTIALS", "remaining_attempts": 2 }
+        });
+        assert_eq!(
+            parse_auth_failure(401, &body),
+            Some(AuthError::InvalidCredentials {
+                remaining_attempts: Some(2)
+            })
+        );
+        assert_eq!(
+            parse_auth_failure(423, &serde_json::json!({ "retry_after": 900 })),
+            Some(AuthError::AccountLocked {
+                retry_after_secs: 900
+            })
+        );
+        assert_eq!(
+            parse_auth_failure(403, &serde_json::json!({ "error": "EMAIL_NOT_VERIFIED" })),
+            Some(AuthError::EmailNotVerified)
+        );
+        assert_eq!(
+            parse_auth_failure(429, &serde_json::Value::Null),
+            Some(AuthError::RateLimited {
+                retry_after_secs: DEFAULT_RETRY_AFTER_SECS
+            })
+        );
+        assert_eq!(parse_auth_failure(500, &serde_json::Value::Null), None);
+        assert_eq!(
+            parse_auth_failure(401, &serde_json::json!({ "error": "REFRESH_TOKEN_REUSED" })),
+            Some(AuthError::RefreshTokenReused)
+        );
+    }
+
     #[test]
     fn test_siwe_message_formatting() {
         let message = SiweMessage {
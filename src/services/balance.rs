@@ -1,9 +1,163 @@
+//! Balance Service - 余额服务
+//!
+//! 余额统一以链上最小单位的大整数（U256）保存，只在渲染时按精度格式化，
+//! 避免 18 位精度代币转为 f64 时丢失精度。
+//! `get_portfolio` 并行查询钱包所有账户的原生币与跟踪代币余额，按账户缓存。
+//! `refresh_committed` 汇总挂单中的限价单、进行中的兑换与处理中的提现订单占用的数量，
+//! 余额展示与客户端余额校验使用扣除占用后的可用余额。
+
+use crate::shared::time;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 
+use crate::components::molecules::order_tracking::OrderStatus;
+use crate::features::swap::history::SwapHistoryStatus;
+use crate::features::swap::limit_expiry;
+use crate::features::wallet::state::{Account, Wallet};
+use crate::services::address_detector::ChainType;
+use crate::services::fiat_offramp::{FiatOfframpOrderStatus, FiatOfframpService};
+use crate::services::limit_order::{LimitOrderQuery, LimitOrderResponse, LimitOrderService};
+use crate::services::token::{TokenInfo, TokenService};
+use crate::services::token_metadata::{TokenMetadataService, TokenMetadataStore};
+use crate::services::transaction_history::{
+    TransactionHistoryItem, TransactionHistoryQuery, TransactionHistoryService,
+};
 use crate::shared::api::ApiClient;
-use crate::shared::error::AppError;
+use crate::shared::error::{ApiError, AppError};
 use crate::shared::request::{CachePolicy, SmartRequestContext};
 use crate::shared::state::AppState;
+use crate::shared::validation::TokenAmount;
+use dioxus::prelude::{ReadableExt, WritableExt};
+
+/// 计入资产快照的代币：(原生币符号, 代币符号, 合约地址, 精度, 是否稳定币)
+const TRACKED_TOKENS: &[(&str, &str, &str, u8, bool)] = &[
+    (
+        "ETH",
+        "USDT",
+        "0xdAC17F958D2ee523a2206206994597C13D831ec7",
+        6,
+        true,
+    ),
+    (
+        "ETH",
+        "USDC",
+        "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+        6,
+        true,
+    ),
+];
+
+/// 稳定币合约：(链, 代币符号, 合约地址, 精度)
+///
+/// BSC 上的 USDT/USDC 为 18 位精度；Polygon 的 USDC 为桥接版 USDC.e。
+pub const STABLECOINS: &[(ChainType, &str, &str, u8)] = &[
+    (
+        ChainType::Ethereum,
+        "USDT",
+        "0xdAC17F958D2ee523a2206206994597C13D831ec7",
+        6,
+    ),
+    (
+        ChainType::Ethereum,
+        "USDC",
+        "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+        6,
+    ),
+    (
+        ChainType::Ethereum,
+        "DAI",
+        "0x6B175474E89094C44Da98b954EedeAC495271d0F",
+        18,
+    ),
+    (
+        ChainType::BSC,
+        "USDT",
+        "0x55d398326f99059fF775485246999027B3197955",
+        18,
+    ),
+    (
+        ChainType::BSC,
+        "USDC",
+        "0x8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d",
+        18,
+    ),
+    (
+        ChainType::BSC,
+        "DAI",
+        "0x1AF3F329e8BE154074D8769D1FFa4eE058B1DBc3",
+        18,
+    ),
+    (
+        ChainType::Polygon,
+        "USDT",
+        "0xc2132D05D31c914a87C6611C10748AEb04B58e8F",
+        6,
+    ),
+    (
+        ChainType::Polygon,
+        "USDC",
+        "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
+        6,
+    ),
+    (
+        ChainType::Polygon,
+        "DAI",
+        "0x8f3Cf7ad23Cd3CaDbD9735AFf958023239c6A063",
+        18,
+    ),
+    (
+        ChainType::Solana,
+        "USDT",
+        "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+        6,
+    ),
+    (
+        ChainType::Solana,
+        "USDC",
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+        6,
+    ),
+];
+
+/// 稳定币符号（卡片按此顺序展示）
+pub const STABLECOIN_SYMBOLS: &[&str] = &["USDT", "USDC", "DAI"];
+
+/// 某条链上的稳定币合约
+pub fn stablecoins_on(
+    chain: ChainType,
+) -> impl Iterator<Item = &'static (ChainType, &'static str, &'static str, u8)> {
+    STABLECOINS.iter().filter(move |(c, ..)| *c == chain)
+}
+
+/// 需要查询稳定币的 (链, 地址)：EVM 账户地址在 Ethereum/BSC/Polygon 上通用，
+/// 每条链取第一个账户；没有稳定币合约的链（BTC、TON）不查询
+pub fn stablecoin_targets(accounts: &[Account]) -> Vec<(ChainType, String)> {
+    let mut targets: Vec<(ChainType, String)> = Vec::new();
+    for account in accounts {
+        let chains: &[ChainType] = match ChainType::from_str(&account.chain) {
+            Some(ChainType::Ethereum | ChainType::BSC | ChainType::Polygon) => {
+                &[ChainType::Ethereum, ChainType::BSC, ChainType::Polygon]
+            }
+            Some(ChainType::Solana) => &[ChainType::Solana],
+            _ => &[],
+        };
+        for chain in chains {
+            if !targets.iter().any(|(c, _)| c == chain) {
+                targets.push((*chain, account.address.clone()));
+            }
+        }
+    }
+    targets.sort_by_key(|(chain, _)| {
+        STABLECOINS
+            .iter()
+            .position(|(c, ..)| c == chain)
+            .unwrap_or(usize::MAX)
+    });
+    targets
+}
 
 // URL编码辅助函数
 fn encode_uri_component(s: &str) -> String {
@@ -19,6 +173,55 @@ fn encode_uri_component(s: &str) -> String {
         .unwrap_or_else(|| s.to_string())
 }
 
+/// 链名 → 原生币符号
+pub fn native_symbol(chain: &str) -> &'static str {
+    match chain.to_lowercase().as_str() {
+        "ethereum" | "eth" => "ETH",
+        "bitcoin" | "btc" => "BTC",
+        "solana" | "sol" => "SOL",
+        "ton" => "TON",
+        _ => "ETH",
+    }
+}
+
+/// 原生币精度
+pub fn native_decimals(symbol: &str) -> u8 {
+    match symbol {
+        "BTC" => 8,
+        "SOL" | "TON" => 9,
+        _ => 18,
+    }
+}
+
+/// 链名 → 余额 API 使用的 chain_id
+pub fn balance_chain_id(chain: &str) -> u64 {
+    match chain.to_lowercase().as_str() {
+        "ethereum" | "eth" => 1,
+        "bitcoin" | "btc" => 0,
+        "solana" | "sol" => 101,
+        "ton" => 0,
+        _ => 1,
+    }
+}
+
+/// 原生币余额的请求缓存键
+fn native_balance_key(chain_id: u64, address: &str) -> String {
+    format!("balance:{}:{}", chain_id, address.to_lowercase())
+}
+
+/// 代币余额的请求缓存键
+fn token_balance_key(chain: ChainType, token_address: &str, wallet_address: &str) -> String {
+    format!(
+        "token_balance:{}:{}:{}",
+        chain.as_str(),
+        token_address.to_lowercase(),
+        wallet_address.to_lowercase()
+    )
+}
+
+/// 余额请求缓存键前缀（强制刷新时清除）
+pub const BALANCE_CACHE_PREFIXES: [&str; 2] = ["balance:", "token_balance:"];
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BalanceResponse {
     pub balance: String,
@@ -26,6 +229,199 @@ pub struct BalanceResponse {
     pub confirmed: bool,
 }
 
+#[derive(Debug, Deserialize)]
+struct TokenBalanceResponse {
+    balance_raw: Option<String>,
+    balance: Option<String>, // 兼容旧格式
+}
+
+/// 单个资产余额
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetBalance {
+    pub symbol: String,
+    /// 代币合约地址（原生币为 None）
+    pub token_address: Option<String>,
+    pub balance: TokenAmount,
+    pub is_stablecoin: bool,
+}
+
+/// 单个账户的余额
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountBalances {
+    pub address: String,
+    pub chain: String,
+    /// 原生币余额（查询失败时为 None）
+    pub native: Option<AssetBalance>,
+    pub tokens: Vec<AssetBalance>,
+    /// 本次结果中最早的缓存写入时间（Unix 秒）；刷新时可能先返回旧缓存
+    pub fetched_at: u64,
+}
+
+/// 钱包资产快照
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortfolioSnapshot {
+    pub accounts: Vec<AccountBalances>,
+}
+
+impl PortfolioSnapshot {
+    /// 所有资产（原生币 + 代币）
+    pub fn assets(&self) -> impl Iterator<Item = &AssetBalance> {
+        self.accounts
+            .iter()
+            .flat_map(|a| a.native.iter().chain(a.tokens.iter()))
+    }
+
+    /// 是否没有任何成功查询到的余额
+    pub fn is_empty(&self) -> bool {
+        self.assets().next().is_none()
+    }
+
+    /// 已查询到余额的原生币符号
+    pub fn native_symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self
+            .accounts
+            .iter()
+            .filter_map(|a| a.native.as_ref().map(|n| n.symbol.clone()))
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// 某原生币在所有账户上的合计余额
+    pub fn native(&self, symbol: &str) -> Option<TokenAmount> {
+        self.accounts
+            .iter()
+            .filter_map(|a| a.native.as_ref())
+            .filter(|n| n.symbol == symbol)
+            .map(|n| n.balance)
+            .reduce(|acc, b| acc.checked_add(&b).unwrap_or(acc))
+    }
+
+    /// 稳定币 USD 价值（1 USDT/USDC ≈ 1 USD）
+    pub fn stablecoin_usd(&self) -> f64 {
+        self.stablecoin_usd_excluding(|_| false)
+    }
+
+    /// 稳定币 USD 价值，跳过 `excluded` 返回 true 的代币（参数为“链:小写合约地址”，如已隐藏的代币）
+    pub fn stablecoin_usd_excluding(&self, excluded: impl Fn(&str) -> bool) -> f64 {
+        let excluded = &excluded;
+        self.accounts
+            .iter()
+            .flat_map(|account| {
+                let chain = ChainType::from_str(&account.chain)
+                    .map(|c| c.as_str())
+                    .unwrap_or(account.chain.as_str());
+                account.tokens.iter().filter(move |a| {
+                    a.token_address.as_ref().is_none_or(|address| {
+                        !excluded(&format!("{}:{}", chain, address.to_lowercase()))
+                    })
+                })
+            })
+            .filter(|a| a.is_stablecoin)
+            .map(|a| a.balance.to_f64_lossy())
+            .sum()
+    }
+
+    /// 是否已有任何资产（按最小单位精确判断）
+    pub fn has_funds(&self) -> bool {
+        self.assets().any(|a| !a.balance.is_zero())
+    }
+
+    /// 快照中最旧的余额查询时间（Unix 秒）
+    pub fn fetched_at(&self) -> Option<u64> {
+        self.accounts.iter().map(|a| a.fetched_at).min()
+    }
+}
+
+/// 已占用的资产数量（按大写代币符号汇总）
+///
+/// 订单进入终态（成交、取消、过期、失败）后不再计入，下次刷新即释放。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommittedBalances {
+    amounts: HashMap<String, f64>,
+}
+
+impl CommittedBalances {
+    pub fn add(&mut self, symbol: &str, amount: f64) {
+        if amount.is_finite() && amount > 0.0 {
+            *self.amounts.entry(symbol.to_uppercase()).or_default() += amount;
+        }
+    }
+
+    /// 某代币的占用数量
+    pub fn get(&self, symbol: &str) -> f64 {
+        self.amounts
+            .get(&symbol.to_uppercase())
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn is_committed(&self, symbol: &str) -> bool {
+        self.get(symbol) > 0.0
+    }
+
+    /// 可用余额 = 总额 - 占用（不足时为 0）
+    pub fn available(&self, symbol: &str, total: TokenAmount) -> TokenAmount {
+        let committed = self.get(symbol);
+        if committed <= 0.0 {
+            return total;
+        }
+        let decimals = total.decimals();
+        TokenAmount::from_units(&format!("{:.*}", decimals as usize, committed), decimals)
+            .and_then(|committed| total.checked_sub(&committed))
+            .unwrap_or_else(|| TokenAmount::zero(decimals))
+    }
+
+    /// 可用余额（浮点版本，用于尚未迁移到 TokenAmount 的校验）
+    pub fn available_f64(&self, symbol: &str, total: f64) -> f64 {
+        (total - self.get(symbol)).max(0.0)
+    }
+
+    /// 挂单中的限价单：未成交部分占用支付代币
+    pub fn add_limit_orders(&mut self, orders: &[LimitOrderResponse], now: DateTime<Utc>) {
+        for order in orders.iter().filter(|o| {
+            limit_expiry::is_open(o)
+                && limit_expiry::urgency(o, now) != Some(limit_expiry::ExpiryUrgency::Expired)
+        }) {
+            let amount = order.amount.parse::<f64>().unwrap_or(0.0);
+            let filled = order
+                .filled_amount
+                .as_deref()
+                .and_then(|f| f.parse::<f64>().ok())
+                .unwrap_or(0.0);
+            self.add(&order.from_token, amount - filled);
+        }
+    }
+
+    /// 进行中的兑换：占用卖出代币
+    pub fn add_swaps(&mut self, items: &[TransactionHistoryItem]) {
+        for item in items.iter().filter(|i| {
+            i.tx_type == "swap"
+                && SwapHistoryStatus::from_status(&i.status)
+                    .map(|s| s.is_in_flight())
+                    .unwrap_or(false)
+        }) {
+            self.add(&item.from_token, item.from_amount.parse().unwrap_or(0.0));
+        }
+    }
+
+    /// 处理中的提现订单：占用卖出代币
+    pub fn add_offramp_orders(&mut self, orders: &[FiatOfframpOrderStatus]) {
+        for order in orders.iter().filter(|o| {
+            matches!(
+                OrderStatus::from_str(&o.status),
+                OrderStatus::Pending | OrderStatus::Processing
+            )
+        }) {
+            self.add(
+                &order.token_symbol,
+                order.token_amount.parse().unwrap_or(0.0),
+            );
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct BalanceService {
     app_state: AppState,
@@ -49,7 +445,7 @@ impl BalanceService {
         address: &str,
         chain_id: u64,
     ) -> Result<BalanceResponse, AppError> {
-        let key = format!("balance:{}:{}", chain_id, address.to_lowercase());
+        let key = native_balance_key(chain_id, address);
         // URL编码地址，因为某些地址（如TON）包含特殊字符（如:）
         let encoded_address = encode_uri_component(address);
         let path = format!(
@@ -71,4 +467,415 @@ impl BalanceService {
 
         Ok(response)
     }
+
+    /// 查询账户原生币余额（最小单位）
+    pub async fn get_native_balance(&self, account: &Account) -> Result<TokenAmount, AppError> {
+        let symbol = native_symbol(&account.chain);
+        let resp = self
+            .get_balance(&account.address, balance_chain_id(&account.chain))
+            .await?;
+        TokenAmount::from_raw_str(&resp.balance, native_decimals(symbol)).ok_or_else(|| {
+            AppError::Api(ApiError::ResponseError(format!(
+                "余额格式无效: {}",
+                resp.balance
+            )))
+        })
+    }
+
+    /// 查询代币余额（最小单位）
+    pub async fn get_token_balance_raw(
+        &self,
+        chain: ChainType,
+        token_address: &str,
+        decimals: u8,
+        wallet_address: &str,
+    ) -> Result<TokenAmount, AppError> {
+        let key = token_balance_key(chain, token_address, wallet_address);
+        let path = format!(
+            "/api/v1/tokens/{}/balance?address={}&chain={}",
+            token_address,
+            encode_uri_component(wallet_address),
+            chain.as_str()
+        );
+        let ctx = self.context();
+        let api = self.api();
+
+        let response: TokenBalanceResponse = ctx
+            .run(&key, CachePolicy::medium(), move || {
+                let api = api.clone();
+                let path = path.clone();
+                async move { api.get(&path).await }
+            })
+            .await
+            .map_err(AppError::from)?;
+
+        let raw = response
+            .balance_raw
+            .or(response.balance)
+            .unwrap_or_else(|| "0".to_string());
+        TokenAmount::from_raw_str(&raw, decimals)
+            .ok_or_else(|| AppError::Api(ApiError::ResponseError(format!("余额格式无效: {}", raw))))
+    }
+
+    /// 查询某条链上的稳定币余额（批量接口，失败时由代币服务降级为逐个查询）
+    pub async fn get_stablecoin_balances(
+        &self,
+        chain: ChainType,
+        wallet_address: &str,
+    ) -> Result<Vec<AssetBalance>, AppError> {
+        let contracts: Vec<_> = stablecoins_on(chain).collect();
+        let addresses: Vec<String> = contracts
+            .iter()
+            .map(|(_, _, address, _)| address.to_string())
+            .collect();
+        let balances = TokenService::new(self.app_state)
+            .get_token_balances_batch(chain, wallet_address, &addresses)
+            .await?;
+        if balances.is_empty() && !addresses.is_empty() {
+            return Err(AppError::Api(ApiError::ResponseError(format!(
+                "{} 稳定币余额查询失败",
+                chain.label()
+            ))));
+        }
+
+        Ok(contracts
+            .iter()
+            .filter_map(|(_, symbol, address, decimals)| {
+                let found = balances
+                    .iter()
+                    .find(|b| b.token.address.eq_ignore_ascii_case(address))?;
+                Some(AssetBalance {
+                    symbol: symbol.to_string(),
+                    token_address: Some(address.to_string()),
+                    balance: TokenAmount::from_raw_str(&found.balance_raw, *decimals)?,
+                    is_stablecoin: true,
+                })
+            })
+            .collect())
+    }
+
+    /// 查询账户上某个资产（原生币或代币）的余额
+    pub async fn get_asset_balance(
+        &self,
+        account: &Account,
+        token: &TokenInfo,
+    ) -> Result<TokenAmount, AppError> {
+        if token.is_native {
+            return self.get_native_balance(account).await;
+        }
+        // 会话中首次查询该代币时先核对链上精度
+        let token = TokenMetadataService::new(self.app_state)
+            .verify(token)
+            .await;
+        self.get_token_balance_raw(
+            token.chain,
+            &token.address,
+            token.decimals,
+            &account.address,
+        )
+        .await
+    }
+
+    /// 清除所有余额缓存（交易广播后调用，各页面下次查询时取最新余额）
+    pub fn invalidate_cache(&self) {
+        let mut cache = self.app_state.cache;
+        cache.write().retain(|key, _| {
+            !BALANCE_CACHE_PREFIXES
+                .iter()
+                .any(|prefix| key.starts_with(prefix))
+        });
+    }
+
+    /// 跳过缓存重新查询原生币余额
+    pub async fn get_native_balance_fresh(
+        &self,
+        account: &Account,
+    ) -> Result<TokenAmount, AppError> {
+        let key = native_balance_key(balance_chain_id(&account.chain), &account.address);
+        let mut cache = self.app_state.cache;
+        cache.write().remove(&key);
+        self.get_native_balance(account).await
+    }
+
+    /// 跳过缓存重新查询资产余额（确认交易前复核使用）
+    pub async fn get_asset_balance_fresh(
+        &self,
+        account: &Account,
+        token: &TokenInfo,
+    ) -> Result<TokenAmount, AppError> {
+        if token.is_native {
+            return self.get_native_balance_fresh(account).await;
+        }
+        let key = token_balance_key(token.chain, &token.address, &account.address);
+        let mut cache = self.app_state.cache;
+        cache.write().remove(&key);
+        self.get_asset_balance(account, token).await
+    }
+
+    /// 查询单个账户的原生币与跟踪代币余额（并行）
+    async fn get_account_balances(&self, account: &Account) -> AccountBalances {
+        let symbol = native_symbol(&account.chain);
+        let tracked: Vec<_> = TRACKED_TOKENS
+            .iter()
+            .filter(|(native, ..)| *native == symbol)
+            .collect();
+
+        let native_future = self.get_native_balance(account);
+        let verified = TokenMetadataStore::load();
+        let token_futures = tracked.iter().map(|(_, _, address, decimals, _)| {
+            let decimals = verified.decimals_for(ChainType::Ethereum, address, *decimals);
+            self.get_token_balance_raw(ChainType::Ethereum, address, decimals, &account.address)
+        });
+        let (native, tokens) = futures::join!(native_future, join_all(token_futures));
+
+        let native = match native {
+            Ok(balance) => Some(AssetBalance {
+                symbol: symbol.to_string(),
+                token_address: None,
+                balance,
+                is_stablecoin: false,
+            }),
+            Err(e) => {
+                log::warn!("Failed to get balance for {}: {}", account.address, e);
+                None
+            }
+        };
+        let tokens = tracked
+            .iter()
+            .zip(tokens)
+            .filter_map(
+                |((_, token_symbol, address, _, stable), result)| match result {
+                    Ok(balance) => Some(AssetBalance {
+                        symbol: token_symbol.to_string(),
+                        token_address: Some(address.to_string()),
+                        balance,
+                        is_stablecoin: *stable,
+                    }),
+                    Err(e) => {
+                        log::warn!(
+                            "Failed to get {} balance for {}: {}",
+                            token_symbol,
+                            account.address,
+                            e
+                        );
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        let keys = std::iter::once(native_balance_key(
+            balance_chain_id(&account.chain),
+            &account.address,
+        ))
+        .chain(tracked.iter().map(|(_, _, address, ..)| {
+            token_balance_key(ChainType::Ethereum, address, &account.address)
+        }));
+        let fetched_at = self.oldest_cached_at(keys);
+
+        AccountBalances {
+            address: account.address.clone(),
+            chain: account.chain.clone(),
+            native,
+            tokens,
+            fetched_at,
+        }
+    }
+
+    /// 多个请求缓存中最早的写入时间（均未缓存时视为刚刚查询）
+    fn oldest_cached_at(&self, keys: impl Iterator<Item = String>) -> u64 {
+        let cache = self.app_state.cache.peek();
+        keys.filter_map(|key| cache.get(&key).map(|entry| entry.stored_at))
+            .min()
+            .unwrap_or_else(time::now_unix)
+    }
+
+    /// 钱包资产快照：并行查询所有账户，单个查询失败不影响其他账户
+    pub async fn get_portfolio(&self, wallet: &Wallet) -> PortfolioSnapshot {
+        let accounts = join_all(wallet.accounts.iter().map(|a| self.get_account_balances(a))).await;
+        PortfolioSnapshot { accounts }
+    }
+
+    /// 重新汇总占用数量并写入全局状态（单个来源查询失败时跳过该来源）
+    pub async fn refresh_committed(&self) -> CommittedBalances {
+        let limit_service = LimitOrderService::new(self.app_state);
+        let history_service = TransactionHistoryService::new(self.app_state);
+        let offramp_service = FiatOfframpService::new(self.app_state);
+        let (limit_orders, swaps, offramp_orders) = futures::join!(
+            limit_service.get_orders(Some(LimitOrderQuery {
+                order_type: None,
+                status: None,
+                page: Some(1),
+                page_size: Some(100),
+                sort_by: None,
+                sort_order: None,
+            })),
+            history_service.get_history(Some(TransactionHistoryQuery {
+                tx_type: Some("swap".to_string()),
+                status: None,
+                page: Some(1),
+                page_size: Some(50),
+                start_date: None,
+                end_date: None,
+            })),
+            offramp_service.get_orders(None, Some(1), Some(50), Some("created_at"), Some("desc")),
+        );
+
+        let mut committed = CommittedBalances::default();
+        match limit_orders {
+            Ok(resp) => committed.add_limit_orders(&resp.orders, Utc::now()),
+            Err(e) => log::warn!("汇总限价单占用失败: {}", e),
+        }
+        match swaps {
+            Ok(resp) => committed.add_swaps(&resp.transactions),
+            Err(e) => log::warn!("汇总兑换占用失败: {}", e),
+        }
+        match offramp_orders {
+            Ok(resp) => committed.add_offramp_orders(&resp.orders),
+            Err(e) => log::warn!("汇总提现占用失败: {}", e),
+        }
+
+        let mut signal = self.app_state.committed_balances;
+        if *signal.peek() != committed {
+            signal.set(committed.clone());
+        }
+        committed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(raw: &str, decimals: u8) -> TokenAmount {
+        TokenAmount::from_raw_str(raw, decimals).unwrap()
+    }
+
+    #[test]
+    fn native_symbol_maps_chain_aliases() {
+        assert_eq!(native_symbol("Ethereum"), "ETH");
+        assert_eq!(native_symbol("btc"), "BTC");
+        assert_eq!(native_symbol("sol"), "SOL");
+        assert_eq!(native_symbol("ton"), "TON");
+        assert_eq!(native_decimals("BTC"), 8);
+        assert_eq!(native_decimals("ETH"), 18);
+    }
+
+    #[test]
+    fn portfolio_sums_native_and_detects_funds() {
+        let asset = |symbol: &str, raw: &str, decimals: u8, stable: bool| AssetBalance {
+            symbol: symbol.to_string(),
+            token_address: None,
+            balance: balance(raw, decimals),
+            is_stablecoin: stable,
+        };
+        let account = |native: AssetBalance, tokens: Vec<AssetBalance>| AccountBalances {
+            address: String::new(),
+            chain: "ethereum".to_string(),
+            native: Some(native),
+            tokens,
+            fetched_at: 0,
+        };
+        let mut snapshot = PortfolioSnapshot {
+            accounts: vec![
+                account(
+                    asset("ETH", "0", 18, false),
+                    vec![asset("USDT", "0", 6, true)],
+                ),
+                account(asset("ETH", "0", 18, false), vec![]),
+            ],
+        };
+        assert!(!snapshot.has_funds());
+
+        snapshot.accounts[0].tokens[0] = asset("USDT", "2500000", 6, true);
+        snapshot.accounts[1].native = Some(asset("ETH", "1", 18, false));
+        assert!(snapshot.has_funds());
+        assert_eq!(snapshot.native("ETH").unwrap().to_raw_string(), "1");
+        assert_eq!(snapshot.stablecoin_usd(), 2.5);
+        snapshot.accounts[0].tokens[0].token_address = Some("0xdAC1".to_string());
+        assert_eq!(
+            snapshot.stablecoin_usd_excluding(|key| key == "ethereum:0xdac1"),
+            0.0
+        );
+        assert_eq!(snapshot.native_symbols(), vec!["ETH".to_string()]);
+    }
+
+    #[test]
+    fn stablecoin_targets_fan_out_evm_accounts() {
+        let account = |chain: &str, address: &str| Account {
+            address: address.to_string(),
+            chain: chain.to_string(),
+            public_key: String::new(),
+            derivation_path: None,
+            account_type: crate::features::wallet::state::AccountType::Imported,
+            balance: String::new(),
+        };
+        let targets = stablecoin_targets(&[
+            account("solana", "So1"),
+            account("bitcoin", "bc1"),
+            account("ethereum", "0xaaa"),
+            account("polygon", "0xbbb"),
+            account("ton", "EQ"),
+        ]);
+        assert_eq!(
+            targets,
+            vec![
+                (ChainType::Ethereum, "0xaaa".to_string()),
+                (ChainType::BSC, "0xaaa".to_string()),
+                (ChainType::Polygon, "0xaaa".to_string()),
+                (ChainType::Solana, "So1".to_string()),
+            ]
+        );
+        assert_eq!(stablecoins_on(ChainType::Solana).count(), 2);
+        assert!(stablecoins_on(ChainType::Bitcoin).next().is_none());
+    }
+
+    #[test]
+    fn committed_excludes_terminal_orders_and_reduces_available() {
+        let limit = |status: &str, amount: &str, filled: Option<&str>| LimitOrderResponse {
+            order_id: "lo-1".to_string(),
+            order_type: "sell".to_string(),
+            from_token: "usdt".to_string(),
+            to_token: "ETH".to_string(),
+            amount: amount.to_string(),
+            limit_price: "2500".to_string(),
+            status: status.to_string(),
+            filled_amount: filled.map(|s| s.to_string()),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            expires_at: Some("2026-02-01T00:00:00Z".to_string()),
+            message: None,
+        };
+        let now = DateTime::parse_from_rfc3339("2026-01-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut committed = CommittedBalances::default();
+        committed.add_limit_orders(
+            &[
+                limit("pending", "10", None),
+                limit("partially_filled", "5", Some("2")),
+                limit("filled", "100", Some("100")),
+                limit("cancelled", "100", None),
+            ],
+            now,
+        );
+        assert_eq!(committed.get("USDT"), 13.0);
+
+        // 到期时间已过的挂单同样释放
+        let later = DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut expired = CommittedBalances::default();
+        expired.add_limit_orders(&[limit("pending", "10", None)], later);
+        assert!(!expired.is_committed("USDT"));
+
+        let total = balance("20000000", 6);
+        assert_eq!(
+            committed.available("USDT", total).to_raw_string(),
+            "7000000"
+        );
+        assert_eq!(committed.available("ETH", total), total);
+        assert_eq!(committed.available_f64("usdt", 20.0), 7.0);
+        assert!(committed.available("USDT", balance("1000000", 6)).is_zero());
+    }
 }
@@ -6,6 +6,7 @@
 //! - GET  /api/v1/bridge/history
 
 use crate::crypto::tx_signer::EthereumTxSigner;
+use crate::features::wallet::chain_accounts;
 use crate::features::wallet::unlock::ensure_wallet_unlocked;
 use crate::services::address_detector::ChainType;
 use crate::services::chain_config::ChainConfigManager;
@@ -13,6 +14,7 @@ use crate::services::gas_limit::GasLimitService;
 use crate::services::transaction::TransactionService;
 use crate::shared::api::ApiClient;
 use crate::shared::api_endpoints;
+use crate::shared::kill_switch::{self, MoneyAction};
 use crate::shared::state::AppState;
 use anyhow::anyhow;
 use dioxus::prelude::ReadableExt;
@@ -217,6 +219,9 @@ impl BridgeService {
     }
 
     /// 执行跨链桥接✅使用统一端点
+    ///
+    /// `destination_gas_amount`：由服务商在到账时兑换为目标链原生币的数量（桥接代币单位，
+    /// 仅在报价支持 gas drop-off 时传入）
     pub async fn bridge_assets(
         &self,
         from_wallet: &str,
@@ -224,6 +229,7 @@ impl BridgeService {
         to_chain: &str,
         token: &str,
         amount: &str,
+        destination_gas_amount: Option<&str>,
     ) -> Result<BridgeResponse, String> {
         // 1) 钱包锁检查
         ensure_wallet_unlocked(&self.app_state, from_wallet)
@@ -310,9 +316,11 @@ impl BridgeService {
             .clone()
             .ok_or_else(|| "Wallet not unlocked (missing key manager)".to_string())?;
 
-        let private_key_hex = key_manager
-            .derive_eth_private_key(source_account_index as u32)
-            .map_err(|e| format!("Failed to derive private key: {}", e))?;
+        // 跨链转账受转账开关控制
+        kill_switch::ensure_allowed(&self.api_client, MoneyAction::Send).await?;
+        let private_key_hex =
+            chain_accounts::signing_key(&key_manager, source_account, source_account_index as u32)
+                .map_err(|e| format!("Failed to derive private key: {}", e))?;
 
         let signed_tx = EthereumTxSigner::sign_transaction(
             &private_key_hex,
@@ -337,6 +345,8 @@ impl BridgeService {
             signed_source_tx: String,
             bridge_provider: Option<String>,
             idempotency_key: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            destination_gas_amount: Option<String>,
         }
 
         let request = ExecuteBridgeRequest {
@@ -349,6 +359,7 @@ impl BridgeService {
             signed_source_tx: signed_tx,
             bridge_provider: None,
             idempotency_key: None,
+            destination_gas_amount: destination_gas_amount.map(str::to_string),
         };
 
         let response: BridgeResponse = self
@@ -449,9 +460,10 @@ impl BridgeService {
             .clone()
             .ok_or_else(|| "Wallet not unlocked (missing key manager)".to_string())?;
 
-        let private_key_hex = key_manager
-            .derive_eth_private_key(source_account_index as u32)
-            .map_err(|e| format!("Failed to derive private key: {}", e))?;
+        kill_switch::ensure_allowed(&self.api_client, MoneyAction::Send).await?;
+        let private_key_hex =
+            chain_accounts::signing_key(&key_manager, source_account, source_account_index as u32)
+                .map_err(|e| format!("Failed to derive private key: {}", e))?;
 
         // 6) 计算 nonce 并签名每一个 step
         let base_nonce = self
@@ -1,5 +1,9 @@
 //! Bridge Fee Service - 跨链桥费用实时查询服务
 //! 集成后端API，实时查询跨链桥费用
+//!
+//! 费用分为三部分：源链 Gas、跨链桥协议费用、目标链 Gas。
+//! 协议费用以桥接代币计价并从桥接数量中扣除；两项 Gas 在源链以原生币支付。
+//! 报价同时返回服务商的单笔最小/最大数量，以及到账时附送的目标链原生币（gas drop-off）。
 
 use crate::services::address_detector::ChainType;
 use crate::shared::api::ApiClient;
@@ -7,7 +11,7 @@ use crate::shared::state::AppState;
 use anyhow::{anyhow, Result};
 
 /// 跨链桥费用报价（企业级实现）
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BridgeFeeQuote {
     /// 跨链桥协议费用（ETH单位）
     /// 注意：这是跨链桥协议收取的费用，与Gas费用和平台服务费完全独立
@@ -27,6 +31,46 @@ pub struct BridgeFeeQuote {
     pub bridge_protocol: String,
     /// 预估时间（秒）
     pub estimated_time_seconds: u64,
+    /// 服务商单笔最小桥接数量（代币单位，None 表示无限制或未知）
+    pub min_amount: Option<f64>,
+    /// 服务商单笔最大桥接数量
+    pub max_amount: Option<f64>,
+    /// 到账时附送的目标链原生币（gas drop-off，0 表示不提供）
+    pub destination_gas_dropoff: f64,
+}
+
+impl BridgeFeeQuote {
+    /// 数量超出服务商限额时的提示
+    pub fn amount_error(&self, amount: f64, token: &str) -> Option<String> {
+        if let Some(min) = self.min_amount.filter(|m| amount < *m) {
+            return Some(format!("最小跨链数量为 {} {}", trim_amount(min), token));
+        }
+        if let Some(max) = self.max_amount.filter(|m| amount > *m) {
+            return Some(format!("单笔最多跨链 {} {}", trim_amount(max), token));
+        }
+        None
+    }
+
+    /// 目标链预计收到的代币数量（扣除协议费用）
+    pub fn net_received(&self, amount: f64) -> f64 {
+        (amount - self.bridge_fee).max(0.0)
+    }
+
+    /// 在源链以原生币支付的 Gas 合计（源链 + 目标链）
+    pub fn gas_total(&self) -> f64 {
+        self.source_gas_fee + self.target_gas_fee
+    }
+
+    /// 是否为无法获取实时报价时的估算值
+    pub fn is_estimated(&self) -> bool {
+        self.bridge_protocol == "estimated"
+    }
+}
+
+/// 去掉多余的小数位（最多 6 位）
+pub fn trim_amount(value: f64) -> String {
+    let s = format!("{:.6}", value);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
 }
 
 /// 跨链桥费用服务
@@ -68,6 +112,9 @@ impl BridgeFeeService {
                 total_fee: 0.0,
                 bridge_protocol: "direct".to_string(),
                 estimated_time_seconds: 0,
+                min_amount: None,
+                max_amount: None,
+                destination_gas_dropoff: 0.0,
             });
         }
 
@@ -112,6 +159,12 @@ impl BridgeFeeService {
             target_gas_fee: Option<f64>,
             bridge_protocol: String,
             estimated_time_seconds: Option<u64>,
+            #[serde(default)]
+            min_amount: Option<f64>,
+            #[serde(default)]
+            max_amount: Option<f64>,
+            #[serde(default, alias = "gas_dropoff")]
+            destination_gas_dropoff: Option<f64>,
         }
 
         let token_symbol = token
@@ -148,7 +201,7 @@ impl BridgeFeeService {
                         .filter(|&v| v >= 0.0 && v.is_finite())
                 })
                 .unwrap_or_else(|| {
-                    tracing::error!(
+                    log::error!(
                         "严重警告：未找到环境变量配置的源链Gas费用默认值 (chain={})，使用硬编码默认值 0.0。生产环境必须配置环境变量 BRIDGE_SOURCE_GAS_FEE_DEFAULT 或 BRIDGE_SOURCE_GAS_FEE_DEFAULT_{}",
                         from_chain.as_str(), from_chain.as_str().to_uppercase()
                     );
@@ -169,7 +222,7 @@ impl BridgeFeeService {
                         .filter(|&v| v >= 0.0 && v.is_finite())
                 })
                 .unwrap_or_else(|| {
-                    tracing::error!(
+                    log::error!(
                         "严重警告：未找到环境变量配置的目标链Gas费用默认值 (chain={})，使用硬编码默认值 0.0。生产环境必须配置环境变量 BRIDGE_TARGET_GAS_FEE_DEFAULT 或 BRIDGE_TARGET_GAS_FEE_DEFAULT_{}",
                         to_chain.as_str(), to_chain.as_str().to_uppercase()
                     );
@@ -189,6 +242,12 @@ impl BridgeFeeService {
             target_gas_fee,
             total_fee,
             bridge_protocol: response.bridge_protocol,
+            min_amount: response.min_amount.filter(|v| v.is_finite() && *v > 0.0),
+            max_amount: response.max_amount.filter(|v| v.is_finite() && *v > 0.0),
+            destination_gas_dropoff: response
+                .destination_gas_dropoff
+                .filter(|v| v.is_finite() && *v > 0.0)
+                .unwrap_or(0.0),
             estimated_time_seconds: response.estimated_time_seconds.unwrap_or_else(|| {
                 // 企业级实现：从环境变量读取默认预估时间
                 std::env::var("BRIDGE_DEFAULT_ESTIMATED_TIME_SECONDS")
@@ -196,7 +255,7 @@ impl BridgeFeeService {
                     .and_then(|v| v.parse::<u64>().ok())
                     .filter(|&v| v > 0 && v <= 3600) // 验证范围：0-3600秒
                     .unwrap_or_else(|| {
-                        tracing::error!(
+                        log::error!(
                             "严重警告：未找到环境变量配置的桥接预估时间，使用硬编码默认值 300秒（5分钟）。生产环境必须配置环境变量 BRIDGE_DEFAULT_ESTIMATED_TIME_SECONDS"
                         );
                         300 // 安全默认值：5分钟（仅作为最后保障，生产环境不应使用）
@@ -304,7 +363,7 @@ impl BridgeFeeService {
                     .filter(|&v| v > 0.0 && v.is_finite() && v <= 5.0)
             })
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的桥接因子，使用硬编码默认值 1.0。生产环境必须配置环境变量 BRIDGE_FACTOR 或 BRIDGE_FACTOR_{}_{}",
                     from.as_str().to_uppercase(), to.as_str().to_uppercase()
                 );
@@ -348,7 +407,7 @@ impl BridgeFeeService {
                     .and_then(|v| v.parse::<u64>().ok())
                     .filter(|&v| v > 0 && v <= 3600) // 验证范围：0-3600秒
                     .unwrap_or_else(|| {
-                        tracing::error!(
+                        log::error!(
                             "严重警告：未找到环境变量配置的桥接预估时间，使用硬编码默认值 300秒（5分钟）。生产环境必须配置环境变量 BRIDGE_DEFAULT_ESTIMATED_TIME_SECONDS"
                         );
                         300 // 安全默认值：5分钟（仅作为最后保障，生产环境不应使用）
@@ -362,6 +421,9 @@ impl BridgeFeeService {
             total_fee,
             bridge_protocol: "estimated".to_string(),
             estimated_time_seconds: estimated_time,
+            min_amount: None,
+            max_amount: None,
+            destination_gas_dropoff: 0.0,
         }
     }
 
@@ -418,3 +480,42 @@ impl BridgeFeeService {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(min: Option<f64>, max: Option<f64>) -> BridgeFeeQuote {
+        BridgeFeeQuote {
+            bridge_fee: 0.6,
+            source_gas_fee: 0.002,
+            target_gas_fee: 0.001,
+            total_fee: 0.603,
+            bridge_protocol: "stargate".to_string(),
+            estimated_time_seconds: 120,
+            min_amount: min,
+            max_amount: max,
+            destination_gas_dropoff: 0.0,
+        }
+    }
+
+    #[test]
+    fn amount_limits_and_net_received() {
+        let q = quote(Some(10.0), Some(50_000.0));
+        assert_eq!(
+            q.amount_error(5.0, "USDC").as_deref(),
+            Some("最小跨链数量为 10 USDC")
+        );
+        assert_eq!(
+            q.amount_error(60_000.5, "USDC").as_deref(),
+            Some("单笔最多跨链 50000 USDC")
+        );
+        assert!(q.amount_error(10.0, "USDC").is_none());
+        assert!(quote(None, None).amount_error(0.01, "USDC").is_none());
+
+        assert!((q.net_received(100.0) - 99.4).abs() < 1e-9);
+        assert_eq!(q.net_received(0.5), 0.0);
+        assert!((q.gas_total() - 0.003).abs() < 1e-12);
+        assert_eq!(trim_amount(0.0025), "0.0025");
+    }
+}
@@ -7,7 +7,7 @@ use std::time::Duration;
 
 /// 获取当前 Unix 时间戳（秒）- WebAssembly 兼容
 fn now_timestamp() -> u64 {
-    js_sys::Date::new_0().get_time() as u64 / 1000
+    crate::shared::time::now_unix()
 }
 
 /// 缓存项
@@ -15,6 +15,8 @@ fn now_timestamp() -> u64 {
 struct CacheItem<T> {
     value: T,
     expires_at: u64, // Unix timestamp in seconds
+    #[serde(default)]
+    fetched_at: u64, // 写入时间（Unix 秒），用于显示「X秒前更新」
 }
 
 /// 内存缓存管理器
@@ -34,12 +36,17 @@ impl MemoryCache {
 
     /// 获取缓存值
     pub fn get<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Option<T> {
+        self.get_with_time(key).map(|(value, _)| value)
+    }
+
+    /// 获取缓存值及其写入时间（Unix 秒）
+    pub fn get_with_time<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Option<(T, u64)> {
         if let Some(serialized) = self.data.get(key) {
             if let Ok(item) = serde_json::from_str::<CacheItem<T>>(serialized) {
                 let now = now_timestamp();
 
                 if now < item.expires_at {
-                    return Some(item.value);
+                    return Some((item.value, item.fetched_at));
                 } else {
                     // 过期，从内存中移除
                     // 注意：这里不能直接修改，需要返回None
@@ -53,9 +60,14 @@ impl MemoryCache {
     pub fn set<T: Serialize>(&mut self, key: String, value: T, ttl: Option<Duration>) {
         let ttl = ttl.unwrap_or(self.default_ttl);
         // WebAssembly 兼容：使用 js_sys::Date 获取当前时间
-        let expires_at = now_timestamp() + ttl.as_secs();
-
-        let item = CacheItem { value, expires_at };
+        let fetched_at = now_timestamp();
+        let expires_at = fetched_at + ttl.as_secs();
+
+        let item = CacheItem {
+            value,
+            expires_at,
+            fetched_at,
+        };
         if let Ok(serialized) = serde_json::to_string(&item) {
             self.data.insert(key, serialized);
         }
@@ -108,6 +120,11 @@ impl CacheKey {
         format!("quote:{}:{}:{}", from, to, amount)
     }
 
+    /// 按路由偏好区分的报价缓存键（同一交易对的各偏好以 `quote(..) + ":"` 为前缀）
+    pub fn routed_quote(from: &str, to: &str, amount: &str, route: &str) -> String {
+        format!("{}:{}", Self::quote(from, to, amount), route)
+    }
+
     /// 生成代币余额缓存键
     pub fn balance(chain: &str, address: &str, token: &str) -> String {
         format!("balance:{}:{}:{}", chain, address, token)
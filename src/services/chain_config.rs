@@ -19,6 +19,79 @@ pub struct ChainConfig {
     pub explorer_url: Option<String>,
     /// 默认Gas Limit（用于估算）
     pub default_gas_limit: u64,
+    /// 确认数要求
+    pub confirmations: ConfirmationPolicy,
+}
+
+/// 大额档位：交易价值不低于 `min_usd` 时至少需要 `confirmations` 个确认
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmationTier {
+    pub min_usd: f64,
+    pub confirmations: u32,
+}
+
+/// 链的确认数要求（默认值、用户可调范围与按价值升级的档位）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmationPolicy {
+    /// 默认确认数
+    pub default: u32,
+    /// 用户自定义的下限
+    pub min: u32,
+    /// 用户自定义的上限
+    pub max: u32,
+    /// 按交易美元价值提高确认数（min_usd 升序）
+    pub tiers: Vec<ConfirmationTier>,
+}
+
+impl ConfirmationPolicy {
+    /// 各链的默认确认数要求
+    pub fn default_for(chain: ChainType) -> Self {
+        let tier = |min_usd: f64, confirmations: u32| ConfirmationTier {
+            min_usd,
+            confirmations,
+        };
+        let (default, min, max, tiers) = match chain {
+            ChainType::Ethereum => (12, 3, 64, vec![tier(10_000.0, 32), tier(100_000.0, 64)]),
+            ChainType::BSC => (15, 3, 50, vec![tier(10_000.0, 30)]),
+            ChainType::Polygon => (64, 16, 256, vec![tier(10_000.0, 128)]),
+            ChainType::Bitcoin => (2, 1, 12, vec![tier(10_000.0, 6), tier(100_000.0, 12)]),
+            // Solana 32 个确认即 finalized
+            ChainType::Solana => (1, 1, 32, vec![tier(10_000.0, 32)]),
+            ChainType::TON => (1, 1, 10, vec![]),
+        };
+        Self {
+            default,
+            min,
+            max,
+            tiers,
+        }
+    }
+
+    /// 将用户自定义值限制在安全范围内
+    pub fn clamp(&self, value: u32) -> u32 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// 所需确认数：用户自定义值（或默认值），大额交易按档位提高
+    pub fn required(&self, value_usd: Option<f64>, user_override: Option<u32>) -> u32 {
+        let base = user_override.map_or(self.default, |v| self.clamp(v));
+        let escalated = value_usd
+            .filter(|v| v.is_finite())
+            .and_then(|usd| {
+                self.tiers
+                    .iter()
+                    .filter(|t| usd >= t.min_usd)
+                    .map(|t| t.confirmations)
+                    .max()
+            })
+            .unwrap_or(0);
+        base.max(escalated)
+    }
+}
+
+/// 确认进度文案（如 "3 / 12 个确认"）
+pub fn confirmation_progress(current: u64, required: u32) -> String {
+    format!("{} / {} 个确认", current, required)
 }
 
 /// 链配置管理器
@@ -82,6 +155,7 @@ impl ChainConfigManager {
                 rpc_url: None,
                 explorer_url: Some("https://etherscan.io".to_string()),
                 default_gas_limit: eth_gas_limit,
+                confirmations: ConfirmationPolicy::default_for(ChainType::Ethereum),
             },
         );
 
@@ -93,6 +167,7 @@ impl ChainConfigManager {
                 rpc_url: None,
                 explorer_url: Some("https://bscscan.com".to_string()),
                 default_gas_limit: bsc_gas_limit,
+                confirmations: ConfirmationPolicy::default_for(ChainType::BSC),
             },
         );
 
@@ -104,6 +179,7 @@ impl ChainConfigManager {
                 rpc_url: None,
                 explorer_url: Some("https://polygonscan.com".to_string()),
                 default_gas_limit: polygon_gas_limit,
+                confirmations: ConfirmationPolicy::default_for(ChainType::Polygon),
             },
         );
 
@@ -115,6 +191,7 @@ impl ChainConfigManager {
                 rpc_url: None,
                 explorer_url: Some("https://blockstream.info".to_string()),
                 default_gas_limit: 0, // Bitcoin不使用Gas
+                confirmations: ConfirmationPolicy::default_for(ChainType::Bitcoin),
             },
         );
 
@@ -126,6 +203,7 @@ impl ChainConfigManager {
                 rpc_url: None,
                 explorer_url: Some("https://solscan.io".to_string()),
                 default_gas_limit: 0, // Solana使用compute units
+                confirmations: ConfirmationPolicy::default_for(ChainType::Solana),
             },
         );
 
@@ -137,6 +215,7 @@ impl ChainConfigManager {
                 rpc_url: None,
                 explorer_url: Some("https://tonscan.org".to_string()),
                 default_gas_limit: 0, // TON使用gas_units
+                confirmations: ConfirmationPolicy::default_for(ChainType::TON),
             },
         );
 
@@ -220,6 +299,7 @@ impl ChainConfigManager {
                                 rpc_url,
                                 explorer_url,
                                 default_gas_limit,
+                                confirmations: ConfirmationPolicy::default_for(chain_type),
                             },
                         );
                     }
@@ -262,6 +342,26 @@ impl ChainConfigManager {
     pub fn get_default_gas_limit(&self, chain: ChainType) -> Result<u64> {
         Ok(self.get_config(chain)?.default_gas_limit)
     }
+
+    /// 获取确认数要求（未配置的链使用内置默认值）
+    pub fn confirmation_policy(&self, chain: ChainType) -> ConfirmationPolicy {
+        self.get_config(chain)
+            .map(|c| c.confirmations.clone())
+            .unwrap_or_else(|_| ConfirmationPolicy::default_for(chain))
+    }
+
+    /// 交易所需确认数
+    ///
+    /// `overrides` 为用户在高级设置中按链（`ChainType::as_str`）自定义的确认数。
+    pub fn required_confirmations(
+        &self,
+        chain: ChainType,
+        value_usd: Option<f64>,
+        overrides: &HashMap<String, u32>,
+    ) -> u32 {
+        self.confirmation_policy(chain)
+            .required(value_usd, overrides.get(chain.as_str()).copied())
+    }
 }
 
 impl Default for ChainConfigManager {
@@ -279,7 +379,288 @@ pub fn network_to_chain_id(network: &str) -> Option<u64> {
         "polygon" | "matic" => Some(137),
         "arbitrum" | "arb" => Some(42161),
         "optimism" | "op" => Some(10),
+        "base" => Some(8453),
         "avalanche" | "avax" => Some(43114),
         _ => None,
     }
 }
+
+/// L2 链的 L1 数据费模型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum L2FeeModel {
+    /// OP Stack（Optimism、Base 等）：GasPriceOracle 在执行费之外单独收取 L1 数据费
+    OpStack,
+    /// Arbitrum：L1 数据费折算为 L2 gas，计入 gas limit
+    Arbitrum,
+}
+
+/// 需要计入 L1 数据费的 L2 链（chain id → 费用模型）
+const L2_FEE_MODELS: &[(u64, L2FeeModel)] = &[
+    (10, L2FeeModel::OpStack),
+    (8453, L2FeeModel::OpStack),
+    (42161, L2FeeModel::Arbitrum),
+];
+
+/// 网络的 L1 数据费模型（非 L2 链返回 None）
+pub fn l2_fee_model(network: &str) -> Option<L2FeeModel> {
+    let chain_id = network_to_chain_id(network)?;
+    L2_FEE_MODELS
+        .iter()
+        .find(|(id, _)| *id == chain_id)
+        .map(|(_, model)| *model)
+}
+
+/// 所有支持的链（选择器按此顺序展示）
+pub const ALL_CHAINS: [ChainType; 6] = [
+    ChainType::Ethereum,
+    ChainType::BSC,
+    ChainType::Polygon,
+    ChainType::Bitcoin,
+    ChainType::Solana,
+    ChainType::TON,
+];
+
+/// 链分组（选择器按分组展示）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainGroup {
+    Evm,
+    Bitcoin,
+    Solana,
+    Ton,
+}
+
+impl ChainGroup {
+    pub const ALL: [ChainGroup; 4] = [
+        ChainGroup::Evm,
+        ChainGroup::Bitcoin,
+        ChainGroup::Solana,
+        ChainGroup::Ton,
+    ];
+
+    pub fn of(chain: ChainType) -> Self {
+        match chain {
+            ChainType::Ethereum | ChainType::BSC | ChainType::Polygon => ChainGroup::Evm,
+            ChainType::Bitcoin => ChainGroup::Bitcoin,
+            ChainType::Solana => ChainGroup::Solana,
+            ChainType::TON => ChainGroup::Ton,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChainGroup::Evm => "EVM",
+            ChainGroup::Bitcoin => "Bitcoin",
+            ChainGroup::Solana => "Solana",
+            ChainGroup::Ton => "TON",
+        }
+    }
+}
+
+/// 按链区分可用性的功能
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainFeature {
+    Send,
+    Receive,
+    Swap,
+    Bridge,
+}
+
+impl ChainFeature {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChainFeature::Send => "转账",
+            ChainFeature::Receive => "收款",
+            ChainFeature::Swap => "兑换",
+            ChainFeature::Bridge => "跨链桥",
+        }
+    }
+}
+
+/// 远程停用某条链时使用的功能开关键（如 `chain_ton`）
+pub fn chain_flag_key(chain: ChainType) -> String {
+    format!("chain_{}", chain.as_str())
+}
+
+impl ChainConfigManager {
+    /// 链在指定功能下不可用的原因（None 表示可用）
+    ///
+    /// `flag_off` 判断某个功能开关是否被显式关闭，未配置的开关视为不限制。
+    pub fn unavailable_reason(
+        &self,
+        chain: ChainType,
+        feature: ChainFeature,
+        flag_off: &dyn Fn(&str) -> bool,
+    ) -> Option<String> {
+        if self.get_config(chain).is_err() {
+            return Some(format!("{} 网络未配置", chain.label()));
+        }
+        if flag_off(&chain_flag_key(chain)) {
+            return Some(format!("{} 网络暂时停用", chain.label()));
+        }
+        // 兑换与跨链桥目前仅支持 EVM 链
+        let evm_only = matches!(feature, ChainFeature::Swap | ChainFeature::Bridge);
+        if evm_only && ChainGroup::of(chain) != ChainGroup::Evm {
+            return Some(format!("{} 暂不支持{}", chain.label(), feature.label()));
+        }
+        None
+    }
+}
+
+/// 部署在多条链上的常见代币（兑换时可手动切换所在链）
+const MULTICHAIN_TOKENS: &[(&str, &[ChainType])] = &[
+    (
+        "USDT",
+        &[ChainType::Ethereum, ChainType::BSC, ChainType::Polygon],
+    ),
+    (
+        "USDC",
+        &[ChainType::Ethereum, ChainType::BSC, ChainType::Polygon],
+    ),
+    (
+        "DAI",
+        &[ChainType::Ethereum, ChainType::BSC, ChainType::Polygon],
+    ),
+    ("WBTC", &[ChainType::Ethereum, ChainType::Polygon]),
+    ("WETH", &[ChainType::Ethereum, ChainType::Polygon]),
+    (
+        "LINK",
+        &[ChainType::Ethereum, ChainType::BSC, ChainType::Polygon],
+    ),
+];
+
+/// 代币所在的所有链（非多链代币返回空）
+pub fn token_chains(symbol: &str) -> &'static [ChainType] {
+    MULTICHAIN_TOKENS
+        .iter()
+        .find(|(s, _)| s.eq_ignore_ascii_case(symbol))
+        .map_or(&[], |(_, chains)| chains)
+}
+
+/// 原生币的包装代币（与原生币 1:1 兑换）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrappedNative {
+    pub symbol: &'static str,
+    pub address: &'static str,
+}
+
+/// 各链原生币对应的包装代币合约
+const WRAPPED_NATIVE: &[(ChainType, WrappedNative)] = &[
+    (
+        ChainType::Ethereum,
+        WrappedNative {
+            symbol: "WETH",
+            address: "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+        },
+    ),
+    (
+        ChainType::BSC,
+        WrappedNative {
+            symbol: "WBNB",
+            address: "0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c",
+        },
+    ),
+    (
+        ChainType::Polygon,
+        WrappedNative {
+            symbol: "WMATIC",
+            address: "0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270",
+        },
+    ),
+    (
+        ChainType::Solana,
+        WrappedNative {
+            symbol: "WSOL",
+            address: "So11111111111111111111111111111111111111112",
+        },
+    ),
+];
+
+/// 链上原生币的包装代币（没有包装代币的链返回 None）
+pub fn wrapped_native(chain: ChainType) -> Option<WrappedNative> {
+    WRAPPED_NATIVE
+        .iter()
+        .find(|(c, _)| *c == chain)
+        .map(|(_, wrapped)| *wrapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn availability_combines_capabilities_and_flags() {
+        let manager = ChainConfigManager::new();
+        let no_flags = |_: &str| false;
+        assert_eq!(
+            manager.unavailable_reason(ChainType::TON, ChainFeature::Bridge, &no_flags),
+            Some("TON 暂不支持跨链桥".to_string())
+        );
+        assert!(manager
+            .unavailable_reason(ChainType::TON, ChainFeature::Receive, &no_flags)
+            .is_none());
+        assert!(manager
+            .unavailable_reason(ChainType::Polygon, ChainFeature::Bridge, &no_flags)
+            .is_none());
+
+        let ton_off = |key: &str| key == "chain_ton";
+        assert_eq!(
+            manager.unavailable_reason(ChainType::TON, ChainFeature::Send, &ton_off),
+            Some("TON 网络暂时停用".to_string())
+        );
+
+        assert_eq!(token_chains("usdt").len(), 3);
+        assert!(token_chains("ETH").is_empty());
+
+        assert_eq!(
+            wrapped_native(ChainType::BSC).map(|w| w.symbol),
+            Some("WBNB")
+        );
+        assert!(wrapped_native(ChainType::Bitcoin).is_none());
+
+        assert_eq!(l2_fee_model("Base"), Some(L2FeeModel::OpStack));
+        assert_eq!(l2_fee_model("arbitrum"), Some(L2FeeModel::Arbitrum));
+        assert_eq!(l2_fee_model("ethereum"), None);
+    }
+
+    #[test]
+    fn confirmations_use_overrides_within_bounds_and_escalate_by_value() {
+        let manager = ChainConfigManager::new();
+        let mut overrides = HashMap::new();
+        assert_eq!(
+            manager.required_confirmations(ChainType::Ethereum, None, &overrides),
+            12
+        );
+        assert_eq!(
+            manager.required_confirmations(ChainType::Bitcoin, Some(500.0), &overrides),
+            2
+        );
+        assert_eq!(
+            manager.required_confirmations(ChainType::Bitcoin, Some(25_000.0), &overrides),
+            6
+        );
+        assert_eq!(
+            manager.required_confirmations(ChainType::Bitcoin, Some(250_000.0), &overrides),
+            12
+        );
+
+        // 自定义值被限制在安全范围内
+        overrides.insert("ethereum".to_string(), 1);
+        assert_eq!(
+            manager.required_confirmations(ChainType::Ethereum, None, &overrides),
+            3
+        );
+        overrides.insert("ethereum".to_string(), 1_000);
+        assert_eq!(
+            manager.required_confirmations(ChainType::Ethereum, None, &overrides),
+            64
+        );
+        // 调低后大额交易仍按档位升级
+        overrides.insert("ethereum".to_string(), 6);
+        assert_eq!(
+            manager.required_confirmations(ChainType::Ethereum, Some(50_000.0), &overrides),
+            32
+        );
+
+        assert_eq!(confirmation_progress(3, 12), "3 / 12 个确认");
+    }
+}
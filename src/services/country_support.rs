@@ -25,6 +25,167 @@ pub struct ProviderCountrySupport {
     pub last_synced: String,
 }
 
+/// 受限司法辖区（法币充值/提现不可用，兑换不受影响）
+const RESTRICTED_JURISDICTIONS: [&str; 4] = ["KP", "IR", "CU", "SY"];
+
+/// 仅在特定国家/地区可用的支付方式
+pub const REGIONAL_METHODS: [(&str, &[&str]); 2] = [("alipay", &["CN"]), ("wechat_pay", &["CN"])];
+
+/// 购买稳定币支持的全部支付方式
+pub const BUY_METHODS: [&str; 6] = [
+    "credit_card",
+    "paypal",
+    "apple_pay",
+    "google_pay",
+    "alipay",
+    "wechat_pay",
+];
+
+/// 提现支持的全部方式
+pub const WITHDRAW_METHODS: [&str; 6] = [
+    "bank_card",
+    "paypal",
+    "apple_pay",
+    "google_pay",
+    "alipay",
+    "wechat_pay",
+];
+
+/// 检测到的国家/地区
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedCountry {
+    pub country_code: String, // ISO 3166-1 alpha-2
+    #[serde(default)]
+    pub country_name: String,
+}
+
+/// 某国家/地区可用的支付方式矩阵
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentMethodMatrix {
+    pub country_code: String,
+    #[serde(default)]
+    pub country_name: String,
+    /// 是否为受限司法辖区（法币功能整体不可用）
+    #[serde(default)]
+    pub restricted: bool,
+    #[serde(default)]
+    pub restriction_reason: Option<String>,
+    #[serde(default)]
+    pub buy_methods: Vec<String>,
+    #[serde(default)]
+    pub withdraw_methods: Vec<String>,
+    /// 检测方式："IP"、"Locale"、"Manual"
+    #[serde(default)]
+    pub detection_method: String,
+}
+
+/// 单个支付方式的可用性
+#[derive(Debug, Clone, PartialEq)]
+pub enum MethodAvailability {
+    Available,
+    Unavailable { reason: String },
+}
+
+impl MethodAvailability {
+    pub fn is_available(&self) -> bool {
+        matches!(self, MethodAvailability::Available)
+    }
+
+    /// 不可用原因（用于提示气泡）
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            MethodAvailability::Available => None,
+            MethodAvailability::Unavailable { reason } => Some(reason),
+        }
+    }
+}
+
+impl PaymentMethodMatrix {
+    /// 后端不可用时的本地规则：受限地区全部禁用，区域性支付方式仅限对应地区
+    pub fn fallback(country_code: &str) -> Self {
+        let code = country_code.to_uppercase();
+        let restricted = is_restricted_jurisdiction(&code);
+        let allowed = |methods: &[&str]| -> Vec<String> {
+            methods
+                .iter()
+                .filter(|m| !restricted && regional_method_allowed(m, &code))
+                .map(|m| m.to_string())
+                .collect()
+        };
+        Self {
+            buy_methods: allowed(&BUY_METHODS),
+            withdraw_methods: allowed(&WITHDRAW_METHODS),
+            country_name: code.clone(),
+            country_code: code,
+            restricted,
+            restriction_reason: restricted
+                .then(|| "根据适用的制裁与合规要求，您所在的地区暂不提供法币服务".to_string()),
+            detection_method: String::new(),
+        }
+    }
+
+    /// 购买支付方式的可用性
+    pub fn buy_availability(&self, method: &str) -> MethodAvailability {
+        self.availability(&self.buy_methods, method)
+    }
+
+    /// 提现方式的可用性
+    pub fn withdraw_availability(&self, method: &str) -> MethodAvailability {
+        self.availability(&self.withdraw_methods, method)
+    }
+
+    fn availability(&self, methods: &[String], method: &str) -> MethodAvailability {
+        if self.restricted {
+            return MethodAvailability::Unavailable {
+                reason: "您所在的地区暂不支持法币服务".to_string(),
+            };
+        }
+        if methods.iter().any(|m| m == method) {
+            return MethodAvailability::Available;
+        }
+        let region_hint = REGIONAL_METHODS
+            .iter()
+            .find(|(m, _)| *m == method)
+            .map(|(_, countries)| format!("，仅支持 {}", countries.join("/")))
+            .unwrap_or_default();
+        MethodAvailability::Unavailable {
+            reason: format!(
+                "该方式在{}暂不可用{}",
+                display_country(&self.country_name, &self.country_code),
+                region_hint
+            ),
+        }
+    }
+}
+
+fn display_country<'a>(name: &'a str, code: &'a str) -> &'a str {
+    if name.is_empty() {
+        code
+    } else {
+        name
+    }
+}
+
+/// 是否为受限司法辖区
+pub fn is_restricted_jurisdiction(country_code: &str) -> bool {
+    RESTRICTED_JURISDICTIONS.contains(&country_code.to_uppercase().as_str())
+}
+
+fn regional_method_allowed(method: &str, country_code: &str) -> bool {
+    REGIONAL_METHODS
+        .iter()
+        .find(|(m, _)| *m == method)
+        .map(|(_, countries)| countries.contains(&country_code))
+        .unwrap_or(true)
+}
+
+/// 从浏览器语言（如 "zh-CN"）推断国家代码，作为地理定位失败时的降级
+pub fn country_from_locale(locale: &str) -> Option<String> {
+    let region = locale.split(['-', '_']).nth(1)?;
+    (region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()))
+        .then(|| region.to_uppercase())
+}
+
 /// 国家支持服务
 pub struct CountrySupportService {
     api_client: Arc<ApiClient>,
@@ -69,6 +230,34 @@ impl CountrySupportService {
             })
     }
 
+    /// 通过后端地理定位检测用户国家/地区
+    pub async fn detect_country(&self) -> Result<DetectedCountry, String> {
+        self.api_client
+            .get::<DetectedCountry>("/api/v1/geo/country")
+            .await
+            .map_err(|e| format!("检测国家/地区失败：{}", e))
+    }
+
+    /// 获取国家/地区可用的支付方式矩阵
+    ///
+    /// # 参数
+    /// - `country_code`: 国家代码（ISO 3166-1 alpha-2）
+    pub async fn get_payment_methods(
+        &self,
+        country_code: &str,
+    ) -> Result<PaymentMethodMatrix, String> {
+        if country_code.is_empty() {
+            return Err("国家代码不能为空".to_string());
+        }
+
+        let url = format!("/api/v1/country-support/{}/payment-methods", country_code);
+
+        self.api_client
+            .get::<PaymentMethodMatrix>(&url)
+            .await
+            .map_err(|e| format!("获取支付方式失败：{}", e))
+    }
+
     /// 获取服务商国家支持列表
     ///
     /// # 参数
@@ -145,3 +334,40 @@ pub struct SyncResult {
     pub sync_time: String,
     pub errors: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_limits_regional_methods() {
+        let us = PaymentMethodMatrix::fallback("us");
+        assert!(us.buy_availability("credit_card").is_available());
+        assert!(!us.buy_availability("alipay").is_available());
+        assert!(us
+            .withdraw_availability("wechat_pay")
+            .reason()
+            .unwrap()
+            .contains("CN"));
+
+        let cn = PaymentMethodMatrix::fallback("CN");
+        assert!(cn.buy_availability("alipay").is_available());
+        assert!(cn.withdraw_availability("wechat_pay").is_available());
+    }
+
+    #[test]
+    fn restricted_jurisdiction_disables_everything() {
+        let matrix = PaymentMethodMatrix::fallback("IR");
+        assert!(matrix.restricted);
+        assert!(matrix.buy_methods.is_empty());
+        assert!(!matrix.withdraw_availability("bank_card").is_available());
+    }
+
+    #[test]
+    fn country_from_locale_extracts_region() {
+        assert_eq!(country_from_locale("zh-CN").as_deref(), Some("CN"));
+        assert_eq!(country_from_locale("en_us").as_deref(), Some("US"));
+        assert_eq!(country_from_locale("en"), None);
+        assert_eq!(country_from_locale("es-419"), None);
+    }
+}
@@ -0,0 +1,160 @@
+//! DApps Service - dApp 精选目录
+//! 后端维护的 dApp 目录（支持的链、分类、风险标签），前端按链和分类筛选
+
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::dapps;
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 风险标签
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DappRisk {
+    /// 经过审计、长期运行
+    Low,
+    Medium,
+    /// 未审计或近期出现过安全事件
+    High,
+    #[serde(other)]
+    Unknown,
+}
+
+impl DappRisk {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DappRisk::Low => "低风险",
+            DappRisk::Medium => "中风险",
+            DappRisk::High => "高风险",
+            DappRisk::Unknown => "未评估",
+        }
+    }
+}
+
+/// 目录中的 dApp
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DappEntry {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    /// 支持的链（如 ethereum、bsc、polygon）
+    #[serde(default)]
+    pub chains: Vec<String>,
+    #[serde(default)]
+    pub category: String,
+    pub risk: DappRisk,
+}
+
+impl DappEntry {
+    /// 只允许打开 https 链接
+    pub fn safe_url(&self) -> Option<&str> {
+        self.url
+            .starts_with("https://")
+            .then_some(self.url.as_str())
+    }
+}
+
+/// dApp 目录服务
+#[derive(Clone)]
+pub struct DappService {
+    api_client: Arc<ApiClient>,
+}
+
+impl DappService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            api_client: Arc::new(app_state.get_api_client()),
+        }
+    }
+
+    /// 精选 dApp 目录（无需登录）
+    pub async fn get_catalog(&self) -> Result<Vec<DappEntry>> {
+        self.api_client
+            .get::<Vec<DappEntry>>(dapps::CATALOG)
+            .await
+            .map_err(|e| anyhow!("获取 dApp 目录失败: {}", e))
+    }
+}
+
+/// 按链与分类筛选（None 表示不限）
+pub fn filter_catalog<'a>(
+    entries: &'a [DappEntry],
+    chain: Option<&str>,
+    category: Option<&str>,
+) -> Vec<&'a DappEntry> {
+    entries
+        .iter()
+        .filter(|e| chain.is_none_or(|c| e.chains.iter().any(|ec| ec.eq_ignore_ascii_case(c))))
+        .filter(|e| category.is_none_or(|c| e.category.eq_ignore_ascii_case(c)))
+        .collect()
+}
+
+/// 目录中出现的链与分类（按出现顺序去重）
+pub fn chains_and_categories(entries: &[DappEntry]) -> (Vec<String>, Vec<String>) {
+    let mut chains: Vec<String> = Vec::new();
+    let mut categories: Vec<String> = Vec::new();
+    for entry in entries {
+        for chain in &entry.chains {
+            let chain = chain.to_lowercase();
+            if !chains.contains(&chain) {
+                chains.push(chain);
+            }
+        }
+        if !entry.category.is_empty() && !categories.contains(&entry.category) {
+            categories.push(entry.category.clone());
+        }
+    }
+    (chains, categories)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, chains: &[&str], category: &str) -> DappEntry {
+        DappEntry {
+            id: id.to_string(),
+            name: id.to_string(),
+            url: format!("https://{}.example", id),
+            description: String::new(),
+            icon_url: None,
+            chains: chains.iter().map(|c| c.to_string()).collect(),
+            category: category.to_string(),
+            risk: DappRisk::Low,
+        }
+    }
+
+    #[test]
+    fn catalog_is_filtered_by_chain_and_category() {
+        let entries = vec![
+            entry("uni", &["ethereum", "polygon"], "DEX"),
+            entry("aave", &["Ethereum"], "Lending"),
+            entry("pancake", &["bsc"], "DEX"),
+        ];
+        let ids = |chain: Option<&str>, category: Option<&str>| -> Vec<String> {
+            filter_catalog(&entries, chain, category)
+                .into_iter()
+                .map(|e| e.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(None, None).len(), 3);
+        assert_eq!(ids(Some("ethereum"), None), vec!["uni", "aave"]);
+        assert_eq!(ids(None, Some("dex")), vec!["uni", "pancake"]);
+        assert_eq!(ids(Some("bsc"), Some("Lending")), Vec::<String>::new());
+
+        let (chains, categories) = chains_and_categories(&entries);
+        assert_eq!(chains, vec!["ethereum", "polygon", "bsc"]);
+        assert_eq!(categories, vec!["DEX", "Lending"]);
+    }
+
+    #[test]
+    fn risk_tolerates_unknown_values() {
+        let risk: DappRisk = serde_json::from_str("\"critical\"").unwrap();
+        assert_eq!(risk, DappRisk::Unknown);
+    }
+}
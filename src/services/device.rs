@@ -0,0 +1,207 @@
+//! Device Service - 可信设备服务
+//! 登记本设备公钥、获取高风险操作的签名挑战、设备列表与移除、新设备的追加验证
+
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::devices;
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 设备状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceStatus {
+    /// 已信任：高风险操作签名后直接放行
+    Trusted,
+    /// 待验证：需先完成邮箱 / TOTP 追加验证
+    Pending,
+    /// 已移除：本地密钥作废，需重新登记
+    Revoked,
+}
+
+impl DeviceStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeviceStatus::Trusted => "已信任",
+            DeviceStatus::Pending => "待验证",
+            DeviceStatus::Revoked => "已移除",
+        }
+    }
+}
+
+/// 已登记的设备
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceRecord {
+    pub id: String,
+    pub name: String,
+    pub status: DeviceStatus,
+    /// 登记时间（秒）
+    pub created_at: u64,
+    /// 最近一次签名时间（秒）
+    #[serde(default)]
+    pub last_used_at: Option<u64>,
+}
+
+/// 高风险操作的签名挑战
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DeviceChallenge {
+    pub challenge_id: String,
+    /// 需要签名的随机数（一次有效）
+    pub nonce: String,
+    /// 发起挑战时设备的状态
+    pub device_status: DeviceStatus,
+}
+
+/// 追加验证方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepUpMethod {
+    Email,
+    Totp,
+}
+
+impl StepUpMethod {
+    pub const ALL: [StepUpMethod; 2] = [StepUpMethod::Email, StepUpMethod::Totp];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StepUpMethod::Email => "邮箱验证码",
+            StepUpMethod::Totp => "身份验证器（TOTP）",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RegisterRequest<'a> {
+    public_key: &'a str,
+    algorithm: &'a str,
+    name: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct ChallengeRequest<'a> {
+    device_id: &'a str,
+    operation: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct StepUpRequest {
+    method: StepUpMethod,
+}
+
+#[derive(Debug, Serialize)]
+struct StepUpVerifyRequest<'a> {
+    method: StepUpMethod,
+    code: &'a str,
+}
+
+/// 可信设备服务
+#[derive(Clone)]
+pub struct DeviceService {
+    api_client: Arc<ApiClient>,
+}
+
+impl DeviceService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            api_client: Arc::new(app_state.get_api_client()),
+        }
+    }
+
+    /// 当前账户登记的设备
+    pub async fn list(&self) -> Result<Vec<DeviceRecord>> {
+        self.api_client
+            .get::<Vec<DeviceRecord>>(devices::LIST)
+            .await
+            .map_err(|e| anyhow!("获取设备列表失败: {}", e))
+    }
+
+    /// 登记本设备公钥（账户的第一台设备由后端直接信任，其余为待验证）
+    pub async fn register(
+        &self,
+        public_key: &str,
+        algorithm: &str,
+        name: &str,
+    ) -> Result<DeviceRecord> {
+        self.api_client
+            .post::<DeviceRecord, _>(
+                devices::REGISTER,
+                &RegisterRequest {
+                    public_key,
+                    algorithm,
+                    name,
+                },
+            )
+            .await
+            .map_err(|e| anyhow!("登记设备失败: {}", e))
+    }
+
+    /// 获取高风险操作的签名挑战
+    pub async fn challenge(&self, device_id: &str, operation: &str) -> Result<DeviceChallenge> {
+        self.api_client
+            .post::<DeviceChallenge, _>(
+                devices::CHALLENGE,
+                &ChallengeRequest {
+                    device_id,
+                    operation,
+                },
+            )
+            .await
+            .map_err(|e| anyhow!("获取设备挑战失败: {}", e))
+    }
+
+    /// 移除设备；`proof` 为本设备对该操作的签名（移除其他设备时必需）
+    pub async fn revoke(&self, device_id: &str, proof: Option<&DeviceProof>) -> Result<()> {
+        let client = match proof {
+            Some(proof) => proof.apply(&self.api_client),
+            None => (*self.api_client).clone(),
+        };
+        client
+            .delete::<serde_json::Value>(&devices::revoke(device_id))
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow!("移除设备失败: {}", e))
+    }
+
+    /// 发送追加验证码（TOTP 无需发送，直接输入验证器中的验证码）
+    pub async fn request_step_up(&self, device_id: &str, method: StepUpMethod) -> Result<()> {
+        self.api_client
+            .post::<serde_json::Value, _>(&devices::step_up(device_id), &StepUpRequest { method })
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow!("发送验证码失败: {}", e))
+    }
+
+    /// 校验追加验证码，通过后设备变为已信任
+    pub async fn verify_step_up(
+        &self,
+        device_id: &str,
+        method: StepUpMethod,
+        code: &str,
+    ) -> Result<DeviceRecord> {
+        self.api_client
+            .post::<DeviceRecord, _>(
+                &devices::step_up_verify(device_id),
+                &StepUpVerifyRequest { method, code },
+            )
+            .await
+            .map_err(|e| anyhow!("验证失败: {}", e))
+    }
+}
+
+/// 附在高风险请求上的设备签名
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceProof {
+    pub device_id: String,
+    pub challenge_id: String,
+    /// base64 编码的 ECDSA P-256 签名
+    pub signature: String,
+}
+
+impl DeviceProof {
+    /// 返回附带本签名请求头的客户端副本
+    pub fn apply(&self, client: &ApiClient) -> ApiClient {
+        client.with_device_proof(&self.device_id, &self.challenge_id, &self.signature)
+    }
+}
@@ -0,0 +1,251 @@
+//! Earn Service - 理财持仓奖励领取与复投
+//! 由后端按协议构建领取/复投交易（calldata），前端估算 Gas 并判断领取是否划算；
+//! 签名与广播在本地完成，不托管资产
+
+use crate::services::balance::native_symbol;
+use crate::services::gas::{GasService, GasSpeed};
+use crate::services::gas_limit::GasLimitService;
+use crate::services::price::PriceService;
+use crate::services::swap::SwapTransactionData;
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::earn;
+use crate::shared::state::AppState;
+use crate::shared::validation::parse_quantity;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Gas 超过奖励价值的该比例时提示"不太划算"
+pub const MARGINAL_GAS_SHARE: f64 = 0.2;
+
+/// 理财持仓
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EarnPosition {
+    pub id: String,
+    /// 协议名称（如 Aave、Lido）
+    pub protocol: String,
+    pub network: String,
+    /// 持仓所属地址
+    pub address: String,
+    pub asset: String,
+    pub staked_amount: String,
+    pub reward_asset: String,
+    /// 待领取奖励数量
+    #[serde(default)]
+    pub pending_rewards: f64,
+    /// 待领取奖励价值（美元）
+    #[serde(default)]
+    pub pending_rewards_usd: f64,
+    /// 协议是否支持复投
+    #[serde(default)]
+    pub compoundable: bool,
+}
+
+/// 奖励操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EarnAction {
+    Claim,
+    Compound,
+}
+
+impl EarnAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EarnAction::Claim => "领取奖励",
+            EarnAction::Compound => "复投奖励",
+        }
+    }
+}
+
+/// 领取是否划算（奖励价值 vs Gas 费用）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClaimEconomics {
+    Economical,
+    /// Gas 占奖励价值比例较高
+    Marginal {
+        gas_share: f64,
+    },
+    /// Gas 不低于奖励价值
+    Uneconomical,
+}
+
+impl ClaimEconomics {
+    pub fn evaluate(reward_usd: f64, gas_usd: f64) -> Self {
+        if reward_usd <= 0.0 || gas_usd >= reward_usd {
+            return ClaimEconomics::Uneconomical;
+        }
+        let gas_share = gas_usd / reward_usd;
+        if gas_share > MARGINAL_GAS_SHARE {
+            ClaimEconomics::Marginal { gas_share }
+        } else {
+            ClaimEconomics::Economical
+        }
+    }
+
+    /// 需要向用户提示的警告
+    pub fn warning(&self, reward_usd: f64, gas_usd: f64) -> Option<String> {
+        match self {
+            ClaimEconomics::Economical => None,
+            ClaimEconomics::Marginal { gas_share } => Some(format!(
+                "Gas 费用约 ${:.2}，占奖励价值（${:.2}）的 {:.0}%，建议等奖励累积更多后再操作",
+                gas_usd,
+                reward_usd,
+                gas_share * 100.0
+            )),
+            ClaimEconomics::Uneconomical => Some(format!(
+                "Gas 费用约 ${:.2}，不低于奖励价值（${:.2}），现在操作不划算",
+                gas_usd, reward_usd
+            )),
+        }
+    }
+}
+
+/// 已构建、待签名的奖励交易
+#[derive(Debug, Clone)]
+pub struct PreparedEarnTx {
+    pub action: EarnAction,
+    pub position: EarnPosition,
+    pub tx: SwapTransactionData,
+    pub chain_id: u64,
+    pub gas_limit: u64,
+    /// 单位：wei
+    pub gas_price: u64,
+    pub gas_usd: f64,
+    pub economics: ClaimEconomics,
+}
+
+impl PreparedEarnTx {
+    pub fn warning(&self) -> Option<String> {
+        self.economics
+            .warning(self.position.pending_rewards_usd, self.gas_usd)
+    }
+}
+
+/// 后端构建的交易
+#[derive(Debug, Clone, Deserialize)]
+struct BuiltTransaction {
+    chain_id: u64,
+    #[serde(flatten)]
+    tx: SwapTransactionData,
+}
+
+/// 理财服务
+#[derive(Clone)]
+pub struct EarnService {
+    app_state: AppState,
+    api_client: Arc<ApiClient>,
+}
+
+impl EarnService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            app_state,
+            api_client: Arc::new(app_state.get_api_client()),
+        }
+    }
+
+    /// 我的理财持仓
+    pub async fn get_positions(&self) -> Result<Vec<EarnPosition>> {
+        self.api_client
+            .get::<Vec<EarnPosition>>(earn::POSITIONS)
+            .await
+            .map_err(|e| anyhow!("获取理财持仓失败: {}", e))
+    }
+
+    /// 构建领取奖励交易，并估算是否划算
+    pub async fn claim_rewards(&self, position: &EarnPosition) -> Result<PreparedEarnTx> {
+        self.prepare(position, EarnAction::Claim).await
+    }
+
+    /// 构建复投交易（领取并重新存入），并估算是否划算
+    pub async fn compound(&self, position: &EarnPosition) -> Result<PreparedEarnTx> {
+        if !position.compoundable {
+            return Err(anyhow!("{} 不支持复投", position.protocol));
+        }
+        self.prepare(position, EarnAction::Compound).await
+    }
+
+    async fn prepare(&self, position: &EarnPosition, action: EarnAction) -> Result<PreparedEarnTx> {
+        let path = match action {
+            EarnAction::Claim => earn::claim(&position.id),
+            EarnAction::Compound => earn::compound(&position.id),
+        };
+        let built = self
+            .api_client
+            .post::<BuiltTransaction, _>(&path, &serde_json::json!({}))
+            .await
+            .map_err(|e| anyhow!("构建{}交易失败: {}", action.label(), e))?;
+
+        let gas_limit = match built.tx.gas.as_deref().and_then(parse_quantity) {
+            Some(limit) => GasLimitService::new(self.app_state).with_margin(built.chain_id, limit),
+            None => GasLimitService::new(self.app_state)
+                .estimate(
+                    built.chain_id,
+                    &position.address,
+                    &built.tx.to,
+                    &built.tx.value,
+                    Some(&built.tx.data),
+                )
+                .await
+                .map_err(|e| anyhow!("估算Gas失败: {}", e))?,
+        };
+        let gas_price = match built.tx.gas_price.as_deref().and_then(parse_quantity) {
+            Some(price) => price,
+            None => {
+                let estimate = GasService::new(self.app_state)
+                    .estimate(&position.network, GasSpeed::Average)
+                    .await
+                    .map_err(|e| anyhow!("获取Gas价格失败: {}", e))?;
+                (estimate.max_fee_per_gas_gwei * 1e9) as u64
+            }
+        };
+
+        let gas_native = gas_limit as f64 * gas_price as f64 / 1e18;
+        let gas_usd = PriceService::new(self.app_state)
+            .get_usd_value(native_symbol(&position.network), gas_native)
+            .await
+            .map_err(|e| anyhow!("获取价格失败: {}", e))?;
+
+        Ok(PreparedEarnTx {
+            action,
+            position: position.clone(),
+            tx: built.tx,
+            chain_id: built.chain_id,
+            gas_limit,
+            gas_price,
+            gas_usd,
+            economics: ClaimEconomics::evaluate(position.pending_rewards_usd, gas_usd),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn claim_economics_compares_reward_with_gas() {
+        assert_eq!(
+            ClaimEconomics::evaluate(100.0, 5.0),
+            ClaimEconomics::Economical
+        );
+        assert_eq!(
+            ClaimEconomics::evaluate(10.0, 5.0),
+            ClaimEconomics::Marginal { gas_share: 0.5 }
+        );
+        assert_eq!(
+            ClaimEconomics::evaluate(3.0, 5.0),
+            ClaimEconomics::Uneconomical
+        );
+        assert_eq!(
+            ClaimEconomics::evaluate(0.0, 0.0),
+            ClaimEconomics::Uneconomical
+        );
+
+        assert!(ClaimEconomics::Economical.warning(100.0, 5.0).is_none());
+        assert!(ClaimEconomics::Uneconomical
+            .warning(3.0, 5.0)
+            .unwrap()
+            .contains("不划算"));
+    }
+}
@@ -10,6 +10,14 @@ use hex;
 /// 选择器: 0xa9059cbb (前4字节)
 const ERC20_TRANSFER_SELECTOR: &[u8] = &[0xa9, 0x05, 0x9c, 0xbb];
 
+/// ERC-20 approve函数选择器
+/// function approve(address spender, uint256 amount) returns (bool)
+const ERC20_APPROVE_SELECTOR: &[u8] = &[0x09, 0x5e, 0xa7, 0xb3];
+
+/// ERC-20 allowance函数选择器
+/// function allowance(address owner, address spender) view returns (uint256)
+const ERC20_ALLOWANCE_SELECTOR: &[u8] = &[0xdd, 0x62, 0xed, 0x3e];
+
 /// ERC-20代币转账编码器
 pub struct Erc20Encoder;
 
@@ -41,6 +49,35 @@ impl Erc20Encoder {
         Ok(format!("0x{}", hex::encode(calldata)))
     }
 
+    /// 编码ERC-20 approve函数调用
+    ///
+    /// # 参数
+    /// - `spender`: 被授权地址（如DEX路由合约）
+    /// - `amount`: 授权额度（最小单位）；None 表示无限授权（uint256 最大值）
+    pub fn encode_approve(spender: &str, amount: Option<&str>) -> Result<String> {
+        let mut calldata = ERC20_APPROVE_SELECTOR.to_vec();
+        calldata.extend_from_slice(&[0u8; 12]);
+        calldata.extend_from_slice(&Self::parse_address(spender)?);
+        match amount {
+            Some(amount) => {
+                Self::parse_amount(amount)?;
+                calldata.extend_from_slice(&Self::u256_to_bytes(amount));
+            }
+            None => calldata.extend_from_slice(&[0xffu8; 32]),
+        }
+        Ok(format!("0x{}", hex::encode(calldata)))
+    }
+
+    /// 编码ERC-20 allowance查询（用于 eth_call）
+    pub fn encode_allowance(owner: &str, spender: &str) -> Result<String> {
+        let mut calldata = ERC20_ALLOWANCE_SELECTOR.to_vec();
+        for address in [owner, spender] {
+            calldata.extend_from_slice(&[0u8; 12]);
+            calldata.extend_from_slice(&Self::parse_address(address)?);
+        }
+        Ok(format!("0x{}", hex::encode(calldata)))
+    }
+
     /// 解析地址（去除0x前缀，验证长度）
     fn parse_address(address: &str) -> Result<[u8; 20]> {
         let addr_clean = address.trim_start_matches("0x");
@@ -175,6 +212,22 @@ mod tests {
         assert_eq!(calldata.len(), 2 + 8 + 64 + 64); // 0x + selector(8 hex chars) + address(64) + amount(64)
     }
 
+    #[test]
+    fn test_encode_approve_and_allowance() {
+        let router = "0x111111125421cA6dc452d289314280a0f8842A65";
+        let exact = Erc20Encoder::encode_approve(router, Some("500000000")).unwrap();
+        assert!(exact.starts_with("0x095ea7b3"));
+        assert!(exact.ends_with(&format!("{:064x}", 500_000_000u128)));
+
+        let unlimited = Erc20Encoder::encode_approve(router, None).unwrap();
+        assert!(unlimited.ends_with(&"f".repeat(64)));
+
+        let owner = "0x742d35Cc6634C0532925a3b844Bc9e8Ef5bEd1e1";
+        let query = Erc20Encoder::encode_allowance(owner, router).unwrap();
+        assert!(query.starts_with("0xdd62ed3e"));
+        assert_eq!(query.len(), 2 + 8 + 64 + 64);
+    }
+
     #[test]
     fn test_calculate_token_amount() {
         // USDT (6 decimals)
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 /// 获取当前 Unix 时间戳（秒）- WebAssembly 兼容
 fn now_timestamp() -> u64 {
-    js_sys::Date::new_0().get_time() as u64 / 1000
+    crate::shared::time::now_unix()
 }
 
 /// 错误级别
@@ -173,6 +173,19 @@ impl ErrorLogger {
     }
 }
 
+/// 本地存储中最近的错误日志（最新的在前）
+pub fn recent_stored_logs(limit: usize) -> Vec<ErrorLog> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item("error_logs").ok().flatten())
+        .and_then(|s| serde_json::from_str::<Vec<ErrorLog>>(&s).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .take(limit)
+        .collect()
+}
+
 /// 全局错误处理器
 pub fn setup_global_error_handler() {
     // 使用web_sys的ErrorEvent处理
@@ -2,7 +2,9 @@
 //! 企业级法币提现服务，集成第三方服务商API
 //! 支持自动两步流程：代币 → 稳定币 → 法币
 
+use crate::services::device::DeviceProof;
 use crate::shared::api::ApiClient;
+use crate::shared::kill_switch::{self, MoneyAction};
 use crate::shared::state::AppState;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -109,6 +111,9 @@ pub struct FiatOfframpOrderStatus {
     pub updated_at: String,
     pub completed_at: Option<String>,
     pub error_message: Option<String>,
+    /// 服务商给出的预计到账时间（如 "1-3 business days" 或 RFC3339 时间）
+    #[serde(default)]
+    pub estimated_arrival: Option<String>,
 }
 
 /// 法币提现服务
@@ -123,7 +128,7 @@ impl FiatOfframpService {
         // 调试：检查API客户端是否有token
         #[cfg(debug_assertions)]
         {
-            use tracing::{info, warn};
+            use log::{info, warn};
             if let Some(token) = api_client.get_token() {
                 info!(
                     "FiatOfframpService: API client has token (length: {})",
@@ -249,6 +254,8 @@ impl FiatOfframpService {
     /// - `withdraw_method`: 提现方式
     /// - `recipient_info`: 收款账户信息
     /// - `quote_id`: 报价ID（可选）
+    /// - `idempotency_key`: 客户端幂等键（可选，重复提交时服务端返回同一订单）
+    /// - `device_proof`: 可信设备签名（大额提现时必需）
     ///
     /// # 错误处理
     /// 返回用户友好的错误消息
@@ -262,6 +269,8 @@ impl FiatOfframpService {
         withdraw_method: &str,
         recipient_info: &str,   // 接收JSON字符串，内部转换为serde_json::Value
         quote_id: Option<&str>, // quote_id是可选的，但后端期望必需字段
+        idempotency_key: Option<&str>,
+        device_proof: Option<&DeviceProof>,
     ) -> Result<FiatOfframpOrderResponse, String> {
         // 验证输入参数
         if token.is_empty() {
@@ -293,6 +302,8 @@ impl FiatOfframpService {
             return Err("请输入收款账户信息".to_string());
         }
 
+        kill_switch::ensure_allowed(&self.api_client, MoneyAction::Withdrawal).await?;
+
         // recipient_info已经是JSON字符串，需要解析为JSON对象
         let recipient_info_json: serde_json::Value = match serde_json::from_str(recipient_info) {
             Ok(json) => json,
@@ -323,9 +334,17 @@ impl FiatOfframpService {
         };
 
         let url = "/api/v1/fiat/offramp/orders";
+        let client = match idempotency_key {
+            Some(key) => self.api_client.with_idempotency_key(key),
+            None => (*self.api_client).clone(),
+        };
+        let client = match device_proof {
+            Some(proof) => proof.apply(&client),
+            None => client,
+        };
 
         // 发送API请求
-        self.api_client
+        client
             .post::<FiatOfframpOrderResponse, CreateFiatOfframpOrderRequest>(url, &request)
             .await
             .map_err(|e| {
@@ -460,6 +479,7 @@ impl FiatOfframpService {
     /// - `status`: 订单状态筛选（可选）
     /// - `page`: 页码（可选）
     /// - `page_size`: 每页数量（可选）
+    /// - `sort_by` / `sort_order`: 服务端排序（可选，如 "created_at" / "desc"）
     ///
     /// # 错误处理
     /// 返回用户友好的错误消息
@@ -468,6 +488,8 @@ impl FiatOfframpService {
         status: Option<&str>,
         page: Option<u32>,
         page_size: Option<u32>,
+        sort_by: Option<&str>,
+        sort_order: Option<&str>,
     ) -> Result<FiatOfframpOrderListResponse, String> {
         let mut query_params = Vec::new();
 
@@ -480,6 +502,12 @@ impl FiatOfframpService {
         if let Some(ps) = page_size {
             query_params.push(format!("page_size={}", ps));
         }
+        if let Some(sb) = sort_by {
+            query_params.push(format!("sort_by={}", encode_uri_component(sb)));
+        }
+        if let Some(so) = sort_order {
+            query_params.push(format!("sort_order={}", encode_uri_component(so)));
+        }
 
         let url = if query_params.is_empty() {
             "/api/v1/fiat/offramp/orders".to_string()
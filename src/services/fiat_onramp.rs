@@ -85,6 +85,9 @@ pub struct FiatOrderStatus {
     pub updated_at: String,
     pub completed_at: Option<String>,
     pub error_message: Option<String>,
+    /// 支付截止时间（待支付订单）
+    #[serde(default)]
+    pub expires_at: Option<String>,
 }
 
 /// 法币充值服务
@@ -97,7 +100,7 @@ impl FiatOnrampService {
         let api_client = app_state.get_api_client();
 
         // 调试：检查API客户端是否有token（强制输出到console）
-        use tracing::{info, warn};
+        use log::{info, warn};
         use wasm_bindgen::prelude::*;
 
         #[wasm_bindgen]
@@ -226,9 +229,11 @@ impl FiatOnrampService {
     /// - `payment_method`: 支付方式
     /// - `wallet_address`: 接收稳定币的钱包地址（可选）
     /// - `quote_id`: 报价ID（可选）
+    /// - `idempotency_key`: 客户端幂等键（可选，重复提交时服务端返回同一订单）
     ///
     /// # 错误处理
     /// 返回用户友好的错误消息
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_order(
         &self,
         amount: &str,
@@ -237,6 +242,7 @@ impl FiatOnrampService {
         payment_method: &str,
         quote_id: &str, // 必需字段
         wallet_address: Option<&str>,
+        idempotency_key: Option<&str>,
     ) -> Result<FiatOrderResponse, String> {
         // 验证输入参数
         if amount.is_empty() {
@@ -267,9 +273,13 @@ impl FiatOnrampService {
         };
 
         let url = "/api/v1/fiat/onramp/orders";
+        let client = match idempotency_key {
+            Some(key) => self.api_client.with_idempotency_key(key),
+            None => (*self.api_client).clone(),
+        };
 
         // 发送API请求
-        self.api_client
+        client
             .post::<FiatOrderResponse, CreateFiatOrderRequest>(url, &request)
             .await
             .map_err(|e| {
@@ -402,6 +412,7 @@ impl FiatOnrampService {
     /// - `status`: 订单状态筛选（可选）
     /// - `page`: 页码（可选）
     /// - `page_size`: 每页数量（可选）
+    /// - `sort_by` / `sort_order`: 服务端排序（可选，如 "created_at" / "desc"）
     ///
     /// # 错误处理
     /// 返回用户友好的错误消息
@@ -410,6 +421,8 @@ impl FiatOnrampService {
         status: Option<&str>,
         page: Option<u32>,
         page_size: Option<u32>,
+        sort_by: Option<&str>,
+        sort_order: Option<&str>,
     ) -> Result<FiatOrderListResponse, String> {
         let mut query_params = Vec::new();
 
@@ -422,6 +435,12 @@ impl FiatOnrampService {
         if let Some(ps) = page_size {
             query_params.push(format!("page_size={}", ps));
         }
+        if let Some(sb) = sort_by {
+            query_params.push(format!("sort_by={}", encode_uri_component(sb)));
+        }
+        if let Some(so) = sort_order {
+            query_params.push(format!("sort_order={}", encode_uri_component(so)));
+        }
 
         let url = if query_params.is_empty() {
             "/api/v1/fiat/onramp/orders".to_string()
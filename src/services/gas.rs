@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::services::chain_config::{l2_fee_model, L2FeeModel};
+use crate::services::transaction::{SimulationRequest, TransactionService};
 use crate::shared::api::ApiClient;
 use crate::shared::error::AppError;
 use crate::shared::request::{CachePolicy, SmartRequestContext};
@@ -32,6 +34,68 @@ pub struct GasEstimateResponse {
     pub fast: GasEstimate,
 }
 
+impl GasSpeed {
+    /// 全部速度档位（按费用从低到高）
+    pub const ALL: [GasSpeed; 3] = [GasSpeed::Slow, GasSpeed::Average, GasSpeed::Fast];
+
+    /// 档位显示名称
+    pub fn label(self) -> &'static str {
+        match self {
+            GasSpeed::Slow => "慢速",
+            GasSpeed::Average => "标准",
+            GasSpeed::Fast => "快速",
+        }
+    }
+}
+
+/// 手动指定的 EIP-1559 费用（gwei）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ManualGasFee {
+    pub max_fee_per_gas_gwei: f64,
+    pub max_priority_fee_gwei: f64,
+}
+
+/// 手动费用超过当前“快速”档位多少倍时给出警告
+pub const MANUAL_FEE_WARN_MULTIPLIER: f64 = 3.0;
+
+/// Pure helper: validate a manual fee against the current fast tier.
+///
+/// - `Err`：输入无效，不能使用
+/// - `Ok(Some(warning))`：可以使用，但明显高于当前快速档位
+pub fn validate_manual_fee(
+    manual: &ManualGasFee,
+    fast: &GasEstimate,
+) -> Result<Option<String>, String> {
+    let max_fee = manual.max_fee_per_gas_gwei;
+    let priority = manual.max_priority_fee_gwei;
+    if !max_fee.is_finite() || max_fee <= 0.0 {
+        return Err("最大费用必须大于 0".to_string());
+    }
+    if !priority.is_finite() || priority < 0.0 {
+        return Err("优先费不能为负数".to_string());
+    }
+    if priority > max_fee {
+        return Err("优先费不能高于最大费用".to_string());
+    }
+    let limit = fast.max_fee_per_gas_gwei * MANUAL_FEE_WARN_MULTIPLIER;
+    if fast.max_fee_per_gas_gwei > 0.0 && max_fee > limit {
+        return Ok(Some(format!(
+            "最大费用 {:.2} gwei 超过当前快速档位的 {:.0} 倍（{:.2} gwei），可能多付手续费",
+            max_fee, MANUAL_FEE_WARN_MULTIPLIER, fast.max_fee_per_gas_gwei
+        )));
+    }
+    Ok(None)
+}
+
+/// Pure helper: human readable confirmation time.
+pub fn format_confirmation_time(seconds: u64) -> String {
+    if seconds < 60 {
+        format!("约 {} 秒", seconds)
+    } else {
+        format!("约 {} 分钟", seconds.div_ceil(60))
+    }
+}
+
 /// Pure helper: pick an estimate by speed from an aggregate response.
 pub fn pick_estimate(all: &GasEstimateResponse, speed: GasSpeed) -> &GasEstimate {
     match speed {
@@ -54,6 +118,129 @@ pub fn gas_fee_eth_from_max_fee_per_gas_gwei(max_fee_per_gas_gwei: f64, gas_limi
     (max_fee_per_gas_gwei * gas_limit as f64) / 1e9
 }
 
+/// OP Stack GasPriceOracle 预编译合约
+const OP_GAS_PRICE_ORACLE: &str = "0x420000000000000000000000000000000000000F";
+/// getL1Fee(bytes)
+const OP_GET_L1_FEE_SELECTOR: [u8; 4] = [0x49, 0x94, 0x8e, 0x0e];
+/// Arbitrum NodeInterface（虚拟合约，只能通过 eth_call 调用）
+const ARB_NODE_INTERFACE: &str = "0x00000000000000000000000000000000000000C8";
+/// gasEstimateL1Component(address,bool,bytes)
+const ARB_L1_COMPONENT_SELECTOR: [u8; 4] = [0x77, 0xd4, 0x88, 0xa2];
+/// 序列化交易中 calldata 以外字段（nonce、gas、to、value 等）的近似字节数
+const TX_ENVELOPE_BYTES: usize = 40;
+
+/// L2 链的 L1 数据费
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum L1DataFee {
+    /// OP Stack：在执行费之外单独收取（原生币）
+    Separate(f64),
+    /// Arbitrum：折算为 L2 gas 计入 gas limit，按 L2 gas 价格收取
+    InGasLimit(u64),
+}
+
+/// 结构化的网络费用（原生币）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeBreakdown {
+    /// L2 执行费
+    pub execution: f64,
+    /// L1 数据费（非 L2 链为 0）
+    pub data: f64,
+    pub total: f64,
+}
+
+/// Pure helper: split a network fee into execution and L1 data components.
+///
+/// Arbitrum 的 gas limit（模拟估算结果）已包含 L1 部分，从中扣出避免重复计算。
+pub fn fee_breakdown(
+    max_fee_per_gas_gwei: f64,
+    gas_limit: u64,
+    data_fee: Option<L1DataFee>,
+) -> FeeBreakdown {
+    let (execution_gas, data) = match data_fee {
+        None => (gas_limit, 0.0),
+        Some(L1DataFee::Separate(fee)) => {
+            (gas_limit, if fee.is_finite() { fee.max(0.0) } else { 0.0 })
+        }
+        Some(L1DataFee::InGasLimit(l1_gas)) => {
+            let l1_gas = l1_gas.min(gas_limit);
+            (
+                gas_limit - l1_gas,
+                gas_fee_eth_from_max_fee_per_gas_gwei(max_fee_per_gas_gwei, l1_gas),
+            )
+        }
+    };
+    let execution = gas_fee_eth_from_max_fee_per_gas_gwei(max_fee_per_gas_gwei, execution_gas);
+    FeeBreakdown {
+        execution,
+        data,
+        total: execution + data,
+    }
+}
+
+/// 附带 L1 数据费的 Gas 估算
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeEstimate {
+    pub gas: GasEstimate,
+    /// 按同一笔交易模拟估算的 gas limit
+    pub gas_limit: u64,
+    /// L1 数据费（非 L2 链为 None）
+    pub data_fee: Option<L1DataFee>,
+    /// L2 链的 L1 数据费查询失败（显示的费用会偏低）
+    pub data_fee_unavailable: bool,
+}
+
+impl FeeEstimate {
+    pub fn breakdown(&self) -> FeeBreakdown {
+        fee_breakdown(self.gas.max_fee_per_gas_gwei, self.gas_limit, self.data_fee)
+    }
+}
+
+fn abi_word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+/// ABI 编码 bytes 参数（长度 + 右侧补零到 32 字节整数倍）
+fn abi_bytes(data: &[u8]) -> Vec<u8> {
+    let mut encoded = abi_word(data.len()).to_vec();
+    encoded.extend_from_slice(data);
+    encoded.resize(32 + data.len().div_ceil(32) * 32, 0);
+    encoded
+}
+
+/// 编码 GasPriceOracle.getL1Fee 调用
+///
+/// oracle 按序列化后的交易计费，这里用 calldata 加上其余字段的近似字节数（按非零字节计）估算
+fn encode_op_l1_fee(calldata: &[u8]) -> String {
+    let mut tx = vec![0xffu8; TX_ENVELOPE_BYTES];
+    tx.extend_from_slice(calldata);
+    let mut encoded = OP_GET_L1_FEE_SELECTOR.to_vec();
+    encoded.extend_from_slice(&abi_word(32));
+    encoded.extend(abi_bytes(&tx));
+    format!("0x{}", hex::encode(encoded))
+}
+
+/// 编码 NodeInterface.gasEstimateL1Component 调用（非合约创建）
+fn encode_arb_l1_component(to: &[u8], calldata: &[u8]) -> String {
+    let mut encoded = ARB_L1_COMPONENT_SELECTOR.to_vec();
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(to);
+    encoded.extend_from_slice(&abi_word(0));
+    encoded.extend_from_slice(&abi_word(96));
+    encoded.extend(abi_bytes(calldata));
+    format!("0x{}", hex::encode(encoded))
+}
+
+/// 返回数据的第一个 32 字节字（超出 u128 视为无效）
+fn first_word(return_data: &str) -> Option<u128> {
+    let word = return_data.trim().trim_start_matches("0x").get(..64)?;
+    if word[..32].chars().any(|c| c != '0') {
+        return None;
+    }
+    u128::from_str_radix(&word[32..], 16).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +274,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn manual_fee_validation_warns_above_fast_multiple() {
+        let fast = dummy_est(10.0, 30);
+        let fee = |max, priority| ManualGasFee {
+            max_fee_per_gas_gwei: max,
+            max_priority_fee_gwei: priority,
+        };
+
+        assert_eq!(validate_manual_fee(&fee(20.0, 2.0), &fast), Ok(None));
+        assert!(validate_manual_fee(&fee(31.0, 2.0), &fast)
+            .unwrap()
+            .is_some());
+        assert!(validate_manual_fee(&fee(0.0, 0.0), &fast).is_err());
+        assert!(validate_manual_fee(&fee(5.0, 6.0), &fast).is_err());
+        assert!(validate_manual_fee(&fee(f64::NAN, 1.0), &fast).is_err());
+    }
+
+    #[test]
+    fn fee_breakdown_adds_separate_data_fee_and_splits_arbitrum_gas() {
+        let plain = fee_breakdown(10.0, 21_000, None);
+        assert_eq!(plain.data, 0.0);
+        assert_eq!(plain.total, plain.execution);
+
+        let op = fee_breakdown(0.01, 21_000, Some(L1DataFee::Separate(0.0002)));
+        assert!((op.execution - 0.00000021).abs() < 1e-12);
+        assert!((op.total - 0.00020021).abs() < 1e-12);
+
+        // Arbitrum：L1 部分已包含在 gas limit 中，总额不变
+        let arb = fee_breakdown(0.1, 500_000, Some(L1DataFee::InGasLimit(400_000)));
+        assert!((arb.data - 0.00004).abs() < 1e-12);
+        assert!((arb.total - 0.00005).abs() < 1e-12);
+        let capped = fee_breakdown(0.1, 100_000, Some(L1DataFee::InGasLimit(400_000)));
+        assert_eq!(capped.execution, 0.0);
+    }
+
+    #[test]
+    fn l1_fee_calls_are_abi_encoded() {
+        let op = encode_op_l1_fee(&[0xab; 4]);
+        // selector + offset + length + 1 个数据字
+        assert_eq!(op.len(), 2 + (4 + 32 * 4) * 2);
+        assert!(op.starts_with("0x49948e0e"));
+        assert!(op.contains(&format!("{:064x}", TX_ENVELOPE_BYTES + 4)));
+
+        let arb = encode_arb_l1_component(&[0x11; 20], &[]);
+        assert!(arb.starts_with("0x77d488a2000000000000000000000000111111"));
+        assert!(arb.ends_with(&format!("{:064x}{:064x}", 96, 0)));
+
+        let word = format!("0x{:064x}{:064x}", 12_345, 7);
+        assert_eq!(first_word(&word), Some(12_345));
+        assert_eq!(first_word("0x1234"), None);
+        assert_eq!(first_word(&format!("0x1{:063x}", 0)), None);
+    }
+
+    #[test]
+    fn confirmation_time_rounds_up_to_minutes() {
+        assert_eq!(format_confirmation_time(45), "约 45 秒");
+        assert_eq!(format_confirmation_time(60), "约 1 分钟");
+        assert_eq!(format_confirmation_time(181), "约 4 分钟");
+    }
+
     #[test]
     fn pick_estimate_selects_expected_tier() {
         let all = GasEstimateResponse {
@@ -170,4 +417,75 @@ impl GasService {
         // 这是最平衡的选择，兼顾速度和成本
         self.estimate(chain, GasSpeed::Average).await
     }
+
+    /// 查询 L2 链的 L1 数据费（非 L2 链返回 None）
+    ///
+    /// 以 eth_call 调用链上的费用合约：OP Stack 为 GasPriceOracle，Arbitrum 为 NodeInterface
+    pub async fn l1_data_fee(&self, tx: &SimulationRequest) -> Result<Option<L1DataFee>, AppError> {
+        let Some(model) = l2_fee_model(&tx.chain) else {
+            return Ok(None);
+        };
+        let calldata = hex::decode(tx.data.trim_start_matches("0x"))
+            .map_err(|e| AppError::Validation(format!("交易数据格式无效: {}", e)))?;
+        let (contract, data) = match model {
+            L2FeeModel::OpStack => (OP_GAS_PRICE_ORACLE, encode_op_l1_fee(&calldata)),
+            L2FeeModel::Arbitrum => {
+                let to = hex::decode(tx.to.trim_start_matches("0x"))
+                    .ok()
+                    .filter(|to| to.len() == 20)
+                    .ok_or_else(|| AppError::Validation(format!("地址格式无效: {}", tx.to)))?;
+                (ARB_NODE_INTERFACE, encode_arb_l1_component(&to, &calldata))
+            }
+        };
+        let result = TransactionService::new(self.app_state)
+            .simulate(&SimulationRequest {
+                chain: tx.chain.clone(),
+                from: tx.from.clone(),
+                to: contract.to_string(),
+                value: "0".to_string(),
+                data,
+            })
+            .await?;
+        if !result.success {
+            return Err(AppError::Validation(format!(
+                "查询 L1 数据费失败: {}",
+                result.revert_reason.unwrap_or_default()
+            )));
+        }
+        let word = result
+            .return_data
+            .as_deref()
+            .and_then(first_word)
+            .ok_or_else(|| AppError::Validation("L1 数据费返回数据无效".to_string()))?;
+        Ok(Some(match model {
+            L2FeeModel::OpStack => L1DataFee::Separate(word as f64 / 1e18),
+            L2FeeModel::Arbitrum => L1DataFee::InGasLimit(u64::try_from(word).unwrap_or(u64::MAX)),
+        }))
+    }
+
+    /// 估算一笔交易的完整网络费用（执行费 + L2 链的 L1 数据费）
+    ///
+    /// `gas_limit` 应为同一笔交易（`tx`）的模拟估算结果：L1 数据费随 calldata 大小变化，
+    /// Arbitrum 的 L1 部分也包含在其中。数据费查询失败时只计执行费，并标记 `data_fee_unavailable`
+    pub async fn estimate_fee(
+        &self,
+        tx: &SimulationRequest,
+        speed: GasSpeed,
+        gas_limit: u64,
+    ) -> Result<FeeEstimate, AppError> {
+        let gas = self.estimate(&tx.chain, speed).await?;
+        let (data_fee, data_fee_unavailable) = match self.l1_data_fee(tx).await {
+            Ok(fee) => (fee, false),
+            Err(e) => {
+                log::warn!("获取 L1 数据费失败: {:?}", e);
+                (None, true)
+            }
+        };
+        Ok(FeeEstimate {
+            gas,
+            gas_limit,
+            data_fee,
+            data_fee_unavailable,
+        })
+    }
 }
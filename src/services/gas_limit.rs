@@ -1,11 +1,46 @@
 //! Gas Limit Estimation Service - 企业级Gas Limit估算服务
 //! 从后端API获取精确的Gas Limit估算，移除硬编码
+//!
+//! 所有估算值都乘以安全余量（设置中可调，默认 1.2×）并以区块 Gas 上限封顶，
+//! 避免估算略低时交易因 Gas 不足失败；使用保守默认值时记录到性能指标中。
 
 use crate::shared::api::ApiClient;
 use crate::shared::error::AppError;
+use crate::shared::metrics;
 use crate::shared::state::AppState;
+use dioxus::prelude::ReadableExt;
 use urlencoding::encode;
 
+/// 可选的 Gas 上限安全余量（百分比）
+pub const GAS_LIMIT_BUFFER_OPTIONS: [u32; 4] = [10, 20, 50, 100];
+/// 默认安全余量：估算值 × 1.2
+pub const DEFAULT_GAS_LIMIT_BUFFER_PERCENT: u32 = 20;
+/// 因 Gas 不足失败后重试时，在原上限基础上增加的比例
+pub const OUT_OF_GAS_RETRY_PERCENT: u32 = 50;
+
+/// 各链的区块 Gas 上限（单笔交易不能超过）
+pub fn block_gas_limit(chain_id: u64) -> u64 {
+    match chain_id {
+        56 | 97 => 140_000_000, // BSC
+        _ => 30_000_000,        // Ethereum / Polygon 及其测试网
+    }
+}
+
+/// 估算值加上安全余量，并以区块 Gas 上限封顶
+pub fn apply_safety_margin(estimate: u64, buffer_percent: u32, block_limit: u64) -> u64 {
+    let buffered = estimate.saturating_add(estimate.saturating_mul(buffer_percent as u64) / 100);
+    buffered.min(block_limit)
+}
+
+/// Gas 不足失败后重试使用的上限
+pub fn retry_gas_limit(previous_limit: u64, chain_id: u64) -> u64 {
+    apply_safety_margin(
+        previous_limit,
+        OUT_OF_GAS_RETRY_PERCENT,
+        block_gas_limit(chain_id),
+    )
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GasLimitEstimate {
     pub gas_limit: u64,
@@ -38,6 +73,23 @@ impl GasLimitService {
         self.app_state.get_api_client()
     }
 
+    /// 对任意来源的估算值（后端、聚合器返回的 `gas` 字段等）应用用户设置的安全余量
+    pub fn with_margin(&self, chain_id: u64, estimate: u64) -> u64 {
+        let buffer = self.app_state.preferences.peek().gas_limit_buffer_percent;
+        apply_safety_margin(estimate, buffer, block_gas_limit(chain_id))
+    }
+
+    /// 记录一次使用保守默认值的情况
+    pub fn record_fallback(chain_id: u64, fallback: u64, reason: &str) {
+        log::warn!(
+            "Gas Limit使用保守默认值: chain_id={}, gas_limit={}, 原因: {}",
+            chain_id,
+            fallback,
+            reason
+        );
+        metrics::record_gas_limit_fallback();
+    }
+
     /// 估算Gas Limit（从后端API）
     ///
     /// # Arguments
@@ -48,7 +100,7 @@ impl GasLimitService {
     /// * `data` - 交易数据（可选，用于ERC-20等合约调用）
     ///
     /// # Returns
-    /// Gas Limit估算值
+    /// 已加上安全余量的Gas Limit估算值
     pub async fn estimate(
         &self,
         chain_id: u64,
@@ -81,14 +133,13 @@ impl GasLimitService {
         }
 
         match api.get::<FeesApiResponse>(&path).await {
-            Ok(response) => Ok(response.gas_limit),
+            Ok(response) => Ok(self.with_margin(chain_id, response.gas_limit)),
             Err(e) => {
                 // 企业级实现：降级策略（API失败时使用保守估算）
                 // 多级降级策略：
                 // 1. 优先从环境变量读取链特定的默认值
                 // 2. 降级：从环境变量读取通用默认值
                 // 3. 最终降级：使用安全默认值（仅作为最后保障）
-                log::warn!("Gas Limit估算API调用失败: {}，使用保守估算", e);
 
                 // 根据是否有data判断是合约调用还是普通转账
                 let default_gas = if data.is_some() {
@@ -140,12 +191,13 @@ impl GasLimitService {
                         })
                 };
 
-                Ok(default_gas)
+                Self::record_fallback(chain_id, default_gas, &e.to_string());
+                Ok(self.with_margin(chain_id, default_gas))
             }
         }
     }
 
-    /// 获取完整的Gas Limit估算（包含Gas价格和费用）
+    /// 获取完整的Gas Limit估算（包含Gas价格和费用，Gas Limit已加上安全余量）
     pub async fn estimate_full(
         &self,
         chain_id: u64,
@@ -178,7 +230,7 @@ impl GasLimitService {
         let response: FeesApiResponse = api.get(&path).await?;
 
         Ok(GasLimitEstimate {
-            gas_limit: response.gas_limit,
+            gas_limit: self.with_margin(chain_id, response.gas_limit),
             gas_price: response.gas_price,
             estimated_fee: response.fee.unwrap_or_else(|| "0".to_string()),
         })
@@ -186,3 +238,17 @@ impl GasLimitService {
 }
 
 use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_is_applied_and_capped_at_block_limit() {
+        assert_eq!(apply_safety_margin(100_000, 20, 30_000_000), 120_000);
+        assert_eq!(apply_safety_margin(21_000, 0, 30_000_000), 21_000);
+        assert_eq!(apply_safety_margin(29_000_000, 20, 30_000_000), 30_000_000);
+        assert_eq!(retry_gas_limit(120_000, 1), 180_000);
+        assert_eq!(retry_gas_limit(25_000_000, 1), 30_000_000);
+    }
+}
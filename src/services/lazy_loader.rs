@@ -1,8 +1,11 @@
 //! Lazy Loader Service - 懒加载服务
 //! 提供组件和资源的懒加载功能
 
+use crate::shared::time;
 use dioxus::prelude::*;
+use js_sys::Reflect;
 use std::sync::Arc;
+use wasm_bindgen::JsValue;
 
 /// 懒加载组件包装器
 pub struct LazyLoader;
@@ -47,7 +50,7 @@ impl LazyLoader {
         let last_run = Arc::new(std::sync::Mutex::new(None));
 
         move || {
-            let now = js_sys::Date::now() as u64;
+            let now = time::now_ms() as u64;
             let mut last = last_run.lock().unwrap();
 
             if let Some(last_time) = *last {
@@ -63,4 +66,38 @@ impl LazyLoader {
             });
         }
     }
+
+    /// 等待浏览器空闲（requestIdleCallback 的 WASM 安全封装）
+    ///
+    /// 不支持 requestIdleCallback 的浏览器（如 Safari）退化为 setTimeout，
+    /// `timeout_ms` 为最长等待时间，避免页面持续繁忙时任务永远不执行
+    pub async fn wait_for_idle(timeout_ms: u32) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            let idle_fn = Reflect::get(&window, &JsValue::from_str("requestIdleCallback"))
+                .ok()
+                .filter(|f| f.is_function());
+
+            match idle_fn {
+                Some(idle_fn) => {
+                    let options = js_sys::Object::new();
+                    let _ = Reflect::set(
+                        &options,
+                        &JsValue::from_str("timeout"),
+                        &JsValue::from(timeout_ms),
+                    );
+                    let _ = js_sys::Function::from(idle_fn).call2(&window, &resolve, &options);
+                }
+                None => {
+                    let _ =
+                        window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 1);
+                }
+            }
+        });
+
+        let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+    }
 }
@@ -57,7 +57,7 @@ pub struct CreateLimitOrderRequest {
 }
 
 /// 限价单响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LimitOrderResponse {
     /// 订单ID
     pub order_id: String,
@@ -86,6 +86,18 @@ pub struct LimitOrderResponse {
     pub message: Option<String>,
 }
 
+/// 修改限价单请求（价格与数量原子更新）
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateLimitOrderRequest {
+    /// 新限价
+    pub limit_price: String,
+    /// 新数量
+    pub amount: String,
+}
+
+/// 服务端不支持原子修改时 `update_order` 返回的错误（调用方据此改用取消后重建）
+pub const UPDATE_UNSUPPORTED: &str = "服务端暂不支持直接修改限价单";
+
 /// 限价单列表查询请求
 #[derive(Debug, Clone, Serialize)]
 pub struct LimitOrderQuery {
@@ -101,6 +113,12 @@ pub struct LimitOrderQuery {
     /// 每页数量
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_size: Option<u32>,
+    /// 排序字段：created_at / amount / status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    /// 排序方向：asc / desc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<String>,
 }
 
 /// 限价单列表响应
@@ -221,6 +239,8 @@ impl LimitOrderService {
             status: None,
             page: Some(1),
             page_size: Some(20),
+            sort_by: None,
+            sort_order: None,
         });
 
         // 构建查询字符串
@@ -237,6 +257,12 @@ impl LimitOrderService {
         if let Some(page_size) = query.page_size {
             query_params.push(format!("page_size={}", page_size));
         }
+        if let Some(sort_by) = &query.sort_by {
+            query_params.push(format!("sort_by={}", sort_by));
+        }
+        if let Some(sort_order) = &query.sort_order {
+            query_params.push(format!("sort_order={}", sort_order));
+        }
 
         // 确保URL格式正确，去除末尾的&符号
         let url = if query_params.is_empty() {
@@ -324,4 +350,101 @@ impl LimitOrderService {
                 }
             })
     }
+
+    /// 延长限价单有效期（需要服务端支持）
+    pub async fn update_expiry(
+        &self,
+        order_id: &str,
+        expiry_days: u32,
+    ) -> Result<LimitOrderResponse, String> {
+        if order_id.is_empty() {
+            return Err("订单ID不能为空".to_string());
+        }
+        if expiry_days == 0 || expiry_days > 365 {
+            return Err("过期天数必须在1-365之间".to_string());
+        }
+
+        let url = format!("/api/v1/limit-orders/{}/expiry", order_id);
+
+        self.get_api_client()
+            .put::<LimitOrderResponse, serde_json::Value>(
+                &url,
+                &serde_json::json!({ "expiry_days": expiry_days }),
+            )
+            .await
+            .map_err(|e| {
+                let error_msg = e.to_string().to_lowercase();
+                if error_msg.contains("not found") || error_msg.contains("404") {
+                    "限价单不存在".to_string()
+                } else if error_msg.contains("405")
+                    || error_msg.contains("501")
+                    || error_msg.contains("not supported")
+                    || error_msg.contains("not implemented")
+                {
+                    "服务端暂不支持延长限价单有效期".to_string()
+                } else if error_msg.contains("status") {
+                    "该限价单无法延期（可能已执行或已过期）".to_string()
+                } else if error_msg.contains("network") || error_msg.contains("connection") {
+                    "网络错误，请稍后重试".to_string()
+                } else {
+                    format!("延长限价单有效期失败：{}", e)
+                }
+            })
+    }
+
+    /// 原子修改挂单的限价与数量（需要服务端支持，不支持时返回 `UPDATE_UNSUPPORTED`）
+    pub async fn update_order(
+        &self,
+        order_id: &str,
+        new_price: &str,
+        new_amount: &str,
+    ) -> Result<LimitOrderResponse, String> {
+        if order_id.is_empty() {
+            return Err("订单ID不能为空".to_string());
+        }
+        if new_amount.is_empty() || new_amount.parse::<f64>().unwrap_or(0.0) <= 0.0 {
+            return Err("数量必须大于0".to_string());
+        }
+        if new_price.is_empty() || new_price.parse::<f64>().unwrap_or(0.0) <= 0.0 {
+            return Err("限价必须大于0".to_string());
+        }
+
+        let url = format!("/api/v1/limit-orders/{}", order_id);
+        let request = UpdateLimitOrderRequest {
+            limit_price: new_price.to_string(),
+            amount: new_amount.to_string(),
+        };
+
+        match self
+            .get_api_client()
+            .put::<LimitOrderResponse, UpdateLimitOrderRequest>(&url, &request)
+            .await
+        {
+            Ok(resp) => Ok(resp),
+            Err(e) => {
+                if crate::shared::auth_handler::is_unauthorized_error(&e) {
+                    crate::shared::auth_handler::handle_unauthorized_and_redirect(self.app_state);
+                }
+
+                let error_msg = e.to_string().to_lowercase();
+                if error_msg.contains("405")
+                    || error_msg.contains("501")
+                    || error_msg.contains("not supported")
+                    || error_msg.contains("not implemented")
+                {
+                    Err(UPDATE_UNSUPPORTED.to_string())
+                } else if error_msg.contains("not found") || error_msg.contains("404") {
+                    Err("限价单不存在".to_string())
+                } else if error_msg.contains("insufficient") || error_msg.contains("balance") {
+                    Err("余额不足，无法修改限价单".to_string())
+                } else if error_msg.contains("status") {
+                    Err("该限价单无法修改（可能已执行或已过期）".to_string())
+                } else if error_msg.contains("network") || error_msg.contains("connection") {
+                    Err("网络错误，请稍后重试".to_string())
+                } else {
+                    Err(format!("修改限价单失败：{}", e))
+                }
+            }
+        }
+    }
 }
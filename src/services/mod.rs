@@ -1,4 +1,5 @@
 pub mod address_detector;
+pub mod address_validation;
 pub mod auth;
 pub mod balance;
 pub mod bridge;
@@ -14,7 +15,10 @@ pub mod storage;
 pub mod swap;
 pub mod token;
 pub mod token_detection;
+pub mod token_logo;
+pub mod token_metadata;
 pub mod transaction;
+pub mod tx_propagation;
 pub mod tx_simple;
 pub mod validation;
 pub mod wallet;
@@ -30,6 +34,7 @@ pub mod fiat_onramp;
 pub mod transaction_history;
 
 // 用户服务
+pub mod preferences; // 用户偏好（单一版本化存储、旧键迁移、可选后端同步）
 pub mod user;
 
 // 智能服务商选择服务
@@ -42,10 +47,17 @@ pub mod limit_order;
 pub mod audit_log;
 pub mod cache;
 pub mod country_support;
+pub mod dapps; // dApp 精选目录（分类、风险标签）
+pub mod device; // 可信设备（公钥登记、挑战签名、追加验证）
+pub mod earn; // 理财持仓（奖励领取、复投）
 pub mod error_logger;
 pub mod error_reporter;
 pub mod lazy_loader;
-// pub mod payment_gateway; // 支付网关集成服务 - TODO: 需要实现
+pub mod password_breach; // 泄露密码检查（HIBP k-匿名区间查询）
+pub mod payment_gateway; // 支付网关集成服务（银行卡令牌化、已保存支付方式）
 pub mod reconciliation;
+pub mod referral; // 邀请返佣（邀请码、邀请记录与奖励）
+pub mod status; // 服务状态（后端、RPC 节点池、服务商、故障公告）
+pub mod support; // 帮助与客服（常见问题、工单）
 pub mod webhook_handler;
 pub mod withdrawal_review;
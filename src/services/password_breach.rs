@@ -0,0 +1,70 @@
+//! Password Breach Check - 泄露密码检查
+//!
+//! 使用 HaveIBeenPwned 的 k-匿名区间接口：只发送密码 SHA-1 的前 5 位，
+//! 在本地比对返回的后缀列表，密码与完整哈希不会离开浏览器。
+
+use crate::shared::error::{ApiError, AppError};
+use sha1::{Digest, Sha1};
+
+const RANGE_API: &str = "https://api.pwnedpasswords.com/range/";
+/// 哈希前缀长度
+const PREFIX_LEN: usize = 5;
+
+/// 计算 SHA-1（大写十六进制）并拆分为区间前缀与后缀
+pub fn range_key(password: &str) -> (String, String) {
+    let hash = hex::encode_upper(Sha1::digest(password.as_bytes()));
+    let (prefix, suffix) = hash.split_at(PREFIX_LEN);
+    (prefix.to_string(), suffix.to_string())
+}
+
+/// 在区间响应（每行 `SUFFIX:COUNT`）中查找后缀的泄露次数；填充行的次数为 0
+pub fn breach_count(range_body: &str, suffix: &str) -> u64 {
+    range_body
+        .lines()
+        .filter_map(|line| line.trim().split_once(':'))
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(suffix))
+        .and_then(|(_, count)| count.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// 查询密码在已知数据泄露中出现的次数（0 表示未发现）
+pub async fn check_password(password: &str) -> Result<u64, AppError> {
+    let (prefix, suffix) = range_key(password);
+    let response = gloo_net::http::Request::get(&format!("{}{}", RANGE_API, prefix))
+        // 填充响应，避免通过响应大小推断前缀
+        .header("Add-Padding", "true")
+        .send()
+        .await
+        .map_err(|e| AppError::Api(ApiError::RequestFailed(e.to_string())))?;
+    if !response.ok() {
+        return Err(AppError::Api(ApiError::ResponseError(format!(
+            "{} - breach range lookup failed",
+            response.status()
+        ))));
+    }
+    let body = response
+        .text()
+        .await
+        .map_err(|e| AppError::Api(ApiError::ResponseError(e.to_string())))?;
+    Ok(breach_count(&body, &suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_prefix_is_sent_and_suffix_is_matched_locally() {
+        // SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+        let (prefix, suffix) = range_key("password");
+        assert_eq!(prefix, "5BAA6");
+        assert_eq!(suffix, "1E4C9B93F3F0682250B6CF8331B7EE68FD8");
+
+        let body = "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:9659365\r\n\
+                    FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF:0";
+        assert_eq!(breach_count(body, &suffix), 9_659_365);
+        assert_eq!(breach_count(body, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"), 0);
+        assert_eq!(breach_count(body, "0000000000000000000000000000000000A"), 0);
+    }
+}
@@ -0,0 +1,320 @@
+//! Payment Gateway Service - 支付网关服务
+//! 银行卡令牌化与已保存支付方式管理
+//!
+//! 完整卡号只在令牌化请求中直接发送给支付网关，前端与后端保存的仅为
+//! 卡品牌、末四位与有效期；删除已保存的卡会在服务端吊销对应令牌。
+
+use crate::shared::api::ApiClient;
+use crate::shared::state::AppState;
+use crate::shared::validation::luhn_valid;
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+
+/// 卡品牌（根据卡号前缀识别）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    UnionPay,
+    Jcb,
+    Discover,
+    Unknown,
+}
+
+impl CardBrand {
+    /// 根据卡号前缀识别卡品牌
+    pub fn detect(number: &str) -> Self {
+        let digits: String = number.chars().filter(|c| c.is_ascii_digit()).collect();
+        let prefix = |len: usize| digits.get(..len).and_then(|p| p.parse::<u32>().ok());
+        match (prefix(1), prefix(2), prefix(4)) {
+            (Some(4), _, _) => Self::Visa,
+            (_, Some(34 | 37), _) => Self::Amex,
+            (_, Some(51..=55), _) | (_, _, Some(2221..=2720)) => Self::Mastercard,
+            (_, Some(35), _) => Self::Jcb,
+            (_, Some(62), _) => Self::UnionPay,
+            (_, Some(65), _) | (_, _, Some(6011)) => Self::Discover,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Visa => "Visa",
+            Self::Mastercard => "Mastercard",
+            Self::Amex => "American Express",
+            Self::UnionPay => "UnionPay",
+            Self::Jcb => "JCB",
+            Self::Discover => "Discover",
+            Self::Unknown => "银行卡",
+        }
+    }
+}
+
+/// 解析有效期（MM/YY 或 MM/YYYY），返回 (月, 四位年份)
+pub fn parse_expiry(value: &str) -> Result<(u32, u32), String> {
+    let (month, year) = value
+        .trim()
+        .split_once('/')
+        .ok_or_else(|| "有效期格式应为 MM/YY".to_string())?;
+    let month: u32 = month
+        .trim()
+        .parse()
+        .map_err(|_| "有效期月份无效".to_string())?;
+    if !(1..=12).contains(&month) {
+        return Err("有效期月份无效".to_string());
+    }
+    let year = year.trim();
+    let year: u32 = match year.len() {
+        2 => {
+            2000 + year
+                .parse::<u32>()
+                .map_err(|_| "有效期年份无效".to_string())?
+        }
+        4 => year.parse().map_err(|_| "有效期年份无效".to_string())?,
+        _ => return Err("有效期年份无效".to_string()),
+    };
+    Ok((month, year))
+}
+
+/// 有效期（含当月）是否已过
+pub fn is_expired(exp_month: u32, exp_year: u32, now_year: u32, now_month: u32) -> bool {
+    (exp_year, exp_month) < (now_year, now_month)
+}
+
+/// 当前 (年, 月)
+pub fn current_year_month() -> (u32, u32) {
+    let now = chrono::Utc::now();
+    (now.year() as u32, now.month())
+}
+
+/// 用户填写的银行卡信息（仅用于令牌化，不做任何持久化）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CardDetailsInput {
+    pub number: String,
+    pub expiry: String,
+    pub cvv: String,
+    pub holder_name: String,
+}
+
+/// 令牌化请求
+#[derive(Debug, Clone, Serialize)]
+struct TokenizeCardRequest {
+    card_number: String,
+    exp_month: u32,
+    exp_year: u32,
+    cvv: String,
+    holder_name: String,
+}
+
+impl CardDetailsInput {
+    /// 校验卡信息并生成令牌化请求
+    fn to_request(&self, now_year: u32, now_month: u32) -> Result<TokenizeCardRequest, String> {
+        let number: String = self
+            .number
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect();
+        if number.len() < 12
+            || number.len() > 19
+            || !number.chars().all(|c| c.is_ascii_digit())
+            || !luhn_valid(&number)
+        {
+            return Err("卡号无效，请检查后重新输入".to_string());
+        }
+        let (exp_month, exp_year) = parse_expiry(&self.expiry)?;
+        if is_expired(exp_month, exp_year, now_year, now_month) {
+            return Err("该卡已过期".to_string());
+        }
+        let cvv = self.cvv.trim();
+        if !(3..=4).contains(&cvv.len()) || !cvv.chars().all(|c| c.is_ascii_digit()) {
+            return Err("安全码（CVV）应为 3-4 位数字".to_string());
+        }
+        let holder_name = self.holder_name.trim();
+        if holder_name.is_empty() {
+            return Err("请输入持卡人姓名".to_string());
+        }
+        Ok(TokenizeCardRequest {
+            card_number: number,
+            exp_month,
+            exp_year,
+            cvv: cvv.to_string(),
+            holder_name: holder_name.to_string(),
+        })
+    }
+}
+
+/// 令牌化结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardToken {
+    /// 支付网关令牌
+    pub token: String,
+    pub brand: String,
+    pub last4: String,
+    pub exp_month: u32,
+    pub exp_year: u32,
+}
+
+/// 已保存的银行卡（仅品牌、末四位与有效期）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedCard {
+    pub id: String,
+    pub brand: String,
+    pub last4: String,
+    pub exp_month: u32,
+    pub exp_year: u32,
+}
+
+impl SavedCard {
+    /// 显示文案，如 "Visa •••• 4242"
+    pub fn label(&self) -> String {
+        format!("{} •••• {}", self.brand, self.last4)
+    }
+
+    /// 有效期文案，如 "08/27"
+    pub fn expiry_label(&self) -> String {
+        format!("{:02}/{:02}", self.exp_month, self.exp_year % 100)
+    }
+
+    pub fn is_expired_at(&self, now_year: u32, now_month: u32) -> bool {
+        is_expired(self.exp_month, self.exp_year, now_year, now_month)
+    }
+}
+
+/// 已保存银行卡列表响应
+#[derive(Debug, Clone, Deserialize)]
+struct SavedCardListResponse {
+    #[serde(default)]
+    cards: Vec<SavedCard>,
+}
+
+/// 支付网关服务
+pub struct PaymentGatewayService {
+    app_state: AppState,
+}
+
+impl PaymentGatewayService {
+    /// 创建新的支付网关服务实例
+    pub fn new(app_state: AppState) -> Self {
+        Self { app_state }
+    }
+
+    /// 获取最新的 API 客户端（包含最新的 token）
+    fn get_api_client(&self) -> ApiClient {
+        self.app_state.get_api_client()
+    }
+
+    /// 银行卡令牌化（卡号只在此请求中发送）
+    pub async fn tokenize_card(&self, card: &CardDetailsInput) -> Result<CardToken, String> {
+        let (now_year, now_month) = current_year_month();
+        let request = card.to_request(now_year, now_month)?;
+
+        self.get_api_client()
+            .post::<CardToken, TokenizeCardRequest>("/api/v1/payments/cards/tokenize", &request)
+            .await
+            .map_err(|e| {
+                let error_msg = e.to_string().to_lowercase();
+                if error_msg.contains("declined") || error_msg.contains("invalid card") {
+                    "银行卡验证未通过，请检查卡信息或更换其他卡".to_string()
+                } else if error_msg.contains("network") || error_msg.contains("connection") {
+                    "网络错误，请稍后重试".to_string()
+                } else {
+                    format!("银行卡验证失败：{}", e)
+                }
+            })
+    }
+
+    /// 获取已保存的银行卡
+    pub async fn list_saved_cards(&self) -> Result<Vec<SavedCard>, String> {
+        self.get_api_client()
+            .get::<SavedCardListResponse>("/api/v1/payments/saved-cards")
+            .await
+            .map(|resp| resp.cards)
+            .map_err(|e| {
+                if crate::shared::auth_handler::is_unauthorized_error(&e) {
+                    crate::shared::auth_handler::handle_unauthorized_and_redirect(self.app_state);
+                }
+                format!("获取已保存的银行卡失败：{}", e)
+            })
+    }
+
+    /// 保存令牌化的银行卡
+    pub async fn save_card(&self, token: &CardToken) -> Result<SavedCard, String> {
+        self.get_api_client()
+            .post::<SavedCard, serde_json::Value>(
+                "/api/v1/payments/saved-cards",
+                &serde_json::json!({ "token": token.token }),
+            )
+            .await
+            .map_err(|e| format!("保存银行卡失败：{}", e))
+    }
+
+    /// 删除已保存的银行卡（服务端同时吊销令牌）
+    pub async fn delete_saved_card(&self, card_id: &str) -> Result<(), String> {
+        if card_id.is_empty() {
+            return Err("银行卡ID不能为空".to_string());
+        }
+
+        let url = format!("/api/v1/payments/saved-cards/{}", card_id);
+
+        self.get_api_client()
+            .delete::<serde_json::Value>(&url)
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                let error_msg = e.to_string().to_lowercase();
+                if error_msg.contains("not found") || error_msg.contains("404") {
+                    "该银行卡已被删除".to_string()
+                } else {
+                    format!("删除银行卡失败：{}", e)
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_brand_and_validates_card() {
+        assert_eq!(CardBrand::detect("4242 4242 4242 4242"), CardBrand::Visa);
+        assert_eq!(CardBrand::detect("5555555555554444"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("2223003122003222"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("378282246310005"), CardBrand::Amex);
+        assert_eq!(CardBrand::detect("6011111111111117"), CardBrand::Discover);
+
+        let mut card = CardDetailsInput {
+            number: "4242 4242 4242 4242".to_string(),
+            expiry: "08/27".to_string(),
+            cvv: "123".to_string(),
+            holder_name: "Alice".to_string(),
+        };
+        let request = card.to_request(2026, 10).unwrap();
+        assert_eq!(request.card_number, "4242424242424242");
+        assert_eq!((request.exp_month, request.exp_year), (8, 2027));
+
+        card.number = "4242 4242 4242 4241".to_string();
+        assert!(card.to_request(2026, 10).is_err());
+        card.number = "4242424242424242".to_string();
+        card.expiry = "09/2026".to_string();
+        assert_eq!(card.to_request(2026, 10).unwrap_err(), "该卡已过期");
+    }
+
+    #[test]
+    fn saved_card_expiry_is_inclusive_of_current_month() {
+        let card = SavedCard {
+            id: "pm_1".to_string(),
+            brand: "Visa".to_string(),
+            last4: "4242".to_string(),
+            exp_month: 10,
+            exp_year: 2026,
+        };
+        assert!(!card.is_expired_at(2026, 10));
+        assert!(card.is_expired_at(2026, 11));
+        assert_eq!(card.expiry_label(), "10/26");
+        assert_eq!(card.label(), "Visa •••• 4242");
+        assert!(parse_expiry("13/27").is_err());
+    }
+}
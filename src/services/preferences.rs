@@ -0,0 +1,350 @@
+//! 用户偏好服务
+//! 所有偏好保存在一个带版本号的存储项中（`app_preferences`），替代分散的独立存储键。
+//! 旧版独立键在首次读取时按迁移表并入并删除；界面通过 AppState 中的信号响应变化，
+//! 开启同步的登录用户在设置变化时上传到后端，登录后按更新时间较新的一方合并
+
+use crate::features::settings::state::UserPreferences;
+use crate::shared::api_endpoints;
+use crate::shared::error::AppError;
+use crate::shared::state::AppState;
+use crate::shared::time::now_unix;
+use dioxus::prelude::{ReadableExt, WritableExt};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 偏好存储键
+pub const STORAGE_KEY: &str = "app_preferences";
+/// 当前存储结构版本；旧版本读取时执行迁移
+pub const SCHEMA_VERSION: u32 = 1;
+/// 默认界面语言
+pub const DEFAULT_UI_LANGUAGE: &str = "zh";
+
+fn default_ui_language() -> String {
+    DEFAULT_UI_LANGUAGE.to_string()
+}
+
+/// 持久化的偏好（单一存储项）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredPreferences {
+    #[serde(default)]
+    pub version: u32,
+    /// 可同步部分（设置与界面语言）最后修改时间（Unix 秒）
+    #[serde(default)]
+    pub updated_at: u64,
+    #[serde(default)]
+    pub settings: UserPreferences,
+    /// 界面语言代码（zh / en / ja / ko）
+    #[serde(default = "default_ui_language")]
+    pub ui_language: String,
+    /// 隐私模式（仅本设备）
+    #[serde(default)]
+    pub privacy_mode: bool,
+    /// 已完成的新手引导（仅本设备）
+    #[serde(default)]
+    pub completed_tours: BTreeSet<String>,
+    /// 性能浮层（仅本设备）
+    #[serde(default)]
+    pub perf_overlay: bool,
+    /// 是否同意上报匿名性能数据（仅本设备）
+    #[serde(default)]
+    pub metrics_consent: bool,
+    /// 各日志类别的级别（仅本设备，调试面板中调整）
+    #[serde(default)]
+    pub log_levels: BTreeMap<String, String>,
+}
+
+impl Default for StoredPreferences {
+    fn default() -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            updated_at: 0,
+            settings: UserPreferences::default(),
+            ui_language: default_ui_language(),
+            privacy_mode: false,
+            completed_tours: BTreeSet::new(),
+            perf_overlay: false,
+            metrics_consent: false,
+            log_levels: BTreeMap::new(),
+        }
+    }
+}
+
+/// 旧版独立存储键的迁移：读取原始值并入新结构，返回是否识别成功
+struct LegacyKey {
+    key: &'static str,
+    apply: fn(&mut StoredPreferences, &str) -> bool,
+}
+
+const LEGACY_KEYS: [LegacyKey; 7] = [
+    LegacyKey {
+        key: "user_preferences",
+        apply: |stored, raw| {
+            serde_json::from_str(raw)
+                .map(|settings| stored.settings = settings)
+                .is_ok()
+        },
+    },
+    LegacyKey {
+        key: "app_language",
+        apply: |stored, raw| {
+            serde_json::from_str(raw)
+                .map(|language| stored.ui_language = language)
+                .is_ok()
+        },
+    },
+    LegacyKey {
+        key: "privacy_mode",
+        apply: |stored, raw| {
+            serde_json::from_str(raw)
+                .map(|enabled| stored.privacy_mode = enabled)
+                .is_ok()
+        },
+    },
+    LegacyKey {
+        key: "onboarding_tours",
+        apply: |stored, raw| {
+            #[derive(Deserialize)]
+            struct Tours {
+                #[serde(default)]
+                completed: BTreeSet<String>,
+            }
+            serde_json::from_str::<Tours>(raw)
+                .map(|tours| stored.completed_tours.extend(tours.completed))
+                .is_ok()
+        },
+    },
+    LegacyKey {
+        key: "has_seen_swap_tour",
+        apply: |stored, raw| {
+            if raw == "true" {
+                stored.completed_tours.insert("swap".to_string());
+            }
+            true
+        },
+    },
+    LegacyKey {
+        key: "perf_overlay",
+        apply: |stored, raw| {
+            serde_json::from_str(raw)
+                .map(|enabled| stored.perf_overlay = enabled)
+                .is_ok()
+        },
+    },
+    LegacyKey {
+        key: "perf_metrics_consent",
+        apply: |stored, raw| {
+            serde_json::from_str(raw)
+                .map(|consent| stored.metrics_consent = consent)
+                .is_ok()
+        },
+    },
+];
+
+/// 把旧版独立键的值并入（`read` 返回旧键的原始值），返回已处理的旧键
+fn migrate_legacy(
+    stored: &mut StoredPreferences,
+    read: impl Fn(&str) -> Option<String>,
+) -> Vec<&'static str> {
+    let mut migrated = Vec::new();
+    for legacy in &LEGACY_KEYS {
+        if let Some(raw) = read(legacy.key) {
+            if !(legacy.apply)(stored, &raw) {
+                log::warn!("偏好迁移：无法解析旧存储项 {}", legacy.key);
+            }
+            migrated.push(legacy.key);
+        }
+    }
+    stored.version = SCHEMA_VERSION;
+    migrated
+}
+
+/// 读取偏好（首次读取时迁移旧版独立键）
+pub fn load() -> StoredPreferences {
+    let stored = LocalStorage::get::<StoredPreferences>(STORAGE_KEY).ok();
+    match stored {
+        Some(stored) if stored.version >= SCHEMA_VERSION => stored,
+        stored => {
+            let mut stored = stored.unwrap_or_default();
+            let migrated = migrate_legacy(&mut stored, |key| {
+                LocalStorage::raw().get_item(key).ok().flatten()
+            });
+            save(&stored);
+            for key in migrated {
+                LocalStorage::delete(key);
+            }
+            stored
+        }
+    }
+}
+
+fn save(stored: &StoredPreferences) {
+    let _ = LocalStorage::set(STORAGE_KEY, stored);
+}
+
+/// 读-改-写偏好存储（各调用方只改动自己的字段，互不覆盖）
+pub fn update(f: impl FnOnce(&mut StoredPreferences)) {
+    let mut stored = load();
+    f(&mut stored);
+    save(&stored);
+}
+
+/// 保存设置部分
+pub fn save_settings(settings: &UserPreferences) {
+    update(|stored| {
+        if stored.settings != *settings {
+            stored.settings = settings.clone();
+            stored.updated_at = now_unix();
+        }
+    });
+}
+
+/// 与后端同步的偏好（不含仅本设备的项）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncedPreferences {
+    pub updated_at: u64,
+    pub settings: UserPreferences,
+    pub ui_language: String,
+}
+
+/// 偏好服务：修改偏好时同时更新界面信号与存储
+#[derive(Clone, Copy)]
+pub struct PreferencesService {
+    app_state: AppState,
+}
+
+impl PreferencesService {
+    pub fn new(app_state: AppState) -> Self {
+        Self { app_state }
+    }
+
+    /// 修改设置
+    pub fn update_settings(&self, f: impl FnOnce(&mut UserPreferences)) {
+        let mut preferences = self.app_state.preferences;
+        let mut settings = preferences.write();
+        f(&mut settings);
+        save_settings(&settings);
+    }
+
+    /// 切换界面语言
+    pub fn set_language(&self, code: &str) {
+        let mut language = self.app_state.language;
+        language.set(code.to_string());
+        update(|stored| {
+            if stored.ui_language != code {
+                stored.ui_language = code.to_string();
+                stored.updated_at = now_unix();
+            }
+        });
+    }
+
+    /// 设置隐私模式
+    pub fn set_privacy_mode(&self, enabled: bool) {
+        let mut privacy_mode = self.app_state.privacy_mode;
+        privacy_mode.set(enabled);
+        update(|stored| stored.privacy_mode = enabled);
+    }
+
+    /// 恢复默认偏好（保留新手引导完成记录、性能数据上报选择与日志级别）
+    pub fn reset(&self) {
+        let defaults = StoredPreferences::default();
+        update(|stored| {
+            stored.settings = defaults.settings.clone();
+            stored.ui_language = defaults.ui_language.clone();
+            stored.privacy_mode = defaults.privacy_mode;
+            stored.perf_overlay = defaults.perf_overlay;
+            stored.updated_at = now_unix();
+        });
+        let mut preferences = self.app_state.preferences;
+        preferences.set(defaults.settings);
+        let mut language = self.app_state.language;
+        language.set(defaults.ui_language);
+        let mut privacy_mode = self.app_state.privacy_mode;
+        privacy_mode.set(defaults.privacy_mode);
+    }
+
+    /// 上传可同步的偏好
+    pub async fn push(&self) -> Result<(), AppError> {
+        let stored = load();
+        let payload = SyncedPreferences {
+            updated_at: stored.updated_at,
+            settings: stored.settings,
+            ui_language: stored.ui_language,
+        };
+        let _: serde_json::Value = self
+            .app_state
+            .get_api_client()
+            .put(api_endpoints::users::PREFERENCES, &payload)
+            .await
+            .map_err(AppError::Api)?;
+        Ok(())
+    }
+
+    /// 拉取后端偏好：比本地新时应用到本地，比本地旧时上传本地偏好
+    pub async fn pull(&self) -> Result<(), AppError> {
+        let remote: Option<SyncedPreferences> = self
+            .app_state
+            .get_api_client()
+            .get(api_endpoints::users::PREFERENCES)
+            .await
+            .map_err(AppError::Api)?;
+        let local = load();
+        match remote {
+            Some(remote) if remote.updated_at > local.updated_at => {
+                update(|stored| {
+                    stored.settings = remote.settings.clone();
+                    stored.ui_language = remote.ui_language.clone();
+                    stored.updated_at = remote.updated_at;
+                });
+                let mut preferences = self.app_state.preferences;
+                preferences.set(remote.settings);
+                let mut language = self.app_state.language;
+                language.set(remote.ui_language);
+                Ok(())
+            }
+            Some(remote) if remote.updated_at == local.updated_at => Ok(()),
+            _ => self.push().await,
+        }
+    }
+
+    /// 已开启偏好同步的登录用户
+    pub fn sync_enabled(&self) -> bool {
+        self.app_state.user.peek().is_authenticated
+            && self.app_state.preferences.peek().sync_preferences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn legacy_keys_are_merged_into_the_blob() {
+        let legacy: HashMap<&str, &str> = [
+            ("app_language", "\"en\""),
+            ("privacy_mode", "true"),
+            ("onboarding_tours", r#"{"completed":["dashboard"]}"#),
+            ("has_seen_swap_tour", "true"),
+            ("perf_metrics_consent", "not-json"),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut stored = StoredPreferences {
+            version: 0,
+            ..StoredPreferences::default()
+        };
+        let migrated = migrate_legacy(&mut stored, |key| legacy.get(key).map(|v| v.to_string()));
+
+        assert_eq!(stored.version, SCHEMA_VERSION);
+        assert_eq!(stored.ui_language, "en");
+        assert!(stored.privacy_mode);
+        assert!(stored.completed_tours.contains("dashboard"));
+        assert!(stored.completed_tours.contains("swap"));
+        // 无法解析的旧值保持默认，但旧键仍会被清理
+        assert!(!stored.metrics_consent);
+        assert_eq!(migrated.len(), 5);
+        assert!(!migrated.contains(&"user_preferences"));
+    }
+}
@@ -4,20 +4,42 @@
 use crate::shared::cache::CacheEntry;
 use crate::shared::error::{ApiError, AppError};
 use crate::shared::state::AppState;
+use crate::shared::time;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 use wasm_bindgen_futures::spawn_local;
 
 const PRICE_CACHE_TTL_SECS: u64 = 300; // 5 minutes
+/// 批量价格缓存键前缀（强制刷新时清除）
+pub const PRICE_CACHE_PREFIX: &str = "price:batch:";
+/// 最近一次成功获取的单个价格（不过期，价格服务不可用时用于展示降级）
+const LAST_KNOWN_PRICE_PREFIX: &str = "price:last:";
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CoinPrice {
     pub symbol: String,
     pub usd: f64,
     pub usd_24h_change: f64,
     pub last_updated: u64,
+    /// Market cap in USD (None when the backend doesn't provide it)
+    #[serde(default)]
+    pub market_cap: Option<f64>,
+}
+
+impl CoinPrice {
+    /// 价格是否已过时（超过缓存有效期，通常来自降级时的最近一次价格）
+    pub fn is_stale(&self) -> bool {
+        time::now_unix().saturating_sub(self.last_updated) > PRICE_CACHE_TTL_SECS
+    }
+}
+
+/// Historical price sample
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceHistoryPoint {
+    /// Unix timestamp (seconds)
+    pub timestamp: u64,
+    pub price: f64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -72,7 +94,7 @@ impl PriceService {
             .collect();
 
         let ids_param = coin_ids.join(",");
-        let cache_key = format!("price:batch:{}", ids_param);
+        let cache_key = format!("{}{}", PRICE_CACHE_PREFIX, ids_param);
 
         // Check cache first
         let cache = self.app_state.cache.read();
@@ -89,13 +111,35 @@ impl PriceService {
 
         // Fetch from backend API proxy (avoids CORS + rate limits)
         let api_client = self.app_state.get_api_client();
+
+        // 演示模式：使用固定演示价格（与演示报价一致），不写入缓存
+        if api_client.is_demo() {
+            let now = time::now_unix();
+            return Ok(symbols
+                .iter()
+                .filter_map(|s| {
+                    let symbol = s.to_uppercase();
+                    crate::shared::demo::price_usd(&symbol).map(|usd| {
+                        let price = CoinPrice {
+                            symbol: symbol.clone(),
+                            usd,
+                            usd_24h_change: 0.0,
+                            last_updated: now,
+                            market_cap: None,
+                        };
+                        (symbol, price)
+                    })
+                })
+                .collect());
+        }
+
         let backend_url = format!(
             "{}/api/v1/prices?symbols={}",
             api_client.base_url(),
             symbols.join(",")
         );
 
-        tracing::info!("Fetching prices from backend: {}", backend_url);
+        log::info!("Fetching prices from backend: {}", backend_url);
 
         // Use gloo-net for WASM-compatible HTTP requests
         use gloo_net::http::Request;
@@ -103,7 +147,7 @@ impl PriceService {
             .send()
             .await
             .map_err(|e| {
-                tracing::error!("Backend price fetch failed: {}", e);
+                log::error!("Backend price fetch failed: {}", e);
                 AppError::Api(ApiError::RequestFailed(format!(
                     "Backend unavailable: {}",
                     e
@@ -118,6 +162,8 @@ impl PriceService {
         struct BackendPriceData {
             symbol: String,
             price_usdt: f64,
+            #[serde(default)]
+            market_cap_usd: Option<f64>,
             source: String,
         }
 
@@ -134,7 +180,7 @@ impl PriceService {
 
         let backend_resp: BackendApiResponse =
             serde_json::from_str(&response_text).map_err(|e| {
-                tracing::error!(
+                log::error!(
                     "Failed to parse backend response: {} - Response: {}",
                     e,
                     response_text
@@ -145,7 +191,7 @@ impl PriceService {
                 )))
             })?;
 
-        let now = now_secs();
+        let now = time::now_unix();
         let mut prices = HashMap::new();
 
         // Convert backend response to our CoinPrice format
@@ -158,22 +204,73 @@ impl PriceService {
                     usd: price_data.price_usdt,
                     usd_24h_change: 0.0, // Backend doesn't provide 24h change yet
                     last_updated: now,
+                    market_cap: price_data.market_cap_usd,
                 },
             );
         }
 
         // Update cache
-        self.app_state.cache.write().insert(
+        let mut cache = self.app_state.cache.write();
+        for (symbol, price) in &prices {
+            if let Ok(value) = serde_json::to_value(price) {
+                cache.insert(
+                    format!("{}{}", LAST_KNOWN_PRICE_PREFIX, symbol),
+                    CacheEntry::new(value, now),
+                );
+            }
+        }
+        cache.insert(
             cache_key,
             CacheEntry {
                 value: serde_json::to_value(&prices).unwrap(),
                 stored_at: now,
             },
         );
+        drop(cache);
 
         Ok(prices)
     }
 
+    /// 批量获取价格，价格服务不可用时退回最近一次成功获取的价格
+    ///
+    /// 仅用于展示（法币估值、费用估算）：退回的价格保留原 `last_updated`，
+    /// 调用方用 [`CoinPrice::is_stale`] 标记“价格可能已过时”。
+    /// 所有代币都没有历史价格时返回原错误。
+    pub async fn get_prices_or_last_known(
+        self,
+        symbols: &[&str],
+    ) -> Result<HashMap<String, CoinPrice>, AppError> {
+        match self.get_prices(symbols).await {
+            Ok(prices) => Ok(prices),
+            Err(e) => {
+                let cache = self.app_state.cache.read();
+                let fallback: HashMap<String, CoinPrice> = symbols
+                    .iter()
+                    .filter_map(|s| {
+                        let symbol = s.to_uppercase();
+                        let entry = cache.get(&format!("{}{}", LAST_KNOWN_PRICE_PREFIX, symbol))?;
+                        let price = serde_json::from_value(entry.value.clone()).ok()?;
+                        Some((symbol, price))
+                    })
+                    .collect();
+                if fallback.is_empty() {
+                    Err(e)
+                } else {
+                    log::warn!("价格服务不可用，使用最近一次价格: {}", e);
+                    Ok(fallback)
+                }
+            }
+        }
+    }
+
+    /// 单个代币价格，价格服务不可用时退回最近一次价格（见 [`Self::get_prices_or_last_known`]）
+    pub async fn get_price_or_last_known(&self, symbol: &str) -> Result<CoinPrice, AppError> {
+        self.get_prices_or_last_known(&[symbol])
+            .await?
+            .remove(&symbol.to_uppercase())
+            .ok_or_else(|| AppError::Api(ApiError::ResponseError("Price not found".into())))
+    }
+
     /// Get asset value in USD
     ///
     /// # Arguments
@@ -188,6 +285,108 @@ impl PriceService {
         Ok(price.usd * amount)
     }
 
+    /// Get USD -> fiat exchange rate (e.g. "CNY")
+    ///
+    /// # Returns
+    /// Units of `currency` per 1 USD
+    pub async fn get_fiat_rate(mut self, currency: &str) -> Result<f64, AppError> {
+        let currency = currency.to_uppercase();
+        if currency == "USD" {
+            return Ok(1.0);
+        }
+
+        let cache_key = format!("fx:USD:{}", currency);
+        if let Some(entry) = self.app_state.cache.read().get(&cache_key) {
+            if !entry.is_expired(PRICE_CACHE_TTL_SECS) {
+                if let Some(rate) = entry.value.as_f64() {
+                    return Ok(rate);
+                }
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct FiatRateResponse {
+            rate: f64,
+        }
+
+        let path = format!("/api/v1/prices/fiat-rate?base=USD&quote={}", currency);
+        let response: FiatRateResponse = self
+            .app_state
+            .get_api_client()
+            .get(&path)
+            .await
+            .map_err(AppError::Api)?;
+
+        if !response.rate.is_finite() || response.rate <= 0.0 {
+            return Err(AppError::Api(ApiError::ResponseError(format!(
+                "Invalid fiat rate for {}",
+                currency
+            ))));
+        }
+
+        self.app_state.cache.write().insert(
+            cache_key,
+            CacheEntry {
+                value: serde_json::json!(response.rate),
+                stored_at: time::now_unix(),
+            },
+        );
+
+        Ok(response.rate)
+    }
+
+    /// Get price history for a symbol over the last `hours`
+    ///
+    /// # Returns
+    /// Samples ordered by timestamp (oldest first)
+    pub async fn get_price_history(
+        mut self,
+        symbol: &str,
+        hours: u32,
+    ) -> Result<Vec<PriceHistoryPoint>, AppError> {
+        let symbol = symbol.to_uppercase();
+        let cache_key = format!("price:history:{}:{}", symbol, hours);
+        if let Some(entry) = self.app_state.cache.read().get(&cache_key) {
+            if !entry.is_expired(PRICE_CACHE_TTL_SECS) {
+                if let Ok(points) =
+                    serde_json::from_value::<Vec<PriceHistoryPoint>>(entry.value.clone())
+                {
+                    return Ok(points);
+                }
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct PriceHistoryResponse {
+            points: Vec<PriceHistoryPoint>,
+        }
+
+        let path = format!("/api/v1/prices/history?symbol={}&hours={}", symbol, hours);
+        let response: PriceHistoryResponse = self
+            .app_state
+            .get_api_client()
+            .get(&path)
+            .await
+            .map_err(AppError::Api)?;
+
+        let mut points: Vec<PriceHistoryPoint> = response
+            .points
+            .into_iter()
+            .filter(|p| p.price.is_finite() && p.price > 0.0)
+            .collect();
+        points.sort_by_key(|p| p.timestamp);
+
+        self.app_state.cache.write().insert(
+            cache_key,
+            CacheEntry {
+                value: serde_json::to_value(&points).unwrap_or_default(),
+                stored_at: time::now_unix(),
+            },
+        );
+
+        Ok(points)
+    }
+
     /// Map common symbol to CoinGecko ID
     fn symbol_to_coingecko_id(symbol: &str) -> String {
         let id = match symbol.to_uppercase().as_str() {
@@ -207,21 +406,6 @@ impl PriceService {
     }
 }
 
-fn now_secs() -> u64 {
-    #[cfg(target_arch = "wasm32")]
-    {
-        (js_sys::Date::new_0().get_time() / 1000.0) as u64
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs()
-    }
-}
-
 /// Hook for using price service in components
 /// 获取价格服务实例
 ///
@@ -250,7 +434,7 @@ pub fn use_live_price(symbol: &str) -> Signal<Option<CoinPrice>> {
                 match service.get_price(&symbol_clone).await {
                     Ok(p) => price.set(Some(p)),
                     Err(e) => {
-                        tracing::error!("Failed to fetch price for {}: {:?}", symbol_clone, e)
+                        log::error!("Failed to fetch price for {}: {:?}", symbol_clone, e)
                     }
                 }
                 gloo_timers::future::TimeoutFuture::new(30_000).await; // 30s
@@ -279,6 +463,7 @@ mod tests {
             usd: 2000.0,
             usd_24h_change: 5.0,
             last_updated: 0,
+            market_cap: None,
         };
 
         let value = price.usd * 1.5;
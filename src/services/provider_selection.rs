@@ -10,7 +10,7 @@ use std::sync::RwLock;
 
 /// 获取当前 Unix 时间戳（秒）- WebAssembly 兼容
 fn now_timestamp() -> u64 {
-    js_sys::Date::new_0().get_time() as u64 / 1000
+    crate::shared::time::now_unix()
 }
 
 /// 服务商类型
@@ -254,7 +254,7 @@ impl ProviderSelectionService {
 
     /// 执行健康检查
     pub async fn check_health(&self, provider: ProviderType) -> Result<ProviderHealth, String> {
-        let start_time = js_sys::Date::new_0().get_time();
+        let start_time = crate::shared::time::monotonic_ms();
 
         // 调用后端健康检查API
         let url = format!(
@@ -267,7 +267,7 @@ impl ProviderSelectionService {
             .await
             .map_err(|e| format!("Health check failed for {}: {}", provider.name(), e))?;
 
-        let end_time = js_sys::Date::new_0().get_time();
+        let end_time = crate::shared::time::monotonic_ms();
         let response_time = Some((end_time - start_time) as u64);
 
         let health = ProviderHealth {
@@ -378,7 +378,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| v > 0.0 && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分最大费率，使用硬编码默认值 10.0%。生产环境必须配置环境变量 PROVIDER_SCORING_MAX_FEE_PERCENTAGE"
                 );
                 10.0 // 安全默认值：10%（仅作为最后保障，生产环境不应使用）
@@ -389,7 +389,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| v > 0.0 && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分理想响应时间，使用硬编码默认值 1.0秒。生产环境必须配置环境变量 PROVIDER_SCORING_IDEAL_RESPONSE_TIME_SECONDS"
                 );
                 1.0 // 安全默认值：1秒（仅作为最后保障，生产环境不应使用）
@@ -400,7 +400,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| v > 0.0 && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分最大响应时间，使用硬编码默认值 10.0秒。生产环境必须配置环境变量 PROVIDER_SCORING_MAX_RESPONSE_TIME_SECONDS"
                 );
                 10.0 // 安全默认值：10秒（仅作为最后保障，生产环境不应使用）
@@ -411,7 +411,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| (0.0..=100.0).contains(&v) && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分未知响应时间分数，使用硬编码默认值 50.0分。生产环境必须配置环境变量 PROVIDER_SCORING_UNKNOWN_RESPONSE_TIME_SCORE"
                 );
                 50.0 // 安全默认值：50分（仅作为最后保障，生产环境不应使用）
@@ -466,7 +466,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| (0.0..=1.0).contains(&v) && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分费用权重，使用硬编码默认值 0.7 (70%)。生产环境必须配置环境变量 PROVIDER_SCORING_FEE_WEIGHT"
                 );
                 0.7 // 安全默认值：70%（仅作为最后保障，生产环境不应使用）
@@ -477,7 +477,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| (0.0..=1.0).contains(&v) && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分响应时间权重，使用硬编码默认值 0.2 (20%)。生产环境必须配置环境变量 PROVIDER_SCORING_RESPONSE_TIME_WEIGHT"
                 );
                 0.2 // 安全默认值：20%（仅作为最后保障，生产环境不应使用）
@@ -488,7 +488,7 @@ impl ProviderSelectionService {
             .and_then(|v| v.parse::<f64>().ok())
             .filter(|&v| (0.0..=1.0).contains(&v) && v.is_finite())
             .unwrap_or_else(|| {
-                tracing::error!(
+                log::error!(
                     "严重警告：未找到环境变量配置的服务商评分成功率权重，使用硬编码默认值 0.1 (10%)。生产环境必须配置环境变量 PROVIDER_SCORING_SUCCESS_RATE_WEIGHT"
                 );
                 0.1 // 安全默认值：10%（仅作为最后保障，生产环境不应使用）
@@ -589,20 +589,20 @@ impl ProviderSelectionService {
         let mut provider_scores = Vec::new();
 
         for health in candidate_providers.iter().take(max_attempts) {
-            let start_time = js_sys::Date::new_0().get_time();
+            let start_time = crate::shared::time::monotonic_ms();
             match self
                 .get_quote(health.provider, from_token, to_token, amount, country_code)
                 .await
             {
                 Ok(quote) => {
-                    let end_time = js_sys::Date::new_0().get_time();
+                    let end_time = crate::shared::time::monotonic_ms();
                     let response_time = Some((end_time - start_time) as u64); // milliseconds
                     let score = self.calculate_score(&quote, health);
                     provider_scores.push((health.provider, quote, score));
                     self.record_request(health.provider, true, response_time);
                 }
                 Err(e) => {
-                    let end_time = js_sys::Date::new_0().get_time();
+                    let end_time = crate::shared::time::monotonic_ms();
                     let response_time = Some((end_time - start_time) as u64); // milliseconds
                     attempts.push((health.provider, e.clone()));
                     self.record_request(health.provider, false, response_time);
@@ -0,0 +1,180 @@
+//! Referral Service - 邀请返佣服务
+//! 获取本人邀请码、注册时绑定邀请码、查询邀请记录与奖励
+
+use crate::shared::api::ApiClient;
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 邀请码长度范围
+const CODE_MIN_LEN: usize = 4;
+const CODE_MAX_LEN: usize = 16;
+
+/// 本人邀请码
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferralCode {
+    pub code: String,
+    /// 后端生成的邀请链接（未提供时由前端拼接）
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// 被邀请用户（后端已脱敏；前端展示前再做一次脱敏）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferredUser {
+    #[serde(alias = "email")]
+    pub display_name: String,
+    pub joined_at: String,
+    /// 是否已完成首笔交易（奖励生效条件）
+    #[serde(default)]
+    pub qualified: bool,
+}
+
+/// 邀请奖励记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferralReward {
+    pub id: String,
+    pub amount: String,
+    pub asset: String,
+    #[serde(default)]
+    pub description: String,
+    pub created_at: String,
+}
+
+/// 邀请统计
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReferralStats {
+    #[serde(default)]
+    pub referred_users: Vec<ReferredUser>,
+    #[serde(default)]
+    pub rewards: Vec<ReferralReward>,
+    /// 累计奖励（美元）
+    #[serde(default)]
+    pub total_rewards_usd: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct ApplyCodeRequest<'a> {
+    code: &'a str,
+}
+
+/// 邀请返佣服务
+#[derive(Clone)]
+pub struct ReferralService {
+    api_client: Arc<ApiClient>,
+}
+
+impl ReferralService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            api_client: Arc::new(app_state.get_api_client()),
+        }
+    }
+
+    /// 获取本人邀请码（首次调用时后端生成）
+    pub async fn get_my_code(&self) -> Result<ReferralCode> {
+        self.api_client
+            .get::<ReferralCode>("/api/v1/referrals/code")
+            .await
+            .map_err(|e| anyhow!("获取邀请码失败: {}", e))
+    }
+
+    /// 绑定邀请码（每个账户只能绑定一次，需在注册后调用）
+    pub async fn apply_code(&self, code: &str) -> Result<()> {
+        let code = normalize_code(code).ok_or_else(|| anyhow!("邀请码格式不正确"))?;
+        self.api_client
+            .post::<serde_json::Value, _>(
+                "/api/v1/referrals/apply",
+                &ApplyCodeRequest { code: &code },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow!("绑定邀请码失败: {}", e))
+    }
+
+    /// 邀请记录与奖励
+    pub async fn get_referral_stats(&self) -> Result<ReferralStats> {
+        self.api_client
+            .get::<ReferralStats>("/api/v1/referrals/stats")
+            .await
+            .map_err(|e| anyhow!("获取邀请统计失败: {}", e))
+    }
+}
+
+/// 规范化邀请码（去空白、转大写，只允许字母数字）；格式不正确时返回 None
+pub fn normalize_code(code: &str) -> Option<String> {
+    let code = code.trim().to_uppercase();
+    let valid = (CODE_MIN_LEN..=CODE_MAX_LEN).contains(&code.len())
+        && code.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then_some(code)
+}
+
+/// 邀请链接（落地页带 `?ref=` 参数）
+pub fn referral_link(origin: &str, code: &ReferralCode) -> String {
+    code.link
+        .clone()
+        .filter(|link| link.starts_with("https://") || link.starts_with("http://"))
+        .unwrap_or_else(|| {
+            format!(
+                "{}/?ref={}",
+                origin.trim_end_matches('/'),
+                urlencoding::encode(&code.code)
+            )
+        })
+}
+
+/// 被邀请用户的展示名：邮箱只保留首字母与域名，其余保留首尾字符
+pub fn anonymize(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    if let Some((local, domain)) = name.split_once('@') {
+        let first = local.chars().next().map(String::from).unwrap_or_default();
+        return format!("{}***@{}", first, domain);
+    }
+    match chars.as_slice() {
+        [] => "***".to_string(),
+        [first] | [first, _] => format!("{}***", first),
+        [first, .., last] => format!("{}***{}", first, last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_normalized_and_validated() {
+        assert_eq!(normalize_code(" abcd12 "), Some("ABCD12".to_string()));
+        assert_eq!(normalize_code("abc"), None);
+        assert_eq!(normalize_code("ab-cd"), None);
+        assert_eq!(normalize_code(&"A".repeat(17)), None);
+    }
+
+    #[test]
+    fn link_falls_back_to_origin() {
+        let code = ReferralCode {
+            code: "IRON42".to_string(),
+            link: None,
+        };
+        assert_eq!(
+            referral_link("https://app.example/", &code),
+            "https://app.example/?ref=IRON42"
+        );
+        let code = ReferralCode {
+            link: Some("javascript:alert(1)".to_string()),
+            ..code
+        };
+        assert_eq!(
+            referral_link("https://app.example", &code),
+            "https://app.example/?ref=IRON42"
+        );
+    }
+
+    #[test]
+    fn referred_users_are_anonymized() {
+        assert_eq!(anonymize("alice@example.com"), "a***@example.com");
+        assert_eq!(anonymize("bob_smith"), "b***h");
+        assert_eq!(anonymize("x"), "x***");
+        assert_eq!(anonymize(""), "***");
+    }
+}
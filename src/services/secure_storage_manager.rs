@@ -70,7 +70,7 @@ impl SecureStorageManager {
             LocalStorage::delete(key);
         }
         
-        tracing::info!("All sensitive data cleared from storage");
+        log::info!("All sensitive data cleared from storage");
     }
     
     /// 安全存储配置
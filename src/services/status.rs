@@ -0,0 +1,253 @@
+//! Status Service - 服务状态
+//! 汇总后端可达性与延迟、各链 RPC 节点池健康、法币服务商状态与故障公告（无需登录）
+
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::system;
+use crate::shared::error::ApiError;
+use crate::shared::metrics;
+use crate::shared::state::AppState;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 后端响应超过该延迟（毫秒）视为降级
+pub const SLOW_BACKEND_MS: f64 = 2000.0;
+
+/// 组件健康状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceHealth {
+    Operational,
+    Degraded,
+    Outage,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ServiceHealth {
+    /// 严重程度（用于取最严重的状态）
+    fn severity(&self) -> u8 {
+        match self {
+            ServiceHealth::Operational => 0,
+            ServiceHealth::Unknown => 1,
+            ServiceHealth::Degraded => 2,
+            ServiceHealth::Outage => 3,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ServiceHealth::Operational => "正常",
+            ServiceHealth::Degraded => "性能下降",
+            ServiceHealth::Outage => "不可用",
+            ServiceHealth::Unknown => "未知",
+        }
+    }
+}
+
+/// 单条链的 RPC 节点池健康
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcPoolHealth {
+    pub chain: String,
+    pub status: ServiceHealth,
+    #[serde(default)]
+    pub healthy_endpoints: u32,
+    #[serde(default)]
+    pub total_endpoints: u32,
+    #[serde(default)]
+    pub latency_ms: Option<u64>,
+    pub last_checked: u64,
+    /// 后端附带的故障说明
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// 法币服务商状态（服务商状态推送的最新结果）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderHealthEntry {
+    pub provider: String,
+    pub status: ServiceHealth,
+    #[serde(default)]
+    pub latency_ms: Option<u64>,
+    pub last_checked: u64,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// 故障公告
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Incident {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub message: String,
+    pub status: ServiceHealth,
+    pub started_at: u64,
+    #[serde(default)]
+    pub resolved_at: Option<u64>,
+}
+
+/// 后端状态接口返回的汇总
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatusSummary {
+    #[serde(default)]
+    pub rpc: Vec<RpcPoolHealth>,
+    #[serde(default)]
+    pub providers: Vec<ProviderHealthEntry>,
+    #[serde(default)]
+    pub incidents: Vec<Incident>,
+}
+
+/// 后端可达性探测结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendProbe {
+    pub status: ServiceHealth,
+    pub latency_ms: Option<f64>,
+    pub checked_at: u64,
+    pub error: Option<String>,
+}
+
+/// 一次状态检查的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusReport {
+    pub backend: BackendProbe,
+    /// 后端不可达时为 None
+    pub summary: Option<StatusSummary>,
+}
+
+impl StatusReport {
+    /// 总体状态：取后端与各组件中最严重的状态（已解决的公告不计入）
+    pub fn overall(&self) -> ServiceHealth {
+        let Some(summary) = &self.summary else {
+            return self.backend.status;
+        };
+        summary
+            .rpc
+            .iter()
+            .map(|r| r.status)
+            .chain(summary.providers.iter().map(|p| p.status))
+            .chain(
+                summary
+                    .incidents
+                    .iter()
+                    .filter(|i| i.resolved_at.is_none())
+                    .map(|i| i.status),
+            )
+            .chain(std::iter::once(self.backend.status))
+            .max_by_key(ServiceHealth::severity)
+            .unwrap_or(ServiceHealth::Operational)
+    }
+}
+
+/// 根据请求结果与耗时判断后端状态
+pub fn probe_status(result: &Result<(), ApiError>, latency_ms: f64) -> ServiceHealth {
+    match result {
+        Ok(()) if latency_ms > SLOW_BACKEND_MS => ServiceHealth::Degraded,
+        Ok(()) => ServiceHealth::Operational,
+        // 有响应但返回错误：后端可达，但状态接口异常
+        Err(ApiError::ResponseError(msg)) if !msg.starts_with('5') => ServiceHealth::Degraded,
+        Err(_) => ServiceHealth::Outage,
+    }
+}
+
+/// 服务状态服务
+#[derive(Clone)]
+pub struct StatusService {
+    api_client: Arc<ApiClient>,
+}
+
+impl StatusService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            api_client: Arc::new(app_state.get_api_client()),
+        }
+    }
+
+    /// 请求状态汇总，同时测量后端延迟
+    pub async fn check(&self) -> StatusReport {
+        let started = metrics::perf_now();
+        let result = self.api_client.get::<StatusSummary>(system::STATUS).await;
+        let latency_ms = metrics::perf_now() - started;
+        let checked_at = crate::shared::time::now_unix();
+
+        let (outcome, summary) = match result {
+            Ok(summary) => (Ok(()), Some(summary)),
+            Err(e) => (Err(e), None),
+        };
+        let status = probe_status(&outcome, latency_ms);
+        StatusReport {
+            backend: BackendProbe {
+                status,
+                latency_ms: (status != ServiceHealth::Outage).then_some(latency_ms),
+                checked_at,
+                error: outcome.err().map(|e| e.to_string()),
+            },
+            summary,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(backend: ServiceHealth, summary: Option<StatusSummary>) -> StatusReport {
+        StatusReport {
+            backend: BackendProbe {
+                status: backend,
+                latency_ms: None,
+                checked_at: 0,
+                error: None,
+            },
+            summary,
+        }
+    }
+
+    #[test]
+    fn overall_reflects_worst_open_component() {
+        let json = r#"{
+            "rpc": [
+                {"chain": "ethereum", "status": "operational", "last_checked": 1},
+                {"chain": "solana", "status": "degraded", "healthy_endpoints": 1, "total_endpoints": 3, "last_checked": 1}
+            ],
+            "providers": [{"provider": "moonpay", "status": "maintenance", "last_checked": 1}],
+            "incidents": [{"id": "i1", "title": "BSC 节点故障", "status": "outage", "started_at": 1, "resolved_at": 2}]
+        }"#;
+        let summary: StatusSummary = serde_json::from_str(json).unwrap();
+        assert_eq!(summary.providers[0].status, ServiceHealth::Unknown);
+
+        let r = report(ServiceHealth::Operational, Some(summary.clone()));
+        assert_eq!(r.overall(), ServiceHealth::Degraded);
+
+        let mut open = summary;
+        open.incidents[0].resolved_at = None;
+        assert_eq!(
+            report(ServiceHealth::Operational, Some(open)).overall(),
+            ServiceHealth::Outage
+        );
+        assert_eq!(
+            report(ServiceHealth::Outage, None).overall(),
+            ServiceHealth::Outage
+        );
+    }
+
+    #[test]
+    fn backend_probe_distinguishes_slow_and_unreachable() {
+        assert_eq!(probe_status(&Ok(()), 120.0), ServiceHealth::Operational);
+        assert_eq!(probe_status(&Ok(()), 3000.0), ServiceHealth::Degraded);
+        assert_eq!(
+            probe_status(
+                &Err(ApiError::ResponseError("404 - not found".into())),
+                50.0
+            ),
+            ServiceHealth::Degraded
+        );
+        assert_eq!(
+            probe_status(&Err(ApiError::ResponseError("503 - down".into())), 50.0),
+            ServiceHealth::Outage
+        );
+        assert_eq!(
+            probe_status(&Err(ApiError::Timeout), 10000.0),
+            ServiceHealth::Outage
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! Support Service - 帮助与客服服务
+//! 常见问题（按语言获取）、提交工单（可附带用户确认的上下文）、查看工单状态与回复
+
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::support;
+use crate::shared::state::AppState;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 常见问题条目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaqEntry {
+    pub id: String,
+    pub question: String,
+    /// Markdown 格式的回答
+    #[serde(alias = "answer_markdown")]
+    pub answer: String,
+    #[serde(default)]
+    pub category: String,
+}
+
+/// 工单附带的上下文（每一项都由用户勾选确认后才会提交）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TicketContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_errors: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+}
+
+impl TicketContext {
+    pub fn is_empty(&self) -> bool {
+        self.route.is_none() && self.recent_errors.is_empty() && self.order_id.is_none()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CreateTicketRequest<'a> {
+    subject: &'a str,
+    message: &'a str,
+    #[serde(skip_serializing_if = "TicketContext::is_empty")]
+    context: &'a TicketContext,
+}
+
+/// 工单状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketStatus {
+    Open,
+    /// 客服已回复，等待用户
+    Answered,
+    Closed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TicketStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TicketStatus::Open => "处理中",
+            TicketStatus::Answered => "已回复",
+            TicketStatus::Closed => "已关闭",
+            TicketStatus::Unknown => "未知",
+        }
+    }
+}
+
+/// 工单摘要
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupportTicket {
+    pub id: String,
+    pub subject: String,
+    pub status: TicketStatus,
+    pub updated_at: String,
+    /// 未读的客服回复数
+    #[serde(default)]
+    pub unread_replies: u32,
+}
+
+/// 工单中的一条消息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TicketMessage {
+    /// "user" 或 "support"
+    pub author: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+impl TicketMessage {
+    pub fn is_support(&self) -> bool {
+        self.author == "support"
+    }
+}
+
+/// 工单详情
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TicketDetail {
+    #[serde(flatten)]
+    pub ticket: SupportTicket,
+    #[serde(default)]
+    pub messages: Vec<TicketMessage>,
+}
+
+/// 帮助与客服服务
+#[derive(Clone)]
+pub struct SupportService {
+    api_client: Arc<ApiClient>,
+}
+
+impl SupportService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            api_client: Arc::new(app_state.get_api_client()),
+        }
+    }
+
+    /// 常见问题（按界面语言，无需登录）
+    pub async fn get_faq(&self, language: &str) -> Result<Vec<FaqEntry>> {
+        self.api_client
+            .get::<Vec<FaqEntry>>(&format!(
+                "{}?lang={}",
+                support::FAQ,
+                urlencoding::encode(language)
+            ))
+            .await
+            .map_err(|e| anyhow!("获取常见问题失败: {}", e))
+    }
+
+    /// 提交工单
+    pub async fn create_ticket(
+        &self,
+        subject: &str,
+        message: &str,
+        context: &TicketContext,
+    ) -> Result<SupportTicket> {
+        self.api_client
+            .post::<SupportTicket, _>(
+                support::TICKETS,
+                &CreateTicketRequest {
+                    subject,
+                    message,
+                    context,
+                },
+            )
+            .await
+            .map_err(|e| anyhow!("提交工单失败: {}", e))
+    }
+
+    /// 我的工单
+    pub async fn list_tickets(&self) -> Result<Vec<SupportTicket>> {
+        self.api_client
+            .get::<Vec<SupportTicket>>(support::TICKETS)
+            .await
+            .map_err(|e| anyhow!("获取工单列表失败: {}", e))
+    }
+
+    /// 工单详情与回复
+    pub async fn get_ticket(&self, ticket_id: &str) -> Result<TicketDetail> {
+        self.api_client
+            .get::<TicketDetail>(&support::ticket(ticket_id))
+            .await
+            .map_err(|e| anyhow!("获取工单详情失败: {}", e))
+    }
+
+    /// 标记工单回复已读
+    pub async fn mark_read(&self, ticket_id: &str) -> Result<()> {
+        self.api_client
+            .post::<serde_json::Value, _>(&support::mark_read(ticket_id), &serde_json::json!({}))
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow!("标记已读失败: {}", e))
+    }
+}
+
+/// 搜索常见问题：所有关键词（空格分隔，不区分大小写）都出现在问题、分类或回答中；
+/// 问题命中的排在前面
+pub fn search_faq<'a>(entries: &'a [FaqEntry], query: &str) -> Vec<&'a FaqEntry> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return entries.iter().collect();
+    }
+    let mut matches: Vec<(bool, &FaqEntry)> = entries
+        .iter()
+        .filter_map(|entry| {
+            let question = entry.question.to_lowercase();
+            let haystack = format!(
+                "{} {} {}",
+                question,
+                entry.category.to_lowercase(),
+                entry.answer.to_lowercase()
+            );
+            terms
+                .iter()
+                .all(|t| haystack.contains(t.as_str()))
+                .then(|| (terms.iter().all(|t| question.contains(t.as_str())), entry))
+        })
+        .collect();
+    matches.sort_by_key(|(in_question, _)| !in_question);
+    matches.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// 所有工单的未读回复数
+pub fn unread_total(tickets: &[SupportTicket]) -> u32 {
+    tickets.iter().map(|t| t.unread_replies).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn faq(id: &str, question: &str, answer: &str) -> FaqEntry {
+        FaqEntry {
+            id: id.to_string(),
+            question: question.to_string(),
+            answer: answer.to_string(),
+            category: String::new(),
+        }
+    }
+
+    #[test]
+    fn search_matches_all_terms_and_ranks_questions_first() {
+        let entries = vec![
+            faq("1", "如何备份助记词", "在钱包详情中选择 **备份**"),
+            faq("2", "Swap failed", "Check gas and slippage"),
+            faq("3", "Gas 费用说明", "每笔交易都需要支付 gas"),
+        ];
+        let ids = |q: &str| -> Vec<String> {
+            search_faq(&entries, q)
+                .into_iter()
+                .map(|e| e.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(""), vec!["1", "2", "3"]);
+        assert_eq!(ids("GAS"), vec!["3", "2"]);
+        assert_eq!(ids("swap slippage"), vec!["2"]);
+        assert!(ids("不存在").is_empty());
+    }
+
+    #[test]
+    fn empty_context_is_omitted() {
+        let context = TicketContext::default();
+        let body = serde_json::to_value(CreateTicketRequest {
+            subject: "s",
+            message: "m",
+            context: &context,
+        })
+        .unwrap();
+        assert!(body.get("context").is_none());
+
+        let context = TicketContext {
+            order_id: Some("ord-1".to_string()),
+            ..Default::default()
+        };
+        let body = serde_json::to_value(CreateTicketRequest {
+            subject: "s",
+            message: "m",
+            context: &context,
+        })
+        .unwrap();
+        assert_eq!(body["context"], serde_json::json!({ "order_id": "ord-1" }));
+    }
+}
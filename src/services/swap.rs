@@ -1,7 +1,9 @@
 //! Swap Service - 代币交换服务
 //! 集成后端Swap API
 
+use crate::features::swap::routing::RoutePreference;
 use crate::shared::api::ApiClient;
+use crate::shared::kill_switch::{self, MoneyAction};
 use crate::shared::state::AppState;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -44,6 +46,9 @@ pub struct SwapQuoteResponse {
     pub estimated_gas_usd: Option<f64>,
     #[serde(default)]
     pub valid_for: Option<u32>,
+    /// 产生该路由的偏好（后端未回传时由请求时的偏好填入）
+    #[serde(default)]
+    pub route_preference: Option<RoutePreference>,
 }
 
 /// Swap执行请求
@@ -100,6 +105,9 @@ pub struct SwapExecuteResponse {
     /// 1inch路由器地址（用于前端显示和验证）
     #[serde(default)]
     pub router_address: Option<String>,
+    /// 是否经防 MEV 的私有通道提交（后端未返回时为 None）
+    #[serde(default)]
+    pub mev_protected: Option<bool>,
 }
 
 /// Swap交易数据
@@ -124,13 +132,26 @@ impl SwapService {
         }
     }
 
-    /// 获取Swap报价（增强错误处理）
+    /// 获取Swap报价（聚合器默认路由）
     pub async fn get_quote(
         &self,
         from: &str,
         to: &str,
         amount: &str,
         network: &str,
+    ) -> Result<SwapQuoteResponse, String> {
+        self.get_routed_quote(from, to, amount, network, RoutePreference::BestOutput)
+            .await
+    }
+
+    /// 按路由偏好获取Swap报价（增强错误处理）
+    pub async fn get_routed_quote(
+        &self,
+        from: &str,
+        to: &str,
+        amount: &str,
+        network: &str,
+        route: RoutePreference,
     ) -> Result<SwapQuoteResponse, String> {
         // 输入验证
         if from.is_empty() || to.is_empty() || amount.is_empty() {
@@ -171,17 +192,23 @@ impl SwapService {
         }
 
         // ✅使用标准端点
-        let url = format!(
+        let mut url = format!(
             "/api/v1/swap/quote?from={}&to={}&amount={}&network={}",
             encode_uri_component(&request.from),
             encode_uri_component(&request.to),
             encode_uri_component(&request.amount),
             encode_uri_component(&request.network)
         );
+        for (key, value) in route.query_params() {
+            url.push_str(&format!("&{}={}", key, value));
+        }
 
         // 调用API并转换错误消息（增强错误处理）
         match self.api_client.get::<SwapQuoteResponse>(&url).await {
-            Ok(response) => Ok(response),
+            Ok(mut response) => {
+                response.route_preference.get_or_insert(route);
+                Ok(response)
+            }
             Err(e) => {
                 // 将ApiError转换为友好的错误消息
                 let error_msg = match e {
@@ -223,6 +250,24 @@ impl SwapService {
         network: &str,
         slippage: Option<f64>,
     ) -> Result<SwapExecuteResponse, String> {
+        self.execute_with_request_id(wallet_id, from, to, amount, network, slippage, None)
+            .await
+    }
+
+    /// 执行Swap并附带客户端请求ID（用于幂等与历史记录分组，如批量小额兑换）
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_with_request_id(
+        &self,
+        wallet_id: &str,
+        from: &str,
+        to: &str,
+        amount: &str,
+        network: &str,
+        slippage: Option<f64>,
+        client_request_id: Option<String>,
+    ) -> Result<SwapExecuteResponse, String> {
+        kill_switch::ensure_allowed(&self.api_client, MoneyAction::Swap).await?;
+
         // 构建请求，匹配后端期望的字段名
         // 注意：后端期望 wallet_name，但前端传入的是 wallet_id
         // 如果后端支持 wallet_id，我们可以直接使用；否则需要获取 wallet_name
@@ -268,10 +313,16 @@ impl SwapService {
                 })
             }),
             password: None,
-            client_request_id: None,
+            client_request_id: client_request_id.clone(),
         };
 
-        self.api_client
+        // 客户端请求ID同时作为幂等键发送，避免重复提交产生两笔兑换
+        let client = match client_request_id.as_deref() {
+            Some(key) => self.api_client.with_idempotency_key(key),
+            None => (*self.api_client).clone(),
+        };
+
+        client
             .post::<SwapExecuteResponse, SwapExecuteRequest>("/api/v1/swap/execute", &request)
             .await
             .map_err(|e| format!("Failed to execute swap: {}", e))
@@ -287,6 +338,16 @@ impl SwapService {
             .map_err(|e| format!("Failed to get swap status: {}", e))
     }
 
+    /// 获取兑换所在区块中同一池子的相邻交易（用于夹子攻击检测）
+    pub async fn get_pool_activity(&self, swap_id: &str) -> Result<PoolActivityResponse, String> {
+        self.api_client
+            .get::<PoolActivityResponse>(&crate::shared::api_endpoints::swap::pool_activity(
+                swap_id,
+            ))
+            .await
+            .map_err(|e| format!("Failed to get pool activity: {}", e))
+    }
+
     /// 更新Swap交易状态（企业级实现）
     pub async fn update_status(
         &self,
@@ -317,6 +378,34 @@ impl SwapService {
     }
 }
 
+/// 兑换所在区块中同一池子的一笔交易
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoolTx {
+    pub tx_hash: String,
+    /// 区块内的交易序号
+    pub tx_index: u32,
+    pub sender: String,
+    pub token_in: String,
+    pub token_out: String,
+    #[serde(default)]
+    pub amount_in: Option<String>,
+    #[serde(default)]
+    pub amount_out: Option<String>,
+}
+
+/// 兑换所在区块的池子活动（后端分析接口，按区块日志整理）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoolActivityResponse {
+    pub block_number: u64,
+    #[serde(default)]
+    pub pool_address: Option<String>,
+    /// 用户兑换交易在区块内的序号
+    pub user_tx_index: u32,
+    /// 同一池子的其他交易（不含用户交易）
+    #[serde(default)]
+    pub transactions: Vec<PoolTx>,
+}
+
 /// Swap状态响应
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SwapStatusResponse {
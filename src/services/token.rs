@@ -2,6 +2,7 @@
 //! 提供代币信息查询、余额查询、代币列表等功能
 
 use crate::services::address_detector::ChainType;
+use crate::services::token_metadata::TokenMetadataStore;
 use crate::shared::api::ApiClient;
 use crate::shared::state::AppState;
 use anyhow::{anyhow, Result};
@@ -26,6 +27,9 @@ pub struct TokenInfo {
     pub logo_url: Option<String>,
     /// 是否为原生代币
     pub is_native: bool,
+    /// 疑似垃圾代币（自动检测到但未验证），不加载其图标链接
+    #[serde(default)]
+    pub is_spam: bool,
 }
 
 /// 代币余额
@@ -62,14 +66,17 @@ impl TokenService {
     /// 代币列表（包含原生代币和常见ERC-20代币）
     pub async fn get_token_list(&self, chain: ChainType) -> Result<Vec<TokenInfo>> {
         // 首先尝试从后端API获取
-        match self.get_token_list_from_api(chain).await {
-            Ok(tokens) => Ok(tokens),
+        let mut tokens = match self.get_token_list_from_api(chain).await {
+            Ok(tokens) => tokens,
             Err(e) => {
                 log::warn!("从API获取代币列表失败: {}，使用默认列表", e);
                 // 降级：使用内置的默认代币列表
-                Ok(Self::get_default_token_list(chain))
+                Self::get_default_token_list(chain)
             }
-        }
+        };
+        // 已做过链上校验的代币以链上精度与符号为准
+        TokenMetadataStore::load().apply_all(&mut tokens);
+        Ok(tokens)
     }
 
     /// 从后端API获取代币列表
@@ -101,6 +108,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: true,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0xdac17f958d2ee523a2206206994597c13d831ec7".to_string(),
@@ -110,6 +118,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string(),
@@ -119,6 +128,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0x6b175474e89094c44da98b954eedeac495271d0f".to_string(),
@@ -128,6 +138,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0x2260fac5e5542a773aa44fbcfedf7c193bc2c599".to_string(),
@@ -137,6 +148,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                 ]);
             }
@@ -151,6 +163,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: true,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0x55d398326f99059ff775485246999027b3197955".to_string(),
@@ -160,6 +173,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0x8ac76a51cc950d9822d68b83fe1ad97b32cd580d".to_string(),
@@ -169,6 +183,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                 ]);
             }
@@ -183,6 +198,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: true,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0xc2132d05d31c914a87c6611c10748aeb04b58e8f".to_string(),
@@ -192,6 +208,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                     TokenInfo {
                         address: "0x2791bca1f2de4661ed88a30c99a7a9449aa84174".to_string(),
@@ -201,6 +218,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: false,
+                        is_spam: false,
                     },
                 ]);
             }
@@ -217,6 +235,7 @@ impl TokenService {
                         chain,
                         logo_url: None,
                         is_native: true,
+                        is_spam: false,
                     });
                 }
             }
@@ -288,6 +307,7 @@ impl TokenService {
             chain,
             logo_url: None,
             is_native: true,
+            is_spam: false,
         })
     }
 
@@ -1,11 +1,12 @@
 // Token Detection Service
 // Auto-discover ERC20/SPL tokens with metadata querying and whitelist filtering
 
+use crate::services::address_detector::ChainType;
+use crate::services::token::TokenInfo;
 use crate::shared::error::AppError;
 use crate::shared::state::AppState;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use wasm_bindgen_futures::spawn_local;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -20,6 +21,41 @@ pub struct TokenMetadata {
     pub balance: Option<String>,
 }
 
+/// Whitelist of known legitimate tokens
+const WHITELIST: &[&str] = &[
+    // Ethereum mainnet
+    "0xdac17f958d2ee523a2206206994597c13d831ec7", // USDT
+    "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", // USDC
+    "0x6b175474e89094c44da98b954eedeac495271d0f", // DAI
+    "0x2260fac5e5542a773aa44fbcfedf7c193bc2c599", // WBTC
+    "0x514910771af9ca656af840dff83e8264ecf986ca", // LINK
+                                                  // Add more trusted tokens here
+];
+
+impl TokenMetadata {
+    /// Potential spam: neither verified by the backend nor whitelisted
+    pub fn is_potential_spam(&self) -> bool {
+        let address = self.contract_address.to_lowercase();
+        !self.verified && !WHITELIST.contains(&address.as_str())
+    }
+
+    /// Convert to `TokenInfo`; potential spam keeps no logo URL and is flagged
+    #[allow(dead_code)] // 用于代币检测功能
+    pub fn to_token_info(&self) -> Option<TokenInfo> {
+        let spam = self.is_potential_spam();
+        Some(TokenInfo {
+            address: self.contract_address.clone(),
+            symbol: self.symbol.clone(),
+            name: self.name.clone(),
+            decimals: self.decimals,
+            chain: ChainType::from_str(&self.chain)?,
+            logo_url: if spam { None } else { self.logo_uri.clone() },
+            is_native: false,
+            is_spam: spam,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenListResponse {
     pub tokens: Vec<TokenMetadata>,
@@ -67,6 +103,42 @@ impl TokenDetectionService {
         Ok(filtered)
     }
 
+    /// Detect tokens owned by an address, keeping potential spam (flagged via `is_potential_spam`)
+    ///
+    /// 供代币管理页面使用：用户需要看到垃圾代币才能隐藏或举报
+    pub async fn detect_tokens_with_spam(
+        &self,
+        chain: &str,
+        address: &str,
+    ) -> Result<Vec<TokenMetadata>, AppError> {
+        let api = self.app_state.get_api_client();
+        let url = format!("/api/v1/tokens/detect?chain={}&address={}", chain, address);
+        let response: TokenListResponse = api.get(&url).await?;
+        Ok(response.tokens)
+    }
+
+    /// Report a token as spam (or not spam) to improve the curated list
+    ///
+    /// # Arguments
+    /// * `chain` - Blockchain
+    /// * `contract_address` - Token contract address
+    /// * `is_spam` - User verdict
+    pub async fn report_spam(
+        &self,
+        chain: &str,
+        contract_address: &str,
+        is_spam: bool,
+    ) -> Result<(), AppError> {
+        let api = self.app_state.get_api_client();
+        let payload = serde_json::json!({
+            "chain": chain,
+            "contract_address": contract_address,
+            "is_spam": is_spam,
+        });
+        let _: serde_json::Value = api.post("/api/v1/tokens/spam-reports", &payload).await?;
+        Ok(())
+    }
+
     /// Get token metadata by contract address
     ///
     /// # Arguments
@@ -210,28 +282,9 @@ impl TokenDetectionService {
 
     /// Filter tokens by whitelist (security measure)
     fn filter_by_whitelist(tokens: Vec<TokenMetadata>) -> Vec<TokenMetadata> {
-        // Whitelist of known legitimate tokens
-        let whitelist: HashSet<&str> = [
-            // Ethereum mainnet
-            "0xdac17f958d2ee523a2206206994597c13d831ec7", // USDT
-            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", // USDC
-            "0x6b175474e89094c44da98b954eedeac495271d0f", // DAI
-            "0x2260fac5e5542a773aa44fbcfedf7c193bc2c599", // WBTC
-            "0x514910771af9ca656af840dff83e8264ecf986ca", // LINK
-                                                          // Add more trusted tokens here
-        ]
-        .iter()
-        .copied()
-        .collect();
-
         tokens
             .into_iter()
-            .filter(|token| {
-                // Always include verified tokens
-                token.verified ||
-                // Or if in whitelist
-                whitelist.contains(token.contract_address.to_lowercase().as_str())
-            })
+            .filter(|token| !token.is_potential_spam())
             .collect()
     }
 
@@ -276,11 +329,11 @@ pub fn use_auto_detect_tokens(chain: &str, address: &str) -> Signal<Option<Vec<T
                 .await
             {
                 Ok(detected) => {
-                    tracing::info!("Auto-detected {} tokens", detected.len());
+                    log::info!("Auto-detected {} tokens", detected.len());
                     tokens.set(Some(detected));
                 }
                 Err(e) => {
-                    tracing::error!("Token detection failed: {:?}", e);
+                    log::error!("Token detection failed: {:?}", e);
                 }
             }
         });
@@ -332,12 +385,17 @@ mod tests {
                 name: "Scam Token".to_string(),
                 symbol: "SCAM".to_string(),
                 decimals: 18,
-                logo_uri: None,
+                logo_uri: Some("https://claim-airdrop.example/logo.png".to_string()),
                 verified: false,
                 balance: Some("1000000".to_string()),
             },
         ];
 
+        let scam = tokens[1].to_token_info().expect("known chain");
+        assert!(scam.is_spam);
+        assert!(scam.logo_url.is_none());
+        assert!(!tokens[0].to_token_info().expect("known chain").is_spam);
+
         let filtered = TokenDetectionService::filter_by_whitelist(tokens);
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].symbol, "USDT");
@@ -0,0 +1,260 @@
+//! Token Logo - 代币图标解析
+//! 按顺序尝试多个来源（后端列表 → Trust Wallet 资源库 → 链上元数据），
+//! 解析结果缓存在内存与本地存储中；所有来源失败或疑似垃圾代币时使用由合约地址生成的标识图
+
+use crate::features::wallet::token_preferences::{identicon_hues, token_key};
+use crate::services::address_detector::ChainType;
+use crate::services::address_validation::to_checksum_address;
+use crate::services::token::TokenInfo;
+use crate::services::token_detection::TokenDetectionService;
+use crate::shared::state::AppState;
+use crate::shared::time;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+const STORAGE_KEY: &str = "token_logo_cache";
+/// 本地最多缓存的代币数
+const MAX_ENTRIES: usize = 500;
+/// 所有来源均失败的记录保留一天，之后重新尝试（新收录的图标可以生效）
+const FALLBACK_TTL_MS: f64 = 24.0 * 3600.0 * 1000.0;
+const TRUSTWALLET_ASSETS: &str =
+    "https://raw.githubusercontent.com/trustwallet/assets/master/blockchains";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedLogo {
+    /// None 表示所有来源均失败，使用标识图
+    url: Option<String>,
+    at: f64,
+}
+
+thread_local! {
+    static CACHE: RefCell<Option<HashMap<String, CachedLogo>>> = const { RefCell::new(None) };
+}
+
+fn with_cache<R>(f: impl FnOnce(&mut HashMap<String, CachedLogo>) -> R) -> R {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let map = cache.get_or_insert_with(|| LocalStorage::get(STORAGE_KEY).unwrap_or_default());
+        f(map)
+    })
+}
+
+/// 已解析的图标：`Some(Some(url))` 为可用图标，`Some(None)` 为使用标识图，`None` 为尚未解析
+pub fn cached(key: &str) -> Option<Option<String>> {
+    let now = time::now_ms();
+    with_cache(|map| {
+        map.get(key)
+            .filter(|entry| entry.url.is_some() || now - entry.at < FALLBACK_TTL_MS)
+            .map(|entry| entry.url.clone())
+    })
+}
+
+/// 记录解析结果（内存与本地存储）
+pub fn remember(key: &str, url: Option<String>) {
+    let entry = CachedLogo {
+        url,
+        at: time::now_ms(),
+    };
+    with_cache(|map| {
+        map.insert(key.to_string(), entry);
+        if map.len() > MAX_ENTRIES {
+            let mut by_age: Vec<(String, f64)> =
+                map.iter().map(|(k, v)| (k.clone(), v.at)).collect();
+            by_age.sort_by(|a, b| a.1.total_cmp(&b.1));
+            for (old, _) in by_age.into_iter().take(map.len() - MAX_ENTRIES) {
+                map.remove(&old);
+            }
+        }
+        let _ = LocalStorage::set(STORAGE_KEY, &*map);
+    });
+}
+
+/// 只允许 https 图片链接（拒绝 data:、javascript:、http: 等）
+pub fn is_safe_logo_url(url: &str) -> bool {
+    let url = url.trim();
+    url.len() <= 2048 && url.starts_with("https://") && !url.contains(char::is_whitespace)
+}
+
+/// Trust Wallet 资源库中的图标地址
+pub fn trustwallet_url(token: &TokenInfo) -> Option<String> {
+    let dir = match token.chain {
+        ChainType::Ethereum => "ethereum",
+        ChainType::BSC => "smartchain",
+        ChainType::Polygon => "polygon",
+        ChainType::Bitcoin => "bitcoin",
+        ChainType::Solana => "solana",
+        ChainType::TON => "ton",
+    };
+    if token.is_native {
+        return Some(format!("{}/{}/info/logo.png", TRUSTWALLET_ASSETS, dir));
+    }
+    let asset = match token.chain {
+        ChainType::Ethereum | ChainType::BSC | ChainType::Polygon => {
+            let hex = token.address.strip_prefix("0x")?.to_lowercase();
+            if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            to_checksum_address(&hex)
+        }
+        ChainType::Solana => token.address.clone(),
+        ChainType::Bitcoin | ChainType::TON => return None,
+    };
+    Some(format!(
+        "{}/{}/assets/{}/logo.png",
+        TRUSTWALLET_ASSETS, dir, asset
+    ))
+}
+
+/// 无需请求即可确定的候选图标（按优先级）；疑似垃圾代币没有候选，直接使用标识图
+pub fn static_sources(token: &TokenInfo) -> Vec<String> {
+    if token.is_spam {
+        return Vec::new();
+    }
+    token
+        .logo_url
+        .iter()
+        .filter(|url| is_safe_logo_url(url))
+        .cloned()
+        .chain(trustwallet_url(token))
+        .fold(Vec::new(), |mut sources, url| {
+            if !sources.contains(&url) {
+                sources.push(url);
+            }
+            sources
+        })
+}
+
+/// 预取图标：按组件的首选来源提前请求图片，写入浏览器缓存（已确定使用标识图的跳过）
+pub fn prefetch(tokens: &[TokenInfo]) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    for token in tokens {
+        let url = match cached(&token_key(token)) {
+            Some(url) => url,
+            None => static_sources(token).into_iter().next(),
+        };
+        let Some(url) = url else {
+            continue;
+        };
+        if let Ok(img) = document.create_element("img") {
+            let _ = img.set_attribute("referrerpolicy", "no-referrer");
+            let _ = img.set_attribute("src", &url);
+        }
+    }
+}
+
+/// 从链上元数据查询图标（元数据判定为疑似垃圾代币时不采用）
+pub async fn metadata_logo(app_state: AppState, token: &TokenInfo) -> Option<String> {
+    if token.is_spam || token.is_native {
+        return None;
+    }
+    let chain = match token.chain {
+        ChainType::Ethereum => "eth",
+        ChainType::BSC => "bsc",
+        ChainType::Polygon => "polygon",
+        ChainType::Solana => "solana",
+        ChainType::Bitcoin | ChainType::TON => return None,
+    };
+    let metadata = TokenDetectionService::new(app_state)
+        .get_token_metadata(chain, &token.address)
+        .await
+        .ok()?;
+    if metadata.is_potential_spam() {
+        return None;
+    }
+    metadata.logo_uri.filter(|url| is_safe_logo_url(url))
+}
+
+/// 由合约地址生成的对称像素标识图（SVG data URI，同一地址结果固定）
+pub fn identicon_data_uri(seed: &str) -> String {
+    let (hue_a, hue_b) = identicon_hues(seed);
+    let bits = seed
+        .to_lowercase()
+        .bytes()
+        .fold(0x811c9dc5u32, |h, b| {
+            (h ^ b as u32).wrapping_mul(0x01000193)
+        })
+        .rotate_left(7);
+    let mut cells = String::new();
+    for row in 0..5u32 {
+        for col in 0..3u32 {
+            if bits >> (row * 3 + col) & 1 == 1 {
+                cells.push_str(&format!(
+                    "<rect x='{}' y='{}' width='1' height='1'/>",
+                    col, row
+                ));
+                if col < 2 {
+                    cells.push_str(&format!(
+                        "<rect x='{}' y='{}' width='1' height='1'/>",
+                        4 - col,
+                        row
+                    ));
+                }
+            }
+        }
+    }
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='-1 -1 7 7' shape-rendering='crispEdges'>\
+         <rect x='-1' y='-1' width='7' height='7' fill='hsl({}, 65%, 90%)'/>\
+         <g fill='hsl({}, 60%, 45%)'>{}</g></svg>",
+        hue_a, hue_b, cells
+    );
+    format!(
+        "data:image/svg+xml,{}",
+        svg.replace('%', "%25")
+            .replace('<', "%3C")
+            .replace('>', "%3E")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(address: &str, logo_url: Option<&str>, is_spam: bool) -> TokenInfo {
+        TokenInfo {
+            address: address.to_string(),
+            symbol: "TKN".to_string(),
+            name: "Token".to_string(),
+            decimals: 18,
+            chain: ChainType::BSC,
+            logo_url: logo_url.map(str::to_string),
+            is_native: false,
+            is_spam,
+        }
+    }
+
+    #[test]
+    fn sources_are_ordered_and_spam_gets_none() {
+        let address = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        let sources = static_sources(&token(address, Some("https://cdn.example/t.png"), false));
+        assert_eq!(sources[0], "https://cdn.example/t.png");
+        assert_eq!(
+            sources[1],
+            format!(
+                "{}/smartchain/assets/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed/logo.png",
+                TRUSTWALLET_ASSETS
+            )
+        );
+
+        // 非 https 链接被忽略
+        let sources = static_sources(&token(address, Some("javascript:alert(1)"), false));
+        assert_eq!(sources.len(), 1);
+
+        assert!(
+            static_sources(&token(address, Some("https://cdn.example/t.png"), true)).is_empty()
+        );
+    }
+
+    #[test]
+    fn identicon_is_deterministic() {
+        let a = identicon_data_uri("0xAbC");
+        assert_eq!(a, identicon_data_uri("0xabc"));
+        assert_ne!(a, identicon_data_uri("0xabd"));
+        assert!(a.starts_with("data:image/svg+xml,%3Csvg"));
+        assert!(!a.contains('<') && !a.contains('#'));
+    }
+}
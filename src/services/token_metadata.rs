@@ -0,0 +1,505 @@
+//! Token Metadata - 代币精度与符号链上校验
+//!
+//! 代币列表（后端或内置默认列表）中的精度、符号可能有误，精度错误会导致余额和转账金额
+//! 差出若干数量级。会话中首次使用某个代币（选择、查询余额）时，通过 eth_call 读取合约的
+//! `decimals()` 与 `symbol()` 与列表比对：
+//! - 不一致时以链上数据为准，记录遥测并在代币详情中提示“已按链上数据校正”
+//! - 校验结果持久保存（LocalStorage），列表记录未变化时不再重复查询
+//! - 多个代币通过 Multicall3 `aggregate3` 合并为每条链一次 eth_call（资产页常用）
+//! - 查询失败时保留列表数据，本次会话内不再重试
+//!
+//! 只校验 EVM 链（Ethereum、BSC、Polygon）上的 ERC-20 代币。
+
+use crate::features::wallet::token_preferences::token_key;
+use crate::services::address_detector::ChainType;
+use crate::services::balance::BalanceService;
+use crate::services::token::TokenInfo;
+use crate::services::transaction::{SimulationRequest, TransactionService};
+use crate::shared::metrics;
+use crate::shared::state::AppState;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+/// Multicall3 合约（Ethereum、BSC、Polygon 上地址相同）
+pub const MULTICALL3: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+/// `aggregate3((address,bool,bytes)[])`
+const AGGREGATE3_SELECTOR: &str = "82ad56cb";
+/// `decimals()`
+const DECIMALS_SELECTOR: &str = "313ce567";
+/// `symbol()`
+const SYMBOL_SELECTOR: &str = "95d89b41";
+/// eth_call 使用的发送地址
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// 校验结果（LocalStorage）
+const STORAGE_KEY: &str = "token_metadata_verified";
+
+/// 是否需要校验：EVM 链上的合约代币
+pub fn is_verifiable(token: &TokenInfo) -> bool {
+    !token.is_native
+        && matches!(
+            token.chain,
+            ChainType::Ethereum | ChainType::BSC | ChainType::Polygon
+        )
+}
+
+/// 编码 `aggregate3` 调用：每个调用为 (合约地址, 调用数据)，均允许失败
+pub fn encode_aggregate3(calls: &[(&str, &str)]) -> Option<String> {
+    let count = calls.len();
+    let mut out = String::from("0x");
+    out.push_str(AGGREGATE3_SELECTOR);
+    out.push_str(&word(0x20));
+    out.push_str(&word(count));
+
+    // 每个元组：地址 | allowFailure | bytes 偏移 | bytes 长度 | bytes 内容（补齐到 32 字节）
+    let mut tuples = String::new();
+    let mut offset = count * 32;
+    for (target, calldata) in calls {
+        let address = target.trim_start_matches("0x");
+        let data = hex::decode(calldata.trim_start_matches("0x")).ok()?;
+        if address.len() != 40 || !address.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let padded_len = data.len().div_ceil(32) * 32;
+        let mut padded = data.clone();
+        padded.resize(padded_len, 0);
+
+        out.push_str(&word(offset));
+        tuples.push_str(&format!("{:0>64}", address.to_lowercase()));
+        tuples.push_str(&word(1));
+        tuples.push_str(&word(0x60));
+        tuples.push_str(&word(data.len()));
+        tuples.push_str(&hex::encode(padded));
+        offset += 32 * 4 + padded_len;
+    }
+    out.push_str(&tuples);
+    Some(out)
+}
+
+/// 解码 `aggregate3` 返回的 `(bool success, bytes returnData)[]`；失败的调用为 None
+pub fn decode_aggregate3(return_data: &str) -> Option<Vec<Option<Vec<u8>>>> {
+    let bytes = hex::decode(return_data.trim_start_matches("0x")).ok()?;
+    let array = read_usize(&bytes, 0)?;
+    let count = read_usize(&bytes, array)?;
+    let heads = array + 32;
+    (0..count)
+        .map(|i| {
+            let tuple = heads + read_usize(&bytes, heads + i * 32)?;
+            let success = read_usize(&bytes, tuple)? == 1;
+            let data = tuple + read_usize(&bytes, tuple + 32)?;
+            let len = read_usize(&bytes, data)?;
+            let content = bytes.get(data + 32..data + 32 + len)?.to_vec();
+            Some(success.then_some(content))
+        })
+        .collect()
+}
+
+/// 解码 `decimals()` 返回值（uint8）
+pub fn decode_decimals(data: &[u8]) -> Option<u8> {
+    let word = data.get(..32)?;
+    word[..31].iter().all(|b| *b == 0).then_some(word[31])
+}
+
+/// 解码 `symbol()` 返回值：标准 ABI string，或早期合约（如 MKR）使用的 bytes32
+pub fn decode_symbol(data: &[u8]) -> Option<String> {
+    let raw = if data.len() == 32 {
+        let end = data.iter().position(|b| *b == 0).unwrap_or(32);
+        data[..end].to_vec()
+    } else {
+        let offset = read_usize(data, 0)?;
+        let len = read_usize(data, offset)?;
+        data.get(offset + 32..offset + 32 + len)?.to_vec()
+    };
+    let symbol = String::from_utf8(raw).ok()?.trim().to_string();
+    (!symbol.is_empty()).then_some(symbol)
+}
+
+fn word(value: usize) -> String {
+    format!("{:064x}", value)
+}
+
+/// 读取 32 字节大端整数（超出 usize 范围视为无效）
+fn read_usize(data: &[u8], at: usize) -> Option<usize> {
+    let word = data.get(at..at.checked_add(32)?)?;
+    let (high, low) = word.split_at(24);
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+    let value = u64::from_be_bytes(low.try_into().ok()?);
+    usize::try_from(value).ok()
+}
+
+/// 单个代币的校验结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerifiedMetadata {
+    /// 链上精度
+    pub decimals: u8,
+    /// 链上符号
+    pub symbol: String,
+    /// 校验时列表中的精度
+    pub listed_decimals: u8,
+    /// 校验时列表中的符号
+    pub listed_symbol: String,
+    /// 校验时间（Unix 秒）
+    pub verified_at: u64,
+}
+
+impl VerifiedMetadata {
+    /// 列表数据与链上不一致
+    pub fn corrected(&self) -> bool {
+        self.decimals != self.listed_decimals || self.symbol != self.listed_symbol
+    }
+
+    /// 该校验结果是否针对当前列表记录（列表记录变化后需要重新校验）
+    fn covers(&self, token: &TokenInfo) -> bool {
+        (token.decimals == self.listed_decimals && token.symbol == self.listed_symbol)
+            || (token.decimals == self.decimals && token.symbol == self.symbol)
+    }
+}
+
+/// 已校验代币（键为 [`token_key`]）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenMetadataStore {
+    #[serde(default)]
+    entries: HashMap<String, VerifiedMetadata>,
+}
+
+impl TokenMetadataStore {
+    pub fn load() -> Self {
+        LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    /// 适用于当前列表记录的校验结果
+    pub fn get(&self, token: &TokenInfo) -> Option<&VerifiedMetadata> {
+        self.entries
+            .get(&token_key(token))
+            .filter(|v| v.covers(token))
+    }
+
+    /// 列表数据被链上数据校正时的校验结果（代币详情据此显示提示）
+    pub fn correction(&self, token: &TokenInfo) -> Option<&VerifiedMetadata> {
+        self.get(token).filter(|v| v.corrected())
+    }
+
+    pub fn insert(&mut self, token: &TokenInfo, verified: VerifiedMetadata) {
+        self.entries.insert(token_key(token), verified);
+    }
+
+    /// 用校验结果覆盖代币的精度与符号，返回是否有改动
+    pub fn apply(&self, token: &mut TokenInfo) -> bool {
+        let Some(verified) = self.get(token) else {
+            return false;
+        };
+        let changed = token.decimals != verified.decimals || token.symbol != verified.symbol;
+        token.decimals = verified.decimals;
+        token.symbol = verified.symbol.clone();
+        changed
+    }
+
+    /// 内置代币表（只有地址与精度）中代币的链上精度
+    pub fn decimals_for(&self, chain: ChainType, address: &str, listed: u8) -> u8 {
+        let key = format!("{}:{}", chain.as_str(), address.to_lowercase());
+        self.entries
+            .get(&key)
+            .filter(|v| v.listed_decimals == listed)
+            .map_or(listed, |v| v.decimals)
+    }
+
+    /// 对整个列表应用校验结果
+    pub fn apply_all(&self, tokens: &mut [TokenInfo]) {
+        for token in tokens {
+            self.apply(token);
+        }
+    }
+}
+
+thread_local! {
+    /// 本次会话中已发起校验的代币（失败后不再重试）
+    static ATTEMPTED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// 代币元数据校验服务
+#[derive(Clone, Copy)]
+pub struct TokenMetadataService {
+    app_state: AppState,
+}
+
+impl TokenMetadataService {
+    pub fn new(app_state: AppState) -> Self {
+        Self { app_state }
+    }
+
+    /// 校验单个代币，返回以链上数据为准的代币信息（无法校验时原样返回）
+    pub async fn verify(&self, token: &TokenInfo) -> TokenInfo {
+        self.verify_batch(std::slice::from_ref(token))
+            .await
+            .pop()
+            .unwrap_or_else(|| token.clone())
+    }
+
+    /// 批量校验：每条链合并为一次 Multicall3 调用，返回顺序与输入一致
+    pub async fn verify_batch(&self, tokens: &[TokenInfo]) -> Vec<TokenInfo> {
+        let mut store = TokenMetadataStore::load();
+        let mut by_chain: Vec<(ChainType, Vec<&TokenInfo>)> = Vec::new();
+        for token in tokens {
+            if !is_verifiable(token) || store.get(token).is_some() {
+                continue;
+            }
+            let first_attempt = ATTEMPTED.with(|a| a.borrow_mut().insert(token_key(token)));
+            if !first_attempt {
+                continue;
+            }
+            match by_chain.iter_mut().find(|(chain, _)| *chain == token.chain) {
+                Some((_, pending)) => pending.push(token),
+                None => by_chain.push((token.chain, vec![token])),
+            }
+        }
+
+        let mut any_corrected = false;
+        let mut any_verified = false;
+        for (chain, pending) in by_chain {
+            let results = match self.fetch_onchain(chain, &pending).await {
+                Ok(results) => results,
+                Err(e) => {
+                    log::warn!("代币元数据链上校验失败（{}）: {}", chain.label(), e);
+                    continue;
+                }
+            };
+            for (token, onchain) in pending.into_iter().zip(results) {
+                let Some((decimals, symbol)) = onchain else {
+                    continue;
+                };
+                let verified = VerifiedMetadata {
+                    decimals,
+                    symbol,
+                    listed_decimals: token.decimals,
+                    listed_symbol: token.symbol.clone(),
+                    verified_at: crate::shared::time::now_unix(),
+                };
+                if verified.corrected() {
+                    log::warn!(
+                        "代币列表数据与链上不一致 {}: 精度 {} → {}，符号 {} → {}",
+                        token_key(token),
+                        verified.listed_decimals,
+                        verified.decimals,
+                        verified.listed_symbol,
+                        verified.symbol
+                    );
+                    metrics::record_token_metadata_correction();
+                    any_corrected = true;
+                }
+                store.insert(token, verified);
+                any_verified = true;
+            }
+        }
+
+        if any_verified {
+            store.save();
+        }
+        if any_corrected {
+            // 按旧精度缓存的余额需要重新查询
+            BalanceService::new(self.app_state).invalidate_cache();
+        }
+
+        tokens
+            .iter()
+            .map(|token| {
+                let mut token = token.clone();
+                store.apply(&mut token);
+                token
+            })
+            .collect()
+    }
+
+    /// 一次 eth_call 读取多个代币的 (精度, 符号)；单个代币读取失败时为 None
+    async fn fetch_onchain(
+        &self,
+        chain: ChainType,
+        tokens: &[&TokenInfo],
+    ) -> Result<Vec<Option<(u8, String)>>, String> {
+        let calls: Vec<(&str, &str)> = tokens
+            .iter()
+            .flat_map(|t| {
+                [
+                    (t.address.as_str(), DECIMALS_SELECTOR),
+                    (t.address.as_str(), SYMBOL_SELECTOR),
+                ]
+            })
+            .collect();
+        let data = encode_aggregate3(&calls).ok_or("代币合约地址无效")?;
+        let result = TransactionService::new(self.app_state)
+            .simulate(&SimulationRequest {
+                chain: chain.as_str().to_string(),
+                from: ZERO_ADDRESS.to_string(),
+                to: MULTICALL3.to_string(),
+                value: "0".to_string(),
+                data,
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        if !result.success {
+            return Err(result
+                .revert_reason
+                .unwrap_or_else(|| "调用回滚".to_string()));
+        }
+        let returns = result
+            .return_data
+            .as_deref()
+            .and_then(decode_aggregate3)
+            .filter(|r| r.len() == calls.len())
+            .ok_or("Multicall 返回数据无效")?;
+        Ok(returns
+            .chunks(2)
+            .map(|pair| {
+                let decimals = pair[0].as_deref().and_then(decode_decimals)?;
+                let symbol = pair[1].as_deref().and_then(decode_symbol)?;
+                Some((decimals, symbol))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+
+    fn token(decimals: u8, symbol: &str) -> TokenInfo {
+        TokenInfo {
+            address: USDT.to_string(),
+            symbol: symbol.to_string(),
+            name: "Tether USD".to_string(),
+            decimals,
+            chain: ChainType::Ethereum,
+            logo_url: None,
+            is_native: false,
+            is_spam: false,
+        }
+    }
+
+    /// 按 ABI 编码 `(bool, bytes)[]` 返回值
+    fn encode_results(results: &[(bool, Vec<u8>)]) -> String {
+        let mut heads = String::new();
+        let mut tails = String::new();
+        let mut offset = results.len() * 32;
+        for (success, data) in results {
+            let padded_len = data.len().div_ceil(32) * 32;
+            let mut padded = data.clone();
+            padded.resize(padded_len, 0);
+            heads.push_str(&word(offset));
+            tails.push_str(&word(*success as usize));
+            tails.push_str(&word(0x40));
+            tails.push_str(&word(data.len()));
+            tails.push_str(&hex::encode(padded));
+            offset += 32 * 3 + padded_len;
+        }
+        format!("0x{}{}{}{}", word(0x20), word(results.len()), heads, tails)
+    }
+
+    fn abi_string(s: &str) -> Vec<u8> {
+        let mut data = hex::decode(word(0x20) + &word(s.len())).unwrap();
+        let mut bytes = s.as_bytes().to_vec();
+        bytes.resize(s.len().div_ceil(32) * 32, 0);
+        data.extend(bytes);
+        data
+    }
+
+    #[test]
+    fn encodes_aggregate3_calls() {
+        let data =
+            encode_aggregate3(&[(USDT, DECIMALS_SELECTOR), (USDT, SYMBOL_SELECTOR)]).unwrap();
+        let body = hex::decode(&data[2 + 8..]).unwrap();
+        assert!(data.starts_with("0x82ad56cb"));
+        assert_eq!(read_usize(&body, 0), Some(0x20));
+        assert_eq!(read_usize(&body, 0x20), Some(2));
+        // 元组偏移从数组内容起算：2 个偏移之后依次排列，每个元组 5 个字
+        assert_eq!(read_usize(&body, 0x40), Some(64));
+        assert_eq!(read_usize(&body, 0x60), Some(64 + 160));
+        let first = 0x40 + 64;
+        assert_eq!(
+            hex::encode(&body[first + 12..first + 32]),
+            USDT.trim_start_matches("0x").to_lowercase()
+        );
+        assert_eq!(read_usize(&body, first + 32), Some(1));
+        assert_eq!(read_usize(&body, first + 96), Some(4));
+        assert_eq!(
+            hex::encode(&body[first + 128..first + 132]),
+            DECIMALS_SELECTOR
+        );
+        assert_eq!(body.len(), 0x40 + 64 + 2 * 160);
+
+        assert!(encode_aggregate3(&[("0x1234", DECIMALS_SELECTOR)]).is_none());
+    }
+
+    #[test]
+    fn decodes_results_decimals_and_symbols() {
+        let mut decimals = vec![0u8; 32];
+        decimals[31] = 6;
+        let mut bytes32_symbol = b"MKR".to_vec();
+        bytes32_symbol.resize(32, 0);
+        let encoded = encode_results(&[
+            (true, decimals),
+            (true, abi_string("USDT")),
+            (false, Vec::new()),
+            (true, bytes32_symbol),
+        ]);
+
+        let results = decode_aggregate3(&encoded).unwrap();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_deref().and_then(decode_decimals), Some(6));
+        assert_eq!(
+            results[1].as_deref().and_then(decode_symbol).as_deref(),
+            Some("USDT")
+        );
+        assert!(results[2].is_none());
+        assert_eq!(
+            results[3].as_deref().and_then(decode_symbol).as_deref(),
+            Some("MKR")
+        );
+
+        // 超出 uint8 的精度视为无效
+        let mut too_large = vec![0u8; 32];
+        too_large[30] = 1;
+        assert_eq!(decode_decimals(&too_large), None);
+        assert!(decode_aggregate3("0x1234").is_none());
+    }
+
+    #[test]
+    fn store_applies_onchain_values_until_the_list_entry_changes() {
+        let mut store = TokenMetadataStore::default();
+        let listed = token(18, "USDT");
+        store.insert(
+            &listed,
+            VerifiedMetadata {
+                decimals: 6,
+                symbol: "USDT".to_string(),
+                listed_decimals: 18,
+                listed_symbol: "USDT".to_string(),
+                verified_at: 0,
+            },
+        );
+
+        let mut corrected = listed.clone();
+        assert!(store.apply(&mut corrected));
+        assert_eq!(corrected.decimals, 6);
+        assert!(store.correction(&corrected).is_some());
+        // 已校正的代币再次应用不变
+        assert!(!store.apply(&mut corrected));
+
+        // 列表记录变化后需要重新校验
+        let mut changed = token(8, "USDT");
+        assert!(!store.apply(&mut changed));
+        assert_eq!(changed.decimals, 8);
+        assert!(store.get(&changed).is_none());
+
+        assert_eq!(store.decimals_for(ChainType::Ethereum, USDT, 18), 6);
+        assert_eq!(store.decimals_for(ChainType::BSC, USDT, 18), 18);
+    }
+}
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::features::activity::labels::TxLabels;
+use crate::services::tx_propagation::{self, PendingBroadcast, Propagation};
 use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints;
 use crate::shared::error::AppError;
+use crate::shared::kill_switch::{self, MoneyAction};
 use crate::shared::state::AppState;
 use gloo_timers::future::TimeoutFuture;
 
@@ -17,6 +21,42 @@ pub struct BroadcastResponse {
     pub status: String,
 }
 
+/// 广播并确认传播后的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastReceipt {
+    pub pending: PendingBroadcast,
+    pub propagation: Propagation,
+}
+
+/// 模拟执行请求（只在节点上 eth_call，不签名、不广播）
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationRequest {
+    pub chain: String,
+    pub from: String,
+    pub to: String,
+    /// 金额（wei，十进制字符串）
+    pub value: String,
+    pub data: String,
+}
+
+/// 模拟执行结果
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SimulationResult {
+    pub success: bool,
+    /// 执行成功时的返回数据
+    #[serde(default)]
+    pub return_data: Option<String>,
+    /// 节点已解析的回滚原因
+    #[serde(default)]
+    pub revert_reason: Option<String>,
+    /// 原始回滚数据（节点未解析时由前端解码）
+    #[serde(default)]
+    pub revert_data: Option<String>,
+    /// eth_estimateGas 结果（回滚时为空）
+    #[serde(default)]
+    pub gas_used: Option<u64>,
+}
+
 // 响应结构体已移除，直接使用 BroadcastResponse 和 TransactionStatus
 // deserialize 方法已自动提取 data 字段
 
@@ -26,6 +66,15 @@ pub struct TransactionStatus {
     pub status: String,
     pub confirmations: u64,
     pub last_seen: Option<u64>,
+    /// 所在区块高度（未确认时为空）
+    #[serde(default)]
+    pub block_number: Option<u64>,
+    /// 实际消耗的 Gas（EVM 收据，未上链时为空）
+    #[serde(default)]
+    pub gas_used: Option<u64>,
+    /// 交易设置的 Gas 上限（EVM）
+    #[serde(default)]
+    pub gas_limit: Option<u64>,
 }
 
 #[derive(Clone, Copy)]
@@ -80,12 +129,53 @@ impl TransactionService {
         };
 
         let api = self.api();
+        // 最后一道关口：全部签名被紧急暂停时不再广播
+        kill_switch::ensure_allowed(&api, MoneyAction::Sign)
+            .await
+            .map_err(AppError::Validation)?;
         // ✅ v1标准路径
         api.post("/api/v1/transactions/broadcast", &payload)
             .await
             .map_err(AppError::Api)
     }
 
+    /// 广播并在 30 秒内确认交易已被节点收到（必要时通过备用端点重新广播）
+    pub async fn broadcast_verified(
+        &self,
+        chain: &str,
+        signed_tx: &str,
+    ) -> Result<BroadcastReceipt, AppError> {
+        let response = self.broadcast(chain, signed_tx).await?;
+        let pending = PendingBroadcast {
+            chain: chain.to_string(),
+            tx_hash: response.tx_hash,
+            signed_tx: signed_tx.to_string(),
+        };
+        // 演示模式返回的是虚构哈希，节点上不可能查到
+        let propagation = if self.api().is_demo() {
+            Propagation::Unverified
+        } else {
+            tx_propagation::verify(&pending, false).await
+        };
+        Ok(BroadcastReceipt {
+            pending,
+            propagation,
+        })
+    }
+
+    /// 用户确认后重新广播同一份签名数据（后端与备用端点），并再次确认传播
+    ///
+    /// 全部签名被紧急暂停时不再向任何端点发送
+    pub async fn rebroadcast(&self, pending: &PendingBroadcast) -> Result<Propagation, AppError> {
+        kill_switch::ensure_allowed(&self.api(), MoneyAction::Sign)
+            .await
+            .map_err(AppError::Validation)?;
+        if let Err(e) = self.broadcast(&pending.chain, &pending.signed_tx).await {
+            log::warn!("后端重新广播失败: {}", e);
+        }
+        Ok(tx_propagation::verify(pending, true).await)
+    }
+
     pub async fn status(&self, tx_hash: &str) -> Result<TransactionStatus, AppError> {
         // Backend currently expects a `chain` query parameter.
         // Default to ethereum for backward compatibility when the caller doesn't know the chain.
@@ -124,18 +214,30 @@ impl TransactionService {
         Ok(response.nonce)
     }
 
+    /// 模拟执行一笔 EVM 交易（不会上链）
+    pub async fn simulate(
+        &self,
+        request: &SimulationRequest,
+    ) -> Result<SimulationResult, AppError> {
+        self.api()
+            .post(api_endpoints::transactions::SIMULATE, request)
+            .await
+            .map_err(AppError::Api)
+    }
+
     // 注意：get_recent_blockhash和get_seqno的完整实现在下面（202-259行）
 
-    #[allow(dead_code)] // 用于交易确认等待功能
+    /// 轮询交易状态，直到确认、失败或达到最大次数
     pub async fn wait_for_confirmation(
         &self,
         tx_hash: &str,
+        chain: &str,
         max_attempts: u32,
         interval_ms: u32,
     ) -> Result<TransactionStatus, AppError> {
         let mut attempts = 0;
         loop {
-            let status = self.status(tx_hash).await?;
+            let status = self.status_on_chain(tx_hash, chain).await?;
             if status.status == "confirmed" || status.status == "failed" {
                 return Ok(status);
             }
@@ -172,6 +274,24 @@ impl TransactionService {
         Ok(data.into_list())
     }
 
+    /// 获取后端同步的交易标签
+    pub async fn get_labels(&self) -> Result<TxLabels, AppError> {
+        self.api()
+            .get(api_endpoints::transactions::LABELS)
+            .await
+            .map_err(AppError::Api)
+    }
+
+    /// 上传交易标签（整体覆盖，合并在客户端完成）
+    pub async fn put_labels(&self, labels: &TxLabels) -> Result<(), AppError> {
+        let _: serde_json::Value = self
+            .api()
+            .put(api_endpoints::transactions::LABELS, labels)
+            .await
+            .map_err(AppError::Api)?;
+        Ok(())
+    }
+
     /// Get Solana recent blockhash
     pub async fn get_recent_blockhash(&self, _chain: &str) -> Result<String, AppError> {
         let api = self.api();
@@ -216,6 +336,12 @@ pub struct TransactionHistoryItem {
     pub token: String,
     pub timestamp: u64,
     pub fee: String,
+    /// 费率（sat/vB，仅 Bitcoin 交易返回）
+    #[serde(default)]
+    pub fee_rate: Option<u64>,
+    /// 本交易通过 RBF 替换的原交易哈希（仅 Bitcoin 交易返回）
+    #[serde(default)]
+    pub replaces: Option<String>,
 }
 
 // HistoryApiResponse 已移除，直接使用 Option<Vec<TransactionHistoryItem>>
@@ -0,0 +1,326 @@
+//! Tx Propagation - 广播后的传播确认
+//! 广播接口返回哈希并不代表交易已进入内存池（节点可能直接丢弃）。
+//! 广播后 30 秒内向各 RPC 端点查询交易是否已知；一直查不到时，用同一份签名数据
+//! 通过备用端点重新广播；窗口结束仍未找到，交给用户决定是否再次广播。
+//! 每次确认结果（哪个端点已收到、向哪些端点重新广播过）记录在本地，便于排查。
+
+use crate::blockchain::bitcoin::BitcoinAdapter;
+use crate::blockchain::ethereum::EthereumAdapter;
+use crate::blockchain::rpc::RpcClient;
+use crate::blockchain::solana::SolanaAdapter;
+use crate::blockchain::traits::ChainAdapter;
+use crate::shared::time;
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::future::TimeoutFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// 确认窗口
+pub const VERIFY_WINDOW_MS: f64 = 30_000.0;
+/// 首次查询前等待节点转发
+const INITIAL_DELAY_MS: u32 = 2_000;
+/// 查询间隔
+const CHECK_INTERVAL_MS: u32 = 5_000;
+/// 超过该时间仍未找到，则通过备用端点重新广播
+const REBROADCAST_AFTER_MS: f64 = 10_000.0;
+
+const DIAGNOSTICS_STORAGE_KEY: &str = "broadcast_diagnostics";
+const MAX_DIAGNOSTICS: usize = 20;
+
+/// 各链用于查询与重新广播的公共 RPC 端点（TON 交易无法按哈希查询，暂不确认）
+pub fn endpoints(chain: &str) -> &'static [&'static str] {
+    match chain.to_lowercase().as_str() {
+        "ethereum" | "eth" => &[
+            "https://cloudflare-eth.com",
+            "https://eth.llamarpc.com",
+            "https://ethereum-rpc.publicnode.com",
+        ],
+        "bsc" | "binance" => &[
+            "https://bsc-dataseed.binance.org",
+            "https://bsc-rpc.publicnode.com",
+        ],
+        "polygon" | "matic" => &[
+            "https://polygon-rpc.com",
+            "https://polygon-bor-rpc.publicnode.com",
+        ],
+        "bitcoin" | "btc" => &["https://blockstream.info/api", "https://mempool.space/api"],
+        "solana" | "sol" => &[
+            "https://api.mainnet-beta.solana.com",
+            "https://solana-rpc.publicnode.com",
+        ],
+        _ => &[],
+    }
+}
+
+/// 已广播、等待确认传播的交易
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingBroadcast {
+    pub chain: String,
+    pub tx_hash: String,
+    /// 原始签名数据（重新广播时原样发送）
+    pub signed_tx: String,
+}
+
+/// 传播确认结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Propagation {
+    /// 已被某个端点收到（查询到或重新广播被接受）
+    Seen { endpoint: String },
+    /// 确认窗口内所有端点都查不到
+    NotFound,
+    /// 该链暂不支持确认（或演示模式）
+    Unverified,
+}
+
+impl Propagation {
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Propagation::NotFound)
+    }
+}
+
+/// 单笔交易的确认记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BroadcastDiagnostic {
+    pub chain: String,
+    pub tx_hash: String,
+    /// 确认收到交易的端点
+    pub accepted_by: Option<String>,
+    /// 重新广播过的端点
+    #[serde(default)]
+    pub rebroadcast_to: Vec<String>,
+    pub checked_at_ms: u64,
+}
+
+/// 最近的确认记录（最新在前）
+pub fn diagnostics() -> Vec<BroadcastDiagnostic> {
+    LocalStorage::get(DIAGNOSTICS_STORAGE_KEY).unwrap_or_default()
+}
+
+fn record_diagnostic(entry: BroadcastDiagnostic) {
+    log::info!(
+        "交易传播确认: chain={}, tx_hash={}, accepted_by={:?}, rebroadcast_to={:?}",
+        entry.chain,
+        entry.tx_hash,
+        entry.accepted_by,
+        entry.rebroadcast_to
+    );
+    let mut list = diagnostics();
+    list.retain(|d| d.tx_hash != entry.tx_hash);
+    list.insert(0, entry);
+    list.truncate(MAX_DIAGNOSTICS);
+    let _ = LocalStorage::set(DIAGNOSTICS_STORAGE_KEY, &list);
+}
+
+/// 签名数据转为原始字节（EVM/Bitcoin 为十六进制，Solana/TON 为 base64）
+pub fn decode_payload(chain: &str, signed_tx: &str) -> Option<Vec<u8>> {
+    let signed_tx = signed_tx.trim();
+    match chain.to_lowercase().as_str() {
+        "solana" | "sol" | "ton" => base64::engine::general_purpose::STANDARD
+            .decode(signed_tx)
+            .ok(),
+        _ => hex::decode(signed_tx.trim_start_matches("0x")).ok(),
+    }
+}
+
+/// 端点的查询结果是否表示交易已知
+pub fn is_known_response(chain: &str, result: &Value) -> bool {
+    match chain.to_lowercase().as_str() {
+        // getSignatureStatuses：未知签名对应 null
+        "solana" | "sol" => result
+            .as_array()
+            .and_then(|statuses| statuses.first())
+            .is_some_and(|status| !status.is_null()),
+        // Esplora：GET /tx/:txid 返回交易详情
+        "bitcoin" | "btc" => result.get("txid").is_some(),
+        // eth_getTransactionByHash：未知交易返回 null
+        _ => !result.is_null(),
+    }
+}
+
+/// 重新广播被拒绝的原因是否说明节点已有该交易
+pub fn is_already_known_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "already known",
+        "known transaction",
+        "already imported",
+        "txn-already-in-mempool",
+        "txn-already-known",
+        "already in block chain",
+        "already been processed",
+        "alreadyprocessed",
+    ]
+    .iter()
+    .any(|pattern| message.contains(pattern))
+}
+
+fn evm_chain_id(chain: &str) -> u64 {
+    match chain.to_lowercase().as_str() {
+        "bsc" | "binance" => 56,
+        "polygon" | "matic" => 137,
+        _ => 1,
+    }
+}
+
+async fn is_known_at(chain: &str, endpoint: &str, tx_hash: &str) -> Result<bool> {
+    let rpc = RpcClient::new(vec![endpoint.to_string()], chain.to_string());
+    let result: Value = match chain.to_lowercase().as_str() {
+        "solana" | "sol" => {
+            rpc.post(
+                "getSignatureStatuses",
+                json!([[tx_hash], { "searchTransactionHistory": true }]),
+            )
+            .await?
+        }
+        "bitcoin" | "btc" => rpc.get_json(&format!("/tx/{}", tx_hash)).await?,
+        _ => {
+            rpc.post("eth_getTransactionByHash", json!([tx_hash]))
+                .await?
+        }
+    };
+    Ok(is_known_response(chain, &result))
+}
+
+async fn rebroadcast_at(chain: &str, endpoint: &str, payload: &[u8]) -> Result<String> {
+    let urls = vec![endpoint.to_string()];
+    let adapter: Box<dyn ChainAdapter> = match chain.to_lowercase().as_str() {
+        "solana" | "sol" => Box::new(SolanaAdapter::new(urls, "mainnet-beta".to_string())),
+        "bitcoin" | "btc" => Box::new(BitcoinAdapter::new(urls, "mainnet".to_string())),
+        "ethereum" | "eth" | "bsc" | "binance" | "polygon" | "matic" => {
+            Box::new(EthereumAdapter::new(urls, evm_chain_id(chain)))
+        }
+        other => return Err(anyhow!("不支持重新广播: {}", other)),
+    };
+    adapter.broadcast_transaction(payload).await
+}
+
+/// 第一个已知该交易的端点
+async fn find_endpoint(chain: &str, tx_hash: &str) -> Option<String> {
+    for endpoint in endpoints(chain) {
+        match is_known_at(chain, endpoint, tx_hash).await {
+            Ok(true) => return Some(endpoint.to_string()),
+            Ok(false) => {}
+            Err(e) => log::debug!("查询交易失败 ({}): {}", endpoint, e),
+        }
+    }
+    None
+}
+
+/// 通过所有端点重新广播同一份签名数据，返回接受该交易的端点
+async fn rebroadcast(pending: &PendingBroadcast, attempted: &mut Vec<String>) -> Option<String> {
+    let payload = decode_payload(&pending.chain, &pending.signed_tx)?;
+    let mut accepted = None;
+    for endpoint in endpoints(&pending.chain) {
+        attempted.push(endpoint.to_string());
+        match rebroadcast_at(&pending.chain, endpoint, &payload).await {
+            Ok(_) => accepted = accepted.or(Some(endpoint.to_string())),
+            Err(e) if is_already_known_error(&e.to_string()) => {
+                accepted = accepted.or(Some(endpoint.to_string()))
+            }
+            Err(e) => log::warn!("重新广播失败 ({}): {}", endpoint, e),
+        }
+    }
+    accepted
+}
+
+/// 确认交易已传播；`rebroadcast_first` 为 true 时先重新广播（用户手动重试）
+pub async fn verify(pending: &PendingBroadcast, rebroadcast_first: bool) -> Propagation {
+    if endpoints(&pending.chain).is_empty() {
+        return Propagation::Unverified;
+    }
+
+    let started = time::now_ms();
+    let mut rebroadcast_to = Vec::new();
+    let mut rebroadcasted = false;
+    let mut accepted_by = None;
+
+    if rebroadcast_first {
+        accepted_by = rebroadcast(pending, &mut rebroadcast_to).await;
+        rebroadcasted = true;
+    } else {
+        TimeoutFuture::new(INITIAL_DELAY_MS).await;
+    }
+
+    while accepted_by.is_none() {
+        accepted_by = find_endpoint(&pending.chain, &pending.tx_hash).await;
+        if accepted_by.is_some() {
+            break;
+        }
+
+        let elapsed = time::now_ms() - started;
+        if !rebroadcasted && elapsed >= REBROADCAST_AFTER_MS {
+            log::warn!(
+                "交易广播后 {:.0} 秒仍未在节点中找到，尝试重新广播: {}",
+                elapsed / 1000.0,
+                pending.tx_hash
+            );
+            accepted_by = rebroadcast(pending, &mut rebroadcast_to).await;
+            rebroadcasted = true;
+            continue;
+        }
+        if elapsed >= VERIFY_WINDOW_MS {
+            break;
+        }
+        TimeoutFuture::new(CHECK_INTERVAL_MS).await;
+    }
+
+    record_diagnostic(BroadcastDiagnostic {
+        chain: pending.chain.clone(),
+        tx_hash: pending.tx_hash.clone(),
+        accepted_by: accepted_by.clone(),
+        rebroadcast_to,
+        checked_at_ms: time::now_ms() as u64,
+    });
+
+    match accepted_by {
+        Some(endpoint) => Propagation::Seen { endpoint },
+        None => Propagation::NotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_payload_per_chain() {
+        assert_eq!(
+            decode_payload("ethereum", "0xf86b01"),
+            Some(vec![0xf8, 0x6b, 0x01])
+        );
+        assert_eq!(decode_payload("bitcoin", "0200"), Some(vec![0x02, 0x00]));
+        assert_eq!(decode_payload("solana", "AQID"), Some(vec![1, 2, 3]));
+        assert_eq!(decode_payload("ethereum", "0xzz"), None);
+        assert!(endpoints("ton").is_empty());
+        assert!(endpoints("BSC").len() >= 2);
+    }
+
+    #[test]
+    fn known_response_per_chain() {
+        assert!(!is_known_response("ethereum", &Value::Null));
+        assert!(is_known_response("polygon", &json!({ "hash": "0xabc" })));
+        assert!(!is_known_response("solana", &json!([null])));
+        assert!(is_known_response(
+            "solana",
+            &json!([{ "slot": 1, "confirmations": 0 }])
+        ));
+        assert!(!is_known_response("solana", &json!([])));
+        assert!(is_known_response("bitcoin", &json!({ "txid": "ab" })));
+        assert!(!is_known_response("bitcoin", &json!({})));
+    }
+
+    #[test]
+    fn already_known_errors_count_as_accepted() {
+        assert!(is_already_known_error(
+            "RPC error: Object {\"code\": Number(-32000), \"message\": String(\"already known\")}"
+        ));
+        assert!(is_already_known_error(
+            "sendrawtransaction RPC error: txn-already-in-mempool"
+        ));
+        assert!(is_already_known_error("Transaction AlreadyProcessed"));
+        assert!(!is_already_known_error("nonce too low"));
+        assert!(!is_already_known_error("insufficient funds for gas"));
+    }
+}
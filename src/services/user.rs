@@ -1,6 +1,7 @@
-//! 用户服务 - 获取用户信息和KYC状态
+//! 用户服务 - 获取用户信息、KYC状态与通知偏好
 
 use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -25,6 +26,187 @@ pub struct UserInfoResponse {
     pub created_at: String,
 }
 
+/// 通知类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCategory {
+    /// 收款、兑换与订单状态
+    Transactions,
+    PriceAlerts,
+    /// 新设备登录、白名单变更等（不可关闭）
+    Security,
+    Marketing,
+}
+
+impl NotificationCategory {
+    pub const ALL: [NotificationCategory; 4] = [
+        NotificationCategory::Transactions,
+        NotificationCategory::PriceAlerts,
+        NotificationCategory::Security,
+        NotificationCategory::Marketing,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Transactions => "交易动态",
+            Self::PriceAlerts => "价格提醒",
+            Self::Security => "安全事件",
+            Self::Marketing => "活动与推广",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Transactions => "收款到账、兑换确认、订单状态变化",
+            Self::PriceAlerts => "关注代币的价格波动提醒",
+            Self::Security => "新设备登录、白名单变更、凭证异常，始终通知",
+            Self::Marketing => "新功能、活动与优惠",
+        }
+    }
+
+    /// 安全类通知按设计不可关闭
+    pub fn is_mandatory(self) -> bool {
+        self == Self::Security
+    }
+}
+
+/// 通知渠道
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationChannel {
+    InApp,
+    Push,
+    Email,
+}
+
+impl NotificationChannel {
+    pub const ALL: [NotificationChannel; 3] = [
+        NotificationChannel::InApp,
+        NotificationChannel::Push,
+        NotificationChannel::Email,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::InApp => "应用内",
+            Self::Push => "推送",
+            Self::Email => "邮件",
+        }
+    }
+}
+
+/// 邮件摘要频率（实时逐条发送，或按日/周汇总为一封）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestFrequency {
+    #[default]
+    Realtime,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub const ALL: [DigestFrequency; 3] = [
+        DigestFrequency::Realtime,
+        DigestFrequency::Daily,
+        DigestFrequency::Weekly,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Realtime => "实时",
+            Self::Daily => "每日摘要",
+            Self::Weekly => "每周摘要",
+        }
+    }
+}
+
+fn enabled() -> bool {
+    true
+}
+
+/// 通知偏好（本地保存在用户偏好中，登录后同步到后端，由后端负责邮件与推送投递）
+///
+/// 安全类通知不在此配置：无论开关如何都会在应用内和邮件中送达
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationPreferences {
+    #[serde(default = "enabled")]
+    pub transactions: bool,
+    #[serde(default = "enabled")]
+    pub price_alerts: bool,
+    #[serde(default)]
+    pub marketing: bool,
+    #[serde(default = "enabled")]
+    pub in_app: bool,
+    #[serde(default)]
+    pub push: bool,
+    #[serde(default = "enabled")]
+    pub email: bool,
+    #[serde(default)]
+    pub digest: DigestFrequency,
+    /// 最后修改时间（Unix 秒），与后端合并时较新者生效
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+impl Default for NotificationPreferences {
+    fn default() -> Self {
+        Self {
+            transactions: true,
+            price_alerts: true,
+            marketing: false,
+            in_app: true,
+            push: false,
+            email: true,
+            digest: DigestFrequency::Realtime,
+            updated_at: 0,
+        }
+    }
+}
+
+impl NotificationPreferences {
+    pub fn category_enabled(&self, category: NotificationCategory) -> bool {
+        match category {
+            NotificationCategory::Transactions => self.transactions,
+            NotificationCategory::PriceAlerts => self.price_alerts,
+            NotificationCategory::Security => true,
+            NotificationCategory::Marketing => self.marketing,
+        }
+    }
+
+    /// 设置类别开关；安全类不可关闭，调用被忽略
+    pub fn set_category(&mut self, category: NotificationCategory, on: bool) {
+        match category {
+            NotificationCategory::Transactions => self.transactions = on,
+            NotificationCategory::PriceAlerts => self.price_alerts = on,
+            NotificationCategory::Security => {}
+            NotificationCategory::Marketing => self.marketing = on,
+        }
+    }
+
+    pub fn channel_enabled(&self, channel: NotificationChannel) -> bool {
+        match channel {
+            NotificationChannel::InApp => self.in_app,
+            NotificationChannel::Push => self.push,
+            NotificationChannel::Email => self.email,
+        }
+    }
+
+    pub fn set_channel(&mut self, channel: NotificationChannel, on: bool) {
+        match channel {
+            NotificationChannel::InApp => self.in_app = on,
+            NotificationChannel::Push => self.push = on,
+            NotificationChannel::Email => self.email = on,
+        }
+    }
+
+    /// 某类通知是否经指定渠道送达（安全类始终经应用内和邮件送达）
+    pub fn delivers(&self, category: NotificationCategory, channel: NotificationChannel) -> bool {
+        if category.is_mandatory() && channel != NotificationChannel::Push {
+            return true;
+        }
+        self.category_enabled(category) && self.channel_enabled(channel)
+    }
+}
+
 /// 用户服务
 pub struct UserService {
     api_client: Arc<ApiClient>,
@@ -43,7 +225,7 @@ impl UserService {
             .get::<UserKycStatusResponse>("/api/v1/users/kyc/status")
             .await?;
 
-        tracing::info!("[UserService] get_kyc_status response: {:?}", response);
+        log::info!("[UserService] get_kyc_status response: {:?}", response);
 
         Ok(response)
     }
@@ -55,8 +237,82 @@ impl UserService {
             .get::<UserInfoResponse>("/api/v1/users/me")
             .await?;
 
-        tracing::info!("[UserService] get_user_info response: {:?}", response);
+        log::info!("[UserService] get_user_info response: {:?}", response);
 
         Ok(response)
     }
+
+    /// 获取后端保存的通知偏好（从未保存过时为 None）
+    pub async fn get_notification_preferences(&self) -> Result<Option<NotificationPreferences>> {
+        let response = self
+            .api_client
+            .get::<Option<NotificationPreferences>>(api_endpoints::users::NOTIFICATION_PREFERENCES)
+            .await?;
+        Ok(response)
+    }
+
+    /// 保存通知偏好（后端据此投递邮件、推送与摘要）
+    pub async fn update_notification_preferences(
+        &self,
+        preferences: &NotificationPreferences,
+    ) -> Result<()> {
+        let _: serde_json::Value = self
+            .api_client
+            .put(api_endpoints::users::NOTIFICATION_PREFERENCES, preferences)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn security_notifications_cannot_be_disabled() {
+        let mut prefs = NotificationPreferences::default();
+        prefs.set_category(NotificationCategory::Security, false);
+        prefs.set_channel(NotificationChannel::InApp, false);
+        prefs.set_channel(NotificationChannel::Email, false);
+        assert!(prefs.category_enabled(NotificationCategory::Security));
+        assert!(prefs.delivers(NotificationCategory::Security, NotificationChannel::InApp));
+        assert!(prefs.delivers(NotificationCategory::Security, NotificationChannel::Email));
+        assert!(!prefs.delivers(NotificationCategory::Security, NotificationChannel::Push));
+    }
+
+    #[test]
+    fn category_and_channel_toggles_both_apply() {
+        let mut prefs = NotificationPreferences::default();
+        assert!(prefs.delivers(
+            NotificationCategory::Transactions,
+            NotificationChannel::InApp
+        ));
+        assert!(!prefs.delivers(NotificationCategory::Marketing, NotificationChannel::Email));
+
+        prefs.set_category(NotificationCategory::Transactions, false);
+        assert!(!prefs.delivers(
+            NotificationCategory::Transactions,
+            NotificationChannel::InApp
+        ));
+
+        prefs.set_channel(NotificationChannel::InApp, false);
+        assert!(!prefs.delivers(
+            NotificationCategory::PriceAlerts,
+            NotificationChannel::InApp
+        ));
+        assert!(prefs.delivers(
+            NotificationCategory::PriceAlerts,
+            NotificationChannel::Email
+        ));
+    }
+
+    #[test]
+    fn missing_fields_use_defaults() {
+        let prefs: NotificationPreferences =
+            serde_json::from_str(r#"{"digest":"weekly","marketing":true}"#).unwrap();
+        assert_eq!(prefs.digest, DigestFrequency::Weekly);
+        assert!(prefs.marketing);
+        assert!(prefs.transactions);
+        assert!(!prefs.push);
+    }
 }
@@ -98,8 +98,13 @@ mod tests {
 
     #[test]
     fn test_validate_address() {
-        let eth_addr = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb6";
+        let eth_addr = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
         assert!(PaymentValidator::validate_address(eth_addr, None).is_ok());
         assert!(PaymentValidator::validate_address(eth_addr, Some(ChainType::Ethereum)).is_ok());
+        // 大小写混合但不符合 EIP-55 校验和
+        let bad_checksum = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb6";
+        assert!(
+            PaymentValidator::validate_address(bad_checksum, Some(ChainType::Ethereum)).is_err()
+        );
     }
 }
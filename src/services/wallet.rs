@@ -155,7 +155,7 @@ impl WalletService {
 
         #[cfg(debug_assertions)]
         {
-            use tracing::info;
+            use log::info;
             info!("🔍 Request path (before API call): {}", path);
         }
 
@@ -172,7 +172,7 @@ impl WalletService {
                 if crate::shared::auth_handler::is_unauthorized_error(&e) {
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::warn;
+                        use log::warn;
                         warn!("⚠️ Token已过期或无效，清理状态");
                     }
                     // 强制清理过期token
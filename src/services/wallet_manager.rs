@@ -2,6 +2,7 @@
 //! 核心功能：助记词生成、加密存储、钱包解锁、签名管理
 
 use crate::crypto::key_manager::KeyManager;
+use crate::shared::time;
 use aes_gcm::{
     aead::{Aead, KeyInit, OsRng},
     Aes256Gcm, Nonce,
@@ -133,7 +134,7 @@ impl WalletManager {
 
         // 从私钥派生公钥（用于后端记录，不涉及签名）
         use k256::ecdsa::SigningKey;
-        let key_bytes = hex::decode(&eth_private_key)?;
+        let key_bytes = crate::crypto::key_manager::decode_private_key_hex(&eth_private_key)?;
         let signing_key = SigningKey::from_bytes(key_bytes.as_slice().into())?;
         let verifying_key = k256::ecdsa::VerifyingKey::from(&signing_key);
         let eth_pubkey = hex::encode(verifying_key.to_encoded_point(false).as_bytes());
@@ -152,7 +153,7 @@ impl WalletManager {
         let btc_private_key = key_manager.derive_btc_private_key(0)?;
         let btc_address = key_manager.get_btc_address(&btc_private_key)?;
 
-        let btc_key_bytes = hex::decode(&btc_private_key)?;
+        let btc_key_bytes = crate::crypto::key_manager::decode_private_key_hex(&btc_private_key)?;
         let btc_signing_key = SigningKey::from_bytes(btc_key_bytes.as_slice().into())?;
         let btc_verifying_key = k256::ecdsa::VerifyingKey::from(&btc_signing_key);
         let btc_pubkey = hex::encode(btc_verifying_key.to_encoded_point(true).as_bytes()); // 压缩格式
@@ -440,7 +441,7 @@ impl WalletManager {
 
     /// 获取当前时间戳（毫秒）
     fn current_timestamp(&self) -> u64 {
-        js_sys::Date::now() as u64
+        time::now_ms() as u64
     }
 }
 
@@ -2,6 +2,7 @@
 //! 企业级双锁机制：自动锁定+后端验证
 
 use anyhow::Result;
+use crate::shared::time;
 use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
@@ -179,7 +180,7 @@ impl WalletSessionManager {
         LocalStorage::delete("wallet_session");
         *self.check_timer_active.lock().unwrap() = false;
         
-        tracing::info!("Wallet locked");
+        log::info!("Wallet locked");
     }
 
     /// 启动自动锁定计时器
@@ -218,7 +219,7 @@ impl WalletSessionManager {
                             LocalStorage::delete("wallet_session");
                             *check_timer_active.lock().unwrap() = false;
                             
-                            tracing::info!("Wallet auto-locked (session timeout)");
+                            log::info!("Wallet auto-locked (session timeout)");
                             
                             // 通知UI
                             Self::notify_ui_locked();
@@ -246,7 +247,7 @@ impl WalletSessionManager {
 
     /// 获取当前时间戳（毫秒）
     fn current_timestamp() -> u64 {
-        js_sys::Date::now() as u64
+        time::now_ms() as u64
     }
 
     /// 获取剩余时间（秒）
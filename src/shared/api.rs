@@ -1,10 +1,18 @@
+use crate::shared::demo::{self, DemoResponse};
 use crate::shared::error::ApiError;
-use futures::future::{select, Either, FutureExt};
+use crate::shared::metrics;
+#[cfg(test)]
+use crate::shared::mock_api::MockApi;
+use crate::shared::state_updates;
+use crate::shared::time;
+use dioxus::prelude::SyncSignal;
+use futures::future::{select, Either};
 use futures::pin_mut;
 use gloo_net::http::{Request, RequestBuilder, Response};
 use gloo_timers::future::TimeoutFuture;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// 空响应类型（用于不需要返回数据的操作）
@@ -37,6 +45,126 @@ impl Default for ApiConfig {
     }
 }
 
+/// 接口耗时类别（决定超时与重试策略）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestCategory {
+    /// 价格、Gas 等：应快速失败，由调用方使用缓存或降级值
+    Fast,
+    Standard,
+    /// 报价、服务商比价等：正常耗时可能超过 10 秒
+    Slow,
+}
+
+/// 快速失败的接口前缀
+const FAST_PATHS: &[&str] = &[
+    "/api/v1/prices",
+    "/api/v1/gas/",
+    "/api/v1/bitcoin/fee-estimates",
+    "/api/v1/features",
+    "/api/v1/network/status",
+    "/api/v1/metrics/",
+];
+
+/// 耗时较长的接口前缀
+const SLOW_PATHS: &[&str] = &[
+    "/api/v1/bridge/quote",
+    "/api/v1/swap/quote",
+    "/api/v1/fiat/onramp/quote",
+    "/api/v1/fiat/offramp/quote",
+    "/api/v1/providers",
+    "/api/v1/country-support/providers",
+];
+
+impl RequestCategory {
+    pub fn for_path(path: &str) -> Self {
+        let path = path.split('?').next().unwrap_or(path);
+        // 价格历史需要聚合，不按快速接口处理
+        if path.starts_with("/api/v1/prices/history") {
+            return RequestCategory::Standard;
+        }
+        if SLOW_PATHS.iter().any(|p| path.starts_with(p)) {
+            RequestCategory::Slow
+        } else if FAST_PATHS.iter().any(|p| path.starts_with(p)) {
+            RequestCategory::Fast
+        } else {
+            RequestCategory::Standard
+        }
+    }
+}
+
+/// 单次请求的超时策略
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeoutPolicy {
+    /// 超过该时长标记为"比平时慢"（0 表示不标记）
+    pub soft_ms: u32,
+    /// 超过该时长中止请求并返回 `ApiError::Timeout`（0 表示不限制）
+    pub hard_ms: u32,
+    /// 超时后的重试次数（慢接口不重试，避免重复请求堆积）
+    pub timeout_retries: u32,
+}
+
+impl TimeoutPolicy {
+    /// 按接口类别的默认策略；`default_secs` 为常规接口的超时（`ApiConfig::timeout`）
+    pub fn for_category(category: RequestCategory, default_secs: u64) -> Self {
+        match category {
+            RequestCategory::Fast => Self {
+                soft_ms: 1_500,
+                hard_ms: 5_000,
+                timeout_retries: 1,
+            },
+            RequestCategory::Standard => Self {
+                soft_ms: 4_000,
+                hard_ms: default_secs.saturating_mul(1000).min(u32::MAX as u64) as u32,
+                timeout_retries: 3,
+            },
+            RequestCategory::Slow => Self {
+                soft_ms: 6_000,
+                hard_ms: 45_000,
+                timeout_retries: 0,
+            },
+        }
+    }
+}
+
+/// 已超过软阈值、仍在进行中的请求（路径 → 数量）
+pub type SlowRequests = SyncSignal<HashMap<String, u32>>;
+
+/// 慢请求标记：存在期间计入 `SlowRequests`，请求结束或被取消时移除
+struct SlowMark {
+    slow_requests: Option<SlowRequests>,
+    key: String,
+}
+
+impl SlowMark {
+    fn new(slow_requests: Option<SlowRequests>, path: &str) -> Self {
+        let key = path.split('?').next().unwrap_or(path).to_string();
+        if let Some(slow_requests) = slow_requests {
+            let key = key.clone();
+            state_updates::enqueue(slow_requests, move |map: &mut HashMap<String, u32>| {
+                *map.entry(key).or_insert(0) += 1;
+            });
+        }
+        Self { slow_requests, key }
+    }
+}
+
+impl Drop for SlowMark {
+    fn drop(&mut self) {
+        let Some(slow_requests) = self.slow_requests else {
+            return;
+        };
+        let key = std::mem::take(&mut self.key);
+        state_updates::enqueue(slow_requests, move |map: &mut HashMap<String, u32>| {
+            if let Some(count) = map.get_mut(&key) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    map.remove(&key);
+                }
+            }
+        });
+    }
+}
+
 type RequestInterceptor = Arc<dyn Fn(&mut RequestBuilder) + Send + Sync>;
 type ResponseInterceptor = Arc<dyn Fn(&Response) + Send + Sync>;
 
@@ -46,6 +174,17 @@ pub struct ApiClient {
     auth: Option<AuthToken>,
     request_interceptors: Arc<Vec<RequestInterceptor>>,
     response_interceptors: Arc<Vec<ResponseInterceptor>>,
+    /// 附加请求头（如幂等键），随重试一起发送
+    extra_headers: Vec<(String, String)>,
+    /// 演示模式：业务接口返回固定数据，不提交真实交易
+    demo: bool,
+    /// 单次调用指定的超时策略（None 时按接口类别）
+    timeout_override: Option<TimeoutPolicy>,
+    /// 记录进行缓慢的请求，供界面显示"比平时慢"提示
+    slow_requests: Option<SlowRequests>,
+    /// 测试用模拟后端（安装后不再访问网络）
+    #[cfg(test)]
+    mock: Option<MockApi>,
 }
 
 #[derive(Clone)]
@@ -62,9 +201,54 @@ impl ApiClient {
             auth: None,
             request_interceptors: Arc::new(Vec::new()),
             response_interceptors: Arc::new(Vec::new()),
+            extra_headers: Vec::new(),
+            demo: false,
+            timeout_override: None,
+            slow_requests: None,
+            #[cfg(test)]
+            mock: None,
         }
     }
 
+    /// 返回附带幂等键的客户端副本（超时重试时沿用同一个键，服务端据此去重）
+    pub fn with_idempotency_key(&self, key: &str) -> Self {
+        let mut client = self.clone();
+        client
+            .extra_headers
+            .push(("Idempotency-Key".to_string(), key.to_string()));
+        client
+    }
+
+    /// 返回附带设备签名的客户端副本（高风险操作由后端校验可信设备）
+    pub fn with_device_proof(&self, device_id: &str, challenge_id: &str, signature: &str) -> Self {
+        let mut client = self.clone();
+        client.extra_headers.extend([
+            ("X-Device-Id".to_string(), device_id.to_string()),
+            ("X-Device-Challenge".to_string(), challenge_id.to_string()),
+            ("X-Device-Signature".to_string(), signature.to_string()),
+        ]);
+        client
+    }
+
+    /// 返回使用指定超时策略的客户端副本
+    #[allow(dead_code)] // 用于个别接口覆盖默认超时
+    pub fn with_timeout(&self, policy: TimeoutPolicy) -> Self {
+        let mut client = self.clone();
+        client.timeout_override = Some(policy);
+        client
+    }
+
+    pub fn track_slow_requests(&mut self, slow_requests: SlowRequests) {
+        self.slow_requests = Some(slow_requests);
+    }
+
+    /// 请求实际使用的超时策略
+    pub fn timeout_policy(&self, path: &str) -> TimeoutPolicy {
+        self.timeout_override.unwrap_or_else(|| {
+            TimeoutPolicy::for_category(RequestCategory::for_path(path), self.config.timeout)
+        })
+    }
+
     #[allow(dead_code)] // 用于 API Key 认证
     pub fn set_api_key(&mut self, token: impl Into<String>) {
         self.auth = Some(AuthToken::ApiKey(token.into()));
@@ -82,6 +266,44 @@ impl ApiClient {
         &self.config.base_url
     }
 
+    pub fn set_demo_mode(&mut self, enabled: bool) {
+        self.demo = enabled;
+    }
+
+    pub fn is_demo(&self) -> bool {
+        self.demo
+    }
+
+    /// 安装测试用模拟后端
+    #[cfg(test)]
+    pub fn install_mock(&mut self, mock: MockApi) {
+        self.mock = Some(mock);
+    }
+
+    /// 演示模式下的处理结果；非演示模式或需要照常请求时返回 None
+    fn demo_response(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Option<Result<Value, ApiError>> {
+        #[cfg(test)]
+        if let Some(mock) = &self.mock {
+            return Some(mock.handle(method, path, body, time::now_ms() as u64));
+        }
+        if !self.demo {
+            return None;
+        }
+        let now_ms = time::now_ms() as u64;
+        match demo::respond(method, path, body, now_ms) {
+            DemoResponse::Fixture(value) => Some(Ok(value)),
+            DemoResponse::Blocked => Some(Err(ApiError::ResponseError(
+                "演示模式下不会提交真实交易".to_string(),
+            ))),
+            DemoResponse::PassThrough => None,
+        }
+    }
+
     #[allow(dead_code)] // 用于请求拦截器功能
     pub fn add_request_interceptor<F>(&mut self, interceptor: F)
     where
@@ -107,7 +329,7 @@ impl ApiClient {
 
         #[cfg(debug_assertions)]
         {
-            use tracing::info;
+            use log::info;
             info!("🔍 API Request URL: {} {}", method, url);
             info!(
                 "🔍 Path length: {}, Path bytes: {:?}",
@@ -139,7 +361,7 @@ impl ApiClient {
                     // Debug: Log token presence (but not the token itself for security)
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::debug;
+                        use log::debug;
                         debug!(
                             "API Request: Adding Authorization header (token length: {})",
                             value.len()
@@ -151,7 +373,7 @@ impl ApiClient {
         } else {
             #[cfg(debug_assertions)]
             {
-                use tracing::warn;
+                use log::warn;
                 warn!(
                     "API Request: No auth token available for request to {}",
                     path
@@ -165,6 +387,10 @@ impl ApiClient {
             .header("X-Request-Id", Self::request_id().as_str())
             .header("X-Platform", "ironforge-web");
 
+        for (name, value) in &self.extra_headers {
+            req = req.header(name, value);
+        }
+
         for interceptor in self.request_interceptors.iter() {
             interceptor(&mut req);
         }
@@ -176,15 +402,20 @@ impl ApiClient {
         method: &str,
         path: &str,
         body: Option<Value>,
+        retry_rate_limited: bool,
     ) -> Result<Response, ApiError> {
         let mut attempts = 0;
         let max_attempts = 3;
         let mut delay_ms: u32 = 500; // Start with 500ms
-        let timeout_ms = (self.config.timeout.saturating_mul(1000)).min(u32::MAX as u64) as u32;
+        let policy = self.timeout_policy(path);
 
         loop {
-            let req_builder = self.build_request(method, path);
+            let controller = web_sys::AbortController::new().ok();
+            let req_builder = self
+                .build_request(method, path)
+                .abort_signal(controller.as_ref().map(|c| c.signal()).as_ref());
             let payload = body.clone();
+            let started = metrics::perf_now();
 
             let send_future = async move {
                 let response_result = if let Some(json_body) = payload {
@@ -193,7 +424,7 @@ impl ApiClient {
                         .map_err(|e| {
                             #[cfg(debug_assertions)]
                             {
-                                use tracing::error;
+                                use log::error;
                                 error!("API Request: Failed to serialize JSON body: {}", e);
                             }
                             ApiError::RequestFailed(e.to_string())
@@ -207,31 +438,28 @@ impl ApiClient {
                 response_result.map_err(|e| {
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::error;
+                        use log::error;
                         error!("API Request: Failed to send request: {}", e);
                     }
                     ApiError::RequestFailed(e.to_string())
                 })
             };
 
-            let resp_result = if timeout_ms == 0 {
-                send_future.await
-            } else {
-                let timeout_future = TimeoutFuture::new(timeout_ms).map(|_| Err(ApiError::Timeout));
-                pin_mut!(send_future);
-                pin_mut!(timeout_future);
-                match select(send_future, timeout_future).await {
-                    Either::Left((res, _)) => res,
-                    Either::Right((res, _)) => res,
+            let resp_result = self.send_with_deadline(path, policy, send_future).await;
+            if matches!(resp_result, Err(ApiError::Timeout)) {
+                if let Some(controller) = &controller {
+                    controller.abort();
                 }
-            };
+            }
+
+            metrics::record_api_latency(path, metrics::perf_now() - started);
 
             match resp_result {
                 Ok(resp) => {
                     for interceptor in self.response_interceptors.iter() {
                         interceptor(&resp);
                     }
-                    if resp.status() == 429 {
+                    if resp.status() == 429 && retry_rate_limited {
                         if attempts >= max_attempts {
                             return Err(ApiError::ResponseError("Rate limit exceeded".to_string()));
                         }
@@ -245,11 +473,13 @@ impl ApiClient {
                     return Ok(resp);
                 }
                 Err(ApiError::Timeout) => {
-                    if attempts >= max_attempts {
+                    if attempts >= policy.timeout_retries {
                         return Err(ApiError::Timeout);
                     }
 
-                    TimeoutFuture::new(delay_ms).await;
+                    // 加入随机抖动，避免同时超时的请求一起重试
+                    let jitter = (js_sys::Math::random() * delay_ms as f64 / 2.0) as u32;
+                    TimeoutFuture::new(delay_ms + jitter).await;
                     attempts += 1;
                     delay_ms = (delay_ms.saturating_mul(2)).min(8_000);
                 }
@@ -260,16 +490,76 @@ impl ApiClient {
         }
     }
 
+    /// 等待响应：超过软阈值时标记为慢请求，超过硬上限返回 `ApiError::Timeout`
+    async fn send_with_deadline<F>(
+        &self,
+        path: &str,
+        policy: TimeoutPolicy,
+        send_future: F,
+    ) -> Result<Response, ApiError>
+    where
+        F: std::future::Future<Output = Result<Response, ApiError>>,
+    {
+        // 计时器在创建时即开始计时
+        let deadline = if policy.hard_ms == 0 {
+            Either::Left(futures::future::pending::<()>())
+        } else {
+            Either::Right(TimeoutFuture::new(policy.hard_ms))
+        };
+        pin_mut!(send_future);
+        pin_mut!(deadline);
+
+        let _slow =
+            if policy.soft_ms > 0 && (policy.hard_ms == 0 || policy.soft_ms < policy.hard_ms) {
+                let soft = TimeoutFuture::new(policy.soft_ms);
+                match select(send_future.as_mut(), soft).await {
+                    Either::Left((res, _)) => return res,
+                    Either::Right(_) => Some(SlowMark::new(self.slow_requests, path)),
+                }
+            } else {
+                None
+            };
+
+        match select(send_future, deadline).await {
+            Either::Left((res, _)) => res,
+            Either::Right(_) => Err(ApiError::Timeout),
+        }
+    }
+
     pub async fn request_json(
         &self,
         method: &str,
         path: &str,
         body: Option<Value>,
     ) -> Result<Value, ApiError> {
-        let resp = self.execute_with_retry(method, path, body).await?;
+        if let Some(result) = self.demo_response(method, path, body.as_ref()) {
+            return result;
+        }
+        let resp = self.execute_with_retry(method, path, body, true).await?;
         self.handle_json(resp).await
     }
 
+    /// 发送 POST 并原样返回状态码与 JSON 响应体（不把非 2xx 当作错误，429 也不自动重试）
+    ///
+    /// 用于登录等需要解析结构化错误（剩余尝试次数、锁定时长）的接口。
+    pub async fn post_with_status<B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<(u16, Value), ApiError> {
+        let body_json =
+            serde_json::to_value(body).map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+        if let Some(result) = self.demo_response("POST", path, Some(&body_json)) {
+            return result.map(|value| (200, value));
+        }
+        let resp = self
+            .execute_with_retry("POST", path, Some(body_json), false)
+            .await?;
+        let status = resp.status();
+        let value = resp.json::<Value>().await.unwrap_or(Value::Null);
+        Ok((status, value))
+    }
+
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError> {
         self.request_json("GET", path, None)
             .await
@@ -315,7 +605,7 @@ impl ApiClient {
             if status == 401 {
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::warn;
+                    use log::warn;
                     warn!("API Response: 401 Unauthorized - Token may be expired or invalid");
                 }
                 return Err(ApiError::Unauthorized);
@@ -327,7 +617,7 @@ impl ApiClient {
                 Err(e) => {
                     #[cfg(debug_assertions)]
                     {
-                        use tracing::warn;
+                        use log::warn;
                         warn!("API Response: Failed to read error response body: {}", e);
                     }
                     format!("Failed to read response: {}", e)
@@ -336,7 +626,7 @@ impl ApiClient {
 
             #[cfg(debug_assertions)]
             {
-                use tracing::error;
+                use log::error;
                 error!("API Response: Error {} - {}", status, text);
             }
             return Err(ApiError::ResponseError(format!("{} - {}", status, text)));
@@ -352,7 +642,7 @@ impl ApiClient {
             Err(e) => {
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::error;
+                    use log::error;
                     error!("API Response: Failed to parse JSON: {}", e);
                 }
                 Err(ApiError::ResponseError(format!(
@@ -363,7 +653,7 @@ impl ApiClient {
         }
     }
 
-    fn deserialize<T: DeserializeOwned>(value: Value) -> Result<T, ApiError> {
+    pub(crate) fn deserialize<T: DeserializeOwned>(value: Value) -> Result<T, ApiError> {
         // 处理统一响应格式: { code, message, data }
         // 如果响应包含 "data" 字段，则提取 data 字段的内容
         if let Some(data) = value.get("data") {
@@ -382,7 +672,7 @@ impl ApiClient {
             // 🔍 调试：打印 data 字段内容
             #[cfg(debug_assertions)]
             {
-                use tracing::info;
+                use log::info;
                 info!(
                     "📥 API Response data field: {}",
                     serde_json::to_string_pretty(data)
@@ -394,7 +684,7 @@ impl ApiClient {
             serde_json::from_value(data.clone()).map_err(|e| {
                 #[cfg(debug_assertions)]
                 {
-                    use tracing::error;
+                    use log::error;
                     error!("❌ Deserialization error: {}", e);
                     error!("   Expected type: {}", std::any::type_name::<T>());
                     error!(
@@ -434,3 +724,36 @@ impl ApiClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_policy_follows_endpoint_category() {
+        assert_eq!(
+            RequestCategory::for_path("/api/v1/prices?symbols=ETH"),
+            RequestCategory::Fast
+        );
+        assert_eq!(
+            RequestCategory::for_path("/api/v1/prices/history?symbol=ETH"),
+            RequestCategory::Standard
+        );
+        assert_eq!(
+            RequestCategory::for_path("/api/v1/bridge/quote"),
+            RequestCategory::Slow
+        );
+        assert_eq!(
+            RequestCategory::for_path("/api/v1/wallets"),
+            RequestCategory::Standard
+        );
+
+        let slow = TimeoutPolicy::for_category(RequestCategory::Slow, 30);
+        assert_eq!(slow.timeout_retries, 0);
+        assert!(slow.soft_ms < slow.hard_ms);
+        let standard = TimeoutPolicy::for_category(RequestCategory::Standard, 30);
+        assert_eq!(standard.hard_ms, 30_000);
+        let fast = TimeoutPolicy::for_category(RequestCategory::Fast, 30);
+        assert!(fast.hard_ms < standard.hard_ms);
+    }
+}
@@ -21,7 +21,10 @@ pub mod auth {
     pub const CHANGE_PASSWORD: &str = "/api/v1/auth/change-password";
     pub const SET_PASSWORD: &str = "/api/v1/auth/set-password";
     pub const RESET_PASSWORD: &str = "/api/v1/auth/reset-password";
+    pub const FORGOT_PASSWORD: &str = "/api/v1/auth/forgot-password";
     pub const LOGIN_HISTORY: &str = "/api/v1/auth/login-history";
+    pub const RESEND_VERIFICATION: &str = "/api/v1/auth/resend-verification";
+    pub const VERIFICATION_STATUS: &str = "/api/v1/auth/verification-status";
     pub const CHALLENGE: &str = "/api/v1/auth/challenge";
     pub const VERIFY: &str = "/api/v1/auth/verify";
 }
@@ -59,6 +62,10 @@ pub mod transactions {
     pub const BROADCAST: &str = "/api/v1/transactions/broadcast";
     pub const NONCE: &str = "/api/v1/transactions/nonce";
     pub const HISTORY: &str = "/api/v1/transactions/history";
+    /// 交易分类与备注（开启同步时使用）
+    pub const LABELS: &str = "/api/v1/transactions/labels";
+    /// 模拟执行（eth_call + eth_estimateGas，不广播）
+    pub const SIMULATE: &str = "/api/v1/transactions/simulate";
 
     /// 交易状态：/api/v1/transactions/:hash/status
     pub fn status(tx_hash: &str) -> String {
@@ -71,6 +78,28 @@ pub mod transactions {
     }
 }
 
+/// 可信设备端点（设备绑定、挑战签名、追加验证）
+pub mod devices {
+    pub const LIST: &str = "/api/v1/devices";
+    pub const REGISTER: &str = "/api/v1/devices";
+    pub const CHALLENGE: &str = "/api/v1/devices/challenge";
+
+    /// 移除设备：/api/v1/devices/:id
+    pub fn revoke(device_id: &str) -> String {
+        format!("/api/v1/devices/{}", device_id)
+    }
+
+    /// 发送追加验证码：/api/v1/devices/:id/step-up
+    pub fn step_up(device_id: &str) -> String {
+        format!("/api/v1/devices/{}/step-up", device_id)
+    }
+
+    /// 校验追加验证码：/api/v1/devices/:id/step-up/verify
+    pub fn step_up_verify(device_id: &str) -> String {
+        format!("/api/v1/devices/{}/step-up/verify", device_id)
+    }
+}
+
 /// 兑换相关端点（企业级标准：v1）
 pub mod swap {
     pub const QUOTE: &str = "/api/v1/swap/quote";
@@ -86,6 +115,11 @@ pub mod swap {
     pub fn history_detail(swap_id: &str) -> String {
         format!("/api/v1/swap/history/{}", swap_id)
     }
+
+    /// 兑换所在区块中同一池子的相邻交易（执行质量分析）：/api/v1/swap/:id/pool-activity
+    pub fn pool_activity(swap_id: &str) -> String {
+        format!("/api/v1/swap/{}/pool-activity", swap_id)
+    }
 }
 
 /// 跨链桥接端点（企业级标准：v1）
@@ -234,6 +268,8 @@ pub mod fees {
 /// 系统状态端点（企业级标准：v1）
 pub mod system {
     pub const NETWORK_STATUS: &str = "/api/v1/network/status";
+    /// 服务状态汇总（RPC 节点池、服务商状态、故障公告；无需登录）
+    pub const STATUS: &str = "/api/v1/status";
     pub const BALANCE: &str = "/api/v1/balance";
 }
 
@@ -245,10 +281,53 @@ pub mod blockchain {
     pub const BITCOIN_FEE_ESTIMATES: &str = "/api/v1/bitcoin/fee-estimates";
 }
 
+/// 帮助与客服端点（企业级标准：v1）
+pub mod support {
+    pub const FAQ: &str = "/api/v1/support/faq";
+    pub const TICKETS: &str = "/api/v1/support/tickets";
+
+    /// 工单详情（含回复）：/api/v1/support/tickets/:id
+    pub fn ticket(ticket_id: &str) -> String {
+        format!("/api/v1/support/tickets/{}", ticket_id)
+    }
+
+    /// 标记工单回复已读：/api/v1/support/tickets/:id/read
+    pub fn mark_read(ticket_id: &str) -> String {
+        format!("/api/v1/support/tickets/{}/read", ticket_id)
+    }
+}
+
+/// dApp 目录端点（企业级标准：v1）
+pub mod dapps {
+    pub const CATALOG: &str = "/api/v1/dapps/catalog";
+}
+
+/// 理财持仓端点（企业级标准：v1）
+pub mod earn {
+    pub const POSITIONS: &str = "/api/v1/earn/positions";
+
+    /// 构建领取奖励交易：/api/v1/earn/positions/:id/claim
+    pub fn claim(position_id: &str) -> String {
+        format!("/api/v1/earn/positions/{}/claim", position_id)
+    }
+
+    /// 构建复投交易：/api/v1/earn/positions/:id/compound
+    pub fn compound(position_id: &str) -> String {
+        format!("/api/v1/earn/positions/{}/compound", position_id)
+    }
+}
+
+/// 用户相关端点
+pub mod users {
+    pub const PREFERENCES: &str = "/api/v1/users/preferences";
+    pub const NOTIFICATION_PREFERENCES: &str = "/api/v1/users/notification-preferences";
+}
+
 /// 其他端点（✅ 企业级标准 V1）
 pub mod misc {
     pub const NETWORK_CONFIG: &str = "/api/v1/network-config";
     pub const FEATURES: &str = "/api/v1/features";
+    pub const FRONTEND_METRICS: &str = "/api/v1/metrics/frontend";
     pub const PRICES: &str = "/api/v1/prices";
     pub const CHAINS: &str = "/api/v1/chains";
     pub const CHAINS_BY_CURVE: &str = "/api/v1/chains/by-curve";
@@ -48,11 +48,11 @@ pub fn handle_unauthorized_and_redirect(app_state: AppState) {
         // - 该功能在后台未开通 / 权限不足
         // - token 真的过期
         // 交给具体页面用自己的文案提示，这里只做 debug 日志
-        tracing::debug!(
+        log::debug!(
             "用户已登录但收到401（可能是接口权限/功能未开通/后端配置变化），交由页面自行处理提示"
         );
     } else {
-        tracing::debug!("401 错误且用户未登录");
+        log::debug!("401 错误且用户未登录");
     }
 
     // 仍然不自动登出和跳转，让用户自己决定
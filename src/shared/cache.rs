@@ -20,11 +20,7 @@ impl CacheEntry {
     }
 
     pub fn is_expired(&self, ttl_secs: u64) -> bool {
-        let now = now_secs();
+        let now = crate::shared::time::now_unix();
         now.saturating_sub(self.stored_at) > ttl_secs
     }
 }
-
-pub fn now_secs() -> u64 {
-    (js_sys::Date::new_0().get_time() / 1000.0) as u64
-}
@@ -0,0 +1,560 @@
+//! Demo Mode - 演示模式固定数据
+//! 开启后 ApiClient 不再请求后端的业务接口，而是返回确定性的演示数据
+//! （报价、余额、下单、按时间推进的订单状态），用于客服演示和 QA 回归。
+//!
+//! - 有演示数据的接口：直接返回固定数据
+//! - 没有演示数据的写操作：一律拦截，保证不会提交真实交易
+//! - 没有演示数据的读操作（如登录态、设置）：照常请求后端
+
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use serde_json::{json, Value};
+
+/// 演示模式功能开关
+pub const FEATURE_FLAG: &str = "demo_mode";
+
+/// 演示订单 ID / 交易哈希前缀（后缀为创建时间毫秒数，用于推进状态）
+const SWAP_PREFIX: &str = "demo-swap-";
+const ONRAMP_PREFIX: &str = "demo-onramp-";
+const OFFRAMP_PREFIX: &str = "demo-offramp-";
+const TX_PREFIX: &str = "0xdemo";
+
+/// 订单状态推进：下单后 5 秒内待处理，15 秒内处理中，之后完成
+const PENDING_MS: u64 = 5_000;
+const PROCESSING_MS: u64 = 15_000;
+
+/// 兑换/提现报价中扣除的演示手续费比例
+const DEMO_FEE_RATE: f64 = 0.003;
+
+/// 演示模式对一个请求的处理方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum DemoResponse {
+    /// 返回固定数据（已包装为 `{code, message, data}`）
+    Fixture(Value),
+    /// 拦截：演示模式下不允许的写操作
+    Blocked,
+    /// 照常请求后端
+    PassThrough,
+}
+
+/// 开启/关闭演示模式（清空请求缓存，避免真实数据与演示数据混用）
+pub fn set_enabled(app_state: AppState, enabled: bool) {
+    let mut preferences = app_state.preferences;
+    let mut prefs = preferences.write();
+    prefs.demo_mode = enabled;
+    prefs.save();
+    drop(prefs);
+    let mut cache = app_state.cache;
+    cache.write().clear();
+}
+
+/// 演示价格（USD）
+pub fn price_usd(symbol: &str) -> Option<f64> {
+    let price = match symbol.to_uppercase().as_str() {
+        "USDT" | "USDC" | "DAI" | "USD" => 1.0,
+        "ETH" | "WETH" => 3000.0,
+        "BTC" | "WBTC" => 60000.0,
+        "SOL" => 150.0,
+        "TON" => 5.0,
+        "BNB" => 600.0,
+        "MATIC" => 0.8,
+        _ => return None,
+    };
+    Some(price)
+}
+
+/// 订单在 `elapsed_ms` 时的演示状态
+pub fn progress_status(elapsed_ms: u64) -> &'static str {
+    if elapsed_ms < PENDING_MS {
+        "pending"
+    } else if elapsed_ms < PROCESSING_MS {
+        "processing"
+    } else {
+        "completed"
+    }
+}
+
+/// 处理一个请求（`now_ms` 为当前时间，保证同一时间点结果确定）
+pub fn respond(method: &str, path: &str, body: Option<&Value>, now_ms: u64) -> DemoResponse {
+    let (route, query) = split_query(path);
+    let param = |key: &str| query_param(query, key);
+    let segments: Vec<&str> = route.trim_matches('/').split('/').collect();
+    let method = method.to_uppercase();
+
+    let data = match (method.as_str(), segments.as_slice()) {
+        ("GET", ["api", "v1", "swap", "quote"]) => swap_quote(
+            &param("from").unwrap_or_default(),
+            &param("to").unwrap_or_default(),
+            &param("amount").unwrap_or_default(),
+        ),
+        ("POST", ["api", "v1", "swap", "execute"]) => swap_execute(body, now_ms),
+        ("GET", ["api", "v1", "swap", id]) if id.starts_with(SWAP_PREFIX) => {
+            swap_status(id, now_ms)
+        }
+        ("PUT", ["api", "v1", "swap", id, "status"]) if id.starts_with(SWAP_PREFIX) => json!({}),
+        ("GET", ["api", "v1", "wallets", _, "balance"]) => {
+            balance(param("chain_id").and_then(|c| c.parse().ok()).unwrap_or(1))
+        }
+        ("GET", ["api", "v1", "gas", "estimate-all"]) => gas_estimates(),
+        ("POST", ["api", "v1", "fees", "calculate"]) => platform_fee(),
+        ("GET", ["api", "v1", "transactions", "nonce"]) => json!({ "nonce": 0 }),
+        ("POST", ["api", "v1", "transactions", "broadcast"]) => json!({
+            "tx_hash": demo_tx_hash(now_ms),
+            "status": "pending",
+        }),
+        ("GET", ["api", "v1", "transactions", hash, "status"]) if hash.starts_with(TX_PREFIX) => {
+            tx_status(hash, now_ms)
+        }
+        ("GET", ["api", "v1", "fiat", "onramp", "quote"]) => onramp_quote(
+            &param("amount").unwrap_or_default(),
+            &param("token").unwrap_or_default(),
+            now_ms,
+        ),
+        ("POST", ["api", "v1", "fiat", "onramp", "orders"]) => onramp_order(body, now_ms),
+        ("GET", ["api", "v1", "fiat", "onramp", "orders"])
+        | ("GET", ["api", "v1", "fiat", "offramp", "orders"]) => empty_order_list(),
+        ("GET", ["api", "v1", "fiat", "onramp", "orders", id]) if id.starts_with(ONRAMP_PREFIX) => {
+            onramp_status(id, now_ms)
+        }
+        ("GET", ["api", "v1", "fiat", "offramp", "quote"]) => offramp_quote(
+            &param("token").unwrap_or_default(),
+            &param("amount").unwrap_or_default(),
+            &param("fiat_currency").unwrap_or_else(|| "USD".to_string()),
+            now_ms,
+        ),
+        ("POST", ["api", "v1", "fiat", "offramp", "orders"]) => offramp_order(body, now_ms),
+        ("GET", ["api", "v1", "fiat", "offramp", "orders", id])
+            if id.starts_with(OFFRAMP_PREFIX) =>
+        {
+            offramp_status(id, now_ms)
+        }
+        ("GET", _) => return DemoResponse::PassThrough,
+        // 登录、刷新令牌等认证接口照常请求后端，避免演示期间被登出
+        (_, ["api", "v1", "auth", ..]) => return DemoResponse::PassThrough,
+        _ => return DemoResponse::Blocked,
+    };
+
+    DemoResponse::Fixture(json!({ "code": 0, "message": "success", "data": data }))
+}
+
+fn split_query(path: &str) -> (&str, &str) {
+    match path.split_once('?') {
+        Some((route, query)) => (route, query),
+        None => (path, ""),
+    }
+}
+
+fn query_param(query: &str, key: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.replace("%2E", ".").replace("%20", " "))
+}
+
+/// ID 后缀中的创建时间
+fn created_ms(id: &str, prefix: &str) -> u64 {
+    id.trim_start_matches(prefix)
+        .split('-')
+        .next()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0)
+}
+
+fn iso_time(ms: u64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms as i64)
+        .unwrap_or_default()
+        .to_rfc3339()
+}
+
+fn demo_tx_hash(now_ms: u64) -> String {
+    format!("{}{:058x}", TX_PREFIX, now_ms)
+}
+
+fn str_field(body: Option<&Value>, key: &str) -> String {
+    body.and_then(|b| b.get(key))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn parse_amount(amount: &str) -> f64 {
+    amount
+        .parse::<f64>()
+        .ok()
+        .filter(|a| a.is_finite() && *a > 0.0)
+        .unwrap_or(0.0)
+}
+
+/// 按演示价格换算并扣除手续费
+fn convert(amount: f64, from: &str, to: &str) -> f64 {
+    let from_price = price_usd(from).unwrap_or(1.0);
+    let to_price = price_usd(to).unwrap_or(1.0);
+    amount * from_price / to_price * (1.0 - DEMO_FEE_RATE)
+}
+
+fn fmt_amount(value: f64) -> String {
+    format!("{:.6}", value)
+}
+
+fn swap_quote(from: &str, to: &str, amount: &str) -> Value {
+    let amount_f = parse_amount(amount);
+    let to_amount = convert(amount_f, from, to);
+    json!({
+        "from_token": from,
+        "to_token": to,
+        "from_amount": amount,
+        "to_amount": fmt_amount(to_amount),
+        "estimated_gas": "200000",
+        "exchange_rate": if amount_f > 0.0 { to_amount / amount_f } else { 0.0 },
+        "price_impact": 0.05,
+        "estimated_gas_usd": 12.0,
+        "valid_for": 30,
+    })
+}
+
+fn swap_execute(body: Option<&Value>, now_ms: u64) -> Value {
+    let from = str_field(body, "from_token");
+    let to = str_field(body, "to_token");
+    let amount = str_field(body, "amount");
+    let amount_f = parse_amount(&amount);
+    let to_amount = convert(amount_f, &from, &to);
+    json!({
+        "tx_id": format!("{}{}", SWAP_PREFIX, now_ms),
+        "status": "pending",
+        "from_amount": amount,
+        "to_amount": fmt_amount(to_amount),
+        "transaction": {
+            "to": "0x1111111254EEB25477B68fb85Ed929f73A960582",
+            "value": "0",
+            "data": "0x",
+            "gas": "200000",
+            "gas_price": "20000000000",
+        },
+        "message": "演示模式：不会提交真实交易",
+        "actual_rate": if amount_f > 0.0 { to_amount / amount_f } else { 0.0 },
+        "confirmations": 0,
+        "needs_approval": false,
+    })
+}
+
+fn swap_status(id: &str, now_ms: u64) -> Value {
+    let created = created_ms(id, SWAP_PREFIX);
+    let status = progress_status(now_ms.saturating_sub(created));
+    let done = status == "completed";
+    json!({
+        "swap_id": id,
+        "status": status,
+        "from_token": "USDT",
+        "to_token": "ETH",
+        "from_amount": "0",
+        "to_amount": Value::Null,
+        "network": "ethereum",
+        "tx_hash": done.then(|| demo_tx_hash(created)),
+        "gas_used": done.then_some("150000"),
+        "confirmations": if done { 12 } else { 0 },
+        "created_at": iso_time(created),
+        "updated_at": iso_time(now_ms),
+    })
+}
+
+/// 演示余额：ETH 0.5、SOL 2、BTC/TON 链 0.01（最小单位）
+fn balance(chain_id: u64) -> Value {
+    let raw = match chain_id {
+        101 => "2000000000",
+        0 => "1000000",
+        _ => "500000000000000000",
+    };
+    json!({ "balance": raw, "chain_id": chain_id, "confirmed": true })
+}
+
+fn gas_tier(base: f64, priority: f64, secs: u64) -> Value {
+    let max_fee = base * 2.0 + priority;
+    let to_wei_hex = |gwei: f64| format!("0x{:x}", (gwei * 1e9) as u64);
+    json!({
+        "base_fee": to_wei_hex(base),
+        "max_priority_fee": to_wei_hex(priority),
+        "max_fee_per_gas": to_wei_hex(max_fee),
+        "estimated_time_seconds": secs,
+        "base_fee_gwei": base,
+        "max_priority_fee_gwei": priority,
+        "max_fee_per_gas_gwei": max_fee,
+    })
+}
+
+fn gas_estimates() -> Value {
+    json!({
+        "slow": gas_tier(10.0, 1.0, 180),
+        "average": gas_tier(10.0, 2.0, 60),
+        "fast": gas_tier(10.0, 3.0, 15),
+    })
+}
+
+fn platform_fee() -> Value {
+    json!({
+        "platform_fee": 0.0,
+        "collector_address": "0x0000000000000000000000000000000000000000",
+        "applied_rule_id": "00000000-0000-0000-0000-000000000000",
+        "rule_version": 0,
+    })
+}
+
+fn tx_status(hash: &str, now_ms: u64) -> Value {
+    let created = u64::from_str_radix(hash.trim_start_matches(TX_PREFIX), 16).unwrap_or(0);
+    let done = progress_status(now_ms.saturating_sub(created)) == "completed";
+    json!({
+        "tx_hash": hash,
+        "status": if done { "confirmed" } else { "pending" },
+        "confirmations": if done { 12 } else { 0 },
+        "last_seen": now_ms / 1000,
+        "block_number": done.then_some(19_000_000u64),
+    })
+}
+
+fn onramp_quote(amount: &str, token: &str, now_ms: u64) -> Value {
+    let fiat = parse_amount(amount);
+    let fee = fiat * 0.015;
+    let crypto = (fiat - fee) / price_usd(token).unwrap_or(1.0);
+    json!({
+        "fiat_amount": amount,
+        "crypto_amount": fmt_amount(crypto),
+        "exchange_rate": fmt_amount(1.0 / price_usd(token).unwrap_or(1.0)),
+        "fee_amount": format!("{:.2}", fee),
+        "fee_percentage": 1.5,
+        "estimated_arrival": "约 1 分钟（演示）",
+        "quote_expires_at": iso_time(now_ms + 60_000),
+        "min_amount": "10",
+        "max_amount": "10000",
+        "quote_id": format!("demo-quote-{}", now_ms),
+    })
+}
+
+fn onramp_order(body: Option<&Value>, now_ms: u64) -> Value {
+    let amount = str_field(body, "amount");
+    let token = str_field(body, "token");
+    let quote = onramp_quote(&amount, &token, now_ms);
+    json!({
+        "order_id": format!("{}{}", ONRAMP_PREFIX, now_ms),
+        "status": "pending",
+        "payment_url": Value::Null,
+        "fiat_amount": amount,
+        "crypto_amount": quote["crypto_amount"],
+        "exchange_rate": quote["exchange_rate"],
+        "fee_amount": quote["fee_amount"],
+        "estimated_arrival": quote["estimated_arrival"],
+        "created_at": iso_time(now_ms),
+        "expires_at": iso_time(now_ms + 15 * 60_000),
+    })
+}
+
+fn onramp_status(id: &str, now_ms: u64) -> Value {
+    let created = created_ms(id, ONRAMP_PREFIX);
+    let status = progress_status(now_ms.saturating_sub(created));
+    let done = status == "completed";
+    json!({
+        "order_id": id,
+        "status": status,
+        "fiat_amount": "100",
+        "crypto_amount": "98.500000",
+        "exchange_rate": "1.000000",
+        "fee_amount": "1.50",
+        "payment_url": Value::Null,
+        "tx_hash": done.then(|| demo_tx_hash(created)),
+        "created_at": iso_time(created),
+        "updated_at": iso_time(now_ms),
+        "completed_at": done.then(|| iso_time(created + PROCESSING_MS)),
+        "error_message": Value::Null,
+    })
+}
+
+fn offramp_quote(token: &str, amount: &str, fiat_currency: &str, now_ms: u64) -> Value {
+    let amount_f = parse_amount(amount);
+    let stable = convert(amount_f, token, "USDT");
+    let fee = stable * 0.01;
+    json!({
+        "token_amount": amount,
+        "token_symbol": token,
+        "stablecoin_amount": fmt_amount(stable),
+        "stablecoin_symbol": "USDT",
+        "fiat_amount": format!("{:.2}", stable - fee),
+        "fiat_currency": fiat_currency,
+        "exchange_rate_token_to_stable": fmt_amount(price_usd(token).unwrap_or(1.0)),
+        "exchange_rate_stable_to_fiat": "1.00",
+        "fee_amount": format!("{:.2}", fee),
+        "fee_percentage": 1.0,
+        "swap_fee": format!("{:.2}", stable * DEMO_FEE_RATE),
+        "withdrawal_fee": format!("{:.2}", fee),
+        "estimated_arrival": "约 1 分钟（演示）",
+        "quote_expires_at": iso_time(now_ms + 60_000),
+        "min_amount": "10",
+        "max_amount": "100000",
+        "quote_id": format!("demo-quote-{}", now_ms),
+    })
+}
+
+fn offramp_order(body: Option<&Value>, now_ms: u64) -> Value {
+    let token = str_field(body, "token");
+    let amount = str_field(body, "amount");
+    let fiat_currency = str_field(body, "fiat_currency");
+    let quote = offramp_quote(&token, &amount, &fiat_currency, now_ms);
+    json!({
+        "order_id": format!("{}{}", OFFRAMP_PREFIX, now_ms),
+        "status": "pending",
+        "review_status": "auto_approved",
+        "token_amount": amount,
+        "token_symbol": token,
+        "stablecoin_amount": quote["stablecoin_amount"],
+        "stablecoin_symbol": "USDT",
+        "fiat_amount": quote["fiat_amount"],
+        "fiat_currency": fiat_currency,
+        "fee_amount": quote["fee_amount"],
+        "estimated_arrival": quote["estimated_arrival"],
+        "swap_tx_hash": Value::Null,
+        "created_at": iso_time(now_ms),
+        "expires_at": iso_time(now_ms + 15 * 60_000),
+    })
+}
+
+fn offramp_status(id: &str, now_ms: u64) -> Value {
+    let created = created_ms(id, OFFRAMP_PREFIX);
+    let status = progress_status(now_ms.saturating_sub(created));
+    let done = status == "completed";
+    json!({
+        "order_id": id,
+        "status": status,
+        "token_amount": "0.1",
+        "token_symbol": "ETH",
+        "stablecoin_amount": "299.100000",
+        "stablecoin_symbol": "USDT",
+        "fiat_amount": "296.11",
+        "fiat_currency": "USD",
+        "fee_amount": "2.99",
+        "swap_tx_hash": (status != "pending").then(|| demo_tx_hash(created)),
+        "withdrawal_tx_hash": Value::Null,
+        "created_at": iso_time(created),
+        "updated_at": iso_time(now_ms),
+        "completed_at": done.then(|| iso_time(created + PROCESSING_MS)),
+        "error_message": Value::Null,
+    })
+}
+
+fn empty_order_list() -> Value {
+    json!({ "orders": [], "total": 0, "page": 1, "page_size": 20, "total_pages": 0 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::fiat_offramp::{
+        FiatOfframpOrderResponse, FiatOfframpOrderStatus, FiatOfframpQuoteResponse,
+    };
+    use crate::services::fiat_onramp::{FiatOrderResponse, FiatOrderStatus, FiatQuoteResponse};
+    use crate::services::gas::GasEstimateResponse;
+    use crate::services::swap::{SwapExecuteResponse, SwapQuoteResponse, SwapStatusResponse};
+    use crate::services::transaction::{BroadcastResponse, TransactionStatus};
+    use crate::shared::api::ApiClient;
+    use serde::de::DeserializeOwned;
+
+    const T0: u64 = 1_700_000_000_000;
+
+    fn fixture<T: DeserializeOwned>(method: &str, path: &str, body: Option<Value>, now: u64) -> T {
+        match respond(method, path, body.as_ref(), now) {
+            DemoResponse::Fixture(value) => ApiClient::deserialize(value).expect("fixture shape"),
+            other => panic!("expected fixture for {} {}, got {:?}", method, path, other),
+        }
+    }
+
+    #[test]
+    fn swap_flow_progresses_to_completed() {
+        let quote: SwapQuoteResponse = fixture(
+            "GET",
+            "/api/v1/swap/quote?from=USDT&to=ETH&amount=300&network=ethereum",
+            None,
+            T0,
+        );
+        assert_eq!(quote.to_amount, "0.099700");
+
+        let body = json!({ "from_token": "USDT", "to_token": "ETH", "amount": "300" });
+        let exec: SwapExecuteResponse = fixture("POST", "/api/v1/swap/execute", Some(body), T0);
+        assert_eq!(exec.to_amount, quote.to_amount);
+        assert!(exec.transaction.is_some());
+
+        let broadcast: BroadcastResponse = fixture(
+            "POST",
+            "/api/v1/transactions/broadcast",
+            Some(json!({})),
+            T0,
+        );
+        let status_path = format!(
+            "/api/v1/transactions/{}/status?chain=ethereum",
+            broadcast.tx_hash
+        );
+        let tx: TransactionStatus = fixture("GET", &status_path, None, T0 + 1_000);
+        assert_eq!(tx.status, "pending");
+        let tx: TransactionStatus = fixture("GET", &status_path, None, T0 + 20_000);
+        assert_eq!(tx.status, "confirmed");
+
+        let path = format!("/api/v1/swap/{}", exec.swap_id);
+        let statuses: Vec<String> = [0, 6_000, 20_000]
+            .iter()
+            .map(|dt| fixture::<SwapStatusResponse>("GET", &path, None, T0 + dt).status)
+            .collect();
+        assert_eq!(statuses, ["pending", "processing", "completed"]);
+    }
+
+    #[test]
+    fn buy_and_withdraw_flows_deserialize() {
+        let quote: FiatQuoteResponse = fixture(
+            "GET",
+            "/api/v1/fiat/onramp/quote?amount=100&currency=USD&token=USDT&payment_method=credit_card",
+            None,
+            T0,
+        );
+        assert_eq!(quote.crypto_amount, "98.500000");
+        let body = json!({ "amount": "100", "token": "USDT", "quote_id": quote.quote_id });
+        let order: FiatOrderResponse =
+            fixture("POST", "/api/v1/fiat/onramp/orders", Some(body), T0);
+        let path = format!("/api/v1/fiat/onramp/orders/{}", order.order_id);
+        let status: FiatOrderStatus = fixture("GET", &path, None, T0 + 20_000);
+        assert_eq!(status.status, "completed");
+        assert!(status.tx_hash.is_some());
+
+        let quote: FiatOfframpQuoteResponse = fixture(
+            "GET",
+            "/api/v1/fiat/offramp/quote?token=ETH&amount=0.1&chain=ethereum&fiat_currency=USD&withdraw_method=bank_account",
+            None,
+            T0,
+        );
+        assert_eq!(quote.stablecoin_amount, "299.100000");
+        let body = json!({ "token": "ETH", "amount": "0.1", "fiat_currency": "USD" });
+        let order: FiatOfframpOrderResponse =
+            fixture("POST", "/api/v1/fiat/offramp/orders", Some(body), T0);
+        let path = format!("/api/v1/fiat/offramp/orders/{}", order.order_id);
+        let status: FiatOfframpOrderStatus = fixture("GET", &path, None, T0 + 6_000);
+        assert_eq!(status.status, "processing");
+
+        let gas: GasEstimateResponse =
+            fixture("GET", "/api/v1/gas/estimate-all?chain=ethereum", None, T0);
+        assert_eq!(gas.average.max_fee_per_gas_gwei, 22.0);
+    }
+
+    #[test]
+    fn writes_without_fixture_are_blocked() {
+        assert_eq!(
+            respond("POST", "/api/v1/limit-orders", Some(&json!({})), T0),
+            DemoResponse::Blocked
+        );
+        assert_eq!(
+            respond("POST", "/api/v1/ton/broadcast", None, T0),
+            DemoResponse::Blocked
+        );
+        assert_eq!(
+            respond("POST", "/api/v1/auth/refresh", None, T0),
+            DemoResponse::PassThrough
+        );
+        assert_eq!(
+            respond("GET", "/api/v1/auth/me", None, T0),
+            DemoResponse::PassThrough
+        );
+    }
+}
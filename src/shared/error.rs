@@ -14,6 +14,9 @@ pub enum AppError {
     #[error("API error: {0}")]
     Api(#[from] ApiError),
 
+    #[error("Auth error: {0}")]
+    Auth(#[from] AuthError),
+
     #[error("Storage error: {0}")]
     Storage(#[from] StorageError),
 
@@ -87,6 +90,22 @@ pub enum ApiError {
     Timeout,
 }
 
+/// 登录失败的具体原因（由后端结构化错误解析而来）
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum AuthError {
+    #[error("Invalid credentials")]
+    InvalidCredentials { remaining_attempts: Option<u32> },
+    #[error("Account locked")]
+    AccountLocked { retry_after_secs: u64 },
+    #[error("Too many requests")]
+    RateLimited { retry_after_secs: u64 },
+    #[error("Email not verified")]
+    EmailNotVerified,
+    /// 已轮换的刷新令牌被再次使用（可能已泄露）
+    #[error("Refresh token reused")]
+    RefreshTokenReused,
+}
+
 #[derive(Error, Debug, Clone, PartialEq)]
 #[allow(dead_code)] // 错误类型定义，用于未来功能
 #[allow(clippy::enum_variant_names)]
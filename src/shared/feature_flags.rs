@@ -2,10 +2,10 @@
 // Configuration-based feature toggles for gradual rollout and remote control
 
 use crate::shared::error::AppError;
+use crate::shared::time;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)] // 功能开关系统，用于未来功能
@@ -95,28 +95,59 @@ impl Default for FeatureFlagsConfig {
             },
         );
 
+        flags.insert(
+            "gas_stablecoin_relayer".to_string(),
+            FeatureFlag {
+                key: "gas_stablecoin_relayer".to_string(),
+                enabled: false,
+                description: "Pay network fees in stablecoins via relayer".to_string(),
+                rollout_percentage: Some(0),
+                allowed_users: None,
+            },
+        );
+
+        flags.insert(
+            "demo_mode".to_string(),
+            FeatureFlag {
+                key: "demo_mode".to_string(),
+                enabled: true,
+                description: "Demo mode with fixture data for support walkthroughs and QA"
+                    .to_string(),
+                rollout_percentage: Some(100),
+                allowed_users: None,
+            },
+        );
+
+        flags.insert(
+            "perf_overlay".to_string(),
+            FeatureFlag {
+                key: "perf_overlay".to_string(),
+                enabled: false,
+                description: "Developer performance metrics overlay".to_string(),
+                rollout_percentage: Some(0),
+                allowed_users: None,
+            },
+        );
+
+        flags.insert(
+            "tx_simulator".to_string(),
+            FeatureFlag {
+                key: "tx_simulator".to_string(),
+                enabled: false,
+                description: "Advanced transaction simulation sandbox for arbitrary calldata"
+                    .to_string(),
+                rollout_percentage: Some(0),
+                allowed_users: None,
+            },
+        );
+
         Self {
             flags,
-            last_updated: now_secs(),
+            last_updated: time::now_unix(),
         }
     }
 }
 
-fn now_secs() -> u64 {
-    #[cfg(target_arch = "wasm32")]
-    {
-        (js_sys::Date::new_0().get_time() / 1000.0) as u64
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs()
-    }
-}
-
 #[derive(Clone, Copy)]
 #[allow(dead_code)] // 功能开关管理器，用于未来功能
 pub struct FeatureFlagsManager {
@@ -192,6 +223,14 @@ impl FeatureFlagsManager {
         false
     }
 
+    /// Check if a feature is explicitly switched off
+    ///
+    /// Unlike `is_enabled`, unknown features are treated as not switched off,
+    /// so remote config can disable something without it being registered here.
+    pub fn is_switched_off(&self, feature_key: &str) -> bool {
+        self.config.read().flags.contains_key(feature_key) && !self.is_enabled(feature_key)
+    }
+
     /// Get all feature flags
     #[allow(dead_code)]
     pub fn get_all_flags(&self) -> HashMap<String, FeatureFlag> {
@@ -204,7 +243,7 @@ impl FeatureFlagsManager {
     /// * `new_config` - New configuration
     #[allow(dead_code)]
     pub fn update_config(&mut self, new_config: FeatureFlagsConfig) {
-        tracing::info!("Updating feature flags configuration");
+        log::info!("Updating feature flags configuration");
         self.config.set(new_config);
     }
 
@@ -253,7 +292,7 @@ impl FeatureFlagsManager {
 
         if let Some(flag) = config.flags.get_mut(feature_key) {
             flag.enabled = !flag.enabled;
-            tracing::info!("Toggled feature '{}' to {}", feature_key, flag.enabled);
+            log::info!("Toggled feature '{}' to {}", feature_key, flag.enabled);
         }
     }
 
@@ -317,7 +356,7 @@ mod tests {
 
         assert!(config.flags.contains_key("token_auto_detect"));
         assert!(config.flags.contains_key("siwe_auth"));
-        assert_eq!(config.flags.len(), 6);
+        assert_eq!(config.flags.len(), 10);
     }
 
     #[test]
@@ -333,6 +372,10 @@ mod tests {
 
         // Unknown feature should be disabled
         assert!(!manager.is_enabled("unknown_feature"));
+
+        // ...but only registered flags count as switched off
+        assert!(manager.is_switched_off("cross_chain_bridge"));
+        assert!(!manager.is_switched_off("unknown_feature"));
     }
 
     #[test]
@@ -0,0 +1,81 @@
+//! Freshness - 数据新鲜度
+//! 价格、报价与余额记录查询时间（Unix 秒）；超过设置的过期时长或网络离线时，
+//! 界面将数据变暗并提供刷新入口，避免把旧数据当作实时数据展示
+
+/// 可选的过期时长（秒）
+pub const STALE_AFTER_OPTIONS: [u64; 4] = [120, 300, 600, 1800];
+/// 默认过期时长（秒）：价格缓存 5 分钟、仪表盘每 30 秒刷新，正常刷新时不会超过
+pub const DEFAULT_STALE_AFTER_SECS: u64 = 600;
+
+/// 数据新鲜度
+#[derive(Debug, Clone, PartialEq)]
+pub struct Freshness {
+    /// 「X秒前更新」提示（没有查询时间时为空）
+    pub label: String,
+    /// 是否应按过期数据展示
+    pub stale: bool,
+}
+
+impl Freshness {
+    pub fn evaluate(
+        fetched_at: Option<u64>,
+        now: u64,
+        stale_after_secs: u64,
+        online: bool,
+    ) -> Self {
+        match fetched_at {
+            Some(fetched_at) => {
+                let age = now.saturating_sub(fetched_at);
+                Self {
+                    label: updated_ago_label(age),
+                    stale: !online || age > stale_after_secs,
+                }
+            }
+            None => Self {
+                label: String::new(),
+                stale: !online,
+            },
+        }
+    }
+}
+
+/// 「X秒前更新」
+pub fn updated_ago_label(age_secs: u64) -> String {
+    match age_secs {
+        0..=4 => "刚刚更新".to_string(),
+        5..=59 => format!("{}秒前更新", age_secs),
+        60..=3599 => format!("{}分钟前更新", age_secs / 60),
+        _ => format!("{}小时前更新", age_secs / 3600),
+    }
+}
+
+/// 过期时长选项的显示文本
+pub fn stale_after_label(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}秒", secs)
+    } else {
+        format!("{}分钟", secs / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_after_threshold_or_offline() {
+        let fresh = Freshness::evaluate(Some(1000), 1030, 120, true);
+        assert_eq!(fresh.label, "30秒前更新");
+        assert!(!fresh.stale);
+
+        let old = Freshness::evaluate(Some(1000), 1000 + 600, 120, true);
+        assert_eq!(old.label, "10分钟前更新");
+        assert!(old.stale);
+
+        // 离线时即使刚查询过也按过期展示
+        assert!(Freshness::evaluate(Some(1000), 1001, 120, false).stale);
+        assert!(!Freshness::evaluate(None, 1000, 120, true).stale);
+        assert_eq!(updated_ago_label(2), "刚刚更新");
+        assert_eq!(updated_ago_label(7200), "2小时前更新");
+    }
+}
@@ -0,0 +1,221 @@
+//! Kill Switch - 资金操作紧急开关
+//!
+//! 签名路径发现漏洞时，由远程功能开关（`/api/v1/features`）暂停兑换、转账、提现或全部签名，
+//! 修复上线前阻止交易被签名和广播；余额、行情、历史记录等只读功能不受影响。
+//! - 在服务层的执行入口（兑换下单、合约调用、转账签名、提现下单、其他签名在派生私钥前、广播与重新广播）检查，不依赖页面状态
+//! - 远程配置只缓存 [`CACHE_TTL_MS`]，开关生效后最迟半分钟内拦截
+//! - 无法获取配置（离线、接口故障）时按 [`FETCH_FAILURE_POLICY`] 放行或拦截
+
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::misc;
+use crate::shared::feature_flags::FeatureFlagsConfig;
+use std::cell::RefCell;
+
+/// 暂停兑换（含授权、包装/解包）
+pub const DISABLE_SWAPS: &str = "disable_swaps";
+/// 暂停转账（含跨链转账）
+pub const DISABLE_SENDS: &str = "disable_sends";
+/// 暂停法币提现
+pub const DISABLE_WITHDRAWALS: &str = "disable_withdrawals";
+/// 暂停所有签名与广播
+pub const DISABLE_ALL_SIGNING: &str = "disable_all_signing";
+
+/// 维护提示的开头（错误分类据此识别，见 [`crate::shared::ui_error`]）
+pub const MAINTENANCE_MARKER: &str = "系统维护中";
+
+/// 远程配置缓存时长（毫秒）
+pub const CACHE_TTL_MS: u64 = 30_000;
+
+/// 无法获取开关配置时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailurePolicy {
+    /// 放行（开关接口故障不影响正常交易）
+    Open,
+    /// 拦截（确认开关未生效前不允许资金操作）
+    Closed,
+}
+
+/// 编译期确定的故障策略
+pub const FETCH_FAILURE_POLICY: FailurePolicy = FailurePolicy::Open;
+
+/// 受紧急开关控制的资金操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoneyAction {
+    Swap,
+    Send,
+    Withdrawal,
+    /// 其他签名广播（质押、加速交易等），只受 [`DISABLE_ALL_SIGNING`] 控制
+    Sign,
+}
+
+impl MoneyAction {
+    /// 单独控制该操作的开关
+    fn flag(self) -> Option<&'static str> {
+        match self {
+            MoneyAction::Swap => Some(DISABLE_SWAPS),
+            MoneyAction::Send => Some(DISABLE_SENDS),
+            MoneyAction::Withdrawal => Some(DISABLE_WITHDRAWALS),
+            MoneyAction::Sign => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MoneyAction::Swap => "兑换",
+            MoneyAction::Send => "转账",
+            MoneyAction::Withdrawal => "提现",
+            MoneyAction::Sign => "交易签名",
+        }
+    }
+}
+
+/// 开关生效时的提示（错误提示组件据“系统维护”识别并附上服务状态页链接）
+pub fn maintenance_message(action: MoneyAction) -> String {
+    format!(
+        "{}：{}功能暂时停用，资产安全不受影响，请稍后再试",
+        MAINTENANCE_MARKER,
+        action.label()
+    )
+}
+
+/// 按开关配置判断操作是否允许；`flags` 为 None 表示未能获取配置
+pub fn evaluate(
+    flags: Option<&FeatureFlagsConfig>,
+    action: MoneyAction,
+    policy: FailurePolicy,
+) -> Result<(), String> {
+    let Some(flags) = flags else {
+        return match policy {
+            FailurePolicy::Open => Ok(()),
+            FailurePolicy::Closed => Err(format!(
+                "{}：暂时无法确认服务状态，{}功能已暂停，请稍后再试",
+                MAINTENANCE_MARKER,
+                action.label()
+            )),
+        };
+    };
+    let switched_on = |key: &str| flags.flags.get(key).is_some_and(|f| f.enabled);
+    if switched_on(DISABLE_ALL_SIGNING) || action.flag().is_some_and(switched_on) {
+        return Err(maintenance_message(action));
+    }
+    Ok(())
+}
+
+thread_local! {
+    /// 最近一次获取的配置及获取时间（毫秒）
+    static CACHE: RefCell<Option<(u64, FeatureFlagsConfig)>> = const { RefCell::new(None) };
+}
+
+fn cached(now_ms: u64) -> Option<FeatureFlagsConfig> {
+    CACHE.with(|c| {
+        c.borrow()
+            .as_ref()
+            .filter(|(at, _)| now_ms.saturating_sub(*at) < CACHE_TTL_MS)
+            .map(|(_, flags)| flags.clone())
+    })
+}
+
+/// 当前开关配置（缓存过期后重新获取，获取失败返回 None）
+async fn current_flags(api: &ApiClient) -> Option<FeatureFlagsConfig> {
+    let now = crate::shared::time::now_ms() as u64;
+    if let Some(flags) = cached(now) {
+        return Some(flags);
+    }
+    match api.get::<Option<FeatureFlagsConfig>>(misc::FEATURES).await {
+        Ok(flags) => {
+            let flags = flags.unwrap_or_else(|| FeatureFlagsConfig {
+                flags: Default::default(),
+                last_updated: now / 1000,
+            });
+            CACHE.with(|c| *c.borrow_mut() = Some((now, flags.clone())));
+            Some(flags)
+        }
+        Err(e) => {
+            log::warn!("获取紧急开关配置失败: {}", e);
+            None
+        }
+    }
+}
+
+/// 执行资金操作前检查紧急开关（在签名之前调用）
+pub async fn ensure_allowed(api: &ApiClient, action: MoneyAction) -> Result<(), String> {
+    let flags = current_flags(api).await;
+    evaluate(flags.as_ref(), action, FETCH_FAILURE_POLICY).inspect_err(|_| {
+        log::warn!("紧急开关拦截{}操作", action.label());
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::feature_flags::FeatureFlag;
+
+    fn flags(on: &[&str]) -> FeatureFlagsConfig {
+        FeatureFlagsConfig {
+            flags: on
+                .iter()
+                .map(|key| {
+                    let flag = FeatureFlag {
+                        key: key.to_string(),
+                        enabled: true,
+                        description: String::new(),
+                        rollout_percentage: None,
+                        allowed_users: None,
+                    };
+                    (key.to_string(), flag)
+                })
+                .collect(),
+            last_updated: 0,
+        }
+    }
+
+    #[test]
+    fn switches_block_their_action_and_all_signing_blocks_everything() {
+        let swaps_off = flags(&[DISABLE_SWAPS]);
+        let err = evaluate(Some(&swaps_off), MoneyAction::Swap, FailurePolicy::Open).unwrap_err();
+        assert!(err.starts_with(MAINTENANCE_MARKER));
+        assert!(evaluate(Some(&swaps_off), MoneyAction::Send, FailurePolicy::Open).is_ok());
+        assert!(evaluate(Some(&swaps_off), MoneyAction::Sign, FailurePolicy::Open).is_ok());
+
+        let all_off = flags(&[DISABLE_ALL_SIGNING]);
+        for action in [
+            MoneyAction::Swap,
+            MoneyAction::Send,
+            MoneyAction::Withdrawal,
+            MoneyAction::Sign,
+        ] {
+            assert!(evaluate(Some(&all_off), action, FailurePolicy::Open).is_err());
+        }
+
+        // 开关关闭（enabled = false）时照常放行
+        let mut disabled = flags(&[DISABLE_WITHDRAWALS]);
+        disabled.flags.get_mut(DISABLE_WITHDRAWALS).unwrap().enabled = false;
+        assert!(evaluate(
+            Some(&disabled),
+            MoneyAction::Withdrawal,
+            FailurePolicy::Closed
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn fetch_failure_follows_policy() {
+        for action in [
+            MoneyAction::Swap,
+            MoneyAction::Send,
+            MoneyAction::Withdrawal,
+        ] {
+            assert!(evaluate(None, action, FailurePolicy::Open).is_ok());
+            let err = evaluate(None, action, FailurePolicy::Closed).unwrap_err();
+            assert!(err.contains(action.label()));
+        }
+    }
+
+    #[test]
+    fn cached_flags_expire_quickly() {
+        let t0 = 1_700_000_000_000;
+        CACHE.with(|c| *c.borrow_mut() = Some((t0, flags(&[DISABLE_SENDS]))));
+        assert!(cached(t0 + CACHE_TTL_MS - 1).is_some());
+        assert!(cached(t0 + CACHE_TTL_MS).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+//! List Query - 列表分页/排序/筛选状态
+//!
+//! 订单类列表共用的查询状态，映射为后端查询参数（服务端筛选与排序）。
+//! 状态按列表保存在 SessionStorage 中，会话内离开页面再返回时保持原有页码与筛选。
+
+use dioxus::prelude::*;
+use gloo_storage::{SessionStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+/// 可选的每页数量
+pub const PAGE_SIZE_OPTIONS: [u32; 3] = [10, 20, 50];
+
+/// 排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortField {
+    Date,
+    Amount,
+    Status,
+}
+
+impl SortField {
+    pub const ALL: [SortField; 3] = [SortField::Date, SortField::Amount, SortField::Status];
+
+    /// 后端 sort_by 参数
+    pub fn param(&self) -> &'static str {
+        match self {
+            SortField::Date => "created_at",
+            SortField::Amount => "amount",
+            SortField::Status => "status",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortField::Date => "日期",
+            SortField::Amount => "金额",
+            SortField::Status => "状态",
+        }
+    }
+}
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    /// 后端 sort_order 参数
+    pub fn param(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "↑",
+            SortOrder::Desc => "↓",
+        }
+    }
+}
+
+/// 列表查询状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListQueryState {
+    pub page: u32,
+    pub page_size: u32,
+    pub sort_by: SortField,
+    pub sort_order: SortOrder,
+    /// 状态筛选（None 表示全部）
+    pub status: Option<String>,
+}
+
+impl Default for ListQueryState {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: PAGE_SIZE_OPTIONS[0],
+            sort_by: SortField::Date,
+            sort_order: SortOrder::Desc,
+            status: None,
+        }
+    }
+}
+
+impl ListQueryState {
+    /// 切换状态筛选（回到第一页）
+    pub fn set_status(&mut self, status: Option<String>) {
+        self.status = status;
+        self.page = 1;
+    }
+
+    /// 点击排序列：同一列切换方向，新列默认降序（回到第一页）
+    pub fn toggle_sort(&mut self, field: SortField) {
+        if self.sort_by == field {
+            self.sort_order = match self.sort_order {
+                SortOrder::Asc => SortOrder::Desc,
+                SortOrder::Desc => SortOrder::Asc,
+            };
+        } else {
+            self.sort_by = field;
+            self.sort_order = SortOrder::Desc;
+        }
+        self.page = 1;
+    }
+
+    /// 修改每页数量（不在可选范围内时使用默认值，回到第一页）
+    pub fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = if PAGE_SIZE_OPTIONS.contains(&page_size) {
+            page_size
+        } else {
+            PAGE_SIZE_OPTIONS[0]
+        };
+        self.page = 1;
+    }
+
+    /// 跳转页码（限制在 1..=total_pages）
+    pub fn go_to(&mut self, page: u32, total_pages: u32) {
+        self.page = page.clamp(1, total_pages.max(1));
+    }
+
+    pub fn has_filters(&self) -> bool {
+        self.status.is_some()
+    }
+
+    pub fn clear_filters(&mut self) {
+        self.set_status(None);
+    }
+
+    /// 缓存键（包含全部查询条件）
+    pub fn cache_key(&self, prefix: &str) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            prefix,
+            self.status.as_deref().unwrap_or("all"),
+            self.sort_by.param(),
+            self.sort_order.param(),
+            self.page_size,
+            self.page
+        )
+    }
+
+    fn storage_key(list: &str) -> String {
+        format!("list_query:{}", list)
+    }
+
+    /// 读取会话内保存的查询状态
+    pub fn load(list: &str) -> Self {
+        SessionStorage::get(Self::storage_key(list)).unwrap_or_default()
+    }
+
+    pub fn save(&self, list: &str) {
+        let _ = SessionStorage::set(Self::storage_key(list), self);
+    }
+}
+
+/// 列表查询状态（会话内持久化）
+pub fn use_list_query(list: &'static str) -> Signal<ListQueryState> {
+    let state = use_signal(|| ListQueryState::load(list));
+    use_effect(move || state.read().save(list));
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_and_sort_changes_reset_page() {
+        let mut state = ListQueryState {
+            page: 4,
+            ..Default::default()
+        };
+        state.set_status(Some("filled".to_string()));
+        assert_eq!(state.page, 1);
+        assert!(state.has_filters());
+
+        state.page = 3;
+        state.toggle_sort(SortField::Date);
+        assert_eq!(state.sort_order, SortOrder::Asc);
+        assert_eq!(state.page, 1);
+
+        state.toggle_sort(SortField::Amount);
+        assert_eq!(state.sort_by, SortField::Amount);
+        assert_eq!(state.sort_order, SortOrder::Desc);
+
+        state.clear_filters();
+        assert!(!state.has_filters());
+    }
+
+    #[test]
+    fn page_navigation_is_clamped() {
+        let mut state = ListQueryState::default();
+        state.go_to(7, 5);
+        assert_eq!(state.page, 5);
+        state.go_to(0, 5);
+        assert_eq!(state.page, 1);
+        state.go_to(2, 0);
+        assert_eq!(state.page, 1);
+
+        state.set_page_size(33);
+        assert_eq!(state.page_size, PAGE_SIZE_OPTIONS[0]);
+        state.set_page_size(50);
+        assert_eq!(state.page_size, 50);
+    }
+
+    #[test]
+    fn cache_key_reflects_query() {
+        let mut state = ListQueryState::default();
+        let base = state.cache_key("limit_orders");
+        state.set_status(Some("expired".to_string()));
+        assert_ne!(state.cache_key("limit_orders"), base);
+        assert!(state.cache_key("limit_orders").contains("expired"));
+    }
+}
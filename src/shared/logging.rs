@@ -0,0 +1,433 @@
+//! Logging - 统一日志门面
+//! 全应用统一使用 `log` 宏，由这里的 logger 处理：按类别（网络、签名、界面、缓存）分级过滤，
+//! 级别可在隐藏的调试面板（`?perf=1`）中运行时调整；info 及以上级别自动脱敏地址、金额与哈希，
+//! 签名类日志在任何级别都脱敏；形似私钥/种子的裸十六进制串无论级别与类别一律替换。
+//! 输出同时写入环形缓冲区，供设置页"下载调试日志"导出
+
+use crate::services::error_logger;
+use crate::services::preferences;
+use crate::shared::time;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use wasm_bindgen::JsValue;
+
+/// 显式类别 target：`log::info!(target: logging::SIGNING, ...)`
+pub const NETWORK: &str = "network";
+pub const SIGNING: &str = "signing";
+pub const UI: &str = "ui";
+pub const CACHE: &str = "cache";
+
+/// 环形缓冲区容量
+const BUFFER_CAPACITY: usize = 1000;
+/// 默认级别
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// 日志类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogCategory {
+    Network,
+    Signing,
+    Ui,
+    Cache,
+}
+
+/// 未显式指定类别时按模块路径归类（按顺序匹配，先命中者优先）
+const MODULE_CATEGORIES: [(&str, LogCategory); 11] = [
+    ("::crypto", LogCategory::Signing),
+    ("::features::wallet", LogCategory::Signing),
+    ("::services::secure_storage_manager", LogCategory::Signing),
+    ("::services::wallet_session_manager", LogCategory::Signing),
+    ("::cache", LogCategory::Cache),
+    ("::storage", LogCategory::Cache),
+    ("::services::", LogCategory::Network),
+    ("::blockchain", LogCategory::Network),
+    ("::shared::api", LogCategory::Network),
+    ("::shared::auth_handler", LogCategory::Network),
+    ("::shared::websocket", LogCategory::Network),
+];
+
+impl LogCategory {
+    pub const ALL: [LogCategory; 4] = [
+        LogCategory::Network,
+        LogCategory::Signing,
+        LogCategory::Ui,
+        LogCategory::Cache,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogCategory::Network => NETWORK,
+            LogCategory::Signing => SIGNING,
+            LogCategory::Ui => UI,
+            LogCategory::Cache => CACHE,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LogCategory::Network => "网络",
+            LogCategory::Signing => "签名",
+            LogCategory::Ui => "界面",
+            LogCategory::Cache => "缓存",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// 由 target 归类：显式类别名优先，其次按模块路径，其余归为界面
+    pub fn from_target(target: &str) -> Self {
+        if let Some(category) = Self::ALL.into_iter().find(|c| c.as_str() == target) {
+            return category;
+        }
+        MODULE_CATEGORIES
+            .iter()
+            .find(|(pattern, _)| target.contains(pattern))
+            .map(|(_, category)| *category)
+            .unwrap_or(LogCategory::Ui)
+    }
+}
+
+/// 缓冲区中的一条日志（已按输出时的规则脱敏）
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp_ms: f64,
+    pub level: Level,
+    pub category: LogCategory,
+    pub message: String,
+}
+
+thread_local! {
+    static LEVELS: RefCell<[LevelFilter; 4]> = const { RefCell::new([DEFAULT_LEVEL; 4]) };
+    static BUFFER: RefCell<VecDeque<LogEntry>> = const { RefCell::new(VecDeque::new()) };
+}
+
+struct AppLogger;
+
+static LOGGER: AppLogger = AppLogger;
+
+impl Log for AppLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= level(LogCategory::from_target(metadata.target()))
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let category = LogCategory::from_target(record.target());
+        let full = record.level() <= Level::Info || category == LogCategory::Signing;
+        let message = redact(&record.args().to_string(), full);
+
+        let line = JsValue::from_str(&format!("[{}] {}", category.as_str(), message));
+        match record.level() {
+            Level::Error => web_sys::console::error_1(&line),
+            Level::Warn => web_sys::console::warn_1(&line),
+            Level::Info => web_sys::console::info_1(&line),
+            Level::Debug => web_sys::console::debug_1(&line),
+            Level::Trace => web_sys::console::log_1(&line),
+        }
+
+        let entry = LogEntry {
+            timestamp_ms: time::now_ms(),
+            level: record.level(),
+            category,
+            message,
+        };
+        BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            if buffer.len() >= BUFFER_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(entry);
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// 安装 logger 并应用保存的级别（应用启动时调用一次）
+pub fn init() {
+    if log::set_logger(&LOGGER).is_err() {
+        return;
+    }
+    let stored = preferences::load().log_levels;
+    LEVELS.with(|levels| {
+        let mut levels = levels.borrow_mut();
+        for category in LogCategory::ALL {
+            if let Some(level) = stored
+                .get(category.as_str())
+                .and_then(|raw| raw.parse::<LevelFilter>().ok())
+            {
+                levels[category.index()] = level;
+            }
+        }
+    });
+    sync_max_level();
+}
+
+/// 让 `log` 宏的全局门槛与最宽松的类别级别一致，被过滤的日志不必格式化
+fn sync_max_level() {
+    let max = LEVELS.with(|levels| levels.borrow().iter().copied().max());
+    log::set_max_level(max.unwrap_or(DEFAULT_LEVEL));
+}
+
+/// 类别当前级别
+pub fn level(category: LogCategory) -> LevelFilter {
+    LEVELS.with(|levels| levels.borrow()[category.index()])
+}
+
+/// 调整类别级别（记入偏好存储，仅本设备）
+pub fn set_level(category: LogCategory, level: LevelFilter) {
+    LEVELS.with(|levels| levels.borrow_mut()[category.index()] = level);
+    sync_max_level();
+    preferences::update(|stored| {
+        stored
+            .log_levels
+            .insert(category.as_str().to_string(), level.to_string());
+    });
+}
+
+/// 识别出的敏感片段
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sensitive {
+    /// 形似私钥/种子的裸十六进制串（任何级别都替换）
+    Secret,
+    Address,
+    Hash,
+    HexData,
+    Amount,
+}
+
+impl Sensitive {
+    fn placeholder(self) -> &'static str {
+        match self {
+            Sensitive::Secret => "[redacted]",
+            Sensitive::Address => "[address]",
+            Sensitive::Hash => "[hash]",
+            Sensitive::HexData => "[hex]",
+            Sensitive::Amount => "[amount]",
+        }
+    }
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn is_hex(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn is_amount(s: &str) -> bool {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    match s.split_once('.') {
+        Some((int, frac)) => {
+            !int.is_empty()
+                && !frac.is_empty()
+                && int.bytes().all(|b| b.is_ascii_digit())
+                && frac.bytes().all(|b| b.is_ascii_digit())
+        }
+        // 7 位以上的整数多为最小单位金额（wei、lamports 等）
+        None => s.len() >= 7 && s.bytes().all(|b| b.is_ascii_digit()),
+    }
+}
+
+fn is_base58_address(s: &str) -> bool {
+    (26..=44).contains(&s.len())
+        && s.chars().all(|c| BASE58_ALPHABET.contains(c))
+        && s.bytes().any(|b| b.is_ascii_digit())
+        && s.bytes().any(|b| b.is_ascii_uppercase())
+        && s.bytes().any(|b| b.is_ascii_lowercase())
+}
+
+fn is_bech32_address(s: &str) -> bool {
+    ["bc1", "tb1", "bcrt1"].iter().any(|p| s.starts_with(p))
+        && (26..=90).contains(&s.len())
+        && s.bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+}
+
+fn is_ton_friendly_address(s: &str) -> bool {
+    s.len() == 48
+        && ["EQ", "UQ", "kQ", "0Q"].iter().any(|p| s.starts_with(p))
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+/// 判断片段类型；`after_workchain` 表示紧跟在 TON 原始地址的 `0:` / `-1:` 之后
+fn classify(token: &str, after_workchain: bool) -> Option<Sensitive> {
+    if let Some(body) = token
+        .strip_prefix("0x")
+        .or_else(|| token.strip_prefix("0X"))
+    {
+        return match body.len() {
+            _ if !is_hex(body) => None,
+            40 => Some(Sensitive::Address),
+            64 => Some(Sensitive::Hash),
+            n if n >= 16 => Some(Sensitive::HexData),
+            _ => None,
+        };
+    }
+    if is_hex(token) && matches!(token.len(), 64 | 128) {
+        return Some(if after_workchain && token.len() == 64 {
+            Sensitive::Address
+        } else {
+            Sensitive::Secret
+        });
+    }
+    if is_amount(token) {
+        return Some(Sensitive::Amount);
+    }
+    if is_bech32_address(token) || is_ton_friendly_address(token) || is_base58_address(token) {
+        return Some(Sensitive::Address);
+    }
+    None
+}
+
+fn is_token_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')
+}
+
+fn flush_token(out: &mut String, token: &mut String, full: bool) {
+    if token.is_empty() {
+        return;
+    }
+    // 句末标点不属于片段本身
+    let core = token.trim_end_matches(['.', '-', '_']);
+    let tail = &token[core.len()..];
+    let after_workchain = out.ends_with("0:") || out.ends_with("-1:");
+    match classify(core, after_workchain) {
+        Some(Sensitive::Secret) => out.push_str(Sensitive::Secret.placeholder()),
+        Some(kind) if full => out.push_str(kind.placeholder()),
+        _ => out.push_str(core),
+    }
+    out.push_str(tail);
+    token.clear();
+}
+
+/// 脱敏：`full` 时替换地址、金额与哈希；形似私钥/种子的裸十六进制串总是替换
+pub fn redact(message: &str, full: bool) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut token = String::new();
+    for c in message.chars() {
+        if is_token_char(c) {
+            token.push(c);
+        } else {
+            flush_token(&mut out, &mut token, full);
+            out.push(c);
+        }
+    }
+    flush_token(&mut out, &mut token, full);
+    out
+}
+
+fn format_timestamp(timestamp_ms: f64) -> String {
+    chrono::DateTime::from_timestamp_millis(timestamp_ms as i64)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S%.3f").to_string())
+        .unwrap_or_default()
+}
+
+/// 导出调试日志文本：环境信息、各类别级别、缓冲区日志与最近的错误记录
+pub fn export_text() -> String {
+    let user_agent = web_sys::window()
+        .and_then(|w| w.navigator().user_agent().ok())
+        .unwrap_or_default();
+    let levels = LogCategory::ALL
+        .iter()
+        .map(|c| format!("{}={}", c.as_str(), level(*c)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut text = format!(
+        "IronForge 调试日志\n生成时间: {}\n版本: {}\n浏览器: {}\n级别: {}\n\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+        env!("CARGO_PKG_VERSION"),
+        user_agent,
+        levels
+    );
+    BUFFER.with(|buffer| {
+        for entry in buffer.borrow().iter() {
+            text.push_str(&format!(
+                "{} {:<5} [{}] {}\n",
+                format_timestamp(entry.timestamp_ms),
+                entry.level,
+                entry.category.as_str(),
+                entry.message
+            ));
+        }
+    });
+
+    let errors = error_logger::recent_stored_logs(50);
+    if !errors.is_empty() {
+        text.push_str("\n最近错误:\n");
+        for error in errors.iter().rev() {
+            text.push_str(&format!(
+                "{} {} {}\n",
+                format_timestamp(error.timestamp as f64 * 1000.0),
+                error.level.label(),
+                redact(&error.message, true)
+            ));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redaction_depends_on_level_but_secrets_never_pass() {
+        let address = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+        let hash = format!("0x{}", "ab".repeat(32));
+        let private_key = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let message = format!(
+            "发送 1.25 ETH 到 {}, tx_hash={}, wei=1250000000000000000, key {}.",
+            address, hash, private_key
+        );
+
+        assert_eq!(
+            redact(&message, true),
+            "发送 [amount] ETH 到 [address], tx_hash=[hash], wei=[amount], key [redacted]."
+        );
+        let verbose = redact(&message, false);
+        assert!(verbose.contains(address) && verbose.contains(&hash));
+        assert!(verbose.contains("1.25"));
+        assert!(!verbose.contains(private_key));
+
+        // 其他链的地址
+        assert_eq!(
+            redact("sol 7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV, btc bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", true),
+            "sol [address], btc [address]"
+        );
+        assert_eq!(
+            redact(&format!("ton 0:{}", "cd".repeat(32)), true),
+            "ton 0:[address]"
+        );
+        // 普通文本、版本号、UUID 与小整数保持原样
+        let plain = "status=200 v0.7.2 id=550e8400-e29b-41d4-a716-446655440000 retry 3";
+        assert_eq!(redact(plain, true), plain);
+    }
+
+    #[test]
+    fn categories_follow_target_then_module_path() {
+        assert_eq!(LogCategory::from_target("signing"), LogCategory::Signing);
+        assert_eq!(
+            LogCategory::from_target("ironforge::crypto::tx_signer"),
+            LogCategory::Signing
+        );
+        assert_eq!(
+            LogCategory::from_target("ironforge::services::cache"),
+            LogCategory::Cache
+        );
+        assert_eq!(
+            LogCategory::from_target("ironforge::services::price"),
+            LogCategory::Network
+        );
+        assert_eq!(
+            LogCategory::from_target("ironforge::pages::swap"),
+            LogCategory::Ui
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Markdown - 轻量 Markdown 解析
+//! 仅支持帮助内容用到的子集（标题、段落、列表、代码块、粗体、行内代码、链接），
+//! 解析为结构化节点后由组件渲染，不使用 innerHTML，原始 HTML 按文本显示
+
+/// 行内节点
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Bold(String),
+    Code(String),
+    /// 只保留 https 链接，其余按文本显示
+    Link {
+        text: String,
+        url: String,
+    },
+}
+
+/// 块级节点
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(u8, Vec<Inline>),
+    Paragraph(Vec<Inline>),
+    List {
+        ordered: bool,
+        items: Vec<Vec<Inline>>,
+    },
+    Code(String),
+}
+
+/// 列表项标记：返回（是否有序, 内容）
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    if let Some(rest) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((false, rest));
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(rest) = line[digits..].strip_prefix(". ") {
+            return Some((true, rest));
+        }
+    }
+    None
+}
+
+/// 解析为块级节点
+pub fn parse(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = source.lines();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(parse_inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(raw) = lines.next() {
+        let line = raw.trim();
+        if line.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+            continue;
+        }
+        if line.starts_with("```") {
+            flush(&mut paragraph, &mut blocks);
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.trim_start().starts_with("```"))
+                .collect();
+            blocks.push(Block::Code(code.join("\n")));
+            continue;
+        }
+        let hashes = line.chars().take_while(|c| *c == '#').count();
+        if (1..=3).contains(&hashes) && line[hashes..].starts_with(' ') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(
+                hashes as u8,
+                parse_inline(line[hashes..].trim()),
+            ));
+            continue;
+        }
+        if let Some((ordered, item)) = list_item(line) {
+            flush(&mut paragraph, &mut blocks);
+            match blocks.last_mut() {
+                Some(Block::List { ordered: o, items }) if *o == ordered => {
+                    items.push(parse_inline(item))
+                }
+                _ => blocks.push(Block::List {
+                    ordered,
+                    items: vec![parse_inline(item)],
+                }),
+            }
+            continue;
+        }
+        paragraph.push(line);
+    }
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+/// 解析行内格式
+pub fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut nodes = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    let push_text = |plain: &mut String, nodes: &mut Vec<Inline>| {
+        if !plain.is_empty() {
+            nodes.push(Inline::Text(std::mem::take(plain)));
+        }
+    };
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**") {
+            if let Some(end) = after.find("**") {
+                push_text(&mut plain, &mut nodes);
+                nodes.push(Inline::Bold(after[..end].to_string()));
+                rest = &after[end + 2..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                push_text(&mut plain, &mut nodes);
+                nodes.push(Inline::Code(after[..end].to_string()));
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix('[') {
+            if let Some((label, tail)) = after.split_once("](") {
+                if let Some(end) = tail.find(')') {
+                    let url = tail[..end].trim();
+                    push_text(&mut plain, &mut nodes);
+                    if url.starts_with("https://") {
+                        nodes.push(Inline::Link {
+                            text: label.to_string(),
+                            url: url.to_string(),
+                        });
+                    } else {
+                        plain.push_str(label);
+                    }
+                    rest = &tail[end + 1..];
+                    continue;
+                }
+            }
+        }
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    push_text(&mut plain, &mut nodes);
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_blocks() {
+        let blocks =
+            parse("## 备份\n\n第一行\n第二行\n\n- 一\n- 二\n1. 甲\n\n```\nlet a = 1;\n```");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading(2, vec![Inline::Text("备份".into())]),
+                Block::Paragraph(vec![Inline::Text("第一行 第二行".into())]),
+                Block::List {
+                    ordered: false,
+                    items: vec![
+                        vec![Inline::Text("一".into())],
+                        vec![Inline::Text("二".into())]
+                    ],
+                },
+                Block::List {
+                    ordered: true,
+                    items: vec![vec![Inline::Text("甲".into())]],
+                },
+                Block::Code("let a = 1;".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_inline_and_drops_unsafe_links() {
+        assert_eq!(
+            parse_inline("打开 **设置** 中的 `备份`，见[文档](https://docs.example)"),
+            vec![
+                Inline::Text("打开 ".into()),
+                Inline::Bold("设置".into()),
+                Inline::Text(" 中的 ".into()),
+                Inline::Code("备份".into()),
+                Inline::Text("，见".into()),
+                Inline::Link {
+                    text: "文档".into(),
+                    url: "https://docs.example".into()
+                },
+            ]
+        );
+        assert_eq!(
+            parse_inline("[点我](javascript:void) <b>x</b>"),
+            vec![Inline::Text("点我 <b>x</b>".into())]
+        );
+    }
+}
@@ -0,0 +1,469 @@
+//! Metrics Registry - 前端性能指标注册表
+//!
+//! 收集：路由切换耗时、首个余额可见时间、各端点 API 延迟分位数、请求缓存命中率、
+//! 堆内存占用、掉帧估算与 Gas Limit 估算降级次数。指标只保存在内存中；用户同意后由 `MetricsReporter`
+//! 按批上报匿名聚合值（不含地址、金额或请求参数）。
+
+use crate::services::preferences;
+use crate::shared::api::ApiClient;
+use crate::shared::api_endpoints::misc;
+use crate::shared::error::ApiError;
+use js_sys::Reflect;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// 每个端点最多保留的延迟样本数
+const MAX_SAMPLES_PER_ENDPOINT: usize = 200;
+/// 路由切换最多保留的样本数
+const MAX_ROUTE_SAMPLES: usize = 100;
+/// 60fps 下的单帧预算（毫秒）
+const FRAME_BUDGET_MS: f64 = 1000.0 / 60.0;
+type FrameCallbackSlot = Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>>;
+
+/// 超过该间隔的帧视为标签页被挂起，不计入掉帧
+const MAX_FRAME_DELTA_MS: f64 = 1000.0;
+
+/// 请求缓存查询结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheOutcome {
+    /// 命中且未过期
+    Hit,
+    /// 命中过期数据（stale-while-revalidate）
+    StaleHit,
+    /// 未命中，发起网络请求
+    Miss,
+}
+
+/// 单个端点的延迟聚合
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointLatency {
+    pub endpoint: String,
+    pub count: usize,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+}
+
+/// 指标快照（浮层展示与上报共用）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub route_transition_p50_ms: Option<f64>,
+    pub route_transition_p95_ms: Option<f64>,
+    pub time_to_first_balance_ms: Option<f64>,
+    pub endpoints: Vec<EndpointLatency>,
+    pub cache_hit_rate: Option<f64>,
+    pub js_heap_mb: Option<f64>,
+    pub wasm_memory_mb: Option<f64>,
+    pub frames_sampled: u64,
+    pub dropped_frames: u64,
+    /// Gas Limit 估算失败、使用保守默认值的次数
+    #[serde(default)]
+    pub gas_limit_fallbacks: u64,
+    /// 代币列表的精度/符号与链上不一致、已按链上数据校正的次数
+    #[serde(default)]
+    pub token_metadata_corrections: u64,
+}
+
+/// 内存中的指标注册表
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    api_latency: HashMap<String, Vec<f64>>,
+    route_transitions: Vec<f64>,
+    time_to_first_balance: Option<f64>,
+    cache_hits: u64,
+    cache_stale_hits: u64,
+    cache_misses: u64,
+    frames_sampled: u64,
+    dropped_frames: u64,
+    gas_limit_fallbacks: u64,
+    token_metadata_corrections: u64,
+}
+
+impl MetricsRegistry {
+    pub fn record_api_latency(&mut self, path: &str, duration_ms: f64) {
+        let samples = self
+            .api_latency
+            .entry(normalize_endpoint(path))
+            .or_default();
+        samples.push(duration_ms);
+        if samples.len() > MAX_SAMPLES_PER_ENDPOINT {
+            samples.remove(0);
+        }
+    }
+
+    pub fn record_route_transition(&mut self, duration_ms: f64) {
+        self.route_transitions.push(duration_ms);
+        if self.route_transitions.len() > MAX_ROUTE_SAMPLES {
+            self.route_transitions.remove(0);
+        }
+    }
+
+    /// 只记录第一次（冷启动后首个余额可见的时间）
+    pub fn record_first_balance(&mut self, since_navigation_ms: f64) {
+        if self.time_to_first_balance.is_none() {
+            self.time_to_first_balance = Some(since_navigation_ms);
+        }
+    }
+
+    pub fn record_cache(&mut self, outcome: CacheOutcome) {
+        match outcome {
+            CacheOutcome::Hit => self.cache_hits += 1,
+            CacheOutcome::StaleHit => self.cache_stale_hits += 1,
+            CacheOutcome::Miss => self.cache_misses += 1,
+        }
+    }
+
+    /// 记录一帧的间隔；超过帧预算的部分按整帧计为掉帧
+    pub fn record_frame(&mut self, delta_ms: f64) {
+        if !delta_ms.is_finite() || delta_ms <= 0.0 || delta_ms > MAX_FRAME_DELTA_MS {
+            return;
+        }
+        self.frames_sampled += 1;
+        let missed = (delta_ms / FRAME_BUDGET_MS).round() as u64;
+        self.dropped_frames += missed.saturating_sub(1);
+    }
+
+    pub fn record_gas_limit_fallback(&mut self) {
+        self.gas_limit_fallbacks += 1;
+    }
+
+    pub fn record_token_metadata_correction(&mut self) {
+        self.token_metadata_corrections += 1;
+    }
+
+    /// 缓存命中率（过期命中也计为命中），无样本时为 None
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let hits = self.cache_hits + self.cache_stale_hits;
+        let total = hits + self.cache_misses;
+        (total > 0).then(|| hits as f64 / total as f64 * 100.0)
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let mut endpoints: Vec<EndpointLatency> = self
+            .api_latency
+            .iter()
+            .map(|(endpoint, samples)| EndpointLatency {
+                endpoint: endpoint.clone(),
+                count: samples.len(),
+                p50_ms: percentile(samples, 50.0),
+                p95_ms: percentile(samples, 95.0),
+                p99_ms: percentile(samples, 99.0),
+            })
+            .collect();
+        endpoints.sort_by(|a, b| b.p95_ms.total_cmp(&a.p95_ms));
+
+        let has_routes = !self.route_transitions.is_empty();
+        MetricsSnapshot {
+            route_transition_p50_ms: has_routes.then(|| percentile(&self.route_transitions, 50.0)),
+            route_transition_p95_ms: has_routes.then(|| percentile(&self.route_transitions, 95.0)),
+            time_to_first_balance_ms: self.time_to_first_balance,
+            endpoints,
+            cache_hit_rate: self.cache_hit_rate(),
+            js_heap_mb: None,
+            wasm_memory_mb: None,
+            frames_sampled: self.frames_sampled,
+            dropped_frames: self.dropped_frames,
+            gas_limit_fallbacks: self.gas_limit_fallbacks,
+            token_metadata_corrections: self.token_metadata_corrections,
+        }
+    }
+}
+
+thread_local! {
+    static REGISTRY: RefCell<MetricsRegistry> = RefCell::new(MetricsRegistry::default());
+    /// 帧采样器只启动一次
+    static FRAME_SAMPLER_STARTED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn with_registry(f: impl FnOnce(&mut MetricsRegistry)) {
+    REGISTRY.with(|r| f(&mut r.borrow_mut()));
+}
+
+/// 记录一次 API 请求耗时（由 `ApiClient` 调用）
+pub fn record_api_latency(path: &str, duration_ms: f64) {
+    with_registry(|r| r.record_api_latency(path, duration_ms));
+}
+
+/// 记录一次路由切换耗时
+pub fn record_route_transition(duration_ms: f64) {
+    with_registry(|r| r.record_route_transition(duration_ms));
+}
+
+/// 记录首个余额可见时间（相对页面导航开始）
+pub fn record_first_balance() {
+    let since_navigation = perf_now();
+    with_registry(|r| r.record_first_balance(since_navigation));
+}
+
+/// 记录一次请求缓存查询结果（由 `SmartRequestContext` 调用）
+pub fn record_cache(outcome: CacheOutcome) {
+    with_registry(|r| r.record_cache(outcome));
+}
+
+/// 记录一帧间隔（由性能浮层的帧采样器调用）
+pub fn record_frame(delta_ms: f64) {
+    with_registry(|r| r.record_frame(delta_ms));
+}
+
+/// 记录一次 Gas Limit 估算降级（由 `GasLimitService` 调用）
+pub fn record_gas_limit_fallback() {
+    with_registry(|r| r.record_gas_limit_fallback());
+}
+
+/// 记录一次代币元数据校正（由 `token_metadata` 链上校验调用）
+pub fn record_token_metadata_correction() {
+    with_registry(|r| r.record_token_metadata_correction());
+}
+
+/// 当前指标快照（包含实时读取的内存占用）
+pub fn snapshot() -> MetricsSnapshot {
+    let mut snapshot = REGISTRY.with(|r| r.borrow().snapshot());
+    snapshot.js_heap_mb = js_heap_mb();
+    snapshot.wasm_memory_mb = wasm_memory_mb();
+    snapshot
+}
+
+/// 高精度时间（performance.now，毫秒）；不可用时退化为 Date.now
+pub fn perf_now() -> f64 {
+    web_sys::window()
+        .and_then(|w| Reflect::get(&w, &JsValue::from_str("performance")).ok())
+        .filter(|p| !p.is_undefined())
+        .and_then(|p| {
+            let now_fn = Reflect::get(&p, &JsValue::from_str("now")).ok()?;
+            js_sys::Function::from(now_fn).call0(&p).ok()?.as_f64()
+        })
+        .unwrap_or_else(js_sys::Date::now)
+}
+
+/// JS 堆内存（MB）：performance.memory 仅 Chromium 系浏览器提供
+fn js_heap_mb() -> Option<f64> {
+    let window = web_sys::window()?;
+    let performance = Reflect::get(&window, &JsValue::from_str("performance")).ok()?;
+    let memory = Reflect::get(&performance, &JsValue::from_str("memory")).ok()?;
+    if memory.is_undefined() || memory.is_null() {
+        return None;
+    }
+    let used = Reflect::get(&memory, &JsValue::from_str("usedJSHeapSize"))
+        .ok()?
+        .as_f64()?;
+    Some(used / (1024.0 * 1024.0))
+}
+
+/// WASM 线性内存大小（MB）
+fn wasm_memory_mb() -> Option<f64> {
+    let memory = wasm_bindgen::memory();
+    let buffer = Reflect::get(&memory, &JsValue::from_str("buffer")).ok()?;
+    let bytes = Reflect::get(&buffer, &JsValue::from_str("byteLength"))
+        .ok()?
+        .as_f64()?;
+    Some(bytes / (1024.0 * 1024.0))
+}
+
+/// 等待下一帧绘制（requestAnimationFrame），不可用时退化为 0ms 定时器
+pub async fn next_frame() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        if window.request_animation_frame(&resolve).is_err() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// 启动 requestAnimationFrame 帧采样器，按帧间隔估算掉帧（重复调用无副作用）
+pub fn start_frame_sampler() {
+    if FRAME_SAMPLER_STARTED.with(|started| started.replace(true)) {
+        return;
+    }
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+
+    // 闭包需要在回调内重新注册自己，因此放在共享槽位中
+    let slot: FrameCallbackSlot = Rc::new(RefCell::new(None));
+    let slot_inner = slot.clone();
+    let last = Rc::new(Cell::new(0.0_f64));
+    let window_inner = window.clone();
+
+    *slot.borrow_mut() = Some(Closure::wrap(Box::new(move |timestamp: f64| {
+        let previous = last.replace(timestamp);
+        if previous > 0.0 {
+            record_frame(timestamp - previous);
+        }
+        if let Some(callback) = slot_inner.borrow().as_ref() {
+            let _ = window_inner.request_animation_frame(callback.as_ref().unchecked_ref());
+        }
+    }) as Box<dyn FnMut(f64)>));
+
+    if let Some(callback) = slot.borrow().as_ref() {
+        let _ = window.request_animation_frame(callback.as_ref().unchecked_ref());
+    };
+}
+
+/// 是否请求显示性能浮层：`?perf=1` 开启、`?perf=0` 关闭，结果记入偏好存储
+pub fn overlay_requested() -> bool {
+    let search = web_sys::window()
+        .and_then(|w| Reflect::get(&w, &JsValue::from_str("location")).ok())
+        .and_then(|loc| Reflect::get(&loc, &JsValue::from_str("search")).ok())
+        .and_then(|v| v.as_string())
+        .unwrap_or_default();
+
+    match perf_query_param(&search) {
+        Some(enabled) => {
+            preferences::update(|stored| stored.perf_overlay = enabled);
+            enabled
+        }
+        None => preferences::load().perf_overlay,
+    }
+}
+
+/// 解析查询串中的 `perf` 参数
+fn perf_query_param(search: &str) -> Option<bool> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("perf="))
+        .map(|value| matches!(value, "1" | "true" | "on"))
+}
+
+/// 上报一批匿名聚合指标（调用方负责检查用户同意）
+pub async fn report_snapshot(api: &ApiClient) -> Result<(), ApiError> {
+    let snapshot = snapshot();
+    if snapshot.endpoints.is_empty() && snapshot.frames_sampled == 0 {
+        return Ok(());
+    }
+    let _: serde_json::Value = api.post(misc::FRONTEND_METRICS, &snapshot).await?;
+    Ok(())
+}
+
+/// 把请求路径归一化为端点模板：去掉查询串，动态段（ID、地址、哈希）替换为 `:id`
+pub fn normalize_endpoint(path: &str) -> String {
+    let without_origin = match path.find("://") {
+        Some(idx) => {
+            let rest = &path[idx + 3..];
+            rest.find('/').map(|i| &rest[i..]).unwrap_or("/")
+        }
+        None => path,
+    };
+    let without_query = without_origin.split('?').next().unwrap_or("");
+
+    without_query
+        .split('/')
+        .map(|segment| {
+            if is_dynamic_segment(segment) {
+                ":id"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_dynamic_segment(segment: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+    let has_digit = segment.chars().any(|c| c.is_ascii_digit());
+    segment.starts_with("0x")
+        || segment.contains('%')
+        || segment.contains(':')
+        || (has_digit && segment.len() >= 8)
+        || segment.chars().all(|c| c.is_ascii_digit())
+}
+
+/// 线性插值分位数（`p` 取 0-100），空样本返回 0
+pub fn percentile(samples: &[f64], p: f64) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = (p.clamp(0.0, 100.0) / 100.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+/// 用户是否已同意上报匿名性能数据
+pub fn has_reporting_consent() -> bool {
+    preferences::load().metrics_consent
+}
+
+/// 设置上报同意状态
+pub fn set_reporting_consent(consent: bool) {
+    preferences::update(|stored| stored.metrics_consent = consent);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_endpoint_strips_query_and_dynamic_segments() {
+        assert_eq!(
+            normalize_endpoint("/api/v1/wallets/0xabc123/balance?chain_id=1"),
+            "/api/v1/wallets/:id/balance"
+        );
+        assert_eq!(
+            normalize_endpoint("http://localhost:8088/api/v1/prices?symbols=ETH"),
+            "/api/v1/prices"
+        );
+        assert_eq!(
+            normalize_endpoint("/api/v1/limit-orders/550e8400-e29b-41d4/cancel"),
+            "/api/v1/limit-orders/:id/cancel"
+        );
+        assert_eq!(
+            normalize_endpoint("/api/v1/gas/estimate-all"),
+            "/api/v1/gas/estimate-all"
+        );
+    }
+
+    #[test]
+    fn percentile_interpolates() {
+        let samples = [10.0, 20.0, 30.0, 40.0, 50.0];
+        assert_eq!(percentile(&samples, 50.0), 30.0);
+        assert_eq!(percentile(&samples, 0.0), 10.0);
+        assert_eq!(percentile(&samples, 100.0), 50.0);
+        assert_eq!(percentile(&[], 95.0), 0.0);
+    }
+
+    #[test]
+    fn registry_aggregates_cache_and_frames() {
+        let mut registry = MetricsRegistry::default();
+        registry.record_cache(CacheOutcome::Hit);
+        registry.record_cache(CacheOutcome::StaleHit);
+        registry.record_cache(CacheOutcome::Miss);
+        registry.record_cache(CacheOutcome::Miss);
+        assert_eq!(registry.cache_hit_rate(), Some(50.0));
+
+        registry.record_frame(16.7);
+        registry.record_frame(50.0); // 约3帧，掉2帧
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.frames_sampled, 2);
+        assert_eq!(snapshot.dropped_frames, 2);
+    }
+
+    #[test]
+    fn perf_query_param_parses_toggle() {
+        assert_eq!(perf_query_param("?perf=1"), Some(true));
+        assert_eq!(perf_query_param("?tab=swap&perf=0"), Some(false));
+        assert_eq!(perf_query_param("?tab=swap"), None);
+        assert_eq!(perf_query_param(""), None);
+    }
+
+    #[test]
+    fn first_balance_is_recorded_once() {
+        let mut registry = MetricsRegistry::default();
+        registry.record_first_balance(1200.0);
+        registry.record_first_balance(3000.0);
+        assert_eq!(registry.snapshot().time_to_first_balance_ms, Some(1200.0));
+    }
+}
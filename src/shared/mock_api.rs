@@ -0,0 +1,232 @@
+//! Mock API - 测试用请求拦截层
+//!
+//! 安装到 [`ApiClient`](crate::shared::api::ApiClient) 后不再访问网络：
+//! - 记录每个请求（方法、路径、请求体），供测试断言请求顺序与参数
+//! - 优先返回测试设置的响应（按路径前缀匹配，后设置的优先）
+//! - 其余请求使用演示模式固定数据；没有固定数据的请求一律返回错误
+
+use crate::shared::demo::{self, DemoResponse};
+use crate::shared::error::ApiError;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// 一次被拦截的请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// 含查询参数的路径
+    pub path: String,
+    pub body: Option<Value>,
+}
+
+impl RecordedRequest {
+    pub fn matches(&self, method: &str, path_prefix: &str) -> bool {
+        self.method.eq_ignore_ascii_case(method) && self.path.starts_with(path_prefix)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Reply {
+    /// 业务数据（自动包装为 `{code, message, data}`）
+    Data(Value),
+    Error(String),
+}
+
+#[derive(Debug)]
+struct Route {
+    method: String,
+    path_prefix: String,
+    reply: Reply,
+    /// 剩余可用次数（None 表示不限）
+    remaining: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    requests: Vec<RecordedRequest>,
+    routes: Vec<Route>,
+}
+
+/// 测试用模拟后端（克隆后共享同一份记录）
+#[derive(Debug, Clone, Default)]
+pub struct MockApi {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn add_route(&self, method: &str, path_prefix: &str, reply: Reply, remaining: Option<usize>) {
+        self.lock().routes.push(Route {
+            method: method.to_uppercase(),
+            path_prefix: path_prefix.to_string(),
+            reply,
+            remaining,
+        });
+    }
+
+    /// 匹配的请求返回 `data`
+    pub fn respond(&self, method: &str, path_prefix: &str, data: Value) {
+        self.add_route(method, path_prefix, Reply::Data(data), None);
+    }
+
+    /// 匹配的请求返回错误（如“报价已过期”）
+    pub fn fail(&self, method: &str, path_prefix: &str, message: &str) {
+        self.add_route(method, path_prefix, Reply::Error(message.to_string()), None);
+    }
+
+    /// 仅下一次匹配的请求返回错误，之后恢复原有响应
+    pub fn fail_once(&self, method: &str, path_prefix: &str, message: &str) {
+        self.add_route(
+            method,
+            path_prefix,
+            Reply::Error(message.to_string()),
+            Some(1),
+        );
+    }
+
+    /// 已记录的全部请求
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// 匹配方法与路径前缀的请求
+    pub fn requests_matching(&self, method: &str, path_prefix: &str) -> Vec<RecordedRequest> {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|r| r.matches(method, path_prefix))
+            .cloned()
+            .collect()
+    }
+
+    /// 最后一个匹配的请求
+    pub fn last_request(&self, method: &str, path_prefix: &str) -> Option<RecordedRequest> {
+        self.requests_matching(method, path_prefix).pop()
+    }
+
+    /// 清空请求记录（保留已设置的响应）
+    pub fn clear_requests(&self) {
+        self.lock().requests.clear();
+    }
+
+    /// 请求记录中是否按顺序出现了这些请求（中间可以夹杂其他请求），
+    /// 不满足时返回第一个未找到的请求
+    pub fn check_sequence(&self, expected: &[(&str, &str)]) -> Result<(), String> {
+        let requests = self.requests();
+        let mut cursor = requests.iter();
+        for (method, prefix) in expected {
+            if !cursor.any(|r| r.matches(method, prefix)) {
+                let seen: Vec<String> = requests
+                    .iter()
+                    .map(|r| format!("{} {}", r.method, r.path))
+                    .collect();
+                return Err(format!(
+                    "未按顺序找到请求 {} {}，实际请求：\n{}",
+                    method,
+                    prefix,
+                    seen.join("\n")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 处理一个请求：记录后依次尝试测试设置的响应、演示数据
+    pub fn handle(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&Value>,
+        now_ms: u64,
+    ) -> Result<Value, ApiError> {
+        let method = method.to_uppercase();
+        let mut state = self.lock();
+        state.requests.push(RecordedRequest {
+            method: method.clone(),
+            path: path.to_string(),
+            body: body.cloned(),
+        });
+
+        let route = state.routes.iter_mut().rev().find(|r| {
+            r.method == method && path.starts_with(&r.path_prefix) && r.remaining != Some(0)
+        });
+        if let Some(route) = route {
+            if let Some(remaining) = route.remaining.as_mut() {
+                *remaining -= 1;
+            }
+            return match &route.reply {
+                Reply::Data(data) => Ok(json!({ "code": 0, "message": "success", "data": data })),
+                Reply::Error(message) => Err(ApiError::ResponseError(message.clone())),
+            };
+        }
+        drop(state);
+
+        match demo::respond(&method, path, body, now_ms) {
+            DemoResponse::Fixture(value) => Ok(value),
+            _ => Err(ApiError::RequestFailed(format!(
+                "未设置模拟响应: {} {}",
+                method, path
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: u64 = 1_700_000_000_000;
+
+    #[test]
+    fn overrides_take_priority_over_fixtures_and_are_recorded() {
+        let mock = MockApi::new();
+        let quote = "/api/v1/swap/quote?from=ETH&to=USDT&amount=0.1&network=ethereum";
+        assert!(mock.handle("GET", quote, None, T0).is_ok());
+
+        mock.fail_once("POST", "/api/v1/swap/execute", "quote expired");
+        let body = json!({ "from_token": "ETH", "to_token": "USDT", "amount": "0.1" });
+        let err = mock
+            .handle("post", "/api/v1/swap/execute", Some(&body), T0)
+            .unwrap_err();
+        assert!(err.to_string().contains("quote expired"));
+        // 一次性错误用完后恢复演示数据
+        assert!(mock
+            .handle("POST", "/api/v1/swap/execute", Some(&body), T0)
+            .is_ok());
+
+        mock.respond("GET", "/api/v1/tokens/list", json!([]));
+        let list = mock
+            .handle("GET", "/api/v1/tokens/list?chain=ethereum", None, T0)
+            .unwrap();
+        assert_eq!(list["data"], json!([]));
+        // 没有演示数据的请求不会访问网络
+        assert!(mock.handle("GET", "/api/v1/auth/me", None, T0).is_err());
+
+        assert_eq!(mock.requests().len(), 5);
+        assert_eq!(
+            mock.last_request("POST", "/api/v1/swap/execute")
+                .and_then(|r| r.body),
+            Some(body)
+        );
+        assert!(mock
+            .check_sequence(&[
+                ("GET", "/api/v1/swap/quote"),
+                ("POST", "/api/v1/swap/execute"),
+                ("GET", "/api/v1/tokens/list"),
+            ])
+            .is_ok());
+        assert!(mock
+            .check_sequence(&[
+                ("GET", "/api/v1/tokens/list"),
+                ("GET", "/api/v1/swap/quote"),
+            ])
+            .is_err());
+    }
+}
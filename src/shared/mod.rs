@@ -6,13 +6,31 @@ pub mod api;
 pub mod api_endpoints; // ✅ 企业级标准：统一 API 端点定义
 pub mod auth_handler; // ✅ 统一的401认证错误处理
 pub mod cache;
+pub mod demo;
 pub mod design_tokens;
 pub mod error;
 pub mod feature_flags;
+pub mod freshness;
+pub mod kill_switch;
+pub mod list_query;
+pub mod logging;
+pub mod markdown;
+pub mod metrics;
+#[cfg(test)]
+pub mod mock_api;
+pub mod navigation_guard;
+pub mod paginated_query;
 pub mod request;
+pub mod scheduler;
 pub mod security;
 pub mod state;
+pub mod state_updates;
 pub mod storage;
+pub mod submission_guard;
+#[cfg(all(test, target_arch = "wasm32"))]
+pub mod test_harness;
+pub mod time;
 pub mod ui_error;
 pub mod validation;
+pub mod web_crypto;
 pub mod websocket;
@@ -0,0 +1,172 @@
+//! Navigation Guard - 关键操作进行中的离开确认
+//!
+//! 签名广播、支付授权、导入文件解析等操作进行中离开页面，界面状态与本地记录可能不一致。
+//! 操作开始时调用 [`hold`] 登记原因，返回的 [`BusyGuard`] 在操作结束（成功、失败或任务被取消）
+//! 时自动释放：
+//! - 路由切换（含浏览器后退）时若仍有登记，弹出确认框，取消则留在当前页（见 `router`）
+//! - 关闭或刷新标签页时由 `beforeunload` 提示
+//! - 登记超过 [`WATCHDOG_MS`] 视为已失效，不会永久阻止离开
+//!
+//! 操作成功后的跳转（如兑换完成后回到资产页）应先释放再跳转。
+
+use crate::shared::time;
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// 单次登记的最长有效时间（毫秒）
+pub const WATCHDOG_MS: f64 = 3.0 * 60.0 * 1000.0;
+
+/// 不能随意离开的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyReason {
+    /// 交易签名、广播中
+    Signing,
+    /// 支付授权中
+    PaymentAuthorizing,
+    /// 导入文件解析、应用中
+    FileImport,
+}
+
+impl BusyReason {
+    /// 离开确认框的提示
+    pub fn confirm_message(self) -> &'static str {
+        match self {
+            BusyReason::Signing => {
+                "交易正在提交中，现在离开不会取消这笔交易，但页面将无法显示提交结果。确定离开吗？"
+            }
+            BusyReason::PaymentAuthorizing => {
+                "支付正在授权中，现在离开不会取消支付，但页面将无法显示支付结果。确定离开吗？"
+            }
+            BusyReason::FileImport => "文件正在导入中，现在离开将中断导入。确定离开吗？",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    id: u64,
+    reason: BusyReason,
+    started_ms: f64,
+}
+
+/// 当前登记中最早的有效原因（超过看门狗时长的登记不计）
+fn active_reason(entries: &[Entry], now_ms: f64) -> Option<BusyReason> {
+    entries
+        .iter()
+        .filter(|e| now_ms - e.started_ms < WATCHDOG_MS)
+        .min_by(|a, b| a.started_ms.total_cmp(&b.started_ms))
+        .map(|e| e.reason)
+}
+
+thread_local! {
+    static ENTRIES: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+    static UNLOAD_INSTALLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// 操作进行中的登记，丢弃时释放
+#[must_use = "丢弃后立即释放，应在操作结束前一直持有"]
+#[derive(Debug)]
+pub struct BusyGuard {
+    id: u64,
+}
+
+impl BusyGuard {
+    /// 提前释放（如操作成功、准备跳转时）
+    pub fn release(self) {}
+}
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        ENTRIES.with(|e| e.borrow_mut().retain(|entry| entry.id != self.id));
+    }
+}
+
+/// 登记一项进行中的关键操作
+pub fn hold(reason: BusyReason) -> BusyGuard {
+    install_unload_prompt();
+    let id = NEXT_ID.with(|n| n.replace(n.get() + 1));
+    let now = time::now_ms();
+    ENTRIES.with(|e| {
+        let mut entries = e.borrow_mut();
+        // 顺带清理看门狗超时的登记
+        entries.retain(|entry| now - entry.started_ms < WATCHDOG_MS);
+        entries.push(Entry {
+            id,
+            reason,
+            started_ms: now,
+        });
+    });
+    BusyGuard { id }
+}
+
+/// 当前是否有进行中的关键操作
+pub fn busy_reason() -> Option<BusyReason> {
+    ENTRIES.with(|e| active_reason(&e.borrow(), time::now_ms()))
+}
+
+/// 路由切换前调用：没有进行中的操作，或用户确认离开时返回 true
+pub fn confirm_leave() -> bool {
+    let Some(reason) = busy_reason() else {
+        return true;
+    };
+    web_sys::window()
+        .and_then(|w| w.confirm_with_message(reason.confirm_message()).ok())
+        .unwrap_or(true)
+}
+
+/// 关闭或刷新标签页时提示（首次登记时安装）
+fn install_unload_prompt() {
+    if UNLOAD_INSTALLED.with(|i| i.replace(true)) {
+        return;
+    }
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let on_unload = Closure::wrap(Box::new(move |e: web_sys::Event| {
+        if let Some(reason) = busy_reason() {
+            // 浏览器显示自带的提示文案，returnValue 仅用于触发提示
+            e.prevent_default();
+            let _ = js_sys::Reflect::set(
+                &e,
+                &JsValue::from_str("returnValue"),
+                &JsValue::from_str(reason.confirm_message()),
+            );
+        }
+    }) as Box<dyn FnMut(web_sys::Event)>);
+    let _ = window.add_event_listener_with_callback(
+        "beforeunload",
+        on_unload.as_ref().unchecked_ref::<js_sys::Function>(),
+    );
+    on_unload.forget();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u64, reason: BusyReason, started_ms: f64) -> Entry {
+        Entry {
+            id,
+            reason,
+            started_ms,
+        }
+    }
+
+    #[test]
+    fn oldest_live_entry_wins_and_watchdog_expires_entries() {
+        let entries = [
+            entry(1, BusyReason::FileImport, 1_000.0),
+            entry(2, BusyReason::Signing, 500.0),
+        ];
+        assert_eq!(active_reason(&entries, 2_000.0), Some(BusyReason::Signing));
+        // 较早的登记超时后由仍有效的登记决定
+        assert_eq!(
+            active_reason(&entries, 500.0 + WATCHDOG_MS),
+            Some(BusyReason::FileImport)
+        );
+        assert_eq!(active_reason(&entries, 1_000.0 + WATCHDOG_MS), None);
+        assert_eq!(active_reason(&[], 0.0), None);
+    }
+}
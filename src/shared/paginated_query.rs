@@ -0,0 +1,375 @@
+//! Paginated Query - 分页列表的加载状态
+//!
+//! 法币订单、交易历史、限价单等分页列表共用：持有查询条件（含页码），条件变化时重新加载，
+//! 结果写入统一的 [`PaginatedState`] 供列表组件渲染。
+//! - 筛选条件变化时防抖后再请求，连续变化时只采用最后一次请求的结果
+//! - 同一查询条件的结果按页缓存，翻回已加载的页面不再请求
+//! - 取消订单等操作可先从列表中移除（乐观更新），页面被删空时自动回到上一页
+//! - 当前页超出总页数（如其他设备删除了记录）时跳到最后一页
+
+use crate::services::cache::MemoryCache;
+use crate::shared::list_query::ListQueryState;
+use dioxus::prelude::*;
+use futures::future::LocalBoxFuture;
+use gloo_timers::future::TimeoutFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// 筛选条件变化后等待的时间（毫秒），连续点击筛选只发出一次请求
+pub const REFETCH_DEBOUNCE_MS: u32 = 250;
+
+/// 带页码的查询条件
+pub trait PagedQuery: Clone + PartialEq + 'static {
+    fn page(&self) -> u32;
+    fn set_page(&mut self, page: u32);
+    /// 缓存键（包含全部查询条件）
+    fn cache_key(&self, prefix: &str) -> String;
+}
+
+impl PagedQuery for ListQueryState {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page.max(1);
+    }
+
+    fn cache_key(&self, prefix: &str) -> String {
+        ListQueryState::cache_key(self, prefix)
+    }
+}
+
+/// 筛选条件（页码以外的部分）是否变化：变化时防抖，单纯翻页立即请求
+fn filters_changed<Q: PagedQuery>(previous: Option<&Q>, next: &Q) -> bool {
+    previous.is_some_and(|previous| {
+        let mut previous = previous.clone();
+        previous.set_page(next.page());
+        previous != *next
+    })
+}
+
+/// 一页查询结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total_pages: u32,
+}
+
+/// 查询一页数据的请求
+pub type PageRequest<T> = LocalBoxFuture<'static, Result<Page<T>, String>>;
+
+/// 分页结果的缓存设置
+#[derive(Debug, Clone, Copy)]
+pub struct PageCache {
+    /// 缓存键前缀（如 "limit_orders"）
+    pub prefix: &'static str,
+    /// 缓存时间，None 表示不缓存（状态变化较快的列表）
+    pub ttl: Option<Duration>,
+}
+
+/// 列表组件渲染的分页状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaginatedState<T> {
+    pub items: Vec<T>,
+    /// 当前显示（或正在加载）的页码
+    pub page: u32,
+    pub total_pages: u32,
+    pub loading: bool,
+    pub error: Option<String>,
+    /// 最近一次请求的序号，较早请求的结果不再写入
+    generation: u64,
+}
+
+impl<T> Default for PaginatedState<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            page: 1,
+            total_pages: 1,
+            loading: false,
+            error: None,
+            generation: 0,
+        }
+    }
+}
+
+impl<T> PaginatedState<T> {
+    pub fn has_prev(&self) -> bool {
+        self.page > 1
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
+
+    /// 开始加载 `page`，返回本次请求的序号
+    fn begin(&mut self, page: u32) -> u64 {
+        self.generation += 1;
+        self.page = page;
+        self.loading = true;
+        self.error = None;
+        self.generation
+    }
+
+    /// 写入请求结果（过时的请求直接忽略）；当前页超出范围或为空时返回应跳转的页码
+    fn finish(&mut self, generation: u64, result: Result<Page<T>, String>) -> Option<u32> {
+        if generation != self.generation {
+            return None;
+        }
+        match result {
+            Ok(page) => {
+                let total_pages = page.total_pages.max(1);
+                self.total_pages = total_pages;
+                let target = if self.page > total_pages {
+                    Some(total_pages)
+                } else if page.items.is_empty() && self.page > 1 {
+                    Some(self.page - 1)
+                } else {
+                    None
+                };
+                if target.is_none() {
+                    self.items = page.items;
+                    self.loading = false;
+                }
+                target
+            }
+            Err(e) => {
+                self.items.clear();
+                self.error = Some(e);
+                self.loading = false;
+                None
+            }
+        }
+    }
+
+    /// 乐观移除符合条件的行；本页被删空时返回需要重新加载的页码
+    fn remove_where(&mut self, mut remove: impl FnMut(&T) -> bool) -> Option<u32> {
+        let before = self.items.len();
+        self.items.retain(|item| !remove(item));
+        if self.items.len() == before || !self.items.is_empty() {
+            return None;
+        }
+        if self.page > 1 {
+            Some(self.page - 1)
+        } else if self.total_pages > 1 {
+            // 第一页被删空但后面还有记录：重新加载第一页
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
+/// 分页列表：查询条件、加载状态与操作
+pub struct PaginatedQuery<T: 'static, Q: 'static> {
+    /// 查询条件（筛选、排序、页码）
+    pub query: Signal<Q>,
+    pub state: Signal<PaginatedState<T>>,
+    cache: Signal<MemoryCache>,
+    cache_prefix: &'static str,
+    reload: Signal<u32>,
+}
+
+impl<T, Q> Clone for PaginatedQuery<T, Q> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Q> Copy for PaginatedQuery<T, Q> {}
+
+impl<T, Q> PaginatedQuery<T, Q>
+where
+    T: Clone + Serialize + DeserializeOwned + 'static,
+    Q: PagedQuery,
+{
+    /// 跳转页码（限制在 1..=总页数）
+    pub fn go_to(&mut self, page: u32) {
+        let total_pages = self.state.peek().total_pages;
+        let page = page.clamp(1, total_pages.max(1));
+        if self.query.peek().page() != page {
+            self.query.write().set_page(page);
+        }
+    }
+
+    /// 清除缓存并重新加载当前页（重试、刷新、操作失败后回滚乐观更新）
+    pub fn reload(&mut self) {
+        self.invalidate();
+        *self.reload.write() += 1;
+    }
+
+    /// 清除缓存（记录被修改后，其他页面的内容可能已变化）
+    pub fn invalidate(&mut self) {
+        self.cache.write().remove_by_prefix(self.cache_prefix);
+    }
+
+    /// 乐观移除符合条件的行（如已取消的订单），本页被删空时加载上一页
+    pub fn remove_where(&mut self, remove: impl FnMut(&T) -> bool) {
+        let target = self.state.write().remove_where(remove);
+        self.invalidate();
+        match target {
+            Some(page) if page == self.query.peek().page() => *self.reload.write() += 1,
+            Some(page) => self.go_to(page),
+            None => {}
+        }
+    }
+}
+
+/// 分页列表查询
+///
+/// `fetch` 在查询条件变化时调用（同步部分中读取的信号变化时也会重新调用），
+/// 返回 None 表示本次不查询（如列表当前不可见）。
+pub fn use_paginated_query<T, Q>(
+    cache: PageCache,
+    query: Signal<Q>,
+    mut fetch: impl FnMut(Q) -> Option<PageRequest<T>> + 'static,
+) -> PaginatedQuery<T, Q>
+where
+    T: Clone + Serialize + DeserializeOwned + 'static,
+    Q: PagedQuery,
+{
+    let mut state = use_signal(PaginatedState::<T>::default);
+    let page_cache = use_signal(|| MemoryCache::new(cache.ttl.unwrap_or_default()));
+    let reload = use_signal(|| 0u32);
+    let last_query = use_hook(|| Rc::new(RefCell::new(Option::<Q>::None)));
+    let mut list = PaginatedQuery {
+        query,
+        state,
+        cache: page_cache,
+        cache_prefix: cache.prefix,
+        reload,
+    };
+
+    use_effect(move || {
+        let _ = reload();
+        let params = query.read().clone();
+        let Some(request) = fetch(params.clone()) else {
+            return;
+        };
+        let debounce = filters_changed(last_query.borrow().as_ref(), &params);
+        *last_query.borrow_mut() = Some(params.clone());
+
+        let key = params.cache_key(cache.prefix);
+        let generation = state.write().begin(params.page());
+        if cache.ttl.is_some() {
+            if let Some(cached) = page_cache.peek().get::<Page<T>>(&key) {
+                if let Some(page) = state.write().finish(generation, Ok(cached)) {
+                    list.go_to(page);
+                }
+                return;
+            }
+        }
+
+        spawn(async move {
+            let mut list = list;
+            if debounce {
+                TimeoutFuture::new(REFETCH_DEBOUNCE_MS).await;
+                if state.peek().generation != generation {
+                    return;
+                }
+            }
+            let result = request.await;
+            if let (Ok(page), Some(ttl)) = (&result, cache.ttl) {
+                let mut page_cache = page_cache;
+                page_cache.write().set(key, page.clone(), Some(ttl));
+            }
+            if let Some(page) = state.write().finish(generation, result) {
+                list.go_to(page);
+            }
+        });
+    });
+
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(items: &[u32], total_pages: u32) -> Result<Page<u32>, String> {
+        Ok(Page {
+            items: items.to_vec(),
+            total_pages,
+        })
+    }
+
+    #[test]
+    fn out_of_range_and_empty_pages_step_back() {
+        let mut state = PaginatedState::<u32>::default();
+        let generation = state.begin(5);
+        // 其他设备删除记录后只剩 3 页
+        assert_eq!(state.finish(generation, page(&[], 3)), Some(3));
+        assert!(state.loading);
+
+        let generation = state.begin(3);
+        assert_eq!(state.finish(generation, page(&[7, 8], 3)), None);
+        assert_eq!(state.items, [7, 8]);
+        assert!(!state.has_next() && state.has_prev());
+
+        // 总页数未变但本页已为空
+        let generation = state.begin(3);
+        assert_eq!(state.finish(generation, page(&[], 3)), Some(2));
+
+        // 第一页为空是正常的空列表
+        let generation = state.begin(1);
+        assert_eq!(state.finish(generation, page(&[], 0)), None);
+        assert_eq!(state.total_pages, 1);
+        assert!(!state.loading);
+    }
+
+    #[test]
+    fn removing_last_rows_reloads_previous_page() {
+        let mut state = PaginatedState::<u32>::default();
+        let generation = state.begin(2);
+        state.finish(generation, page(&[1, 2], 2));
+
+        assert_eq!(state.remove_where(|&id| id == 9), None);
+        assert_eq!(state.remove_where(|&id| id == 1), None);
+        assert_eq!(state.items, [2]);
+        assert_eq!(state.remove_where(|&id| id == 2), Some(1));
+
+        let mut state = PaginatedState::<u32>::default();
+        let generation = state.begin(1);
+        state.finish(generation, page(&[1], 3));
+        assert_eq!(state.remove_where(|&id| id == 1), Some(1));
+
+        let generation = state.begin(1);
+        state.finish(generation, page(&[1], 1));
+        assert_eq!(state.remove_where(|&id| id == 1), None);
+        assert!(state.items.is_empty());
+    }
+
+    #[test]
+    fn only_latest_request_is_applied() {
+        let mut state = PaginatedState::<u32>::default();
+        let first = state.begin(1);
+        let second = state.begin(1);
+        // 先发出的请求后返回：丢弃
+        assert_eq!(state.finish(second, page(&[2], 1)), None);
+        assert_eq!(state.finish(first, page(&[1], 4)), None);
+        assert_eq!(state.items, [2]);
+        assert_eq!(state.total_pages, 1);
+        assert_eq!(state.finish(first, Err("超时".to_string())), None);
+        assert!(state.error.is_none());
+
+        let third = state.begin(1);
+        state.finish(third, Err("网络错误".to_string()));
+        assert!(state.items.is_empty());
+        assert_eq!(state.error.as_deref(), Some("网络错误"));
+    }
+
+    #[test]
+    fn page_changes_skip_debounce() {
+        let mut query = ListQueryState::default();
+        assert!(!filters_changed(None, &query));
+        let previous = query.clone();
+        query.set_page(3);
+        assert!(!filters_changed(Some(&previous), &query));
+        query.set_status(Some("filled".to_string()));
+        assert!(filters_changed(Some(&previous), &query));
+    }
+}
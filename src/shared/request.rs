@@ -1,3 +1,4 @@
+use crate::shared::time;
 use std::collections::{HashMap, HashSet};
 use std::future::Future;
 
@@ -6,8 +7,9 @@ use gloo_timers::future::TimeoutFuture;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use crate::shared::cache::{self, CacheEntry};
+use crate::shared::cache::CacheEntry;
 use crate::shared::error::ApiError;
+use crate::shared::metrics::{self, CacheOutcome};
 use crate::shared::state::AppState;
 
 #[derive(Clone, Copy, Debug)]
@@ -81,13 +83,14 @@ impl SmartRequestContext {
         F: FnOnce() -> Fut + 'static,
         Fut: 'static + Future<Output = Result<Value, ApiError>>,
     {
-        let now = cache::now_secs();
+        let now = time::now_unix();
         let mut stale: Option<CacheEntry> = None;
         let mut fetcher_opt = Some(fetcher);
 
         if policy.is_cache_enabled() {
             if let Some(entry) = self.cache.read().get(key) {
                 if now.saturating_sub(entry.stored_at) <= policy.ttl_secs {
+                    metrics::record_cache(CacheOutcome::Hit);
                     return Self::deserialize(entry.value.clone());
                 }
                 stale = Some(entry.clone());
@@ -101,6 +104,7 @@ impl SmartRequestContext {
                         self.spawn_revalidation(key.to_string(), policy, fetcher_fn);
                     }
                 }
+                metrics::record_cache(CacheOutcome::StaleHit);
                 return Self::deserialize(entry.value);
             }
         }
@@ -110,11 +114,13 @@ impl SmartRequestContext {
 
         if !owns_request {
             if let Some(entry) = stale {
+                metrics::record_cache(CacheOutcome::StaleHit);
                 return Self::deserialize(entry.value);
             }
 
             if policy.dedupe_wait_ms > 0 {
                 if let Some(value) = self.wait_for_refresh(key, policy.dedupe_wait_ms).await {
+                    metrics::record_cache(CacheOutcome::Hit);
                     return Self::deserialize(value);
                 }
             }
@@ -123,6 +129,9 @@ impl SmartRequestContext {
         }
 
         if owns_request {
+            if policy.is_cache_enabled() {
+                metrics::record_cache(CacheOutcome::Miss);
+            }
             let fetcher_fn = fetcher_opt
                 .take()
                 .expect("fetcher already consumed when executing request");
@@ -132,7 +141,7 @@ impl SmartRequestContext {
             match result {
                 Ok(value) => {
                     if policy.is_cache_enabled() {
-                        self.write_cache(key.to_string(), value.clone(), cache::now_secs());
+                        self.write_cache(key.to_string(), value.clone(), time::now_unix());
                     }
                     Self::deserialize(value)
                 }
@@ -156,7 +165,7 @@ impl SmartRequestContext {
             match result {
                 Ok(value) => {
                     if policy.is_cache_enabled() {
-                        ctx.write_cache(key.clone(), value.clone(), cache::now_secs());
+                        ctx.write_cache(key.clone(), value.clone(), time::now_unix());
                     }
                 }
                 Err(err) => {
@@ -0,0 +1,115 @@
+//! Scheduler - 感知标签页可见性的定时调度
+//! 定时任务按策略注册：隐藏时暂停、隐藏时放慢或始终运行。标签页隐藏期间暂停的任务
+//! 在切回前台时只补跑一次（不会把隐藏期间错过的轮次连续补发），避免重复通知
+
+use futures::channel::oneshot;
+use futures::future::select;
+use gloo_timers::future::TimeoutFuture;
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// 隐藏时放慢的倍数
+const HIDDEN_SLOWDOWN: u32 = 6;
+
+/// 定时任务在标签页隐藏时的策略
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TickPolicy {
+    /// 隐藏时暂停，切回前台时补跑一次
+    PauseWhenHidden,
+    /// 隐藏时按放慢后的间隔运行，切回前台时立即补跑一次
+    SlowWhenHidden,
+    /// 始终按间隔运行
+    Always,
+}
+
+thread_local! {
+    static HIDDEN: Cell<bool> = const { Cell::new(false) };
+    static INSTALLED: Cell<bool> = const { Cell::new(false) };
+    static WAITERS: RefCell<Vec<oneshot::Sender<()>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// 标签页当前是否隐藏
+pub fn is_hidden() -> bool {
+    HIDDEN.with(Cell::get)
+}
+
+/// 监听 visibilitychange（应用启动时调用一次）
+pub fn install() {
+    if INSTALLED.with(|i| i.replace(true)) {
+        return;
+    }
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    HIDDEN.with(|h| h.set(document.hidden()));
+
+    let doc = document.clone();
+    let on_visibility = Closure::wrap(Box::new(move || {
+        let hidden = doc.hidden();
+        HIDDEN.with(|h| h.set(hidden));
+        if !hidden {
+            // 唤醒所有等待中的任务，各自补跑一次
+            for waiter in WAITERS.with(|w| std::mem::take(&mut *w.borrow_mut())) {
+                let _ = waiter.send(());
+            }
+        }
+    }) as Box<dyn FnMut()>);
+    let _ = document.add_event_listener_with_callback(
+        "visibilitychange",
+        on_visibility.as_ref().unchecked_ref::<js_sys::Function>(),
+    );
+    on_visibility.forget();
+}
+
+/// 等到标签页切回前台
+async fn until_visible() {
+    if !is_hidden() {
+        return;
+    }
+    let (tx, rx) = oneshot::channel();
+    WAITERS.with(|w| w.borrow_mut().push(tx));
+    let _ = rx.await;
+}
+
+/// 隐藏状态下还需额外等待的时长：None 表示一直等到切回前台
+fn hidden_extra_delay_ms(policy: TickPolicy, interval_ms: u32) -> Option<u32> {
+    match policy {
+        TickPolicy::PauseWhenHidden => None,
+        TickPolicy::SlowWhenHidden => Some(interval_ms.saturating_mul(HIDDEN_SLOWDOWN - 1)),
+        TickPolicy::Always => Some(0),
+    }
+}
+
+/// 等待下一次执行：前台时按间隔；隐藏时按策略暂停或放慢，切回前台时立即返回
+pub async fn next_tick(policy: TickPolicy, interval_ms: u32) {
+    TimeoutFuture::new(interval_ms).await;
+    if !is_hidden() {
+        return;
+    }
+    match hidden_extra_delay_ms(policy, interval_ms) {
+        Some(0) => {}
+        Some(extra) => {
+            let _ = select(Box::pin(until_visible()), TimeoutFuture::new(extra)).await;
+        }
+        None => until_visible().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_delay_follows_policy() {
+        assert_eq!(
+            hidden_extra_delay_ms(TickPolicy::PauseWhenHidden, 30_000),
+            None
+        );
+        assert_eq!(
+            hidden_extra_delay_ms(TickPolicy::SlowWhenHidden, 5_000),
+            Some(25_000)
+        );
+        assert_eq!(hidden_extra_delay_ms(TickPolicy::Always, 5_000), Some(0));
+    }
+}
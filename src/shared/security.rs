@@ -1,6 +1,9 @@
 //! Security utilities for input validation and sanitization
 //! UI 安全工具：输入验证和清理
 
+use crate::services::address_detector::ChainType;
+use crate::services::address_validation::validate_address as validate_chain_address;
+
 /// Sanitize string input to prevent XSS attacks
 /// 清理字符串输入以防止 XSS 攻击
 pub fn sanitize_html(input: &str) -> String {
@@ -20,25 +23,11 @@ pub fn validate_address(address: &str, chain: Option<&str>) -> bool {
         return false;
     }
 
-    // Basic format checks based on chain
-    match chain {
-        Some("ethereum") | Some("polygon") | Some("bsc") => {
-            // Ethereum-style addresses: 0x followed by 40 hex characters
-            address.starts_with("0x")
-                && address.len() == 42
-                && address[2..].chars().all(|c| c.is_ascii_hexdigit())
-        }
-        Some("bitcoin") => {
-            // Bitcoin addresses: bech32 (bc1...) or legacy/base58
-            address.starts_with("bc1")
-                || address.starts_with("tb1")
-                || address.len() >= 26 && address.len() <= 35
-        }
-        Some("solana") => {
-            // Solana addresses: base58, typically 32-44 characters
-            address.len() >= 32 && address.len() <= 44
-        }
-        _ => {
+    // Known chains go through the strict per-chain validator
+    // 已知链使用统一的按链校验（校验和、网络）
+    match chain.and_then(ChainType::from_str) {
+        Some(chain) => validate_chain_address(chain, address).is_ok(),
+        None => {
             // Generic validation: alphanumeric and common address characters
             address
                 .chars()
@@ -141,6 +130,10 @@ mod tests {
     #[test]
     fn test_validate_address() {
         assert!(validate_address(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            Some("ethereum")
+        ));
+        assert!(!validate_address(
             "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb6",
             Some("ethereum")
         ));
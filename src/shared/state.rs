@@ -1,10 +1,20 @@
-use crate::components::molecules::toast::{ToastMessage, ToastType};
+use crate::components::molecules::performance_monitor::PhaseTiming;
+use crate::components::molecules::toast::{
+    push_toast, upsert_keyed_toast, ToastAction, ToastMessage, ToastType,
+};
 use crate::crypto::key_manager::KeyManager;
 use crate::features::auth::state::UserState;
+use crate::features::market::store::MarketData;
 use crate::features::settings::state::UserPreferences;
+use crate::features::support::HelpPanelRequest;
+use crate::features::swap::pending::PendingSwaps;
+use crate::features::wallet::discovery::AccountDiscovery;
 use crate::features::wallet::state::WalletState;
-use crate::shared::api::{ApiClient, ApiConfig};
+use crate::services::balance::CommittedBalances;
+use crate::services::user::{NotificationCategory, NotificationChannel};
+use crate::shared::api::{ApiClient, ApiConfig, SlowRequests};
 use crate::shared::cache::CacheEntry;
+use crate::shared::state_updates;
 use dioxus::prelude::ReadableExt;
 use dioxus::prelude::*;
 use gloo_storage::Storage;
@@ -18,20 +28,29 @@ pub struct AppState {
     pub preferences: Signal<UserPreferences>,
     pub api: Signal<ApiClient>,
     pub key_manager: Signal<Option<KeyManager>>,
-    pub last_active: Signal<u64>, // Timestamp for auto-lock (账户锁 - 1小时自动登出)
+    pub last_active: Signal<u64>, // Timestamp for auto-lock (无操作自动锁定应用)
+    pub app_locked: Signal<bool>, // 应用是否处于锁屏状态（会话保留，密钥已清除）
     pub wallet_unlock_time: Signal<HashMap<String, u64>>, // 每个钱包的解锁时间戳（钱包锁 - 15分钟自动锁）
     pub is_online: Signal<bool>,                          // Network status
     pub cache: Signal<HashMap<String, CacheEntry>>,       // Smart Cache: Key -> Value + timestamp
     pub inflight_requests: Signal<HashSet<String>>,       // Request Deduplication
-    #[allow(dead_code)] // 隐私模式，用于未来功能
-    pub privacy_mode: Signal<bool>, // Hide amounts when blurred
+    pub slow_requests: SlowRequests,                      // 超过软阈值仍在进行的请求（路径 → 数量）
+    pub privacy_mode: Signal<bool>,                       // 隐私模式：隐藏余额与金额
     pub toasts: Signal<Vec<ToastMessage>>,                // Toast消息列表
     pub language: Signal<String>,                         // 当前语言: "zh", "en", "ja", "ko"
+    pub phase_timings: Signal<Vec<PhaseTiming>>,          // 分阶段加载耗时（性能监控）
+    pub committed_balances: Signal<CommittedBalances>,    // 挂单/进行中订单占用的资产数量
+    pub pending_swaps: Signal<PendingSwaps>,              // 已广播、尚未确认的授权与兑换
+    pub help_panel: Signal<Option<HelpPanelRequest>>,     // 帮助面板（None 为关闭）
+    pub resume_tick: Signal<u32>, // 标签页切回前台的次数，屏幕上的数据据此立即刷新
+    pub account_discovery: Signal<AccountDiscovery>, // 导入助记词后的账户发现（离开导入页后继续扫描）
+    pub market: Signal<MarketData>, // 共享的价格与 gas 价格（后台统一轮询，按订阅查询）
 }
 
 impl AppState {
     pub fn new() -> Self {
-        let now = (js_sys::Date::new_0().get_time() / 1000.0) as u64;
+        let now = crate::shared::time::now_unix();
+        let stored_preferences = crate::services::preferences::load();
 
         // Allow overriding API base URL via LocalStorage key `api_base_url`
         let mut api_cfg = ApiConfig::default();
@@ -44,23 +63,49 @@ impl AppState {
         Self {
             user: Signal::new(UserState::load()),
             wallet: Signal::new(WalletState::default()),
-            preferences: Signal::new(UserPreferences::load()),
+            preferences: Signal::new(stored_preferences.settings),
             api: Signal::new(ApiClient::new(api_cfg)),
             key_manager: Signal::new(None),
             last_active: Signal::new(now),
+            app_locked: Signal::new(crate::features::security::app_lock::persisted_locked()),
             wallet_unlock_time: Signal::new(HashMap::new()), // 钱包锁时间戳
             is_online: Signal::new(true),                    // Assume online initially
             cache: Signal::new(HashMap::new()),
             inflight_requests: Signal::new(HashSet::new()),
-            privacy_mode: Signal::new(false),
+            slow_requests: Signal::new_maybe_sync(HashMap::new()),
+            privacy_mode: Signal::new(stored_preferences.privacy_mode),
             toasts: Signal::new(Vec::new()),
-            language: Signal::new(
-                gloo_storage::LocalStorage::get::<String>("app_language")
-                    .unwrap_or_else(|_| "zh".to_string()),
-            ),
+            language: Signal::new(stored_preferences.ui_language),
+            phase_timings: Signal::new(Vec::new()),
+            committed_balances: Signal::new(CommittedBalances::default()),
+            pending_swaps: Signal::new(PendingSwaps::load(now)),
+            help_panel: Signal::new(None),
+            resume_tick: Signal::new(0),
+            account_discovery: Signal::new(AccountDiscovery::default()),
+            market: Signal::new(MarketData::default()),
         }
     }
 
+    /// 打开帮助面板（从订单详情打开时附带订单号）
+    pub fn open_help(self, order_id: Option<String>) {
+        self.open_help_with(HelpPanelRequest {
+            order_id,
+            draft: None,
+        });
+    }
+
+    /// 打开帮助面板并预填工单（附带订单号）
+    pub fn open_help_with(self, request: HelpPanelRequest) {
+        let mut help_panel = self.help_panel;
+        help_panel.set(Some(request));
+    }
+
+    /// 切换隐私模式并持久化
+    pub fn toggle_privacy_mode(self) {
+        let enabled = !*self.privacy_mode.peek();
+        crate::services::preferences::PreferencesService::new(self).set_privacy_mode(enabled);
+    }
+
     /// Get a cloned copy of the ApiClient with the latest auth token from UserState
     /// Dioxus 0.7 compatible: uses Readable trait
     /// This ensures the ApiClient always has the current authentication token
@@ -90,7 +135,7 @@ impl AppState {
                     #[cfg(debug_assertions)]
                     {
                         log(&format!("✅ Token synced (length: {})", token.len()));
-                        use tracing::debug;
+                        use log::debug;
                         debug!(
                             "API Client: Token synced from UserState (length: {})",
                             token.len()
@@ -100,7 +145,7 @@ impl AppState {
                     #[cfg(debug_assertions)]
                     {
                         log("⚠️ UserState has EMPTY token");
-                        use tracing::warn;
+                        use log::warn;
                         warn!("API Client: UserState has empty token, clearing auth");
                     }
                     api_client.clear_auth();
@@ -109,7 +154,7 @@ impl AppState {
                 #[cfg(debug_assertions)]
                 {
                     log("⚠️ UserState.access_token is None");
-                    use tracing::warn;
+                    use log::warn;
                     warn!("API Client: UserState.is_authenticated=true but access_token is None");
                 }
                 api_client.clear_auth();
@@ -119,12 +164,15 @@ impl AppState {
             #[cfg(debug_assertions)]
             {
                 log("❌ User NOT authenticated");
-                use tracing::debug;
+                use log::debug;
                 debug!("API Client: User not authenticated, clearing auth");
             }
             api_client.clear_auth();
         }
+        drop(user_state);
 
+        api_client.set_demo_mode(self.preferences.read().demo_mode);
+        api_client.track_slow_requests(self.slow_requests);
         api_client
     }
 
@@ -141,32 +189,135 @@ impl AppState {
     }
 
     /// 显示Toast消息（辅助函数）
+    /// 去重窗口内相同内容的提示会合并为一条
+    #[track_caller]
     pub fn show_toast(
-        mut toasts: Signal<Vec<ToastMessage>>,
+        toasts: Signal<Vec<ToastMessage>>,
+        message: String,
+        toast_type: ToastType,
+        duration: Option<u32>,
+    ) {
+        Self::show_toast_with_action(toasts, message, toast_type, duration, None);
+    }
+
+    /// 显示带操作按钮的Toast消息（如“交易已确认 — 查看”）
+    ///
+    /// 提示经修改队列写入，可在任意异步任务中调用
+    #[track_caller]
+    pub fn show_toast_with_action(
+        toasts: Signal<Vec<ToastMessage>>,
         message: String,
         toast_type: ToastType,
         duration: Option<u32>,
+        action: Option<ToastAction>,
     ) {
-        let mut toasts_guard = toasts.write();
-        let id = (js_sys::Date::new_0().get_time() as u64) + toasts_guard.len() as u64;
-        toasts_guard.push(ToastMessage {
-            id,
+        let toast = ToastMessage::new(
+            Self::next_toast_id(),
             message,
             toast_type,
-            duration: duration.unwrap_or(3000), // 默认3秒
+            duration.unwrap_or(3000), // 默认3秒
+            crate::shared::time::now_ms(),
+        )
+        .with_action(action);
+        state_updates::enqueue(toasts, move |list: &mut Vec<ToastMessage>| {
+            push_toast(list, toast);
         });
     }
 
+    /// 显示或更新进度提示（按 key 定位同一条，不自动关闭）
+    #[track_caller]
+    pub fn show_progress(toasts: Signal<Vec<ToastMessage>>, key: &str, message: String) {
+        let toast = ToastMessage::new(
+            Self::next_toast_id(),
+            message,
+            ToastType::Progress,
+            0,
+            crate::shared::time::now_ms(),
+        )
+        .with_key(key);
+        state_updates::enqueue(toasts, move |list: &mut Vec<ToastMessage>| {
+            upsert_keyed_toast(list, toast);
+        });
+    }
+
+    /// 将进度提示更新为最终状态（成功/失败等），恢复自动关闭
+    #[track_caller]
+    pub fn finish_progress(
+        toasts: Signal<Vec<ToastMessage>>,
+        key: &str,
+        toast_type: ToastType,
+        message: String,
+        action: Option<ToastAction>,
+    ) {
+        let message = if toast_type == ToastType::Error {
+            crate::shared::ui_error::sanitize_user_message(message)
+        } else {
+            message
+        };
+        // 带操作按钮或错误的提示多停留一会儿
+        let duration = if action.is_some() || toast_type == ToastType::Error {
+            6000
+        } else {
+            3000
+        };
+        let toast = ToastMessage::new(
+            Self::next_toast_id(),
+            message,
+            toast_type,
+            duration,
+            crate::shared::time::now_ms(),
+        )
+        .with_key(key)
+        .with_action(action);
+        state_updates::enqueue(toasts, move |list: &mut Vec<ToastMessage>| {
+            upsert_keyed_toast(list, toast);
+        });
+    }
+
+    /// 提示ID在入队时分配（不读取列表），保持单调递增
+    fn next_toast_id() -> u64 {
+        thread_local! {
+            static LAST_TOAST_ID: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+        }
+        LAST_TOAST_ID.with(|last| {
+            let id = (crate::shared::time::now_ms() as u64).max(last.get() + 1);
+            last.set(id);
+            id
+        })
+    }
+
+    /// 显示应用内通知：按用户的通知偏好过滤（安全类始终显示），返回是否已显示
+    #[track_caller]
+    pub fn notify(
+        self,
+        category: NotificationCategory,
+        message: String,
+        toast_type: ToastType,
+        duration: Option<u32>,
+    ) -> bool {
+        let shown = self
+            .preferences
+            .read()
+            .notifications
+            .delivers(category, NotificationChannel::InApp);
+        if shown {
+            Self::show_toast(self.toasts, message, toast_type, duration);
+        }
+        shown
+    }
+
     /// 显示成功消息
+    #[track_caller]
     pub fn show_success(toasts: Signal<Vec<ToastMessage>>, message: String) {
         Self::show_toast(toasts, message, ToastType::Success, None);
     }
 
     /// 显示错误消息
+    #[track_caller]
     pub fn show_error(toasts: Signal<Vec<ToastMessage>>, message: String) {
         #[cfg(debug_assertions)]
         {
-            tracing::debug!("toast_error_raw={}", message);
+            log::debug!("toast_error_raw={}", message);
         }
 
         let message = crate::shared::ui_error::sanitize_user_message(message);
@@ -175,19 +326,35 @@ impl AppState {
 
     /// 显示警告消息
     #[allow(dead_code)] // 警告提示，用于未来功能
+    #[track_caller]
     pub fn show_warning(toasts: Signal<Vec<ToastMessage>>, message: String) {
         Self::show_toast(toasts, message, ToastType::Warning, None);
     }
 
     /// 显示信息消息
+    #[track_caller]
     pub fn show_info(toasts: Signal<Vec<ToastMessage>>, message: String) {
         Self::show_toast(toasts, message, ToastType::Info, None);
     }
 
     /// 移除Toast消息
-    #[allow(dead_code)] // 移除提示，用于未来功能
-    pub fn remove_toast(mut toasts: Signal<Vec<ToastMessage>>, id: u64) {
-        let mut toasts_guard = toasts.write();
-        toasts_guard.retain(|t| t.id != id);
+    #[track_caller]
+    pub fn remove_toast(toasts: Signal<Vec<ToastMessage>>, id: u64) {
+        state_updates::enqueue(toasts, move |list: &mut Vec<ToastMessage>| {
+            list.retain(|t| t.id != id);
+        });
+    }
+
+    /// 修改钱包状态（经修改队列写入并持久化，可在任意异步任务中调用）
+    ///
+    /// 需要立即读取修改结果的同步流程仍直接 `write()`，但不得跨 `await` 持有
+    #[track_caller]
+    pub fn update_wallet(self, update: impl FnOnce(&mut WalletState) + 'static) {
+        state_updates::enqueue(self.wallet, move |wallet: &mut WalletState| {
+            update(wallet);
+            if let Err(e) = wallet.save() {
+                log::warn!("保存钱包状态失败: {}", e);
+            }
+        });
     }
 }
@@ -0,0 +1,324 @@
+//! State Updates - 热点信号的排队修改
+//!
+//! 多个异步任务同时修改 toasts、钱包等全局信号时，直接 `write()` 可能撞上尚未释放的借用而 panic，
+//! `try_write()` 失败则会静默丢弃修改。这里按状态类型维护一个修改队列，在一处按提交顺序应用：
+//! 能取得写锁时立即应用，否则在下一个 tick 重试，修改不会丢失。
+//!
+//! 每种状态类型共用一个队列，只用于 `AppState` 中唯一的热点信号（toasts、wallet、慢请求）。
+
+use dioxus::prelude::*;
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::panic::Location;
+use std::rc::Rc;
+
+/// 排队的修改
+pub type Update<T> = Box<dyn FnOnce(&mut T)>;
+
+/// 可尝试取得写锁的状态
+pub trait TryWrite<T> {
+    /// 取得写锁并执行 `f`；状态正被借用时不执行，返回 `Err(借用信息)`
+    fn try_with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Result<R, String>;
+}
+
+impl<T: 'static> TryWrite<T> for Signal<T> {
+    fn try_with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Result<R, String> {
+        self.try_write()
+            .map(|mut guard| f(&mut guard))
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl<T: Send + Sync + 'static> TryWrite<T> for SyncSignal<T> {
+    fn try_with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Result<R, String> {
+        self.try_write()
+            .map(|mut guard| f(&mut guard))
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// 一次 flush 的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Flush {
+    /// 已应用的修改数量（队列已清空）
+    Applied(usize),
+    /// 状态正被借用，修改保留在队列中
+    Busy(String),
+    /// 正在应用本队列的修改（修改闭包内又提交了修改），由外层这一轮一并应用
+    Nested,
+}
+
+/// 单个状态的修改队列
+pub struct UpdateQueue<T> {
+    pending: RefCell<VecDeque<Update<T>>>,
+    draining: Cell<bool>,
+    retry_scheduled: Cell<bool>,
+}
+
+impl<T> Default for UpdateQueue<T> {
+    fn default() -> Self {
+        Self {
+            pending: RefCell::new(VecDeque::new()),
+            draining: Cell::new(false),
+            retry_scheduled: Cell::new(false),
+        }
+    }
+}
+
+impl<T> UpdateQueue<T> {
+    pub fn push(&self, update: Update<T>) {
+        self.pending.borrow_mut().push_back(update);
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 在一次写锁内按提交顺序应用全部待处理修改
+    pub fn flush(&self, target: &mut impl TryWrite<T>) -> Flush {
+        if self.draining.get() {
+            return Flush::Nested;
+        }
+        if self.is_empty() {
+            return Flush::Applied(0);
+        }
+        self.draining.set(true);
+        let result = target.try_with_mut(|state| {
+            let mut applied = 0;
+            loop {
+                // 逐个取出：修改闭包内提交的修改进入队尾，本轮一并应用
+                let next = self.pending.borrow_mut().pop_front();
+                let Some(update) = next else {
+                    break;
+                };
+                update(state);
+                applied += 1;
+            }
+            applied
+        });
+        self.draining.set(false);
+        match result {
+            Ok(applied) => Flush::Applied(applied),
+            Err(e) => Flush::Busy(e),
+        }
+    }
+}
+
+thread_local! {
+    static QUEUES: RefCell<HashMap<TypeId, Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// 按信号类型（而非内部数据类型）取队列，同类数据的不同种信号互不干扰
+fn queue_for<T: 'static, S: 'static>() -> Rc<UpdateQueue<T>> {
+    QUEUES.with(|queues| {
+        let queue = queues
+            .borrow_mut()
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| Rc::new(UpdateQueue::<T>::default()) as Rc<dyn Any>)
+            .clone();
+        queue
+            .downcast::<UpdateQueue<T>>()
+            .unwrap_or_else(|_| unreachable!("队列按类型登记"))
+    })
+}
+
+/// 提交对热点信号的修改：能取得写锁时立即应用，否则下一个 tick 重试
+#[track_caller]
+pub fn enqueue<T, S>(target: S, update: impl FnOnce(&mut T) + 'static)
+where
+    T: 'static,
+    S: TryWrite<T> + Copy + 'static,
+{
+    let queue = queue_for::<T, S>();
+    queue.push(Box::new(update));
+    flush_or_retry(target, queue, Location::caller());
+}
+
+fn flush_or_retry<T, S>(
+    mut target: S,
+    queue: Rc<UpdateQueue<T>>,
+    caller: &'static Location<'static>,
+) where
+    T: 'static,
+    S: TryWrite<T> + Copy + 'static,
+{
+    let Flush::Busy(reason) = queue.flush(&mut target) else {
+        return;
+    };
+    // 单线程下写锁被占用，说明调用栈上已有借用（嵌套写入）或有任务跨 await 持有借用
+    #[cfg(debug_assertions)]
+    log::error!(
+        "检测到嵌套写入：{} 提交修改时状态仍被借用（{}），将在下一个 tick 重试",
+        caller,
+        reason
+    );
+    #[cfg(not(debug_assertions))]
+    let _ = reason;
+    if queue.retry_scheduled.replace(true) {
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async move {
+        gloo_timers::future::TimeoutFuture::new(0).await;
+        queue.retry_scheduled.set(false);
+        flush_or_retry(target, queue, caller);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::LocalPool;
+    use futures::task::LocalSpawnExt;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    /// 让出一次执行权（模拟 await 点）
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    /// 测试用状态：可手动占用写锁模拟其他任务持有借用
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u32>>>);
+
+    impl TryWrite<Vec<u32>> for Shared {
+        fn try_with_mut<R>(&mut self, f: impl FnOnce(&mut Vec<u32>) -> R) -> Result<R, String> {
+            self.0
+                .try_borrow_mut()
+                .map(|mut guard| f(&mut guard))
+                .map_err(|e| e.to_string())
+        }
+    }
+
+    #[test]
+    fn applies_updates_in_submission_order() {
+        let queue = UpdateQueue::default();
+        let mut state = Shared::default();
+        for i in 0..5 {
+            queue.push(Box::new(move |v: &mut Vec<u32>| v.push(i)));
+        }
+        assert_eq!(queue.flush(&mut state), Flush::Applied(5));
+        assert_eq!(*state.0.borrow(), vec![0, 1, 2, 3, 4]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn keeps_updates_while_state_is_borrowed() {
+        let queue = UpdateQueue::default();
+        let mut state = Shared::default();
+        let held = state.0.clone();
+        let guard = held.borrow();
+        queue.push(Box::new(|v: &mut Vec<u32>| v.push(1)));
+        assert!(matches!(queue.flush(&mut state), Flush::Busy(_)));
+        assert_eq!(queue.len(), 1);
+        drop(guard);
+        assert_eq!(queue.flush(&mut state), Flush::Applied(1));
+        assert_eq!(*state.0.borrow(), vec![1]);
+    }
+
+    #[test]
+    fn nested_submissions_apply_in_the_same_round() {
+        let queue = Rc::new(UpdateQueue::default());
+        let mut state = Shared::default();
+        let inner_queue = queue.clone();
+        let mut inner_state = state.clone();
+        queue.push(Box::new(move |v: &mut Vec<u32>| {
+            v.push(1);
+            inner_queue.push(Box::new(|v: &mut Vec<u32>| v.push(2)));
+            // 修改闭包内再次 flush 不会嵌套借用
+            assert_eq!(inner_queue.flush(&mut inner_state), Flush::Nested);
+        }));
+        assert_eq!(queue.flush(&mut state), Flush::Applied(2));
+        assert_eq!(*state.0.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn stress_concurrent_tasks_do_not_panic_or_drop_updates() {
+        const TASKS: u32 = 200;
+        const UPDATES_PER_TASK: u32 = 25;
+
+        let queue = Rc::new(UpdateQueue::default());
+        let state = Shared::default();
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        for task in 0..TASKS {
+            let queue = queue.clone();
+            let mut state = state.clone();
+            spawner
+                .spawn_local(async move {
+                    for n in 0..UPDATES_PER_TASK {
+                        // 部分任务跨 yield 持有读借用，模拟跨 await 的借用冲突
+                        let held = (task % 7 == 0).then(|| state.0.clone());
+                        let guard = held.as_ref().map(|s| s.borrow());
+                        queue.push(Box::new(move |v: &mut Vec<u32>| {
+                            v.push(task * UPDATES_PER_TASK + n)
+                        }));
+                        let _ = queue.flush(&mut state);
+                        YieldNow(false).await;
+                        drop(guard);
+                        let _ = queue.flush(&mut state);
+                    }
+                })
+                .unwrap();
+        }
+        pool.run();
+
+        // 最后一次 tick 重试
+        let mut state_for_retry = state.clone();
+        assert!(matches!(
+            queue.flush(&mut state_for_retry),
+            Flush::Applied(_)
+        ));
+        let mut values = state.0.borrow().clone();
+        assert_eq!(values.len() as u32, TASKS * UPDATES_PER_TASK);
+        values.sort_unstable();
+        values.dedup();
+        assert_eq!(values.len() as u32, TASKS * UPDATES_PER_TASK);
+    }
+
+    #[test]
+    fn stress_per_task_order_is_preserved() {
+        let queue = Rc::new(UpdateQueue::default());
+        let state = Shared::default();
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+        for task in 0..50u32 {
+            let queue = queue.clone();
+            let mut state = state.clone();
+            spawner
+                .spawn_local(async move {
+                    for n in 0..20u32 {
+                        queue.push(Box::new(move |v: &mut Vec<u32>| v.push(task * 100 + n)));
+                        let _ = queue.flush(&mut state);
+                        YieldNow(false).await;
+                    }
+                })
+                .unwrap();
+        }
+        pool.run();
+
+        let values = state.0.borrow().clone();
+        assert_eq!(values.len(), 50 * 20);
+        for task in 0..50u32 {
+            let ours: Vec<u32> = values.iter().copied().filter(|v| v / 100 == task).collect();
+            assert!(ours.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
@@ -1,46 +1,67 @@
+use crate::shared::web_crypto::{call, resolve, set, subtle, KeyStore};
 use anyhow::Result;
 use async_trait::async_trait;
 use gloo_storage::{LocalStorage, Storage};
+use js_sys::{Array, Object, Uint8Array};
+use rand::RngCore;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+
+/// 本设备存储密钥（不可导出的 AES-GCM `CryptoKey`）所在的 IndexedDB 仓库
+const KEY_STORE: KeyStore = KeyStore {
+    db_name: "ironforge_storage",
+    store: "storage_keys",
+};
+const DEVICE_KEY_ID: &str = "device";
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+thread_local! {
+    /// 已加载的存储密钥；首次生成时串行执行，避免并发调用各自生成密钥互相覆盖
+    static DEVICE_KEY: Rc<futures::lock::Mutex<Option<JsValue>>> =
+        Rc::new(futures::lock::Mutex::new(None));
+}
 
 #[async_trait]
-#[allow(dead_code)] // 存储适配器接口，用于未来功能
 pub trait StorageAdapter {
     async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
     async fn set(&self, key: &str, value: &[u8]) -> Result<()>;
     async fn remove(&self, key: &str) -> Result<()>;
 }
 
-#[allow(dead_code)] // 加密存储，用于未来功能
+/// 加密存储（AES-GCM，密钥为本设备不可导出的 WebCrypto 密钥，密文以十六进制写入底层存储）
 pub struct EncryptedStorage<S: StorageAdapter> {
     adapter: S,
-    key: [u8; 32],
 }
 
-#[allow(dead_code)] // 加密存储实现，用于未来功能
 impl<S: StorageAdapter> EncryptedStorage<S> {
-    #[allow(dead_code)]
-    pub fn new(adapter: S, key: [u8; 32]) -> Self {
-        Self { adapter, key }
+    pub fn new(adapter: S) -> Self {
+        Self { adapter }
     }
 
-    #[allow(dead_code)]
     pub async fn save(&self, key: &str, data: &[u8]) -> Result<()> {
-        let encrypted = crate::crypto::encryption::encrypt(&self.key, data)?;
+        let device_key = device_key().await.map_err(anyhow::Error::msg)?;
+        let encrypted = seal(&device_key, data).await.map_err(anyhow::Error::msg)?;
         self.adapter.set(key, &encrypted).await
     }
 
-    #[allow(dead_code)]
     pub async fn load(&self, key: &str) -> Result<Option<Vec<u8>>> {
         if let Some(encrypted) = self.adapter.get(key).await? {
-            let decrypted = crate::crypto::encryption::decrypt(&self.key, &encrypted)?;
+            let device_key = device_key().await.map_err(anyhow::Error::msg)?;
+            let decrypted = open(&device_key, &encrypted)
+                .await
+                .map_err(anyhow::Error::msg)?;
             Ok(Some(decrypted))
         } else {
             Ok(None)
         }
     }
+
+    pub async fn remove(&self, key: &str) -> Result<()> {
+        self.adapter.remove(key).await
+    }
 }
 
-#[allow(dead_code)] // 为未来功能准备
 pub struct LocalStorageAdapter;
 
 #[async_trait]
@@ -67,3 +88,103 @@ impl StorageAdapter for LocalStorageAdapter {
         Ok(())
     }
 }
+
+/// 以本设备密钥加密的 LocalStorage（会话、用户设置等不以明文落盘的数据）
+pub fn device_storage() -> EncryptedStorage<LocalStorageAdapter> {
+    EncryptedStorage::new(LocalStorageAdapter)
+}
+
+/// 本设备的存储加密密钥：首次使用时生成不可导出的 AES-GCM 密钥并存入 IndexedDB，
+/// 密钥材料不会出现在 LocalStorage 中
+async fn device_key() -> Result<JsValue, String> {
+    let cache = DEVICE_KEY.with(Rc::clone);
+    let mut cached = cache.lock().await;
+    if let Some(key) = cached.as_ref() {
+        return Ok(key.clone());
+    }
+    let key = match KEY_STORE.load(DEVICE_KEY_ID).await? {
+        Some(key) => key,
+        None => {
+            let key = generate_key().await?;
+            KEY_STORE.store(DEVICE_KEY_ID, &key).await?;
+            key
+        }
+    };
+    *cached = Some(key.clone());
+    Ok(key)
+}
+
+fn aes_gcm(extra: &[(&str, JsValue)]) -> Result<JsValue, String> {
+    let params: JsValue = Object::new().into();
+    set(&params, "name", &JsValue::from_str("AES-GCM"))?;
+    for (key, value) in extra {
+        set(&params, key, value)?;
+    }
+    Ok(params)
+}
+
+async fn generate_key() -> Result<JsValue, String> {
+    let usages = Array::of2(&JsValue::from_str("encrypt"), &JsValue::from_str("decrypt"));
+    let promise = call(
+        &subtle()?,
+        "generateKey",
+        &[
+            aes_gcm(&[("length", JsValue::from_f64(256.0))])?,
+            JsValue::from_bool(false),
+            usages.into(),
+        ],
+    )?;
+    resolve(promise).await
+}
+
+/// 加密为 nonce ‖ 密文（含认证标签）
+async fn seal(key: &JsValue, data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let promise = call(
+        &subtle()?,
+        "encrypt",
+        &[
+            aes_gcm(&[("iv", Uint8Array::from(&nonce[..]).into())])?,
+            key.clone(),
+            Uint8Array::from(data).into(),
+        ],
+    )?;
+    let ciphertext = resolve(promise).await?;
+    let mut sealed = nonce.to_vec();
+    sealed.extend(Uint8Array::new(&ciphertext).to_vec());
+    Ok(sealed)
+}
+
+async fn open(key: &JsValue, sealed: &[u8]) -> Result<Vec<u8>, String> {
+    let (nonce, ciphertext) = split_sealed(sealed).ok_or_else(|| "密文长度无效".to_string())?;
+    let promise = call(
+        &subtle()?,
+        "decrypt",
+        &[
+            aes_gcm(&[("iv", Uint8Array::from(nonce).into())])?,
+            key.clone(),
+            Uint8Array::from(ciphertext).into(),
+        ],
+    )?;
+    let plaintext = resolve(promise).await.map_err(|_| "解密失败".to_string())?;
+    Ok(Uint8Array::new(&plaintext).to_vec())
+}
+
+fn split_sealed(sealed: &[u8]) -> Option<(&[u8], &[u8])> {
+    (sealed.len() >= NONCE_LEN + TAG_LEN).then(|| sealed.split_at(NONCE_LEN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_data_needs_nonce_and_tag() {
+        assert!(split_sealed(&[0u8; NONCE_LEN + TAG_LEN - 1]).is_none());
+        let sealed = [7u8; NONCE_LEN + TAG_LEN + 3];
+        let (nonce, ciphertext) = split_sealed(&sealed).unwrap();
+        assert_eq!(nonce.len(), NONCE_LEN);
+        assert_eq!(ciphertext.len(), TAG_LEN + 3);
+    }
+}
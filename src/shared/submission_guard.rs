@@ -0,0 +1,141 @@
+//! Submission Guard - 下单防重复提交
+//!
+//! - 幂等键：进入确认阶段时生成一次，同一次提交（含超时重试）沿用同一个键，服务端据此去重
+//! - 近期重复检测：两分钟内创建过相同订单（同币种、同金额、同方式）时，提交前提示用户确认
+//!
+//! 近期订单记录保存在 SessionStorage 中，仅在当前会话内有效。
+
+use crate::shared::time;
+use gloo_storage::{SessionStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+/// 提交按钮点击后的最短禁用时长
+pub const SUBMIT_COOLDOWN_MS: u32 = 1_500;
+
+/// 重复订单检测窗口
+pub const DUPLICATE_WINDOW_SECS: u64 = 120;
+
+const RECENT_ORDERS_KEY: &str = "recent_order_fingerprints";
+
+/// 生成客户端幂等键
+pub fn new_idempotency_key() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// 订单特征（用于识别重复订单）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderFingerprint {
+    /// 订单类别：onramp / offramp
+    pub kind: String,
+    pub token: String,
+    /// 规范化后的金额（去掉多余的 0）
+    pub amount: String,
+    /// 支付方式或提现方式
+    pub method: String,
+}
+
+impl OrderFingerprint {
+    pub fn new(kind: &str, token: &str, amount: &str, method: &str) -> Self {
+        let amount = amount.trim();
+        let amount = if amount.contains('.') {
+            amount.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            amount
+        };
+        Self {
+            kind: kind.to_string(),
+            token: token.trim().to_uppercase(),
+            amount: amount.to_string(),
+            method: method.trim().to_lowercase(),
+        }
+    }
+}
+
+/// 近期创建的订单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentOrder {
+    pub fingerprint: OrderFingerprint,
+    /// 创建时间（秒）
+    pub created_at: u64,
+}
+
+/// 在窗口内查找相同订单，返回距今秒数
+pub fn find_duplicate(
+    recent: &[RecentOrder],
+    fingerprint: &OrderFingerprint,
+    now_secs: u64,
+) -> Option<u64> {
+    recent
+        .iter()
+        .filter(|order| &order.fingerprint == fingerprint)
+        .map(|order| now_secs.saturating_sub(order.created_at))
+        .filter(|elapsed| *elapsed <= DUPLICATE_WINDOW_SECS)
+        .min()
+}
+
+fn load_recent() -> Vec<RecentOrder> {
+    SessionStorage::get(RECENT_ORDERS_KEY).unwrap_or_default()
+}
+
+/// 当前会话内是否刚创建过相同订单（返回距今秒数）
+pub fn recent_duplicate(fingerprint: &OrderFingerprint) -> Option<u64> {
+    find_duplicate(&load_recent(), fingerprint, time::now_unix())
+}
+
+/// 记录刚创建成功的订单（同时清理窗口外的记录）
+pub fn record_order(fingerprint: OrderFingerprint) {
+    let now = time::now_unix();
+    let mut recent = load_recent();
+    recent.retain(|order| now.saturating_sub(order.created_at) <= DUPLICATE_WINDOW_SECS);
+    recent.push(RecentOrder {
+        fingerprint,
+        created_at: now,
+    });
+    let _ = SessionStorage::set(RECENT_ORDERS_KEY, &recent);
+}
+
+/// 重复订单提示文案
+pub fn duplicate_warning(elapsed_secs: u64) -> String {
+    format!(
+        "您在 {} 秒前刚创建过相同的订单（同币种、同金额、同方式），确定要再次提交吗？",
+        elapsed_secs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_normalizes_inputs() {
+        assert_eq!(
+            OrderFingerprint::new("onramp", "usdt", "100.50", "Credit_Card"),
+            OrderFingerprint::new("onramp", "USDT ", "100.5", "credit_card")
+        );
+        assert_eq!(
+            OrderFingerprint::new("onramp", "USDT", "100.00", "x").amount,
+            "100"
+        );
+        assert_eq!(
+            OrderFingerprint::new("onramp", "USDT", "100", "x").amount,
+            "100"
+        );
+    }
+
+    #[test]
+    fn duplicates_only_within_window() {
+        let fingerprint = OrderFingerprint::new("offramp", "USDC", "50", "bank_card");
+        let recent = vec![
+            RecentOrder {
+                fingerprint: fingerprint.clone(),
+                created_at: 1_000,
+            },
+            RecentOrder {
+                fingerprint: OrderFingerprint::new("offramp", "USDC", "51", "bank_card"),
+                created_at: 1_090,
+            },
+        ];
+        assert_eq!(find_duplicate(&recent, &fingerprint, 1_030), Some(30));
+        assert_eq!(find_duplicate(&recent, &fingerprint, 1_000 + 121), None);
+    }
+}
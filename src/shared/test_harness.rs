@@ -0,0 +1,274 @@
+//! Test Harness - 浏览器端到端测试工具
+//!
+//! 在 wasm-bindgen-test 的浏览器环境中挂载完整路由（含应用布局），
+//! API 请求全部由 [`MockApi`] 拦截，测试通过真实的 DOM 事件驱动页面：
+//! - [`Harness::mount`]：挂载到指定路由，挂载前可直接修改 [`AppState`]（如预置已解锁钱包）
+//! - `click_*` / [`Harness::type_into`]：等待元素出现且可用后触发事件
+//! - `wait_*`：轮询等待文本、请求或状态满足条件，超时则测试失败
+//!
+//! 运行：`wasm-pack test --headless --firefox`
+
+use crate::components::molecules::OnboardingManager;
+use crate::crypto::bip39::MnemonicSecret;
+use crate::crypto::key_manager::KeyManager;
+use crate::features::wallet::chain_accounts;
+use crate::features::wallet::state::Wallet;
+use crate::router::Route;
+use crate::services::address_detector::ChainType;
+use crate::shared::mock_api::{MockApi, RecordedRequest};
+use crate::shared::state::AppState;
+use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::JsCast;
+
+/// 等待条件满足的最长时间
+pub const WAIT_TIMEOUT_MS: u32 = 5_000;
+/// 轮询间隔
+const POLL_MS: u32 = 20;
+
+/// 测试钱包助记词（公开的测试助记词，切勿用于真实资产）
+pub const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
+pub const TEST_WALLET_ID: &str = "e2e-wallet";
+
+type Setup = Box<dyn FnOnce(AppState)>;
+
+thread_local! {
+    /// 下一次挂载使用的模拟后端与状态预置
+    static PENDING: RefCell<Option<(MockApi, Setup)>> = RefCell::new(None);
+    /// 最近一次挂载的全局状态
+    static MOUNTED: RefCell<Option<AppState>> = const { RefCell::new(None) };
+    static NEXT_ROOT: Cell<u32> = const { Cell::new(0) };
+}
+
+/// 测试根组件：提供全局状态、安装模拟后端后渲染路由
+#[component]
+fn HarnessRoot() -> Element {
+    use_context_provider(AppState::new);
+    let app_state = use_context::<AppState>();
+
+    use_hook(move || {
+        let mut app_state = app_state;
+        if let Some((mock, setup)) = PENDING.with(|p| p.borrow_mut().take()) {
+            app_state.api.write().install_mock(mock);
+            app_state.language.set("zh".to_string());
+            setup(app_state);
+        }
+        MOUNTED.with(|m| *m.borrow_mut() = Some(app_state));
+    });
+
+    rsx! {
+        Router::<Route> {}
+    }
+}
+
+/// 一次挂载的应用
+pub struct Harness {
+    pub mock: MockApi,
+    pub state: AppState,
+    root: web_sys::Element,
+}
+
+impl Harness {
+    /// 清空本地存储后挂载到 `route`；`setup` 在首次渲染前执行
+    pub async fn mount(
+        route: Route,
+        mock: MockApi,
+        setup: impl FnOnce(AppState) + 'static,
+    ) -> Self {
+        LocalStorage::clear();
+        // 新手引导会遮挡页面，测试中视为已完成
+        for tour in OnboardingManager::new().tours() {
+            OnboardingManager::mark_completed(tour.id);
+        }
+
+        let document = document();
+        let id = format!("e2e-root-{}", NEXT_ROOT.with(|n| n.replace(n.get() + 1)));
+        let root = document.create_element("div").expect("create root");
+        root.set_id(&id);
+        document
+            .body()
+            .expect("document body")
+            .append_child(&root)
+            .expect("append root");
+
+        web_sys::window()
+            .expect("window")
+            .history()
+            .expect("history")
+            .push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&route.to_string()))
+            .expect("push route");
+
+        MOUNTED.with(|m| m.borrow_mut().take());
+        PENDING.with(|p| *p.borrow_mut() = Some((mock.clone(), Box::new(setup))));
+        let config = dioxus::web::Config::new().rootname(id);
+        wasm_bindgen_futures::spawn_local(async move {
+            dioxus::web::run(VirtualDom::new(HarnessRoot), config).await;
+        });
+
+        let state = wait_for("应用挂载", || MOUNTED.with(|m| *m.borrow())).await;
+        Self { mock, state, root }
+    }
+
+    /// 当前路径
+    pub fn path(&self) -> String {
+        web_sys::window()
+            .and_then(|w| w.location().pathname().ok())
+            .unwrap_or_default()
+    }
+
+    /// 页面文本
+    pub fn text(&self) -> String {
+        self.root.text_content().unwrap_or_default()
+    }
+
+    /// 等待页面出现文本
+    pub async fn wait_for_text(&self, text: &str) {
+        wait_until(&format!("页面出现「{}」", text), || {
+            self.text().contains(text)
+        })
+        .await;
+    }
+
+    /// 等待匹配 `selector` 且文本包含 `text` 的可用元素
+    pub async fn find_text(&self, selector: &str, text: &str) -> web_sys::HtmlElement {
+        wait_for(&format!("可用的 {}「{}」", selector, text), || {
+            self.query_all(selector).into_iter().find(|el| {
+                el.text_content().unwrap_or_default().contains(text)
+                    && !el.has_attribute("disabled")
+            })
+        })
+        .await
+    }
+
+    /// 点击匹配 `selector` 且文本包含 `text` 的元素
+    pub async fn click_text(&self, selector: &str, text: &str) {
+        self.find_text(selector, text).await.click();
+    }
+
+    /// 在已打开的代币选择列表中点击符号为 `symbol` 的代币
+    pub async fn click_option(&self, symbol: &str) {
+        let option = wait_for(&format!("代币选项 {}", symbol), || {
+            self.query_all("[role=option] span.font-bold")
+                .into_iter()
+                .find(|el| el.text_content().unwrap_or_default().trim() == symbol)
+                .and_then(|el| el.closest("[role=option]").ok().flatten())
+                .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+        })
+        .await;
+        option.click();
+    }
+
+    /// 在输入框中输入（设置值后派发 input 事件）
+    pub async fn type_into(&self, selector: &str, value: &str) {
+        let input = wait_for(&format!("输入框 {}", selector), || {
+            self.query_all(selector)
+                .into_iter()
+                .find_map(|el| el.dyn_into::<web_sys::HtmlInputElement>().ok())
+        })
+        .await;
+        input.set_value(value);
+        let init = web_sys::EventInit::new();
+        init.set_bubbles(true);
+        let event = web_sys::Event::new_with_event_init_dict("input", &init).expect("input event");
+        input.dispatch_event(&event).expect("dispatch input");
+    }
+
+    /// 等待出现匹配的请求，返回最后一个
+    pub async fn wait_for_request(&self, method: &str, path_prefix: &str) -> RecordedRequest {
+        wait_for(&format!("请求 {} {}", method, path_prefix), || {
+            self.mock.last_request(method, path_prefix)
+        })
+        .await
+    }
+
+    /// 等待出现包含 `text` 的提示
+    pub async fn wait_for_toast(&self, text: &str) {
+        wait_until(&format!("提示「{}」", text), || {
+            self.state
+                .toasts
+                .peek()
+                .iter()
+                .any(|t| t.message.contains(text))
+        })
+        .await;
+    }
+
+    /// 卸载：移除挂载点（下一个测试重新挂载）
+    pub fn unmount(self) {
+        self.root.remove();
+    }
+
+    fn query_all(&self, selector: &str) -> Vec<web_sys::HtmlElement> {
+        let Ok(nodes) = self.root.query_selector_all(selector) else {
+            return Vec::new();
+        };
+        (0..nodes.length())
+            .filter_map(|i| nodes.item(i))
+            .filter_map(|n| n.dyn_into::<web_sys::HtmlElement>().ok())
+            .collect()
+    }
+}
+
+fn document() -> web_sys::Document {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .expect("document")
+}
+
+/// 轮询直到 `probe` 返回值，超时则测试失败
+pub async fn wait_for<T>(what: &str, mut probe: impl FnMut() -> Option<T>) -> T {
+    let mut waited = 0;
+    loop {
+        if let Some(value) = probe() {
+            return value;
+        }
+        if waited >= WAIT_TIMEOUT_MS {
+            panic!("等待超时（{}ms）：{}", WAIT_TIMEOUT_MS, what);
+        }
+        gloo_timers::future::TimeoutFuture::new(POLL_MS).await;
+        waited += POLL_MS;
+    }
+}
+
+/// 轮询直到条件成立，超时则测试失败
+pub async fn wait_until(what: &str, mut cond: impl FnMut() -> bool) {
+    wait_for(what, || cond().then_some(())).await
+}
+
+/// 预置已登录用户与已解锁的测试钱包（以太坊账户由 [`TEST_MNEMONIC`] 派生）
+pub fn seed_unlocked_wallet(mut app_state: AppState) -> Wallet {
+    let now = crate::shared::time::now_unix();
+    {
+        let mut user = app_state.user.write();
+        user.is_authenticated = true;
+        user.user_id = Some("e2e-user".to_string());
+        user.email = Some("e2e@example.com".to_string());
+        user.access_token = Some("e2e-token".to_string());
+        user.token_created_at = Some(now);
+        user.email_verified = Some(true);
+    }
+
+    let seed = MnemonicSecret::new(TEST_MNEMONIC.to_string()).to_seed("");
+    let key_manager = KeyManager::new(seed);
+    let account = chain_accounts::derive_account(&key_manager, ChainType::Ethereum)
+        .expect("derive test account");
+
+    let mut wallet = Wallet::new(TEST_WALLET_ID.to_string(), "E2E".to_string());
+    wallet.is_locked = false;
+    wallet.backup_verified = true;
+    wallet.accounts.push(account);
+    wallet.selected_account_index = Some(0);
+    {
+        let mut state = app_state.wallet.write();
+        state.add_wallet(wallet.clone());
+        state.selected_wallet_id = Some(wallet.id.clone());
+    }
+
+    app_state.key_manager.set(Some(key_manager));
+    app_state
+        .wallet_unlock_time
+        .write()
+        .insert(wallet.id.clone(), now);
+    wallet
+}
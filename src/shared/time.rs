@@ -0,0 +1,310 @@
+//! Time - 统一的时间工具
+//!
+//! 时间戳统一使用 Unix 秒（需要毫秒精度时用 [`now_ms`]）。倒计时基于单调时钟
+//! （`performance.now()`），用户修改系统时间也不会跳变。显示时间按时间戳所在时刻的
+//! 本地时区偏移换算，跨越夏令时切换的历史记录同样显示正确；文案随界面语言切换。
+
+use chrono::{DateTime, FixedOffset, Local, Offset, TimeZone, Utc};
+
+pub const MINUTE_SECS: u64 = 60;
+pub const HOUR_SECS: u64 = 60 * MINUTE_SECS;
+pub const DAY_SECS: u64 = 24 * HOUR_SECS;
+
+/// 当前时间（Unix 毫秒）
+pub fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Utc::now().timestamp_millis() as f64
+    }
+}
+
+/// 当前时间（Unix 秒）
+pub fn now_unix() -> u64 {
+    (now_ms() / 1000.0) as u64
+}
+
+/// 单调时钟（毫秒，起点不固定），只用于计算经过的时间
+pub fn monotonic_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.performance())
+            .map(|p| p.now())
+            .unwrap_or_else(now_ms)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        thread_local! {
+            static START: std::time::Instant = std::time::Instant::now();
+        }
+        START.with(|start| start.elapsed().as_secs_f64() * 1000.0)
+    }
+}
+
+/// 倒计时：开始时按系统时间换算剩余秒数，之后只按单调时钟递减
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Countdown {
+    started_at_ms: f64,
+    total_secs: u64,
+}
+
+impl Countdown {
+    /// 从现在起倒计时 `secs` 秒
+    pub fn start(secs: u64) -> Self {
+        Self::start_at(secs, monotonic_ms())
+    }
+
+    /// 倒计时到某个 Unix 时间点（秒）
+    pub fn until(deadline: u64) -> Self {
+        Self::start(deadline.saturating_sub(now_unix()))
+    }
+
+    pub fn start_at(secs: u64, monotonic_now_ms: f64) -> Self {
+        Self {
+            started_at_ms: monotonic_now_ms,
+            total_secs: secs,
+        }
+    }
+
+    pub fn remaining_at(&self, monotonic_now_ms: f64) -> u64 {
+        let elapsed = ((monotonic_now_ms - self.started_at_ms) / 1000.0).max(0.0) as u64;
+        self.total_secs.saturating_sub(elapsed)
+    }
+
+    /// 剩余秒数
+    pub fn remaining(&self) -> u64 {
+        self.remaining_at(monotonic_ms())
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total_secs
+    }
+}
+
+/// 时间显示使用的语言（由界面语言代码决定："zh"、"en"、"ja"、"ko"）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeLocale {
+    Zh,
+    En,
+    Ja,
+    Ko,
+}
+
+impl TimeLocale {
+    pub fn from_language(lang: &str) -> Self {
+        match lang.split(['-', '_']).next().unwrap_or_default() {
+            "en" => Self::En,
+            "ja" => Self::Ja,
+            "ko" => Self::Ko,
+            _ => Self::Zh,
+        }
+    }
+
+    fn datetime_format(&self) -> &'static str {
+        match self {
+            Self::En => "%b %-d, %Y %H:%M",
+            _ => "%Y-%m-%d %H:%M",
+        }
+    }
+
+    fn date_format(&self) -> &'static str {
+        match self {
+            Self::En => "%b %-d, %Y",
+            _ => "%Y-%m-%d",
+        }
+    }
+
+    /// 一天以内的相对时间
+    fn relative(&self, diff: u64) -> Option<String> {
+        if diff >= DAY_SECS {
+            return None;
+        }
+        let (minutes, hours) = (diff / MINUTE_SECS, diff / HOUR_SECS);
+        let text = match self {
+            Self::Zh if diff < MINUTE_SECS => "刚刚".to_string(),
+            Self::Zh if diff < HOUR_SECS => format!("{}分钟前", minutes),
+            Self::Zh => format!("{}小时前", hours),
+            Self::En if diff < MINUTE_SECS => "just now".to_string(),
+            Self::En if diff < HOUR_SECS => format!("{} min ago", minutes),
+            Self::En => format!("{} h ago", hours),
+            Self::Ja if diff < MINUTE_SECS => "たった今".to_string(),
+            Self::Ja if diff < HOUR_SECS => format!("{}分前", minutes),
+            Self::Ja => format!("{}時間前", hours),
+            Self::Ko if diff < MINUTE_SECS => "방금".to_string(),
+            Self::Ko if diff < HOUR_SECS => format!("{}분 전", minutes),
+            Self::Ko => format!("{}시간 전", hours),
+        };
+        Some(text)
+    }
+}
+
+/// 该时刻的本地时区偏移（秒，东正西负）；按时间戳本身计算，夏令时前后的时间各用各的偏移
+pub fn local_offset_secs(timestamp: i64) -> i32 {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|dt| dt.offset().fix().local_minus_utc())
+        .unwrap_or(0)
+}
+
+fn to_local(timestamp: i64, offset_at: impl Fn(i64) -> i32) -> Option<DateTime<FixedOffset>> {
+    let offset = FixedOffset::east_opt(offset_at(timestamp))?;
+    Some(DateTime::from_timestamp(timestamp, 0)?.with_timezone(&offset))
+}
+
+/// "UTC+8"、"UTC-4"、"UTC+5:30"
+fn zone_label(offset_secs: i32) -> String {
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    let abs = offset_secs.unsigned_abs();
+    let (hours, minutes) = (abs / 3600, abs % 3600 / 60);
+    if minutes == 0 {
+        format!("UTC{}{}", sign, hours)
+    } else {
+        format!("UTC{}{}:{:02}", sign, hours, minutes)
+    }
+}
+
+pub fn format_datetime_with(
+    timestamp: i64,
+    locale: TimeLocale,
+    offset_at: impl Fn(i64) -> i32,
+) -> String {
+    to_local(timestamp, offset_at)
+        .map(|dt| {
+            format!(
+                "{} {}",
+                dt.format(locale.datetime_format()),
+                zone_label(dt.offset().local_minus_utc())
+            )
+        })
+        .unwrap_or_else(|| "—".to_string())
+}
+
+/// 本地日期时间（带时区）："2026-03-08 15:30 UTC+8"
+pub fn format_datetime(timestamp: i64, lang: &str) -> String {
+    format_datetime_with(
+        timestamp,
+        TimeLocale::from_language(lang),
+        local_offset_secs,
+    )
+}
+
+/// 本地日期
+pub fn format_date(timestamp: i64, lang: &str) -> String {
+    to_local(timestamp, local_offset_secs)
+        .map(|dt| {
+            dt.format(TimeLocale::from_language(lang).date_format())
+                .to_string()
+        })
+        .unwrap_or_else(|| "—".to_string())
+}
+
+pub fn format_relative_with(
+    timestamp: i64,
+    now: i64,
+    locale: TimeLocale,
+    offset_at: impl Fn(i64) -> i32,
+) -> String {
+    let diff = now.saturating_sub(timestamp).max(0) as u64;
+    locale
+        .relative(diff)
+        .unwrap_or_else(|| format_datetime_with(timestamp, locale, offset_at))
+}
+
+/// 相对时间："刚刚"、"5分钟前"、"3小时前"，超过一天显示本地日期时间
+pub fn format_relative(timestamp: i64, lang: &str) -> String {
+    format_relative_with(
+        timestamp,
+        now_unix() as i64,
+        TimeLocale::from_language(lang),
+        local_offset_secs,
+    )
+}
+
+/// 解析接口返回的 RFC3339 时间
+pub fn parse_rfc3339(text: &str) -> Option<i64> {
+    DateTime::parse_from_rfc3339(text.trim())
+        .ok()
+        .map(|dt| dt.timestamp())
+}
+
+/// 接口返回的 RFC3339 时间按本地时区显示；无法解析时原样显示
+pub fn format_api_datetime(text: &str, lang: &str) -> String {
+    parse_rfc3339(text)
+        .map(|ts| format_datetime(ts, lang))
+        .unwrap_or_else(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026 年美国东部时间：3 月 8 日 07:00 UTC 起为夏令时（UTC-4），此前为 UTC-5
+    const DST_START: i64 = 1_772_953_200;
+
+    fn new_york(timestamp: i64) -> i32 {
+        if timestamp >= DST_START {
+            -4 * 3600
+        } else {
+            -5 * 3600
+        }
+    }
+
+    #[test]
+    fn formats_each_timestamp_with_its_own_offset_across_dst() {
+        let before = format_datetime_with(DST_START - 60, TimeLocale::Zh, new_york);
+        let after = format_datetime_with(DST_START, TimeLocale::Zh, new_york);
+        assert_eq!(before, "2026-03-08 01:59 UTC-5");
+        assert_eq!(after, "2026-03-08 03:00 UTC-4");
+        assert_eq!(
+            format_datetime_with(DST_START, TimeLocale::En, new_york),
+            "Mar 8, 2026 03:00 UTC-4"
+        );
+        assert_eq!(
+            format_datetime_with(0, TimeLocale::Zh, |_| 19_800),
+            "1970-01-01 05:30 UTC+5:30"
+        );
+    }
+
+    #[test]
+    fn relative_time_follows_locale() {
+        let now = DST_START;
+        assert_eq!(
+            format_relative_with(now - 30, now, TimeLocale::Zh, new_york),
+            "刚刚"
+        );
+        assert_eq!(
+            format_relative_with(now - 300, now, TimeLocale::En, new_york),
+            "5 min ago"
+        );
+        assert_eq!(
+            format_relative_with(now - 2 * 3600, now, TimeLocale::Ja, new_york),
+            "2時間前"
+        );
+        assert_eq!(
+            format_relative_with(now - 2 * 86_400, now, TimeLocale::Zh, new_york),
+            "2026-03-06 02:00 UTC-5"
+        );
+        // 时间戳在未来（时钟偏差）时按“刚刚”显示
+        assert_eq!(
+            format_relative_with(now + 600, now, TimeLocale::Ko, new_york),
+            "방금"
+        );
+        assert_eq!(TimeLocale::from_language("en-US"), TimeLocale::En);
+    }
+
+    #[test]
+    fn countdown_ignores_system_clock_changes() {
+        let countdown = Countdown::start_at(30, 1_000.0);
+        assert_eq!(countdown.remaining_at(1_000.0), 30);
+        assert_eq!(countdown.remaining_at(11_500.0), 20);
+        assert_eq!(countdown.remaining_at(100_000.0), 0);
+        // 剩余时间只取决于单调时钟读数，系统时间被调快或调慢都不影响
+        assert_eq!(countdown.remaining_at(0.0), 30);
+        assert_eq!(parse_rfc3339("2026-03-08T07:00:00Z"), Some(DST_START));
+    }
+}
@@ -3,6 +3,7 @@
 //! 目标：避免把底层 `ApiError` / HTTP 状态 / 反序列化错误等直接展示给用户，
 //! 统一输出更清晰、更可操作的提示。
 
+use crate::shared::kill_switch::MAINTENANCE_MARKER;
 use std::borrow::Cow;
 
 /// 将错误消息转换为更适合展示给用户的提示。
@@ -11,6 +12,9 @@ use std::borrow::Cow;
 /// - 如果消息本身已经是可读的中文提示（例如“复制失败，请手动复制”），则保持原样。
 pub fn sanitize_user_message(message: impl Into<String>) -> String {
     let message: String = message.into();
+    if let Some(notice) = maintenance_notice(&message) {
+        return notice.to_string();
+    }
     let lower = message.to_lowercase();
 
     // 先快速判断：如果不包含任何“看起来像底层错误”的关键字，就不动它。
@@ -21,22 +25,15 @@ pub fn sanitize_user_message(message: impl Into<String>) -> String {
     // 尝试从消息中提取“业务上下文前缀”，例如："导入失败: ..." -> "导入失败"
     let (prefix, has_prefix) = extract_prefix(&message);
 
-    let friendly = if is_auth_error(&lower) {
-        "认证失败，请先登录或重新登录"
-    } else if is_timeout_error(&lower) {
-        "请求超时，请稍后再试"
-    } else if is_rate_limit_error(&lower) {
-        "请求过于频繁，请稍后再试"
-    } else if is_network_error(&lower) {
-        "网络异常，请检查网络后重试"
-    } else if is_server_error(&lower) {
-        "服务暂时不可用，请稍后再试"
-    } else if is_client_input_error(&lower) {
-        "输入参数有误，请检查后重试"
-    } else if is_deserialize_error(&lower) {
-        "服务响应异常，请稍后再试"
-    } else {
-        "操作失败，请稍后再试"
+    let friendly = match classify_lower(&lower) {
+        ErrorClass::Auth => "认证失败，请先登录或重新登录",
+        ErrorClass::Timeout => "请求超时，请稍后再试",
+        ErrorClass::RateLimited => "请求过于频繁，请稍后再试",
+        ErrorClass::ServiceUnavailable | ErrorClass::Maintenance => "服务暂时不可用，请稍后再试",
+        ErrorClass::Network => "网络异常，请检查网络后重试",
+        ErrorClass::InvalidInput => "输入参数有误，请检查后重试",
+        ErrorClass::BadResponse => "服务响应异常，请稍后再试",
+        ErrorClass::Other => "操作失败，请稍后再试",
     };
 
     if has_prefix {
@@ -46,6 +43,57 @@ pub fn sanitize_user_message(message: impl Into<String>) -> String {
     }
 }
 
+/// 错误分类（用于选择提示文案，以及服务不可用时引导用户查看服务状态页）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    Auth,
+    Timeout,
+    RateLimited,
+    /// 后端或上游服务不可用（5xx）
+    ServiceUnavailable,
+    /// 紧急开关暂停了资金操作（见 [`crate::shared::kill_switch`]）
+    Maintenance,
+    Network,
+    InvalidInput,
+    BadResponse,
+    Other,
+}
+
+/// 对错误消息分类
+pub fn classify(message: &str) -> ErrorClass {
+    classify_lower(&message.to_lowercase())
+}
+
+fn classify_lower(lower: &str) -> ErrorClass {
+    if lower.contains(MAINTENANCE_MARKER) {
+        ErrorClass::Maintenance
+    } else if is_auth_error(lower) {
+        ErrorClass::Auth
+    } else if is_timeout_error(lower) {
+        ErrorClass::Timeout
+    } else if is_rate_limit_error(lower) {
+        ErrorClass::RateLimited
+    } else if is_server_error(lower) {
+        // 先于网络错误判断："Response error: 503 ..." 属于服务不可用而非网络问题
+        ErrorClass::ServiceUnavailable
+    } else if is_network_error(lower) {
+        ErrorClass::Network
+    } else if is_client_input_error(lower) {
+        ErrorClass::InvalidInput
+    } else if is_deserialize_error(lower) {
+        ErrorClass::BadResponse
+    } else {
+        ErrorClass::Other
+    }
+}
+
+/// 维护提示本身（去掉“交换失败: Validation error: ”等前缀）
+pub fn maintenance_notice(message: &str) -> Option<&str> {
+    message
+        .find(MAINTENANCE_MARKER)
+        .map(|idx| message[idx..].trim())
+}
+
 fn looks_like_raw_error(lower: &str) -> bool {
     // ApiError / HTTP / 底层库常见特征
     lower.contains("api error")
@@ -125,6 +173,9 @@ fn is_server_error(lower: &str) -> bool {
         || lower.contains("503")
         || lower.contains("504")
         || lower.contains("internal server error")
+        || lower.contains("service unavailable")
+        || lower.contains("bad gateway")
+        || lower.contains("服务暂时不可用")
 }
 
 fn is_client_input_error(lower: &str) -> bool {
@@ -141,3 +192,39 @@ fn is_deserialize_error(lower: &str) -> bool {
         || lower.contains("serde")
         || lower.contains("json")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_errors_classify_as_service_unavailable() {
+        assert_eq!(
+            classify("Response error: 503 - upstream down"),
+            ErrorClass::ServiceUnavailable
+        );
+        assert_eq!(
+            classify("服务暂时不可用，请稍后再试"),
+            ErrorClass::ServiceUnavailable
+        );
+        assert_eq!(
+            classify("Request failed: Failed to fetch"),
+            ErrorClass::Network
+        );
+        assert_eq!(classify("Timeout"), ErrorClass::Timeout);
+        assert_eq!(
+            sanitize_user_message("加载失败: Response error: 502 - bad gateway"),
+            "加载失败：服务暂时不可用，请稍后再试"
+        );
+    }
+
+    #[test]
+    fn maintenance_notices_are_kept_verbatim() {
+        let raw = "交换失败: Validation error: 系统维护中：兑换功能暂时停用，资产安全不受影响，请稍后再试";
+        assert_eq!(classify(raw), ErrorClass::Maintenance);
+        assert_eq!(
+            sanitize_user_message(raw),
+            "系统维护中：兑换功能暂时停用，资产安全不受影响，请稍后再试"
+        );
+    }
+}
@@ -92,31 +92,6 @@ pub fn validate_slippage(input: &str) -> Result<f64, String> {
     Ok(slippage)
 }
 
-/// 验证地址格式
-pub fn validate_address(address: &str, chain_type: &str) -> Result<(), String> {
-    if address.is_empty() {
-        return Err("请输入地址".to_string());
-    }
-    
-    match chain_type {
-        "ethereum" | "bsc" | "polygon" => {
-            if !address.starts_with("0x") || address.len() != 42 {
-                return Err("请输入有效的以太坊地址（0x开头，42字符）".to_string());
-            }
-        }
-        "bitcoin" => {
-            if address.len() < 26 || address.len() > 62 {
-                return Err("请输入有效的比特币地址".to_string());
-            }
-        }
-        _ => {
-            // 其他链的验证可以在这里添加
-        }
-    }
-    
-    Ok(())
-}
-
 /// 限制输入长度
 pub fn limit_input_length(input: &str, max_length: usize) -> String {
     if input.len() > max_length {
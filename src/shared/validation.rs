@@ -1,118 +1,782 @@
+use crate::services::address_detector::ChainType;
+use crate::services::address_validation::validate_address;
 use anyhow::{anyhow, Result};
-use sha3::{Digest, Keccak256};
+use dioxus::prelude::*;
+use primitive_types::U256;
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
 
-pub fn validate_eth_address(address: &str) -> Result<()> {
-    if !address.starts_with("0x") {
-        return Err(anyhow!("Ethereum address must start with 0x"));
+/// 代币数量（链上最小单位整数 + 精度）
+///
+/// 金额的解析、比较和百分比运算都在整数上完成，避免 f64 误差
+/// （如 0.1 + 0.2 = 0.30000000000000004），只在渲染时格式化。
+/// 不同精度的数量按数值比较。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenAmount {
+    raw: U256,
+    decimals: u8,
+}
+
+impl TokenAmount {
+    pub fn new(raw: U256, decimals: u8) -> Self {
+        Self { raw, decimals }
     }
-    if address.len() != 42 {
-        return Err(anyhow!("Ethereum address must be 42 characters long"));
+
+    pub fn zero(decimals: u8) -> Self {
+        Self::new(U256::zero(), decimals)
     }
-    let hex_part = &address[2..];
-    if hex::decode(hex_part).is_err() {
-        return Err(anyhow!("Invalid hex characters"));
+
+    /// 解析最小单位字符串（十进制或 0x 十六进制）
+    pub fn from_raw_str(raw: &str, decimals: u8) -> Option<Self> {
+        let raw = raw.trim();
+        let value = match raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+            Some("") => U256::zero(),
+            Some(hex) => U256::from_str_radix(hex, 16).ok()?,
+            None if raw.is_empty() => U256::zero(),
+            None => U256::from_dec_str(raw).ok()?,
+        };
+        Some(Self::new(value, decimals))
     }
 
-    // EIP-55 Checksum
-    let address_lower = address.to_lowercase();
-    if address == address_lower || address == address.to_uppercase() {
-        // All lower or all upper is valid (non-checksummed)
-        return Ok(());
+    /// 解析已格式化的十进制字符串（如 "1.5"），超出精度的小数位截断
+    pub fn from_units(value: &str, decimals: u8) -> Option<Self> {
+        let value = value.trim();
+        let (int_part, frac_part) = value.split_once('.').unwrap_or((value, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+        if !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let mut frac: String = frac_part.chars().take(decimals as usize).collect();
+        while frac.len() < decimals as usize {
+            frac.push('0');
+        }
+        let digits = format!("{}{}", int_part, frac);
+        let digits = digits.trim_start_matches('0');
+        let raw = if digits.is_empty() {
+            U256::zero()
+        } else {
+            U256::from_dec_str(digits).ok()?
+        };
+        Some(Self::new(raw, decimals))
     }
 
-    // Mixed case: must match checksum
-    let hash = Keccak256::digest(&address_lower.as_bytes()[2..]);
-    let hash_hex = hex::encode(hash);
+    /// 解析用户输入的金额
+    ///
+    /// 容忍常见的本地化写法：全角数字、空格/下划线分组、千分位逗号（1,234.5），
+    /// 以及以逗号作小数点（1.234,5 或 0,5）。小数位超过代币精度时报错而不是截断。
+    pub fn parse_input(input: &str, decimals: u8) -> std::result::Result<Self, String> {
+        let normalized = normalize_amount_chars(input);
+        if normalized.is_empty() {
+            return Err("请输入金额".to_string());
+        }
 
-    for (i, char) in address[2..].chars().enumerate() {
-        let hash_char = hash_hex.chars().nth(i).unwrap();
-        let hash_val = u8::from_str_radix(&hash_char.to_string(), 16).unwrap();
+        let separator = decimal_separator(&normalized);
+        let cleaned: String = normalized
+            .chars()
+            .filter_map(|c| {
+                if Some(c) == separator {
+                    Some('.')
+                } else if c == ',' || c == '.' {
+                    None
+                } else {
+                    Some(c)
+                }
+            })
+            .collect();
 
-        if hash_val >= 8 {
-            if char.is_ascii_lowercase() {
-                return Err(anyhow!(
-                    "Invalid checksum: expected uppercase at index {}",
-                    i
-                ));
-            }
-        } else if char.is_ascii_uppercase() {
-            return Err(anyhow!(
-                "Invalid checksum: expected lowercase at index {}",
-                i
-            ));
+        let frac_len = cleaned.split_once('.').map_or(0, |(_, f)| f.len());
+        if frac_len > decimals as usize {
+            return Err(format!("最多支持 {} 位小数", decimals));
         }
+        Self::from_units(&cleaned, decimals).ok_or_else(|| "金额格式无效".to_string())
     }
 
-    Ok(())
-}
+    pub fn raw(&self) -> U256 {
+        self.raw
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.raw.is_zero()
+    }
 
-pub fn validate_btc_address(address: &str) -> Result<()> {
-    if address.starts_with("1") || address.starts_with("3") {
-        // Legacy / Nested Segwit (Base58)
-        let decoded = bs58::decode(address).into_vec();
-        if decoded.is_err() {
-            return Err(anyhow!("Invalid Base58 characters"));
+    /// 相同精度的数量相加（溢出时返回 None）
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if self.decimals != other.decimals {
+            return None;
         }
-        let bytes = decoded.unwrap();
-        if bytes.len() != 25 {
-            return Err(anyhow!("Invalid Bitcoin address length"));
+        Some(Self::new(self.raw.checked_add(other.raw)?, self.decimals))
+    }
+
+    /// 相同精度的数量相减（不足时返回 None）
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self.decimals != other.decimals {
+            return None;
         }
-        // Checksum validation omitted for brevity, but length/base58 is good start
-    } else if address.starts_with("bc1") {
-        // Native Segwit (Bech32)
-        // Basic char check
-        if address.len() > 90 {
-            return Err(anyhow!("Invalid Bitcoin address length"));
+        Some(Self::new(self.raw.checked_sub(other.raw)?, self.decimals))
+    }
+
+    /// 按百分比取值（向下取整），用于 50% / 最大 按钮
+    pub fn percent(&self, percent: u32) -> Self {
+        Self::new(mul_div_floor(self.raw, percent, 100), self.decimals)
+    }
+
+    /// 扣除滑点后的最少收到数量（向下取整）
+    pub fn apply_slippage_bps(&self, bps: u32) -> Self {
+        let kept = 10_000u32.saturating_sub(bps);
+        Self::new(mul_div_floor(self.raw, kept, 10_000), self.decimals)
+    }
+
+    /// API 使用的最小单位十进制字符串（如 wei）
+    pub fn to_raw_string(self) -> String {
+        self.raw.to_string()
+    }
+
+    /// 格式化为十进制字符串，最多保留 `max_fraction_digits` 位小数（向下截断，去掉末尾 0）
+    pub fn format(&self, max_fraction_digits: usize) -> String {
+        let decimals = self.decimals as usize;
+        let divisor = U256::exp10(decimals);
+        let int_part = self.raw / divisor;
+        let frac_part = self.raw % divisor;
+
+        let mut frac = format!("{:0>width$}", frac_part.to_string(), width = decimals);
+        frac.truncate(max_fraction_digits.min(decimals));
+        let frac = frac.trim_end_matches('0');
+        if frac.is_empty() {
+            int_part.to_string()
+        } else {
+            format!("{}.{}", int_part, frac)
         }
-    } else {
-        return Err(anyhow!("Unknown Bitcoin address format"));
     }
-    Ok(())
+
+    /// 近似浮点值（仅用于法币估值等展示，不可用于校验）
+    pub fn to_f64_lossy(self) -> f64 {
+        self.format(self.decimals as usize).parse().unwrap_or(0.0)
+    }
+}
+
+/// 统一全角数字与标点，去掉空白、下划线、撇号等分组字符
+fn normalize_amount_chars(input: &str) -> String {
+    input
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32),
+            '．' | '。' => Some('.'),
+            '，' => Some(','),
+            ' ' | '_' | '\'' | '\u{00a0}' | '\u{202f}' => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+/// 展开科学计数法（如 "1e18"、"2.5E-3"），格式不合法时返回 None
+fn expand_exponent(value: &str) -> Option<String> {
+    let (mantissa, exponent) = value.split_once(['e', 'E'])?;
+    let exponent: i32 = exponent.parse().ok().filter(|e: &i32| e.abs() <= 77)?;
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", int_part, frac_part);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let point = int_part.len() as i32 + exponent;
+    Some(if point >= digits.len() as i32 {
+        format!(
+            "{}{}",
+            digits,
+            "0".repeat((point - digits.len() as i32) as usize)
+        )
+    } else if point <= 0 {
+        format!("0.{}{}", "0".repeat((-point) as usize), digits)
+    } else {
+        let (int_digits, frac_digits) = digits.split_at(point as usize);
+        format!("{}.{}", int_digits, frac_digits)
+    })
 }
 
-pub fn validate_sol_address(address: &str) -> Result<()> {
-    let decoded = bs58::decode(address).into_vec();
-    match decoded {
-        Ok(bytes) => {
-            if bytes.len() != 32 {
-                return Err(anyhow!("Invalid Solana address length"));
+/// 清洗金额输入框内容（键入与粘贴）
+///
+/// 去掉千分位与非数字字符、展开科学计数法，并把小数位截断到代币精度。
+/// 与 `TokenAmount::parse_input` 不同，保留 "1." 这类输入中的中间状态。
+pub fn sanitize_amount_input(input: &str, decimals: u8) -> String {
+    let normalized = normalize_amount_chars(input);
+    let expanded = expand_exponent(&normalized).unwrap_or(normalized);
+    let separator = decimal_separator(&expanded);
+
+    let mut int_part = String::new();
+    let mut frac_part: Option<String> = None;
+    for c in expanded.chars() {
+        if Some(c) == separator && frac_part.is_none() {
+            frac_part = Some(String::new());
+        } else if c.is_ascii_digit() {
+            match frac_part.as_mut() {
+                Some(frac) => frac.push(c),
+                None => int_part.push(c),
             }
-            Ok(())
         }
-        Err(_) => Err(anyhow!("Invalid Base58 characters")),
+    }
+
+    let int_part = match int_part.trim_start_matches('0') {
+        "" if int_part.is_empty() && frac_part.is_none() => String::new(),
+        "" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    };
+    match frac_part {
+        Some(mut frac) if decimals > 0 => {
+            frac.truncate(decimals as usize);
+            format!("{}.{}", int_part, frac)
+        }
+        _ => int_part,
+    }
+}
+
+/// 判断输入中的小数点字符（其余的 `.`/`,` 视为千分位分组）
+fn decimal_separator(value: &str) -> Option<char> {
+    match (value.rfind('.'), value.rfind(',')) {
+        (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+        (Some(_), None) => (value.matches('.').count() == 1).then_some('.'),
+        (None, Some(comma)) => {
+            // "1,234" 视为千分位；"0,5" / "1,25" 视为小数
+            let int_part = &value[..comma];
+            let frac_len = value.len() - comma - 1;
+            let single = value.matches(',').count() == 1;
+            (single && (frac_len != 3 || int_part.trim_start_matches('0').is_empty()))
+                .then_some(',')
+        }
+        (None, None) => None,
     }
 }
 
-pub fn validate_ton_address(address: &str) -> Result<()> {
-    // TON addresses can be in two formats:
-    // 1. Raw format: 48 hex characters (24 bytes)
-    // 2. User-friendly format: starts with "EQ" or "UQ" followed by Base64-like encoding
+/// floor(value * numerator / denominator)，拆分计算避免中间结果溢出
+fn mul_div_floor(value: U256, numerator: u32, denominator: u32) -> U256 {
+    let (n, d) = (U256::from(numerator), U256::from(denominator));
+    (value / d) * n + (value % d) * n / d
+}
+
+/// 滑点百分比（如 0.5 表示 0.5%）转换为基点，超出 0~100% 时返回 None
+pub fn slippage_percent_to_bps(percent: f64) -> Option<u32> {
+    (percent.is_finite() && (0.0..=100.0).contains(&percent))
+        .then(|| (percent * 100.0).round() as u32)
+}
 
-    let trimmed = address.trim();
+/// 解析十六进制或十进制数量（如 RPC 返回的 gas、gasPrice）
+pub fn parse_quantity(value: &str) -> Option<u64> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
 
-    // Check for user-friendly format (EQ/UQ prefix)
-    if trimmed.starts_with("EQ") || trimmed.starts_with("UQ") {
-        // User-friendly format: should be 48 characters after prefix
-        if trimmed.len() < 48 || trimmed.len() > 50 {
-            return Err(anyhow!("Invalid TON address length"));
+impl TokenAmount {
+    /// 按数值比较（精度不同时放大到相同精度，溢出的一方更大）
+    fn cmp_value(&self, other: &Self) -> Ordering {
+        let scale = |raw: U256, diff: u8| raw.checked_mul(U256::exp10(diff as usize));
+        let (a, b) = match self.decimals.cmp(&other.decimals) {
+            Ordering::Equal => (Some(self.raw), Some(other.raw)),
+            Ordering::Less => (
+                scale(self.raw, other.decimals - self.decimals),
+                Some(other.raw),
+            ),
+            Ordering::Greater => (
+                Some(self.raw),
+                scale(other.raw, self.decimals - other.decimals),
+            ),
+        };
+        match (a, b) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (None, _) => Ordering::Greater,
+            (_, None) => Ordering::Less,
         }
-        // Basic validation: should contain only base64url-like characters
-        let body = &trimmed[2..];
-        if !body
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
-        {
-            return Err(anyhow!("Invalid TON address characters"));
+    }
+}
+
+impl PartialEq for TokenAmount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp_value(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TokenAmount {}
+
+impl PartialOrd for TokenAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TokenAmount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_value(other)
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(self.decimals as usize))
+    }
+}
+
+/// Luhn 校验（银行卡号，仅统计数字字符）
+pub fn luhn_valid(digits: &str) -> bool {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// 单个验证器的结果，错误为面向用户的提示
+pub type ValidationResult = std::result::Result<(), String>;
+
+/// 字段验证器
+///
+/// 除 `required` 外的验证器都跳过空值，可选字段直接组合使用即可。
+#[derive(Clone)]
+pub struct Validator(Rc<dyn Fn(&str) -> ValidationResult>);
+
+impl Validator {
+    pub fn custom(check: impl Fn(&str) -> ValidationResult + 'static) -> Self {
+        Self(Rc::new(check))
+    }
+
+    /// 非空（忽略首尾空白）
+    pub fn required(message: &str) -> Self {
+        let message = message.to_string();
+        Self::custom(move |value| {
+            if value.trim().is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// 大于 0 的金额，按代币精度精确解析
+    pub fn amount(decimals: u8) -> Self {
+        Self::custom(move |value| {
+            skip_empty(value, |v| match TokenAmount::parse_input(v, decimals)? {
+                amount if amount.is_zero() => Err("金额必须大于0".to_string()),
+                _ => Ok(()),
+            })
+        })
+    }
+
+    /// 数值闭区间（边界为十进制字符串，如 "10"）
+    pub fn range(decimals: u8, min: Option<&str>, max: Option<&str>) -> Self {
+        let bound = |b: Option<&str>| {
+            b.map(|b| (b.to_string(), TokenAmount::parse_input(b, decimals).ok()))
+        };
+        let (min, max) = (bound(min), bound(max));
+        Self::custom(move |value| {
+            skip_empty(value, |v| {
+                let amount = TokenAmount::parse_input(v, decimals)?;
+                if let Some((label, Some(min))) = &min {
+                    if amount < *min {
+                        return Err(format!("不能小于 {}", label));
+                    }
+                }
+                if let Some((label, Some(max))) = &max {
+                    if amount > *max {
+                        return Err(format!("不能大于 {}", label));
+                    }
+                }
+                Ok(())
+            })
+        })
+    }
+
+    /// 按链校验地址格式（EVM 链共用以太坊地址格式，含 EIP-55 校验和）
+    pub fn address(chain: &str) -> Self {
+        let name = chain.to_lowercase();
+        let chain = ChainType::from_str(&name);
+        Self::custom(move |value| {
+            skip_empty(value, |v| {
+                let chain = chain.ok_or_else(|| format!("不支持的链: {}", name))?;
+                validate_address(chain, v)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            })
+        })
+    }
+
+    /// 邮箱格式（name@domain.tld）
+    pub fn email() -> Self {
+        Self::custom(|value| {
+            skip_empty(value, |v| {
+                let valid = v.split_once('@').is_some_and(|(name, domain)| {
+                    !name.is_empty()
+                        && !v.contains(char::is_whitespace)
+                        && !domain.contains('@')
+                        && domain
+                            .split_once('.')
+                            .is_some_and(|(host, tld)| !host.is_empty() && !tld.is_empty())
+                        && !domain.ends_with('.')
+                });
+                if valid {
+                    Ok(())
+                } else {
+                    Err("请输入有效的邮箱地址".to_string())
+                }
+            })
+        })
+    }
+
+    /// 银行卡号（12~19 位数字，允许空格/连字符分组，需通过 Luhn 校验）
+    pub fn card_number() -> Self {
+        Self::custom(|value| {
+            skip_empty(value, |v| {
+                let digits: String = v.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+                if (12..=19).contains(&digits.len())
+                    && digits.chars().all(|c| c.is_ascii_digit())
+                    && luhn_valid(&digits)
+                {
+                    Ok(())
+                } else {
+                    Err("卡号无效，请检查后重新输入".to_string())
+                }
+            })
+        })
+    }
+
+    /// 组合：先执行当前验证器，通过后再执行 `next`
+    pub fn and(self, next: Validator) -> Self {
+        Self::custom(move |value| {
+            self.validate(value)?;
+            next.validate(value)
+        })
+    }
+
+    /// 验证失败时统一替换为指定的提示
+    pub fn with_message(self, message: &str) -> Self {
+        let message = message.to_string();
+        Self::custom(move |value| self.validate(value).map_err(|_| message.clone()))
+    }
+
+    pub fn validate(&self, value: &str) -> ValidationResult {
+        (self.0)(value)
+    }
+}
+
+fn skip_empty(value: &str, check: impl FnOnce(&str) -> ValidationResult) -> ValidationResult {
+    let value = value.trim();
+    if value.is_empty() {
+        Ok(())
+    } else {
+        check(value)
+    }
+}
+
+/// 依次执行验证器，返回第一个错误
+pub fn validate_all(value: &str, validators: &[Validator]) -> ValidationResult {
+    validators.iter().try_for_each(|v| v.validate(value))
+}
+
+/// 带验证状态的表单字段
+///
+/// `value` 直接暴露给输入框绑定；错误随值和规则实时计算，
+/// 但只在字段失焦（touched）后展示，避免用户尚未输入时满屏报错。
+#[derive(Clone, Copy, PartialEq)]
+pub struct ValidatedField {
+    pub value: Signal<String>,
+    initial: Signal<String>,
+    touched: Signal<bool>,
+    rules: Signal<Vec<Validator>>,
+    error: Memo<Option<String>>,
+}
+
+impl ValidatedField {
+    pub fn get(&self) -> String {
+        self.value.read().clone()
+    }
+
+    pub fn set(&mut self, value: impl Into<String>) {
+        self.value.set(value.into());
+    }
+
+    /// 标记为已触碰（失焦或提交时调用）
+    pub fn touch(&mut self) {
+        if !*self.touched.peek() {
+            self.touched.set(true);
         }
-        return Ok(());
     }
 
-    // Check for raw hex format
-    if trimmed.len() == 48 && trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
-        return Ok(());
+    /// 替换验证规则（规则依赖代币精度、链或提现方式时在 effect 中更新）
+    pub fn set_rules(&mut self, rules: Vec<Validator>) {
+        self.rules.set(rules);
+    }
+
+    /// 恢复初始值并清除触碰状态（如提交成功后清空表单）
+    pub fn reset(&mut self) {
+        let initial = self.initial.peek().clone();
+        self.value.set(initial);
+        self.touched.set(false);
+    }
+
+    pub fn is_touched(&self) -> bool {
+        *self.touched.read()
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        *self.value.read() != *self.initial.read()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.error.read().is_none()
+    }
+
+    /// 当前错误（无论是否已触碰）
+    pub fn error(&self) -> Option<String> {
+        self.error.read().clone()
     }
 
-    Err(anyhow!(
-        "Invalid TON address format. Expected format: EQ... or UQ... or 48 hex characters"
-    ))
+    /// 需要展示在输入框下方的错误
+    pub fn visible_error(&self) -> Option<String> {
+        if self.is_touched() {
+            self.error()
+        } else {
+            None
+        }
+    }
+}
+
+/// 创建带验证状态的表单字段
+pub fn use_validated_field(rules: impl FnOnce() -> Vec<Validator>) -> ValidatedField {
+    let value = use_signal(String::new);
+    let initial = use_signal(String::new);
+    let touched = use_signal(|| false);
+    let rules = use_signal(rules);
+    let error = use_memo(move || validate_all(&value.read(), &rules.read()).err());
+    ValidatedField {
+        value,
+        initial,
+        touched,
+        rules,
+        error,
+    }
+}
+
+/// 表单整体是否有效（用于提交按钮的禁用状态）
+pub fn all_valid(fields: &[ValidatedField]) -> bool {
+    fields.iter().all(ValidatedField::is_valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(raw: &str, decimals: u8) -> TokenAmount {
+        TokenAmount::from_raw_str(raw, decimals).unwrap()
+    }
+
+    #[test]
+    fn formats_tokens_with_common_decimals() {
+        assert_eq!(amount("42", 0).format(6), "42");
+        assert_eq!(amount("1234567", 6).format(6), "1.234567");
+        assert_eq!(amount("1234567", 6).format(2), "1.23");
+        assert_eq!(amount("100000000", 8).format(8), "1");
+        assert_eq!(amount("12345", 8).format(8), "0.00012345");
+        assert_eq!(
+            amount("1000000000000000001", 18).to_string(),
+            "1.000000000000000001"
+        );
+        assert_eq!(amount("1000000000000000001", 18).format(6), "1");
+        assert_eq!(amount("0x0de0b6b3a7640000", 18).format(18), "1");
+        assert_eq!(amount("", 18), TokenAmount::zero(18));
+        assert!(TokenAmount::from_raw_str("1.5", 18).is_none());
+    }
+
+    #[test]
+    fn handles_amounts_beyond_u64() {
+        // u64::MAX + 1
+        let big = amount("18446744073709551616", 18);
+        assert_eq!(big.format(18), "18.446744073709551616");
+        let sum = big.checked_add(&big).unwrap();
+        assert_eq!(sum.raw(), U256::from(u64::MAX) * 2 + 2);
+        assert!(big.checked_add(&amount("1", 6)).is_none());
+        assert!(TokenAmount::new(U256::MAX, 18)
+            .checked_add(&amount("1", 18))
+            .is_none());
+    }
+
+    #[test]
+    fn parses_formatted_units_exactly() {
+        assert_eq!(
+            TokenAmount::from_units("0.1", 18).unwrap(),
+            amount("100000000000000000", 18)
+        );
+        assert_eq!(
+            TokenAmount::from_units("1.23456789", 6).unwrap(),
+            amount("1234567", 6)
+        );
+        assert_eq!(TokenAmount::from_units("5", 0).unwrap(), amount("5", 0));
+        assert!(TokenAmount::from_units("abc", 6).is_none());
+        assert!(TokenAmount::from_units("-1", 6).is_none());
+    }
+
+    #[test]
+    fn parses_locale_tolerant_input() {
+        let parse = |s: &str| TokenAmount::parse_input(s, 6).map(|a| a.to_raw_string());
+        assert_eq!(parse("1.5"), Ok("1500000".to_string()));
+        assert_eq!(parse(" 1,234.5 "), Ok("1234500000".to_string()));
+        assert_eq!(parse("1.234,5"), Ok("1234500000".to_string()));
+        assert_eq!(parse("0,5"), Ok("500000".to_string()));
+        assert_eq!(parse("1,25"), Ok("1250000".to_string()));
+        assert_eq!(parse("1,000"), Ok("1000000000".to_string()));
+        assert_eq!(parse("1 000 000"), Ok("1000000000000".to_string()));
+        assert_eq!(parse("１２．５"), Ok("12500000".to_string()));
+        assert_eq!(parse(".5"), Ok("500000".to_string()));
+        assert!(parse("").is_err());
+        assert!(parse("-1").is_err());
+        assert!(parse("1e5").is_err());
+        assert_eq!(parse("0.1234567"), Err("最多支持 6 位小数".to_string()));
+    }
+
+    #[test]
+    fn decimal_math_has_no_float_drift() {
+        let a = TokenAmount::parse_input("0.1", 18).unwrap();
+        let b = TokenAmount::parse_input("0.2", 18).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().to_string(), "0.3");
+        assert_eq!(
+            a.checked_add(&b).unwrap(),
+            TokenAmount::parse_input("0.3", 18).unwrap()
+        );
+        assert!(b.checked_sub(&a.checked_add(&b).unwrap()).is_none());
+
+        // 50% 与最大：奇数最小单位向下取整，不会超过余额
+        let balance = amount("3", 6);
+        assert_eq!(balance.percent(50).to_raw_string(), "1");
+        assert_eq!(balance.percent(100), balance);
+        let big = TokenAmount::new(U256::MAX, 18);
+        assert!(big.percent(50) < big);
+
+        // 不同精度按数值比较
+        assert_eq!(amount("1000000", 6), amount("1000000000000000000", 18));
+        assert!(amount("1000001", 6) > amount("1000000000000000000", 18));
+        assert!(TokenAmount::new(U256::MAX, 0) > amount("1", 18));
+    }
+
+    #[test]
+    fn slippage_rounds_minimum_received_down() {
+        assert_eq!(slippage_percent_to_bps(0.5), Some(50));
+        assert_eq!(slippage_percent_to_bps(0.1 + 0.2), Some(30));
+        assert_eq!(slippage_percent_to_bps(101.0), None);
+        assert_eq!(slippage_percent_to_bps(f64::NAN), None);
+
+        let quoted = amount("1999", 6);
+        assert_eq!(quoted.apply_slippage_bps(50).to_raw_string(), "1989");
+        assert_eq!(quoted.apply_slippage_bps(0), quoted);
+        assert!(quoted.apply_slippage_bps(10_000).is_zero());
+    }
+
+    #[test]
+    fn parse_quantity_accepts_hex_and_decimal() {
+        assert_eq!(parse_quantity("0x5208"), Some(21000));
+        assert_eq!(parse_quantity("21000"), Some(21000));
+        assert_eq!(parse_quantity("0xzz"), None);
+    }
+
+    #[test]
+    fn validators_compose_and_skip_empty_values() {
+        let amount_rules = vec![
+            Validator::required("请输入金额"),
+            Validator::amount(2),
+            Validator::range(2, Some("10"), Some("1000")).with_message("金额需在 10~1000 之间"),
+        ];
+        assert_eq!(
+            validate_all("", &amount_rules),
+            Err("请输入金额".to_string())
+        );
+        assert_eq!(
+            validate_all("0", &amount_rules),
+            Err("金额必须大于0".to_string())
+        );
+        assert_eq!(
+            validate_all("abc", &amount_rules),
+            Err("金额格式无效".to_string())
+        );
+        assert_eq!(
+            validate_all("1.005", &amount_rules),
+            Err("最多支持 2 位小数".to_string())
+        );
+        assert!(validate_all("9.99", &amount_rules).is_err());
+        assert!(validate_all("10", &amount_rules).is_ok());
+        assert!(validate_all("1,000", &amount_rules).is_ok());
+        assert!(validate_all("1000.01", &amount_rules).is_err());
+
+        // 非 required 验证器放行空值
+        assert!(Validator::email().validate("  ").is_ok());
+        let chained = Validator::required("必填").and(Validator::email());
+        assert_eq!(chained.validate(""), Err("必填".to_string()));
+        assert!(chained.validate("a@b").is_err());
+    }
+
+    #[test]
+    fn format_validators() {
+        let eth = Validator::address("bsc");
+        assert!(eth
+            .validate("0x742d35cc6634c0532925a3b844bc9e7595f0beb6")
+            .is_ok());
+        assert!(eth.validate("0x742d35cc").is_err());
+        assert!(Validator::address("dogecoin").validate("D8x").is_err());
+
+        let email = Validator::email();
+        assert!(email.validate("user@example.com").is_ok());
+        for bad in [
+            "user@",
+            "@example.com",
+            "user@example",
+            "a b@example.com",
+            "a@b@c.com",
+        ] {
+            assert!(email.validate(bad).is_err(), "{bad}");
+        }
+
+        let card = Validator::card_number();
+        assert!(card.validate("4242 4242 4242 4242").is_ok());
+        assert!(card.validate("4242-4242-4242-4241").is_err());
+        assert!(card.validate("4242").is_err());
+    }
+
+    #[test]
+    fn sanitizes_typed_and_pasted_amounts() {
+        assert_eq!(sanitize_amount_input("1,234,567.89", 6), "1234567.89");
+        assert_eq!(sanitize_amount_input(" 1 000,5 ", 6), "1000.5");
+        assert_eq!(sanitize_amount_input("1e18", 18), "1000000000000000000");
+        assert_eq!(sanitize_amount_input("2.5E-3", 18), "0.0025");
+        assert_eq!(sanitize_amount_input("1.23456789", 6), "1.234567");
+        assert_eq!(sanitize_amount_input("12.5", 0), "12");
+        assert_eq!(sanitize_amount_input("$00.50 USDT", 6), "0.50");
+
+        // 键入中间状态保持不变
+        assert_eq!(sanitize_amount_input("", 6), "");
+        assert_eq!(sanitize_amount_input("0", 6), "0");
+        assert_eq!(sanitize_amount_input("1.", 6), "1.");
+        assert_eq!(sanitize_amount_input(".", 6), "0.");
+        assert_eq!(sanitize_amount_input("1e", 6), "1");
+    }
 }
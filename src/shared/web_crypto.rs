@@ -0,0 +1,152 @@
+//! WebCrypto / IndexedDB 调用（通过 Reflect，无需额外的 web-sys 特性）
+//!
+//! 不可导出的 `CryptoKey` 对象可直接存入 IndexedDB，密钥材料永远不离开浏览器。
+
+use js_sys::{Array, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+pub fn js_error(value: JsValue) -> String {
+    value
+        .as_string()
+        .or_else(|| {
+            Reflect::get(&value, &JsValue::from_str("message"))
+                .ok()
+                .and_then(|m| m.as_string())
+        })
+        .unwrap_or_else(|| format!("{:?}", value))
+}
+
+pub fn get(target: &JsValue, key: &str) -> Result<JsValue, String> {
+    Reflect::get(target, &JsValue::from_str(key)).map_err(js_error)
+}
+
+pub fn set(target: &JsValue, key: &str, value: &JsValue) -> Result<(), String> {
+    Reflect::set(target, &JsValue::from_str(key), value)
+        .map(|_| ())
+        .map_err(js_error)
+}
+
+pub fn call(target: &JsValue, method: &str, args: &[JsValue]) -> Result<JsValue, String> {
+    let function: js_sys::Function = get(target, method)?
+        .dyn_into()
+        .map_err(|_| format!("{} 不可用", method))?;
+    let args: Array = args.iter().collect();
+    Reflect::apply(&function, target, &args).map_err(js_error)
+}
+
+pub async fn resolve(promise: JsValue) -> Result<JsValue, String> {
+    let promise: Promise = promise
+        .dyn_into()
+        .map_err(|_| "预期返回 Promise".to_string())?;
+    JsFuture::from(promise).await.map_err(js_error)
+}
+
+pub fn subtle() -> Result<JsValue, String> {
+    let subtle = get(&js_sys::global(), "crypto").and_then(|crypto| get(&crypto, "subtle"))?;
+    if subtle.is_undefined() {
+        return Err("当前环境不支持 WebCrypto（需要 HTTPS）".to_string());
+    }
+    Ok(subtle)
+}
+
+/// 将 IDBRequest 包装为 Promise
+fn request_promise(request: &JsValue) -> Promise {
+    Promise::new(&mut |resolve, reject| {
+        let on_success_request = request.clone();
+        let on_success = Closure::once_into_js(move |_event: JsValue| {
+            let result = get(&on_success_request, "result").unwrap_or(JsValue::UNDEFINED);
+            let _ = resolve.call1(&JsValue::NULL, &result);
+        });
+        let on_error_request = request.clone();
+        let on_error = Closure::once_into_js(move |_event: JsValue| {
+            let error = get(&on_error_request, "error").unwrap_or(JsValue::UNDEFINED);
+            let _ = reject.call1(&JsValue::NULL, &error);
+        });
+        let _ = set(request, "onsuccess", &on_success);
+        let _ = set(request, "onerror", &on_error);
+    })
+}
+
+/// IndexedDB 中的密钥仓库（一个数据库只含一个对象仓库，值为 `CryptoKey` 等可结构化克隆的对象）
+pub struct KeyStore {
+    pub db_name: &'static str,
+    pub store: &'static str,
+}
+
+impl KeyStore {
+    const DB_VERSION: f64 = 1.0;
+
+    async fn open_db(&self) -> Result<JsValue, String> {
+        let factory = get(&js_sys::global(), "indexedDB")?;
+        if factory.is_undefined() || factory.is_null() {
+            return Err("当前浏览器不支持 IndexedDB".to_string());
+        }
+        let request = call(
+            &factory,
+            "open",
+            &[
+                JsValue::from_str(self.db_name),
+                JsValue::from_f64(Self::DB_VERSION),
+            ],
+        )?;
+        let upgrade_request = request.clone();
+        let store = self.store;
+        let on_upgrade = Closure::once_into_js(move |_event: JsValue| {
+            if let Ok(db) = get(&upgrade_request, "result") {
+                let _ = call(&db, "createObjectStore", &[JsValue::from_str(store)]);
+            }
+        });
+        set(&request, "onupgradeneeded", &on_upgrade)?;
+        JsFuture::from(request_promise(&request))
+            .await
+            .map_err(js_error)
+    }
+
+    /// 在密钥仓库上执行一次请求
+    async fn request(
+        &self,
+        mode: &str,
+        op: impl FnOnce(&JsValue) -> Result<JsValue, String>,
+    ) -> Result<JsValue, String> {
+        let db = self.open_db().await?;
+        let tx = call(
+            &db,
+            "transaction",
+            &[JsValue::from_str(self.store), JsValue::from_str(mode)],
+        )?;
+        let store = call(&tx, "objectStore", &[JsValue::from_str(self.store)])?;
+        let request = op(&store)?;
+        let result = JsFuture::from(request_promise(&request))
+            .await
+            .map_err(js_error);
+        let _ = call(&db, "close", &[]);
+        result
+    }
+
+    pub async fn load(&self, id: &str) -> Result<Option<JsValue>, String> {
+        let value = self
+            .request("readonly", |store| {
+                call(store, "get", &[JsValue::from_str(id)])
+            })
+            .await?;
+        Ok((!value.is_undefined() && !value.is_null()).then_some(value))
+    }
+
+    pub async fn store(&self, id: &str, value: &JsValue) -> Result<(), String> {
+        self.request("readwrite", |store| {
+            call(store, "put", &[value.clone(), JsValue::from_str(id)])
+        })
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), String> {
+        self.request("readwrite", |store| {
+            call(store, "delete", &[JsValue::from_str(id)])
+        })
+        .await
+        .map(|_| ())
+    }
+}
@@ -6,6 +6,7 @@ use futures::stream::StreamExt;
 use gloo_net::websocket::{futures::WebSocket, Message};
 use gloo_timers::future::TimeoutFuture;
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
 use std::sync::Arc;
 use wasm_bindgen_futures::spawn_local;
 
@@ -48,6 +49,50 @@ pub enum WsMessage {
     Pong,
 }
 
+impl WsMessage {
+    /// 是否计为用户活动（交易/余额推送说明用户正在等待结果；行情推送与心跳不算）
+    pub fn counts_as_activity(&self) -> bool {
+        matches!(
+            self,
+            WsMessage::TxUpdate { .. } | WsMessage::BalanceUpdate { .. }
+        )
+    }
+}
+
+impl ConnectionState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectionState::Disconnected => "已断开",
+            ConnectionState::Connecting => "连接中",
+            ConnectionState::Connected => "已连接",
+            ConnectionState::Reconnecting => "重连中",
+            ConnectionState::Failed => "连接失败",
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ACTIVITY_SECS: Cell<u64> = const { Cell::new(0) };
+    static LAST_STATE: Cell<Option<(ConnectionState, u64)>> = const { Cell::new(None) };
+}
+
+/// 最近一次连接状态变化（状态, 时间秒）；尚未建立过连接时为 None
+pub fn last_known_state() -> Option<(ConnectionState, u64)> {
+    LAST_STATE.with(Cell::get)
+}
+
+/// 更新连接状态并记录供状态页读取
+fn set_state(state: &mut Signal<ConnectionState>, next: ConnectionState) {
+    state.set(next);
+    let now = crate::shared::time::now_unix();
+    LAST_STATE.with(|last| last.set(Some((next, now))));
+}
+
+/// 最近一条计为活动的 WebSocket 消息时间（秒），没有时为 0
+pub fn last_activity_secs() -> u64 {
+    LAST_ACTIVITY_SECS.with(Cell::get)
+}
+
 pub struct WebSocketManager {
     url: String,
     auth_token: Option<String>,
@@ -93,14 +138,14 @@ impl WebSocketManager {
 
         spawn_local(async move {
             loop {
-                state.set(ConnectionState::Connecting);
-                tracing::info!("WebSocket connecting to: {}", url);
+                set_state(&mut state, ConnectionState::Connecting);
+                log::info!("WebSocket connecting to: {}", url);
 
                 match WebSocket::open(&url) {
                     Ok(ws) => {
-                        state.set(ConnectionState::Connected);
+                        set_state(&mut state, ConnectionState::Connected);
                         reconnect_attempts.set(0);
-                        tracing::info!("WebSocket connected successfully");
+                        log::info!("WebSocket connected successfully");
 
                         let (_, mut read) = ws.split();
 
@@ -115,6 +160,11 @@ impl WebSocketManager {
                                             continue;
                                         }
 
+                                        if ws_msg.counts_as_activity() {
+                                            let now = crate::shared::time::now_unix();
+                                            LAST_ACTIVITY_SECS.with(|last| last.set(now));
+                                        }
+
                                         // Update last message signal
                                         last_message.set(Some(ws_msg.clone()));
 
@@ -123,49 +173,46 @@ impl WebSocketManager {
                                             handler(ws_msg.clone());
                                         }
                                     } else {
-                                        tracing::warn!(
-                                            "Failed to parse WebSocket message: {}",
-                                            text
-                                        );
+                                        log::warn!("Failed to parse WebSocket message: {}", text);
                                     }
                                 }
                                 Ok(Message::Bytes(_)) => {
-                                    tracing::debug!("Received binary WebSocket message (ignored)");
+                                    log::debug!("Received binary WebSocket message (ignored)");
                                 }
                                 Err(e) => {
-                                    tracing::error!("WebSocket error: {:?}", e);
+                                    log::error!("WebSocket error: {:?}", e);
                                     break;
                                 }
                             }
                         }
 
                         // Connection lost
-                        tracing::warn!("WebSocket connection closed");
-                        state.set(ConnectionState::Disconnected);
+                        log::warn!("WebSocket connection closed");
+                        set_state(&mut state, ConnectionState::Disconnected);
                     }
                     Err(e) => {
-                        tracing::error!("WebSocket connection failed: {:?}", e);
-                        state.set(ConnectionState::Failed);
+                        log::error!("WebSocket connection failed: {:?}", e);
+                        set_state(&mut state, ConnectionState::Failed);
                     }
                 }
 
                 // Reconnection logic
                 let current_attempts = *reconnect_attempts.read();
                 if current_attempts >= max_attempts {
-                    tracing::error!(
+                    log::error!(
                         "Max reconnection attempts ({}) reached, giving up",
                         max_attempts
                     );
-                    state.set(ConnectionState::Failed);
+                    set_state(&mut state, ConnectionState::Failed);
                     break;
                 }
 
                 reconnect_attempts.set(current_attempts + 1);
-                state.set(ConnectionState::Reconnecting);
+                set_state(&mut state, ConnectionState::Reconnecting);
 
                 // Exponential backoff
                 let backoff_delay = delay_ms * (2_u32.pow(current_attempts));
-                tracing::info!(
+                log::info!(
                     "Reconnecting in {}ms (attempt {}/{})",
                     backoff_delay,
                     current_attempts + 1,
@@ -211,7 +258,7 @@ pub fn subscribe_tx_updates(tx_hash: &str) -> impl Fn(WsMessage) {
         } = msg
         {
             if tx_hash == hash {
-                tracing::info!(
+                log::info!(
                     "Transaction {} updated: status={}, confirmations={}",
                     tx_hash,
                     status,
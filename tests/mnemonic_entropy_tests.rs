@@ -0,0 +1,85 @@
+//! 助记词生成测试
+//!
+//! 测试覆盖:
+//! - 12/24 词助记词的校验和有效性
+//! - 熵混合（白化）步骤的确定性与长度
+//! - 相同熵 → 相同助记词 → 相同账户地址
+
+#[cfg(test)]
+mod mnemonic_entropy_tests {
+    use iron_forge::crypto::bip39::{
+        entropy_len, generate_mnemonic, generate_mnemonic_with_entropy, mix_entropy,
+        mnemonic_from_entropy, validate_mnemonic, MnemonicSecret, WORD_COUNTS,
+    };
+    use iron_forge::crypto::key_manager::KeyManager;
+
+    fn eth_address(phrase: &str, index: u32) -> String {
+        let seed = MnemonicSecret::new(phrase.to_string()).to_seed("");
+        let km = KeyManager::new(seed);
+        let key = km.derive_eth_private_key(index).expect("derive key");
+        km.get_eth_address(&key).expect("address")
+    }
+
+    #[test]
+    fn generated_mnemonics_have_valid_checksum() {
+        for count in WORD_COUNTS {
+            for _ in 0..5 {
+                let mnemonic = generate_mnemonic(count).expect("generate");
+                assert_eq!(mnemonic.as_str().split_whitespace().count(), count);
+                assert!(validate_mnemonic(mnemonic.as_str()).is_ok());
+            }
+            let mixed =
+                generate_mnemonic_with_entropy(count, b"pointer samples").expect("generate");
+            assert_eq!(mixed.as_str().split_whitespace().count(), count);
+            assert!(validate_mnemonic(mixed.as_str()).is_ok());
+        }
+    }
+
+    #[test]
+    fn unsupported_word_count_is_rejected() {
+        assert_eq!(entropy_len(12).unwrap(), 16);
+        assert_eq!(entropy_len(24).unwrap(), 32);
+        assert!(entropy_len(15).is_err());
+        assert!(generate_mnemonic(18).is_err());
+    }
+
+    #[test]
+    fn entropy_mixing_is_deterministic() {
+        let system = [7u8; 32];
+        let a = mix_entropy(&system, b"moves", 16);
+        let b = mix_entropy(&system, b"moves", 16);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 16);
+        assert_eq!(mix_entropy(&system, b"moves", 32).len(), 32);
+        // 额外熵不同 → 结果不同
+        assert_ne!(a, mix_entropy(&system, b"other", 16));
+        // 额外熵为空时结果仍由系统熵决定，而不是原样输出
+        assert_ne!(mix_entropy(&system, b"", 32), system.to_vec());
+    }
+
+    #[test]
+    fn same_entropy_yields_same_accounts() {
+        let entropy = mix_entropy(&[42u8; 16], b"fixed", 16);
+        let first = mnemonic_from_entropy(&entropy).expect("mnemonic");
+        let second = mnemonic_from_entropy(&entropy).expect("mnemonic");
+        assert_eq!(first.as_str(), second.as_str());
+
+        for index in 0..3 {
+            assert_eq!(
+                eth_address(first.as_str(), index),
+                eth_address(second.as_str(), index)
+            );
+        }
+        assert_ne!(
+            eth_address(first.as_str(), 0),
+            eth_address(first.as_str(), 1)
+        );
+
+        // BIP39 标准向量：全零 128 位熵
+        let zero = mnemonic_from_entropy(&[0u8; 16]).expect("mnemonic");
+        assert_eq!(
+            zero.as_str(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+    }
+}
@@ -17,7 +17,7 @@
 #[cfg(test)]
 mod tx_signer_tests {
     use iron_forge::crypto::tx_signer::{
-        BitcoinTxSigner, EthereumTxSigner, SolanaTxSigner, TonTxSigner,
+        BitcoinTxSigner, EthereumTxSigner, SolanaTxSigner, TonTxSigner, BITCOIN_RBF_SEQUENCE,
     };
     use std::time::Instant;
 
@@ -234,6 +234,7 @@ mod tx_signer_tests {
             SOLANA_TEST_ADDRESS,
             "1000000", // 0.001 SOL (1 SOL = 1e9 lamports)
             "11111111111111111111111111111111",
+            &[],
         );
 
         assert!(result.is_ok(), "Solana transfer should succeed");
@@ -253,6 +254,7 @@ mod tx_signer_tests {
             SOLANA_TEST_ADDRESS,
             "1",
             "11111111111111111111111111111111",
+            &[],
         );
 
         assert!(result.is_ok(), "Minimal transfer should work");
@@ -266,6 +268,7 @@ mod tx_signer_tests {
             SOLANA_TEST_ADDRESS,
             "1000000000", // 1 SOL
             "11111111111111111111111111111111",
+            &[],
         );
 
         assert!(result.is_ok(), "Large transfer should work");
@@ -279,6 +282,7 @@ mod tx_signer_tests {
             "invalid_address",
             "1000000",
             "11111111111111111111111111111111",
+            &[],
         );
 
         // 取决于实现，可能返回错误或在内部处理
@@ -296,6 +300,7 @@ mod tx_signer_tests {
             SOLANA_TEST_ADDRESS,
             "1000000",
             "11111111111111111111111111111111",
+            &[],
         );
 
         let duration = start.elapsed().as_millis();
@@ -384,6 +389,42 @@ mod tx_signer_tests {
         let _ = result;
     }
 
+    /// Test 4.6: Bitcoin 默认开启 RBF，替换与 CPFP 交易携带对应字段
+    #[test]
+    fn test_bitcoin_rbf_and_cpfp_payloads() {
+        let original =
+            BitcoinTxSigner::sign_transaction(TEST_PRIVATE_KEY, BTC_TEST_ADDRESS, "100000", 10)
+                .unwrap();
+        let original: serde_json::Value = serde_json::from_str(&original).unwrap();
+        assert_eq!(original["rbf"], true);
+        assert_eq!(original["sequence"], BITCOIN_RBF_SEQUENCE);
+
+        let replacement = BitcoinTxSigner::sign_replacement(
+            TEST_PRIVATE_KEY,
+            BTC_TEST_ADDRESS,
+            "100000",
+            15,
+            "aa".repeat(32).as_str(),
+        )
+        .unwrap();
+        let replacement: serde_json::Value = serde_json::from_str(&replacement).unwrap();
+        assert_eq!(replacement["fee_rate"], 15);
+        assert_eq!(replacement["replaces"], "aa".repeat(32));
+
+        let child = BitcoinTxSigner::sign_cpfp(
+            TEST_PRIVATE_KEY,
+            "bb".repeat(32).as_str(),
+            Some(1),
+            BTC_TEST_ADDRESS,
+            "50000",
+            40,
+        )
+        .unwrap();
+        let child: serde_json::Value = serde_json::from_str(&child).unwrap();
+        assert_eq!(child["spend_parent"]["vout"], 1);
+        assert_eq!(child["subtract_fee_from_amount"], true);
+    }
+
     // ============ TON 签名测试 ============
 
     /// Test 5.1: TON 标准转账
@@ -533,6 +574,7 @@ mod tx_signer_tests {
                 SOLANA_TEST_ADDRESS,
                 "1000000000", // 1 SOL
                 "11111111111111111111111111111111",
+                &[],
             );
             assert!(result.is_ok(), "Solana signing should succeed");
             signatures.push(result.unwrap());